@@ -0,0 +1,78 @@
+use iced::{
+    widget::{button, column, container, row, text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{animated_number::Transition, helpers::animated_number};
+
+fn main() -> iced::Result {
+    AnimatedNumberExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Add(f64),
+    Double,
+    Reset,
+}
+
+struct AnimatedNumberExample {
+    visitors: f64,
+}
+
+impl Sandbox for AnimatedNumberExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        AnimatedNumberExample { visitors: 1284.0 }
+    }
+
+    fn title(&self) -> String {
+        String::from("AnimatedNumber example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Add(amount) => self.visitors = (self.visitors + amount).max(0.0),
+            Message::Double => self.visitors *= 2.0,
+            Message::Reset => self.visitors = 0.0,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let odometer = column![
+            text("Visitors"),
+            animated_number(self.visitors).text_size(48.0),
+        ]
+        .align_items(Alignment::End);
+
+        let revenue = column![
+            text("Revenue"),
+            animated_number(self.visitors * 2.49)
+                .transition(Transition::Count)
+                .format(|value| format!("$ {value:.2}"))
+                .text_size(48.0),
+        ]
+        .align_items(Alignment::End);
+
+        let controls = row![
+            button("-12").on_press(Message::Add(-12.0)),
+            button("+1").on_press(Message::Add(1.0)),
+            button("+37").on_press(Message::Add(37.0)),
+            button("Double").on_press(Message::Double),
+            button("Reset").on_press(Message::Reset),
+        ]
+        .spacing(10);
+
+        container(
+            column![row![odometer, revenue].spacing(60), controls]
+                .spacing(30)
+                .align_items(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x()
+        .center_y()
+        .into()
+    }
+}
@@ -0,0 +1,170 @@
+use iced::{
+    executor, font,
+    widget::{column, container, pick_list, text},
+    Alignment, Application, Command, Element, Length, Settings, Subscription, Theme,
+};
+
+use iced_aw::{helpers::auth_form, AuthFormStyles};
+use std::time::Duration;
+
+fn main() -> iced::Result {
+    AuthFormExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    EmailChanged(String),
+    PasswordChanged(String),
+    RevealToggled(bool),
+    RememberToggled(bool),
+    Submit,
+    Authenticated,
+    StyleSelected(Style),
+    FontLoaded(Result<(), font::Error>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Style {
+    Default,
+    Material,
+    Cupertino,
+}
+
+impl Style {
+    const ALL: [Style; 3] = [Style::Default, Style::Material, Style::Cupertino];
+}
+
+impl std::fmt::Display for Style {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Style::Default => "Default",
+            Style::Material => "Material",
+            Style::Cupertino => "Cupertino",
+        })
+    }
+}
+
+struct AuthFormExample {
+    email: String,
+    password: String,
+    revealed: bool,
+    remember: bool,
+    loading: bool,
+    error: Option<String>,
+    signed_in: bool,
+    style: Style,
+}
+
+impl Application for AuthFormExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        (
+            AuthFormExample {
+                email: String::new(),
+                password: String::new(),
+                revealed: false,
+                remember: true,
+                loading: false,
+                error: None,
+                signed_in: false,
+                style: Style::Default,
+            },
+            font::load(iced_aw::graphics::icons::ICON_FONT_BYTES).map(Message::FontLoaded),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("AuthForm example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::EmailChanged(email) => self.email = email,
+            Message::PasswordChanged(password) => self.password = password,
+            Message::RevealToggled(revealed) => self.revealed = revealed,
+            Message::RememberToggled(remember) => self.remember = remember,
+            Message::Submit => {
+                self.loading = true;
+                self.error = None;
+            }
+            Message::Authenticated => {
+                self.loading = false;
+                if self.password == "password" {
+                    self.signed_in = true;
+                } else {
+                    self.error = Some(String::from("Wrong email or password. Try \"password\"."));
+                }
+            }
+            Message::StyleSelected(style) => self.style = style,
+            Message::FontLoaded(_) => {}
+        }
+
+        Command::none()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        // Pretend to ask a server while loading.
+        if self.loading {
+            iced::time::every(Duration::from_secs(1)).map(|_| Message::Authenticated)
+        } else {
+            Subscription::none()
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let content: Element<'_, Message> = if self.signed_in {
+            text(format!("Signed in as {}", self.email)).into()
+        } else {
+            auth_form(
+                &self.email,
+                Message::EmailChanged,
+                &self.password,
+                Message::PasswordChanged,
+                Message::Submit,
+            )
+            .title("Sign in")
+            .validate_identifier(|email| {
+                if email.contains('@') {
+                    Ok(())
+                } else {
+                    Err(String::from("Enter a valid email address"))
+                }
+            })
+            .validate_password(|password| {
+                if password.len() >= 6 {
+                    Ok(())
+                } else {
+                    Err(String::from("The password has at least 6 characters"))
+                }
+            })
+            .reveal(self.revealed, Message::RevealToggled)
+            .remember(self.remember, Message::RememberToggled)
+            .loading(self.loading)
+            .error(self.error.as_deref())
+            .style(match self.style {
+                Style::Default => AuthFormStyles::Default,
+                Style::Material => AuthFormStyles::Material,
+                Style::Cupertino => AuthFormStyles::Cupertino,
+            })
+            .into()
+        };
+
+        container(
+            column![
+                pick_list(&Style::ALL[..], Some(self.style), Message::StyleSelected),
+                content,
+            ]
+            .spacing(20)
+            .align_items(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x()
+        .center_y()
+        .into()
+    }
+}
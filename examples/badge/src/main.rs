@@ -1,9 +1,9 @@
 use iced::{
-    widget::{Column, Container, Row, Text},
+    widget::{Button, Column, Container, Row, Text},
     Alignment, Element, Length, Sandbox, Settings,
 };
 
-use iced_aw::{helpers::badge, style::BadgeStyles};
+use iced_aw::{helpers::badge, style::BadgeStyles, Badge};
 
 const BADGE_TEXT_SIZE: u16 = 15;
 
@@ -12,10 +12,16 @@ fn main() -> iced::Result {
 }
 
 #[derive(Debug, Clone)]
-enum Message {}
+enum Message {
+    Receive(usize),
+    ReadAll,
+    Announce(String),
+}
 
 struct BadgeExample {
     messages: Vec<(String, usize)>,
+    unread: usize,
+    announcement: String,
 }
 
 impl Sandbox for BadgeExample {
@@ -28,6 +34,8 @@ impl Sandbox for BadgeExample {
                 ("Keanu Reeves".to_string(), 42),
                 ("Stephen Hawking".to_string(), 21),
             ],
+            unread: 3,
+            announcement: String::new(),
         }
     }
 
@@ -35,7 +43,13 @@ impl Sandbox for BadgeExample {
         String::from("Badge example")
     }
 
-    fn update(&mut self, _message: Message) {}
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Receive(count) => self.unread += count,
+            Message::ReadAll => self.unread = 0,
+            Message::Announce(announcement) => self.announcement = announcement,
+        }
+    }
 
     fn view(&self) -> Element<Message> {
         let content = Column::new()
@@ -59,6 +73,29 @@ impl Sandbox for BadgeExample {
                     )
                 });
 
+        let content_inbox = Column::new()
+            .spacing(10)
+            .push(
+                Row::new()
+                    .spacing(10)
+                    .align_items(Alignment::Center)
+                    .push(Text::new("Inbox").size(32))
+                    .push(
+                        Badge::with_count(self.unread, 99)
+                            .style(BadgeStyles::Danger)
+                            .accessible_label("unread message", "unread messages")
+                            .on_announce(Message::Announce),
+                    ),
+            )
+            .push(
+                Row::new()
+                    .spacing(10)
+                    .push(Button::new(Text::new("Receive 1")).on_press(Message::Receive(1)))
+                    .push(Button::new(Text::new("Receive 50")).on_press(Message::Receive(50)))
+                    .push(Button::new(Text::new("Read all")).on_press(Message::ReadAll)),
+            )
+            .push(Text::new(format!("Announced: {}", self.announcement)));
+
         let content_all = Column::new()
             .spacing(10)
             .push(Text::new("All available badge styles:").size(32))
@@ -85,6 +122,7 @@ impl Sandbox for BadgeExample {
             Column::new()
                 .spacing(40)
                 .push(content_messages)
+                .push(content_inbox)
                 .push(content_all),
         )
         .width(Length::Fill)
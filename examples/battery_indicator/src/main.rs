@@ -0,0 +1,85 @@
+use iced::{
+    widget::{checkbox, column, container, row, slider, text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{helpers::battery_indicator, style::BatteryIndicatorStyles};
+
+fn main() -> iced::Result {
+    BatteryIndicatorExample::run(Settings::default())
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Message {
+    Level(f32),
+    Charging(bool),
+}
+
+struct BatteryIndicatorExample {
+    level: f32,
+    charging: bool,
+}
+
+impl Sandbox for BatteryIndicatorExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        BatteryIndicatorExample {
+            level: 0.75,
+            charging: false,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("BatteryIndicator example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Level(level) => self.level = level,
+            Message::Charging(charging) => self.charging = charging,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let indicator = |style| {
+            battery_indicator(self.level)
+                .charging(self.charging)
+                .show_percentage()
+                .size(16.0)
+                .style(style)
+        };
+
+        let indicators = row![
+            indicator(BatteryIndicatorStyles::Default),
+            indicator(BatteryIndicatorStyles::Material),
+            indicator(BatteryIndicatorStyles::Cupertino),
+        ]
+        .spacing(30)
+        .align_items(Alignment::Center);
+
+        let level = row![
+            text("Charge"),
+            slider(0.0..=1.0, self.level, Message::Level)
+                .step(0.01)
+                .width(200),
+        ]
+        .spacing(10)
+        .align_items(Alignment::Center);
+
+        container(
+            column![
+                indicators,
+                level,
+                checkbox("Charging", self.charging, Message::Charging),
+            ]
+            .spacing(30)
+            .align_items(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x()
+        .center_y()
+        .into()
+    }
+}
@@ -0,0 +1,107 @@
+use iced::{
+    executor, font,
+    widget::{checkbox, column, container, row, text, Column},
+    Application, Command, Element, Length, Settings, Theme,
+};
+
+use iced_aw::{
+    calendar::{Date, Weekday},
+    helpers::calendar,
+};
+
+fn main() -> iced::Result {
+    CalendarExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Select(Date),
+    WeekendsToggled(bool),
+    WeekNumbersToggled(bool),
+    FontLoaded(Result<(), font::Error>),
+}
+
+struct CalendarExample {
+    date: Date,
+    appointments: Vec<(Date, &'static str)>,
+    weekends: bool,
+    week_numbers: bool,
+}
+
+impl Application for CalendarExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        let today = Date::today();
+
+        (
+            CalendarExample {
+                date: today,
+                appointments: vec![
+                    (today, "Team meeting at 10:00"),
+                    (today, "Lunch with Alex"),
+                    (today.add_days(1), "Dentist at 15:30"),
+                    (today.add_days(3), "Release planning"),
+                    (today.add_days(9), "Conference talk"),
+                ],
+                weekends: false,
+                week_numbers: true,
+            },
+            font::load(iced_aw::graphics::icons::ICON_FONT_BYTES).map(Message::FontLoaded),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Calendar example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::Select(date) => self.date = date,
+            Message::WeekendsToggled(weekends) => self.weekends = weekends,
+            Message::WeekNumbersToggled(week_numbers) => self.week_numbers = week_numbers,
+            Message::FontLoaded(_) => {}
+        }
+
+        Command::none()
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let weekends = self.weekends;
+        let calendar = calendar(self.date, Message::Select)
+            .week_numbers(self.week_numbers)
+            .disable_if(move |date| {
+                !weekends && matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+            });
+
+        let agenda = self
+            .appointments
+            .iter()
+            .filter(|(date, _)| *date == self.date)
+            .fold(
+                Column::new()
+                    .spacing(10)
+                    .push(text(format!("Agenda for {}", self.date)).size(24)),
+                |agenda, (_, appointment)| agenda.push(text(appointment)),
+            );
+
+        let options = row![
+            checkbox("Weekends", self.weekends, Message::WeekendsToggled),
+            checkbox(
+                "Week numbers",
+                self.week_numbers,
+                Message::WeekNumbersToggled
+            ),
+        ]
+        .spacing(20);
+
+        container(column![row![calendar, agenda].spacing(30), options].spacing(20))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(20)
+            .into()
+    }
+}
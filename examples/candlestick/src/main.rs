@@ -0,0 +1,112 @@
+use iced::{
+    widget::{checkbox, column, container, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{
+    candlestick::{moving_average, Annotation, Candle},
+    helpers::candlestick_chart,
+};
+
+fn main() -> iced::Result {
+    CandlestickExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    BarsToggled(bool),
+}
+
+struct CandlestickExample {
+    candles: Vec<Candle>,
+    short_average: Vec<Option<f32>>,
+    long_average: Vec<Option<f32>>,
+    bars: bool,
+}
+
+/// Simulates a year of daily prices as a random walk.
+fn candles() -> Vec<Candle> {
+    // A cheap linear congruential generator is enough for a random walk.
+    let mut seed: u32 = 7;
+    let mut random = move || {
+        seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        f64::from(seed >> 8) / f64::from(1_u32 << 24) - 0.5
+    };
+
+    let mut close = 100.0;
+    (0..365_u16)
+        .map(|day| {
+            let open = close;
+            close = (open + random() * 4.0 + 0.05).max(1.0);
+            let high = open.max(close) + random().abs() * 2.0;
+            let low = open.min(close) - random().abs() * 2.0;
+
+            #[allow(clippy::cast_possible_truncation)]
+            Candle::new(
+                f64::from(day),
+                open as f32,
+                high as f32,
+                low as f32,
+                close as f32,
+            )
+        })
+        .collect()
+}
+
+impl Sandbox for CandlestickExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        let candles = candles();
+
+        CandlestickExample {
+            short_average: moving_average(&candles, 20),
+            long_average: moving_average(&candles, 50),
+            candles,
+            bars: false,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("CandlestickChart example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::BarsToggled(bars) => self.bars = bars,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let peak = self
+            .candles
+            .iter()
+            .max_by(|a, b| a.high.total_cmp(&b.high))
+            .map(|candle| Annotation::point(candle.time, candle.high, "Peak"));
+
+        let chart = candlestick_chart(&self.candles)
+            .overlay("SMA 20", &self.short_average)
+            .overlay("SMA 50", &self.long_average)
+            .annotation(Annotation::time_region(273.0, 364.0, "Q4"))
+            .annotation(Annotation::vertical_line(300.0, "Earnings"))
+            .visible(90)
+            .bars(self.bars)
+            .format_time(|day| format!("Day {}", day + 1.0));
+        let chart = peak
+            .into_iter()
+            .fold(chart, |chart, peak| chart.annotation(peak));
+
+        let content = column![
+            checkbox("OHLC bars", self.bars, Message::BarsToggled),
+            chart,
+            text("Scroll to zoom, drag to pan and hover the candles to read their prices"),
+        ]
+        .spacing(20)
+        .padding(40);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+}
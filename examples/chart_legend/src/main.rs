@@ -0,0 +1,96 @@
+use iced::{
+    widget::{column, container},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{
+    helpers::{chart_legend, radar_chart},
+    style::radar::Appearance,
+};
+
+fn main() -> iced::Result {
+    ChartLegendExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Toggle(usize, bool),
+    Highlight(Option<usize>),
+}
+
+struct ChartLegendExample {
+    axes: Vec<String>,
+    phones: Vec<(&'static str, Vec<f32>)>,
+    visible: Vec<bool>,
+    highlight: Option<usize>,
+}
+
+impl Sandbox for ChartLegendExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        let phones = vec![
+            ("Phone A", vec![8.0, 6.0, 9.0, 5.0, 7.0]),
+            ("Phone B", vec![6.0, 9.0, 5.0, 8.0, 6.0]),
+            ("Phone C", vec![9.0, 4.0, 6.0, 9.0, 5.0]),
+            ("Phone D", vec![5.0, 7.0, 8.0, 6.0, 9.0]),
+        ];
+
+        ChartLegendExample {
+            axes: ["Camera", "Battery", "Display", "Speed", "Price"]
+                .map(String::from)
+                .to_vec(),
+            visible: vec![true; phones.len()],
+            phones,
+            highlight: None,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("ChartLegend example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Toggle(index, visible) => self.visible[index] = visible,
+            Message::Highlight(highlight) => self.highlight = highlight,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        // The legend uses the colors the chart gives its series.
+        let palette = Appearance::default().palette;
+
+        let chart = self
+            .phones
+            .iter()
+            .fold(radar_chart(&self.axes), |chart, (name, scores)| {
+                chart.series(*name, scores)
+            })
+            .range(0.0, 10.0)
+            .rings(5)
+            .visible(&self.visible)
+            .highlight(self.highlight);
+
+        let legend = self
+            .phones
+            .iter()
+            .zip(&self.visible)
+            .enumerate()
+            .fold(chart_legend(), |legend, (index, ((name, _), visible))| {
+                legend.entry(*name, palette[index % palette.len()], *visible)
+            })
+            .on_toggle(Message::Toggle)
+            .on_highlight(Message::Highlight);
+
+        container(
+            column![chart, legend]
+                .spacing(20)
+                .align_items(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(20)
+        .into()
+    }
+}
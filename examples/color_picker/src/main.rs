@@ -4,7 +4,7 @@ use iced::{
     Alignment, Application, Color, Command, Element, Length, Settings, Theme,
 };
 
-use iced_aw::helpers::color_picker;
+use iced_aw::{color_picker::ColorSpace, helpers::color_picker};
 
 fn main() -> iced::Result {
     ColorPickerExample::run(Settings::default())
@@ -104,7 +104,14 @@ impl Application for ColorPickerExample {
                     but,
                     Message::CancelColor,
                     Message::SubmitColor,
-                );
+                )
+                .swatches([
+                    Color::from_rgb8(0x1E, 0x88, 0xE5),
+                    Color::from_rgb8(0x43, 0xA0, 0x47),
+                    Color::from_rgb8(0xFB, 0x8C, 0x00),
+                    Color::from_rgb8(0xE5, 0x39, 0x35),
+                ])
+                .color_space(ColorSpace::Hsv);
 
                 let row = Row::new()
                     .align_items(Alignment::Center)
@@ -0,0 +1,97 @@
+use iced::{
+    font,
+    widget::{container, text},
+    Application, Color, Command, Element, Length, Settings, Theme,
+};
+
+use iced_aw::{contrast_checker::Swatch, helpers::contrast_checker};
+
+fn main() -> iced::Result {
+    ContrastCheckerExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Pick(Option<Swatch>),
+    Changed(Swatch, Color),
+    FontLoaded(Result<(), font::Error>),
+}
+
+enum ContrastCheckerExample {
+    Loading,
+    Loaded(State),
+}
+
+struct State {
+    foreground: Color,
+    background: Color,
+    picking: Option<Swatch>,
+}
+
+impl Application for ContrastCheckerExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        (
+            ContrastCheckerExample::Loading,
+            font::load(iced_aw::graphics::icons::ICON_FONT_BYTES).map(Message::FontLoaded),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Contrast checker example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match self {
+            ContrastCheckerExample::Loading => {
+                if let Message::FontLoaded(_) = message {
+                    *self = ContrastCheckerExample::Loaded(State {
+                        foreground: Color::from_rgb8(0x76, 0x76, 0x76),
+                        background: Color::WHITE,
+                        picking: None,
+                    });
+                }
+            }
+            ContrastCheckerExample::Loaded(state) => match message {
+                Message::Pick(swatch) => state.picking = swatch,
+                Message::Changed(Swatch::Foreground, color) => {
+                    state.foreground = color;
+                    state.picking = None;
+                }
+                Message::Changed(Swatch::Background, color) => {
+                    state.background = color;
+                    state.picking = None;
+                }
+                Message::FontLoaded(_) => {}
+            },
+        }
+
+        Command::none()
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let content: Element<'_, Message> = match self {
+            ContrastCheckerExample::Loading => text("Loading").into(),
+            ContrastCheckerExample::Loaded(state) => contrast_checker(
+                state.foreground,
+                state.background,
+                state.picking,
+                Message::Pick,
+                Message::Changed,
+            )
+            .into(),
+        };
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(20)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
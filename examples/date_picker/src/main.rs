@@ -1,9 +1,14 @@
 use iced::{
     alignment, font,
-    widget::{container, text, Button, Container, Row, Text},
+    widget::{checkbox, container, radio, text, Button, Column, Container, Row, Text},
     Alignment, Application, Command, Element, Length, Settings, Theme,
 };
-use iced_aw::{date_picker::Date, helpers::date_picker};
+use iced_aw::{
+    date_picker::{Date, Weekday},
+    helpers::{date_picker, date_picker_multiple, date_picker_range},
+    i18n::{self, English, Translations},
+};
+use std::collections::BTreeSet;
 
 fn main() -> iced::Result {
     DatePickerExample::run(Settings::default())
@@ -15,6 +20,15 @@ enum Message {
     ChooseDate,
     SubmitDate(Date),
     CancelDate,
+    ChooseDates,
+    SubmitDates(BTreeSet<Date>),
+    CancelDates,
+    ChooseRange,
+    SubmitRange(Date, Date),
+    CancelRange,
+    GermanToggled(bool),
+    FirstDayChanged(Weekday),
+    WeekNumbersToggled(bool),
     Loaded(Result<(), String>),
     FontLoaded(Result<(), font::Error>),
 }
@@ -27,6 +41,56 @@ enum DatePickerExample {
 struct State {
     date: Date,
     show_picker: bool,
+    dates: BTreeSet<Date>,
+    show_multiple_picker: bool,
+    range: (Date, Date),
+    show_range_picker: bool,
+    german: bool,
+    first_day_of_week: Weekday,
+    week_numbers: bool,
+}
+
+/// The German names of the months and the weekdays and the labels of the
+/// buttons.
+struct German;
+
+impl Translations for German {
+    fn cancel(&self) -> String {
+        String::from("Abbrechen")
+    }
+
+    fn submit(&self) -> String {
+        String::from("Übernehmen")
+    }
+
+    fn month(&self, month: u32) -> String {
+        [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ]
+        .get(month as usize - 1)
+        .map_or_else(String::new, |name| (*name).to_owned())
+    }
+
+    fn weekday(&self, weekday: u32) -> String {
+        ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"]
+            .get(weekday as usize)
+            .map_or_else(String::new, |name| (*name).to_owned())
+    }
+
+    fn week(&self) -> String {
+        String::from("KW")
+    }
 }
 
 async fn load() -> Result<(), String> {
@@ -60,6 +124,13 @@ impl Application for DatePickerExample {
                     *self = DatePickerExample::Loaded(State {
                         date: Date::today(),
                         show_picker: false,
+                        dates: BTreeSet::new(),
+                        show_multiple_picker: false,
+                        range: (Date::today(), Date::today()),
+                        show_range_picker: false,
+                        german: false,
+                        first_day_of_week: Weekday::Mon,
+                        week_numbers: false,
                     })
                 }
             }
@@ -74,6 +145,40 @@ impl Application for DatePickerExample {
                 Message::CancelDate => {
                     state.show_picker = false;
                 }
+                Message::ChooseDates => {
+                    state.show_multiple_picker = true;
+                }
+                Message::SubmitDates(dates) => {
+                    state.dates = dates;
+                    state.show_multiple_picker = false;
+                }
+                Message::CancelDates => {
+                    state.show_multiple_picker = false;
+                }
+                Message::ChooseRange => {
+                    state.show_range_picker = true;
+                }
+                Message::SubmitRange(start, end) => {
+                    state.range = (start, end);
+                    state.show_range_picker = false;
+                }
+                Message::CancelRange => {
+                    state.show_range_picker = false;
+                }
+                Message::GermanToggled(german) => {
+                    state.german = german;
+                    if german {
+                        i18n::set(German);
+                    } else {
+                        i18n::set(English);
+                    }
+                }
+                Message::FirstDayChanged(first_day_of_week) => {
+                    state.first_day_of_week = first_day_of_week;
+                }
+                Message::WeekNumbersToggled(week_numbers) => {
+                    state.week_numbers = week_numbers;
+                }
                 _ => {}
             },
         }
@@ -102,7 +207,11 @@ impl Application for DatePickerExample {
                     but,
                     Message::CancelDate,
                     Message::SubmitDate,
-                );
+                )
+                .labeled_buttons(true)
+                .first_day_of_week(state.first_day_of_week)
+                .week_numbers(state.week_numbers)
+                .input_format("%Y-%m-%d");
 
                 let row = Row::new()
                     .align_items(Alignment::Center)
@@ -110,7 +219,88 @@ impl Application for DatePickerExample {
                     .push(datepicker)
                     .push(Text::new(format!("Date: {}", state.date,)));
 
-                Container::new(row)
+                let but = Button::new(Text::new("Set Workdays")).on_press(Message::ChooseDates);
+
+                let multiple_datepicker = date_picker_multiple(
+                    state.show_multiple_picker,
+                    state.dates.iter().copied(),
+                    but,
+                    Message::CancelDates,
+                    Message::SubmitDates,
+                )
+                .disable_if(|date| date.weekday().number_from_monday() > 5)
+                .first_day_of_week(state.first_day_of_week)
+                .pick_weeks(state.week_numbers);
+
+                let dates = state
+                    .dates
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                let multiple_row = Row::new()
+                    .align_items(Alignment::Center)
+                    .spacing(10)
+                    .push(multiple_datepicker)
+                    .push(Text::new(format!("Workdays: {dates}")));
+
+                let but = Button::new(Text::new("Set Range")).on_press(Message::ChooseRange);
+
+                let range_datepicker = date_picker_range(
+                    state.show_range_picker,
+                    state.range,
+                    but,
+                    Message::CancelRange,
+                    Message::SubmitRange,
+                )
+                .min_date(Date::today())
+                .first_day_of_week(state.first_day_of_week)
+                .pick_weeks(state.week_numbers)
+                .months_shown(2);
+
+                let range_row = Row::new()
+                    .align_items(Alignment::Center)
+                    .spacing(10)
+                    .push(range_datepicker)
+                    .push(Text::new(format!(
+                        "Range: {} to {}",
+                        state.range.0, state.range.1
+                    )));
+
+                let first_days = [
+                    ("Sunday", Weekday::Sun),
+                    ("Monday", Weekday::Mon),
+                    ("Saturday", Weekday::Sat),
+                ]
+                .into_iter()
+                .fold(
+                    Row::new().spacing(10).push(Text::new("Weeks start on")),
+                    |row, (label, weekday)| {
+                        row.push(radio(
+                            label,
+                            weekday,
+                            Some(state.first_day_of_week),
+                            Message::FirstDayChanged,
+                        ))
+                    },
+                );
+
+                let column = Column::new()
+                    .align_items(Alignment::Center)
+                    .spacing(20)
+                    .push(checkbox("Deutsch", state.german, Message::GermanToggled))
+                    .push(first_days)
+                    .push(checkbox(
+                        "Week numbers",
+                        state.week_numbers,
+                        Message::WeekNumbersToggled,
+                    ))
+                    .push(row)
+                    .push(multiple_row)
+                    .push(range_row);
+
+                Container::new(column)
                     .center_x()
                     .center_y()
                     .width(Length::Fill)
@@ -0,0 +1,133 @@
+use iced::{
+    alignment, font,
+    widget::{container, text, Button, Container, Row, Text},
+    Alignment, Application, Command, Element, Length, Settings, Theme,
+};
+use iced_aw::{date_picker::Date, time_picker::Time, DateTimePicker};
+
+fn main() -> iced::Result {
+    DateTimePickerExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    ChooseDateTime,
+    SubmitDateTime(Date, Time),
+    CancelDateTime,
+    Loaded(Result<(), String>),
+    FontLoaded(Result<(), font::Error>),
+}
+
+#[derive(Debug)]
+enum DateTimePickerExample {
+    Loading,
+    Loaded(State),
+}
+
+#[derive(Debug)]
+struct State {
+    date: Date,
+    time: Time,
+    show_picker: bool,
+}
+
+async fn load() -> Result<(), String> {
+    Ok(())
+}
+
+impl Application for DateTimePickerExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (DateTimePickerExample, Command<Message>) {
+        (
+            DateTimePickerExample::Loading,
+            Command::batch(vec![
+                font::load(iced_aw::graphics::icons::ICON_FONT_BYTES).map(Message::FontLoaded),
+                Command::perform(load(), Message::Loaded),
+            ]),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("DateTimePicker example")
+    }
+
+    fn update(&mut self, message: Self::Message) -> Command<Message> {
+        match self {
+            DateTimePickerExample::Loading => {
+                if let Message::Loaded(_) = message {
+                    *self = DateTimePickerExample::Loaded(State {
+                        date: Date::today(),
+                        time: Time::now_hm(true),
+                        show_picker: false,
+                    })
+                }
+            }
+            DateTimePickerExample::Loaded(state) => match message {
+                Message::ChooseDateTime => {
+                    state.show_picker = true;
+                }
+                Message::SubmitDateTime(date, time) => {
+                    state.date = date;
+                    state.time = time;
+                    state.show_picker = false;
+                }
+                Message::CancelDateTime => {
+                    state.show_picker = false;
+                }
+                _ => {}
+            },
+        }
+
+        Command::none()
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        match self {
+            DateTimePickerExample::Loading => container(
+                text("Loading...")
+                    .horizontal_alignment(alignment::Horizontal::Center)
+                    .size(50),
+            )
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_y()
+            .center_x()
+            .into(),
+            DateTimePickerExample::Loaded(state) => {
+                let but =
+                    Button::new(Text::new("Set Appointment")).on_press(Message::ChooseDateTime);
+
+                let date_time_picker = DateTimePicker::new(
+                    state.show_picker,
+                    state.date,
+                    state.time,
+                    but,
+                    Message::CancelDateTime,
+                    Message::SubmitDateTime,
+                )
+                .use_24h()
+                .minute_step(15);
+
+                let row = Row::new()
+                    .align_items(Alignment::Center)
+                    .spacing(10)
+                    .push(date_time_picker)
+                    .push(Text::new(format!(
+                        "Appointment: {} {}",
+                        state.date, state.time
+                    )));
+
+                Container::new(row)
+                    .center_x()
+                    .center_y()
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .into()
+            }
+        }
+    }
+}
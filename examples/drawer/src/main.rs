@@ -0,0 +1,110 @@
+use iced::{
+    widget::{button, column, container, pick_list, row, text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::drawer::{DrawerMode, Edge};
+use iced_aw::helpers::drawer;
+
+fn main() -> iced::Result {
+    DrawerExample::run(Settings::default())
+}
+
+const EDGES: [Edge; 4] = [Edge::Left, Edge::Right, Edge::Top, Edge::Bottom];
+
+#[derive(Clone, Debug)]
+enum Message {
+    Open,
+    Close,
+    EdgeSelected(Edge),
+    TogglePush,
+}
+
+struct DrawerExample {
+    open: bool,
+    edge: Edge,
+    push: bool,
+}
+
+impl Sandbox for DrawerExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        DrawerExample {
+            open: false,
+            edge: Edge::Left,
+            push: false,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Drawer example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Open => self.open = true,
+            Message::Close => self.open = false,
+            Message::EdgeSelected(edge) => self.edge = edge,
+            Message::TogglePush => self.push = !self.push,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let underlay = container(
+            column![
+                row![
+                    text("Edge:"),
+                    pick_list(
+                        EDGES
+                            .iter()
+                            .map(|edge| format!("{edge:?}"))
+                            .collect::<Vec<_>>(),
+                        Some(format!("{:?}", self.edge)),
+                        |selected| {
+                            Message::EdgeSelected(
+                                EDGES
+                                    .into_iter()
+                                    .find(|edge| format!("{edge:?}") == selected)
+                                    .unwrap_or_default(),
+                            )
+                        },
+                    ),
+                ]
+                .spacing(10)
+                .align_items(Alignment::Center),
+                button(text(if self.push {
+                    "Mode: push"
+                } else {
+                    "Mode: overlay"
+                }))
+                .on_press(Message::TogglePush),
+                button(text("Open drawer")).on_press(Message::Open),
+            ]
+            .spacing(10)
+            .align_items(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x()
+        .center_y();
+
+        let content = column![
+            text("Drawer").size(24),
+            text("Swipe the panel back, press Escape or click the backdrop to close it."),
+            button(text("Close")).on_press(Message::Close),
+        ]
+        .spacing(10)
+        .padding(20);
+
+        drawer(self.open, underlay, content)
+            .edge(self.edge)
+            .mode(if self.push {
+                DrawerMode::Push
+            } else {
+                DrawerMode::Overlay
+            })
+            .on_dismiss(Message::Close)
+            .into()
+    }
+}
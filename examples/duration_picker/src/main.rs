@@ -0,0 +1,97 @@
+use std::time::Duration;
+
+use iced::{
+    font,
+    widget::{Button, Container, Row, Text},
+    Alignment, Application, Command, Element, Length, Settings, Theme,
+};
+use iced_aw::DurationPicker;
+
+fn main() -> iced::Result {
+    DurationPickerExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    ChooseDuration,
+    SubmitDuration(Duration),
+    CancelDuration,
+    FontLoaded(Result<(), font::Error>),
+}
+
+struct DurationPickerExample {
+    duration: Duration,
+    show_picker: bool,
+}
+
+impl Application for DurationPickerExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        (
+            DurationPickerExample {
+                duration: Duration::from_mins(25),
+                show_picker: false,
+            },
+            font::load(iced_aw::graphics::icons::ICON_FONT_BYTES).map(Message::FontLoaded),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("DurationPicker example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::ChooseDuration => {
+                self.show_picker = true;
+            }
+            Message::SubmitDuration(duration) => {
+                self.duration = duration;
+                self.show_picker = false;
+            }
+            Message::CancelDuration => {
+                self.show_picker = false;
+            }
+            Message::FontLoaded(_) => {}
+        }
+
+        Command::none()
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let but = Button::new(Text::new("Set Timer")).on_press(Message::ChooseDuration);
+
+        let duration_picker = DurationPicker::new(
+            self.show_picker,
+            self.duration,
+            but,
+            Message::CancelDuration,
+            Message::SubmitDuration,
+        )
+        .show_seconds();
+
+        let seconds = self.duration.as_secs();
+
+        let row = Row::new()
+            .align_items(Alignment::Center)
+            .spacing(10)
+            .push(duration_picker)
+            .push(Text::new(format!(
+                "Timer: {}:{:02}:{:02}",
+                seconds / 3600,
+                seconds / 60 % 60,
+                seconds % 60
+            )));
+
+        Container::new(row)
+            .center_x()
+            .center_y()
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+}
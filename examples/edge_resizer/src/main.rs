@@ -0,0 +1,119 @@
+use iced::{
+    font, subscription,
+    widget::{column, container, text},
+    window, Application, Command, Element, Event, Length, Point, Settings, Size, Subscription,
+    Theme, Vector,
+};
+
+use iced_aw::{
+    helpers::{edge_resizer, title_bar},
+    ResizeDirection, WindowControl,
+};
+
+const INITIAL_SIZE: Size = Size::new(640.0, 480.0);
+const MIN_SIZE: Size = Size::new(320.0, 240.0);
+
+fn main() -> iced::Result {
+    EdgeResizerExample::run(Settings {
+        window: window::Settings {
+            size: (INITIAL_SIZE.width as u32, INITIAL_SIZE.height as u32),
+            decorations: false,
+            ..window::Settings::default()
+        },
+        ..Settings::default()
+    })
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Window(WindowControl),
+    Resize(ResizeDirection, Vector),
+    Resized(Size),
+    Moved(Point),
+    FontLoaded(Result<(), font::Error>),
+}
+
+struct EdgeResizerExample {
+    size: Size,
+    position: Point,
+}
+
+impl Application for EdgeResizerExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        (
+            EdgeResizerExample {
+                size: INITIAL_SIZE,
+                position: Point::ORIGIN,
+            },
+            font::load(iced_aw::graphics::icons::ICON_FONT_BYTES).map(Message::FontLoaded),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("EdgeResizer example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::Window(control) => control.command(),
+            Message::Resize(direction, delta) => {
+                let (size, offset) = direction.resize(self.size, delta, MIN_SIZE);
+                self.size = size;
+
+                let resize = window::resize(iced::Size::new(size.width as u32, size.height as u32));
+
+                if offset == Vector::ZERO {
+                    resize
+                } else {
+                    self.position = self.position + offset;
+                    Command::batch([
+                        window::move_to(self.position.x as i32, self.position.y as i32),
+                        resize,
+                    ])
+                }
+            }
+            Message::Resized(size) => {
+                self.size = size;
+                Command::none()
+            }
+            Message::Moved(position) => {
+                self.position = position;
+                Command::none()
+            }
+            Message::FontLoaded(_) => Command::none(),
+        }
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        subscription::events_with(|event, _status| match event {
+            Event::Window(window::Event::Resized { width, height }) => {
+                Some(Message::Resized(Size::new(width as f32, height as f32)))
+            }
+            Event::Window(window::Event::Moved { x, y }) => {
+                Some(Message::Moved(Point::new(x as f32, y as f32)))
+            }
+            _ => None,
+        })
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let content = column![
+            title_bar("EdgeResizer example", Message::Window),
+            container(text(format!(
+                "Drag the edges or corners to resize the window.\nSize: {} x {}",
+                self.size.width, self.size.height
+            )))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y(),
+        ];
+
+        edge_resizer(content, Message::Resize).into()
+    }
+}
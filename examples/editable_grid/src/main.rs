@@ -0,0 +1,133 @@
+use iced::{
+    font,
+    widget::{column, container, text},
+    Application, Command, Element, Length, Settings, Theme,
+};
+
+use iced_aw::{helpers::editable_grid, CellEditor, ColumnLayout};
+
+fn main() -> iced::Result {
+    EditableGridExample::run(Settings::default())
+}
+
+/// The number of data rows of the sheet.
+const ROWS: usize = 50;
+/// The number of data columns of the sheet.
+const COLUMNS: usize = 60;
+
+/// Returns the spreadsheet name of the given column, like `A` or `AB`.
+fn column_name(column: usize) -> String {
+    let letter = |index: usize| char::from(b'A' + u8::try_from(index % 26).expect("a letter"));
+
+    if column < 26 {
+        letter(column).to_string()
+    } else {
+        format!("{}{}", letter(column / 26 - 1), letter(column))
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    CellEdited(usize, usize, String),
+    ColumnLayoutChanged(ColumnLayout),
+    RowsExpanded(Vec<usize>),
+    FontLoaded(Result<(), font::Error>),
+}
+
+struct EditableGridExample {
+    cells: Vec<Vec<String>>,
+    last_edit: Option<(usize, usize)>,
+    column_layout: ColumnLayout,
+    expanded: Vec<usize>,
+}
+
+impl Application for EditableGridExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        let mut header = vec![String::new()];
+        header.extend((0..COLUMNS).map(column_name));
+
+        let mut cells = vec![header];
+        cells.extend((1..=ROWS).map(|row| {
+            let mut cells = vec![row.to_string()];
+            cells.extend((0..COLUMNS).map(|_| String::new()));
+            cells
+        }));
+
+        (
+            EditableGridExample {
+                cells,
+                last_edit: None,
+                column_layout: ColumnLayout::new(),
+                expanded: Vec::new(),
+            },
+            font::load(iced_aw::graphics::icons::ICON_FONT_BYTES).map(Message::FontLoaded),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("EditableGrid example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::CellEdited(row, column, value) => {
+                self.cells[row][column] = value;
+                self.last_edit = Some((row, column));
+            }
+            Message::ColumnLayoutChanged(column_layout) => {
+                self.column_layout = column_layout;
+            }
+            Message::RowsExpanded(expanded) => self.expanded = expanded,
+            Message::FontLoaded(_) => {}
+        }
+
+        Command::none()
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let status = self.last_edit.map_or_else(
+            || String::from("Column A takes numbers, B a choice and C dates, right click the headers to manage the columns"),
+            |(row, column)| format!("Edited {}{}", self.cells[0][column], self.cells[row][0]),
+        );
+
+        let grid = editable_grid(&self.cells, Message::CellEdited)
+            .frozen(1, 1)
+            .column_layout(self.column_layout.clone(), Message::ColumnLayoutChanged)
+            .column_widths(std::iter::once(48.0))
+            .column_editor(1, CellEditor::Number)
+            .column_editor(
+                2,
+                CellEditor::Choice(vec![
+                    String::from("Low"),
+                    String::from("Medium"),
+                    String::from("High"),
+                ]),
+            )
+            .column_editor(3, CellEditor::Date)
+            .details(
+                &self.expanded,
+                |row| {
+                    let filled = self.cells[row][1..]
+                        .iter()
+                        .filter(|cell| !cell.is_empty())
+                        .count();
+
+                    container(text(format!("Row {row} has {filled} filled cells")))
+                        .padding(10)
+                        .into()
+                },
+                Message::RowsExpanded,
+            );
+
+        container(column![grid, text(status)].spacing(10))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(10)
+            .into()
+    }
+}
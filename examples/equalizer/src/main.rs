@@ -0,0 +1,88 @@
+use iced::{
+    widget::{button, column, container, row, text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{helpers::equalizer, Band};
+
+fn main() -> iced::Result {
+    EqualizerExample::run(Settings::default())
+}
+
+/// The center frequencies of a ten band graphic equalizer.
+const FREQUENCIES: [f32; 10] = [
+    31.0, 62.0, 125.0, 250.0, 500.0, 1000.0, 2000.0, 4000.0, 8000.0, 16000.0,
+];
+
+#[derive(Clone, Debug)]
+enum Message {
+    GainChanged(usize, f32),
+    Flat,
+    BassBoost,
+}
+
+struct EqualizerExample {
+    bands: Vec<Band>,
+}
+
+impl Sandbox for EqualizerExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        EqualizerExample {
+            bands: FREQUENCIES
+                .iter()
+                .map(|frequency| Band::new(*frequency, 0.0))
+                .collect(),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Equalizer example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::GainChanged(index, gain) => self.bands[index].gain = gain,
+            Message::Flat => self.bands.iter_mut().for_each(|band| band.gain = 0.0),
+            Message::BassBoost => {
+                for (band, gain) in self
+                    .bands
+                    .iter_mut()
+                    .zip([9.0, 7.0, 5.0, 3.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0])
+                {
+                    band.gain = gain;
+                }
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let gains = self
+            .bands
+            .iter()
+            .map(|band| format!("{:+.1}", band.gain))
+            .collect::<Vec<_>>()
+            .join("  ");
+
+        let content = column![
+            row![
+                button("Flat").on_press(Message::Flat),
+                button("Bass boost").on_press(Message::BassBoost),
+            ]
+            .spacing(10),
+            equalizer(&self.bands, Message::GainChanged).width(500),
+            text("Drag across the bands to sketch a curve"),
+            text(gains),
+        ]
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
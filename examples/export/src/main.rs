@@ -0,0 +1,107 @@
+use iced::{
+    widget::{Button, Column, Container, Row, Text},
+    Alignment, Element, Length, Sandbox, Settings, Size,
+};
+
+use iced_aw::{export::Export, Badge};
+
+fn main() -> iced::Result {
+    ExportExample::run(Settings::default())
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    ExportPng,
+    ExportPdf,
+}
+
+struct ExportExample {
+    sales: Vec<(&'static str, u32)>,
+    status: String,
+}
+
+impl Sandbox for ExportExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        ExportExample {
+            sales: vec![("Apples", 42), ("Pears", 17), ("Plums", 8)],
+            status: String::from("Nothing exported yet"),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Export example")
+    }
+
+    fn update(&mut self, message: Message) {
+        let image = Export::new(Size::new(300.0, 160.0))
+            .scale_factor(2.0)
+            .render(report(&self.sales));
+
+        let (path, result) = match message {
+            Message::ExportPng => (
+                "report.png",
+                image
+                    .to_png()
+                    .map_err(|error| error.to_string())
+                    .and_then(|png| std::fs::write("report.png", png).map_err(|e| e.to_string())),
+            ),
+            Message::ExportPdf => (
+                "report.pdf",
+                image
+                    .to_pdf()
+                    .and_then(|pdf| std::fs::write("report.pdf", pdf))
+                    .map_err(|error| error.to_string()),
+            ),
+        };
+
+        self.status = match result {
+            Ok(()) => format!("Exported {path}"),
+            Err(error) => format!("Could not export {path}: {error}"),
+        };
+    }
+
+    fn view(&self) -> Element<Message> {
+        let buttons = Row::new()
+            .spacing(10)
+            .push(Button::new(Text::new("Export PNG")).on_press(Message::ExportPng))
+            .push(Button::new(Text::new("Export PDF")).on_press(Message::ExportPdf));
+
+        let content = Column::new()
+            .spacing(20)
+            .align_items(Alignment::Center)
+            .push(report(&self.sales))
+            .push(buttons)
+            .push(Text::new(&self.status));
+
+        Container::new(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
+
+/// The report shown in the window and rendered offscreen on export.
+fn report<'a>(sales: &[(&'static str, u32)]) -> Element<'a, Message> {
+    sales
+        .iter()
+        .fold(
+            Column::new()
+                .spacing(10)
+                .padding(10)
+                .push(Text::new("Sales report").size(24)),
+            |column, (fruit, count)| {
+                column.push(
+                    Row::new()
+                        .spacing(10)
+                        .align_items(Alignment::Center)
+                        .push(Text::new(*fruit).width(Length::Fixed(100.0)))
+                        .push(Badge::new(Text::new(count.to_string()))),
+                )
+            },
+        )
+        .into()
+}
@@ -0,0 +1,110 @@
+use std::ops::Range;
+
+use iced::{
+    font,
+    widget::{column, container, scrollable, text},
+    Application, Command, Element, Length, Settings, Theme,
+};
+
+use iced_aw::{
+    find::{self, Query},
+    helpers::find_bar,
+};
+
+fn main() -> iced::Result {
+    FindBarExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    QueryChanged(Query),
+    Navigated(usize, Range<usize>),
+    ReplacementChanged(String),
+    Replaced(Range<usize>, String),
+    ReplacedAll(Vec<Range<usize>>, String),
+    FontLoaded(Result<(), font::Error>),
+}
+
+struct FindBarExample {
+    content: String,
+    query: Query,
+    replacement: String,
+    current: Option<(usize, Range<usize>)>,
+}
+
+impl Application for FindBarExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        (
+            FindBarExample {
+                content: String::from(
+                    "The quick brown fox jumps over the lazy dog.\n\
+                     The dog wakes up and chases the fox.\n\
+                     In 2023, the fox was seen 3 times, the dog 12 times.",
+                ),
+                query: Query::new("fox"),
+                replacement: String::from("cat"),
+                current: None,
+            },
+            font::load(iced_aw::graphics::icons::ICON_FONT_BYTES).map(Message::FontLoaded),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("FindBar example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::QueryChanged(query) => {
+                self.query = query;
+                self.current = None;
+            }
+            Message::Navigated(index, range) => self.current = Some((index, range)),
+            Message::ReplacementChanged(replacement) => self.replacement = replacement,
+            Message::Replaced(range, replacement) => {
+                self.content.replace_range(range, &replacement);
+                self.current = None;
+            }
+            Message::ReplacedAll(matches, replacement) => {
+                let _ = find::replace_all(&mut self.content, &matches, &replacement);
+                self.current = None;
+            }
+            Message::FontLoaded(_) => {}
+        }
+
+        Command::none()
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let status = match &self.current {
+            Some((_, range)) => {
+                let line = self.content[..range.start].matches('\n').count() + 1;
+                format!("Line {line}: \"{}\"", &self.content[range.clone()])
+            }
+            None => String::from("Press Enter or the arrows to go to a match"),
+        };
+
+        container(
+            column![
+                find_bar(&self.query, &self.content, Message::QueryChanged)
+                    .current(self.current.as_ref().map(|(index, _)| *index))
+                    .on_navigate(Message::Navigated)
+                    .replacement(&self.replacement, Message::ReplacementChanged)
+                    .on_replace(Message::Replaced)
+                    .on_replace_all(Message::ReplacedAll),
+                text(status),
+                scrollable(text(&self.content)).height(Length::Fill),
+            ]
+            .spacing(10),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(10)
+        .into()
+    }
+}
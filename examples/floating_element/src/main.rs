@@ -118,8 +118,14 @@ impl Application for FloatingElementExample {
                         CircleButtonStyle::new(theme::Button::Primary),
                     ))),
                 )
+                .push(
+                    Container::new(Text::new(format!("{} lines", lines.len())))
+                        .padding(8)
+                        .style(theme::Container::Box),
+                )
                 .anchor(Anchor::SouthEast)
                 .offset(20.0)
+                .spacing(10.0)
                 .hide(false);
 
                 Container::new(content)
@@ -0,0 +1,106 @@
+use iced::{
+    keyboard, subscription,
+    widget::{self, column, container, row, text, text_input},
+    Application, Command, Element, Event, Length, Settings, Subscription, Theme,
+};
+
+use iced_aw::helpers::{focus_group, focus_ring, toggle_group};
+
+fn main() -> iced::Result {
+    FocusGroupExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    NameChanged(String),
+    DayChanged(String),
+    MonthChanged(String),
+    YearChanged(String),
+    StyleChanged(Vec<usize>),
+    Tab { shift: bool },
+}
+
+#[derive(Default)]
+struct FocusGroupExample {
+    name: String,
+    day: String,
+    month: String,
+    year: String,
+    style: Vec<usize>,
+}
+
+impl Application for FocusGroupExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        (FocusGroupExample::default(), Command::none())
+    }
+
+    fn title(&self) -> String {
+        String::from("FocusGroup example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::NameChanged(name) => self.name = name,
+            Message::DayChanged(day) => self.day = day,
+            Message::MonthChanged(month) => self.month = month,
+            Message::YearChanged(year) => self.year = year,
+            Message::StyleChanged(style) => self.style = style,
+            Message::Tab { shift: true } => return widget::focus_previous(),
+            Message::Tab { shift: false } => return widget::focus_next(),
+        }
+
+        Command::none()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        subscription::events_with(|event, _status| match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Tab,
+                modifiers,
+            }) => Some(Message::Tab {
+                shift: modifiers.shift(),
+            }),
+            _ => None,
+        })
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let date = focus_group(
+            row![
+                focus_ring(text_input("Day", &self.day).on_input(Message::DayChanged)),
+                focus_ring(text_input("Month", &self.month).on_input(Message::MonthChanged)),
+                focus_ring(text_input("Year", &self.year).on_input(Message::YearChanged)),
+            ]
+            .spacing(10),
+        )
+        .wrap(false);
+
+        let content = column![
+            text("Tab moves between the name, the date and the style"),
+            focus_ring(text_input("Name", &self.name).on_input(Message::NameChanged)),
+            text("Up and Down move between the fields of the date"),
+            date,
+            text("The arrow keys move between the styles"),
+            toggle_group(
+                ["Bold", "Italic", "Underline"],
+                &self.style,
+                Message::StyleChanged,
+            )
+            .mode(iced_aw::toggle_group::SelectionMode::Multiple),
+        ]
+        .spacing(20)
+        .max_width(400);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
@@ -0,0 +1,85 @@
+use iced::{
+    keyboard, subscription,
+    widget::{self, column, container, text, text_input},
+    Application, Command, Element, Event, Length, Settings, Subscription, Theme,
+};
+
+use iced_aw::helpers::focus_ring;
+
+fn main() -> iced::Result {
+    FocusRingExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    NameChanged(String),
+    EmailChanged(String),
+    CityChanged(String),
+    Tab { shift: bool },
+}
+
+#[derive(Default)]
+struct FocusRingExample {
+    name: String,
+    email: String,
+    city: String,
+}
+
+impl Application for FocusRingExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        (FocusRingExample::default(), Command::none())
+    }
+
+    fn title(&self) -> String {
+        String::from("FocusRing example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::NameChanged(name) => self.name = name,
+            Message::EmailChanged(email) => self.email = email,
+            Message::CityChanged(city) => self.city = city,
+            Message::Tab { shift: true } => return widget::focus_previous(),
+            Message::Tab { shift: false } => return widget::focus_next(),
+        }
+
+        Command::none()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        subscription::events_with(|event, _status| match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Tab,
+                modifiers,
+            }) => Some(Message::Tab {
+                shift: modifiers.shift(),
+            }),
+            _ => None,
+        })
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let content = column![
+            text("Press Tab to move the focus between the fields"),
+            focus_ring(text_input("Name", &self.name).on_input(Message::NameChanged)),
+            focus_ring(text_input("Email", &self.email).on_input(Message::EmailChanged)),
+            text("Only outlined when focused with the keyboard"),
+            focus_ring(text_input("City", &self.city).on_input(Message::CityChanged))
+                .keyboard_only(),
+        ]
+        .spacing(20)
+        .max_width(300);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
@@ -0,0 +1,101 @@
+use iced::{
+    widget::{button, checkbox, column, container, scrollable, text, text_input},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{form::FormRow, helpers::form};
+
+fn main() -> iced::Result {
+    FormExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    NameChanged(String),
+    EmailChanged(String),
+    WebsiteChanged(String),
+    NewsletterToggled(bool),
+    Submit,
+}
+
+#[derive(Default)]
+struct FormExample {
+    name: String,
+    email: String,
+    website: String,
+    newsletter: bool,
+    submitted: Option<String>,
+}
+
+impl Sandbox for FormExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn title(&self) -> String {
+        String::from("Form example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::NameChanged(name) => self.name = name,
+            Message::EmailChanged(email) => self.email = email,
+            Message::WebsiteChanged(website) => self.website = website,
+            Message::NewsletterToggled(newsletter) => self.newsletter = newsletter,
+            Message::Submit => {
+                self.submitted = Some(if self.name.is_empty() || self.email.is_empty() {
+                    String::from("Please fill in the required fields.")
+                } else {
+                    format!("Thanks, {}!", self.name)
+                });
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let form = form(vec![
+            FormRow::new(
+                "Name",
+                text_input("Jane Doe", &self.name).on_input(Message::NameChanged),
+            )
+            .required(true),
+            FormRow::new(
+                "Email address",
+                text_input("jane@example.com", &self.email).on_input(Message::EmailChanged),
+            )
+            .required(true)
+            .help("We never share your email address."),
+            FormRow::new(
+                "Website",
+                text_input("https://", &self.website).on_input(Message::WebsiteChanged),
+            ),
+            FormRow::new(
+                "Newsletter",
+                checkbox(
+                    "Send me the monthly newsletter",
+                    self.newsletter,
+                    Message::NewsletterToggled,
+                ),
+            )
+            .help("You can unsubscribe at any time."),
+        ]);
+
+        let content = column![
+            text("Resize the window below 400 pixels to stack the labels."),
+            form,
+            button("Submit").on_press(Message::Submit),
+            text(self.submitted.as_deref().unwrap_or_default()),
+        ]
+        .spacing(20)
+        .max_width(600);
+
+        container(scrollable(content))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(20)
+            .center_x()
+            .into()
+    }
+}
@@ -0,0 +1,108 @@
+use iced::{
+    keyboard::{self, KeyCode},
+    subscription,
+    widget::{button, column, container, row, text},
+    Application, Command, Element, Event, Length, Settings, Subscription, Theme,
+};
+
+use iced_aw::{
+    gamepad::{self, Button, Mapping},
+    helpers::gamepad_target,
+};
+
+fn main() -> iced::Result {
+    GamepadExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    /// A button of the gamepad was pressed. The keyboard stands in for a
+    /// gamepad in this example; a real application would use e.g. `gilrs`.
+    Pressed(Button),
+    Play(usize),
+}
+
+struct GamepadExample {
+    mapping: Mapping,
+    played: Option<usize>,
+}
+
+impl Application for GamepadExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        (
+            GamepadExample {
+                mapping: Mapping::default(),
+                played: None,
+            },
+            Command::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Gamepad example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::Pressed(button) => self
+                .mapping
+                .get(button)
+                .map_or_else(Command::none, gamepad::navigate),
+            Message::Play(game) => {
+                self.played = Some(game);
+                Command::none()
+            }
+        }
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        subscription::events_with(|event, _status| match event {
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => match key_code {
+                KeyCode::Up => Some(Button::DPadUp),
+                KeyCode::Down => Some(Button::DPadDown),
+                KeyCode::Left => Some(Button::DPadLeft),
+                KeyCode::Right => Some(Button::DPadRight),
+                KeyCode::Enter => Some(Button::South),
+                KeyCode::Escape => Some(Button::East),
+                KeyCode::Q => Some(Button::LeftShoulder),
+                KeyCode::E => Some(Button::RightShoulder),
+                _ => None,
+            }
+            .map(Message::Pressed),
+            _ => None,
+        })
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let games = (0..3).fold(column![].spacing(20), |games, line| {
+            games.push((0..4).fold(row![].spacing(20), |games, column| {
+                let game = line * 4 + column;
+                games.push(
+                    gamepad_target(
+                        button(text(format!("Game {}", game + 1)))
+                            .padding(20)
+                            .on_press(Message::Play(game)),
+                    )
+                    .on_activate(Message::Play(game)),
+                )
+            }))
+        });
+
+        let status = self.played.map_or_else(
+            || String::from("Use the arrows, Enter, Escape, Q and E as a gamepad."),
+            |game| format!("Playing game {}", game + 1),
+        );
+
+        container(column![games, text(status)].spacing(30))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
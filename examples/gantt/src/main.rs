@@ -0,0 +1,92 @@
+use iced::{
+    widget::{checkbox, column, container, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{
+    gantt::{Link, LinkKind, Task},
+    helpers::gantt,
+};
+
+fn main() -> iced::Result {
+    GanttExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Linked(Link),
+    Unlinked(usize),
+    CriticalPathToggled(bool),
+}
+
+struct GanttExample {
+    tasks: Vec<Task>,
+    links: Vec<Link>,
+    critical_path: bool,
+}
+
+impl Sandbox for GanttExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        GanttExample {
+            tasks: vec![
+                Task::new("Requirements", 0.0, 3.0),
+                Task::new("Design", 3.0, 4.0),
+                Task::new("Backend", 7.0, 8.0),
+                Task::new("Frontend", 7.0, 6.0),
+                Task::new("Documentation", 9.0, 4.0),
+                Task::new("Testing", 15.0, 4.0),
+                Task::new("Release", 19.0, 1.0),
+            ],
+            links: vec![
+                Link::new(0, 1, LinkKind::FinishToStart),
+                Link::new(1, 2, LinkKind::FinishToStart),
+                Link::new(1, 3, LinkKind::FinishToStart),
+                Link::new(2, 5, LinkKind::FinishToStart),
+                Link::new(3, 5, LinkKind::FinishToStart),
+                Link::new(5, 6, LinkKind::FinishToStart),
+            ],
+            critical_path: true,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Gantt example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Linked(link) => self.links.push(link),
+            Message::Unlinked(index) => {
+                let _ = self.links.remove(index);
+            }
+            Message::CriticalPathToggled(critical_path) => self.critical_path = critical_path,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let chart = gantt(&self.tasks, &self.links)
+            .on_link(Message::Linked)
+            .on_unlink(Message::Unlinked)
+            .critical_path(self.critical_path);
+
+        container(
+            column![
+                checkbox(
+                    "Highlight the critical path",
+                    self.critical_path,
+                    Message::CriticalPathToggled
+                ),
+                chart,
+                text("Drag between the handles at the ends of two bars to link them"),
+                text("Click a link and press Delete to remove it"),
+            ]
+            .spacing(20),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(20)
+        .into()
+    }
+}
@@ -0,0 +1,85 @@
+use iced::{
+    widget::{button, column, container, row, slider, text},
+    Alignment, Color, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::helpers::gauge;
+
+fn main() -> iced::Result {
+    GaugeExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    SpeedChanged(f32),
+    LoadChanged(f32),
+    Reset,
+}
+
+struct GaugeExample {
+    speed: f32,
+    load: f32,
+}
+
+impl Sandbox for GaugeExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        GaugeExample {
+            speed: 72.0,
+            load: 35.0,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Gauge example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::SpeedChanged(speed) => self.speed = speed,
+            Message::LoadChanged(load) => self.load = load,
+            Message::Reset => {
+                self.speed = 0.0;
+                self.load = 0.0;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let speed = gauge(self.speed)
+            .range(0.0, 200.0)
+            .ticks(10)
+            .minor_ticks(2)
+            .zone(140.0, 170.0, Color::from_rgb(0.95, 0.7, 0.2))
+            .zone(170.0, 200.0, Color::from_rgb(0.85, 0.25, 0.25))
+            .label("km/h");
+
+        let load = gauge(self.load)
+            .zone(0.0, 60.0, Color::from_rgb(0.35, 0.7, 0.4))
+            .zone(60.0, 85.0, Color::from_rgb(0.95, 0.7, 0.2))
+            .zone(85.0, 100.0, Color::from_rgb(0.85, 0.25, 0.25))
+            .format_value(|value| format!("{value:.0}%"))
+            .label("CPU load");
+
+        let controls = row![
+            text("Speed"),
+            slider(0.0..=200.0, self.speed, Message::SpeedChanged).width(200),
+            text("Load"),
+            slider(0.0..=100.0, self.load, Message::LoadChanged).width(200),
+            button("Reset").on_press(Message::Reset),
+        ]
+        .spacing(10)
+        .align_items(Alignment::Center);
+
+        container(
+            column![row![speed, load].spacing(20).height(300), controls]
+                .spacing(20)
+                .align_items(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(20)
+        .into()
+    }
+}
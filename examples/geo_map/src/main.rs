@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+use iced::{
+    widget::{column, container, text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{geo_map::world, helpers::geo_map, Region};
+
+fn main() -> iced::Result {
+    GeoMapExample::run(Settings::default())
+}
+
+/// The population of some countries in millions.
+const POPULATION: [(&str, f32); 20] = [
+    ("USA", 333.3),
+    ("CAN", 38.9),
+    ("MEX", 127.5),
+    ("BRA", 215.3),
+    ("ARG", 46.2),
+    ("GBR", 66.9),
+    ("FRA", 68.0),
+    ("DEU", 84.1),
+    ("ITA", 58.9),
+    ("ESP", 47.6),
+    ("RUS", 144.2),
+    ("CHN", 1412.0),
+    ("IND", 1417.2),
+    ("JPN", 125.1),
+    ("IDN", 275.5),
+    ("AUS", 26.0),
+    ("NGA", 218.5),
+    ("EGY", 111.0),
+    ("ZAF", 59.9),
+    ("TUR", 85.3),
+];
+
+#[derive(Clone, Debug)]
+enum Message {
+    RegionPressed(String),
+}
+
+struct GeoMapExample {
+    regions: Vec<Region>,
+    population: HashMap<String, f32>,
+    selected: Option<String>,
+}
+
+impl Sandbox for GeoMapExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        GeoMapExample {
+            regions: world(),
+            population: POPULATION
+                .iter()
+                .map(|(id, population)| ((*id).to_owned(), *population))
+                .collect(),
+            selected: None,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("GeoMap example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::RegionPressed(id) => self.selected = Some(id),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let selected = self
+            .selected
+            .as_ref()
+            .and_then(|id| self.regions.iter().find(|region| &region.id == id))
+            .map_or_else(
+                || String::from("Click a country"),
+                |region| format!("Selected: {}", region.name),
+            );
+
+        let content = column![
+            geo_map(&self.regions, &self.population)
+                .on_press(Message::RegionPressed)
+                .range(0.0, 300.0)
+                .format(|population| format!("{population:.1} million")),
+            text("Scroll to zoom, drag to pan"),
+            text(selected),
+        ]
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(20)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
@@ -0,0 +1,83 @@
+use iced::{
+    widget::{column, container, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{gesture_detector::Gesture, helpers::gesture_detector};
+
+fn main() -> iced::Result {
+    GestureDetectorExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Gesture(Gesture),
+}
+
+struct GestureDetectorExample {
+    gestures: Vec<String>,
+    zoom: f32,
+}
+
+impl Sandbox for GestureDetectorExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        GestureDetectorExample {
+            gestures: Vec::new(),
+            zoom: 1.0,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("GestureDetector example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Gesture(gesture) => {
+                let description = match gesture {
+                    Gesture::Tap(position) => format!("Tap at {position:?}"),
+                    Gesture::DoubleTap(position) => format!("Double tap at {position:?}"),
+                    Gesture::LongPress(position) => format!("Long press at {position:?}"),
+                    Gesture::Swipe {
+                        direction,
+                        velocity,
+                    } => format!(
+                        "Swipe {direction:?} at {:.0} px/s",
+                        (velocity.x * velocity.x + velocity.y * velocity.y).sqrt()
+                    ),
+                    Gesture::Pinch { scale, .. } => {
+                        self.zoom *= scale;
+                        format!("Pinch to {:.0}%", self.zoom * 100.0)
+                    }
+                };
+
+                self.gestures.insert(0, description);
+                self.gestures.truncate(10);
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let log = self
+            .gestures
+            .iter()
+            .fold(column![].spacing(5), |log, gesture| log.push(text(gesture)));
+
+        let area = container(text("Tap, long press or swipe here"))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .style(iced::theme::Container::Box);
+
+        column![
+            gesture_detector(area, Message::Gesture),
+            container(log).height(200).padding(10),
+        ]
+        .padding(10)
+        .spacing(10)
+        .into()
+    }
+}
@@ -0,0 +1,126 @@
+use iced::{
+    widget::{column, container, scrollable, text, Column},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{helpers::histogram, histogram::Bins};
+
+fn main() -> iced::Result {
+    HistogramExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Select(f32, f32),
+    Clear,
+}
+
+struct Product {
+    name: String,
+    price: f32,
+}
+
+struct HistogramExample {
+    products: Vec<Product>,
+    bins: Bins,
+    selection: Option<(f32, f32)>,
+}
+
+impl Sandbox for HistogramExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        // Prices clustered around a few typical amounts, from a simple
+        // pseudo-random generator.
+        let mut seed: u32 = 7;
+        let mut random = move || {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            (seed >> 16) as f32 / 65_536.0
+        };
+
+        let products: Vec<Product> = (1..=400)
+            .map(|i| {
+                let center = [15.0, 40.0, 90.0][i % 3];
+                let spread = (random() + random() + random() - 1.5) * center * 0.6;
+
+                Product {
+                    name: format!("Product {i}"),
+                    price: (center + spread).max(1.0),
+                }
+            })
+            .collect();
+
+        let bins = Bins::new(products.iter().map(|product| product.price), 40);
+
+        HistogramExample {
+            products,
+            bins,
+            selection: None,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Histogram example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Select(min, max) => self.selection = Some((min, max)),
+            Message::Clear => self.selection = None,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let mut prices = histogram(&self.bins)
+            .height(120)
+            .on_select(Message::Select)
+            .on_clear(Message::Clear);
+
+        if let Some((min, max)) = self.selection {
+            prices = prices.selection(min, max);
+        }
+
+        let filtered: Vec<&Product> = self
+            .products
+            .iter()
+            .filter(|product| {
+                self.selection
+                    .is_none_or(|(min, max)| (min..=max).contains(&product.price))
+            })
+            .collect();
+
+        let summary = self.selection.map_or_else(
+            || {
+                format!(
+                    "All {} products. Drag over the prices to filter them.",
+                    filtered.len()
+                )
+            },
+            |(min, max)| {
+                format!(
+                    "{} products from {min:.2} to {max:.2}. Click the selection to clear it.",
+                    filtered.len()
+                )
+            },
+        );
+
+        let list = filtered
+            .iter()
+            .fold(Column::new().spacing(4), |list, product| {
+                list.push(text(format!("{}: {:.2}", product.name, product.price)))
+            });
+
+        container(
+            column![
+                prices,
+                text(summary),
+                scrollable(list.width(Length::Fill)).height(Length::Fill),
+            ]
+            .spacing(10),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(20)
+        .into()
+    }
+}
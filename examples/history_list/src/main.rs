@@ -0,0 +1,92 @@
+use iced::{
+    widget::{button, column, container, row, scrollable, text, text_input},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::helpers::history_list;
+
+fn main() -> iced::Result {
+    HistoryListExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    InputChanged(String),
+    Apply,
+    Undo,
+    Redo,
+    Jump(usize),
+}
+
+struct HistoryListExample {
+    input: String,
+    labels: Vec<String>,
+    documents: Vec<String>,
+    current: usize,
+}
+
+impl Sandbox for HistoryListExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        HistoryListExample {
+            input: String::new(),
+            labels: vec![String::from("Initial state")],
+            documents: vec![String::new()],
+            current: 0,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("HistoryList example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::InputChanged(input) => self.input = input,
+            Message::Apply => {
+                // Applying a new change discards the undone entries.
+                self.labels.truncate(self.current + 1);
+                self.documents.truncate(self.current + 1);
+                self.labels.push(format!("Set text to \"{}\"", self.input));
+                self.documents.push(self.input.clone());
+                self.current = self.labels.len() - 1;
+            }
+            Message::Undo => self.current = self.current.saturating_sub(1),
+            Message::Redo => self.current = (self.current + 1).min(self.labels.len() - 1),
+            Message::Jump(index) => self.current = index,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let editor = column![
+            text_input("Type something", &self.input)
+                .on_input(Message::InputChanged)
+                .on_submit(Message::Apply),
+            row![
+                button(text("Apply")).on_press(Message::Apply),
+                button(text("Undo")).on_press(Message::Undo),
+                button(text("Redo")).on_press(Message::Redo),
+            ]
+            .spacing(10),
+            text(format!("Document: {}", self.documents[self.current])),
+        ]
+        .spacing(10)
+        .width(Length::FillPortion(2));
+
+        let history = column![
+            text("History"),
+            scrollable(history_list(&self.labels, self.current, Message::Jump)),
+        ]
+        .spacing(10)
+        .width(Length::FillPortion(1));
+
+        container(
+            row![editor, history]
+                .spacing(20)
+                .align_items(Alignment::Start),
+        )
+        .padding(20)
+        .into()
+    }
+}
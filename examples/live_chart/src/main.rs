@@ -0,0 +1,151 @@
+use std::time::{Duration, Instant};
+
+use iced::{
+    executor,
+    widget::{button, column, container, row, text},
+    Application, Command, Element, Length, Settings, Subscription, Theme,
+};
+
+use iced_aw::{
+    helpers::live_chart,
+    live_chart::{Annotation, Series},
+};
+
+fn main() -> iced::Result {
+    LiveChartExample::run(Settings::default())
+}
+
+/// The number of samples per second of the signals.
+const SAMPLE_RATE: f64 = 1000.0;
+
+#[derive(Clone, Debug)]
+enum Message {
+    Tick(Instant),
+    TogglePause,
+    WindowChanged(u64),
+}
+
+struct LiveChartExample {
+    series: Vec<Series>,
+    start: Instant,
+    now: f64,
+    sampled: f64,
+    paused: bool,
+    window: u64,
+    noise: u32,
+}
+
+impl LiveChartExample {
+    /// Samples the signals up to the current time.
+    fn sample(&mut self) {
+        while self.sampled < self.now {
+            self.sampled += 1.0 / SAMPLE_RATE;
+            let time = self.sampled;
+
+            // A cheap linear congruential generator is enough for a noisy
+            // signal.
+            self.noise = self
+                .noise
+                .wrapping_mul(1_664_525)
+                .wrapping_add(1_013_904_223);
+            let noise = f64::from(self.noise >> 8) / f64::from(1_u32 << 24) - 0.5;
+
+            #[allow(clippy::cast_possible_truncation)]
+            {
+                self.series[0].push(time, (50.0 + 30.0 * (time * 0.5).sin()) as f32);
+                self.series[1].push(
+                    time,
+                    (30.0 + 10.0 * (time * 3.0).sin() + 8.0 * noise) as f32,
+                );
+            }
+        }
+    }
+}
+
+impl Application for LiveChartExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        (
+            LiveChartExample {
+                // One minute of samples at the sample rate.
+                series: vec![Series::new("CPU", 60_000), Series::new("Memory", 60_000)],
+                start: Instant::now(),
+                now: 0.0,
+                sampled: 0.0,
+                paused: false,
+                window: 10,
+                noise: 1,
+            },
+            Command::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("LiveChart example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::Tick(now) => {
+                self.now = (now - self.start).as_secs_f64();
+                self.sample();
+            }
+            Message::TogglePause => self.paused = !self.paused,
+            Message::WindowChanged(window) => self.window = window,
+        }
+
+        Command::none()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        if self.paused {
+            Subscription::none()
+        } else {
+            iced::time::every(Duration::from_millis(16)).map(Message::Tick)
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let windows = [10, 30, 60]
+            .into_iter()
+            .fold(row![].spacing(10), |windows, seconds| {
+                let label = text(format!("{seconds} s"));
+                windows.push(if seconds == self.window {
+                    button(label)
+                } else {
+                    button(label).on_press(Message::WindowChanged(seconds))
+                })
+            });
+
+        let chart = live_chart(&self.series)
+            .window(Duration::from_secs(self.window))
+            .now(self.now)
+            .annotation(Annotation::value_region(20.0, 40.0, "Idle"))
+            .annotation(Annotation::horizontal_line(75.0, "Alarm"))
+            .format_value(|value| format!("{value}%"));
+
+        let content = column![
+            row![
+                button(if self.paused { "Resume" } else { "Pause" }).on_press(Message::TogglePause),
+                windows,
+            ]
+            .spacing(20),
+            chart,
+            text(format!(
+                "{} samples per series, hover the chart to read the values",
+                self.series[0].len()
+            )),
+        ]
+        .spacing(20)
+        .padding(40);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+}
@@ -351,12 +351,10 @@ fn debug_sub_menu<'a>(
 }
 
 fn separator<'a>() -> MenuTree<'a, Message, iced::Renderer> {
-    menu_tree!(quad::Quad {
-        color: [0.5; 3].into(),
-        border_radius: [4.0; 4],
-        inner_bounds: quad::InnerBounds::Ratio(0.98, 0.1),
-        ..Default::default()
-    })
+    menu_tree!(quad::Quad::default()
+        .color([0.5; 3].into())
+        .border_radius([4.0; 4])
+        .inner_bounds(quad::InnerBounds::Ratio(0.98, 0.1)))
 }
 
 fn dot_separator<'a>() -> MenuTree<'a, Message, iced::Renderer> {
@@ -369,18 +367,14 @@ fn dot_separator<'a>() -> MenuTree<'a, Message, iced::Renderer> {
 }
 
 fn labeled_separator(label: &'_ str) -> MenuTree<'_, Message, iced::Renderer> {
-    let q_1 = quad::Quad {
-        color: [0.5; 3].into(),
-        border_radius: [4.0; 4],
-        inner_bounds: quad::InnerBounds::Ratio(0.98, 0.1),
-        ..Default::default()
-    };
-    let q_2 = quad::Quad {
-        color: [0.5; 3].into(),
-        border_radius: [4.0; 4],
-        inner_bounds: quad::InnerBounds::Ratio(0.98, 0.1),
-        ..Default::default()
-    };
+    let q_1 = quad::Quad::default()
+        .color([0.5; 3].into())
+        .border_radius([4.0; 4])
+        .inner_bounds(quad::InnerBounds::Ratio(0.98, 0.1));
+    let q_2 = quad::Quad::default()
+        .color([0.5; 3].into())
+        .border_radius([4.0; 4])
+        .inner_bounds(quad::InnerBounds::Ratio(0.98, 0.1));
 
     menu_tree!(row![
         q_1,
@@ -394,12 +388,10 @@ fn labeled_separator(label: &'_ str) -> MenuTree<'_, Message, iced::Renderer> {
 fn circle(color: Color) -> quad::Quad {
     let radius = 10.0;
 
-    quad::Quad {
-        color,
-        inner_bounds: quad::InnerBounds::Square(radius * 2.0),
-        border_radius: [radius; 4],
-        ..Default::default()
-    }
+    quad::Quad::default()
+        .color(color)
+        .inner_bounds(quad::InnerBounds::Square(radius * 2.0))
+        .border_radius([radius; 4])
 }
 
 fn menu_1<'a>(_app: &App) -> MenuTree<'a, Message, iced::Renderer> {
@@ -0,0 +1,93 @@
+use iced::{
+    font,
+    widget::{column, container, row, text},
+    Application, Command, Element, Length, Settings, Theme,
+};
+
+use iced_aw::{helpers::menu_button, MenuItem};
+
+fn main() -> iced::Result {
+    MenuButtonExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    NewFile,
+    NewFolder,
+    NewWindow,
+    SortBy(&'static str),
+    FontLoaded(Result<(), font::Error>),
+}
+
+struct MenuButtonExample {
+    last_action: String,
+}
+
+impl Application for MenuButtonExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        (
+            MenuButtonExample {
+                last_action: String::from("None"),
+            },
+            font::load(iced_aw::graphics::icons::ICON_FONT_BYTES).map(Message::FontLoaded),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("MenuButton example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        self.last_action = match message {
+            Message::NewFile => String::from("New file"),
+            Message::NewFolder => String::from("New folder"),
+            Message::NewWindow => String::from("New window"),
+            Message::SortBy(key) => format!("Sort by {key}"),
+            Message::FontLoaded(_) => return Command::none(),
+        };
+
+        Command::none()
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let new = menu_button(
+            "New",
+            vec![
+                MenuItem::new("File")
+                    .on_select(Message::NewFile)
+                    .shortcut("Ctrl+N"),
+                MenuItem::new("Folder").on_select(Message::NewFolder),
+                MenuItem::new("Window")
+                    .on_select(Message::NewWindow)
+                    .shortcut("Ctrl+Shift+N"),
+            ],
+        );
+
+        let sort = menu_button(
+            "Sort",
+            vec![
+                MenuItem::new("Name").on_select(Message::SortBy("name")),
+                MenuItem::new("Date").on_select(Message::SortBy("date")),
+                MenuItem::new("Size"),
+            ],
+        );
+
+        let content = column![
+            row![new, sort].spacing(10),
+            text(format!("Last action: {}", self.last_action)),
+        ]
+        .spacing(20);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
@@ -0,0 +1,132 @@
+use iced::{
+    font,
+    widget::{button, column, container, row, text},
+    Application, Command, Element, Length, Settings, Theme,
+};
+
+use iced_aw::{
+    core::tree_node::{self, DropPosition},
+    helpers::mind_map,
+    MindMapLayout, TreeNode,
+};
+
+fn main() -> iced::Result {
+    MindMapExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Selected(usize),
+    Added(usize),
+    Removed(usize),
+    Renamed(usize, String),
+    Moved(usize, usize),
+    LayoutChanged(MindMapLayout),
+    FontLoaded(Result<(), font::Error>),
+}
+
+struct MindMapExample {
+    // The tree functions work on a list of nodes, which has only the root.
+    nodes: Vec<TreeNode>,
+    next_id: usize,
+    selected: Option<usize>,
+    layout: MindMapLayout,
+}
+
+impl Application for MindMapExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        let root = TreeNode::new(0, "Holiday").children([
+            TreeNode::new(1, "Travel")
+                .children([TreeNode::new(2, "Train tickets"), TreeNode::new(3, "Hotel")]),
+            TreeNode::new(4, "Packing").children([
+                TreeNode::new(5, "Clothes"),
+                TreeNode::new(6, "Books"),
+                TreeNode::new(7, "Charger"),
+            ]),
+            TreeNode::new(8, "Budget"),
+        ]);
+
+        (
+            MindMapExample {
+                nodes: vec![root],
+                next_id: 9,
+                selected: None,
+                layout: MindMapLayout::Radial,
+            },
+            font::load(iced_aw::graphics::icons::ICON_FONT_BYTES).map(Message::FontLoaded),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("MindMap example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::Selected(id) => self.selected = Some(id),
+            Message::Added(parent) => {
+                if let Some(node) = tree_node::find_mut(&mut self.nodes, parent) {
+                    node.children.push(TreeNode::new(self.next_id, "New idea"));
+                    self.next_id += 1;
+                }
+            }
+            Message::Removed(id) => {
+                let _ = tree_node::remove(&mut self.nodes, id);
+                self.selected = None;
+            }
+            Message::Renamed(id, label) => {
+                if let Some(node) = tree_node::find_mut(&mut self.nodes, id) {
+                    node.label = label;
+                }
+            }
+            Message::Moved(source, target) => {
+                let _ = tree_node::move_node(&mut self.nodes, source, target, DropPosition::Into);
+            }
+            Message::LayoutChanged(layout) => self.layout = layout,
+            Message::FontLoaded(_) => {}
+        }
+
+        Command::none()
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let selected = self
+            .selected
+            .and_then(|id| tree_node::find(&self.nodes, id))
+            .map_or_else(
+                || String::from("Nothing selected"),
+                |node| format!("Selected: {}", node.label),
+            );
+
+        let map = mind_map(&self.nodes[0])
+            .layout(self.layout)
+            .on_select(Message::Selected)
+            .on_add(Message::Added)
+            .on_remove(Message::Removed)
+            .on_rename(Message::Renamed)
+            .on_move(Message::Moved);
+
+        container(
+            column![
+                row![
+                    button("Radial").on_press(Message::LayoutChanged(MindMapLayout::Radial)),
+                    button("Tree").on_press(Message::LayoutChanged(MindMapLayout::Tree)),
+                ]
+                .spacing(10),
+                map,
+                text(selected),
+                text("Double click to rename, drag a node onto another to move it"),
+            ]
+            .spacing(10),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(10)
+        .into()
+    }
+}
@@ -5,7 +5,11 @@ use iced::{
     Alignment, Application, Command, Element, Length, Settings, Theme,
 };
 
-use iced_aw::{modal, Card};
+use iced_aw::{
+    modal,
+    modal::{Edge, Transition},
+    Card,
+};
 
 fn main() -> iced::Result {
     ModalExample::run(Settings::default())
@@ -113,45 +117,44 @@ impl Application for ModalExample {
                         ))),
                 );
 
-                let overlay = if state.show_modal {
-                    Some(
-                        Card::new(
-                            Text::new("My modal"),
-                            Text::new("This is a modal!"), //Text::new("Zombie ipsum reversus ab viral inferno, nam rick grimes malum cerebro. De carne lumbering animata corpora quaeritis. Summus brains sit​​, morbo vel maleficia? De apocalypsi gorger omero undead survivor dictum mauris. Hi mindless mortuis soulless creaturas, imo evil stalking monstra adventus resi dentevil vultus comedat cerebella viventium. Qui animated corpse, cricket bat max brucks terribilem incessu zomby. The voodoo sacerdos flesh eater, suscitat mortuos comedere carnem virus. Zonbi tattered for solum oculi eorum defunctis go lum cerebro. Nescio brains an Undead zombies. Sicut malus putrid voodoo horror. Nigh tofth eliv ingdead.")
-                        )
-                        .foot(
-                            Row::new()
-                                .spacing(10)
-                                .padding(5)
+                let overlay = Some(
+                    Card::new(
+                        Text::new("My modal"),
+                        Text::new("This is a modal!"), //Text::new("Zombie ipsum reversus ab viral inferno, nam rick grimes malum cerebro. De carne lumbering animata corpora quaeritis. Summus brains sit​​, morbo vel maleficia? De apocalypsi gorger omero undead survivor dictum mauris. Hi mindless mortuis soulless creaturas, imo evil stalking monstra adventus resi dentevil vultus comedat cerebella viventium. Qui animated corpse, cricket bat max brucks terribilem incessu zomby. The voodoo sacerdos flesh eater, suscitat mortuos comedere carnem virus. Zonbi tattered for solum oculi eorum defunctis go lum cerebro. Nescio brains an Undead zombies. Sicut malus putrid voodoo horror. Nigh tofth eliv ingdead.")
+                    )
+                    .foot(
+                        Row::new()
+                            .spacing(10)
+                            .padding(5)
+                            .width(Length::Fill)
+                            .push(
+                                Button::new(
+                                    Text::new("Cancel").horizontal_alignment(Horizontal::Center),
+                                )
                                 .width(Length::Fill)
-                                .push(
-                                    Button::new(
-                                        Text::new("Cancel")
-                                            .horizontal_alignment(Horizontal::Center),
-                                    )
-                                    .width(Length::Fill)
-                                    .on_press(Message::CancelButtonPressed),
+                                .on_press(Message::CancelButtonPressed),
+                            )
+                            .push(
+                                Button::new(
+                                    Text::new("Ok").horizontal_alignment(Horizontal::Center),
                                 )
-                                .push(
-                                    Button::new(
-                                        Text::new("Ok").horizontal_alignment(Horizontal::Center),
-                                    )
-                                    .width(Length::Fill)
-                                    .on_press(Message::OkButtonPressed),
-                                ),
-                        )
-                        .max_width(300.0)
-                        //.width(Length::Shrink)
-                        .on_close(Message::CloseModal),
+                                .width(Length::Fill)
+                                .on_press(Message::OkButtonPressed),
+                            ),
                     )
-                } else {
-                    None
-                };
+                    .max_width(300.0)
+                    //.width(Length::Shrink)
+                    .on_close(Message::CloseModal),
+                );
 
                 modal(underlay, overlay)
                     .backdrop(Message::CloseModal)
                     .on_esc(Message::CloseModal)
+                    .open(state.show_modal)
+                    .transition(Transition::Slide(Edge::Top))
                     .align_y(alignment::Vertical::Top)
+                    .padding(20)
+                    .drag_handle(30.0)
                     .into()
             }
         }
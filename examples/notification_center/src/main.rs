@@ -0,0 +1,130 @@
+use std::time::{Duration, SystemTime};
+
+use iced::{
+    font,
+    widget::{button, container, row, text},
+    Alignment, Application, Command, Element, Length, Settings, Theme,
+};
+
+use iced_aw::{
+    helpers::{badge, notification_center},
+    notification_center::{unread_count, GroupBy},
+    Notification,
+};
+
+fn main() -> iced::Result {
+    NotificationCenterExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Open,
+    Close,
+    MarkRead(usize),
+    MarkAllRead,
+    Remove(usize),
+    ClearAll,
+    ToggleGrouping,
+    Reply(usize),
+    FontLoaded(Result<(), font::Error>),
+}
+
+struct NotificationCenterExample {
+    open: bool,
+    group_by: GroupBy,
+    notifications: Vec<Notification<Message>>,
+}
+
+impl Application for NotificationCenterExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        let now = SystemTime::now();
+
+        let example = NotificationCenterExample {
+            open: false,
+            group_by: GroupBy::Time,
+            notifications: vec![
+                Notification::new(0, "Mail", "Meeting notes")
+                    .body("The notes of today's meeting are attached.")
+                    .action("Reply", Message::Reply(0)),
+                Notification::new(1, "Calendar", "Stand-up in 15 minutes")
+                    .timestamp(now - Duration::from_secs(60 * 20)),
+                Notification::new(2, "Updates", "System updated")
+                    .body("Version 1.2.3 has been installed.")
+                    .timestamp(now - Duration::from_secs(60 * 60 * 5))
+                    .read(true),
+                Notification::new(3, "Mail", "Weekly digest")
+                    .timestamp(now - Duration::from_secs(60 * 60 * 24 * 3)),
+            ],
+        };
+
+        (
+            example,
+            font::load(iced_aw::graphics::icons::ICON_FONT_BYTES).map(Message::FontLoaded),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Notification center example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::Open => self.open = true,
+            Message::Close => self.open = false,
+            Message::MarkRead(id) | Message::Reply(id) => {
+                self.notifications = std::mem::take(&mut self.notifications)
+                    .into_iter()
+                    .map(|n| if n.id() == id { n.read(true) } else { n })
+                    .collect();
+            }
+            Message::MarkAllRead => {
+                self.notifications = std::mem::take(&mut self.notifications)
+                    .into_iter()
+                    .map(|n| n.read(true))
+                    .collect();
+            }
+            Message::Remove(id) => self.notifications.retain(|n| n.id() != id),
+            Message::ClearAll => self.notifications.clear(),
+            Message::ToggleGrouping => {
+                self.group_by = match self.group_by {
+                    GroupBy::Time => GroupBy::Source,
+                    GroupBy::Source => GroupBy::Time,
+                }
+            }
+            Message::FontLoaded(_) => {}
+        }
+
+        Command::none()
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let underlay = container(
+            row![
+                button(text("Notifications")).on_press(Message::Open),
+                badge(text(unread_count(&self.notifications))),
+                button(text(format!("Grouped by {:?}", self.group_by)))
+                    .on_press(Message::ToggleGrouping),
+            ]
+            .spacing(10)
+            .align_items(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x()
+        .center_y();
+
+        notification_center(self.open, underlay, &self.notifications)
+            .group_by(self.group_by)
+            .on_mark_read(Message::MarkRead)
+            .on_mark_all_read(Message::MarkAllRead)
+            .on_remove(Message::Remove)
+            .on_clear_all(Message::ClearAll)
+            .on_dismiss(Message::Close)
+            .into()
+    }
+}
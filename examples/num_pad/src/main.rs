@@ -0,0 +1,89 @@
+use iced::{
+    font,
+    widget::{button, column, container, row, text},
+    Alignment, Application, Command, Element, Length, Settings, Theme,
+};
+
+use iced_aw::{helpers::num_pad, NumberInput};
+
+fn main() -> iced::Result {
+    NumPadExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Changed(f32),
+    Typed(String),
+    Open,
+    Close,
+    FontLoaded(Result<(), font::Error>),
+}
+
+struct NumPadExample {
+    value: f32,
+    show_num_pad: bool,
+}
+
+impl Application for NumPadExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        (
+            NumPadExample {
+                value: 27.5,
+                show_num_pad: false,
+            },
+            font::load(iced_aw::graphics::icons::ICON_FONT_BYTES).map(Message::FontLoaded),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("NumPad example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::Changed(value) => self.value = value,
+            // The keypad produces partial numbers like `3.`, which are
+            // parsed as far as possible.
+            Message::Typed(text) => {
+                self.value = text.trim_end_matches('.').parse().unwrap_or(0.0);
+            }
+            Message::Open => self.show_num_pad = true,
+            Message::Close => self.show_num_pad = false,
+            Message::FontLoaded(_) => {}
+        }
+
+        Command::none()
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let input = num_pad(
+            self.show_num_pad,
+            NumberInput::new(self.value, 1000.0, Message::Changed)
+                .min(-1000.0)
+                .step(0.5),
+            self.value.to_string(),
+            Message::Typed,
+        )
+        .on_submit(Message::Close)
+        .on_cancel(Message::Close);
+
+        container(
+            column![
+                row![input, button("Keypad").on_press(Message::Open)]
+                    .spacing(10)
+                    .align_items(Alignment::Center),
+                text(format!("Value: {}", self.value)),
+            ]
+            .spacing(20),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(20)
+        .into()
+    }
+}
@@ -1,10 +1,15 @@
 use iced::{
     alignment, font,
     theme::Theme,
-    widget::{container, text, Container, Row, Text},
+    widget::{container, text, Column, Container, Row, Text},
     window, Alignment, Application, Command, Element, Length, Settings,
 };
-use iced_aw::{number_input, style::NumberInputStyles};
+use iced_aw::{
+    lens::{lens, Update},
+    number_input,
+    style::NumberInputStyles,
+    NumberInput,
+};
 
 #[derive(Debug)]
 enum NumberInputDemo {
@@ -15,11 +20,13 @@ enum NumberInputDemo {
 #[derive(Default, Debug)]
 pub struct State {
     value: f32,
+    width: u32,
 }
 
 #[derive(Debug, Clone)]
 pub enum Message {
     NumInpChanged(f32),
+    Edit(Update<State>),
     Loaded(Result<(), String>),
     FontLoaded(Result<(), font::Error>),
 }
@@ -63,14 +70,17 @@ impl Application for NumberInputDemo {
         match self {
             NumberInputDemo::Loading => {
                 if let Message::Loaded(_) = message {
-                    *self = NumberInputDemo::Loaded(State { value: 27.0 })
-                }
-            }
-            NumberInputDemo::Loaded(State { value }) => {
-                if let Message::NumInpChanged(val) = message {
-                    *value = val;
+                    *self = NumberInputDemo::Loaded(State {
+                        value: 27.0,
+                        width: 640,
+                    })
                 }
             }
+            NumberInputDemo::Loaded(state) => match message {
+                Message::NumInpChanged(val) => state.value = val,
+                Message::Edit(update) => update.apply(state),
+                _ => {}
+            },
         }
 
         Command::none()
@@ -88,18 +98,37 @@ impl Application for NumberInputDemo {
             .center_y()
             .center_x()
             .into(),
-            NumberInputDemo::Loaded(State { value }) => {
+            NumberInputDemo::Loaded(state) => {
                 let lb_minute = Text::new("Number Input:");
-                let txt_minute = number_input(*value, 255.0, Message::NumInpChanged)
+                let txt_minute = number_input(state.value, 255.0, Message::NumInpChanged)
                     .style(NumberInputStyles::Default)
                     .step(0.5);
 
+                // The input is bound to the width through a lens.
+                let lb_width = Text::new("Width:");
+                let width = lens(
+                    |state: &State| state.width,
+                    |state, width| state.width = width,
+                );
+                let txt_width = NumberInput::bind(state, width, 4096, Message::Edit);
+
                 Container::new(
-                    Row::new()
+                    Column::new()
                         .spacing(10)
-                        .align_items(Alignment::Center)
-                        .push(lb_minute)
-                        .push(txt_minute),
+                        .push(
+                            Row::new()
+                                .spacing(10)
+                                .align_items(Alignment::Center)
+                                .push(lb_minute)
+                                .push(txt_minute),
+                        )
+                        .push(
+                            Row::new()
+                                .spacing(10)
+                                .align_items(Alignment::Center)
+                                .push(lb_width)
+                                .push(txt_width),
+                        ),
                 )
                 .width(Length::Fill)
                 .height(Length::Fill)
@@ -0,0 +1,84 @@
+use iced::{
+    font,
+    widget::{column, container, pick_list, text, text_input},
+    Application, Command, Element, Length, Settings, Theme,
+};
+
+use iced_aw::{helpers::on_screen_keyboard, KeyboardLayout};
+
+fn main() -> iced::Result {
+    OnScreenKeyboardExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    NameChanged(String),
+    Submitted,
+    LayoutSelected(KeyboardLayout),
+    FontLoaded(Result<(), font::Error>),
+}
+
+struct OnScreenKeyboardExample {
+    name: String,
+    greeting: Option<String>,
+    layout: KeyboardLayout,
+}
+
+impl Application for OnScreenKeyboardExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        (
+            OnScreenKeyboardExample {
+                name: String::new(),
+                greeting: None,
+                layout: KeyboardLayout::default(),
+            },
+            Command::batch([
+                font::load(iced_aw::graphics::icons::ICON_FONT_BYTES).map(Message::FontLoaded),
+                text_input::focus(text_input::Id::new("name")),
+            ]),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("OnScreenKeyboard example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::NameChanged(name) => self.name = name,
+            Message::Submitted => self.greeting = Some(format!("Hello, {}!", self.name)),
+            Message::LayoutSelected(layout) => self.layout = layout,
+            Message::FontLoaded(_) => {}
+        }
+
+        Command::none()
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let content = container(
+            column![
+                pick_list(
+                    &KeyboardLayout::ALL[..],
+                    Some(self.layout),
+                    Message::LayoutSelected
+                ),
+                text_input("Your name", &self.name)
+                    .id(text_input::Id::new("name"))
+                    .on_input(Message::NameChanged)
+                    .on_submit(Message::Submitted),
+                text(self.greeting.as_deref().unwrap_or_default()),
+            ]
+            .spacing(20),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(20);
+
+        on_screen_keyboard(content).layout(self.layout).into()
+    }
+}
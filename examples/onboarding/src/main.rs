@@ -0,0 +1,78 @@
+use iced::{
+    widget::{button, column, container, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{helpers::onboarding, onboarding::OnboardingPage};
+
+fn main() -> iced::Result {
+    OnboardingExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    PageChanged(usize),
+    Finished,
+    Restart,
+}
+
+struct OnboardingExample {
+    page: usize,
+    finished: bool,
+}
+
+impl Sandbox for OnboardingExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        OnboardingExample {
+            page: 0,
+            finished: false,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Onboarding example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::PageChanged(page) => self.page = page,
+            Message::Finished => self.finished = true,
+            Message::Restart => *self = Self::new(),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        if self.finished {
+            return container(
+                column![
+                    text("Welcome to the app!"),
+                    button("Show the introduction again").on_press(Message::Restart),
+                ]
+                .spacing(10),
+            )
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into();
+        }
+
+        let pages = vec![
+            OnboardingPage::new("Welcome", "Thanks for trying out this little notes app.")
+                .illustration(text("📝").size(64)),
+            OnboardingPage::new(
+                "Everywhere",
+                "Your notes are synchronized across all of your devices.",
+            )
+            .illustration(text("🔄").size(64)),
+            OnboardingPage::new("Private", "Only you can read your notes.")
+                .illustration(text("🔒").size(64)),
+        ];
+
+        onboarding(pages, self.page, Message::PageChanged, Message::Finished)
+            .call_to_action("Start writing")
+            .into()
+    }
+}
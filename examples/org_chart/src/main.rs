@@ -0,0 +1,90 @@
+use iced::{
+    widget::{column, container, text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{helpers::org_chart, OrgNode};
+
+fn main() -> iced::Result {
+    OrgChartExample::run(Settings::default())
+}
+
+/// The employees of the organization with the index of their manager.
+const EMPLOYEES: [(&str, &str, Option<usize>); 10] = [
+    ("Ada", "Chief Executive Officer", None),
+    ("Grace", "Chief Technology Officer", Some(0)),
+    ("Alan", "Chief Financial Officer", Some(0)),
+    ("Linus", "Head of Platform", Some(1)),
+    ("Margaret", "Head of Applications", Some(1)),
+    ("Dennis", "Engineer", Some(3)),
+    ("Ken", "Engineer", Some(3)),
+    ("Barbara", "Engineer", Some(4)),
+    ("John", "Accountant", Some(2)),
+    ("Frances", "Controller", Some(2)),
+];
+
+#[derive(Clone, Debug)]
+enum Message {
+    Selected(usize),
+}
+
+struct OrgChartExample {
+    selected: Option<usize>,
+}
+
+impl Sandbox for OrgChartExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        OrgChartExample { selected: None }
+    }
+
+    fn title(&self) -> String {
+        String::from("OrgChart example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Selected(index) => self.selected = Some(index),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let selected = self.selected.map_or_else(
+            || String::from("Click an employee"),
+            |index| format!("Selected: {}", EMPLOYEES[index].0),
+        );
+
+        let content = column![
+            text(selected),
+            container(org_chart(node(0)))
+                .width(Length::Fill)
+                .height(Length::Fill),
+            text("Drag to pan, scroll to zoom out, click a toggle to collapse a branch"),
+        ]
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(20)
+            .into()
+    }
+}
+
+/// Returns the node of the employee with the given index and of the
+/// employees they manage.
+fn node(index: usize) -> OrgNode<'static, Message> {
+    let (name, title, _) = EMPLOYEES[index];
+
+    OrgNode::new(column![text(name).size(16), text(title).size(12)].align_items(Alignment::Center))
+        .on_press(Message::Selected(index))
+        .children(
+            EMPLOYEES
+                .iter()
+                .enumerate()
+                .filter(|(_, (_, _, manager))| *manager == Some(index))
+                .map(|(report, _)| node(report)),
+        )
+}
@@ -0,0 +1,79 @@
+use iced::{
+    widget::{column, container, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{core::piano::note_name, helpers::piano_keys};
+
+fn main() -> iced::Result {
+    PianoKeysExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    NoteOn(u8),
+    NoteOff(u8),
+}
+
+#[derive(Default)]
+struct PianoKeysExample {
+    sounding: Vec<u8>,
+    log: Vec<String>,
+}
+
+impl Sandbox for PianoKeysExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn title(&self) -> String {
+        String::from("PianoKeys example")
+    }
+
+    fn update(&mut self, message: Message) {
+        let entry = match message {
+            Message::NoteOn(note) => {
+                self.sounding.push(note);
+                format!("Note on: {}", note_name(note))
+            }
+            Message::NoteOff(note) => {
+                self.sounding.retain(|sounding| *sounding != note);
+                format!("Note off: {}", note_name(note))
+            }
+        };
+
+        self.log.insert(0, entry);
+        self.log.truncate(8);
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let chord = self
+            .sounding
+            .iter()
+            .map(|note| note_name(*note))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let log = self
+            .log
+            .iter()
+            .fold(column![].spacing(4), |log, entry| log.push(text(entry)));
+
+        let content = column![
+            text("Play with the mouse, touch or the keys A to ' and W to P"),
+            // Two octaves from C3, played from the computer keyboard from C4.
+            piano_keys(48, 83, Message::NoteOn, Message::NoteOff).keyboard_mapping(60),
+            text(format!("Sounding: {chord}")),
+            log,
+        ]
+        .spacing(20)
+        .padding(40);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+}
@@ -0,0 +1,130 @@
+use iced::{
+    widget::{checkbox, column, container, row, slider, text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::helpers::radar_chart;
+
+fn main() -> iced::Result {
+    RadarExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    ToggleCharacter(usize, bool),
+    FillOpacityChanged(f32),
+    ToggleFixedRange(bool),
+}
+
+struct Character {
+    name: &'static str,
+    stats: Vec<f32>,
+    shown: bool,
+}
+
+struct RadarExample {
+    axes: Vec<String>,
+    characters: Vec<Character>,
+    fill_opacity: f32,
+    fixed_range: bool,
+}
+
+impl Sandbox for RadarExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        RadarExample {
+            axes: [
+                "Strength",
+                "Agility",
+                "Stamina",
+                "Intellect",
+                "Charisma",
+                "Luck",
+            ]
+            .map(String::from)
+            .to_vec(),
+            characters: vec![
+                Character {
+                    name: "Knight",
+                    stats: vec![9.0, 4.0, 8.0, 3.0, 6.0, 4.0],
+                    shown: true,
+                },
+                Character {
+                    name: "Rogue",
+                    stats: vec![4.0, 9.5, 5.0, 6.0, 5.0, 8.0],
+                    shown: true,
+                },
+                Character {
+                    name: "Mage",
+                    stats: vec![2.0, 5.0, 3.0, 10.0, 7.0, 5.0],
+                    shown: false,
+                },
+            ],
+            fill_opacity: 0.25,
+            fixed_range: true,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("RadarChart example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::ToggleCharacter(index, shown) => self.characters[index].shown = shown,
+            Message::FillOpacityChanged(fill_opacity) => self.fill_opacity = fill_opacity,
+            Message::ToggleFixedRange(fixed_range) => self.fixed_range = fixed_range,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let chart = self
+            .characters
+            .iter()
+            .filter(|character| character.shown)
+            .fold(radar_chart(&self.axes), |chart, character| {
+                chart.series(character.name, &character.stats)
+            })
+            .fill_opacity(self.fill_opacity)
+            .format_value(|value| format!("{value:.1}"));
+        let chart = if self.fixed_range {
+            chart.range(0.0, 10.0).rings(5)
+        } else {
+            chart
+        };
+
+        let characters = self.characters.iter().enumerate().fold(
+            row![].spacing(20),
+            |row, (index, character)| {
+                row.push(checkbox(character.name, character.shown, move |shown| {
+                    Message::ToggleCharacter(index, shown)
+                }))
+            },
+        );
+
+        let controls = row![
+            checkbox(
+                "Scale from 0 to 10",
+                self.fixed_range,
+                Message::ToggleFixedRange
+            ),
+            text("Fill opacity"),
+            slider(0.0..=1.0, self.fill_opacity, Message::FillOpacityChanged)
+                .step(0.05)
+                .width(150),
+        ]
+        .spacing(10)
+        .align_items(Alignment::Center);
+
+        container(
+            column![characters, chart, controls]
+                .spacing(20)
+                .align_items(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(20)
+        .into()
+    }
+}
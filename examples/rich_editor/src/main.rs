@@ -0,0 +1,71 @@
+use iced::{
+    widget::{column, container, scrollable, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{helpers::rich_editor, Document};
+
+fn main() -> iced::Result {
+    RichEditorExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Edited(Document),
+    LinkOpened(String),
+}
+
+struct RichEditorExample {
+    document: Document,
+    opened: Option<String>,
+}
+
+impl Sandbox for RichEditorExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        RichEditorExample {
+            document: Document::from_markdown(
+                "Some **bold**, *italic*, <u>underlined</u> and `code` text.\n\
+                 - A [link](https://iced.rs)\n\
+                 - Ctrl+click opens it",
+            ),
+            opened: None,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("RichEditor example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Edited(document) => self.document = document,
+            Message::LinkOpened(link) => self.opened = Some(link),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let opened = self
+            .opened
+            .as_ref()
+            .map_or_else(String::new, |link| format!("Opened {link}"));
+
+        container(
+            column![
+                rich_editor(&self.document, Message::Edited)
+                    .on_link(Message::LinkOpened)
+                    .height(Length::FillPortion(2)),
+                text(opened),
+                text("Markdown"),
+                scrollable(text(self.document.to_markdown()).font(iced::Font::MONOSPACE))
+                    .height(Length::FillPortion(1)),
+            ]
+            .spacing(10),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(10)
+        .into()
+    }
+}
@@ -0,0 +1,79 @@
+use iced::{
+    widget::{column, container, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::helpers::seek_bar;
+
+fn main() -> iced::Result {
+    SeekBarExample::run(Settings::default())
+}
+
+/// The duration of the track in seconds.
+const DURATION: f64 = 245.0;
+
+#[derive(Clone, Debug)]
+enum Message {
+    Seek(f64),
+    Released,
+}
+
+struct SeekBarExample {
+    position: f64,
+    last_seek: Option<f64>,
+}
+
+impl Sandbox for SeekBarExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        SeekBarExample {
+            position: 42.0,
+            last_seek: None,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("SeekBar example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Seek(position) => self.position = position,
+            Message::Released => self.last_seek = Some(self.position),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let seek_bar = seek_bar(DURATION, self.position, Message::Seek)
+            .buffered([(0.0, 90.0), (120.0, 150.0)])
+            .chapter(0.0, "Intro")
+            .chapter(35.0, "Verse")
+            .chapter(95.0, "Chorus")
+            .chapter(180.0, "Outro")
+            .on_release(Message::Released);
+
+        let last_seek = self.last_seek.map_or_else(
+            || String::from("Drag the handle, move away from the bar to scrub finely"),
+            |position| format!("Released at {}", iced_aw::seek_bar::format_time(position)),
+        );
+
+        let content = column![
+            text(format!(
+                "{} / {}",
+                iced_aw::seek_bar::format_time(self.position),
+                iced_aw::seek_bar::format_time(DURATION)
+            )),
+            seek_bar,
+            text(last_seek),
+        ]
+        .spacing(20)
+        .padding(40);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_y()
+            .into()
+    }
+}
@@ -0,0 +1,97 @@
+use iced::{
+    widget::{button, checkbox, column, container, row, slider, text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{helpers::segment_display, segment_display::Segments, SegmentDisplayStyles};
+
+fn main() -> iced::Result {
+    SegmentDisplayExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Increment,
+    Decrement,
+    SlantChanged(f32),
+    FourteenToggled(bool),
+}
+
+struct SegmentDisplayExample {
+    seconds: u32,
+    slant: f32,
+    fourteen: bool,
+}
+
+impl Sandbox for SegmentDisplayExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        SegmentDisplayExample {
+            seconds: 754,
+            slant: 0.1,
+            fourteen: false,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("SegmentDisplay example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Increment => self.seconds = self.seconds.saturating_add(15),
+            Message::Decrement => self.seconds = self.seconds.saturating_sub(15),
+            Message::SlantChanged(slant) => self.slant = slant,
+            Message::FourteenToggled(fourteen) => self.fourteen = fourteen,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let segments = if self.fourteen {
+            Segments::Fourteen
+        } else {
+            Segments::Seven
+        };
+
+        let timer = segment_display(format!("{:02}:{:02}", self.seconds / 60, self.seconds % 60))
+            .segments(segments)
+            .digit_height(64.0)
+            .slant(self.slant);
+
+        let temperature = segment_display(format!("{:.1}", f64::from(self.seconds) / 40.0 - 5.0))
+            .segments(segments)
+            .digits(5)
+            .slant(self.slant)
+            .style(SegmentDisplayStyles::Green);
+
+        let label = segment_display("HELLO")
+            .segments(Segments::Fourteen)
+            .slant(self.slant)
+            .decimal_points(false);
+
+        let controls = row![
+            button("-15 s").on_press(Message::Decrement),
+            button("+15 s").on_press(Message::Increment),
+            text("Slant"),
+            slider(-0.3..=0.3, self.slant, Message::SlantChanged)
+                .step(0.01)
+                .width(200),
+            checkbox("Fourteen segments", self.fourteen, Message::FourteenToggled),
+        ]
+        .spacing(10)
+        .align_items(Alignment::Center);
+
+        container(
+            column![timer, temperature, label, controls]
+                .spacing(20)
+                .align_items(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x()
+        .center_y()
+        .padding(20)
+        .into()
+    }
+}
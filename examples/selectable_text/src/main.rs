@@ -0,0 +1,53 @@
+use iced::{
+    widget::{column, container, scrollable, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::helpers::selectable_text;
+
+fn main() -> iced::Result {
+    SelectableTextExample::run(Settings::default())
+}
+
+struct SelectableTextExample {
+    log: String,
+}
+
+impl Sandbox for SelectableTextExample {
+    type Message = ();
+
+    fn new() -> Self {
+        let log = (1..=200)
+            .map(|line| {
+                format!(
+                    "[{line:>3}] INFO  request_{line} finished in {} ms",
+                    line * 7 % 97
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        SelectableTextExample { log }
+    }
+
+    fn title(&self) -> String {
+        String::from("SelectableText example")
+    }
+
+    fn update(&mut self, _message: ()) {}
+
+    fn view(&self) -> Element<'_, ()> {
+        container(
+            column![
+                text("Drag, double click or triple click to select, Ctrl+C to copy"),
+                scrollable(selectable_text(self.log.as_str()).font(iced::Font::MONOSPACE))
+                    .height(Length::Fill),
+            ]
+            .spacing(10),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(10)
+        .into()
+    }
+}
@@ -0,0 +1,169 @@
+use iced::{
+    executor,
+    widget::{column, scrollable, text},
+    Application, Command, Element, Settings, Theme,
+};
+
+use iced_aw::{
+    helpers::settings_page,
+    settings_page::{scroll_to_section, SettingsRow, SettingsSection},
+};
+
+fn main() -> iced::Result {
+    SettingsPageExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Search(String),
+    SectionSelected(usize),
+    DarkModeToggled(bool),
+    FontSizeChanged(f32),
+    LanguageSelected(String),
+    NotificationsToggled(bool),
+    SoundsToggled(bool),
+    VolumeChanged(f32),
+    ClearCache,
+    Reset,
+}
+
+struct SettingsPageExample {
+    search: String,
+    section: usize,
+    dark_mode: bool,
+    font_size: f32,
+    language: String,
+    notifications: bool,
+    sounds: bool,
+    volume: f32,
+    status: String,
+}
+
+const LANGUAGES: [&str; 4] = ["English", "Deutsch", "Français", "Español"];
+
+fn settings_id() -> scrollable::Id {
+    scrollable::Id::new("settings")
+}
+
+impl Application for SettingsPageExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        (
+            SettingsPageExample {
+                search: String::new(),
+                section: 0,
+                dark_mode: false,
+                font_size: 16.0,
+                language: String::from(LANGUAGES[0]),
+                notifications: true,
+                sounds: false,
+                volume: 0.5,
+                status: String::new(),
+            },
+            Command::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("SettingsPage example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::Search(search) => self.search = search,
+            Message::SectionSelected(section) => {
+                self.section = section;
+                return scroll_to_section(settings_id(), section);
+            }
+            Message::DarkModeToggled(dark_mode) => self.dark_mode = dark_mode,
+            Message::FontSizeChanged(font_size) => self.font_size = font_size,
+            Message::LanguageSelected(language) => self.language = language,
+            Message::NotificationsToggled(notifications) => self.notifications = notifications,
+            Message::SoundsToggled(sounds) => self.sounds = sounds,
+            Message::VolumeChanged(volume) => self.volume = volume,
+            Message::ClearCache => self.status = String::from("Cache cleared"),
+            Message::Reset => {
+                let (reset, _) = Self::new(());
+                *self = SettingsPageExample {
+                    status: String::from("Settings reset"),
+                    ..reset
+                };
+            }
+        }
+
+        Command::none()
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let sections = vec![
+            SettingsSection::new("Appearance")
+                .push(
+                    SettingsRow::toggle("Dark mode", self.dark_mode, Message::DarkModeToggled)
+                        .description("Use a dark theme for all windows."),
+                )
+                .push(SettingsRow::slider(
+                    "Font size",
+                    10.0..=24.0,
+                    self.font_size,
+                    Message::FontSizeChanged,
+                )),
+            SettingsSection::new("Language").push(
+                SettingsRow::dropdown(
+                    "Display language",
+                    LANGUAGES,
+                    Some(self.language.clone()),
+                    Message::LanguageSelected,
+                )
+                .description("The language of menus and dialogs."),
+            ),
+            SettingsSection::new("Notifications")
+                .push(SettingsRow::toggle(
+                    "Show notifications",
+                    self.notifications,
+                    Message::NotificationsToggled,
+                ))
+                .push(SettingsRow::toggle(
+                    "Play sounds",
+                    self.sounds,
+                    Message::SoundsToggled,
+                ))
+                .push(
+                    SettingsRow::slider("Volume", 0.0..=1.0, self.volume, Message::VolumeChanged)
+                        .step(0.05),
+                ),
+            SettingsSection::new("Storage")
+                .push(
+                    SettingsRow::action("Cache", "Clear", Message::ClearCache)
+                        .description("Remove temporary files to free some space."),
+                )
+                .push(SettingsRow::action(
+                    "Reset all settings",
+                    "Reset",
+                    Message::Reset,
+                )),
+        ];
+
+        let page = settings_page(
+            settings_id(),
+            sections,
+            &self.search,
+            Message::Search,
+            Message::SectionSelected,
+        )
+        .selected(Some(self.section));
+
+        column![page, text(&self.status)].into()
+    }
+
+    fn theme(&self) -> Theme {
+        if self.dark_mode {
+            Theme::Dark
+        } else {
+            Theme::Light
+        }
+    }
+}
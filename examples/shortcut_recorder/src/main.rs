@@ -0,0 +1,86 @@
+use iced::{
+    keyboard::{KeyCode, Modifiers},
+    widget::{column, container, row, text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{helpers::shortcut_recorder, shortcut_recorder::Shortcut};
+
+fn main() -> iced::Result {
+    ShortcutRecorderExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Recorded(usize, Shortcut),
+    Cleared(usize),
+}
+
+struct ShortcutRecorderExample {
+    actions: Vec<(&'static str, Option<Shortcut>)>,
+    reserved: Vec<Shortcut>,
+}
+
+impl Sandbox for ShortcutRecorderExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        ShortcutRecorderExample {
+            actions: vec![
+                ("Save", Some(Shortcut::new(Modifiers::CTRL, KeyCode::S))),
+                ("Find", Some(Shortcut::new(Modifiers::CTRL, KeyCode::F))),
+                ("Help", Some(Shortcut::new(Modifiers::empty(), KeyCode::F1))),
+                ("Toggle sidebar", None),
+            ],
+            reserved: vec![
+                Shortcut::new(Modifiers::CTRL, KeyCode::Q),
+                Shortcut::new(Modifiers::ALT, KeyCode::F4),
+            ],
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Shortcut recorder example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Recorded(action, shortcut) => self.actions[action].1 = Some(shortcut),
+            Message::Cleared(action) => self.actions[action].1 = None,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let rows = self.actions.iter().enumerate().fold(
+            column![].spacing(10),
+            |rows, (action, (label, shortcut))| {
+                rows.push(
+                    row![
+                        text(label).width(150),
+                        shortcut_recorder(*shortcut, move |shortcut| {
+                            Message::Recorded(action, shortcut)
+                        })
+                        .on_clear(Message::Cleared(action))
+                        .reserved(&self.reserved)
+                        .width(300),
+                    ]
+                    .spacing(10)
+                    .align_items(Alignment::Center),
+                )
+            },
+        );
+
+        container(
+            column![
+                rows,
+                text("Ctrl+Q and Alt+F4 are reserved. Backspace clears a shortcut.").size(14),
+            ]
+            .spacing(20),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x()
+        .center_y()
+        .into()
+    }
+}
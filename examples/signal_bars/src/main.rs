@@ -0,0 +1,90 @@
+use iced::{
+    widget::{button, column, container, row, slider, text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{
+    helpers::signal_bars,
+    signal_bars::{Shape, Status},
+};
+
+fn main() -> iced::Result {
+    SignalBarsExample::run(Settings::default())
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Message {
+    Level(f32),
+    Status(Status),
+}
+
+struct SignalBarsExample {
+    level: f32,
+    status: Status,
+}
+
+impl Sandbox for SignalBarsExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        SignalBarsExample {
+            level: 0.6,
+            status: Status::Connected,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("SignalBars example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Level(level) => self.level = level,
+            Message::Status(status) => self.status = status,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let indicators = row![
+            signal_bars(self.level).status(self.status).size(24.0),
+            signal_bars(self.level)
+                .bars(5)
+                .status(self.status)
+                .size(24.0),
+            signal_bars(self.level)
+                .shape(Shape::Arcs)
+                .bars(3)
+                .status(self.status)
+                .size(24.0),
+        ]
+        .spacing(30)
+        .align_items(Alignment::Center);
+
+        let level = row![
+            text("Level"),
+            slider(0.0..=1.0, self.level, Message::Level)
+                .step(0.05)
+                .width(200),
+        ]
+        .spacing(10)
+        .align_items(Alignment::Center);
+
+        let controls = row![
+            button("Connected").on_press(Message::Status(Status::Connected)),
+            button("Searching").on_press(Message::Status(Status::Searching)),
+            button("Error").on_press(Message::Status(Status::Error)),
+        ]
+        .spacing(10);
+
+        container(
+            column![indicators, level, controls]
+                .spacing(30)
+                .align_items(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x()
+        .center_y()
+        .into()
+    }
+}
@@ -0,0 +1,90 @@
+use iced::{
+    font,
+    widget::{column, container, row, text},
+    Application, Command, Element, Length, Settings, Theme,
+};
+
+use iced_aw::{helpers::split_button, MenuItem};
+
+fn main() -> iced::Result {
+    SplitButtonExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Save,
+    SaveAs,
+    SaveAll,
+    Export(&'static str),
+    FontLoaded(Result<(), font::Error>),
+}
+
+struct SplitButtonExample {
+    last_action: String,
+}
+
+impl Application for SplitButtonExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        (
+            SplitButtonExample {
+                last_action: String::from("None"),
+            },
+            font::load(iced_aw::graphics::icons::ICON_FONT_BYTES).map(Message::FontLoaded),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("SplitButton example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        self.last_action = match message {
+            Message::Save => String::from("Save"),
+            Message::SaveAs => String::from("Save as"),
+            Message::SaveAll => String::from("Save all"),
+            Message::Export(format) => format!("Export as {format}"),
+            Message::FontLoaded(_) => return Command::none(),
+        };
+
+        Command::none()
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let save = split_button(
+            "Save",
+            vec![
+                MenuItem::new("Save as…")
+                    .on_select(Message::SaveAs)
+                    .shortcut("Ctrl+Shift+S"),
+                MenuItem::new("Save all").on_select(Message::SaveAll),
+                MenuItem::new("Save a copy"),
+            ],
+        )
+        .on_press(Message::Save);
+
+        let export = split_button(
+            "Export",
+            vec![
+                MenuItem::new("PNG").on_select(Message::Export("PNG")),
+                MenuItem::new("SVG").on_select(Message::Export("SVG")),
+            ],
+        );
+
+        container(
+            column![
+                row![save, export].spacing(10),
+                text(format!("Last action: {}", self.last_action)),
+            ]
+            .spacing(20),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(20)
+        .into()
+    }
+}
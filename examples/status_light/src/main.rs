@@ -0,0 +1,106 @@
+use iced::{
+    widget::{button, column, container, row, text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{
+    helpers::status_light,
+    status_light::{Pattern, Severity, Shape},
+};
+
+fn main() -> iced::Result {
+    StatusLightExample::run(Settings::default())
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Message {
+    Connect,
+    Disconnect,
+    Fail,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Connection {
+    Connecting,
+    Online,
+    Offline,
+    Failed,
+}
+
+struct StatusLightExample {
+    connection: Connection,
+}
+
+impl Sandbox for StatusLightExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        StatusLightExample {
+            connection: Connection::Offline,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("StatusLight example")
+    }
+
+    fn update(&mut self, message: Message) {
+        self.connection = match (message, self.connection) {
+            (Message::Connect, Connection::Connecting) => Connection::Online,
+            (Message::Connect, _) => Connection::Connecting,
+            (Message::Disconnect, _) => Connection::Offline,
+            (Message::Fail, _) => Connection::Failed,
+        };
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let connection = match self.connection {
+            Connection::Connecting => status_light(Severity::Warning)
+                .pattern(Pattern::slow())
+                .label("Connecting"),
+            Connection::Online => status_light(Severity::Success).label("Online"),
+            Connection::Offline => status_light(Severity::Neutral)
+                .pattern(Pattern::Off)
+                .label("Offline"),
+            Connection::Failed => status_light(Severity::Danger)
+                .pattern(Pattern::fast())
+                .label("Connection failed"),
+        };
+
+        let devices = row![
+            status_light(Severity::Success).shape(Shape::Square),
+            status_light(Severity::Success).shape(Shape::Square),
+            status_light(Severity::Warning)
+                .shape(Shape::Square)
+                .pattern(Pattern::slow()),
+            status_light(Severity::Neutral)
+                .shape(Shape::Square)
+                .pattern(Pattern::Off),
+        ]
+        .spacing(6);
+
+        let controls = row![
+            button("Connect").on_press(Message::Connect),
+            button("Disconnect").on_press(Message::Disconnect),
+            button("Fail").on_press(Message::Fail),
+        ]
+        .spacing(10);
+
+        container(
+            column![
+                connection.size(16.0).text_size(20.0),
+                row![text("Devices"), devices]
+                    .spacing(10)
+                    .align_items(Alignment::Center),
+                controls
+            ]
+            .spacing(30)
+            .align_items(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x()
+        .center_y()
+        .into()
+    }
+}
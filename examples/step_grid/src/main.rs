@@ -0,0 +1,107 @@
+use std::time::Duration;
+
+use iced::{
+    executor,
+    widget::{button, column, container, row, text},
+    Application, Command, Element, Length, Settings, Subscription, Theme,
+};
+
+use iced_aw::helpers::step_grid;
+
+fn main() -> iced::Result {
+    StepGridExample::run(Settings::default())
+}
+
+/// The instruments of the drum machine.
+const INSTRUMENTS: [&str; 4] = ["Kick", "Snare", "Closed hat", "Open hat"];
+/// The number of steps of the pattern.
+const STEPS: usize = 16;
+
+#[derive(Clone, Debug)]
+enum Message {
+    CellChanged(usize, usize, Option<f32>),
+    Tick,
+    TogglePlayback,
+}
+
+struct StepGridExample {
+    cells: Vec<Vec<Option<f32>>>,
+    playing: bool,
+    step: usize,
+}
+
+impl Application for StepGridExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        let mut cells = vec![vec![None; STEPS]; INSTRUMENTS.len()];
+        for step in (0..STEPS).step_by(4) {
+            cells[0][step] = Some(1.0);
+        }
+        for step in (4..STEPS).step_by(8) {
+            cells[1][step] = Some(0.8);
+        }
+        for step in (0..STEPS).step_by(2) {
+            cells[2][step] = Some(0.5);
+        }
+
+        (
+            StepGridExample {
+                cells,
+                playing: false,
+                step: 0,
+            },
+            Command::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("StepGrid example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::CellChanged(row, step, velocity) => self.cells[row][step] = velocity,
+            Message::Tick => self.step = (self.step + 1) % STEPS,
+            Message::TogglePlayback => self.playing = !self.playing,
+        }
+
+        Command::none()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        if self.playing {
+            // Sixteenth notes at 120 BPM
+            iced::time::every(Duration::from_millis(125)).map(|_| Message::Tick)
+        } else {
+            Subscription::none()
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let labels = INSTRUMENTS
+            .iter()
+            .fold(column![].spacing(2), |labels, instrument| {
+                labels.push(container(text(instrument)).height(24).center_y())
+            });
+
+        let grid = step_grid(&self.cells, Message::CellChanged).playhead(Some(self.step));
+
+        let content = column![
+            button(if self.playing { "Stop" } else { "Play" }).on_press(Message::TogglePlayback),
+            row![labels.width(100), grid],
+            text("Drag to paint or erase, drag an active cell up or down to change its velocity"),
+        ]
+        .spacing(20)
+        .padding(40);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_y()
+            .into()
+    }
+}
@@ -0,0 +1,90 @@
+use iced::{
+    widget::{column, container, text},
+    Color, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{helpers::sunburst, HierarchyNode};
+
+fn main() -> iced::Result {
+    SunburstExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Drilled(Vec<usize>),
+}
+
+struct SunburstExample {
+    root: HierarchyNode,
+    path: Vec<usize>,
+}
+
+impl Sandbox for SunburstExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        SunburstExample {
+            root: HierarchyNode::new("Disk", 0.0).children([
+                HierarchyNode::new("Pictures", 0.0).children([
+                    HierarchyNode::new("Holidays", 0.0).children([
+                        HierarchyNode::new("2022", 64.0),
+                        HierarchyNode::new("2023", 96.0),
+                        HierarchyNode::new("2024", 48.0),
+                    ]),
+                    HierarchyNode::new("Screenshots", 12.0),
+                    HierarchyNode::new("Wallpapers", 24.0),
+                ]),
+                HierarchyNode::new("Music", 0.0).children([
+                    HierarchyNode::new("Jazz", 40.0),
+                    HierarchyNode::new("Rock", 72.0),
+                    HierarchyNode::new("Classical", 30.0),
+                ]),
+                HierarchyNode::new("Documents", 0.0).children([
+                    HierarchyNode::new("Invoices", 8.0),
+                    HierarchyNode::new("Letters", 4.0),
+                    HierarchyNode::new("Thesis", 20.0),
+                ]),
+            ]),
+            path: Vec::new(),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Sunburst example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Drilled(path) => self.path = path,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let chart = sunburst(&self.root)
+            .on_drill(Message::Drilled)
+            .color_by(|path, _node| match path.first() {
+                Some(0) => Color::from_rgb(0.2, 0.45, 0.8),
+                Some(1) => Color::from_rgb(0.9, 0.5, 0.2),
+                _ => Color::from_rgb(0.3, 0.7, 0.45),
+            })
+            .format(|size| format!("{size} GB"));
+
+        let level = self
+            .root
+            .get(&self.path)
+            .map_or_else(String::new, |node| node.label.clone());
+
+        container(
+            column![
+                text(format!("Showing: {level}")),
+                chart,
+                text("Click a segment to drill down and the center or right click to go back up"),
+            ]
+            .spacing(20),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(20)
+        .into()
+    }
+}
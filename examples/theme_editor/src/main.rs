@@ -0,0 +1,115 @@
+use iced::{
+    font,
+    theme::Palette,
+    widget::{column, container, scrollable, text},
+    Application, Command, Element, Length, Settings, Theme,
+};
+
+use iced_aw::{
+    helpers::theme_editor,
+    theme_editor::{Export, PaletteColor},
+};
+
+fn main() -> iced::Result {
+    ThemeEditorExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Edit(Option<PaletteColor>),
+    Changed(Palette),
+    Exported(Export, String),
+    FontLoaded(Result<(), font::Error>),
+}
+
+enum ThemeEditorExample {
+    Loading,
+    Loaded(State),
+}
+
+struct State {
+    palette: Palette,
+    editing: Option<PaletteColor>,
+    exported: String,
+}
+
+impl Application for ThemeEditorExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        (
+            ThemeEditorExample::Loading,
+            font::load(iced_aw::graphics::icons::ICON_FONT_BYTES).map(Message::FontLoaded),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Theme editor example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match self {
+            ThemeEditorExample::Loading => {
+                if let Message::FontLoaded(_) = message {
+                    *self = ThemeEditorExample::Loaded(State {
+                        palette: Palette::LIGHT,
+                        editing: None,
+                        exported: String::new(),
+                    });
+                }
+            }
+            ThemeEditorExample::Loaded(state) => match message {
+                Message::Edit(editing) => state.editing = editing,
+                Message::Changed(palette) => {
+                    state.palette = palette;
+                    state.editing = None;
+                }
+                Message::Exported(format, exported) => {
+                    state.exported = format!("// {format:?}\n{exported}");
+                }
+                Message::FontLoaded(_) => {}
+            },
+        }
+
+        Command::none()
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let content: Element<'_, Message> = match self {
+            ThemeEditorExample::Loading => text("Loading").into(),
+            ThemeEditorExample::Loaded(state) => scrollable(
+                column![
+                    theme_editor(
+                        state.palette,
+                        state.editing,
+                        Message::Edit,
+                        Message::Changed
+                    )
+                    .on_export(Message::Exported),
+                    text(&state.exported).font(font::Font::MONOSPACE),
+                ]
+                .spacing(20)
+                .max_width(600),
+            )
+            .into(),
+        };
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(20)
+            .center_x()
+            .center_y()
+            .into()
+    }
+
+    fn theme(&self) -> Theme {
+        match self {
+            ThemeEditorExample::Loading => Theme::Light,
+            ThemeEditorExample::Loaded(state) => Theme::custom(state.palette),
+        }
+    }
+}
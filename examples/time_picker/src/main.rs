@@ -1,9 +1,12 @@
 use iced::{
     alignment, font,
-    widget::{container, text, Button, Container, Row, Text},
+    widget::{container, text, Button, Column, Container, Row, Text},
     Alignment, Application, Command, Element, Length, Settings, Theme,
 };
-use iced_aw::{time_picker::Time, TimePicker};
+use iced_aw::{
+    time_picker::{Period, Time},
+    TimePicker,
+};
 
 fn main() -> iced::Result {
     TimePickerExample::run(Settings::default())
@@ -15,6 +18,9 @@ enum Message {
     ChooseTime,
     SubmitTime(Time),
     CancelTime,
+    ChooseShift,
+    SubmitShift(Time, Time),
+    CancelShift,
     Loaded(Result<(), String>),
     FontLoaded(Result<(), font::Error>),
 }
@@ -29,6 +35,8 @@ enum TimePickerExample {
 struct State {
     time: Time,
     show_picker: bool,
+    shift: (Time, Time),
+    show_shift_picker: bool,
 }
 
 async fn load() -> Result<(), String> {
@@ -62,6 +70,19 @@ impl Application for TimePickerExample {
                     *self = TimePickerExample::Loaded(State {
                         time: Time::now_hm(true),
                         show_picker: false,
+                        shift: (
+                            Time::Hm {
+                                hour: 22,
+                                minute: 0,
+                                period: Period::H24,
+                            },
+                            Time::Hm {
+                                hour: 6,
+                                minute: 0,
+                                period: Period::H24,
+                            },
+                        ),
+                        show_shift_picker: false,
                     })
                 }
             }
@@ -76,6 +97,16 @@ impl Application for TimePickerExample {
                 Message::CancelTime => {
                     state.show_picker = false;
                 }
+                Message::ChooseShift => {
+                    state.show_shift_picker = true;
+                }
+                Message::SubmitShift(start, end) => {
+                    state.shift = (start, end);
+                    state.show_shift_picker = false;
+                }
+                Message::CancelShift => {
+                    state.show_shift_picker = false;
+                }
                 _ => {}
             },
         }
@@ -106,15 +137,42 @@ impl Application for TimePickerExample {
                     Message::SubmitTime,
                 )
                 //.show_seconds()
-                .use_24h();
+                .use_24h()
+                .minute_step(5);
+
+                let but = Button::new(Text::new("Set Shift")).on_press(Message::ChooseShift);
+
+                let shift_picker = TimePicker::new_range(
+                    state.show_shift_picker,
+                    state.shift,
+                    but,
+                    Message::CancelShift,
+                    Message::SubmitShift,
+                )
+                .use_24h()
+                .minute_step(15);
 
-                let row = Row::new()
-                    .align_items(Alignment::Center)
+                let column = Column::new()
                     .spacing(10)
-                    .push(timepicker)
-                    .push(Text::new(format!("Time: {}", state.time)));
+                    .push(
+                        Row::new()
+                            .align_items(Alignment::Center)
+                            .spacing(10)
+                            .push(timepicker)
+                            .push(Text::new(format!("Time: {}", state.time))),
+                    )
+                    .push(
+                        Row::new()
+                            .align_items(Alignment::Center)
+                            .spacing(10)
+                            .push(shift_picker)
+                            .push(Text::new(format!(
+                                "Shift: {} - {}",
+                                state.shift.0, state.shift.1
+                            ))),
+                    );
 
-                Container::new(row)
+                Container::new(column)
                     .center_x()
                     .center_y()
                     .width(Length::Fill)
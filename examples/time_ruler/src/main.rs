@@ -0,0 +1,70 @@
+use iced::{
+    widget::{column, container, row, slider, text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::helpers::time_ruler;
+
+fn main() -> iced::Result {
+    TimeRulerExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Zoom(f64),
+    Scroll(f64),
+}
+
+struct TimeRulerExample {
+    /// The visible duration as a power of ten in seconds.
+    zoom: f64,
+    /// The start of the visible range in seconds.
+    offset: f64,
+}
+
+impl Sandbox for TimeRulerExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        TimeRulerExample {
+            zoom: 2.0,
+            offset: 0.0,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("TimeRuler example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Zoom(zoom) => self.zoom = zoom,
+            Message::Scroll(offset) => self.offset = offset,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let duration = 10f64.powf(self.zoom);
+
+        let content = column![
+            time_ruler(self.offset, self.offset + duration),
+            row![
+                text("Zoom"),
+                slider(0.0..=6.5, self.zoom, Message::Zoom).step(0.01),
+            ]
+            .spacing(10)
+            .align_items(Alignment::Center),
+            row![
+                text("Scroll"),
+                slider(0.0..=1_000_000.0, self.offset, Message::Scroll),
+            ]
+            .spacing(10)
+            .align_items(Alignment::Center),
+            text(format!("Showing {duration:.1} seconds")),
+        ]
+        .spacing(20)
+        .width(Length::Fill);
+
+        container(content).padding(20).into()
+    }
+}
@@ -0,0 +1,108 @@
+use iced::{
+    font,
+    widget::{column, container, pick_list, text},
+    window, Application, Command, Element, Length, Settings, Theme,
+};
+
+use iced_aw::{helpers::title_bar, ControlsPlacement, WindowControl};
+
+fn main() -> iced::Result {
+    TitleBarExample::run(Settings {
+        window: window::Settings {
+            decorations: false,
+            ..window::Settings::default()
+        },
+        ..Settings::default()
+    })
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Window(WindowControl),
+    PlacementSelected(Placement),
+    FontLoaded(Result<(), font::Error>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Placement {
+    Leading,
+    Trailing,
+}
+
+impl Placement {
+    const ALL: [Placement; 2] = [Placement::Leading, Placement::Trailing];
+}
+
+impl std::fmt::Display for Placement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Placement::Leading => "Leading (macOS)",
+            Placement::Trailing => "Trailing (Windows, Linux)",
+        })
+    }
+}
+
+struct TitleBarExample {
+    placement: Placement,
+}
+
+impl Application for TitleBarExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        let placement = match ControlsPlacement::platform() {
+            ControlsPlacement::Leading => Placement::Leading,
+            ControlsPlacement::Trailing => Placement::Trailing,
+        };
+
+        (
+            TitleBarExample { placement },
+            font::load(iced_aw::graphics::icons::ICON_FONT_BYTES).map(Message::FontLoaded),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("TitleBar example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::Window(control) => control.command(),
+            Message::PlacementSelected(placement) => {
+                self.placement = placement;
+                Command::none()
+            }
+            Message::FontLoaded(_) => Command::none(),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let placement = match self.placement {
+            Placement::Leading => ControlsPlacement::Leading,
+            Placement::Trailing => ControlsPlacement::Trailing,
+        };
+
+        let content = column![
+            text("Drag the title bar to move the window, double-click it to maximize."),
+            pick_list(
+                &Placement::ALL[..],
+                Some(self.placement),
+                Message::PlacementSelected
+            ),
+        ]
+        .spacing(10);
+
+        column![
+            title_bar("TitleBar example", Message::Window).placement(placement),
+            container(content)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .center_x()
+                .center_y(),
+        ]
+        .into()
+    }
+}
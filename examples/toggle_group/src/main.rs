@@ -0,0 +1,91 @@
+use iced::{
+    widget::{column, container, text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{helpers::toggle_group, SelectionMode};
+
+fn main() -> iced::Result {
+    ToggleGroupExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    AlignmentChanged(Vec<usize>),
+    FormattingChanged(Vec<usize>),
+}
+
+struct ToggleGroupExample {
+    alignment: Vec<usize>,
+    formatting: Vec<usize>,
+}
+
+const ALIGNMENTS: [&str; 4] = ["Left", "Center", "Right", "Justify"];
+const FORMATTING: [&str; 4] = ["Bold", "Italic", "Underline", "Strikethrough"];
+
+impl Sandbox for ToggleGroupExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        ToggleGroupExample {
+            alignment: vec![0],
+            formatting: Vec::new(),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("ToggleGroup example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::AlignmentChanged(selection) => self.alignment = selection,
+            Message::FormattingChanged(selection) => self.formatting = selection,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let alignment =
+            toggle_group(ALIGNMENTS, &self.alignment, Message::AlignmentChanged).allow_empty(false);
+
+        let formatting = toggle_group(FORMATTING, &self.formatting, Message::FormattingChanged)
+            .mode(SelectionMode::Multiple)
+            .width(Length::Fill);
+
+        let summary = |selection: &[usize], labels: &[&str]| {
+            if selection.is_empty() {
+                String::from("None")
+            } else {
+                selection
+                    .iter()
+                    .map(|index| labels[*index])
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }
+        };
+
+        let content = column![
+            text("Alignment (exclusive)"),
+            alignment,
+            text(format!(
+                "Alignment: {}",
+                summary(&self.alignment, &ALIGNMENTS)
+            )),
+            text("Formatting (multiple)"),
+            formatting,
+            text(format!(
+                "Formatting: {}",
+                summary(&self.formatting, &FORMATTING)
+            )),
+        ]
+        .spacing(10)
+        .max_width(500);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
@@ -0,0 +1,125 @@
+use std::time::Duration;
+
+use iced::{
+    font,
+    widget::{column, container, text},
+    Application, Command, Element, Length, Settings, Subscription, Theme,
+};
+
+use iced_aw::{helpers::transport_controls, TransportEvent};
+
+fn main() -> iced::Result {
+    TransportControlsExample::run(Settings::default())
+}
+
+/// The tracks of the playlist with their durations in seconds.
+const TRACKS: [(&str, f64); 3] = [("Overture", 95.0), ("Interlude", 42.0), ("Finale", 187.0)];
+
+#[derive(Clone, Debug)]
+enum Message {
+    Transport(TransportEvent),
+    Tick,
+    FontLoaded(Result<(), font::Error>),
+}
+
+struct TransportControlsExample {
+    track: usize,
+    playing: bool,
+    position: f64,
+    volume: f32,
+}
+
+impl Application for TransportControlsExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        (
+            TransportControlsExample {
+                track: 0,
+                playing: false,
+                position: 0.0,
+                volume: 0.8,
+            },
+            font::load(iced_aw::graphics::icons::ICON_FONT_BYTES).map(Message::FontLoaded),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("TransportControls example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::Transport(event) => match event {
+                TransportEvent::Play => self.playing = true,
+                TransportEvent::Pause => self.playing = false,
+                TransportEvent::Stop => {
+                    self.playing = false;
+                    self.position = 0.0;
+                }
+                TransportEvent::Previous => {
+                    self.track = (self.track + TRACKS.len() - 1) % TRACKS.len();
+                    self.position = 0.0;
+                }
+                TransportEvent::Next => {
+                    self.track = (self.track + 1) % TRACKS.len();
+                    self.position = 0.0;
+                }
+                TransportEvent::Seek(position) => self.position = position,
+                TransportEvent::SeekReleased => {}
+                TransportEvent::Volume(volume) => self.volume = volume,
+            },
+            Message::Tick => {
+                self.position += 0.25;
+
+                if self.position >= TRACKS[self.track].1 {
+                    self.track = (self.track + 1) % TRACKS.len();
+                    self.position = 0.0;
+                }
+            }
+            Message::FontLoaded(_) => {}
+        }
+
+        Command::none()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        if self.playing {
+            iced::time::every(Duration::from_millis(250)).map(|_| Message::Tick)
+        } else {
+            Subscription::none()
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let (title, duration) = TRACKS[self.track];
+
+        let controls = transport_controls(
+            self.playing,
+            self.position,
+            duration,
+            self.volume,
+            Message::Transport,
+        )
+        .buffered([(0.0, (self.position + 30.0).min(duration))])
+        .chapter(0.0, "Intro")
+        .chapter(duration / 2.0, "Theme");
+
+        let content = column![
+            text(format!("Now playing: {title}")).size(24),
+            controls,
+            text(format!("Volume: {:.0}%", self.volume * 100.0)),
+        ]
+        .spacing(20)
+        .padding(40);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_y()
+            .into()
+    }
+}
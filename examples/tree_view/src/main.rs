@@ -0,0 +1,119 @@
+use iced::{
+    font,
+    widget::{column, container, text},
+    Application, Command, Element, Length, Settings, Theme,
+};
+
+use iced_aw::{
+    core::tree_node::{self, DropPosition},
+    helpers::tree_view,
+    TreeNode,
+};
+
+fn main() -> iced::Result {
+    TreeViewExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Selected(usize),
+    Checked(Vec<usize>),
+    Moved(usize, usize, DropPosition),
+    Renamed(usize, String),
+    FontLoaded(Result<(), font::Error>),
+}
+
+struct TreeViewExample {
+    nodes: Vec<TreeNode>,
+    selected: Option<usize>,
+    checked: Vec<usize>,
+}
+
+impl Application for TreeViewExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        let nodes = vec![
+            TreeNode::new(0, "Applications").children([
+                TreeNode::new(1, "Editor"),
+                TreeNode::new(2, "Terminal"),
+                TreeNode::new(3, "Games")
+                    .children([TreeNode::new(4, "Chess"), TreeNode::new(5, "Solitaire")]),
+            ]),
+            TreeNode::new(6, "Documentation").children([
+                TreeNode::new(7, "Manual pages"),
+                TreeNode::new(8, "Tutorials"),
+            ]),
+            TreeNode::new(9, "Fonts"),
+        ];
+
+        (
+            TreeViewExample {
+                nodes,
+                selected: None,
+                checked: vec![1, 7],
+            },
+            font::load(iced_aw::graphics::icons::ICON_FONT_BYTES).map(Message::FontLoaded),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("TreeView example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::Selected(id) => self.selected = Some(id),
+            Message::Checked(checked) => self.checked = checked,
+            Message::Moved(source, target, position) => {
+                let _ = tree_node::move_node(&mut self.nodes, source, target, position);
+            }
+            Message::Renamed(id, label) => {
+                if let Some(node) = tree_node::find_mut(&mut self.nodes, id) {
+                    node.label = label;
+                }
+            }
+            Message::FontLoaded(_) => {}
+        }
+
+        Command::none()
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let selected = self
+            .selected
+            .and_then(|id| tree_node::find(&self.nodes, id))
+            .map_or_else(
+                || String::from("Nothing selected"),
+                |node| format!("Selected: {}", node.label),
+            );
+
+        let tree = tree_view(&self.nodes)
+            .on_select(Message::Selected)
+            .checkboxes(&self.checked, Message::Checked)
+            .on_move(Message::Moved)
+            .on_rename(Message::Renamed)
+            // Only the nodes with children are folders that take dropped nodes.
+            .can_drop(|_, target, position| {
+                position != DropPosition::Into
+                    || tree_node::find(&self.nodes, target)
+                        .is_some_and(|node| !node.children.is_empty())
+            });
+
+        container(
+            column![
+                tree,
+                text(selected),
+                text(format!("Checked: {:?}", self.checked)),
+            ]
+            .spacing(10),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(10)
+        .into()
+    }
+}
@@ -0,0 +1,96 @@
+use iced::{
+    font,
+    widget::{column, container, text},
+    Application, Command, Element, Length, Settings, Theme,
+};
+
+use iced_aw::{core::volume::gain_to_db, helpers::volume_slider, VolumeScale};
+
+fn main() -> iced::Result {
+    VolumeSliderExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    VolumeChanged(f32),
+    MuteToggled(bool),
+    FontLoaded(Result<(), font::Error>),
+}
+
+struct VolumeSliderExample {
+    volume: f32,
+    muted: bool,
+}
+
+impl Application for VolumeSliderExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        (
+            VolumeSliderExample {
+                volume: 0.5,
+                muted: false,
+            },
+            font::load(iced_aw::graphics::icons::ICON_FONT_BYTES).map(Message::FontLoaded),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("VolumeSlider example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::VolumeChanged(volume) => {
+                self.volume = volume;
+                self.muted = false;
+            }
+            Message::MuteToggled(muted) => self.muted = muted,
+            Message::FontLoaded(_) => {}
+        }
+
+        Command::none()
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let label = if self.muted {
+            String::from("Muted")
+        } else if self.volume <= 0.0 {
+            String::from("Silent")
+        } else {
+            format!(
+                "Gain {:.3} ({:.1} dB)",
+                self.volume,
+                gain_to_db(self.volume)
+            )
+        };
+
+        let content = column![
+            text("Decibel scale"),
+            volume_slider(self.volume, Message::VolumeChanged)
+                .muted(self.muted)
+                .on_toggle_mute(Message::MuteToggled)
+                .width(300),
+            text("Linear scale"),
+            volume_slider(self.volume, Message::VolumeChanged)
+                .muted(self.muted)
+                .on_toggle_mute(Message::MuteToggled)
+                .scale(VolumeScale::Linear)
+                .width(300),
+            text(label),
+            text("Scroll to adjust, double click to reset"),
+        ]
+        .spacing(20)
+        .padding(40);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
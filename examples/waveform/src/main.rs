@@ -0,0 +1,100 @@
+use iced::{
+    widget::{button, column, container, row, text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{helpers::waveform, Peaks};
+
+fn main() -> iced::Result {
+    WaveformExample::run(Settings::default())
+}
+
+/// The sample rate of the generated signal.
+const SAMPLE_RATE: usize = 44_100;
+
+#[derive(Clone, Debug)]
+enum Message {
+    Select(usize, usize),
+    Forward,
+    Rewind,
+}
+
+struct WaveformExample {
+    peaks: Peaks,
+    selection: Option<(usize, usize)>,
+    playhead: usize,
+}
+
+impl Sandbox for WaveformExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        // A minute of a beating tone with a slowly changing volume.
+        let samples: Vec<f32> = (0..SAMPLE_RATE * 60)
+            .map(|i| {
+                let t = i as f32 / SAMPLE_RATE as f32;
+                let volume = (t * 0.3).sin().abs() * 0.8 + 0.1;
+
+                volume * (t * 440.0 * std::f32::consts::TAU).sin() * (t * 2.0).cos()
+            })
+            .collect();
+
+        WaveformExample {
+            peaks: Peaks::new(samples),
+            selection: None,
+            playhead: 0,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Waveform example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Select(start, end) => self.selection = Some((start, end)),
+            Message::Forward => {
+                self.playhead = (self.playhead + SAMPLE_RATE * 5).min(self.peaks.len());
+            }
+            Message::Rewind => self.playhead = self.playhead.saturating_sub(SAMPLE_RATE * 5),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let mut wave = waveform(&self.peaks)
+            .height(200)
+            .playhead(self.playhead)
+            .on_select(Message::Select);
+
+        if let Some((start, end)) = self.selection {
+            wave = wave.selection(start, end);
+        }
+
+        let selection = self.selection.map_or_else(
+            || String::from("Drag over the waveform to select a region."),
+            |(start, end)| {
+                format!(
+                    "Selected {:.2} s to {:.2} s",
+                    start as f32 / SAMPLE_RATE as f32,
+                    end as f32 / SAMPLE_RATE as f32
+                )
+            },
+        );
+
+        let content = column![
+            text("Scroll with the mouse wheel, zoom while holding Ctrl."),
+            wave,
+            row![
+                button(text("-5 s")).on_press(Message::Rewind),
+                button(text("+5 s")).on_press(Message::Forward),
+                text(selection),
+            ]
+            .spacing(10)
+            .align_items(Alignment::Center),
+        ]
+        .spacing(20)
+        .width(Length::Fill);
+
+        container(content).padding(20).into()
+    }
+}
@@ -0,0 +1,123 @@
+use iced::{
+    widget::{button, checkbox, column, container, text, text_input},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{
+    helpers::wizard,
+    wizard::{WizardPage, WizardProgress},
+};
+
+fn main() -> iced::Result {
+    WizardExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Open,
+    Cancel,
+    PageChanged(WizardProgress),
+    Finish,
+    NameChanged(String),
+    EmailChanged(String),
+    TermsToggled(bool),
+}
+
+#[derive(Default)]
+struct WizardExample {
+    show: bool,
+    progress: WizardProgress,
+    name: String,
+    email: String,
+    terms: bool,
+    status: String,
+}
+
+impl Sandbox for WizardExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn title(&self) -> String {
+        String::from("Wizard example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Open => self.show = true,
+            // The progress is kept, so the wizard resumes where it was left.
+            Message::Cancel => self.show = false,
+            Message::PageChanged(progress) => self.progress = progress,
+            Message::Finish => {
+                self.show = false;
+                self.progress = WizardProgress::new();
+                self.status = format!("Welcome, {} <{}>!", self.name, self.email);
+            }
+            Message::NameChanged(name) => self.name = name,
+            Message::EmailChanged(email) => self.email = email,
+            Message::TermsToggled(terms) => self.terms = terms,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let underlay = container(
+            column![
+                button("Create an account").on_press(Message::Open),
+                text(&self.status),
+            ]
+            .spacing(10),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x()
+        .center_y();
+
+        let pages = vec![
+            WizardPage::new(
+                "Name",
+                column![
+                    text("What is your name?"),
+                    text_input("Jane Doe", &self.name).on_input(Message::NameChanged),
+                ]
+                .spacing(10),
+            )
+            .validate(|| !self.name.trim().is_empty()),
+            WizardPage::new(
+                "Email",
+                column![
+                    text("Where can we reach you?"),
+                    text_input("jane@example.com", &self.email).on_input(Message::EmailChanged),
+                ]
+                .spacing(10),
+            )
+            .validate(|| self.email.contains('@')),
+            WizardPage::new(
+                "Terms",
+                checkbox(
+                    "I accept the terms and conditions",
+                    self.terms,
+                    Message::TermsToggled,
+                ),
+            )
+            .validate(|| self.terms),
+            WizardPage::new(
+                "Done",
+                text(format!("Ready to create the account of {}.", self.name)),
+            ),
+        ];
+
+        wizard(
+            underlay,
+            self.show,
+            "Create an account",
+            pages,
+            self.progress,
+            Message::PageChanged,
+            Message::Finish,
+        )
+        .on_cancel(Message::Cancel)
+        .into()
+    }
+}
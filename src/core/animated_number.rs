@@ -0,0 +1,155 @@
+//! Roll the digits of an animated number like an odometer.
+//!
+//! *This API requires the following crate features to be activated: `animated_number`*
+
+/// The way an animated number moves to a new value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Transition {
+    /// The digits roll to the new value like an odometer, the lower digits
+    /// passing more of the digits in between.
+    #[default]
+    Roll,
+    /// The value counts up or down to the new value, formatting each value in
+    /// between.
+    Count,
+}
+
+/// A column of an animated number at a moment of its transition.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Reel {
+    /// The character of the column before the transition.
+    pub from: char,
+    /// The character of the column after the transition.
+    pub to: char,
+    /// The character leaving the column.
+    pub current: char,
+    /// The character rolling into the column.
+    pub next: char,
+    /// How far the next character rolled in, from 0.0 to 1.0.
+    pub offset: f32,
+}
+
+impl Reel {
+    /// Creates a new [`Reel`] resting at the given character.
+    #[must_use]
+    pub fn new(character: char) -> Self {
+        Self {
+            from: character,
+            to: character,
+            current: character,
+            next: character,
+            offset: 0.0,
+        }
+    }
+}
+
+/// Returns the value at the given progress between `from` and `to`, from 0.0
+/// to 1.0.
+#[must_use]
+pub fn interpolate(from: f64, to: f64, progress: f32) -> f64 {
+    from + (to - from) * f64::from(progress.clamp(0.0, 1.0))
+}
+
+/// Returns the columns of a number rolling from the text `from` to the text
+/// `to` at the given progress, from 0.0 to 1.0.
+///
+/// The texts are aligned right. The digits roll up through the digits in
+/// between if the number is `rising` and down otherwise, while other
+/// characters roll over once.
+#[must_use]
+pub fn reels(from: &str, to: &str, rising: bool, progress: f32) -> Vec<Reel> {
+    let from: Vec<char> = from.chars().collect();
+    let to: Vec<char> = to.chars().collect();
+    let length = from.len().max(to.len());
+    let padded = |chars: &[char], i: usize| {
+        (i + chars.len())
+            .checked_sub(length)
+            .map_or(' ', |i| chars[i])
+    };
+    let progress = progress.clamp(0.0, 1.0);
+
+    (0..length)
+        .map(|i| {
+            let (from, to) = (padded(&from, i), padded(&to, i));
+
+            match (from.to_digit(10), to.to_digit(10)) {
+                _ if from == to => Reel::new(to),
+                _ if progress >= 1.0 => Reel::new(to),
+                (Some(start), Some(end)) => {
+                    let steps = if rising {
+                        (end + 10 - start) % 10
+                    } else {
+                        (start + 10 - end) % 10
+                    };
+                    #[allow(clippy::cast_precision_loss)]
+                    let position = steps as f32 * progress;
+                    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                    let passed = position.floor() as u32;
+                    let digit = |step: u32| {
+                        let digit = if rising {
+                            start + step
+                        } else {
+                            start + 10 * (step / 10 + 1) - step
+                        } % 10;
+                        char::from_digit(digit, 10).unwrap_or(to)
+                    };
+
+                    Reel {
+                        from,
+                        to,
+                        current: digit(passed),
+                        next: digit(passed + 1),
+                        offset: position.fract(),
+                    }
+                }
+                _ => Reel {
+                    from,
+                    to,
+                    current: from,
+                    next: to,
+                    offset: progress,
+                },
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{interpolate, reels, Reel};
+
+    #[test]
+    fn interpolate_test() {
+        assert!((interpolate(10.0, 20.0, 0.5) - 15.0).abs() < f64::EPSILON);
+        assert!((interpolate(10.0, 20.0, 2.0) - 20.0).abs() < f64::EPSILON);
+        assert!((interpolate(10.0, -10.0, 0.25) - 5.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn reels_test() {
+        // The ones roll up from 9 past 0 to 5, the tens by one.
+        let rolling = reels("19", "25", true, 0.5);
+        assert_eq!(rolling.len(), 2);
+        assert_eq!((rolling[0].current, rolling[0].next), ('1', '2'));
+        assert!((rolling[0].offset - 0.5).abs() < f32::EPSILON);
+        assert_eq!((rolling[1].current, rolling[1].next), ('2', '3'));
+        assert!(rolling[1].offset.abs() < f32::EPSILON);
+
+        // The digits roll down for a falling number.
+        let falling = reels("25", "19", false, 0.5);
+        assert_eq!((falling[1].current, falling[1].next), ('2', '1'));
+        assert_eq!((falling[0].current, falling[0].next), ('2', '1'));
+
+        // A longer number is aligned right, rolling in the new column.
+        let growing = reels("99", "100", true, 0.25);
+        assert_eq!((growing[0].from, growing[0].to), (' ', '1'));
+        assert_eq!((growing[0].current, growing[0].next), (' ', '1'));
+        assert_eq!(growing[2].current, '9');
+
+        // The number rests at the end.
+        assert_eq!(
+            reels("12.5", "13.0", true, 1.0),
+            "13.0".chars().map(Reel::new).collect::<Vec<_>>()
+        );
+    }
+}
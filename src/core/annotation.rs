@@ -0,0 +1,448 @@
+//! Describe the guide lines, shaded regions and labeled points drawn above
+//! the series of charts.
+//!
+//! *This API requires the following crate features to be activated: `live_chart` or `candlestick`*
+use iced_widget::core::{
+    alignment::{Horizontal, Vertical},
+    renderer::Quad,
+    text, Background, Color, Point, Rectangle, Size,
+};
+use iced_widget::text::LineHeight;
+
+/// The distance in pixels within which the cursor hovers a line or a point.
+const HOVER_DISTANCE: f32 = 6.0;
+/// The padding around the labels.
+const PADDING: f32 = 4.0;
+/// The radius of the dot of a point.
+const DOT_RADIUS: f32 = 3.0;
+
+/// An annotation of a chart, given in the time and value units of its axes.
+///
+/// The annotations are drawn above the series. Hovering one emphasizes it
+/// and highlights its label.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Annotation {
+    /// A vertical guide line at a time, like the time of an event.
+    VerticalLine {
+        /// The time of the line.
+        time: f64,
+        /// The label at the top of the line.
+        label: String,
+    },
+    /// A horizontal guide line at a value, like a threshold.
+    HorizontalLine {
+        /// The value of the line.
+        value: f32,
+        /// The label above the left end of the line.
+        label: String,
+    },
+    /// A region between two times, shaded over the height of the plot.
+    TimeRegion {
+        /// The time the region starts at.
+        start: f64,
+        /// The time the region ends at.
+        end: f64,
+        /// The label in the top left corner of the region.
+        label: String,
+    },
+    /// A region between two values, shaded over the width of the plot.
+    ValueRegion {
+        /// The lowest value of the region.
+        low: f32,
+        /// The highest value of the region.
+        high: f32,
+        /// The label in the top left corner of the region.
+        label: String,
+    },
+    /// A point marked with a dot and a label.
+    Point {
+        /// The time of the point.
+        time: f64,
+        /// The value of the point.
+        value: f32,
+        /// The label right of the dot.
+        label: String,
+    },
+}
+
+impl Annotation {
+    /// Creates a vertical guide line at the given time.
+    #[must_use]
+    pub fn vertical_line(time: f64, label: impl Into<String>) -> Self {
+        Self::VerticalLine {
+            time,
+            label: label.into(),
+        }
+    }
+
+    /// Creates a horizontal guide line at the given value.
+    #[must_use]
+    pub fn horizontal_line(value: f32, label: impl Into<String>) -> Self {
+        Self::HorizontalLine {
+            value,
+            label: label.into(),
+        }
+    }
+
+    /// Creates a region between the given times.
+    #[must_use]
+    pub fn time_region(start: f64, end: f64, label: impl Into<String>) -> Self {
+        Self::TimeRegion {
+            start: start.min(end),
+            end: end.max(start),
+            label: label.into(),
+        }
+    }
+
+    /// Creates a region between the given values.
+    #[must_use]
+    pub fn value_region(low: f32, high: f32, label: impl Into<String>) -> Self {
+        Self::ValueRegion {
+            low: low.min(high),
+            high: high.max(low),
+            label: label.into(),
+        }
+    }
+
+    /// Creates a point at the given time and value.
+    #[must_use]
+    pub fn point(time: f64, value: f32, label: impl Into<String>) -> Self {
+        Self::Point {
+            time,
+            value,
+            label: label.into(),
+        }
+    }
+
+    /// Returns the label of the [`Annotation`].
+    #[must_use]
+    pub fn label(&self) -> &str {
+        match self {
+            Self::VerticalLine { label, .. }
+            | Self::HorizontalLine { label, .. }
+            | Self::TimeRegion { label, .. }
+            | Self::ValueRegion { label, .. }
+            | Self::Point { label, .. } => label,
+        }
+    }
+
+    /// Returns whether the [`Annotation`] is a shaded region.
+    #[must_use]
+    pub fn is_region(&self) -> bool {
+        matches!(self, Self::TimeRegion { .. } | Self::ValueRegion { .. })
+    }
+
+    /// Lays out the [`Annotation`] on the given plot, with the given
+    /// positions of the times and the values.
+    ///
+    /// The lines are one pixel wide and the points have no size. Returns
+    /// `None` if the [`Annotation`] is outside of the plot.
+    #[must_use]
+    pub fn layout(
+        &self,
+        plot: Rectangle,
+        x: impl Fn(f64) -> f32,
+        y: impl Fn(f32) -> f32,
+    ) -> Option<Rectangle> {
+        let (left, right) = (plot.x, plot.x + plot.width);
+        let (top, bottom) = (plot.y, plot.y + plot.height);
+
+        let (x_range, y_range) = match self {
+            Self::VerticalLine { time, .. } => {
+                let x = x(*time).floor();
+                ((x, x + 1.0), (top, bottom))
+            }
+            Self::HorizontalLine { value, .. } => {
+                let y = y(*value).floor();
+                ((left, right), (y, y + 1.0))
+            }
+            Self::TimeRegion { start, end, .. } => ((x(*start), x(*end)), (top, bottom)),
+            Self::ValueRegion { low, high, .. } => ((left, right), (y(*high), y(*low))),
+            Self::Point { time, value, .. } => {
+                let point = Point::new(x(*time), y(*value));
+                return plot
+                    .contains(point)
+                    .then(|| Rectangle::new(point, Size::ZERO));
+            }
+        };
+
+        let (x0, x1) = (x_range.0.max(left), x_range.1.min(right));
+        let (y0, y1) = (y_range.0.max(top), y_range.1.min(bottom));
+
+        (x0 < x1 && y0 < y1)
+            .then(|| Rectangle::new(Point::new(x0, y0), Size::new(x1 - x0, y1 - y0)))
+    }
+}
+
+/// Returns the index of the annotation hovered at the given position, with
+/// the bounds of the annotations laid out by [`Annotation::layout`].
+///
+/// The nearest line or point within a few pixels wins over the regions,
+/// which are drawn below them. Of the regions containing the position, the
+/// smallest one wins.
+#[must_use]
+pub fn hovered(
+    annotations: &[Annotation],
+    bounds: &[Option<Rectangle>],
+    position: Point,
+) -> Option<usize> {
+    let laid_out = || {
+        annotations
+            .iter()
+            .zip(bounds)
+            .enumerate()
+            .filter_map(|(index, (annotation, bounds))| Some((index, annotation, (*bounds)?)))
+    };
+
+    let nearest = laid_out()
+        .filter(|(_, annotation, _)| !annotation.is_region())
+        .map(|(index, _, bounds)| (index, distance(bounds, position)))
+        .filter(|(_, distance)| *distance <= HOVER_DISTANCE)
+        .min_by(|(_, a), (_, b)| a.total_cmp(b));
+
+    nearest.map(|(index, _)| index).or_else(|| {
+        laid_out()
+            .filter(|(_, annotation, bounds)| annotation.is_region() && bounds.contains(position))
+            .min_by(|(_, _, a), (_, _, b)| (a.width * a.height).total_cmp(&(b.width * b.height)))
+            .map(|(index, _, _)| index)
+    })
+}
+
+/// Returns the distance between the given bounds and the given position.
+fn distance(bounds: Rectangle, position: Point) -> f32 {
+    let dx = (bounds.x - position.x)
+        .max(position.x - bounds.x - bounds.width)
+        .max(0.0);
+    let dy = (bounds.y - position.y)
+        .max(position.y - bounds.y - bounds.height)
+        .max(0.0);
+
+    dx.hypot(dy)
+}
+
+/// The colors and the text size of the annotations drawn by [`draw`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Style {
+    /// The color of the lines, the points and the labels.
+    pub(crate) color: Color,
+    /// The color of the regions.
+    pub(crate) region_color: Color,
+    /// The background of the label of the hovered annotation.
+    pub(crate) label_background: Background,
+    /// The color of the label of the hovered annotation.
+    pub(crate) label_text_color: Color,
+    /// The text size of the labels.
+    pub(crate) text_size: f32,
+}
+
+/// Draws the given annotations with their bounds laid out by
+/// [`Annotation::layout`], emphasizing the hovered one.
+///
+/// The annotations are clipped to the plot, so they are drawn in their own
+/// layer above the series.
+pub(crate) fn draw<Renderer>(
+    renderer: &mut Renderer,
+    annotations: &[Annotation],
+    bounds: &[Option<Rectangle>],
+    hovered: Option<usize>,
+    plot: Rectangle,
+    style: Style,
+) where
+    Renderer: text::Renderer,
+{
+    let line_height = style.text_size * 1.3;
+
+    renderer.with_layer(plot, |renderer| {
+        // The regions are drawn first, below the lines and the points.
+        let mut order: Vec<usize> = (0..annotations.len()).collect();
+        order.sort_by_key(|index| !annotations[*index].is_region());
+
+        for index in order {
+            let (annotation, Some(bounds)) = (&annotations[index], bounds[index]) else {
+                continue;
+            };
+            let is_hovered = hovered == Some(index);
+
+            let shape = match annotation {
+                Annotation::VerticalLine { .. } if is_hovered => Rectangle {
+                    x: bounds.x - 0.5,
+                    width: 2.0,
+                    ..bounds
+                },
+                Annotation::HorizontalLine { .. } if is_hovered => Rectangle {
+                    y: bounds.y - 0.5,
+                    height: 2.0,
+                    ..bounds
+                },
+                Annotation::Point { .. } => {
+                    let radius = if is_hovered {
+                        DOT_RADIUS + 1.0
+                    } else {
+                        DOT_RADIUS
+                    };
+                    Rectangle::new(
+                        Point::new(bounds.x - radius, bounds.y - radius),
+                        Size::new(radius * 2.0, radius * 2.0),
+                    )
+                }
+                _ => bounds,
+            };
+            let (color, border_radius) = match annotation {
+                Annotation::TimeRegion { .. } | Annotation::ValueRegion { .. } => {
+                    let alpha = if is_hovered { 2.0 } else { 1.0 };
+                    (
+                        Color {
+                            a: (style.region_color.a * alpha).min(1.0),
+                            ..style.region_color
+                        },
+                        0.0,
+                    )
+                }
+                Annotation::Point { .. } => (style.color, shape.width / 2.0),
+                _ => (style.color, 0.0),
+            };
+
+            renderer.fill_quad(
+                Quad {
+                    bounds: shape,
+                    border_radius: border_radius.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                color,
+            );
+
+            let label = annotation.label();
+            if label.is_empty() {
+                continue;
+            }
+
+            let width = renderer.measure_width(
+                label,
+                style.text_size,
+                renderer.default_font(),
+                text::Shaping::Advanced,
+            ) + PADDING * 2.0;
+            let position = match annotation {
+                Annotation::VerticalLine { .. } => {
+                    Point::new(bounds.x + PADDING, bounds.y + PADDING)
+                }
+                Annotation::HorizontalLine { .. } => {
+                    Point::new(bounds.x + PADDING, bounds.y - line_height - PADDING / 2.0)
+                }
+                Annotation::TimeRegion { .. } | Annotation::ValueRegion { .. } => {
+                    Point::new(bounds.x + PADDING / 2.0, bounds.y + PADDING / 2.0)
+                }
+                Annotation::Point { .. } => Point::new(
+                    bounds.x + DOT_RADIUS + PADDING,
+                    bounds.y - line_height / 2.0,
+                ),
+            };
+            let label_bounds = Rectangle::new(position, Size::new(width, line_height));
+
+            let text_color = if is_hovered {
+                renderer.fill_quad(
+                    Quad {
+                        bounds: label_bounds,
+                        border_radius: (2.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    style.label_background,
+                );
+                style.label_text_color
+            } else {
+                style.color
+            };
+
+            renderer.fill_text(text::Text {
+                content: label,
+                bounds: Rectangle {
+                    x: label_bounds.x + PADDING,
+                    y: label_bounds.center_y(),
+                    ..label_bounds
+                },
+                size: style.text_size,
+                line_height: LineHeight::default(),
+                color: text_color,
+                font: renderer.default_font(),
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Advanced,
+            });
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hovered, Annotation};
+    use iced_widget::core::{Point, Rectangle, Size};
+
+    fn plot() -> Rectangle {
+        Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0))
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn layout(annotation: &Annotation) -> Option<Rectangle> {
+        // One unit of time or value per pixel, with the values upwards.
+        annotation.layout(plot(), |time| time as f32, |value| 100.0 - value)
+    }
+
+    #[test]
+    fn layout_test() {
+        assert_eq!(
+            layout(&Annotation::vertical_line(20.5, "")),
+            Some(Rectangle::new(Point::new(20.0, 0.0), Size::new(1.0, 100.0)))
+        );
+        assert_eq!(
+            layout(&Annotation::horizontal_line(30.0, "")),
+            Some(Rectangle::new(Point::new(0.0, 70.0), Size::new(100.0, 1.0)))
+        );
+        // The regions are clipped to the plot and normalized.
+        assert_eq!(
+            layout(&Annotation::time_region(90.0, 150.0, "")),
+            Some(Rectangle::new(
+                Point::new(90.0, 0.0),
+                Size::new(10.0, 100.0)
+            ))
+        );
+        assert_eq!(
+            layout(&Annotation::value_region(60.0, 40.0, "")),
+            Some(Rectangle::new(
+                Point::new(0.0, 40.0),
+                Size::new(100.0, 20.0)
+            ))
+        );
+        assert_eq!(
+            layout(&Annotation::point(10.0, 20.0, "")),
+            Some(Rectangle::new(Point::new(10.0, 80.0), Size::ZERO))
+        );
+
+        assert_eq!(layout(&Annotation::vertical_line(-5.0, "")), None);
+        assert_eq!(layout(&Annotation::time_region(120.0, 150.0, "")), None);
+        assert_eq!(layout(&Annotation::point(10.0, 200.0, "")), None);
+    }
+
+    #[test]
+    fn hovered_test() {
+        let annotations = [
+            Annotation::time_region(0.0, 60.0, "Wide"),
+            Annotation::time_region(20.0, 40.0, "Narrow"),
+            Annotation::vertical_line(30.0, "Line"),
+            Annotation::point(80.0, 50.0, "Point"),
+            Annotation::vertical_line(500.0, "Outside"),
+        ];
+        let bounds: Vec<_> = annotations.iter().map(layout).collect();
+        let hovered = |x, y| hovered(&annotations, &bounds, Point::new(x, y));
+
+        // The lines and the points win over the regions below them.
+        assert_eq!(hovered(34.0, 50.0), Some(2));
+        assert_eq!(hovered(82.0, 52.0), Some(3));
+        // The smallest containing region wins.
+        assert_eq!(hovered(22.0, 50.0), Some(1));
+        assert_eq!(hovered(10.0, 50.0), Some(0));
+        assert_eq!(hovered(70.0, 20.0), None);
+        assert_eq!(annotations[1].label(), "Narrow");
+    }
+}
@@ -0,0 +1,54 @@
+//! Lay out the labeled values of the axes of charts.
+//!
+//! *This API requires the following crate features to be activated: `live_chart`, `candlestick` or `radar`*
+
+/// Returns evenly spaced values at round steps of 1, 2 or 5 times a power of
+/// ten, covering the range from `min` to `max` with at most about `count`
+/// steps.
+///
+/// The first and the last value enclose the range, so they can be used as
+/// the bounds of an axis.
+#[must_use]
+pub fn nice_ticks(min: f32, max: f32, count: usize) -> Vec<f32> {
+    if !min.is_finite() || !max.is_finite() {
+        return Vec::new();
+    }
+    let (min, max) = (f64::from(min.min(max)), f64::from(max.max(min)));
+    // An empty range is widened around its value.
+    let (min, max) = if max - min > f64::EPSILON {
+        (min, max)
+    } else {
+        let margin = (min.abs() / 2.0).max(1.0);
+        (min - margin, max + margin)
+    };
+
+    #[allow(clippy::cast_precision_loss)]
+    let rough = (max - min) / count.max(1) as f64;
+    let magnitude = 10_f64.powf(rough.log10().floor());
+    let step = [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|factor| factor * magnitude)
+        .find(|&step| step >= rough)
+        .unwrap_or(10.0 * magnitude);
+
+    let first = (min / step).floor();
+    let last = (max / step).ceil();
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    (0..=(last - first) as u32)
+        .map(|i| ((first + f64::from(i)) * step) as f32)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::nice_ticks;
+
+    #[test]
+    fn nice_ticks_test() {
+        assert_eq!(nice_ticks(0.3, 9.2, 5), vec![0.0, 2.0, 4.0, 6.0, 8.0, 10.0]);
+        assert_eq!(nice_ticks(-0.12, 0.12, 4), vec![-0.2, -0.1, 0.0, 0.1, 0.2]);
+        assert_eq!(nice_ticks(5.0, 5.0, 2), vec![0.0, 5.0, 10.0]);
+        assert!(nice_ticks(f32::NAN, 1.0, 5).is_empty());
+    }
+}
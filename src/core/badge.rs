@@ -0,0 +1,110 @@
+//! Helpers for the counts shown in badges.
+//!
+//! *This API requires the following crate features to be activated: `badge` or `tab_bar`*
+use super::i18n;
+
+use std::time::{Duration, Instant};
+
+/// The default maximum count shown before it overflows, e.g. `99+`.
+pub const DEFAULT_MAX_COUNT: usize = 99;
+
+/// The duration of the animation played when a count changes.
+pub(crate) const PULSE_DURATION: Duration = Duration::from_millis(400);
+
+/// Returns the text showing the given count, or the maximum count followed by
+/// a `+` if it overflows.
+#[must_use]
+pub fn count_text(count: usize, max: usize) -> String {
+    if count > max {
+        format!("{max}+")
+    } else {
+        count.to_string()
+    }
+}
+
+/// Returns the text describing the given count to assistive technologies,
+/// e.g. `"3 unread messages"` for the nouns `"unread message"` and
+/// `"unread messages"`.
+///
+/// Unlike [`count_text`], the exact count is always spelled out, in the
+/// language of the installed [`i18n::Translations`].
+#[must_use]
+pub fn accessible_text(count: usize, singular: &str, plural: &str) -> String {
+    i18n::get().count(count, singular, plural)
+}
+
+/// Tracks the changes of a count to animate and announce them.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct CountTracker {
+    /// The last seen count.
+    count: Option<usize>,
+    /// The instant at which the count last changed.
+    changed_at: Option<Instant>,
+}
+
+impl CountTracker {
+    /// Records the count seen at the given instant, returning `true` if it
+    /// changed since the last one.
+    ///
+    /// The first count seen is not a change.
+    pub(crate) fn update(&mut self, count: usize, now: Instant) -> bool {
+        let changed = self.count.is_some_and(|previous| previous != count);
+
+        if changed {
+            self.changed_at = Some(now);
+        }
+        self.count = Some(count);
+
+        changed
+    }
+
+    /// Returns the progress of the change animation between 0 and 1 at the
+    /// given instant, if it is playing.
+    pub(crate) fn pulse(&self, now: Instant) -> Option<f32> {
+        let elapsed = now.checked_duration_since(self.changed_at?)?;
+
+        (elapsed < PULSE_DURATION).then(|| elapsed.as_secs_f32() / PULSE_DURATION.as_secs_f32())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{accessible_text, count_text, CountTracker, PULSE_DURATION};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn count_text_test() {
+        assert_eq!(count_text(0, 99), "0");
+        assert_eq!(count_text(99, 99), "99");
+        assert_eq!(count_text(100, 99), "99+");
+        assert_eq!(count_text(12, 9), "9+");
+    }
+
+    #[test]
+    fn accessible_text_test() {
+        let text = |count| accessible_text(count, "unread message", "unread messages");
+
+        assert_eq!(text(0), "No unread messages");
+        assert_eq!(text(1), "1 unread message");
+        assert_eq!(text(3), "3 unread messages");
+        assert_eq!(text(1000), "1000 unread messages");
+    }
+
+    #[test]
+    fn tracker_test() {
+        let start = Instant::now();
+        let mut tracker = CountTracker::default();
+
+        assert!(!tracker.update(3, start));
+        assert!(!tracker.update(3, start));
+        assert_eq!(tracker.pulse(start), None);
+
+        assert!(tracker.update(4, start));
+        assert_eq!(tracker.pulse(start), Some(0.0));
+        assert!(tracker
+            .pulse(start + PULSE_DURATION / 2)
+            .is_some_and(|progress| (progress - 0.5).abs() < 1e-3));
+        assert_eq!(tracker.pulse(start + PULSE_DURATION), None);
+        assert_eq!(tracker.pulse(start + Duration::from_secs(10)), None);
+    }
+}
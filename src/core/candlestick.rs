@@ -0,0 +1,146 @@
+//! Describe the candles of a candlestick chart and compute their overlays.
+//!
+//! *This API requires the following crate features to be activated: `candlestick`*
+
+/// The prices of a period of a
+/// [`CandlestickChart`](crate::native::candlestick::CandlestickChart).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Candle {
+    /// The time the period starts at, in the units of the chart.
+    pub time: f64,
+    /// The first price of the period.
+    pub open: f32,
+    /// The highest price of the period.
+    pub high: f32,
+    /// The lowest price of the period.
+    pub low: f32,
+    /// The last price of the period.
+    pub close: f32,
+}
+
+impl Candle {
+    /// Creates a new [`Candle`] of the period starting at the given time.
+    #[must_use]
+    pub fn new(time: f64, open: f32, high: f32, low: f32, close: f32) -> Self {
+        Self {
+            time,
+            open,
+            high: high.max(open).max(close),
+            low: low.min(open).min(close),
+            close,
+        }
+    }
+
+    /// Returns whether the price rose during the period.
+    #[must_use]
+    pub fn is_bullish(&self) -> bool {
+        self.close >= self.open
+    }
+}
+
+/// Returns the lowest and the highest price of the given candles, or `None`
+/// if there are none.
+#[must_use]
+pub fn price_range(candles: &[Candle]) -> Option<(f32, f32)> {
+    candles.iter().fold(None, |range, candle| {
+        let (low, high) = range.unwrap_or((candle.low, candle.high));
+
+        Some((low.min(candle.low), high.max(candle.high)))
+    })
+}
+
+/// Returns the fractional index of the given time among the given candles,
+/// ordered by time, or `None` if there are none.
+///
+/// The times between two candles are interpolated, and the times outside of
+/// the candles are extrapolated from the nearest two.
+#[must_use]
+pub fn index_at(candles: &[Candle], time: f64) -> Option<f32> {
+    if candles.len() < 2 {
+        return candles.first().map(|_| 0.0);
+    }
+
+    let next = candles.partition_point(|candle| candle.time <= time);
+    let first = next.clamp(1, candles.len() - 1) - 1;
+    let (start, end) = (candles[first].time, candles[first + 1].time);
+    let fraction = if end > start {
+        (time - start) / (end - start)
+    } else {
+        0.0
+    };
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    Some((first as f64 + fraction) as f32)
+}
+
+/// Returns the simple moving average of the closing prices over the given
+/// number of candles, with one value per candle.
+///
+/// The candles before the first full period have no value.
+#[must_use]
+pub fn moving_average(candles: &[Candle], period: usize) -> Vec<Option<f32>> {
+    let period = period.max(1);
+    let mut sum = 0.0;
+
+    candles
+        .iter()
+        .enumerate()
+        .map(|(i, candle)| {
+            sum += f64::from(candle.close);
+            if i >= period {
+                sum -= f64::from(candles[i - period].close);
+            }
+
+            #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+            (i + 1 >= period).then(|| (sum / period as f64) as f32)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{index_at, moving_average, price_range, Candle};
+
+    fn candles() -> Vec<Candle> {
+        [10.0, 12.0, 11.0, 15.0, 14.0]
+            .into_iter()
+            .zip(0_u8..)
+            .map(|(close, i)| {
+                Candle::new(f64::from(i), close - 1.0, close + 2.0, close - 3.0, close)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn candle_test() {
+        // The high and the low enclose the open and the close.
+        let candle = Candle::new(0.0, 10.0, 9.0, 11.0, 8.0);
+        assert_eq!((candle.low, candle.high), (8.0, 10.0));
+        assert!(!candle.is_bullish());
+
+        assert_eq!(price_range(&candles()), Some((7.0, 17.0)));
+        assert_eq!(price_range(&[]), None);
+    }
+
+    #[test]
+    fn index_at_test() {
+        let candles = candles();
+
+        assert_eq!(index_at(&candles, 2.0), Some(2.0));
+        assert_eq!(index_at(&candles, 2.5), Some(2.5));
+        assert_eq!(index_at(&candles, -1.0), Some(-1.0));
+        assert_eq!(index_at(&candles, 6.0), Some(6.0));
+        assert_eq!(index_at(&candles[..1], 6.0), Some(0.0));
+        assert_eq!(index_at(&[], 6.0), None);
+    }
+
+    #[test]
+    fn moving_average_test() {
+        assert_eq!(
+            moving_average(&candles(), 3),
+            vec![None, None, Some(11.0), Some(38.0 / 3.0), Some(40.0 / 3.0)]
+        );
+        assert_eq!(moving_average(&candles(), 1)[3], Some(15.0));
+        assert!(moving_average(&candles(), 9).iter().all(Option::is_none));
+    }
+}
@@ -0,0 +1,111 @@
+//! The editors of the cells of a grid.
+//!
+//! *This API requires the following crate features to be activated: `editable_grid`*
+
+/// The editor of the cells of a column of an
+/// [`EditableGrid`](crate::native::editable_grid::EditableGrid).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CellEditor {
+    /// Edits any text.
+    #[default]
+    Text,
+    /// Edits a number.
+    Number,
+    /// Edits a date in the `YYYY-MM-DD` format.
+    Date,
+    /// Chooses one of the given options from a dropdown list.
+    Choice(Vec<String>),
+}
+
+impl CellEditor {
+    /// Returns `true` if the given character can be typed into the editor.
+    #[must_use]
+    pub fn accepts_char(&self, c: char) -> bool {
+        match self {
+            Self::Text => !c.is_control(),
+            Self::Number => c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E'),
+            Self::Date => c.is_ascii_digit() || c == '-',
+            Self::Choice(_) => false,
+        }
+    }
+
+    /// Returns `true` if the given content can be committed to a cell.
+    ///
+    /// Empty content is always accepted, for clearing cells.
+    #[must_use]
+    pub fn accepts(&self, value: &str) -> bool {
+        if value.is_empty() {
+            return true;
+        }
+
+        match self {
+            Self::Text => true,
+            Self::Number => value.trim().parse::<f64>().is_ok(),
+            Self::Date => is_date(value),
+            Self::Choice(options) => options.iter().any(|option| option == value),
+        }
+    }
+}
+
+/// Returns `true` if the given text is a valid date in the `YYYY-MM-DD` format.
+fn is_date(text: &str) -> bool {
+    let mut parts = text.split('-');
+
+    let (Some(year), Some(month), Some(day), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return false;
+    };
+
+    if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+        return false;
+    }
+
+    let (Ok(year), Ok(month), Ok(day)) = (
+        year.parse::<u32>(),
+        month.parse::<u32>(),
+        day.parse::<u32>(),
+    ) else {
+        return false;
+    };
+
+    let is_leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year => 29,
+        2 => 28,
+        _ => return false,
+    };
+
+    (1..=days).contains(&day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CellEditor;
+
+    #[test]
+    fn accepts_test() {
+        assert!(CellEditor::Number.accepts("-1.5e3"));
+        assert!(!CellEditor::Number.accepts("12a"));
+        assert!(CellEditor::Number.accepts(""));
+
+        let choice = CellEditor::Choice(vec![String::from("Yes"), String::from("No")]);
+        assert!(choice.accepts("No"));
+        assert!(!choice.accepts("Maybe"));
+
+        assert!(CellEditor::Number.accepts_char('7'));
+        assert!(!CellEditor::Number.accepts_char('x'));
+        assert!(!CellEditor::Text.accepts_char('\t'));
+    }
+
+    #[test]
+    fn date_test() {
+        assert!(CellEditor::Date.accepts("2024-02-29"));
+        assert!(!CellEditor::Date.accepts("2023-02-29"));
+        assert!(!CellEditor::Date.accepts("2000-13-01"));
+        assert!(!CellEditor::Date.accepts("2000-1-01"));
+        assert!(!CellEditor::Date.accepts("2000-01-01-01"));
+    }
+}
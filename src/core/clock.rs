@@ -1,7 +1,7 @@
 //! Helper functions for calculating the clock
 
 use iced_widget::core::Point;
-use std::fmt::Display;
+use std::{fmt::Display, ops::RangeInclusive};
 
 /// The size of the period on the clock based on the clock's size.
 pub const PERIOD_PERCENTAGE: f32 = 0.1;
@@ -121,11 +121,79 @@ pub fn nearest_radius(
     distance_vec[0].1.clone()
 }
 
+/// Returns the angle in radians of the given time on the hour ring of the
+/// clock, measured clockwise from the right, with noon and midnight at the
+/// top.
+#[must_use]
+pub fn time_angle(hour: u32, minute: u32) -> f32 {
+    #[allow(clippy::cast_precision_loss)]
+    let minutes = ((hour % 12) * 60 + minute % 60) as f32;
+
+    -std::f32::consts::FRAC_PI_2 + std::f32::consts::TAU * minutes / 720.0
+}
+
+/// Rounds the given minute to the nearest multiple of the step, wrapping the
+/// end of the hour around to its start.
+#[must_use]
+pub fn snap_minute(minute: u32, step: u32) -> u32 {
+    let step = step.clamp(1, 60);
+
+    ((minute + step / 2) / step * step) % 60
+}
+
+/// Returns the minutes to go back and forth from the given minute to the
+/// previous and the next multiple of the step, which starts the hour again
+/// after the last multiple in it.
+#[must_use]
+pub fn minute_steps(minute: u32, step: u32) -> (u32, u32) {
+    let step = step.clamp(1, 60);
+    let last = 59 / step * step;
+
+    let back = if minute == 0 {
+        60 - last
+    } else {
+        minute - (minute - 1) / step * step
+    };
+    let next = (minute / step + 1) * step;
+    let forth = next.min(60) - minute;
+
+    (back, forth)
+}
+
+/// Returns the value of a segment of a digital clock after typing the given
+/// digit, if it is in the range of the segment, and the value typed so far if
+/// another digit may follow.
+///
+/// A second digit completes the `typed` value, or starts it again if the two
+/// digits are out of the range.
+#[must_use]
+pub fn type_digit(
+    typed: Option<u32>,
+    digit: u32,
+    range: RangeInclusive<u32>,
+) -> (Option<u32>, Option<u32>) {
+    let value = typed.map_or(digit, |typed| typed * 10 + digit);
+
+    if typed.is_some() && !range.contains(&value) {
+        return type_digit(None, digit, range);
+    }
+
+    let more = typed.is_none() && value * 10 <= *range.end();
+
+    (
+        range.contains(&value).then_some(value),
+        more.then_some(value),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use iced_widget::core::{Point, Vector};
 
-    use super::{circle_points, nearest_point, nearest_radius, NearestRadius};
+    use super::{
+        circle_points, minute_steps, nearest_point, nearest_radius, snap_minute, time_angle,
+        type_digit, NearestRadius,
+    };
 
     #[test]
     fn circle_points_test() {
@@ -215,4 +283,54 @@ mod tests {
         result = nearest_point(&points, cursor_position);
         assert_eq!(index, result);
     }
+
+    #[test]
+    fn snap_minute_test() {
+        assert_eq!(snap_minute(7, 1), 7);
+        assert_eq!(snap_minute(7, 5), 5);
+        assert_eq!(snap_minute(8, 5), 10);
+        assert_eq!(snap_minute(53, 15), 0);
+        assert_eq!(snap_minute(59, 7), 56);
+        assert_eq!(snap_minute(30, 0), 30);
+    }
+
+    #[test]
+    fn minute_steps_test() {
+        assert_eq!(minute_steps(7, 1), (1, 1));
+        assert_eq!(minute_steps(15, 15), (15, 15));
+        assert_eq!(minute_steps(20, 15), (5, 10));
+        assert_eq!(minute_steps(45, 15), (15, 15));
+        assert_eq!(minute_steps(0, 15), (15, 15));
+        // The last multiple of 25 in the hour is 50.
+        assert_eq!(minute_steps(50, 25), (25, 10));
+        assert_eq!(minute_steps(0, 25), (10, 25));
+    }
+
+    #[test]
+    fn time_angle_test() {
+        use std::f32::consts::{FRAC_PI_2, PI};
+
+        assert!((time_angle(0, 0) + FRAC_PI_2).abs() < f32::EPSILON);
+        assert!((time_angle(15, 0)).abs() < f32::EPSILON);
+        assert!((time_angle(6, 0) - FRAC_PI_2).abs() < 1e-6);
+        assert!((time_angle(21, 0) - PI).abs() < 1e-6);
+    }
+
+    #[test]
+    fn type_digit_test() {
+        // A 2 may start a 24h hour, a 5 may not.
+        assert_eq!(type_digit(None, 2, 0..=23), (Some(2), Some(2)));
+        assert_eq!(type_digit(Some(2), 3, 0..=23), (Some(23), None));
+        assert_eq!(type_digit(None, 5, 0..=23), (Some(5), None));
+
+        // A 12h hour is not 0 until another digit follows.
+        assert_eq!(type_digit(None, 0, 1..=12), (None, Some(0)));
+        assert_eq!(type_digit(Some(0), 9, 1..=12), (Some(9), None));
+        assert_eq!(type_digit(Some(0), 0, 1..=12), (None, Some(0)));
+
+        // Digits out of range start the value again.
+        assert_eq!(type_digit(Some(1), 7, 1..=12), (Some(7), None));
+        assert_eq!(type_digit(Some(5), 9, 0..=59), (Some(59), None));
+        assert_eq!(type_digit(None, 6, 0..=59), (Some(6), None));
+    }
 }
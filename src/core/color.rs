@@ -43,6 +43,244 @@ impl HexString for Color {
     }
 }
 
+/// Parses a color from a string of hexadecimal characters in the form of
+/// `#RRGGBB` or `#RRGGBBAA`, where the `#` is optional.
+#[must_use]
+pub fn parse_hex(text: &str) -> Option<Color> {
+    let text = text.trim();
+    let digits = text.strip_prefix('#').unwrap_or(text);
+
+    if !matches!(digits.len(), 6 | 8) || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let channel = |index: usize| u8::from_str_radix(&digits[2 * index..2 * index + 2], 16).ok();
+    let alpha = if digits.len() == 8 {
+        channel(3)?
+    } else {
+        u8::MAX
+    };
+
+    Some(Color::from_rgba8(
+        channel(0)?,
+        channel(1)?,
+        channel(2)?,
+        f32::from(alpha) / 255.0,
+    ))
+}
+
+/// A color space the bars of a color picker pick the channels of a color in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ColorSpace {
+    /// The red, green and blue channels.
+    #[default]
+    Rgb,
+    /// The hue, saturation and value.
+    Hsv,
+    /// The hue, saturation and lightness.
+    Hsl,
+    /// The perceived lightness, the chroma and the hue of the Oklab color
+    /// space, changing the lightness evenly for all hues.
+    Oklch,
+}
+
+/// The largest chroma of an [`Oklch`](ColorSpace::Oklch) color, which is its
+/// chroma of 100%.
+const MAX_CHROMA: f32 = 0.4;
+
+/// The matrix of linear sRGB to the cone responses of Oklab.
+const RGB_TO_LMS: [[f32; 3]; 3] = [
+    [0.412_221_47, 0.536_332_55, 0.051_445_995],
+    [0.211_903_5, 0.680_699_5, 0.107_396_96],
+    [0.088_302_46, 0.281_718_85, 0.629_978_7],
+];
+/// The matrix of the cube roots of the cone responses to Oklab.
+const LMS_TO_LAB: [[f32; 3]; 3] = [
+    [0.210_454_26, 0.793_617_8, -0.004_072_047],
+    [1.977_998_5, -2.428_592_2, 0.450_593_7],
+    [0.025_904_037, 0.782_771_77, -0.808_675_77],
+];
+/// The matrix of Oklab to the cube roots of the cone responses.
+const LAB_TO_LMS: [[f32; 3]; 3] = [
+    [1.0, 0.396_337_78, 0.215_803_76],
+    [1.0, -0.105_561_35, -0.063_854_17],
+    [1.0, -0.089_484_18, -1.291_485_5],
+];
+/// The matrix of the cone responses of Oklab to linear sRGB.
+const LMS_TO_RGB: [[f32; 3]; 3] = [
+    [4.076_741_7, -3.307_711_6, 0.230_969_94],
+    [-1.268_438, 2.609_757_4, -0.341_319_38],
+    [-0.004_196_086_3, -0.703_418_6, 1.707_614_7],
+];
+
+impl ColorSpace {
+    /// All color spaces, in the order of the switcher of the color picker.
+    pub const ALL: [Self; 4] = [Self::Rgb, Self::Hsv, Self::Hsl, Self::Oklch];
+
+    /// Returns the name of the [`ColorSpace`].
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Rgb => "RGB",
+            Self::Hsv => "HSV",
+            Self::Hsl => "HSL",
+            Self::Oklch => "OKLCH",
+        }
+    }
+
+    /// Returns the labels of the three channels of the [`ColorSpace`].
+    #[must_use]
+    pub const fn labels(self) -> [&'static str; 3] {
+        match self {
+            Self::Rgb => ["R:", "G:", "B:"],
+            Self::Hsv => ["H:", "S:", "V:"],
+            Self::Hsl => ["H:", "S:", "L:"],
+            Self::Oklch => ["L:", "C:", "H:"],
+        }
+    }
+
+    /// Returns the largest values of the three channels of the
+    /// [`ColorSpace`] as they are typed: bytes, degrees of the hue and
+    /// percentages.
+    #[must_use]
+    pub const fn maxima(self) -> [u16; 3] {
+        match self {
+            Self::Rgb => [255, 255, 255],
+            Self::Hsv | Self::Hsl => [360, 100, 100],
+            Self::Oklch => [100, 100, 360],
+        }
+    }
+
+    /// Returns the three channels of the color in the [`ColorSpace`], each
+    /// between 0 and 1.
+    #[must_use]
+    pub fn channels(self, color: Color) -> [f32; 3] {
+        let max = color.r.max(color.g.max(color.b));
+        let min = color.r.min(color.g.min(color.b));
+        let chroma = max - min;
+
+        match self {
+            Self::Rgb => [color.r, color.g, color.b],
+            Self::Hsv => [
+                hue(color, max, chroma),
+                if max == 0.0 { 0.0 } else { chroma / max },
+                max,
+            ],
+            Self::Hsl => {
+                let lightness = f32::midpoint(max, min);
+                let saturation = if chroma == 0.0 {
+                    0.0
+                } else {
+                    chroma / (1.0 - 2.0f32.mul_add(lightness, -1.0).abs())
+                };
+
+                [hue(color, max, chroma), saturation.min(1.0), lightness]
+            }
+            Self::Oklch => {
+                let rgb = [color.r, color.g, color.b].map(to_linear);
+                let [lightness, a, b] =
+                    multiply(LMS_TO_LAB, multiply(RGB_TO_LMS, rgb).map(f32::cbrt));
+                let hue = b.atan2(a).to_degrees().rem_euclid(360.0) / 360.0;
+
+                [
+                    lightness.clamp(0.0, 1.0),
+                    (a.hypot(b) / MAX_CHROMA).min(1.0),
+                    if a.hypot(b) < 1e-4 { 0.0 } else { hue },
+                ]
+            }
+        }
+    }
+
+    /// Returns the opaque color of the three channels in the [`ColorSpace`],
+    /// each between 0 and 1.
+    ///
+    /// The channels of colors outside of sRGB are clipped.
+    #[must_use]
+    pub fn color(self, channels: [f32; 3]) -> Color {
+        let [first, second, third] = channels.map(|channel| channel.clamp(0.0, 1.0));
+
+        match self {
+            Self::Rgb => Color::from_rgb(first, second, third),
+            Self::Hsv => {
+                let chroma = third * second;
+                from_hue(first, chroma, third - chroma)
+            }
+            Self::Hsl => {
+                let chroma = (1.0 - 2.0f32.mul_add(third, -1.0).abs()) * second;
+                from_hue(first, chroma, third - chroma / 2.0)
+            }
+            Self::Oklch => {
+                let chroma = second * MAX_CHROMA;
+                let (sin, cos) = (third * 360.0).to_radians().sin_cos();
+                let lms = multiply(LAB_TO_LMS, [first, chroma * cos, chroma * sin]);
+                let [r, g, b] = multiply(LMS_TO_RGB, lms.map(|value| value.powi(3)))
+                    .map(|value| from_linear(value).clamp(0.0, 1.0));
+
+                Color::from_rgb(r, g, b)
+            }
+        }
+    }
+}
+
+/// Returns the hue of the color between 0 and 1, given its largest channel
+/// and its chroma.
+fn hue(color: Color, max: f32, chroma: f32) -> f32 {
+    if chroma == 0.0 {
+        return 0.0;
+    }
+
+    let sector = if max <= color.r {
+        ((color.g - color.b) / chroma).rem_euclid(6.0)
+    } else if max <= color.g {
+        (color.b - color.r) / chroma + 2.0
+    } else {
+        (color.r - color.g) / chroma + 4.0
+    };
+
+    sector / 6.0
+}
+
+/// Returns the opaque color of the given hue between 0 and 1 with the given
+/// chroma, lifted by the given amount.
+fn from_hue(hue: f32, chroma: f32, lift: f32) -> Color {
+    let sector = (hue * 6.0).rem_euclid(6.0);
+    let second = chroma * (1.0 - (sector.rem_euclid(2.0) - 1.0).abs());
+
+    let (r, g, b) = match sector as u8 {
+        0 => (chroma, second, 0.0),
+        1 => (second, chroma, 0.0),
+        2 => (0.0, chroma, second),
+        3 => (0.0, second, chroma),
+        4 => (second, 0.0, chroma),
+        _ => (chroma, 0.0, second),
+    };
+
+    Color::from_rgb(r + lift, g + lift, b + lift)
+}
+
+/// Multiplies the matrix with the vector.
+fn multiply(matrix: [[f32; 3]; 3], vector: [f32; 3]) -> [f32; 3] {
+    matrix.map(|row| row[0].mul_add(vector[0], row[1].mul_add(vector[1], row[2] * vector[2])))
+}
+
+/// Returns the linear value of an sRGB channel.
+fn to_linear(value: f32) -> f32 {
+    if value <= 0.040_45 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Returns the sRGB channel of a linear value.
+fn from_linear(value: f32) -> f32 {
+    if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055f32.mul_add(value.powf(1.0 / 2.4), -0.055)
+    }
+}
+
 impl From<Color> for Hsv {
     // https://de.wikipedia.org/wiki/HSV-Farbraum#Umrechnung_RGB_in_HSV/HSL
     fn from(color: Color) -> Self {
@@ -101,7 +339,77 @@ impl From<Hsv> for Color {
 mod tests {
     use iced_widget::core::Color;
 
-    use super::Hsv;
+    use super::{parse_hex, ColorSpace, HexString, Hsv};
+
+    /// Asserts that the channels are the expected ones, up to rounding.
+    fn assert_channels(channels: [f32; 3], expected: [f32; 3]) {
+        for (channel, expected) in channels.into_iter().zip(expected) {
+            assert!(
+                (channel - expected).abs() < 1e-3,
+                "{channels:?} != {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn color_space_test() {
+        let red = Color::from_rgb(1.0, 0.0, 0.0);
+        assert_channels(ColorSpace::Hsv.channels(red), [0.0, 1.0, 1.0]);
+        assert_channels(ColorSpace::Hsl.channels(red), [0.0, 1.0, 0.5]);
+        assert_channels(
+            ColorSpace::Oklch.channels(red),
+            [0.627_955, 0.257_683 / 0.4, 29.233_9 / 360.0],
+        );
+
+        let brown = Color::from_rgb(0.5, 0.25, 0.25);
+        assert_channels(ColorSpace::Hsl.channels(brown), [0.0, 1.0 / 3.0, 0.375]);
+        assert_channels(ColorSpace::Oklch.channels(Color::WHITE), [1.0, 0.0, 0.0]);
+
+        // The colors survive the conversions to every color space and back.
+        for color in [
+            red,
+            brown,
+            Color::BLACK,
+            Color::from_rgb(0.2, 0.6, 0.4),
+            Color::from_rgb(0.9, 0.8, 0.1),
+            Color::from_rgb(0.3, 0.1, 0.7),
+        ] {
+            for color_space in ColorSpace::ALL {
+                let Color { r, g, b, .. } = color_space.color(color_space.channels(color));
+                assert_channels([r, g, b], [color.r, color.g, color.b]);
+            }
+        }
+
+        // The channels of colors outside of sRGB are clipped.
+        let Color { r, g, b, .. } = ColorSpace::Oklch.color([0.5, 1.0, 0.4]);
+        assert!([r, g, b]
+            .iter()
+            .all(|channel| (0.0..=1.0).contains(channel)));
+    }
+
+    #[test]
+    fn parse_hex_test() {
+        assert_eq!(
+            parse_hex("#FF8000"),
+            Some(Color::from_rgb8(0xFF, 0x80, 0x00))
+        );
+        assert_eq!(
+            parse_hex(" 3366cc80 "),
+            Some(Color::from_rgba8(0x33, 0x66, 0xCC, 128.0 / 255.0))
+        );
+        assert_eq!(parse_hex("#FF80"), None);
+        assert_eq!(parse_hex("#FF800G"), None);
+        assert_eq!(parse_hex("#+F8000"), None);
+
+        // Every byte survives parsing and printing.
+        for byte in 0..=u8::MAX {
+            let hex = format!("#{byte:02X}{byte:02X}{byte:02X}{byte:02X}");
+            assert_eq!(
+                parse_hex(&hex).map(|color| color.as_hex_string()),
+                Some(hex)
+            );
+        }
+    }
 
     #[allow(clippy::cognitive_complexity)]
     #[test]
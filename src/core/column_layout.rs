@@ -0,0 +1,114 @@
+//! The order and visibility of the columns of a grid.
+//!
+//! *This API requires the following crate features to be activated: `editable_grid`*
+
+/// The order and visibility of the columns of an
+/// [`EditableGrid`](crate::native::editable_grid::EditableGrid).
+///
+/// Columns are identified by their index in the data, so a layout can be
+/// stored and restored independently of the displayed order.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ColumnLayout {
+    /// The columns in display order.
+    ///
+    /// Columns missing from the order are displayed after the listed ones.
+    pub order: Vec<usize>,
+    /// The hidden columns.
+    pub hidden: Vec<usize>,
+}
+
+impl ColumnLayout {
+    /// Creates a new [`ColumnLayout`] displaying all columns in data order.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns all of the given number of columns in display order,
+    /// including the hidden ones.
+    #[must_use]
+    pub fn order(&self, columns: usize) -> Vec<usize> {
+        let mut order: Vec<usize> = Vec::with_capacity(columns);
+
+        for column in self.order.iter().copied().chain(0..columns) {
+            if column < columns && !order.contains(&column) {
+                order.push(column);
+            }
+        }
+
+        order
+    }
+
+    /// Returns the visible columns of the given number of columns in display order.
+    #[must_use]
+    pub fn visible(&self, columns: usize) -> Vec<usize> {
+        self.order(columns)
+            .into_iter()
+            .filter(|column| !self.is_hidden(*column))
+            .collect()
+    }
+
+    /// Returns `true` if the given column is hidden.
+    #[must_use]
+    pub fn is_hidden(&self, column: usize) -> bool {
+        self.hidden.contains(&column)
+    }
+
+    /// Shows the given column if it is hidden, or hides it otherwise.
+    pub fn toggle(&mut self, column: usize) {
+        if let Some(index) = self.hidden.iter().position(|hidden| *hidden == column) {
+            let _ = self.hidden.remove(index);
+        } else {
+            self.hidden.push(column);
+        }
+    }
+
+    /// Moves the given column of the given number of columns to the display
+    /// position of the `target` column.
+    pub fn move_column(&mut self, columns: usize, column: usize, target: usize) {
+        let mut order = self.order(columns);
+
+        if let (Some(from), Some(to)) = (
+            order.iter().position(|c| *c == column),
+            order.iter().position(|c| *c == target),
+        ) {
+            let column = order.remove(from);
+            order.insert(to, column);
+        }
+
+        self.order = order;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ColumnLayout;
+
+    #[test]
+    fn order_test() {
+        let layout = ColumnLayout {
+            order: vec![2, 7, 0],
+            hidden: vec![1],
+        };
+
+        assert_eq!(layout.order(4), vec![2, 0, 1, 3]);
+        assert_eq!(layout.visible(4), vec![2, 0, 3]);
+        assert_eq!(ColumnLayout::new().visible(3), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn move_column_test() {
+        let mut layout = ColumnLayout::new();
+
+        layout.move_column(4, 0, 2);
+        assert_eq!(layout.order, vec![1, 2, 0, 3]);
+
+        layout.move_column(4, 3, 1);
+        assert_eq!(layout.order, vec![3, 1, 2, 0]);
+
+        layout.toggle(2);
+        layout.toggle(3);
+        layout.toggle(2);
+        assert_eq!(layout.visible(4), vec![1, 2, 0]);
+    }
+}
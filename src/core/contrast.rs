@@ -0,0 +1,144 @@
+//! Helper functions and structs for checking the contrast between colors.
+//!
+//! The computations follow the [Web Content Accessibility Guidelines].
+//!
+//! [Web Content Accessibility Guidelines]: https://www.w3.org/TR/WCAG21/#contrast-minimum
+
+use iced_widget::core::Color;
+
+/// A level of conformance to the Web Content Accessibility Guidelines.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Level {
+    /// The minimum contrast (level AA).
+    AA,
+    /// The enhanced contrast (level AAA).
+    AAA,
+}
+
+/// The size of a text whose contrast is checked.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextSize {
+    /// A text smaller than 18 points, or 14 points if bold.
+    Normal,
+    /// A text of at least 18 points, or 14 points if bold.
+    Large,
+}
+
+impl Level {
+    /// Returns the minimum contrast ratio required by the [`Level`] for texts
+    /// of the given size.
+    #[must_use]
+    pub const fn min_ratio(self, size: TextSize) -> f32 {
+        match (self, size) {
+            (Self::AA, TextSize::Normal) | (Self::AAA, TextSize::Large) => 4.5,
+            (Self::AA, TextSize::Large) => 3.0,
+            (Self::AAA, TextSize::Normal) => 7.0,
+        }
+    }
+
+    /// Returns true if the contrast ratio conforms to the [`Level`] for texts
+    /// of the given size.
+    #[must_use]
+    pub fn passes(self, ratio: f32, size: TextSize) -> bool {
+        ratio >= self.min_ratio(size)
+    }
+}
+
+/// Returns the relative luminance of the given opaque color, from 0 for the
+/// darkest black to 1 for the lightest white.
+#[must_use]
+pub fn relative_luminance(color: Color) -> f32 {
+    let linear = |channel: f32| {
+        if channel <= 0.040_45 {
+            channel / 12.92
+        } else {
+            ((channel + 0.055) / 1.055).powf(2.4)
+        }
+    };
+
+    0.2126f32.mul_add(
+        linear(color.r),
+        0.7152f32.mul_add(linear(color.g), 0.0722 * linear(color.b)),
+    )
+}
+
+/// Returns the contrast ratio of a foreground color drawn on a background
+/// color, from 1 for no contrast to 21 for black on white.
+///
+/// The background is considered opaque and a translucent foreground is
+/// blended on it.
+#[must_use]
+pub fn contrast_ratio(foreground: Color, background: Color) -> f32 {
+    let alpha = foreground.a;
+    let foreground = Color::from_rgb(
+        foreground.r.mul_add(alpha, background.r * (1.0 - alpha)),
+        foreground.g.mul_add(alpha, background.g * (1.0 - alpha)),
+        foreground.b.mul_add(alpha, background.b * (1.0 - alpha)),
+    );
+
+    let (foreground, background) = (
+        relative_luminance(foreground),
+        relative_luminance(background),
+    );
+    let (lighter, darker) = if foreground > background {
+        (foreground, background)
+    } else {
+        (background, foreground)
+    };
+
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_widget::core::Color;
+
+    use super::{contrast_ratio, relative_luminance, Level, TextSize};
+
+    #[test]
+    fn luminance() {
+        assert!(relative_luminance(Color::BLACK).abs() < f32::EPSILON);
+        assert!((relative_luminance(Color::WHITE) - 1.0).abs() < 1e-6);
+        assert!((relative_luminance(Color::from_rgb(1.0, 0.0, 0.0)) - 0.2126).abs() < 1e-6);
+    }
+
+    #[test]
+    fn ratio() {
+        assert!((contrast_ratio(Color::BLACK, Color::WHITE) - 21.0).abs() < 1e-4);
+        assert!((contrast_ratio(Color::WHITE, Color::BLACK) - 21.0).abs() < 1e-4);
+        assert!((contrast_ratio(Color::WHITE, Color::WHITE) - 1.0).abs() < f32::EPSILON);
+
+        // #767676 on white is the lightest gray passing AA for normal texts.
+        let gray = Color::from_rgb8(0x76, 0x76, 0x76);
+        assert!((contrast_ratio(gray, Color::WHITE) - 4.54).abs() < 0.01);
+    }
+
+    #[test]
+    fn ratio_translucent() {
+        let transparent = Color {
+            a: 0.0,
+            ..Color::BLACK
+        };
+        assert!((contrast_ratio(transparent, Color::WHITE) - 1.0).abs() < f32::EPSILON);
+
+        let translucent = Color {
+            a: 0.5,
+            ..Color::BLACK
+        };
+        let gray = Color::from_rgb(0.5, 0.5, 0.5);
+        assert!(
+            (contrast_ratio(translucent, Color::WHITE) - contrast_ratio(gray, Color::WHITE)).abs()
+                < 1e-4
+        );
+    }
+
+    #[test]
+    fn levels() {
+        assert!(Level::AA.passes(4.5, TextSize::Normal));
+        assert!(!Level::AA.passes(4.49, TextSize::Normal));
+        assert!(Level::AA.passes(3.0, TextSize::Large));
+        assert!(!Level::AAA.passes(6.9, TextSize::Normal));
+        assert!(Level::AAA.passes(7.0, TextSize::Normal));
+        assert!(Level::AAA.passes(4.5, TextSize::Large));
+    }
+}
@@ -4,8 +4,10 @@ use chrono::{Datelike, Duration, Local, NaiveDate};
 use once_cell::sync::Lazy;
 use std::fmt::Display;
 
+pub use chrono::Weekday;
+
 /// The date value
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Date {
     /// The year value of the date.
     pub year: i32,
@@ -28,6 +30,80 @@ impl Date {
     pub const fn from_ymd(year: i32, month: u32, day: u32) -> Self {
         Self { year, month, day }
     }
+
+    /// Checks if the date exists in the calendar.
+    #[must_use]
+    pub fn is_valid(self) -> bool {
+        NaiveDate::from_ymd_opt(self.year, self.month, self.day).is_some()
+    }
+
+    /// Checks if the year of the date is a leap year.
+    #[must_use]
+    pub const fn is_leap_year(self) -> bool {
+        is_leap_year(self.year)
+    }
+
+    /// Gets the number of days of the month of the date.
+    #[must_use]
+    pub const fn days_in_month(self) -> u32 {
+        num_days_of_month(self.year, self.month)
+    }
+
+    /// # Panics
+    /// Calculates the date the given number of months later, clamping the day
+    /// to the last day of the resulting month.
+    /// panics if the date doesnt exist.
+    #[must_use]
+    pub fn add_months(self, months: i32) -> Self {
+        add_months(self.into(), months).into()
+    }
+
+    /// # Panics
+    /// Calculates the date the given number of months earlier, clamping the day
+    /// to the last day of the resulting month.
+    /// panics if the date doesnt exist.
+    #[must_use]
+    pub fn sub_months(self, months: i32) -> Self {
+        add_months(self.into(), -months).into()
+    }
+
+    /// # Panics
+    /// Calculates the date the given number of days later.
+    /// panics if the date doesnt exist.
+    #[must_use]
+    pub fn add_days(self, days: i64) -> Self {
+        (NaiveDate::from(self) + Duration::days(days)).into()
+    }
+
+    /// # Panics
+    /// Calculates the date the given number of days earlier.
+    /// panics if the date doesnt exist.
+    #[must_use]
+    pub fn sub_days(self, days: i64) -> Self {
+        (NaiveDate::from(self) - Duration::days(days)).into()
+    }
+
+    /// # Panics
+    /// Gets the day of the week of the date.
+    /// panics if the date doesnt exist.
+    #[must_use]
+    pub fn weekday(self) -> Weekday {
+        NaiveDate::from(self).weekday()
+    }
+
+    /// # Panics
+    /// Gets the ISO 8601 week of the date as the ISO year and the week number (1 - 53).
+    /// panics if the date doesnt exist.
+    #[must_use]
+    pub fn iso_week(self) -> (i32, u32) {
+        iso_week(self.into())
+    }
+
+    /// Gets the `n`-th (1 - 5) given weekday of the month in the year, if it exists.
+    #[must_use]
+    pub fn nth_weekday_of_month(year: i32, month: u32, weekday: Weekday, n: u8) -> Option<Self> {
+        nth_weekday_of_month(year, month, weekday, n).map(Into::into)
+    }
 }
 
 impl Display for Date {
@@ -50,35 +126,40 @@ impl From<NaiveDate> for Date {
 }
 
 /// # Panics
-/// Creates a date with the previous month based on the given date.
+/// Creates a date the given number of months apart from the given date,
+/// clamping the day to the last day of the resulting month.
 /// panics if year, month or day doesnt exist.
 #[must_use]
-pub fn pred_month(date: NaiveDate) -> NaiveDate {
-    let (year, month) = if date.month() == 1 {
-        (date.year() - 1, 12)
-    } else {
-        (date.year(), date.month() - 1)
-    };
+pub fn add_months(date: NaiveDate, months: i32) -> NaiveDate {
+    let months = date.year() * 12 + date.month0() as i32 + months;
+    let (year, month) = (months.div_euclid(12), months.rem_euclid(12) as u32 + 1);
 
     let day = date.day().min(num_days_of_month(year, month));
 
     NaiveDate::from_ymd_opt(year, month, day).expect("Year, Month or Day doesnt Exist")
 }
 
+/// Returns the number of months from the month of the first date to the
+/// month of the second date, regardless of their days.
+#[must_use]
+pub fn months_between(from: NaiveDate, to: NaiveDate) -> i32 {
+    (to.year() - from.year()) * 12 + to.month() as i32 - from.month() as i32
+}
+
+/// # Panics
+/// Creates a date with the previous month based on the given date.
+/// panics if year, month or day doesnt exist.
+#[must_use]
+pub fn pred_month(date: NaiveDate) -> NaiveDate {
+    add_months(date, -1)
+}
+
 /// # Panics
 /// Creates a date with the next month based on given date.
 /// panics if year, month or day doesnt exist.
 #[must_use]
 pub fn succ_month(date: NaiveDate) -> NaiveDate {
-    let (year, month) = if date.month() == 12 {
-        (date.year() + 1, 1)
-    } else {
-        (date.year(), date.month() + 1)
-    };
-
-    let day = date.day().min(num_days_of_month(year, month));
-
-    NaiveDate::from_ymd_opt(year, month, day).expect("Year, Month or Day doesnt Exist")
+    add_months(date, 1)
 }
 
 /// # Panics
@@ -133,6 +214,37 @@ pub fn succ_day(date: NaiveDate) -> NaiveDate {
     date + Duration::days(1)
 }
 
+/// Steps from the given date with the given step until a date passing the
+/// given check, giving up after the steps of a year.
+#[must_use]
+pub fn step_to(
+    date: NaiveDate,
+    step: fn(NaiveDate) -> NaiveDate,
+    is_enabled: impl Fn(NaiveDate) -> bool,
+) -> Option<NaiveDate> {
+    std::iter::successors(Some(step(date)), |&date| Some(step(date)))
+        .take(366)
+        .find(|&date| is_enabled(date))
+}
+
+/// Gets the date nearest to the given one in the same month passing the given
+/// check, preferring the later one of two equally near dates.
+#[must_use]
+pub fn nearest_in_month(
+    date: NaiveDate,
+    is_enabled: impl Fn(NaiveDate) -> bool,
+) -> Option<NaiveDate> {
+    (0..31)
+        .flat_map(|distance| {
+            [
+                date + Duration::days(distance),
+                date - Duration::days(distance),
+            ]
+        })
+        .filter(|candidate| candidate.month() == date.month() && candidate.year() == date.year())
+        .find(|&candidate| is_enabled(candidate))
+}
+
 /// Specifies if the calculated day lays in the previous, same or next month of
 /// the date.
 
@@ -154,11 +266,26 @@ pub enum IsInMonth {
 /// panics if year, month or day does not exist.
 #[must_use]
 pub fn position_to_day(x: usize, y: usize, year: i32, month: u32) -> (usize, IsInMonth) {
+    position_to_day_from(x, y, year, month, Weekday::Mon)
+}
+
+/// # Panics
+/// Calculates the day number at the given position in the calendar table based
+/// on the given year and month, with the columns of the table starting on the
+/// given weekday.
+/// panics if year, month or day does not exist.
+#[must_use]
+pub fn position_to_day_from(
+    x: usize,
+    y: usize,
+    year: i32,
+    month: u32,
+    first_weekday: Weekday,
+) -> (usize, IsInMonth) {
     let (x, y) = (x as isize, y as isize);
     let first_day =
         NaiveDate::from_ymd_opt(year, month, 1).expect("Year, Month or Day doesnt Exist");
-    let day_of_week = first_day.weekday().num_days_from_monday() as isize;
-    let day_of_week = if day_of_week == 0 { 7 } else { day_of_week };
+    let day_of_week = leading_days(first_day, first_weekday) as isize;
 
     let day = (x + 7 * y) + 1 - day_of_week;
 
@@ -178,9 +305,53 @@ pub fn position_to_day(x: usize, y: usize, year: i32, month: u32) -> (usize, IsI
     }
 }
 
-/// Checks if the given year is a leap year.
+/// # Panics
+/// Calculates the Monday of the ISO 8601 week shown in the given row of the
+/// calendar table based on the given year and month, with the columns of the
+/// table starting on the given weekday.
+/// panics if year or month does not exist.
+#[must_use]
+pub fn row_to_week_start(y: usize, year: i32, month: u32, first_weekday: Weekday) -> NaiveDate {
+    let first_day =
+        NaiveDate::from_ymd_opt(year, month, 1).expect("Year, Month or Day doesnt Exist");
+    // The column of the Monday, which is always in the row.
+    let monday = i64::from((7 - first_weekday.num_days_from_monday()) % 7);
+    let row_start = 7 * y as i64 - i64::from(leading_days(first_day, first_weekday));
 
-const fn is_leap_year(year: i32) -> bool {
+    first_day + Duration::days(row_start + monday)
+}
+
+/// Gets the Monday of the ISO 8601 week of the given date.
+#[must_use]
+pub fn week_start(date: NaiveDate) -> NaiveDate {
+    week_start_from(date, Weekday::Mon)
+}
+
+/// Gets the first day of the week of the given date, with the weeks starting
+/// on the given weekday.
+#[must_use]
+pub fn week_start_from(date: NaiveDate, first_weekday: Weekday) -> NaiveDate {
+    let offset =
+        (date.weekday().num_days_from_monday() + 7 - first_weekday.num_days_from_monday()) % 7;
+
+    date - Duration::days(offset.into())
+}
+
+/// Calculates the number of days of the previous month shown before the
+/// first day of the month in the first row of the calendar table, which is
+/// always at least one.
+fn leading_days(first_day: NaiveDate, first_weekday: Weekday) -> u32 {
+    match (first_day.weekday().num_days_from_monday() + 7 - first_weekday.num_days_from_monday())
+        % 7
+    {
+        0 => 7,
+        days => days,
+    }
+}
+
+/// Checks if the given year is a leap year.
+#[must_use]
+pub const fn is_leap_year(year: i32) -> bool {
     let mod4 = year % 4 == 0;
     let mod100 = year % 100 == 0;
     let mod400 = year % 400 == 0;
@@ -189,8 +360,8 @@ const fn is_leap_year(year: i32) -> bool {
 }
 
 /// Gets the number of days the given month in the year has.
-
-const fn num_days_of_month(year: i32, month: u32) -> u32 {
+#[must_use]
+pub const fn num_days_of_month(year: i32, month: u32) -> u32 {
     match month {
         4 | 6 | 9 | 11 => 30,
         2 => {
@@ -204,6 +375,22 @@ const fn num_days_of_month(year: i32, month: u32) -> u32 {
     }
 }
 
+/// Gets the ISO 8601 week of the given date as the ISO year and the week number (1 - 53).
+///
+/// The ISO year differs from the calendar year for the days at the turn of the
+/// year belonging to a week of the previous or next year.
+#[must_use]
+pub fn iso_week(date: NaiveDate) -> (i32, u32) {
+    let week = date.iso_week();
+    (week.year(), week.week())
+}
+
+/// Gets the `n`-th (1 - 5) given weekday of the month in the year, if it exists.
+#[must_use]
+pub fn nth_weekday_of_month(year: i32, month: u32, weekday: Weekday, n: u8) -> Option<NaiveDate> {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, n)
+}
+
 /// Gets the string representation of the year of the given date.
 
 #[must_use]
@@ -218,6 +405,29 @@ pub fn month_as_string(date: NaiveDate) -> String {
     date.format("%B").to_string()
 }
 
+/// Formats the given date in the given [`chrono`] format, like `%Y-%m-%d`,
+/// or returns an empty string if the format is invalid.
+#[must_use]
+pub fn format(date: NaiveDate, format: &str) -> String {
+    let items = chrono::format::StrftimeItems::new(format);
+
+    if items
+        .clone()
+        .any(|item| item == chrono::format::Item::Error)
+    {
+        return String::new();
+    }
+
+    date.format_with_items(items).to_string()
+}
+
+/// Parses a date typed in the given [`chrono`] format, like `%Y-%m-%d`,
+/// ignoring the surrounding whitespace.
+#[must_use]
+pub fn parse(text: &str, format: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(text.trim(), format).ok()
+}
+
 /// Gets the length of the longest month name.
 pub static MAX_MONTH_STR_LEN: Lazy<usize> = Lazy::new(|| {
     let months = [
@@ -274,11 +484,13 @@ pub static WEEKDAY_LABELS: Lazy<Vec<String>> = Lazy::new(|| {
 #[cfg(test)]
 
 mod tests {
-    use chrono::NaiveDate;
+    use chrono::{Datelike, Duration, NaiveDate};
 
     use super::{
-        is_leap_year, num_days_of_month, position_to_day, pred_month, pred_year, succ_month,
-        succ_year, IsInMonth,
+        add_months, format, is_leap_year, iso_week, months_between, nearest_in_month,
+        nth_weekday_of_month, num_days_of_month, parse, position_to_day, position_to_day_from,
+        pred_day, pred_month, pred_year, row_to_week_start, step_to, succ_day, succ_month,
+        succ_year, week_start, week_start_from, Date, IsInMonth, Weekday,
     };
 
     #[test]
@@ -417,6 +629,58 @@ mod tests {
         assert_eq!(is_in_month, IsInMonth::Next);
     }
 
+    #[allow(clippy::shadow_unrelated)]
+    #[test]
+    fn position_to_day_from_test() {
+        // December 2020 starts on a Tuesday.
+        let (day, is_in_month) = position_to_day_from(0, 0, 2020, 12, Weekday::Sun);
+        assert_eq!(day, 29);
+        assert_eq!(is_in_month, IsInMonth::Previous);
+
+        let (day, is_in_month) = position_to_day_from(2, 0, 2020, 12, Weekday::Sun);
+        assert_eq!(day, 1);
+        assert_eq!(is_in_month, IsInMonth::Same);
+
+        let (day, is_in_month) = position_to_day_from(3, 0, 2020, 12, Weekday::Sat);
+        assert_eq!(day, 1);
+        assert_eq!(is_in_month, IsInMonth::Same);
+
+        // A month starting on the first weekday starts in the second row.
+        let (day, is_in_month) = position_to_day_from(0, 1, 2020, 11, Weekday::Sun);
+        assert_eq!(day, 1);
+        assert_eq!(is_in_month, IsInMonth::Same);
+
+        assert_eq!(
+            position_to_day_from(4, 3, 2021, 2, Weekday::Mon),
+            position_to_day(4, 3, 2021, 2)
+        );
+    }
+
+    #[test]
+    fn row_to_week_start_test() {
+        let date = |day| NaiveDate::from_ymd_opt(2020, 12, day).expect("Day should exist");
+
+        // December 2020 starts on a Tuesday, after the Monday of November 30.
+        let monday = NaiveDate::from_ymd_opt(2020, 11, 30).expect("Day should exist");
+        assert_eq!(row_to_week_start(0, 2020, 12, Weekday::Mon), monday);
+        assert_eq!(row_to_week_start(0, 2020, 12, Weekday::Sun), monday);
+        assert_eq!(row_to_week_start(2, 2020, 12, Weekday::Sat), date(14));
+
+        // A row starting on Sunday shows the week of the following Monday.
+        assert_eq!(
+            row_to_week_start(1, 2020, 11, Weekday::Sun),
+            NaiveDate::from_ymd_opt(2020, 11, 2).expect("Day should exist")
+        );
+
+        assert_eq!(week_start(date(6)), monday);
+        assert_eq!(week_start(date(7)), date(7));
+        assert_eq!(week_start_from(date(7), Weekday::Sun), date(6));
+        assert_eq!(
+            week_start_from(date(4), Weekday::Sat),
+            monday - Duration::days(2)
+        );
+    }
+
     #[test]
     fn is_leap_year_test() {
         assert!(is_leap_year(2020));
@@ -442,4 +706,115 @@ mod tests {
         assert_eq!(num_days_of_month(2020, 11), 30);
         assert_eq!(num_days_of_month(2020, 12), 31);
     }
+
+    #[test]
+    fn add_months_test() {
+        let date = NaiveDate::from_ymd_opt(2020, 5, 6).expect("Year, Month or Day doesnt Exist");
+        let result = add_months(date, 14);
+        let expected =
+            NaiveDate::from_ymd_opt(2021, 7, 6).expect("Year, Month or Day doesnt Exist");
+        assert_eq!(result, expected);
+
+        let date = NaiveDate::from_ymd_opt(2020, 2, 15).expect("Year, Month or Day doesnt Exist");
+        let result = add_months(date, -14);
+        let expected =
+            NaiveDate::from_ymd_opt(2018, 12, 15).expect("Year, Month or Day doesnt Exist");
+        assert_eq!(result, expected);
+
+        let date = NaiveDate::from_ymd_opt(2019, 10, 31).expect("Year, Month or Day doesnt Exist");
+        let result = add_months(date, 4);
+        let expected =
+            NaiveDate::from_ymd_opt(2020, 2, 29).expect("Year, Month or Day doesnt Exist");
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn months_between_test() {
+        let date = |year, month, day| {
+            NaiveDate::from_ymd_opt(year, month, day).expect("Year, Month or Day doesnt Exist")
+        };
+
+        assert_eq!(months_between(date(2020, 5, 31), date(2020, 6, 1)), 1);
+        assert_eq!(months_between(date(2020, 5, 1), date(2020, 5, 31)), 0);
+        assert_eq!(months_between(date(2021, 1, 15), date(2020, 11, 15)), -2);
+    }
+
+    #[test]
+    fn format_parse_test() {
+        let date = NaiveDate::from_ymd_opt(2020, 12, 24).expect("Year, Month or Day doesnt Exist");
+
+        assert_eq!(format(date, "%d.%m.%Y"), "24.12.2020");
+        assert_eq!(format(date, "%Q"), "");
+        assert_eq!(parse(" 24.12.2020 ", "%d.%m.%Y"), Some(date));
+        assert_eq!(parse("2020-12-24", "%Y-%m-%d"), Some(date));
+        assert_eq!(parse("31.02.2020", "%d.%m.%Y"), None);
+        assert_eq!(parse("24.12.", "%d.%m.%Y"), None);
+    }
+
+    #[test]
+    fn iso_week_test() {
+        let date = NaiveDate::from_ymd_opt(2020, 5, 6).expect("Year, Month or Day doesnt Exist");
+        assert_eq!(iso_week(date), (2020, 19));
+
+        let date = NaiveDate::from_ymd_opt(2021, 1, 3).expect("Year, Month or Day doesnt Exist");
+        assert_eq!(iso_week(date), (2020, 53));
+
+        let date = NaiveDate::from_ymd_opt(2019, 12, 30).expect("Year, Month or Day doesnt Exist");
+        assert_eq!(iso_week(date), (2020, 1));
+    }
+
+    #[test]
+    fn nth_weekday_of_month_test() {
+        let expected =
+            NaiveDate::from_ymd_opt(2020, 11, 26).expect("Year, Month or Day doesnt Exist");
+        assert_eq!(
+            nth_weekday_of_month(2020, 11, Weekday::Thu, 4),
+            Some(expected)
+        );
+
+        assert_eq!(nth_weekday_of_month(2021, 2, Weekday::Mon, 5), None);
+    }
+
+    #[test]
+    fn date_test() {
+        let date = Date::from_ymd(2020, 1, 31);
+        assert_eq!(date.add_months(1), Date::from_ymd(2020, 2, 29));
+        assert_eq!(date.sub_months(2), Date::from_ymd(2019, 11, 30));
+        assert_eq!(date.add_days(30), Date::from_ymd(2020, 3, 1));
+        assert_eq!(date.sub_days(31), Date::from_ymd(2019, 12, 31));
+        assert_eq!(date.weekday(), Weekday::Fri);
+        assert_eq!(date.iso_week(), (2020, 5));
+        assert_eq!(date.days_in_month(), 31);
+        assert!(date.is_leap_year());
+        assert!(date.is_valid());
+        assert!(!Date::from_ymd(2021, 2, 29).is_valid());
+    }
+
+    #[test]
+    fn step_to_test() {
+        let is_weekday = |date: NaiveDate| date.weekday().number_from_monday() <= 5;
+        let friday = NaiveDate::from_ymd_opt(2020, 5, 8).expect("Year, Month or Day doesnt Exist");
+        let monday = NaiveDate::from_ymd_opt(2020, 5, 11).expect("Year, Month or Day doesnt Exist");
+
+        assert_eq!(step_to(friday, succ_day, is_weekday), Some(monday));
+        assert_eq!(step_to(monday, pred_day, is_weekday), Some(friday));
+        assert_eq!(step_to(monday, succ_day, |_| false), None);
+    }
+
+    #[test]
+    fn nearest_in_month_test() {
+        let is_weekday = |date: NaiveDate| date.weekday().number_from_monday() <= 5;
+        let date =
+            |day| NaiveDate::from_ymd_opt(2020, 5, day).expect("Year, Month or Day doesnt Exist");
+
+        // Saturday the 9th is as near to Friday as to Monday.
+        assert_eq!(nearest_in_month(date(9), is_weekday), Some(date(8)));
+        assert_eq!(nearest_in_month(date(10), is_weekday), Some(date(11)));
+        // The 1st of May 2020 is a Friday, and April is not searched.
+        assert_eq!(
+            nearest_in_month(date(2), |date| date.day() < 2),
+            Some(date(1))
+        );
+        assert_eq!(nearest_in_month(date(2), |date| date.month() == 4), None);
+    }
 }
@@ -0,0 +1,117 @@
+//! Helper functions for picking durations.
+//!
+//! *This API requires the following crate features to be activated: `duration_picker`*
+use std::time::Duration;
+
+/// A unit of the segments of a picked duration.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Unit {
+    /// The whole hours.
+    Hours,
+    /// The minutes of the hour.
+    Minutes,
+    /// The seconds of the minute.
+    Seconds,
+}
+
+impl Unit {
+    /// Returns the number of seconds in one step of the unit.
+    #[must_use]
+    pub const fn seconds(self) -> u64 {
+        match self {
+            Self::Hours => 3600,
+            Self::Minutes => 60,
+            Self::Seconds => 1,
+        }
+    }
+
+    /// Returns the length of one step of the unit.
+    #[must_use]
+    pub const fn step(self) -> Duration {
+        Duration::from_secs(self.seconds())
+    }
+
+    /// Returns the value of the unit in the given duration, ignoring its
+    /// fractions of a second.
+    #[must_use]
+    pub const fn of(self, duration: Duration) -> u64 {
+        let seconds = duration.as_secs() / self.seconds();
+
+        match self {
+            Self::Hours => seconds,
+            Self::Minutes | Self::Seconds => seconds % 60,
+        }
+    }
+}
+
+/// Returns the given duration in whole seconds with the value of the given
+/// unit replaced, clamped to the given maximum.
+#[must_use]
+pub fn with_unit(duration: Duration, unit: Unit, value: u64, max: Duration) -> Duration {
+    let step = unit.seconds();
+    let without = duration.as_secs() - unit.of(duration) * step;
+
+    Duration::from_secs(without.saturating_add(value.saturating_mul(step))).min(max)
+}
+
+/// Returns the given duration in whole seconds moved by one step of the given
+/// unit forth or back, staying between zero and the given maximum.
+#[must_use]
+pub fn step(duration: Duration, unit: Unit, forth: bool, max: Duration) -> Duration {
+    let seconds = Duration::from_secs(duration.as_secs());
+
+    if forth {
+        seconds.saturating_add(unit.step()).min(max)
+    } else {
+        seconds.saturating_sub(unit.step()).min(max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{step, with_unit, Unit};
+    use std::time::Duration;
+
+    #[test]
+    fn unit_of_test() {
+        let duration = Duration::from_secs(26 * 3600 + 5 * 60 + 9) + Duration::from_millis(700);
+
+        assert_eq!(Unit::Hours.of(duration), 26);
+        assert_eq!(Unit::Minutes.of(duration), 5);
+        assert_eq!(Unit::Seconds.of(duration), 9);
+    }
+
+    #[test]
+    fn with_unit_test() {
+        let max = Duration::from_hours(99);
+        let duration = Duration::from_secs(3600 + 30 * 60 + 15);
+
+        assert_eq!(
+            with_unit(duration, Unit::Minutes, 45, max),
+            Duration::from_secs(3600 + 45 * 60 + 15)
+        );
+        assert_eq!(
+            with_unit(duration, Unit::Hours, 0, max),
+            Duration::from_secs(30 * 60 + 15)
+        );
+        assert_eq!(with_unit(duration, Unit::Hours, 120, max), max);
+    }
+
+    #[test]
+    fn step_test() {
+        let max = Duration::from_hours(2);
+        let duration = Duration::from_secs(59 * 60 + 30);
+
+        // The steps carry over into the next unit.
+        assert_eq!(
+            step(duration, Unit::Minutes, true, max),
+            Duration::from_secs(3600 + 30)
+        );
+        assert_eq!(step(duration, Unit::Hours, false, max), Duration::ZERO);
+        assert_eq!(step(max, Unit::Seconds, true, max), max);
+        assert_eq!(
+            step(Duration::from_millis(1500), Unit::Seconds, false, max),
+            Duration::ZERO
+        );
+    }
+}
@@ -0,0 +1,138 @@
+//! Easing curves shaping the progress of animations.
+use std::time::{Duration, Instant};
+
+/// The curve mapping the linear progress of an animation to its eased
+/// progress, both from 0.0 to 1.0.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Easing {
+    /// A constant speed.
+    Linear,
+    /// Starts slowly and accelerates.
+    EaseIn,
+    /// Starts quickly and decelerates.
+    #[default]
+    EaseOut,
+    /// Starts and ends slowly.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Eases the given linear progress, clamped between 0.0 and 1.0.
+    #[must_use]
+    pub fn apply(self, progress: f32) -> f32 {
+        let t = progress.clamp(0.0, 1.0);
+
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t * t,
+            Self::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Self::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+/// The linear progress of an animation moving between a closed and an open
+/// position over a duration.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Animation {
+    /// How far the animation has opened, from 0.0 (closed) to 1.0 (open).
+    pub progress: f32,
+    /// The time of the last animation step.
+    pub last_update: Option<Instant>,
+}
+
+impl Animation {
+    /// Creates a new closed [`Animation`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            progress: 0.0,
+            last_update: None,
+        }
+    }
+
+    /// Advances the animation towards the open or closed position, moving
+    /// all the way in one step if the duration is zero.
+    ///
+    /// Returns true if the animation has not finished yet.
+    pub fn animate(&mut self, open: bool, duration: Duration, now: Instant) -> bool {
+        let target = if open { 1.0 } else { 0.0 };
+
+        if (self.progress - target).abs() <= f32::EPSILON {
+            self.progress = target;
+            self.last_update = None;
+            return false;
+        }
+
+        let last_update = self.last_update.replace(now).unwrap_or(now);
+        let step = if duration.is_zero() {
+            1.0
+        } else {
+            (now - last_update).as_secs_f32() / duration.as_secs_f32()
+        };
+
+        self.progress = if open {
+            (self.progress + step).min(1.0)
+        } else {
+            (self.progress - step).max(0.0)
+        };
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Animation, Easing};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn easing_bounds_test() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+        ] {
+            assert!(easing.apply(0.0).abs() < f32::EPSILON);
+            assert!((easing.apply(1.0) - 1.0).abs() < f32::EPSILON);
+            assert!((easing.apply(2.0) - 1.0).abs() < f32::EPSILON);
+        }
+    }
+
+    #[test]
+    fn easing_shape_test() {
+        assert!(Easing::EaseIn.apply(0.5) < 0.5);
+        assert!(Easing::EaseOut.apply(0.5) > 0.5);
+        assert!((Easing::EaseInOut.apply(0.5) - 0.5).abs() < f32::EPSILON);
+        assert!((Easing::Linear.apply(0.25) - 0.25).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn animation_test() {
+        let mut animation = Animation::new();
+        let now = Instant::now();
+        let duration = Duration::from_millis(100);
+
+        // The first step only starts the clock.
+        assert!(animation.animate(true, duration, now));
+        assert!(animation.progress.abs() < f32::EPSILON);
+        assert!(animation.animate(true, duration, now + Duration::from_millis(50)));
+        assert!((animation.progress - 0.5).abs() < 0.01);
+        assert!(animation.animate(true, duration, now + Duration::from_millis(200)));
+        assert!((animation.progress - 1.0).abs() < f32::EPSILON);
+        assert!(!animation.animate(true, duration, now + Duration::from_millis(250)));
+        assert_eq!(animation.last_update, None);
+
+        // Without a duration, the animation closes in one step.
+        assert!(animation.animate(false, Duration::ZERO, now));
+        assert!(animation.progress.abs() < f32::EPSILON);
+        assert!(!animation.animate(false, Duration::ZERO, now));
+    }
+}
@@ -0,0 +1,635 @@
+//! Find and replace the matches of a search query in a text.
+//!
+//! *This API requires the following crate features to be activated: `find_bar`*
+use std::{borrow::Cow, fmt, ops::Range};
+
+/// A search query of a [`FindBar`](crate::native::find_bar::FindBar).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Query {
+    /// The searched text or pattern.
+    pub text: String,
+    /// Whether the case of the letters must match.
+    pub case_sensitive: bool,
+    /// Whether the text is a regular expression.
+    pub regex: bool,
+}
+
+impl Query {
+    /// Creates a new case insensitive [`Query`] for the given text.
+    #[must_use]
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the byte ranges of the non-overlapping, non-empty matches of
+    /// the [`Query`] in the given text.
+    ///
+    /// Regular expressions support literals, `.`, character classes like
+    /// `[a-z]`, `[^0-9]`, `\d`, `\w` and `\s`, the line anchors `^` and `$`,
+    /// groups, alternations with `|`, and the greedy and lazy quantifiers `*`,
+    /// `+`, `?` and `{n,m}`.
+    ///
+    /// # Errors
+    /// Returns a [`PatternError`] if the text of a regular expression query is
+    /// not a valid pattern.
+    pub fn find(&self, text: &str) -> Result<Vec<Range<usize>>, PatternError> {
+        if self.text.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let alternatives = if self.regex {
+            Parser::new(&self.text).parse()?
+        } else {
+            vec![self
+                .text
+                .chars()
+                .map(|c| Piece::once(Node::Char(c)))
+                .collect()]
+        };
+
+        let matcher = Matcher {
+            chars: text.chars().collect(),
+            case_sensitive: self.case_sensitive,
+        };
+        let offsets: Vec<usize> = text
+            .char_indices()
+            .map(|(offset, _)| offset)
+            .chain(std::iter::once(text.len()))
+            .collect();
+
+        let root = [Piece::once(Node::Group(alternatives))];
+        let mut found = Vec::new();
+        let mut start = 0;
+
+        while start < matcher.chars.len() {
+            let mut end = None;
+            let _ = matcher.sequence(&root, start, &mut |position| {
+                end = Some(position);
+                true
+            });
+
+            match end {
+                Some(end) if end > start => {
+                    found.push(offsets[start]..offsets[end]);
+                    start = end;
+                }
+                _ => start += 1,
+            }
+        }
+
+        Ok(found)
+    }
+}
+
+/// The error of an invalid regular expression of a [`Query`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PatternError {
+    /// A group is not closed with `)`.
+    UnclosedGroup,
+    /// A `)` does not close a group.
+    UnopenedGroup,
+    /// A character class is not closed with `]`.
+    UnclosedClass,
+    /// A quantifier does not follow anything to repeat.
+    NothingToRepeat,
+    /// The minimum of a `{n,m}` quantifier is larger than its maximum.
+    InvalidRepetition,
+    /// The pattern ends with a `\`.
+    TrailingBackslash,
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::UnclosedGroup => "unclosed group",
+            Self::UnopenedGroup => "unopened group",
+            Self::UnclosedClass => "unclosed character class",
+            Self::NothingToRepeat => "nothing to repeat",
+            Self::InvalidRepetition => "invalid repetition",
+            Self::TrailingBackslash => "trailing backslash",
+        })
+    }
+}
+
+impl std::error::Error for PatternError {}
+
+/// A text that can be searched with a [`FindBar`](crate::native::find_bar::FindBar).
+pub trait Searchable {
+    /// Returns the searched text.
+    fn search_text(&self) -> Cow<'_, str>;
+}
+
+/// A [`Searchable`] text whose matches can be replaced.
+pub trait Replaceable: Searchable {
+    /// Replaces the given byte range of the searched text.
+    fn replace_range(&mut self, range: Range<usize>, replacement: &str);
+}
+
+impl Searchable for str {
+    fn search_text(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl Searchable for String {
+    fn search_text(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl Replaceable for String {
+    fn replace_range(&mut self, range: Range<usize>, replacement: &str) {
+        Self::replace_range(self, range, replacement);
+    }
+}
+
+#[cfg(feature = "rich_editor")]
+impl Searchable for crate::core::rich_text::Document {
+    fn search_text(&self) -> Cow<'_, str> {
+        Cow::Owned(self.text(crate::core::rich_text::Position::default()..self.end()))
+    }
+}
+
+#[cfg(feature = "rich_editor")]
+impl Replaceable for crate::core::rich_text::Document {
+    fn replace_range(&mut self, range: Range<usize>, replacement: &str) {
+        use crate::core::rich_text::Position;
+
+        // The blocks of the searched text are separated by a line break.
+        let position = |offset: usize| {
+            let mut start = 0;
+            for (block, content) in self.blocks.iter().enumerate() {
+                if offset <= start + content.len() {
+                    return Position::new(block, offset - start);
+                }
+                start += content.len() + 1;
+            }
+            self.end()
+        };
+        let (start, end) = (position(range.start), position(range.end));
+
+        let style = self.blocks[start.block].style_at(start.offset);
+        self.delete(start..end);
+        let _ = self.insert(start, replacement, &style);
+    }
+}
+
+/// Replaces the given matches of the target with the replacement, and returns
+/// the number of replaced matches.
+pub fn replace_all<T>(target: &mut T, matches: &[Range<usize>], replacement: &str) -> usize
+where
+    T: Replaceable + ?Sized,
+{
+    // The matches are replaced from the last, keeping the ranges of the
+    // others valid.
+    for range in matches.iter().rev() {
+        target.replace_range(range.clone(), replacement);
+    }
+
+    matches.len()
+}
+
+/// A node of a regular expression.
+#[derive(Clone, Debug)]
+enum Node {
+    /// A character.
+    Char(char),
+    /// Any character except a line break.
+    Any,
+    /// A character class.
+    Class {
+        /// Whether the class matches the characters not in it.
+        negated: bool,
+        /// The items of the class.
+        items: Vec<ClassItem>,
+    },
+    /// The start of a line.
+    LineStart,
+    /// The end of a line.
+    LineEnd,
+    /// A group of alternative sequences.
+    Group(Vec<Vec<Piece>>),
+}
+
+/// An item of a character class.
+#[derive(Clone, Copy, Debug)]
+enum ClassItem {
+    /// An inclusive range of characters.
+    Range(char, char),
+    /// A digit, `\d`.
+    Digit,
+    /// A word character, `\w`.
+    Word,
+    /// A whitespace character, `\s`.
+    Space,
+    /// The negation of an item, e.g. `\D`.
+    Not(&'static Self),
+}
+
+impl ClassItem {
+    /// Returns `true` if the character is in the item.
+    fn contains(self, c: char, case_sensitive: bool) -> bool {
+        match self {
+            Self::Range(start, end) if case_sensitive => (start..=end).contains(&c),
+            Self::Range(start, end) => c
+                .to_lowercase()
+                .chain(c.to_uppercase())
+                .any(|c| (start..=end).contains(&c)),
+            Self::Digit => c.is_ascii_digit(),
+            Self::Word => c.is_alphanumeric() || c == '_',
+            Self::Space => c.is_whitespace(),
+            Self::Not(item) => !item.contains(c, case_sensitive),
+        }
+    }
+}
+
+/// A repeated [`Node`] of a regular expression.
+#[derive(Clone, Debug)]
+struct Piece {
+    /// The repeated node.
+    node: Node,
+    /// The minimal number of repetitions.
+    min: usize,
+    /// The maximal number of repetitions, if any.
+    max: Option<usize>,
+    /// Whether as many repetitions as possible are tried first.
+    greedy: bool,
+}
+
+impl Piece {
+    /// Creates a [`Piece`] matching the node once.
+    const fn once(node: Node) -> Self {
+        Self {
+            node,
+            min: 1,
+            max: Some(1),
+            greedy: true,
+        }
+    }
+}
+
+/// The parser of a regular expression.
+struct Parser<'a> {
+    /// The remaining characters of the pattern.
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    /// Creates a new [`Parser`] of the given pattern.
+    fn new(pattern: &'a str) -> Self {
+        Self {
+            chars: pattern.chars().peekable(),
+        }
+    }
+
+    /// Parses the whole pattern.
+    fn parse(mut self) -> Result<Vec<Vec<Piece>>, PatternError> {
+        let alternatives = self.alternatives()?;
+
+        match self.chars.next() {
+            Some(_) => Err(PatternError::UnopenedGroup),
+            None => Ok(alternatives),
+        }
+    }
+
+    /// Parses alternative sequences up to the end of a group.
+    fn alternatives(&mut self) -> Result<Vec<Vec<Piece>>, PatternError> {
+        let mut alternatives = vec![Vec::new()];
+
+        while let Some(&c) = self.chars.peek() {
+            if c == ')' {
+                break;
+            }
+            let _ = self.chars.next();
+
+            let node = match c {
+                '|' => {
+                    alternatives.push(Vec::new());
+                    continue;
+                }
+                '*' | '+' | '?' | '{' => return Err(PatternError::NothingToRepeat),
+                '.' => Node::Any,
+                '^' => Node::LineStart,
+                '$' => Node::LineEnd,
+                '[' => self.class()?,
+                '(' => {
+                    // Non-capturing groups are the same as groups.
+                    if self.chars.peek() == Some(&'?') {
+                        let _ = self.chars.next();
+                        if self.chars.next() != Some(':') {
+                            return Err(PatternError::NothingToRepeat);
+                        }
+                    }
+                    let group = self.alternatives()?;
+                    if self.chars.next() != Some(')') {
+                        return Err(PatternError::UnclosedGroup);
+                    }
+                    Node::Group(group)
+                }
+                '\\' => self.escape()?,
+                c => Node::Char(c),
+            };
+
+            let piece = self.quantifier(node)?;
+            if let Some(sequence) = alternatives.last_mut() {
+                sequence.push(piece);
+            }
+        }
+
+        Ok(alternatives)
+    }
+
+    /// Parses the optional quantifier of a node.
+    fn quantifier(&mut self, node: Node) -> Result<Piece, PatternError> {
+        let (min, max) = match self.chars.peek() {
+            Some('*') => (0, None),
+            Some('+') => (1, None),
+            Some('?') => (0, Some(1)),
+            Some('{') => {
+                let _ = self.chars.next();
+                let (min, max) = self.repetition()?;
+                return Ok(self.laziness(Piece {
+                    node,
+                    min,
+                    max,
+                    greedy: true,
+                }));
+            }
+            _ => return Ok(Piece::once(node)),
+        };
+        let _ = self.chars.next();
+
+        Ok(self.laziness(Piece {
+            node,
+            min,
+            max,
+            greedy: true,
+        }))
+    }
+
+    /// Makes the piece lazy if its quantifier is followed by `?`.
+    fn laziness(&mut self, mut piece: Piece) -> Piece {
+        if self.chars.peek() == Some(&'?') {
+            let _ = self.chars.next();
+            piece.greedy = false;
+        }
+        piece
+    }
+
+    /// Parses the bounds of a `{n}`, `{n,}` or `{n,m}` quantifier.
+    fn repetition(&mut self) -> Result<(usize, Option<usize>), PatternError> {
+        let mut content = String::new();
+        loop {
+            match self.chars.next() {
+                Some('}') => break,
+                Some(c) if c.is_ascii_digit() || c == ',' => content.push(c),
+                _ => return Err(PatternError::InvalidRepetition),
+            }
+        }
+
+        let number = |text: &str| {
+            text.parse::<usize>()
+                .map_err(|_error| PatternError::InvalidRepetition)
+        };
+
+        let (min, max) = match content.split_once(',') {
+            None => {
+                let count = number(&content)?;
+                (count, Some(count))
+            }
+            Some((min, "")) => (number(min)?, None),
+            Some((min, max)) => (number(min)?, Some(number(max)?)),
+        };
+
+        if max.is_some_and(|max| max < min) {
+            return Err(PatternError::InvalidRepetition);
+        }
+        Ok((min, max))
+    }
+
+    /// Parses a character class after its `[`.
+    fn class(&mut self) -> Result<Node, PatternError> {
+        let negated = self.chars.peek() == Some(&'^');
+        if negated {
+            let _ = self.chars.next();
+        }
+
+        let mut items = Vec::new();
+        let mut first = true;
+
+        loop {
+            let c = self.chars.next().ok_or(PatternError::UnclosedClass)?;
+
+            // A `]` at the start of the class is a literal.
+            if c == ']' && !first {
+                break;
+            }
+            first = false;
+
+            let start = if c == '\\' {
+                match self.escape()? {
+                    Node::Char(c) => c,
+                    Node::Class { items: escaped, .. } => {
+                        items.extend(escaped);
+                        continue;
+                    }
+                    _ => unreachable!("escapes are characters or classes"),
+                }
+            } else {
+                c
+            };
+
+            let lookahead: Vec<char> = self.chars.clone().take(2).collect();
+            let end = match lookahead[..] {
+                ['-', end] if end != ']' && end != '\\' => {
+                    let _ = self.chars.next();
+                    let _ = self.chars.next();
+                    end
+                }
+                _ => start,
+            };
+
+            items.push(ClassItem::Range(start.min(end), start.max(end)));
+        }
+
+        Ok(Node::Class { negated, items })
+    }
+
+    /// Parses an escape sequence after its `\`.
+    fn escape(&mut self) -> Result<Node, PatternError> {
+        let class = |item| Node::Class {
+            negated: false,
+            items: vec![item],
+        };
+
+        Ok(match self.chars.next() {
+            None => return Err(PatternError::TrailingBackslash),
+            Some('d') => class(ClassItem::Digit),
+            Some('w') => class(ClassItem::Word),
+            Some('s') => class(ClassItem::Space),
+            Some('D') => class(ClassItem::Not(&ClassItem::Digit)),
+            Some('W') => class(ClassItem::Not(&ClassItem::Word)),
+            Some('S') => class(ClassItem::Not(&ClassItem::Space)),
+            Some('n') => Node::Char('\n'),
+            Some('t') => Node::Char('\t'),
+            Some(c) => Node::Char(c),
+        })
+    }
+}
+
+/// The backtracking matcher of a parsed regular expression.
+struct Matcher {
+    /// The characters of the searched text.
+    chars: Vec<char>,
+    /// Whether the case of the letters must match.
+    case_sensitive: bool,
+}
+
+impl Matcher {
+    /// Matches the pieces at the given character index, calling the
+    /// continuation with the end of each match until it returns `true`.
+    fn sequence(
+        &self,
+        pieces: &[Piece],
+        index: usize,
+        next: &mut dyn FnMut(usize) -> bool,
+    ) -> bool {
+        let Some((piece, rest)) = pieces.split_first() else {
+            return next(index);
+        };
+
+        self.repeat(piece, 0, index, &mut |end| self.sequence(rest, end, next))
+    }
+
+    /// Matches the remaining repetitions of the piece.
+    fn repeat(
+        &self,
+        piece: &Piece,
+        count: usize,
+        index: usize,
+        next: &mut dyn FnMut(usize) -> bool,
+    ) -> bool {
+        let can_stop = count >= piece.min;
+        let can_repeat = piece.max.is_none_or(|max| count < max);
+
+        if can_stop && !piece.greedy && next(index) {
+            return true;
+        }
+
+        // Repetitions matching nothing stop the repetition.
+        if can_repeat
+            && self.node(&piece.node, index, &mut |end| {
+                (end > index || !can_stop) && self.repeat(piece, count + 1, end, next)
+            })
+        {
+            return true;
+        }
+
+        can_stop && piece.greedy && next(index)
+    }
+
+    /// Matches the node at the given character index.
+    fn node(&self, node: &Node, index: usize, next: &mut dyn FnMut(usize) -> bool) -> bool {
+        let current = self.chars.get(index).copied();
+
+        match node {
+            Node::Char(expected) => {
+                current.is_some_and(|c| self.equals(c, *expected)) && next(index + 1)
+            }
+            Node::Any => current.is_some_and(|c| c != '\n') && next(index + 1),
+            Node::Class { negated, items } => {
+                current.is_some_and(|c| {
+                    items
+                        .iter()
+                        .any(|item| item.contains(c, self.case_sensitive))
+                        != *negated
+                }) && next(index + 1)
+            }
+            Node::LineStart => (index == 0 || self.chars[index - 1] == '\n') && next(index),
+            Node::LineEnd => current.is_none_or(|c| c == '\n') && next(index),
+            Node::Group(alternatives) => alternatives
+                .iter()
+                .any(|sequence| self.sequence(sequence, index, next)),
+        }
+    }
+
+    /// Returns `true` if the characters are equal.
+    fn equals(&self, a: char, b: char) -> bool {
+        a == b || (!self.case_sensitive && a.to_lowercase().eq(b.to_lowercase()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{replace_all, PatternError, Query, Range};
+
+    fn find(text: &str, pattern: &str, regex: bool) -> Vec<String> {
+        let query = Query {
+            text: pattern.to_owned(),
+            case_sensitive: false,
+            regex,
+        };
+
+        query
+            .find(text)
+            .expect("valid pattern")
+            .into_iter()
+            .map(|range| text[range].to_owned())
+            .collect()
+    }
+
+    #[test]
+    fn find_test() {
+        assert_eq!(find("Foo foo fOo", "foo", false), vec!["Foo", "foo", "fOo"]);
+        assert_eq!(find("a.b axb", "a.b", false), vec!["a.b"]);
+        assert_eq!(find("aaaa", "aa", false), vec!["aa", "aa"]);
+
+        let query = Query {
+            text: String::from("Foo"),
+            case_sensitive: true,
+            regex: false,
+        };
+        assert_eq!(query.find("foo Foo"), Ok(vec![Range { start: 4, end: 7 }]));
+        assert_eq!(Query::new("").find("text"), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn regex_test() {
+        assert_eq!(find("a1 b22 c333", r"\d+", true), vec!["1", "22", "333"]);
+        assert_eq!(find("cat cot cut", "c[ao]t", true), vec!["cat", "cot"]);
+        assert_eq!(find("cat dog bird", "dog|cat", true), vec!["cat", "dog"]);
+        assert_eq!(find("<a><b>", "<.*?>", true), vec!["<a>", "<b>"]);
+        assert_eq!(find("<a><b>", "<.*>", true), vec!["<a><b>"]);
+        assert_eq!(find("ab\nabab", "^(ab)+$", true), vec!["ab", "abab"]);
+        assert_eq!(find("x1234y", r"\d{2,3}", true), vec!["123"]);
+        assert_eq!(find("a-b_c d", r"[^\w]", true), vec!["-", " "]);
+        assert_eq!(find("aaa", "b*", true), Vec::<&str>::new());
+
+        let error = |pattern: &str| {
+            Query {
+                text: pattern.to_owned(),
+                case_sensitive: false,
+                regex: true,
+            }
+            .find("")
+        };
+        assert_eq!(error("(a"), Err(PatternError::UnclosedGroup));
+        assert_eq!(error("a)"), Err(PatternError::UnopenedGroup));
+        assert_eq!(error("[a"), Err(PatternError::UnclosedClass));
+        assert_eq!(error("*a"), Err(PatternError::NothingToRepeat));
+        assert_eq!(error("a{3,1}"), Err(PatternError::InvalidRepetition));
+        assert_eq!(error("a\\"), Err(PatternError::TrailingBackslash));
+    }
+
+    #[test]
+    fn replace_all_test() {
+        let mut text = String::from("one two one");
+        let matches = Query::new("one").find(&text).expect("valid pattern");
+
+        assert_eq!(replace_all(&mut text, &matches, "three"), 2);
+        assert_eq!(text, "three two three");
+    }
+}
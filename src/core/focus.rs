@@ -0,0 +1,173 @@
+//! Move the keyboard focus between the focusable widgets of a widget tree by
+//! their order, e.g. to trap the focus inside of a dialog or to rove it
+//! through a group with the arrow keys.
+
+use iced_widget::core::{
+    keyboard::KeyCode,
+    widget::{operation::Focusable, Id, Operation},
+    Rectangle,
+};
+
+/// The [`Operation`] counting the focusable widgets and finding the focused
+/// one, optionally unfocusing it.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FindFocused {
+    /// The index of the focused widget, if any.
+    pub focused: Option<usize>,
+    /// The number of found focusable widgets.
+    pub count: usize,
+    /// Whether the focused widget is unfocused.
+    unfocus: bool,
+}
+
+impl FindFocused {
+    /// Creates a new [`FindFocused`] leaving the focus as it is.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`FindFocused`] unfocusing the focused widget.
+    #[must_use]
+    pub fn unfocusing() -> Self {
+        Self {
+            unfocus: true,
+            ..Self::default()
+        }
+    }
+}
+
+impl<T> Operation<T> for FindFocused {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        if state.is_focused() {
+            self.focused = Some(self.count);
+            if self.unfocus {
+                state.unfocus();
+            }
+        }
+        self.count += 1;
+    }
+}
+
+/// The [`Operation`] focusing the focusable widget with the given index and
+/// unfocusing all others.
+#[derive(Clone, Copy, Debug)]
+pub struct FocusIndex {
+    /// The index of the widget to focus, if any.
+    target: Option<usize>,
+    /// The index of the next found focusable widget.
+    index: usize,
+}
+
+impl FocusIndex {
+    /// Creates a new [`FocusIndex`] focusing the widget with the given index,
+    /// or only unfocusing if there is none.
+    #[must_use]
+    pub const fn new(target: Option<usize>) -> Self {
+        Self { target, index: 0 }
+    }
+}
+
+impl<T> Operation<T> for FocusIndex {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        if self.target == Some(self.index) {
+            state.focus();
+        } else {
+            state.unfocus();
+        }
+        self.index += 1;
+    }
+}
+
+/// Returns the index of the focusable widget following the focused one
+/// among the given number of widgets, wrapping around, or preceding it if
+/// not `forward`.
+///
+/// Without a focused widget, the first or last widget follows.
+#[must_use]
+pub const fn cycle(focused: Option<usize>, count: usize, forward: bool) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+
+    Some(match (focused, forward) {
+        (Some(focused), true) => (focused + 1) % count,
+        (Some(focused), false) => (focused + count - 1) % count,
+        (None, true) => 0,
+        (None, false) => count - 1,
+    })
+}
+
+/// Returns the index of the item the given key moves the roving focus of a
+/// group to among the given number of items, or `None` if the key does not
+/// move it.
+///
+/// The arrow keys move to the preceding or following item, wrapping around
+/// at the ends if `wrap`, and `Home` and `End` to the first and last item.
+#[must_use]
+pub const fn rove(focused: usize, count: usize, key_code: KeyCode, wrap: bool) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+
+    let last = count - 1;
+    let focused = if focused < last { focused } else { last };
+
+    Some(match key_code {
+        KeyCode::Left | KeyCode::Up if focused > 0 => focused - 1,
+        KeyCode::Left | KeyCode::Up if wrap => last,
+        KeyCode::Right | KeyCode::Down if focused < last => focused + 1,
+        KeyCode::Right | KeyCode::Down if wrap => 0,
+        KeyCode::Left | KeyCode::Up | KeyCode::Right | KeyCode::Down => focused,
+        KeyCode::Home => 0,
+        KeyCode::End => last,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cycle, rove};
+    use iced_widget::core::keyboard::KeyCode;
+
+    #[test]
+    fn cycle_test() {
+        assert_eq!(cycle(Some(0), 3, true), Some(1));
+        assert_eq!(cycle(Some(2), 3, true), Some(0));
+        assert_eq!(cycle(Some(0), 3, false), Some(2));
+        assert_eq!(cycle(None, 3, true), Some(0));
+        assert_eq!(cycle(None, 3, false), Some(2));
+        assert_eq!(cycle(None, 0, true), None);
+    }
+
+    #[test]
+    fn rove_test() {
+        assert_eq!(rove(1, 3, KeyCode::Right, true), Some(2));
+        assert_eq!(rove(2, 3, KeyCode::Down, true), Some(0));
+        assert_eq!(rove(0, 3, KeyCode::Up, true), Some(2));
+        assert_eq!(rove(2, 3, KeyCode::Right, false), Some(2));
+        assert_eq!(rove(0, 3, KeyCode::Left, false), Some(0));
+        assert_eq!(rove(1, 3, KeyCode::End, false), Some(2));
+        assert_eq!(rove(5, 3, KeyCode::Home, true), Some(0));
+        assert_eq!(rove(1, 3, KeyCode::Enter, true), None);
+        assert_eq!(rove(0, 0, KeyCode::Right, true), None);
+    }
+}
@@ -0,0 +1,266 @@
+//! Helper functions and structs for navigating with a gamepad.
+
+use super::spatial_nav::{nearest, Direction};
+
+use iced_widget::core::Rectangle;
+use std::collections::HashMap;
+
+/// A button of a gamepad, named after its position on the gamepad.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Button {
+    /// The up button of the directional pad.
+    DPadUp,
+    /// The down button of the directional pad.
+    DPadDown,
+    /// The left button of the directional pad.
+    DPadLeft,
+    /// The right button of the directional pad.
+    DPadRight,
+    /// The bottom face button, e.g. `A` or `Cross`.
+    South,
+    /// The right face button, e.g. `B` or `Circle`.
+    East,
+    /// The left face button, e.g. `X` or `Square`.
+    West,
+    /// The top face button, e.g. `Y` or `Triangle`.
+    North,
+    /// The left shoulder button.
+    LeftShoulder,
+    /// The right shoulder button.
+    RightShoulder,
+    /// The start button.
+    Start,
+    /// The select button.
+    Select,
+}
+
+/// A navigation between the targets of a user interface.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Navigation {
+    /// Moves the focus to the nearest target above.
+    Up,
+    /// Moves the focus to the nearest target below.
+    Down,
+    /// Moves the focus to the nearest target on the left.
+    Left,
+    /// Moves the focus to the nearest target on the right.
+    Right,
+    /// Moves the focus to the next target.
+    Next,
+    /// Moves the focus to the previous target.
+    Previous,
+    /// Activates the focused target.
+    Activate,
+    /// Removes the focus.
+    Back,
+}
+
+/// A mapping of the buttons of a gamepad to [`Navigation`]s.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mapping {
+    /// The [`Navigation`] of each mapped button.
+    buttons: HashMap<Button, Navigation>,
+}
+
+impl Default for Mapping {
+    /// The conventional mapping: the directional pad moves the focus, the
+    /// shoulder buttons move it in order, the bottom face button activates
+    /// and the right face button goes back.
+    fn default() -> Self {
+        Self {
+            buttons: HashMap::from([
+                (Button::DPadUp, Navigation::Up),
+                (Button::DPadDown, Navigation::Down),
+                (Button::DPadLeft, Navigation::Left),
+                (Button::DPadRight, Navigation::Right),
+                (Button::LeftShoulder, Navigation::Previous),
+                (Button::RightShoulder, Navigation::Next),
+                (Button::South, Navigation::Activate),
+                (Button::East, Navigation::Back),
+            ]),
+        }
+    }
+}
+
+impl Mapping {
+    /// Creates a new [`Mapping`] without any mapped button.
+    #[must_use]
+    pub fn empty() -> Self {
+        Self {
+            buttons: HashMap::new(),
+        }
+    }
+
+    /// Maps the button to the [`Navigation`], or unmaps it with `None`.
+    #[must_use]
+    pub fn with(mut self, button: Button, navigation: Option<Navigation>) -> Self {
+        let _ = match navigation {
+            Some(navigation) => self.buttons.insert(button, navigation),
+            None => self.buttons.remove(&button),
+        };
+        self
+    }
+
+    /// Returns the [`Navigation`] of the pressed button, if it is mapped.
+    #[must_use]
+    pub fn get(&self, button: Button) -> Option<Navigation> {
+        self.buttons.get(&button).copied()
+    }
+}
+
+/// The state of an analog stick, turning its movements into [`Navigation`]s.
+///
+/// A [`Navigation`] is produced when the stick leaves its dead zone, and
+/// the next one only after it went back to it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stick {
+    /// The distance from the center below which the stick is at rest.
+    dead_zone: f32,
+    /// Whether the stick is out of its dead zone.
+    is_tilted: bool,
+}
+
+impl Default for Stick {
+    fn default() -> Self {
+        Self::new(0.5)
+    }
+}
+
+impl Stick {
+    /// Creates a new [`Stick`] with the given dead zone, between 0 and 1.
+    #[must_use]
+    pub const fn new(dead_zone: f32) -> Self {
+        Self {
+            dead_zone,
+            is_tilted: false,
+        }
+    }
+
+    /// Updates the [`Stick`] with its new position, each axis between -1
+    /// and 1 with the y axis pointing up, and returns the [`Navigation`]
+    /// of its movement, if any.
+    pub fn update(&mut self, x: f32, y: f32) -> Option<Navigation> {
+        if x.hypot(y) < self.dead_zone {
+            self.is_tilted = false;
+            return None;
+        }
+
+        if self.is_tilted {
+            return None;
+        }
+        self.is_tilted = true;
+
+        Some(if x.abs() > y.abs() {
+            if x > 0.0 {
+                Navigation::Right
+            } else {
+                Navigation::Left
+            }
+        } else if y > 0.0 {
+            Navigation::Up
+        } else {
+            Navigation::Down
+        })
+    }
+}
+
+/// Returns the index of the target focused after the [`Navigation`], given
+/// the bounds of all the targets and the index of the focused one.
+///
+/// The first target is focused if none is. A directional [`Navigation`]
+/// keeps the focus if there is no target in its direction.
+#[must_use]
+pub fn next_target(
+    targets: &[Rectangle],
+    focused: Option<usize>,
+    navigation: Navigation,
+) -> Option<usize> {
+    let count = targets.len();
+    let focused = match focused {
+        Some(focused) if focused < count => focused,
+        _ => return (count > 0 && navigation != Navigation::Back).then_some(0),
+    };
+
+    let direction = match navigation {
+        Navigation::Next => return Some((focused + 1) % count),
+        Navigation::Previous => return Some((focused + count - 1) % count),
+        Navigation::Activate => return Some(focused),
+        Navigation::Back => return None,
+        Navigation::Up => Direction::Up,
+        Navigation::Down => Direction::Down,
+        Navigation::Left => Direction::Left,
+        Navigation::Right => Direction::Right,
+    };
+
+    nearest(targets, focused, direction).or(Some(focused))
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_widget::core::{Point, Rectangle, Size};
+
+    use super::{next_target, Button, Mapping, Navigation, Stick};
+
+    /// Returns a 3x2 grid of targets, row by row.
+    fn grid() -> Vec<Rectangle> {
+        (0u8..6)
+            .map(|index| {
+                Rectangle::new(
+                    Point::new(f32::from(index % 3) * 100.0, f32::from(index / 3) * 50.0),
+                    Size::new(80.0, 40.0),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn mapping() {
+        let mapping = Mapping::default();
+        assert_eq!(mapping.get(Button::South), Some(Navigation::Activate));
+        assert_eq!(mapping.get(Button::Start), None);
+
+        let mapping = mapping
+            .with(Button::South, Some(Navigation::Back))
+            .with(Button::East, Some(Navigation::Activate))
+            .with(Button::DPadUp, None);
+        assert_eq!(mapping.get(Button::South), Some(Navigation::Back));
+        assert_eq!(mapping.get(Button::East), Some(Navigation::Activate));
+        assert_eq!(mapping.get(Button::DPadUp), None);
+    }
+
+    #[test]
+    fn stick() {
+        let mut stick = Stick::default();
+        assert_eq!(stick.update(0.1, 0.2), None);
+        assert_eq!(stick.update(0.9, 0.2), Some(Navigation::Right));
+        assert_eq!(stick.update(1.0, 0.0), None);
+        assert_eq!(stick.update(0.0, 0.0), None);
+        assert_eq!(stick.update(0.0, -0.8), Some(Navigation::Down));
+        assert_eq!(stick.update(0.0, 0.1), None);
+        assert_eq!(stick.update(-0.3, 0.7), Some(Navigation::Up));
+    }
+
+    #[test]
+    fn order() {
+        let targets = grid();
+        assert_eq!(next_target(&targets, None, Navigation::Down), Some(0));
+        assert_eq!(next_target(&targets, Some(5), Navigation::Next), Some(0));
+        assert_eq!(
+            next_target(&targets, Some(0), Navigation::Previous),
+            Some(5)
+        );
+        assert_eq!(next_target(&targets, Some(2), Navigation::Back), None);
+        assert_eq!(next_target(&[], None, Navigation::Next), None);
+    }
+
+    #[test]
+    fn directions() {
+        let targets = grid();
+        assert_eq!(next_target(&targets, Some(0), Navigation::Right), Some(1));
+        assert_eq!(next_target(&targets, Some(1), Navigation::Down), Some(4));
+        assert_eq!(next_target(&targets, Some(4), Navigation::Up), Some(1));
+        assert_eq!(next_target(&targets, Some(5), Navigation::Left), Some(4));
+        assert_eq!(next_target(&targets, Some(2), Navigation::Right), Some(2));
+        assert_eq!(next_target(&targets, Some(0), Navigation::Up), Some(0));
+    }
+}
@@ -0,0 +1,310 @@
+//! Schedule the tasks of a Gantt chart and route its dependency links.
+//!
+//! *This API requires the following crate features to be activated: `gantt`*
+use iced_widget::core::{Point, Rectangle};
+
+/// The tolerance of comparing the times of a schedule.
+const EPSILON: f32 = 1e-3;
+
+/// A task of a [`Gantt`](crate::native::gantt::Gantt) chart.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Task {
+    /// The label of the task.
+    pub label: String,
+    /// The time the task starts at, in the units of the chart.
+    pub start: f32,
+    /// The duration of the task, in the units of the chart.
+    pub duration: f32,
+}
+
+impl Task {
+    /// Creates a new [`Task`] with the given label, start and duration.
+    #[must_use]
+    pub fn new(label: impl Into<String>, start: f32, duration: f32) -> Self {
+        Self {
+            label: label.into(),
+            start,
+            duration: duration.max(0.0),
+        }
+    }
+
+    /// Returns the time the task ends at.
+    #[must_use]
+    pub fn end(&self) -> f32 {
+        self.start + self.duration
+    }
+}
+
+/// How a [`Link`] constrains the task it points to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum LinkKind {
+    /// The task starts after the other one finished.
+    #[default]
+    FinishToStart,
+    /// The task starts after the other one started.
+    StartToStart,
+    /// The task finishes after the other one finished.
+    FinishToFinish,
+    /// The task finishes after the other one started.
+    StartToFinish,
+}
+
+impl LinkKind {
+    /// Returns the kind of a link between the given ends of two tasks, which
+    /// are `true` for the finish and `false` for the start of a task.
+    #[must_use]
+    pub fn between(from_finish: bool, to_finish: bool) -> Self {
+        match (from_finish, to_finish) {
+            (true, false) => Self::FinishToStart,
+            (false, false) => Self::StartToStart,
+            (true, true) => Self::FinishToFinish,
+            (false, true) => Self::StartToFinish,
+        }
+    }
+
+    /// Returns whether the link starts at the finish of its first task.
+    #[must_use]
+    pub fn from_finish(self) -> bool {
+        matches!(self, Self::FinishToStart | Self::FinishToFinish)
+    }
+
+    /// Returns whether the link ends at the finish of its second task.
+    #[must_use]
+    pub fn to_finish(self) -> bool {
+        matches!(self, Self::FinishToFinish | Self::StartToFinish)
+    }
+}
+
+/// A dependency between two tasks of a [`Gantt`](crate::native::gantt::Gantt)
+/// chart.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Link {
+    /// The index of the task the link starts at.
+    pub from: usize,
+    /// The index of the task the link points to.
+    pub to: usize,
+    /// How the link constrains the task it points to.
+    pub kind: LinkKind,
+}
+
+impl Link {
+    /// Creates a new [`Link`] of the given kind between the tasks with the
+    /// given indices.
+    #[must_use]
+    pub fn new(from: usize, to: usize, kind: LinkKind) -> Self {
+        Self { from, to, kind }
+    }
+}
+
+/// Returns which of the given tasks are on the critical path, where any
+/// delay delays the end of the whole schedule.
+///
+/// The tasks start no earlier than their start and as early as their links
+/// allow. A task is critical if it can't finish any later without delaying
+/// the last task or breaking a link. Links to missing tasks and links
+/// forming a cycle are ignored.
+#[must_use]
+pub fn critical_path(tasks: &[Task], links: &[Link]) -> Vec<bool> {
+    let links: Vec<&Link> = links
+        .iter()
+        .filter(|link| link.from < tasks.len() && link.to < tasks.len() && link.from != link.to)
+        .collect();
+
+    // The earliest start of every task, relaxed until no link moves it.
+    let mut early: Vec<f32> = tasks.iter().map(|task| task.start).collect();
+    for _ in 0..=tasks.len() {
+        let mut changed = false;
+
+        for link in &links {
+            let (from, to) = (&tasks[link.from], &tasks[link.to]);
+            let start = early[link.from];
+            let bound = match link.kind {
+                LinkKind::FinishToStart => start + from.duration,
+                LinkKind::StartToStart => start,
+                LinkKind::FinishToFinish => start + from.duration - to.duration,
+                LinkKind::StartToFinish => start - to.duration,
+            };
+
+            if bound > early[link.to] + EPSILON {
+                early[link.to] = bound;
+                changed = true;
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    let end = tasks
+        .iter()
+        .zip(&early)
+        .map(|(task, start)| start + task.duration)
+        .fold(f32::NEG_INFINITY, f32::max);
+
+    // The latest finish of every task, relaxed backwards.
+    let mut late: Vec<f32> = vec![end; tasks.len()];
+    for _ in 0..=tasks.len() {
+        let mut changed = false;
+
+        for link in &links {
+            let (from, to) = (&tasks[link.from], &tasks[link.to]);
+            let finish = late[link.to];
+            let bound = match link.kind {
+                LinkKind::FinishToStart => finish - to.duration,
+                LinkKind::StartToStart => finish - to.duration + from.duration,
+                LinkKind::FinishToFinish => finish,
+                LinkKind::StartToFinish => finish + from.duration,
+            };
+
+            if bound < late[link.from] - EPSILON {
+                late[link.from] = bound;
+                changed = true;
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    tasks
+        .iter()
+        .enumerate()
+        .map(|(i, task)| late[i] - (early[i] + task.duration) <= EPSILON)
+        .collect()
+}
+
+/// Returns the corners of an arrow of the given [`LinkKind`] between the
+/// bars of two tasks, leaving and entering the bars horizontally at least
+/// `gap` away from them.
+///
+/// The arrow goes straight down or up between the bars if there is room for
+/// it, or else around the ends of the bars through the middle between them.
+#[must_use]
+pub fn route(from: Rectangle, to: Rectangle, kind: LinkKind, gap: f32) -> Vec<Point> {
+    let (source, out) = if kind.from_finish() {
+        (Point::new(from.x + from.width, from.center_y()), gap)
+    } else {
+        (Point::new(from.x, from.center_y()), -gap)
+    };
+    // The arrow points rightwards into the start of a task and leftwards
+    // into its finish.
+    let (target, into) = if kind.to_finish() {
+        (Point::new(to.x + to.width, to.center_y()), -gap)
+    } else {
+        (Point::new(to.x, to.center_y()), gap)
+    };
+
+    let exit = source.x + out;
+    let entry = target.x - into;
+    let has_room = if into > 0.0 {
+        exit <= target.x - gap / 2.0 && (out > 0.0 || exit <= entry)
+    } else {
+        exit >= target.x + gap / 2.0 && (out < 0.0 || exit >= entry)
+    };
+
+    let mut points = if has_room {
+        vec![
+            source,
+            Point::new(exit, source.y),
+            Point::new(exit, target.y),
+            target,
+        ]
+    } else {
+        let middle = source.y + (target.y - source.y) / 2.0;
+
+        vec![
+            source,
+            Point::new(exit, source.y),
+            Point::new(exit, middle),
+            Point::new(entry, middle),
+            Point::new(entry, target.y),
+            target,
+        ]
+    };
+    points.dedup();
+
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{critical_path, route, Link, LinkKind, Task};
+    use iced_widget::core::{Point, Rectangle, Size};
+
+    #[test]
+    fn critical_path_test() {
+        let tasks = [
+            Task::new("Design", 0.0, 2.0),
+            Task::new("Build", 2.0, 4.0),
+            Task::new("Docs", 2.0, 1.0),
+            Task::new("Release", 6.0, 1.0),
+        ];
+        let links = [
+            Link::new(0, 1, LinkKind::FinishToStart),
+            Link::new(0, 2, LinkKind::FinishToStart),
+            Link::new(1, 3, LinkKind::FinishToStart),
+            Link::new(2, 3, LinkKind::FinishToStart),
+        ];
+
+        // The documentation can slip until the build is done.
+        assert_eq!(critical_path(&tasks, &links), vec![true, true, false, true]);
+
+        // A link delaying the documentation puts it on the critical path.
+        let links = [
+            Link::new(0, 1, LinkKind::FinishToStart),
+            Link::new(1, 2, LinkKind::FinishToFinish),
+            Link::new(2, 3, LinkKind::FinishToStart),
+        ];
+        assert_eq!(critical_path(&tasks, &links), vec![true, true, true, true]);
+
+        // Cycles and links to missing tasks don't hang or panic.
+        let links = [
+            Link::new(0, 1, LinkKind::StartToStart),
+            Link::new(1, 0, LinkKind::StartToStart),
+            Link::new(1, 9, LinkKind::FinishToStart),
+        ];
+        assert_eq!(critical_path(&tasks, &links).len(), 4);
+    }
+
+    #[test]
+    fn route_test() {
+        let bar = |x, y| Rectangle::new(Point::new(x, y), Size::new(40.0, 10.0));
+
+        // The arrow goes down between bars with room between them.
+        assert_eq!(
+            route(bar(0.0, 0.0), bar(60.0, 20.0), LinkKind::FinishToStart, 8.0),
+            vec![
+                Point::new(40.0, 5.0),
+                Point::new(48.0, 5.0),
+                Point::new(48.0, 25.0),
+                Point::new(60.0, 25.0),
+            ]
+        );
+
+        // Or else around the ends of the bars through the middle of them.
+        assert_eq!(
+            route(bar(0.0, 0.0), bar(20.0, 20.0), LinkKind::FinishToStart, 8.0),
+            vec![
+                Point::new(40.0, 5.0),
+                Point::new(48.0, 5.0),
+                Point::new(48.0, 15.0),
+                Point::new(12.0, 15.0),
+                Point::new(12.0, 25.0),
+                Point::new(20.0, 25.0),
+            ]
+        );
+
+        // Links between the finishes enter the bars from the right.
+        assert_eq!(
+            route(bar(0.0, 0.0), bar(0.0, 20.0), LinkKind::FinishToFinish, 8.0),
+            vec![
+                Point::new(40.0, 5.0),
+                Point::new(48.0, 5.0),
+                Point::new(48.0, 25.0),
+                Point::new(40.0, 25.0),
+            ]
+        );
+    }
+}
@@ -0,0 +1,121 @@
+//! Place the values of a gauge on the arc of its dial.
+//!
+//! *This API requires the following crate features to be activated: `gauge`*
+use iced_widget::core::{Color, Point, Vector};
+
+/// The angle in radians the dial starts at, at its bottom left, measured
+/// clockwise from the right.
+pub const START_ANGLE: f32 = std::f32::consts::PI * 0.75;
+
+/// The angle in radians the dial sweeps clockwise from its start to its end,
+/// at its bottom right.
+pub const SWEEP_ANGLE: f32 = std::f32::consts::PI * 1.5;
+
+/// A range of values of a gauge highlighted in a color, e.g. a warning or a
+/// critical zone.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Zone {
+    /// The lowest value of the zone.
+    pub start: f32,
+    /// The highest value of the zone.
+    pub end: f32,
+    /// The color of the zone.
+    pub color: Color,
+}
+
+impl Zone {
+    /// Creates a new [`Zone`] between the given values, in any order.
+    #[must_use]
+    pub fn new(start: f32, end: f32, color: Color) -> Self {
+        Self {
+            start: start.min(end),
+            end: end.max(start),
+            color,
+        }
+    }
+
+    /// Returns whether the given value is in the [`Zone`].
+    #[must_use]
+    pub fn contains(&self, value: f32) -> bool {
+        (self.start..=self.end).contains(&value)
+    }
+}
+
+/// Returns the position of the given value between `min` and `max`, from 0.0
+/// at the start to 1.0 at the end of the dial.
+///
+/// Values outside of the range are kept at the start or the end.
+#[must_use]
+pub fn fraction(value: f32, min: f32, max: f32) -> f32 {
+    if max > min && value.is_finite() {
+        ((value - min) / (max - min)).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+/// Returns the angle in radians of the given value between `min` and `max` on
+/// the dial.
+#[must_use]
+pub fn value_angle(value: f32, min: f32, max: f32) -> f32 {
+    START_ANGLE + SWEEP_ANGLE * fraction(value, min, max)
+}
+
+/// Returns the point at the given distance from the center in the direction
+/// of the given angle.
+#[must_use]
+pub fn dial_point(center: Point, distance: f32, angle: f32) -> Point {
+    center + Vector::new(angle.cos() * distance, angle.sin() * distance)
+}
+
+/// Returns the values of the given number of equal steps between `min` and
+/// `max`, including both.
+#[must_use]
+pub fn tick_values(min: f32, max: f32, steps: usize) -> Vec<f32> {
+    let steps = steps.max(1);
+
+    #[allow(clippy::cast_precision_loss)]
+    (0..=steps)
+        .map(|step| min + (max - min) * step as f32 / steps as f32)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{dial_point, fraction, tick_values, value_angle, Zone, START_ANGLE, SWEEP_ANGLE};
+    use iced_widget::core::{Color, Point};
+
+    #[test]
+    fn value_angle_test() {
+        assert!((value_angle(0.0, 0.0, 100.0) - START_ANGLE).abs() < f32::EPSILON);
+        assert!((value_angle(100.0, 0.0, 100.0) - START_ANGLE - SWEEP_ANGLE).abs() < 1e-5);
+        // The middle of the dial points up.
+        assert!((value_angle(50.0, 0.0, 100.0) - std::f32::consts::PI * 1.5).abs() < 1e-5);
+        assert!((fraction(150.0, 0.0, 100.0) - 1.0).abs() < f32::EPSILON);
+        assert!(fraction(-10.0, 0.0, 100.0).abs() < f32::EPSILON);
+        assert!(fraction(f32::NAN, 0.0, 100.0).abs() < f32::EPSILON);
+        assert!(fraction(5.0, 10.0, 10.0).abs() < f32::EPSILON);
+
+        let top = dial_point(Point::new(10.0, 10.0), 5.0, value_angle(50.0, 0.0, 100.0));
+        assert!((top.x - 10.0).abs() < 1e-5 && (top.y - 5.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn tick_values_test() {
+        assert_eq!(
+            tick_values(0.0, 100.0, 4),
+            vec![0.0, 25.0, 50.0, 75.0, 100.0]
+        );
+        assert_eq!(tick_values(-1.0, 1.0, 0), vec![-1.0, 1.0]);
+    }
+
+    #[test]
+    fn zone_test() {
+        let zone = Zone::new(80.0, 60.0, Color::BLACK);
+
+        assert!((zone.start - 60.0).abs() < f32::EPSILON);
+        assert!((zone.end - 80.0).abs() < f32::EPSILON);
+        assert!(zone.contains(70.0));
+        assert!(!zone.contains(90.0));
+    }
+}
@@ -0,0 +1,366 @@
+//! Project and hit test the outlines of the regions of a map.
+//!
+//! *This API requires the following crate features to be activated: `geo_map`*
+
+/// The westernmost and easternmost longitudes shown on a map.
+pub const LONGITUDES: (f32, f32) = (-180.0, 180.0);
+/// The northernmost and southernmost latitudes shown on a map.
+pub const LATITUDES: (f32, f32) = (85.0, -60.0);
+
+/// A region of a map, like a country, with its outlines.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Region {
+    /// The identifier of the region, e.g. the ISO 3166-1 alpha-3 code of a
+    /// country.
+    pub id: String,
+    /// The name of the region shown in the tooltip.
+    pub name: String,
+    /// The polygons of the outlines of the region, as longitude and latitude
+    /// in degrees.
+    pub polygons: Vec<Vec<(f32, f32)>>,
+}
+
+impl Region {
+    /// Creates a new [`Region`] with the given identifier, name and polygons.
+    #[must_use]
+    pub fn new(
+        id: impl Into<String>,
+        name: impl Into<String>,
+        polygons: Vec<Vec<(f32, f32)>>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            polygons,
+        }
+    }
+
+    /// Returns true if one of the polygons of the [`Region`] contains the
+    /// given longitude and latitude.
+    #[must_use]
+    pub fn contains(&self, longitude: f32, latitude: f32) -> bool {
+        self.polygons
+            .iter()
+            .any(|polygon| contains(polygon, (longitude, latitude)))
+    }
+}
+
+/// Returns true if the polygon contains the point, by casting a ray from the
+/// point and counting the crossed edges.
+fn contains(polygon: &[(f32, f32)], (x, y): (f32, f32)) -> bool {
+    let mut inside = false;
+    let mut previous = match polygon.last() {
+        Some(point) => *point,
+        None => return false,
+    };
+
+    for &(x1, y1) in polygon {
+        let (x0, y0) = previous;
+        if (y1 > y) != (y0 > y) && x < (x0 - x1) * (y - y1) / (y0 - y1) + x1 {
+            inside = !inside;
+        }
+        previous = (x1, y1);
+    }
+
+    inside
+}
+
+/// Projects a longitude and latitude in degrees to a position on the map,
+/// from (0.0, 0.0) at the top left to (1.0, 1.0) at the bottom right.
+///
+/// The projection is equirectangular, keeping the longitudes and latitudes
+/// evenly spaced.
+#[must_use]
+pub fn project(longitude: f32, latitude: f32) -> (f32, f32) {
+    (
+        (longitude - LONGITUDES.0) / (LONGITUDES.1 - LONGITUDES.0),
+        (latitude - LATITUDES.0) / (LATITUDES.1 - LATITUDES.0),
+    )
+}
+
+/// Returns the longitude and latitude in degrees of a position on the map,
+/// inverting [`project`].
+#[must_use]
+pub fn unproject(x: f32, y: f32) -> (f32, f32) {
+    (
+        LONGITUDES.0 + x * (LONGITUDES.1 - LONGITUDES.0),
+        LATITUDES.0 + y * (LATITUDES.1 - LATITUDES.0),
+    )
+}
+
+/// Returns the ratio between the width and the height of the map.
+#[must_use]
+pub fn aspect_ratio() -> f32 {
+    (LONGITUDES.1 - LONGITUDES.0) / (LATITUDES.0 - LATITUDES.1)
+}
+
+/// Returns the embedded outlines of the largest countries of the world,
+/// identified by their ISO 3166-1 alpha-3 codes.
+///
+/// The outlines are strongly simplified to a few dozen points per country,
+/// which is enough for a dashboard overview but not for precise borders.
+/// Small countries and islands are left out.
+#[must_use]
+pub fn world() -> Vec<Region> {
+    COUNTRIES
+        .iter()
+        .map(|(id, name, polygons)| {
+            Region::new(
+                *id,
+                *name,
+                polygons.iter().map(|polygon| polygon.to_vec()).collect(),
+            )
+        })
+        .collect()
+}
+
+/// The identifier, the name and the polygons of the outline of a country.
+type Country = (&'static str, &'static str, &'static [&'static [(f32, f32)]]);
+
+/// The simplified outlines of the countries of [`world`].
+#[rustfmt::skip]
+const COUNTRIES: &[Country] = &[
+    ("USA", "United States", &[
+        &[(-124.7, 48.4), (-123.0, 46.0), (-124.2, 42.0), (-120.5, 34.5), (-117.1, 32.5), (-111.0, 31.3),
+          (-106.5, 31.8), (-103.0, 29.0), (-97.2, 25.9), (-97.4, 27.8), (-94.0, 29.7), (-89.6, 29.9),
+          (-84.0, 30.0), (-82.7, 28.0), (-81.0, 25.2), (-80.0, 26.7), (-81.4, 30.7), (-75.5, 35.2),
+          (-76.0, 38.0), (-74.0, 40.5), (-70.0, 41.7), (-70.6, 43.0), (-67.0, 44.8), (-69.2, 47.4),
+          (-71.5, 45.0), (-75.0, 45.0), (-79.0, 43.4), (-83.0, 42.0), (-83.5, 46.0), (-89.0, 48.0),
+          (-95.2, 49.0)],
+        &[(-141.0, 69.6), (-156.8, 71.3), (-166.0, 68.9), (-164.8, 63.2), (-165.0, 60.5), (-157.0, 58.8),
+          (-164.0, 55.0), (-152.0, 57.9), (-149.0, 60.0), (-141.0, 60.3)],
+    ]),
+    ("CAN", "Canada", &[
+        &[(-141.0, 69.6), (-141.0, 60.3), (-130.0, 55.0), (-133.0, 54.2), (-123.3, 48.3), (-95.2, 49.0),
+          (-89.0, 48.0), (-83.5, 46.0), (-83.0, 42.0), (-79.0, 43.4), (-75.0, 45.0), (-71.5, 45.0),
+          (-69.2, 47.4), (-67.0, 44.8), (-61.0, 45.3), (-64.5, 48.8), (-57.0, 51.5), (-55.7, 52.0),
+          (-61.0, 56.0), (-65.0, 60.0), (-70.0, 58.8), (-77.5, 62.5), (-78.0, 58.0), (-76.7, 55.8),
+          (-79.5, 51.5), (-82.5, 55.0), (-92.5, 57.0), (-94.6, 59.0), (-94.5, 61.8), (-88.0, 64.0),
+          (-87.0, 67.0), (-96.0, 68.2), (-108.0, 68.0), (-115.0, 68.8), (-124.0, 69.3), (-134.0, 69.6)],
+    ]),
+    ("MEX", "Mexico", &[
+        &[(-117.1, 32.5), (-114.7, 32.7), (-111.0, 31.3), (-106.5, 31.8), (-103.0, 29.0), (-97.2, 25.9),
+          (-97.8, 22.3), (-96.0, 19.0), (-94.6, 18.2), (-91.0, 18.8), (-90.4, 21.0), (-87.0, 21.5),
+          (-87.5, 18.5), (-89.1, 17.9), (-91.3, 17.2), (-92.2, 14.5), (-94.5, 16.2), (-96.5, 15.7),
+          (-101.0, 17.3), (-105.5, 20.5), (-105.2, 21.8), (-109.3, 26.5), (-112.5, 29.8), (-114.7, 31.6)],
+    ]),
+    ("BRA", "Brazil", &[
+        &[(-60.0, 5.0), (-51.6, 4.2), (-50.0, 1.8), (-48.5, -1.4), (-44.3, -2.5), (-39.3, -3.0),
+          (-34.9, -7.1), (-35.3, -9.5), (-38.9, -13.4), (-39.2, -17.7), (-40.9, -21.9), (-44.0, -23.0),
+          (-48.5, -26.3), (-48.6, -28.5), (-53.4, -33.7), (-57.6, -30.2), (-53.8, -27.1), (-54.6, -25.5),
+          (-58.2, -20.1), (-57.8, -16.3), (-60.4, -13.5), (-65.3, -10.0), (-70.5, -11.0), (-73.2, -7.3),
+          (-70.0, -4.3), (-69.5, -1.0), (-70.0, 1.5), (-66.8, 1.2), (-64.0, 4.0)],
+    ]),
+    ("ARG", "Argentina", &[
+        &[(-57.6, -30.2), (-58.2, -33.1), (-57.5, -38.2), (-62.3, -38.8), (-65.0, -41.0), (-63.8, -42.1),
+          (-65.3, -44.5), (-67.6, -46.5), (-65.9, -47.8), (-69.0, -50.3), (-68.4, -52.3), (-71.9, -52.0),
+          (-73.2, -50.5), (-72.3, -48.0), (-71.7, -44.0), (-71.4, -40.5), (-70.9, -36.3), (-70.4, -32.0),
+          (-69.7, -28.3), (-68.5, -24.5), (-66.2, -21.8), (-62.8, -22.0), (-58.2, -20.1), (-54.6, -25.5),
+          (-53.8, -27.1)],
+    ]),
+    ("CHL", "Chile", &[
+        &[(-69.7, -18.0), (-70.3, -18.4), (-70.4, -23.5), (-71.4, -30.0), (-71.7, -33.0), (-73.5, -37.0),
+          (-73.5, -41.5), (-74.5, -47.0), (-75.5, -52.0), (-71.9, -52.0), (-73.2, -50.5), (-72.3, -48.0),
+          (-71.7, -44.0), (-71.4, -40.5), (-70.9, -36.3), (-70.4, -32.0), (-69.7, -28.3), (-68.5, -24.5),
+          (-67.2, -22.8), (-68.6, -19.0)],
+    ]),
+    ("GBR", "United Kingdom", &[
+        &[(-5.7, 50.0), (1.4, 51.2), (1.7, 52.7), (0.2, 53.5), (-1.6, 55.6), (-2.1, 57.6), (-3.1, 58.6),
+          (-5.0, 58.6), (-6.2, 56.8), (-5.0, 55.0), (-3.1, 54.9), (-3.3, 53.4), (-4.7, 52.8), (-4.9, 51.7),
+          (-3.0, 51.4)],
+    ]),
+    ("IRL", "Ireland", &[
+        &[(-6.2, 53.9), (-6.0, 52.2), (-8.5, 51.6), (-10.4, 51.8), (-9.9, 53.9), (-8.3, 55.2), (-6.0, 55.1)],
+    ]),
+    ("FRA", "France", &[
+        &[(-1.8, 43.4), (-1.4, 46.0), (-4.7, 48.4), (-1.6, 48.6), (1.6, 50.9), (4.2, 49.9), (8.2, 49.0),
+          (7.6, 47.6), (6.1, 46.2), (7.0, 44.1), (4.2, 43.5), (3.1, 42.4)],
+    ]),
+    ("ESP", "Spain", &[
+        &[(-9.3, 43.2), (-1.8, 43.4), (3.1, 42.4), (3.2, 41.9), (0.8, 41.0), (-0.3, 39.4), (0.2, 38.7),
+          (-2.1, 36.7), (-5.6, 36.0), (-7.4, 37.2), (-7.0, 38.2), (-7.5, 39.6), (-6.9, 41.9), (-8.9, 41.9)],
+    ]),
+    ("PRT", "Portugal", &[
+        &[(-8.9, 41.9), (-6.9, 41.9), (-7.5, 39.6), (-7.0, 38.2), (-7.4, 37.2), (-8.9, 37.0), (-9.5, 38.8),
+          (-8.9, 40.5)],
+    ]),
+    ("DEU", "Germany", &[
+        &[(5.9, 51.0), (6.2, 49.5), (8.2, 49.0), (7.6, 47.6), (9.6, 47.5), (13.0, 47.5), (13.8, 48.6),
+          (12.1, 50.3), (14.8, 51.0), (14.2, 53.9), (11.0, 54.0), (8.6, 55.0), (7.0, 53.4), (7.1, 52.2)],
+    ]),
+    ("ITA", "Italy", &[
+        &[(7.5, 43.8), (6.6, 45.1), (7.0, 45.9), (9.0, 46.0), (10.5, 46.9), (12.4, 47.1), (13.7, 46.5),
+          (13.6, 45.6), (12.3, 45.3), (12.4, 44.2), (13.6, 43.5), (14.7, 42.0), (16.1, 41.4), (18.5, 40.1),
+          (16.8, 39.2), (15.6, 38.0), (15.8, 40.0), (14.0, 40.8), (12.3, 41.8), (10.5, 42.9), (10.2, 43.9),
+          (8.8, 44.4)],
+    ]),
+    ("POL", "Poland", &[
+        &[(14.2, 53.9), (14.8, 51.0), (18.8, 49.5), (22.6, 49.1), (24.0, 50.7), (23.5, 52.0), (23.5, 53.9),
+          (19.6, 54.4), (18.6, 54.8), (16.0, 54.3)],
+    ]),
+    ("NOR", "Norway", &[
+        &[(7.0, 58.0), (10.5, 59.2), (11.4, 59.0), (12.4, 60.9), (12.1, 61.7), (14.5, 65.3), (18.0, 68.5),
+          (20.6, 69.1), (25.0, 68.6), (28.9, 69.8), (31.0, 70.3), (25.5, 71.1), (19.0, 70.1), (14.0, 68.3),
+          (11.2, 64.8), (5.4, 62.3), (5.0, 60.0), (5.7, 58.9)],
+    ]),
+    ("SWE", "Sweden", &[
+        &[(11.1, 58.9), (12.7, 56.2), (14.3, 55.4), (16.4, 56.6), (16.5, 57.9), (18.8, 59.3), (17.3, 60.7),
+          (17.4, 62.5), (20.4, 63.8), (22.2, 65.7), (24.0, 65.9), (23.9, 67.0), (20.6, 69.1), (18.0, 68.5),
+          (14.5, 65.3), (12.1, 61.7), (12.4, 60.9), (11.4, 59.0)],
+    ]),
+    ("FIN", "Finland", &[
+        &[(24.0, 65.9), (21.5, 63.2), (21.4, 60.9), (23.0, 59.9), (27.0, 60.5), (29.4, 61.6), (31.5, 62.9),
+          (29.6, 64.2), (30.0, 67.7), (29.0, 69.7), (28.9, 69.8), (25.0, 68.6), (20.6, 69.1), (23.9, 67.0)],
+    ]),
+    ("TUR", "Turkey", &[
+        &[(26.0, 40.8), (26.5, 41.8), (28.0, 42.0), (31.4, 41.2), (36.0, 41.6), (41.5, 41.5), (43.6, 41.1),
+          (44.8, 39.7), (44.2, 37.9), (42.3, 37.2), (36.7, 36.8), (36.2, 36.0), (32.5, 36.1), (29.7, 36.1),
+          (27.2, 37.3), (26.3, 38.2), (26.8, 39.0)],
+    ]),
+    ("RUS", "Russia", &[
+        &[(31.0, 70.3), (29.0, 69.7), (30.0, 67.7), (29.6, 64.2), (31.5, 62.9), (28.0, 60.5), (27.7, 57.8),
+          (28.2, 56.2), (31.8, 52.1), (34.4, 51.3), (38.2, 50.0), (40.0, 48.8), (39.5, 47.2), (38.0, 46.5),
+          (40.0, 43.5), (45.5, 42.5), (48.0, 42.0), (47.5, 45.5), (46.7, 48.5), (50.5, 51.6), (55.7, 50.6),
+          (61.0, 50.8), (61.5, 52.5), (68.0, 54.9), (76.5, 54.0), (87.3, 49.2), (97.0, 50.0), (108.0, 49.5),
+          (116.7, 49.9), (120.5, 53.0), (126.0, 52.8), (133.0, 48.4), (134.7, 48.2), (131.0, 42.9),
+          (133.2, 42.8), (141.0, 48.5), (141.0, 52.0), (140.3, 54.0), (135.0, 54.7), (138.0, 56.5),
+          (150.0, 59.5), (155.0, 59.2), (156.6, 51.0), (162.0, 56.0), (163.6, 59.8), (172.0, 60.4),
+          (180.0, 64.5), (180.0, 69.0), (170.0, 70.0), (160.8, 69.6), (150.0, 71.5), (139.0, 71.5),
+          (128.0, 72.0), (113.0, 73.8), (105.0, 77.7), (98.8, 76.2), (88.5, 75.3), (80.0, 73.5),
+          (68.5, 76.9), (66.0, 69.5), (60.5, 69.8), (53.7, 68.8), (44.0, 68.5), (43.3, 66.0), (37.0, 66.9),
+          (41.0, 67.5), (33.0, 69.3)],
+    ]),
+    ("KAZ", "Kazakhstan", &[
+        &[(46.7, 48.5), (50.5, 51.6), (55.7, 50.6), (61.0, 50.8), (61.5, 52.5), (68.0, 54.9), (76.5, 54.0),
+          (87.3, 49.2), (85.7, 47.2), (82.5, 45.3), (80.2, 42.2), (74.5, 43.0), (70.0, 42.5), (66.0, 42.9),
+          (62.0, 43.5), (58.5, 45.6), (55.9, 45.0), (53.0, 42.0), (52.7, 45.2), (49.0, 46.5)],
+    ]),
+    ("MNG", "Mongolia", &[
+        &[(87.3, 49.2), (90.6, 47.7), (90.9, 45.3), (95.3, 44.3), (96.3, 42.7), (105.0, 41.6), (111.9, 43.7),
+          (115.5, 48.0), (117.8, 49.5), (116.7, 49.9), (108.0, 49.5), (97.0, 50.0)],
+    ]),
+    ("CHN", "China", &[
+        &[(73.5, 39.5), (75.0, 37.3), (78.0, 35.5), (79.0, 32.5), (81.0, 30.2), (86.0, 28.0), (88.9, 27.3),
+          (92.0, 27.5), (97.0, 28.2), (98.7, 25.9), (97.7, 24.0), (100.1, 21.5), (101.8, 22.4), (106.7, 22.8),
+          (108.0, 21.6), (110.4, 21.2), (111.0, 21.5), (113.6, 22.2), (117.0, 23.6), (119.5, 25.6),
+          (121.7, 28.2), (122.0, 30.9), (120.3, 34.4), (119.2, 35.0), (122.5, 37.0), (121.0, 37.8),
+          (117.7, 38.8), (121.1, 40.9), (121.6, 39.4), (124.3, 39.9), (126.9, 41.8), (130.6, 42.4),
+          (131.0, 42.9), (134.7, 48.2), (133.0, 48.4), (126.0, 52.8), (120.5, 53.0), (116.7, 49.9),
+          (117.8, 49.5), (115.5, 48.0), (111.9, 43.7), (105.0, 41.6), (96.3, 42.7), (95.3, 44.3),
+          (90.9, 45.3), (90.6, 47.7), (87.3, 49.2), (85.7, 47.2), (82.5, 45.3), (80.2, 42.2), (74.5, 40.5)],
+    ]),
+    ("IND", "India", &[
+        &[(68.2, 23.7), (68.8, 24.3), (71.0, 24.4), (70.0, 28.0), (74.5, 31.0), (74.4, 34.7), (78.0, 35.5),
+          (79.0, 32.5), (81.0, 30.2), (80.0, 28.8), (84.0, 27.4), (88.1, 26.5), (88.9, 27.3), (92.0, 27.5),
+          (97.0, 28.2), (97.3, 27.0), (94.6, 24.7), (93.2, 22.3), (92.5, 23.7), (91.6, 22.9), (92.0, 25.2),
+          (89.9, 25.3), (88.1, 22.0), (86.9, 21.4), (85.0, 19.4), (82.4, 17.0), (80.3, 15.9), (79.9, 10.4),
+          (77.5, 8.0), (76.3, 10.0), (74.6, 14.7), (73.0, 19.0), (72.8, 21.0), (70.5, 20.8), (69.0, 22.4)],
+    ]),
+    ("IRN", "Iran", &[
+        &[(44.1, 39.4), (48.0, 38.9), (49.0, 37.8), (53.9, 37.2), (56.2, 37.9), (59.3, 37.5), (61.1, 36.5),
+          (60.5, 33.7), (60.9, 29.8), (61.8, 28.7), (63.2, 27.2), (61.7, 25.4), (57.3, 25.8), (54.7, 26.5),
+          (51.5, 27.9), (50.1, 30.1), (48.6, 29.9), (47.7, 31.0), (46.1, 33.0), (45.4, 35.9), (44.2, 37.9)],
+    ]),
+    ("SAU", "Saudi Arabia", &[
+        &[(34.6, 28.1), (37.0, 31.5), (38.8, 32.0), (42.0, 31.1), (44.7, 29.2), (47.7, 28.5), (48.4, 28.5),
+          (50.1, 26.7), (51.6, 24.2), (55.7, 22.7), (55.0, 20.0), (52.0, 19.0), (48.7, 18.0), (46.8, 17.3),
+          (43.4, 17.5), (42.8, 16.4), (41.2, 18.7), (39.0, 21.3), (38.6, 23.5), (35.6, 27.4)],
+    ]),
+    ("JPN", "Japan", &[
+        &[(130.9, 34.0), (132.5, 35.4), (135.9, 35.6), (137.0, 37.0), (139.8, 38.8), (140.0, 40.5),
+          (141.4, 41.3), (142.0, 39.5), (140.9, 37.0), (140.8, 35.7), (139.8, 35.0), (138.8, 34.6),
+          (136.8, 34.3), (135.1, 33.9), (133.3, 34.4), (131.0, 33.9)],
+        &[(140.0, 41.5), (141.6, 42.6), (143.2, 42.0), (145.5, 43.3), (144.8, 44.0), (141.7, 45.4),
+          (141.3, 43.3), (140.3, 43.2)],
+        &[(129.7, 33.2), (130.9, 33.9), (131.8, 32.3), (130.6, 31.1), (130.2, 32.6)],
+    ]),
+    ("IDN", "Indonesia", &[
+        &[(95.3, 5.5), (97.5, 5.2), (100.4, 2.3), (104.2, -1.0), (106.0, -3.1), (105.8, -5.9), (104.6, -5.9),
+          (102.6, -4.2), (100.3, -0.9), (98.6, 1.8)],
+        &[(105.2, -6.8), (106.1, -6.0), (108.5, -6.4), (112.6, -6.9), (114.6, -7.8), (113.0, -8.4),
+          (108.7, -7.7), (106.5, -7.4)],
+        &[(109.0, 1.3), (110.0, -1.5), (111.5, -3.0), (114.5, -3.9), (116.5, -3.0), (117.5, 0.0),
+          (117.8, 4.2), (115.5, 4.2), (114.0, 1.5), (109.7, 2.0)],
+    ]),
+    ("AUS", "Australia", &[
+        &[(113.4, -22.0), (114.0, -26.5), (115.0, -34.3), (118.0, -35.0), (123.5, -33.9), (129.0, -31.7),
+          (131.5, -31.5), (135.8, -34.8), (137.8, -32.6), (138.5, -35.6), (140.6, -38.0), (143.5, -38.8),
+          (146.3, -39.1), (150.0, -37.5), (151.3, -33.9), (153.6, -28.2), (153.1, -25.5), (149.7, -22.3),
+          (146.3, -18.9), (145.4, -14.8), (142.5, -10.7), (141.6, -12.9), (141.5, -17.0), (140.2, -17.7),
+          (137.1, -15.9), (135.5, -14.7), (136.9, -12.2), (132.7, -11.5), (130.1, -13.0), (129.4, -14.9),
+          (126.1, -14.1), (122.2, -17.7), (121.3, -19.5), (117.5, -20.7)],
+    ]),
+    ("NZL", "New Zealand", &[
+        &[(172.7, -34.4), (174.3, -35.3), (176.0, -37.6), (178.5, -37.7), (177.9, -39.2), (176.8, -40.2),
+          (175.2, -41.6), (174.6, -39.9), (173.8, -39.3), (174.6, -38.1), (174.6, -36.9)],
+        &[(172.7, -40.5), (174.3, -41.7), (173.3, -43.5), (171.2, -44.4), (169.3, -46.6), (166.5, -46.0),
+          (167.0, -45.0), (168.3, -44.0), (170.6, -43.0), (172.1, -41.4)],
+    ]),
+    ("MAR", "Morocco", &[
+        &[(-1.8, 35.1), (-1.7, 33.0), (-5.0, 30.0), (-8.7, 28.8), (-8.7, 27.7), (-13.2, 27.7), (-9.8, 29.9),
+          (-9.6, 32.5), (-6.8, 34.0), (-5.9, 35.8)],
+    ]),
+    ("DZA", "Algeria", &[
+        &[(-1.8, 35.1), (3.2, 36.8), (8.6, 36.9), (8.3, 34.6), (9.5, 30.3), (9.3, 26.1), (10.0, 25.3),
+          (11.9, 23.5), (5.7, 19.6), (4.3, 19.2), (3.2, 19.0), (1.8, 20.6), (-4.9, 25.0), (-8.7, 27.3),
+          (-8.7, 28.8), (-5.0, 30.0), (-1.7, 33.0)],
+    ]),
+    ("LBY", "Libya", &[
+        &[(25.0, 31.6), (25.0, 22.0), (25.0, 20.0), (24.0, 19.5), (15.9, 23.4), (14.1, 22.5), (11.9, 23.5),
+          (10.0, 25.3), (9.3, 26.1), (9.9, 30.5), (11.5, 33.1), (15.3, 32.3), (20.0, 31.0), (20.1, 32.2),
+          (23.3, 32.2)],
+    ]),
+    ("EGY", "Egypt", &[
+        &[(25.0, 31.6), (29.0, 30.9), (32.3, 31.3), (34.2, 31.3), (34.9, 29.5), (33.9, 27.6), (35.7, 23.9),
+          (36.9, 22.0), (25.0, 22.0)],
+    ]),
+    ("NGA", "Nigeria", &[
+        &[(2.7, 6.3), (2.7, 9.0), (3.6, 11.7), (4.1, 13.5), (6.8, 13.1), (9.0, 12.8), (13.3, 13.6),
+          (14.6, 12.1), (14.2, 9.5), (12.6, 8.6), (11.9, 7.0), (9.9, 7.0), (8.5, 4.6), (7.0, 4.4), (5.6, 4.9),
+          (4.3, 6.3)],
+    ]),
+    ("ZAF", "South Africa", &[
+        &[(16.5, -28.6), (18.2, -32.6), (18.4, -34.2), (20.0, -34.8), (22.5, -34.0), (25.9, -33.8),
+          (28.2, -32.8), (30.7, -30.3), (32.9, -26.9), (31.9, -25.4), (31.3, -22.4), (29.4, -22.1),
+          (27.1, -23.6), (25.6, -25.5), (22.8, -25.5), (20.0, -24.8), (20.0, -28.4), (17.4, -28.8)],
+    ]),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::{project, unproject, world, Region};
+
+    #[test]
+    fn contains_test() {
+        let square = Region::new(
+            "SQ",
+            "Square",
+            vec![vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)]],
+        );
+
+        assert!(square.contains(5.0, 5.0));
+        assert!(!square.contains(15.0, 5.0));
+        assert!(!square.contains(5.0, -5.0));
+
+        let world = world();
+        let country = |longitude, latitude| {
+            world
+                .iter()
+                .find(|region| region.contains(longitude, latitude))
+                .map(|region| region.id.as_str())
+        };
+
+        // Paris, Berlin, the outback and the middle of the Atlantic.
+        assert_eq!(country(2.3, 48.9), Some("FRA"));
+        assert_eq!(country(13.4, 52.5), Some("DEU"));
+        assert_eq!(country(135.0, -25.0), Some("AUS"));
+        assert_eq!(country(-30.0, 30.0), None);
+    }
+
+    #[test]
+    fn project_test() {
+        assert_eq!(project(-180.0, 85.0), (0.0, 0.0));
+        assert_eq!(project(180.0, -60.0), (1.0, 1.0));
+        assert_eq!(unproject(0.5, 0.0), (0.0, 85.0));
+    }
+}
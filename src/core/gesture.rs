@@ -0,0 +1,384 @@
+//! Recognize high-level gestures from raw mouse and touch events.
+//!
+//! *This API requires the following crate features to be activated: `gesture_detector`*
+use iced_widget::core::{Point, Vector};
+use std::time::{Duration, Instant};
+
+/// A gesture recognized from raw mouse and touch events.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Gesture {
+    /// A short press and release at the position.
+    Tap(Point),
+    /// A second tap shortly after and close to a first tap at the position.
+    ///
+    /// The first tap is still reported as a [`Gesture::Tap`].
+    DoubleTap(Point),
+    /// A press held in place at the position.
+    LongPress(Point),
+    /// A fast movement in the direction, with the velocity of the pointer in
+    /// pixels per second.
+    Swipe {
+        /// The dominant direction of the swipe.
+        direction: SwipeDirection,
+        /// The velocity of the pointer in pixels per second.
+        velocity: Vector,
+    },
+    /// Two pointers moving apart or together around the center.
+    Pinch {
+        /// The center between the two pointers.
+        center: Point,
+        /// The change of the distance between the two pointers since the last
+        /// [`Gesture::Pinch`], e.g. `1.1` for a 10% zoom in.
+        scale: f32,
+    },
+}
+
+/// The direction of a [`Gesture::Swipe`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SwipeDirection {
+    /// Towards the left.
+    Left,
+    /// Towards the right.
+    Right,
+    /// Upwards.
+    Up,
+    /// Downwards.
+    Down,
+}
+
+/// The thresholds of the [`Recognizer`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Thresholds {
+    /// How far a pointer can move while still tapping or long pressing.
+    pub slop: f32,
+    /// The longest time between two taps of a double tap.
+    pub double_tap: Duration,
+    /// How long a pointer has to be held for a long press.
+    pub long_press: Duration,
+    /// The shortest distance of a swipe.
+    pub swipe_distance: f32,
+    /// The lowest velocity of a swipe in pixels per second.
+    pub swipe_velocity: f32,
+}
+
+impl Thresholds {
+    /// The default [`Thresholds`].
+    pub const DEFAULT: Self = Self {
+        slop: 10.0,
+        double_tap: Duration::from_millis(300),
+        long_press: Duration::from_millis(500),
+        swipe_distance: 50.0,
+        swipe_velocity: 300.0,
+    };
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// A pointer pressed on the [`Recognizer`].
+#[derive(Clone, Copy, Debug)]
+struct Pointer {
+    /// The id of the pointer.
+    id: u64,
+    /// Where the pointer was pressed.
+    start: Point,
+    /// When the pointer was pressed.
+    pressed_at: Instant,
+    /// The current position of the pointer.
+    position: Point,
+}
+
+/// A state machine turning pointer presses, movements and lifts into
+/// [`Gesture`]s.
+#[derive(Debug, Default)]
+pub struct Recognizer {
+    /// The pressed pointers.
+    pointers: Vec<Pointer>,
+    /// Whether the current sequence moved beyond the slop, or used several
+    /// pointers, and can no longer be a tap or long press.
+    moved: bool,
+    /// Whether the long press of the current sequence was recognized.
+    long_pressed: bool,
+    /// The position and time of the last tap.
+    last_tap: Option<(Point, Instant)>,
+}
+
+impl Recognizer {
+    /// Creates a new [`Recognizer`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            pointers: Vec::new(),
+            moved: false,
+            long_pressed: false,
+            last_tap: None,
+        }
+    }
+
+    /// Returns whether a pointer is pressed.
+    #[must_use]
+    pub fn is_pressed(&self) -> bool {
+        !self.pointers.is_empty()
+    }
+
+    /// Presses the pointer with the given id at the given position.
+    pub fn press(&mut self, id: u64, position: Point, now: Instant) {
+        if self.pointers.is_empty() {
+            self.moved = false;
+            self.long_pressed = false;
+        } else {
+            self.moved = true;
+        }
+
+        self.pointers.retain(|pointer| pointer.id != id);
+        self.pointers.push(Pointer {
+            id,
+            start: position,
+            pressed_at: now,
+            position,
+        });
+    }
+
+    /// Moves the pointer with the given id to the given position, returning
+    /// a [`Gesture::Pinch`] if two pointers are pressed.
+    pub fn moved(&mut self, id: u64, position: Point, thresholds: &Thresholds) -> Option<Gesture> {
+        let index = self.pointers.iter().position(|pointer| pointer.id == id)?;
+        let previous = self.pointers[index].position;
+        self.pointers[index].position = position;
+
+        if self.pointers[index].start.distance(position) > thresholds.slop {
+            self.moved = true;
+        }
+
+        match self.pointers.as_slice() {
+            [first, second] => {
+                let other = if index == 0 { second } else { first };
+                let before = previous.distance(other.position);
+                let after = position.distance(other.position);
+
+                (before > 0.0).then(|| Gesture::Pinch {
+                    center: position + (other.position - position) * 0.5,
+                    scale: after / before,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Lifts the pointer with the given id at the given position, returning
+    /// the tap, double tap or swipe it completes.
+    pub fn lift(
+        &mut self,
+        id: u64,
+        position: Point,
+        now: Instant,
+        thresholds: &Thresholds,
+    ) -> Option<Gesture> {
+        let index = self.pointers.iter().position(|pointer| pointer.id == id)?;
+        let pointer = self.pointers.remove(index);
+
+        if !self.pointers.is_empty() {
+            return None;
+        }
+
+        let delta = position - pointer.start;
+        let elapsed = now.duration_since(pointer.pressed_at).as_secs_f32();
+        let distance = pointer.start.distance(position);
+
+        if self.long_pressed {
+            None
+        } else if !self.moved && distance <= thresholds.slop {
+            match self.last_tap.take() {
+                Some((last, at))
+                    if now.duration_since(at) <= thresholds.double_tap
+                        && last.distance(position) <= thresholds.slop =>
+                {
+                    Some(Gesture::DoubleTap(position))
+                }
+                _ => {
+                    self.last_tap = Some((position, now));
+                    Some(Gesture::Tap(position))
+                }
+            }
+        } else if distance >= thresholds.swipe_distance
+            && elapsed > 0.0
+            && distance / elapsed >= thresholds.swipe_velocity
+        {
+            let direction = if delta.x.abs() >= delta.y.abs() {
+                if delta.x < 0.0 {
+                    SwipeDirection::Left
+                } else {
+                    SwipeDirection::Right
+                }
+            } else if delta.y < 0.0 {
+                SwipeDirection::Up
+            } else {
+                SwipeDirection::Down
+            };
+
+            Some(Gesture::Swipe {
+                direction,
+                velocity: delta * (1.0 / elapsed),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Cancels the current sequence, e.g. when a touch is lost.
+    pub fn cancel(&mut self) {
+        self.pointers.clear();
+        self.moved = false;
+        self.long_pressed = false;
+    }
+
+    /// Returns when the pressed pointer becomes a long press, if it can.
+    #[must_use]
+    pub fn long_press_deadline(&self, thresholds: &Thresholds) -> Option<Instant> {
+        match self.pointers.as_slice() {
+            [pointer] if !self.moved && !self.long_pressed => {
+                Some(pointer.pressed_at + thresholds.long_press)
+            }
+            _ => None,
+        }
+    }
+
+    /// Updates the [`Recognizer`] to the given time, returning a
+    /// [`Gesture::LongPress`] once the pressed pointer was held long enough.
+    pub fn tick(&mut self, now: Instant, thresholds: &Thresholds) -> Option<Gesture> {
+        let deadline = self.long_press_deadline(thresholds)?;
+
+        (now >= deadline).then(|| {
+            self.long_pressed = true;
+            self.last_tap = None;
+            Gesture::LongPress(self.pointers[0].start)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Gesture, Recognizer, SwipeDirection, Thresholds};
+    use iced_widget::core::Point;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn tap_test() {
+        let thresholds = Thresholds::default();
+        let mut recognizer = Recognizer::new();
+        let now = Instant::now();
+        let position = Point::new(10.0, 10.0);
+
+        recognizer.press(0, position, now);
+        assert_eq!(
+            recognizer.lift(0, position, now + Duration::from_millis(50), &thresholds),
+            Some(Gesture::Tap(position))
+        );
+
+        recognizer.press(0, position, now + Duration::from_millis(100));
+        assert_eq!(
+            recognizer.lift(0, position, now + Duration::from_millis(150), &thresholds),
+            Some(Gesture::DoubleTap(position))
+        );
+
+        recognizer.press(0, position, now + Duration::from_secs(1));
+        assert_eq!(
+            recognizer.lift(0, position, now + Duration::from_millis(1050), &thresholds),
+            Some(Gesture::Tap(position))
+        );
+    }
+
+    #[test]
+    fn long_press_test() {
+        let thresholds = Thresholds::default();
+        let mut recognizer = Recognizer::new();
+        let now = Instant::now();
+        let position = Point::new(10.0, 10.0);
+
+        recognizer.press(0, position, now);
+        assert_eq!(
+            recognizer.tick(now + Duration::from_millis(100), &thresholds),
+            None
+        );
+        assert_eq!(
+            recognizer.tick(now + Duration::from_millis(500), &thresholds),
+            Some(Gesture::LongPress(position))
+        );
+        assert_eq!(
+            recognizer.tick(now + Duration::from_millis(600), &thresholds),
+            None
+        );
+        assert_eq!(
+            recognizer.lift(0, position, now + Duration::from_millis(700), &thresholds),
+            None
+        );
+
+        recognizer.press(0, position, now);
+        let _ = recognizer.moved(0, Point::new(30.0, 10.0), &thresholds);
+        assert_eq!(recognizer.long_press_deadline(&thresholds), None);
+    }
+
+    #[test]
+    fn swipe_test() {
+        let thresholds = Thresholds::default();
+        let mut recognizer = Recognizer::new();
+        let now = Instant::now();
+
+        recognizer.press(0, Point::new(100.0, 10.0), now);
+        let _ = recognizer.moved(0, Point::new(50.0, 15.0), &thresholds);
+        let gesture = recognizer.lift(
+            0,
+            Point::new(0.0, 20.0),
+            now + Duration::from_millis(100),
+            &thresholds,
+        );
+        assert!(matches!(
+            gesture,
+            Some(Gesture::Swipe {
+                direction: SwipeDirection::Left,
+                ..
+            })
+        ));
+
+        recognizer.press(0, Point::new(100.0, 10.0), now);
+        let _ = recognizer.moved(0, Point::new(50.0, 15.0), &thresholds);
+        assert_eq!(
+            recognizer.lift(
+                0,
+                Point::new(0.0, 20.0),
+                now + Duration::from_secs(2),
+                &thresholds
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn pinch_test() {
+        let thresholds = Thresholds::default();
+        let mut recognizer = Recognizer::new();
+        let now = Instant::now();
+
+        recognizer.press(0, Point::new(0.0, 0.0), now);
+        recognizer.press(1, Point::new(100.0, 0.0), now);
+
+        assert_eq!(
+            recognizer.moved(1, Point::new(200.0, 0.0), &thresholds),
+            Some(Gesture::Pinch {
+                center: Point::new(100.0, 0.0),
+                scale: 2.0,
+            })
+        );
+        assert_eq!(
+            recognizer.lift(1, Point::new(200.0, 0.0), now, &thresholds),
+            None
+        );
+        assert_eq!(
+            recognizer.lift(0, Point::new(0.0, 0.0), now, &thresholds),
+            None
+        );
+    }
+}
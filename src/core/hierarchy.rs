@@ -0,0 +1,390 @@
+//! Lay out hierarchical size data as nested tiles or rings.
+//!
+//! *This API requires the following crate features to be activated: `treemap` or `sunburst`*
+use iced_widget::core::{Point, Rectangle, Size};
+
+/// A node of hierarchical size data shown by a
+/// [`Treemap`](crate::native::treemap::Treemap) or a
+/// [`Sunburst`](crate::native::sunburst::Sunburst).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HierarchyNode {
+    /// The label of the node.
+    pub label: String,
+    /// The size of the node, which is only used if it has no children.
+    pub value: f32,
+    /// The children of the node.
+    pub children: Vec<Self>,
+}
+
+impl HierarchyNode {
+    /// Creates a new [`HierarchyNode`] of the given size without children.
+    #[must_use]
+    pub fn new(label: impl Into<String>, value: f32) -> Self {
+        Self {
+            label: label.into(),
+            value,
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the children of the [`HierarchyNode`].
+    #[must_use]
+    pub fn children(mut self, children: impl IntoIterator<Item = Self>) -> Self {
+        self.children = children.into_iter().collect();
+        self
+    }
+
+    /// Returns the size of the node, which is the sum of the sizes of its
+    /// children if it has any.
+    #[must_use]
+    pub fn total(&self) -> f32 {
+        if self.children.is_empty() {
+            self.value.max(0.0)
+        } else {
+            self.children.iter().map(Self::total).sum()
+        }
+    }
+
+    /// Returns the descendant at the given path of child indices.
+    #[must_use]
+    pub fn get(&self, path: &[usize]) -> Option<&Self> {
+        path.iter()
+            .try_fold(self, |node, &index| node.children.get(index))
+    }
+}
+
+/// A tile of a [`HierarchyNode`] laid out by [`tiles`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tile {
+    /// The path of child indices from the laid out node to the node of the
+    /// tile.
+    pub path: Vec<usize>,
+    /// The bounds of the tile.
+    pub bounds: Rectangle,
+}
+
+/// Lays out the descendants of the node as nested tiles in the given bounds,
+/// down to the given number of levels.
+///
+/// The tiles of a level are sized by the totals of their nodes with
+/// [`squarify`]. The children of a tile are laid out inside of it, below a
+/// header of the given height and inside of the given padding. The tiles
+/// are ordered from the outermost to the innermost ones.
+#[must_use]
+pub fn tiles(
+    node: &HierarchyNode,
+    bounds: Rectangle,
+    levels: usize,
+    header: f32,
+    padding: f32,
+) -> Vec<Tile> {
+    let mut tiles = Vec::new();
+    push_tiles(
+        node,
+        bounds,
+        levels,
+        header,
+        padding,
+        &mut Vec::new(),
+        &mut tiles,
+    );
+
+    tiles
+}
+
+/// Pushes the tiles of the descendants of the node below the given path.
+fn push_tiles(
+    node: &HierarchyNode,
+    bounds: Rectangle,
+    levels: usize,
+    header: f32,
+    padding: f32,
+    path: &mut Vec<usize>,
+    tiles: &mut Vec<Tile>,
+) {
+    if levels == 0 {
+        return;
+    }
+
+    let totals: Vec<f32> = node.children.iter().map(HierarchyNode::total).collect();
+
+    for (index, (child, rectangle)) in node
+        .children
+        .iter()
+        .zip(squarify(&totals, bounds))
+        .enumerate()
+    {
+        if rectangle.width <= 0.0 || rectangle.height <= 0.0 {
+            continue;
+        }
+
+        path.push(index);
+        tiles.push(Tile {
+            path: path.clone(),
+            bounds: rectangle,
+        });
+
+        let inner = Rectangle {
+            x: rectangle.x + padding,
+            y: rectangle.y + header,
+            width: rectangle.width - padding * 2.0,
+            height: rectangle.height - header - padding,
+        };
+        if !child.children.is_empty() && inner.width > 0.0 && inner.height > 0.0 {
+            push_tiles(child, inner, levels - 1, header, padding, path, tiles);
+        }
+        let _ = path.pop();
+    }
+}
+
+/// Divides the given bounds into rectangles with the areas of the given
+/// values, keeping them as close to squares as possible.
+///
+/// The rectangles are returned in the order of the values. Values that
+/// aren't positive get an empty rectangle.
+#[must_use]
+pub fn squarify(values: &[f32], bounds: Rectangle) -> Vec<Rectangle> {
+    let mut rectangles = vec![Rectangle::new(bounds.position(), Size::ZERO); values.len()];
+    let total: f32 = values.iter().map(|value| value.max(0.0)).sum();
+
+    if total <= 0.0 || bounds.width <= 0.0 || bounds.height <= 0.0 {
+        return rectangles;
+    }
+
+    let scale = bounds.width * bounds.height / total;
+    let mut order: Vec<usize> = (0..values.len()).filter(|&i| values[i] > 0.0).collect();
+    order.sort_by(|&a, &b| values[b].total_cmp(&values[a]));
+
+    let mut free = bounds;
+    let mut row: Vec<usize> = Vec::new();
+    let mut next = 0;
+
+    // The values are added to the row along the shorter side of the free
+    // space as long as that improves its worst aspect ratio.
+    while next < order.len() {
+        let side = free.width.min(free.height);
+        let areas = |row: &[usize]| row.iter().map(|&i| values[i] * scale).collect::<Vec<_>>();
+        let mut extended = row.clone();
+        extended.push(order[next]);
+
+        if row.is_empty() || worst(&areas(&extended), side) <= worst(&areas(&row), side) {
+            row = extended;
+            next += 1;
+        } else {
+            free = lay_row(&areas(&row), &row, free, &mut rectangles);
+            row.clear();
+        }
+    }
+    if !row.is_empty() {
+        let areas: Vec<f32> = row.iter().map(|&i| values[i] * scale).collect();
+        let _ = lay_row(&areas, &row, free, &mut rectangles);
+    }
+
+    rectangles
+}
+
+/// Returns the worst aspect ratio of a row of the given areas along a side
+/// of the given length.
+fn worst(areas: &[f32], side: f32) -> f32 {
+    let sum: f32 = areas.iter().sum();
+    let max = areas.iter().copied().fold(f32::MIN, f32::max);
+    let min = areas.iter().copied().fold(f32::MAX, f32::min);
+
+    (side * side * max / (sum * sum)).max(sum * sum / (side * side * min))
+}
+
+/// Lays out a row of the given areas along the shorter side of the free
+/// space, returning the space left.
+fn lay_row(
+    areas: &[f32],
+    row: &[usize],
+    free: Rectangle,
+    rectangles: &mut [Rectangle],
+) -> Rectangle {
+    let sum: f32 = areas.iter().sum();
+    let mut position = free.position();
+
+    if free.width >= free.height {
+        let width = sum / free.height;
+
+        for (&index, area) in row.iter().zip(areas) {
+            let height = area / width;
+            rectangles[index] = Rectangle::new(position, Size::new(width, height));
+            position.y += height;
+        }
+
+        Rectangle {
+            x: free.x + width,
+            width: free.width - width,
+            ..free
+        }
+    } else {
+        let height = sum / free.width;
+
+        for (&index, area) in row.iter().zip(areas) {
+            let width = area / height;
+            rectangles[index] = Rectangle::new(position, Size::new(width, height));
+            position.x += width;
+        }
+
+        Rectangle {
+            y: free.y + height,
+            height: free.height - height,
+            ..free
+        }
+    }
+}
+
+/// A segment of a ring of a [`HierarchyNode`] partitioned by [`partition`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Segment {
+    /// The path of child indices from the partitioned node to the node of the
+    /// segment, whose length is the ring of the segment counted from the
+    /// innermost ring at 1.
+    pub path: Vec<usize>,
+    /// The start of the segment, as the fraction of a full turn.
+    pub start: f32,
+    /// The end of the segment, as the fraction of a full turn.
+    pub end: f32,
+}
+
+/// Partitions the full turn between the descendants of the node by their
+/// totals, with one ring per level down to the given number of rings.
+///
+/// The segments of the children of a node span the segment of the node.
+/// The segments are ordered from the innermost to the outermost ring.
+#[must_use]
+pub fn partition(node: &HierarchyNode, rings: usize) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut level: Vec<(&HierarchyNode, Vec<usize>, f32, f32)> = vec![(node, Vec::new(), 0.0, 1.0)];
+
+    for _ in 0..rings {
+        let mut next = Vec::new();
+
+        for (parent, path, start, end) in level {
+            let total = parent.total();
+            if total <= 0.0 {
+                continue;
+            }
+
+            let mut angle = start;
+            for (index, child) in parent.children.iter().enumerate() {
+                let sweep = (end - start) * child.total() / total;
+                if sweep <= 0.0 {
+                    continue;
+                }
+
+                let mut path = path.clone();
+                path.push(index);
+                segments.push(Segment {
+                    path: path.clone(),
+                    start: angle,
+                    end: angle + sweep,
+                });
+                next.push((child, path, angle, angle + sweep));
+                angle += sweep;
+            }
+        }
+
+        level = next;
+    }
+
+    segments
+}
+
+/// Returns the point at the given fraction of a full turn on a circle,
+/// starting at the top and turning clockwise.
+#[must_use]
+pub fn polar(center: Point, radius: f32, turn: f32) -> Point {
+    let angle = (turn - 0.25) * std::f32::consts::TAU;
+
+    Point::new(
+        center.x + radius * angle.cos(),
+        center.y + radius * angle.sin(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{partition, squarify, tiles, HierarchyNode};
+    use iced_widget::core::{Point, Rectangle, Size};
+
+    fn data() -> HierarchyNode {
+        HierarchyNode::new("Root", 0.0).children([
+            HierarchyNode::new("A", 0.0)
+                .children([HierarchyNode::new("A1", 2.0), HierarchyNode::new("A2", 4.0)]),
+            HierarchyNode::new("B", 2.0),
+            HierarchyNode::new("C", 0.0),
+        ])
+    }
+
+    #[test]
+    fn total_test() {
+        let root = data();
+
+        assert!((root.total() - 8.0).abs() < f32::EPSILON);
+        assert_eq!(
+            root.get(&[0, 1]).map(|node| node.label.as_str()),
+            Some("A2")
+        );
+        assert_eq!(root.get(&[3]), None);
+    }
+
+    #[test]
+    fn squarify_test() {
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(6.0, 4.0));
+        let values = [6.0, 6.0, 4.0, 3.0, 2.0, 2.0, 1.0];
+        let rectangles = squarify(&values, bounds);
+
+        // Every rectangle has the area of its value, inside of the bounds.
+        for (value, rectangle) in values.iter().zip(&rectangles) {
+            assert!((rectangle.width * rectangle.height - value).abs() < 1e-3);
+            assert!(rectangle.x >= -1e-3 && rectangle.y >= -1e-3);
+            assert!(rectangle.x + rectangle.width <= 6.0 + 1e-3);
+            assert!(rectangle.y + rectangle.height <= 4.0 + 1e-3);
+        }
+
+        // The two largest ones fill the shorter side as squares.
+        assert_eq!((rectangles[0].width, rectangles[0].height), (3.0, 2.0));
+
+        let empty = squarify(&[0.0, -1.0], bounds);
+        assert!(empty
+            .iter()
+            .all(|rectangle| rectangle.area() < f32::EPSILON));
+    }
+
+    #[test]
+    fn tiles_test() {
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(80.0, 40.0));
+        let nested = tiles(&data(), bounds, 2, 10.0, 2.0);
+        let paths: Vec<Vec<usize>> = nested.iter().map(|tile| tile.path.clone()).collect();
+
+        // The empty node gets no tile, and the children of A are inside of it.
+        assert_eq!(paths, vec![vec![0], vec![0, 0], vec![0, 1], vec![1]]);
+        assert!(nested[0].bounds.contains(nested[1].bounds.position()));
+        assert!(nested[1].bounds.y >= nested[0].bounds.y + 10.0);
+
+        let top = tiles(&data(), bounds, 1, 10.0, 2.0);
+        assert_eq!(top.len(), 2);
+    }
+
+    #[test]
+    fn partition_test() {
+        let segments = partition(&data(), 2);
+        let spans: Vec<(Vec<usize>, f32, f32)> = segments
+            .into_iter()
+            .map(|segment| (segment.path, segment.start, segment.end))
+            .collect();
+
+        assert_eq!(
+            spans,
+            vec![
+                (vec![0], 0.0, 0.75),
+                (vec![1], 0.75, 1.0),
+                (vec![0, 0], 0.0, 0.25),
+                (vec![0, 1], 0.25, 0.75),
+            ]
+        );
+        assert_eq!(partition(&data(), 1).len(), 2);
+    }
+}
@@ -0,0 +1,158 @@
+//! Count values in the bins of a histogram.
+//!
+//! *This API requires the following crate features to be activated: `histogram`*
+
+/// The number of values falling into each of a number of equally wide bins
+/// dividing a range of values, shown by a
+/// [`Histogram`](crate::native::histogram::Histogram).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Bins {
+    /// The value the first bin starts at.
+    start: f32,
+    /// The value the last bin ends at.
+    end: f32,
+    /// The number of values of each bin.
+    counts: Vec<usize>,
+}
+
+impl Bins {
+    /// Counts the given values in the given number of bins dividing the range
+    /// from the smallest to the largest finite value.
+    #[must_use]
+    pub fn new(values: impl IntoIterator<Item = f32>, count: usize) -> Self {
+        let values: Vec<f32> = values
+            .into_iter()
+            .filter(|value| value.is_finite())
+            .collect();
+        let (start, end) = values
+            .iter()
+            .fold(None, |range: Option<(f32, f32)>, &value| {
+                let (min, max) = range.unwrap_or((value, value));
+                Some((min.min(value), max.max(value)))
+            })
+            .unwrap_or((0.0, 1.0));
+
+        // A single distinct value gets a bin around it.
+        let (start, end) = if start < end {
+            (start, end)
+        } else {
+            (start - 0.5, end + 0.5)
+        };
+
+        Self::with_range(values, start, end, count)
+    }
+
+    /// Counts the given values in the given number of bins dividing the range
+    /// from `start` to `end`, ignoring the values outside of it.
+    #[must_use]
+    pub fn with_range(
+        values: impl IntoIterator<Item = f32>,
+        start: f32,
+        end: f32,
+        count: usize,
+    ) -> Self {
+        let mut bins = Self {
+            start: start.min(end),
+            end: start.max(end),
+            counts: vec![0; count.max(1)],
+        };
+
+        for value in values {
+            if let Some(index) = bins.bin_of(value) {
+                bins.counts[index] += 1;
+            }
+        }
+
+        bins
+    }
+
+    /// Returns the number of bins.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Returns whether there are no bins, which is never the case.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Returns the number of values of each bin.
+    #[must_use]
+    pub fn counts(&self) -> &[usize] {
+        &self.counts
+    }
+
+    /// Returns the largest number of values of a bin.
+    #[must_use]
+    pub fn max_count(&self) -> usize {
+        self.counts.iter().copied().max().unwrap_or(0)
+    }
+
+    /// Returns the value the first bin starts at and the value the last bin
+    /// ends at.
+    #[must_use]
+    pub fn range(&self) -> (f32, f32) {
+        (self.start, self.end)
+    }
+
+    /// Returns the width of a bin.
+    #[must_use]
+    pub fn bin_width(&self) -> f32 {
+        #[allow(clippy::cast_precision_loss)]
+        let len = self.counts.len() as f32;
+        (self.end - self.start) / len
+    }
+
+    /// Returns the values the bins from `first` to `last` start and end at.
+    #[must_use]
+    pub fn edges(&self, first: usize, last: usize) -> (f32, f32) {
+        let (first, last) = (first.min(last), first.max(last));
+
+        #[allow(clippy::cast_precision_loss)]
+        let edge = |index: usize| self.start + index as f32 * self.bin_width();
+
+        (edge(first), edge(last + 1).min(self.end))
+    }
+
+    /// Returns the index of the bin the given value falls into, with the end
+    /// of the range falling into the last bin.
+    #[must_use]
+    pub fn bin_of(&self, value: f32) -> Option<usize> {
+        if !(self.start..=self.end).contains(&value) {
+            return None;
+        }
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let index = ((value - self.start) / self.bin_width()) as usize;
+
+        Some(index.min(self.counts.len() - 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Bins;
+
+    #[test]
+    fn bins_test() {
+        let bins = Bins::new([0.0, 1.0, 1.5, 2.5, 4.0, f32::NAN], 4);
+
+        assert_eq!(bins.range(), (0.0, 4.0));
+        assert_eq!(bins.counts(), &[1, 2, 1, 1]);
+        assert_eq!(bins.max_count(), 2);
+        assert_eq!(bins.edges(1, 2), (1.0, 3.0));
+        assert_eq!(bins.edges(3, 1), (1.0, 4.0));
+        assert_eq!(bins.bin_of(4.0), Some(3));
+        assert_eq!(bins.bin_of(-1.0), None);
+
+        // The values outside of the range are ignored.
+        let bins = Bins::with_range([-1.0, 0.5, 9.0], 0.0, 2.0, 2);
+        assert_eq!(bins.counts(), &[1, 0]);
+
+        // A single distinct value gets a bin around it.
+        let bins = Bins::new([3.0, 3.0], 1);
+        assert_eq!((bins.range(), bins.counts()), ((2.5, 3.5), &[2][..]));
+    }
+}
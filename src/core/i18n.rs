@@ -0,0 +1,475 @@
+//! Translate the built-in texts of the widgets, e.g. button labels,
+//! placeholders and empty states, so that the crate can be used in
+//! non-English products.
+//!
+//! The [`Translations`] trait provides every built-in text, in English by
+//! default. An application overrides the texts of its language and
+//! installs its translations once with [`set`]:
+//!
+//! ```ignore
+//! # use iced_aw::i18n::{self, Translations};
+//! #
+//! struct German;
+//!
+//! impl Translations for German {
+//!     fn cancel(&self) -> String {
+//!         String::from("Abbrechen")
+//!     }
+//!
+//!     fn match_position(&self, current: usize, total: usize) -> String {
+//!         format!("{current} von {total}")
+//!     }
+//! }
+//!
+//! i18n::set(German);
+//! ```
+//!
+//! The widgets read the [`Translations`] when they are created, so the texts
+//! change with the next `view`. Texts set on a widget with its builders
+//! always win over the [`Translations`].
+
+use std::sync::{Arc, RwLock};
+
+/// The built-in texts of the widgets.
+///
+/// Every text is English by default, so an implementation only overrides
+/// the texts it translates.
+///
+/// Symbols and icons are the same in every language and are not
+/// translated: the code (`<>`), bulleted list (`•`) and numbered list (`1.`)
+/// buttons of the rich editor, the icons of the buttons, the keys of the
+/// letters of an on-screen keyboard, which follow its layout, and the
+/// channel letters of a color picker, e.g. `R:` and `H:`.
+pub trait Translations {
+    /// The label of the buttons cancelling a dialog.
+    fn cancel(&self) -> String {
+        String::from("Cancel")
+    }
+
+    /// The label of the buttons submitting a dialog.
+    fn submit(&self) -> String {
+        String::from("Submit")
+    }
+
+    /// The label of the buttons going to the previous page.
+    fn back(&self) -> String {
+        String::from("Back")
+    }
+
+    /// The label of the buttons going to the next page.
+    fn next(&self) -> String {
+        String::from("Next")
+    }
+
+    /// The label of the button finishing a wizard.
+    fn finish(&self) -> String {
+        String::from("Finish")
+    }
+
+    /// The label of the button skipping an onboarding.
+    fn skip(&self) -> String {
+        String::from("Skip")
+    }
+
+    /// The default call to action of the last page of an onboarding.
+    fn get_started(&self) -> String {
+        String::from("Get started")
+    }
+
+    /// The placeholder of the query field of a find bar.
+    fn find(&self) -> String {
+        String::from("Find")
+    }
+
+    /// The placeholder of the replacement field of a find bar, and the label
+    /// of the button replacing the current match.
+    fn replace(&self) -> String {
+        String::from("Replace")
+    }
+
+    /// The label of the button replacing all matches of a find bar.
+    fn replace_all(&self) -> String {
+        String::from("Replace all")
+    }
+
+    /// The counter of a find bar without any match.
+    fn no_matches(&self) -> String {
+        String::from("No matches")
+    }
+
+    /// The counter of a find bar with the given number of matches, none of
+    /// them being current.
+    fn match_count(&self, count: usize) -> String {
+        if count == 1 {
+            String::from("1 match")
+        } else {
+            format!("{count} matches")
+        }
+    }
+
+    /// The counter of a find bar at the given one-based current match.
+    fn match_position(&self, current: usize, total: usize) -> String {
+        format!("{current} of {total}")
+    }
+
+    /// The counter of a find bar whose regular expression is invalid.
+    fn invalid_pattern(&self, error: &str) -> String {
+        format!("Invalid pattern: {error}")
+    }
+
+    /// The default title of a notification center.
+    fn notifications(&self) -> String {
+        String::from("Notifications")
+    }
+
+    /// The default empty state of a notification center.
+    fn no_notifications(&self) -> String {
+        String::from("No notifications")
+    }
+
+    /// The label of the button marking all notifications as read.
+    fn mark_all_as_read(&self) -> String {
+        String::from("Mark all as read")
+    }
+
+    /// The label of the button clearing all notifications.
+    fn clear_all(&self) -> String {
+        String::from("Clear all")
+    }
+
+    /// The group of the notifications received in the last hour.
+    fn last_hour(&self) -> String {
+        String::from("Last hour")
+    }
+
+    /// The group of the notifications received in the last 24 hours.
+    fn last_day(&self) -> String {
+        String::from("Last 24 hours")
+    }
+
+    /// The group of the notifications received in the last 7 days.
+    fn last_week(&self) -> String {
+        String::from("Last 7 days")
+    }
+
+    /// The group of the older notifications.
+    fn older(&self) -> String {
+        String::from("Older")
+    }
+
+    /// The time of a notification received less than a minute ago.
+    fn now(&self) -> String {
+        String::from("now")
+    }
+
+    /// The time of a notification received the given minutes ago.
+    fn minutes_ago(&self, minutes: u64) -> String {
+        format!("{minutes} min ago")
+    }
+
+    /// The time of a notification received the given hours ago.
+    fn hours_ago(&self, hours: u64) -> String {
+        format!("{hours} h ago")
+    }
+
+    /// The time of a notification received the given days ago.
+    fn days_ago(&self, days: u64) -> String {
+        format!("{days} d ago")
+    }
+
+    /// The default singular noun of the items counted by a badge.
+    fn item(&self) -> String {
+        String::from("item")
+    }
+
+    /// The default plural noun of the items counted by a badge.
+    fn items(&self) -> String {
+        String::from("items")
+    }
+
+    /// The text describing the count of a badge to assistive technologies,
+    /// given the singular and plural nouns of the counted items.
+    fn count(&self, count: usize, singular: &str, plural: &str) -> String {
+        match count {
+            0 => format!("No {plural}"),
+            1 => format!("1 {singular}"),
+            _ => format!("{count} {plural}"),
+        }
+    }
+
+    /// The default placeholder of a shortcut recorder without a shortcut.
+    fn record_shortcut(&self) -> String {
+        String::from("Click to record a shortcut")
+    }
+
+    /// The prompt of a recording shortcut recorder.
+    fn press_shortcut(&self) -> String {
+        String::from("Press a shortcut")
+    }
+
+    /// The error of a shortcut recorder rejecting a reserved shortcut.
+    fn shortcut_reserved(&self) -> String {
+        String::from("is reserved")
+    }
+
+    /// The error of a shortcut recorder rejecting a shortcut without a
+    /// modifier.
+    fn shortcut_needs_modifier(&self) -> String {
+        String::from("needs a modifier")
+    }
+
+    /// The placeholder of the search field of a settings page.
+    fn search_settings(&self) -> String {
+        String::from("Search settings")
+    }
+
+    /// The empty state of a settings page without a matching setting.
+    fn no_matching_settings(&self) -> String {
+        String::from("No matching settings")
+    }
+
+    /// The default label of the identifier of an authentication form.
+    fn email(&self) -> String {
+        String::from("Email")
+    }
+
+    /// The label of the password of an authentication form.
+    fn password(&self) -> String {
+        String::from("Password")
+    }
+
+    /// The label of the remember me checkbox of an authentication form.
+    fn remember_me(&self) -> String {
+        String::from("Remember me")
+    }
+
+    /// The default label of the submit button of an authentication form.
+    fn sign_in(&self) -> String {
+        String::from("Sign in")
+    }
+
+    /// The label of the foreground swatch of a contrast checker.
+    fn foreground(&self) -> String {
+        String::from("Text")
+    }
+
+    /// The label of the background swatch of a contrast checker.
+    fn background(&self) -> String {
+        String::from("Background")
+    }
+
+    /// The badge of a contrast checker for the given WCAG level, e.g. `AA`,
+    /// of large or normal texts.
+    fn contrast_badge(&self, level: &str, large: bool, passed: bool) -> String {
+        format!(
+            "{level} {} {}",
+            if large { "large" } else { "normal" },
+            if passed { "pass" } else { "fail" }
+        )
+    }
+
+    /// The label of the button exporting the palette of a theme editor as
+    /// Rust code.
+    fn export_rust(&self) -> String {
+        String::from("Export Rust")
+    }
+
+    /// The label of the button exporting the palette of a theme editor as
+    /// TOML.
+    fn export_toml(&self) -> String {
+        String::from("Export TOML")
+    }
+
+    /// The label of the key submitting the number of a numeric keypad.
+    fn ok(&self) -> String {
+        String::from("OK")
+    }
+
+    /// The default header of the given zero-based column of an editable
+    /// grid.
+    fn column(&self, column: usize) -> String {
+        format!("Column {}", column + 1)
+    }
+
+    /// The name of the given month, from 1 for January to 12 for December,
+    /// shown in the header of a date picker.
+    fn month(&self, month: u32) -> String {
+        const MONTHS: [&str; 12] = [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ];
+
+        MONTHS
+            .get(month.wrapping_sub(1) as usize)
+            .map_or_else(String::new, |name| (*name).to_owned())
+    }
+
+    /// The abbreviation of the given weekday, from 0 for Monday to 6 for
+    /// Sunday, shown above the days of a date picker.
+    fn weekday(&self, weekday: u32) -> String {
+        const WEEKDAYS: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+        WEEKDAYS
+            .get(weekday as usize)
+            .map_or_else(String::new, |name| (*name).to_owned())
+    }
+
+    /// The abbreviation of a week, shown above the week numbers of a date
+    /// picker.
+    fn week(&self) -> String {
+        String::from("Wk")
+    }
+
+    /// The label of the start of a range of times picked by a time picker.
+    fn range_start(&self) -> String {
+        String::from("Start")
+    }
+
+    /// The label of the end of a range of times picked by a time picker.
+    fn range_end(&self) -> String {
+        String::from("End")
+    }
+
+    /// The label of the hours picked by a duration picker.
+    fn hours(&self) -> String {
+        String::from("Hours")
+    }
+
+    /// The label of the minutes picked by a duration picker.
+    fn minutes(&self) -> String {
+        String::from("Minutes")
+    }
+
+    /// The label of the seconds picked by a duration picker.
+    fn seconds(&self) -> String {
+        String::from("Seconds")
+    }
+
+    /// The abbreviation of the morning, after a time of a 12 hour clock.
+    fn am(&self) -> String {
+        String::from("AM")
+    }
+
+    /// The abbreviation of the afternoon, after a time of a 12 hour clock.
+    fn pm(&self) -> String {
+        String::from("PM")
+    }
+
+    /// The label of the toolbar button of a rich editor making text bold.
+    fn bold(&self) -> String {
+        String::from("B")
+    }
+
+    /// The label of the toolbar button of a rich editor making text italic.
+    fn italic(&self) -> String {
+        String::from("I")
+    }
+
+    /// The label of the toolbar button of a rich editor underlining text.
+    fn underline(&self) -> String {
+        String::from("U")
+    }
+
+    /// The label of the toolbar button of a rich editor linking text.
+    fn link(&self) -> String {
+        String::from("Link")
+    }
+
+    /// The label of the key of an on-screen keyboard showing the letters.
+    fn letters_page(&self) -> String {
+        String::from("ABC")
+    }
+
+    /// The label of the key of an on-screen keyboard showing the digits and
+    /// symbols.
+    fn symbols_page(&self) -> String {
+        String::from("?123")
+    }
+}
+
+/// The English [`Translations`], used until others are [`set`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct English;
+
+impl Translations for English {}
+
+/// The installed [`Translations`], if any.
+static TRANSLATIONS: RwLock<Option<Arc<dyn Translations + Send + Sync>>> = RwLock::new(None);
+
+/// Installs the given [`Translations`] for all widgets created afterwards.
+pub fn set(translations: impl Translations + Send + Sync + 'static) {
+    if let Ok(mut installed) = TRANSLATIONS.write() {
+        *installed = Some(Arc::new(translations));
+    }
+}
+
+/// Gets the installed [`Translations`], or the [`English`] ones.
+#[must_use]
+pub fn get() -> Arc<dyn Translations + Send + Sync> {
+    TRANSLATIONS
+        .read()
+        .ok()
+        .and_then(|installed| installed.clone())
+        .unwrap_or_else(|| Arc::new(English))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{English, Translations};
+
+    struct German;
+
+    impl Translations for German {
+        fn cancel(&self) -> String {
+            String::from("Abbrechen")
+        }
+
+        fn match_position(&self, current: usize, total: usize) -> String {
+            format!("{current} von {total}")
+        }
+
+        fn month(&self, month: u32) -> String {
+            match month {
+                3 => String::from("März"),
+                _ => English.month(month),
+            }
+        }
+    }
+
+    #[test]
+    fn english_test() {
+        assert_eq!(English.cancel(), "Cancel");
+        assert_eq!(English.match_count(1), "1 match");
+        assert_eq!(English.match_count(3), "3 matches");
+        assert_eq!(English.match_position(2, 5), "2 of 5");
+        assert_eq!(English.contrast_badge("AA", true, false), "AA large fail");
+        assert_eq!(English.count(0, "item", "items"), "No items");
+        assert_eq!(English.month(1), "January");
+        assert_eq!(English.month(12), "December");
+        assert_eq!(English.month(13), "");
+        assert_eq!(English.weekday(0), "Mo");
+        assert_eq!(English.weekday(6), "Su");
+        assert_eq!(English.pm(), "PM");
+        assert_eq!(English.link(), "Link");
+        assert_eq!(English.symbols_page(), "?123");
+    }
+
+    #[test]
+    fn override_test() {
+        assert_eq!(German.cancel(), "Abbrechen");
+        assert_eq!(German.match_position(2, 5), "2 von 5");
+        assert_eq!(German.next(), "Next");
+        assert_eq!(German.month(3), "März");
+        assert_eq!(German.month(4), "April");
+    }
+}
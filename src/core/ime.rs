@@ -0,0 +1,129 @@
+//! Report the caret of text-entry surfaces, so that the candidate window of
+//! an input method editor (IME) can be shown next to the edited text.
+//!
+//! The focused text-entry surfaces of this crate report their [`Caret`] to
+//! the [`Operation`]s going through them, and [`caret`] produces a
+//! [`Command`] finding it. iced does not place the candidate window by
+//! itself, so applications driving their own event loop pass the [`Caret`]
+//! on to the windowing system, e.g. to `winit`'s
+//! `Window::set_ime_cursor_area`.
+//!
+//! Other widgets report their caret with [`Caret::report`] in their
+//! [`Widget::operate`](iced_widget::core::Widget::operate).
+
+use iced_widget::{
+    core::{
+        widget::{
+            operation::{Operation, Outcome},
+            Id,
+        },
+        Point, Rectangle,
+    },
+    runtime::Command,
+};
+use std::any::Any;
+
+/// The area of the caret of a focused text-entry surface, in the same
+/// coordinates as the cursor.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Caret {
+    /// The bounds of the caret, one line high.
+    pub bounds: Rectangle,
+}
+
+impl Caret {
+    /// Creates a new [`Caret`] at the given top left position with the given
+    /// line height.
+    #[must_use]
+    pub fn new(position: Point, line_height: f32) -> Self {
+        Self {
+            bounds: Rectangle {
+                x: position.x,
+                y: position.y,
+                width: 1.0,
+                height: line_height,
+            },
+        }
+    }
+
+    /// Reports the [`Caret`] to the [`Operation`].
+    pub fn report<Message>(mut self, operation: &mut dyn Operation<Message>) {
+        operation.custom(&mut self, None);
+    }
+}
+
+/// Produces a [`Command`] finding the [`Caret`] of the focused text-entry
+/// surface, if any.
+pub fn caret<Message, F>(on_caret: F) -> Command<Message>
+where
+    Message: 'static,
+    F: 'static + Fn(Option<Caret>) -> Message,
+{
+    Command::widget(FindCaret {
+        on_caret: Box::new(on_caret),
+        caret: None,
+    })
+}
+
+/// The [`Operation`] finding the [`Caret`] of the focused text-entry surface.
+struct FindCaret<Message> {
+    /// The function producing the message with the found caret.
+    on_caret: Box<dyn Fn(Option<Caret>) -> Message>,
+    /// The found caret.
+    caret: Option<Caret>,
+}
+
+impl<Message: 'static> Operation<Message> for FindCaret<Message> {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<Message>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, _id: Option<&Id>) {
+        if let Some(caret) = state.downcast_ref::<Caret>() {
+            self.caret = Some(*caret);
+        }
+    }
+
+    fn finish(&self) -> Outcome<Message> {
+        Outcome::Some((self.on_caret)(self.caret))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_widget::core::{
+        widget::operation::{Operation, Outcome},
+        Point, Rectangle,
+    };
+
+    use super::{Caret, FindCaret};
+
+    #[test]
+    fn find_caret() {
+        let mut operation = FindCaret {
+            on_caret: Box::new(|caret: Option<Caret>| caret.map(|caret| caret.bounds)),
+            caret: None,
+        };
+        assert!(matches!(operation.finish(), Outcome::Some(None)));
+
+        let caret = Caret::new(Point::new(10.0, 20.0), 16.0);
+        operation.container(None, Rectangle::default(), &mut |operation| {
+            caret.report(operation);
+        });
+
+        assert!(matches!(
+            operation.finish(),
+            Outcome::Some(Some(bounds)) if bounds == Rectangle {
+                x: 10.0,
+                y: 20.0,
+                width: 1.0,
+                height: 16.0,
+            }
+        ));
+    }
+}
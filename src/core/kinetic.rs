@@ -0,0 +1,304 @@
+//! Scroll custom scroll widgets kinetically by touch, with flings and
+//! rubber-band overscroll.
+use iced_widget::core::{Point, Vector};
+use std::time::{Duration, Instant};
+
+/// How far a pointer can move before a press becomes a drag.
+const SLOP: f32 = 6.0;
+
+/// How long the movements of a drag are taken into account for the velocity
+/// of the fling.
+const VELOCITY_WINDOW: Duration = Duration::from_millis(100);
+
+/// How fast a fling slows down, per second.
+const FRICTION: f32 = 4.0;
+
+/// The velocity below which a fling stops, in pixels per second.
+const MIN_VELOCITY: f32 = 20.0;
+
+/// How fast an overscrolled offset springs back, per second.
+const SPRING: f32 = 12.0;
+
+/// The overscroll at which dragging further has half of its effect.
+const RUBBER_BAND_EXTENT: f32 = 100.0;
+
+/// The kinetic scroll offset of a custom scroll widget.
+///
+/// A pointer drags the offset like the content follows a finger, resisting
+/// when dragged beyond the scrollable range. Releasing the pointer flings
+/// the offset with its velocity, slowing down with friction, and springs it
+/// back into the scrollable range.
+#[derive(Clone, Debug, Default)]
+pub struct Kinetic {
+    /// The scroll offset, beyond the scrollable range while overscrolled.
+    offset: Vector,
+    /// The velocity of the fling, in pixels per second.
+    velocity: Vector,
+    /// The last position of the dragging pointer.
+    pointer: Option<Point>,
+    /// Where the pointer was pressed.
+    origin: Point,
+    /// Whether the pointer moved beyond the slop.
+    dragged: bool,
+    /// The recent movements of the offset and their time.
+    samples: Vec<(Instant, Vector)>,
+    /// The time of the last step of the fling.
+    last_tick: Option<Instant>,
+}
+
+impl Kinetic {
+    /// Creates a new [`Kinetic`] scroll offset.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            offset: Vector::new(0.0, 0.0),
+            velocity: Vector::new(0.0, 0.0),
+            pointer: None,
+            origin: Point::ORIGIN,
+            dragged: false,
+            samples: Vec::new(),
+            last_tick: None,
+        }
+    }
+
+    /// Gets the scroll offset, beyond the scrollable range while
+    /// overscrolled.
+    #[must_use]
+    pub fn offset(&self) -> Vector {
+        self.offset
+    }
+
+    /// Sets the scroll offset, stopping any fling.
+    pub fn set_offset(&mut self, offset: Vector) {
+        self.offset = offset;
+        self.velocity = Vector::new(0.0, 0.0);
+        self.last_tick = None;
+    }
+
+    /// Returns whether a pointer drags the offset.
+    #[must_use]
+    pub fn is_dragging(&self) -> bool {
+        self.pointer.is_some()
+    }
+
+    /// Returns whether the offset is flung or springs back, and
+    /// [`Kinetic::tick`] has to be called on the next frame.
+    #[must_use]
+    pub fn is_animating(&self) -> bool {
+        self.last_tick.is_some()
+    }
+
+    /// Grabs the offset with a pointer pressed at the given position,
+    /// stopping any fling.
+    pub fn grab(&mut self, position: Point) {
+        self.pointer = Some(position);
+        self.origin = position;
+        self.dragged = false;
+        self.samples.clear();
+        self.velocity = Vector::new(0.0, 0.0);
+        self.last_tick = None;
+    }
+
+    /// Drags the offset with the pointer moved to the given position, within
+    /// the scrollable range from zero to the given maximum.
+    pub fn drag(&mut self, position: Point, max: Vector, now: Instant) {
+        let Some(last) = self.pointer.replace(position) else {
+            return;
+        };
+
+        if !self.dragged && self.origin.distance(position) <= SLOP {
+            return;
+        }
+        self.dragged = true;
+
+        let delta = last - position;
+        let offset = Vector::new(
+            resist(self.offset.x, delta.x, max.x),
+            resist(self.offset.y, delta.y, max.y),
+        );
+
+        self.samples.push((now, offset - self.offset));
+        self.samples
+            .retain(|(time, _)| now.duration_since(*time) <= VELOCITY_WINDOW);
+        self.offset = offset;
+    }
+
+    /// Releases the pointer, flinging the offset with its recent velocity.
+    ///
+    /// Returns whether the pointer dragged the offset, or only tapped.
+    pub fn release(&mut self, now: Instant) -> bool {
+        if self.pointer.take().is_none() {
+            return false;
+        }
+
+        let recent: Vec<_> = self
+            .samples
+            .drain(..)
+            .filter(|(time, _)| now.duration_since(*time) <= VELOCITY_WINDOW)
+            .collect();
+
+        self.velocity = match (recent.first(), recent.last()) {
+            (Some((first, _)), Some((last, _))) if last > first => {
+                let distance = recent
+                    .iter()
+                    .skip(1)
+                    .fold(Vector::new(0.0, 0.0), |sum, (_, delta)| sum + *delta);
+
+                distance * (1.0 / last.duration_since(*first).as_secs_f32())
+            }
+            _ => Vector::new(0.0, 0.0),
+        };
+        self.last_tick = Some(now);
+
+        self.dragged
+    }
+
+    /// Advances the fling and the spring back to the given time, within the
+    /// scrollable range from zero to the given maximum.
+    ///
+    /// Returns whether the offset is still moving.
+    pub fn tick(&mut self, max: Vector, now: Instant) -> bool {
+        let Some(last_tick) = self.last_tick else {
+            return false;
+        };
+        let elapsed = now.duration_since(last_tick).as_secs_f32();
+
+        let (x, velocity_x) = step(self.offset.x, self.velocity.x, max.x, elapsed);
+        let (y, velocity_y) = step(self.offset.y, self.velocity.y, max.y, elapsed);
+
+        self.offset = Vector::new(x, y);
+        self.velocity = Vector::new(velocity_x, velocity_y);
+
+        let moving = velocity_x != 0.0
+            || velocity_y != 0.0
+            || !(0.0..=max.x.max(0.0)).contains(&x)
+            || !(0.0..=max.y.max(0.0)).contains(&y);
+
+        self.last_tick = moving.then_some(now);
+        moving
+    }
+}
+
+/// Moves the offset by the delta, resisting beyond the range from zero to the
+/// maximum like a rubber band.
+fn resist(offset: f32, delta: f32, max: f32) -> f32 {
+    let max = max.max(0.0);
+    let moved = offset + delta;
+
+    let overscroll = if moved < 0.0 {
+        -moved
+    } else if moved > max {
+        moved - max
+    } else {
+        return moved;
+    };
+
+    // Only the part of the movement beyond the range is resisted.
+    let inside = if offset < 0.0 || offset > max {
+        0.0
+    } else if moved < 0.0 {
+        -offset
+    } else {
+        max - offset
+    };
+
+    offset + inside + (delta - inside) / (1.0 + overscroll / RUBBER_BAND_EXTENT)
+}
+
+/// Advances the offset on one axis by the elapsed seconds, returning the new
+/// offset and velocity.
+fn step(offset: f32, velocity: f32, max: f32, elapsed: f32) -> (f32, f32) {
+    let max = max.max(0.0);
+    let clamped = offset.clamp(0.0, max);
+
+    if !(0.0..=max).contains(&offset) {
+        // Overscrolled: spring back and stop the fling.
+        let offset = clamped + (offset - clamped) * (-SPRING * elapsed).exp();
+        let offset = if (offset - clamped).abs() < 0.5 {
+            clamped
+        } else {
+            offset
+        };
+
+        return (offset, 0.0);
+    }
+
+    let velocity = velocity * (-FRICTION * elapsed).exp();
+    if velocity.abs() < MIN_VELOCITY {
+        return (offset, 0.0);
+    }
+
+    (offset + velocity * elapsed, velocity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Kinetic;
+    use iced_widget::core::{Point, Vector};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn tap_test() {
+        let mut kinetic = Kinetic::new();
+        let now = Instant::now();
+
+        kinetic.grab(Point::new(10.0, 10.0));
+        kinetic.drag(Point::new(12.0, 10.0), Vector::new(100.0, 100.0), now);
+
+        assert!(!kinetic.release(now));
+        assert_eq!(kinetic.offset(), Vector::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn fling_test() {
+        let mut kinetic = Kinetic::new();
+        let max = Vector::new(0.0, 1000.0);
+        let now = Instant::now();
+
+        kinetic.grab(Point::new(0.0, 300.0));
+        for step in 1..=5_u16 {
+            kinetic.drag(
+                Point::new(0.0, 300.0 - f32::from(step) * 20.0),
+                max,
+                now + Duration::from_millis(u64::from(step) * 10),
+            );
+        }
+        assert_eq!(kinetic.offset(), Vector::new(0.0, 100.0));
+
+        let released = now + Duration::from_millis(50);
+        assert!(kinetic.release(released));
+
+        assert!(kinetic.tick(max, released + Duration::from_millis(16)));
+        let flung = kinetic.offset().y;
+        assert!(flung > 100.0);
+
+        let mut time = released;
+        while kinetic.tick(max, time) {
+            time += Duration::from_millis(16);
+        }
+        assert!(kinetic.offset().y > flung);
+        assert!(kinetic.offset().y <= max.y);
+        assert!(!kinetic.is_animating());
+    }
+
+    #[test]
+    fn rubber_band_test() {
+        let mut kinetic = Kinetic::new();
+        let max = Vector::new(0.0, 100.0);
+        let now = Instant::now();
+
+        kinetic.grab(Point::new(0.0, 0.0));
+        kinetic.drag(Point::new(0.0, 100.0), max, now);
+
+        let overscroll = kinetic.offset().y;
+        assert!(overscroll < 0.0 && overscroll > -100.0);
+
+        let _ = kinetic.release(now + Duration::from_secs(1));
+
+        let mut time = now + Duration::from_secs(1);
+        while kinetic.tick(max, time) {
+            time += Duration::from_millis(16);
+        }
+        assert_eq!(kinetic.offset(), Vector::new(0.0, 0.0));
+    }
+}
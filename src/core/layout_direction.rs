@@ -0,0 +1,195 @@
+//! Lay out the widgets from left to right or from right to left, since
+//! several locales are written from right to left.
+//!
+//! The [`LayoutDirection`] is left to right by default. An application of a
+//! right-to-left locale installs its direction once with [`set`]:
+//!
+//! ```ignore
+//! # use iced_aw::layout_direction::{self, LayoutDirection};
+//! #
+//! layout_direction::set(LayoutDirection::RightToLeft);
+//! ```
+//!
+//! From right to left:
+//! - the tabs of a tab bar are ordered from the right, with their close
+//!   buttons and badges on the left,
+//! - the roots of a menu bar are ordered from the right and their menus and
+//!   submenus open to the left,
+//! - the first element of a vertical split is on the right,
+//! - the start and end alignments of a badge are swapped, and
+//! - the month, year and day cells of the date picker are ordered from the
+//!   right, and the cancel and submit buttons of the pickers are swapped.
+//!
+//! Like the [`Translations`](crate::core::i18n::Translations), the widgets
+//! read the [`LayoutDirection`] when they are created, so the layouts change
+//! with the next `view`.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use iced_widget::core::{layout::Node, Alignment, Point};
+
+/// The horizontal direction the widgets are laid out in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum LayoutDirection {
+    /// From left to right, e.g. for English.
+    #[default]
+    LeftToRight,
+    /// From right to left, e.g. for Arabic or Hebrew.
+    RightToLeft,
+}
+
+impl LayoutDirection {
+    /// Returns true if the [`LayoutDirection`] is right to left.
+    #[must_use]
+    pub const fn is_right_to_left(self) -> bool {
+        matches!(self, Self::RightToLeft)
+    }
+
+    /// Returns the horizontal alignment at the same side in this
+    /// [`LayoutDirection`], swapping the start and the end from right to left.
+    #[must_use]
+    pub const fn align(self, alignment: Alignment) -> Alignment {
+        match (self, alignment) {
+            (Self::RightToLeft, Alignment::Start) => Alignment::End,
+            (Self::RightToLeft, Alignment::End) => Alignment::Start,
+            (_, alignment) => alignment,
+        }
+    }
+
+    /// Arranges the children of the laid out node in this
+    /// [`LayoutDirection`], mirroring their positions inside of the node from
+    /// right to left.
+    ///
+    /// The children of the children are left as they are.
+    #[must_use]
+    pub fn arrange(self, node: Node) -> Node {
+        if self.is_right_to_left() {
+            mirror(&node, false)
+        } else {
+            node
+        }
+    }
+
+    /// Arranges all descendants of the laid out node in this
+    /// [`LayoutDirection`], mirroring their positions inside of their parents
+    /// from right to left.
+    ///
+    /// Only for nodes whose descendants are all laid out by the same widget.
+    #[must_use]
+    pub fn arrange_deep(self, node: Node) -> Node {
+        if self.is_right_to_left() {
+            mirror(&node, true)
+        } else {
+            node
+        }
+    }
+}
+
+/// Mirrors the positions of the children of the node inside of it, and of
+/// their descendants if `deep`.
+fn mirror(node: &Node, deep: bool) -> Node {
+    let width = node.bounds().width;
+
+    let children = node
+        .children()
+        .iter()
+        .map(|child| {
+            let mut child = if deep {
+                mirror(child, true)
+            } else {
+                child.clone()
+            };
+            let bounds = child.bounds();
+            child.move_to(Point::new(width - bounds.x - bounds.width, bounds.y));
+            child
+        })
+        .collect();
+
+    let mut mirrored = Node::with_children(node.size(), children);
+    mirrored.move_to(node.bounds().position());
+    mirrored
+}
+
+/// Whether the installed [`LayoutDirection`] is right to left.
+static RIGHT_TO_LEFT: AtomicBool = AtomicBool::new(false);
+
+/// Installs the given [`LayoutDirection`] for all widgets created afterwards.
+pub fn set(direction: LayoutDirection) {
+    RIGHT_TO_LEFT.store(direction.is_right_to_left(), Ordering::Relaxed);
+}
+
+/// Gets the installed [`LayoutDirection`], left to right by default.
+#[must_use]
+pub fn get() -> LayoutDirection {
+    if RIGHT_TO_LEFT.load(Ordering::Relaxed) {
+        LayoutDirection::RightToLeft
+    } else {
+        LayoutDirection::LeftToRight
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LayoutDirection;
+    use iced_widget::core::{layout::Node, Alignment, Point, Rectangle, Size};
+
+    fn row() -> Node {
+        let mut inner = Node::new(Size::new(10.0, 10.0));
+        inner.move_to(Point::new(2.0, 0.0));
+        let mut first = Node::with_children(Size::new(30.0, 10.0), vec![inner]);
+        first.move_to(Point::new(0.0, 0.0));
+        let mut second = Node::new(Size::new(20.0, 10.0));
+        second.move_to(Point::new(40.0, 0.0));
+
+        let mut row = Node::with_children(Size::new(100.0, 10.0), vec![first, second]);
+        row.move_to(Point::new(5.0, 5.0));
+        row
+    }
+
+    #[test]
+    fn arrange_test() {
+        let node = LayoutDirection::RightToLeft.arrange(row());
+
+        assert_eq!(
+            node.bounds(),
+            Rectangle::new(Point::new(5.0, 5.0), Size::new(100.0, 10.0))
+        );
+        assert_eq!(
+            node.children()[0].bounds().position(),
+            Point::new(70.0, 0.0)
+        );
+        assert_eq!(
+            node.children()[1].bounds().position(),
+            Point::new(40.0, 0.0)
+        );
+        assert_eq!(
+            node.children()[0].children()[0].bounds().position(),
+            Point::new(2.0, 0.0)
+        );
+
+        let node = LayoutDirection::RightToLeft.arrange_deep(row());
+        assert_eq!(
+            node.children()[0].children()[0].bounds().position(),
+            Point::new(18.0, 0.0)
+        );
+
+        let node = LayoutDirection::LeftToRight.arrange_deep(row());
+        assert_eq!(node.children()[0].bounds().position(), Point::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn align_test() {
+        assert_eq!(
+            LayoutDirection::RightToLeft.align(Alignment::Start),
+            Alignment::End
+        );
+        assert_eq!(
+            LayoutDirection::RightToLeft.align(Alignment::Center),
+            Alignment::Center
+        );
+        assert_eq!(
+            LayoutDirection::LeftToRight.align(Alignment::Start),
+            Alignment::Start
+        );
+    }
+}
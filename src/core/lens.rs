@@ -0,0 +1,160 @@
+//! Bind inputs to a field of the state of an application through a [`Lens`],
+//! instead of a message for each field.
+//!
+//! An input bound through a [`Lens`] produces an [`Update`] applying the new
+//! value to the state, so a form with dozens of fields needs a single message.
+use std::{fmt, sync::Arc};
+
+/// Reads and writes a value inside of a model.
+pub trait Lens<Model> {
+    /// The type of the value.
+    type Value;
+
+    /// Gets the value from the model.
+    fn get(&self, model: &Model) -> Self::Value;
+
+    /// Sets the value in the model.
+    fn set(&self, model: &mut Model, value: Self::Value);
+}
+
+/// A [`Lens`] made of a getter and a setter function.
+pub struct FnLens<Model, T> {
+    /// The getter of the value.
+    get: fn(&Model) -> T,
+    /// The setter of the value.
+    set: fn(&mut Model, T),
+}
+
+impl<Model, T> Clone for FnLens<Model, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Model, T> Copy for FnLens<Model, T> {}
+
+impl<Model, T> fmt::Debug for FnLens<Model, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FnLens").finish_non_exhaustive()
+    }
+}
+
+impl<Model, T> Lens<Model> for FnLens<Model, T> {
+    type Value = T;
+
+    fn get(&self, model: &Model) -> T {
+        (self.get)(model)
+    }
+
+    fn set(&self, model: &mut Model, value: T) {
+        (self.set)(model, value);
+    }
+}
+
+/// Creates a [`Lens`] from a getter and a setter function.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::lens::lens;
+/// #
+/// struct Settings {
+///     width: u32,
+/// }
+///
+/// let width = lens(|settings: &Settings| settings.width, |settings, width| settings.width = width);
+/// ```
+#[must_use]
+pub fn lens<Model, T>(get: fn(&Model) -> T, set: fn(&mut Model, T)) -> FnLens<Model, T> {
+    FnLens { get, set }
+}
+
+/// A change of the model produced by an input bound through a [`Lens`].
+///
+/// It is `Send`, so it can be carried by the message of an application.
+pub struct Update<Model>(Arc<dyn Fn(&mut Model) + Send + Sync>);
+
+impl<Model> Update<Model> {
+    /// Creates a new [`Update`] applying the given function to the model.
+    pub fn new(apply: impl Fn(&mut Model) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(apply))
+    }
+
+    /// Creates a new [`Update`] setting the value of the [`Lens`] in the
+    /// model.
+    pub fn set<L>(lens: L, value: L::Value) -> Self
+    where
+        L: Lens<Model> + Send + Sync + 'static,
+        L::Value: Clone + Send + Sync + 'static,
+    {
+        Self::new(move |model| lens.set(model, value.clone()))
+    }
+
+    /// Applies the [`Update`] to the model.
+    pub fn apply(&self, model: &mut Model) {
+        (self.0)(model);
+    }
+}
+
+impl<Model> Clone for Update<Model> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<Model> fmt::Debug for Update<Model> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Update").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lens, Lens, Update};
+
+    #[derive(Debug, Default, PartialEq)]
+    struct Model {
+        width: u32,
+        height: u32,
+    }
+
+    #[test]
+    fn lens_test() {
+        let width = lens(
+            |model: &Model| model.width,
+            |model, width| model.width = width,
+        );
+        let height = lens(
+            |model: &Model| model.height,
+            |model, height| model.height = height,
+        );
+        let mut model = Model::default();
+
+        let updates = [Update::set(width, 640), Update::set(height, 480)];
+        for update in &updates {
+            update.apply(&mut model);
+        }
+
+        assert_eq!(
+            model,
+            Model {
+                width: 640,
+                height: 480
+            }
+        );
+        assert_eq!(width.get(&model), 640);
+    }
+
+    #[test]
+    fn update_send_test() {
+        fn assert_send<T: Send + Sync>(_: &T) {}
+
+        // The update can be carried by the message of an application.
+        assert_send(&Update::set(
+            lens(
+                |model: &Model| model.width,
+                |model, width| model.width = width,
+            ),
+            640,
+        ));
+    }
+}
@@ -0,0 +1,238 @@
+//! Buffer, decimate and scale streaming data for a live chart.
+//!
+//! *This API requires the following crate features to be activated: `live_chart`*
+use std::collections::VecDeque;
+
+/// A series of samples of a [`LiveChart`](crate::native::live_chart::LiveChart)
+/// kept in a ring buffer of a fixed capacity.
+///
+/// The samples are pairs of a time in seconds and a value. They are expected
+/// to be pushed in the order of their times.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Series {
+    /// The label of the series.
+    pub label: String,
+    /// The maximum number of samples kept.
+    capacity: usize,
+    /// The samples, from the oldest to the latest one.
+    samples: VecDeque<(f64, f32)>,
+}
+
+impl Series {
+    /// Creates a new empty [`Series`] keeping up to the given number of
+    /// samples.
+    #[must_use]
+    pub fn new(label: impl Into<String>, capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+
+        Self {
+            label: label.into(),
+            capacity,
+            samples: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Appends a sample, dropping the oldest one if the [`Series`] is full.
+    pub fn push(&mut self, time: f64, value: f32) {
+        if self.samples.len() == self.capacity {
+            let _ = self.samples.pop_front();
+        }
+
+        self.samples.push_back((time, value));
+    }
+
+    /// Removes all samples of the [`Series`].
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Returns the maximum number of samples kept.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of samples.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Returns whether the [`Series`] has no samples.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Returns the latest sample.
+    #[must_use]
+    pub fn latest(&self) -> Option<(f64, f32)> {
+        self.samples.back().copied()
+    }
+
+    /// Returns the samples, from the oldest to the latest one.
+    #[must_use]
+    pub fn samples(&self) -> impl DoubleEndedIterator<Item = (f64, f32)> + '_ {
+        self.samples.iter().copied()
+    }
+
+    /// Returns the samples from `start` to `end`, with the samples right
+    /// before and after them to continue the line to the edges.
+    pub fn window(&self, start: f64, end: f64) -> impl Iterator<Item = (f64, f32)> + '_ {
+        let first = self.samples.partition_point(|&(time, _)| time < start);
+        let last = self.samples.partition_point(|&(time, _)| time <= end);
+
+        self.samples
+            .range(first.saturating_sub(1)..(last + 1).min(self.samples.len()))
+            .copied()
+    }
+
+    /// Returns the value of the [`Series`] at the given time, interpolated
+    /// linearly between the samples around it.
+    #[must_use]
+    pub fn value_at(&self, time: f64) -> Option<f32> {
+        let next = self.samples.partition_point(|&(t, _)| t < time);
+        let (end, to) = *self.samples.get(next)?;
+
+        if end <= time || next == 0 {
+            return (end <= time).then_some(to);
+        }
+
+        let (start, from) = self.samples[next - 1];
+        #[allow(clippy::cast_possible_truncation)]
+        let t = ((time - start) / (end - start)) as f32;
+
+        Some(from + (to - from) * t)
+    }
+}
+
+/// Reduces the given samples, sorted by time, to the first, the smallest,
+/// the largest and the last sample of each of the given number of buckets
+/// dividing the time from `start` to `end`.
+///
+/// This keeps the shape of the line drawn with one bucket per pixel, while
+/// bounding the number of drawn samples by the width of the chart. Samples
+/// outside of the buckets are kept as they are.
+#[must_use]
+pub fn decimate(
+    samples: impl IntoIterator<Item = (f64, f32)>,
+    start: f64,
+    end: f64,
+    buckets: usize,
+) -> Vec<(f64, f32)> {
+    let mut decimated = Vec::new();
+    let mut bucket: Vec<(f64, f32)> = Vec::new();
+    let mut current = None;
+
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    let index = |time: f64| {
+        let position = (time - start) / (end - start) * buckets as f64;
+
+        (end > start && (0.0..buckets as f64).contains(&position)).then_some(position as usize)
+    };
+
+    for sample in samples {
+        let index = index(sample.0);
+
+        if index != current || index.is_none() {
+            flush(&mut bucket, &mut decimated);
+            current = index;
+        }
+
+        if index.is_some() {
+            bucket.push(sample);
+        } else {
+            decimated.push(sample);
+        }
+    }
+    flush(&mut bucket, &mut decimated);
+
+    decimated
+}
+
+/// Pushes the first, the smallest, the largest and the last sample of the
+/// bucket in the order of their times, and clears the bucket.
+fn flush(bucket: &mut Vec<(f64, f32)>, decimated: &mut Vec<(f64, f32)>) {
+    if bucket.len() <= 4 {
+        decimated.append(bucket);
+        return;
+    }
+
+    let extreme = |pick: fn(f32, f32) -> bool| {
+        (0..bucket.len())
+            .reduce(|a, b| if pick(bucket[b].1, bucket[a].1) { b } else { a })
+            .unwrap_or(0)
+    };
+    let mut kept = [
+        0,
+        extreme(|a, b| a < b),
+        extreme(|a, b| a > b),
+        bucket.len() - 1,
+    ];
+    kept.sort_unstable();
+
+    let mut previous = None;
+    for index in kept {
+        if previous != Some(index) {
+            decimated.push(bucket[index]);
+        }
+        previous = Some(index);
+    }
+    bucket.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decimate, Series};
+
+    #[test]
+    fn series_test() {
+        let mut series = Series::new("CPU", 3);
+        for i in 0..5_u8 {
+            series.push(f64::from(i), f32::from(i) * 10.0);
+        }
+
+        // The oldest samples are dropped.
+        assert_eq!(series.len(), 3);
+        assert_eq!(
+            series.samples().collect::<Vec<_>>(),
+            vec![(2.0, 20.0), (3.0, 30.0), (4.0, 40.0)]
+        );
+        assert_eq!(
+            series.window(2.5, 3.5).collect::<Vec<_>>(),
+            vec![(2.0, 20.0), (3.0, 30.0), (4.0, 40.0)]
+        );
+        assert_eq!(series.window(5.0, 6.0).count(), 1);
+
+        assert_eq!(series.value_at(2.5), Some(25.0));
+        assert_eq!(series.value_at(4.0), Some(40.0));
+        assert_eq!(series.value_at(1.0), None);
+        assert_eq!(series.value_at(5.0), None);
+    }
+
+    #[test]
+    fn decimate_test() {
+        let samples: Vec<(f64, f32)> = (0..100)
+            .map(|i| (f64::from(i) / 10.0, if i == 37 { 9.0 } else { 1.0 }))
+            .collect();
+
+        // Ten buckets of ten samples keep at most four samples each, and the
+        // spike survives.
+        let decimated = decimate(samples.iter().copied(), 0.0, 10.0, 10);
+        assert!(decimated.len() <= 40);
+        assert!(decimated.contains(&(3.7, 9.0)));
+        assert!(decimated.windows(2).all(|pair| pair[0].0 < pair[1].0));
+
+        // Samples outside of the buckets are kept, and a flat bucket keeps
+        // its first and last sample.
+        let decimated = decimate(samples.iter().copied(), 2.0, 3.0, 1);
+        assert_eq!(decimated.len(), 20 + 2 + 70);
+
+        // Sparse samples are kept as they are.
+        assert_eq!(decimate(samples.iter().copied(), 0.0, 10.0, 100), samples);
+    }
+}
@@ -0,0 +1,110 @@
+//! The actions listed in the dropdown menus of buttons.
+//!
+//! *This API requires the following crate features to be activated: `split_button` or `menu_button`*
+
+/// An action of a dropdown menu, e.g. of a [`SplitButton`](crate::SplitButton)
+/// or a [`MenuButton`](crate::MenuButton).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MenuItem<Message> {
+    /// The label of the [`MenuItem`].
+    label: String,
+    /// The message produced when the [`MenuItem`] is chosen.
+    message: Option<Message>,
+    /// The hint of the keyboard shortcut of the [`MenuItem`].
+    shortcut: Option<String>,
+}
+
+impl<Message> MenuItem<Message> {
+    /// Creates a new disabled [`MenuItem`] with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            message: None,
+            shortcut: None,
+        }
+    }
+
+    /// Sets the message produced when the [`MenuItem`] is chosen, enabling it.
+    #[must_use]
+    pub fn on_select(mut self, message: Message) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sets the hint of the keyboard shortcut shown next to the label, e.g.
+    /// `"Ctrl+S"`.
+    #[must_use]
+    pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    /// Gets the label of the [`MenuItem`].
+    #[must_use]
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Gets the message produced when the [`MenuItem`] is chosen.
+    #[must_use]
+    pub const fn message(&self) -> Option<&Message> {
+        self.message.as_ref()
+    }
+
+    /// Gets the hint of the keyboard shortcut of the [`MenuItem`].
+    #[must_use]
+    pub fn shortcut_hint(&self) -> Option<&str> {
+        self.shortcut.as_deref()
+    }
+
+    /// Gets if the [`MenuItem`] can be chosen.
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.message.is_some()
+    }
+}
+
+/// Returns the index of the next enabled item after the given index in the
+/// given direction, wrapping around.
+#[must_use]
+pub fn next_enabled<Message>(
+    items: &[MenuItem<Message>],
+    current: Option<usize>,
+    forward: bool,
+) -> Option<usize> {
+    let count = items.len();
+
+    (1..=count)
+        .map(|step| match (current, forward) {
+            (Some(current), true) => (current + step) % count,
+            (Some(current), false) => (current + count - step % count) % count,
+            (None, true) => step - 1,
+            (None, false) => count - step,
+        })
+        .find(|index| items[*index].is_enabled())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{next_enabled, MenuItem};
+
+    #[test]
+    fn next_enabled_test() {
+        let items = vec![
+            MenuItem::new("Cut").on_select(0),
+            MenuItem::new("Copy"),
+            MenuItem::new("Paste").on_select(2),
+        ];
+
+        assert_eq!(next_enabled(&items, None, true), Some(0));
+        assert_eq!(next_enabled(&items, None, false), Some(2));
+        assert_eq!(next_enabled(&items, Some(0), true), Some(2));
+        assert_eq!(next_enabled(&items, Some(2), true), Some(0));
+        assert_eq!(next_enabled(&items, Some(0), false), Some(2));
+        assert_eq!(next_enabled::<u8>(&[], None, true), None);
+        assert_eq!(
+            next_enabled(&[MenuItem::<u8>::new("Undo")], Some(0), true),
+            None
+        );
+    }
+}
@@ -0,0 +1,191 @@
+//! Lay out the nodes of a mind map around its root.
+//!
+//! *This API requires the following crate features to be activated: `mind_map`*
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use iced_widget::core::{Point, Size};
+
+use crate::core::tree_node::TreeNode;
+
+/// How the nodes of a [`MindMap`](crate::native::mind_map::MindMap) are laid
+/// out around the root.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum MindMapLayout {
+    /// The levels of the tree are rings around the root, and every branch
+    /// gets a share of the ring by the number of its leaves.
+    #[default]
+    Radial,
+    /// The levels of the tree are columns from left to right, with every
+    /// parent centered beside its children.
+    Tree,
+}
+
+/// A node of a mind map placed by [`place`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Placed {
+    /// The identifier of the node.
+    pub id: usize,
+    /// The identifier of the parent of the node, if it is not the root.
+    pub parent: Option<usize>,
+    /// The position of the center of the node, with the root at the origin.
+    pub position: Point,
+}
+
+/// Places the nodes of the tree with the given root in preorder.
+///
+/// With [`MindMapLayout::Radial`], the width of `spacing` is the distance
+/// between the rings. With [`MindMapLayout::Tree`], the width is the
+/// distance between the columns and the height is the distance between the
+/// leaves.
+#[must_use]
+pub fn place(root: &TreeNode, layout: MindMapLayout, spacing: Size) -> Vec<Placed> {
+    let mut placed = Vec::new();
+
+    match layout {
+        MindMapLayout::Radial => {
+            place_radial(root, None, 0, (-FRAC_PI_2, TAU), spacing.width, &mut placed);
+        }
+        MindMapLayout::Tree => {
+            let mut next_leaf = 0.0;
+            let _ = place_tree(root, None, 0, spacing, &mut next_leaf, &mut placed);
+
+            // The tree is moved to put the root at the origin.
+            let root = placed[0].position;
+            for node in &mut placed {
+                node.position.y -= root.y;
+            }
+        }
+    }
+
+    placed
+}
+
+/// Returns the number of leaves of the tree with the given root.
+fn leaves(node: &TreeNode) -> usize {
+    if node.children.is_empty() {
+        1
+    } else {
+        node.children.iter().map(leaves).sum()
+    }
+}
+
+/// Places the node in the middle of the given wedge and shares the wedge
+/// between its children.
+#[allow(clippy::cast_precision_loss)]
+fn place_radial(
+    node: &TreeNode,
+    parent: Option<usize>,
+    depth: usize,
+    (start, extent): (f32, f32),
+    ring: f32,
+    placed: &mut Vec<Placed>,
+) {
+    let angle = start + extent / 2.0;
+    let radius = depth as f32 * ring;
+    placed.push(Placed {
+        id: node.id,
+        parent,
+        position: Point::new(radius * angle.cos(), radius * angle.sin()),
+    });
+
+    let total = leaves(node) as f32;
+    let mut start = start;
+    for child in &node.children {
+        let share = extent * leaves(child) as f32 / total;
+        place_radial(
+            child,
+            Some(node.id),
+            depth + 1,
+            (start, share),
+            ring,
+            placed,
+        );
+        start += share;
+    }
+}
+
+/// Places the leaves of the tree one below the other and centers every
+/// parent beside its children, returning the vertical position of the node.
+#[allow(clippy::cast_precision_loss)]
+fn place_tree(
+    node: &TreeNode,
+    parent: Option<usize>,
+    depth: usize,
+    spacing: Size,
+    next_leaf: &mut f32,
+    placed: &mut Vec<Placed>,
+) -> f32 {
+    let index = placed.len();
+    placed.push(Placed {
+        id: node.id,
+        parent,
+        position: Point::new(depth as f32 * spacing.width, 0.0),
+    });
+
+    let y = if node.children.is_empty() {
+        let y = *next_leaf;
+        *next_leaf += spacing.height;
+        y
+    } else {
+        let children: Vec<f32> = node
+            .children
+            .iter()
+            .map(|child| place_tree(child, Some(node.id), depth + 1, spacing, next_leaf, placed))
+            .collect();
+        let (first, last) = (children[0], children[children.len() - 1]);
+        first + (last - first) / 2.0
+    };
+
+    placed[index].position.y = y;
+    y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{place, MindMapLayout};
+    use crate::core::tree_node::TreeNode;
+    use iced_widget::core::{Point, Size};
+
+    fn tree() -> TreeNode {
+        TreeNode::new(0, "Root").children([
+            TreeNode::new(1, "A").children([TreeNode::new(3, "C"), TreeNode::new(4, "D")]),
+            TreeNode::new(2, "B"),
+        ])
+    }
+
+    #[test]
+    fn place_tree_test() {
+        let placed = place(&tree(), MindMapLayout::Tree, Size::new(100.0, 20.0));
+        let positions: Vec<_> = placed.iter().map(|node| (node.id, node.position)).collect();
+
+        assert_eq!(
+            positions,
+            vec![
+                (0, Point::new(0.0, 0.0)),
+                (1, Point::new(100.0, -15.0)),
+                (3, Point::new(200.0, -25.0)),
+                (4, Point::new(200.0, -5.0)),
+                (2, Point::new(100.0, 15.0)),
+            ]
+        );
+        assert_eq!(placed[2].parent, Some(1));
+    }
+
+    #[test]
+    fn place_radial_test() {
+        let placed = place(&tree(), MindMapLayout::Radial, Size::new(100.0, 20.0));
+        let rounded: Vec<_> = placed
+            .iter()
+            .map(|node| (node.id, node.position.x.round(), node.position.y.round()))
+            .collect();
+
+        // A has two of the three leaves, so it gets two thirds of the ring
+        // starting at the top, and B the last third.
+        assert_eq!(rounded[0], (0, 0.0, 0.0));
+        assert_eq!(rounded[1], (1, 87.0, 50.0));
+        assert_eq!(rounded[4], (2, -87.0, -50.0));
+        // The leaves are twice as far from the root.
+        let c = placed[2].position;
+        assert!((c.x.hypot(c.y) - 200.0).abs() < 0.01);
+    }
+}
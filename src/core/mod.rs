@@ -4,15 +4,146 @@
 //#[cfg(all(feature = "date_picker", not(target_arch = "wasm32")))]
 pub mod date;
 
-#[cfg(feature = "time_picker")]
+#[cfg(any(feature = "time_picker", feature = "duration_picker"))]
 pub mod clock;
 
 #[cfg(feature = "color_picker")]
 pub mod color;
 
+#[cfg(feature = "contrast_checker")]
+pub mod contrast;
+
+#[cfg(feature = "theme_editor")]
+pub mod palette;
+
+#[cfg(feature = "shortcut_recorder")]
+pub mod shortcut;
+
+#[cfg(feature = "num_pad")]
+pub mod num_pad;
+
+#[cfg(feature = "on_screen_keyboard")]
+pub mod on_screen_keyboard;
+
+#[cfg(feature = "geo_map")]
+pub mod geo_map;
+
+#[cfg(feature = "org_chart")]
+pub mod org_chart;
+
+#[cfg(feature = "mind_map")]
+pub mod mind_map;
+
+#[cfg(feature = "gantt")]
+pub mod gantt;
+
+#[cfg(feature = "gamepad")]
+pub mod gamepad;
+
+pub mod layout_direction;
+
+pub mod easing;
+
+pub mod viewport;
+
+pub mod focus;
+
+pub mod i18n;
+
+pub mod ime;
+
+pub mod spatial_nav;
+
+pub mod kinetic;
+
+pub mod lens;
+
 pub mod overlay;
 
 pub mod renderer;
 
 #[cfg(feature = "time_picker")]
 pub mod time;
+
+#[cfg(feature = "volume_slider")]
+pub mod volume;
+
+#[cfg(feature = "piano_keys")]
+pub mod piano;
+
+#[cfg(feature = "editable_grid")]
+pub mod tsv;
+
+#[cfg(feature = "editable_grid")]
+pub mod column_layout;
+
+#[cfg(feature = "editable_grid")]
+pub mod cell_editor;
+
+#[cfg(any(feature = "tree_view", feature = "mind_map"))]
+pub mod tree_node;
+
+#[cfg(any(feature = "treemap", feature = "sunburst"))]
+pub mod hierarchy;
+
+#[cfg(feature = "live_chart")]
+pub mod live_chart;
+
+#[cfg(any(feature = "live_chart", feature = "candlestick", feature = "radar"))]
+pub mod axis;
+
+#[cfg(any(feature = "live_chart", feature = "candlestick"))]
+pub mod annotation;
+
+#[cfg(feature = "candlestick")]
+pub mod candlestick;
+
+#[cfg(feature = "histogram")]
+pub mod histogram;
+
+#[cfg(feature = "radar")]
+pub mod radar;
+
+#[cfg(feature = "gauge")]
+pub mod gauge;
+
+#[cfg(feature = "segment_display")]
+pub mod segment_display;
+
+#[cfg(feature = "animated_number")]
+pub mod animated_number;
+#[cfg(feature = "duration_picker")]
+pub mod duration;
+#[cfg(feature = "signal_bars")]
+pub mod signal_bars;
+#[cfg(feature = "status_light")]
+pub mod status_light;
+
+#[cfg(any(feature = "selectable_text", feature = "rich_editor"))]
+pub mod text_boundary;
+
+#[cfg(feature = "rich_editor")]
+pub mod rich_text;
+
+#[cfg(feature = "find_bar")]
+pub mod find;
+
+#[cfg(feature = "split_button")]
+pub mod menu_item;
+
+#[cfg(any(feature = "badge", feature = "tab_bar"))]
+pub mod badge;
+
+#[cfg(feature = "edge_resizer")]
+pub mod resize;
+
+#[cfg(any(feature = "gesture_detector", feature = "context_menu"))]
+pub mod gesture;
+
+#[cfg(any(
+    feature = "badge",
+    feature = "card",
+    feature = "split",
+    feature = "quad"
+))]
+pub mod pixel;
@@ -0,0 +1,88 @@
+//! Edit the text of a number with the keys of a numeric keypad.
+//!
+//! *This API requires the following crate features to be activated: `num_pad`*
+
+/// A key of a [`NumPad`](crate::native::num_pad::NumPad).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Key {
+    /// A digit from 0 to 9.
+    Digit(u8),
+    /// The decimal separator.
+    Decimal,
+    /// The key toggling the sign.
+    Sign,
+    /// The key deleting the last character.
+    Backspace,
+    /// The key submitting the number.
+    Submit,
+}
+
+/// Returns the text of a number edited with the given [`Key`], using the
+/// given decimal separator.
+///
+/// A leading zero is replaced by the next digit, a single decimal separator
+/// is accepted and the sign is toggled at the start of the text. The text is
+/// left as it is by [`Key::Submit`].
+#[must_use]
+pub fn edit(text: &str, key: Key, separator: char) -> String {
+    let (sign, digits) = text
+        .strip_prefix('-')
+        .map_or(("", text), |digits| ("-", digits));
+
+    match key {
+        Key::Digit(digit) => {
+            let digit = char::from(b'0' + digit.min(9));
+            if digits == "0" {
+                format!("{sign}{digit}")
+            } else {
+                format!("{sign}{digits}{digit}")
+            }
+        }
+        Key::Decimal if digits.contains(separator) => text.to_owned(),
+        Key::Decimal if digits.is_empty() => format!("{sign}0{separator}"),
+        Key::Decimal => format!("{text}{separator}"),
+        Key::Sign if sign.is_empty() => format!("-{digits}"),
+        Key::Sign => digits.to_owned(),
+        Key::Backspace => {
+            let mut digits = digits.to_owned();
+            let _ = digits.pop();
+            if digits.is_empty() {
+                digits
+            } else {
+                format!("{sign}{digits}")
+            }
+        }
+        Key::Submit => text.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{edit, Key};
+
+    #[test]
+    fn edit_digits_test() {
+        assert_eq!(edit("", Key::Digit(4), '.'), "4");
+        assert_eq!(edit("4", Key::Digit(2), '.'), "42");
+        assert_eq!(edit("0", Key::Digit(7), '.'), "7");
+        assert_eq!(edit("-0", Key::Digit(7), '.'), "-7");
+    }
+
+    #[test]
+    fn edit_decimal_test() {
+        assert_eq!(edit("", Key::Decimal, '.'), "0.");
+        assert_eq!(edit("-", Key::Decimal, ','), "-0,");
+        assert_eq!(edit("3", Key::Decimal, '.'), "3.");
+        assert_eq!(edit("3.1", Key::Decimal, '.'), "3.1");
+    }
+
+    #[test]
+    fn edit_sign_and_backspace_test() {
+        assert_eq!(edit("12", Key::Sign, '.'), "-12");
+        assert_eq!(edit("-12", Key::Sign, '.'), "12");
+        assert_eq!(edit("-12", Key::Backspace, '.'), "-1");
+        assert_eq!(edit("-1", Key::Backspace, '.'), "");
+        assert_eq!(edit("", Key::Backspace, '.'), "");
+        assert_eq!(edit("12", Key::Submit, '.'), "12");
+    }
+}
@@ -0,0 +1,175 @@
+//! Lay out the keys of an on-screen keyboard.
+//!
+//! *This API requires the following crate features to be activated: `on_screen_keyboard`*
+
+/// The layout of the letters of an
+/// [`OnScreenKeyboard`](crate::native::on_screen_keyboard::OnScreenKeyboard).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum KeyboardLayout {
+    /// The English QWERTY layout.
+    #[default]
+    Qwerty,
+    /// The German QWERTZ layout.
+    Qwertz,
+    /// The French AZERTY layout.
+    Azerty,
+}
+
+impl KeyboardLayout {
+    /// All the layouts of an on-screen keyboard.
+    pub const ALL: [Self; 3] = [Self::Qwerty, Self::Qwertz, Self::Azerty];
+
+    /// Returns the characters of the three rows of the letters of the
+    /// [`KeyboardLayout`].
+    #[must_use]
+    pub const fn letters(self) -> [&'static str; 3] {
+        match self {
+            Self::Qwerty => ["qwertyuiop", "asdfghjkl", "zxcvbnm,."],
+            Self::Qwertz => ["qwertzuiopü", "asdfghjklöä", "yxcvbnm,."],
+            Self::Azerty => ["azertyuiop", "qsdfghjklm", "wxcvbn,.'"],
+        }
+    }
+}
+
+impl std::fmt::Display for KeyboardLayout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Qwerty => "QWERTY",
+            Self::Qwertz => "QWERTZ",
+            Self::Azerty => "AZERTY",
+        })
+    }
+}
+
+/// The characters of the three rows of the page of symbols.
+const SYMBOLS: [&str; 3] = ["1234567890", "@#$%&*-+()", "!\"':;/?,."];
+
+/// The page of keys shown by an on-screen keyboard.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Page {
+    /// The letters of the [`KeyboardLayout`].
+    #[default]
+    Letters,
+    /// The digits and symbols.
+    Symbols,
+}
+
+/// The state of the shift key of an on-screen keyboard.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Shift {
+    /// The letters are entered in lower case.
+    #[default]
+    Off,
+    /// The next letter is entered in upper case.
+    Once,
+    /// The letters are entered in upper case until shift is pressed again.
+    Locked,
+}
+
+impl Shift {
+    /// Returns the [`Shift`] after the shift key was pressed.
+    #[must_use]
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Off => Self::Once,
+            Self::Once => Self::Locked,
+            Self::Locked => Self::Off,
+        }
+    }
+
+    /// Returns the [`Shift`] after a character was entered.
+    #[must_use]
+    pub const fn after_character(self) -> Self {
+        match self {
+            Self::Once => Self::Off,
+            shift => shift,
+        }
+    }
+
+    /// Returns the given character in upper case if the [`Shift`] is active.
+    #[must_use]
+    pub fn apply(self, character: char) -> char {
+        match self {
+            Self::Off => character,
+            Self::Once | Self::Locked => character.to_uppercase().next().unwrap_or(character),
+        }
+    }
+}
+
+/// A key of an on-screen keyboard.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Key {
+    /// A key entering a character.
+    Character(char),
+    /// The space bar.
+    Space,
+    /// The key deleting the character before the cursor.
+    Backspace,
+    /// The key submitting the input.
+    Enter,
+    /// The shift key.
+    Shift,
+    /// The key switching between the letters and the symbols.
+    Page,
+}
+
+impl Key {
+    /// Returns whether the [`Key`] repeats while it is held.
+    #[must_use]
+    pub const fn repeats(self) -> bool {
+        matches!(self, Self::Character(_) | Self::Space | Self::Backspace)
+    }
+}
+
+/// Returns the rows of the keys of the given page of the given
+/// [`KeyboardLayout`], with the width of each key relative to a letter.
+#[must_use]
+pub fn rows(layout: KeyboardLayout, page: Page) -> [Vec<(Key, f32)>; 4] {
+    let [first, second, third] = match page {
+        Page::Letters => layout.letters(),
+        Page::Symbols => SYMBOLS,
+    };
+    let characters = |row: &'static str| row.chars().map(|c| (Key::Character(c), 1.0));
+
+    [
+        characters(first)
+            .chain(std::iter::once((Key::Backspace, 1.5)))
+            .collect(),
+        characters(second)
+            .chain(std::iter::once((Key::Enter, 1.5)))
+            .collect(),
+        std::iter::once((Key::Shift, 1.5))
+            .chain(characters(third))
+            .collect(),
+        vec![(Key::Page, 1.5), (Key::Space, 6.0), (Key::Page, 1.5)],
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rows, Key, KeyboardLayout, Page, Shift};
+
+    #[test]
+    fn shift_test() {
+        assert_eq!(Shift::Off.apply('a'), 'a');
+        assert_eq!(Shift::Once.apply('a'), 'A');
+        assert_eq!(Shift::Locked.apply('ü'), 'Ü');
+        assert_eq!(Shift::Once.apply('1'), '1');
+
+        assert_eq!(Shift::Once.after_character(), Shift::Off);
+        assert_eq!(Shift::Locked.after_character(), Shift::Locked);
+        assert_eq!(Shift::Off.next().next().next(), Shift::Off);
+    }
+
+    #[test]
+    fn rows_test() {
+        let [first, _, third, fourth] = rows(KeyboardLayout::Azerty, Page::Letters);
+        assert_eq!(first[0], (Key::Character('a'), 1.0));
+        assert_eq!(first.last(), Some(&(Key::Backspace, 1.5)));
+        assert_eq!(third[0], (Key::Shift, 1.5));
+        assert!(fourth.contains(&(Key::Space, 6.0)));
+
+        let [first, ..] = rows(KeyboardLayout::Azerty, Page::Symbols);
+        assert_eq!(first[0], (Key::Character('1'), 1.0));
+    }
+}
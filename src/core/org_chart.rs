@@ -0,0 +1,148 @@
+//! Lay out the nodes of an org chart as a tree from the top down.
+//!
+//! *This API requires the following crate features to be activated: `org_chart`*
+use iced_widget::core::{Point, Size};
+
+/// The placement of the nodes of an org chart.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Placement {
+    /// The top left corners of the nodes, or `None` for the nodes hidden in
+    /// a collapsed branch.
+    pub positions: Vec<Option<Point>>,
+    /// The size of the whole chart.
+    pub size: Size,
+}
+
+/// Places the nodes of a tree from the top down, with every parent centered
+/// above its children and the nodes of each level in a row.
+///
+/// The nodes are given in preorder with the root first, by their sizes and
+/// the indices of their children. The children of the collapsed nodes are
+/// hidden, and `spacing` is the gap between the siblings and the rows.
+#[must_use]
+pub fn place(
+    sizes: &[Size],
+    children: &[Vec<usize>],
+    collapsed: &[bool],
+    spacing: Size,
+) -> Placement {
+    let mut positions = vec![None; sizes.len()];
+    if sizes.is_empty() {
+        return Placement {
+            positions,
+            size: Size::ZERO,
+        };
+    }
+
+    let expanded = |node: usize| !collapsed.get(node).copied().unwrap_or(false);
+    let visible_children = |node: usize| -> &[usize] {
+        if expanded(node) {
+            &children[node]
+        } else {
+            &[]
+        }
+    };
+
+    // The widths of the subtrees and the heights of the rows, from the
+    // leaves up.
+    let mut widths = vec![0.0; sizes.len()];
+    let mut rows: Vec<f32> = Vec::new();
+    let mut stack = vec![(0, 0, false)];
+    while let Some((node, depth, visited)) = stack.pop() {
+        if visited {
+            let row: f32 = visible_children(node)
+                .iter()
+                .map(|child| widths[*child] + spacing.width)
+                .sum::<f32>()
+                - spacing.width;
+            widths[node] = sizes[node].width.max(row);
+
+            if rows.len() <= depth {
+                rows.resize(depth + 1, 0.0);
+            }
+            rows[depth] = rows[depth].max(sizes[node].height);
+        } else {
+            stack.push((node, depth, true));
+            stack.extend(
+                visible_children(node)
+                    .iter()
+                    .map(|child| (*child, depth + 1, false)),
+            );
+        }
+    }
+
+    let tops: Vec<f32> = rows
+        .iter()
+        .scan(0.0, |top, height| {
+            let row = *top;
+            *top += height + spacing.height;
+            Some(row)
+        })
+        .collect();
+
+    let mut stack = vec![(0, 0.0, 0)];
+    while let Some((node, left, depth)) = stack.pop() {
+        positions[node] = Some(Point::new(
+            left + (widths[node] - sizes[node].width) / 2.0,
+            tops[depth],
+        ));
+
+        let row: f32 = visible_children(node)
+            .iter()
+            .map(|child| widths[*child] + spacing.width)
+            .sum::<f32>()
+            - spacing.width;
+        let mut left = left + (widths[node] - row) / 2.0;
+        for child in visible_children(node) {
+            stack.push((*child, left, depth + 1));
+            left += widths[*child] + spacing.width;
+        }
+    }
+
+    Placement {
+        positions,
+        size: Size::new(
+            widths[0],
+            tops.last().copied().unwrap_or(0.0) + rows.last().copied().unwrap_or(0.0),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::place;
+    use iced_widget::core::{Point, Size};
+
+    #[test]
+    fn place_test() {
+        // A root with two children, the first with two children itself.
+        let sizes = [Size::new(40.0, 20.0); 5];
+        let children = [vec![1, 4], vec![2, 3], vec![], vec![], vec![]];
+        let spacing = Size::new(10.0, 30.0);
+
+        let placement = place(&sizes, &children, &[false; 5], spacing);
+        assert_eq!(placement.size, Size::new(140.0, 120.0));
+        assert_eq!(
+            placement.positions,
+            vec![
+                Some(Point::new(50.0, 0.0)),
+                Some(Point::new(25.0, 50.0)),
+                Some(Point::new(0.0, 100.0)),
+                Some(Point::new(50.0, 100.0)),
+                Some(Point::new(100.0, 50.0)),
+            ]
+        );
+
+        // Collapsing the first child hides its children.
+        let placement = place(
+            &sizes,
+            &children,
+            &[false, true, false, false, false],
+            spacing,
+        );
+        assert_eq!(placement.size, Size::new(90.0, 70.0));
+        assert_eq!(placement.positions[0], Some(Point::new(25.0, 0.0)));
+        assert_eq!(placement.positions[1], Some(Point::new(0.0, 50.0)));
+        assert_eq!(placement.positions[2], None);
+    }
+}
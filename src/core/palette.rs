@@ -0,0 +1,205 @@
+//! Helper functions and structs for editing and exporting palettes.
+
+use iced_widget::{
+    core::Color,
+    style::theme::palette::{Extended, Palette},
+};
+
+/// A color of a [`Palette`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PaletteColor {
+    /// The background color.
+    Background,
+    /// The text color.
+    Text,
+    /// The primary color.
+    Primary,
+    /// The success color.
+    Success,
+    /// The danger color.
+    Danger,
+}
+
+/// A format of an exported [`Palette`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Export {
+    /// A Rust expression creating the [`Palette`].
+    Rust,
+    /// A TOML table of hexadecimal colors.
+    Toml,
+}
+
+impl PaletteColor {
+    /// All the colors of a [`Palette`], in order.
+    pub const ALL: [Self; 5] = [
+        Self::Background,
+        Self::Text,
+        Self::Primary,
+        Self::Success,
+        Self::Danger,
+    ];
+
+    /// Returns the name of the field of the [`Palette`].
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Background => "background",
+            Self::Text => "text",
+            Self::Primary => "primary",
+            Self::Success => "success",
+            Self::Danger => "danger",
+        }
+    }
+
+    /// Returns the color of the [`Palette`].
+    #[must_use]
+    pub const fn get(self, palette: &Palette) -> Color {
+        match self {
+            Self::Background => palette.background,
+            Self::Text => palette.text,
+            Self::Primary => palette.primary,
+            Self::Success => palette.success,
+            Self::Danger => palette.danger,
+        }
+    }
+
+    /// Sets the color of the [`Palette`].
+    pub fn set(self, palette: &mut Palette, color: Color) {
+        let field = match self {
+            Self::Background => &mut palette.background,
+            Self::Text => &mut palette.text,
+            Self::Primary => &mut palette.primary,
+            Self::Success => &mut palette.success,
+            Self::Danger => &mut palette.danger,
+        };
+        *field = color;
+    }
+
+    /// Returns the weak, base and strong shades generated from the color in
+    /// the [`Extended`] palette.
+    ///
+    /// The text color has no shades, so the text colors generated for the
+    /// shades of the background are returned instead.
+    #[must_use]
+    pub const fn shades(self, extended: &Extended) -> [Color; 3] {
+        match self {
+            Self::Background => [
+                extended.background.weak.color,
+                extended.background.base.color,
+                extended.background.strong.color,
+            ],
+            Self::Text => [
+                extended.background.weak.text,
+                extended.background.base.text,
+                extended.background.strong.text,
+            ],
+            Self::Primary => [
+                extended.primary.weak.color,
+                extended.primary.base.color,
+                extended.primary.strong.color,
+            ],
+            Self::Success => [
+                extended.success.weak.color,
+                extended.success.base.color,
+                extended.success.strong.color,
+            ],
+            Self::Danger => [
+                extended.danger.weak.color,
+                extended.danger.base.color,
+                extended.danger.strong.color,
+            ],
+        }
+    }
+}
+
+/// Exports the [`Palette`] in the given format.
+#[must_use]
+pub fn export(palette: &Palette, format: Export) -> String {
+    let entries = PaletteColor::ALL.into_iter().map(|entry| {
+        let [r, g, b, a] = entry.get(palette).into_rgba8();
+        let name = entry.name();
+
+        match format {
+            Export::Rust if a == u8::MAX => {
+                format!("    {name}: Color::from_rgb8(0x{r:02X}, 0x{g:02X}, 0x{b:02X}),\n")
+            }
+            Export::Rust => format!(
+                "    {name}: Color::from_rgba8(0x{r:02X}, 0x{g:02X}, 0x{b:02X}, {}),\n",
+                entry.get(palette).a
+            ),
+            Export::Toml if a == u8::MAX => format!("{name} = \"#{r:02X}{g:02X}{b:02X}\"\n"),
+            Export::Toml => format!("{name} = \"#{r:02X}{g:02X}{b:02X}{a:02X}\"\n"),
+        }
+    });
+
+    let (header, footer) = match format {
+        Export::Rust => ("Palette {\n", "}\n"),
+        Export::Toml => ("[palette]\n", ""),
+    };
+
+    std::iter::once(header.to_owned())
+        .chain(entries)
+        .chain(std::iter::once(footer.to_owned()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_widget::{core::Color, style::theme::palette::Palette};
+
+    use super::{export, Export, PaletteColor};
+
+    #[test]
+    fn get_set() {
+        let mut palette = Palette::LIGHT;
+
+        for (index, entry) in PaletteColor::ALL.into_iter().enumerate() {
+            let color = Color::from_rgb8(index as u8, 0, 0);
+            entry.set(&mut palette, color);
+            assert_eq!(entry.get(&palette), color);
+        }
+
+        assert_eq!(palette.primary, Color::from_rgb8(2, 0, 0));
+    }
+
+    #[test]
+    fn export_rust() {
+        let palette = Palette {
+            background: Color::WHITE,
+            text: Color::BLACK,
+            primary: Color::from_rgb8(0x5E, 0x7C, 0xE2),
+            success: Color::from_rgb8(0x12, 0x66, 0x4F),
+            danger: Color {
+                a: 0.5,
+                ..Color::from_rgb8(0xC3, 0x42, 0x3F)
+            },
+        };
+
+        assert_eq!(
+            export(&palette, Export::Rust),
+            "Palette {\n\
+             \x20   background: Color::from_rgb8(0xFF, 0xFF, 0xFF),\n\
+             \x20   text: Color::from_rgb8(0x00, 0x00, 0x00),\n\
+             \x20   primary: Color::from_rgb8(0x5E, 0x7C, 0xE2),\n\
+             \x20   success: Color::from_rgb8(0x12, 0x66, 0x4F),\n\
+             \x20   danger: Color::from_rgba8(0xC3, 0x42, 0x3F, 0.5),\n\
+             }\n"
+        );
+    }
+
+    #[test]
+    fn export_toml() {
+        let palette = Palette {
+            danger: Color {
+                a: 0.5,
+                ..Color::from_rgb8(0xC3, 0x42, 0x3F)
+            },
+            ..Palette::DARK
+        };
+
+        let toml = export(&palette, Export::Toml);
+        assert!(toml.starts_with("[palette]\nbackground = \"#"));
+        assert!(toml.ends_with("danger = \"#C3423F80\"\n"));
+        assert_eq!(toml.lines().count(), 6);
+    }
+}
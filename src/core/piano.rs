@@ -0,0 +1,62 @@
+//! Helper functions for the keys of a piano keyboard.
+//!
+//! *This API requires the following crate features to be activated: `piano_keys`*
+
+/// The names of the notes of an octave, starting at C.
+const NOTE_NAMES: [&str; 12] = [
+    "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+];
+
+/// Returns `true` if the given MIDI note is played on a black key.
+#[must_use]
+pub const fn is_black_key(note: u8) -> bool {
+    matches!(note % 12, 1 | 3 | 6 | 8 | 10)
+}
+
+/// Returns the octave of the given MIDI note, with middle C (60) in octave 4.
+#[must_use]
+pub fn octave(note: u8) -> i8 {
+    // Notes are at most 127, so the octave fits into an i8.
+    #[allow(clippy::cast_possible_wrap)]
+    let octave = (note / 12) as i8;
+    octave - 1
+}
+
+/// Returns the name of the given MIDI note, like `C4` for middle C.
+#[must_use]
+pub fn note_name(note: u8) -> String {
+    format!("{}{}", NOTE_NAMES[usize::from(note % 12)], octave(note))
+}
+
+/// Returns the number of white keys below the given MIDI note.
+#[must_use]
+pub fn white_keys_below(note: u8) -> usize {
+    (0..note).filter(|note| !is_black_key(*note)).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_black_key, note_name, octave, white_keys_below};
+
+    #[test]
+    fn black_key_test() {
+        let black: Vec<u8> = (60..72).filter(|note| is_black_key(*note)).collect();
+        assert_eq!(black, vec![61, 63, 66, 68, 70]);
+    }
+
+    #[test]
+    fn note_name_test() {
+        assert_eq!(octave(60), 4);
+        assert_eq!(octave(0), -1);
+        assert_eq!(note_name(60), "C4");
+        assert_eq!(note_name(69), "A4");
+        assert_eq!(note_name(22), "A#0");
+    }
+
+    #[test]
+    fn white_keys_below_test() {
+        assert_eq!(white_keys_below(0), 0);
+        assert_eq!(white_keys_below(12), 7);
+        assert_eq!(white_keys_below(64) - white_keys_below(60), 2);
+    }
+}
@@ -0,0 +1,132 @@
+//! Snap the geometry of custom-drawn widgets to physical pixels.
+//!
+//! Lines drawn at fractional logical positions, or with widths that are not a
+//! whole number of physical pixels, are antialiased across two rows of
+//! physical pixels at scale factors like 125% or 150%. Hairlines then look
+//! blurry or disappear entirely.
+use iced_widget::core::Rectangle;
+
+/// Snaps the logical coordinate to the nearest physical pixel at the given
+/// scale factor.
+#[must_use]
+pub fn snap(value: f32, scale_factor: f32) -> f32 {
+    if scale_factor <= 0.0 {
+        return value;
+    }
+
+    (value * scale_factor).round() / scale_factor
+}
+
+/// Snaps the logical width of a line to a whole number of physical pixels at
+/// the given scale factor.
+///
+/// A visible line never gets thinner than a single physical pixel.
+#[must_use]
+pub fn snap_width(width: f32, scale_factor: f32) -> f32 {
+    if width <= 0.0 || scale_factor <= 0.0 {
+        return width;
+    }
+
+    (width * scale_factor).round().max(1.0) / scale_factor
+}
+
+/// Snaps the edges of the [`Rectangle`] to the nearest physical pixels at the
+/// given scale factor.
+///
+/// A visible [`Rectangle`] never gets thinner than a single physical pixel,
+/// so hairlines like separators do not disappear.
+#[must_use]
+pub fn snap_rectangle(bounds: Rectangle, scale_factor: f32) -> Rectangle {
+    let snap_axis = |start: f32, length: f32| {
+        let snapped = snap(start, scale_factor);
+        let end = snap(start + length, scale_factor);
+
+        if length > 0.0 {
+            (snapped, (end - snapped).max(hairline(scale_factor)))
+        } else {
+            (snapped, end - snapped)
+        }
+    };
+
+    let (x, width) = snap_axis(bounds.x, bounds.width);
+    let (y, height) = snap_axis(bounds.y, bounds.height);
+
+    Rectangle {
+        x,
+        y,
+        width,
+        height,
+    }
+}
+
+/// Snaps the edges of the [`Rectangle`] like [`snap_rectangle`] if the scale
+/// factor is known, or else leaves them as they are.
+#[must_use]
+pub fn snap_rectangle_to(bounds: Rectangle, scale_factor: Option<f32>) -> Rectangle {
+    scale_factor.map_or(bounds, |scale_factor| snap_rectangle(bounds, scale_factor))
+}
+
+/// Snaps the logical width of a line like [`snap_width`] if the scale factor
+/// is known, or else leaves it as it is.
+#[must_use]
+pub fn snap_width_to(width: f32, scale_factor: Option<f32>) -> f32 {
+    scale_factor.map_or(width, |scale_factor| snap_width(width, scale_factor))
+}
+
+/// Returns the logical width of a single physical pixel at the given scale
+/// factor.
+#[must_use]
+pub fn hairline(scale_factor: f32) -> f32 {
+    snap_width(f32::EPSILON, scale_factor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hairline, snap, snap_rectangle, snap_rectangle_to, snap_width, snap_width_to};
+    use iced_widget::core::Rectangle;
+
+    #[test]
+    fn snap_test() {
+        assert!((snap(10.3, 1.0) - 10.0).abs() < f32::EPSILON);
+        assert!((snap(10.3, 1.25) - 10.4).abs() < 1e-5);
+        assert!((snap(10.3, 1.5) - 10.0).abs() < 1e-5);
+        assert!((snap(10.3, 0.0) - 10.3).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn snap_width_test() {
+        assert!((snap_width(1.0, 1.25) - 0.8).abs() < 1e-5);
+        assert!((snap_width(1.0, 1.5) - 2.0 / 1.5).abs() < 1e-5);
+        assert!((snap_width(0.1, 2.0) - 0.5).abs() < f32::EPSILON);
+        assert!(snap_width(0.0, 1.5).abs() < f32::EPSILON);
+        assert!((hairline(1.25) - 0.8).abs() < 1e-5);
+    }
+
+    #[test]
+    fn snap_rectangle_test() {
+        let bounds = snap_rectangle(Rectangle::new([0.5, 0.3].into(), [9.9, 1.0].into()), 1.5);
+
+        assert!((bounds.x - 2.0 / 3.0).abs() < 1e-5);
+        assert!(bounds.y.abs() < f32::EPSILON);
+        assert!((bounds.width - 10.0).abs() < 1e-5);
+        assert!((bounds.height - 4.0 / 3.0).abs() < 1e-5);
+
+        let hairline = snap_rectangle(Rectangle::new([0.0, 4.9].into(), [10.0, 0.2].into()), 1.25);
+
+        assert!((hairline.y - 4.8).abs() < 1e-5);
+        assert!((hairline.height - 0.8).abs() < 1e-5);
+    }
+
+    #[test]
+    fn snap_to_test() {
+        let bounds = Rectangle::new([0.5, 0.3].into(), [9.9, 1.0].into());
+
+        assert_eq!(snap_rectangle_to(bounds, None), bounds);
+        assert_eq!(
+            snap_rectangle_to(bounds, Some(1.5)),
+            snap_rectangle(bounds, 1.5)
+        );
+        assert!((snap_width_to(0.1, None) - 0.1).abs() < f32::EPSILON);
+        assert!((snap_width_to(0.1, Some(2.0)) - 0.5).abs() < f32::EPSILON);
+    }
+}
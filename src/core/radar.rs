@@ -0,0 +1,107 @@
+//! Place the values of a radar chart on the spokes of its axes.
+//!
+//! *This API requires the following crate features to be activated: `radar`*
+use iced_widget::core::{Point, Vector};
+
+/// Returns the angle in radians of the spoke of the axis at the given index
+/// of the given number of axes, starting at the top and going clockwise.
+#[must_use]
+pub fn spoke_angle(index: usize, count: usize) -> f32 {
+    #[allow(clippy::cast_precision_loss)]
+    let turn = index as f32 / count.max(1) as f32;
+
+    std::f32::consts::TAU * turn - std::f32::consts::FRAC_PI_2
+}
+
+/// Returns the point at the given distance from the center on the spoke of
+/// the axis at the given index of the given number of axes.
+#[must_use]
+pub fn spoke_point(center: Point, distance: f32, index: usize, count: usize) -> Point {
+    let angle = spoke_angle(index, count);
+
+    center + Vector::new(angle.cos() * distance, angle.sin() * distance)
+}
+
+/// Returns the position of the given value between `min` and `max`, from 0.0
+/// at the center to 1.0 at the end of a spoke.
+///
+/// Values outside of the range are kept at the center or the end.
+#[must_use]
+pub fn fraction(value: f32, min: f32, max: f32) -> f32 {
+    if max > min {
+        ((value - min) / (max - min)).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+/// Returns whether the given point is inside of the given polygon.
+#[must_use]
+pub fn polygon_contains(polygon: &[Point], point: Point) -> bool {
+    let edges = polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .take(polygon.len());
+
+    // The point is inside if a ray to its right crosses an odd number of
+    // edges.
+    edges
+        .filter(|(a, b)| {
+            (a.y > point.y) != (b.y > point.y)
+                && point.x < a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x)
+        })
+        .count()
+        % 2
+        == 1
+}
+
+/// Returns the area of the given polygon.
+#[must_use]
+pub fn polygon_area(polygon: &[Point]) -> f32 {
+    let twice: f32 = polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .take(polygon.len())
+        .map(|(a, b)| a.x * b.y - b.x * a.y)
+        .sum();
+
+    twice.abs() / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fraction, polygon_area, polygon_contains, spoke_point};
+    use iced_widget::core::Point;
+
+    #[test]
+    fn spoke_point_test() {
+        let center = Point::new(50.0, 50.0);
+        let round = |point: Point| (point.x.round(), point.y.round());
+
+        // The first spoke points up, and the others follow clockwise.
+        assert_eq!(round(spoke_point(center, 10.0, 0, 4)), (50.0, 40.0));
+        assert_eq!(round(spoke_point(center, 10.0, 1, 4)), (60.0, 50.0));
+        assert_eq!(round(spoke_point(center, 10.0, 2, 4)), (50.0, 60.0));
+
+        assert_eq!(
+            (fraction(5.0, 0.0, 10.0), fraction(20.0, 0.0, 10.0)),
+            (0.5, 1.0)
+        );
+        assert!(fraction(-1.0, 0.0, 10.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn polygon_test() {
+        let square = [
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(10.0, 10.0),
+            Point::new(0.0, 10.0),
+        ];
+
+        assert!(polygon_contains(&square, Point::new(5.0, 5.0)));
+        assert!(!polygon_contains(&square, Point::new(15.0, 5.0)));
+        assert!(!polygon_contains(&[], Point::new(5.0, 5.0)));
+        assert!((polygon_area(&square) - 100.0).abs() < f32::EPSILON);
+    }
+}
@@ -0,0 +1,163 @@
+//! Resize undecorated windows from their edges and corners.
+//!
+//! *This API requires the following crate features to be activated: `edge_resizer`*
+use iced_widget::core::{Point, Size, Vector};
+
+/// The edge or corner of a window being resized.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ResizeDirection {
+    /// The top edge.
+    North,
+    /// The top right corner.
+    NorthEast,
+    /// The right edge.
+    East,
+    /// The bottom right corner.
+    SouthEast,
+    /// The bottom edge.
+    South,
+    /// The bottom left corner.
+    SouthWest,
+    /// The left edge.
+    West,
+    /// The top left corner.
+    NorthWest,
+}
+
+impl ResizeDirection {
+    /// Returns the edge or corner of an area of the given size at the given
+    /// position, relative to the top left corner of the area, if it lies
+    /// within the given thickness of an edge.
+    ///
+    /// Corners extend twice the thickness along their edges to be easier to
+    /// grab.
+    #[must_use]
+    pub fn at(size: Size, position: Point, thickness: f32) -> Option<Self> {
+        if position.x < 0.0
+            || position.y < 0.0
+            || position.x > size.width
+            || position.y > size.height
+        {
+            return None;
+        }
+
+        let corner = thickness * 2.0;
+        let north = position.y < thickness;
+        let south = position.y > size.height - thickness;
+        let west = position.x < thickness;
+        let east = position.x > size.width - thickness;
+        let near_north = position.y < corner;
+        let near_south = position.y > size.height - corner;
+        let near_west = position.x < corner;
+        let near_east = position.x > size.width - corner;
+
+        if (north && near_west) || (west && near_north) {
+            Some(Self::NorthWest)
+        } else if (north && near_east) || (east && near_north) {
+            Some(Self::NorthEast)
+        } else if (south && near_west) || (west && near_south) {
+            Some(Self::SouthWest)
+        } else if (south && near_east) || (east && near_south) {
+            Some(Self::SouthEast)
+        } else if north {
+            Some(Self::North)
+        } else if south {
+            Some(Self::South)
+        } else if west {
+            Some(Self::West)
+        } else if east {
+            Some(Self::East)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the horizontal and vertical sides moved by the
+    /// [`ResizeDirection`], `-1` for the left or top side, `1` for the right
+    /// or bottom side, and `0` if none.
+    #[must_use]
+    pub const fn sides(self) -> (i8, i8) {
+        match self {
+            Self::North => (0, -1),
+            Self::NorthEast => (1, -1),
+            Self::East => (1, 0),
+            Self::SouthEast => (1, 1),
+            Self::South => (0, 1),
+            Self::SouthWest => (-1, 1),
+            Self::West => (-1, 0),
+            Self::NorthWest => (-1, -1),
+        }
+    }
+
+    /// Resizes a window of the given size by dragging the edge or corner by
+    /// the given delta, without shrinking it below the given minimum size.
+    ///
+    /// Returns the new size of the window, and the offset by which the window
+    /// has to be moved to keep its opposite edges in place.
+    #[must_use]
+    pub fn resize(self, size: Size, delta: Vector, min: Size) -> (Size, Vector) {
+        let (horizontal, vertical) = self.sides();
+
+        let resize_axis = |side: i8, length: f32, delta: f32, min: f32| match side {
+            1 => ((length + delta).max(min), 0.0),
+            -1 => {
+                let resized = (length - delta).max(min);
+                (resized, length - resized)
+            }
+            _ => (length, 0.0),
+        };
+
+        let (width, x) = resize_axis(horizontal, size.width, delta.x, min.width);
+        let (height, y) = resize_axis(vertical, size.height, delta.y, min.height);
+
+        (Size::new(width, height), Vector::new(x, y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResizeDirection;
+    use iced_widget::core::{Point, Size, Vector};
+
+    #[test]
+    fn at_test() {
+        let size = Size::new(200.0, 100.0);
+        let at = |x, y| ResizeDirection::at(size, Point::new(x, y), 5.0);
+
+        assert_eq!(at(100.0, 50.0), None);
+        assert_eq!(at(100.0, 2.0), Some(ResizeDirection::North));
+        assert_eq!(at(100.0, 98.0), Some(ResizeDirection::South));
+        assert_eq!(at(2.0, 50.0), Some(ResizeDirection::West));
+        assert_eq!(at(198.0, 50.0), Some(ResizeDirection::East));
+        assert_eq!(at(2.0, 2.0), Some(ResizeDirection::NorthWest));
+        assert_eq!(at(8.0, 2.0), Some(ResizeDirection::NorthWest));
+        assert_eq!(at(198.0, 8.0), Some(ResizeDirection::NorthEast));
+        assert_eq!(at(2.0, 98.0), Some(ResizeDirection::SouthWest));
+        assert_eq!(at(192.0, 98.0), Some(ResizeDirection::SouthEast));
+        assert_eq!(at(-1.0, 50.0), None);
+        assert_eq!(at(201.0, 50.0), None);
+    }
+
+    #[test]
+    fn resize_test() {
+        let size = Size::new(200.0, 100.0);
+        let min = Size::new(50.0, 50.0);
+
+        assert_eq!(
+            ResizeDirection::East.resize(size, Vector::new(10.0, 10.0), min),
+            (Size::new(210.0, 100.0), Vector::new(0.0, 0.0))
+        );
+        assert_eq!(
+            ResizeDirection::NorthWest.resize(size, Vector::new(-10.0, 20.0), min),
+            (Size::new(210.0, 80.0), Vector::new(-10.0, 20.0))
+        );
+        assert_eq!(
+            ResizeDirection::North.resize(size, Vector::new(0.0, 80.0), min),
+            (Size::new(200.0, 50.0), Vector::new(0.0, 50.0))
+        );
+        assert_eq!(
+            ResizeDirection::SouthEast.resize(size, Vector::new(-500.0, -500.0), min),
+            (min, Vector::new(0.0, 0.0))
+        );
+    }
+}
@@ -0,0 +1,692 @@
+//! A document of formatted text, with bold, italic, underlined, code and
+//! linked spans in paragraphs and lists.
+//!
+//! *This API requires the following crate features to be activated: `rich_editor`*
+use std::ops::Range;
+
+/// A format of a [`Span`] that can be toggled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// Bold text.
+    Bold,
+    /// Italic text.
+    Italic,
+    /// Underlined text.
+    Underline,
+    /// Inline code.
+    Code,
+}
+
+/// The style of a [`Span`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct SpanStyle {
+    /// Whether the text is bold.
+    pub bold: bool,
+    /// Whether the text is italic.
+    pub italic: bool,
+    /// Whether the text is underlined.
+    pub underline: bool,
+    /// Whether the text is inline code.
+    pub code: bool,
+    /// The target of the link of the text.
+    pub link: Option<String>,
+}
+
+impl SpanStyle {
+    /// Returns `true` if the style has the given [`Format`].
+    #[must_use]
+    pub fn has(&self, format: Format) -> bool {
+        match format {
+            Format::Bold => self.bold,
+            Format::Italic => self.italic,
+            Format::Underline => self.underline,
+            Format::Code => self.code,
+        }
+    }
+
+    /// Adds or removes the given [`Format`].
+    pub fn set(&mut self, format: Format, value: bool) {
+        match format {
+            Format::Bold => self.bold = value,
+            Format::Italic => self.italic = value,
+            Format::Underline => self.underline = value,
+            Format::Code => self.code = value,
+        }
+    }
+}
+
+/// A run of text with the same [`SpanStyle`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Span {
+    /// The text of the span.
+    pub text: String,
+    /// The style of the span.
+    pub style: SpanStyle,
+}
+
+/// The kind of a [`Block`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BlockKind {
+    /// A paragraph.
+    #[default]
+    Paragraph,
+    /// An item of a bulleted list.
+    Bullet,
+    /// An item of a numbered list.
+    Numbered,
+}
+
+/// A paragraph or a list item of a [`Document`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Block {
+    /// The kind of the block.
+    pub kind: BlockKind,
+    /// The spans of the block.
+    pub spans: Vec<Span>,
+}
+
+impl Block {
+    /// Creates a new empty [`Block`] of the given kind.
+    #[must_use]
+    pub fn new(kind: BlockKind) -> Self {
+        Self {
+            kind,
+            spans: Vec::new(),
+        }
+    }
+
+    /// Returns the text of the block.
+    #[must_use]
+    pub fn text(&self) -> String {
+        self.spans.iter().map(|span| span.text.as_str()).collect()
+    }
+
+    /// Returns the length of the text of the block in bytes.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.spans.iter().map(|span| span.text.len()).sum()
+    }
+
+    /// Returns `true` if the block has no text.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the style of the text before the given byte offset, or of the
+    /// first character at the start of the block.
+    #[must_use]
+    pub fn style_at(&self, offset: usize) -> SpanStyle {
+        let mut start = 0;
+
+        for span in &self.spans {
+            let end = start + span.text.len();
+            if offset <= end && (offset > start || start == 0) {
+                return span.style.clone();
+            }
+            start = end;
+        }
+
+        SpanStyle::default()
+    }
+
+    /// Returns the characters of the block with their styles.
+    fn chars(&self) -> Vec<(char, SpanStyle)> {
+        self.spans
+            .iter()
+            .flat_map(|span| span.text.chars().map(|c| (c, span.style.clone())))
+            .collect()
+    }
+
+    /// Replaces the spans of the block with the given characters, merging the
+    /// neighbouring characters of the same style.
+    fn set_chars(&mut self, chars: Vec<(char, SpanStyle)>) {
+        self.spans.clear();
+
+        for (c, style) in chars {
+            match self.spans.last_mut() {
+                Some(span) if span.style == style => span.text.push(c),
+                _ => self.spans.push(Span {
+                    text: c.to_string(),
+                    style,
+                }),
+            }
+        }
+    }
+}
+
+/// A position in a [`Document`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    /// The index of the block.
+    pub block: usize,
+    /// The byte offset in the text of the block.
+    pub offset: usize,
+}
+
+impl Position {
+    /// Creates a new [`Position`].
+    #[must_use]
+    pub fn new(block: usize, offset: usize) -> Self {
+        Self { block, offset }
+    }
+}
+
+/// A document of formatted text.
+///
+/// A document has at least one [`Block`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Document {
+    /// The blocks of the document.
+    pub blocks: Vec<Block>,
+}
+
+impl Default for Document {
+    fn default() -> Self {
+        Self {
+            blocks: vec![Block::default()],
+        }
+    }
+}
+
+impl Document {
+    /// Creates a new empty [`Document`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the position at the end of the [`Document`].
+    #[must_use]
+    pub fn end(&self) -> Position {
+        let block = self.blocks.len() - 1;
+        Position::new(block, self.blocks[block].len())
+    }
+
+    /// Returns the plain text of the given range, with a line break between
+    /// blocks.
+    #[must_use]
+    pub fn text(&self, range: Range<Position>) -> String {
+        let mut text = String::new();
+
+        for block in range.start.block..=range.end.block.min(self.blocks.len() - 1) {
+            let content = self.blocks[block].text();
+            let start = if block == range.start.block {
+                range.start.offset
+            } else {
+                text.push('\n');
+                0
+            };
+            let end = if block == range.end.block {
+                range.end.offset
+            } else {
+                content.len()
+            };
+            text.push_str(&content[start.min(end)..end]);
+        }
+
+        text
+    }
+
+    /// Inserts the given text with the given style at the given position and
+    /// returns the position after it.
+    ///
+    /// Line breaks in the text split the block.
+    pub fn insert(&mut self, position: Position, text: &str, style: &SpanStyle) -> Position {
+        let mut position = position;
+
+        for (index, line) in text.split('\n').enumerate() {
+            if index > 0 {
+                position = self.split(position);
+            }
+
+            let block = &mut self.blocks[position.block];
+            let mut chars = block.chars();
+            let at = char_index(&chars, position.offset);
+            let _ = chars.splice(at..at, line.chars().map(|c| (c, style.clone())));
+            block.set_chars(chars);
+
+            position.offset += line.len();
+        }
+
+        position
+    }
+
+    /// Splits the block at the given position and returns the position at the
+    /// start of the new block, which continues the kind of the split block.
+    pub fn split(&mut self, position: Position) -> Position {
+        let block = &mut self.blocks[position.block];
+        let mut chars = block.chars();
+        let tail = chars.split_off(char_index(&chars, position.offset));
+        block.set_chars(chars);
+
+        let mut next = Block::new(block.kind);
+        next.set_chars(tail);
+        self.blocks.insert(position.block + 1, next);
+
+        Position::new(position.block + 1, 0)
+    }
+
+    /// Deletes the given range, joining the blocks at its ends.
+    pub fn delete(&mut self, range: Range<Position>) {
+        let (start, end) = (range.start, range.end);
+        if start >= end {
+            return;
+        }
+
+        let mut chars = self.blocks[start.block].chars();
+        let tail = {
+            let end_chars = self.blocks[end.block].chars();
+            let at = char_index(&end_chars, end.offset);
+            end_chars[at..].to_vec()
+        };
+        chars.truncate(char_index(&chars, start.offset));
+        chars.extend(tail);
+
+        self.blocks[start.block].set_chars(chars);
+        let _ = self.blocks.drain(start.block + 1..=end.block);
+    }
+
+    /// Returns `true` if all of the text in the given range has the given
+    /// [`Format`].
+    ///
+    /// An empty range has no format.
+    #[must_use]
+    pub fn has_format(&self, range: Range<Position>, format: Format) -> bool {
+        let mut any = false;
+        let mut all = true;
+
+        self.each_char(range, |_, style| {
+            any = true;
+            all &= style.has(format);
+        });
+
+        any && all
+    }
+
+    /// Toggles the given [`Format`] of the text in the given range.
+    ///
+    /// The format is removed if all of the text has it, and added otherwise.
+    pub fn toggle(&mut self, range: Range<Position>, format: Format) {
+        let value = !self.has_format(range.clone(), format);
+        self.restyle(range, |style| style.set(format, value));
+    }
+
+    /// Sets the target of the link of the text in the given range, or removes
+    /// the link.
+    pub fn set_link(&mut self, range: Range<Position>, link: Option<&str>) {
+        self.restyle(range, |style| style.link = link.map(str::to_owned));
+    }
+
+    /// Returns the target of the link at the given position.
+    #[must_use]
+    pub fn link_at(&self, position: Position) -> Option<String> {
+        let block = self.blocks.get(position.block)?;
+        let chars = block.chars();
+
+        chars
+            .get(char_index(&chars, position.offset))
+            .and_then(|(_, style)| style.link.clone())
+    }
+
+    /// Sets the kind of the given blocks.
+    pub fn set_kind(&mut self, blocks: Range<usize>, kind: BlockKind) {
+        for block in &mut self.blocks[blocks] {
+            block.kind = kind;
+        }
+    }
+
+    /// Calls the given function with each character in the given range and
+    /// its style.
+    fn each_char(&self, range: Range<Position>, mut f: impl FnMut(char, &SpanStyle)) {
+        for block in range.start.block..=range.end.block {
+            let chars = self.blocks[block].chars();
+            let (start, end) = block_range(&chars, block, &range);
+
+            for (c, style) in &chars[start..end] {
+                f(*c, style);
+            }
+        }
+    }
+
+    /// Changes the style of each character in the given range.
+    fn restyle(&mut self, range: Range<Position>, f: impl Fn(&mut SpanStyle)) {
+        for block in range.start.block..=range.end.block {
+            let mut chars = self.blocks[block].chars();
+            let (start, end) = block_range(&chars, block, &range);
+
+            for (_, style) in &mut chars[start..end] {
+                f(style);
+            }
+            self.blocks[block].set_chars(chars);
+        }
+    }
+
+    /// Parses a [`Document`] from Markdown.
+    ///
+    /// Each line is a block: lines starting with `- ` or `* ` are bulleted
+    /// items and lines starting with a number and `. ` are numbered items.
+    /// Inline `**bold**`, `*italic*`, `<u>underline</u>`, `` `code` `` and
+    /// `[links](target)` are recognized, and `\` escapes a character.
+    #[must_use]
+    pub fn from_markdown(markdown: &str) -> Self {
+        Self {
+            blocks: markdown.split('\n').map(parse_block).collect(),
+        }
+    }
+
+    /// Serializes the [`Document`] to Markdown, with one line per block, as
+    /// read by [`from_markdown`](Self::from_markdown).
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::new();
+        let mut number = 0;
+
+        for (index, block) in self.blocks.iter().enumerate() {
+            if index > 0 {
+                markdown.push('\n');
+            }
+
+            let mut line = String::new();
+            write_spans(&mut line, &block.spans);
+
+            match block.kind {
+                BlockKind::Paragraph => {
+                    number = 0;
+
+                    // A paragraph looking like a list item is escaped.
+                    if parse_block(&line).kind != BlockKind::Paragraph {
+                        markdown.push('\\');
+                    }
+                }
+                BlockKind::Bullet => {
+                    number = 0;
+                    markdown.push_str("- ");
+                }
+                BlockKind::Numbered => {
+                    number += 1;
+                    markdown.push_str(&number.to_string());
+                    markdown.push_str(". ");
+                }
+            }
+
+            markdown.push_str(&line);
+        }
+
+        markdown
+    }
+}
+
+/// Returns the index in the given characters of the given byte offset.
+fn char_index(chars: &[(char, SpanStyle)], offset: usize) -> usize {
+    let mut bytes = 0;
+
+    chars
+        .iter()
+        .position(|(c, _)| {
+            let reached = bytes >= offset;
+            bytes += c.len_utf8();
+            reached
+        })
+        .unwrap_or(chars.len())
+}
+
+/// Returns the range of the given characters of the given block inside of
+/// the given range of positions.
+fn block_range(
+    chars: &[(char, SpanStyle)],
+    block: usize,
+    range: &Range<Position>,
+) -> (usize, usize) {
+    let start = if block == range.start.block {
+        char_index(chars, range.start.offset)
+    } else {
+        0
+    };
+    let end = if block == range.end.block {
+        char_index(chars, range.end.offset)
+    } else {
+        chars.len()
+    };
+
+    (start, end.max(start))
+}
+
+/// The characters escaped in the Markdown of a [`Document`].
+const ESCAPED: &[char] = &['\\', '*', '`', '[', ']', '<'];
+
+/// Writes the given spans as Markdown.
+fn write_spans(markdown: &mut String, spans: &[Span]) {
+    let mut index = 0;
+
+    while index < spans.len() {
+        // The neighbouring spans of a link are written as one link.
+        let link = spans[index].style.link.clone();
+        let end = spans[index..]
+            .iter()
+            .position(|span| span.style.link != link)
+            .map_or(spans.len(), |end| index + end);
+
+        if link.is_some() {
+            markdown.push('[');
+        }
+
+        for span in &spans[index..end] {
+            write_span(markdown, span);
+        }
+
+        if let Some(link) = link {
+            markdown.push_str("](");
+            markdown.push_str(&link);
+            markdown.push(')');
+        }
+
+        index = end;
+    }
+}
+
+/// Writes the given span as Markdown.
+fn write_span(markdown: &mut String, span: &Span) {
+    let style = &span.style;
+    let (open, close) = (
+        [
+            (style.bold, "**"),
+            (style.italic, "*"),
+            (style.underline, "<u>"),
+        ],
+        [
+            (style.underline, "</u>"),
+            (style.italic, "*"),
+            (style.bold, "**"),
+        ],
+    );
+
+    for (_, marker) in open.iter().filter(|(on, _)| *on) {
+        markdown.push_str(marker);
+    }
+
+    if style.code {
+        // The fence is longer than any run of backticks in the code.
+        let longest = span
+            .text
+            .split(|c| c != '`')
+            .map(str::len)
+            .max()
+            .unwrap_or(0);
+        let fence = "`".repeat(longest + 1);
+        let padding = if longest > 0 { " " } else { "" };
+
+        markdown.push_str(&fence);
+        markdown.push_str(padding);
+        markdown.push_str(&span.text);
+        markdown.push_str(padding);
+        markdown.push_str(&fence);
+    } else {
+        for c in span.text.chars() {
+            if ESCAPED.contains(&c) {
+                markdown.push('\\');
+            }
+            markdown.push(c);
+        }
+    }
+
+    for (_, marker) in close.iter().filter(|(on, _)| *on) {
+        markdown.push_str(marker);
+    }
+}
+
+/// Parses a line of Markdown as a [`Block`].
+fn parse_block(line: &str) -> Block {
+    let numbered = line
+        .find(". ")
+        .filter(|digits| *digits > 0 && line[..*digits].chars().all(|c| c.is_ascii_digit()));
+
+    let bullet = line.strip_prefix("- ").or_else(|| line.strip_prefix("* "));
+
+    let (kind, content) = match (bullet, numbered) {
+        (Some(content), _) => (BlockKind::Bullet, content),
+        (None, Some(digits)) => (BlockKind::Numbered, &line[digits + 2..]),
+        (None, None) => (BlockKind::Paragraph, line),
+    };
+
+    let mut block = Block::new(kind);
+    block.set_chars(parse_inline(content));
+    block
+}
+
+/// Parses inline Markdown into styled characters.
+fn parse_inline(content: &str) -> Vec<(char, SpanStyle)> {
+    let mut chars = Vec::new();
+    let mut style = SpanStyle::default();
+    // The index of the first character of the link being parsed.
+    let mut link_start = None;
+    let mut rest = content;
+
+    while let Some(c) = rest.chars().next() {
+        if let Some(escaped) = rest.strip_prefix('\\').and_then(|rest| rest.chars().next()) {
+            chars.push((escaped, style.clone()));
+            rest = &rest[1 + escaped.len_utf8()..];
+        } else if c == '`' {
+            let fence = rest.len() - rest.trim_start_matches('`').len();
+            let code = &rest[fence..];
+
+            if let Some(end) = code.find(&rest[..fence]) {
+                let text = &code[..end];
+                let text = if fence > 1 {
+                    text.strip_prefix(' ')
+                        .and_then(|text| text.strip_suffix(' '))
+                        .unwrap_or(text)
+                } else {
+                    text
+                };
+
+                let code_style = SpanStyle {
+                    code: true,
+                    ..style.clone()
+                };
+                chars.extend(text.chars().map(|c| (c, code_style.clone())));
+                rest = &code[end + fence..];
+            } else {
+                chars.extend(rest[..fence].chars().map(|c| (c, style.clone())));
+                rest = code;
+            }
+        } else if let Some(after) = rest.strip_prefix("**") {
+            style.bold = !style.bold;
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix('*') {
+            style.italic = !style.italic;
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix("<u>") {
+            style.underline = true;
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix("</u>") {
+            style.underline = false;
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix('[') {
+            link_start = Some(chars.len());
+            rest = after;
+        } else if let (Some(start), Some(after)) = (link_start, rest.strip_prefix("](")) {
+            let Some(end) = after.find(')') else {
+                break;
+            };
+
+            for (_, style) in &mut chars[start..] {
+                style.link = Some(after[..end].to_owned());
+            }
+            link_start = None;
+            rest = &after[end + 1..];
+        } else {
+            chars.push((c, style.clone()));
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+
+    // An unclosed link is plain text.
+    if let Some(start) = link_start {
+        let style = chars
+            .get(start)
+            .map_or_else(SpanStyle::default, |(_, style)| style.clone());
+        chars.insert(start, ('[', style));
+    }
+
+    chars
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BlockKind, Document, Format, Position, SpanStyle};
+
+    #[test]
+    fn edit_test() {
+        let mut document = Document::new();
+
+        let end = document.insert(Position::new(0, 0), "Hello world", &SpanStyle::default());
+        assert_eq!(end, Position::new(0, 11));
+
+        document.toggle(Position::new(0, 6)..end, Format::Bold);
+        assert!(document.has_format(Position::new(0, 6)..end, Format::Bold));
+        assert!(!document.has_format(Position::new(0, 0)..end, Format::Bold));
+        assert_eq!(document.blocks[0].spans.len(), 2);
+
+        let end = document.insert(end, "!\nSecond", &SpanStyle::default());
+        assert_eq!(end, Position::new(1, 6));
+        assert_eq!(document.text(Position::new(0, 6)..end), "world!\nSecond");
+
+        document.delete(Position::new(0, 5)..Position::new(1, 0));
+        assert_eq!(document.blocks.len(), 1);
+        assert_eq!(document.blocks[0].text(), "HelloSecond");
+        assert_eq!(document.blocks[0].spans.len(), 1);
+    }
+
+    #[test]
+    fn markdown_test() {
+        let markdown = "Some **bold *and italic*** and <u>underlined</u> text\n\
+            - a `code` item with a [**link**](https://iced.rs)\n\
+            1. first\n\
+            2. escaped \\* and ``a`b``";
+        let document = Document::from_markdown(markdown);
+
+        assert_eq!(document.blocks.len(), 4);
+        assert_eq!(document.blocks[1].kind, BlockKind::Bullet);
+        assert_eq!(document.blocks[3].kind, BlockKind::Numbered);
+        assert_eq!(
+            document.blocks[0].text(),
+            "Some bold and italic and underlined text"
+        );
+        assert!(document.has_format(Position::new(0, 10)..Position::new(0, 20), Format::Italic));
+        assert!(document.has_format(Position::new(0, 5)..Position::new(0, 20), Format::Bold));
+        assert!(document.has_format(
+            Position::new(0, 25)..Position::new(0, 35),
+            Format::Underline
+        ));
+        assert_eq!(
+            document.link_at(Position::new(1, 20)).as_deref(),
+            Some("https://iced.rs")
+        );
+        assert_eq!(document.blocks[3].text(), "escaped * and a`b");
+
+        assert_eq!(Document::from_markdown(&document.to_markdown()), document);
+        assert_eq!(Document::from_markdown(""), Document::new());
+    }
+}
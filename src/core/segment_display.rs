@@ -0,0 +1,399 @@
+//! Map characters to the lit segments of a segment display.
+//!
+//! *This API requires the following crate features to be activated: `segment_display`*
+use iced_widget::core::{Point, Size};
+
+/// The top segment.
+pub const A: u16 = 1 << 0;
+/// The upper right segment.
+pub const B: u16 = 1 << 1;
+/// The lower right segment.
+pub const C: u16 = 1 << 2;
+/// The bottom segment.
+pub const D: u16 = 1 << 3;
+/// The lower left segment.
+pub const E: u16 = 1 << 4;
+/// The upper left segment.
+pub const F: u16 = 1 << 5;
+/// The left half of the middle segment.
+pub const G1: u16 = 1 << 6;
+/// The right half of the middle segment.
+pub const G2: u16 = 1 << 7;
+/// The upper left diagonal segment of a fourteen-segment display.
+pub const H: u16 = 1 << 8;
+/// The upper vertical center segment of a fourteen-segment display.
+pub const I: u16 = 1 << 9;
+/// The upper right diagonal segment of a fourteen-segment display.
+pub const J: u16 = 1 << 10;
+/// The lower left diagonal segment of a fourteen-segment display.
+pub const K: u16 = 1 << 11;
+/// The lower vertical center segment of a fourteen-segment display.
+pub const L: u16 = 1 << 12;
+/// The lower right diagonal segment of a fourteen-segment display.
+pub const M: u16 = 1 << 13;
+
+/// The middle segment, which is a single segment on a seven-segment display.
+pub const G: u16 = G1 | G2;
+
+/// The number of segments of each character of a segment display.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Segments {
+    /// Seven segments showing digits and a limited set of letters.
+    #[default]
+    Seven,
+    /// Fourteen segments, with diagonal and center segments, showing digits,
+    /// all letters and a few symbols.
+    Fourteen,
+}
+
+impl Segments {
+    /// Returns the segments of the given number of [`Segments`] in the
+    /// order of their bits.
+    #[must_use]
+    pub fn all(self) -> &'static [u16] {
+        match self {
+            Self::Seven => &[A, B, C, D, E, F, G],
+            Self::Fourteen => &[A, B, C, D, E, F, G1, G2, H, I, J, K, L, M],
+        }
+    }
+}
+
+/// Returns the lit segments showing the given character on a display with
+/// the given number of [`Segments`], if it can be shown.
+///
+/// A seven-segment display shows the letters in the case they can be told
+/// apart in, e.g. `b` and `d` in lower case.
+#[must_use]
+pub fn glyph(character: char, segments: Segments) -> Option<u16> {
+    match segments {
+        Segments::Seven => seven(character)
+            .or_else(|| seven(character.to_ascii_uppercase()))
+            .or_else(|| seven(character.to_ascii_lowercase())),
+        Segments::Fourteen => fourteen(character.to_ascii_uppercase()),
+    }
+}
+
+/// Returns the lit segments of the given character on a seven-segment
+/// display.
+fn seven(character: char) -> Option<u16> {
+    Some(match character {
+        ' ' => 0,
+        '0' | 'O' => A | B | C | D | E | F,
+        '1' => B | C,
+        '2' => A | B | G | E | D,
+        '3' => A | B | G | C | D,
+        '4' => F | G | B | C,
+        '5' | 'S' => A | F | G | C | D,
+        '6' => A | F | G | E | C | D,
+        '7' => A | B | C,
+        '8' => A | B | C | D | E | F | G,
+        '9' => A | B | C | D | F | G,
+        'A' => A | B | C | E | F | G,
+        'b' => C | D | E | F | G,
+        'C' => A | D | E | F,
+        'c' => D | E | G,
+        'd' => B | C | D | E | G,
+        'E' => A | D | E | F | G,
+        'F' => A | E | F | G,
+        'G' => A | C | D | E | F,
+        'H' => B | C | E | F | G,
+        'h' => C | E | F | G,
+        'I' => E | F,
+        'J' => B | C | D | E,
+        'L' => D | E | F,
+        'n' => C | E | G,
+        'o' => C | D | E | G,
+        'P' => A | B | E | F | G,
+        'q' => A | B | C | F | G,
+        'r' => E | G,
+        't' => D | E | F | G,
+        'U' => B | C | D | E | F,
+        'u' => C | D | E,
+        'y' => B | C | D | F | G,
+        '-' => G,
+        '_' => D,
+        '=' => D | G,
+        _ => return None,
+    })
+}
+
+/// Returns the lit segments of the given upper case character on a
+/// fourteen-segment display.
+fn fourteen(character: char) -> Option<u16> {
+    Some(match character {
+        ' ' => 0,
+        '0' => A | B | C | D | E | F | J | K,
+        '1' => B | C | J,
+        '2' => A | B | G | E | D,
+        '3' => A | B | G2 | C | D,
+        '4' => F | G | B | C,
+        '5' | 'S' => A | F | G | C | D,
+        '6' => A | F | G | E | C | D,
+        '7' => A | B | C,
+        '8' => A | B | C | D | E | F | G,
+        '9' => A | B | C | D | F | G,
+        'A' => A | B | C | E | F | G,
+        'B' => A | B | C | D | G2 | I | L,
+        'C' => A | D | E | F,
+        'D' => A | B | C | D | I | L,
+        'E' => A | D | E | F | G1,
+        'F' => A | E | F | G1,
+        'G' => A | C | D | E | F | G2,
+        'H' => B | C | E | F | G,
+        'I' => A | D | I | L,
+        'J' => B | C | D | E,
+        'K' => E | F | G1 | J | M,
+        'L' => D | E | F,
+        'M' => B | C | E | F | H | J,
+        'N' => B | C | E | F | H | M,
+        'O' => A | B | C | D | E | F,
+        'P' => A | B | E | F | G,
+        'Q' => A | B | C | D | E | F | M,
+        'R' => A | B | E | F | G | M,
+        'T' => A | I | L,
+        'U' => B | C | D | E | F,
+        'V' => E | F | J | K,
+        'W' => B | C | E | F | K | M,
+        'X' => H | J | K | M,
+        'Y' => H | J | L,
+        'Z' => A | D | J | K,
+        '-' => G,
+        '_' => D,
+        '=' => D | G,
+        '+' => G | I | L,
+        '*' => G | H | I | J | K | L | M,
+        '/' => J | K,
+        '\\' => H | M,
+        _ => return None,
+    })
+}
+
+/// A character cell of a segment display.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Cell {
+    /// A character with its lit segments and whether its decimal point is
+    /// lit.
+    Glyph {
+        /// The lit segments.
+        segments: u16,
+        /// Whether the decimal point is lit.
+        point: bool,
+    },
+    /// A colon between two characters, e.g. of a clock.
+    Colon,
+}
+
+/// Returns the cells showing the given text on a display with the given
+/// number of [`Segments`].
+///
+/// A `.` lights the decimal point of the character before it, a `:` is shown
+/// as a colon, and the characters that can't be shown are left blank.
+#[must_use]
+pub fn cells(text: &str, segments: Segments) -> Vec<Cell> {
+    let mut cells: Vec<Cell> = Vec::new();
+
+    for character in text.chars() {
+        match (character, cells.last_mut()) {
+            ('.', Some(Cell::Glyph { point, .. })) if !*point => *point = true,
+            ('.', _) => cells.push(Cell::Glyph {
+                segments: 0,
+                point: true,
+            }),
+            (':', _) => cells.push(Cell::Colon),
+            (character, _) => cells.push(Cell::Glyph {
+                segments: glyph(character, segments).unwrap_or(0),
+                point: false,
+            }),
+        }
+    }
+
+    cells
+}
+
+/// Returns the corners of the given segment of a character of the given size
+/// with segments of the given thickness, before it is slanted.
+///
+/// The corners are relative to the top left corner of the character.
+#[must_use]
+pub fn segment_polygon(segment: u16, size: Size, thickness: f32) -> Vec<Point> {
+    let half = thickness / 2.0;
+    // The gap between the ends of two segments.
+    let gap = thickness * 0.15;
+    let (left, right) = (half, size.width - half);
+    let (top, middle, bottom) = (half, size.height / 2.0, size.height - half);
+    let center = size.width / 2.0;
+
+    let horizontal = |from: f32, to: f32, y: f32| {
+        let (from, to) = (from + gap, to - gap);
+        vec![
+            Point::new(from, y),
+            Point::new(from + half, y - half),
+            Point::new(to - half, y - half),
+            Point::new(to, y),
+            Point::new(to - half, y + half),
+            Point::new(from + half, y + half),
+        ]
+    };
+    let vertical = |x: f32, from: f32, to: f32| {
+        let (from, to) = (from + gap, to - gap);
+        vec![
+            Point::new(x, from),
+            Point::new(x + half, from + half),
+            Point::new(x + half, to - half),
+            Point::new(x, to),
+            Point::new(x - half, to - half),
+            Point::new(x - half, from + half),
+        ]
+    };
+    // The diagonals fill the corners between the other segments.
+    let diagonal = |from: Point, to: Point| {
+        let (inset_x, inset_y) = (half + gap * 2.0, half + gap * 2.0);
+        let sign_x = (to.x - from.x).signum();
+        let sign_y = (to.y - from.y).signum();
+        let from = Point::new(from.x + sign_x * inset_x, from.y + sign_y * inset_y);
+        let to = Point::new(to.x - sign_x * inset_x, to.y - sign_y * inset_y);
+        let width = half * 0.8;
+
+        vec![
+            Point::new(from.x, from.y),
+            Point::new(from.x + sign_x * width, from.y),
+            Point::new(to.x, to.y - sign_y * width),
+            Point::new(to.x, to.y),
+            Point::new(to.x - sign_x * width, to.y),
+            Point::new(from.x, from.y + sign_y * width),
+        ]
+    };
+
+    match segment {
+        A => horizontal(left, right, top),
+        B => vertical(right, top, middle),
+        C => vertical(right, middle, bottom),
+        D => horizontal(left, right, bottom),
+        E => vertical(left, middle, bottom),
+        F => vertical(left, top, middle),
+        G => horizontal(left, right, middle),
+        G1 => horizontal(left, center, middle),
+        G2 => horizontal(center, right, middle),
+        H => diagonal(Point::new(left, top), Point::new(center, middle)),
+        I => vertical(center, top, middle),
+        J => diagonal(Point::new(right, top), Point::new(center, middle)),
+        K => diagonal(Point::new(left, bottom), Point::new(center, middle)),
+        L => vertical(center, middle, bottom),
+        M => diagonal(Point::new(right, bottom), Point::new(center, middle)),
+        _ => Vec::new(),
+    }
+}
+
+/// Slants the given point of a character of the given height to the right
+/// by the given fraction of its height above the bottom.
+#[must_use]
+pub fn slant(point: Point, height: f32, slant: f32) -> Point {
+    Point::new(point.x + (height - point.y) * slant, point.y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        cells, glyph, segment_polygon, slant, Cell, Segments, A, B, C, D, E, F, G, G1, G2, I, L,
+    };
+    use iced_widget::core::{Point, Size};
+
+    #[test]
+    fn glyph_test() {
+        assert_eq!(glyph('8', Segments::Seven), Some(A | B | C | D | E | F | G));
+        assert_eq!(glyph('1', Segments::Seven), Some(B | C));
+        // Letters fall back to the case that can be shown.
+        assert_eq!(glyph('B', Segments::Seven), glyph('b', Segments::Seven));
+        assert_eq!(glyph('a', Segments::Seven), glyph('A', Segments::Seven));
+        assert_eq!(glyph('W', Segments::Seven), None);
+
+        assert_eq!(glyph('t', Segments::Fourteen), Some(A | I | L));
+        assert!(glyph('W', Segments::Fourteen).is_some());
+        assert_eq!(glyph('#', Segments::Fourteen), None);
+
+        // Every digit and letter can be shown on fourteen segments.
+        assert!(('0'..='9')
+            .chain('A'..='Z')
+            .all(|character| glyph(character, Segments::Fourteen).is_some()));
+    }
+
+    #[test]
+    fn cells_test() {
+        assert_eq!(
+            cells("1.5:.", Segments::Seven),
+            vec![
+                Cell::Glyph {
+                    segments: B | C,
+                    point: true,
+                },
+                Cell::Glyph {
+                    segments: A | F | G | C | D,
+                    point: false,
+                },
+                Cell::Colon,
+                Cell::Glyph {
+                    segments: 0,
+                    point: true,
+                },
+            ]
+        );
+        // A second point lights its own cell, and unknown characters are
+        // blank.
+        assert_eq!(
+            cells("..#", Segments::Seven),
+            vec![
+                Cell::Glyph {
+                    segments: 0,
+                    point: true,
+                },
+                Cell::Glyph {
+                    segments: 0,
+                    point: true,
+                },
+                Cell::Glyph {
+                    segments: 0,
+                    point: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn segment_polygon_test() {
+        let size = Size::new(20.0, 40.0);
+        let bounds = |segment| {
+            segment_polygon(segment, size, 4.0).iter().fold(
+                (
+                    f32::INFINITY,
+                    f32::INFINITY,
+                    f32::NEG_INFINITY,
+                    f32::NEG_INFINITY,
+                ),
+                |(x0, y0, x1, y1), point| {
+                    (
+                        x0.min(point.x),
+                        y0.min(point.y),
+                        x1.max(point.x),
+                        y1.max(point.y),
+                    )
+                },
+            )
+        };
+
+        // The segments stay within the character.
+        for segment in Segments::Fourteen.all().iter().chain([&G]) {
+            let (x0, y0, x1, y1) = bounds(*segment);
+            assert!(x0 >= 0.0 && y0 >= 0.0 && x1 <= 20.0 && y1 <= 40.0);
+        }
+
+        // The halves of the middle segment meet in the center.
+        assert!(bounds(G1).2 <= 10.0 && bounds(G2).0 >= 10.0);
+        assert!(bounds(A).1.abs() < f32::EPSILON);
+        assert!((bounds(D).3 - 40.0).abs() < f32::EPSILON);
+        assert!(segment_polygon(0, size, 4.0).is_empty());
+
+        let slanted = slant(Point::new(0.0, 0.0), 40.0, 0.1);
+        assert!((slanted.x - 4.0).abs() < f32::EPSILON);
+        assert!(slant(Point::new(0.0, 40.0), 40.0, 0.1).x.abs() < f32::EPSILON);
+    }
+}
@@ -0,0 +1,190 @@
+//! Helper functions and structs for recording keyboard shortcuts.
+
+use iced_widget::core::keyboard::{KeyCode, Modifiers};
+use std::fmt;
+
+/// A key combination, e.g. `Ctrl+Shift+S`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+    /// The modifiers held while pressing the key.
+    pub modifiers: Modifiers,
+    /// The pressed key.
+    pub key_code: KeyCode,
+}
+
+impl Shortcut {
+    /// Creates a new [`Shortcut`] of the given modifiers and key.
+    #[must_use]
+    pub const fn new(modifiers: Modifiers, key_code: KeyCode) -> Self {
+        Self {
+            modifiers,
+            key_code,
+        }
+    }
+
+    /// Returns the labels of the keys of the [`Shortcut`], the modifiers
+    /// first.
+    #[must_use]
+    pub fn keys(&self) -> Vec<String> {
+        modifier_labels(self.modifiers)
+            .into_iter()
+            .map(str::to_owned)
+            .chain(std::iter::once(key_label(self.key_code)))
+            .collect()
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.keys().join("+"))
+    }
+}
+
+/// Returns the labels of the held modifiers, in the conventional order.
+#[must_use]
+pub fn modifier_labels(modifiers: Modifiers) -> Vec<&'static str> {
+    [
+        (Modifiers::CTRL, "Ctrl"),
+        (Modifiers::ALT, "Alt"),
+        (Modifiers::SHIFT, "Shift"),
+        (
+            Modifiers::LOGO,
+            if cfg!(target_os = "macos") {
+                "Cmd"
+            } else {
+                "Super"
+            },
+        ),
+    ]
+    .into_iter()
+    .filter(|(modifier, _)| modifiers.contains(*modifier))
+    .map(|(_, label)| label)
+    .collect()
+}
+
+/// Returns the label of the key on its keycap.
+#[must_use]
+pub fn key_label(key_code: KeyCode) -> String {
+    let label = match key_code {
+        KeyCode::Key0 | KeyCode::Numpad0 => "0",
+        KeyCode::Key1 | KeyCode::Numpad1 => "1",
+        KeyCode::Key2 | KeyCode::Numpad2 => "2",
+        KeyCode::Key3 | KeyCode::Numpad3 => "3",
+        KeyCode::Key4 | KeyCode::Numpad4 => "4",
+        KeyCode::Key5 | KeyCode::Numpad5 => "5",
+        KeyCode::Key6 | KeyCode::Numpad6 => "6",
+        KeyCode::Key7 | KeyCode::Numpad7 => "7",
+        KeyCode::Key8 | KeyCode::Numpad8 => "8",
+        KeyCode::Key9 | KeyCode::Numpad9 => "9",
+        KeyCode::Escape => "Esc",
+        KeyCode::Delete => "Del",
+        KeyCode::Insert => "Ins",
+        KeyCode::PageUp => "PgUp",
+        KeyCode::PageDown => "PgDn",
+        KeyCode::Left => "←",
+        KeyCode::Up => "↑",
+        KeyCode::Right => "→",
+        KeyCode::Down => "↓",
+        KeyCode::Enter | KeyCode::NumpadEnter => "Enter",
+        KeyCode::Snapshot => "PrtSc",
+        KeyCode::Apostrophe => "'",
+        KeyCode::Backslash => "\\",
+        KeyCode::Comma | KeyCode::NumpadComma => ",",
+        KeyCode::Equals | KeyCode::NumpadEquals => "=",
+        KeyCode::Grave => "`",
+        KeyCode::LBracket => "[",
+        KeyCode::RBracket => "]",
+        KeyCode::Minus | KeyCode::NumpadSubtract => "-",
+        KeyCode::Period | KeyCode::NumpadDecimal => ".",
+        KeyCode::Plus | KeyCode::NumpadAdd => "+",
+        KeyCode::Semicolon => ";",
+        KeyCode::Slash | KeyCode::NumpadDivide => "/",
+        KeyCode::Asterisk | KeyCode::NumpadMultiply => "*",
+        _ => return format!("{key_code:?}"),
+    };
+
+    label.to_owned()
+}
+
+/// Returns true if the key is a modifier, which can't make a [`Shortcut`]
+/// on its own.
+#[must_use]
+pub const fn is_modifier(key_code: KeyCode) -> bool {
+    matches!(
+        key_code,
+        KeyCode::LShift
+            | KeyCode::RShift
+            | KeyCode::LControl
+            | KeyCode::RControl
+            | KeyCode::LAlt
+            | KeyCode::RAlt
+            | KeyCode::LWin
+            | KeyCode::RWin
+    )
+}
+
+/// Returns true if the key is a function key, which makes a [`Shortcut`]
+/// without modifiers.
+#[must_use]
+pub const fn is_function_key(key_code: KeyCode) -> bool {
+    matches!(
+        key_code,
+        KeyCode::F1
+            | KeyCode::F2
+            | KeyCode::F3
+            | KeyCode::F4
+            | KeyCode::F5
+            | KeyCode::F6
+            | KeyCode::F7
+            | KeyCode::F8
+            | KeyCode::F9
+            | KeyCode::F10
+            | KeyCode::F11
+            | KeyCode::F12
+            | KeyCode::F13
+            | KeyCode::F14
+            | KeyCode::F15
+            | KeyCode::F16
+            | KeyCode::F17
+            | KeyCode::F18
+            | KeyCode::F19
+            | KeyCode::F20
+            | KeyCode::F21
+            | KeyCode::F22
+            | KeyCode::F23
+            | KeyCode::F24
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_widget::core::keyboard::{KeyCode, Modifiers};
+
+    use super::{is_function_key, is_modifier, Shortcut};
+
+    #[test]
+    fn display() {
+        let save = Shortcut::new(Modifiers::SHIFT | Modifiers::CTRL, KeyCode::S);
+        assert_eq!(save.to_string(), "Ctrl+Shift+S");
+        assert_eq!(save.keys(), vec!["Ctrl", "Shift", "S"]);
+
+        let help = Shortcut::new(Modifiers::empty(), KeyCode::F1);
+        assert_eq!(help.to_string(), "F1");
+
+        let tab = Shortcut::new(Modifiers::ALT, KeyCode::Key1);
+        assert_eq!(tab.to_string(), "Alt+1");
+
+        let back = Shortcut::new(Modifiers::ALT, KeyCode::Left);
+        assert_eq!(back.to_string(), "Alt+←");
+    }
+
+    #[test]
+    fn keys() {
+        assert!(is_modifier(KeyCode::LControl));
+        assert!(is_modifier(KeyCode::RWin));
+        assert!(!is_modifier(KeyCode::A));
+
+        assert!(is_function_key(KeyCode::F12));
+        assert!(!is_function_key(KeyCode::Escape));
+    }
+}
@@ -0,0 +1,85 @@
+//! Count the lit bars of a signal strength indicator.
+//!
+//! *This API requires the following crate features to be activated: `signal_bars`*
+use std::time::Duration;
+
+/// The most bars a signal strength indicator shows.
+pub const MAX_BARS: u8 = 5;
+
+/// The shape of a signal strength indicator.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Shape {
+    /// Bars rising from left to right, like on a phone.
+    #[default]
+    Bars,
+    /// Arcs around a dot, like a wifi symbol.
+    Arcs,
+}
+
+/// The state of the connection shown by a signal strength indicator.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// The connection is up and its level is shown.
+    #[default]
+    Connected,
+    /// A connection is searched for, sweeping through the bars.
+    Searching,
+    /// The connection failed, crossing out the bars.
+    Error,
+}
+
+/// Returns how many of the given number of bars are lit for a level from
+/// `0.0` to `1.0`.
+#[must_use]
+pub fn lit_bars(level: f32, count: u8) -> u8 {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let lit = (level.clamp(0.0, 1.0) * f32::from(count)).round() as u8;
+
+    lit
+}
+
+/// Returns how many of the given number of bars are lit after the given time
+/// of searching, lighting one more bar every step and starting over after
+/// all of them, and the time until the next step.
+#[must_use]
+pub fn sweep(count: u8, elapsed: Duration, step: Duration) -> (u8, Option<Duration>) {
+    if count == 0 || step.is_zero() {
+        return (count, None);
+    }
+
+    let steps = elapsed.as_nanos() / step.as_nanos();
+    #[allow(clippy::cast_possible_truncation)]
+    let lit = (steps % u128::from(count)) as u8 + 1;
+    #[allow(clippy::cast_possible_truncation)]
+    let until = Duration::from_nanos((step.as_nanos() * (steps + 1) - elapsed.as_nanos()) as u64);
+
+    (lit, Some(until))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lit_bars, sweep};
+    use std::time::Duration;
+
+    #[test]
+    fn lit_bars_test() {
+        assert_eq!(lit_bars(0.0, 4), 0);
+        assert_eq!(lit_bars(0.1, 4), 0);
+        assert_eq!(lit_bars(0.5, 4), 2);
+        assert_eq!(lit_bars(0.9, 5), 5);
+        assert_eq!(lit_bars(2.0, 3), 3);
+        assert_eq!(lit_bars(-1.0, 3), 0);
+        assert_eq!(lit_bars(f32::NAN, 3), 0);
+    }
+
+    #[test]
+    fn sweep_test() {
+        let millis = Duration::from_millis;
+
+        assert_eq!(sweep(3, millis(0), millis(300)), (1, Some(millis(300))));
+        assert_eq!(sweep(3, millis(350), millis(300)), (2, Some(millis(250))));
+        assert_eq!(sweep(3, millis(600), millis(300)), (3, Some(millis(300))));
+        assert_eq!(sweep(3, millis(900), millis(300)), (1, Some(millis(300))));
+        assert_eq!(sweep(3, millis(10), Duration::ZERO), (3, None));
+    }
+}
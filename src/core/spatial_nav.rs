@@ -0,0 +1,286 @@
+//! Move the keyboard focus to the geometrically nearest focusable widget in
+//! the direction of an arrow key, e.g. for user interfaces driven by the
+//! directional pad of a remote control.
+//!
+//! The application turns the pressed keys into [`Direction`]s, e.g. from a
+//! subscription, and runs the [`Command`] produced by [`spatial_nav`] with
+//! the [`SpatialNav`] of the shown screen.
+//!
+//! A focusable widget takes the bounds of the innermost container around
+//! it. The `ToggleGroup` and the `FocusGroup` report their own bounds, while
+//! other focusable widgets, like text inputs, are wrapped in a
+//! [`container`](iced_widget::container) or a `FocusRing`.
+
+use iced_widget::{
+    core::{
+        keyboard::KeyCode,
+        widget::{
+            operation::{Focusable, Operation, Outcome},
+            Id,
+        },
+        Rectangle,
+    },
+    runtime::Command,
+};
+use std::collections::HashMap;
+
+use super::focus::FocusIndex;
+
+/// A direction of the spatial navigation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Moves the focus to the nearest widget above.
+    Up,
+    /// Moves the focus to the nearest widget below.
+    Down,
+    /// Moves the focus to the nearest widget on the left.
+    Left,
+    /// Moves the focus to the nearest widget on the right.
+    Right,
+}
+
+impl Direction {
+    /// Returns the [`Direction`] of the given arrow key, if it is one.
+    #[must_use]
+    pub const fn from_key_code(key_code: KeyCode) -> Option<Self> {
+        match key_code {
+            KeyCode::Up => Some(Self::Up),
+            KeyCode::Down => Some(Self::Down),
+            KeyCode::Left => Some(Self::Left),
+            KeyCode::Right => Some(Self::Right),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the index of the target nearest to the focused one in the
+/// [`Direction`], given the bounds of all the targets, or `None` if there
+/// is no target in the direction.
+///
+/// Targets across the direction count twice as far as targets along it, so
+/// the focus keeps to its row or column.
+#[must_use]
+pub fn nearest(targets: &[Rectangle], focused: usize, direction: Direction) -> Option<usize> {
+    let center = targets.get(focused)?.center();
+
+    targets
+        .iter()
+        .enumerate()
+        .filter_map(|(index, target)| {
+            let target = target.center();
+            let (dx, dy) = (target.x - center.x, target.y - center.y);
+
+            // The distance along the direction, and across it.
+            let (along, across) = match direction {
+                Direction::Up => (-dy, dx),
+                Direction::Down => (dy, dx),
+                Direction::Left => (-dx, dy),
+                Direction::Right => (dx, dy),
+            };
+
+            (along > 0.5).then(|| (index, across.abs().mul_add(2.0, along)))
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(index, _)| index)
+}
+
+/// The spatial navigation of a screen of the user interface.
+///
+/// By default, the focus moves between all the focusable widgets of the
+/// user interface. The navigation can be limited to the widgets inside of
+/// the container with a given [`Id`], e.g. the shown screen or dialog, and
+/// moves between widgets with an [`Id`] can be set explicitly where the
+/// nearest widget is not the expected one.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SpatialNav {
+    /// The [`Id`] of the container limiting the navigation, if any.
+    scope: Option<Id>,
+    /// The explicitly set moves between widgets.
+    links: HashMap<(Id, Direction), Id>,
+}
+
+impl SpatialNav {
+    /// Creates a new [`SpatialNav`] between all the focusable widgets.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits the [`SpatialNav`] to the focusable widgets inside of the
+    /// container with the given [`Id`].
+    #[must_use]
+    pub fn scope(mut self, id: impl Into<Id>) -> Self {
+        self.scope = Some(id.into());
+        self
+    }
+
+    /// Moves the focus from the widget with the [`Id`] `from` to the widget
+    /// with the [`Id`] `to` in the [`Direction`], instead of to the nearest
+    /// widget.
+    #[must_use]
+    pub fn link(mut self, from: impl Into<Id>, direction: Direction, to: impl Into<Id>) -> Self {
+        let _ = self.links.insert((from.into(), direction), to.into());
+        self
+    }
+}
+
+/// Produces a [`Command`] moving the focus in the [`Direction`] by the
+/// given [`SpatialNav`].
+///
+/// The first focusable widget is focused if none is, and the focus stays
+/// where it is if there is no widget in the direction.
+pub fn spatial_nav<Message: 'static>(
+    direction: Direction,
+    spatial_nav: &SpatialNav,
+) -> Command<Message> {
+    Command::widget(Navigate::new(direction, spatial_nav))
+}
+
+/// A focusable widget found by the [`Navigate`] operation.
+#[derive(Clone, Debug)]
+struct Target {
+    /// The index of the widget among all focusable widgets.
+    index: usize,
+    /// The [`Id`] of the widget, if any.
+    id: Option<Id>,
+    /// The bounds of the widget.
+    bounds: Rectangle,
+}
+
+/// The [`Operation`] of a [`spatial_nav`], finding the focusable widgets and
+/// focusing the one in the [`Direction`].
+#[derive(Clone, Debug)]
+pub struct Navigate {
+    /// The direction to move the focus in.
+    direction: Direction,
+    /// The spatial navigation of the screen.
+    spatial_nav: SpatialNav,
+    /// The bounds of the containers around the next found widget.
+    containers: Vec<Rectangle>,
+    /// The number of containers with the scope around the next found widget.
+    in_scope: usize,
+    /// The number of found focusable widgets.
+    count: usize,
+    /// The found focusable widgets in the scope.
+    targets: Vec<Target>,
+    /// The index of the focused target, if any.
+    focused: Option<usize>,
+}
+
+impl Navigate {
+    /// Creates a new [`Navigate`] operation moving the focus in the
+    /// [`Direction`] by the given [`SpatialNav`].
+    #[must_use]
+    pub fn new(direction: Direction, spatial_nav: &SpatialNav) -> Self {
+        Self {
+            direction,
+            spatial_nav: spatial_nav.clone(),
+            containers: Vec::new(),
+            in_scope: 0,
+            count: 0,
+            targets: Vec::new(),
+            focused: None,
+        }
+    }
+
+    /// Returns the index of the focusable widget to focus among all of them,
+    /// if the focus moves.
+    fn target(&self) -> Option<usize> {
+        let Some(focused) = self.focused else {
+            return self.targets.first().map(|target| target.index);
+        };
+
+        let link = self.targets[focused]
+            .id
+            .as_ref()
+            .and_then(|id| self.spatial_nav.links.get(&(id.clone(), self.direction)));
+        if let Some(link) = link {
+            return self
+                .targets
+                .iter()
+                .find(|target| target.id.as_ref() == Some(link))
+                .map(|target| target.index);
+        }
+
+        let bounds: Vec<Rectangle> = self.targets.iter().map(|target| target.bounds).collect();
+        nearest(&bounds, focused, self.direction).map(|target| self.targets[target].index)
+    }
+}
+
+impl<T> Operation<T> for Navigate {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        let is_scope = id.is_some() && id == self.spatial_nav.scope.as_ref();
+
+        self.containers.push(bounds);
+        self.in_scope += usize::from(is_scope);
+        operate_on_children(self);
+        self.in_scope -= usize::from(is_scope);
+        let _ = self.containers.pop();
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
+        if self.spatial_nav.scope.is_none() || self.in_scope > 0 {
+            if state.is_focused() {
+                self.focused = Some(self.targets.len());
+            }
+            self.targets.push(Target {
+                index: self.count,
+                id: id.cloned(),
+                bounds: self.containers.last().copied().unwrap_or_default(),
+            });
+        }
+        self.count += 1;
+    }
+
+    fn finish(&self) -> Outcome<T> {
+        self.target().map_or(Outcome::None, |target| {
+            Outcome::Chain(Box::new(FocusIndex::new(Some(target))))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_widget::core::{keyboard::KeyCode, Point, Rectangle, Size};
+
+    use super::{nearest, Direction};
+
+    /// Returns a 3x2 grid of targets, row by row.
+    fn grid() -> Vec<Rectangle> {
+        (0u8..6)
+            .map(|index| {
+                Rectangle::new(
+                    Point::new(f32::from(index % 3) * 100.0, f32::from(index / 3) * 50.0),
+                    Size::new(80.0, 40.0),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn direction_test() {
+        assert_eq!(Direction::from_key_code(KeyCode::Up), Some(Direction::Up));
+        assert_eq!(
+            Direction::from_key_code(KeyCode::Right),
+            Some(Direction::Right)
+        );
+        assert_eq!(Direction::from_key_code(KeyCode::Tab), None);
+    }
+
+    #[test]
+    fn nearest_test() {
+        let targets = grid();
+        assert_eq!(nearest(&targets, 0, Direction::Right), Some(1));
+        assert_eq!(nearest(&targets, 1, Direction::Down), Some(4));
+        assert_eq!(nearest(&targets, 4, Direction::Up), Some(1));
+        assert_eq!(nearest(&targets, 5, Direction::Left), Some(4));
+        assert_eq!(nearest(&targets, 2, Direction::Right), None);
+        assert_eq!(nearest(&targets, 0, Direction::Up), None);
+        assert_eq!(nearest(&targets, 6, Direction::Up), None);
+    }
+}
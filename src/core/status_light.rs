@@ -0,0 +1,129 @@
+//! Time the blinking of a status light.
+//!
+//! *This API requires the following crate features to be activated: `status_light`*
+use std::time::Duration;
+
+/// The severity of a status light, choosing its color.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Severity {
+    /// A neutral state, like idle or connecting.
+    #[default]
+    Neutral,
+    /// A good state, like online or passing.
+    Success,
+    /// A state needing attention, like degraded or pending.
+    Warning,
+    /// A bad state, like offline or failing.
+    Danger,
+}
+
+/// The shape of a status light.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Shape {
+    /// A round light.
+    #[default]
+    Circle,
+    /// A square light with slightly rounded corners.
+    Square,
+}
+
+/// The way a status light is lit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Pattern {
+    /// The light is always on.
+    #[default]
+    On,
+    /// The light is always off.
+    Off,
+    /// The light is on for a duration, then off for a duration, starting on.
+    Blink {
+        /// How long the light is on.
+        on: Duration,
+        /// How long the light is off.
+        off: Duration,
+    },
+}
+
+impl Pattern {
+    /// A pattern blinking once a second.
+    #[must_use]
+    pub const fn slow() -> Self {
+        Self::blink(Duration::from_secs(1))
+    }
+
+    /// A pattern blinking four times a second.
+    #[must_use]
+    pub const fn fast() -> Self {
+        Self::blink(Duration::from_millis(250))
+    }
+
+    /// A pattern blinking once per period, on for one half of it and off for
+    /// the other.
+    #[must_use]
+    pub const fn blink(period: Duration) -> Self {
+        #[allow(clippy::cast_possible_truncation)]
+        let half = Duration::from_nanos(period.as_nanos() as u64 / 2);
+
+        Self::Blink {
+            on: half,
+            off: half,
+        }
+    }
+}
+
+/// Returns if a light with the given pattern is on after the given time
+/// since it started, and the time until it changes if it blinks.
+#[must_use]
+pub fn phase(pattern: Pattern, elapsed: Duration) -> (bool, Option<Duration>) {
+    match pattern {
+        Pattern::On => (true, None),
+        Pattern::Off => (false, None),
+        Pattern::Blink { on, off } => {
+            let cycle = (on + off).as_nanos();
+
+            if cycle == 0 || off.is_zero() {
+                return (true, None);
+            }
+            if on.is_zero() {
+                return (false, None);
+            }
+
+            #[allow(clippy::cast_possible_truncation)]
+            let time = Duration::from_nanos((elapsed.as_nanos() % cycle) as u64);
+
+            if time < on {
+                (true, Some(on.saturating_sub(time)))
+            } else {
+                (false, Some((on + off).saturating_sub(time)))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{phase, Pattern};
+    use std::time::Duration;
+
+    #[test]
+    fn phase_test() {
+        let millis = Duration::from_millis;
+        let pattern = Pattern::Blink {
+            on: millis(200),
+            off: millis(300),
+        };
+
+        assert_eq!(phase(pattern, millis(0)), (true, Some(millis(200))));
+        assert_eq!(phase(pattern, millis(150)), (true, Some(millis(50))));
+        assert_eq!(phase(pattern, millis(200)), (false, Some(millis(300))));
+        assert_eq!(phase(pattern, millis(1120)), (true, Some(millis(80))));
+
+        assert_eq!(phase(Pattern::On, millis(1234)), (true, None));
+        assert_eq!(phase(Pattern::Off, millis(1234)), (false, None));
+        assert_eq!(Pattern::slow(), Pattern::blink(millis(1000)));
+        assert_eq!(
+            phase(Pattern::blink(Duration::ZERO), millis(10)),
+            (true, None)
+        );
+    }
+}
@@ -0,0 +1,115 @@
+//! Find the boundaries of the characters, words and lines of a text.
+//!
+//! *This API requires the following crate features to be activated: `selectable_text` or `rich_editor`*
+use std::ops::Range;
+
+/// Returns the byte ranges of the lines of the given text, without their line
+/// breaks.
+#[must_use]
+pub fn lines(text: &str) -> Vec<Range<usize>> {
+    let mut start = 0;
+
+    text.split('\n')
+        .map(|line| {
+            let range = start..start + line.len();
+            start = range.end + 1;
+            range
+        })
+        .collect()
+}
+
+/// Returns the byte index of the character before the given byte index.
+#[must_use]
+pub fn previous_char(text: &str, index: usize) -> usize {
+    text[..index]
+        .char_indices()
+        .next_back()
+        .map_or(0, |(index, _)| index)
+}
+
+/// Returns the byte index of the character after the given byte index.
+#[must_use]
+pub fn next_char(text: &str, index: usize) -> usize {
+    text[index..]
+        .chars()
+        .next()
+        .map_or(index, |c| index + c.len_utf8())
+}
+
+/// Returns the byte range of the word at the given byte index.
+///
+/// A word is a run of alphanumeric characters and underscores. Any other
+/// character is a word of its own.
+#[must_use]
+pub fn word_at(text: &str, index: usize) -> Range<usize> {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+
+    let Some(c) = text[index..].chars().next() else {
+        return index..index;
+    };
+    if !is_word(c) {
+        return index..index + c.len_utf8();
+    }
+
+    let start = text[..index]
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| is_word(*c))
+        .last()
+        .map_or(index, |(start, _)| start);
+    let end = text[index..]
+        .char_indices()
+        .find(|(_, c)| !is_word(*c))
+        .map_or(text.len(), |(end, _)| index + end);
+
+    start..end
+}
+
+/// Returns the byte range of the line at the given byte index, without its
+/// line break.
+#[must_use]
+pub fn line_at(text: &str, index: usize) -> Range<usize> {
+    let start = text[..index].rfind('\n').map_or(0, |start| start + 1);
+    let end = text[index..]
+        .find('\n')
+        .map_or(text.len(), |end| index + end);
+
+    start..end
+}
+
+/// Returns the nearest character boundary at or before the given byte index.
+#[must_use]
+pub fn floor_char_boundary(text: &str, index: usize) -> usize {
+    let mut index = index.min(text.len());
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{floor_char_boundary, line_at, lines, next_char, previous_char, word_at};
+
+    #[test]
+    fn lines_test() {
+        assert_eq!(lines("ab\n\ncde"), vec![0..2, 3..3, 4..7]);
+        assert_eq!(lines(""), vec![0..0]);
+        assert_eq!(line_at("ab\ncde\nf", 4), 3..6);
+        assert_eq!(line_at("ab\ncde", 2), 0..2);
+    }
+
+    #[test]
+    fn word_at_test() {
+        let text = "let värde = some_value;";
+
+        assert_eq!(word_at(text, 5), 4..10);
+        assert_eq!(word_at(text, 13), 13..23);
+        assert_eq!(word_at(text, 11), 11..12);
+        assert_eq!(word_at(text, text.len()), text.len()..text.len());
+
+        assert_eq!(next_char(text, 5), 7);
+        assert_eq!(previous_char(text, 7), 5);
+        assert_eq!(floor_char_boundary(text, 6), 5);
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! *This API requires the following crate features to be activated: `time_picker`*
 
-use chrono::{Local, Timelike};
+use chrono::{Duration, Local, NaiveTime, Timelike};
 use std::fmt::Display;
 
 /// The time value
@@ -180,12 +180,28 @@ impl From<chrono::NaiveTime> for Time {
     }
 }
 
+/// Returns the duration of the range of times from `start` to `end`, which
+/// wraps past midnight if `end` is before `start`, or `None` if both are the
+/// same and the range is empty.
+#[must_use]
+pub fn range_duration(start: NaiveTime, end: NaiveTime) -> Option<Duration> {
+    let duration = end - start;
+
+    if duration.is_zero() {
+        None
+    } else if duration < Duration::zero() {
+        Some(duration + Duration::days(1))
+    } else {
+        Some(duration)
+    }
+}
+
 #[cfg(test)]
 
 mod tests {
-    use chrono::NaiveTime;
+    use chrono::{Duration, NaiveTime};
 
-    use super::{Period, Time};
+    use super::{range_duration, Period, Time};
 
     #[test]
     fn time_to_naive() {
@@ -279,4 +295,20 @@ mod tests {
             NaiveTime::from_hms_opt(17, 52, 0).expect("Time Conversion failed")
         );
     }
+
+    #[test]
+    fn range_duration_test() {
+        let time = |hour, minute| NaiveTime::from_hms_opt(hour, minute, 0).expect("Valid time");
+
+        assert_eq!(
+            range_duration(time(9, 0), time(17, 30)),
+            Some(Duration::minutes(510))
+        );
+        // The range wraps past midnight.
+        assert_eq!(
+            range_duration(time(22, 0), time(6, 0)),
+            Some(Duration::hours(8))
+        );
+        assert_eq!(range_duration(time(8, 0), time(8, 0)), None);
+    }
 }
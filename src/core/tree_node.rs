@@ -0,0 +1,294 @@
+//! The nodes of a tree and the check states derived from them.
+//!
+//! *This API requires the following crate features to be activated: `tree_view`*
+
+/// A node of a [`TreeView`](crate::native::tree_view::TreeView).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TreeNode {
+    /// The identifier of the node, unique in its tree.
+    pub id: usize,
+    /// The label of the node.
+    pub label: String,
+    /// The children of the node.
+    pub children: Vec<Self>,
+}
+
+/// The check state of a [`TreeNode`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CheckState {
+    /// The node and all of its descendants are unchecked.
+    Unchecked,
+    /// The node and all of its descendants are checked.
+    Checked,
+    /// Some of the descendants of the node are checked.
+    Indeterminate,
+}
+
+/// Where a dragged [`TreeNode`] is dropped, relative to the target node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DropPosition {
+    /// Before the target, as its previous sibling.
+    Before,
+    /// After the target, as its next sibling.
+    After,
+    /// Into the target, as its last child.
+    Into,
+}
+
+impl TreeNode {
+    /// Creates a new [`TreeNode`] without children.
+    pub fn new(id: usize, label: impl Into<String>) -> Self {
+        Self {
+            id,
+            label: label.into(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the children of the [`TreeNode`].
+    #[must_use]
+    pub fn children(mut self, children: impl IntoIterator<Item = Self>) -> Self {
+        self.children = children.into_iter().collect();
+        self
+    }
+
+    /// Returns the node with the given identifier in this subtree.
+    #[must_use]
+    pub fn find(&self, id: usize) -> Option<&Self> {
+        if self.id == id {
+            return Some(self);
+        }
+
+        self.children.iter().find_map(|child| child.find(id))
+    }
+
+    /// Returns the mutable node with the given identifier in this subtree.
+    #[must_use]
+    pub fn find_mut(&mut self, id: usize) -> Option<&mut Self> {
+        if self.id == id {
+            return Some(self);
+        }
+
+        self.children
+            .iter_mut()
+            .find_map(|child| child.find_mut(id))
+    }
+
+    /// Returns the identifiers of the nodes of this subtree in depth-first order.
+    #[must_use]
+    pub fn ids(&self) -> Vec<usize> {
+        let mut ids = vec![self.id];
+        for child in &self.children {
+            ids.extend(child.ids());
+        }
+        ids
+    }
+
+    /// Returns the [`CheckState`] of the node with the given checked nodes.
+    ///
+    /// The state of a node with children is derived from the states of its
+    /// children, so only the checked leaves matter.
+    #[must_use]
+    pub fn check_state(&self, checked: &[usize]) -> CheckState {
+        if self.children.is_empty() {
+            return if checked.contains(&self.id) {
+                CheckState::Checked
+            } else {
+                CheckState::Unchecked
+            };
+        }
+
+        let mut states = self.children.iter().map(|child| child.check_state(checked));
+        let first = states.next().unwrap_or(CheckState::Unchecked);
+
+        if states.all(|state| state == first) {
+            first
+        } else {
+            CheckState::Indeterminate
+        }
+    }
+}
+
+/// Returns the node with the given identifier in the given nodes.
+#[must_use]
+pub fn find(nodes: &[TreeNode], id: usize) -> Option<&TreeNode> {
+    nodes.iter().find_map(|node| node.find(id))
+}
+
+/// Returns the mutable node with the given identifier in the given nodes.
+#[must_use]
+pub fn find_mut(nodes: &mut [TreeNode], id: usize) -> Option<&mut TreeNode> {
+    nodes.iter_mut().find_map(|node| node.find_mut(id))
+}
+
+/// Moves the node with the given identifier to the given position relative
+/// to the `target` node.
+///
+/// Returns `false` without changing the nodes if either node is missing or if
+/// the target is the moved node or one of its descendants.
+pub fn move_node(
+    nodes: &mut Vec<TreeNode>,
+    source: usize,
+    target: usize,
+    position: DropPosition,
+) -> bool {
+    let Some(node) = find(nodes, source) else {
+        return false;
+    };
+    if node.find(target).is_some() || find(nodes, target).is_none() {
+        return false;
+    }
+
+    let Some(node) = remove(nodes, source) else {
+        return false;
+    };
+
+    insert(nodes, node, target, position).is_none()
+}
+
+/// Removes the node with the given identifier from the given nodes,
+/// returning it with its descendants.
+pub fn remove(nodes: &mut Vec<TreeNode>, id: usize) -> Option<TreeNode> {
+    if let Some(index) = nodes.iter().position(|node| node.id == id) {
+        return Some(nodes.remove(index));
+    }
+
+    nodes
+        .iter_mut()
+        .find_map(|node| remove(&mut node.children, id))
+}
+
+/// Inserts the given node at the given position relative to the `target`
+/// node, returning the node back if the target is missing.
+fn insert(
+    nodes: &mut Vec<TreeNode>,
+    node: TreeNode,
+    target: usize,
+    position: DropPosition,
+) -> Option<TreeNode> {
+    if let Some(index) = nodes.iter().position(|node| node.id == target) {
+        match position {
+            DropPosition::Before => nodes.insert(index, node),
+            DropPosition::After => nodes.insert(index + 1, node),
+            DropPosition::Into => nodes[index].children.push(node),
+        }
+        return None;
+    }
+
+    let mut node = node;
+    for parent in nodes {
+        match insert(&mut parent.children, node, target, position) {
+            Some(rejected) => node = rejected,
+            None => return None,
+        }
+    }
+    Some(node)
+}
+
+/// Returns the checked nodes after toggling the checkbox of the node with the
+/// given identifier.
+///
+/// Checking a node checks all of its descendants, unchecking it unchecks
+/// them, and its ancestors are checked once all of their children are. The
+/// returned nodes are in depth-first order and include every checked parent.
+#[must_use]
+pub fn toggle_check(nodes: &[TreeNode], checked: &[usize], id: usize) -> Vec<usize> {
+    let Some(node) = find(nodes, id) else {
+        return checked.to_vec();
+    };
+
+    let subtree = node.ids();
+    let mut leaves: Vec<usize> = checked
+        .iter()
+        .copied()
+        .filter(|id| !subtree.contains(id))
+        .collect();
+
+    if node.check_state(checked) != CheckState::Checked {
+        leaves.extend(subtree);
+    }
+
+    let mut checked = Vec::new();
+    for node in nodes {
+        collect_checked(node, &leaves, &mut checked);
+    }
+    checked
+}
+
+/// Collects the checked nodes of the subtree of the given node.
+fn collect_checked(node: &TreeNode, leaves: &[usize], checked: &mut Vec<usize>) {
+    if node.check_state(leaves) == CheckState::Checked {
+        checked.push(node.id);
+    }
+
+    for child in &node.children {
+        collect_checked(child, leaves, checked);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{move_node, toggle_check, CheckState, DropPosition, TreeNode};
+
+    fn tree() -> Vec<TreeNode> {
+        vec![TreeNode::new(0, "Root").children([
+            TreeNode::new(1, "Docs").children([TreeNode::new(2, "a"), TreeNode::new(3, "b")]),
+            TreeNode::new(4, "c"),
+        ])]
+    }
+
+    #[test]
+    fn check_state_test() {
+        let tree = tree();
+
+        assert_eq!(tree[0].check_state(&[]), CheckState::Unchecked);
+        assert_eq!(tree[0].check_state(&[2]), CheckState::Indeterminate);
+        assert_eq!(
+            tree[0].children[0].check_state(&[2, 3]),
+            CheckState::Checked
+        );
+        assert_eq!(tree[0].check_state(&[2, 3, 4]), CheckState::Checked);
+    }
+
+    #[test]
+    fn toggle_check_test() {
+        let tree = tree();
+
+        let checked = toggle_check(&tree, &[], 1);
+        assert_eq!(checked, vec![1, 2, 3]);
+
+        let checked = toggle_check(&tree, &checked, 4);
+        assert_eq!(checked, vec![0, 1, 2, 3, 4]);
+
+        let checked = toggle_check(&tree, &checked, 2);
+        assert_eq!(checked, vec![3, 4]);
+
+        // Checking an indeterminate parent checks all of its descendants.
+        let checked = toggle_check(&tree, &checked, 0);
+        assert_eq!(checked, vec![0, 1, 2, 3, 4]);
+
+        assert_eq!(toggle_check(&tree, &checked, 0), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn move_node_test() {
+        let mut tree = tree();
+
+        assert!(move_node(&mut tree, 4, 2, DropPosition::Before));
+        assert_eq!(tree[0].children[0].ids(), vec![1, 4, 2, 3]);
+
+        assert!(move_node(&mut tree, 1, 0, DropPosition::After));
+        assert_eq!(
+            tree.iter().map(|node| node.id).collect::<Vec<_>>(),
+            vec![0, 1]
+        );
+
+        assert!(move_node(&mut tree, 0, 3, DropPosition::Into));
+        assert_eq!(tree[0].ids(), vec![1, 4, 2, 3, 0]);
+
+        // A node can't be moved into itself or its descendants.
+        assert!(!move_node(&mut tree, 1, 0, DropPosition::Into));
+        assert!(!move_node(&mut tree, 3, 3, DropPosition::After));
+        assert!(!move_node(&mut tree, 3, 42, DropPosition::After));
+    }
+}
@@ -0,0 +1,68 @@
+//! Helper functions for copying blocks of cells as tab-separated values.
+//!
+//! *This API requires the following crate features to be activated: `editable_grid`*
+
+/// Formats the given rows of cells as tab-separated values, as used by
+/// spreadsheets on the clipboard.
+///
+/// Tabs and line breaks inside of cells are replaced by spaces.
+#[must_use]
+pub fn format<S: AsRef<str>>(rows: &[Vec<S>]) -> String {
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| cell.as_ref().replace(['\t', '\n', '\r'], " "))
+                .collect::<Vec<_>>()
+                .join("\t")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Parses tab-separated values, as pasted from spreadsheets, into rows of cells.
+///
+/// Both `\n` and `\r\n` line breaks are accepted and a trailing line break
+/// does not add an empty row.
+#[must_use]
+pub fn parse(text: &str) -> Vec<Vec<String>> {
+    let text = text.strip_suffix('\n').unwrap_or(text);
+    let text = text.strip_suffix('\r').unwrap_or(text);
+
+    if text.is_empty() {
+        return Vec::new();
+    }
+
+    text.split('\n')
+        .map(|line| {
+            line.strip_suffix('\r')
+                .unwrap_or(line)
+                .split('\t')
+                .map(String::from)
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format, parse};
+
+    #[test]
+    fn format_test() {
+        let rows = vec![vec!["a", "b"], vec!["c\td", ""]];
+        assert_eq!(format(&rows), "a\tb\nc d\t");
+    }
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(parse(""), Vec::<Vec<String>>::new());
+        assert_eq!(
+            parse("a\tb\r\nc\td\r\n"),
+            vec![vec!["a", "b"], vec!["c", "d"]]
+        );
+        assert_eq!(parse("x\n\ny"), vec![vec!["x"], vec![""], vec!["y"]]);
+
+        let rows = vec![vec!["1", "2", "3"], vec!["4", "", "6"]];
+        assert_eq!(parse(&format(&rows)), rows);
+    }
+}
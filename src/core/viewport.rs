@@ -0,0 +1,92 @@
+//! Pan and zoom the content of custom widgets.
+use iced_widget::core::{Point, Rectangle, Vector};
+
+/// The pan and the zoom of the content of a widget.
+///
+/// The origin of the content is shown at the center of the bounds of the
+/// widget, moved by the pan offset, and the content is scaled by the zoom.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Viewport {
+    /// The offset the content is panned by, in pixels on the screen.
+    pub offset: Vector,
+    /// The zoom, with 1.0 showing the content at its size.
+    pub zoom: f32,
+}
+
+impl Default for Viewport {
+    fn default() -> Self {
+        Self {
+            offset: Vector::new(0.0, 0.0),
+            zoom: 1.0,
+        }
+    }
+}
+
+impl Viewport {
+    /// Returns the position on the screen of the given position of the
+    /// content in the widget with the given bounds.
+    #[must_use]
+    pub fn project(self, bounds: Rectangle, position: Point) -> Point {
+        bounds.center() + self.offset + Vector::new(position.x, position.y) * self.zoom
+    }
+
+    /// Returns the position of the content at the given position on the
+    /// screen, inverting [`project`](Self::project).
+    #[must_use]
+    pub fn unproject(self, bounds: Rectangle, position: Point) -> Point {
+        let offset = (position - bounds.center() - self.offset) * (1.0 / self.zoom);
+
+        Point::new(offset.x, offset.y)
+    }
+
+    /// Pans the content by the given offset on the screen.
+    pub fn pan(&mut self, delta: Vector) {
+        self.offset = self.offset + delta;
+    }
+
+    /// Zooms by the given factor within the given range, keeping the content
+    /// at the given position on the screen in place.
+    pub fn zoom_at(
+        &mut self,
+        bounds: Rectangle,
+        position: Point,
+        factor: f32,
+        (min, max): (f32, f32),
+    ) {
+        let anchor = self.unproject(bounds, position);
+        self.zoom = (self.zoom * factor).clamp(min, max);
+        self.offset = self.offset + (position - self.project(bounds, anchor));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Viewport;
+    use iced_widget::core::{Point, Rectangle, Size, Vector};
+
+    #[test]
+    fn zoom_at_test() {
+        let bounds = Rectangle::new(Point::new(10.0, 10.0), Size::new(200.0, 100.0));
+        let mut viewport = Viewport::default();
+
+        assert_eq!(
+            viewport.project(bounds, Point::ORIGIN),
+            Point::new(110.0, 60.0)
+        );
+
+        viewport.pan(Vector::new(10.0, 0.0));
+        viewport.zoom_at(bounds, Point::new(170.0, 60.0), 2.0, (0.5, 4.0));
+        assert_eq!(
+            viewport.project(bounds, Point::new(50.0, 0.0)),
+            Point::new(170.0, 60.0)
+        );
+        assert_eq!(
+            viewport.unproject(bounds, Point::new(70.0, 60.0)),
+            Point::new(0.0, 0.0)
+        );
+
+        // The zoom stays within its range.
+        viewport.zoom_at(bounds, Point::new(170.0, 60.0), 4.0, (0.5, 4.0));
+        assert_eq!(viewport.zoom.to_bits(), 4.0_f32.to_bits());
+    }
+}
@@ -0,0 +1,91 @@
+//! Helper functions for mapping the position of a volume slider to a gain.
+//!
+//! *This API requires the following crate features to be activated: `volume_slider`*
+
+/// The scale a [`VolumeSlider`](crate::native::VolumeSlider) maps its
+/// position to a gain with.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VolumeScale {
+    /// The gain grows linearly with the position.
+    Linear,
+    /// The position is mapped linearly to decibels from `min_db` at the
+    /// start of the slider to 0 dB at the end, with the very start being silent.
+    Decibel {
+        /// The attenuation in decibels right after the start of the slider.
+        min_db: f32,
+    },
+}
+
+impl Default for VolumeScale {
+    fn default() -> Self {
+        Self::Decibel { min_db: -60.0 }
+    }
+}
+
+impl VolumeScale {
+    /// Returns the gain from 0.0 to 1.0 at the given position from 0.0 to 1.0.
+    #[must_use]
+    pub fn gain_at(self, position: f32) -> f32 {
+        let position = position.clamp(0.0, 1.0);
+
+        match self {
+            Self::Linear => position,
+            Self::Decibel { .. } if position <= 0.0 => 0.0,
+            Self::Decibel { min_db } => db_to_gain(min_db * (1.0 - position)),
+        }
+    }
+
+    /// Returns the position from 0.0 to 1.0 of the given gain from 0.0 to 1.0.
+    #[must_use]
+    pub fn position_of(self, gain: f32) -> f32 {
+        let gain = gain.clamp(0.0, 1.0);
+
+        match self {
+            Self::Linear => gain,
+            Self::Decibel { .. } if gain <= 0.0 => 0.0,
+            Self::Decibel { min_db } if min_db >= 0.0 => 1.0,
+            Self::Decibel { min_db } => (1.0 - gain_to_db(gain) / min_db).clamp(0.0, 1.0),
+        }
+    }
+}
+
+/// Converts decibels to a gain factor.
+#[must_use]
+pub fn db_to_gain(db: f32) -> f32 {
+    10.0_f32.powf(db / 20.0)
+}
+
+/// Converts a gain factor to decibels.
+#[must_use]
+pub fn gain_to_db(gain: f32) -> f32 {
+    20.0 * gain.log10()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{db_to_gain, gain_to_db, VolumeScale};
+
+    #[test]
+    fn db_gain_test() {
+        assert!((db_to_gain(0.0) - 1.0).abs() < 1e-6);
+        assert!((db_to_gain(-20.0) - 0.1).abs() < 1e-6);
+        assert!((gain_to_db(0.5) + 6.020_6).abs() < 1e-3);
+    }
+
+    #[test]
+    fn scale_round_trip_test() {
+        for scale in [VolumeScale::Linear, VolumeScale::Decibel { min_db: -60.0 }] {
+            assert!(scale.gain_at(0.0).abs() < f32::EPSILON);
+            assert!((scale.gain_at(1.0) - 1.0).abs() < f32::EPSILON);
+
+            for position in [0.1, 0.25, 0.5, 0.9] {
+                let gain = scale.gain_at(position);
+                assert!((scale.position_of(gain) - position).abs() < 1e-4);
+            }
+        }
+
+        // Half of the slider is -30 dB on a 60 dB scale.
+        let scale = VolumeScale::Decibel { min_db: -60.0 };
+        assert!((gain_to_db(scale.gain_at(0.5)) + 30.0).abs() < 1e-3);
+    }
+}
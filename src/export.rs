@@ -0,0 +1,303 @@
+//! Render an element offscreen at a chosen size and scale into a PNG image
+//! or a PDF page, e.g. to export reports and charts.
+//!
+//! The element is laid out and drawn by a headless renderer, independently of
+//! the window it may also be shown in.
+//!
+//! *This API requires the following crate features to be activated: `export`,
+//! and `export_pdf` for [`Image::to_pdf`]*
+use crate::headless;
+
+use iced_widget::{
+    core::{mouse::Cursor, renderer::Style, Element, Size},
+    renderer::Renderer,
+    runtime::user_interface::{Cache, UserInterface},
+    style::Theme,
+};
+
+/// The settings of an offscreen rendering of an [`Element`] into an [`Image`].
+///
+/// # Example
+/// ```ignore
+/// # use iced::Size;
+/// # use iced_aw::export::Export;
+/// #
+/// let image = Export::new(Size::new(400.0, 300.0))
+///     .scale_factor(2.0)
+///     .render(report_view(&report));
+///
+/// std::fs::write("report.png", image.to_png()?)?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct Export {
+    /// The logical size of the rendered [`Element`].
+    size: Size,
+    /// The scale factor of the rendered [`Image`].
+    scale_factor: f32,
+    /// The theme the [`Element`] is drawn with.
+    theme: Theme,
+}
+
+impl Export {
+    /// Creates a new [`Export`] rendering an [`Element`] in a viewport of the
+    /// given logical size.
+    #[must_use]
+    pub fn new(size: Size) -> Self {
+        Self {
+            size,
+            scale_factor: 1.0,
+            theme: Theme::default(),
+        }
+    }
+
+    /// Sets the scale factor of the rendered [`Image`], e.g. `2.0` for a
+    /// high resolution export.
+    #[must_use]
+    pub fn scale_factor(mut self, scale_factor: f32) -> Self {
+        self.scale_factor = scale_factor;
+        self
+    }
+
+    /// Sets the [`Theme`] the [`Element`] is drawn with.
+    #[must_use]
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Lays out, draws and rasterizes the given element into an [`Image`].
+    ///
+    /// The messages of the element are never produced.
+    pub fn render<'a, Message, E>(&self, element: E) -> Image
+    where
+        E: Into<Element<'a, Message, Renderer<Theme>>>,
+    {
+        let mut renderer = headless::renderer();
+        let mut interface = UserInterface::build(element, self.size, Cache::new(), &mut renderer);
+
+        let _ = interface.draw(
+            &mut renderer,
+            &self.theme,
+            &Style {
+                text_color: self.theme.palette().text,
+            },
+            Cursor::Unavailable,
+        );
+
+        let (width, height, pixels) = headless::rasterize(
+            &mut renderer,
+            self.size,
+            self.scale_factor,
+            self.theme.palette().background,
+        );
+
+        Image {
+            width,
+            height,
+            scale_factor: self.scale_factor,
+            pixels,
+        }
+    }
+}
+
+/// An [`Element`] rendered offscreen by an [`Export`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Image {
+    /// The width of the [`Image`] in physical pixels.
+    pub width: u32,
+    /// The height of the [`Image`] in physical pixels.
+    pub height: u32,
+    /// The scale factor the [`Image`] was rendered with.
+    pub scale_factor: f32,
+    /// The premultiplied RGBA pixels of the [`Image`], row by row.
+    pub pixels: Vec<u8>,
+}
+
+impl Image {
+    /// Returns the RGBA pixels of the [`Image`] with straight alpha, row by
+    /// row.
+    #[must_use]
+    pub fn rgba(&self) -> Vec<u8> {
+        self.pixels
+            .chunks_exact(4)
+            .flat_map(|pixel| {
+                let alpha = pixel[3];
+                let demultiply = |channel: u8| {
+                    if alpha == 0 {
+                        0
+                    } else {
+                        (u16::from(channel) * 255 / u16::from(alpha)).min(255) as u8
+                    }
+                };
+
+                [
+                    demultiply(pixel[0]),
+                    demultiply(pixel[1]),
+                    demultiply(pixel[2]),
+                    alpha,
+                ]
+            })
+            .collect()
+    }
+
+    /// Encodes the [`Image`] as a PNG file.
+    ///
+    /// # Errors
+    /// Fails if the [`Image`] is empty.
+    pub fn to_png(&self) -> Result<Vec<u8>, png::EncodingError> {
+        let mut bytes = Vec::new();
+
+        let mut encoder = png::Encoder::new(&mut bytes, self.width, self.height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.rgba())?;
+        writer.finish()?;
+
+        Ok(bytes)
+    }
+
+    /// Encodes the [`Image`] as a PDF document of a single page.
+    ///
+    /// The page has the logical size of the rendered [`Element`] in points,
+    /// and the translucent pixels are blended over white.
+    ///
+    /// *This API requires the following crate features to be activated: `export_pdf`*
+    ///
+    /// # Errors
+    /// Fails if the pixels cannot be compressed.
+    #[cfg(any(test, feature = "export_pdf"))]
+    pub fn to_pdf(&self) -> std::io::Result<Vec<u8>> {
+        use std::io::Write;
+
+        let rgb: Vec<u8> = self
+            .pixels
+            .chunks_exact(4)
+            .flat_map(|pixel| {
+                let white = 255 - pixel[3];
+                [
+                    pixel[0].saturating_add(white),
+                    pixel[1].saturating_add(white),
+                    pixel[2].saturating_add(white),
+                ]
+            })
+            .collect();
+
+        let mut encoder =
+            flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&rgb)?;
+        let image = encoder.finish()?;
+
+        let width = self.width as f32 / self.scale_factor;
+        let height = self.height as f32 / self.scale_factor;
+        let contents = format!("q {width:.2} 0 0 {height:.2} 0 0 cm /Im0 Do Q");
+
+        let mut pdf = b"%PDF-1.4\n".to_vec();
+        let mut offsets = Vec::new();
+
+        let mut object = |pdf: &mut Vec<u8>, dictionary: String, stream: Option<&[u8]>| {
+            offsets.push(pdf.len());
+            pdf.extend_from_slice(format!("{} 0 obj\n{dictionary}\n", offsets.len()).as_bytes());
+            if let Some(stream) = stream {
+                pdf.extend_from_slice(b"stream\n");
+                pdf.extend_from_slice(stream);
+                pdf.extend_from_slice(b"\nendstream\n");
+            }
+            pdf.extend_from_slice(b"endobj\n");
+        };
+
+        object(
+            &mut pdf,
+            "<< /Type /Catalog /Pages 2 0 R >>".to_owned(),
+            None,
+        );
+        object(
+            &mut pdf,
+            "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_owned(),
+            None,
+        );
+        object(
+            &mut pdf,
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {width:.2} {height:.2}] \
+                 /Resources << /XObject << /Im0 4 0 R >> >> /Contents 5 0 R >>"
+            ),
+            None,
+        );
+        object(
+            &mut pdf,
+            format!(
+                "<< /Type /XObject /Subtype /Image /Width {} /Height {} \
+                 /ColorSpace /DeviceRGB /BitsPerComponent 8 /Filter /FlateDecode /Length {} >>",
+                self.width,
+                self.height,
+                image.len(),
+            ),
+            Some(&image),
+        );
+        object(
+            &mut pdf,
+            format!("<< /Length {} >>", contents.len()),
+            Some(contents.as_bytes()),
+        );
+
+        let xref = pdf.len();
+        pdf.extend_from_slice(
+            format!("xref\n0 {}\n0000000000 65535 f \n", offsets.len() + 1).as_bytes(),
+        );
+        for offset in &offsets {
+            pdf.extend_from_slice(format!("{offset:010} 00000 n \n").as_bytes());
+        }
+        pdf.extend_from_slice(
+            format!(
+                "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref}\n%%EOF\n",
+                offsets.len() + 1
+            )
+            .as_bytes(),
+        );
+
+        Ok(pdf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Export;
+    use iced_widget::{core::Size, text};
+
+    #[test]
+    fn export_scale_test() {
+        let image = Export::new(Size::new(40.0, 20.0))
+            .scale_factor(2.0)
+            .render::<(), _>(text("Export"));
+
+        assert_eq!((image.width, image.height), (80, 40));
+        assert_eq!(image.pixels.len(), 80 * 40 * 4);
+    }
+
+    #[test]
+    fn export_png_test() {
+        let png = Export::new(Size::new(40.0, 20.0))
+            .render::<(), _>(text("Export"))
+            .to_png()
+            .expect("Export should encode a PNG.");
+
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+    }
+
+    #[test]
+    fn export_pdf_test() {
+        let pdf = Export::new(Size::new(40.0, 20.0))
+            .scale_factor(2.0)
+            .render::<(), _>(text("Export"))
+            .to_pdf()
+            .expect("Export should encode a PDF.");
+        let pdf = String::from_utf8_lossy(&pdf);
+
+        assert!(pdf.starts_with("%PDF-1.4"));
+        assert!(pdf.contains("/MediaBox [0 0 40.00 20.00]"));
+        assert!(pdf.contains("/Width 80 /Height 40"));
+        assert!(pdf.trim_end().ends_with("%%EOF"));
+    }
+}
@@ -0,0 +1,71 @@
+//! Rasterize the primitives of a headless tiny-skia renderer, shared by the
+//! test harness and the offscreen export.
+use iced_widget::{
+    core::{Color, Font, Rectangle, Size},
+    graphics::Viewport,
+    renderer::Renderer,
+    style::Theme,
+};
+
+/// Creates a new headless tiny-skia renderer.
+pub fn renderer() -> Renderer<Theme> {
+    Renderer::TinySkia(iced_tiny_skia::Renderer::new(iced_tiny_skia::Backend::new(
+        iced_tiny_skia::Settings {
+            default_font: Font::default(),
+            default_text_size: 16.0,
+        },
+    )))
+}
+
+/// Rasterizes the drawn primitives of the renderer over the background in a
+/// viewport of the given logical size and scale factor.
+///
+/// Returns the physical width, height and premultiplied RGBA pixels.
+pub fn rasterize(
+    renderer: &mut Renderer<Theme>,
+    size: Size,
+    scale_factor: f32,
+    background: Color,
+) -> (u32, u32, Vec<u8>) {
+    let viewport = Viewport::with_physical_size(
+        Size::new(
+            (size.width * scale_factor).ceil() as u32,
+            (size.height * scale_factor).ceil() as u32,
+        ),
+        f64::from(scale_factor),
+    );
+    let physical = viewport.physical_size();
+
+    let mut pixels = vec![0; physical.width as usize * physical.height as usize * 4];
+
+    // Only a tiny-skia renderer is ever created headlessly.
+    #[allow(irrefutable_let_patterns)]
+    if let Renderer::TinySkia(renderer) = renderer {
+        renderer.with_primitives(|backend, primitives| {
+            if let (Some(mut pixmap), Some(mut clip_mask)) = (
+                tiny_skia::PixmapMut::from_bytes(&mut pixels, physical.width, physical.height),
+                tiny_skia::Mask::new(physical.width, physical.height),
+            ) {
+                backend.draw(
+                    &mut pixmap,
+                    &mut clip_mask,
+                    primitives,
+                    &viewport,
+                    &[Rectangle::with_size(Size::new(
+                        physical.width as f32,
+                        physical.height as f32,
+                    ))],
+                    background,
+                    &[] as &[&str],
+                );
+            }
+        });
+    }
+
+    // The backend draws into a BGRA buffer.
+    for pixel in pixels.chunks_exact_mut(4) {
+        pixel.swap(0, 2);
+    }
+
+    (physical.width, physical.height, pixels)
+}
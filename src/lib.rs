@@ -53,6 +53,15 @@ pub mod native;
 pub mod core;
 pub mod style;
 
+#[cfg(any(test, feature = "test_utils"))]
+pub mod test_utils;
+
+#[cfg(any(test, feature = "export"))]
+pub mod export;
+
+#[cfg(any(test, feature = "test_utils", feature = "export"))]
+mod headless;
+
 pub use iced_widget::core::Element;
 use iced_widget::{renderer, style as iced_style};
 
@@ -78,6 +87,10 @@ mod platform {
     #[cfg(feature = "date_picker")]
     pub use {crate::native::date_picker, date_picker::DatePicker};
 
+    #[doc(no_inline)]
+    #[cfg(feature = "calendar")]
+    pub use {crate::native::calendar, calendar::Calendar};
+
     #[doc(no_inline)]
     #[cfg(feature = "floating_element")]
     pub use {crate::native::floating_element, floating_element::FloatingElement};
@@ -153,6 +166,358 @@ mod platform {
     pub use {
         crate::native::context_menu, crate::style::ContextMenuStyle, context_menu::ContextMenu,
     };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "drawer")]
+    pub use {crate::native::drawer, crate::style::DrawerStyles, drawer::Drawer};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "notification_center")]
+    pub use {
+        crate::native::notification_center,
+        crate::style::NotificationCenterStyles,
+        notification_center::{Notification, NotificationCenter},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "history_list")]
+    pub use {
+        crate::native::history_list, crate::style::HistoryListStyles, history_list::HistoryList,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "time_ruler")]
+    pub use {crate::native::time_ruler, crate::style::TimeRulerStyles, time_ruler::TimeRuler};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "waveform")]
+    pub use {
+        crate::native::waveform,
+        crate::style::WaveformStyles,
+        waveform::{Peaks, Waveform},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "seek_bar")]
+    pub use {crate::native::seek_bar, crate::style::SeekBarStyles, seek_bar::SeekBar};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "transport_controls")]
+    pub use {
+        crate::native::transport_controls,
+        crate::style::TransportControlsStyles,
+        transport_controls::{TransportControls, TransportEvent},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "volume_slider")]
+    pub use {
+        crate::core::volume::VolumeScale, crate::native::volume_slider,
+        crate::style::VolumeSliderStyles, volume_slider::VolumeSlider,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "equalizer")]
+    pub use {
+        crate::native::equalizer,
+        crate::style::EqualizerStyles,
+        equalizer::{Band, Equalizer},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "piano_keys")]
+    pub use {crate::native::piano_keys, crate::style::PianoKeysStyles, piano_keys::PianoKeys};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "step_grid")]
+    pub use {crate::native::step_grid, crate::style::StepGridStyles, step_grid::StepGrid};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "editable_grid")]
+    pub use {
+        crate::core::{cell_editor::CellEditor, column_layout::ColumnLayout},
+        crate::native::editable_grid,
+        crate::style::EditableGridStyles,
+        editable_grid::{EditableGrid, RowExpansion},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(any(feature = "tree_view", feature = "mind_map"))]
+    pub use crate::core::tree_node::TreeNode;
+
+    #[doc(no_inline)]
+    #[cfg(feature = "tree_view")]
+    pub use {
+        crate::core::tree_node::CheckState, crate::native::tree_view, crate::style::TreeViewStyles,
+        tree_view::TreeView,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "selectable_text")]
+    pub use {
+        crate::native::selectable_text, crate::style::SelectableTextStyles,
+        selectable_text::SelectableText,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "rich_editor")]
+    pub use {
+        crate::core::rich_text::{BlockKind, Document, Format, Position, Span, SpanStyle},
+        crate::native::rich_editor,
+        crate::style::RichEditorStyles,
+        rich_editor::RichEditor,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "find_bar")]
+    pub use {
+        crate::core::find, crate::native::find_bar, crate::style::FindBarStyles, find_bar::FindBar,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "split_button")]
+    pub use {
+        crate::core::menu_item::MenuItem, crate::native::split_button,
+        crate::style::SplitButtonStyles, split_button::SplitButton,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "toggle_group")]
+    pub use {
+        crate::native::toggle_group,
+        crate::style::ToggleGroupStyles,
+        toggle_group::{SelectionMode, ToggleGroup},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "menu_button")]
+    pub use {crate::native::menu_button, menu_button::MenuButton};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "title_bar")]
+    pub use {
+        crate::native::title_bar,
+        crate::style::TitleBarStyles,
+        title_bar::{ControlsPlacement, TitleBar, WindowControl},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "edge_resizer")]
+    pub use {
+        crate::core::resize, crate::native::edge_resizer, edge_resizer::EdgeResizer,
+        resize::ResizeDirection,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "gesture_detector")]
+    pub use {crate::native::gesture_detector, gesture_detector::GestureDetector};
+
+    #[doc(no_inline)]
+    pub use crate::core::lens;
+
+    #[doc(no_inline)]
+    pub use crate::core::ime;
+
+    #[doc(no_inline)]
+    pub use crate::core::spatial_nav;
+
+    #[doc(no_inline)]
+    pub use crate::core::i18n;
+
+    #[doc(no_inline)]
+    pub use crate::core::layout_direction;
+
+    #[doc(no_inline)]
+    #[cfg(feature = "form")]
+    pub use {
+        crate::native::form,
+        crate::style::FormStyles,
+        form::{Form, FormRow},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "settings_page")]
+    pub use {
+        crate::native::settings_page,
+        crate::style::SettingsPageStyles,
+        settings_page::{SettingsPage, SettingsRow, SettingsSection},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "wizard")]
+    pub use {
+        crate::native::wizard,
+        crate::style::WizardStyles,
+        wizard::{Wizard, WizardPage, WizardProgress},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "auth_form")]
+    pub use {crate::native::auth_form, crate::style::AuthFormStyles, auth_form::AuthForm};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "onboarding")]
+    pub use {
+        crate::native::onboarding,
+        crate::style::OnboardingStyles,
+        onboarding::{Onboarding, OnboardingPage},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "contrast_checker")]
+    pub use {
+        crate::native::contrast_checker,
+        crate::style::ContrastCheckerStyles,
+        contrast_checker::{ContrastChecker, Swatch},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "theme_editor")]
+    pub use {
+        crate::native::theme_editor, crate::style::ThemeEditorStyles, theme_editor::ThemeEditor,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "shortcut_recorder")]
+    pub use {
+        crate::native::shortcut_recorder,
+        crate::style::ShortcutRecorderStyles,
+        shortcut_recorder::{Shortcut, ShortcutRecorder},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "gamepad")]
+    pub use {crate::native::gamepad, crate::style::GamepadTargetStyles, gamepad::GamepadTarget};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "num_pad")]
+    pub use {crate::native::num_pad, crate::style::NumPadStyles, num_pad::NumPad};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "on_screen_keyboard")]
+    pub use {
+        crate::native::on_screen_keyboard,
+        crate::style::OnScreenKeyboardStyles,
+        on_screen_keyboard::{KeyboardLayout, OnScreenKeyboard},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "geo_map")]
+    pub use {
+        crate::native::geo_map,
+        crate::style::GeoMapStyles,
+        geo_map::{world, GeoMap, Region},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "org_chart")]
+    pub use {
+        crate::native::org_chart,
+        crate::style::OrgChartStyles,
+        org_chart::{OrgChart, OrgNode},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "mind_map")]
+    pub use {
+        crate::native::mind_map,
+        crate::style::MindMapStyles,
+        mind_map::{MindMap, MindMapLayout},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "gantt")]
+    pub use {crate::native::gantt, crate::style::GanttStyles, gantt::Gantt};
+
+    #[doc(no_inline)]
+    #[cfg(any(feature = "treemap", feature = "sunburst"))]
+    pub use crate::core::hierarchy::HierarchyNode;
+
+    #[doc(no_inline)]
+    #[cfg(feature = "treemap")]
+    pub use {crate::native::treemap, crate::style::TreemapStyles, treemap::Treemap};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "sunburst")]
+    pub use {crate::native::sunburst, crate::style::SunburstStyles, sunburst::Sunburst};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "live_chart")]
+    pub use {crate::native::live_chart, crate::style::LiveChartStyles, live_chart::LiveChart};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "candlestick")]
+    pub use {
+        crate::native::candlestick, crate::style::CandlestickStyles, candlestick::CandlestickChart,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "histogram")]
+    pub use {crate::native::histogram, crate::style::HistogramStyles, histogram::Histogram};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "radar")]
+    pub use {crate::native::radar, crate::style::RadarChartStyles, radar::RadarChart};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "chart_legend")]
+    pub use {
+        crate::native::chart_legend, crate::style::ChartLegendStyles, chart_legend::ChartLegend,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "gauge")]
+    pub use {crate::native::gauge, crate::style::GaugeStyles, gauge::Gauge};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "segment_display")]
+    pub use {
+        crate::native::segment_display, crate::style::SegmentDisplayStyles,
+        segment_display::SegmentDisplay,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "animated_number")]
+    pub use {
+        crate::native::animated_number, crate::style::AnimatedNumberStyles,
+        animated_number::AnimatedNumber,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "status_light")]
+    pub use {
+        crate::native::status_light, crate::style::StatusLightStyles, status_light::StatusLight,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "date_time_picker")]
+    pub use {crate::native::date_time_picker, date_time_picker::DateTimePicker};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "signal_bars")]
+    pub use {crate::native::signal_bars, crate::style::SignalBarsStyles, signal_bars::SignalBars};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "battery_indicator")]
+    pub use {
+        crate::native::battery_indicator, crate::style::BatteryIndicatorStyles,
+        battery_indicator::BatteryIndicator,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "duration_picker")]
+    pub use {
+        crate::native::duration_picker, crate::style::DurationPickerStyles,
+        duration_picker::DurationPicker,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "focus_ring")]
+    pub use {crate::native::focus_ring, crate::style::FocusRingStyles, focus_ring::FocusRing};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "focus_group")]
+    pub use {crate::native::focus_group, focus_group::FocusGroup};
 }
 
 #[doc(no_inline)]
@@ -0,0 +1,480 @@
+//! Use an animated number to show a value rolling or counting to its updates.
+//!
+//! *This API requires the following crate features to be activated: `animated_number`*
+use std::time::{Duration, Instant};
+
+use crate::core::animated_number::{interpolate, reels, Reel};
+
+use iced_widget::core::{
+    self,
+    alignment::{Horizontal, Vertical},
+    event,
+    layout::{Limits, Node},
+    mouse::{self, Cursor},
+    renderer,
+    text::{self, LineHeight},
+    widget::{
+        tree::{State as TreeState, Tag},
+        Tree,
+    },
+    window, Clipboard, Color, Element, Event, Layout, Length, Rectangle, Shell, Size, Widget,
+};
+
+pub use crate::{
+    core::{animated_number::Transition, easing::Easing},
+    style::animated_number::{Appearance, StyleSheet},
+};
+
+/// A number animating to its updates, either rolling its digits like an
+/// odometer or counting up or down to the new value.
+///
+/// The number fades from the rising or the falling color of its style while
+/// it moves, so frequent updates on a dashboard stand out.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{AnimatedNumber, animated_number::Transition};
+/// #
+/// let visitors = AnimatedNumber::new(1284.0)
+///     .transition(Transition::Count)
+///     .format(|value| format!("{value:.0} visitors"));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct AnimatedNumber<'a, Renderer = crate::Renderer>
+where
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The shown value.
+    value: f64,
+    /// The way the number moves to a new value.
+    transition: Transition,
+    /// The function formatting the value.
+    format: Box<dyn Fn(f64) -> String + 'a>,
+    /// The duration of the movement to a new value.
+    duration: Duration,
+    /// The easing of the movement to a new value.
+    easing: Easing,
+    /// The text size of the number.
+    text_size: f32,
+    /// The font of the number.
+    font: core::Font,
+    /// The horizontal alignment of the number.
+    horizontal_alignment: Horizontal,
+    /// The width of the [`AnimatedNumber`].
+    width: Length,
+    /// The height of the [`AnimatedNumber`].
+    height: Length,
+    /// The style of the [`AnimatedNumber`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Renderer> AnimatedNumber<'a, Renderer>
+where
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`AnimatedNumber`] showing the given value, rounded to
+    /// a whole number until [`format`](Self::format) is set.
+    #[must_use]
+    pub fn new(value: f64) -> Self {
+        Self {
+            value,
+            transition: Transition::default(),
+            format: Box::new(|value| format!("{value:.0}")),
+            duration: Duration::from_millis(600),
+            easing: Easing::default(),
+            text_size: 24.0,
+            font: core::Font::default(),
+            horizontal_alignment: Horizontal::Right,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the [`Transition`] of the [`AnimatedNumber`] to a new value.
+    #[must_use]
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transition = transition;
+        self
+    }
+
+    /// Sets the function formatting the value of the [`AnimatedNumber`],
+    /// e.g. with its decimals, a unit or thousands separators.
+    ///
+    /// The digits of the formatted values roll in place, so the formatting
+    /// should keep the number of decimals the same.
+    #[must_use]
+    pub fn format<F>(mut self, format: F) -> Self
+    where
+        F: 'a + Fn(f64) -> String,
+    {
+        self.format = Box::new(format);
+        self
+    }
+
+    /// Sets the duration of the movement of the [`AnimatedNumber`] to a new
+    /// value.
+    #[must_use]
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the easing of the movement of the [`AnimatedNumber`] to a new
+    /// value.
+    #[must_use]
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Sets the text size of the [`AnimatedNumber`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the [`AnimatedNumber`].
+    #[must_use]
+    pub fn font(mut self, font: core::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the horizontal alignment of the [`AnimatedNumber`] within its
+    /// width, which is to the right by default.
+    #[must_use]
+    pub fn horizontal_alignment(mut self, alignment: Horizontal) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the width of the [`AnimatedNumber`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`AnimatedNumber`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`AnimatedNumber`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the columns of the number at the given movement.
+    fn reels(&self, movement: &Movement) -> Vec<Reel> {
+        let progress = self.easing.apply(movement.progress);
+
+        match self.transition {
+            Transition::Roll => reels(
+                &(self.format)(movement.from),
+                &(self.format)(movement.to),
+                movement.to >= movement.from,
+                progress,
+            ),
+            Transition::Count => (self.format)(interpolate(movement.from, movement.to, progress))
+                .chars()
+                .map(Reel::new)
+                .collect(),
+        }
+    }
+
+    /// Returns the width of the given character, with all digits as wide as
+    /// the widest one so they roll in place.
+    fn char_width(&self, renderer: &Renderer, character: char) -> f32 {
+        let measure = |content: &str| {
+            renderer.measure_width(content, self.text_size, self.font, text::Shaping::Basic)
+        };
+
+        if character.is_ascii_digit() {
+            ('0'..='9')
+                .map(|digit| measure(&digit.to_string()))
+                .fold(0.0, f32::max)
+        } else {
+            measure(&character.to_string())
+        }
+    }
+
+    /// Returns the width of the given column.
+    fn reel_width(&self, renderer: &Renderer, reel: &Reel) -> f32 {
+        self.char_width(renderer, reel.from)
+            .max(self.char_width(renderer, reel.to))
+    }
+}
+
+/// The state of an [`AnimatedNumber`].
+#[derive(Clone, Copy, Debug)]
+struct State {
+    /// The movement of the number to its value.
+    movement: Movement,
+}
+
+/// The movement of an [`AnimatedNumber`] to a new value.
+#[derive(Clone, Copy, Debug)]
+struct Movement {
+    /// The value the number started at.
+    from: f64,
+    /// The value the number moves to.
+    to: f64,
+    /// The progress of the movement, from 0.0 to 1.0.
+    progress: f32,
+    /// The time of the last step of the movement.
+    last_update: Option<Instant>,
+}
+
+impl Movement {
+    /// Creates a new [`Movement`] resting at the given value.
+    fn new(value: f64) -> Self {
+        Self {
+            from: value,
+            to: value,
+            progress: 1.0,
+            last_update: None,
+        }
+    }
+
+    /// Moves the number from where it is shown to the given value.
+    fn aim(&mut self, value: f64, easing: Easing) {
+        if (value - self.to).abs() > f64::EPSILON {
+            *self = Self {
+                from: interpolate(self.from, self.to, easing.apply(self.progress)),
+                to: value,
+                progress: 0.0,
+                last_update: None,
+            };
+        }
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for AnimatedNumber<'_, Renderer>
+where
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State {
+            movement: Movement::new(self.value),
+        })
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        let width = self
+            .reels(&Movement::new(self.value))
+            .iter()
+            .map(|reel| self.reel_width(renderer, reel))
+            .sum();
+        let height = LineHeight::default().to_absolute(self.text_size.into()).0;
+
+        Node::new(limits.resolve(Size::new(width, height)))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let movement = &mut tree.state.downcast_mut::<State>().movement;
+            movement.aim(self.value, self.easing);
+
+            if movement.progress < 1.0 {
+                let last_update = movement.last_update.replace(now).unwrap_or(now);
+                movement.progress = if self.duration.is_zero() {
+                    1.0
+                } else {
+                    (movement.progress
+                        + (now - last_update).as_secs_f32() / self.duration.as_secs_f32())
+                    .min(1.0)
+                };
+
+                if movement.progress < 1.0 {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        mouse::Interaction::default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let movement = tree.state.downcast_ref::<State>().movement;
+        let rising = movement.to >= movement.from;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        // The number fades from the color of its direction to its rest.
+        let color = if movement.progress < 1.0 {
+            let start = if rising {
+                appearance.rising_color
+            } else {
+                appearance.falling_color
+            };
+            let progress = self.easing.apply(movement.progress);
+            let mix = |from: f32, to: f32| from + (to - from) * progress;
+
+            Color {
+                r: mix(start.r, appearance.text_color.r),
+                g: mix(start.g, appearance.text_color.g),
+                b: mix(start.b, appearance.text_color.b),
+                a: mix(start.a, appearance.text_color.a),
+            }
+        } else {
+            appearance.text_color
+        };
+
+        let reels = self.reels(&movement);
+        let widths: Vec<f32> = reels
+            .iter()
+            .map(|reel| self.reel_width(renderer, reel))
+            .collect();
+        let content_width: f32 = widths.iter().sum();
+        let mut x = match self.horizontal_alignment {
+            Horizontal::Left => bounds.x,
+            Horizontal::Center => bounds.center_x() - content_width / 2.0,
+            Horizontal::Right => bounds.x + bounds.width - content_width,
+        };
+        let line_height = LineHeight::default().to_absolute(self.text_size.into()).0;
+        let y = bounds.center_y() - line_height / 2.0;
+
+        renderer.with_layer(bounds, |renderer| {
+            for (reel, width) in reels.iter().zip(widths) {
+                let mut fill = |character: char, offset: f32| {
+                    renderer.fill_text(core::text::Text {
+                        content: &character.to_string(),
+                        bounds: Rectangle {
+                            x: x + width / 2.0,
+                            y: y + offset * line_height,
+                            width,
+                            height: line_height,
+                        },
+                        size: self.text_size,
+                        line_height: LineHeight::default(),
+                        color,
+                        font: self.font,
+                        horizontal_alignment: Horizontal::Center,
+                        vertical_alignment: Vertical::Top,
+                        shaping: text::Shaping::Basic,
+                    });
+                };
+
+                if reel.offset > 0.0 {
+                    // A rising number rolls its digits up, a falling one
+                    // down.
+                    let direction = if rising { -1.0 } else { 1.0 };
+                    fill(reel.current, direction * reel.offset);
+                    fill(reel.next, direction * (reel.offset - 1.0));
+                } else {
+                    fill(reel.current, 0.0);
+                }
+
+                x += width;
+            }
+        });
+    }
+}
+
+impl<'a, Message, Renderer> From<AnimatedNumber<'a, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: 'a + StyleSheet,
+{
+    fn from(animated_number: AnimatedNumber<'a, Renderer>) -> Self {
+        Element::new(animated_number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AnimatedNumber, Transition};
+    use crate::test_utils::Harness;
+    use iced_widget::core::Size;
+    use std::time::Duration;
+
+    #[test]
+    fn animated_number_test() {
+        let ink = |value: f64, transition: Transition| {
+            let number = AnimatedNumber::new(value)
+                .transition(transition)
+                .text_size(32.0);
+            let mut harness = Harness::<()>::new(number, Size::new(120.0, 48.0));
+            let snapshot = harness.snapshot();
+
+            // The number rests at its value.
+            let _ = harness.advance(Duration::from_millis(100));
+            assert_eq!(harness.snapshot().diff(&snapshot, 0), Some(0));
+
+            (0..snapshot.width)
+                .flat_map(|x| (0..snapshot.height).map(move |y| (x, y)))
+                .filter_map(|(x, y)| snapshot.color(x, y))
+                .filter(|color| color.a > 0.5 && color.r < 0.5)
+                .count()
+        };
+
+        // An 8 has more ink than a 1, whichever way it moves.
+        let eights = ink(88.0, Transition::Roll);
+        assert!(eights > ink(11.0, Transition::Roll));
+        assert_eq!(eights, ink(88.0, Transition::Count));
+    }
+}
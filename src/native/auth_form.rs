@@ -0,0 +1,515 @@
+//! Use an authentication form to sign in with an identifier and a password.
+//!
+//! *This API requires the following crate features to be activated: `auth_form`*
+
+use crate::core::i18n;
+use crate::graphics::icons::{icon_to_char, Icon, ICON_FONT};
+use crate::native::helpers::Styled;
+use crate::native::spinner::Spinner;
+use crate::style::spinner;
+
+use iced_widget::{
+    button, checkbox,
+    core::{self, renderer, Alignment, Color, Element, Length, Rectangle},
+    renderer::Renderer,
+    text, text_input, Button, Checkbox, Column, Row, Text, TextInput,
+};
+use std::rc::Rc;
+
+pub use crate::style::auth_form::{Appearance, StyleSheet};
+
+/// The padding around the form.
+const PADDING: f32 = 20.0;
+/// The spacing between the fields.
+const SPACING: f32 = 12.0;
+/// The padding inside of the fields.
+const FIELD_PADDING: f32 = 10.0;
+
+/// The function validating a field, returning the error to show below it.
+type ValidateFn<'a> = Box<dyn Fn(&str) -> Result<(), String> + 'a>;
+/// The function producing the message when a toggle is toggled.
+type ToggleFn<'a, Message> = Box<dyn Fn(bool) -> Message + 'a>;
+
+/// A sign in form with an identifier, e.g. a username or an email address,
+/// and a password.
+///
+/// The password can be revealed with the eye button next to it. Invalid
+/// fields show the error of their validation function below them, and keep
+/// the submit button disabled like empty fields. While
+/// [`loading`](AuthForm::loading), the fields are read-only and the submit
+/// button shows a [`Spinner`]. An error, e.g. of the authentication, is
+/// shown in a banner above the fields.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::AuthForm;
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     EmailChanged(String),
+///     PasswordChanged(String),
+///     Submit,
+/// }
+///
+/// let email = String::new();
+/// let password = String::new();
+///
+/// let auth_form = AuthForm::new(
+///     &email,
+///     Message::EmailChanged,
+///     &password,
+///     Message::PasswordChanged,
+///     Message::Submit,
+/// )
+/// .validate_identifier(|email| {
+///     if email.contains('@') {
+///         Ok(())
+///     } else {
+///         Err(String::from("Enter a valid email address"))
+///     }
+/// });
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct AuthForm<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The title of the form.
+    title: Option<String>,
+    /// The label of the identifier field.
+    identifier_label: String,
+    /// The identifier.
+    identifier: &'a str,
+    /// The function producing the message when the identifier is changed.
+    on_identifier: Rc<dyn Fn(String) -> Message + 'a>,
+    /// The function validating the identifier.
+    validate_identifier: Option<ValidateFn<'a>>,
+    /// The password.
+    password: &'a str,
+    /// The function producing the message when the password is changed.
+    on_password: Rc<dyn Fn(String) -> Message + 'a>,
+    /// The function validating the password.
+    validate_password: Option<ValidateFn<'a>>,
+    /// Whether the password is revealed, and the function producing the
+    /// message when it is toggled.
+    reveal: Option<(bool, ToggleFn<'a, Message>)>,
+    /// Whether the credentials are remembered, and the function producing
+    /// the message when it is toggled.
+    remember: Option<(bool, ToggleFn<'a, Message>)>,
+    /// The message produced when the form is submitted.
+    on_submit: Message,
+    /// The label of the submit button.
+    submit_label: String,
+    /// Whether the form is being submitted.
+    loading: bool,
+    /// The error shown in the banner.
+    error: Option<&'a str>,
+    /// The width of the form.
+    width: Length,
+    /// The text size of the form.
+    text_size: f32,
+    /// The style of the form.
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> AuthForm<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + StyleSheet
+        + button::StyleSheet
+        + checkbox::StyleSheet
+        + spinner::StyleSheet
+        + text::StyleSheet
+        + text_input::StyleSheet,
+{
+    /// Creates a new [`AuthForm`].
+    ///
+    /// It expects:
+    ///     * the identifier, e.g. a username or an email address.
+    ///     * the function producing the message when the identifier is changed.
+    ///     * the password.
+    ///     * the function producing the message when the password is changed.
+    ///     * the message produced when the form is submitted.
+    pub fn new<I, P>(
+        identifier: &'a str,
+        on_identifier: I,
+        password: &'a str,
+        on_password: P,
+        on_submit: Message,
+    ) -> Self
+    where
+        I: 'a + Fn(String) -> Message,
+        P: 'a + Fn(String) -> Message,
+    {
+        let translations = i18n::get();
+
+        Self {
+            title: None,
+            identifier_label: translations.email(),
+            identifier,
+            on_identifier: Rc::new(on_identifier),
+            validate_identifier: None,
+            password,
+            on_password: Rc::new(on_password),
+            validate_password: None,
+            reveal: None,
+            remember: None,
+            on_submit,
+            submit_label: translations.sign_in(),
+            loading: false,
+            error: None,
+            width: Length::Fixed(360.0),
+            text_size: 16.0,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the title shown above the fields of the [`AuthForm`].
+    #[must_use]
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the label of the identifier field of the [`AuthForm`], e.g.
+    /// `"Username"`.
+    #[must_use]
+    pub fn identifier_label(mut self, label: impl Into<String>) -> Self {
+        self.identifier_label = label.into();
+        self
+    }
+
+    /// Sets the function validating the identifier of the [`AuthForm`].
+    #[must_use]
+    pub fn validate_identifier<F>(mut self, validate: F) -> Self
+    where
+        F: 'a + Fn(&str) -> Result<(), String>,
+    {
+        self.validate_identifier = Some(Box::new(validate));
+        self
+    }
+
+    /// Sets the function validating the password of the [`AuthForm`].
+    #[must_use]
+    pub fn validate_password<F>(mut self, validate: F) -> Self
+    where
+        F: 'a + Fn(&str) -> Result<(), String>,
+    {
+        self.validate_password = Some(Box::new(validate));
+        self
+    }
+
+    /// Shows a button revealing the password of the [`AuthForm`].
+    #[must_use]
+    pub fn reveal<F>(mut self, revealed: bool, on_toggle: F) -> Self
+    where
+        F: 'a + Fn(bool) -> Message,
+    {
+        self.reveal = Some((revealed, Box::new(on_toggle)));
+        self
+    }
+
+    /// Shows a "Remember me" checkbox in the [`AuthForm`].
+    #[must_use]
+    pub fn remember<F>(mut self, remember: bool, on_toggle: F) -> Self
+    where
+        F: 'a + Fn(bool) -> Message,
+    {
+        self.remember = Some((remember, Box::new(on_toggle)));
+        self
+    }
+
+    /// Sets the label of the submit button of the [`AuthForm`].
+    #[must_use]
+    pub fn submit_label(mut self, label: impl Into<String>) -> Self {
+        self.submit_label = label.into();
+        self
+    }
+
+    /// Sets whether the [`AuthForm`] is being submitted.
+    #[must_use]
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Sets the error shown in a banner above the fields of the [`AuthForm`].
+    #[must_use]
+    pub fn error(mut self, error: Option<&'a str>) -> Self {
+        self.error = error;
+        self
+    }
+
+    /// Sets the width of the [`AuthForm`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the text size of the [`AuthForm`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the style of the [`AuthForm`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Wraps the given element to draw it with the [`Appearance`].
+    fn styled(
+        &self,
+        content: impl Into<Element<'a, Message, Renderer<Theme>>>,
+        role: Role,
+    ) -> Element<'a, Message, Renderer<Theme>> {
+        let style = self.style.clone();
+
+        Styled::new(
+            content,
+            move |renderer, theme: &Theme, _defaults, bounds, _cursor| {
+                role.draw(renderer, &StyleSheet::active(theme, &style), bounds)
+            },
+        )
+        .into()
+    }
+
+    /// Creates a labeled field with the error of its validation below it.
+    fn field(
+        &self,
+        label: &str,
+        input: impl Into<Element<'a, Message, Renderer<Theme>>>,
+        error: Option<String>,
+    ) -> Element<'a, Message, Renderer<Theme>> {
+        let mut field = Column::new()
+            .spacing(4.0)
+            .push(Text::new(label.to_owned()).size(self.text_size * 0.9))
+            .push(input);
+
+        if let Some(error) = error {
+            field = field.push(self.styled(
+                Text::new(error).size(self.text_size * 0.8),
+                Role::FieldError,
+            ));
+        }
+
+        field.into()
+    }
+}
+
+impl<'a, Message, Theme> From<AuthForm<'a, Message, Theme>>
+    for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + StyleSheet
+        + button::StyleSheet
+        + checkbox::StyleSheet
+        + spinner::StyleSheet
+        + text::StyleSheet
+        + text_input::StyleSheet,
+{
+    fn from(mut auth_form: AuthForm<'a, Message, Theme>) -> Self {
+        // Empty fields are not validated, but still keep the form from being
+        // submitted.
+        let validate = |value: &str, validate: &Option<ValidateFn<'a>>| {
+            validate
+                .as_ref()
+                .filter(|_| !value.is_empty())
+                .and_then(|validate| validate(value).err())
+        };
+        let identifier_error = validate(auth_form.identifier, &auth_form.validate_identifier);
+        let password_error = validate(auth_form.password, &auth_form.validate_password);
+
+        let can_submit = !auth_form.loading
+            && !auth_form.identifier.is_empty()
+            && !auth_form.password.is_empty()
+            && identifier_error.is_none()
+            && password_error.is_none();
+        let submit = can_submit.then(|| auth_form.on_submit.clone());
+
+        let mut identifier = TextInput::new("", auth_form.identifier)
+            .size(auth_form.text_size)
+            .padding(FIELD_PADDING);
+        if !auth_form.loading {
+            let on_identifier = Rc::clone(&auth_form.on_identifier);
+            identifier = identifier.on_input(move |text| on_identifier(text));
+        }
+        if let Some(submit) = submit.clone() {
+            identifier = identifier.on_submit(submit);
+        }
+
+        let revealed = auth_form
+            .reveal
+            .as_ref()
+            .is_some_and(|(revealed, _)| *revealed);
+        let mut password = TextInput::new("", auth_form.password)
+            .size(auth_form.text_size)
+            .padding(FIELD_PADDING)
+            .width(Length::Fill);
+        if !revealed {
+            password = password.password();
+        }
+        if !auth_form.loading {
+            let on_password = Rc::clone(&auth_form.on_password);
+            password = password.on_input(move |text| on_password(text));
+        }
+        if let Some(submit) = submit.clone() {
+            password = password.on_submit(submit);
+        }
+
+        let password: Element<'a, Message, Renderer<Theme>> = match auth_form.reveal.take() {
+            Some((revealed, on_toggle)) => {
+                let icon = if revealed { Icon::EyeSlash } else { Icon::Eye };
+                Row::new()
+                    .spacing(4.0)
+                    .align_items(Alignment::Center)
+                    .push(password)
+                    .push(
+                        Button::new(
+                            Text::new(icon_to_char(icon).to_string())
+                                .font(ICON_FONT)
+                                .size(auth_form.text_size),
+                        )
+                        .padding(FIELD_PADDING)
+                        .on_press(on_toggle(!revealed)),
+                    )
+                    .into()
+            }
+            None => password.into(),
+        };
+
+        let mut content = Column::new().spacing(SPACING).width(Length::Fill);
+
+        if let Some(title) = auth_form.title.take() {
+            content = content.push(auth_form.styled(
+                Text::new(title).size(auth_form.text_size * 1.5),
+                Role::Title,
+            ));
+        }
+
+        if let Some(error) = auth_form.error {
+            content = content.push(
+                auth_form.styled(
+                    Row::new()
+                        .spacing(8.0)
+                        .padding(FIELD_PADDING)
+                        .width(Length::Fill)
+                        .push(
+                            Text::new(icon_to_char(Icon::ExclamationCircle).to_string())
+                                .font(ICON_FONT)
+                                .size(auth_form.text_size),
+                        )
+                        .push(Text::new(error.to_owned()).size(auth_form.text_size)),
+                    Role::Banner,
+                ),
+            );
+        }
+
+        let translations = i18n::get();
+
+        content = content
+            .push(auth_form.field(&auth_form.identifier_label, identifier, identifier_error))
+            .push(auth_form.field(&translations.password(), password, password_error));
+
+        if let Some((remember, on_toggle)) = auth_form.remember.take() {
+            content = content.push(
+                Checkbox::new(translations.remember_me(), remember, on_toggle)
+                    .text_size(auth_form.text_size),
+            );
+        }
+
+        let submit_label: Element<'a, Message, Renderer<Theme>> = if auth_form.loading {
+            Row::new()
+                .spacing(8.0)
+                .align_items(Alignment::Center)
+                .push(Spinner::new())
+                .push(Text::new(auth_form.submit_label.clone()).size(auth_form.text_size))
+                .into()
+        } else {
+            Text::new(auth_form.submit_label.clone())
+                .size(auth_form.text_size)
+                .into()
+        };
+        let mut submit_button = Button::new(
+            Column::new()
+                .width(Length::Fill)
+                .align_items(Alignment::Center)
+                .push(submit_label),
+        )
+        .padding(FIELD_PADDING)
+        .width(Length::Fill);
+        if let Some(submit) = submit {
+            submit_button = submit_button.on_press(submit);
+        }
+        content = content.push(submit_button);
+
+        auth_form.styled(
+            Column::new()
+                .padding(PADDING)
+                .width(auth_form.width)
+                .push(content),
+            Role::Frame,
+        )
+    }
+}
+
+/// How a [`Styled`] element is drawn with the [`Appearance`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Role {
+    /// The background of the [`AuthForm`].
+    Frame,
+    /// The title.
+    Title,
+    /// The error banner.
+    Banner,
+    /// The error of the validation of a field.
+    FieldError,
+}
+
+impl Role {
+    /// Draws the background of an element with the [`Role`], returning the
+    /// color of its text.
+    fn draw<Renderer: core::Renderer>(
+        self,
+        renderer: &mut Renderer,
+        appearance: &Appearance,
+        bounds: Rectangle,
+    ) -> Color {
+        match self {
+            Self::Frame => {
+                core::Renderer::fill_quad(
+                    renderer,
+                    renderer::Quad {
+                        bounds,
+                        border_radius: appearance.border_radius.into(),
+                        border_width: appearance.border_width,
+                        border_color: appearance.border_color,
+                    },
+                    appearance.background,
+                );
+                appearance.text_color
+            }
+            Self::Title => appearance.title_color,
+            Self::Banner => {
+                core::Renderer::fill_quad(
+                    renderer,
+                    renderer::Quad {
+                        bounds,
+                        border_radius: appearance.banner_border_radius.into(),
+                        border_width: 1.0,
+                        border_color: appearance.error_color,
+                    },
+                    appearance.error_background,
+                );
+                appearance.error_color
+            }
+            Self::FieldError => appearance.error_color,
+        }
+    }
+}
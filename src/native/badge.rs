@@ -1,20 +1,36 @@
 //! Use a badge for color highlighting important information.
 //!
 //! *This API requires the following crate features to be activated: badge*
-use iced_widget::core::{
-    self, event,
-    layout::{Limits, Node},
-    mouse::{self, Cursor},
-    renderer,
-    widget::tree::Tree,
-    Alignment, Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
+use crate::core::{
+    badge::CountTracker,
+    i18n,
+    layout_direction::{self, LayoutDirection},
+    pixel,
 };
 
+use iced_widget::{
+    core::{
+        self, event,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer,
+        widget::tree::{State, Tag, Tree},
+        window, Alignment, Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle,
+        Shell, Widget,
+    },
+    text, Text,
+};
+use std::time::Instant;
+
+pub use crate::core::badge::{accessible_text, count_text, DEFAULT_MAX_COUNT};
 pub use crate::style::badge::{Appearance, StyleSheet};
 
 /// The ratio of the border radius.
 const BORDER_RADIUS_RATIO: f32 = 34.0 / 15.0;
 
+/// How far the ring played when the count changes grows around the [`Badge`].
+const PULSE_SPREAD: f32 = 6.0;
+
 /// A badge for color highlighting small information.
 ///
 /// # Example
@@ -28,6 +44,23 @@ const BORDER_RADIUS_RATIO: f32 = 34.0 / 15.0;
 ///
 /// let badge = Badge::<Message>::new(Text::new("Text"));
 /// ```
+///
+/// A [`Badge`] showing a count can announce its changes to assistive
+/// technologies, and plays a short animation when it changes:
+/// ```ignore
+/// # use iced_aw::Badge;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Announce(String),
+/// }
+///
+/// let unread = 3;
+///
+/// let badge = Badge::with_count(unread, 99)
+///     .accessible_label("unread message", "unread messages")
+///     .on_announce(Message::Announce);
+/// ```
 #[allow(missing_debug_implementations)]
 pub struct Badge<'a, Message, Renderer = crate::Renderer>
 where
@@ -48,6 +81,16 @@ where
     style: <Renderer::Theme as StyleSheet>::Style,
     /// The content [`Element`] of the [`Badge`].
     content: Element<'a, Message, Renderer>,
+    /// The count shown by the [`Badge`].
+    count: Option<usize>,
+    /// The singular and plural nouns describing the count.
+    accessible_label: (String, String),
+    /// The function producing the message announcing a changed count.
+    on_announce: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    /// The scale factor the border of the [`Badge`] is snapped to, if any.
+    scale_factor: Option<f32>,
+    /// The [`LayoutDirection`] the content of the [`Badge`] is aligned in.
+    direction: LayoutDirection,
 }
 
 impl<'a, Message, Renderer> Badge<'a, Message, Renderer>
@@ -63,6 +106,8 @@ where
     where
         T: Into<Element<'a, Message, Renderer>>,
     {
+        let translations = i18n::get();
+
         Badge {
             padding: 7,
             width: Length::Shrink,
@@ -71,10 +116,62 @@ where
             vertical_alignment: Alignment::Center,
             style: <Renderer::Theme as StyleSheet>::Style::default(),
             content: content.into(),
+            count: None,
+            accessible_label: (translations.item(), translations.items()),
+            on_announce: None,
+            scale_factor: None,
+            direction: layout_direction::get(),
         }
     }
 
+    /// Sets the count shown by the [`Badge`].
+    ///
+    /// The [`Badge`] plays a short animation and announces the count when it
+    /// changes.
+    #[must_use]
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the singular and plural nouns describing the count of the
+    /// [`Badge`] to assistive technologies, e.g. `"unread message"` and
+    /// `"unread messages"`.
+    #[must_use]
+    pub fn accessible_label(
+        mut self,
+        singular: impl Into<String>,
+        plural: impl Into<String>,
+    ) -> Self {
+        self.accessible_label = (singular.into(), plural.into());
+        self
+    }
+
+    /// Sets the function producing the message announcing the count of the
+    /// [`Badge`] when it changes, e.g. `"3 unread messages"`, to inform
+    /// assistive technologies.
+    #[must_use]
+    pub fn on_announce<F>(mut self, on_announce: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        self.on_announce = Some(Box::new(on_announce));
+        self
+    }
+
+    /// Gets the text describing the count of the [`Badge`] to assistive
+    /// technologies, if it shows a count.
+    #[must_use]
+    pub fn accessible_text(&self) -> Option<String> {
+        let (singular, plural) = &self.accessible_label;
+
+        self.count
+            .map(|count| accessible_text(count, singular, plural))
+    }
+
     /// Sets the horizontal alignment of the content of the [`Badge`].
+    ///
+    /// The start is on the right if the [`LayoutDirection`] is right to left.
     #[must_use]
     pub fn align_x(mut self, alignment: Alignment) -> Self {
         self.horizontal_alignment = alignment;
@@ -88,6 +185,16 @@ where
         self
     }
 
+    /// Sets the scale factor the [`Badge`] is rendered at, to snap its
+    /// border to physical pixels.
+    ///
+    /// Nothing is snapped unless the scale factor is set.
+    #[must_use]
+    pub fn scale_factor(mut self, scale_factor: f32) -> Self {
+        self.scale_factor = Some(scale_factor);
+        self
+    }
+
     /// Sets the height of the [`Badge`].
     #[must_use]
     pub fn height(mut self, height: Length) -> Self {
@@ -117,12 +224,34 @@ where
     }
 }
 
+impl<'a, Message, Renderer> Badge<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + core::text::Renderer,
+    Renderer::Theme: StyleSheet + text::StyleSheet,
+{
+    /// Creates a new [`Badge`] showing the given count, or the maximum count
+    /// followed by a `+` if it overflows, e.g. `99+`.
+    #[must_use]
+    pub fn with_count(count: usize, max: usize) -> Self {
+        Self::new(Text::new(count_text(count, max))).count(count)
+    }
+}
+
 impl<'a, Message, Renderer> Widget<Message, Renderer> for Badge<'a, Message, Renderer>
 where
     Message: 'a + Clone,
     Renderer: 'a + core::Renderer,
     Renderer::Theme: StyleSheet,
 {
+    fn tag(&self) -> Tag {
+        Tag::of::<CountTracker>()
+    }
+
+    fn state(&self) -> State {
+        State::new(CountTracker::default())
+    }
+
     fn children(&self) -> Vec<Tree> {
         vec![Tree::new(&self.content)]
     }
@@ -151,7 +280,11 @@ where
         let size = limits.resolve(content.size());
 
         content.move_to(Point::new(padding.left, padding.top));
-        content.align(self.horizontal_alignment, self.vertical_alignment, size);
+        content.align(
+            self.direction.align(self.horizontal_alignment),
+            self.vertical_alignment,
+            size,
+        );
 
         Node::with_children(size.pad(padding), vec![content])
     }
@@ -167,6 +300,23 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
+        if let (Event::Window(window::Event::RedrawRequested(now)), Some(count)) =
+            (&event, self.count)
+        {
+            let tracker = state.state.downcast_mut::<CountTracker>();
+
+            if tracker.update(count, *now) {
+                if let Some(on_announce) = &self.on_announce {
+                    let (singular, plural) = &self.accessible_label;
+                    shell.publish(on_announce(accessible_text(count, singular, plural)));
+                }
+            }
+
+            if tracker.pulse(*now).is_some() {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+
         self.content.as_widget_mut().on_event(
             &mut state.children[0],
             event,
@@ -209,7 +359,7 @@ where
         cursor: Cursor,
         viewport: &Rectangle,
     ) {
-        let bounds = layout.bounds();
+        let bounds = pixel::snap_rectangle_to(layout.bounds(), self.scale_factor);
         let mut children = layout.children();
         let is_mouse_over = bounds.contains(cursor.position().unwrap_or_default());
         let style_sheet = if is_mouse_over {
@@ -229,12 +379,39 @@ where
             renderer::Quad {
                 bounds,
                 border_radius: border_radius.into(),
-                border_width: style_sheet.border_width,
+                border_width: pixel::snap_width_to(style_sheet.border_width, self.scale_factor),
                 border_color: style_sheet.border_color.unwrap_or(Color::BLACK),
             },
             style_sheet.background,
         );
 
+        if let Some(progress) = tree
+            .state
+            .downcast_ref::<CountTracker>()
+            .pulse(Instant::now())
+        {
+            let spread = PULSE_SPREAD * progress;
+            let color = style_sheet.border_color.unwrap_or(style_sheet.text_color);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x - spread,
+                        y: bounds.y - spread,
+                        width: bounds.width + spread * 2.0,
+                        height: bounds.height + spread * 2.0,
+                    },
+                    border_radius: (border_radius + spread).into(),
+                    border_width: 2.0,
+                    border_color: Color {
+                        a: color.a * (1.0 - progress),
+                        ..color
+                    },
+                },
+                Color::TRANSPARENT,
+            );
+        }
+
         self.content.as_widget().draw(
             &tree.children[0],
             renderer,
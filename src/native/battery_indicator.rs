@@ -0,0 +1,402 @@
+//! Use a battery indicator to show the charge of a battery.
+//!
+//! *This API requires the following crate features to be activated: `battery_indicator`*
+use crate::graphics::icons::{icon_to_char, Icon, ICON_FONT};
+
+use iced_widget::core::{
+    self,
+    alignment::{Horizontal, Vertical},
+    layout::{Limits, Node},
+    mouse::{self, Cursor},
+    renderer,
+    text::{self, LineHeight},
+    widget::Tree,
+    Color, Element, Layout, Length, Rectangle, Size, Widget,
+};
+
+pub use crate::style::battery_indicator::{Appearance, StyleSheet};
+
+/// An indicator of the charge of a battery, filled up to its level, with a
+/// bolt while charging, a warning color when the charge is low and an
+/// optional percentage next to it.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{BatteryIndicator, style::BatteryIndicatorStyles};
+/// #
+/// let battery = BatteryIndicator::new(0.42)
+///     .charging(true)
+///     .show_percentage()
+///     .style(BatteryIndicatorStyles::Cupertino);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct BatteryIndicator<Renderer = crate::Renderer>
+where
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The charge of the battery, from `0.0` to `1.0`.
+    level: f32,
+    /// Whether the battery is charging.
+    charging: bool,
+    /// The charge up to which the battery is low.
+    low_level: f32,
+    /// Whether the percentage is shown next to the battery.
+    show_percentage: bool,
+    /// The height of the battery.
+    size: f32,
+    /// The text size of the percentage.
+    text_size: f32,
+    /// The font of the percentage.
+    font: core::Font,
+    /// The space between the battery and the percentage.
+    spacing: f32,
+    /// The width of the [`BatteryIndicator`].
+    width: Length,
+    /// The height of the [`BatteryIndicator`].
+    height: Length,
+    /// The style of the [`BatteryIndicator`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<Renderer> BatteryIndicator<Renderer>
+where
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`BatteryIndicator`] showing the given charge, from
+    /// `0.0` to `1.0`.
+    #[must_use]
+    pub fn new(level: f32) -> Self {
+        Self {
+            level,
+            charging: false,
+            low_level: 0.2,
+            show_percentage: false,
+            size: 12.0,
+            text_size: 14.0,
+            font: core::Font::default(),
+            spacing: 6.0,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets whether the battery of the [`BatteryIndicator`] is charging,
+    /// showing a bolt over it.
+    #[must_use]
+    pub fn charging(mut self, charging: bool) -> Self {
+        self.charging = charging;
+        self
+    }
+
+    /// Sets the charge up to which the [`BatteryIndicator`] shows the battery
+    /// as low, `0.2` by default.
+    #[must_use]
+    pub fn low_level(mut self, low_level: f32) -> Self {
+        self.low_level = low_level;
+        self
+    }
+
+    /// Shows the percentage of the charge next to the battery of the
+    /// [`BatteryIndicator`].
+    #[must_use]
+    pub fn show_percentage(mut self) -> Self {
+        self.show_percentage = true;
+        self
+    }
+
+    /// Sets the height of the battery of the [`BatteryIndicator`].
+    #[must_use]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the text size of the percentage of the [`BatteryIndicator`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the percentage of the [`BatteryIndicator`].
+    #[must_use]
+    pub fn font(mut self, font: core::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the space between the battery and the percentage of the
+    /// [`BatteryIndicator`].
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the width of the [`BatteryIndicator`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`BatteryIndicator`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`BatteryIndicator`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The charge, clamped to a valid level.
+    fn charge(&self) -> f32 {
+        if self.level.is_nan() {
+            0.0
+        } else {
+            self.level.clamp(0.0, 1.0)
+        }
+    }
+
+    /// The percentage shown next to the battery.
+    fn percentage(&self) -> String {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let percentage = (self.charge() * 100.0).round() as u8;
+
+        format!("{percentage}%")
+    }
+
+    /// The size of the body of the battery.
+    fn body_size(&self) -> Size {
+        Size::new(self.size * 2.0, self.size)
+    }
+
+    /// The width of the terminal cap of the battery.
+    fn cap_width(&self) -> f32 {
+        (self.size / 6.0).max(1.0)
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for BatteryIndicator<Renderer>
+where
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        let battery = Size::new(self.body_size().width + self.cap_width(), self.size);
+
+        let size = if self.show_percentage {
+            let width = renderer.measure_width(
+                &self.percentage(),
+                self.text_size,
+                self.font,
+                text::Shaping::Basic,
+            );
+            let height = LineHeight::default().to_absolute(self.text_size.into()).0;
+
+            Size::new(
+                battery.width + self.spacing + width,
+                battery.height.max(height),
+            )
+        } else {
+            battery
+        };
+
+        Node::new(limits.resolve(size))
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        mouse::Interaction::default()
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let charge = self.charge();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        let body_size = self.body_size();
+        let body = Rectangle {
+            x: bounds.x,
+            y: bounds.center_y() - body_size.height / 2.0,
+            width: body_size.width,
+            height: body_size.height,
+        };
+
+        let cap_width = self.cap_width();
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: body.x + body.width,
+                    y: body.center_y() - body.height / 4.0,
+                    width: cap_width,
+                    height: body.height / 2.0,
+                },
+                border_radius: [0.0, cap_width / 2.0, cap_width / 2.0, 0.0].into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.cap_color,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: body,
+                border_radius: appearance.border_radius.into(),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.body_background,
+        );
+
+        let inset = appearance.border_width + appearance.padding;
+        let fill = Rectangle {
+            x: body.x + inset,
+            y: body.y + inset,
+            width: (body.width - 2.0 * inset).max(0.0) * charge,
+            height: (body.height - 2.0 * inset).max(0.0),
+        };
+        let fill_color = if self.charging {
+            appearance.charging_color
+        } else if charge <= self.low_level {
+            appearance.low_color
+        } else {
+            appearance.fill_color
+        };
+
+        if fill.width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: fill,
+                    border_radius: (appearance.border_radius - inset).max(0.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                fill_color,
+            );
+        }
+
+        if self.charging {
+            renderer.fill_text(core::text::Text {
+                content: &icon_to_char(Icon::LightningChargeFill).to_string(),
+                bounds: Rectangle {
+                    x: body.center_x(),
+                    y: body.center_y(),
+                    ..body
+                },
+                size: body.height * 0.9,
+                line_height: LineHeight::Relative(1.0),
+                color: appearance.bolt_color,
+                font: ICON_FONT,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Basic,
+            });
+        }
+
+        if self.show_percentage {
+            renderer.fill_text(core::text::Text {
+                content: &self.percentage(),
+                bounds: Rectangle {
+                    x: body.x + body.width + cap_width + self.spacing,
+                    y: bounds.center_y(),
+                    width: f32::INFINITY,
+                    ..bounds
+                },
+                size: self.text_size,
+                line_height: LineHeight::default(),
+                color: appearance.text_color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Basic,
+            });
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<BatteryIndicator<Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: 'a + StyleSheet,
+{
+    fn from(battery_indicator: BatteryIndicator<Renderer>) -> Self {
+        Element::new(battery_indicator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BatteryIndicator;
+    use crate::test_utils::Harness;
+    use iced_widget::core::{Color, Size};
+
+    #[test]
+    fn battery_indicator_test() {
+        let battery = BatteryIndicator::new(0.5).size(20.0).show_percentage();
+        let mut harness = Harness::<()>::new(battery, Size::new(100.0, 30.0));
+        let snapshot = harness.snapshot();
+        let color = |x, y| snapshot.color(x, y).expect("Pixel is in bounds");
+
+        // The battery is filled up to half of its charge.
+        let full = color(8, 10);
+        assert!(full.g > full.r);
+        assert_eq!(color(30, 10), Color::WHITE);
+
+        // The percentage is shown next to it.
+        assert!((50..90).any(|x| (4..16).any(|y| color(x, y).r < 0.3)));
+
+        // A low battery turns into the warning color, unless it is charging.
+        let battery = BatteryIndicator::new(0.1).size(20.0);
+        let mut harness = Harness::<()>::new(battery, Size::new(100.0, 30.0));
+        let low = harness.snapshot().color(4, 10).expect("Pixel is in bounds");
+        assert!(low.r > 0.6 && low.g < 0.4);
+
+        let battery = BatteryIndicator::new(0.1).size(20.0).charging(true);
+        let mut harness = Harness::<()>::new(battery, Size::new(100.0, 30.0));
+        assert_eq!(harness.snapshot().color(4, 10), Some(full));
+    }
+}
@@ -0,0 +1,521 @@
+//! Use a calendar to show a month and pick a day inline in the layout.
+//!
+//! *This API requires the following crate features to be activated: `calendar`*
+
+use super::overlay::date_picker::{
+    calendar, cell_date, days, month_year, Constraints, DayAppearances, Focus, Selection,
+    WeekNumbers,
+};
+use crate::{
+    core::{i18n, layout_direction},
+    style::style_state::StyleState,
+};
+
+use chrono::{Datelike, NaiveDate};
+use iced_widget::{
+    button, container,
+    core::{
+        event,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer, touch,
+        widget::{
+            tree::{State as TreeState, Tag},
+            Tree,
+        },
+        Clipboard, Element, Event, Layout, Length, Padding, Point, Rectangle, Renderer as _, Shell,
+        Size, Widget,
+    },
+    renderer::Renderer,
+    text,
+};
+use std::collections::HashMap;
+
+pub use crate::{
+    core::date::{Date, Weekday},
+    style::date_picker::{Appearance, StyleSheet},
+};
+
+/// The padding around the month and year bar and the days.
+const PADDING: f32 = 10.0;
+
+/// A calendar showing the days of a month inline in the layout, without the
+/// button and the overlay of a [`DatePicker`](crate::native::DatePicker).
+///
+/// Clicking a day produces a message with its date. The arrows of the month
+/// and year bar browse the months and the years, and the calendar follows
+/// the date it is given when it changes.
+///
+/// The [`Calendar`] is styled like the overlay of the
+/// [`DatePicker`](crate::native::DatePicker).
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{calendar::Date, Calendar};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Select(Date),
+/// }
+///
+/// let calendar = Calendar::new(Date::today(), Message::Select);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Calendar<'a, Message, Theme = iced_widget::style::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The picked date.
+    date: Date,
+    /// The function producing the message when a day is clicked.
+    on_select: Box<dyn Fn(Date) -> Message + 'a>,
+    /// The earliest date that can be picked.
+    min_date: Option<Date>,
+    /// The latest date that can be picked.
+    max_date: Option<Date>,
+    /// The function disabling the dates that can't be picked.
+    disable_if: Option<Box<dyn Fn(Date) -> bool + 'a>>,
+    /// The weekday the weeks start on.
+    first_day_of_week: Weekday,
+    /// Whether the ISO 8601 week numbers are shown.
+    week_numbers: bool,
+    /// The width of the [`Calendar`].
+    width: Length,
+    /// The height of the [`Calendar`].
+    height: Length,
+    /// The style of the [`Calendar`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> Calendar<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Calendar`] showing the month of the given picked date,
+    /// which produces a message with the date of a clicked day.
+    pub fn new<F>(date: impl Into<Date>, on_select: F) -> Self
+    where
+        F: 'a + Fn(Date) -> Message,
+    {
+        Self {
+            date: date.into(),
+            on_select: Box::new(on_select),
+            min_date: None,
+            max_date: None,
+            disable_if: None,
+            first_day_of_week: Weekday::Mon,
+            week_numbers: false,
+            width: Length::Fixed(300.0),
+            height: Length::Fixed(300.0),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the earliest date that can be picked with the [`Calendar`].
+    #[must_use]
+    pub fn min_date(mut self, date: impl Into<Date>) -> Self {
+        self.min_date = Some(date.into());
+        self
+    }
+
+    /// Sets the latest date that can be picked with the [`Calendar`].
+    #[must_use]
+    pub fn max_date(mut self, date: impl Into<Date>) -> Self {
+        self.max_date = Some(date.into());
+        self
+    }
+
+    /// Sets the function disabling the dates that can't be picked with the
+    /// [`Calendar`], e.g. weekends or holidays.
+    #[must_use]
+    pub fn disable_if<F>(mut self, disable_if: F) -> Self
+    where
+        F: 'a + Fn(Date) -> bool,
+    {
+        self.disable_if = Some(Box::new(disable_if));
+        self
+    }
+
+    /// Sets the weekday the weeks of the [`Calendar`] start on.
+    ///
+    /// The weeks start on Monday by default.
+    #[must_use]
+    pub fn first_day_of_week(mut self, first_day_of_week: Weekday) -> Self {
+        self.first_day_of_week = first_day_of_week;
+        self
+    }
+
+    /// Sets whether the [`Calendar`] shows the ISO 8601 week numbers in a
+    /// column before the days.
+    #[must_use]
+    pub fn week_numbers(mut self, week_numbers: bool) -> Self {
+        self.week_numbers = week_numbers;
+        self
+    }
+
+    /// Sets the width of the [`Calendar`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Calendar`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`Calendar`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the dates that can be picked.
+    fn constraints(&self) -> Constraints<'_> {
+        Constraints {
+            min: self.min_date.map(Into::into),
+            max: self.max_date.map(Into::into),
+            disable_if: self.disable_if.as_deref(),
+        }
+    }
+
+    /// Returns whether the week numbers are shown.
+    fn week_number_column(&self) -> WeekNumbers {
+        if self.week_numbers {
+            WeekNumbers::Shown
+        } else {
+            WeekNumbers::Hidden
+        }
+    }
+
+    /// Returns the date of the day under the cursor in the given layout of
+    /// the days showing the month of the given date.
+    fn day_at(&self, layout: Layout<'_>, month: NaiveDate, cursor: Cursor) -> Option<NaiveDate> {
+        // The first row holds the labels of the weekdays.
+        layout
+            .children()
+            .skip(1)
+            .enumerate()
+            .find_map(|(y, row)| {
+                let mut cells = row.children();
+                if self.week_numbers {
+                    let _week_layout = cells.next();
+                }
+
+                cells
+                    .enumerate()
+                    .find(|(_, cell)| cursor.is_over(cell.bounds()))
+                    .map(|(x, _)| (x, y))
+            })
+            .map(|(x, y)| {
+                let (day, is_in_month) = crate::core::date::position_to_day_from(
+                    x,
+                    y,
+                    month.year(),
+                    month.month(),
+                    self.first_day_of_week,
+                );
+
+                cell_date(month, day, &is_in_month)
+            })
+    }
+}
+
+/// The state of a [`Calendar`].
+#[derive(Clone, Copy, Debug)]
+struct State {
+    /// The picked date the [`Calendar`] was last given.
+    date: NaiveDate,
+    /// A date in the shown month.
+    month: NaiveDate,
+}
+
+/// Returns the layouts of the month and year bar and of the days of the
+/// given layout of a [`Calendar`].
+fn parts(layout: Layout<'_>) -> (Layout<'_>, Layout<'_>) {
+    let mut children = layout
+        .children()
+        .next()
+        .expect("Native: Layout should have a calendar layout")
+        .children();
+
+    let month_year = children
+        .next()
+        .expect("Native: Layout should have a month/year layout");
+    let days = children
+        .next()
+        .expect("Native: Layout should have a days table parent")
+        .children()
+        .next()
+        .expect("Native: Layout should have a days table layout");
+
+    (month_year, days)
+}
+
+/// Returns the bounds of the arrows to the previous and the next month or
+/// year of the given layout of the month or the year.
+fn arrows(layout: Layout<'_>) -> (Rectangle, Rectangle) {
+    let mut children = layout.children();
+
+    let previous = children
+        .next()
+        .expect("Native: Layout should have a left arrow layout")
+        .bounds();
+    let _center = children.next();
+    let next = children
+        .next()
+        .expect("Native: Layout should have a right arrow layout")
+        .bounds();
+
+    (previous, next)
+}
+
+impl<Message, Theme> Widget<Message, Renderer<Theme>> for Calendar<'_, Message, Theme>
+where
+    Theme: StyleSheet + button::StyleSheet + text::StyleSheet + container::StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State {
+            date: self.date.into(),
+            month: self.date.into(),
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        // A new picked date shows its month.
+        let state = tree.state.downcast_mut::<State>();
+        let date: NaiveDate = self.date.into();
+
+        if state.date != date {
+            *state = State { date, month: date };
+        }
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer<Theme>, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = limits.resolve(Size::ZERO);
+
+        let mut content = calendar(
+            renderer,
+            &Limits::new(Size::ZERO, size).pad(Padding::from(PADDING)),
+            self.week_number_column(),
+        );
+        content.move_to(Point::new(PADDING, PADDING));
+
+        layout_direction::get().arrange_deep(Node::with_children(size, vec![content]))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer<Theme>,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        if !matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        ) {
+            return event::Status::Ignored;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let (month_year_layout, days_layout) = parts(layout);
+        let constraints = self.constraints();
+
+        // The arrows of the month and of the year bar browse by a month and
+        // by a year.
+        for (bar, months) in month_year_layout.children().zip([1, 12]) {
+            let (previous, next) = arrows(bar);
+
+            let months = if cursor.is_over(previous) {
+                -months
+            } else if cursor.is_over(next) {
+                months
+            } else {
+                continue;
+            };
+
+            state.month = constraints.browse(crate::core::date::add_months(state.month, months));
+            return event::Status::Captured;
+        }
+
+        match self.day_at(days_layout, state.month, cursor) {
+            Some(date) if constraints.allows(date) => {
+                state.month = date;
+                shell.publish((self.on_select)(date.into()));
+                event::Status::Captured
+            }
+            Some(_) => event::Status::Captured,
+            None => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer<Theme>,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let (month_year_layout, days_layout) = parts(layout);
+
+        let over_arrow = month_year_layout.children().any(|bar| {
+            let (previous, next) = arrows(bar);
+            cursor.is_over(previous) || cursor.is_over(next)
+        });
+        let over_day = self
+            .day_at(days_layout, state.month, cursor)
+            .is_some_and(|date| self.constraints().allows(date));
+
+        if over_arrow || over_day {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer<Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let (month_year_layout, days_layout) = parts(layout);
+        let translations = i18n::get();
+        let direction = layout_direction::get();
+
+        let mut style_sheet: HashMap<StyleState, Appearance> = HashMap::new();
+        let _ = style_sheet.insert(StyleState::Active, StyleSheet::active(theme, &self.style));
+        let _ = style_sheet.insert(
+            StyleState::Selected,
+            StyleSheet::selected(theme, &self.style),
+        );
+        let _ = style_sheet.insert(StyleState::Hovered, StyleSheet::hovered(theme, &self.style));
+        let _ = style_sheet.insert(StyleState::Focused, StyleSheet::focused(theme, &self.style));
+        let day_appearances = DayAppearances {
+            in_range: StyleSheet::in_range(theme, &self.style),
+            disabled: StyleSheet::disabled(theme, &self.style),
+        };
+        let weekdays: Vec<String> = (0..7)
+            .map(|offset| {
+                translations.weekday((self.first_day_of_week.num_days_from_monday() + offset) % 7)
+            })
+            .collect();
+
+        let style_state = if cursor.is_over(bounds) {
+            StyleState::Hovered
+        } else {
+            StyleState::Active
+        };
+
+        // Background
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: style_sheet[&style_state].border_radius.into(),
+                border_width: style_sheet[&style_state].border_width,
+                border_color: style_sheet[&style_state].border_color,
+            },
+            style_sheet[&style_state].background,
+        );
+
+        month_year(
+            renderer,
+            month_year_layout,
+            &translations.month(state.month.month()),
+            &crate::core::date::year_as_string(state.month),
+            cursor.position().unwrap_or_default(),
+            &style_sheet,
+            Focus::None,
+            direction,
+        );
+
+        days(
+            renderer,
+            days_layout,
+            state.month,
+            self.first_day_of_week,
+            Selection::Single(self.date.into()),
+            self.constraints(),
+            cursor.position().unwrap_or_default(),
+            &style_sheet,
+            &day_appearances,
+            &weekdays,
+            self.week_number_column(),
+            &translations.week(),
+            Focus::None,
+        );
+    }
+}
+
+impl<'a, Message, Theme> From<Calendar<'a, Message, Theme>>
+    for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet + button::StyleSheet + text::StyleSheet + container::StyleSheet,
+{
+    fn from(calendar: Calendar<'a, Message, Theme>) -> Self {
+        Element::new(calendar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Calendar;
+    use crate::core::date::{Date, Weekday};
+    use crate::test_utils::Harness;
+    use iced_widget::core::{Point, Size};
+
+    #[test]
+    fn calendar_test() {
+        // Wednesday, December 9, 2020, in weeks starting on Monday.
+        let calendar = Calendar::new(Date::from_ymd(2020, 12, 9), Some)
+            .disable_if(|date| date.weekday() == Weekday::Sun);
+        let mut harness = Harness::new(calendar, Size::new(300.0, 300.0));
+
+        // Clicking a day picks it, unless it is disabled.
+        assert_eq!(
+            harness.click(Point::new(100.0, 130.0)),
+            vec![Some(Date::from_ymd(2020, 12, 9))]
+        );
+        assert!(harness.click(Point::new(260.0, 130.0)).is_empty());
+
+        // The arrow to the previous month browses to November, which starts
+        // on a Sunday.
+        assert!(harness.click(Point::new(15.0, 20.0)).is_empty());
+        assert_eq!(
+            harness.click(Point::new(20.0, 95.0)),
+            vec![Some(Date::from_ymd(2020, 10, 26))]
+        );
+    }
+}
@@ -0,0 +1,934 @@
+//! Use a candlestick chart to show the prices of financial data over time.
+//!
+//! *This API requires the following crate features to be activated: `candlestick`*
+use std::ops::Range;
+
+use crate::core::{
+    annotation,
+    axis::nice_ticks,
+    candlestick::{index_at, price_range},
+};
+
+use iced_widget::{
+    canvas::{self, Path, Stroke},
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer,
+        text::{self, Renderer as _},
+        touch,
+        widget::{
+            tree::{State as TreeState, Tag},
+            Tree,
+        },
+        Background, Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle,
+        Renderer as _, Shell, Size, Vector, Widget,
+    },
+    graphics::geometry::Renderer as _,
+    renderer::Renderer,
+    text::LineHeight,
+};
+
+pub use crate::core::{
+    annotation::Annotation,
+    candlestick::{moving_average, Candle},
+};
+pub use crate::style::candlestick::{Appearance, StyleSheet};
+
+/// The padding around the axis labels and the text of the readouts.
+const PADDING: f32 = 4.0;
+/// The minimum distance between two labels of the time axis.
+const MIN_SPACING: f32 = 80.0;
+/// The distance in pixels the cursor has to move while pressed before the
+/// press pans the chart.
+const PAN_THRESHOLD: f32 = 3.0;
+/// The factor the zoom changes by for every line scrolled.
+const ZOOM_STEP: f32 = 1.2;
+/// The number of pixels panned per horizontally scrolled line.
+const SCROLL_STEP: f32 = 40.0;
+/// The smallest number of candles shown when zooming in.
+const MIN_VISIBLE: f32 = 5.0;
+
+/// A candlestick chart showing the open, high, low and close prices of
+/// periods of financial data, with overlay lines like moving averages.
+///
+/// The chart is zoomed along the time axis with the mouse wheel and panned
+/// by dragging or by scrolling horizontally. The value axis adapts to the
+/// shown candles. Hovering the chart shows a crosshair with the price under
+/// the cursor and the prices of the hovered candle.
+///
+/// Guide lines, shaded regions and labeled points can be added above the
+/// candles as [`Annotation`]s, with the times of the candles.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::candlestick::{moving_average, Candle, CandlestickChart};
+/// #
+/// let candles = vec![
+///     Candle::new(0.0, 10.0, 12.0, 9.5, 11.0),
+///     Candle::new(1.0, 11.0, 11.5, 10.0, 10.5),
+/// ];
+/// let average = moving_average(&candles, 20);
+///
+/// let chart = CandlestickChart::new(&candles).overlay("SMA 20", &average);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct CandlestickChart<'a, Theme = iced_widget::style::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The shown candles, ordered by time.
+    candles: &'a [Candle],
+    /// The labels and the values of the overlay lines, with one value per
+    /// candle.
+    overlays: Vec<(String, &'a [Option<f32>])>,
+    /// The annotations drawn above the candles and the overlays.
+    annotations: Vec<Annotation>,
+    /// The number of candles shown before zooming.
+    visible: usize,
+    /// Whether the candles are drawn as OHLC bars.
+    bars: bool,
+    /// The width of the [`CandlestickChart`].
+    width: Length,
+    /// The height of the [`CandlestickChart`].
+    height: Length,
+    /// The text size of the labels.
+    text_size: f32,
+    /// The function formatting the times of the candles.
+    format_time: Box<dyn Fn(f64) -> String + 'a>,
+    /// The function formatting the prices.
+    format_value: Box<dyn Fn(f32) -> String + 'a>,
+    /// The style of the [`CandlestickChart`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Theme> CandlestickChart<'a, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`CandlestickChart`] of the given candles, ordered by
+    /// time.
+    #[must_use]
+    pub fn new(candles: &'a [Candle]) -> Self {
+        Self {
+            candles,
+            overlays: Vec::new(),
+            annotations: Vec::new(),
+            visible: 60,
+            bars: false,
+            width: Length::Fill,
+            height: Length::Fill,
+            text_size: 12.0,
+            format_time: Box::new(|time| format!("{time}")),
+            format_value: Box::new(|value| format!("{value:.2}")),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Adds an overlay line with the given label and one value per candle,
+    /// like the values computed by [`moving_average`].
+    #[must_use]
+    pub fn overlay(mut self, label: impl Into<String>, values: &'a [Option<f32>]) -> Self {
+        self.overlays.push((label.into(), values));
+        self
+    }
+
+    /// Adds an [`Annotation`] drawn above the candles and the overlays of
+    /// the [`CandlestickChart`].
+    ///
+    /// The times between two candles are placed between them.
+    #[must_use]
+    pub fn annotation(mut self, annotation: Annotation) -> Self {
+        self.annotations.push(annotation);
+        self
+    }
+
+    /// Sets the number of the latest candles shown before zooming.
+    #[must_use]
+    pub fn visible(mut self, visible: usize) -> Self {
+        self.visible = visible.max(1);
+        self
+    }
+
+    /// Sets whether the candles are drawn as OHLC bars, with ticks for the
+    /// open and the close price, instead of as candlesticks.
+    #[must_use]
+    pub fn bars(mut self, bars: bool) -> Self {
+        self.bars = bars;
+        self
+    }
+
+    /// Sets the width of the [`CandlestickChart`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`CandlestickChart`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the text size of the labels of the [`CandlestickChart`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the function formatting the times of the candles.
+    #[must_use]
+    pub fn format_time<F>(mut self, format_time: F) -> Self
+    where
+        F: 'a + Fn(f64) -> String,
+    {
+        self.format_time = Box::new(format_time);
+        self
+    }
+
+    /// Sets the function formatting the prices on the value axis and in the
+    /// readouts.
+    #[must_use]
+    pub fn format_value<F>(mut self, format_value: F) -> Self
+    where
+        F: 'a + Fn(f32) -> String,
+    {
+        self.format_value = Box::new(format_value);
+        self
+    }
+
+    /// Sets the style of the [`CandlestickChart`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the shown range of candles of the [`State`].
+    fn view(&self, state: &State) -> View {
+        #[allow(clippy::cast_precision_loss)]
+        let (len, visible) = (self.candles.len() as f32, self.visible as f32);
+
+        self.clamp(state.view.unwrap_or(View {
+            first: len - visible,
+            count: visible,
+        }))
+    }
+
+    /// Limits the view to the candles.
+    fn clamp(&self, view: View) -> View {
+        #[allow(clippy::cast_precision_loss)]
+        let len = (self.candles.len() as f32).max(1.0);
+        let count = view.count.clamp(MIN_VISIBLE.min(len), len);
+
+        View {
+            first: view.first.clamp(0.0, len - count),
+            count,
+        }
+    }
+
+    /// Returns the indices of the candles at least partly in the view.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn visible_range(&self, view: View) -> Range<usize> {
+        let start = (view.first.floor() as usize).min(self.candles.len());
+        let end = ((view.first + view.count).ceil() as usize).min(self.candles.len());
+
+        start..end
+    }
+
+    /// Lays out the plot of the candles and the value axis in the given
+    /// bounds.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    fn plot(&self, renderer: &Renderer<Theme>, bounds: Rectangle, view: View) -> Plot {
+        let line_height = self.text_size * 1.3;
+        let top = line_height / 2.0;
+        let height = (bounds.height - top - line_height - PADDING * 2.0).max(1.0);
+
+        let visible = self.visible_range(view);
+        let overlays = self.overlays.iter().flat_map(|(_, values)| {
+            values
+                .get(visible.clone())
+                .unwrap_or_default()
+                .iter()
+                .flatten()
+        });
+        let (low, high) = overlays.fold(
+            price_range(&self.candles[visible.clone()]).unwrap_or((0.0, 1.0)),
+            |(low, high), value| (low.min(*value), high.max(*value)),
+        );
+
+        let count = (height / (self.text_size * 3.0)).max(2.0) as usize;
+        let ticks = nice_ticks(low, high, count);
+        let (low, high) = match (ticks.first(), ticks.last()) {
+            (Some(&low), Some(&high)) if high > low => (low, high),
+            _ => (0.0, 1.0),
+        };
+
+        let labels: Vec<String> = ticks
+            .iter()
+            .map(|tick| (self.format_value)(*tick))
+            .collect();
+        let axis_width = labels
+            .iter()
+            .map(|label| {
+                renderer.measure_width(
+                    label,
+                    self.text_size,
+                    core::Font::default(),
+                    text::Shaping::Advanced,
+                )
+            })
+            .fold(0.0, f32::max)
+            + PADDING * 2.0;
+
+        Plot {
+            bounds: Rectangle {
+                x: bounds.x,
+                y: bounds.y + top,
+                width: (bounds.width - axis_width).max(1.0),
+                height,
+            },
+            low,
+            high,
+            ticks,
+            labels,
+            axis_width,
+        }
+    }
+}
+
+/// The range of candles shown by a [`CandlestickChart`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct View {
+    /// The index of the first shown candle, which may be cut off.
+    first: f32,
+    /// The number of shown candles.
+    count: f32,
+}
+
+/// The state of a [`CandlestickChart`].
+#[derive(Debug, Default)]
+struct State {
+    /// The shown range of candles, or `None` to show the latest ones.
+    view: Option<View>,
+    /// The position the ongoing press started at, with the view at that time.
+    press: Option<(Point, View)>,
+    /// Whether the ongoing press moved far enough to pan the chart.
+    panning: bool,
+}
+
+/// The plot of a [`CandlestickChart`] laid out for a view.
+#[derive(Debug)]
+struct Plot {
+    /// The bounds of the plot, left of the value axis.
+    bounds: Rectangle,
+    /// The price at the bottom of the plot.
+    low: f32,
+    /// The price at the top of the plot.
+    high: f32,
+    /// The prices labeled on the value axis.
+    ticks: Vec<f32>,
+    /// The labels of the value axis.
+    labels: Vec<String>,
+    /// The width of the value axis.
+    axis_width: f32,
+}
+
+impl Plot {
+    /// Returns the vertical position of the given price.
+    fn y(&self, value: f32) -> f32 {
+        self.bounds.y + self.bounds.height
+            - (value - self.low) / (self.high - self.low) * self.bounds.height
+    }
+
+    /// Returns the price at the given vertical position.
+    fn value_at(&self, y: f32) -> f32 {
+        self.low
+            + (self.bounds.y + self.bounds.height - y) / self.bounds.height * (self.high - self.low)
+    }
+}
+
+impl<Message, Theme> Widget<Message, Renderer<Theme>> for CandlestickChart<'_, Theme>
+where
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer<Theme>, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        Node::new(limits.resolve(Size::new(f32::INFINITY, f32::INFINITY)))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer<Theme>,
+        _clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let view = self.view(state);
+        let plot = self.plot(renderer, bounds, view).bounds;
+        let candle_width = plot.width / view.count;
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let Some(position) = cursor.position_over(plot) else {
+                    return event::Status::Ignored;
+                };
+
+                let (x, y) = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => (x, y),
+                    mouse::ScrollDelta::Pixels { x, y } => (x / SCROLL_STEP, y / SCROLL_STEP),
+                };
+
+                // Zoom around the candle under the cursor, and pan by the
+                // horizontal scrolling.
+                let ratio = (position.x - plot.x) / plot.width;
+                let anchor = view.first + ratio * view.count;
+                let count = view.count / ZOOM_STEP.powf(y);
+                let first = anchor - ratio * count - x * SCROLL_STEP / candle_width;
+
+                state.view = Some(self.clamp(View { first, count }));
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position_over(plot) {
+                    state.press = Some((position, view));
+                    state.panning = false;
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some((origin, pressed)) = state.press {
+                    let delta = position.x - origin.x;
+                    if state.panning || delta.abs() > PAN_THRESHOLD {
+                        state.panning = true;
+                        state.view = Some(self.clamp(View {
+                            first: pressed.first - delta / candle_width,
+                            ..pressed
+                        }));
+                    }
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if state.press.is_some() =>
+            {
+                state.press = None;
+                state.panning = false;
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer<Theme>,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.panning {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Crosshair
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer<Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let line_height = self.text_size * 1.3;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        let view = self.view(state);
+        let plot = self.plot(renderer, bounds, view);
+        let area = plot.bounds;
+        let visible = self.visible_range(view);
+        let candle_width = area.width / view.count;
+        #[allow(clippy::cast_precision_loss)]
+        let center = |index: usize| area.x + (index as f32 - view.first + 0.5) * candle_width;
+
+        // The labels of the time axis are a round number of candles apart.
+        let spacing = MIN_SPACING / candle_width;
+        let step = (0..9)
+            .flat_map(|exponent| [1, 2, 5].map(|factor| factor * 10_usize.pow(exponent)))
+            .find(|&step| {
+                #[allow(clippy::cast_precision_loss)]
+                let step = step as f32;
+                step >= spacing
+            })
+            .unwrap_or(usize::MAX);
+        let labeled: Vec<usize> = visible.clone().filter(|index| index % step == 0).collect();
+
+        for tick in &plot.ticks {
+            let rule = Rectangle::new(
+                Point::new(area.x, plot.y(*tick).floor()),
+                Size::new(area.width, 1.0),
+            );
+            fill_rule(renderer, rule, appearance.grid_color);
+        }
+        renderer.with_layer(area, |renderer| {
+            for index in &labeled {
+                let rule = Rectangle::new(
+                    Point::new(center(*index).floor(), area.y),
+                    Size::new(1.0, area.height),
+                );
+                fill_rule(renderer, rule, appearance.grid_color);
+            }
+        });
+        fill_rule(
+            renderer,
+            Rectangle::new(
+                Point::new((area.x + area.width).floor(), area.y),
+                Size::new(1.0, area.height),
+            ),
+            appearance.axis_color,
+        );
+        fill_rule(
+            renderer,
+            Rectangle::new(
+                Point::new(area.x, (area.y + area.height).floor()),
+                Size::new(area.width, 1.0),
+            ),
+            appearance.axis_color,
+        );
+
+        let hovered = cursor
+            .position_over(area)
+            .filter(|_| !state.panning)
+            .and_then(|position| {
+                let index = view.first + (position.x - area.x) / candle_width;
+
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let index = index.max(0.0) as usize;
+
+                (index < self.candles.len()).then_some((position, index))
+            });
+
+        renderer.with_layer(area, |renderer| {
+            if let Some((position, index)) = hovered {
+                fill_rule(
+                    renderer,
+                    Rectangle::new(
+                        Point::new(center(index).floor(), area.y),
+                        Size::new(1.0, area.height),
+                    ),
+                    appearance.crosshair_color,
+                );
+                fill_rule(
+                    renderer,
+                    Rectangle::new(
+                        Point::new(area.x, position.y.floor()),
+                        Size::new(area.width, 1.0),
+                    ),
+                    appearance.crosshair_color,
+                );
+            }
+
+            let body_width = (candle_width * 0.7).max(1.0);
+
+            for index in visible.clone() {
+                let candle = &self.candles[index];
+                let color = if candle.is_bullish() {
+                    appearance.bullish_color
+                } else {
+                    appearance.bearish_color
+                };
+                let x = center(index);
+                let (top, bottom) = (
+                    plot.y(candle.open.max(candle.close)),
+                    plot.y(candle.open.min(candle.close)),
+                );
+
+                fill_rule(
+                    renderer,
+                    Rectangle::new(
+                        Point::new(x.floor(), plot.y(candle.high)),
+                        Size::new(1.0, plot.y(candle.low) - plot.y(candle.high)),
+                    ),
+                    color,
+                );
+
+                if self.bars {
+                    // The open is ticked on the left and the close on the
+                    // right of the bar.
+                    let tick = (body_width / 2.0).max(1.0);
+                    fill_rule(
+                        renderer,
+                        Rectangle::new(
+                            Point::new(x.floor() - tick, plot.y(candle.open).floor()),
+                            Size::new(tick, 1.0),
+                        ),
+                        color,
+                    );
+                    fill_rule(
+                        renderer,
+                        Rectangle::new(
+                            Point::new(x.floor() + 1.0, plot.y(candle.close).floor()),
+                            Size::new(tick, 1.0),
+                        ),
+                        color,
+                    );
+                } else {
+                    fill_rule(
+                        renderer,
+                        Rectangle::new(
+                            Point::new(x - body_width / 2.0, top),
+                            Size::new(body_width, (bottom - top).max(1.0)),
+                        ),
+                        color,
+                    );
+                }
+            }
+        });
+
+        // The overlay lines continue to the candles right outside of the
+        // view, and break where they have no values.
+        let mut lines = canvas::Frame::new(renderer, bounds.size());
+        let around = visible.start.saturating_sub(1)..(visible.end + 1).min(self.candles.len());
+
+        for (i, (_, values)) in self.overlays.iter().enumerate() {
+            let path = Path::new(|builder| {
+                let mut drawing = false;
+
+                for index in around.clone() {
+                    let Some(Some(value)) = values.get(index) else {
+                        drawing = false;
+                        continue;
+                    };
+                    let point =
+                        Point::new(center(index), plot.y(*value)) - Vector::new(bounds.x, bounds.y);
+
+                    if drawing {
+                        builder.line_to(point);
+                    } else {
+                        builder.move_to(point);
+                    }
+                    drawing = true;
+                }
+            });
+
+            lines.stroke(
+                &path,
+                Stroke::default()
+                    .with_color(appearance.overlay_palette[i % appearance.overlay_palette.len()])
+                    .with_width(appearance.overlay_width),
+            );
+        }
+
+        renderer.with_layer(area, |renderer| {
+            renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+                renderer.draw(vec![lines.into_geometry()]);
+            });
+        });
+
+        // Without candles, the times are left of the plot.
+        let time_x = |time: f64| {
+            index_at(self.candles, time).map_or(f32::NEG_INFINITY, |index| {
+                area.x + (index - view.first + 0.5) * candle_width
+            })
+        };
+        let marks: Vec<Option<Rectangle>> = self
+            .annotations
+            .iter()
+            .map(|annotation| annotation.layout(area, time_x, |value| plot.y(value)))
+            .collect();
+        annotation::draw(
+            renderer,
+            &self.annotations,
+            &marks,
+            cursor
+                .position_over(area)
+                .filter(|_| !state.panning)
+                .and_then(|position| annotation::hovered(&self.annotations, &marks, position)),
+            area,
+            annotation::Style {
+                color: appearance.annotation_color,
+                region_color: appearance.region_color,
+                label_background: appearance.readout_background,
+                label_text_color: appearance.readout_text_color,
+                text_size: self.text_size,
+            },
+        );
+
+        let axis_x = area.x + area.width + PADDING;
+
+        renderer.with_layer(bounds, |renderer| {
+            for (tick, label) in plot.ticks.iter().zip(&plot.labels) {
+                fill_label(
+                    renderer,
+                    label,
+                    Rectangle::new(
+                        Point::new(axis_x, plot.y(*tick)),
+                        Size::new(plot.axis_width, line_height),
+                    ),
+                    self.text_size,
+                    appearance.text_color,
+                    Horizontal::Left,
+                );
+            }
+
+            for index in &labeled {
+                let label = (self.format_time)(self.candles[*index].time);
+                let width = renderer.measure_width(
+                    &label,
+                    self.text_size,
+                    core::Font::default(),
+                    text::Shaping::Advanced,
+                );
+                // Labels scrolling out of the plot are hidden as a whole.
+                let x = center(*index);
+                if x - width / 2.0 < area.x || x + width / 2.0 > area.x + area.width {
+                    continue;
+                }
+
+                fill_label(
+                    renderer,
+                    &label,
+                    Rectangle::new(
+                        Point::new(x, area.y + area.height + PADDING + line_height / 2.0),
+                        Size::new(width, line_height),
+                    ),
+                    self.text_size,
+                    appearance.text_color,
+                    Horizontal::Center,
+                );
+            }
+        });
+
+        let Some((position, index)) = hovered else {
+            return;
+        };
+        let candle = &self.candles[index];
+
+        // The crosshair reads out the price under the cursor on the value
+        // axis, the time of the candle on the time axis and the prices of
+        // the candle at the top of the plot.
+        let price = (self.format_value)(plot.value_at(position.y));
+        let time = (self.format_time)(candle.time);
+        let prices = format!(
+            "{time}  O {}  H {}  L {}  C {}",
+            (self.format_value)(candle.open),
+            (self.format_value)(candle.high),
+            (self.format_value)(candle.low),
+            (self.format_value)(candle.close),
+        );
+        let summary = std::iter::once(prices)
+            .chain(self.overlays.iter().filter_map(|(label, values)| {
+                let value = values.get(index).copied().flatten()?;
+                Some(format!("{label} {}", (self.format_value)(value)))
+            }))
+            .collect::<Vec<_>>()
+            .join("  ");
+
+        let width = |content: &str| {
+            renderer.measure_width(
+                content,
+                self.text_size,
+                core::Font::default(),
+                text::Shaping::Advanced,
+            ) + PADDING * 2.0
+        };
+        let readouts = [
+            Rectangle::new(
+                Point::new(area.x + area.width, position.y - line_height / 2.0),
+                Size::new(plot.axis_width.max(width(&price)), line_height),
+            ),
+            Rectangle::new(
+                Point::new(
+                    center(index) - width(&time) / 2.0,
+                    area.y + area.height + PADDING,
+                ),
+                Size::new(width(&time), line_height),
+            ),
+            Rectangle::new(area.position(), Size::new(width(&summary), line_height)),
+        ];
+
+        for (content, readout) in [price, time, summary].iter().zip(readouts) {
+            renderer.with_layer(readout, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: readout,
+                        border_radius: (2.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.readout_background,
+                );
+
+                fill_label(
+                    renderer,
+                    content,
+                    Rectangle::new(
+                        Point::new(readout.x + PADDING, readout.center_y()),
+                        readout.size(),
+                    ),
+                    self.text_size,
+                    appearance.readout_text_color,
+                    Horizontal::Left,
+                );
+            });
+        }
+    }
+}
+
+impl<'a, Message, Theme> From<CandlestickChart<'a, Theme>> for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+{
+    fn from(candlestick_chart: CandlestickChart<'a, Theme>) -> Self {
+        Element::new(candlestick_chart)
+    }
+}
+
+/// Fills the given rectangle of a straight line or a candle of the chart.
+fn fill_rule<Theme>(
+    renderer: &mut Renderer<Theme>,
+    bounds: Rectangle,
+    color: impl Into<Background>,
+) {
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border_radius: (0.0).into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        },
+        color,
+    );
+}
+
+/// Fills the given label, vertically centered at the top of the given
+/// bounds.
+fn fill_label<Theme>(
+    renderer: &mut Renderer<Theme>,
+    content: &str,
+    bounds: Rectangle,
+    size: f32,
+    color: Color,
+    horizontal_alignment: Horizontal,
+) {
+    renderer.fill_text(core::text::Text {
+        content,
+        bounds,
+        size,
+        line_height: LineHeight::default(),
+        color,
+        font: core::Font::default(),
+        horizontal_alignment,
+        vertical_alignment: Vertical::Center,
+        shaping: text::Shaping::Advanced,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{moving_average, Candle, CandlestickChart};
+    use crate::test_utils::Harness;
+    use iced_widget::core::{Point, Size, Vector};
+
+    #[test]
+    fn candlestick_test() {
+        let candles: Vec<Candle> = (0..100_u8)
+            .map(|i| {
+                let open = 100.0 + f32::from(i % 10);
+                Candle::new(f64::from(i), open, open + 3.0, open - 3.0, open + 1.0)
+            })
+            .collect();
+        let average = moving_average(&candles, 5);
+
+        let chart = CandlestickChart::new(&candles)
+            .overlay("SMA 5", &average)
+            .visible(20);
+        let mut harness = Harness::<()>::new(chart, Size::new(400.0, 200.0));
+        let latest = harness.snapshot();
+
+        // Dragging pans to older candles, and dragging back restores the
+        // view.
+        assert!(harness
+            .drag(Point::new(100.0, 100.0), Point::new(200.0, 100.0), 4)
+            .is_empty());
+        let panned = harness.snapshot();
+        assert!(latest.diff(&panned, 8).is_some_and(|count| count > 100));
+
+        let _ = harness.drag(Point::new(200.0, 100.0), Point::new(100.0, 100.0), 4);
+        let _ = harness.move_cursor(Point::new(0.0, 0.0));
+        assert_eq!(latest.diff(&harness.snapshot(), 8), Some(0));
+
+        // Scrolling zooms in around the cursor, and out again.
+        let _ = harness.move_cursor(Point::new(150.0, 100.0));
+        let hovered = harness.snapshot();
+        let _ = harness.scroll(Vector::new(0.0, 2.0));
+        assert!(hovered
+            .diff(&harness.snapshot(), 8)
+            .is_some_and(|count| count > 100));
+        let _ = harness.scroll(Vector::new(0.0, -2.0));
+        assert!(hovered
+            .diff(&harness.snapshot(), 8)
+            .is_some_and(|count| count < 50));
+    }
+}
@@ -2,7 +2,10 @@
 //!
 //! *This API requires the following crate features to be activated: card*
 
-use crate::graphics::icons::{Icon, ICON_FONT};
+use crate::{
+    core::pixel,
+    graphics::icons::{Icon, ICON_FONT},
+};
 
 use iced_widget::{
     core::{
@@ -76,6 +79,8 @@ where
     foot: Option<Element<'a, Message, Renderer>>,
     /// The style of the [`Card`].
     style: <Renderer::Theme as StyleSheet>::Style,
+    /// The scale factor the border of the [`Card`] is snapped to, if any.
+    scale_factor: Option<f32>,
 }
 
 impl<'a, Message, Renderer> Card<'a, Message, Renderer>
@@ -107,6 +112,7 @@ where
             body: body.into(),
             foot: None,
             style: <Renderer::Theme as StyleSheet>::Style::default(),
+            scale_factor: None,
         }
     }
 
@@ -191,6 +197,16 @@ where
         self
     }
 
+    /// Sets the scale factor the [`Card`] is rendered at, to snap its border
+    /// to physical pixels.
+    ///
+    /// Nothing is snapped unless the scale factor is set.
+    #[must_use]
+    pub fn scale_factor(mut self, scale_factor: f32) -> Self {
+        self.scale_factor = Some(scale_factor);
+        self
+    }
+
     /// Sets the style of the [`Card`].
     #[must_use]
     pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
@@ -487,16 +503,17 @@ where
         cursor: Cursor,
         viewport: &Rectangle,
     ) {
-        let bounds = layout.bounds();
+        let bounds = pixel::snap_rectangle_to(layout.bounds(), self.scale_factor);
         let mut children = layout.children();
         let style_sheet = theme.active(&self.style);
+        let border_width = pixel::snap_width_to(style_sheet.border_width, self.scale_factor);
 
         // Background
         renderer.fill_quad(
             renderer::Quad {
                 bounds,
                 border_radius: style_sheet.border_radius.into(),
-                border_width: style_sheet.border_width,
+                border_width,
                 border_color: style_sheet.border_color,
             },
             style_sheet.background,
@@ -508,7 +525,7 @@ where
             renderer::Quad {
                 bounds,
                 border_radius: style_sheet.border_radius.into(),
-                border_width: style_sheet.border_width,
+                border_width,
                 border_color: style_sheet.border_color,
             },
             Color::TRANSPARENT,
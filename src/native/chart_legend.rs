@@ -0,0 +1,466 @@
+//! Use a chart legend to list the series of a chart and toggle them.
+//!
+//! *This API requires the following crate features to be activated: `chart_legend`*
+use crate::style::chart_legend::StyleSheet;
+
+use iced_widget::{
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer, text, touch,
+        widget::{
+            tree::{State as TreeState, Tag},
+            Tree,
+        },
+        Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+    },
+    text::LineHeight,
+};
+
+/// The padding around the swatch and the label of an entry.
+const PADDING: f32 = 4.0;
+/// The gap between the swatch and the label of an entry.
+const GAP: f32 = 6.0;
+
+/// The direction the entries of a [`ChartLegend`] are arranged in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Orientation {
+    /// The entries are arranged in rows, wrapping at the width of the
+    /// [`ChartLegend`].
+    #[default]
+    Horizontal,
+    /// The entries are arranged in a column.
+    Vertical,
+}
+
+/// A legend listing the series of a chart with a color swatch and a label
+/// each.
+///
+/// Clicking an entry produces a message to show or hide its series, and
+/// hovering one produces a message to highlight it, so the chart can follow
+/// along. The swatches of the hidden series are drawn as outlines and their
+/// labels are dimmed.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::ChartLegend;
+/// # use iced::Color;
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Toggle(usize, bool),
+///     Highlight(Option<usize>),
+/// }
+///
+/// let legend = ChartLegend::new()
+///     .entry("CPU", Color::from_rgb(0.31, 0.47, 0.65), true)
+///     .entry("Memory", Color::from_rgb(0.95, 0.56, 0.17), false)
+///     .on_toggle(Message::Toggle)
+///     .on_highlight(Message::Highlight);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ChartLegend<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: core::text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The labels, colors and visibilities of the series.
+    entries: Vec<(String, Color, bool)>,
+    /// The function producing the message when an entry is clicked.
+    on_toggle: Option<Box<dyn Fn(usize, bool) -> Message + 'a>>,
+    /// The function producing the message when the hovered entry changes.
+    on_highlight: Option<Box<dyn Fn(Option<usize>) -> Message + 'a>>,
+    /// The direction the entries are arranged in.
+    orientation: Orientation,
+    /// The spacing between the entries.
+    spacing: f32,
+    /// The text size of the labels.
+    text_size: Option<f32>,
+    /// The width of the [`ChartLegend`].
+    width: Length,
+    /// The style of the [`ChartLegend`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> ChartLegend<'a, Message, Renderer>
+where
+    Renderer: core::text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new empty [`ChartLegend`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            on_toggle: None,
+            on_highlight: None,
+            orientation: Orientation::default(),
+            spacing: 8.0,
+            text_size: None,
+            width: Length::Shrink,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Adds an entry for a series with the given label and color to the
+    /// [`ChartLegend`], which is shown or hidden in the chart.
+    #[must_use]
+    pub fn entry(mut self, label: impl Into<String>, color: Color, visible: bool) -> Self {
+        self.entries.push((label.into(), color, visible));
+        self
+    }
+
+    /// Sets the function producing the message with the index of a clicked
+    /// entry and whether its series is shown after the click.
+    #[must_use]
+    pub fn on_toggle<F>(mut self, on_toggle: F) -> Self
+    where
+        F: 'a + Fn(usize, bool) -> Message,
+    {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Sets the function producing the message with the index of the hovered
+    /// entry when the cursor enters it, or [`None`] when it leaves the
+    /// entries.
+    #[must_use]
+    pub fn on_highlight<F>(mut self, on_highlight: F) -> Self
+    where
+        F: 'a + Fn(Option<usize>) -> Message,
+    {
+        self.on_highlight = Some(Box::new(on_highlight));
+        self
+    }
+
+    /// Sets the direction the entries of the [`ChartLegend`] are arranged in.
+    #[must_use]
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets the spacing between the entries of the [`ChartLegend`].
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the labels of the [`ChartLegend`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the width of the [`ChartLegend`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the style of the [`ChartLegend`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the text size of the labels.
+    fn text_size_of(&self, renderer: &Renderer) -> f32 {
+        self.text_size.unwrap_or_else(|| renderer.default_size())
+    }
+}
+
+impl<Message, Renderer> Default for ChartLegend<'_, Message, Renderer>
+where
+    Renderer: core::text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The state of a [`ChartLegend`].
+#[derive(Clone, Copy, Debug, Default)]
+struct State {
+    /// The index of the hovered entry.
+    hovered: Option<usize>,
+}
+
+/// Returns the index of the entry under the cursor.
+fn entry_at(layout: Layout<'_>, cursor: Cursor) -> Option<usize> {
+    layout
+        .children()
+        .position(|entry| cursor.is_over(entry.bounds()))
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for ChartLegend<'_, Message, Renderer>
+where
+    Renderer: core::text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let max_width = limits.max().width;
+
+        let text_size = self.text_size_of(renderer);
+        let height = text_size * 1.3 + PADDING * 2.0;
+
+        let mut position = Point::ORIGIN;
+        let mut content = Size::ZERO;
+        let children = self
+            .entries
+            .iter()
+            .map(|(label, _, _)| {
+                let label_width = renderer.measure_width(
+                    label,
+                    text_size,
+                    renderer.default_font(),
+                    text::Shaping::Advanced,
+                );
+                let width = PADDING * 2.0 + text_size + GAP + label_width;
+
+                // The rows wrap before an entry crossing the width.
+                if self.orientation == Orientation::Horizontal
+                    && position.x > 0.0
+                    && position.x + width > max_width
+                {
+                    position = Point::new(0.0, position.y + height + self.spacing);
+                }
+
+                let mut node = Node::new(Size::new(width, height));
+                node.move_to(position);
+                content = Size::new(content.width.max(position.x + width), position.y + height);
+
+                match self.orientation {
+                    Orientation::Horizontal => position.x += width + self.spacing,
+                    Orientation::Vertical => position.y += height + self.spacing,
+                }
+
+                node
+            })
+            .collect();
+
+        Node::with_children(limits.resolve(content), children)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft)
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let hovered = match event {
+                    Event::Mouse(mouse::Event::CursorLeft) => None,
+                    _ => entry_at(layout, cursor),
+                };
+
+                if hovered != state.hovered {
+                    state.hovered = hovered;
+                    if let Some(on_highlight) = &self.on_highlight {
+                        shell.publish(on_highlight(hovered));
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let (Some(on_toggle), Some(index)) = (&self.on_toggle, entry_at(layout, cursor))
+                {
+                    shell.publish(on_toggle(index, !self.entries[index].2));
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.on_toggle.is_some() && entry_at(layout, cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let text_size = self.text_size_of(renderer);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        for ((label, color, visible), entry) in self.entries.iter().zip(layout.children()) {
+            let entry_bounds = entry.bounds();
+
+            if cursor.is_over(entry_bounds) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: entry_bounds,
+                        border_radius: appearance.border_radius.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.hovered_background,
+                );
+            }
+
+            // The swatches of the hidden series are only outlined.
+            let swatch = Rectangle {
+                x: entry_bounds.x + PADDING,
+                y: entry_bounds.center_y() - text_size / 2.0,
+                width: text_size,
+                height: text_size,
+            };
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: swatch,
+                    border_radius: appearance.border_radius.into(),
+                    border_width: if *visible {
+                        0.0
+                    } else {
+                        appearance.swatch_border_width
+                    },
+                    border_color: *color,
+                },
+                if *visible { *color } else { Color::TRANSPARENT },
+            );
+
+            renderer.fill_text(text::Text {
+                content: label,
+                bounds: Rectangle {
+                    x: swatch.x + swatch.width + GAP,
+                    y: entry_bounds.center_y(),
+                    width: f32::INFINITY,
+                    ..entry_bounds
+                },
+                size: text_size,
+                line_height: LineHeight::default(),
+                color: if *visible {
+                    appearance.text_color
+                } else {
+                    appearance.hidden_text_color
+                },
+                font: renderer.default_font(),
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Advanced,
+            });
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<ChartLegend<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + core::text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(chart_legend: ChartLegend<'a, Message, Renderer>) -> Self {
+        Element::new(chart_legend)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChartLegend, Orientation};
+    use crate::test_utils::Harness;
+    use iced_widget::core::{Color, Point, Size};
+
+    #[test]
+    fn chart_legend_test() {
+        #[derive(Clone, Debug, PartialEq)]
+        enum Change {
+            Toggle(usize, bool),
+            Highlight(Option<usize>),
+        }
+
+        let legend = ChartLegend::new()
+            .entry("Alpha", Color::from_rgb(0.8, 0.2, 0.2), true)
+            .entry("Beta", Color::from_rgb(0.2, 0.2, 0.8), false)
+            .orientation(Orientation::Vertical)
+            .on_toggle(Change::Toggle)
+            .on_highlight(Change::Highlight);
+        let mut harness = Harness::new(legend, Size::new(200.0, 100.0));
+
+        // Hovering an entry highlights it until the cursor leaves it.
+        assert_eq!(
+            harness.move_cursor(Point::new(10.0, 50.0)),
+            vec![Change::Highlight(Some(1))]
+        );
+        assert!(harness.move_cursor(Point::new(20.0, 50.0)).is_empty());
+        assert_eq!(
+            harness.move_cursor(Point::new(150.0, 90.0)),
+            vec![Change::Highlight(None)]
+        );
+
+        // Clicking an entry toggles its series.
+        assert_eq!(
+            harness.click(Point::new(10.0, 14.0)),
+            vec![Change::Highlight(Some(0)), Change::Toggle(0, false)]
+        );
+        assert_eq!(
+            harness.click(Point::new(10.0, 50.0)),
+            vec![Change::Highlight(Some(1)), Change::Toggle(1, true)]
+        );
+    }
+}
@@ -5,6 +5,7 @@
 use super::overlay::color_picker::{
     self, ColorBarDragged, ColorPickerOverlay, ColorPickerOverlayButtons,
 };
+use crate::core::lens::{Lens, Update};
 
 use iced_widget::{
     button,
@@ -20,9 +21,13 @@ use iced_widget::{
         Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
     },
     renderer::Renderer,
+    text_input,
 };
 
-pub use crate::style::color_picker::{Appearance, StyleSheet};
+pub use crate::{
+    core::color::ColorSpace,
+    style::color_picker::{Appearance, StyleSheet},
+};
 
 //TODO: Remove ignore when Null is updated. Temp fix for Test runs
 /// An input element for picking colors.
@@ -68,12 +73,18 @@ where
     style: <Theme as StyleSheet>::Style,
     /// The buttons of the overlay.
     overlay_state: Element<'a, Message, Renderer<Theme>>,
+    /// Toggle the alpha bar of the [`ColorPickerOverlay`].
+    show_alpha: bool,
+    /// The preset colors of the [`ColorPickerOverlay`].
+    swatches: Vec<Color>,
+    /// The color space of the bars of the [`ColorPickerOverlay`].
+    color_space: ColorSpace,
 }
 
 impl<'a, Message, Theme> ColorPicker<'a, Message, Theme>
 where
     Message: 'a + Clone,
-    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet,
+    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
 {
     /// Creates a new [`ColorPicker`] wrapping around the given underlay.
     ///
@@ -105,9 +116,91 @@ where
             on_submit: Box::new(on_submit),
             style: <Theme as StyleSheet>::Style::default(),
             overlay_state: ColorPickerOverlayButtons::default().into(),
+            show_alpha: true,
+            swatches: Vec::new(),
+            color_space: ColorSpace::default(),
         }
     }
 
+    /// Creates a new [`ColorPicker`] bound to the color of the model through a
+    /// [`Lens`], wrapping around the given underlay.
+    ///
+    /// Instead of the picked color, the [`ColorPicker`] submits an [`Update`]
+    /// setting it in the model, so the fields of a form can share a single
+    /// message.
+    ///
+    /// # Example
+    /// ```ignore
+    /// # use iced::Color;
+    /// # use iced_aw::{lens::{lens, Update}, ColorPicker};
+    /// #
+    /// struct Settings {
+    ///     accent: Color,
+    /// }
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum Message {
+    ///     Cancel,
+    ///     Edit(Update<Settings>),
+    /// }
+    ///
+    /// let settings = Settings { accent: Color::BLACK };
+    /// let accent = lens(|settings: &Settings| settings.accent, |settings, accent| settings.accent = accent);
+    ///
+    /// let picker = ColorPicker::bind(true, &settings, accent, "Underlay", Message::Cancel, Message::Edit);
+    /// ```
+    pub fn bind<U, Model, L, F>(
+        show_picker: bool,
+        model: &Model,
+        lens: L,
+        underlay: U,
+        on_cancel: Message,
+        on_update: F,
+    ) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer<Theme>>>,
+        Model: 'static,
+        L: 'static + Lens<Model, Value = Color> + Copy + Send + Sync,
+        F: 'static + Fn(Update<Model>) -> Message,
+    {
+        Self::new(
+            show_picker,
+            lens.get(model),
+            underlay,
+            on_cancel,
+            move |color| on_update(Update::set(lens, color)),
+        )
+    }
+
+    /// Sets whether the [`ColorPicker`] shows a bar for picking the alpha
+    /// channel of the color, shown by default. Without it, only opaque colors
+    /// are picked.
+    #[must_use]
+    pub fn show_alpha(mut self, show_alpha: bool) -> Self {
+        self.show_alpha = show_alpha;
+        self
+    }
+
+    /// Sets the preset colors the [`ColorPicker`] shows as swatches picked
+    /// with a click, like the brand colors of an application.
+    ///
+    /// The recently submitted colors are shown as swatches below them.
+    #[must_use]
+    pub fn swatches(mut self, swatches: impl IntoIterator<Item = Color>) -> Self {
+        self.swatches = swatches.into_iter().collect();
+        self
+    }
+
+    /// Sets the [`ColorSpace`] the bars of the [`ColorPicker`] pick the color
+    /// in, RGB by default.
+    ///
+    /// The switcher above the bars changes it until the set one changes.
+    #[must_use]
+    pub fn color_space(mut self, color_space: ColorSpace) -> Self {
+        self.color_space = color_space;
+        self
+    }
+
     /// Sets the style of the [`ColorPicker`].
     #[must_use]
     pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
@@ -121,6 +214,8 @@ where
 pub struct State {
     /// The state of the overlay.
     pub(crate) overlay_state: color_picker::State,
+    /// The color space last set by the [`ColorPicker`].
+    color_space: ColorSpace,
 }
 
 impl State {
@@ -129,9 +224,16 @@ impl State {
     pub fn new(color: Color) -> Self {
         Self {
             overlay_state: color_picker::State::new(color),
+            color_space: ColorSpace::default(),
         }
     }
 
+    /// Sets the color space of the bars.
+    fn set_color_space(&mut self, color_space: ColorSpace) {
+        self.color_space = color_space;
+        self.overlay_state.switch(color_space);
+    }
+
     /// Resets the color of the state.
     pub fn reset(&mut self) {
         self.overlay_state.color = Color::from_rgb(0.5, 0.25, 0.25);
@@ -142,14 +244,17 @@ impl State {
 impl<'a, Message, Theme> Widget<Message, Renderer<Theme>> for ColorPicker<'a, Message, Theme>
 where
     Message: 'static + Clone,
-    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet,
+    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
 {
     fn tag(&self) -> Tag {
         Tag::of::<State>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::new(self.color))
+        let mut state = State::new(self.color);
+        state.set_color_space(self.color_space);
+
+        tree::State::new(state)
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -163,6 +268,16 @@ where
             color_picker_state.overlay_state.color = self.color;
         }
 
+        // The switched color space is kept until another one is set.
+        if color_picker_state.color_space != self.color_space {
+            color_picker_state.set_color_space(self.color_space);
+        }
+
+        // Discard the typed text of a hidden picker.
+        if !self.show_picker {
+            color_picker_state.overlay_state.reset_input();
+        }
+
         tree.diff_children(&[&self.underlay, &self.overlay_state]);
     }
 
@@ -254,6 +369,13 @@ where
                 .overlay(&mut state.children[0], layout, renderer);
         }
 
+        let overlay_state = &mut picker_state.overlay_state;
+        overlay_state.alpha = self.show_alpha;
+        overlay_state.swatches.clone_from(&self.swatches);
+        if !self.show_alpha {
+            overlay_state.color.a = 1.0;
+        }
+
         let bounds = layout.bounds();
         let position = Point::new(bounds.center_x(), bounds.center_y());
 
@@ -275,9 +397,185 @@ impl<'a, Message, Theme> From<ColorPicker<'a, Message, Theme>>
     for Element<'a, Message, Renderer<Theme>>
 where
     Message: 'static + Clone,
-    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet,
+    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
 {
     fn from(color_picker: ColorPicker<'a, Message, Theme>) -> Self {
         Element::new(color_picker)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ColorPicker, ColorSpace};
+    use crate::test_utils::Harness;
+    use iced_widget::{
+        core::{
+            keyboard::{KeyCode, Modifiers},
+            Color, Point, Size,
+        },
+        text,
+    };
+
+    #[test]
+    fn color_picker_alpha_test() {
+        let color = Color::from_rgba(1.0, 0.0, 0.0, 0.5);
+        let mut harness = Harness::new(
+            ColorPicker::new(true, color, text("Underlay"), None, Some),
+            Size::new(600.0, 400.0),
+        );
+
+        // The preview shows the translucent color over a checkerboard.
+        let snapshot = harness.snapshot();
+        let light = snapshot.color(456, 240).expect("Pixel is in bounds");
+        let dark = snapshot.color(464, 240).expect("Pixel is in bounds");
+        assert!(light.g > dark.g + 0.05);
+        assert!(light.r > light.g);
+
+        // A quarter of the alpha bar picks a mostly transparent color.
+        let _ = harness.click(Point::new(394.0, 195.0));
+        let picked = harness.click(Point::new(521.0, 295.0));
+        let picked = picked[0].expect("A color is submitted");
+        assert!((picked.a - 0.25).abs() < 0.02);
+        assert_eq!((picked.r, picked.g, picked.b), (1.0, 0.0, 0.0));
+
+        // Without the alpha bar the picked color is opaque.
+        let mut harness = Harness::new(
+            ColorPicker::new(true, color, text("Underlay"), None, Some).show_alpha(false),
+            Size::new(600.0, 400.0),
+        );
+        assert_eq!(
+            harness.click(Point::new(521.0, 295.0)),
+            vec![Some(Color::from_rgb(1.0, 0.0, 0.0))]
+        );
+        assert_eq!(
+            harness.snapshot().color(456, 240),
+            harness.snapshot().color(464, 240)
+        );
+    }
+
+    #[test]
+    fn color_picker_input_test() {
+        let color = Color::from_rgb(1.0, 0.0, 0.0);
+        let mut harness = Harness::new(
+            ColorPicker::new(true, color, text("Underlay"), None, Some),
+            Size::new(600.0, 400.0),
+        );
+
+        // The hex string is cleared and a color typed.
+        let _ = harness.click(Point::new(368.0, 247.0));
+        let _ = harness.press_key(KeyCode::End);
+        for _ in 0..9 {
+            let _ = harness.press_key(KeyCode::Backspace);
+        }
+        let _ = harness.type_text("#00ff0080");
+        let green = Color::from_rgba8(0, 255, 0, 128.0 / 255.0);
+        assert_eq!(harness.press_key(KeyCode::Enter), vec![Some(green)]);
+
+        // The bars follow the typed color.
+        let snapshot = harness.snapshot();
+        let bar = snapshot.color(437, 115).expect("Pixel is in bounds");
+        assert!(bar.g > bar.r);
+        let valid = snapshot.color(307, 247).expect("Pixel is in bounds");
+        assert!(valid.b > valid.r);
+
+        // Text that isn't a color is outlined and can't be submitted.
+        let _ = harness.type_text("x");
+        let invalid = harness
+            .snapshot()
+            .color(307, 247)
+            .expect("Pixel is in bounds");
+        assert!(invalid.r > invalid.b);
+        assert!(harness.press_key(KeyCode::Enter).is_empty());
+
+        // A pasted value of a bar keeps the rest of the typed color.
+        let _ = harness.press_key(KeyCode::Escape);
+        let _ = harness.click(Point::new(560.0, 75.0));
+        let _ = harness.press_key(KeyCode::End);
+        for _ in 0..3 {
+            let _ = harness.press_key(KeyCode::Backspace);
+        }
+        harness.set_clipboard("64");
+        let _ = harness.set_modifiers(Modifiers::CTRL);
+        let _ = harness.press_key(KeyCode::V);
+        let _ = harness.set_modifiers(Modifiers::default());
+        assert_eq!(
+            harness.click(Point::new(521.0, 295.0)),
+            vec![Some(Color {
+                r: 64.0 / 255.0,
+                ..green
+            })]
+        );
+    }
+
+    #[test]
+    fn color_picker_color_space_test() {
+        let green = Color::from_rgb(0.0, 1.0, 0.0);
+        let color_picker = |color_space: ColorSpace| {
+            ColorPicker::new(true, green, text("Underlay"), None, Some).color_space(color_space)
+        };
+        let submit = Point::new(521.0, 295.0);
+        let retype = |harness: &mut Harness<Option<Color>>, position: Point, text: &str| {
+            let _ = harness.click(position);
+            let _ = harness.press_key(KeyCode::End);
+            for _ in 0..3 {
+                let _ = harness.press_key(KeyCode::Backspace);
+            }
+            harness.type_text(text)
+        };
+
+        // The switcher turns the first bar into the hue.
+        let mut harness = Harness::new(color_picker(ColorSpace::Rgb), Size::new(600.0, 400.0));
+        let _ = harness.click(Point::new(413.0, 25.0));
+        let _ = retype(&mut harness, Point::new(560.0, 75.0), "240");
+        assert_eq!(
+            harness.click(submit),
+            vec![Some(Color::from_rgb(0.0, 0.0, 1.0))]
+        );
+
+        // The lightness keeps the hue of the color while it is white.
+        let mut harness = Harness::new(color_picker(ColorSpace::Hsl), Size::new(600.0, 400.0));
+        let _ = retype(&mut harness, Point::new(560.0, 155.0), "100");
+        assert_eq!(harness.press_key(KeyCode::Enter), vec![Some(Color::WHITE)]);
+        let _ = retype(&mut harness, Point::new(560.0, 155.0), "50");
+        assert_eq!(harness.press_key(KeyCode::Enter), vec![Some(green)]);
+    }
+
+    #[test]
+    fn color_picker_swatches_test() {
+        let red = Color::from_rgb(1.0, 0.0, 0.0);
+        let green = Color::from_rgb(0.0, 1.0, 0.0);
+        let blue = Color::from_rgba(0.0, 0.0, 1.0, 0.5);
+        let color_picker = |show_alpha: bool| {
+            ColorPicker::new(
+                true,
+                Color::from_rgb(0.5, 0.5, 0.5),
+                text("Underlay"),
+                None,
+                Some,
+            )
+            .swatches([red, green, blue])
+            .show_alpha(show_alpha)
+        };
+        let mut harness = Harness::new(color_picker(true), Size::new(600.0, 400.0));
+        let submit = Point::new(521.0, 295.0);
+
+        // A click on a preset swatch picks its color, and the swatches move
+        // up for the row of the submitted colors.
+        let _ = harness.click(Point::new(45.0, 290.0));
+        assert_eq!(harness.click(submit), vec![Some(green)]);
+        let _ = harness.click(Point::new(20.0, 270.0));
+        assert_eq!(harness.click(submit), vec![Some(red)]);
+
+        // The submitted colors are shown below the presets, the latest first.
+        assert_eq!(harness.snapshot().color(20, 300), Some(red));
+        let _ = harness.click(Point::new(45.0, 300.0));
+        assert_eq!(harness.click(submit), vec![Some(green)]);
+        let _ = harness.click(Point::new(45.0, 300.0));
+        assert_eq!(harness.click(submit), vec![Some(red)]);
+
+        // Without the alpha bar the swatches pick opaque colors.
+        let mut harness = Harness::new(color_picker(false), Size::new(600.0, 400.0));
+        let _ = harness.click(Point::new(70.0, 290.0));
+        assert_eq!(harness.click(submit), vec![Some(Color { a: 1.0, ..blue })]);
+    }
+}
@@ -1,19 +1,23 @@
-//! A context menu for showing actions on right click.
+//! A context menu for showing actions on right click or long press.
 //!
 use iced_widget::core::{
     self, event,
     layout::{Limits, Node},
     mouse::{self, Button, Cursor},
-    overlay, renderer,
+    overlay, renderer, touch,
     widget::{tree, Operation, Tree},
-    Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
+    window, Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
 };
+use std::time::Instant;
+
+use crate::core::gesture::{Gesture, Recognizer, Thresholds};
 
 use crate::native::overlay::ContextMenuOverlay;
 pub use crate::style::context_menu::StyleSheet;
 
 /// A context menu
 ///
+/// It opens on a right click, or on a long press of a touch screen.
 ///
 /// # Example
 /// ```ignore
@@ -36,7 +40,6 @@ pub use crate::style::context_menu::StyleSheet;
 pub struct ContextMenu<'a, Overlay, Message, Renderer = crate::Renderer>
 where
     Overlay: Fn() -> Element<'a, Message, Renderer>,
-    Message: Clone,
     Renderer: core::Renderer,
     Renderer::Theme: StyleSheet,
 {
@@ -51,7 +54,6 @@ where
 impl<'a, Overlay, Message, Renderer> ContextMenu<'a, Overlay, Message, Renderer>
 where
     Overlay: Fn() -> Element<'a, Message, Renderer>,
-    Message: Clone,
     Renderer: core::Renderer,
     Renderer::Theme: StyleSheet,
 {
@@ -83,7 +85,7 @@ impl<'a, Content, Message, Renderer> Widget<Message, Renderer>
     for ContextMenu<'a, Content, Message, Renderer>
 where
     Content: 'a + Fn() -> Element<'a, Message, Renderer>,
-    Message: 'a + Clone,
+    Message: 'a,
     Renderer: 'a + core::Renderer,
     Renderer::Theme: StyleSheet,
 {
@@ -181,6 +183,44 @@ where
             }
         }
 
+        let s: &mut State = state.state.downcast_mut();
+        let thresholds = Thresholds::default();
+        let long_press = match event {
+            Event::Touch(touch::Event::FingerPressed { id, position })
+                if layout.bounds().contains(position) =>
+            {
+                s.long_press.press(id.0, position, Instant::now());
+                None
+            }
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                let _ = s.long_press.moved(id.0, position, &thresholds);
+                None
+            }
+            Event::Touch(touch::Event::FingerLifted { id, position }) => {
+                let _ = s
+                    .long_press
+                    .lift(id.0, position, Instant::now(), &thresholds);
+                None
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                s.long_press.cancel();
+                None
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                s.long_press.tick(now, &thresholds)
+            }
+            _ => None,
+        };
+
+        if let Some(Gesture::LongPress(position)) = long_press {
+            // The overlay receives the events of the finger from now on.
+            s.long_press.cancel();
+            s.cursor_position = position;
+            s.show = true;
+        } else if let Some(deadline) = s.long_press.long_press_deadline(&thresholds) {
+            shell.request_redraw(window::RedrawRequest::At(deadline));
+        }
+
         self.underlay.as_widget_mut().on_event(
             &mut state.children[0],
             event,
@@ -240,7 +280,7 @@ impl<'a, Content, Message, Renderer> From<ContextMenu<'a, Content, Message, Rend
     for Element<'a, Message, Renderer>
 where
     Content: 'a + Fn() -> Element<'a, Message, Renderer>,
-    Message: 'a + Clone,
+    Message: 'a,
     Renderer: 'a + core::Renderer,
     Renderer::Theme: StyleSheet,
 {
@@ -256,6 +296,8 @@ pub(crate) struct State {
     pub show: bool,
     /// Use for showing the overlay where the click was made.
     pub cursor_position: Point,
+    /// Recognizes the long press opening the [`ContextMenu`] on touch screens.
+    pub long_press: Recognizer,
 }
 
 impl State {
@@ -264,6 +306,7 @@ impl State {
         Self {
             show: false,
             cursor_position: Point::ORIGIN,
+            long_press: Recognizer::new(),
         }
     }
 }
@@ -0,0 +1,411 @@
+//! Use a contrast checker to check the contrast between two colors.
+//!
+//! *This API requires the following crate features to be activated: `contrast_checker`*
+
+use crate::core::color::HexString;
+use crate::core::contrast::{contrast_ratio, Level, TextSize};
+use crate::core::i18n;
+use crate::native::color_picker::{self, ColorPicker};
+use crate::native::helpers::Styled;
+
+use iced_widget::{
+    button,
+    core::{self, renderer, Alignment, Color, Element, Length, Rectangle},
+    renderer::Renderer,
+    text, text_input, Button, Column, Row, Space, Text,
+};
+use std::rc::Rc;
+
+pub use crate::style::contrast_checker::{Appearance, StyleSheet};
+
+/// The padding around the [`ContrastChecker`].
+const PADDING: f32 = 16.0;
+/// The spacing between the elements.
+const SPACING: f32 = 12.0;
+/// The size of the color of a swatch.
+const SWATCH_SIZE: f32 = 28.0;
+/// The size of the large text of the sample; 18 points.
+const LARGE_TEXT_SIZE: f32 = 24.0;
+
+/// One of the two colors of a [`ContrastChecker`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Swatch {
+    /// The color of the text.
+    Foreground,
+    /// The color behind the text.
+    Background,
+}
+
+/// A checker of the contrast between a text color and a background color.
+///
+/// Both colors are shown in swatches that open a [`ColorPicker`] when
+/// pressed. Below, a sample text is drawn with the colors, along with their
+/// contrast ratio and whether it passes the levels AA and AAA of the Web
+/// Content Accessibility Guidelines for normal and large texts.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::contrast_checker::{ContrastChecker, Swatch};
+/// # use iced::Color;
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Pick(Option<Swatch>),
+///     Changed(Swatch, Color),
+/// }
+///
+/// let contrast_checker = ContrastChecker::new(
+///     Color::BLACK,
+///     Color::WHITE,
+///     None,
+///     Message::Pick,
+///     Message::Changed,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ContrastChecker<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The color of the text.
+    foreground: Color,
+    /// The color behind the text.
+    background: Color,
+    /// The swatch whose color is being picked.
+    picking: Option<Swatch>,
+    /// The function producing the message when a swatch is pressed, or when
+    /// picking a color is canceled.
+    on_pick: Rc<dyn Fn(Option<Swatch>) -> Message>,
+    /// The function producing the message when a color is picked.
+    on_change: Rc<dyn Fn(Swatch, Color) -> Message>,
+    /// The text of the sample.
+    sample: &'a str,
+    /// The text size of the [`ContrastChecker`].
+    text_size: f32,
+    /// The style of the [`ContrastChecker`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> ContrastChecker<'a, Message, Theme>
+where
+    Message: 'static + Clone,
+    Theme: 'a
+        + StyleSheet
+        + color_picker::StyleSheet
+        + button::StyleSheet
+        + text::StyleSheet
+        + text_input::StyleSheet,
+{
+    /// Creates a new [`ContrastChecker`] of the given colors.
+    ///
+    /// It expects:
+    ///     * the color of the text.
+    ///     * the color behind the text.
+    ///     * the swatch whose color is being picked, if any.
+    ///     * the function producing the message with the swatch when it is
+    ///       pressed, or with `None` when picking a color is canceled.
+    ///     * the function producing the message with the swatch and its new
+    ///       color when a color is picked.
+    pub fn new<P, F>(
+        foreground: Color,
+        background: Color,
+        picking: Option<Swatch>,
+        on_pick: P,
+        on_change: F,
+    ) -> Self
+    where
+        P: 'static + Fn(Option<Swatch>) -> Message,
+        F: 'static + Fn(Swatch, Color) -> Message,
+    {
+        Self {
+            foreground,
+            background,
+            picking,
+            on_pick: Rc::new(on_pick),
+            on_change: Rc::new(on_change),
+            sample: "The quick brown fox jumps over the lazy dog.",
+            text_size: 16.0,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the text of the sample of the [`ContrastChecker`].
+    #[must_use]
+    pub fn sample(mut self, sample: &'a str) -> Self {
+        self.sample = sample;
+        self
+    }
+
+    /// Sets the text size of the [`ContrastChecker`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the style of the [`ContrastChecker`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the contrast ratio of the colors of the [`ContrastChecker`].
+    #[must_use]
+    pub fn ratio(&self) -> f32 {
+        contrast_ratio(self.foreground, self.background)
+    }
+
+    /// Wraps the given element to draw it with the [`Appearance`].
+    fn styled(
+        &self,
+        content: impl Into<Element<'a, Message, Renderer<Theme>>>,
+        role: Role,
+    ) -> Element<'a, Message, Renderer<Theme>> {
+        let style = self.style.clone();
+
+        Styled::new(
+            content,
+            move |renderer, theme: &Theme, _defaults, bounds, _cursor| {
+                role.draw(renderer, &StyleSheet::active(theme, &style), bounds)
+            },
+        )
+        .into()
+    }
+
+    /// Creates the swatch of the given color opening a [`ColorPicker`].
+    fn swatch(&self, swatch: Swatch, label: &str) -> Element<'a, Message, Renderer<Theme>> {
+        let color = match swatch {
+            Swatch::Foreground => self.foreground,
+            Swatch::Background => self.background,
+        };
+
+        let (on_pick, on_change) = (Rc::clone(&self.on_pick), Rc::clone(&self.on_change));
+
+        let content = Row::new()
+            .spacing(SPACING / 2.0)
+            .align_items(Alignment::Center)
+            .push(self.styled(
+                Space::new(SWATCH_SIZE, SWATCH_SIZE),
+                Role::Sample {
+                    foreground: color,
+                    background: color,
+                },
+            ))
+            .push(
+                Column::new()
+                    .push(Text::new(label.to_owned()).size(self.text_size * 0.8))
+                    .push(Text::new(color.as_hex_string()).size(self.text_size)),
+            );
+
+        ColorPicker::new(
+            self.picking == Some(swatch),
+            color,
+            Button::new(content).on_press((self.on_pick)(Some(swatch))),
+            on_pick(None),
+            move |color| on_change(swatch, color),
+        )
+        .into()
+    }
+
+    /// Creates the badge of the given level for texts of the given size.
+    fn badge(
+        &self,
+        ratio: f32,
+        level: Level,
+        size: TextSize,
+    ) -> Element<'a, Message, Renderer<Theme>> {
+        let passed = level.passes(ratio, size);
+
+        let label =
+            i18n::get().contrast_badge(&format!("{level:?}"), size == TextSize::Large, passed);
+
+        self.styled(
+            Row::new()
+                .padding([2.0, 8.0])
+                .push(Text::new(label).size(self.text_size * 0.8)),
+            Role::Badge { passed },
+        )
+    }
+}
+
+impl<'a, Message, Theme> From<ContrastChecker<'a, Message, Theme>>
+    for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'static + Clone,
+    Theme: 'a
+        + StyleSheet
+        + color_picker::StyleSheet
+        + button::StyleSheet
+        + text::StyleSheet
+        + text_input::StyleSheet,
+{
+    fn from(checker: ContrastChecker<'a, Message, Theme>) -> Self {
+        let ratio = checker.ratio();
+
+        let translations = i18n::get();
+
+        let swatches = Row::new()
+            .spacing(SPACING)
+            .push(checker.swatch(Swatch::Foreground, &translations.foreground()))
+            .push(checker.swatch(Swatch::Background, &translations.background()));
+
+        let sample = checker.styled(
+            Column::new()
+                .padding(SPACING)
+                .spacing(SPACING / 2.0)
+                .width(Length::Fill)
+                .push(Text::new(checker.sample).size(LARGE_TEXT_SIZE))
+                .push(Text::new(checker.sample).size(checker.text_size)),
+            Role::Sample {
+                foreground: checker.foreground,
+                background: checker.background,
+            },
+        );
+
+        let badges = [Level::AA, Level::AAA].into_iter().fold(
+            Column::new().spacing(SPACING / 2.0),
+            |badges, level| {
+                badges.push(
+                    Row::new()
+                        .spacing(SPACING / 2.0)
+                        .push(checker.badge(ratio, level, TextSize::Normal))
+                        .push(checker.badge(ratio, level, TextSize::Large)),
+                )
+            },
+        );
+
+        let summary = Row::new()
+            .spacing(SPACING)
+            .align_items(Alignment::Center)
+            .push(checker.styled(
+                Text::new(format!("{ratio:.2}:1")).size(checker.text_size * 2.0),
+                Role::Label,
+            ))
+            .push(badges);
+
+        checker.styled(
+            Column::new()
+                .padding(PADDING)
+                .spacing(SPACING)
+                .push(swatches)
+                .push(sample)
+                .push(summary),
+            Role::Frame,
+        )
+    }
+}
+
+/// How a [`Styled`] element is drawn with the [`Appearance`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Role {
+    /// The background of the [`ContrastChecker`].
+    Frame,
+    /// A text drawn with the text color.
+    Label,
+    /// A text drawn with the given colors.
+    Sample {
+        /// The color of the text.
+        foreground: Color,
+        /// The color behind the text.
+        background: Color,
+    },
+    /// The badge of a level.
+    Badge {
+        /// Whether the level is passed.
+        passed: bool,
+    },
+}
+
+impl Role {
+    /// Draws the background of an element with the [`Role`], returning the
+    /// color of its text.
+    fn draw<Renderer: core::Renderer>(
+        self,
+        renderer: &mut Renderer,
+        appearance: &Appearance,
+        bounds: Rectangle,
+    ) -> Color {
+        let (background, border_radius, border_width, border_color, text_color) = match self {
+            Self::Frame => (
+                Some(appearance.background),
+                appearance.border_radius,
+                appearance.border_width,
+                appearance.border_color,
+                appearance.text_color,
+            ),
+            Self::Label => (None, 0.0, 0.0, Color::TRANSPARENT, appearance.text_color),
+            Self::Sample {
+                foreground,
+                background,
+            } => (
+                Some(background.into()),
+                appearance.swatch_border_radius,
+                1.0,
+                appearance.swatch_border_color,
+                foreground,
+            ),
+            Self::Badge { passed: true } => (
+                Some(appearance.pass_background),
+                appearance.swatch_border_radius,
+                0.0,
+                Color::TRANSPARENT,
+                appearance.pass_text_color,
+            ),
+            Self::Badge { passed: false } => (
+                Some(appearance.fail_background),
+                appearance.swatch_border_radius,
+                0.0,
+                Color::TRANSPARENT,
+                appearance.fail_text_color,
+            ),
+        };
+
+        if let Some(background) = background {
+            core::Renderer::fill_quad(
+                renderer,
+                renderer::Quad {
+                    bounds,
+                    border_radius: border_radius.into(),
+                    border_width,
+                    border_color,
+                },
+                background,
+            );
+        }
+
+        text_color
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ContrastChecker, Swatch};
+    use crate::test_utils::Harness;
+    use iced_widget::core::{Color, Point, Size};
+
+    #[test]
+    fn contrast_checker_picker_test() {
+        let checker = |picking| {
+            ContrastChecker::new(Color::BLACK, Color::WHITE, picking, Err, |swatch, color| {
+                Ok((swatch, color))
+            })
+        };
+        let size = Size::new(600.0, 400.0);
+
+        let mut harness = Harness::new(checker(None), size);
+        let closed = harness.snapshot();
+        assert_eq!(
+            harness.click(Point::new(30.0, 30.0)),
+            vec![Err(Some(Swatch::Foreground))]
+        );
+
+        // The picker of the picked swatch opens and submits its color.
+        let mut harness = Harness::new(checker(Some(Swatch::Foreground)), size);
+        assert!(harness.snapshot().diff(&closed, 8) > Some(10_000));
+        assert_eq!(
+            harness.click(Point::new(521.0, 295.0)),
+            vec![Ok((Swatch::Foreground, Color::BLACK))]
+        );
+    }
+}
@@ -2,7 +2,10 @@
 //!
 //! *This API requires the following crate features to be activated: `date_picker`*
 
-use super::overlay::date_picker::{self, DatePickerOverlay, DatePickerOverlayButtons};
+use super::overlay::date_picker::{
+    self, Constraints, DatePickerOverlay, DatePickerOverlayButtons, WeekNumbers,
+};
+use crate::core::lens::{Lens, Update};
 
 use chrono::Local;
 use iced_widget::{
@@ -19,17 +22,24 @@ use iced_widget::{
         Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
     },
     renderer::Renderer,
-    text,
+    text, text_input,
 };
+use std::collections::BTreeSet;
 
 pub use crate::{
-    core::date::Date,
+    core::date::{Date, Weekday},
     style::date_picker::{Appearance, StyleSheet},
 };
 
 //TODO: Remove ignore when Null is updated. Temp fix for Test runs
 /// An input element for picking dates.
 ///
+/// The overlay can be used with the keyboard alone: the arrow keys move the
+/// focused day, `PageUp` and `PageDown` browse the months, or the years with
+/// `Shift` held, `Home` and `End` move to the first and the last day of the
+/// week, `Space` picks the focused day if multiple dates or a range are
+/// picked, `Enter` submits and `Escape` cancels.
+///
 /// # Example
 /// ```ignore
 /// # use iced_aw::DatePicker;
@@ -51,7 +61,7 @@ pub use crate::{
 ///     Message::Submit,
 /// );
 /// ```
-#[allow(missing_debug_implementations)]
+#[allow(missing_debug_implementations, clippy::struct_excessive_bools)]
 pub struct DatePicker<'a, Message, Theme>
 where
     Message: Clone,
@@ -66,7 +76,29 @@ where
     /// The message that is send if the cancel button of the [`DatePickerOverlay`] is pressed.
     on_cancel: Message,
     /// The function that produces a message when the submit button of the [`DatePickerOverlay`] is pressed.
-    on_submit: Box<dyn Fn(Date) -> Message>,
+    on_submit: OnSubmit<Message>,
+    /// The initially picked dates if multiple dates can be picked.
+    dates: BTreeSet<Date>,
+    /// The initially picked range if a range of dates is picked.
+    range: Option<(Date, Date)>,
+    /// The earliest date that can be picked.
+    min_date: Option<Date>,
+    /// The latest date that can be picked.
+    max_date: Option<Date>,
+    /// The function disabling the dates that can't be picked.
+    disable_if: Option<Box<dyn Fn(Date) -> bool + 'a>>,
+    /// Whether the buttons show their translated labels instead of icons.
+    labeled_buttons: bool,
+    /// The weekday the weeks of the calendar start on.
+    first_day_of_week: Weekday,
+    /// Whether the ISO 8601 week numbers are shown.
+    week_numbers: bool,
+    /// Whether clicking a week number picks the days of its week.
+    pick_weeks: bool,
+    /// The number of consecutive months shown side by side.
+    months_shown: u8,
+    /// The format of the dates typed into the text field, if it is shown.
+    input_format: Option<String>,
     /// The style of the [`DatePickerOverlay`].
     style: <Theme as StyleSheet>::Style,
     /// The buttons of the overlay.
@@ -106,13 +138,273 @@ where
             date: date.into(),
             underlay: underlay.into(),
             on_cancel,
-            on_submit: Box::new(on_submit),
+            on_submit: OnSubmit::Single(Box::new(on_submit)),
+            dates: BTreeSet::new(),
+            range: None,
+            min_date: None,
+            max_date: None,
+            disable_if: None,
+            labeled_buttons: false,
+            first_day_of_week: Weekday::Mon,
+            week_numbers: false,
+            pick_weeks: false,
+            months_shown: 1,
+            input_format: None,
             style: <Theme as StyleSheet>::Style::default(),
             overlay_state: DatePickerOverlayButtons::default().into(),
             //button_style: <Renderer as button::Renderer>::Style::default(),
         }
     }
 
+    /// Creates a new [`DatePicker`] bound to the date of the model through a
+    /// [`Lens`], wrapping around the given underlay.
+    ///
+    /// Instead of the picked date, the [`DatePicker`] submits an [`Update`]
+    /// setting it in the model, so the fields of a form can share a single
+    /// message.
+    ///
+    /// # Example
+    /// ```ignore
+    /// # use iced_aw::{date_picker::Date, lens::{lens, Update}, DatePicker};
+    /// #
+    /// struct Profile {
+    ///     birthday: Date,
+    /// }
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum Message {
+    ///     Cancel,
+    ///     Edit(Update<Profile>),
+    /// }
+    ///
+    /// let profile = Profile { birthday: Date::today() };
+    /// let birthday = lens(|profile: &Profile| profile.birthday, |profile, birthday| profile.birthday = birthday);
+    ///
+    /// let picker = DatePicker::bind(true, &profile, birthday, "Underlay", Message::Cancel, Message::Edit);
+    /// ```
+    pub fn bind<U, Model, L, F>(
+        show_picker: bool,
+        model: &Model,
+        lens: L,
+        underlay: U,
+        on_cancel: Message,
+        on_update: F,
+    ) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer<Theme>>>,
+        Model: 'static,
+        L: 'static + Lens<Model, Value = Date> + Copy + Send + Sync,
+        F: 'static + Fn(Update<Model>) -> Message,
+    {
+        Self::new(
+            show_picker,
+            lens.get(model),
+            underlay,
+            on_cancel,
+            move |date| on_update(Update::set(lens, date)),
+        )
+    }
+
+    /// Creates a new [`DatePicker`] for picking any number of dates wrapping
+    /// around the given underlay.
+    ///
+    /// Clicking a day in the calendar toggles it on or off. The toggled dates
+    /// are submitted as a set when the submit button is pressed.
+    ///
+    /// It expects:
+    ///     * if the overlay of the date picker is visible.
+    ///     * the initially picked dates.
+    ///     * the underlay [`Element`] on which this [`DatePicker`]
+    ///         will be wrapped around.
+    ///     * a message that will be send when the cancel button of the [`DatePicker`]
+    ///         is pressed.
+    ///     * a function that will be called when the submit button of the [`DatePicker`]
+    ///         is pressed, which takes the set of picked [`Date`](crate::date_picker::Date) values.
+    pub fn new_multiple<U, F>(
+        show_picker: bool,
+        dates: impl IntoIterator<Item = Date>,
+        underlay: U,
+        on_cancel: Message,
+        on_submit: F,
+    ) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer<Theme>>>,
+        F: 'static + Fn(BTreeSet<Date>) -> Message,
+    {
+        let dates: BTreeSet<Date> = dates.into_iter().collect();
+
+        Self {
+            show_picker,
+            date: dates.first().copied().unwrap_or_else(Date::today),
+            underlay: underlay.into(),
+            on_cancel,
+            on_submit: OnSubmit::Multiple(Box::new(on_submit)),
+            dates,
+            range: None,
+            min_date: None,
+            max_date: None,
+            disable_if: None,
+            labeled_buttons: false,
+            first_day_of_week: Weekday::Mon,
+            week_numbers: false,
+            pick_weeks: false,
+            months_shown: 1,
+            input_format: None,
+            style: <Theme as StyleSheet>::Style::default(),
+            overlay_state: DatePickerOverlayButtons::default().into(),
+        }
+    }
+
+    /// Creates a new [`DatePicker`] for picking a range of dates wrapping
+    /// around the given underlay.
+    ///
+    /// The first click on a day in the calendar starts the range and the
+    /// second one ends it, highlighting the days between them. The first and
+    /// the last day of the range are submitted when the submit button is
+    /// pressed, in order regardless of the order they were clicked in.
+    ///
+    /// It expects:
+    ///     * if the overlay of the date picker is visible.
+    ///     * the first and the last day of the initially picked range.
+    ///     * the underlay [`Element`] on which this [`DatePicker`]
+    ///         will be wrapped around.
+    ///     * a message that will be send when the cancel button of the [`DatePicker`]
+    ///         is pressed.
+    ///     * a function that will be called when the submit button of the [`DatePicker`]
+    ///         is pressed, which takes the first and the last picked
+    ///         [`Date`](crate::date_picker::Date) values.
+    pub fn new_range<U, F>(
+        show_picker: bool,
+        range: (impl Into<Date>, impl Into<Date>),
+        underlay: U,
+        on_cancel: Message,
+        on_submit: F,
+    ) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer<Theme>>>,
+        F: 'static + Fn(Date, Date) -> Message,
+    {
+        let (start, end) = (range.0.into(), range.1.into());
+
+        Self {
+            show_picker,
+            date: start.min(end),
+            underlay: underlay.into(),
+            on_cancel,
+            on_submit: OnSubmit::Range(Box::new(on_submit)),
+            dates: BTreeSet::new(),
+            range: Some((start.min(end), start.max(end))),
+            min_date: None,
+            max_date: None,
+            disable_if: None,
+            labeled_buttons: false,
+            first_day_of_week: Weekday::Mon,
+            week_numbers: false,
+            pick_weeks: false,
+            months_shown: 1,
+            input_format: None,
+            style: <Theme as StyleSheet>::Style::default(),
+            overlay_state: DatePickerOverlayButtons::default().into(),
+        }
+    }
+
+    /// Sets the earliest date that can be picked with the [`DatePicker`].
+    #[must_use]
+    pub fn min_date(mut self, date: impl Into<Date>) -> Self {
+        self.min_date = Some(date.into());
+        self
+    }
+
+    /// Sets the latest date that can be picked with the [`DatePicker`].
+    #[must_use]
+    pub fn max_date(mut self, date: impl Into<Date>) -> Self {
+        self.max_date = Some(date.into());
+        self
+    }
+
+    /// Sets the function disabling the dates that can't be picked with the
+    /// [`DatePicker`], e.g. weekends or holidays.
+    ///
+    /// The disabled days are drawn with the
+    /// [`disabled`](crate::style::date_picker::StyleSheet::disabled)
+    /// appearance and are skipped by the arrow keys.
+    #[must_use]
+    pub fn disable_if<F>(mut self, disable_if: F) -> Self
+    where
+        F: 'a + Fn(Date) -> bool,
+    {
+        self.disable_if = Some(Box::new(disable_if));
+        self
+    }
+
+    /// Sets whether the cancel and submit buttons of the [`DatePicker`] show
+    /// their labels from the installed
+    /// [`Translations`](crate::core::i18n::Translations) instead of their
+    /// icons.
+    #[must_use]
+    pub fn labeled_buttons(mut self, labeled_buttons: bool) -> Self {
+        self.labeled_buttons = labeled_buttons;
+        self
+    }
+
+    /// Sets the weekday the weeks of the calendar of the [`DatePicker`] start
+    /// on, e.g. [`Weekday::Sun`] or [`Weekday::Sat`] depending on the region.
+    ///
+    /// The weeks start on Monday by default.
+    #[must_use]
+    pub fn first_day_of_week(mut self, first_day_of_week: Weekday) -> Self {
+        self.first_day_of_week = first_day_of_week;
+        self
+    }
+
+    /// Sets whether the calendar of the [`DatePicker`] shows the ISO 8601
+    /// week numbers in a column before the days.
+    #[must_use]
+    pub fn week_numbers(mut self, week_numbers: bool) -> Self {
+        self.week_numbers = week_numbers;
+        self
+    }
+
+    /// Sets whether clicking a week number of the [`DatePicker`] picks the
+    /// days of its week, which also shows the week numbers.
+    ///
+    /// The picked date moves to the Monday of the week, or the first day of
+    /// the week that can be picked. If a range of dates is picked, the week
+    /// becomes the range, and if multiple dates can be picked, its days are
+    /// toggled together.
+    #[must_use]
+    pub fn pick_weeks(mut self, pick_weeks: bool) -> Self {
+        self.pick_weeks = pick_weeks;
+        self
+    }
+
+    /// Sets the number of consecutive months shown side by side in the
+    /// calendar of the [`DatePicker`], from one to three, e.g. to pick a
+    /// range of dates across two months.
+    ///
+    /// The month of the picked date is shown first. Browsing the months with
+    /// the arrows moves all shown months, while picking a day or navigating
+    /// the days with the keyboard only moves them when leaving them.
+    #[must_use]
+    pub fn months_shown(mut self, months_shown: u8) -> Self {
+        self.months_shown = months_shown.clamp(1, 3);
+        self
+    }
+
+    /// Shows a text field above the calendar of the [`DatePicker`] for typing
+    /// dates in the given [`chrono` format](chrono::format::strftime), e.g.
+    /// `"%d.%m.%Y"`.
+    ///
+    /// The calendar jumps to a typed date as soon as it is complete and can
+    /// be picked, the text field is outlined in the
+    /// [`invalid`](crate::style::date_picker::StyleSheet::invalid) style
+    /// otherwise, and `Enter` submits the date.
+    #[must_use]
+    pub fn input_format(mut self, format: impl Into<String>) -> Self {
+        self.input_format = Some(format.into());
+        self
+    }
+
     /// Sets the style of the [`DatePicker`].
     #[must_use]
     pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
@@ -122,6 +414,30 @@ where
     }
 }
 
+impl<Message, Theme> DatePicker<'_, Message, Theme>
+where
+    Message: Clone,
+    Theme: StyleSheet + button::StyleSheet,
+{
+    /// Resets the picked dates of the given state to the initially picked ones.
+    fn reset_selection(&self, state: &mut State) {
+        state.overlay_state.selection = self.dates.iter().copied().map(Into::into).collect();
+        state.overlay_state.range = self.range.map(|(start, end)| (start.into(), end.into()));
+        state.overlay_state.range_anchor = None;
+    }
+}
+
+/// The function producing the message when the picked dates of a [`DatePicker`] are submitted.
+#[allow(missing_debug_implementations)]
+pub enum OnSubmit<Message> {
+    /// A single date is picked.
+    Single(Box<dyn Fn(Date) -> Message>),
+    /// Any number of dates are toggled on or off.
+    Multiple(Box<dyn Fn(BTreeSet<Date>) -> Message>),
+    /// A range of dates is picked by its first and last date.
+    Range(Box<dyn Fn(Date, Date) -> Message>),
+}
+
 /// The state of the [`DatePicker`] / [`DatePickerOverlay`].
 #[derive(Debug)]
 pub struct State {
@@ -155,14 +471,21 @@ impl State {
 impl<'a, Message, Theme> Widget<Message, Renderer<Theme>> for DatePicker<'a, Message, Theme>
 where
     Message: 'static + Clone,
-    Theme: StyleSheet + button::StyleSheet + text::StyleSheet + container::StyleSheet,
+    Theme: StyleSheet
+        + button::StyleSheet
+        + text::StyleSheet
+        + container::StyleSheet
+        + text_input::StyleSheet,
 {
     fn tag(&self) -> Tag {
         Tag::of::<State>()
     }
 
     fn state(&self) -> widget::tree::State {
-        widget::tree::State::new(State::new(self.date))
+        let mut state = State::new(self.date);
+        self.reset_selection(&mut state);
+
+        widget::tree::State::new(state)
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -170,6 +493,17 @@ where
     }
 
     fn diff(&self, tree: &mut Tree) {
+        // Discard the picked dates of a cancelled picker while it is hidden.
+        if !self.show_picker && !matches!(self.on_submit, OnSubmit::Single(_)) {
+            self.reset_selection(tree.state.downcast_mut());
+        }
+
+        // Discard the typed text of a hidden picker.
+        if !self.show_picker {
+            let state: &mut State = tree.state.downcast_mut();
+            state.overlay_state.reset_input();
+        }
+
         tree.diff_children(&[&self.underlay, &self.overlay_state]);
     }
 
@@ -269,6 +603,22 @@ where
                 picker_state,
                 self.on_cancel.clone(),
                 &self.on_submit,
+                Constraints {
+                    min: self.min_date.map(Into::into),
+                    max: self.max_date.map(Into::into),
+                    disable_if: self.disable_if.as_deref(),
+                },
+                self.labeled_buttons,
+                self.first_day_of_week,
+                if self.pick_weeks {
+                    WeekNumbers::Pickable
+                } else if self.week_numbers {
+                    WeekNumbers::Shown
+                } else {
+                    WeekNumbers::Hidden
+                },
+                self.months_shown,
+                self.input_format.as_deref(),
                 position,
                 self.style.clone(),
                 &mut state.children[1],
@@ -282,9 +632,216 @@ impl<'a, Message, Theme> From<DatePicker<'a, Message, Theme>>
     for Element<'a, Message, Renderer<Theme>>
 where
     Message: 'static + Clone,
-    Theme: 'a + StyleSheet + button::StyleSheet + text::StyleSheet + container::StyleSheet,
+    Theme: 'a
+        + StyleSheet
+        + button::StyleSheet
+        + text::StyleSheet
+        + container::StyleSheet
+        + text_input::StyleSheet,
 {
     fn from(date_picker: DatePicker<'a, Message, Theme>) -> Self {
         Element::new(date_picker)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Date, DatePicker, Weekday};
+    use crate::{core::lens::lens, test_utils::Harness};
+    use iced_widget::{
+        core::{
+            keyboard::{KeyCode, Modifiers},
+            Point, Size,
+        },
+        text,
+    };
+
+    #[test]
+    fn date_picker_months_test() {
+        let date_picker = DatePicker::new_range(
+            true,
+            (Date::from_ymd(2020, 12, 9), Date::from_ymd(2020, 12, 9)),
+            text("Underlay"),
+            None,
+            |start, end| Some((start, end)),
+        )
+        .months_shown(2);
+        let mut harness = Harness::new(date_picker, Size::new(700.0, 400.0));
+        let range = Some((Date::from_ymd(2020, 12, 28), Date::from_ymd(2021, 1, 5)));
+
+        // December and January are shown side by side, so the range is
+        // picked across them.
+        let _ = harness.click(Point::new(20.0, 220.0));
+        let _ = harness.click(Point::new(380.0, 120.0));
+        assert_eq!(harness.press_key(KeyCode::Enter), vec![range]);
+
+        // Picking a day in January keeps December shown first.
+        let _ = harness.click(Point::new(20.0, 220.0));
+
+        // Navigating the days past January shows January first, whose
+        // second week starts on the fifth.
+        for _ in 0..6 {
+            let _ = harness.press_key(KeyCode::Down);
+        }
+        let _ = harness.click(Point::new(60.0, 120.0));
+        assert_eq!(harness.press_key(KeyCode::Enter), vec![range]);
+    }
+
+    #[test]
+    fn date_picker_range_constraints_test() {
+        let date_picker = DatePicker::new_range(
+            true,
+            (Date::from_ymd(2020, 12, 9), Date::from_ymd(2020, 12, 9)),
+            text("Underlay"),
+            None,
+            |start, end| Some((start, end)),
+        )
+        .disable_if(|date| date == Date::from_ymd(2020, 12, 9));
+        let mut harness = Harness::new(date_picker, Size::new(400.0, 400.0));
+
+        // The initial range starts on a disabled day, so it can't be
+        // submitted.
+        assert!(harness.press_key(KeyCode::Enter).is_empty());
+
+        // A range of days that can be picked is submitted.
+        let _ = harness.press_key(KeyCode::Right);
+        let _ = harness.press_key(KeyCode::Space);
+        let _ = harness.press_key(KeyCode::Right);
+        let _ = harness.press_key(KeyCode::Space);
+        assert_eq!(
+            harness.press_key(KeyCode::Enter),
+            vec![Some((
+                Date::from_ymd(2020, 12, 10),
+                Date::from_ymd(2020, 12, 11)
+            ))]
+        );
+    }
+
+    #[test]
+    fn date_picker_input_test() {
+        let date_picker = DatePicker::new(
+            true,
+            Date::from_ymd(2020, 12, 9),
+            text("Underlay"),
+            None,
+            Some,
+        )
+        .max_date(Date::from_ymd(2021, 12, 31))
+        .input_format("%d.%m.%Y");
+        let mut harness = Harness::new(date_picker, Size::new(400.0, 400.0));
+
+        // The text field above the calendar is cleared and a date typed.
+        let _ = harness.click(Point::new(160.0, 25.0));
+        let _ = harness.press_key(KeyCode::End);
+        for _ in 0..10 {
+            let _ = harness.press_key(KeyCode::Backspace);
+        }
+        let _ = harness.type_text("24.12.2020");
+        assert_eq!(
+            harness.press_key(KeyCode::Enter),
+            vec![Some(Date::from_ymd(2020, 12, 24))]
+        );
+        let valid = harness
+            .snapshot()
+            .color(12, 10)
+            .expect("Pixel is in bounds");
+        assert!(valid.b > valid.r);
+
+        // A date after the latest one is outlined and can't be submitted.
+        let _ = harness.type_text("1");
+        let invalid = harness
+            .snapshot()
+            .color(12, 10)
+            .expect("Pixel is in bounds");
+        assert!(invalid.r > invalid.b);
+        assert!(harness.press_key(KeyCode::Enter).is_empty());
+
+        // The calendar kept the typed date, and the keys navigate it again
+        // once the text field is left.
+        let _ = harness.press_key(KeyCode::Escape);
+        let _ = harness.press_key(KeyCode::Right);
+        assert_eq!(
+            harness.press_key(KeyCode::Enter),
+            vec![Some(Date::from_ymd(2020, 12, 25))]
+        );
+    }
+
+    #[test]
+    fn date_picker_keyboard_test() {
+        // Wednesday, December 9, 2020.
+        let date_picker = DatePicker::new(
+            true,
+            Date::from_ymd(2020, 12, 9),
+            text("Underlay"),
+            None,
+            Some,
+        )
+        .first_day_of_week(Weekday::Sun)
+        .max_date(Date::from_ymd(2021, 12, 31));
+        let mut harness = Harness::new(date_picker, Size::new(400.0, 400.0));
+
+        // The arrow keys move the day without clicking the overlay first.
+        let _ = harness.press_key(KeyCode::Right);
+        let _ = harness.press_key(KeyCode::Down);
+        assert_eq!(
+            harness.press_key(KeyCode::Enter),
+            vec![Some(Date::from_ymd(2020, 12, 17))]
+        );
+
+        // Home and End move to the boundaries of the week starting on Sunday.
+        let _ = harness.press_key(KeyCode::Home);
+        assert_eq!(
+            harness.press_key(KeyCode::Enter),
+            vec![Some(Date::from_ymd(2020, 12, 13))]
+        );
+        let _ = harness.press_key(KeyCode::End);
+        assert_eq!(
+            harness.press_key(KeyCode::Enter),
+            vec![Some(Date::from_ymd(2020, 12, 19))]
+        );
+
+        // Page up and down browse the months, and the years with shift held,
+        // within the latest date.
+        let _ = harness.press_key(KeyCode::PageDown);
+        assert_eq!(
+            harness.press_key(KeyCode::Enter),
+            vec![Some(Date::from_ymd(2021, 1, 19))]
+        );
+        let _ = harness.set_modifiers(Modifiers::SHIFT);
+        let _ = harness.press_key(KeyCode::PageDown);
+        let _ = harness.set_modifiers(Modifiers::empty());
+        assert_eq!(
+            harness.press_key(KeyCode::Enter),
+            vec![Some(Date::from_ymd(2021, 12, 31))]
+        );
+
+        assert_eq!(harness.press_key(KeyCode::Escape), vec![None]);
+    }
+
+    #[test]
+    fn date_picker_bind_test() {
+        struct Profile {
+            birthday: Date,
+        }
+
+        let mut profile = Profile {
+            birthday: Date::from_ymd(2020, 12, 9),
+        };
+        let birthday = lens(
+            |profile: &Profile| profile.birthday,
+            |profile, birthday| profile.birthday = birthday,
+        );
+        let date_picker = DatePicker::bind(true, &profile, birthday, text("Underlay"), None, Some);
+        let mut harness = Harness::new(date_picker, Size::new(400.0, 400.0));
+
+        // The picker starts at the date of the model and submits an update
+        // setting the picked date in it.
+        let _ = harness.press_key(KeyCode::Right);
+        let messages = harness.press_key(KeyCode::Enter);
+        assert_eq!(messages.len(), 1);
+        for update in messages.iter().flatten() {
+            update.apply(&mut profile);
+        }
+        assert_eq!(profile.birthday, Date::from_ymd(2020, 12, 10));
+    }
+}
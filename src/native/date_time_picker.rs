@@ -0,0 +1,489 @@
+//! Use a date time picker as an input element for picking a date and a time.
+//!
+//! *This API requires the following crate features to be activated: `date_time_picker`*
+
+use super::overlay::{
+    date_picker::{Constraints, DatePickerOverlay, DatePickerOverlayButtons, WeekNumbers},
+    date_time_picker::{DateTimePickerOverlay, Page, PageMessage},
+    time_picker::{TimePickerOverlay, TimePickerOverlayButtons},
+};
+
+use iced_widget::{
+    button, container,
+    core::{
+        self, event,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer,
+        widget::tree::{self, Tag, Tree},
+        Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
+    },
+    renderer::Renderer,
+    text, text_input,
+};
+
+pub use crate::{
+    date_picker::{Date, Weekday},
+    style::{date_picker::StyleSheet as DateStyleSheet, time_picker::StyleSheet as TimeStyleSheet},
+    time_picker::{Period, Time},
+};
+
+/// An input element for picking a date and a time in one overlay.
+///
+/// The overlay shows the calendar of a [`DatePicker`](crate::DatePicker) or
+/// the clock of a [`TimePicker`](crate::TimePicker) below a tab of each,
+/// which switch between them. Submitting the date moves on to the time, and
+/// submitting the time submits both.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{DateTimePicker, date_picker::Date, time_picker::Time};
+/// # use iced_widget::{button, Button, Text};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Open,
+///     Cancel,
+///     Submit(Date, Time),
+/// }
+///
+/// let date_time_picker = DateTimePicker::new(
+///     true,
+///     Date::today(),
+///     Time::now_hm(true),
+///     Button::new(Text::new("Pick date and time"))
+///         .on_press(Message::Open),
+///     Message::Cancel,
+///     Message::Submit,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DateTimePicker<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: DateStyleSheet + TimeStyleSheet + button::StyleSheet,
+{
+    /// Show the picker.
+    show_picker: bool,
+    /// The date to show.
+    date: Date,
+    /// The time to show.
+    time: Time,
+    /// The underlying element.
+    underlay: Element<'a, Message, Renderer<Theme>>,
+    /// The message that is send if the cancel button of the [`DateTimePickerOverlay`] is pressed.
+    on_cancel: Message,
+    /// The function that produces a message when the date and the time are submitted.
+    on_submit: Box<dyn Fn(Date, Time) -> Message>,
+    /// The earliest date that can be picked.
+    min_date: Option<Date>,
+    /// The latest date that can be picked.
+    max_date: Option<Date>,
+    /// The weekday the weeks of the calendar start on.
+    first_day_of_week: Weekday,
+    /// Toggle the use of the 24h clock.
+    use_24h: bool,
+    /// Toggle the use of the seconds.
+    show_seconds: bool,
+    /// The interval of the minutes the clock snaps to.
+    minute_step: u8,
+    /// The style of the calendar and the tabs.
+    date_style: <Theme as DateStyleSheet>::Style,
+    /// The style of the clock.
+    time_style: <Theme as TimeStyleSheet>::Style,
+    /// The buttons of the calendar.
+    date_buttons: Element<'a, PageMessage, Renderer<Theme>>,
+    /// The buttons of the clock.
+    time_buttons: Element<'a, PageMessage, Renderer<Theme>>,
+    /// The function producing the message of the calendar when the date is submitted.
+    date_submit: crate::date_picker::OnSubmit<PageMessage>,
+    /// The function producing the message of the clock when the time is submitted.
+    time_submit: crate::time_picker::OnSubmit<PageMessage>,
+}
+
+impl<'a, Message, Theme> DateTimePicker<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + DateStyleSheet
+        + TimeStyleSheet
+        + button::StyleSheet
+        + text::StyleSheet
+        + container::StyleSheet,
+{
+    /// Creates a new [`DateTimePicker`] wrapping around the given underlay.
+    ///
+    /// It expects:
+    ///     * if the overlay of the date time picker is visible.
+    ///     * the initial date and time to show.
+    ///     * the underlay [`Element`] on which this [`DateTimePicker`]
+    ///         will be wrapped around.
+    ///     * a message that will be send when the cancel button of the [`DateTimePicker`]
+    ///         is pressed.
+    ///     * a function that will be called when the time is submitted, which takes
+    ///         the picked [`Date`] and [`Time`].
+    pub fn new<U, F>(
+        show_picker: bool,
+        date: impl Into<Date>,
+        time: impl Into<Time>,
+        underlay: U,
+        on_cancel: Message,
+        on_submit: F,
+    ) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer<Theme>>>,
+        F: 'static + Fn(Date, Time) -> Message,
+    {
+        Self {
+            show_picker,
+            date: date.into(),
+            time: time.into(),
+            underlay: underlay.into(),
+            on_cancel,
+            on_submit: Box::new(on_submit),
+            min_date: None,
+            max_date: None,
+            first_day_of_week: Weekday::Mon,
+            use_24h: false,
+            show_seconds: false,
+            minute_step: 1,
+            date_style: <Theme as DateStyleSheet>::Style::default(),
+            time_style: <Theme as TimeStyleSheet>::Style::default(),
+            date_buttons: DatePickerOverlayButtons::default().into(),
+            time_buttons: TimePickerOverlayButtons::default().into(),
+            date_submit: crate::date_picker::OnSubmit::Single(Box::new(|_| PageMessage::Date)),
+            time_submit: crate::time_picker::OnSubmit::Single(Box::new(PageMessage::Time)),
+        }
+    }
+
+    /// Sets the earliest date that can be picked with the [`DateTimePicker`].
+    #[must_use]
+    pub fn min_date(mut self, date: impl Into<Date>) -> Self {
+        self.min_date = Some(date.into());
+        self
+    }
+
+    /// Sets the latest date that can be picked with the [`DateTimePicker`].
+    #[must_use]
+    pub fn max_date(mut self, date: impl Into<Date>) -> Self {
+        self.max_date = Some(date.into());
+        self
+    }
+
+    /// Sets the weekday the weeks of the calendar of the [`DateTimePicker`]
+    /// start on, which is Monday by default.
+    #[must_use]
+    pub fn first_day_of_week(mut self, first_day_of_week: Weekday) -> Self {
+        self.first_day_of_week = first_day_of_week;
+        self
+    }
+
+    /// Use 24 hour format instead of AM/PM.
+    #[must_use]
+    pub fn use_24h(mut self) -> Self {
+        self.use_24h = true;
+        self
+    }
+
+    /// Enables the picker to also pick seconds.
+    #[must_use]
+    pub fn show_seconds(mut self) -> Self {
+        self.show_seconds = true;
+        self
+    }
+
+    /// Sets the interval of the minutes the clock of the [`DateTimePicker`]
+    /// snaps to, e.g. 5, 10 or 15 minutes.
+    #[must_use]
+    pub fn minute_step(mut self, minute_step: u8) -> Self {
+        self.minute_step = minute_step.clamp(1, 60);
+        self
+    }
+
+    /// Sets the style of the calendar and the tabs of the [`DateTimePicker`].
+    #[must_use]
+    pub fn date_style(mut self, style: <Theme as DateStyleSheet>::Style) -> Self {
+        self.date_style = style;
+        self
+    }
+
+    /// Sets the style of the clock of the [`DateTimePicker`].
+    #[must_use]
+    pub fn time_style(mut self, style: <Theme as TimeStyleSheet>::Style) -> Self {
+        self.time_style = style;
+        self
+    }
+}
+
+/// The state of the [`DateTimePicker`] / [`DateTimePickerOverlay`].
+#[derive(Debug)]
+pub struct State {
+    /// The state of the calendar.
+    pub(crate) date: crate::date_picker::State,
+    /// The state of the clock.
+    pub(crate) time: crate::time_picker::State,
+    /// The shown page of the overlay.
+    pub(crate) page: Page,
+}
+
+impl State {
+    /// Creates a new [`State`] with the given date and time.
+    #[must_use]
+    pub fn new(date: Date, time: Time) -> Self {
+        Self {
+            date: crate::date_picker::State::new(date),
+            time: crate::time_picker::State::new(time),
+            page: Page::default(),
+        }
+    }
+}
+
+impl<'a, Message, Theme> Widget<Message, Renderer<Theme>> for DateTimePicker<'a, Message, Theme>
+where
+    Message: 'static + Clone,
+    Theme: DateStyleSheet
+        + TimeStyleSheet
+        + button::StyleSheet
+        + text::StyleSheet
+        + container::StyleSheet
+        + text_input::StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.date, self.time))
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![
+            Tree::new(&self.underlay),
+            Tree::new(&self.date_buttons),
+            Tree::new(&self.time_buttons),
+        ]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        // A reopened picker starts with the date again.
+        if !self.show_picker {
+            tree.state.downcast_mut::<State>().page = Page::Date;
+        }
+
+        tree.children[0].diff(&self.underlay);
+        tree.children[1].diff(&self.date_buttons);
+        tree.children[2].diff(&self.time_buttons);
+    }
+
+    fn width(&self) -> Length {
+        self.underlay.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.underlay.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer<Theme>, limits: &Limits) -> Node {
+        self.underlay.as_widget().layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer<Theme>,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.underlay.as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer<Theme>,
+    ) -> mouse::Interaction {
+        self.underlay.as_widget().mouse_interaction(
+            &state.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer<Theme>,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.underlay.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer<Theme>,
+    ) -> Option<core::overlay::Element<'b, Message, Renderer<Theme>>> {
+        if !self.show_picker {
+            return self
+                .underlay
+                .as_widget_mut()
+                .overlay(&mut state.children[0], layout, renderer);
+        }
+
+        let State { date, time, page } = state.state.downcast_mut();
+        let [_, date_tree, time_tree] = state.children.as_mut_slice() else {
+            return None;
+        };
+
+        let clock = &mut time.overlay_state;
+        clock.use_24h = self.use_24h;
+        clock.show_seconds = self.show_seconds;
+        clock.minute_step = u32::from(self.minute_step);
+        clock.snap_minutes();
+
+        let picked_date = date.overlay_state.date;
+        let picked_time = clock.picked(clock.time);
+
+        let bounds = layout.bounds();
+        let position = Point::new(bounds.center_x(), bounds.center_y());
+
+        let content = match page {
+            Page::Date => DatePickerOverlay::new(
+                date,
+                PageMessage::Cancel,
+                &self.date_submit,
+                Constraints {
+                    min: self.min_date.map(Into::into),
+                    max: self.max_date.map(Into::into),
+                    disable_if: None,
+                },
+                false,
+                self.first_day_of_week,
+                WeekNumbers::Hidden,
+                1,
+                None,
+                position,
+                self.date_style.clone(),
+                date_tree,
+            )
+            .overlay(),
+            Page::Time => TimePickerOverlay::new(
+                time,
+                PageMessage::Cancel,
+                &self.time_submit,
+                position,
+                self.time_style.clone(),
+                time_tree,
+            )
+            .overlay(),
+        };
+
+        Some(
+            DateTimePickerOverlay::new(
+                page,
+                content,
+                picked_date,
+                picked_time,
+                self.on_cancel.clone(),
+                self.on_submit.as_ref(),
+                position,
+                self.date_style.clone(),
+            )
+            .overlay(),
+        )
+    }
+}
+
+impl<'a, Message, Theme> From<DateTimePicker<'a, Message, Theme>>
+    for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'static + Clone,
+    Theme: 'a
+        + DateStyleSheet
+        + TimeStyleSheet
+        + button::StyleSheet
+        + text::StyleSheet
+        + container::StyleSheet
+        + text_input::StyleSheet,
+{
+    fn from(date_time_picker: DateTimePicker<'a, Message, Theme>) -> Self {
+        Element::new(date_time_picker)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DateTimePicker;
+    use crate::core::date::Date;
+    use crate::test_utils::Harness;
+    use chrono::NaiveTime;
+    use iced_widget::{
+        core::{Point, Size},
+        text,
+    };
+
+    #[test]
+    fn date_time_picker_test() {
+        let date_time_picker = DateTimePicker::new(
+            true,
+            Date::from_ymd(2020, 12, 9),
+            NaiveTime::from_hms_opt(10, 15, 0).expect("Valid time"),
+            text("Underlay"),
+            None,
+            |date, time| {
+                Some((
+                    Date::from(chrono::NaiveDate::from(date)),
+                    NaiveTime::from(time),
+                ))
+            },
+        )
+        .use_24h();
+        let mut harness = Harness::new(date_time_picker, Size::new(400.0, 500.0));
+
+        // Submitting the picked date leads on to the time.
+        let _ = harness.click(Point::new(159.0, 223.0));
+        assert!(harness.click(Point::new(237.0, 332.0)).is_empty());
+        assert_eq!(
+            harness.click(Point::new(237.0, 382.0)),
+            vec![Some((
+                Date::from_ymd(2020, 12, 24),
+                NaiveTime::from_hms_opt(10, 15, 0).expect("Valid time")
+            ))]
+        );
+
+        // The tab of the date switches back to the calendar.
+        let _ = harness.click(Point::new(80.0, 16.0));
+        assert_eq!(harness.click(Point::new(82.0, 332.0)), vec![None]);
+    }
+}
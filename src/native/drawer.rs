@@ -0,0 +1,549 @@
+//! A drawer for sliding a panel in from an edge of some content.
+//!
+//! *This API requires the following crate features to be activated: drawer*
+
+use super::overlay::drawer::{draw_panel, layout_panel, DrawerOverlay};
+use crate::core::easing::Animation;
+
+use iced_widget::core::{
+    self, event,
+    layout::{Limits, Node},
+    mouse::{self, Cursor},
+    overlay, renderer,
+    widget::{tree, Operation, Tree},
+    window, Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+use std::time::Duration;
+
+pub use crate::style::drawer::StyleSheet;
+
+/// The default width of a [`Drawer`] sliding in from the left or right.
+const DEFAULT_WIDTH: f32 = 300.0;
+/// The default height of a [`Drawer`] sliding in from the top or bottom.
+const DEFAULT_HEIGHT: f32 = 200.0;
+/// The default duration of the slide animation.
+const DEFAULT_DURATION: Duration = Duration::from_millis(200);
+
+/// The edge of the underlay the panel of a [`Drawer`] slides in from.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Edge {
+    /// The panel slides in from the left.
+    #[default]
+    Left,
+    /// The panel slides in from the right.
+    Right,
+    /// The panel slides in from the top.
+    Top,
+    /// The panel slides in from the bottom.
+    Bottom,
+}
+
+impl Edge {
+    /// Returns true if the panel slides in horizontally.
+    #[must_use]
+    pub const fn is_horizontal(self) -> bool {
+        matches!(self, Self::Left | Self::Right)
+    }
+}
+
+/// How the panel of a [`Drawer`] shares its space with the underlay.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DrawerMode {
+    /// The panel slides over the underlay, dimming it with a backdrop.
+    #[default]
+    Overlay,
+    /// The panel is laid out next to the underlay, pushing it aside.
+    ///
+    /// Opening and closing the panel in this mode is not animated.
+    Push,
+}
+
+/// A panel sliding in from an edge of its underlay.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::Text;
+/// # use iced_aw::{drawer::Edge, Drawer};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     CloseDrawer,
+/// }
+///
+/// let drawer = Drawer::new(
+///     true,
+///     Text::new("Underlay"),
+///     Text::new("Panel"),
+/// )
+/// .edge(Edge::Right)
+/// .on_dismiss(Message::CloseDrawer);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Drawer<'a, Message, Renderer = crate::Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Show the panel of the [`Drawer`].
+    open: bool,
+    /// The underlying element.
+    underlay: Element<'a, Message, Renderer>,
+    /// The content of the panel.
+    content: Element<'a, Message, Renderer>,
+    /// The edge the panel slides in from.
+    edge: Edge,
+    /// The way the panel shares its space with the underlay.
+    mode: DrawerMode,
+    /// The width of the panel when sliding in from the left or right.
+    width: f32,
+    /// The height of the panel when sliding in from the top or bottom.
+    height: f32,
+    /// The duration of the slide animation.
+    duration: Duration,
+    /// The optional message that will be send when the panel is dismissed.
+    on_dismiss: Option<Message>,
+    /// The style of the [`Drawer`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Drawer<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Drawer`] wrapping the underlying element.
+    ///
+    /// It expects:
+    ///     * if the panel of the [`Drawer`] is open.
+    ///     * the underlay [`Element`] on which this [`Drawer`] will be wrapped around.
+    ///     * the content [`Element`] of the panel.
+    pub fn new<U, C>(open: bool, underlay: U, content: C) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer>>,
+        C: Into<Element<'a, Message, Renderer>>,
+    {
+        Drawer {
+            open,
+            underlay: underlay.into(),
+            content: content.into(),
+            edge: Edge::default(),
+            mode: DrawerMode::default(),
+            width: DEFAULT_WIDTH,
+            height: DEFAULT_HEIGHT,
+            duration: DEFAULT_DURATION,
+            on_dismiss: None,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the [`Edge`] the panel of the [`Drawer`] slides in from.
+    #[must_use]
+    pub fn edge(mut self, edge: Edge) -> Self {
+        self.edge = edge;
+        self
+    }
+
+    /// Sets the [`DrawerMode`] of the [`Drawer`].
+    #[must_use]
+    pub fn mode(mut self, mode: DrawerMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the width of the panel when sliding in from the left or right.
+    #[must_use]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the panel when sliding in from the top or bottom.
+    #[must_use]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the duration of the slide animation of the [`Drawer`].
+    #[must_use]
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the message that will be produced when the panel is dismissed.
+    ///
+    /// The panel is dismissed by clicking on the backdrop, pressing the
+    /// Escape key or swiping it back towards its edge.
+    #[must_use]
+    pub fn on_dismiss(mut self, message: Message) -> Self {
+        self.on_dismiss = Some(message);
+        self
+    }
+
+    /// Sets the style of the [`Drawer`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the extent of the panel along the axis it slides on.
+    fn extent(&self) -> f32 {
+        if self.edge.is_horizontal() {
+            self.width
+        } else {
+            self.height
+        }
+    }
+
+    /// Returns true if the panel is laid out next to the underlay.
+    fn is_pushing(&self) -> bool {
+        self.open && self.mode == DrawerMode::Push
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Drawer<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.underlay), Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.underlay, &self.content]);
+    }
+
+    fn width(&self) -> Length {
+        self.underlay.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.underlay.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        if !self.is_pushing() {
+            let underlay = self.underlay.as_widget().layout(renderer, limits);
+            return Node::with_children(underlay.size(), vec![underlay]);
+        }
+
+        let extent = if self.edge.is_horizontal() {
+            Size::new(self.width, 0.0)
+        } else {
+            Size::new(0.0, self.height)
+        };
+
+        let mut underlay = self
+            .underlay
+            .as_widget()
+            .layout(renderer, &limits.shrink(extent));
+        let underlay_size = underlay.size();
+
+        let mut panel = layout_panel(
+            &self.content,
+            renderer,
+            if self.edge.is_horizontal() {
+                Size::new(self.width, underlay_size.height)
+            } else {
+                Size::new(underlay_size.width, self.height)
+            },
+        );
+
+        match self.edge {
+            Edge::Left => underlay.move_to(Point::new(self.width, 0.0)),
+            Edge::Top => underlay.move_to(Point::new(0.0, self.height)),
+            Edge::Right => panel.move_to(Point::new(underlay_size.width, 0.0)),
+            Edge::Bottom => panel.move_to(Point::new(0.0, underlay_size.height)),
+        }
+
+        Node::with_children(
+            Size::new(
+                underlay_size.width + extent.width,
+                underlay_size.height + extent.height,
+            ),
+            vec![underlay, panel],
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let drawer_state: &mut State = state.state.downcast_mut();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            if self.mode == DrawerMode::Push {
+                drawer_state.animation.progress = if self.open { 1.0 } else { 0.0 };
+            } else if drawer_state
+                .animation
+                .animate(self.open, self.duration, now)
+            {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+                return event::Status::Captured;
+            }
+        }
+
+        if self.mode == DrawerMode::Overlay && drawer_state.is_visible(self.open) {
+            return event::Status::Ignored;
+        }
+
+        let mut children = layout.children();
+        let underlay_status = self.underlay.as_widget_mut().on_event(
+            &mut state.children[0],
+            event.clone(),
+            children
+                .next()
+                .expect("Native: Layout should have an underlay layout for a drawer."),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        children.next().map_or(underlay_status, |panel| {
+            let content_status = self.content.as_widget_mut().on_event(
+                &mut state.children[1],
+                event,
+                panel
+                    .children()
+                    .next()
+                    .expect("Native: Layout should have a content layout for a drawer."),
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+
+            underlay_status.merge(content_status)
+        })
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let drawer_state: &State = state.state.downcast_ref();
+
+        if self.mode == DrawerMode::Overlay && drawer_state.is_visible(self.open) {
+            return mouse::Interaction::default();
+        }
+
+        let mut children = layout.children();
+        let underlay_interaction = self.underlay.as_widget().mouse_interaction(
+            &state.children[0],
+            children
+                .next()
+                .expect("Native: Layout should have an underlay layout for a drawer."),
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        children.next().map_or(underlay_interaction, |panel| {
+            underlay_interaction.max(
+                self.content.as_widget().mouse_interaction(
+                    &state.children[1],
+                    panel
+                        .children()
+                        .next()
+                        .expect("Native: Layout should have a content layout for a drawer."),
+                    cursor,
+                    viewport,
+                    renderer,
+                ),
+            )
+        })
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let mut children = layout.children();
+
+        self.underlay.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            children
+                .next()
+                .expect("Graphics: Layout should have an underlay layout for a drawer."),
+            cursor,
+            viewport,
+        );
+
+        if let Some(panel) = children.next() {
+            draw_panel(
+                &self.content,
+                &state.children[1],
+                renderer,
+                theme,
+                style,
+                &theme.active(&self.style),
+                panel,
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let drawer_state: &mut State = state.state.downcast_mut();
+
+        if self.mode == DrawerMode::Push || !drawer_state.is_visible(self.open) {
+            let mut children = layout.children();
+            let (underlay_tree, content_tree) = state.children.split_at_mut(1);
+
+            let overlays: Vec<_> = [
+                children.next().and_then(|underlay| {
+                    self.underlay
+                        .as_widget_mut()
+                        .overlay(&mut underlay_tree[0], underlay, renderer)
+                }),
+                children
+                    .next()
+                    .and_then(|panel| panel.children().next())
+                    .and_then(|content| {
+                        self.content.as_widget_mut().overlay(
+                            &mut content_tree[0],
+                            content,
+                            renderer,
+                        )
+                    }),
+            ]
+            .into_iter()
+            .flatten()
+            .collect();
+
+            return (!overlays.is_empty())
+                .then(|| overlay::Group::with_children(overlays).overlay());
+        }
+
+        let bounds = layout.bounds();
+        let extent = self.extent();
+        self.content.as_widget().diff(&mut state.children[1]);
+
+        Some(
+            DrawerOverlay::new(
+                &mut state.children[1],
+                &mut self.content,
+                drawer_state,
+                self.open,
+                self.edge,
+                extent,
+                self.on_dismiss.clone(),
+                self.style.clone(),
+                bounds,
+            )
+            .overlay(Point::new(bounds.x, bounds.y)),
+        )
+    }
+
+    fn operate<'b>(
+        &'b self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let mut children = layout.children();
+
+        self.underlay.as_widget().operate(
+            &mut state.children[0],
+            children
+                .next()
+                .expect("Native: Layout should have an underlay layout for a drawer."),
+            renderer,
+            operation,
+        );
+
+        if let Some(panel) = children.next() {
+            self.content.as_widget().operate(
+                &mut state.children[1],
+                panel
+                    .children()
+                    .next()
+                    .expect("Native: Layout should have a content layout for a drawer."),
+                renderer,
+                operation,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Drawer<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(drawer: Drawer<'a, Message, Renderer>) -> Self {
+        Element::new(drawer)
+    }
+}
+
+/// The state of the [`Drawer`].
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    /// How far the panel has slid in, from 0.0 (closed) to 1.0 (open).
+    pub animation: Animation,
+    /// The position a swipe on the panel was started at.
+    pub swipe_origin: Option<Point>,
+    /// How far the panel has been swiped back towards its edge.
+    pub swipe_offset: f32,
+}
+
+impl State {
+    /// Creates a new [`State`] with a closed panel.
+    pub const fn new() -> Self {
+        Self {
+            animation: Animation::new(),
+            swipe_origin: None,
+            swipe_offset: 0.0,
+        }
+    }
+
+    /// Returns true if any part of the panel is visible.
+    pub fn is_visible(&self, open: bool) -> bool {
+        open || self.animation.progress > 0.0
+    }
+}
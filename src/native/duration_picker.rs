@@ -0,0 +1,393 @@
+//! Use a duration picker as an input element for picking durations.
+//!
+//! *This API requires the following crate features to be activated: `duration_picker`*
+
+use super::overlay::duration_picker::{self, DurationPickerOverlay, DurationPickerOverlayButtons};
+use crate::core::lens::{Lens, Update};
+
+use iced_widget::{
+    button,
+    core::{
+        event,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        overlay, renderer,
+        widget::tree::{self, Tag, Tree},
+        Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
+    },
+    renderer::Renderer,
+    text,
+};
+use std::time::Duration;
+
+pub use crate::{
+    core::duration::Unit,
+    style::duration_picker::{Appearance, StyleSheet},
+};
+
+//TODO: Remove ignore when Null is updated. Temp fix for Test runs
+/// An input element for picking durations, like the length of a timer, with
+/// spinners for the hours, the minutes and optionally the seconds.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::DurationPicker;
+/// # use iced_widget::{button, Button, Text};
+/// # use std::time::Duration;
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Open,
+///     Cancel,
+///     Submit(Duration),
+/// }
+///
+/// let duration_picker = DurationPicker::new(
+///     true,
+///     Duration::from_mins(25),
+///     Button::new(Text::new("Set timer"))
+///         .on_press(Message::Open),
+///     Message::Cancel,
+///     Message::Submit,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DurationPicker<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: StyleSheet + button::StyleSheet,
+{
+    /// Show the picker.
+    show_picker: bool,
+    /// The duration to show.
+    duration: Duration,
+    /// The underlying element.
+    underlay: Element<'a, Message, Renderer<Theme>>,
+    /// The message that is send if the cancel button of the [`DurationPickerOverlay`] is pressed.
+    on_cancel: Message,
+    /// The function that produces a message when the submit button of the [`DurationPickerOverlay`] is pressed.
+    on_submit: Box<dyn Fn(Duration) -> Message>,
+    /// The style of the [`DurationPickerOverlay`].
+    style: <Theme as StyleSheet>::Style,
+    /// The buttons of the overlay.
+    overlay_state: Element<'a, Message, Renderer<Theme>>,
+    /// Toggle the use of the seconds of the [`DurationPickerOverlay`].
+    show_seconds: bool,
+    /// The longest duration the [`DurationPickerOverlay`] picks.
+    max: Duration,
+}
+
+impl<'a, Message, Theme> DurationPicker<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a + StyleSheet + button::StyleSheet + text::StyleSheet,
+{
+    /// Creates a new [`DurationPicker`] wrapping around the given underlay.
+    ///
+    /// It expects:
+    ///     * if the overlay of the duration picker is visible.
+    ///     * the initial duration to show.
+    ///     * the underlay [`Element`] on which this [`DurationPicker`]
+    ///         will be wrapped around.
+    ///     * a message that will be send when the cancel button of the [`DurationPicker`] is pressed.
+    ///     * a function that will be called when the submit button of the [`DurationPicker`]
+    ///         is pressed, which takes the picked [`Duration`].
+    pub fn new<U, F>(
+        show_picker: bool,
+        duration: Duration,
+        underlay: U,
+        on_cancel: Message,
+        on_submit: F,
+    ) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer<Theme>>>,
+        F: 'static + Fn(Duration) -> Message,
+    {
+        Self {
+            show_picker,
+            duration,
+            underlay: underlay.into(),
+            on_cancel,
+            on_submit: Box::new(on_submit),
+            style: <Theme as StyleSheet>::Style::default(),
+            overlay_state: DurationPickerOverlayButtons::default().into(),
+            show_seconds: false,
+            max: duration_picker::State::default().max,
+        }
+    }
+
+    /// Creates a new [`DurationPicker`] bound to the duration of the model through a
+    /// [`Lens`], wrapping around the given underlay.
+    ///
+    /// Instead of the picked duration, the [`DurationPicker`] submits an [`Update`]
+    /// setting it in the model, so the fields of a form can share a single
+    /// message.
+    ///
+    /// # Example
+    /// ```ignore
+    /// # use std::time::Duration;
+    /// # use iced_aw::{lens::{lens, Update}, DurationPicker};
+    /// #
+    /// struct Settings {
+    ///     timeout: Duration,
+    /// }
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum Message {
+    ///     Cancel,
+    ///     Edit(Update<Settings>),
+    /// }
+    ///
+    /// let settings = Settings { timeout: Duration::from_secs(30) };
+    /// let timeout = lens(|settings: &Settings| settings.timeout, |settings, timeout| settings.timeout = timeout);
+    ///
+    /// let picker = DurationPicker::bind(true, &settings, timeout, "Underlay", Message::Cancel, Message::Edit);
+    /// ```
+    pub fn bind<U, Model, L, F>(
+        show_picker: bool,
+        model: &Model,
+        lens: L,
+        underlay: U,
+        on_cancel: Message,
+        on_update: F,
+    ) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer<Theme>>>,
+        Model: 'static,
+        L: 'static + Lens<Model, Value = Duration> + Copy + Send + Sync,
+        F: 'static + Fn(Update<Model>) -> Message,
+    {
+        Self::new(
+            show_picker,
+            lens.get(model),
+            underlay,
+            on_cancel,
+            move |duration| on_update(Update::set(lens, duration)),
+        )
+    }
+
+    /// Enables the picker to also pick seconds.
+    #[must_use]
+    pub fn show_seconds(mut self) -> Self {
+        self.show_seconds = true;
+        self
+    }
+
+    /// Sets the longest duration the [`DurationPicker`] picks, 99 hours, 59
+    /// minutes and 59 seconds by default.
+    #[must_use]
+    pub fn max(mut self, max: Duration) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Sets the style of the [`DurationPicker`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+/// The state of the [`DurationPicker`] / [`DurationPickerOverlay`].
+#[derive(Debug)]
+pub struct State {
+    /// The state of the overlay.
+    pub(crate) overlay_state: duration_picker::State,
+}
+
+impl State {
+    /// Creates a new [`State`] with the given duration.
+    #[must_use]
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            overlay_state: duration_picker::State::new(duration),
+        }
+    }
+}
+
+impl<Message, Theme> Widget<Message, Renderer<Theme>> for DurationPicker<'_, Message, Theme>
+where
+    Message: 'static + Clone,
+    Theme: StyleSheet + button::StyleSheet + text::StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.duration))
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.underlay), Tree::new(&self.overlay_state)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        // Discard the duration picked by a cancelled picker while it is hidden.
+        if !self.show_picker {
+            let state: &mut State = tree.state.downcast_mut();
+            state.overlay_state = duration_picker::State::new(self.duration);
+        }
+
+        tree.diff_children(&[&self.underlay, &self.overlay_state]);
+    }
+
+    fn width(&self) -> Length {
+        self.underlay.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.underlay.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer<Theme>, limits: &Limits) -> Node {
+        self.underlay.as_widget().layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer<Theme>,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.underlay.as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer<Theme>,
+    ) -> mouse::Interaction {
+        self.underlay.as_widget().mouse_interaction(
+            &state.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer<Theme>,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.underlay.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer<Theme>,
+    ) -> Option<overlay::Element<'b, Message, Renderer<Theme>>> {
+        let picker_state: &mut State = state.state.downcast_mut();
+
+        if !self.show_picker {
+            return self
+                .underlay
+                .as_widget_mut()
+                .overlay(&mut state.children[0], layout, renderer);
+        }
+
+        let overlay_state = &mut picker_state.overlay_state;
+        overlay_state.show_seconds = self.show_seconds;
+        overlay_state.max = self.max;
+        overlay_state.clamp();
+
+        let bounds = layout.bounds();
+        let position = Point::new(bounds.center_x(), bounds.center_y());
+
+        Some(
+            DurationPickerOverlay::new(
+                overlay_state,
+                self.on_cancel.clone(),
+                &self.on_submit,
+                position,
+                self.style.clone(),
+                &mut state.children[1],
+            )
+            .overlay(),
+        )
+    }
+}
+
+impl<'a, Message, Theme> From<DurationPicker<'a, Message, Theme>>
+    for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'static + Clone,
+    Theme: 'a + StyleSheet + button::StyleSheet + text::StyleSheet,
+{
+    fn from(duration_picker: DurationPicker<'a, Message, Theme>) -> Self {
+        Element::new(duration_picker)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DurationPicker;
+    use crate::test_utils::Harness;
+    use iced_widget::{
+        core::{keyboard::KeyCode, Point, Size},
+        text,
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn duration_picker_test() {
+        let duration_picker =
+            DurationPicker::new(true, Duration::from_mins(25), text("Underlay"), None, Some)
+                .show_seconds();
+        let mut harness = Harness::new(duration_picker, Size::new(300.0, 300.0));
+
+        // The arrows step the minutes and focus them for the keyboard.
+        assert!(harness.click(Point::new(110.0, 18.0)).is_empty());
+        let _ = harness.press_key(KeyCode::Up);
+        assert_eq!(
+            harness.click(Point::new(161.0, 121.0)),
+            vec![Some(Duration::from_mins(27))]
+        );
+
+        // Typing fills in the focused segment and moves on to the next one.
+        let _ = harness.click(Point::new(39.0, 42.0));
+        let _ = harness.type_text("1:30");
+        let _ = harness.press_key(KeyCode::Up);
+        assert_eq!(
+            harness.click(Point::new(161.0, 121.0)),
+            vec![Some(Duration::from_secs(3600 + 30 * 60 + 1))]
+        );
+
+        assert_eq!(harness.click(Point::new(58.0, 121.0)), vec![None]);
+    }
+}
@@ -0,0 +1,298 @@
+//! Use an edge resizer to resize an undecorated window from its edges.
+//!
+//! *This API requires the following crate features to be activated: `edge_resizer`*
+pub use crate::core::resize::ResizeDirection;
+
+use iced_widget::core::{
+    self, event,
+    layout::{Limits, Node},
+    mouse::{self, Cursor},
+    overlay, renderer, touch,
+    widget::{
+        tree::{State as TreeState, Tag},
+        Operation, Tree,
+    },
+    Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Vector, Widget,
+};
+
+/// A wrapper placing invisible resize grips along the edges and corners of
+/// its content, typically the whole content of an undecorated window.
+///
+/// While a grip is dragged, the [`EdgeResizer`] produces the dragged
+/// [`ResizeDirection`] with the movement of the cursor since the last
+/// message. [`ResizeDirection::resize`] turns it into the new size of the
+/// window and the offset by which to move it.
+///
+/// iced has no diagonal resize cursors, so the corners show a crosshair.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::Text;
+/// # use iced_aw::{resize::ResizeDirection, EdgeResizer};
+/// # use iced::Vector;
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Resize(ResizeDirection, Vector),
+/// }
+///
+/// let edge_resizer = EdgeResizer::new(Text::new("Content"), Message::Resize);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct EdgeResizer<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer,
+{
+    /// The content of the [`EdgeResizer`].
+    content: Element<'a, Message, Renderer>,
+    /// The function producing the message when a grip is dragged.
+    on_resize: Box<dyn Fn(ResizeDirection, Vector) -> Message + 'a>,
+    /// The thickness of the grips.
+    thickness: f32,
+    /// Whether the grips are enabled.
+    enabled: bool,
+}
+
+impl<'a, Message, Renderer> EdgeResizer<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    /// Creates a new [`EdgeResizer`] around the given content.
+    ///
+    /// It expects:
+    ///     * the content [`Element`] of the [`EdgeResizer`].
+    ///     * the function producing the message with the dragged
+    ///       [`ResizeDirection`] and the movement of the cursor.
+    pub fn new<C, F>(content: C, on_resize: F) -> Self
+    where
+        C: Into<Element<'a, Message, Renderer>>,
+        F: 'a + Fn(ResizeDirection, Vector) -> Message,
+    {
+        Self {
+            content: content.into(),
+            on_resize: Box::new(on_resize),
+            thickness: 6.0,
+            enabled: true,
+        }
+    }
+
+    /// Sets the thickness of the grips of the [`EdgeResizer`].
+    ///
+    /// The grips of the corners extend twice the thickness along the edges.
+    #[must_use]
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Sets whether the grips of the [`EdgeResizer`] are enabled, e.g. to
+    /// disable them while the window is maximized.
+    #[must_use]
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Returns the grip under the cursor, if any.
+    fn grip(&self, bounds: Rectangle, cursor: Cursor) -> Option<ResizeDirection> {
+        if !self.enabled {
+            return None;
+        }
+
+        let position = cursor.position_over(bounds)?;
+
+        ResizeDirection::at(
+            bounds.size(),
+            Point::new(position.x - bounds.x, position.y - bounds.y),
+            self.thickness,
+        )
+    }
+}
+
+/// The state of an [`EdgeResizer`].
+#[derive(Debug, Default)]
+struct State {
+    /// The dragged grip and the last position of the cursor.
+    drag: Option<(ResizeDirection, Point)>,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for EdgeResizer<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let (Some(direction), Some(position)) =
+                    (self.grip(bounds, cursor), cursor.position())
+                {
+                    state.drag = Some((direction, position));
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some((direction, last)) = &mut state.drag {
+                    let delta = position - *last;
+                    *last = position;
+
+                    if delta != Vector::ZERO {
+                        shell.publish((self.on_resize)(*direction, delta));
+                    }
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if state.drag.is_some() =>
+            {
+                state.drag = None;
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        // The content does not see the cursor over the grips.
+        let cursor = if self.grip(bounds, cursor).is_some() {
+            Cursor::Unavailable
+        } else {
+            cursor
+        };
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let direction = state
+            .drag
+            .map(|(direction, _)| direction)
+            .or_else(|| self.grip(layout.bounds(), cursor));
+
+        match direction.map(ResizeDirection::sides) {
+            Some((0, _)) => mouse::Interaction::ResizingVertically,
+            Some((_, 0)) => mouse::Interaction::ResizingHorizontally,
+            Some(_) => mouse::Interaction::Crosshair,
+            None => self.content.as_widget().mouse_interaction(
+                &tree.children[0],
+                layout,
+                cursor,
+                viewport,
+                renderer,
+            ),
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate<'b>(
+        &'b self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<EdgeResizer<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + core::Renderer,
+{
+    fn from(edge_resizer: EdgeResizer<'a, Message, Renderer>) -> Self {
+        Element::new(edge_resizer)
+    }
+}
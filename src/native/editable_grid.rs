@@ -0,0 +1,1983 @@
+//! Use an editable grid to enter data in a spreadsheet like table of cells.
+//!
+//! *This API requires the following crate features to be activated: `editable_grid`*
+use super::overlay::editable_grid::{ChoiceList, ColumnMenu};
+use crate::core::{
+    cell_editor::CellEditor, column_layout::ColumnLayout, i18n, ime::Caret, kinetic::Kinetic, tsv,
+};
+use crate::graphics::icons::{Icon, ICON_FONT};
+use crate::style::editable_grid::StyleSheet;
+
+use std::{
+    collections::HashMap,
+    ops::Range,
+    time::{Duration, Instant},
+};
+
+use iced_widget::{
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event, keyboard,
+        layout::{Limits, Node},
+        mouse::{self, click, Cursor},
+        overlay, renderer, text, touch,
+        widget::{
+            tree::{State as TreeState, Tag},
+            Operation, Tree,
+        },
+        window, Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size,
+        Vector, Widget,
+    },
+    text::LineHeight,
+};
+
+/// The number of pixels scrolled per scrolled line.
+const SCROLL_STEP: f32 = 40.0;
+/// The distance a header has to be dragged before its column is moved.
+const DRAG_THRESHOLD: f32 = 4.0;
+/// The width of the gutter holding the toggles of the detail rows.
+const GUTTER_WIDTH: f32 = 20.0;
+/// The duration of the animation expanding a detail row.
+const EXPAND_DURATION: Duration = Duration::from_millis(150);
+
+/// The function formatting the copied cells of an [`EditableGrid`].
+type CopyFn<'a> = Box<dyn Fn(&[Vec<&str>]) -> String + 'a>;
+
+/// The editor of the columns without a [`CellEditor`].
+static TEXT_EDITOR: CellEditor = CellEditor::Text;
+
+/// How many rows of an [`EditableGrid`] can show their details at once.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RowExpansion {
+    /// Expanding a row collapses the other rows.
+    Single,
+    /// Any number of rows can be expanded.
+    #[default]
+    Multiple,
+}
+
+/// A spreadsheet like grid of text cells for data entry.
+///
+/// Cells are selected with a click, ranges of cells by dragging or with
+/// `Shift`, and the selection is moved with the arrow keys. A double click,
+/// `F2` or typing edits the focused cell in place, `Enter` or `Tab` commit the
+/// edit and `Escape` cancels it. Selected blocks are copied and pasted as
+/// tab-separated values, which spreadsheet applications understand. The
+/// leading rows and columns can be frozen as headers, staying visible while
+/// the other cells scroll.
+///
+/// With a [`ColumnLayout`] the columns can be reordered by dragging their
+/// headers, and shown or hidden from a context menu opened by right clicking
+/// the header row.
+///
+/// Rows can be expanded in place to reveal a detail [`Element`] below them,
+/// toggled from a gutter in front of the rows.
+///
+/// Each column can have its own [`CellEditor`], like a number editor that
+/// only commits numbers or a dropdown list of options. Content rejected by an
+/// editor is neither committed nor pasted.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::EditableGrid;
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     CellEdited(usize, usize, String),
+/// }
+///
+/// let cells = vec![
+///     vec![String::from("Name"), String::from("Amount")],
+///     vec![String::from("Apples"), String::from("3")],
+/// ];
+///
+/// let grid = EditableGrid::new(&cells, Message::CellEdited).frozen(1, 0);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct EditableGrid<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The rows of cells.
+    cells: &'a [Vec<String>],
+    /// The function producing the message when a cell is edited.
+    on_edit: Box<dyn Fn(usize, usize, String) -> Message + 'a>,
+    /// The displayed columns, as indices of the data columns.
+    visible_columns: Vec<usize>,
+    /// The layout of the columns.
+    column_layout: Option<ColumnLayout>,
+    /// The function producing the message when the layout of the columns changes.
+    on_layout_change: Option<Box<dyn Fn(ColumnLayout) -> Message + 'a>>,
+    /// The details of the expanded rows, in row order.
+    details: Vec<(usize, Element<'a, Message, Renderer>)>,
+    /// The function producing the message when the expanded rows change.
+    on_expand: Option<Box<dyn Fn(Vec<usize>) -> Message + 'a>>,
+    /// The function formatting the copied cells.
+    copy_format: Option<CopyFn<'a>>,
+    /// How many rows can be expanded at once.
+    expansion: RowExpansion,
+    /// The number of frozen leading rows.
+    frozen_rows: usize,
+    /// The number of frozen leading columns.
+    frozen_columns: usize,
+    /// The editors of the columns, indexed by data column.
+    column_editors: Vec<CellEditor>,
+    /// The widths of the columns.
+    column_widths: Vec<f32>,
+    /// The width of the columns without a width in `column_widths`.
+    default_column_width: f32,
+    /// The offsets of the displayed columns in the content, followed by the
+    /// width of all columns.
+    column_offsets: Vec<f32>,
+    /// The height of a row.
+    row_height: f32,
+    /// The width of the [`EditableGrid`].
+    width: Length,
+    /// The height of the [`EditableGrid`].
+    height: Length,
+    /// The horizontal padding inside of the cells.
+    padding: f32,
+    /// The text size of the cells.
+    text_size: f32,
+    /// The font of the cells.
+    font: Renderer::Font,
+    /// The style of the [`EditableGrid`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> EditableGrid<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`EditableGrid`] of the given rows of cells, producing
+    /// the message of `on_edit` with the row, the column and the new content
+    /// of an edited cell.
+    pub fn new<F>(cells: &'a [Vec<String>], on_edit: F) -> Self
+    where
+        F: 'a + Fn(usize, usize, String) -> Message,
+    {
+        let mut grid = Self {
+            cells,
+            on_edit: Box::new(on_edit),
+            visible_columns: (0..cells.iter().map(Vec::len).max().unwrap_or(0)).collect(),
+            column_layout: None,
+            on_layout_change: None,
+            details: Vec::new(),
+            on_expand: None,
+            copy_format: None,
+            expansion: RowExpansion::default(),
+            frozen_rows: 0,
+            frozen_columns: 0,
+            column_editors: Vec::new(),
+            column_widths: Vec::new(),
+            default_column_width: 100.0,
+            column_offsets: Vec::new(),
+            row_height: 28.0,
+            width: Length::Fill,
+            height: Length::Fill,
+            padding: 6.0,
+            text_size: 14.0,
+            font: core::Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        };
+        grid.update_column_offsets();
+        grid
+    }
+
+    /// Freezes the given number of leading rows and columns of the
+    /// [`EditableGrid`] as headers.
+    #[must_use]
+    pub fn frozen(mut self, rows: usize, columns: usize) -> Self {
+        self.frozen_rows = rows;
+        self.frozen_columns = columns;
+        self
+    }
+
+    /// Sets the [`ColumnLayout`] of the [`EditableGrid`] and the function
+    /// producing the message when the user reorders, shows or hides columns.
+    ///
+    /// The frozen columns count the displayed columns.
+    #[must_use]
+    pub fn column_layout<F>(mut self, layout: ColumnLayout, on_change: F) -> Self
+    where
+        F: 'a + Fn(ColumnLayout) -> Message,
+    {
+        self.visible_columns = layout.visible(self.data_columns());
+        self.column_layout = Some(layout);
+        self.on_layout_change = Some(Box::new(on_change));
+        self.update_column_offsets();
+        self
+    }
+
+    /// Sets the details of the given expanded rows of the [`EditableGrid`],
+    /// created by `detail` for each expanded row, and the function producing
+    /// the message with the new expanded rows when the user toggles a row.
+    ///
+    /// The frozen header rows cannot be expanded.
+    #[must_use]
+    pub fn details<F, G>(mut self, expanded: &[usize], detail: F, on_expand: G) -> Self
+    where
+        F: Fn(usize) -> Element<'a, Message, Renderer>,
+        G: 'a + Fn(Vec<usize>) -> Message,
+    {
+        let mut expanded = expanded.to_vec();
+        expanded.sort_unstable();
+        expanded.dedup();
+
+        self.details = expanded
+            .into_iter()
+            .filter(|row| *row < self.rows())
+            .map(|row| (row, detail(row)))
+            .collect();
+        self.on_expand = Some(Box::new(on_expand));
+        self.update_column_offsets();
+        self
+    }
+
+    /// Sets how many rows of the [`EditableGrid`] can be expanded at once.
+    #[must_use]
+    pub fn expansion(mut self, expansion: RowExpansion) -> Self {
+        self.expansion = expansion;
+        self
+    }
+
+    /// Sets the [`CellEditor`] of the given data column of the [`EditableGrid`].
+    ///
+    /// Columns without an editor are edited as text.
+    #[must_use]
+    pub fn column_editor(mut self, column: usize, editor: CellEditor) -> Self {
+        if self.column_editors.len() <= column {
+            self.column_editors
+                .resize(column + 1, CellEditor::default());
+        }
+        self.column_editors[column] = editor;
+        self
+    }
+
+    /// Sets the widths of the columns of the [`EditableGrid`].
+    ///
+    /// The widths are indexed by data column, and columns without a width get
+    /// the default column width.
+    #[must_use]
+    pub fn column_widths(mut self, widths: impl IntoIterator<Item = f32>) -> Self {
+        self.column_widths = widths.into_iter().collect();
+        self.update_column_offsets();
+        self
+    }
+
+    /// Sets the default width of the columns of the [`EditableGrid`].
+    #[must_use]
+    pub fn default_column_width(mut self, width: f32) -> Self {
+        self.default_column_width = width;
+        self.update_column_offsets();
+        self
+    }
+
+    /// Sets the height of the rows of the [`EditableGrid`].
+    #[must_use]
+    pub fn row_height(mut self, row_height: f32) -> Self {
+        self.row_height = row_height;
+        self
+    }
+
+    /// Sets the width of the [`EditableGrid`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`EditableGrid`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the horizontal padding inside of the cells of the [`EditableGrid`].
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the cells of the [`EditableGrid`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the cells of the [`EditableGrid`].
+    #[must_use]
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the function formatting the copied cells, given row by row.
+    ///
+    /// By default, the cells are copied as tab-separated values, which can
+    /// be pasted into spreadsheets.
+    #[must_use]
+    pub fn copy_with<F>(mut self, format: F) -> Self
+    where
+        F: 'a + Fn(&[Vec<&str>]) -> String,
+    {
+        self.copy_format = Some(Box::new(format));
+        self
+    }
+
+    /// Sets the style of the [`EditableGrid`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the number of rows.
+    fn rows(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns the number of columns of the widest row.
+    fn data_columns(&self) -> usize {
+        self.cells.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    /// Returns the number of displayed columns.
+    fn columns(&self) -> usize {
+        self.visible_columns.len()
+    }
+
+    /// Returns the content of the given cell in the given displayed column.
+    fn cell(&self, (row, column): (usize, usize)) -> &str {
+        self.cells
+            .get(row)
+            .zip(self.visible_columns.get(column))
+            .and_then(|(cells, column)| cells.get(*column))
+            .map_or("", String::as_str)
+    }
+
+    /// Returns the message for the given new content of the given cell in
+    /// the given displayed column.
+    fn edit(&self, (row, column): (usize, usize), value: String) -> Message {
+        (self.on_edit)(row, self.visible_columns[column], value)
+    }
+
+    /// Returns the [`CellEditor`] of the given displayed column.
+    fn column_editor_of(&self, column: usize) -> &CellEditor {
+        self.visible_columns
+            .get(column)
+            .and_then(|column| self.column_editors.get(*column))
+            .unwrap_or(&TEXT_EDITOR)
+    }
+
+    /// Starts editing the given cell with the given content.
+    fn start_editing(&self, state: &mut State, cell: (usize, usize), value: String) {
+        state.anchor = cell;
+        state.focus = Some(cell);
+
+        let mut editor = Editor::new(cell, value);
+        if let CellEditor::Choice(options) = self.column_editor_of(cell.1) {
+            editor.choose(
+                options,
+                options
+                    .iter()
+                    .position(|option| *option == editor.value)
+                    .unwrap_or(0),
+            );
+        }
+
+        state.editor = Some(editor);
+    }
+
+    /// Returns the width of the given displayed column.
+    fn column_width(&self, column: usize) -> f32 {
+        self.column_widths
+            .get(self.visible_columns[column])
+            .copied()
+            .unwrap_or(self.default_column_width)
+    }
+
+    /// Returns the name of the given data column, as shown in the column menu.
+    fn column_name(&self, column: usize) -> String {
+        self.cells
+            .first()
+            .filter(|_| self.frozen_rows > 0)
+            .and_then(|header| header.get(column))
+            .filter(|name| !name.is_empty())
+            .map_or_else(|| i18n::get().column(column), Clone::clone)
+    }
+
+    /// Computes the offsets of the displayed columns, so that wide grids do
+    /// not have to sum up the column widths for every cell.
+    fn update_column_offsets(&mut self) {
+        let gutter = self.gutter_width();
+        let mut offset = gutter;
+        self.column_offsets = std::iter::once(gutter)
+            .chain((0..self.columns()).map(|column| {
+                offset += self.column_width(column);
+                offset
+            }))
+            .collect();
+    }
+
+    /// Returns the width of the gutter holding the toggles of the detail rows.
+    fn gutter_width(&self) -> f32 {
+        if self.on_expand.is_some() {
+            GUTTER_WIDTH
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the horizontal offset of the given displayed column in the content.
+    fn column_x(&self, column: usize) -> f32 {
+        self.column_offsets[column.min(self.columns())]
+    }
+
+    /// Returns the displayed column at the given horizontal offset in the content.
+    fn column_at(&self, x: f32) -> Option<usize> {
+        let index = self.column_offsets.partition_point(|offset| *offset <= x);
+
+        (1..=self.columns()).contains(&index).then_some(index - 1)
+    }
+
+    /// Returns the displayed columns overlapping the given horizontal range
+    /// of the content.
+    fn column_range(&self, left: f32, right: f32) -> Range<usize> {
+        let start = self
+            .column_offsets
+            .partition_point(|offset| *offset <= left)
+            .saturating_sub(1);
+        let end = self
+            .column_offsets
+            .partition_point(|offset| *offset < right)
+            .min(self.columns());
+
+        start..end
+    }
+
+    /// Returns the rows overlapping the given vertical range of the content.
+    fn row_range(&self, view: &View, top: f32, bottom: f32) -> Range<usize> {
+        let start = self.row_at(view, top.max(0.0));
+        let end = self.row_at(view, bottom.max(0.0)) + 1;
+
+        start.min(self.rows())..end.min(self.rows())
+    }
+
+    /// Returns the row at the given vertical offset in the content, including
+    /// the area of its details.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn row_at(&self, view: &View, y: f32) -> usize {
+        let mut details = 0.0;
+
+        for (row, height) in &view.details {
+            let top = self.row_y(&View::default(), row + 1) + details;
+            if y < top {
+                break;
+            }
+            if y < top + height {
+                return *row;
+            }
+            details += height;
+        }
+
+        ((y - details) / self.row_height) as usize
+    }
+
+    /// Returns the vertical offset of the given row in the content.
+    #[allow(clippy::cast_precision_loss)]
+    fn row_y(&self, view: &View, row: usize) -> f32 {
+        let details: f32 = view
+            .details
+            .iter()
+            .take_while(|(expanded, _)| *expanded < row)
+            .map(|(_, height)| height)
+            .sum();
+
+        row as f32 * self.row_height + details
+    }
+
+    /// Returns the size of all cells.
+    fn content_size(&self, view: &View) -> Size {
+        Size::new(self.column_x(self.columns()), self.row_y(view, self.rows()))
+    }
+
+    /// Returns the size of the frozen header cells.
+    fn frozen_size(&self) -> Size {
+        Size::new(
+            self.column_x(self.frozen_columns.min(self.columns())),
+            self.row_y(&View::default(), self.frozen_rows.min(self.rows())),
+        )
+    }
+
+    /// Returns the current [`View`] of the [`EditableGrid`].
+    fn view(&self, state: &State, layout: Layout<'_>) -> View {
+        View {
+            scroll: state.scroll,
+            details: self
+                .details
+                .iter()
+                .zip(layout.children())
+                .filter(|((row, _), _)| *row >= self.frozen_rows)
+                .map(|((row, _), layout)| {
+                    let progress = state.expanded.get(row).copied().unwrap_or(0.0);
+                    (*row, layout.bounds().height * progress)
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the bounds of the details of the given expanded row on screen.
+    fn detail_bounds(&self, bounds: Rectangle, view: &View, row: usize) -> Rectangle {
+        let height = view
+            .details
+            .iter()
+            .find(|(expanded, _)| *expanded == row)
+            .map_or(0.0, |(_, height)| *height);
+
+        Rectangle {
+            x: bounds.x,
+            y: bounds.y + self.row_y(view, row) + self.row_height - view.scroll.y,
+            width: bounds.width,
+            height,
+        }
+    }
+
+    /// Returns the maximum scroll offset for the given bounds.
+    fn max_scroll(&self, bounds: Rectangle, view: &View) -> Vector {
+        let content = self.content_size(view);
+
+        Vector::new(
+            (content.width - bounds.width).max(0.0),
+            (content.height - bounds.height).max(0.0),
+        )
+    }
+
+    /// Returns the bounds of the given cell on screen.
+    fn cell_bounds(
+        &self,
+        bounds: Rectangle,
+        view: &View,
+        (row, column): (usize, usize),
+    ) -> Rectangle {
+        let scroll_x = if column < self.frozen_columns {
+            0.0
+        } else {
+            view.scroll.x
+        };
+        let scroll_y = if row < self.frozen_rows {
+            0.0
+        } else {
+            view.scroll.y
+        };
+
+        Rectangle {
+            x: bounds.x + self.column_x(column) - scroll_x,
+            y: bounds.y + self.row_y(view, row) - scroll_y,
+            width: self.column_width(column),
+            height: self.row_height,
+        }
+    }
+
+    /// Returns the row at the given position on screen, if the position is
+    /// not on its details.
+    fn row_at_position(&self, bounds: Rectangle, view: &View, position: Point) -> Option<usize> {
+        if !bounds.contains(position) {
+            return None;
+        }
+
+        let y = position.y - bounds.y;
+        let y = if y < self.frozen_size().height {
+            y
+        } else {
+            y + view.scroll.y
+        };
+        let row = self.row_at(view, y);
+
+        (row < self.rows() && y < self.row_y(view, row) + self.row_height).then_some(row)
+    }
+
+    /// Returns the cell at the given position on screen.
+    fn cell_at(&self, bounds: Rectangle, view: &View, position: Point) -> Option<(usize, usize)> {
+        let row = self.row_at_position(bounds, view, position)?;
+
+        let x = position.x - bounds.x;
+        let x = if x < self.frozen_size().width {
+            x
+        } else {
+            x + view.scroll.x
+        };
+
+        Some((row, self.column_at(x)?))
+    }
+
+    /// Scrolls the given cell into the view.
+    fn scroll_to(
+        &self,
+        state: &mut State,
+        bounds: Rectangle,
+        view: &View,
+        (row, column): (usize, usize),
+    ) {
+        let frozen = self.frozen_size();
+
+        if column >= self.frozen_columns {
+            let left = self.column_x(column);
+            let right = left + self.column_width(column);
+
+            if left - state.scroll.x < frozen.width {
+                state.scroll.x = left - frozen.width;
+            } else if right - state.scroll.x > bounds.width {
+                state.scroll.x = right - bounds.width;
+            }
+        }
+
+        if row >= self.frozen_rows {
+            let top = self.row_y(view, row);
+            let bottom = top + self.row_height;
+
+            if top - state.scroll.y < frozen.height {
+                state.scroll.y = top - frozen.height;
+            } else if bottom - state.scroll.y > bounds.height {
+                state.scroll.y = bottom - bounds.height;
+            }
+        }
+
+        let max = self.max_scroll(bounds, view);
+        state.scroll.x = state.scroll.x.clamp(0.0, max.x);
+        state.scroll.y = state.scroll.y.clamp(0.0, max.y);
+    }
+
+    /// Moves the focused cell by the given number of rows and columns,
+    /// extending the selection if `extend` is set.
+    fn move_focus(
+        &self,
+        state: &mut State,
+        bounds: Rectangle,
+        view: &View,
+        rows: isize,
+        columns: isize,
+        extend: bool,
+    ) {
+        let Some((row, column)) = state.focus else {
+            return;
+        };
+
+        let focus = (
+            row.saturating_add_signed(rows)
+                .min(self.rows().saturating_sub(1)),
+            column
+                .saturating_add_signed(columns)
+                .min(self.columns().saturating_sub(1)),
+        );
+
+        state.focus = Some(focus);
+        if !extend {
+            state.anchor = focus;
+        }
+        self.scroll_to(state, bounds, view, focus);
+    }
+
+    /// Returns the visible part of the details of the given row on screen,
+    /// the translation from their layout to the screen and the cursor
+    /// inside of them.
+    fn detail_view(
+        &self,
+        bounds: Rectangle,
+        view: &View,
+        row: usize,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) -> Option<(Rectangle, Vector, Cursor)> {
+        if row < self.frozen_rows {
+            return None;
+        }
+
+        let frozen = self.frozen_size();
+        let detail = self.detail_bounds(bounds, view, row);
+        let scrolled = Rectangle {
+            y: bounds.y + frozen.height,
+            height: bounds.height - frozen.height,
+            ..bounds
+        };
+        let clip = detail
+            .intersection(&scrolled)
+            .filter(|clip| clip.height > 0.0)?;
+
+        let translation = Vector::new(0.0, detail.y - layout.bounds().y);
+        let cursor = match cursor.position() {
+            Some(position) if clip.contains(position) => Cursor::Available(position - translation),
+            _ => Cursor::Unavailable,
+        };
+
+        Some((clip, translation, cursor))
+    }
+
+    /// Advances the animation revealing the details of the expanded rows.
+    ///
+    /// Returns true if the animation has not finished yet.
+    fn animate(&self, state: &mut State, now: Instant) -> bool {
+        let step = state.last_update.map_or(0.0, |last_update| {
+            (now - last_update).as_secs_f32() / EXPAND_DURATION.as_secs_f32()
+        });
+
+        state
+            .expanded
+            .retain(|row, _| self.details.iter().any(|(expanded, _)| expanded == row));
+
+        let mut animating = false;
+        for (row, _) in &self.details {
+            let progress = state.expanded.entry(*row).or_insert(0.0);
+
+            if *progress < 1.0 {
+                *progress = (*progress + step).min(1.0);
+                animating = true;
+            }
+        }
+
+        state.last_update = animating.then_some(now);
+        animating
+    }
+
+    /// Expands the given row if it is collapsed, or collapses it otherwise.
+    fn toggle_details(&self, row: usize, shell: &mut Shell<'_, Message>) {
+        let Some(on_expand) = &self.on_expand else {
+            return;
+        };
+
+        let mut expanded: Vec<usize> = self.details.iter().map(|(row, _)| *row).collect();
+
+        if let Some(index) = expanded.iter().position(|expanded| *expanded == row) {
+            let _ = expanded.remove(index);
+        } else if self.expansion == RowExpansion::Single {
+            expanded = vec![row];
+        } else {
+            expanded.push(row);
+            expanded.sort_unstable();
+        }
+
+        shell.publish(on_expand(expanded));
+    }
+
+    /// Publishes the content of the edited cell, if it changed, and stops editing.
+    fn commit(&self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        if let Some(editor) = state.editor.take() {
+            if editor.value != self.cell(editor.cell)
+                && self.column_editor_of(editor.cell.1).accepts(&editor.value)
+            {
+                shell.publish(self.edit(editor.cell, editor.value));
+            }
+        }
+    }
+
+    /// Returns the selected cells formatted for the clipboard.
+    fn copy(&self, selection: Selection) -> String {
+        let rows: Vec<Vec<&str>> = (selection.top..=selection.bottom)
+            .map(|row| {
+                (selection.left..=selection.right)
+                    .map(|column| self.cell((row, column)))
+                    .collect()
+            })
+            .collect();
+
+        self.copy_format
+            .as_ref()
+            .map_or_else(|| tsv::format(&rows), |format| format(&rows))
+    }
+
+    /// Clears the content of the selected cells.
+    fn clear(&self, selection: Selection, shell: &mut Shell<'_, Message>) {
+        for row in selection.top..=selection.bottom {
+            for column in selection.left..=selection.right {
+                if !self.cell((row, column)).is_empty() {
+                    shell.publish(self.edit((row, column), String::new()));
+                }
+            }
+        }
+    }
+
+    /// Pastes the tab-separated values starting at the top left corner of the
+    /// selection, and selects the pasted cells.
+    fn paste(&self, state: &mut State, text: &str, shell: &mut Shell<'_, Message>) {
+        let Some(selection) = state.selection() else {
+            return;
+        };
+
+        let block = tsv::parse(text);
+        let mut end = (selection.top, selection.left);
+
+        for (row, cells) in (selection.top..self.rows()).zip(block) {
+            for (column, value) in (selection.left..self.columns()).zip(cells) {
+                end = (end.0.max(row), end.1.max(column));
+
+                if value != self.cell((row, column))
+                    && self.column_editor_of(column).accepts(&value)
+                {
+                    shell.publish(self.edit((row, column), value));
+                }
+            }
+        }
+
+        state.anchor = (selection.top, selection.left);
+        state.focus = Some(end);
+    }
+
+    /// Returns the [`Caret`] of the editor of the cell with the given bounds.
+    fn caret(&self, renderer: &Renderer, editor: &Editor, bounds: Rectangle) -> Caret {
+        let offset = renderer.measure_width(
+            &editor.value[..editor.cursor],
+            self.text_size,
+            self.font,
+            text::Shaping::Basic,
+        );
+        let height = self.text_size * 1.2;
+
+        Caret::new(
+            Point::new(
+                (bounds.x + self.padding + offset).min(bounds.x + bounds.width - 2.0),
+                bounds.y + (bounds.height - height) / 2.0,
+            ),
+            height,
+        )
+    }
+
+    /// Draws the gutter of the given row with the toggle of its details.
+    fn draw_gutter(
+        &self,
+        renderer: &mut Renderer,
+        appearance: &crate::style::editable_grid::Appearance,
+        bounds: Rectangle,
+        view: &View,
+        row: usize,
+    ) {
+        let width = self.gutter_width();
+        if width <= 0.0 {
+            return;
+        }
+
+        let scroll_y = if row < self.frozen_rows {
+            0.0
+        } else {
+            view.scroll.y
+        };
+        let gutter = Rectangle {
+            x: bounds.x,
+            y: bounds.y + self.row_y(view, row) - scroll_y,
+            width,
+            height: self.row_height,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: gutter,
+                border_radius: (0.0).into(),
+                border_width: 0.5,
+                border_color: appearance.grid_line_color,
+            },
+            appearance.header_background,
+        );
+
+        if row < self.frozen_rows {
+            return;
+        }
+
+        let expanded = self.details.iter().any(|(expanded, _)| *expanded == row);
+        renderer.fill_text(core::text::Text {
+            content: &char::from(if expanded {
+                Icon::CaretDownFill
+            } else {
+                Icon::CaretRightFill
+            })
+            .to_string(),
+            bounds: Rectangle {
+                x: gutter.center_x(),
+                y: gutter.center_y(),
+                ..gutter
+            },
+            size: self.text_size * 0.8,
+            line_height: LineHeight::default(),
+            color: appearance.header_text_color,
+            font: ICON_FONT,
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+            shaping: text::Shaping::Basic,
+        });
+    }
+
+    /// Draws the given cell.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_cell(
+        &self,
+        renderer: &mut Renderer,
+        appearance: &crate::style::editable_grid::Appearance,
+        state: &State,
+        cell: (usize, usize),
+        bounds: Rectangle,
+        header: bool,
+        selected: bool,
+    ) {
+        let editor = state.editor.as_ref().filter(|editor| editor.cell == cell);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: 0.5,
+                border_color: appearance.grid_line_color,
+            },
+            if editor.is_some() {
+                appearance.editor_background
+            } else if header {
+                appearance.header_background
+            } else {
+                appearance.background
+            },
+        );
+
+        if selected && editor.is_none() {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: (0.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.selection_background,
+            );
+        }
+
+        if state.focus == Some(cell) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: (0.0).into(),
+                    border_width: 2.0,
+                    border_color: appearance.focus_border_color,
+                },
+                Color::TRANSPARENT,
+            );
+        }
+
+        let content = editor.map_or_else(|| self.cell(cell), |editor| editor.value.as_str());
+        let text_bounds = Rectangle {
+            x: bounds.x + self.padding,
+            y: bounds.center_y(),
+            width: (bounds.width - self.padding * 2.0).max(0.0),
+            height: bounds.height,
+        };
+
+        let draw_text = |renderer: &mut Renderer| {
+            renderer.fill_text(core::text::Text {
+                content,
+                bounds: text_bounds,
+                size: self.text_size,
+                line_height: LineHeight::default(),
+                color: if header {
+                    appearance.header_text_color
+                } else {
+                    appearance.text_color
+                },
+                font: self.font,
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Basic,
+            });
+        };
+
+        // Long content is clipped to its cell.
+        let width =
+            renderer.measure_width(content, self.text_size, self.font, text::Shaping::Basic);
+        if width > text_bounds.width {
+            renderer.with_layer(bounds, draw_text);
+        } else {
+            draw_text(renderer);
+        }
+
+        if editor.is_some_and(|editor| editor.choice.is_some()) {
+            renderer.fill_text(core::text::Text {
+                content: &char::from(Icon::CaretDownFill).to_string(),
+                bounds: Rectangle {
+                    x: bounds.x + bounds.width - self.padding,
+                    ..text_bounds
+                },
+                size: self.text_size * 0.8,
+                line_height: LineHeight::default(),
+                color: appearance.text_color,
+                font: ICON_FONT,
+                horizontal_alignment: Horizontal::Right,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Basic,
+            });
+        } else if let Some(editor) = editor {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: self.caret(renderer, editor, bounds).bounds,
+                    border_radius: (0.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.cursor_color,
+            );
+        }
+
+        if let Some(editor) = editor {
+            if !self.column_editor_of(cell.1).accepts(&editor.value) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: (0.0).into(),
+                        border_width: 2.0,
+                        border_color: appearance.invalid_border_color,
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+        }
+    }
+}
+
+/// The state of an [`EditableGrid`].
+#[derive(Clone, Debug, Default)]
+pub(crate) struct State {
+    /// The position of the opened column menu.
+    pub menu: Option<Point>,
+    /// The dragged header of a column being moved.
+    column_drag: Option<ColumnDrag>,
+    /// Whether the [`EditableGrid`] receives the keyboard input.
+    is_focused: bool,
+    /// The cell the selection started at.
+    anchor: (usize, usize),
+    /// The focused cell, at the other corner of the selection.
+    focus: Option<(usize, usize)>,
+    /// Whether a selection is being dragged.
+    selecting: bool,
+    /// The editor of the cell being edited.
+    pub editor: Option<Editor>,
+    /// The scroll offset of the cells that are not frozen.
+    scroll: Vector,
+    /// The kinetic scrolling of the cells by touch.
+    kinetic: Kinetic,
+    /// How far the details of the expanded rows have been revealed, from 0.0 to 1.0.
+    expanded: HashMap<usize, f32>,
+    /// The time of the last step of the expand animation.
+    last_update: Option<Instant>,
+    /// The pressed keyboard modifiers.
+    modifiers: keyboard::Modifiers,
+    /// The last click, for detecting double clicks.
+    last_click: Option<click::Click>,
+}
+
+impl State {
+    /// Returns the selected range of cells.
+    fn selection(&self) -> Option<Selection> {
+        let focus = self.focus?;
+
+        Some(Selection {
+            top: self.anchor.0.min(focus.0),
+            left: self.anchor.1.min(focus.1),
+            bottom: self.anchor.0.max(focus.0),
+            right: self.anchor.1.max(focus.1),
+        })
+    }
+}
+
+/// The scroll offset and the heights of the details of an [`EditableGrid`],
+/// which lay out its rows.
+#[derive(Clone, Debug, Default)]
+struct View {
+    /// The scroll offset of the cells that are not frozen.
+    scroll: Vector,
+    /// The expanded rows with the current height of their details, in row order.
+    details: Vec<(usize, f32)>,
+}
+
+/// The dragged header of a column of an [`EditableGrid`].
+#[derive(Clone, Copy, Debug)]
+struct ColumnDrag {
+    /// The displayed column being dragged.
+    column: usize,
+    /// The horizontal position the drag started at.
+    origin: f32,
+    /// The displayed column the dragged column would be moved to.
+    target: Option<usize>,
+}
+
+/// A rectangular range of selected cells.
+#[derive(Clone, Copy, Debug)]
+struct Selection {
+    /// The first selected row.
+    top: usize,
+    /// The first selected column.
+    left: usize,
+    /// The last selected row.
+    bottom: usize,
+    /// The last selected column.
+    right: usize,
+}
+
+impl Selection {
+    /// Returns `true` if the given cell is selected.
+    fn contains(self, (row, column): (usize, usize)) -> bool {
+        (self.top..=self.bottom).contains(&row) && (self.left..=self.right).contains(&column)
+    }
+}
+
+/// The in place editor of a cell of an [`EditableGrid`].
+#[derive(Clone, Debug)]
+pub(crate) struct Editor {
+    /// The cell being edited.
+    cell: (usize, usize),
+    /// The edited content.
+    value: String,
+    /// The byte index of the text cursor.
+    cursor: usize,
+    /// The highlighted option of a [`CellEditor::Choice`].
+    pub choice: Option<usize>,
+}
+
+impl Editor {
+    /// Creates a new [`Editor`] for the given cell with the cursor at the end.
+    fn new(cell: (usize, usize), value: String) -> Self {
+        Self {
+            cell,
+            cursor: value.len(),
+            value,
+            choice: None,
+        }
+    }
+
+    /// Highlights the option at the given index as the edited content.
+    fn choose(&mut self, options: &[String], index: usize) {
+        if let Some(option) = options.get(index) {
+            self.value.clone_from(option);
+            self.cursor = self.value.len();
+            self.choice = Some(index);
+        }
+    }
+
+    /// Returns the byte index of the character before the cursor.
+    fn previous(&self) -> usize {
+        self.value[..self.cursor]
+            .char_indices()
+            .next_back()
+            .map_or(0, |(index, _)| index)
+    }
+
+    /// Returns the byte index of the character after the cursor.
+    fn next(&self) -> usize {
+        self.value[self.cursor..]
+            .chars()
+            .next()
+            .map_or(self.cursor, |c| self.cursor + c.len_utf8())
+    }
+}
+
+/// Returns the index of the first option starting with the given character,
+/// ignoring the case.
+fn find_option(options: &[String], c: char) -> Option<usize> {
+    options.iter().position(|option| {
+        option
+            .chars()
+            .next()
+            .is_some_and(|first| first.to_lowercase().eq(c.to_lowercase()))
+    })
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for EditableGrid<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.details
+            .iter()
+            .map(|(_, detail)| Tree::new(detail))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(
+            &self
+                .details
+                .iter()
+                .map(|(_, detail)| detail)
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        // The details span the width of the grid.
+        let width = limits
+            .resolve(Size::new(self.column_x(self.columns()), 0.0))
+            .width;
+        let details: Vec<Node> = self
+            .details
+            .iter()
+            .map(|(_, detail)| {
+                detail.as_widget().layout(
+                    renderer,
+                    &Limits::new(Size::new(width, 0.0), Size::new(width, f32::INFINITY)),
+                )
+            })
+            .collect();
+
+        let view = View {
+            details: self
+                .details
+                .iter()
+                .zip(&details)
+                .filter(|((row, _), _)| *row >= self.frozen_rows)
+                .map(|((row, _), node)| (*row, node.size().height))
+                .collect(),
+            ..View::default()
+        };
+
+        Node::with_children(limits.resolve(self.content_size(&view)), details)
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let view = self.view(state, layout);
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let max = self.max_scroll(bounds, &view);
+            let scrolling = state.kinetic.tick(max, now);
+            if scrolling {
+                state.scroll = state.kinetic.offset();
+            }
+
+            if self.animate(state, now) || scrolling {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+
+        // The details get the events first.
+        let detail_views: Vec<_> = self
+            .details
+            .iter()
+            .zip(layout.children())
+            .map(|((row, _), layout)| self.detail_view(bounds, &view, *row, layout, cursor))
+            .collect();
+
+        for ((((_, detail), tree), layout), detail_view) in self
+            .details
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .zip(detail_views)
+        {
+            let Some((clip, _, cursor)) = detail_view else {
+                continue;
+            };
+
+            if detail.as_widget_mut().on_event(
+                tree,
+                event.clone(),
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                &clip,
+            ) == event::Status::Captured
+            {
+                return event::Status::Captured;
+            }
+        }
+
+        match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right))
+                if self.column_layout.is_some() =>
+            {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                if self
+                    .cell_at(bounds, &view, position)
+                    .is_some_and(|(row, _)| row < self.frozen_rows)
+                {
+                    self.commit(state, shell);
+                    state.menu = Some(position);
+                    return event::Status::Captured;
+                }
+            }
+            Event::Touch(touch::Event::FingerPressed { position, .. })
+                if position.x >= bounds.x + self.gutter_width()
+                    && self
+                        .cell_at(bounds, &view, position)
+                        .is_some_and(|(row, _)| row >= self.frozen_rows) =>
+            {
+                // Touching the cells scrolls them, a tap selects a cell.
+                self.commit(state, shell);
+                state.is_focused = true;
+                state.kinetic.set_offset(state.scroll);
+                state.kinetic.grab(position);
+                return event::Status::Captured;
+            }
+            Event::Touch(touch::Event::FingerMoved { position, .. })
+                if state.kinetic.is_dragging() =>
+            {
+                let max = self.max_scroll(bounds, &view);
+                state.kinetic.drag(position, max, Instant::now());
+                state.scroll = state.kinetic.offset();
+                return event::Status::Captured;
+            }
+            Event::Touch(touch::Event::FingerLifted { position, .. })
+                if state.kinetic.is_dragging() =>
+            {
+                if state.kinetic.release(Instant::now()) {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                } else if let Some(cell) = self.cell_at(bounds, &view, position) {
+                    state.anchor = cell;
+                    state.focus = Some(cell);
+                }
+                return event::Status::Captured;
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) if state.kinetic.is_dragging() => {
+                let _ = state.kinetic.release(Instant::now());
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    self.commit(state, shell);
+                    state.is_focused = false;
+                    return event::Status::Ignored;
+                };
+                state.is_focused = true;
+
+                if position.x < bounds.x + self.gutter_width() {
+                    if let Some(row) = self
+                        .row_at_position(bounds, &view, position)
+                        .filter(|row| *row >= self.frozen_rows)
+                    {
+                        self.commit(state, shell);
+                        self.toggle_details(row, shell);
+                    }
+                    return event::Status::Captured;
+                }
+
+                let Some(cell) = self.cell_at(bounds, &view, position) else {
+                    return event::Status::Captured;
+                };
+
+                if state
+                    .editor
+                    .as_ref()
+                    .is_some_and(|editor| editor.cell == cell)
+                {
+                    return event::Status::Captured;
+                }
+                self.commit(state, shell);
+
+                let click = click::Click::new(position, state.last_click);
+                state.last_click = Some(click);
+
+                if matches!(click.kind(), click::Kind::Double) {
+                    self.start_editing(state, cell, self.cell(cell).to_owned());
+                } else {
+                    if !(state.modifiers.shift() && state.focus.is_some()) {
+                        state.anchor = cell;
+                    }
+                    state.focus = Some(cell);
+                    state.selecting = true;
+
+                    if self.column_layout.is_some() && cell.0 < self.frozen_rows {
+                        state.column_drag = Some(ColumnDrag {
+                            column: cell.1,
+                            origin: position.x,
+                            target: None,
+                        });
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if state.column_drag.is_some() =>
+            {
+                let header = Point::new(position.x, bounds.y + self.row_height / 2.0);
+                let target = self.cell_at(bounds, &view, header);
+
+                if let Some(drag) = &mut state.column_drag {
+                    if drag.target.is_some() || (position.x - drag.origin).abs() > DRAG_THRESHOLD {
+                        drag.target = target.map(|(_, column)| column).or(drag.target);
+                        state.selecting = false;
+                    }
+                }
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if state.column_drag.is_some() =>
+            {
+                state.selecting = false;
+
+                if let (Some(drag), Some(layout), Some(on_change)) = (
+                    state.column_drag.take(),
+                    &self.column_layout,
+                    &self.on_layout_change,
+                ) {
+                    if let Some(target) = drag.target.filter(|target| *target != drag.column) {
+                        let mut layout = layout.clone();
+                        layout.move_column(
+                            self.data_columns(),
+                            self.visible_columns[drag.column],
+                            self.visible_columns[target],
+                        );
+                        shell.publish(on_change(layout));
+
+                        state.anchor = (state.anchor.0, target);
+                        state.focus = state.focus.map(|(row, _)| (row, target));
+                    }
+                }
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if state.selecting =>
+            {
+                if let Some(cell) = self.cell_at(bounds, &view, position) {
+                    state.focus = Some(cell);
+                }
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if state.selecting =>
+            {
+                state.selecting = false;
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => Vector::new(x, y) * SCROLL_STEP,
+                    mouse::ScrollDelta::Pixels { x, y } => Vector::new(x, y),
+                };
+                // Shift turns vertical scrolling into horizontal scrolling.
+                let delta = if state.modifiers.shift() {
+                    Vector::new(delta.y, delta.x)
+                } else {
+                    delta
+                };
+
+                let max = self.max_scroll(bounds, &view);
+                state.scroll = Vector::new(
+                    (state.scroll.x - delta.x).clamp(0.0, max.x),
+                    (state.scroll.y - delta.y).clamp(0.0, max.y),
+                );
+                state.kinetic.set_offset(state.scroll);
+                return event::Status::Captured;
+            }
+            Event::Keyboard(event) if state.is_focused => {
+                if let Some(editor) = &mut state.editor {
+                    let cell_editor = self.column_editor_of(editor.cell.1);
+                    let options = match cell_editor {
+                        CellEditor::Choice(options) => Some(options),
+                        _ => None,
+                    };
+
+                    match event {
+                        keyboard::Event::CharacterReceived(c) if !state.modifiers.command() => {
+                            if let Some(options) = options {
+                                if let Some(index) = find_option(options, c) {
+                                    editor.choose(options, index);
+                                }
+                            } else if cell_editor.accepts_char(c) {
+                                editor.value.insert(editor.cursor, c);
+                                editor.cursor += c.len_utf8();
+                            }
+                        }
+                        keyboard::Event::KeyPressed {
+                            key_code,
+                            modifiers,
+                        } => match key_code {
+                            // Rejected content keeps the editor open.
+                            keyboard::KeyCode::Enter
+                            | keyboard::KeyCode::NumpadEnter
+                            | keyboard::KeyCode::Tab
+                                if !cell_editor.accepts(&editor.value) => {}
+                            keyboard::KeyCode::Enter | keyboard::KeyCode::NumpadEnter => {
+                                self.commit(state, shell);
+                                self.move_focus(state, bounds, &view, 1, 0, false);
+                            }
+                            keyboard::KeyCode::Tab => {
+                                self.commit(state, shell);
+                                let step = if modifiers.shift() { -1 } else { 1 };
+                                self.move_focus(state, bounds, &view, 0, step, false);
+                            }
+                            keyboard::KeyCode::Escape => state.editor = None,
+                            keyboard::KeyCode::Up | keyboard::KeyCode::Down => {
+                                if let (Some(options), Some(choice)) = (options, editor.choice) {
+                                    let index = if key_code == keyboard::KeyCode::Up {
+                                        choice.saturating_sub(1)
+                                    } else {
+                                        (choice + 1).min(options.len().saturating_sub(1))
+                                    };
+                                    editor.choose(options, index);
+                                }
+                            }
+                            _ if options.is_some() => {}
+                            keyboard::KeyCode::Backspace if editor.cursor > 0 => {
+                                let previous = editor.previous();
+                                editor.value.replace_range(previous..editor.cursor, "");
+                                editor.cursor = previous;
+                            }
+                            keyboard::KeyCode::Delete if editor.cursor < editor.value.len() => {
+                                let next = editor.next();
+                                editor.value.replace_range(editor.cursor..next, "");
+                            }
+                            keyboard::KeyCode::Left => editor.cursor = editor.previous(),
+                            keyboard::KeyCode::Right => editor.cursor = editor.next(),
+                            keyboard::KeyCode::Home => editor.cursor = 0,
+                            keyboard::KeyCode::End => editor.cursor = editor.value.len(),
+                            _ => {}
+                        },
+                        _ => {}
+                    }
+
+                    return event::Status::Captured;
+                }
+
+                let Some(selection) = state.selection() else {
+                    return event::Status::Ignored;
+                };
+
+                match event {
+                    keyboard::Event::CharacterReceived(c)
+                        if !c.is_control() && !state.modifiers.command() =>
+                    {
+                        // Typing replaces the content of the focused cell.
+                        if let Some(focus) = state.focus {
+                            let editor = self.column_editor_of(focus.1);
+
+                            if let CellEditor::Choice(options) = editor {
+                                self.start_editing(state, focus, self.cell(focus).to_owned());
+                                if let (Some(editor), Some(index)) =
+                                    (&mut state.editor, find_option(options, c))
+                                {
+                                    editor.choose(options, index);
+                                }
+                            } else if editor.accepts_char(c) {
+                                self.start_editing(state, focus, c.to_string());
+                            }
+                        }
+                    }
+                    keyboard::Event::KeyPressed {
+                        key_code,
+                        modifiers,
+                    } => {
+                        let extend = modifiers.shift();
+
+                        match key_code {
+                            keyboard::KeyCode::Up => {
+                                self.move_focus(state, bounds, &view, -1, 0, extend);
+                            }
+                            keyboard::KeyCode::Down => {
+                                self.move_focus(state, bounds, &view, 1, 0, extend);
+                            }
+                            keyboard::KeyCode::Left => {
+                                self.move_focus(state, bounds, &view, 0, -1, extend);
+                            }
+                            keyboard::KeyCode::Right => {
+                                self.move_focus(state, bounds, &view, 0, 1, extend);
+                            }
+                            keyboard::KeyCode::Enter | keyboard::KeyCode::NumpadEnter => {
+                                self.move_focus(state, bounds, &view, 1, 0, false);
+                            }
+                            keyboard::KeyCode::Tab => {
+                                let step = if modifiers.shift() { -1 } else { 1 };
+                                self.move_focus(state, bounds, &view, 0, step, false);
+                            }
+                            keyboard::KeyCode::F2 => {
+                                if let Some(focus) = state.focus {
+                                    self.start_editing(state, focus, self.cell(focus).to_owned());
+                                }
+                            }
+                            keyboard::KeyCode::Delete | keyboard::KeyCode::Backspace => {
+                                self.clear(selection, shell);
+                            }
+                            keyboard::KeyCode::C if modifiers.command() => {
+                                clipboard.write(self.copy(selection));
+                            }
+                            keyboard::KeyCode::X if modifiers.command() => {
+                                clipboard.write(self.copy(selection));
+                                self.clear(selection, shell);
+                            }
+                            keyboard::KeyCode::V if modifiers.command() => {
+                                if let Some(text) = clipboard.read() {
+                                    self.paste(state, &text, shell);
+                                }
+                            }
+                            keyboard::KeyCode::A if modifiers.command() => {
+                                state.anchor = (0, 0);
+                                state.focus = Some((
+                                    self.rows().saturating_sub(1),
+                                    self.columns().saturating_sub(1),
+                                ));
+                            }
+                            _ => return event::Status::Ignored,
+                        }
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let view = self.view(state, layout);
+
+        let detail_interaction = self
+            .details
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .filter_map(|(((row, detail), tree), layout)| {
+                let (clip, _, cursor) = self.detail_view(bounds, &view, *row, layout, cursor)?;
+
+                Some(
+                    detail
+                        .as_widget()
+                        .mouse_interaction(tree, layout, cursor, &clip, renderer),
+                )
+            })
+            .find(|interaction| *interaction != mouse::Interaction::default());
+
+        if state.column_drag.is_some_and(|drag| drag.target.is_some()) {
+            mouse::Interaction::Grabbing
+        } else if let Some(interaction) = detail_interaction {
+            interaction
+        } else if cursor
+            .position_over(bounds)
+            .is_some_and(|position| position.x < bounds.x + self.gutter_width())
+        {
+            mouse::Interaction::Pointer
+        } else if cursor.is_over(bounds) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let view = self.view(state, layout);
+        let appearance = theme.active(&self.style);
+        let frozen = self.frozen_size();
+        let (rows, columns) = (self.rows(), self.columns());
+        let (frozen_rows, frozen_columns) =
+            (self.frozen_rows.min(rows), self.frozen_columns.min(columns));
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        // The frozen header cells are drawn in their own regions, so that the
+        // scrolled cells slide underneath them.
+        let scrolled = Size::new(
+            (bounds.width - frozen.width).max(0.0),
+            (bounds.height - frozen.height).max(0.0),
+        );
+        // Only the rows and columns inside of the view are drawn, which keeps
+        // large and wide grids responsive.
+        let scrolled_rows = self.row_range(
+            &view,
+            state.scroll.y + frozen.height,
+            state.scroll.y + bounds.height,
+        );
+        let scrolled_columns =
+            self.column_range(state.scroll.x + frozen.width, state.scroll.x + bounds.width);
+        let scrolled_rows = scrolled_rows.start.max(frozen_rows)..scrolled_rows.end;
+        let scrolled_columns = scrolled_columns.start.max(frozen_columns)..scrolled_columns.end;
+
+        let regions = [
+            (
+                Rectangle::new(
+                    Point::new(bounds.x + frozen.width, bounds.y + frozen.height),
+                    scrolled,
+                ),
+                scrolled_rows.clone(),
+                scrolled_columns.clone(),
+                false,
+            ),
+            (
+                Rectangle::new(
+                    Point::new(bounds.x + frozen.width, bounds.y),
+                    Size::new(scrolled.width, frozen.height),
+                ),
+                0..frozen_rows,
+                scrolled_columns,
+                false,
+            ),
+            (
+                Rectangle::new(
+                    Point::new(bounds.x, bounds.y + frozen.height),
+                    Size::new(frozen.width, scrolled.height),
+                ),
+                scrolled_rows,
+                0..frozen_columns,
+                true,
+            ),
+            (
+                Rectangle::new(bounds.position(), frozen),
+                0..frozen_rows,
+                0..frozen_columns,
+                true,
+            ),
+        ];
+
+        let selection = state.selection();
+
+        for (region, region_rows, region_columns, has_gutter) in regions {
+            if region.width <= 0.0 || region.height <= 0.0 {
+                continue;
+            }
+
+            renderer.with_layer(region, |renderer| {
+                for row in region_rows {
+                    if has_gutter {
+                        self.draw_gutter(renderer, &appearance, bounds, &view, row);
+                    }
+
+                    for column in region_columns.clone() {
+                        let cell = (row, column);
+                        let cell_bounds = self.cell_bounds(bounds, &view, cell);
+
+                        self.draw_cell(
+                            renderer,
+                            &appearance,
+                            state,
+                            cell,
+                            cell_bounds,
+                            row < frozen_rows || column < frozen_columns,
+                            selection.is_some_and(|selection| selection.contains(cell)),
+                        );
+                    }
+                }
+            });
+        }
+
+        for (((row, detail), tree), layout) in self
+            .details
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            let Some((clip, translation, cursor)) =
+                self.detail_view(bounds, &view, *row, layout, cursor)
+            else {
+                continue;
+            };
+
+            renderer.with_layer(clip, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: self.detail_bounds(bounds, &view, *row),
+                        border_radius: (0.0).into(),
+                        border_width: 0.5,
+                        border_color: appearance.grid_line_color,
+                    },
+                    appearance.background,
+                );
+
+                renderer.with_translation(translation, |renderer| {
+                    detail.as_widget().draw(
+                        tree,
+                        renderer,
+                        theme,
+                        style,
+                        layout,
+                        cursor,
+                        &Rectangle {
+                            y: clip.y - translation.y,
+                            ..clip
+                        },
+                    );
+                });
+            });
+        }
+
+        // The position a dragged column would be moved to.
+        if let Some(drag) = state.column_drag {
+            if let Some(target) = drag.target.filter(|target| *target != drag.column) {
+                let target_bounds = self.cell_bounds(bounds, &view, (0, target));
+                let x = if target < drag.column {
+                    target_bounds.x
+                } else {
+                    target_bounds.x + target_bounds.width
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: x.clamp(bounds.x, bounds.x + bounds.width) - 1.0,
+                            y: bounds.y,
+                            width: 2.0,
+                            height: bounds.height,
+                        },
+                        border_radius: (0.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.focus_border_color,
+                );
+            }
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        // The caret of the edited cell is reported for the IME.
+        if let (true, Some(editor)) = (state.is_focused, &state.editor) {
+            let view = self.view(state, layout);
+            let bounds = self.cell_bounds(layout.bounds(), &view, editor.cell);
+
+            self.caret(renderer, editor, bounds).report(operation);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        let Some(position) = state.menu else {
+            let cell = state.editor.as_ref()?.cell;
+            let CellEditor::Choice(options) = self.column_editor_of(cell.1) else {
+                return None;
+            };
+            let bounds = self.cell_bounds(layout.bounds(), &self.view(state, layout), cell);
+            let grid: &'b Self = self;
+
+            return Some(
+                ChoiceList::new(
+                    state,
+                    options,
+                    Box::new(move |value| grid.edit(cell, value)),
+                    bounds,
+                    self.row_height,
+                    self.text_size,
+                    self.font,
+                    self.style.clone(),
+                )
+                .overlay(),
+            );
+        };
+
+        let layout = self.column_layout.as_ref()?;
+        let on_change = self.on_layout_change.as_ref()?;
+        let names = (0..self.data_columns())
+            .map(|column| self.column_name(column))
+            .collect();
+
+        Some(
+            ColumnMenu::new(
+                state,
+                layout,
+                names,
+                on_change.as_ref(),
+                position,
+                self.row_height,
+                self.text_size,
+                self.font,
+                self.style.clone(),
+            )
+            .overlay(),
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<EditableGrid<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(grid: EditableGrid<'a, Message, Renderer>) -> Self {
+        Element::new(grid)
+    }
+}
@@ -0,0 +1,511 @@
+//! Use an equalizer to edit the gains of frequency bands.
+//!
+//! *This API requires the following crate features to be activated: `equalizer`*
+use crate::style::equalizer::StyleSheet;
+
+use iced_widget::{
+    canvas::{self, Path, Stroke},
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer,
+        text::Renderer as _,
+        touch,
+        widget::{
+            tree::{State as TreeState, Tag},
+            Tree,
+        },
+        Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Renderer as _, Shell,
+        Size, Vector, Widget,
+    },
+    graphics::geometry::Renderer as _,
+    renderer::Renderer,
+    text::LineHeight,
+};
+
+/// The width of the rail of a band slider.
+const RAIL_WIDTH: f32 = 4.0;
+/// The radius of the handle of a band slider.
+const HANDLE_RADIUS: f32 = 6.0;
+
+/// A frequency band of an [`Equalizer`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Band {
+    /// The center frequency of the band in Hz.
+    pub frequency: f32,
+    /// The gain of the band in dB.
+    pub gain: f32,
+}
+
+impl Band {
+    /// Creates a new [`Band`] at the given frequency in Hz with the given gain in dB.
+    #[must_use]
+    pub const fn new(frequency: f32, gain: f32) -> Self {
+        Self { frequency, gain }
+    }
+}
+
+/// An editor for the gains of the frequency bands of an equalizer.
+///
+/// Every band has a vertical gain slider, and a response curve is drawn
+/// through the gains of all bands. Dragging across the bands sketches a
+/// curve by setting the gains of all bands passed over.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{Band, Equalizer};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     GainChanged(usize, f32),
+/// }
+///
+/// let bands = vec![Band::new(100.0, 0.0), Band::new(1000.0, 3.0), Band::new(10000.0, -2.0)];
+///
+/// let equalizer = Equalizer::new(&bands, Message::GainChanged);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Equalizer<'a, Message, Theme = iced_widget::style::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The frequency bands.
+    bands: &'a [Band],
+    /// The function producing the message when the gain of a band changes.
+    on_change: Box<dyn Fn(usize, f32) -> Message + 'a>,
+    /// The range of the gains in dB.
+    range: (f32, f32),
+    /// The width of the [`Equalizer`].
+    width: Length,
+    /// The height of the [`Equalizer`].
+    height: f32,
+    /// The text size of the frequency labels.
+    text_size: f32,
+    /// The style of the [`Equalizer`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> Equalizer<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Equalizer`] for the given bands, producing the message
+    /// of `on_change` with the index and the new gain of a changed band.
+    pub fn new<F>(bands: &'a [Band], on_change: F) -> Self
+    where
+        F: 'a + Fn(usize, f32) -> Message,
+    {
+        Self {
+            bands,
+            on_change: Box::new(on_change),
+            range: (-12.0, 12.0),
+            width: Length::Fill,
+            height: 200.0,
+            text_size: 12.0,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the range of the gains of the [`Equalizer`] in dB.
+    #[must_use]
+    pub fn range(mut self, min: f32, max: f32) -> Self {
+        if min < max {
+            self.range = (min, max);
+        }
+        self
+    }
+
+    /// Sets the width of the [`Equalizer`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Equalizer`].
+    #[must_use]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the text size of the frequency labels of the [`Equalizer`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the style of the [`Equalizer`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the area of the sliders, leaving room for the handles and the labels.
+    fn slider_area(&self, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            x: bounds.x,
+            y: bounds.y + HANDLE_RADIUS,
+            width: bounds.width,
+            height: (bounds.height - HANDLE_RADIUS * 2.0 - self.label_height()).max(0.0),
+        }
+    }
+
+    /// Returns the height of the frequency labels.
+    fn label_height(&self) -> f32 {
+        self.text_size * 1.5
+    }
+
+    /// Returns the width of the column of a band.
+    #[allow(clippy::cast_precision_loss)]
+    fn band_width(&self, area: Rectangle) -> f32 {
+        area.width / self.bands.len().max(1) as f32
+    }
+
+    /// Returns the horizontal center of the band with the given index.
+    #[allow(clippy::cast_precision_loss)]
+    fn band_x(&self, area: Rectangle, index: usize) -> f32 {
+        area.x + self.band_width(area) * (index as f32 + 0.5)
+    }
+
+    /// Returns the index of the band at the given horizontal position.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    fn band_at(&self, area: Rectangle, x: f32) -> usize {
+        let index = ((x - area.x) / self.band_width(area)).max(0.0) as usize;
+        index.min(self.bands.len().saturating_sub(1))
+    }
+
+    /// Returns the gain at the given vertical position.
+    fn gain_at(&self, area: Rectangle, y: f32) -> f32 {
+        let (min, max) = self.range;
+        let ratio = if area.height > 0.0 {
+            ((area.y + area.height - y) / area.height).clamp(0.0, 1.0)
+        } else {
+            0.5
+        };
+
+        min + (max - min) * ratio
+    }
+
+    /// Returns the vertical position of the given gain.
+    fn y_of(&self, area: Rectangle, gain: f32) -> f32 {
+        let (min, max) = self.range;
+        let ratio = ((gain - min) / (max - min)).clamp(0.0, 1.0);
+
+        area.y + area.height - area.height * ratio
+    }
+
+    /// Sets the gains of the bands from `from` to `to` by interpolating the
+    /// gains at both ends, for sketching a curve across the bands.
+    #[allow(clippy::cast_precision_loss)]
+    fn sketch(&self, from: (usize, f32), to: (usize, f32), shell: &mut Shell<'_, Message>) {
+        let (start, end) = (from.0.min(to.0), from.0.max(to.0));
+
+        for index in start..=end {
+            if index == from.0 && from.0 != to.0 {
+                continue;
+            }
+
+            let t = if from.0 == to.0 {
+                1.0
+            } else {
+                (index as f32 - from.0 as f32) / (to.0 as f32 - from.0 as f32)
+            };
+
+            shell.publish((self.on_change)(index, from.1 + (to.1 - from.1) * t));
+        }
+    }
+}
+
+/// The state of an [`Equalizer`].
+#[derive(Clone, Copy, Debug, Default)]
+struct State {
+    /// The band and gain the ongoing drag was last at.
+    drag: Option<(usize, f32)>,
+}
+
+impl<'a, Message, Theme> Widget<Message, Renderer<Theme>> for Equalizer<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Fixed(self.height)
+    }
+
+    fn layout(&self, _renderer: &Renderer<Theme>, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(Length::Fixed(self.height));
+
+        Node::new(limits.resolve(Size::new(limits.max().width, self.height)))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer<Theme>,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        if self.bands.is_empty() {
+            return event::Status::Ignored;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let area = self.slider_area(layout.bounds());
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position_over(layout.bounds()) {
+                    let target = (
+                        self.band_at(area, position.x),
+                        self.gain_at(area, position.y),
+                    );
+                    state.drag = Some(target);
+                    shell.publish((self.on_change)(target.0, target.1));
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(last) = state.drag {
+                    let target = (
+                        self.band_at(area, position.x),
+                        self.gain_at(area, position.y),
+                    );
+                    self.sketch(last, target, shell);
+                    state.drag = Some(target);
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if state.drag.is_some() =>
+            {
+                state.drag = None;
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer<Theme>,
+    ) -> mouse::Interaction {
+        if tree.state.downcast_ref::<State>().drag.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer<Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let area = self.slider_area(bounds);
+        let appearance = theme.active(&self.style);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        let zero = self.y_of(area, 0.0);
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: area.x,
+                    y: zero - 0.5,
+                    width: area.width,
+                    height: 1.0,
+                },
+                border_radius: (0.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.zero_line_color,
+        );
+
+        for (index, band) in self.bands.iter().enumerate() {
+            let x = self.band_x(area, index);
+            let y = self.y_of(area, band.gain);
+            let rail = Rectangle {
+                x: x - RAIL_WIDTH / 2.0,
+                width: RAIL_WIDTH,
+                ..area
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: rail,
+                    border_radius: (RAIL_WIDTH / 2.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.rail_color,
+            );
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: y.min(zero),
+                        height: (y - zero).abs(),
+                        ..rail
+                    },
+                    border_radius: (0.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.fill_color,
+            );
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: x - HANDLE_RADIUS,
+                        y: y - HANDLE_RADIUS,
+                        width: HANDLE_RADIUS * 2.0,
+                        height: HANDLE_RADIUS * 2.0,
+                    },
+                    border_radius: HANDLE_RADIUS.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.handle_color,
+            );
+
+            renderer.fill_text(core::text::Text {
+                content: &format_frequency(band.frequency),
+                bounds: Rectangle {
+                    x,
+                    y: bounds.y + bounds.height - self.label_height() / 2.0,
+                    width: self.band_width(area),
+                    height: self.label_height(),
+                },
+                size: self.text_size,
+                line_height: LineHeight::default(),
+                color: appearance.text_color,
+                font: core::Font::default(),
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+                shaping: core::text::Shaping::Basic,
+            });
+        }
+
+        if self.bands.is_empty() {
+            return;
+        }
+
+        // The response curve runs smoothly through the gains of the bands and
+        // continues flat to the edges.
+        let mut points: Vec<Point> = self
+            .bands
+            .iter()
+            .enumerate()
+            .map(|(index, band)| Point::new(self.band_x(area, index), self.y_of(area, band.gain)))
+            .collect();
+        points.insert(0, Point::new(area.x, points[0].y));
+        points.push(Point::new(area.x + area.width, points[points.len() - 1].y));
+
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+        let offset = Vector::new(-bounds.x, -bounds.y);
+        let curve = Path::new(|builder| {
+            builder.move_to(points[0] + offset);
+
+            for i in 0..points.len() - 1 {
+                let previous = points[i.saturating_sub(1)];
+                let next = points[(i + 2).min(points.len() - 1)];
+                let (start, end) = (points[i], points[i + 1]);
+
+                // Catmull-Rom spline as cubic Bézier segments.
+                let control_1 = Point::new(
+                    start.x + (end.x - previous.x) / 6.0,
+                    start.y + (end.y - previous.y) / 6.0,
+                );
+                let control_2 = Point::new(
+                    end.x - (next.x - start.x) / 6.0,
+                    end.y - (next.y - start.y) / 6.0,
+                );
+                builder.bezier_curve_to(control_1 + offset, control_2 + offset, end + offset);
+            }
+        });
+        frame.stroke(
+            &curve,
+            Stroke::default()
+                .with_color(appearance.curve_color)
+                .with_width(2.0),
+        );
+
+        renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+            renderer.draw(vec![frame.into_geometry()]);
+        });
+    }
+}
+
+impl<'a, Message, Theme> From<Equalizer<'a, Message, Theme>>
+    for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+{
+    fn from(equalizer: Equalizer<'a, Message, Theme>) -> Self {
+        Element::new(equalizer)
+    }
+}
+
+/// Formats the given frequency in Hz as a short label like `60` or `2.5k`.
+#[must_use]
+pub fn format_frequency(frequency: f32) -> String {
+    if frequency >= 1000.0 {
+        let label = format!("{:.1}", frequency / 1000.0);
+        format!("{}k", label.trim_end_matches(".0"))
+    } else {
+        format!("{frequency:.0}")
+    }
+}
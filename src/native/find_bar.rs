@@ -0,0 +1,530 @@
+//! Use a find bar to search and replace the text of another widget.
+//!
+//! *This API requires the following crate features to be activated: `find_bar`*
+
+use crate::core::{
+    find::{PatternError, Query, Searchable},
+    i18n,
+};
+use crate::graphics::icons::{icon_to_char, Icon, ICON_FONT};
+use crate::native::helpers::Styled;
+
+use iced_widget::{
+    button,
+    core::{
+        self,
+        mouse::{self, Cursor},
+        renderer, Alignment, Color, Element, Length, Rectangle,
+    },
+    renderer::Renderer,
+    text, text_input, Button, Column, MouseArea, Row, Text, TextInput,
+};
+use std::{ops::Range, rc::Rc};
+
+pub use crate::style::find_bar::{Appearance, StyleSheet};
+
+/// The padding around the elements.
+const PADDING: f32 = 4.0;
+/// The spacing between the elements.
+const SPACING: f32 = 4.0;
+/// The padding around the label of a tool.
+const TOOL_PADDING: f32 = 4.0;
+
+/// The function producing the message when a match is navigated to.
+type NavigateFn<'a, Message> = Box<dyn Fn(usize, Range<usize>) -> Message + 'a>;
+/// The function producing the message when the replacement text is changed.
+type InputFn<'a, Message> = Box<dyn Fn(String) -> Message + 'a>;
+/// The function producing the message when a match is replaced.
+type ReplaceFn<'a, Message> = Box<dyn Fn(Range<usize>, String) -> Message + 'a>;
+/// The function producing the message when all matches are replaced.
+type ReplaceAllFn<'a, Message> = Box<dyn Fn(Vec<Range<usize>>, String) -> Message + 'a>;
+
+/// A bar searching the text of another widget, with a match counter,
+/// previous and next buttons, case and regular expression toggles, and an
+/// optional replace row.
+///
+/// The [`FindBar`] searches any [`Searchable`] text, e.g. the content of a
+/// [`SelectableText`](crate::SelectableText) or the
+/// [`Document`](crate::core::rich_text::Document) of a
+/// [`RichEditor`](crate::RichEditor). It does not change the text itself;
+/// it produces messages with the byte ranges of the matches to navigate to or
+/// to replace, which can be applied to a [`Replaceable`](crate::core::find::Replaceable)
+/// text with [`replace_all`](crate::core::find::replace_all).
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{find::Query, FindBar};
+/// # use std::ops::Range;
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     QueryChanged(Query),
+///     Navigated(usize, Range<usize>),
+/// }
+///
+/// let text = "Some searched text";
+/// let query = Query::new("text");
+///
+/// let find_bar = FindBar::new(&query, text, Message::QueryChanged)
+///     .on_navigate(Message::Navigated);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct FindBar<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: StyleSheet,
+{
+    /// The search query.
+    query: &'a Query,
+    /// The matches of the query in the searched text.
+    matches: Result<Vec<Range<usize>>, PatternError>,
+    /// The index of the current match.
+    current: Option<usize>,
+    /// The function producing the message when the query is changed.
+    on_query: Rc<dyn Fn(Query) -> Message + 'a>,
+    /// The function producing the message when a match is navigated to.
+    on_navigate: Option<NavigateFn<'a, Message>>,
+    /// The replacement text and the function producing the message when it is changed.
+    replacement: Option<(&'a str, InputFn<'a, Message>)>,
+    /// The function producing the message when the current match is replaced.
+    on_replace: Option<ReplaceFn<'a, Message>>,
+    /// The function producing the message when all matches are replaced.
+    on_replace_all: Option<ReplaceAllFn<'a, Message>>,
+    /// The message produced when the [`FindBar`] is closed.
+    on_close: Option<Message>,
+    /// The id of the search field.
+    id: Option<text_input::Id>,
+    /// The width of the [`FindBar`].
+    width: Length,
+    /// The text size of the [`FindBar`].
+    text_size: f32,
+    /// The style of the [`FindBar`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> FindBar<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a + StyleSheet + button::StyleSheet + text::StyleSheet + text_input::StyleSheet,
+{
+    /// Creates a new [`FindBar`] searching the given text.
+    ///
+    /// It expects:
+    ///     * the search [`Query`].
+    ///     * the searched text.
+    ///     * the function producing the message when the query is changed.
+    pub fn new<T, F>(query: &'a Query, target: &T, on_query: F) -> Self
+    where
+        T: Searchable + ?Sized,
+        F: 'a + Fn(Query) -> Message,
+    {
+        Self {
+            query,
+            matches: query.find(&target.search_text()),
+            current: None,
+            on_query: Rc::new(on_query),
+            on_navigate: None,
+            replacement: None,
+            on_replace: None,
+            on_replace_all: None,
+            on_close: None,
+            id: None,
+            width: Length::Fill,
+            text_size: 14.0,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the index of the current match of the [`FindBar`].
+    #[must_use]
+    pub fn current(mut self, current: Option<usize>) -> Self {
+        self.current = current;
+        self
+    }
+
+    /// Sets the function producing the message with the index and byte range
+    /// of the match navigated to with the previous and next buttons or
+    /// `Enter`.
+    #[must_use]
+    pub fn on_navigate<F>(mut self, on_navigate: F) -> Self
+    where
+        F: 'a + Fn(usize, Range<usize>) -> Message,
+    {
+        self.on_navigate = Some(Box::new(on_navigate));
+        self
+    }
+
+    /// Shows the replace row of the [`FindBar`] with the given replacement
+    /// text and the function producing the message when it is changed.
+    #[must_use]
+    pub fn replacement<F>(mut self, replacement: &'a str, on_input: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        self.replacement = Some((replacement, Box::new(on_input)));
+        self
+    }
+
+    /// Sets the function producing the message with the byte range of the
+    /// current match and the replacement text when it is replaced.
+    #[must_use]
+    pub fn on_replace<F>(mut self, on_replace: F) -> Self
+    where
+        F: 'a + Fn(Range<usize>, String) -> Message,
+    {
+        self.on_replace = Some(Box::new(on_replace));
+        self
+    }
+
+    /// Sets the function producing the message with the byte ranges of all
+    /// matches and the replacement text when they are replaced.
+    #[must_use]
+    pub fn on_replace_all<F>(mut self, on_replace_all: F) -> Self
+    where
+        F: 'a + Fn(Vec<Range<usize>>, String) -> Message,
+    {
+        self.on_replace_all = Some(Box::new(on_replace_all));
+        self
+    }
+
+    /// Sets the message produced when the close button is pressed.
+    #[must_use]
+    pub fn on_close(mut self, message: Message) -> Self {
+        self.on_close = Some(message);
+        self
+    }
+
+    /// Sets the [`Id`](text_input::Id) of the search field, e.g. to focus it
+    /// when the [`FindBar`] is opened.
+    #[must_use]
+    pub fn id(mut self, id: text_input::Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the width of the [`FindBar`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the text size of the [`FindBar`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the style of the [`FindBar`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the matches, or none for an invalid pattern.
+    fn matches(&self) -> &[Range<usize>] {
+        self.matches.as_deref().unwrap_or_default()
+    }
+
+    /// Returns the index of the current match, if it is one of the matches.
+    fn current_match(&self) -> Option<usize> {
+        self.current
+            .filter(|current| *current < self.matches().len())
+    }
+
+    /// Returns the message navigating from the current match by the given
+    /// number of matches.
+    fn navigate(&self, forward: bool) -> Option<Message> {
+        let on_navigate = self.on_navigate.as_ref()?;
+        let count = self.matches().len();
+        if count == 0 {
+            return None;
+        }
+
+        let index = match (self.current_match(), forward) {
+            (Some(current), true) => (current + 1) % count,
+            (Some(current), false) => (current + count - 1) % count,
+            (None, true) => 0,
+            (None, false) => count - 1,
+        };
+
+        Some(on_navigate(index, self.matches()[index].clone()))
+    }
+
+    /// Returns the text of the match counter.
+    fn counter(&self) -> String {
+        let translations = i18n::get();
+
+        match &self.matches {
+            Err(error) => translations.invalid_pattern(&error.to_string()),
+            Ok(_) if self.query.text.is_empty() => String::new(),
+            Ok(matches) if matches.is_empty() => translations.no_matches(),
+            Ok(matches) => self.current_match().map_or_else(
+                || translations.match_count(matches.len()),
+                |current| translations.match_position(current + 1, matches.len()),
+            ),
+        }
+    }
+
+    /// Wraps the given element to draw it with the [`Appearance`].
+    fn styled(
+        &self,
+        content: impl Into<Element<'a, Message, Renderer<Theme>>>,
+        role: Role,
+    ) -> Element<'a, Message, Renderer<Theme>> {
+        let style = self.style.clone();
+
+        Styled::new(
+            content,
+            move |renderer, theme: &Theme, defaults, bounds, cursor| {
+                role.draw(
+                    renderer,
+                    &StyleSheet::active(theme, &style),
+                    defaults.text_color,
+                    bounds,
+                    cursor,
+                )
+            },
+        )
+        .interaction(
+            matches!(role, Role::Tool { enabled: true, .. }).then_some(mouse::Interaction::Pointer),
+        )
+        .into()
+    }
+
+    /// Creates a tool with the given label, producing the given message.
+    fn tool(
+        &self,
+        label: Text<'a, Renderer<Theme>>,
+        active: bool,
+        message: Option<Message>,
+    ) -> Element<'a, Message, Renderer<Theme>> {
+        let tool = self.styled(
+            Row::new()
+                .padding(TOOL_PADDING)
+                .push(label.size(self.text_size)),
+            Role::Tool {
+                active,
+                enabled: message.is_some(),
+            },
+        );
+
+        match message {
+            Some(message) => MouseArea::new(tool).on_press(message).into(),
+            None => tool,
+        }
+    }
+
+    /// Creates the icon label of a tool.
+    fn icon(icon: Icon) -> Text<'a, Renderer<Theme>> {
+        Text::new(icon_to_char(icon).to_string()).font(ICON_FONT)
+    }
+
+    /// Creates the search row.
+    fn search_row(&mut self) -> Element<'a, Message, Renderer<Theme>> {
+        let query = self.query.clone();
+        let toggle_case = (self.on_query)(Query {
+            case_sensitive: !query.case_sensitive,
+            ..query.clone()
+        });
+        let toggle_regex = (self.on_query)(Query {
+            regex: !query.regex,
+            ..query.clone()
+        });
+        let previous = self.navigate(false);
+        let next = self.navigate(true);
+
+        let on_query = Rc::clone(&self.on_query);
+        let mut input = TextInput::new(&i18n::get().find(), &query.text)
+            .on_input(move |text| {
+                on_query(Query {
+                    text,
+                    ..query.clone()
+                })
+            })
+            .size(self.text_size)
+            .padding(TOOL_PADDING)
+            .width(Length::Fill);
+        if let Some(id) = self.id.take() {
+            input = input.id(id);
+        }
+        if let Some(next) = &next {
+            input = input.on_submit(next.clone());
+        }
+
+        let counter = self.styled(
+            Text::new(self.counter()).size(self.text_size),
+            Role::Counter {
+                matched: !self.matches().is_empty() || self.query.text.is_empty(),
+            },
+        );
+
+        let mut row = Row::new()
+            .spacing(SPACING)
+            .align_items(Alignment::Center)
+            .push(input)
+            .push(counter)
+            .push(self.tool(
+                Text::new("Aa"),
+                self.query.case_sensitive,
+                Some(toggle_case),
+            ))
+            .push(self.tool(Text::new(".*"), self.query.regex, Some(toggle_regex)))
+            .push(self.tool(Self::icon(Icon::CaretUpFill), false, previous))
+            .push(self.tool(Self::icon(Icon::CaretDownFill), false, next));
+
+        if let Some(on_close) = self.on_close.clone() {
+            row = row.push(self.tool(Self::icon(Icon::X), false, Some(on_close)));
+        }
+
+        row.into()
+    }
+
+    /// Creates the replace row, if it is shown.
+    fn replace_row(&mut self) -> Option<Element<'a, Message, Renderer<Theme>>> {
+        let (replacement, on_input) = self.replacement.take()?;
+        let current = self
+            .current_match()
+            .map(|current| self.matches()[current].clone());
+
+        let replace = self
+            .on_replace
+            .as_ref()
+            .zip(current)
+            .map(|(on_replace, range)| on_replace(range, replacement.to_owned()));
+        let replace_all = self
+            .on_replace_all
+            .as_ref()
+            .filter(|_| !self.matches().is_empty())
+            .map(|on_replace_all| on_replace_all(self.matches().to_vec(), replacement.to_owned()));
+
+        let translations = i18n::get();
+        let mut input = TextInput::new(&translations.replace(), replacement)
+            .on_input(on_input)
+            .size(self.text_size)
+            .padding(TOOL_PADDING)
+            .width(Length::Fill);
+        if let Some(replace) = &replace {
+            input = input.on_submit(replace.clone());
+        }
+
+        let button = |label: &str, message: Option<Message>| {
+            let button =
+                Button::new(Text::new(label.to_owned()).size(self.text_size)).padding(TOOL_PADDING);
+            match message {
+                Some(message) => button.on_press(message),
+                None => button,
+            }
+        };
+
+        Some(
+            Row::new()
+                .spacing(SPACING)
+                .align_items(Alignment::Center)
+                .push(input)
+                .push(button(&translations.replace(), replace))
+                .push(button(&translations.replace_all(), replace_all))
+                .into(),
+        )
+    }
+}
+
+impl<'a, Message, Theme> From<FindBar<'a, Message, Theme>> for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'a + Clone,
+    Theme: 'a + StyleSheet + button::StyleSheet + text::StyleSheet + text_input::StyleSheet,
+{
+    fn from(mut find_bar: FindBar<'a, Message, Theme>) -> Self {
+        let replace_row = find_bar.replace_row();
+        let search_row = find_bar.search_row();
+
+        let mut content = Column::new()
+            .spacing(SPACING)
+            .padding(PADDING)
+            .width(find_bar.width)
+            .push(search_row);
+
+        if let Some(replace_row) = replace_row {
+            content = content.push(replace_row);
+        }
+
+        find_bar.styled(content, Role::Frame)
+    }
+}
+
+/// How a [`Styled`] element is drawn with the [`Appearance`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Role {
+    /// The background of the [`FindBar`].
+    Frame,
+    /// The match counter.
+    Counter {
+        /// Whether the query has matches.
+        matched: bool,
+    },
+    /// A toggle or button.
+    Tool {
+        /// Whether the toggle is enabled.
+        active: bool,
+        /// Whether the tool can be pressed.
+        enabled: bool,
+    },
+}
+
+impl Role {
+    /// Draws the background of an element with the [`Role`], returning the
+    /// color of its text.
+    fn draw<Renderer: core::Renderer>(
+        self,
+        renderer: &mut Renderer,
+        appearance: &Appearance,
+        text_color: Color,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Color {
+        match self {
+            Self::Frame => {
+                core::Renderer::fill_quad(
+                    renderer,
+                    renderer::Quad {
+                        bounds,
+                        border_radius: appearance.border_radius.into(),
+                        border_width: appearance.border_width,
+                        border_color: appearance.border_color,
+                    },
+                    appearance.background,
+                );
+                text_color
+            }
+            Self::Counter { matched: true } => appearance.counter_text_color,
+            Self::Counter { matched: false } => appearance.no_match_text_color,
+            Self::Tool { active, enabled } => {
+                let hovered = enabled && cursor.is_over(bounds);
+
+                if active || hovered {
+                    core::Renderer::fill_quad(
+                        renderer,
+                        renderer::Quad {
+                            bounds,
+                            border_radius: (3.0).into(),
+                            border_width: if active { 1.0 } else { 0.0 },
+                            border_color: appearance.active_toggle_color,
+                        },
+                        Color {
+                            a: 0.15,
+                            ..appearance.active_toggle_color
+                        },
+                    );
+                }
+
+                if enabled {
+                    text_color
+                } else {
+                    appearance.counter_text_color
+                }
+            }
+        }
+    }
+}
@@ -17,6 +17,8 @@ pub mod anchor;
 pub use anchor::Anchor;
 pub mod offset;
 pub use offset::Offset;
+pub mod stacking;
+pub use stacking::Stacking;
 
 /// A floating element floating over some content.
 ///
@@ -38,6 +40,27 @@ pub use offset::Offset;
 ///         .into()
 /// );
 /// ```
+///
+/// Several elements can float over the same content. Elements sharing the
+/// same [`Anchor`] are stacked next to each other following the
+/// [`Stacking`] of the [`FloatingElement`], e.g. a floating action button with
+/// a toast above it:
+/// ```ignore
+/// # use iced::widget::{Button, Column, Container, Text};
+/// # use iced_aw::native::FloatingElement;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     ButtonPressed,
+/// }
+///
+/// let content = Column::new();
+/// let floating_element = FloatingElement::new(
+///     content,
+///     Button::new(Text::new("+")).on_press(Message::ButtonPressed),
+/// )
+/// .push(Container::new(Text::new("Saved!")));
+/// ```
 #[allow(missing_debug_implementations)]
 pub struct FloatingElement<'a, Message, Renderer = crate::Renderer>
 where
@@ -49,10 +72,17 @@ where
     offset: Offset,
     /// The visibility of the element.
     hidden: bool,
+    /// The direction in which the elements sharing an anchor are stacked.
+    stacking: Stacking,
+    /// The spacing between the stacked elements.
+    spacing: f32,
     /// The underlying element.
     underlay: Element<'a, Message, Renderer>,
-    /// The floating element of the [`FloatingElementOverlay`].
-    element: Element<'a, Message, Renderer>,
+    /// The floating elements of the [`FloatingElementOverlay`].
+    elements: Vec<Element<'a, Message, Renderer>>,
+    /// The anchors of the floating elements, if they differ from the anchor
+    /// of the [`FloatingElement`].
+    anchors: Vec<Option<Anchor>>,
 }
 
 impl<'a, Message, Renderer> FloatingElement<'a, Message, Renderer>
@@ -75,11 +105,38 @@ where
             anchor: Anchor::SouthEast,
             offset: 5.0.into(),
             hidden: false,
+            stacking: Stacking::default(),
+            spacing: 5.0,
             underlay: underlay.into(),
-            element: element.into(),
+            elements: vec![element.into()],
+            anchors: vec![None],
         }
     }
 
+    /// Pushes another floating [`Element`] at the [`Anchor`] of the
+    /// [`FloatingElement`], stacked with the elements already there.
+    #[must_use]
+    pub fn push<E>(mut self, element: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.elements.push(element.into());
+        self.anchors.push(None);
+        self
+    }
+
+    /// Pushes another floating [`Element`] at the given [`Anchor`], stacked
+    /// with the elements already there.
+    #[must_use]
+    pub fn push_anchored<E>(mut self, anchor: Anchor, element: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.elements.push(element.into());
+        self.anchors.push(Some(anchor));
+        self
+    }
+
     /// Sets the [`Anchor`] of the [`FloatingElement`].
     #[must_use]
     pub fn anchor(mut self, anchor: Anchor) -> Self {
@@ -103,6 +160,22 @@ where
         self.offset = offset.into();
         self
     }
+
+    /// Sets the [`Stacking`] of the elements sharing an [`Anchor`] on the
+    /// [`FloatingElement`].
+    #[must_use]
+    pub fn stacking(mut self, stacking: Stacking) -> Self {
+        self.stacking = stacking;
+        self
+    }
+
+    /// Sets the spacing between the elements sharing an [`Anchor`] on the
+    /// [`FloatingElement`].
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer> for FloatingElement<'a, Message, Renderer>
@@ -111,11 +184,18 @@ where
     Renderer: core::Renderer,
 {
     fn children(&self) -> Vec<Tree> {
-        vec![Tree::new(&self.underlay), Tree::new(&self.element)]
+        std::iter::once(&self.underlay)
+            .chain(&self.elements)
+            .map(Tree::new)
+            .collect()
     }
 
     fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(&[&self.underlay, &self.element]);
+        tree.diff_children(
+            &std::iter::once(&self.underlay)
+                .chain(&self.elements)
+                .collect::<Vec<_>>(),
+        );
     }
 
     fn width(&self) -> Length {
@@ -216,16 +296,22 @@ where
                 .overlay(&mut state.children[0], layout, renderer);
         }
 
-        if state.children.len() == 2 {
+        if state.children.len() == self.elements.len() + 1 {
             let bounds = layout.bounds();
+            let anchors = self
+                .anchors
+                .iter()
+                .map(|anchor| anchor.unwrap_or(self.anchor))
+                .collect();
 
             Some(overlay::Element::new(
                 bounds.position(),
                 Box::new(FloatingElementOverlay::new(
-                    &mut state.children[1],
-                    &mut self.element,
-                    &self.anchor,
+                    &mut state.children[1..],
+                    &mut self.elements,
+                    anchors,
                     &self.offset,
+                    (self.stacking, self.spacing),
                     bounds,
                 )),
             ))
@@ -3,7 +3,7 @@
 //! *This API requires the following crate features to be activated: `floating_element`*
 
 /// Positional [`Anchor`] for the [`FloatingElement`](super::FloatingElement).
-#[derive(Copy, Clone, Debug, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Anchor {
     /// North [`Anchor`] for positioning the [`Button`](iced_widget::Button)
     ///  on the top of the underlying element.
@@ -0,0 +1,19 @@
+//! Use a floating element to overlay a button over some content
+//!
+//! *This API requires the following crate features to be activated: `floating_element`*
+
+/// The direction in which the elements of a
+/// [`FloatingElement`](super::FloatingElement) sharing the same
+/// [`Anchor`](super::Anchor) are stacked so they don't overlap.
+///
+/// The elements cascade away from the edge they are anchored to, or downwards
+/// and rightwards for centered anchors.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Stacking {
+    /// Stack the elements above or below each other.
+    #[default]
+    Vertical,
+
+    /// Stack the elements next to each other.
+    Horizontal,
+}
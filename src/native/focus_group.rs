@@ -0,0 +1,399 @@
+//! Use a focus group to make several focusable widgets a single Tab stop,
+//! moving the focus between them with the arrow keys.
+//!
+//! *This API requires the following crate features to be activated: `focus_group`*
+use crate::core::focus::{rove, FindFocused, FocusIndex};
+
+use iced_widget::core::{
+    self, event, keyboard,
+    layout::{Limits, Node},
+    mouse::{self, Cursor},
+    overlay, renderer,
+    widget::{
+        operation::{Focusable, Scrollable, TextInput},
+        tree::{State as TreeState, Tag},
+        Id, Operation, Tree,
+    },
+    Clipboard, Element, Event, Layout, Length, Rectangle, Shell, Vector, Widget,
+};
+use std::any::Any;
+
+/// A wrapper turning the focusable widgets inside of its content, like text
+/// inputs, into a group with a roving focus.
+///
+/// Operations moving the focus by the order of the widgets, like
+/// [`focus_next`](iced_widget::focus_next) on Tab, see the whole group as a
+/// single focusable widget, which focuses the child that was focused last.
+/// Inside of the group, the arrow keys move the focus to the preceding or
+/// following child, and `Home` and `End` to the first and last one.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::{row, text_input};
+/// # use iced_aw::FocusGroup;
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     HoursChanged(String),
+///     MinutesChanged(String),
+/// }
+///
+/// let focus_group = FocusGroup::new(row![
+///     text_input("Hours", "").on_input(Message::HoursChanged),
+///     text_input("Minutes", "").on_input(Message::MinutesChanged),
+/// ])
+/// .wrap(false);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct FocusGroup<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer,
+{
+    /// The content of the [`FocusGroup`].
+    content: Element<'a, Message, Renderer>,
+    /// Whether the arrow keys wrap around at the first and last child.
+    wrap: bool,
+}
+
+impl<'a, Message, Renderer> FocusGroup<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    /// Creates a new [`FocusGroup`] of the focusable widgets inside of the
+    /// given content.
+    pub fn new<C>(content: C) -> Self
+    where
+        C: Into<Element<'a, Message, Renderer>>,
+    {
+        Self {
+            content: content.into(),
+            wrap: true,
+        }
+    }
+
+    /// Sets whether the arrow keys of the [`FocusGroup`] wrap around from the
+    /// last child to the first one and back, which they do by default.
+    #[must_use]
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Finds the focused child, remembering it to focus it again once the
+    /// focus returns to the group.
+    fn find_focus(&self, tree: &mut Tree, layout: Layout<'_>, renderer: &Renderer) -> FindFocused {
+        let mut find_focused = FindFocused::new();
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            &mut find_focused,
+        );
+
+        if let Some(focused) = find_focused.focused {
+            tree.state.downcast_mut::<State>().active = focused;
+        }
+
+        find_focused
+    }
+}
+
+/// The state of a [`FocusGroup`].
+#[derive(Debug, Default)]
+struct State {
+    /// The index of the child that was focused last.
+    active: usize,
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for FocusGroup<'_, Message, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let find_focused = self.find_focus(tree, layout, renderer);
+
+        // The keys not used by the focused child move the focus.
+        match (status, event, find_focused.focused) {
+            (
+                event::Status::Ignored,
+                Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }),
+                Some(focused),
+            ) => match rove(focused, find_focused.count, key_code, self.wrap) {
+                Some(target) => {
+                    self.content.as_widget().operate(
+                        &mut tree.children[0],
+                        layout,
+                        renderer,
+                        &mut FocusIndex::new(Some(target)),
+                    );
+                    tree.state.downcast_mut::<State>().active = target;
+
+                    event::Status::Captured
+                }
+                None => event::Status::Ignored,
+            },
+            (status, _, _) => status,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate<'b>(
+        &'b self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let find_focused = self.find_focus(tree, layout, renderer);
+
+        // The whole group is a single focusable widget for the operation.
+        if find_focused.count > 0 {
+            let mut group = Group {
+                focused: find_focused.focused.is_some(),
+            };
+            operation.container(None, layout.bounds(), &mut |operation| {
+                operation.focusable(&mut group, None);
+            });
+
+            if group.focused != find_focused.focused.is_some() {
+                let active = tree.state.downcast_ref::<State>().active;
+                let target = group.focused.then_some(active.min(find_focused.count - 1));
+
+                self.content.as_widget().operate(
+                    &mut tree.children[0],
+                    layout,
+                    renderer,
+                    &mut FocusIndex::new(target),
+                );
+            }
+        }
+
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            &mut Children { operation },
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<FocusGroup<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + core::Renderer,
+{
+    fn from(focus_group: FocusGroup<'a, Message, Renderer>) -> Self {
+        Element::new(focus_group)
+    }
+}
+
+/// The focus of a whole [`FocusGroup`], seen as a single focusable widget.
+#[derive(Debug)]
+struct Group {
+    /// Whether a child of the group is focused.
+    focused: bool,
+}
+
+impl Focusable for Group {
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn focus(&mut self) {
+        self.focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.focused = false;
+    }
+}
+
+/// The [`Operation`] passing all but the focusable children of a
+/// [`FocusGroup`] on to the given operation.
+struct Children<'a, T> {
+    /// The operation on the children.
+    operation: &'a mut dyn Operation<T>,
+}
+
+impl<T> Operation<T> for Children<'_, T> {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        self.operation.container(id, bounds, &mut |operation| {
+            operate_on_children(&mut Children { operation });
+        });
+    }
+
+    fn scrollable(
+        &mut self,
+        state: &mut dyn Scrollable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.operation.scrollable(state, id, bounds, translation);
+    }
+
+    fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+        self.operation.text_input(state, id);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+        self.operation.custom(state, id);
+    }
+}
+
+#[cfg(all(test, feature = "toggle_group"))]
+mod tests {
+    use super::FocusGroup;
+    use crate::native::toggle_group::ToggleGroup;
+    use crate::test_utils::Harness;
+    use iced_widget::{
+        column,
+        core::{
+            keyboard::KeyCode,
+            widget::operation::focusable::{focus_next, focus_previous},
+            Size,
+        },
+        row, text_input,
+    };
+
+    #[test]
+    fn focus_group_test() {
+        let input = |index: usize| text_input("", "").on_input(move |value| (index, value));
+        let content = column![
+            input(0),
+            FocusGroup::new(row![input(1), input(2), input(3)]).wrap(false),
+            ToggleGroup::new(["A", "B"], &[], |selection| {
+                (4, format!("{selection:?}"))
+            }),
+        ];
+        let mut harness = Harness::new(content, Size::new(300.0, 200.0));
+
+        // Tab enters the group at its first child.
+        let _ = harness.operate(&mut focus_next());
+        assert_eq!(harness.type_text("a")[0].0, 0);
+        let _ = harness.operate(&mut focus_next());
+        assert_eq!(harness.type_text("a")[0].0, 1);
+
+        // The arrow keys move the focus inside of the group up to its end.
+        let _ = harness.press_key(KeyCode::Down);
+        assert_eq!(harness.type_text("a")[0].0, 2);
+        let _ = harness.press_key(KeyCode::Down);
+        let _ = harness.press_key(KeyCode::Down);
+        assert_eq!(harness.type_text("a")[0].0, 3);
+
+        // The group is a single Tab stop, and so is the toggle group.
+        let _ = harness.operate(&mut focus_next());
+        let _ = harness.press_key(KeyCode::Right);
+        assert_eq!(
+            harness.press_key(KeyCode::Space),
+            vec![(4, String::from("[1]"))]
+        );
+
+        // The focus returns to the child of the group focused last.
+        let _ = harness.operate(&mut focus_previous());
+        assert_eq!(harness.type_text("a")[0].0, 3);
+    }
+}
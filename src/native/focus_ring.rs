@@ -0,0 +1,385 @@
+//! Use a focus ring to outline the content holding the keyboard focus.
+//!
+//! *This API requires the following crate features to be activated: `focus_ring`*
+use crate::core::focus::FindFocused;
+
+use iced_widget::core::{
+    self, event, keyboard,
+    layout::{Limits, Node},
+    mouse::{self, Cursor},
+    overlay, renderer, touch,
+    widget::{
+        tree::{State as TreeState, Tag},
+        Operation, Tree,
+    },
+    Clipboard, Color, Element, Event, Layout, Length, Rectangle, Shell, Widget,
+};
+
+pub use crate::style::focus_ring::{Appearance, StyleSheet};
+
+/// A wrapper drawing a ring around its content while a focusable widget
+/// inside of it, like a text input, holds the keyboard focus.
+///
+/// Wrapping the focusable widgets of an application outlines whichever of
+/// them is focused the same way, styled by the theme. The ring follows the
+/// focus when it is moved by clicking or by operations like
+/// [`focus_next`](iced_widget::focus_next) on Tab.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::text_input;
+/// # use iced_aw::FocusRing;
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     NameChanged(String),
+/// }
+///
+/// let focus_ring = FocusRing::new(
+///     text_input("Name", "").on_input(Message::NameChanged),
+/// )
+/// .keyboard_only();
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct FocusRing<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The content of the [`FocusRing`].
+    content: Element<'a, Message, Renderer>,
+    /// Whether the ring is only shown after the keyboard was used.
+    keyboard_only: bool,
+    /// The style of the [`FocusRing`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> FocusRing<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`FocusRing`] around the given content.
+    pub fn new<C>(content: C) -> Self
+    where
+        C: Into<Element<'a, Message, Renderer>>,
+    {
+        Self {
+            content: content.into(),
+            keyboard_only: false,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Only shows the ring of the [`FocusRing`] once Tab was pressed, hiding
+    /// it again on a click, so focusing the content with the mouse does not
+    /// outline it.
+    #[must_use]
+    pub fn keyboard_only(mut self) -> Self {
+        self.keyboard_only = true;
+        self
+    }
+
+    /// Sets the style of the [`FocusRing`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Updates whether the content holds the focus.
+    fn find_focus(&self, tree: &mut Tree, layout: Layout<'_>, renderer: &Renderer) {
+        let mut find_focused = FindFocused::new();
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            &mut find_focused,
+        );
+
+        tree.state.downcast_mut::<State>().focused = find_focused.focused.is_some();
+    }
+}
+
+/// The state of a [`FocusRing`].
+#[derive(Debug, Default)]
+struct State {
+    /// Whether the content holds the focus.
+    focused: bool,
+    /// Whether Tab was pressed since the last click.
+    keyboard: bool,
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for FocusRing<'_, Message, Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Tab,
+                ..
+            }) => state.keyboard = true,
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => state.keyboard = false,
+            _ => {}
+        }
+
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        self.find_focus(tree, layout, renderer);
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        let state = tree.state.downcast_ref::<State>();
+        if !state.focused || (self.keyboard_only && !state.keyboard) {
+            return;
+        }
+
+        let appearance = theme.active(&self.style);
+        let spread = appearance.offset + appearance.width;
+        let bounds = layout.bounds();
+        let ring = Rectangle {
+            x: bounds.x - spread,
+            y: bounds.y - spread,
+            width: bounds.width + 2.0 * spread,
+            height: bounds.height + 2.0 * spread,
+        };
+
+        // The ring is drawn in its own layer above the content.
+        renderer.with_layer(ring, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: ring,
+                    border_radius: appearance.border_radius.into(),
+                    border_width: appearance.width,
+                    border_color: appearance.color,
+                },
+                Color::TRANSPARENT,
+            );
+        });
+    }
+
+    fn operate<'b>(
+        &'b self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        // The bounds of the ring are the bounds of the focusable content.
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.content
+                .as_widget()
+                .operate(&mut tree.children[0], layout, renderer, operation);
+        });
+
+        // The operation may have moved the focus, e.g. on Tab.
+        self.find_focus(tree, layout, renderer);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<FocusRing<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + core::Renderer,
+    Renderer::Theme: 'a + StyleSheet,
+{
+    fn from(focus_ring: FocusRing<'a, Message, Renderer>) -> Self {
+        Element::new(focus_ring)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FocusRing;
+    use crate::core::spatial_nav::{Direction, Navigate, SpatialNav};
+    use crate::test_utils::Harness;
+    use iced_widget::{
+        column, container,
+        core::{keyboard::KeyCode, Color, Point, Size},
+        row, text_input,
+    };
+
+    #[test]
+    fn focus_ring_test() {
+        let content = column![
+            FocusRing::new(text_input("", "").on_input(|value| value).width(200)),
+            FocusRing::new(text_input("", "").on_input(|value| value).width(200)).keyboard_only(),
+        ]
+        .spacing(20)
+        .padding(10);
+        let mut harness = Harness::new(content, Size::new(300.0, 200.0));
+        let white = Some(Color::WHITE);
+
+        // Only the content holding the focus is outlined.
+        assert_eq!(harness.snapshot().color(50, 7), white);
+        let _ = harness.click(Point::new(50.0, 25.0));
+        let ring = harness.snapshot().color(50, 7);
+        assert_ne!(ring, white);
+        assert_eq!(harness.snapshot().color(50, 57), white);
+
+        // A ring shown only for the keyboard waits for Tab.
+        let _ = harness.click(Point::new(50.0, 75.0));
+        assert_eq!(harness.snapshot().color(50, 7), white);
+        assert_eq!(harness.snapshot().color(50, 57), white);
+        let _ = harness.press_key(KeyCode::Tab);
+        assert_eq!(harness.snapshot().color(50, 57), ring);
+    }
+
+    #[test]
+    fn spatial_nav_test() {
+        let input = |index: usize| {
+            text_input("", "")
+                .id(text_input::Id::new(index.to_string()))
+                .on_input(move |value| (index, value))
+        };
+        let content = column![
+            container(
+                column![
+                    row![
+                        container(input(0)),
+                        container(input(1)),
+                        container(input(2))
+                    ],
+                    row![container(input(3)), container(input(4))],
+                ]
+                .spacing(10),
+            )
+            .id(container::Id::new("grid")),
+            FocusRing::new(input(5)),
+        ]
+        .spacing(10);
+        let mut harness = Harness::new(content, Size::new(300.0, 200.0));
+        let spatial_nav = SpatialNav::new();
+
+        // Without a focused widget, the first one is focused.
+        let _ = harness.operate(&mut Navigate::new(Direction::Down, &spatial_nav));
+        assert_eq!(harness.type_text("a")[0].0, 0);
+
+        // The focus moves to the nearest widget in the direction.
+        let _ = harness.operate(&mut Navigate::new(Direction::Right, &spatial_nav));
+        let _ = harness.operate(&mut Navigate::new(Direction::Right, &spatial_nav));
+        assert_eq!(harness.type_text("a")[0].0, 2);
+        let _ = harness.operate(&mut Navigate::new(Direction::Down, &spatial_nav));
+        assert_eq!(harness.type_text("a")[0].0, 4);
+        let _ = harness.operate(&mut Navigate::new(Direction::Down, &spatial_nav));
+        assert_eq!(harness.type_text("a")[0].0, 5);
+        let _ = harness.operate(&mut Navigate::new(Direction::Up, &spatial_nav));
+        assert_eq!(harness.type_text("a")[0].0, 4);
+
+        // The focus stays at the edge of the scope.
+        let scoped = SpatialNav::new().scope(container::Id::new("grid"));
+        let _ = harness.operate(&mut Navigate::new(Direction::Down, &scoped));
+        assert_eq!(harness.type_text("a")[0].0, 4);
+
+        // An explicit link overrides the nearest widget.
+        let linked = SpatialNav::new().link(
+            text_input::Id::new("4"),
+            Direction::Left,
+            text_input::Id::new("0"),
+        );
+        let _ = harness.operate(&mut Navigate::new(Direction::Left, &linked));
+        assert_eq!(harness.type_text("a")[0].0, 0);
+    }
+}
@@ -0,0 +1,529 @@
+//! Use a form to lay out labeled fields in aligned rows.
+//!
+//! *This API requires the following crate features to be activated: `form`*
+use crate::style::form::StyleSheet;
+
+use iced_widget::{
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        overlay, renderer, text,
+        widget::{Operation, Tree},
+        Clipboard, Element, Event, Font, Layout, Length, Pixels, Point, Rectangle, Shell, Size,
+        Widget,
+    },
+    text::LineHeight,
+};
+
+/// The marker appended to the labels of required fields.
+const REQUIRED_MARKER: &str = " *";
+
+/// The size of the help text relative to the text size of the labels.
+const HELP_TEXT_SCALE: f32 = 0.85;
+
+/// A row of a [`Form`]: a label, a field and an optional help text.
+#[allow(missing_debug_implementations)]
+pub struct FormRow<'a, Message, Renderer = crate::Renderer> {
+    /// The label of the field.
+    label: String,
+    /// The field.
+    field: Element<'a, Message, Renderer>,
+    /// Whether the field is marked as required.
+    required: bool,
+    /// The help text shown below the field.
+    help: Option<String>,
+}
+
+impl<'a, Message, Renderer> FormRow<'a, Message, Renderer> {
+    /// Creates a new [`FormRow`] with the given label and field.
+    pub fn new<L, F>(label: L, field: F) -> Self
+    where
+        L: Into<String>,
+        F: Into<Element<'a, Message, Renderer>>,
+    {
+        Self {
+            label: label.into(),
+            field: field.into(),
+            required: false,
+            help: None,
+        }
+    }
+
+    /// Marks the field of the [`FormRow`] as required.
+    #[must_use]
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Sets the help text shown below the field of the [`FormRow`].
+    #[must_use]
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+}
+
+/// A layout of labeled fields.
+///
+/// The labels share a column as wide as the widest label, with the fields
+/// next to them. Below the [`breakpoint`](Form::breakpoint) width, each label
+/// is stacked above its field instead.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::TextInput;
+/// # use iced_aw::{form::FormRow, Form};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     NameChanged(String),
+///     EmailChanged(String),
+/// }
+///
+/// let form = Form::new()
+///     .push(FormRow::new("Name", TextInput::new("", "").on_input(Message::NameChanged)).required(true))
+///     .push(
+///         FormRow::new("Email", TextInput::new("", "").on_input(Message::EmailChanged))
+///             .help("We never share your email."),
+///     );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Form<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The rows of the [`Form`].
+    rows: Vec<FormRow<'a, Message, Renderer>>,
+    /// The vertical space between the rows.
+    spacing: f32,
+    /// The space between a label and its field.
+    label_spacing: f32,
+    /// The width below which the labels are stacked above the fields.
+    breakpoint: f32,
+    /// The width of the [`Form`].
+    width: Length,
+    /// The text size of the labels.
+    text_size: f32,
+    /// The font of the labels and help texts.
+    font: Font,
+    /// The style of the [`Form`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Form<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new empty [`Form`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_rows(Vec::new())
+    }
+
+    /// Creates a new [`Form`] with the given rows.
+    #[must_use]
+    pub fn with_rows(rows: Vec<FormRow<'a, Message, Renderer>>) -> Self {
+        Self {
+            rows,
+            spacing: 12.0,
+            label_spacing: 10.0,
+            breakpoint: 400.0,
+            width: Length::Fill,
+            text_size: 16.0,
+            font: Font::DEFAULT,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Adds a row to the [`Form`].
+    #[must_use]
+    pub fn push(mut self, row: FormRow<'a, Message, Renderer>) -> Self {
+        self.rows.push(row);
+        self
+    }
+
+    /// Sets the vertical space between the rows of the [`Form`].
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the space between the labels and the fields of the [`Form`].
+    #[must_use]
+    pub fn label_spacing(mut self, label_spacing: f32) -> Self {
+        self.label_spacing = label_spacing;
+        self
+    }
+
+    /// Sets the width below which the labels of the [`Form`] are stacked
+    /// above the fields.
+    #[must_use]
+    pub fn breakpoint(mut self, breakpoint: f32) -> Self {
+        self.breakpoint = breakpoint;
+        self
+    }
+
+    /// Sets the width of the [`Form`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the text size of the labels of the [`Form`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the labels and help texts of the [`Form`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Form`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the text size of the help texts.
+    fn help_text_size(&self) -> f32 {
+        self.text_size * HELP_TEXT_SCALE
+    }
+
+    /// Returns the width of the label column, fitting the widest label and
+    /// its required marker.
+    fn label_width(&self, renderer: &Renderer) -> f32 {
+        self.rows
+            .iter()
+            .map(|row| {
+                let marker = if row.required { REQUIRED_MARKER } else { "" };
+                renderer.measure_width(
+                    &format!("{}{marker}", row.label),
+                    self.text_size,
+                    self.font,
+                    text::Shaping::Advanced,
+                )
+            })
+            .fold(0.0, f32::max)
+            .ceil()
+    }
+}
+
+impl<'a, Message, Renderer> Default for Form<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Form<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.rows.iter().map(|row| Tree::new(&row.field)).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children_custom(
+            &self.rows,
+            |tree, row| tree.diff(&row.field),
+            |row| Tree::new(&row.field),
+        );
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let width = limits.max().width;
+        let stacked = width < self.breakpoint;
+
+        let label_width = self.label_width(renderer);
+        let label_height = LineHeight::default().to_absolute(Pixels(self.text_size)).0;
+        let help_height = LineHeight::default()
+            .to_absolute(Pixels(self.help_text_size()))
+            .0;
+
+        let (field_x, field_width) = if stacked {
+            (0.0, width)
+        } else {
+            let field_x = label_width + self.label_spacing;
+            (field_x, (width - field_x).max(0.0))
+        };
+
+        let mut y = 0.0;
+        let mut content_width: f32 = 0.0;
+        let rows = self
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(index, row)| {
+                if index > 0 {
+                    y += self.spacing;
+                }
+
+                let field_y = if stacked {
+                    label_height + self.label_spacing / 2.0
+                } else {
+                    0.0
+                };
+                let mut field = row.field.as_widget().layout(
+                    renderer,
+                    &Limits::new(Size::ZERO, Size::new(field_width, limits.max().height)),
+                );
+                field.move_to(Point::new(field_x, field_y));
+                let field_size = field.size();
+
+                // Side by side, the label is centered on single line fields
+                // and aligned to the top of taller ones.
+                let label_y = if stacked || field_size.height > label_height * 2.0 {
+                    0.0
+                } else {
+                    ((field_size.height - label_height) / 2.0).max(0.0)
+                };
+                let mut label = Node::new(Size::new(
+                    if stacked { width } else { label_width },
+                    label_height,
+                ));
+                label.move_to(Point::new(0.0, label_y));
+
+                let help_y = field_y + field_size.height;
+                let mut help = Node::new(Size::new(
+                    field_width,
+                    if row.help.is_some() { help_height } else { 0.0 },
+                ));
+                help.move_to(Point::new(field_x, help_y));
+
+                let row_height = (label_y + label_height).max(help_y + help.size().height);
+                content_width = content_width.max(field_x + field_size.width);
+
+                let mut node =
+                    Node::with_children(Size::new(width, row_height), vec![label, field, help]);
+                node.move_to(Point::new(0.0, y));
+                y += row_height;
+
+                node
+            })
+            .collect();
+
+        Node::with_children(limits.resolve(Size::new(content_width, y)), rows)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.rows
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(field_layouts(layout))
+            .map(|((row, state), layout)| {
+                row.field.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.rows
+            .iter()
+            .zip(&tree.children)
+            .zip(field_layouts(layout))
+            .map(|((row, state), layout)| {
+                row.field
+                    .as_widget()
+                    .mouse_interaction(state, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let appearance = theme.active(&self.style);
+
+        for ((row, state), row_layout) in
+            self.rows.iter().zip(&tree.children).zip(layout.children())
+        {
+            let mut children = row_layout.children();
+            let label_bounds = children
+                .next()
+                .expect("Form row has a label layout")
+                .bounds();
+            let field_layout = children.next().expect("Form row has a field layout");
+            let help_bounds = children
+                .next()
+                .expect("Form row has a help layout")
+                .bounds();
+
+            renderer.fill_text(core::text::Text {
+                content: &row.label,
+                bounds: label_bounds,
+                size: self.text_size,
+                line_height: LineHeight::default(),
+                color: appearance.label_color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Top,
+                shaping: text::Shaping::Advanced,
+            });
+
+            if row.required {
+                let label_width = renderer.measure_width(
+                    &row.label,
+                    self.text_size,
+                    self.font,
+                    text::Shaping::Advanced,
+                );
+
+                renderer.fill_text(core::text::Text {
+                    content: REQUIRED_MARKER,
+                    bounds: Rectangle {
+                        x: label_bounds.x + label_width,
+                        ..label_bounds
+                    },
+                    size: self.text_size,
+                    line_height: LineHeight::default(),
+                    color: appearance.required_color,
+                    font: self.font,
+                    horizontal_alignment: Horizontal::Left,
+                    vertical_alignment: Vertical::Top,
+                    shaping: text::Shaping::Advanced,
+                });
+            }
+
+            row.field.as_widget().draw(
+                state,
+                renderer,
+                theme,
+                style,
+                field_layout,
+                cursor,
+                viewport,
+            );
+
+            if let Some(help) = &row.help {
+                renderer.fill_text(core::text::Text {
+                    content: help,
+                    bounds: help_bounds,
+                    size: self.help_text_size(),
+                    line_height: LineHeight::default(),
+                    color: appearance.help_color,
+                    font: self.font,
+                    horizontal_alignment: Horizontal::Left,
+                    vertical_alignment: Vertical::Top,
+                    shaping: text::Shaping::Advanced,
+                });
+            }
+        }
+    }
+
+    fn operate<'b>(
+        &'b self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.rows
+                .iter()
+                .zip(&mut tree.children)
+                .zip(field_layouts(layout))
+                .for_each(|((row, state), layout)| {
+                    row.field
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let children = self
+            .rows
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(field_layouts(layout))
+            .filter_map(|((row, state), layout)| {
+                row.field.as_widget_mut().overlay(state, layout, renderer)
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+/// Returns the layouts of the fields of the rows of a [`Form`].
+fn field_layouts<'a>(layout: Layout<'a>) -> impl Iterator<Item = Layout<'a>> {
+    layout.children().filter_map(|row| row.children().nth(1))
+}
+
+impl<'a, Message, Renderer> From<Form<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(form: Form<'a, Message, Renderer>) -> Self {
+        Element::new(form)
+    }
+}
@@ -0,0 +1,350 @@
+//! Use gamepad targets to move the focus and activate elements with a
+//! gamepad.
+//!
+//! The application receives the gamepad events itself, e.g. from a
+//! subscription, turns them into [`Navigation`]s with a [`Mapping`] or a
+//! [`Stick`], and runs the [`Command`] produced by [`navigate`].
+//!
+//! *This API requires the following crate features to be activated: `gamepad`*
+pub use crate::core::gamepad::{Button, Mapping, Navigation, Stick};
+
+use crate::core::gamepad::next_target;
+use crate::style::gamepad::StyleSheet;
+
+use iced_widget::{
+    core::{
+        self, event,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        overlay, renderer,
+        widget::{
+            operation::{Operation, Outcome},
+            tree::{State as TreeState, Tag},
+            Id, Tree,
+        },
+        Clipboard, Color, Element, Event, Layout, Length, Rectangle, Shell, Widget,
+    },
+    runtime::Command,
+};
+use std::any::Any;
+
+/// A wrapper making its content a target of the gamepad navigation.
+///
+/// The focused [`GamepadTarget`] is drawn with a focus ring, and produces
+/// its message when activated.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::{button, Text};
+/// # use iced_aw::GamepadTarget;
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Play,
+/// }
+///
+/// let target = GamepadTarget::new(button(Text::new("Play")).on_press(Message::Play))
+///     .on_activate(Message::Play);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct GamepadTarget<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The content of the [`GamepadTarget`].
+    content: Element<'a, Message, Renderer>,
+    /// The message produced when the [`GamepadTarget`] is activated.
+    on_activate: Option<Message>,
+    /// The style of the [`GamepadTarget`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> GamepadTarget<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`GamepadTarget`] around the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            on_activate: None,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the message produced when the [`GamepadTarget`] is activated.
+    #[must_use]
+    pub fn on_activate(mut self, message: Message) -> Self {
+        self.on_activate = Some(message);
+        self
+    }
+
+    /// Sets the style of the [`GamepadTarget`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+/// The state of a [`GamepadTarget`], found by the [`navigate`] operations.
+struct State<Message> {
+    /// Whether the [`GamepadTarget`] has the focus.
+    is_focused: bool,
+    /// The bounds of the [`GamepadTarget`].
+    bounds: Rectangle,
+    /// The message produced when the [`GamepadTarget`] is activated.
+    on_activate: Option<Message>,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for GamepadTarget<'a, Message, Renderer>
+where
+    Message: 'static + Clone,
+    Renderer: core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State<Message>>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::<Message> {
+            is_focused: false,
+            bounds: Rectangle::default(),
+            on_activate: None,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        if tree.state.downcast_ref::<State<Message>>().is_focused {
+            let appearance = theme.active(&self.style);
+            let bounds = layout.bounds();
+            let offset = appearance.offset + appearance.border_width;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x - offset,
+                        y: bounds.y - offset,
+                        width: offset.mul_add(2.0, bounds.width),
+                        height: offset.mul_add(2.0, bounds.height),
+                    },
+                    border_radius: appearance.border_radius.into(),
+                    border_width: appearance.border_width,
+                    border_color: appearance.focus_color,
+                },
+                Color::TRANSPARENT,
+            );
+        }
+    }
+
+    fn operate<'b>(
+        &'b self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Message>>();
+        state.bounds = layout.bounds();
+        state.on_activate.clone_from(&self.on_activate);
+        operation.custom(state, None);
+
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<GamepadTarget<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'static + Clone,
+    Renderer: 'a + core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(target: GamepadTarget<'a, Message, Renderer>) -> Self {
+        Element::new(target)
+    }
+}
+
+/// Produces a [`Command`] applying the [`Navigation`] to the
+/// [`GamepadTarget`]s of the user interface.
+///
+/// Activating the focused [`GamepadTarget`] produces its message.
+pub fn navigate<Message: 'static + Clone>(navigation: Navigation) -> Command<Message> {
+    Command::widget(Navigate {
+        navigation,
+        targets: Vec::new(),
+        focused: None,
+        activated: None,
+    })
+}
+
+/// The [`Operation`] finding the [`GamepadTarget`]s and the focused one.
+struct Navigate<Message> {
+    /// The applied navigation.
+    navigation: Navigation,
+    /// The bounds of the found targets.
+    targets: Vec<Rectangle>,
+    /// The index of the focused target.
+    focused: Option<usize>,
+    /// The message of the focused target, if it is activated.
+    activated: Option<Message>,
+}
+
+impl<Message: 'static + Clone> Operation<Message> for Navigate<Message> {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<Message>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, _id: Option<&Id>) {
+        let Some(state) = state.downcast_ref::<State<Message>>() else {
+            return;
+        };
+
+        if state.is_focused {
+            self.focused = Some(self.targets.len());
+            if self.navigation == Navigation::Activate {
+                self.activated.clone_from(&state.on_activate);
+            }
+        }
+        self.targets.push(state.bounds);
+    }
+
+    fn finish(&self) -> Outcome<Message> {
+        if self.navigation == Navigation::Activate && self.focused.is_some() {
+            return self.activated.clone().map_or(Outcome::None, Outcome::Some);
+        }
+
+        Outcome::Chain(Box::new(Focus {
+            target: next_target(&self.targets, self.focused, self.navigation),
+            index: 0,
+        }))
+    }
+}
+
+/// The [`Operation`] focusing the [`GamepadTarget`] with the given index.
+struct Focus {
+    /// The index of the target to focus, if any.
+    target: Option<usize>,
+    /// The index of the next found target.
+    index: usize,
+}
+
+impl<Message: 'static> Operation<Message> for Focus {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<Message>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, _id: Option<&Id>) {
+        let Some(state) = state.downcast_mut::<State<Message>>() else {
+            return;
+        };
+
+        state.is_focused = self.target == Some(self.index);
+        self.index += 1;
+    }
+}
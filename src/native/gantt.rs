@@ -0,0 +1,708 @@
+//! Use a Gantt chart to schedule tasks linked by their dependencies.
+//!
+//! *This API requires the following crate features to be activated: `gantt`*
+use crate::core::gantt::{critical_path, route};
+
+use iced_widget::{
+    canvas::{self, Path, Stroke},
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event, keyboard,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer,
+        text::{self, Renderer as _},
+        touch,
+        widget::{
+            tree::{State as TreeState, Tag},
+            Tree,
+        },
+        Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Renderer as _, Shell,
+        Size, Vector, Widget,
+    },
+    graphics::geometry::Renderer as _,
+    renderer::Renderer,
+    text::LineHeight,
+};
+
+pub use crate::core::gantt::{Link, LinkKind, Task};
+pub use crate::style::gantt::{Appearance, StyleSheet};
+
+/// The radius of the handles at the ends of the bars.
+const HANDLE_RADIUS: f32 = 5.0;
+/// The distance the arrows keep from the ends of the bars.
+const LINK_GAP: f32 = 10.0;
+/// The distance from a link within which it is clicked.
+const LINK_TOLERANCE: f32 = 4.0;
+/// The size of the heads of the arrows.
+const ARROW_SIZE: f32 = 6.0;
+
+/// A Gantt chart showing tasks as bars on a timeline, one task per row,
+/// with arrows for the dependency links between them.
+///
+/// Dragging from a handle at the start or the finish of a bar to a handle of
+/// another bar produces a [`Link`] between those ends with
+/// [`on_link`](Self::on_link). Dropping onto the bar itself links to its
+/// start. A clicked link is selected and `Delete` removes it with
+/// [`on_unlink`](Self::on_unlink).
+///
+/// The tasks on the critical path, whose delay delays the whole schedule,
+/// are highlighted with [`critical_path`](Self::critical_path).
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::gantt::{Gantt, Link, LinkKind, Task};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Linked(Link),
+///     Unlinked(usize),
+/// }
+///
+/// let tasks = vec![Task::new("Design", 0.0, 2.0), Task::new("Build", 2.0, 4.0)];
+/// let links = vec![Link::new(0, 1, LinkKind::FinishToStart)];
+///
+/// let gantt = Gantt::new(&tasks, &links)
+///     .on_link(Message::Linked)
+///     .on_unlink(Message::Unlinked)
+///     .critical_path(true);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Gantt<'a, Message, Theme = iced_widget::style::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The tasks of the chart.
+    tasks: &'a [Task],
+    /// The dependency links between the tasks.
+    links: &'a [Link],
+    /// The function producing the message adding a link.
+    on_link: Option<Box<dyn Fn(Link) -> Message + 'a>>,
+    /// The function producing the message removing the link with an index.
+    on_unlink: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    /// Whether the critical path is highlighted.
+    critical_path: bool,
+    /// The width of the [`Gantt`] chart.
+    width: Length,
+    /// The height of the [`Gantt`] chart.
+    height: Length,
+    /// The height of the row of a task.
+    row_height: f32,
+    /// The width of the column of the labels of the tasks.
+    label_width: f32,
+    /// The width of one unit of time.
+    scale: f32,
+    /// The text size of the labels.
+    text_size: f32,
+    /// The style of the [`Gantt`] chart.
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> Gantt<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Gantt`] chart of the given tasks and the links between
+    /// them.
+    #[must_use]
+    pub fn new(tasks: &'a [Task], links: &'a [Link]) -> Self {
+        Self {
+            tasks,
+            links,
+            on_link: None,
+            on_unlink: None,
+            critical_path: false,
+            width: Length::Fill,
+            height: Length::Shrink,
+            row_height: 32.0,
+            label_width: 120.0,
+            scale: 24.0,
+            text_size: 14.0,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the function producing the message with a [`Link`] dragged
+    /// between the handles of two bars.
+    #[must_use]
+    pub fn on_link<F>(mut self, on_link: F) -> Self
+    where
+        F: 'a + Fn(Link) -> Message,
+    {
+        self.on_link = Some(Box::new(on_link));
+        self
+    }
+
+    /// Sets the function producing the message with the index of the
+    /// selected link when `Delete` is pressed.
+    #[must_use]
+    pub fn on_unlink<F>(mut self, on_unlink: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        self.on_unlink = Some(Box::new(on_unlink));
+        self
+    }
+
+    /// Sets whether the tasks and the links on the critical path are
+    /// highlighted.
+    #[must_use]
+    pub fn critical_path(mut self, critical_path: bool) -> Self {
+        self.critical_path = critical_path;
+        self
+    }
+
+    /// Sets the width of the [`Gantt`] chart.
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Gantt`] chart.
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the height of the row of a task.
+    #[must_use]
+    pub fn row_height(mut self, row_height: f32) -> Self {
+        self.row_height = row_height;
+        self
+    }
+
+    /// Sets the width of the column of the labels of the tasks.
+    #[must_use]
+    pub fn label_width(mut self, label_width: f32) -> Self {
+        self.label_width = label_width;
+        self
+    }
+
+    /// Sets the width of one unit of time.
+    #[must_use]
+    pub fn scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Sets the text size of the labels of the [`Gantt`] chart.
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the style of the [`Gantt`] chart.
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the bar of the task with the given index.
+    #[allow(clippy::cast_precision_loss)]
+    fn bar(&self, bounds: Rectangle, index: usize) -> Rectangle {
+        let task = &self.tasks[index];
+
+        Rectangle {
+            x: bounds.x + self.label_width + task.start * self.scale,
+            y: bounds.y + index as f32 * self.row_height + self.row_height * 0.2,
+            width: task.duration * self.scale,
+            height: self.row_height * 0.6,
+        }
+    }
+
+    /// Returns the handle at the start or the finish of the given bar.
+    fn handle(bar: Rectangle, finish: bool) -> Point {
+        if finish {
+            Point::new(bar.x + bar.width, bar.center_y())
+        } else {
+            Point::new(bar.x, bar.center_y())
+        }
+    }
+
+    /// Returns the task and the end of the handle at the given position.
+    fn handle_at(&self, bounds: Rectangle, position: Point) -> Option<(usize, bool)> {
+        (0..self.tasks.len())
+            .flat_map(|index| [(index, true), (index, false)])
+            .find(|&(index, finish)| {
+                Self::handle(self.bar(bounds, index), finish).distance(position)
+                    <= HANDLE_RADIUS + 2.0
+            })
+    }
+
+    /// Returns the task whose bar is at the given position.
+    fn bar_at(&self, bounds: Rectangle, position: Point) -> Option<usize> {
+        (0..self.tasks.len()).find(|&index| self.bar(bounds, index).contains(position))
+    }
+
+    /// Returns the corners of the arrows of the valid links, with their
+    /// indices.
+    fn routes(&self, bounds: Rectangle) -> Vec<(usize, Vec<Point>)> {
+        self.links
+            .iter()
+            .enumerate()
+            .filter(|(_, link)| link.from < self.tasks.len() && link.to < self.tasks.len())
+            .map(|(index, link)| {
+                let from = self.bar(bounds, link.from);
+                let to = self.bar(bounds, link.to);
+
+                (index, route(from, to, link.kind, LINK_GAP))
+            })
+            .collect()
+    }
+
+    /// Returns the index of the link at the given position.
+    fn link_at(&self, bounds: Rectangle, position: Point) -> Option<usize> {
+        self.routes(bounds)
+            .into_iter()
+            .find(|(_, points)| {
+                points
+                    .windows(2)
+                    .any(|segment| distance(position, segment[0], segment[1]) <= LINK_TOLERANCE)
+            })
+            .map(|(index, _)| index)
+    }
+}
+
+/// Returns the distance of the given point from the segment between `a` and
+/// `b`.
+fn distance(point: Point, a: Point, b: Point) -> f32 {
+    let segment = b - a;
+    let length = segment.x * segment.x + segment.y * segment.y;
+    let t = if length > 0.0 {
+        (((point.x - a.x) * segment.x + (point.y - a.y) * segment.y) / length).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+
+    point.distance(a + segment * t)
+}
+
+/// The state of a [`Gantt`] chart.
+#[derive(Debug, Default)]
+struct State {
+    /// The link being dragged from the handle of a bar.
+    drag: Option<Drag>,
+    /// The index of the selected link.
+    selected: Option<usize>,
+}
+
+/// A link dragged from the handle of a bar of a [`Gantt`] chart.
+#[derive(Clone, Copy, Debug)]
+struct Drag {
+    /// The index of the task the link starts at.
+    from: usize,
+    /// Whether the link starts at the finish of the task.
+    finish: bool,
+    /// The position of the cursor.
+    cursor: Point,
+}
+
+impl<'a, Message, Theme> Widget<Message, Renderer<Theme>> for Gantt<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn layout(&self, _renderer: &Renderer<Theme>, limits: &Limits) -> Node {
+        let end = self.tasks.iter().map(Task::end).fold(0.0, f32::max);
+        let limits = limits.width(self.width).height(self.height);
+
+        Node::new(limits.resolve(Size::new(
+            self.label_width + end * self.scale + LINK_GAP * 2.0,
+            self.tasks.len() as f32 * self.row_height,
+        )))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer<Theme>,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    state.selected = None;
+                    return event::Status::Ignored;
+                };
+
+                state.selected = None;
+                if let Some((from, finish)) = self
+                    .on_link
+                    .as_ref()
+                    .and_then(|_| self.handle_at(bounds, position))
+                {
+                    state.drag = Some(Drag {
+                        from,
+                        finish,
+                        cursor: position,
+                    });
+                } else {
+                    state.selected = self.link_at(bounds, position);
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(drag) = &mut state.drag {
+                    drag.cursor = position;
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                let Some(drag) = state.drag.take() else {
+                    return event::Status::Ignored;
+                };
+
+                let target = self
+                    .handle_at(bounds, drag.cursor)
+                    .or_else(|| self.bar_at(bounds, drag.cursor).map(|index| (index, false)));
+
+                if let (Some((to, to_finish)), Some(on_link)) = (target, &self.on_link) {
+                    let link = Link::new(drag.from, to, LinkKind::between(drag.finish, to_finish));
+
+                    if to != drag.from && !self.links.contains(&link) {
+                        shell.publish(on_link(link));
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => match key_code {
+                keyboard::KeyCode::Delete | keyboard::KeyCode::Backspace => {
+                    let selected = state.selected.filter(|&index| index < self.links.len());
+
+                    if let (Some(index), Some(on_unlink)) = (selected, &self.on_unlink) {
+                        shell.publish(on_unlink(index));
+                        state.selected = None;
+                        return event::Status::Captured;
+                    }
+                }
+                keyboard::KeyCode::Escape if state.drag.is_some() || state.selected.is_some() => {
+                    state.drag = None;
+                    state.selected = None;
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer<Theme>,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        if state.drag.is_some() {
+            return mouse::Interaction::Crosshair;
+        }
+        let Some(position) = cursor.position_over(bounds) else {
+            return mouse::Interaction::default();
+        };
+
+        if self.on_link.is_some() && self.handle_at(bounds, position).is_some() {
+            mouse::Interaction::Crosshair
+        } else if self.link_at(bounds, position).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer<Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let critical = if self.critical_path {
+            critical_path(self.tasks, self.links)
+        } else {
+            vec![false; self.tasks.len()]
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        for (index, task) in self.tasks.iter().enumerate() {
+            let y = bounds.y + index as f32 * self.row_height;
+
+            if index > 0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle::new(
+                            Point::new(bounds.x, y),
+                            Size::new(bounds.width, 1.0),
+                        ),
+                        border_radius: (0.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.grid_color,
+                );
+            }
+
+            renderer.fill_text(core::text::Text {
+                content: &task.label,
+                bounds: Rectangle {
+                    x: bounds.x + 4.0,
+                    y: y + self.row_height / 2.0,
+                    width: self.label_width - 8.0,
+                    height: self.row_height,
+                },
+                size: self.text_size,
+                line_height: LineHeight::default(),
+                color: appearance.text_color,
+                font: core::Font::default(),
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Advanced,
+            });
+
+            let bar = self.bar(bounds, index);
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: bar,
+                    border_radius: 3.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                if critical[index] {
+                    appearance.critical_bar_color
+                } else {
+                    appearance.bar_color
+                },
+            );
+        }
+
+        // The arrows are drawn over the bars they leave and enter.
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+        let offset = Vector::new(-bounds.x, -bounds.y);
+
+        for (index, points) in self.routes(bounds) {
+            let link = self.links[index];
+            let color = if state.selected == Some(index) {
+                appearance.selected_link_color
+            } else if critical[link.from] && critical[link.to] {
+                appearance.critical_link_color
+            } else {
+                appearance.link_color
+            };
+
+            let arrow = Path::new(|builder| {
+                builder.move_to(points[0] + offset);
+                for point in &points[1..] {
+                    builder.line_to(*point + offset);
+                }
+            });
+            frame.stroke(&arrow, Stroke::default().with_color(color).with_width(1.5));
+
+            // The head points along the last segment into the bar.
+            let tip = points[points.len() - 1];
+            let direction = if points[points.len() - 2].x <= tip.x {
+                1.0
+            } else {
+                -1.0
+            };
+            let head = Path::new(|builder| {
+                builder.move_to(tip + offset);
+                builder.line_to(
+                    Point::new(tip.x - direction * ARROW_SIZE, tip.y - ARROW_SIZE / 2.0) + offset,
+                );
+                builder.line_to(
+                    Point::new(tip.x - direction * ARROW_SIZE, tip.y + ARROW_SIZE / 2.0) + offset,
+                );
+                builder.close();
+            });
+            frame.fill(&head, color);
+        }
+
+        if let Some(drag) = state.drag {
+            let start = Self::handle(self.bar(bounds, drag.from), drag.finish);
+            let line = Path::line(start + offset, drag.cursor + offset);
+            frame.stroke(
+                &line,
+                Stroke::default()
+                    .with_color(appearance.selected_link_color)
+                    .with_width(1.5),
+            );
+        }
+
+        renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+            renderer.draw(vec![frame.into_geometry()]);
+        });
+
+        if self.on_link.is_none() {
+            return;
+        }
+
+        // The handles of the hovered bar, the dragged link and its target are
+        // drawn over the arrows.
+        let position = state
+            .drag
+            .map(|drag| drag.cursor)
+            .or_else(|| cursor.position_over(bounds));
+        let hovered = position.and_then(|position| {
+            self.handle_at(bounds, position)
+                .map(|(index, _)| index)
+                .or_else(|| self.bar_at(bounds, position))
+        });
+
+        renderer.with_layer(bounds, |renderer| {
+            for index in [hovered, state.drag.map(|drag| drag.from)]
+                .into_iter()
+                .flatten()
+            {
+                let bar = self.bar(bounds, index);
+
+                for finish in [false, true] {
+                    let center = Self::handle(bar, finish);
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: center.x - HANDLE_RADIUS,
+                                y: center.y - HANDLE_RADIUS,
+                                width: HANDLE_RADIUS * 2.0,
+                                height: HANDLE_RADIUS * 2.0,
+                            },
+                            border_radius: HANDLE_RADIUS.into(),
+                            border_width: 1.5,
+                            border_color: if critical[index] {
+                                appearance.critical_bar_color
+                            } else {
+                                appearance.bar_color
+                            },
+                        },
+                        appearance.handle_color,
+                    );
+                }
+            }
+        });
+    }
+}
+
+impl<'a, Message, Theme> From<Gantt<'a, Message, Theme>> for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+{
+    fn from(gantt: Gantt<'a, Message, Theme>) -> Self {
+        Element::new(gantt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Gantt, Link, LinkKind, Task};
+    use crate::test_utils::Harness;
+    use iced_widget::core::{keyboard::KeyCode, Point, Size};
+
+    #[test]
+    fn gantt_test() {
+        #[derive(Clone, Debug, PartialEq)]
+        enum Change {
+            Link(Link),
+            Unlink(usize),
+        }
+
+        let tasks = [Task::new("A", 0.0, 2.0), Task::new("B", 3.0, 2.0)];
+        let gantt = Gantt::new(&tasks, &[])
+            .on_link(Change::Link)
+            .on_unlink(Change::Unlink);
+        let mut harness = Harness::new(gantt, Size::new(400.0, 200.0));
+
+        // The bars start after the labels, 24 pixels per unit of time.
+        let (a_start, a_finish) = (Point::new(120.0, 16.0), Point::new(168.0, 16.0));
+        let (b_start, b_finish) = (Point::new(192.0, 48.0), Point::new(240.0, 48.0));
+        assert_eq!(
+            harness.drag(a_finish, b_start, 5),
+            vec![Change::Link(Link::new(0, 1, LinkKind::FinishToStart))]
+        );
+        assert_eq!(
+            harness.drag(a_start, b_finish, 5),
+            vec![Change::Link(Link::new(0, 1, LinkKind::StartToFinish))]
+        );
+        assert_eq!(
+            harness.drag(b_finish, a_finish, 5),
+            vec![Change::Link(Link::new(1, 0, LinkKind::FinishToFinish))]
+        );
+
+        // Dropping onto a bar links to its start, but not to the same bar.
+        assert_eq!(
+            harness.drag(a_finish, Point::new(215.0, 48.0), 5),
+            vec![Change::Link(Link::new(0, 1, LinkKind::FinishToStart))]
+        );
+        assert!(harness
+            .drag(a_finish, Point::new(140.0, 16.0), 5)
+            .is_empty());
+
+        // A clicked link is removed with Delete.
+        let links = [Link::new(0, 1, LinkKind::FinishToStart)];
+        let gantt = Gantt::new(&tasks, &links)
+            .on_link(Change::Link)
+            .on_unlink(Change::Unlink);
+        let mut harness = Harness::new(gantt, Size::new(400.0, 200.0));
+
+        assert!(harness.drag(a_finish, b_start, 5).is_empty());
+        assert!(harness.press_key(KeyCode::Delete).is_empty());
+        assert!(harness.click(Point::new(178.0, 30.0)).is_empty());
+        assert_eq!(harness.press_key(KeyCode::Delete), vec![Change::Unlink(0)]);
+        assert!(harness.press_key(KeyCode::Delete).is_empty());
+    }
+}
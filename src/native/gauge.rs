@@ -0,0 +1,603 @@
+//! Use a gauge to show a value on the arc of a dial.
+//!
+//! *This API requires the following crate features to be activated: `gauge`*
+use std::time::{Duration, Instant};
+
+use crate::core::gauge::{dial_point, tick_values, value_angle, START_ANGLE, SWEEP_ANGLE};
+
+use iced_widget::{
+    canvas::{self, Path},
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer,
+        text::{self, Renderer as _},
+        widget::{
+            tree::{State as TreeState, Tag},
+            Tree,
+        },
+        window, Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Renderer as _,
+        Shell, Size, Vector, Widget,
+    },
+    graphics::geometry::Renderer as _,
+    renderer::Renderer,
+    text::LineHeight,
+};
+
+pub use crate::{
+    core::{easing::Easing, gauge::Zone},
+    style::gauge::{Appearance, StyleSheet},
+};
+
+/// The padding around the dial.
+const PADDING: f32 = 4.0;
+/// The length of the major ticks of the scale.
+const MAJOR_TICK: f32 = 8.0;
+/// The length of the minor ticks of the scale.
+const MINOR_TICK: f32 = 4.0;
+/// The radius of the hub of the needle.
+const HUB_RADIUS: f32 = 6.0;
+
+/// A gauge showing a value with a needle on the arc of a dial, like a
+/// speedometer on a monitoring dashboard.
+///
+/// The arc is colored in the [`Zone`]s of the value, e.g. a warning and a
+/// critical zone, and labeled at the major ticks of its scale. The value is
+/// shown in the center, and the needle moves smoothly to a new value.
+///
+/// # Example
+/// ```ignore
+/// # use iced::Color;
+/// # use iced_aw::Gauge;
+/// #
+/// let gauge = Gauge::new(72.0)
+///     .range(0.0, 120.0)
+///     .zone(80.0, 100.0, Color::from_rgb(0.95, 0.7, 0.2))
+///     .zone(100.0, 120.0, Color::from_rgb(0.85, 0.25, 0.25))
+///     .label("km/h");
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Gauge<'a, Theme = iced_widget::style::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The shown value.
+    value: f32,
+    /// The lowest value of the scale.
+    min: f32,
+    /// The highest value of the scale.
+    max: f32,
+    /// The zones colored on the arc.
+    zones: Vec<Zone>,
+    /// The number of steps between the major ticks of the scale.
+    ticks: usize,
+    /// The number of steps between the minor ticks within a major step.
+    minor_ticks: usize,
+    /// The label shown below the value, e.g. its unit.
+    label: Option<String>,
+    /// The duration of the movement of the needle to a new value.
+    duration: Duration,
+    /// The easing of the movement of the needle to a new value.
+    easing: Easing,
+    /// The width of the [`Gauge`].
+    width: Length,
+    /// The height of the [`Gauge`].
+    height: Length,
+    /// The text size of the labels of the ticks.
+    text_size: f32,
+    /// The function formatting the labels of the ticks and the shown value.
+    format_value: Box<dyn Fn(f32) -> String + 'a>,
+    /// The style of the [`Gauge`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Theme> Gauge<'a, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Gauge`] showing the given value on a scale from 0 to
+    /// 100.
+    #[must_use]
+    pub fn new(value: f32) -> Self {
+        Self {
+            value,
+            min: 0.0,
+            max: 100.0,
+            zones: Vec::new(),
+            ticks: 5,
+            minor_ticks: 4,
+            label: None,
+            duration: Duration::from_millis(400),
+            easing: Easing::default(),
+            width: Length::Fill,
+            height: Length::Fill,
+            text_size: 12.0,
+            format_value: Box::new(|value| format!("{}", value.round())),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the lowest and the highest value of the scale of the [`Gauge`].
+    ///
+    /// Values outside of the range keep the needle at the start or the end of
+    /// the dial.
+    #[must_use]
+    pub fn range(mut self, min: f32, max: f32) -> Self {
+        self.min = min.min(max);
+        self.max = max.max(min);
+        self
+    }
+
+    /// Adds a [`Zone`] between the given values colored in the given color
+    /// to the arc of the [`Gauge`].
+    ///
+    /// Zones added later are drawn above the earlier ones.
+    #[must_use]
+    pub fn zone(mut self, start: f32, end: f32, color: Color) -> Self {
+        self.zones.push(Zone::new(start, end, color));
+        self
+    }
+
+    /// Sets the number of equal steps between the labeled major ticks of the
+    /// scale of the [`Gauge`].
+    #[must_use]
+    pub fn ticks(mut self, ticks: usize) -> Self {
+        self.ticks = ticks.max(1);
+        self
+    }
+
+    /// Sets the number of equal steps between the minor ticks within a step
+    /// of the major ticks, where 1 hides them.
+    #[must_use]
+    pub fn minor_ticks(mut self, minor_ticks: usize) -> Self {
+        self.minor_ticks = minor_ticks.max(1);
+        self
+    }
+
+    /// Sets the label shown below the value of the [`Gauge`], e.g. its unit.
+    #[must_use]
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the duration of the movement of the needle of the [`Gauge`] to a
+    /// new value, where zero moves it at once.
+    #[must_use]
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the easing of the movement of the needle of the [`Gauge`] to a
+    /// new value.
+    #[must_use]
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Sets the width of the [`Gauge`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Gauge`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the text size of the labels of the ticks of the [`Gauge`], with
+    /// the value shown at twice the size.
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the function formatting the labels of the ticks and the shown
+    /// value, which are rounded by default.
+    #[must_use]
+    pub fn format_value<F>(mut self, format_value: F) -> Self
+    where
+        F: 'a + Fn(f32) -> String,
+    {
+        self.format_value = Box::new(format_value);
+        self
+    }
+
+    /// Sets the style of the [`Gauge`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the value the needle moves to, which is kept on the scale.
+    fn target(&self) -> f32 {
+        if self.value.is_finite() {
+            self.value.clamp(self.min, self.max)
+        } else {
+            self.min
+        }
+    }
+}
+
+/// Returns the center and the radius of the arc of a dial within the given
+/// bounds.
+fn dial(bounds: Rectangle, track_width: f32) -> (Point, f32) {
+    // The dial is open at the bottom, so it is less tall than wide.
+    let depth = 1.0 + std::f32::consts::FRAC_1_SQRT_2;
+    let radius = (bounds.width / 2.0)
+        .min(bounds.height / depth)
+        .max(track_width + PADDING * 2.0)
+        - PADDING
+        - track_width / 2.0;
+    let center = Point::new(
+        bounds.center_x(),
+        bounds.y
+            + (bounds.height - (radius + track_width / 2.0) * depth) / 2.0
+            + radius
+            + track_width / 2.0,
+    );
+
+    (center, radius)
+}
+
+/// The state of a [`Gauge`].
+#[derive(Clone, Copy, Debug)]
+struct State {
+    /// The movement of the needle to its value.
+    needle: Needle,
+}
+
+/// The movement of the needle of a [`Gauge`] to a new value.
+#[derive(Clone, Copy, Debug)]
+struct Needle {
+    /// The value the needle started at.
+    from: f32,
+    /// The value the needle moves to.
+    to: f32,
+    /// The progress of the movement, from 0.0 to 1.0.
+    progress: f32,
+    /// The time of the last step of the movement.
+    last_update: Option<Instant>,
+}
+
+impl Needle {
+    /// Creates a new [`Needle`] resting at the given value.
+    fn new(value: f32) -> Self {
+        Self {
+            from: value,
+            to: value,
+            progress: 1.0,
+            last_update: None,
+        }
+    }
+
+    /// Returns the value the needle is shown at with the eased progress.
+    fn value(&self, easing: Easing) -> f32 {
+        self.from + (self.to - self.from) * easing.apply(self.progress)
+    }
+
+    /// Moves the needle from where it is shown to the given value.
+    fn aim(&mut self, value: f32, easing: Easing) {
+        if (value - self.to).abs() > f32::EPSILON {
+            *self = Self {
+                from: self.value(easing),
+                to: value,
+                progress: 0.0,
+                last_update: None,
+            };
+        }
+    }
+}
+
+impl<Message, Theme> Widget<Message, Renderer<Theme>> for Gauge<'_, Theme>
+where
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State {
+            needle: Needle::new(self.target()),
+        })
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer<Theme>, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        Node::new(limits.resolve(Size::new(f32::INFINITY, f32::INFINITY)))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _renderer: &Renderer<Theme>,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let needle = &mut tree.state.downcast_mut::<State>().needle;
+            needle.aim(self.target(), self.easing);
+
+            if needle.progress < 1.0 {
+                let last_update = needle.last_update.replace(now).unwrap_or(now);
+                needle.progress = if self.duration.is_zero() {
+                    1.0
+                } else {
+                    (needle.progress
+                        + (now - last_update).as_secs_f32() / self.duration.as_secs_f32())
+                    .min(1.0)
+                };
+
+                if needle.progress < 1.0 {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer<Theme>,
+    ) -> mouse::Interaction {
+        mouse::Interaction::default()
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer<Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let needle = tree.state.downcast_ref::<State>().needle.value(self.easing);
+        let line_height = self.text_size * 1.3;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        let track_width = appearance.track_width;
+        let (center, radius) = dial(bounds, track_width);
+        let center = center - Vector::new(bounds.x, bounds.y);
+        let angle = |value: f32| value_angle(value, self.min, self.max);
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+
+        // The arcs are traced with one corner per degree.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let arc = |from: f32, to: f32| {
+            let steps = ((to - from).to_degrees().ceil().max(1.0)) as usize;
+            #[allow(clippy::cast_precision_loss)]
+            let step_angle = |step: usize| from + (to - from) * step as f32 / steps as f32;
+            let outer = radius + track_width / 2.0;
+            let inner = radius - track_width / 2.0;
+
+            Path::new(|builder| {
+                builder.move_to(dial_point(center, outer, from));
+                for step in 1..=steps {
+                    builder.line_to(dial_point(center, outer, step_angle(step)));
+                }
+                for step in (0..=steps).rev() {
+                    builder.line_to(dial_point(center, inner, step_angle(step)));
+                }
+                builder.close();
+            })
+        };
+
+        frame.fill(
+            &arc(START_ANGLE, START_ANGLE + SWEEP_ANGLE),
+            appearance.track_color,
+        );
+        for zone in &self.zones {
+            let (from, to) = (angle(zone.start), angle(zone.end));
+            if to - from > f32::EPSILON {
+                frame.fill(&arc(from, to), zone.color);
+            }
+        }
+
+        // The ticks are thin quads inside of the arc.
+        let tick = |value: f32, length: f32, width: f32| {
+            let angle = angle(value);
+            let outer = radius - track_width / 2.0 - 2.0;
+            let across = Vector::new(-angle.sin(), angle.cos()) * (width / 2.0);
+
+            Path::new(|builder| {
+                builder.move_to(dial_point(center, outer, angle) + across);
+                builder.line_to(dial_point(center, outer - length, angle) + across);
+                builder.line_to(dial_point(center, outer - length, angle) - across);
+                builder.line_to(dial_point(center, outer, angle) - across);
+                builder.close();
+            })
+        };
+
+        let majors = tick_values(self.min, self.max, self.ticks);
+        for (step, value) in tick_values(self.min, self.max, self.ticks * self.minor_ticks)
+            .into_iter()
+            .enumerate()
+        {
+            let path = if step % self.minor_ticks == 0 {
+                tick(value, MAJOR_TICK, 2.0)
+            } else {
+                tick(value, MINOR_TICK, 1.0)
+            };
+            frame.fill(&path, appearance.tick_color);
+        }
+
+        // The needle tapers from its hub to just inside of the ticks.
+        let needle_angle = angle(needle);
+        let across = Vector::new(-needle_angle.sin(), needle_angle.cos()) * (HUB_RADIUS / 2.0);
+        let length = radius - track_width / 2.0 - 2.0;
+        let needle_path = Path::new(|builder| {
+            builder.move_to(dial_point(center, length, needle_angle));
+            builder.line_to(center + across);
+            builder.line_to(dial_point(
+                center,
+                HUB_RADIUS,
+                needle_angle + std::f32::consts::PI,
+            ));
+            builder.line_to(center - across);
+            builder.close();
+        });
+        frame.fill(&needle_path, appearance.needle_color);
+        frame.fill(&Path::circle(center, HUB_RADIUS), appearance.needle_color);
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+                renderer.draw(vec![frame.into_geometry()]);
+            });
+        });
+
+        let center = center + Vector::new(bounds.x, bounds.y);
+        let label_radius =
+            radius - track_width / 2.0 - 2.0 - MAJOR_TICK - PADDING - self.text_size / 2.0;
+
+        renderer.with_layer(bounds, |renderer| {
+            for value in &majors {
+                let position = dial_point(center, label_radius, angle(*value));
+
+                renderer.fill_text(core::text::Text {
+                    content: &(self.format_value)(*value),
+                    bounds: Rectangle {
+                        x: position.x,
+                        y: position.y,
+                        width: label_radius,
+                        height: line_height,
+                    },
+                    size: self.text_size,
+                    line_height: LineHeight::default(),
+                    color: appearance.text_color,
+                    font: core::Font::default(),
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                });
+            }
+
+            // The value is shown in the open bottom of the dial.
+            let value_size = self.text_size * 2.0;
+            let value_y = center.y + radius * 0.45;
+
+            renderer.fill_text(core::text::Text {
+                content: &(self.format_value)(self.value),
+                bounds: Rectangle {
+                    x: center.x,
+                    y: value_y,
+                    width: radius * 2.0,
+                    height: value_size * 1.3,
+                },
+                size: value_size,
+                line_height: LineHeight::default(),
+                color: appearance.value_color,
+                font: core::Font::default(),
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Advanced,
+            });
+
+            if let Some(label) = &self.label {
+                renderer.fill_text(core::text::Text {
+                    content: label,
+                    bounds: Rectangle {
+                        x: center.x,
+                        y: value_y + value_size * 0.65 + line_height / 2.0,
+                        width: radius * 2.0,
+                        height: line_height,
+                    },
+                    size: self.text_size,
+                    line_height: LineHeight::default(),
+                    color: appearance.text_color,
+                    font: core::Font::default(),
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                });
+            }
+        });
+    }
+}
+
+impl<'a, Message, Theme> From<Gauge<'a, Theme>> for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+{
+    fn from(gauge: Gauge<'a, Theme>) -> Self {
+        Element::new(gauge)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Gauge;
+    use crate::test_utils::Harness;
+    use iced_widget::core::{Color, Size};
+    use std::time::Duration;
+
+    #[test]
+    fn gauge_test() {
+        let gauge = Gauge::new(75.0)
+            .zone(0.0, 50.0, Color::from_rgb(0.0, 0.8, 0.0))
+            .zone(50.0, 100.0, Color::from_rgb(0.8, 0.0, 0.0));
+        let mut harness = Harness::<()>::new(gauge, Size::new(200.0, 200.0));
+        let snapshot = harness.snapshot();
+        let color = |x, y| snapshot.color(x, y).expect("Pixel is in bounds");
+
+        // The zones color the arc from its bottom left to its bottom right.
+        let low = color(14, 142);
+        assert!(low.g > 0.5 && low.r < 0.2);
+        let high = color(186, 142);
+        assert!(high.r > 0.5 && high.g < 0.2);
+
+        // The needle points to the upper right.
+        let needle = color(137, 99);
+        assert!(needle.b > needle.r);
+        let empty = color(63, 99);
+        assert!(empty.r > 0.9 && empty.b > 0.9);
+
+        // The needle rests at its value.
+        let _ = harness.advance(Duration::from_millis(100));
+        assert_eq!(harness.snapshot().diff(&snapshot, 0), Some(0));
+    }
+}
@@ -0,0 +1,541 @@
+//! Use a map to color the regions of the world by a value.
+//!
+//! *This API requires the following crate features to be activated: `geo_map`*
+use std::collections::HashMap;
+
+use crate::core::{
+    geo_map::{aspect_ratio, project, unproject},
+    viewport::Viewport,
+};
+use crate::native::helpers::chart::{self, mix};
+
+use iced_widget::{
+    canvas::{self, Path, Stroke},
+    core::{
+        self, event,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer, touch,
+        widget::{
+            tree::{State as TreeState, Tag},
+            Tree,
+        },
+        Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Renderer as _, Shell,
+        Size, Vector, Widget,
+    },
+    graphics::geometry::Renderer as _,
+    renderer::Renderer,
+};
+
+pub use crate::core::geo_map::{world, Region};
+pub use crate::style::geo_map::{Appearance, StyleSheet};
+
+/// The distance in pixels the cursor has to move while pressed before the
+/// press becomes a pan instead of a click.
+const PAN_THRESHOLD: f32 = 3.0;
+/// The factor the zoom changes by for every line scrolled.
+const ZOOM_STEP: f32 = 1.2;
+
+/// A choropleth map coloring its regions by a value.
+///
+/// Hovering a region shows its name and value in a tooltip, and clicking it
+/// produces a message with its identifier. The map is zoomed with the mouse
+/// wheel and panned by dragging.
+///
+/// # Example
+/// ```ignore
+/// # use std::collections::HashMap;
+/// # use iced_aw::{geo_map::world, GeoMap};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     RegionPressed(String),
+/// }
+///
+/// let regions = world();
+/// let values = HashMap::from([(String::from("FRA"), 68.0), (String::from("DEU"), 84.0)]);
+///
+/// let geo_map = GeoMap::new(&regions, &values).on_press(Message::RegionPressed);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct GeoMap<'a, Message, Theme = iced_widget::style::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The regions of the map.
+    regions: &'a [Region],
+    /// The values of the regions by their identifiers.
+    values: &'a HashMap<String, f32>,
+    /// The function producing the message when a region is clicked.
+    on_press: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    /// The range of the values mapped to the colors, if not the range of the
+    /// given values.
+    range: Option<(f32, f32)>,
+    /// The function formatting a value for the tooltip.
+    format: Box<dyn Fn(f32) -> String + 'a>,
+    /// The width of the [`GeoMap`].
+    width: Length,
+    /// The height of the [`GeoMap`].
+    height: Length,
+    /// The maximal zoom of the [`GeoMap`].
+    max_zoom: f32,
+    /// The text size of the tooltip.
+    text_size: f32,
+    /// The style of the [`GeoMap`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> GeoMap<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`GeoMap`] of the given regions, colored by the values
+    /// of their identifiers.
+    ///
+    /// Regions without a value are drawn in the color for missing data.
+    #[must_use]
+    pub fn new(regions: &'a [Region], values: &'a HashMap<String, f32>) -> Self {
+        Self {
+            regions,
+            values,
+            on_press: None,
+            range: None,
+            format: Box::new(|value| format!("{value}")),
+            width: Length::Fill,
+            height: Length::Shrink,
+            max_zoom: 32.0,
+            text_size: 14.0,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the function producing the message with the identifier of a
+    /// clicked region.
+    #[must_use]
+    pub fn on_press<F>(mut self, on_press: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        self.on_press = Some(Box::new(on_press));
+        self
+    }
+
+    /// Sets the range of the values mapped from the lowest to the highest
+    /// color, instead of the range of the given values.
+    #[must_use]
+    pub fn range(mut self, min: f32, max: f32) -> Self {
+        if min < max {
+            self.range = Some((min, max));
+        }
+        self
+    }
+
+    /// Sets the function formatting the values shown in the tooltip.
+    #[must_use]
+    pub fn format<F>(mut self, format: F) -> Self
+    where
+        F: 'a + Fn(f32) -> String,
+    {
+        self.format = Box::new(format);
+        self
+    }
+
+    /// Sets the width of the [`GeoMap`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`GeoMap`].
+    ///
+    /// By default, the height follows the aspect ratio of the map.
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the maximal zoom of the [`GeoMap`], relative to the whole map.
+    #[must_use]
+    pub fn max_zoom(mut self, max_zoom: f32) -> Self {
+        self.max_zoom = max_zoom.max(1.0);
+        self
+    }
+
+    /// Sets the text size of the tooltip of the [`GeoMap`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the style of the [`GeoMap`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the range of the values mapped to the colors.
+    fn value_range(&self) -> Option<(f32, f32)> {
+        self.range.or_else(|| {
+            self.regions
+                .iter()
+                .filter_map(|region| self.values.get(&region.id).copied())
+                .fold(None, |range, value| match range {
+                    None => Some((value, value)),
+                    Some((min, max)) => Some((f32::min(min, value), f32::max(max, value))),
+                })
+        })
+    }
+
+    /// Returns the region at the given position.
+    fn region_at(&self, bounds: Rectangle, state: &State, position: Point) -> Option<&Region> {
+        let (x, y) = state.map_position(bounds, position);
+        let (longitude, latitude) = unproject(x, y);
+
+        self.regions
+            .iter()
+            .find(|region| region.contains(longitude, latitude))
+    }
+}
+
+/// The state of a [`GeoMap`].
+#[derive(Clone, Copy, Debug, Default)]
+struct State {
+    /// The pan and zoom of the map, whose content is the whole map at a zoom
+    /// of 1.0 centered on the origin.
+    viewport: Viewport,
+    /// The position the ongoing press started at, with the pan offset at that
+    /// time.
+    press: Option<(Point, Vector)>,
+    /// Whether the ongoing press moved far enough to pan the map.
+    panning: bool,
+}
+
+impl State {
+    /// Returns the size of the whole map at a zoom of 1.0, fitting the widget.
+    fn map_size(bounds: Rectangle) -> Size {
+        let width = bounds.width.min(bounds.height * aspect_ratio());
+
+        Size::new(width, width / aspect_ratio())
+    }
+
+    /// Returns the position in the widget of the given position on the map.
+    fn screen_position(&self, bounds: Rectangle, (x, y): (f32, f32)) -> Point {
+        let size = Self::map_size(bounds);
+
+        self.viewport.project(
+            bounds,
+            Point::new((x - 0.5) * size.width, (y - 0.5) * size.height),
+        )
+    }
+
+    /// Returns the position on the map at the given position in the widget.
+    fn map_position(&self, bounds: Rectangle, position: Point) -> (f32, f32) {
+        let size = Self::map_size(bounds);
+        let position = self.viewport.unproject(bounds, position);
+
+        (
+            0.5 + position.x / size.width.max(f32::EPSILON),
+            0.5 + position.y / size.height.max(f32::EPSILON),
+        )
+    }
+
+    /// Limits the pan offset so that the map covers as much of the widget as
+    /// possible, centering the map along the axes where it is smaller than
+    /// the widget.
+    fn clamp_offset(&mut self, bounds: Rectangle) {
+        let size = Self::map_size(bounds);
+        let clamp = |offset: f32, extent: f32, available: f32| {
+            let slack = (extent * self.viewport.zoom - available) / 2.0;
+            if slack <= 0.0 {
+                0.0
+            } else {
+                offset.clamp(-slack, slack)
+            }
+        };
+
+        self.viewport.offset = Vector::new(
+            clamp(self.viewport.offset.x, size.width, bounds.width),
+            clamp(self.viewport.offset.y, size.height, bounds.height),
+        );
+    }
+
+    /// Zooms by the given factor, keeping the position on the map under the
+    /// cursor in place.
+    fn zoom_at(&mut self, bounds: Rectangle, position: Point, factor: f32, max_zoom: f32) {
+        self.viewport
+            .zoom_at(bounds, position, factor, (1.0, max_zoom));
+        self.clamp_offset(bounds);
+    }
+}
+
+impl<'a, Message, Theme> Widget<Message, Renderer<Theme>> for GeoMap<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer<Theme>, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        let width = limits.max().width;
+
+        Node::new(limits.resolve(Size::new(width, width / aspect_ratio())))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer<Theme>,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    let lines = match delta {
+                        mouse::ScrollDelta::Lines { y, .. } => y,
+                        mouse::ScrollDelta::Pixels { y, .. } => y / 60.0,
+                    };
+                    state.zoom_at(bounds, position, ZOOM_STEP.powf(lines), self.max_zoom);
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    state.press = Some((position, state.viewport.offset));
+                    state.panning = false;
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some((origin, offset)) = state.press {
+                    let delta = position - origin;
+                    if state.panning || delta.x.hypot(delta.y) > PAN_THRESHOLD {
+                        state.panning = true;
+                        state.viewport.offset = offset;
+                        state.viewport.pan(delta);
+                        state.clamp_offset(bounds);
+                    }
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if let Some((origin, _)) = state.press.take() {
+                    if !state.panning {
+                        if let (Some(on_press), Some(region)) =
+                            (&self.on_press, self.region_at(bounds, state, origin))
+                        {
+                            shell.publish(on_press(region.id.clone()));
+                        }
+                    }
+                    state.panning = false;
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer<Theme>,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        if state.panning {
+            mouse::Interaction::Grabbing
+        } else if self.on_press.is_some()
+            && cursor
+                .position_over(bounds)
+                .is_some_and(|position| self.region_at(bounds, state, position).is_some())
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer<Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let range = self.value_range();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        let hovered = if state.panning {
+            None
+        } else {
+            cursor
+                .position_over(bounds)
+                .and_then(|position| self.region_at(bounds, state, position))
+        };
+
+        let offset = Vector::new(-bounds.x, -bounds.y);
+        let outline = |region: &Region| {
+            Path::new(|builder| {
+                for polygon in &region.polygons {
+                    let mut points = polygon.iter().map(|&(longitude, latitude)| {
+                        state.screen_position(bounds, project(longitude, latitude)) + offset
+                    });
+                    if let Some(first) = points.next() {
+                        builder.move_to(first);
+                        points.for_each(|point| builder.line_to(point));
+                        builder.close();
+                    }
+                }
+            })
+        };
+
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+        for region in self.regions {
+            let path = outline(region);
+            let color = match (self.values.get(&region.id), range) {
+                (Some(&value), Some((min, max))) => {
+                    let t = if max > min {
+                        ((value - min) / (max - min)).clamp(0.0, 1.0)
+                    } else {
+                        1.0
+                    };
+                    mix(appearance.low_color, appearance.high_color, t)
+                }
+                _ => appearance.no_data_color,
+            };
+
+            frame.fill(&path, color);
+            frame.stroke(
+                &path,
+                Stroke::default()
+                    .with_color(appearance.border_color)
+                    .with_width(appearance.border_width),
+            );
+        }
+        if let Some(region) = hovered {
+            frame.stroke(
+                &outline(region),
+                Stroke::default()
+                    .with_color(appearance.hovered_border_color)
+                    .with_width(appearance.border_width.max(1.0) * 2.0),
+            );
+        }
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+                renderer.draw(vec![frame.into_geometry()]);
+            });
+        });
+
+        let (Some(region), Some(position)) = (hovered, cursor.position()) else {
+            return;
+        };
+
+        let content = match self.values.get(&region.id) {
+            Some(&value) => format!("{}: {}", region.name, (self.format)(value)),
+            None => region.name.clone(),
+        };
+        chart::tooltip(
+            renderer,
+            &[content],
+            self.text_size,
+            core::text::Shaping::Basic,
+            position,
+            bounds,
+            appearance.tooltip_background,
+            appearance.tooltip_text_color,
+        );
+    }
+}
+
+impl<'a, Message, Theme> From<GeoMap<'a, Message, Theme>> for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+{
+    fn from(geo_map: GeoMap<'a, Message, Theme>) -> Self {
+        Element::new(geo_map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{world, GeoMap};
+    use crate::test_utils::Harness;
+    use iced_widget::core::{Point, Size};
+    use std::collections::HashMap;
+
+    #[test]
+    fn geo_map_test() {
+        let regions = world();
+        let values = HashMap::from([(String::from("FRA"), 1.0)]);
+        let geo_map = GeoMap::new(&regions, &values)
+            .on_press(|id| id)
+            .width(360)
+            .height(145);
+        let mut harness = Harness::new(geo_map, Size::new(360.0, 145.0));
+
+        // The whole map fills the widget, with one pixel per degree.
+        let paris = Point::new(182.3, 36.1);
+        assert_eq!(harness.click(paris), vec!["FRA"]);
+        assert!(harness.click(Point::new(150.0, 50.0)).is_empty());
+
+        // Zooming keeps the region under the cursor in place.
+        let _ = harness.move_cursor(paris);
+        let _ = harness.scroll(iced_widget::core::Vector::new(0.0, 1.0));
+        assert_eq!(harness.click(paris), vec!["FRA"]);
+
+        // Panning moves the map with the cursor without clicking.
+        assert!(harness.drag(paris, Point::new(162.3, 36.1), 5).is_empty());
+        assert_eq!(harness.click(Point::new(162.3, 36.1)), vec!["FRA"]);
+    }
+}
@@ -0,0 +1,276 @@
+//! Use a gesture detector to turn raw mouse and touch events into taps, long
+//! presses, swipes and pinches.
+//!
+//! *This API requires the following crate features to be activated: `gesture_detector`*
+pub use crate::core::gesture::{Gesture, SwipeDirection, Thresholds};
+
+use crate::core::gesture::Recognizer;
+
+use iced_widget::core::{
+    self, event,
+    layout::{Limits, Node},
+    mouse::{self, Cursor},
+    overlay, renderer, touch,
+    widget::{
+        tree::{State as TreeState, Tag},
+        Operation, Tree,
+    },
+    window, Clipboard, Element, Event, Layout, Length, Rectangle, Shell, Widget,
+};
+use std::time::Instant;
+
+/// The pointer id of the left mouse button.
+const MOUSE_POINTER: u64 = u64::MAX;
+
+/// A wrapper recognizing [`Gesture`]s on its content.
+///
+/// The events are still passed on to the content. The positions of the
+/// [`Gesture`]s are in the same coordinates as the cursor.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::Text;
+/// # use iced_aw::{gesture_detector::Gesture, GestureDetector};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Gesture(Gesture),
+/// }
+///
+/// let gesture_detector = GestureDetector::new(Text::new("Swipe me"), Message::Gesture);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct GestureDetector<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer,
+{
+    /// The content of the [`GestureDetector`].
+    content: Element<'a, Message, Renderer>,
+    /// The function producing the message of a recognized [`Gesture`], if
+    /// it is handled.
+    on_gesture: Box<dyn Fn(Gesture) -> Option<Message> + 'a>,
+    /// The thresholds of the recognized [`Gesture`]s.
+    thresholds: Thresholds,
+}
+
+impl<'a, Message, Renderer> GestureDetector<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    /// Creates a new [`GestureDetector`] around the given content.
+    ///
+    /// It expects:
+    ///     * the content [`Element`] of the [`GestureDetector`].
+    ///     * the function producing the message of a recognized [`Gesture`].
+    pub fn new<C, F>(content: C, on_gesture: F) -> Self
+    where
+        C: Into<Element<'a, Message, Renderer>>,
+        F: 'a + Fn(Gesture) -> Message,
+    {
+        Self::filter_map(content, move |gesture| Some(on_gesture(gesture)))
+    }
+
+    /// Creates a new [`GestureDetector`] around the given content, only
+    /// handling the [`Gesture`]s for which the function produces a message.
+    ///
+    /// The unhandled [`Gesture`]s do not capture their events.
+    pub fn filter_map<C, F>(content: C, on_gesture: F) -> Self
+    where
+        C: Into<Element<'a, Message, Renderer>>,
+        F: 'a + Fn(Gesture) -> Option<Message>,
+    {
+        Self {
+            content: content.into(),
+            on_gesture: Box::new(on_gesture),
+            thresholds: Thresholds::default(),
+        }
+    }
+
+    /// Sets the [`Thresholds`] of the [`Gesture`]s recognized by the
+    /// [`GestureDetector`].
+    #[must_use]
+    pub fn thresholds(mut self, thresholds: Thresholds) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for GestureDetector<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<Recognizer>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(Recognizer::new())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let recognizer = tree.state.downcast_mut::<Recognizer>();
+        let bounds = layout.bounds();
+        let now = Instant::now();
+
+        let gesture = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    recognizer.press(MOUSE_POINTER, position, now);
+                }
+                None
+            }
+            Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                if bounds.contains(position) {
+                    recognizer.press(id.0, position, now);
+                }
+                None
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                recognizer.moved(MOUSE_POINTER, position, &self.thresholds)
+            }
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                recognizer.moved(id.0, position, &self.thresholds)
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                cursor.position().and_then(|position| {
+                    recognizer.lift(MOUSE_POINTER, position, now, &self.thresholds)
+                })
+            }
+            Event::Touch(touch::Event::FingerLifted { id, position }) => {
+                recognizer.lift(id.0, position, now, &self.thresholds)
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                recognizer.cancel();
+                None
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                recognizer.tick(now, &self.thresholds)
+            }
+            _ => None,
+        };
+
+        if let Some(deadline) = recognizer.long_press_deadline(&self.thresholds) {
+            shell.request_redraw(window::RedrawRequest::At(deadline));
+        }
+
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        gesture
+            .and_then(&self.on_gesture)
+            .map_or(status, |message| {
+                shell.publish(message);
+                event::Status::Captured
+            })
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate<'b>(
+        &'b self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<GestureDetector<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + core::Renderer,
+{
+    fn from(gesture_detector: GestureDetector<'a, Message, Renderer>) -> Self {
+        Element::new(gesture_detector)
+    }
+}
@@ -7,6 +7,34 @@ use iced_widget::core::{self, Color, Element};
 #[allow(unused_imports)]
 use std::{borrow::Cow, fmt::Display, hash::Hash};
 
+#[cfg(any(
+    feature = "find_bar",
+    feature = "settings_page",
+    feature = "auth_form",
+    feature = "onboarding",
+    feature = "contrast_checker",
+    feature = "theme_editor"
+))]
+mod styled;
+#[cfg(any(
+    feature = "find_bar",
+    feature = "settings_page",
+    feature = "auth_form",
+    feature = "onboarding",
+    feature = "contrast_checker",
+    feature = "theme_editor"
+))]
+pub(crate) use styled::Styled;
+
+#[cfg(any(
+    feature = "geo_map",
+    feature = "treemap",
+    feature = "sunburst",
+    feature = "live_chart",
+    feature = "radar"
+))]
+pub(crate) mod chart;
+
 /// Creates a [`Grid`] with the given [`GridRow`]s.
 ///
 /// [`Grid`]: crate::Grid
@@ -167,7 +195,8 @@ where
     Theme: 'a
         + crate::style::color_picker::StyleSheet
         + iced_widget::button::StyleSheet
-        + iced_widget::text::StyleSheet,
+        + iced_widget::text::StyleSheet
+        + iced_widget::text_input::StyleSheet,
     F: 'static + Fn(Color) -> Message,
 {
     crate::ColorPicker::new(show_picker, color, underlay, on_cancel, on_submit)
@@ -196,6 +225,52 @@ where
     crate::DatePicker::new(show_picker, date, underlay, on_cancel, on_submit)
 }
 
+#[cfg(feature = "date_picker")]
+/// Shortcut helper to create a [`DatePicker`] Widget for picking any number of dates.
+///
+/// [`DatePicker`]: crate::DatePicker
+pub fn date_picker_multiple<'a, Message, Theme, F>(
+    show_picker: bool,
+    dates: impl IntoIterator<Item = crate::core::date::Date>,
+    underlay: impl Into<Element<'a, Message, iced_widget::renderer::Renderer<Theme>>>,
+    on_cancel: Message,
+    on_submit: F,
+) -> crate::DatePicker<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + crate::style::date_picker::StyleSheet
+        + iced_widget::button::StyleSheet
+        + iced_widget::text::StyleSheet
+        + iced_widget::container::StyleSheet,
+    F: 'static + Fn(std::collections::BTreeSet<crate::core::date::Date>) -> Message,
+{
+    crate::DatePicker::new_multiple(show_picker, dates, underlay, on_cancel, on_submit)
+}
+
+#[cfg(feature = "date_picker")]
+/// Shortcut helper to create a [`DatePicker`] Widget for picking a range of dates.
+///
+/// [`DatePicker`]: crate::DatePicker
+pub fn date_picker_range<'a, Message, Theme, F>(
+    show_picker: bool,
+    range: (crate::core::date::Date, crate::core::date::Date),
+    underlay: impl Into<Element<'a, Message, iced_widget::renderer::Renderer<Theme>>>,
+    on_cancel: Message,
+    on_submit: F,
+) -> crate::DatePicker<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + crate::style::date_picker::StyleSheet
+        + iced_widget::button::StyleSheet
+        + iced_widget::text::StyleSheet
+        + iced_widget::container::StyleSheet,
+    F: 'static + Fn(crate::core::date::Date, crate::core::date::Date) -> Message,
+{
+    crate::DatePicker::new_range(show_picker, range, underlay, on_cancel, on_submit)
+}
+
 #[cfg(feature = "floating_element")]
 /// Shortcut helper to create a [`FloatingElement`] Widget.
 ///
@@ -278,7 +353,6 @@ pub fn modal<'a, Message, Renderer>(
     overlay: Option<impl Into<Element<'a, Message, Renderer>>>,
 ) -> crate::Modal<'a, Message, Renderer>
 where
-    Message: Clone,
     Renderer: core::Renderer,
     Renderer::Theme: crate::style::modal::StyleSheet,
 {
@@ -368,3 +442,958 @@ where
 {
     crate::SelectionList::new(options, on_selected)
 }
+
+#[cfg(feature = "drawer")]
+/// Shortcut helper to create a [`Drawer`] Widget.
+///
+/// [`Drawer`]: crate::Drawer
+#[must_use]
+pub fn drawer<'a, Message, Renderer>(
+    open: bool,
+    underlay: impl Into<Element<'a, Message, Renderer>>,
+    content: impl Into<Element<'a, Message, Renderer>>,
+) -> crate::Drawer<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer,
+    Renderer::Theme: crate::style::drawer::StyleSheet,
+{
+    crate::Drawer::new(open, underlay, content)
+}
+
+#[cfg(feature = "notification_center")]
+/// Shortcut helper to create a [`NotificationCenter`] Widget.
+///
+/// [`NotificationCenter`]: crate::NotificationCenter
+#[must_use]
+pub fn notification_center<'a, Message, Theme>(
+    open: bool,
+    underlay: impl Into<Element<'a, Message, iced_widget::renderer::Renderer<Theme>>>,
+    notifications: &'a [crate::Notification<Message>],
+) -> crate::NotificationCenter<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + crate::style::notification_center::StyleSheet
+        + crate::style::drawer::StyleSheet
+        + iced_widget::button::StyleSheet
+        + iced_widget::text::StyleSheet
+        + iced_widget::container::StyleSheet
+        + iced_widget::scrollable::StyleSheet,
+{
+    crate::NotificationCenter::new(open, underlay, notifications)
+}
+
+#[cfg(feature = "history_list")]
+/// Shortcut helper to create a [`HistoryList`] Widget.
+///
+/// [`HistoryList`]: crate::HistoryList
+#[must_use]
+pub fn history_list<'a, T, Message, Renderer, F>(
+    entries: &'a [T],
+    current: usize,
+    on_jump: F,
+) -> crate::HistoryList<'a, T, Message, Renderer>
+where
+    T: Display,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: crate::style::history_list::StyleSheet,
+    F: 'a + Fn(usize) -> Message,
+{
+    crate::HistoryList::new(entries, current, on_jump)
+}
+
+#[cfg(feature = "time_ruler")]
+/// Shortcut helper to create a [`TimeRuler`] Widget.
+///
+/// [`TimeRuler`]: crate::TimeRuler
+#[must_use]
+pub fn time_ruler<'a, Renderer>(start: f64, end: f64) -> crate::TimeRuler<'a, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: crate::style::time_ruler::StyleSheet,
+{
+    crate::TimeRuler::new(start, end)
+}
+
+#[cfg(feature = "waveform")]
+/// Shortcut helper to create a [`Waveform`] Widget.
+///
+/// [`Waveform`]: crate::Waveform
+#[must_use]
+pub fn waveform<'a, Message, Renderer>(
+    peaks: &'a crate::Peaks,
+) -> crate::Waveform<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: crate::style::waveform::StyleSheet,
+{
+    crate::Waveform::new(peaks)
+}
+
+#[cfg(feature = "seek_bar")]
+/// Shortcut helper to create a [`SeekBar`] Widget.
+///
+/// [`SeekBar`]: crate::SeekBar
+#[must_use]
+pub fn seek_bar<'a, Message, Renderer, F>(
+    duration: f64,
+    position: f64,
+    on_seek: F,
+) -> crate::SeekBar<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: crate::style::seek_bar::StyleSheet,
+    F: 'a + Fn(f64) -> Message,
+{
+    crate::SeekBar::new(duration, position, on_seek)
+}
+
+#[cfg(feature = "transport_controls")]
+/// Shortcut helper to create [`TransportControls`] Widget.
+///
+/// [`TransportControls`]: crate::TransportControls
+#[must_use]
+pub fn transport_controls<'a, Message, Renderer, F>(
+    playing: bool,
+    position: f64,
+    duration: f64,
+    volume: f32,
+    on_event: F,
+) -> crate::TransportControls<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme:
+        crate::style::transport_controls::StyleSheet + crate::style::seek_bar::StyleSheet,
+    F: 'a + Fn(crate::TransportEvent) -> Message,
+{
+    crate::TransportControls::new(playing, position, duration, volume, on_event)
+}
+
+#[cfg(feature = "volume_slider")]
+/// Shortcut helper to create a [`VolumeSlider`] Widget.
+///
+/// [`VolumeSlider`]: crate::VolumeSlider
+#[must_use]
+pub fn volume_slider<'a, Message, Renderer, F>(
+    volume: f32,
+    on_change: F,
+) -> crate::VolumeSlider<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: crate::style::volume_slider::StyleSheet,
+    F: 'a + Fn(f32) -> Message,
+{
+    crate::VolumeSlider::new(volume, on_change)
+}
+
+#[cfg(feature = "equalizer")]
+/// Shortcut helper to create an [`Equalizer`] Widget.
+///
+/// [`Equalizer`]: crate::Equalizer
+#[must_use]
+pub fn equalizer<'a, Message, Theme, F>(
+    bands: &'a [crate::Band],
+    on_change: F,
+) -> crate::Equalizer<'a, Message, Theme>
+where
+    Theme: crate::style::equalizer::StyleSheet,
+    F: 'a + Fn(usize, f32) -> Message,
+{
+    crate::Equalizer::new(bands, on_change)
+}
+
+#[cfg(feature = "geo_map")]
+/// Shortcut helper to create a [`GeoMap`] Widget.
+///
+/// [`GeoMap`]: crate::GeoMap
+#[must_use]
+#[allow(clippy::implicit_hasher)]
+pub fn geo_map<'a, Message, Theme>(
+    regions: &'a [crate::Region],
+    values: &'a std::collections::HashMap<String, f32>,
+) -> crate::GeoMap<'a, Message, Theme>
+where
+    Theme: crate::style::geo_map::StyleSheet,
+{
+    crate::GeoMap::new(regions, values)
+}
+
+#[cfg(feature = "piano_keys")]
+/// Shortcut helper to create [`PianoKeys`] Widget.
+///
+/// [`PianoKeys`]: crate::PianoKeys
+#[must_use]
+pub fn piano_keys<'a, Message, Renderer, F, G>(
+    lowest: u8,
+    highest: u8,
+    on_note_on: F,
+    on_note_off: G,
+) -> crate::PianoKeys<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: crate::style::piano_keys::StyleSheet,
+    F: 'a + Fn(u8) -> Message,
+    G: 'a + Fn(u8) -> Message,
+{
+    crate::PianoKeys::new(lowest, highest, on_note_on, on_note_off)
+}
+
+#[cfg(feature = "step_grid")]
+/// Shortcut helper to create a [`StepGrid`] Widget.
+///
+/// [`StepGrid`]: crate::StepGrid
+#[must_use]
+pub fn step_grid<'a, Message, Renderer, F>(
+    cells: &'a [Vec<Option<f32>>],
+    on_change: F,
+) -> crate::StepGrid<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: crate::style::step_grid::StyleSheet,
+    F: 'a + Fn(usize, usize, Option<f32>) -> Message,
+{
+    crate::StepGrid::new(cells, on_change)
+}
+
+#[cfg(feature = "editable_grid")]
+/// Shortcut helper to create an [`EditableGrid`] Widget.
+///
+/// [`EditableGrid`]: crate::EditableGrid
+#[must_use]
+pub fn editable_grid<'a, Message, Renderer, F>(
+    cells: &'a [Vec<String>],
+    on_edit: F,
+) -> crate::EditableGrid<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: crate::style::editable_grid::StyleSheet,
+    F: 'a + Fn(usize, usize, String) -> Message,
+{
+    crate::EditableGrid::new(cells, on_edit)
+}
+
+#[cfg(feature = "tree_view")]
+/// Shortcut helper to create a [`TreeView`] Widget.
+///
+/// [`TreeView`]: crate::TreeView
+#[must_use]
+pub fn tree_view<'a, Message, Renderer>(
+    nodes: &'a [crate::TreeNode],
+) -> crate::TreeView<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: crate::style::tree_view::StyleSheet,
+{
+    crate::TreeView::new(nodes)
+}
+
+#[cfg(feature = "org_chart")]
+/// Shortcut helper to create an [`OrgChart`] Widget.
+///
+/// [`OrgChart`]: crate::OrgChart
+#[must_use]
+pub fn org_chart<'a, Message, Renderer>(
+    root: crate::OrgNode<'a, Message, Renderer>,
+) -> crate::OrgChart<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: crate::style::org_chart::StyleSheet,
+{
+    crate::OrgChart::new(root)
+}
+
+#[cfg(feature = "mind_map")]
+/// Shortcut helper to create a [`MindMap`] Widget.
+///
+/// [`MindMap`]: crate::MindMap
+#[must_use]
+pub fn mind_map<'a, Message, Theme>(root: &'a crate::TreeNode) -> crate::MindMap<'a, Message, Theme>
+where
+    Theme: crate::style::mind_map::StyleSheet,
+{
+    crate::MindMap::new(root)
+}
+
+#[cfg(feature = "gantt")]
+/// Shortcut helper to create a [`Gantt`] Widget.
+///
+/// [`Gantt`]: crate::Gantt
+#[must_use]
+pub fn gantt<'a, Message, Theme>(
+    tasks: &'a [crate::gantt::Task],
+    links: &'a [crate::gantt::Link],
+) -> crate::Gantt<'a, Message, Theme>
+where
+    Theme: crate::style::gantt::StyleSheet,
+{
+    crate::Gantt::new(tasks, links)
+}
+
+#[cfg(feature = "treemap")]
+/// Shortcut helper to create a [`Treemap`] Widget.
+///
+/// [`Treemap`]: crate::Treemap
+#[must_use]
+pub fn treemap<'a, Message, Theme>(
+    root: &'a crate::HierarchyNode,
+) -> crate::Treemap<'a, Message, Theme>
+where
+    Theme: crate::style::treemap::StyleSheet,
+{
+    crate::Treemap::new(root)
+}
+
+#[cfg(feature = "sunburst")]
+/// Shortcut helper to create a [`Sunburst`] Widget.
+///
+/// [`Sunburst`]: crate::Sunburst
+#[must_use]
+pub fn sunburst<'a, Message, Theme>(
+    root: &'a crate::HierarchyNode,
+) -> crate::Sunburst<'a, Message, Theme>
+where
+    Theme: crate::style::sunburst::StyleSheet,
+{
+    crate::Sunburst::new(root)
+}
+
+#[cfg(feature = "live_chart")]
+/// Shortcut helper to create a [`LiveChart`] Widget.
+///
+/// [`LiveChart`]: crate::LiveChart
+#[must_use]
+pub fn live_chart<Theme>(series: &[crate::live_chart::Series]) -> crate::LiveChart<'_, Theme>
+where
+    Theme: crate::style::live_chart::StyleSheet,
+{
+    crate::LiveChart::new(series)
+}
+
+#[cfg(feature = "candlestick")]
+/// Shortcut helper to create a [`CandlestickChart`] Widget.
+///
+/// [`CandlestickChart`]: crate::CandlestickChart
+#[must_use]
+pub fn candlestick_chart<Theme>(
+    candles: &[crate::candlestick::Candle],
+) -> crate::CandlestickChart<'_, Theme>
+where
+    Theme: crate::style::candlestick::StyleSheet,
+{
+    crate::CandlestickChart::new(candles)
+}
+
+#[cfg(feature = "histogram")]
+/// Shortcut helper to create a [`Histogram`] Widget.
+///
+/// [`Histogram`]: crate::Histogram
+#[must_use]
+pub fn histogram<'a, Message, Renderer>(
+    bins: &'a crate::histogram::Bins,
+) -> crate::Histogram<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: crate::style::histogram::StyleSheet,
+{
+    crate::Histogram::new(bins)
+}
+
+#[cfg(feature = "radar")]
+/// Shortcut helper to create a [`RadarChart`] Widget.
+///
+/// [`RadarChart`]: crate::RadarChart
+#[must_use]
+pub fn radar_chart<Theme>(axes: &[String]) -> crate::RadarChart<'_, Theme>
+where
+    Theme: crate::style::radar::StyleSheet,
+{
+    crate::RadarChart::new(axes)
+}
+
+#[cfg(feature = "calendar")]
+/// Shortcut helper to create a [`Calendar`] Widget.
+///
+/// [`Calendar`]: crate::Calendar
+#[must_use]
+pub fn calendar<'a, Message, Theme, F>(
+    date: impl Into<crate::core::date::Date>,
+    on_select: F,
+) -> crate::Calendar<'a, Message, Theme>
+where
+    Theme: crate::style::date_picker::StyleSheet,
+    F: 'a + Fn(crate::core::date::Date) -> Message,
+{
+    crate::Calendar::new(date, on_select)
+}
+
+#[cfg(feature = "chart_legend")]
+/// Shortcut helper to create a [`ChartLegend`] Widget.
+///
+/// [`ChartLegend`]: crate::ChartLegend
+#[must_use]
+pub fn chart_legend<'a, Message, Renderer>() -> crate::ChartLegend<'a, Message, Renderer>
+where
+    Renderer: core::text::Renderer,
+    Renderer::Theme: crate::style::chart_legend::StyleSheet,
+{
+    crate::ChartLegend::new()
+}
+
+#[cfg(feature = "gauge")]
+/// Shortcut helper to create a [`Gauge`] Widget.
+///
+/// [`Gauge`]: crate::Gauge
+#[must_use]
+pub fn gauge<'a, Theme>(value: f32) -> crate::Gauge<'a, Theme>
+where
+    Theme: crate::style::gauge::StyleSheet,
+{
+    crate::Gauge::new(value)
+}
+
+#[cfg(feature = "segment_display")]
+/// Shortcut helper to create a [`SegmentDisplay`] Widget.
+///
+/// [`SegmentDisplay`]: crate::SegmentDisplay
+#[must_use]
+pub fn segment_display<Theme>(text: impl Into<String>) -> crate::SegmentDisplay<Theme>
+where
+    Theme: crate::style::segment_display::StyleSheet,
+{
+    crate::SegmentDisplay::new(text)
+}
+
+#[cfg(feature = "animated_number")]
+/// Shortcut helper to create an [`AnimatedNumber`] Widget.
+///
+/// [`AnimatedNumber`]: crate::AnimatedNumber
+#[must_use]
+pub fn animated_number<'a, Renderer>(value: f64) -> crate::AnimatedNumber<'a, Renderer>
+where
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: crate::style::animated_number::StyleSheet,
+{
+    crate::AnimatedNumber::new(value)
+}
+
+#[cfg(feature = "status_light")]
+/// Shortcut helper to create a [`StatusLight`] Widget.
+///
+/// [`StatusLight`]: crate::StatusLight
+#[must_use]
+pub fn status_light<Renderer>(
+    severity: crate::status_light::Severity,
+) -> crate::StatusLight<Renderer>
+where
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: crate::style::status_light::StyleSheet,
+{
+    crate::StatusLight::new(severity)
+}
+
+#[cfg(feature = "date_time_picker")]
+/// Shortcut helper to create a [`DateTimePicker`] Widget.
+///
+/// [`DateTimePicker`]: crate::DateTimePicker
+pub fn date_time_picker<'a, Message, Theme, F>(
+    show_picker: bool,
+    date: impl Into<crate::core::date::Date>,
+    time: impl Into<crate::core::time::Time>,
+    underlay: impl Into<Element<'a, Message, iced_widget::renderer::Renderer<Theme>>>,
+    on_cancel: Message,
+    on_submit: F,
+) -> crate::DateTimePicker<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + crate::style::date_picker::StyleSheet
+        + crate::style::time_picker::StyleSheet
+        + iced_widget::button::StyleSheet
+        + iced_widget::text::StyleSheet
+        + iced_widget::container::StyleSheet,
+    F: 'static + Fn(crate::core::date::Date, crate::core::time::Time) -> Message,
+{
+    crate::DateTimePicker::new(show_picker, date, time, underlay, on_cancel, on_submit)
+}
+
+#[cfg(feature = "signal_bars")]
+/// Shortcut helper to create a [`SignalBars`] Widget.
+///
+/// [`SignalBars`]: crate::SignalBars
+#[must_use]
+pub fn signal_bars<Renderer>(level: f32) -> crate::SignalBars<Renderer>
+where
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: crate::style::signal_bars::StyleSheet,
+{
+    crate::SignalBars::new(level)
+}
+
+#[cfg(feature = "battery_indicator")]
+/// Shortcut helper to create a [`BatteryIndicator`] Widget.
+///
+/// [`BatteryIndicator`]: crate::BatteryIndicator
+#[must_use]
+pub fn battery_indicator<Renderer>(level: f32) -> crate::BatteryIndicator<Renderer>
+where
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: crate::style::battery_indicator::StyleSheet,
+{
+    crate::BatteryIndicator::new(level)
+}
+
+#[cfg(feature = "duration_picker")]
+/// Shortcut helper to create a [`DurationPicker`] Widget.
+///
+/// [`DurationPicker`]: crate::DurationPicker
+pub fn duration_picker<'a, Message, Theme, F>(
+    show_picker: bool,
+    duration: std::time::Duration,
+    underlay: impl Into<Element<'a, Message, iced_widget::renderer::Renderer<Theme>>>,
+    on_cancel: Message,
+    on_submit: F,
+) -> crate::DurationPicker<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + crate::style::duration_picker::StyleSheet
+        + iced_widget::button::StyleSheet
+        + iced_widget::text::StyleSheet,
+    F: 'static + Fn(std::time::Duration) -> Message,
+{
+    crate::DurationPicker::new(show_picker, duration, underlay, on_cancel, on_submit)
+}
+
+#[cfg(feature = "focus_ring")]
+/// Shortcut helper to create a [`FocusRing`] Widget.
+///
+/// [`FocusRing`]: crate::FocusRing
+#[must_use]
+pub fn focus_ring<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+) -> crate::FocusRing<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: crate::style::focus_ring::StyleSheet,
+{
+    crate::FocusRing::new(content)
+}
+
+#[cfg(feature = "focus_group")]
+/// Shortcut helper to create a [`FocusGroup`] Widget.
+///
+/// [`FocusGroup`]: crate::FocusGroup
+#[must_use]
+pub fn focus_group<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+) -> crate::FocusGroup<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    crate::FocusGroup::new(content)
+}
+
+#[cfg(feature = "selectable_text")]
+/// Shortcut helper to create a [`SelectableText`] Widget.
+///
+/// [`SelectableText`]: crate::SelectableText
+#[must_use]
+pub fn selectable_text<'a, Renderer>(
+    content: impl Into<std::borrow::Cow<'a, str>>,
+) -> crate::SelectableText<'a, Renderer>
+where
+    Renderer: core::text::Renderer,
+    Renderer::Theme: crate::style::selectable_text::StyleSheet,
+{
+    crate::SelectableText::new(content)
+}
+
+#[cfg(feature = "rich_editor")]
+/// Shortcut helper to create a [`RichEditor`] Widget.
+///
+/// [`RichEditor`]: crate::RichEditor
+#[must_use]
+pub fn rich_editor<'a, Message, Renderer, F>(
+    document: &'a crate::core::rich_text::Document,
+    on_change: F,
+) -> crate::RichEditor<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: crate::style::rich_editor::StyleSheet,
+    F: 'a + Fn(crate::core::rich_text::Document) -> Message,
+{
+    crate::RichEditor::new(document, on_change)
+}
+
+#[cfg(feature = "find_bar")]
+/// Shortcut helper to create a [`FindBar`] Widget.
+///
+/// [`FindBar`]: crate::FindBar
+#[must_use]
+pub fn find_bar<'a, Message, Theme, T, F>(
+    query: &'a crate::find::Query,
+    target: &T,
+    on_query: F,
+) -> crate::FindBar<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + crate::style::find_bar::StyleSheet
+        + iced_widget::button::StyleSheet
+        + iced_widget::text::StyleSheet
+        + iced_widget::text_input::StyleSheet,
+    T: crate::find::Searchable + ?Sized,
+    F: 'a + Fn(crate::find::Query) -> Message,
+{
+    crate::FindBar::new(query, target, on_query)
+}
+
+#[cfg(feature = "split_button")]
+/// Shortcut helper to create a [`SplitButton`] Widget.
+///
+/// [`SplitButton`]: crate::SplitButton
+#[must_use]
+pub fn split_button<Message, Renderer>(
+    label: impl Into<String>,
+    items: Vec<crate::MenuItem<Message>>,
+) -> crate::SplitButton<Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: crate::style::split_button::StyleSheet,
+{
+    crate::SplitButton::new(label, items)
+}
+
+#[cfg(feature = "num_pad")]
+/// Shortcut helper to create a [`NumPad`] Widget.
+///
+/// [`NumPad`]: crate::NumPad
+#[must_use]
+pub fn num_pad<'a, Message, Renderer, F>(
+    show: bool,
+    underlay: impl Into<Element<'a, Message, Renderer>>,
+    value: impl Into<String>,
+    on_change: F,
+) -> crate::NumPad<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: crate::style::num_pad::StyleSheet,
+    F: 'a + Fn(String) -> Message,
+{
+    crate::NumPad::new(show, underlay, value, on_change)
+}
+
+#[cfg(feature = "on_screen_keyboard")]
+/// Shortcut helper to create an [`OnScreenKeyboard`] Widget.
+///
+/// [`OnScreenKeyboard`]: crate::OnScreenKeyboard
+#[must_use]
+pub fn on_screen_keyboard<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+) -> crate::OnScreenKeyboard<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: crate::style::on_screen_keyboard::StyleSheet,
+{
+    crate::OnScreenKeyboard::new(content)
+}
+
+#[cfg(feature = "toggle_group")]
+/// Shortcut helper to create a [`ToggleGroup`] Widget.
+///
+/// [`ToggleGroup`]: crate::ToggleGroup
+#[must_use]
+pub fn toggle_group<'a, Message, Renderer, L, F>(
+    labels: L,
+    selection: &'a [usize],
+    on_change: F,
+) -> crate::ToggleGroup<'a, Message, Renderer>
+where
+    L: IntoIterator,
+    L::Item: Into<String>,
+    F: 'a + Fn(Vec<usize>) -> Message,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: crate::style::toggle_group::StyleSheet,
+{
+    crate::ToggleGroup::new(labels, selection, on_change)
+}
+
+#[cfg(feature = "menu_button")]
+/// Shortcut helper to create a [`MenuButton`] Widget.
+///
+/// [`MenuButton`]: crate::MenuButton
+#[must_use]
+pub fn menu_button<Message, Renderer>(
+    label: impl Into<String>,
+    items: Vec<crate::MenuItem<Message>>,
+) -> crate::MenuButton<Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: crate::style::split_button::StyleSheet,
+{
+    crate::MenuButton::new(label, items)
+}
+
+#[cfg(feature = "title_bar")]
+/// Shortcut helper to create a [`TitleBar`] Widget.
+///
+/// [`TitleBar`]: crate::TitleBar
+#[must_use]
+pub fn title_bar<'a, Message, Renderer, F>(
+    title: impl Into<String>,
+    on_control: F,
+) -> crate::TitleBar<'a, Message, Renderer>
+where
+    F: 'a + Fn(crate::WindowControl) -> Message,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: crate::style::title_bar::StyleSheet,
+{
+    crate::TitleBar::new(title, on_control)
+}
+
+#[cfg(feature = "edge_resizer")]
+/// Shortcut helper to create an [`EdgeResizer`] Widget.
+///
+/// [`EdgeResizer`]: crate::EdgeResizer
+#[must_use]
+pub fn edge_resizer<'a, Message, Renderer, F>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+    on_resize: F,
+) -> crate::EdgeResizer<'a, Message, Renderer>
+where
+    F: 'a + Fn(crate::ResizeDirection, core::Vector) -> Message,
+    Renderer: core::Renderer,
+{
+    crate::EdgeResizer::new(content, on_resize)
+}
+
+#[cfg(feature = "gesture_detector")]
+/// Shortcut helper to create a [`GestureDetector`] Widget.
+///
+/// [`GestureDetector`]: crate::GestureDetector
+#[must_use]
+pub fn gesture_detector<'a, Message, Renderer, F>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+    on_gesture: F,
+) -> crate::GestureDetector<'a, Message, Renderer>
+where
+    F: 'a + Fn(crate::gesture_detector::Gesture) -> Message,
+    Renderer: core::Renderer,
+{
+    crate::GestureDetector::new(content, on_gesture)
+}
+
+#[cfg(feature = "form")]
+/// Shortcut helper to create a [`Form`] Widget.
+///
+/// [`Form`]: crate::Form
+#[must_use]
+pub fn form<'a, Message, Renderer>(
+    rows: Vec<crate::form::FormRow<'a, Message, Renderer>>,
+) -> crate::Form<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: crate::style::form::StyleSheet,
+{
+    crate::Form::with_rows(rows)
+}
+
+#[cfg(feature = "settings_page")]
+/// Shortcut helper to create a [`SettingsPage`] Widget.
+///
+/// [`SettingsPage`]: crate::SettingsPage
+#[must_use]
+pub fn settings_page<'a, Message, Theme, S, F>(
+    id: iced_widget::scrollable::Id,
+    sections: Vec<crate::settings_page::SettingsSection<'a, Message>>,
+    search: &'a str,
+    on_search: S,
+    on_section: F,
+) -> crate::SettingsPage<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + crate::style::settings_page::StyleSheet
+        + iced_widget::button::StyleSheet
+        + iced_widget::container::StyleSheet
+        + iced_widget::overlay::menu::StyleSheet
+        + iced_widget::pick_list::StyleSheet
+        + iced_widget::scrollable::StyleSheet
+        + iced_widget::slider::StyleSheet
+        + iced_widget::text::StyleSheet
+        + iced_widget::text_input::StyleSheet
+        + iced_widget::toggler::StyleSheet,
+    <Theme as iced_widget::overlay::menu::StyleSheet>::Style:
+        From<<Theme as iced_widget::pick_list::StyleSheet>::Style>,
+    S: 'a + Fn(String) -> Message,
+    F: 'a + Fn(usize) -> Message,
+{
+    crate::SettingsPage::new(id, sections, search, on_search, on_section)
+}
+
+#[cfg(feature = "wizard")]
+/// Shortcut helper to create a [`Wizard`] Widget.
+///
+/// [`Wizard`]: crate::Wizard
+#[must_use]
+pub fn wizard<'a, Message, Theme, U, F>(
+    underlay: U,
+    show: bool,
+    title: impl Into<String>,
+    pages: Vec<crate::wizard::WizardPage<'a, Message, Theme>>,
+    progress: crate::wizard::WizardProgress,
+    on_change: F,
+    on_finish: Message,
+) -> crate::Wizard<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + crate::style::wizard::StyleSheet
+        + crate::style::card::StyleSheet
+        + crate::style::modal::StyleSheet
+        + iced_widget::button::StyleSheet
+        + iced_widget::text::StyleSheet,
+    U: Into<Element<'a, Message, iced_widget::renderer::Renderer<Theme>>>,
+    F: 'a + Fn(crate::wizard::WizardProgress) -> Message,
+{
+    crate::Wizard::new(underlay, show, title, pages, progress, on_change, on_finish)
+}
+
+#[cfg(feature = "auth_form")]
+/// Shortcut helper to create an [`AuthForm`] Widget.
+///
+/// [`AuthForm`]: crate::AuthForm
+#[must_use]
+pub fn auth_form<'a, Message, Theme, I, P>(
+    identifier: &'a str,
+    on_identifier: I,
+    password: &'a str,
+    on_password: P,
+    on_submit: Message,
+) -> crate::AuthForm<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + crate::style::auth_form::StyleSheet
+        + crate::style::spinner::StyleSheet
+        + iced_widget::button::StyleSheet
+        + iced_widget::checkbox::StyleSheet
+        + iced_widget::text::StyleSheet
+        + iced_widget::text_input::StyleSheet,
+    I: 'a + Fn(String) -> Message,
+    P: 'a + Fn(String) -> Message,
+{
+    crate::AuthForm::new(identifier, on_identifier, password, on_password, on_submit)
+}
+
+#[cfg(feature = "onboarding")]
+/// Shortcut helper to create an [`Onboarding`] Widget.
+///
+/// [`Onboarding`]: crate::Onboarding
+#[must_use]
+pub fn onboarding<'a, Message, Theme, F>(
+    pages: Vec<crate::onboarding::OnboardingPage<'a, Message, Theme>>,
+    current: usize,
+    on_change: F,
+    on_finish: Message,
+) -> crate::Onboarding<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + crate::style::onboarding::StyleSheet
+        + iced_widget::button::StyleSheet
+        + iced_widget::text::StyleSheet,
+    F: 'a + Fn(usize) -> Message,
+{
+    crate::Onboarding::new(pages, current, on_change, on_finish)
+}
+
+#[cfg(feature = "contrast_checker")]
+/// Shortcut helper to create a [`ContrastChecker`] Widget.
+///
+/// [`ContrastChecker`]: crate::ContrastChecker
+#[must_use]
+pub fn contrast_checker<'a, Message, Theme, P, F>(
+    foreground: iced_widget::core::Color,
+    background: iced_widget::core::Color,
+    picking: Option<crate::contrast_checker::Swatch>,
+    on_pick: P,
+    on_change: F,
+) -> crate::ContrastChecker<'a, Message, Theme>
+where
+    Message: 'static + Clone,
+    Theme: 'a
+        + crate::style::contrast_checker::StyleSheet
+        + crate::style::color_picker::StyleSheet
+        + iced_widget::button::StyleSheet
+        + iced_widget::text::StyleSheet
+        + iced_widget::text_input::StyleSheet,
+    P: 'static + Fn(Option<crate::contrast_checker::Swatch>) -> Message,
+    F: 'static + Fn(crate::contrast_checker::Swatch, iced_widget::core::Color) -> Message,
+{
+    crate::ContrastChecker::new(foreground, background, picking, on_pick, on_change)
+}
+
+#[cfg(feature = "theme_editor")]
+/// Shortcut helper to create a [`ThemeEditor`] Widget.
+///
+/// [`ThemeEditor`]: crate::ThemeEditor
+#[must_use]
+pub fn theme_editor<'a, Message, Theme, E, F>(
+    palette: iced_widget::style::theme::Palette,
+    editing: Option<crate::theme_editor::PaletteColor>,
+    on_edit: E,
+    on_change: F,
+) -> crate::ThemeEditor<'a, Message, Theme>
+where
+    Message: 'static + Clone,
+    Theme: 'a
+        + crate::style::theme_editor::StyleSheet
+        + crate::style::color_picker::StyleSheet
+        + iced_widget::button::StyleSheet
+        + iced_widget::text::StyleSheet
+        + iced_widget::text_input::StyleSheet,
+    E: 'static + Fn(Option<crate::theme_editor::PaletteColor>) -> Message,
+    F: 'static + Fn(iced_widget::style::theme::Palette) -> Message,
+{
+    crate::ThemeEditor::new(palette, editing, on_edit, on_change)
+}
+
+#[cfg(feature = "shortcut_recorder")]
+/// Shortcut helper to create a [`ShortcutRecorder`] Widget.
+///
+/// [`ShortcutRecorder`]: crate::ShortcutRecorder
+#[must_use]
+pub fn shortcut_recorder<'a, Message, Renderer, F>(
+    shortcut: Option<crate::shortcut_recorder::Shortcut>,
+    on_record: F,
+) -> crate::ShortcutRecorder<'a, Message, Renderer>
+where
+    F: 'a + Fn(crate::shortcut_recorder::Shortcut) -> Message,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: crate::style::shortcut_recorder::StyleSheet,
+{
+    crate::ShortcutRecorder::new(shortcut, on_record)
+}
+
+#[cfg(feature = "gamepad")]
+/// Shortcut helper to create a [`GamepadTarget`] Widget.
+///
+/// [`GamepadTarget`]: crate::GamepadTarget
+#[must_use]
+pub fn gamepad_target<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+) -> crate::GamepadTarget<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: crate::style::gamepad::StyleSheet,
+{
+    crate::GamepadTarget::new(content)
+}
@@ -0,0 +1,99 @@
+//! The tooltip and the colors shared by the charts.
+
+use iced_widget::core::{
+    self,
+    alignment::{Horizontal, Vertical},
+    renderer,
+    text::{self, LineHeight},
+    Background, Color, Point, Rectangle, Size, Vector,
+};
+
+/// The padding around the text of the tooltip.
+const TOOLTIP_PADDING: f32 = 4.0;
+/// The distance of the tooltip from the cursor.
+const TOOLTIP_OFFSET: f32 = 12.0;
+
+/// Draws a tooltip with the given lines next to the cursor at `position`.
+///
+/// The tooltip follows the cursor and flips to its other side at the edges
+/// of the `bounds` of the chart.
+#[allow(clippy::too_many_arguments)]
+pub fn tooltip<Renderer>(
+    renderer: &mut Renderer,
+    lines: &[String],
+    text_size: f32,
+    shaping: text::Shaping,
+    position: Point,
+    bounds: Rectangle,
+    background: Background,
+    text_color: Color,
+) where
+    Renderer: text::Renderer<Font = core::Font>,
+{
+    let line_height = text_size * 1.3;
+    let text_width = lines
+        .iter()
+        .map(|line| renderer.measure_width(line, text_size, core::Font::default(), shaping))
+        .fold(0.0, f32::max);
+    #[allow(clippy::cast_precision_loss)]
+    let size = Size::new(
+        text_width + TOOLTIP_PADDING * 2.0,
+        line_height * lines.len() as f32 + TOOLTIP_PADDING * 2.0,
+    );
+
+    let mut tooltip = Rectangle::new(position + Vector::new(TOOLTIP_OFFSET, TOOLTIP_OFFSET), size);
+    if tooltip.x + tooltip.width > bounds.x + bounds.width {
+        tooltip.x = position.x - TOOLTIP_OFFSET - tooltip.width;
+    }
+    if tooltip.y + tooltip.height > bounds.y + bounds.height {
+        tooltip.y = position.y - TOOLTIP_OFFSET - tooltip.height;
+    }
+
+    renderer.with_layer(tooltip, |renderer| {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: tooltip,
+                border_radius: (2.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            background,
+        );
+
+        for (i, line) in lines.iter().enumerate() {
+            #[allow(clippy::cast_precision_loss)]
+            let y = tooltip.y + TOOLTIP_PADDING + line_height * i as f32;
+
+            renderer.fill_text(core::text::Text {
+                content: line,
+                bounds: Rectangle {
+                    x: tooltip.x + TOOLTIP_PADDING,
+                    y,
+                    width: text_width,
+                    height: line_height,
+                },
+                size: text_size,
+                line_height: LineHeight::default(),
+                color: text_color,
+                font: core::Font::default(),
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Top,
+                shaping,
+            });
+        }
+    });
+}
+
+/// Mixes the colors linearly, from `from` at 0.0 to `to` at 1.0.
+#[cfg_attr(
+    not(any(feature = "geo_map", feature = "treemap", feature = "sunburst")),
+    allow(dead_code)
+)]
+pub fn mix(from: Color, to: Color, t: f32) -> Color {
+    Color {
+        r: from.r + (to.r - from.r) * t,
+        g: from.g + (to.g - from.g) * t,
+        b: from.b + (to.b - from.b) * t,
+        a: from.a + (to.a - from.a) * t,
+    }
+}
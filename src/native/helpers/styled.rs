@@ -0,0 +1,210 @@
+//! A wrapper drawing its content with the appearance of the widget it is a
+//! part of.
+
+use iced_widget::core::{
+    self, event,
+    layout::{Limits, Node},
+    mouse::{self, Cursor},
+    overlay, renderer,
+    widget::{Operation, Tree},
+    Clipboard, Color, Element, Event, Layout, Length, Rectangle, Shell, Widget,
+};
+
+/// The function drawing the background of a [`Styled`] element, returning
+/// the color of its text.
+type DrawFn<'a, Renderer> = Box<
+    dyn Fn(
+            &mut Renderer,
+            &<Renderer as core::Renderer>::Theme,
+            &renderer::Style,
+            Rectangle,
+            Cursor,
+        ) -> Color
+        + 'a,
+>;
+
+/// The function passing the bounds of a [`Styled`] element to an operation.
+type OperateFn<'a, Message> = Box<dyn Fn(Rectangle, &mut dyn Operation<Message>) + 'a>;
+
+/// Draws its content over a background and with a text color, both picked
+/// by the widget it is a part of, e.g. by the role of the content.
+pub struct Styled<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    /// The content of the [`Styled`] element.
+    content: Element<'a, Message, Renderer>,
+    /// Draws the background and returns the text color.
+    draw: DrawFn<'a, Renderer>,
+    /// The interaction of the mouse over the whole element, if any.
+    interaction: Option<mouse::Interaction>,
+    /// Passes the element to operations before its content, if set.
+    operate: Option<OperateFn<'a, Message>>,
+}
+
+impl<'a, Message, Renderer> Styled<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    /// Creates a new [`Styled`] element drawing the given content after the
+    /// given function, which draws the background and returns the text color.
+    pub fn new<F>(content: impl Into<Element<'a, Message, Renderer>>, draw: F) -> Self
+    where
+        F: 'a + Fn(&mut Renderer, &Renderer::Theme, &renderer::Style, Rectangle, Cursor) -> Color,
+    {
+        Self {
+            content: content.into(),
+            draw: Box::new(draw),
+            interaction: None,
+            operate: None,
+        }
+    }
+
+    /// Sets the interaction of the mouse over the whole [`Styled`] element,
+    /// e.g. a pointer over clickable content.
+    #[must_use]
+    pub fn interaction(mut self, interaction: Option<mouse::Interaction>) -> Self {
+        self.interaction = interaction;
+        self
+    }
+
+    /// Sets the function passing the bounds of the [`Styled`] element to
+    /// operations, e.g. to make it findable by a custom operation.
+    #[must_use]
+    #[cfg_attr(not(feature = "settings_page"), allow(dead_code))]
+    pub fn operate<F>(mut self, operate: F) -> Self
+    where
+        F: 'a + Fn(Rectangle, &mut dyn Operation<Message>),
+    {
+        self.operate = Some(Box::new(operate));
+        self
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Styled<'_, Message, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match self.interaction {
+            Some(interaction) if cursor.is_over(layout.bounds()) => interaction,
+            _ => self.content.as_widget().mouse_interaction(
+                &state.children[0],
+                layout,
+                cursor,
+                viewport,
+                renderer,
+            ),
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let text_color = (self.draw)(renderer, theme, style, layout.bounds(), cursor);
+
+        self.content.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            &renderer::Style { text_color },
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        if let Some(operate) = &self.operate {
+            operate(layout.bounds(), operation);
+        }
+
+        self.content
+            .as_widget()
+            .operate(&mut state.children[0], layout, renderer, operation);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut state.children[0], layout, renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<Styled<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + core::Renderer,
+{
+    fn from(styled: Styled<'a, Message, Renderer>) -> Self {
+        Element::new(styled)
+    }
+}
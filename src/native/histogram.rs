@@ -0,0 +1,449 @@
+//! Use a histogram to show the distribution of values and brush over a range.
+//!
+//! *This API requires the following crate features to be activated: `histogram`*
+use crate::style::histogram::StyleSheet;
+
+use iced_widget::core::{
+    self, event,
+    layout::{Limits, Node},
+    mouse::{self, Cursor},
+    renderer, touch,
+    widget::{
+        tree::{State as TreeState, Tag},
+        Tree,
+    },
+    Background, Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size,
+    Widget,
+};
+
+pub use crate::core::histogram::Bins;
+
+/// The distance in pixels the cursor has to move while pressed before the
+/// press brushes over the bins.
+const DRAG_THRESHOLD: f32 = 3.0;
+/// The space above the highest bar.
+const TOP_PADDING: f32 = 4.0;
+/// The width of the edges of the brush.
+const EDGE_WIDTH: f32 = 2.0;
+
+/// A display of the number of values in the [`Bins`] of a histogram.
+///
+/// Dragging over the [`Histogram`] brushes over a range of bins and selects
+/// the values from the start of the first to the end of the last bin, which
+/// makes it a filter control for the values shown in a table or a list.
+/// Clicking a bin selects just that bin, and clicking inside of the selected
+/// range clears it.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{histogram::Bins, Histogram};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Select(f32, f32),
+///     Clear,
+/// }
+///
+/// let bins = Bins::new(vec![1.0, 2.5, 2.75, 4.0], 4);
+///
+/// let histogram = Histogram::new(&bins)
+///     .selection(2.0, 3.0)
+///     .on_select(Message::Select)
+///     .on_clear(Message::Clear);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Histogram<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The bins to display.
+    bins: &'a Bins,
+    /// The selected range of values.
+    selection: Option<(f32, f32)>,
+    /// The function producing the message when a range is brushed over.
+    on_select: Option<Box<dyn Fn(f32, f32) -> Message + 'a>>,
+    /// The message produced when the selected range is cleared.
+    on_clear: Option<Message>,
+    /// The width of the [`Histogram`].
+    width: Length,
+    /// The height of the [`Histogram`].
+    height: Length,
+    /// The style of the [`Histogram`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Histogram<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Histogram`] displaying the given [`Bins`].
+    #[must_use]
+    pub fn new(bins: &'a Bins) -> Self {
+        Self {
+            bins,
+            selection: None,
+            on_select: None,
+            on_clear: None,
+            width: Length::Fill,
+            height: Length::Fixed(100.0),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the selected range of values of the [`Histogram`] from `min` to
+    /// `max`.
+    #[must_use]
+    pub fn selection(mut self, min: f32, max: f32) -> Self {
+        self.selection = Some((min.min(max), min.max(max)));
+        self
+    }
+
+    /// Sets the function producing the message with the smallest and the
+    /// largest value of the range the [`Histogram`] is brushed over.
+    ///
+    /// The range starts at the start of the first and ends at the end of the
+    /// last brushed bin.
+    #[must_use]
+    pub fn on_select<F>(mut self, on_select: F) -> Self
+    where
+        F: 'a + Fn(f32, f32) -> Message,
+    {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the message produced when the selected range of the [`Histogram`]
+    /// is cleared by clicking inside of it.
+    #[must_use]
+    pub fn on_clear(mut self, on_clear: Message) -> Self {
+        self.on_clear = Some(on_clear);
+        self
+    }
+
+    /// Sets the width of the [`Histogram`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Histogram`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`Histogram`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the index of the bin at the given horizontal position.
+    fn bin_at(&self, bounds: Rectangle, x: f32) -> usize {
+        #[allow(clippy::cast_precision_loss)]
+        let len = self.bins.len() as f32;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let index = ((x - bounds.x) / bounds.width.max(1.0) * len).max(0.0) as usize;
+        index.min(self.bins.len() - 1)
+    }
+
+    /// Returns the horizontal position of the given value.
+    fn x_of(&self, bounds: Rectangle, value: f32) -> f32 {
+        let (start, end) = self.bins.range();
+
+        bounds.x + (value - start) / (end - start) * bounds.width
+    }
+
+    /// Returns whether the center of the given bin is in the selected range.
+    fn is_selected(&self, index: usize) -> bool {
+        let (start, end) = self.bins.edges(index, index);
+        let center = f32::midpoint(start, end);
+
+        self.selection
+            .is_some_and(|(min, max)| (min..=max).contains(&center))
+    }
+}
+
+/// The state of a [`Histogram`].
+#[derive(Clone, Copy, Debug, Default)]
+struct State {
+    /// The position the ongoing press started at, with the bin under it.
+    press: Option<(Point, usize)>,
+    /// Whether the ongoing press moved far enough to brush over the bins.
+    brushing: bool,
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Histogram<'_, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        let Some(on_select) = &self.on_select else {
+            return event::Status::Ignored;
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    state.press = Some((position, self.bin_at(bounds, position.x)));
+                    state.brushing = false;
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some((origin, first)) = state.press {
+                    if state.brushing || (position.x - origin.x).abs() > DRAG_THRESHOLD {
+                        state.brushing = true;
+
+                        let last = self.bin_at(bounds, position.x);
+                        let (min, max) = self.bins.edges(first, last);
+                        shell.publish(on_select(min, max));
+                    }
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if state.press.is_some() =>
+            {
+                if let (Some((_, index)), false) = (state.press, state.brushing) {
+                    match &self.on_clear {
+                        Some(on_clear) if self.is_selected(index) => {
+                            shell.publish(on_clear.clone());
+                        }
+                        _ => {
+                            let (min, max) = self.bins.edges(index, index);
+                            shell.publish(on_select(min, max));
+                        }
+                    }
+                }
+
+                state.press = None;
+                state.brushing = false;
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.on_select.is_some() && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Crosshair
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+
+        let fill = |renderer: &mut Renderer, bounds: Rectangle, background: Background| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: (0.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                background,
+            );
+        };
+
+        fill(renderer, bounds, appearance.background);
+
+        renderer.with_layer(bounds, |renderer| {
+            let brush = self.selection.map(|(min, max)| {
+                let left = self.x_of(bounds, min).max(bounds.x);
+                let right = self.x_of(bounds, max).min(bounds.x + bounds.width);
+
+                (left, right.max(left))
+            });
+
+            // Brush, behind the bars.
+            if let Some((left, right)) = brush {
+                fill(
+                    renderer,
+                    Rectangle {
+                        x: left,
+                        y: bounds.y,
+                        width: right - left,
+                        height: bounds.height,
+                    },
+                    appearance.brush_background,
+                );
+            }
+
+            // Bars, separated by a gap if they are wide enough.
+            #[allow(clippy::cast_precision_loss)]
+            let bar_width = bounds.width / self.bins.len() as f32;
+            let gap = if bar_width > 4.0 { 1.0 } else { 0.0 };
+            #[allow(clippy::cast_precision_loss)]
+            let max_count = self.bins.max_count().max(1) as f32;
+
+            for (index, &count) in self.bins.counts().iter().enumerate() {
+                if count == 0 {
+                    continue;
+                }
+
+                #[allow(clippy::cast_precision_loss)]
+                let height = count as f32 / max_count * (bounds.height - TOP_PADDING).max(0.0);
+                #[allow(clippy::cast_precision_loss)]
+                let x = bounds.x + index as f32 * bar_width;
+
+                fill(
+                    renderer,
+                    Rectangle {
+                        x: x + gap / 2.0,
+                        y: bounds.y + bounds.height - height,
+                        width: (bar_width - gap).max(1.0),
+                        height: height.max(1.0),
+                    },
+                    if self.is_selected(index) {
+                        appearance.selected_bar_color
+                    } else {
+                        appearance.bar_color
+                    }
+                    .into(),
+                );
+            }
+
+            // Edges of the brush, above the bars.
+            if let Some((left, right)) = brush {
+                for x in [left, right - EDGE_WIDTH] {
+                    fill(
+                        renderer,
+                        Rectangle {
+                            x: x.clamp(
+                                bounds.x,
+                                (bounds.x + bounds.width - EDGE_WIDTH).max(bounds.x),
+                            ),
+                            y: bounds.y,
+                            width: EDGE_WIDTH,
+                            height: bounds.height,
+                        },
+                        appearance.brush_edge_color.into(),
+                    );
+                }
+            }
+        });
+    }
+}
+
+impl<'a, Message, Renderer> From<Histogram<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(histogram: Histogram<'a, Message, Renderer>) -> Self {
+        Element::new(histogram)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bins, Histogram};
+    use crate::test_utils::Harness;
+    use iced_widget::core::{Point, Size};
+
+    #[test]
+    fn histogram_test() {
+        let values: Vec<f32> = (0..100_u8).map(|i| f32::from(i) / 10.0).collect();
+        let bins = Bins::with_range(values, 0.0, 10.0, 10);
+        let histogram = || {
+            Histogram::new(&bins)
+                .on_select(|min, max| Some((min, max)))
+                .on_clear(None)
+        };
+
+        // Brushing from the second to the sixth bin selects their values.
+        let mut harness = Harness::new(histogram(), Size::new(200.0, 100.0));
+        let plain = harness.snapshot();
+        let messages = harness.drag(Point::new(30.0, 50.0), Point::new(110.0, 50.0), 4);
+        assert_eq!(messages.last(), Some(&Some((1.0, 6.0))));
+
+        // Clicking a bin outside of the selection selects it, and clicking
+        // inside of the selection clears it.
+        let mut harness = Harness::new(histogram().selection(1.0, 6.0), Size::new(200.0, 100.0));
+        assert!(plain
+            .diff(&harness.snapshot(), 8)
+            .is_some_and(|count| count > 100));
+        assert_eq!(
+            harness.click(Point::new(190.0, 50.0)),
+            vec![Some((9.0, 10.0))]
+        );
+        assert_eq!(harness.click(Point::new(70.0, 50.0)), vec![None]);
+
+        // The edges of the brush stay inside of a histogram without width.
+        harness = Harness::new(
+            histogram().selection(1.0, 6.0).width(0.0),
+            Size::new(200.0, 100.0),
+        );
+        let _ = harness.snapshot();
+    }
+}
@@ -0,0 +1,305 @@
+//! Use a history list to visualize and navigate an undo stack.
+//!
+//! *This API requires the following crate features to be activated: `history_list`*
+use crate::style::history_list::StyleSheet;
+
+use iced_widget::{
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer, text, touch,
+        widget::Tree,
+        Clipboard, Color, Element, Event, Layout, Length, Rectangle, Shell, Size, Widget,
+    },
+    text::LineHeight,
+};
+use std::fmt::Display;
+
+/// A list visualizing an undo stack of labeled entries.
+///
+/// The entries are ordered from the oldest to the newest state. The entry of
+/// the current state is highlighted and the entries after it, which have been
+/// undone and can be redone, are dimmed. Clicking an entry produces a message
+/// to jump to its state.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::HistoryList;
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Jump(usize),
+/// }
+///
+/// let entries = ["Open file", "Insert text", "Delete line"];
+///
+/// let history_list = HistoryList::new(&entries, 1, Message::Jump);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct HistoryList<'a, T, Message, Renderer = crate::Renderer>
+where
+    T: Display,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The labeled entries of the undo stack, from the oldest to the newest.
+    entries: &'a [T],
+    /// The index of the entry of the current state.
+    current: usize,
+    /// The function producing the message when an entry is clicked.
+    on_jump: Box<dyn Fn(usize) -> Message + 'a>,
+    /// The width of the [`HistoryList`].
+    width: Length,
+    /// The text size of the entries.
+    text_size: f32,
+    /// The padding around the label of each entry.
+    padding: f32,
+    /// The font of the entries.
+    font: Renderer::Font,
+    /// The style of the [`HistoryList`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> HistoryList<'a, T, Message, Renderer>
+where
+    T: Display,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`HistoryList`] with the given `entries`, the index of the
+    /// `current` entry and the message to produce when an entry is clicked.
+    pub fn new<F>(entries: &'a [T], current: usize, on_jump: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        Self {
+            entries,
+            current,
+            on_jump: Box::new(on_jump),
+            width: Length::Fill,
+            text_size: 14.0,
+            padding: 5.0,
+            font: core::Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`HistoryList`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the text size of the entries of the [`HistoryList`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the padding around the label of each entry of the [`HistoryList`].
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the font of the entries of the [`HistoryList`].
+    #[must_use]
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`HistoryList`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the height of a single entry.
+    fn entry_height(&self) -> f32 {
+        self.text_size + self.padding * 2.0
+    }
+
+    /// Returns the index of the entry at the given position, if any.
+    fn entry_at(&self, bounds: Rectangle, cursor: Cursor) -> Option<usize> {
+        let position = cursor.position_in(bounds)?;
+        let index = (position.y / self.entry_height()) as usize;
+
+        (index < self.entries.len()).then_some(index)
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer> for HistoryList<'a, T, Message, Renderer>
+where
+    T: Display,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &Limits) -> Node {
+        #[allow(clippy::cast_precision_loss)]
+        let height = self.entry_height() * self.entries.len() as f32;
+        let limits = limits.width(self.width).height(Length::Fixed(height));
+
+        Node::new(limits.resolve(Size::new(limits.max().width, height)))
+    }
+
+    fn on_event(
+        &mut self,
+        _state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(index) = self.entry_at(layout.bounds(), cursor) {
+                    if index != self.current {
+                        shell.publish((self.on_jump)(index));
+                    }
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match self.entry_at(layout.bounds(), cursor) {
+            Some(index) if index != self.current => mouse::Interaction::Pointer,
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let entry_height = self.entry_height();
+        let hovered = self.entry_at(bounds, cursor);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        // Only draw the entries inside of the viewport.
+        let offset = (viewport.y - bounds.y).max(0.0);
+        let start = (offset / entry_height) as usize;
+        let end = ((offset + viewport.height) / entry_height).ceil() as usize;
+
+        for (index, entry) in self.entries.iter().enumerate().take(end).skip(start) {
+            #[allow(clippy::cast_precision_loss)]
+            let entry_bounds = Rectangle {
+                x: bounds.x,
+                y: bounds.y + entry_height * index as f32,
+                width: bounds.width,
+                height: entry_height,
+            };
+
+            let is_current = index == self.current;
+            let is_hovered = hovered == Some(index);
+
+            let background = if is_current {
+                Some(appearance.current_background)
+            } else if is_hovered {
+                Some(appearance.hovered_background)
+            } else {
+                None
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: entry_bounds,
+                        border_radius: (0.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    background,
+                );
+            }
+
+            let color = if is_current {
+                appearance.current_text_color
+            } else if is_hovered {
+                appearance.hovered_text_color
+            } else if index > self.current {
+                appearance.undone_text_color
+            } else {
+                appearance.text_color
+            };
+
+            renderer.fill_text(text::Text {
+                content: &entry.to_string(),
+                bounds: Rectangle {
+                    x: entry_bounds.x + self.padding,
+                    y: entry_bounds.center_y(),
+                    width: f32::INFINITY,
+                    ..entry_bounds
+                },
+                size: self.text_size,
+                line_height: LineHeight::default(),
+                color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Advanced,
+            });
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> From<HistoryList<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: Display,
+    Message: 'a,
+    Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(history_list: HistoryList<'a, T, Message, Renderer>) -> Self {
+        Element::new(history_list)
+    }
+}
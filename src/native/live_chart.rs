@@ -0,0 +1,646 @@
+//! Use a live chart to plot streaming data over a scrolling time window.
+//!
+//! *This API requires the following crate features to be activated: `live_chart`*
+use std::time::Duration;
+
+use crate::core::{annotation, axis::nice_ticks, live_chart::decimate};
+use crate::native::helpers::chart;
+use crate::native::time_ruler::{format_time, TickStep};
+
+use iced_widget::{
+    canvas::{self, Path, Stroke},
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer,
+        text::{self, Renderer as _},
+        widget::Tree,
+        Color, Element, Layout, Length, Point, Rectangle, Renderer as _, Size, Vector, Widget,
+    },
+    graphics::geometry::Renderer as _,
+    renderer::Renderer,
+    text::LineHeight,
+};
+
+pub use crate::core::{annotation::Annotation, live_chart::Series};
+pub use crate::style::live_chart::{Appearance, StyleSheet};
+
+/// The padding around the axis labels and the text of the tooltip.
+const PADDING: f32 = 4.0;
+/// The minimum distance between two labels of the time axis.
+const MIN_SPACING: f32 = 80.0;
+
+/// A line chart of streaming data, showing the latest samples of each
+/// [`Series`] in a time window scrolling to the left.
+///
+/// The lines are decimated to a few samples per pixel, so dense data is
+/// drawn as fast as sparse data. The value axis adapts to the shown samples
+/// unless it is given a fixed range. Hovering the chart shows a crosshair
+/// and the values of the series at the time under the cursor.
+///
+/// Guide lines, shaded regions and labeled points can be added above the
+/// series as [`Annotation`]s, with the time in seconds.
+///
+/// # Example
+/// ```ignore
+/// # use std::time::Duration;
+/// # use iced_aw::live_chart::{LiveChart, Series};
+/// #
+/// let mut cpu = Series::new("CPU", 1000);
+/// cpu.push(0.0, 12.0);
+/// cpu.push(0.5, 18.0);
+///
+/// let series = [cpu];
+/// let chart = LiveChart::new(&series).window(Duration::from_secs(30));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct LiveChart<'a, Theme = iced_widget::style::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The plotted series.
+    series: &'a [Series],
+    /// The duration of the shown time window.
+    window: Duration,
+    /// The time at the right edge of the chart in seconds.
+    now: Option<f64>,
+    /// The fixed range of the value axis.
+    range: Option<(f32, f32)>,
+    /// The annotations drawn above the series.
+    annotations: Vec<Annotation>,
+    /// The width of the [`LiveChart`].
+    width: Length,
+    /// The height of the [`LiveChart`].
+    height: Length,
+    /// The text size of the labels.
+    text_size: f32,
+    /// The function formatting the labels of the time axis.
+    format_time: Box<dyn Fn(f64, TickStep) -> String + 'a>,
+    /// The function formatting the labels of the value axis and the values
+    /// in the tooltip.
+    format_value: Box<dyn Fn(f32) -> String + 'a>,
+    /// The style of the [`LiveChart`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Theme> LiveChart<'a, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`LiveChart`] of the given series.
+    #[must_use]
+    pub fn new(series: &'a [Series]) -> Self {
+        Self {
+            series,
+            window: Duration::from_secs(10),
+            now: None,
+            range: None,
+            annotations: Vec::new(),
+            width: Length::Fill,
+            height: Length::Fill,
+            text_size: 12.0,
+            format_time: Box::new(format_time),
+            format_value: Box::new(|value| format!("{value}")),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the duration of the time window shown by the [`LiveChart`].
+    #[must_use]
+    pub fn window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// Sets the time in seconds at the right edge of the [`LiveChart`].
+    ///
+    /// By default, this is the time of the latest sample, so the chart
+    /// scrolls in steps as samples arrive. Setting it from a clock on
+    /// every frame scrolls the chart smoothly.
+    #[must_use]
+    pub fn now(mut self, now: f64) -> Self {
+        self.now = Some(now);
+        self
+    }
+
+    /// Sets the fixed range of the value axis, instead of adapting it to the
+    /// shown samples.
+    #[must_use]
+    pub fn range(mut self, min: f32, max: f32) -> Self {
+        self.range = Some((min.min(max), max.max(min)));
+        self
+    }
+
+    /// Adds an [`Annotation`] drawn above the series of the [`LiveChart`].
+    #[must_use]
+    pub fn annotation(mut self, annotation: Annotation) -> Self {
+        self.annotations.push(annotation);
+        self
+    }
+
+    /// Sets the width of the [`LiveChart`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`LiveChart`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the text size of the labels of the [`LiveChart`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the function formatting the labels of the time axis from the
+    /// time in seconds and the current [`TickStep`].
+    #[must_use]
+    pub fn format_time<F>(mut self, format_time: F) -> Self
+    where
+        F: 'a + Fn(f64, TickStep) -> String,
+    {
+        self.format_time = Box::new(format_time);
+        self
+    }
+
+    /// Sets the function formatting the labels of the value axis and the
+    /// values shown in the tooltip.
+    #[must_use]
+    pub fn format_value<F>(mut self, format_value: F) -> Self
+    where
+        F: 'a + Fn(f32) -> String,
+    {
+        self.format_value = Box::new(format_value);
+        self
+    }
+
+    /// Sets the style of the [`LiveChart`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the start and the end of the shown time window.
+    fn time_range(&self) -> (f64, f64) {
+        let end = self.now.unwrap_or_else(|| {
+            self.series
+                .iter()
+                .filter_map(Series::latest)
+                .map(|(time, _)| time)
+                .reduce(f64::max)
+                .unwrap_or(0.0)
+        });
+
+        (end - self.window.as_secs_f64().max(f64::EPSILON), end)
+    }
+
+    /// Returns the range of the value axis and the values labeled on it,
+    /// for the given samples and the given height of the plot.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    fn value_range(&self, samples: &[Vec<(f64, f32)>], height: f32) -> ((f32, f32), Vec<f32>) {
+        let count = (height / (self.text_size * 3.0)).max(2.0) as usize;
+
+        if let Some((min, max)) = self.range {
+            let ticks = nice_ticks(min, max, count)
+                .into_iter()
+                .filter(|tick| (min..=max).contains(tick))
+                .collect();
+
+            return ((min, max), ticks);
+        }
+
+        let (min, max) = samples.iter().flatten().fold(
+            (f32::INFINITY, f32::NEG_INFINITY),
+            |(min, max), (_, value)| (min.min(*value), max.max(*value)),
+        );
+        let ticks = if min <= max {
+            nice_ticks(min, max, count)
+        } else {
+            nice_ticks(0.0, 1.0, count)
+        };
+
+        match (ticks.first(), ticks.last()) {
+            (Some(&low), Some(&high)) if high > low => ((low, high), ticks),
+            _ => ((0.0, 1.0), ticks),
+        }
+    }
+}
+
+impl<Message, Theme> Widget<Message, Renderer<Theme>> for LiveChart<'_, Theme>
+where
+    Theme: StyleSheet,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer<Theme>, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        Node::new(limits.resolve(Size::new(f32::INFINITY, f32::INFINITY)))
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer<Theme>,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Crosshair
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn draw(
+        &self,
+        _state: &Tree,
+        renderer: &mut Renderer<Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let line_height = self.text_size * 1.3;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        let (start, end) = self.time_range();
+        let samples: Vec<Vec<(f64, f32)>> = self
+            .series
+            .iter()
+            .map(|series| series.window(start, end).collect())
+            .collect();
+
+        // The value axis is labeled on the left and the time axis below,
+        // with room above for the half of the topmost label.
+        let top = line_height / 2.0;
+        let bottom = line_height + PADDING * 2.0;
+        let ((low, high), ticks) = self.value_range(&samples, bounds.height - top - bottom);
+        let labels: Vec<String> = ticks
+            .iter()
+            .map(|tick| (self.format_value)(*tick))
+            .collect();
+        let left = labels
+            .iter()
+            .map(|label| {
+                renderer.measure_width(
+                    label,
+                    self.text_size,
+                    core::Font::default(),
+                    text::Shaping::Advanced,
+                )
+            })
+            .fold(0.0, f32::max)
+            + PADDING * 2.0;
+
+        let plot = Rectangle {
+            x: bounds.x + left,
+            y: bounds.y + top,
+            width: (bounds.width - left - PADDING).max(1.0),
+            height: (bounds.height - top - bottom).max(1.0),
+        };
+        let seconds = end - start;
+
+        #[allow(clippy::cast_possible_truncation)]
+        let x = |time: f64| plot.x + ((time - start) / seconds) as f32 * plot.width;
+        let y = |value: f32| plot.y + plot.height - (value - low) / (high - low) * plot.height;
+        let local = |point: Point| point - Vector::new(bounds.x, bounds.y);
+
+        let step = TickStep::for_zoom(seconds / f64::from(plot.width), MIN_SPACING);
+
+        // The straight lines are drawn as quads, crisp at any position.
+        for tick in &ticks {
+            let rule = Rectangle::new(
+                Point::new(plot.x, y(*tick).floor()),
+                Size::new(plot.width, 1.0),
+            );
+            fill_rule(renderer, rule, appearance.grid_color);
+        }
+        for time in step.ticks(start, end) {
+            let rule = Rectangle::new(
+                Point::new(x(time).floor(), plot.y),
+                Size::new(1.0, plot.height),
+            );
+            fill_rule(renderer, rule, appearance.grid_color);
+        }
+
+        fill_rule(
+            renderer,
+            Rectangle::new(
+                Point::new(plot.x.floor(), plot.y),
+                Size::new(1.0, plot.height),
+            ),
+            appearance.axis_color,
+        );
+        fill_rule(
+            renderer,
+            Rectangle::new(
+                Point::new(plot.x, (plot.y + plot.height).floor()),
+                Size::new(plot.width, 1.0),
+            ),
+            appearance.axis_color,
+        );
+
+        // The lines are drawn with a few samples per pixel, and clipped to
+        // the plot.
+        let mut lines = canvas::Frame::new(renderer, bounds.size());
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let buckets = plot.width.ceil() as usize;
+
+        for (index, samples) in samples.iter().enumerate() {
+            let decimated = decimate(samples.iter().copied(), start, end, buckets);
+            if decimated.len() < 2 {
+                continue;
+            }
+
+            let path = Path::new(|builder| {
+                for (i, (time, value)) in decimated.iter().enumerate() {
+                    let point = local(Point::new(x(*time), y(*value)));
+
+                    if i == 0 {
+                        builder.move_to(point);
+                    } else {
+                        builder.line_to(point);
+                    }
+                }
+            });
+            lines.stroke(
+                &path,
+                Stroke::default()
+                    .with_color(appearance.palette[index % appearance.palette.len()])
+                    .with_width(appearance.line_width),
+            );
+        }
+
+        let hovered = cursor.position_over(plot).map(|position| {
+            let time = start + f64::from((position.x - plot.x) / plot.width) * seconds;
+            let values: Vec<(usize, f32)> = self
+                .series
+                .iter()
+                .enumerate()
+                .filter_map(|(index, series)| Some((index, series.value_at(time)?)))
+                .collect();
+
+            (position, time, values)
+        });
+
+        if let Some((position, _, values)) = &hovered {
+            fill_rule(
+                renderer,
+                Rectangle::new(
+                    Point::new(position.x.floor(), plot.y),
+                    Size::new(1.0, plot.height),
+                ),
+                appearance.crosshair_color,
+            );
+            fill_rule(
+                renderer,
+                Rectangle::new(
+                    Point::new(plot.x, position.y.floor()),
+                    Size::new(plot.width, 1.0),
+                ),
+                appearance.crosshair_color,
+            );
+
+            for (index, value) in values {
+                lines.fill(
+                    &Path::circle(local(Point::new(position.x, y(*value))), 3.0),
+                    appearance.palette[index % appearance.palette.len()],
+                );
+            }
+        }
+
+        renderer.with_layer(plot, |renderer| {
+            renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+                renderer.draw(vec![lines.into_geometry()]);
+            });
+        });
+
+        let marks: Vec<Option<Rectangle>> = self
+            .annotations
+            .iter()
+            .map(|annotation| annotation.layout(plot, x, y))
+            .collect();
+        annotation::draw(
+            renderer,
+            &self.annotations,
+            &marks,
+            cursor
+                .position_over(plot)
+                .and_then(|position| annotation::hovered(&self.annotations, &marks, position)),
+            plot,
+            annotation::Style {
+                color: appearance.annotation_color,
+                region_color: appearance.region_color,
+                label_background: appearance.tooltip_background,
+                label_text_color: appearance.tooltip_text_color,
+                text_size: self.text_size,
+            },
+        );
+
+        renderer.with_layer(bounds, |renderer| {
+            for (tick, label) in ticks.iter().zip(&labels) {
+                renderer.fill_text(core::text::Text {
+                    content: label,
+                    bounds: Rectangle {
+                        x: plot.x - PADDING,
+                        y: y(*tick),
+                        width: left,
+                        height: line_height,
+                    },
+                    size: self.text_size,
+                    line_height: LineHeight::default(),
+                    color: appearance.text_color,
+                    font: core::Font::default(),
+                    horizontal_alignment: Horizontal::Right,
+                    vertical_alignment: Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                });
+            }
+
+            for time in step.ticks(start, end) {
+                let label = (self.format_time)(time, step);
+                let width = renderer.measure_width(
+                    &label,
+                    self.text_size,
+                    core::Font::default(),
+                    text::Shaping::Advanced,
+                );
+                // Labels scrolling out of the chart are hidden as a whole.
+                let x = x(time);
+                if x - width / 2.0 < bounds.x || x + width / 2.0 > bounds.x + bounds.width {
+                    continue;
+                }
+
+                renderer.fill_text(core::text::Text {
+                    content: &label,
+                    bounds: Rectangle {
+                        x,
+                        y: plot.y + plot.height + PADDING,
+                        width,
+                        height: line_height,
+                    },
+                    size: self.text_size,
+                    line_height: LineHeight::default(),
+                    color: appearance.text_color,
+                    font: core::Font::default(),
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Top,
+                    shaping: text::Shaping::Advanced,
+                });
+            }
+        });
+
+        let Some((position, time, values)) = hovered else {
+            return;
+        };
+
+        let lines: Vec<String> = std::iter::once((self.format_time)(
+            time,
+            TickStep::for_zoom(seconds / f64::from(plot.width), 1.0),
+        ))
+        .chain(values.iter().map(|(index, value)| {
+            format!(
+                "{}: {}",
+                self.series[*index].label,
+                (self.format_value)(*value)
+            )
+        }))
+        .collect();
+        chart::tooltip(
+            renderer,
+            &lines,
+            self.text_size,
+            text::Shaping::Advanced,
+            position,
+            bounds,
+            appearance.tooltip_background,
+            appearance.tooltip_text_color,
+        );
+    }
+}
+
+impl<'a, Message, Theme> From<LiveChart<'a, Theme>> for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+{
+    fn from(live_chart: LiveChart<'a, Theme>) -> Self {
+        Element::new(live_chart)
+    }
+}
+
+/// Fills the given rectangle of a straight line of the chart.
+fn fill_rule<Theme>(renderer: &mut Renderer<Theme>, bounds: Rectangle, color: Color) {
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border_radius: (0.0).into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        },
+        color,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Annotation, LiveChart, Series};
+    use crate::test_utils::Harness;
+    use iced_widget::core::{Color, Point, Size};
+    use std::time::Duration;
+
+    #[test]
+    fn live_chart_test() {
+        // Ten seconds of dense samples at a thousand samples per second.
+        let mut series = Series::new("Load", 20_000);
+        for i in 0..10_000_u32 {
+            let time = f64::from(i) / 1000.0;
+            #[allow(clippy::cast_possible_truncation)]
+            series.push(time, 50.0 + 20.0 * time.sin() as f32);
+        }
+        let series = [series];
+
+        let chart = LiveChart::new(&series)
+            .window(Duration::from_secs(5))
+            .range(0.0, 100.0);
+        let mut harness = Harness::<()>::new(chart, Size::new(300.0, 200.0));
+        let plain = harness.snapshot();
+
+        // The decimated line is drawn across the chart.
+        let line = Color::from_rgb(0.31, 0.47, 0.65);
+        assert!((0..200).any(|y| plain.color(250, y).is_some_and(|color| {
+            (color.r - line.r).abs() < 0.1
+                && (color.g - line.g).abs() < 0.1
+                && (color.b - line.b).abs() < 0.1
+        })));
+
+        // Hovering shows the crosshair and the tooltip.
+        assert!(harness.move_cursor(Point::new(150.0, 50.0)).is_empty());
+        let hovered = harness.snapshot();
+        assert!(plain.diff(&hovered, 8).is_some_and(|count| count > 100));
+    }
+
+    #[test]
+    fn annotation_test() {
+        let mut series = Series::new("Load", 100);
+        series.push(0.0, 20.0);
+        series.push(10.0, 40.0);
+        let series = [series];
+
+        let chart = LiveChart::new(&series)
+            .range(0.0, 100.0)
+            .annotation(Annotation::time_region(2.0, 4.0, ""))
+            .annotation(Annotation::horizontal_line(50.0, "Limit"));
+        let mut harness = Harness::<()>::new(chart, Size::new(300.0, 200.0));
+        let plain = harness.snapshot();
+
+        // The region is shaded over the height of the plot.
+        let shaded = plain.color(100, 160).expect("pixel inside of the chart");
+        let background = plain.color(200, 160).expect("pixel inside of the chart");
+        assert!(shaded.b - shaded.r > 0.03);
+        assert!((background.b - background.r).abs() < 0.01);
+
+        // Hovering the region emphasizes it.
+        let _ = harness.move_cursor(Point::new(100.0, 30.0));
+        let hovered = harness
+            .snapshot()
+            .color(95, 160)
+            .expect("pixel inside of the chart");
+        assert!(hovered.r < shaded.r - 0.03);
+    }
+}
@@ -5,7 +5,10 @@ use super::{
     },
     menu_tree::MenuTree,
 };
-use crate::style::menu_bar::StyleSheet;
+use crate::{
+    core::layout_direction::{self, LayoutDirection},
+    style::menu_bar::StyleSheet,
+};
 
 use iced_widget::core::{
     event,
@@ -74,6 +77,7 @@ where
     path_highlight: Option<PathHighlight>,
     menu_roots: Vec<MenuTree<'a, Message, Renderer>>,
     style: <Renderer::Theme as StyleSheet>::Style,
+    direction: LayoutDirection,
 }
 
 impl<'a, Message, Renderer> MenuBar<'a, Message, Renderer>
@@ -105,6 +109,7 @@ where
             path_highlight: Some(PathHighlight::MenuActive),
             menu_roots,
             style: <Renderer::Theme as StyleSheet>::Style::default(),
+            direction: layout_direction::get(),
         }
     }
 
@@ -284,7 +289,7 @@ where
             .iter()
             .map(|root| &root.item)
             .collect::<Vec<_>>();
-        flex::resolve(
+        self.direction.arrange(flex::resolve(
             &flex::Axis::Horizontal,
             renderer,
             &limits,
@@ -292,7 +297,7 @@ where
             self.spacing,
             Alignment::Center,
             &children,
-        )
+        ))
     }
 
     fn on_event(
@@ -416,6 +421,7 @@ where
                 root_bounds_list: layout.children().map(|lo| lo.bounds()).collect(),
                 path_highlight: self.path_highlight,
                 style: &self.style,
+                direction: self.direction,
             }
             .overlay(),
         )
@@ -1,6 +1,6 @@
 //! Menu tree overlay
 use super::{menu_bar::MenuBarState, menu_tree::MenuTree};
-use crate::style::menu_bar::StyleSheet;
+use crate::{core::layout_direction::LayoutDirection, style::menu_bar::StyleSheet};
 
 use iced_widget::core::{
     event,
@@ -440,6 +440,7 @@ where
     pub(super) root_bounds_list: Vec<Rectangle>,
     pub(super) path_highlight: Option<PathHighlight>,
     pub(super) style: &'b <Renderer::Theme as StyleSheet>::Style,
+    pub(super) direction: LayoutDirection,
 }
 impl<'a, 'b, Message, Renderer> Menu<'a, 'b, Message, Renderer>
 where
@@ -731,11 +732,14 @@ fn init_root_menu<Message, Renderer>(
             let view_center = viewport_size.width * 0.5;
             let rb_center = root_bounds.center_x();
 
-            state.horizontal_direction = if rb_center > view_center {
-                Direction::Negative
-            } else {
-                Direction::Positive
-            };
+            // From right to left, the menus open to the left unless there
+            // is not enough space.
+            state.horizontal_direction =
+                if menu.direction.is_right_to_left() || rb_center > view_center {
+                    Direction::Negative
+                } else {
+                    Direction::Positive
+                };
 
             let aod = Aod {
                 horizontal: true,
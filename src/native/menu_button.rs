@@ -0,0 +1,348 @@
+//! Use a menu button to choose an action from a dropdown menu.
+//!
+//! *This API requires the following crate features to be activated: `menu_button`*
+use crate::core::menu_item::{self, MenuItem};
+use crate::graphics::icons::{icon_to_char, Icon, ICON_FONT};
+use crate::native::overlay::split_button::{MenuState, SplitButtonMenu};
+use crate::style::split_button::StyleSheet;
+
+use iced_widget::{
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event, keyboard,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        overlay, renderer, text, touch,
+        widget::{
+            tree::{State as TreeState, Tag},
+            Tree,
+        },
+        Clipboard, Element, Event, Font, Layout, Length, Pixels, Rectangle, Shell, Size, Widget,
+    },
+    text::LineHeight,
+};
+
+/// The width of the chevron after the label.
+const CHEVRON_WIDTH: f32 = 20.0;
+
+/// A button opening a dropdown menu of actions.
+///
+/// The [`MenuButton`] keeps track of whether its menu is open: a press on the
+/// button toggles the menu, and a press outside of it or `Escape` closes it.
+/// Once focused by a press, the `Down` arrow key opens the menu with its
+/// first enabled action highlighted. The button is disabled if none of the
+/// [`MenuItem`]s are enabled.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{MenuButton, MenuItem};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     NewFile,
+///     NewFolder,
+/// }
+///
+/// let menu_button = MenuButton::new(
+///     "New",
+///     vec![
+///         MenuItem::new("File").on_select(Message::NewFile),
+///         MenuItem::new("Folder").on_select(Message::NewFolder),
+///     ],
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct MenuButton<Message, Renderer = crate::Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The label of the button.
+    label: String,
+    /// The actions of the dropdown menu.
+    items: Vec<MenuItem<Message>>,
+    /// The width of the [`MenuButton`].
+    width: Length,
+    /// The padding around the label.
+    padding: f32,
+    /// The text size of the [`MenuButton`].
+    text_size: f32,
+    /// The font of the [`MenuButton`].
+    font: Font,
+    /// The style of the [`MenuButton`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<Message, Renderer> MenuButton<Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`MenuButton`] with the given label and the actions of
+    /// its dropdown menu.
+    pub fn new(label: impl Into<String>, items: Vec<MenuItem<Message>>) -> Self {
+        Self {
+            label: label.into(),
+            items,
+            width: Length::Shrink,
+            padding: 8.0,
+            text_size: 16.0,
+            font: Font::DEFAULT,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`MenuButton`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding around the label of the [`MenuButton`].
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`MenuButton`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the [`MenuButton`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`MenuButton`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns `true` if the dropdown menu has an enabled action.
+    fn is_enabled(&self) -> bool {
+        self.items.iter().any(MenuItem::is_enabled)
+    }
+}
+
+/// The state of a [`MenuButton`].
+#[derive(Clone, Debug, Default)]
+struct State {
+    /// Whether the [`MenuButton`] has the keyboard focus.
+    is_focused: bool,
+    /// The state of the dropdown menu.
+    menu: MenuState,
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for MenuButton<Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let label_width = renderer.measure_width(
+            &self.label,
+            self.text_size,
+            self.font,
+            text::Shaping::Advanced,
+        );
+        let line_height = LineHeight::default().to_absolute(Pixels(self.text_size)).0;
+
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let size = limits.resolve(Size::new(
+            label_width + self.padding * 2.0 + CHEVRON_WIDTH,
+            line_height + self.padding * 2.0,
+        ));
+
+        Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if cursor.is_over(layout.bounds()) && self.is_enabled() {
+                    state.is_focused = true;
+                    state.menu.is_open = !state.menu.is_open;
+                    state.menu.highlighted = None;
+                    return event::Status::Captured;
+                }
+                state.is_focused = false;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Down,
+                ..
+            }) if state.is_focused && !state.menu.is_open && self.is_enabled() => {
+                state.menu.is_open = true;
+                state.menu.highlighted = menu_item::next_enabled(&self.items, None, true);
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) && self.is_enabled() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let appearance = if !self.is_enabled() {
+            theme.disabled(&self.style)
+        } else if state.menu.is_open {
+            theme.pressed(&self.style)
+        } else if cursor.is_over(bounds) {
+            theme.hovered(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius.into(),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        let label = Rectangle {
+            width: (bounds.width - CHEVRON_WIDTH).max(0.0),
+            ..bounds
+        };
+
+        renderer.fill_text(core::text::Text {
+            content: &self.label,
+            bounds: Rectangle {
+                x: label.center_x(),
+                y: label.center_y(),
+                ..label
+            },
+            size: self.text_size,
+            line_height: LineHeight::default(),
+            color: appearance.text_color,
+            font: self.font,
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+            shaping: text::Shaping::Advanced,
+        });
+
+        renderer.fill_text(core::text::Text {
+            content: &icon_to_char(Icon::CaretDownFill).to_string(),
+            bounds: Rectangle {
+                x: label.x + label.width + CHEVRON_WIDTH / 2.0 - self.padding / 2.0,
+                y: bounds.center_y(),
+                ..bounds
+            },
+            size: self.text_size * 0.75,
+            line_height: LineHeight::default(),
+            color: appearance.text_color,
+            font: ICON_FONT,
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+            shaping: text::Shaping::Basic,
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.menu.is_open {
+            return None;
+        }
+
+        Some(
+            SplitButtonMenu::new(
+                &mut state.menu,
+                &self.items,
+                layout.bounds(),
+                layout.bounds(),
+                self.text_size,
+                self.font,
+                self.style.clone(),
+            )
+            .overlay(),
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<MenuButton<Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(menu_button: MenuButton<Message, Renderer>) -> Self {
+        Element::new(menu_button)
+    }
+}
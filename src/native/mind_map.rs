@@ -0,0 +1,1068 @@
+//! Use a mind map to edit a tree of ideas around a central one.
+//!
+//! *This API requires the following crate features to be activated: `mind_map`*
+use std::collections::HashMap;
+
+use crate::core::{
+    mind_map::{place, Placed},
+    tree_node::TreeNode,
+    viewport::Viewport,
+};
+use crate::graphics::icons::{Icon, ICON_FONT};
+
+use iced_widget::{
+    canvas::{self, Path, Stroke},
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event, keyboard,
+        layout::{Limits, Node},
+        mouse::{self, click, Cursor},
+        renderer,
+        text::{self, Renderer as _},
+        touch,
+        widget::{
+            tree::{State as TreeState, Tag},
+            Tree,
+        },
+        Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Renderer as _, Shell,
+        Size, Vector, Widget,
+    },
+    graphics::geometry::Renderer as _,
+    renderer::Renderer,
+    text::LineHeight,
+};
+
+pub use crate::core::mind_map::MindMapLayout;
+pub use crate::style::mind_map::{Appearance, StyleSheet};
+
+/// The radius of the buttons adding and removing nodes.
+const BUTTON_RADIUS: f32 = 9.0;
+/// The distance a node or the map has to be dragged before it moves.
+const DRAG_THRESHOLD: f32 = 4.0;
+/// The factor the zoom changes by for every line scrolled.
+const ZOOM_STEP: f32 = 1.2;
+/// The minimal width of the box of a node.
+const MIN_NODE_WIDTH: f32 = 40.0;
+
+/// An editor for a mind map, a tree of ideas laid out around a central one.
+///
+/// The nodes are laid out automatically, radially around the root or as a
+/// tree from left to right, and can be dragged to another place. Dropping a
+/// dragged node onto another node moves it there with
+/// [`on_move`](Self::on_move).
+///
+/// A click selects a node, and a double click, `F2` or `Enter` edits its
+/// label in place. The selected node has buttons, also bound to `Tab` and
+/// `Delete`, to add a child and to remove it. The map is panned by dragging
+/// its background and zoomed with the mouse wheel.
+///
+/// The [`MindMap`] only emits the structural changes as messages, so the
+/// application stays the owner of the tree, e.g. moving nodes with
+/// [`move_node`](crate::core::tree_node::move_node).
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{MindMap, TreeNode};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Add(usize),
+///     Rename(usize, String),
+/// }
+///
+/// let root = TreeNode::new(0, "Idea").children([TreeNode::new(1, "Detail")]);
+///
+/// let mind_map = MindMap::new(&root)
+///     .on_add(Message::Add)
+///     .on_rename(Message::Rename);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct MindMap<'a, Message, Theme = iced_widget::style::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The root of the tree.
+    root: &'a TreeNode,
+    /// The function producing the message when a node is selected.
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    /// The function producing the message adding a child to a node.
+    on_add: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    /// The function producing the message removing a node.
+    on_remove: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    /// The function producing the message when a node is renamed.
+    on_rename: Option<Box<dyn Fn(usize, String) -> Message + 'a>>,
+    /// The function producing the message moving a node into another one.
+    on_move: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    /// The layout of the nodes.
+    layout: MindMapLayout,
+    /// The distances between the levels and the leaves of the tree.
+    spacing: Size,
+    /// The width of the [`MindMap`].
+    width: Length,
+    /// The height of the [`MindMap`].
+    height: Length,
+    /// The text size of the labels.
+    text_size: f32,
+    /// The padding between the boxes of the nodes and their labels.
+    padding: f32,
+    /// The range of the zoom.
+    zoom_range: (f32, f32),
+    /// The style of the [`MindMap`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> MindMap<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`MindMap`] of the tree with the given root.
+    #[must_use]
+    pub fn new(root: &'a TreeNode) -> Self {
+        Self {
+            root,
+            on_select: None,
+            on_add: None,
+            on_remove: None,
+            on_rename: None,
+            on_move: None,
+            layout: MindMapLayout::default(),
+            spacing: Size::new(160.0, 48.0),
+            width: Length::Fill,
+            height: Length::Fill,
+            text_size: 14.0,
+            padding: 8.0,
+            zoom_range: (0.25, 4.0),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the function producing the message with the identifier of a node
+    /// when it is selected.
+    #[must_use]
+    pub fn on_select<F>(mut self, on_select: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the function producing the message with the identifier of the
+    /// node to add a new child to.
+    #[must_use]
+    pub fn on_add<F>(mut self, on_add: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        self.on_add = Some(Box::new(on_add));
+        self
+    }
+
+    /// Sets the function producing the message with the identifier of the
+    /// node to remove. The root can't be removed.
+    #[must_use]
+    pub fn on_remove<F>(mut self, on_remove: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        self.on_remove = Some(Box::new(on_remove));
+        self
+    }
+
+    /// Sets the function producing the message with the identifier and the
+    /// new label of a renamed node.
+    #[must_use]
+    pub fn on_rename<F>(mut self, on_rename: F) -> Self
+    where
+        F: 'a + Fn(usize, String) -> Message,
+    {
+        self.on_rename = Some(Box::new(on_rename));
+        self
+    }
+
+    /// Sets the function producing the message with the identifiers of a
+    /// dragged node and of the node it is dropped onto, to become its last
+    /// child.
+    #[must_use]
+    pub fn on_move<F>(mut self, on_move: F) -> Self
+    where
+        F: 'a + Fn(usize, usize) -> Message,
+    {
+        self.on_move = Some(Box::new(on_move));
+        self
+    }
+
+    /// Sets the [`MindMapLayout`] of the [`MindMap`].
+    #[must_use]
+    pub fn layout(mut self, layout: MindMapLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Sets the distance between the levels of the tree and, with
+    /// [`MindMapLayout::Tree`], between its leaves.
+    #[must_use]
+    pub fn spacing(mut self, level: f32, leaf: f32) -> Self {
+        self.spacing = Size::new(level, leaf);
+        self
+    }
+
+    /// Sets the width of the [`MindMap`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`MindMap`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the text size of the labels of the [`MindMap`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the padding between the boxes of the nodes and their labels.
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the minimal and the maximal zoom of the [`MindMap`].
+    #[must_use]
+    pub fn zoom_range(mut self, min: f32, max: f32) -> Self {
+        if 0.0 < min && min <= max {
+            self.zoom_range = (min, max);
+        }
+        self
+    }
+
+    /// Sets the style of the [`MindMap`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the nodes with their boxes on the screen, in preorder.
+    fn nodes(&self, state: &State, bounds: Rectangle, renderer: &Renderer<Theme>) -> Vec<Shown> {
+        let mut offsets: HashMap<usize, Vector> = HashMap::new();
+
+        place(self.root, self.layout, self.spacing)
+            .into_iter()
+            .map(|placed| {
+                // A dragged node takes its branch with it.
+                let offset = placed
+                    .parent
+                    .and_then(|parent| offsets.get(&parent).copied())
+                    .unwrap_or(Vector::new(0.0, 0.0))
+                    + state
+                        .offsets
+                        .get(&placed.id)
+                        .copied()
+                        .unwrap_or(Vector::new(0.0, 0.0));
+                let _ = offsets.insert(placed.id, offset);
+
+                let label = match &state.edit {
+                    Some(edit) if edit.id == placed.id => edit.value.clone(),
+                    _ => self
+                        .root
+                        .find(placed.id)
+                        .map(|node| node.label.clone())
+                        .unwrap_or_default(),
+                };
+                let width = renderer.measure_width(
+                    &label,
+                    self.text_size,
+                    core::Font::default(),
+                    text::Shaping::Advanced,
+                );
+                let size = Size::new(
+                    (width + self.padding * 2.0).max(MIN_NODE_WIDTH) * state.viewport.zoom,
+                    (self.text_size * 1.3 + self.padding * 2.0) * state.viewport.zoom,
+                );
+                let center = state.viewport.project(bounds, placed.position + offset);
+
+                Shown {
+                    placed,
+                    label,
+                    bounds: Rectangle::new(
+                        center - Vector::new(size.width / 2.0, size.height / 2.0),
+                        size,
+                    ),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the identifier of the top-most node at the given position.
+    fn node_at(nodes: &[Shown], position: Point) -> Option<usize> {
+        nodes
+            .iter()
+            .rev()
+            .find(|node| node.bounds.contains(position))
+            .map(|node| node.placed.id)
+    }
+
+    /// Returns the buttons adding a child to and removing the selected node.
+    fn buttons(&self, state: &State, nodes: &[Shown]) -> Vec<(Button, Point)> {
+        let Some(selected) = state
+            .selected
+            .filter(|_| state.edit.is_none())
+            .and_then(|id| nodes.iter().find(|node| node.placed.id == id))
+        else {
+            return Vec::new();
+        };
+        let bounds = selected.bounds;
+        let mut buttons = Vec::new();
+
+        if self.on_add.is_some() {
+            buttons.push((
+                Button::Add,
+                Point::new(
+                    bounds.x + bounds.width + BUTTON_RADIUS + 2.0,
+                    bounds.center_y(),
+                ),
+            ));
+        }
+        if self.on_remove.is_some() && selected.placed.parent.is_some() {
+            buttons.push((
+                Button::Remove,
+                Point::new(bounds.x + bounds.width, bounds.y),
+            ));
+        }
+
+        buttons
+    }
+
+    /// Returns the button at the given position.
+    fn button_at(&self, state: &State, nodes: &[Shown], position: Point) -> Option<Button> {
+        self.buttons(state, nodes)
+            .into_iter()
+            .find(|(_, center)| center.distance(position) <= BUTTON_RADIUS)
+            .map(|(button, _)| button)
+    }
+
+    /// Returns the node a dragged node would be dropped onto at the given
+    /// position, which can't be the dragged branch or the current parent.
+    fn drop_target(&self, nodes: &[Shown], source: usize, position: Point) -> Option<usize> {
+        let branch = self
+            .root
+            .find(source)
+            .map(TreeNode::ids)
+            .unwrap_or_default();
+        let parent = nodes
+            .iter()
+            .find(|node| node.placed.id == source)
+            .and_then(|node| node.placed.parent);
+
+        nodes
+            .iter()
+            .rev()
+            .find(|node| {
+                node.bounds.contains(position)
+                    && !branch.contains(&node.placed.id)
+                    && Some(node.placed.id) != parent
+            })
+            .map(|node| node.placed.id)
+    }
+
+    /// Selects the given node.
+    fn select(&self, state: &mut State, id: usize, shell: &mut Shell<'_, Message>) {
+        if state.selected == Some(id) {
+            return;
+        }
+
+        state.selected = Some(id);
+        if let Some(on_select) = &self.on_select {
+            shell.publish(on_select(id));
+        }
+    }
+
+    /// Publishes the message of the given button for the selected node.
+    fn press_button(&self, state: &mut State, button: Button, shell: &mut Shell<'_, Message>) {
+        let Some(selected) = state.selected else {
+            return;
+        };
+
+        match button {
+            Button::Add => {
+                if let Some(on_add) = &self.on_add {
+                    shell.publish(on_add(selected));
+                }
+            }
+            Button::Remove => {
+                if let Some(on_remove) = &self.on_remove {
+                    shell.publish(on_remove(selected));
+                    state.selected = None;
+                }
+            }
+        }
+    }
+
+    /// Starts editing the label of the given node.
+    fn start_edit(&self, state: &mut State, id: usize) {
+        if self.on_rename.is_none() {
+            return;
+        }
+
+        if let Some(node) = self.root.find(id) {
+            state.edit = Some(Edit::new(id, node.label.clone()));
+            state.drag = None;
+        }
+    }
+
+    /// Commits the edited label, if it changed and is not blank.
+    fn commit_edit(&self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        let (Some(edit), Some(on_rename)) = (state.edit.take(), &self.on_rename) else {
+            return;
+        };
+
+        let changed = self
+            .root
+            .find(edit.id)
+            .is_some_and(|node| node.label != edit.value);
+        if changed && !edit.value.trim().is_empty() {
+            shell.publish(on_rename(edit.id, edit.value));
+        }
+    }
+
+    /// Applies the given keyboard event to the edited label.
+    fn edit_label(
+        &self,
+        state: &mut State,
+        event: keyboard::Event,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let Some(edit) = &mut state.edit else {
+            return;
+        };
+
+        match event {
+            keyboard::Event::CharacterReceived(c)
+                if !c.is_control() && !state.modifiers.command() =>
+            {
+                edit.value.insert(edit.cursor, c);
+                edit.cursor += c.len_utf8();
+            }
+            keyboard::Event::KeyPressed { key_code, .. } => match key_code {
+                keyboard::KeyCode::Enter | keyboard::KeyCode::NumpadEnter => {
+                    self.commit_edit(state, shell);
+                }
+                keyboard::KeyCode::Escape => state.edit = None,
+                keyboard::KeyCode::Backspace if edit.cursor > 0 => {
+                    let previous = edit.previous();
+                    edit.value.replace_range(previous..edit.cursor, "");
+                    edit.cursor = previous;
+                }
+                keyboard::KeyCode::Delete if edit.cursor < edit.value.len() => {
+                    let next = edit.next();
+                    edit.value.replace_range(edit.cursor..next, "");
+                }
+                keyboard::KeyCode::Left => edit.cursor = edit.previous(),
+                keyboard::KeyCode::Right => edit.cursor = edit.next(),
+                keyboard::KeyCode::Home => edit.cursor = 0,
+                keyboard::KeyCode::End => edit.cursor = edit.value.len(),
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+}
+
+/// A node of a [`MindMap`] shown on the screen.
+struct Shown {
+    /// The placement of the node.
+    placed: Placed,
+    /// The label shown in the node, which is the edited label while editing.
+    label: String,
+    /// The box of the node on the screen.
+    bounds: Rectangle,
+}
+
+/// A button of the selected node of a [`MindMap`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Button {
+    /// Adds a child to the node.
+    Add,
+    /// Removes the node.
+    Remove,
+}
+
+/// The state of a [`MindMap`].
+#[derive(Debug, Default)]
+struct State {
+    /// The pan and zoom of the map.
+    viewport: Viewport,
+    /// The offsets of the dragged nodes from their automatic positions.
+    offsets: HashMap<usize, Vector>,
+    /// The selected node.
+    selected: Option<usize>,
+    /// Whether the [`MindMap`] takes the keyboard.
+    is_focused: bool,
+    /// The ongoing drag of a node or of the map.
+    drag: Option<Drag>,
+    /// The label being edited.
+    edit: Option<Edit>,
+    /// The pressed keyboard modifiers.
+    modifiers: keyboard::Modifiers,
+    /// The last click, for detecting double clicks.
+    last_click: Option<click::Click>,
+}
+
+/// A drag of a node or of the whole map of a [`MindMap`].
+#[derive(Clone, Copy, Debug)]
+struct Drag {
+    /// The dragged node, or `None` if the map is panned.
+    node: Option<usize>,
+    /// The position the drag started at.
+    origin: Point,
+    /// The offset of the node or of the map when the drag started.
+    start: Vector,
+    /// Whether the drag moved far enough to move the node or the map.
+    is_moving: bool,
+    /// The node the dragged node would be dropped onto.
+    target: Option<usize>,
+}
+
+/// The label of a node of a [`MindMap`] being edited.
+#[derive(Clone, Debug)]
+struct Edit {
+    /// The edited node.
+    id: usize,
+    /// The edited label.
+    value: String,
+    /// The byte index of the text cursor.
+    cursor: usize,
+}
+
+impl Edit {
+    /// Creates a new [`Edit`] of the given node with the cursor at the end.
+    fn new(id: usize, value: String) -> Self {
+        Self {
+            id,
+            cursor: value.len(),
+            value,
+        }
+    }
+
+    /// Returns the byte index of the character before the cursor.
+    fn previous(&self) -> usize {
+        self.value[..self.cursor]
+            .char_indices()
+            .next_back()
+            .map_or(0, |(index, _)| index)
+    }
+
+    /// Returns the byte index of the character after the cursor.
+    fn next(&self) -> usize {
+        self.value[self.cursor..]
+            .chars()
+            .next()
+            .map_or(self.cursor, |c| self.cursor + c.len_utf8())
+    }
+}
+
+impl<'a, Message, Theme> Widget<Message, Renderer<Theme>> for MindMap<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer<Theme>, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer<Theme>,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let nodes = self.nodes(state, bounds, renderer);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    self.commit_edit(state, shell);
+                    state.is_focused = false;
+                    return event::Status::Ignored;
+                };
+                state.is_focused = true;
+
+                let node = Self::node_at(&nodes, position);
+                if node.is_some() && state.edit.as_ref().map(|edit| edit.id) == node {
+                    return event::Status::Captured;
+                }
+                self.commit_edit(state, shell);
+
+                if let Some(button) = self.button_at(state, &nodes, position) {
+                    self.press_button(state, button, shell);
+                    return event::Status::Captured;
+                }
+
+                let click = click::Click::new(position, state.last_click);
+                state.last_click = Some(click);
+
+                if let Some(id) = node {
+                    self.select(state, id, shell);
+
+                    if matches!(click.kind(), click::Kind::Double) && self.on_rename.is_some() {
+                        self.start_edit(state, id);
+                        return event::Status::Captured;
+                    }
+                }
+
+                state.drag = Some(Drag {
+                    node,
+                    origin: position,
+                    start: match node {
+                        Some(id) => state
+                            .offsets
+                            .get(&id)
+                            .copied()
+                            .unwrap_or(Vector::new(0.0, 0.0)),
+                        None => state.viewport.offset,
+                    },
+                    is_moving: false,
+                    target: None,
+                });
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let Some(mut drag) = state.drag else {
+                    return event::Status::Ignored;
+                };
+
+                drag.is_moving |= position.distance(drag.origin) > DRAG_THRESHOLD;
+                if drag.is_moving {
+                    let delta = position - drag.origin;
+
+                    if let Some(id) = drag.node {
+                        let _ = state
+                            .offsets
+                            .insert(id, drag.start + delta * (1.0 / state.viewport.zoom));
+                        drag.target = self
+                            .on_move
+                            .as_ref()
+                            .and_then(|_| self.drop_target(&nodes, id, position));
+                    } else {
+                        state.viewport.offset = drag.start + delta;
+                    }
+                }
+                state.drag = Some(drag);
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                let Some(drag) = state.drag.take() else {
+                    return event::Status::Ignored;
+                };
+
+                if let (Some(id), Some(target), Some(on_move)) =
+                    (drag.node, drag.target, &self.on_move)
+                {
+                    // The moved node is laid out in its new place.
+                    let _ = state.offsets.remove(&id);
+                    shell.publish(on_move(id, target));
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) if state.drag.is_some() => {
+                if let Some(Drag {
+                    node: Some(id),
+                    start,
+                    ..
+                }) = state.drag.take()
+                {
+                    let _ = state.offsets.insert(id, start);
+                }
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    let lines = match delta {
+                        mouse::ScrollDelta::Lines { y, .. } => y,
+                        mouse::ScrollDelta::Pixels { y, .. } => y / 60.0,
+                    };
+                    state.viewport.zoom_at(
+                        bounds,
+                        position,
+                        ZOOM_STEP.powf(lines),
+                        self.zoom_range,
+                    );
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+            }
+            Event::Keyboard(event) if state.edit.is_some() => {
+                self.edit_label(state, event, shell);
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) if state.is_focused => {
+                let Some(selected) = state.selected else {
+                    return event::Status::Ignored;
+                };
+
+                match key_code {
+                    keyboard::KeyCode::F2 | keyboard::KeyCode::Enter
+                        if self.on_rename.is_some() =>
+                    {
+                        self.start_edit(state, selected);
+                    }
+                    keyboard::KeyCode::Tab | keyboard::KeyCode::Insert if self.on_add.is_some() => {
+                        self.press_button(state, Button::Add, shell);
+                    }
+                    keyboard::KeyCode::Delete
+                        if self.on_remove.is_some() && selected != self.root.id =>
+                    {
+                        self.press_button(state, Button::Remove, shell);
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer<Theme>,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        if state.drag.is_some_and(|drag| drag.is_moving) {
+            return mouse::Interaction::Grabbing;
+        }
+        let Some(position) = cursor.position_over(bounds) else {
+            return mouse::Interaction::default();
+        };
+
+        let nodes = self.nodes(state, bounds, renderer);
+        let node = Self::node_at(&nodes, position);
+
+        if self.button_at(state, &nodes, position).is_some() {
+            mouse::Interaction::Pointer
+        } else if node.is_some() && state.edit.as_ref().map(|edit| edit.id) == node {
+            mouse::Interaction::Text
+        } else if node.is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::Grab
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer<Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let nodes = self.nodes(state, bounds, renderer);
+        let zoom = state.viewport.zoom;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        // The connectors are drawn below the nodes, which cover their ends.
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+        let offset = Vector::new(-bounds.x, -bounds.y);
+        let connectors = Path::new(|builder| {
+            for node in &nodes {
+                let Some(parent) = node
+                    .placed
+                    .parent
+                    .and_then(|parent| nodes.iter().find(|other| other.placed.id == parent))
+                else {
+                    continue;
+                };
+                let from = parent.bounds.center() + offset;
+                let to = node.bounds.center() + offset;
+
+                builder.move_to(from);
+                match self.layout {
+                    MindMapLayout::Radial => builder.line_to(to),
+                    MindMapLayout::Tree => {
+                        let middle = from.x + (to.x - from.x) / 2.0;
+                        builder.bezier_curve_to(
+                            Point::new(middle, from.y),
+                            Point::new(middle, to.y),
+                            to,
+                        );
+                    }
+                }
+            }
+        });
+        frame.stroke(
+            &connectors,
+            Stroke::default()
+                .with_color(appearance.connector_color)
+                .with_width((1.5 * zoom).max(1.0)),
+        );
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+                renderer.draw(vec![frame.into_geometry()]);
+            });
+        });
+
+        let target = state.drag.and_then(|drag| drag.target);
+        renderer.with_layer(bounds, |renderer| {
+            for node in &nodes {
+                let id = node.placed.id;
+                let is_root = node.placed.parent.is_none();
+                let (border_width, border_color) = if target == Some(id) {
+                    (2.0, appearance.drop_target_color)
+                } else if state.selected == Some(id) {
+                    (2.0, appearance.selected_border_color)
+                } else {
+                    (1.0, appearance.node_border_color)
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: node.bounds,
+                        border_radius: (6.0 * zoom).into(),
+                        border_width,
+                        border_color,
+                    },
+                    if is_root {
+                        appearance.root_background
+                    } else {
+                        appearance.node_background
+                    },
+                );
+
+                renderer.fill_text(core::text::Text {
+                    content: &node.label,
+                    bounds: Rectangle {
+                        x: node.bounds.center_x(),
+                        y: node.bounds.center_y(),
+                        ..node.bounds
+                    },
+                    size: self.text_size * zoom,
+                    line_height: LineHeight::default(),
+                    color: if is_root {
+                        appearance.root_text_color
+                    } else {
+                        appearance.text_color
+                    },
+                    font: core::Font::default(),
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                });
+
+                if let Some(edit) = state.edit.as_ref().filter(|edit| edit.id == id) {
+                    let width = |text: &str| {
+                        renderer.measure_width(
+                            text,
+                            self.text_size * zoom,
+                            core::Font::default(),
+                            text::Shaping::Advanced,
+                        )
+                    };
+                    let x = node.bounds.center_x() - width(&edit.value) / 2.0
+                        + width(&edit.value[..edit.cursor]);
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x,
+                                y: node.bounds.center_y() - self.text_size * zoom * 0.6,
+                                width: 1.0,
+                                height: self.text_size * zoom * 1.2,
+                            },
+                            border_radius: (0.0).into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        if is_root {
+                            appearance.root_text_color
+                        } else {
+                            appearance.cursor_color
+                        },
+                    );
+                }
+            }
+
+            for (button, center) in self.buttons(state, &nodes) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: center.x - BUTTON_RADIUS,
+                            y: center.y - BUTTON_RADIUS,
+                            width: BUTTON_RADIUS * 2.0,
+                            height: BUTTON_RADIUS * 2.0,
+                        },
+                        border_radius: BUTTON_RADIUS.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.button_background,
+                );
+
+                let icon = match button {
+                    Button::Add => Icon::Plus,
+                    Button::Remove => Icon::X,
+                };
+                renderer.fill_text(core::text::Text {
+                    content: &char::from(icon).to_string(),
+                    bounds: Rectangle {
+                        x: center.x,
+                        y: center.y,
+                        width: BUTTON_RADIUS * 2.0,
+                        height: BUTTON_RADIUS * 2.0,
+                    },
+                    size: BUTTON_RADIUS * 2.0,
+                    line_height: LineHeight::default(),
+                    color: appearance.button_icon_color,
+                    font: ICON_FONT,
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    shaping: text::Shaping::Basic,
+                });
+            }
+        });
+    }
+}
+
+impl<'a, Message, Theme> From<MindMap<'a, Message, Theme>> for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+{
+    fn from(mind_map: MindMap<'a, Message, Theme>) -> Self {
+        Element::new(mind_map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MindMap, MindMapLayout};
+    use crate::core::tree_node::TreeNode;
+    use crate::test_utils::Harness;
+    use iced_widget::core::{keyboard::KeyCode, Point, Size};
+
+    #[test]
+    fn mind_map_test() {
+        #[derive(Clone, Debug, PartialEq)]
+        enum Change {
+            Select(usize),
+            Add(usize),
+            Remove(usize),
+            Rename(usize, String),
+            Move(usize, usize),
+        }
+
+        let root =
+            TreeNode::new(0, "Root").children([TreeNode::new(1, "A"), TreeNode::new(2, "B")]);
+        let mind_map = MindMap::new(&root)
+            .layout(MindMapLayout::Tree)
+            .spacing(120.0, 48.0)
+            .on_select(Change::Select)
+            .on_add(Change::Add)
+            .on_remove(Change::Remove)
+            .on_rename(Change::Rename)
+            .on_move(Change::Move);
+        let mut harness = Harness::new(mind_map, Size::new(400.0, 300.0));
+
+        // The root is centered with its children in the next column.
+        let (a, b) = (Point::new(320.0, 126.0), Point::new(320.0, 174.0));
+        assert_eq!(
+            harness.click(Point::new(200.0, 150.0)),
+            vec![Change::Select(0)]
+        );
+        assert_eq!(harness.press_key(KeyCode::Tab), vec![Change::Add(0)]);
+        assert_eq!(harness.click(a), vec![Change::Select(1)]);
+        assert_eq!(harness.press_key(KeyCode::Delete), vec![Change::Remove(1)]);
+
+        // A double click edits the label in place.
+        assert_eq!(harness.double_click(b), vec![Change::Select(2)]);
+        assert!(harness.type_text("x").is_empty());
+        assert_eq!(
+            harness.press_key(KeyCode::Enter),
+            vec![Change::Rename(2, String::from("Bx"))]
+        );
+
+        // Dropping a node onto another moves it there.
+        assert_eq!(harness.drag(b, a, 5), vec![Change::Move(2, 1)]);
+
+        // Dragging the background pans and scrolling zooms at the cursor.
+        assert!(harness
+            .drag(Point::new(100.0, 250.0), Point::new(60.0, 250.0), 5)
+            .is_empty());
+        let root = Point::new(160.0, 150.0);
+        assert_eq!(harness.click(root), vec![Change::Select(0)]);
+        let _ = harness.move_cursor(root);
+        let _ = harness.scroll(iced_widget::core::Vector::new(0.0, 1.0));
+        assert_eq!(
+            harness.click(Point::new(304.0, 121.0)),
+            vec![Change::Select(1)]
+        );
+    }
+}
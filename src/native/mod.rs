@@ -40,6 +40,11 @@ pub mod date_picker;
 #[cfg(feature = "date_picker")]
 pub use date_picker::DatePicker;
 
+#[cfg(feature = "calendar")]
+pub mod calendar;
+#[cfg(feature = "calendar")]
+pub use calendar::Calendar;
+
 #[cfg(feature = "selection_list")]
 pub mod selection_list;
 #[cfg(feature = "selection_list")]
@@ -132,3 +137,313 @@ pub mod segmented_button;
 /// A badge for color highlighting small information.
 pub type SegmentedButton<'a, Message, Renderer> =
     segmented_button::SegmentedButton<'a, Message, Renderer>;
+
+#[cfg(feature = "drawer")]
+pub mod drawer;
+#[cfg(feature = "drawer")]
+/// A panel sliding in from an edge of some content.
+pub type Drawer<'a, Message, Renderer> = drawer::Drawer<'a, Message, Renderer>;
+
+#[cfg(feature = "notification_center")]
+pub mod notification_center;
+#[cfg(feature = "notification_center")]
+pub use notification_center::NotificationCenter;
+
+#[cfg(feature = "history_list")]
+pub mod history_list;
+#[cfg(feature = "history_list")]
+/// A list visualizing an undo stack of labeled entries.
+pub type HistoryList<'a, T, Message, Renderer> =
+    history_list::HistoryList<'a, T, Message, Renderer>;
+
+#[cfg(feature = "time_ruler")]
+pub mod time_ruler;
+#[cfg(feature = "time_ruler")]
+/// A horizontal ruler showing tick marks and labels for a time range.
+pub type TimeRuler<'a, Renderer> = time_ruler::TimeRuler<'a, Renderer>;
+
+#[cfg(feature = "waveform")]
+pub mod waveform;
+#[cfg(feature = "waveform")]
+/// A display of the peaks of audio samples.
+pub type Waveform<'a, Message, Renderer> = waveform::Waveform<'a, Message, Renderer>;
+
+#[cfg(feature = "seek_bar")]
+pub mod seek_bar;
+#[cfg(feature = "seek_bar")]
+/// A bar showing and changing the playback position of some media.
+pub type SeekBar<'a, Message, Renderer> = seek_bar::SeekBar<'a, Message, Renderer>;
+
+#[cfg(feature = "transport_controls")]
+pub mod transport_controls;
+#[cfg(feature = "transport_controls")]
+/// A cluster of the playback controls of a media player.
+pub type TransportControls<'a, Message, Renderer> =
+    transport_controls::TransportControls<'a, Message, Renderer>;
+
+#[cfg(feature = "volume_slider")]
+pub mod volume_slider;
+#[cfg(feature = "volume_slider")]
+/// A slider for the volume of some audio with a mute toggle.
+pub type VolumeSlider<'a, Message, Renderer> = volume_slider::VolumeSlider<'a, Message, Renderer>;
+
+#[cfg(feature = "equalizer")]
+pub mod equalizer;
+#[cfg(feature = "equalizer")]
+pub use equalizer::Equalizer;
+
+#[cfg(feature = "piano_keys")]
+pub mod piano_keys;
+#[cfg(feature = "piano_keys")]
+/// A piano keyboard for playing notes.
+pub type PianoKeys<'a, Message, Renderer> = piano_keys::PianoKeys<'a, Message, Renderer>;
+
+#[cfg(feature = "step_grid")]
+pub mod step_grid;
+#[cfg(feature = "step_grid")]
+/// A matrix of the steps of a sequencer.
+pub type StepGrid<'a, Message, Renderer> = step_grid::StepGrid<'a, Message, Renderer>;
+
+#[cfg(feature = "editable_grid")]
+pub mod editable_grid;
+#[cfg(feature = "editable_grid")]
+/// A spreadsheet like grid of editable cells.
+pub type EditableGrid<'a, Message, Renderer> = editable_grid::EditableGrid<'a, Message, Renderer>;
+
+#[cfg(feature = "tree_view")]
+pub mod tree_view;
+#[cfg(feature = "tree_view")]
+/// A tree of expandable nodes with labels.
+pub type TreeView<'a, Message, Renderer> = tree_view::TreeView<'a, Message, Renderer>;
+
+#[cfg(feature = "selectable_text")]
+pub mod selectable_text;
+#[cfg(feature = "selectable_text")]
+/// A read-only text whose content can be selected and copied.
+pub type SelectableText<'a, Renderer> = selectable_text::SelectableText<'a, Renderer>;
+
+#[cfg(feature = "rich_editor")]
+pub mod rich_editor;
+#[cfg(feature = "rich_editor")]
+/// An editor of formatted text.
+pub type RichEditor<'a, Message, Renderer> = rich_editor::RichEditor<'a, Message, Renderer>;
+
+#[cfg(feature = "find_bar")]
+pub mod find_bar;
+#[cfg(feature = "find_bar")]
+/// A bar searching and replacing the text of another widget.
+pub type FindBar<'a, Message, Theme> = find_bar::FindBar<'a, Message, Theme>;
+
+#[cfg(feature = "split_button")]
+pub mod split_button;
+#[cfg(feature = "split_button")]
+/// A button with a main action and a dropdown menu of alternative actions.
+pub type SplitButton<Message, Renderer> = split_button::SplitButton<Message, Renderer>;
+
+#[cfg(feature = "toggle_group")]
+pub mod toggle_group;
+#[cfg(feature = "toggle_group")]
+/// A row of toggle buttons with exclusive or multiple selection.
+pub type ToggleGroup<'a, Message, Renderer> = toggle_group::ToggleGroup<'a, Message, Renderer>;
+
+#[cfg(feature = "menu_button")]
+pub mod menu_button;
+#[cfg(feature = "menu_button")]
+/// A button opening a dropdown menu of actions.
+pub type MenuButton<Message, Renderer> = menu_button::MenuButton<Message, Renderer>;
+
+#[cfg(feature = "title_bar")]
+pub mod title_bar;
+#[cfg(feature = "title_bar")]
+/// A title bar moving and controlling an undecorated window.
+pub type TitleBar<'a, Message, Renderer> = title_bar::TitleBar<'a, Message, Renderer>;
+
+#[cfg(feature = "edge_resizer")]
+pub mod edge_resizer;
+#[cfg(feature = "edge_resizer")]
+/// A wrapper placing resize grips along the edges of its content.
+pub type EdgeResizer<'a, Message, Renderer> = edge_resizer::EdgeResizer<'a, Message, Renderer>;
+
+#[cfg(feature = "gesture_detector")]
+pub mod gesture_detector;
+#[cfg(feature = "gesture_detector")]
+/// A wrapper recognizing taps, long presses, swipes and pinches on its content.
+pub type GestureDetector<'a, Message, Renderer> =
+    gesture_detector::GestureDetector<'a, Message, Renderer>;
+
+#[cfg(feature = "form")]
+pub mod form;
+#[cfg(feature = "form")]
+/// A layout of labeled fields in aligned rows.
+pub type Form<'a, Message, Renderer> = form::Form<'a, Message, Renderer>;
+
+#[cfg(feature = "settings_page")]
+pub mod settings_page;
+#[cfg(feature = "settings_page")]
+/// A page of grouped settings with a searchable list of their sections.
+pub type SettingsPage<'a, Message, Theme> = settings_page::SettingsPage<'a, Message, Theme>;
+
+#[cfg(feature = "wizard")]
+pub mod wizard;
+#[cfg(feature = "wizard")]
+/// A dialog guiding through a sequence of pages.
+pub type Wizard<'a, Message, Theme> = wizard::Wizard<'a, Message, Theme>;
+
+#[cfg(feature = "auth_form")]
+pub mod auth_form;
+#[cfg(feature = "auth_form")]
+/// A sign in form with an identifier and a password.
+pub type AuthForm<'a, Message, Theme> = auth_form::AuthForm<'a, Message, Theme>;
+
+#[cfg(feature = "onboarding")]
+pub mod onboarding;
+#[cfg(feature = "onboarding")]
+/// An introduction of an application in a sequence of pages.
+pub type Onboarding<'a, Message, Theme> = onboarding::Onboarding<'a, Message, Theme>;
+
+#[cfg(feature = "contrast_checker")]
+pub mod contrast_checker;
+#[cfg(feature = "contrast_checker")]
+/// A checker of the contrast between a text color and a background color.
+pub type ContrastChecker<'a, Message, Theme> =
+    contrast_checker::ContrastChecker<'a, Message, Theme>;
+
+#[cfg(feature = "theme_editor")]
+pub mod theme_editor;
+#[cfg(feature = "theme_editor")]
+/// An editor of the colors of a palette.
+pub type ThemeEditor<'a, Message, Theme> = theme_editor::ThemeEditor<'a, Message, Theme>;
+
+#[cfg(feature = "shortcut_recorder")]
+pub mod shortcut_recorder;
+#[cfg(feature = "shortcut_recorder")]
+/// A field recording a key combination.
+pub type ShortcutRecorder<'a, Message, Renderer> =
+    shortcut_recorder::ShortcutRecorder<'a, Message, Renderer>;
+
+#[cfg(feature = "gamepad")]
+pub mod gamepad;
+#[cfg(feature = "gamepad")]
+/// A wrapper making its content a target of the gamepad navigation.
+pub type GamepadTarget<'a, Message, Renderer> = gamepad::GamepadTarget<'a, Message, Renderer>;
+
+#[cfg(feature = "num_pad")]
+pub mod num_pad;
+#[cfg(feature = "num_pad")]
+/// A numeric keypad attached to an element.
+pub type NumPad<'a, Message, Renderer> = num_pad::NumPad<'a, Message, Renderer>;
+
+#[cfg(feature = "on_screen_keyboard")]
+pub mod on_screen_keyboard;
+#[cfg(feature = "on_screen_keyboard")]
+/// An on-screen keyboard docked below some content.
+pub type OnScreenKeyboard<'a, Message, Renderer> =
+    on_screen_keyboard::OnScreenKeyboard<'a, Message, Renderer>;
+
+#[cfg(feature = "geo_map")]
+pub mod geo_map;
+#[cfg(feature = "geo_map")]
+pub use geo_map::GeoMap;
+
+#[cfg(feature = "org_chart")]
+pub mod org_chart;
+#[cfg(feature = "org_chart")]
+pub use org_chart::{OrgChart, OrgNode};
+
+#[cfg(feature = "mind_map")]
+pub mod mind_map;
+#[cfg(feature = "mind_map")]
+pub use mind_map::MindMap;
+
+#[cfg(feature = "gantt")]
+pub mod gantt;
+#[cfg(feature = "gantt")]
+pub use gantt::Gantt;
+
+#[cfg(feature = "treemap")]
+pub mod treemap;
+#[cfg(feature = "treemap")]
+pub use treemap::Treemap;
+
+#[cfg(feature = "sunburst")]
+pub mod sunburst;
+#[cfg(feature = "sunburst")]
+pub use sunburst::Sunburst;
+
+#[cfg(feature = "live_chart")]
+pub mod live_chart;
+#[cfg(feature = "live_chart")]
+pub use live_chart::LiveChart;
+
+#[cfg(feature = "candlestick")]
+pub mod candlestick;
+#[cfg(feature = "candlestick")]
+pub use candlestick::CandlestickChart;
+
+#[cfg(feature = "histogram")]
+pub mod histogram;
+#[cfg(feature = "histogram")]
+/// A display of the number of values in the bins of a histogram.
+pub type Histogram<'a, Message, Renderer> = histogram::Histogram<'a, Message, Renderer>;
+
+#[cfg(feature = "radar")]
+pub mod radar;
+#[cfg(feature = "radar")]
+pub use radar::RadarChart;
+
+#[cfg(feature = "chart_legend")]
+pub mod chart_legend;
+#[cfg(feature = "chart_legend")]
+/// A legend listing the series of a chart with a color swatch and a label
+/// each.
+pub type ChartLegend<'a, Message, Renderer> = chart_legend::ChartLegend<'a, Message, Renderer>;
+
+#[cfg(feature = "gauge")]
+pub mod gauge;
+#[cfg(feature = "gauge")]
+pub use gauge::Gauge;
+
+#[cfg(feature = "segment_display")]
+pub mod segment_display;
+#[cfg(feature = "segment_display")]
+pub use segment_display::SegmentDisplay;
+
+#[cfg(feature = "animated_number")]
+pub mod animated_number;
+#[cfg(feature = "animated_number")]
+pub use animated_number::AnimatedNumber;
+
+#[cfg(feature = "status_light")]
+pub mod status_light;
+#[cfg(feature = "status_light")]
+pub use status_light::StatusLight;
+
+#[cfg(feature = "date_time_picker")]
+pub mod date_time_picker;
+#[cfg(feature = "date_time_picker")]
+pub use date_time_picker::DateTimePicker;
+
+#[cfg(feature = "signal_bars")]
+pub mod signal_bars;
+#[cfg(feature = "signal_bars")]
+pub use signal_bars::SignalBars;
+
+#[cfg(feature = "battery_indicator")]
+pub mod battery_indicator;
+#[cfg(feature = "battery_indicator")]
+pub use battery_indicator::BatteryIndicator;
+
+#[cfg(feature = "duration_picker")]
+pub mod duration_picker;
+#[cfg(feature = "duration_picker")]
+pub use duration_picker::DurationPicker;
+
+#[cfg(feature = "focus_ring")]
+pub mod focus_ring;
+#[cfg(feature = "focus_ring")]
+pub use focus_ring::FocusRing;
+
+#[cfg(feature = "focus_group")]
+pub mod focus_group;
+#[cfg(feature = "focus_group")]
+pub use focus_group::FocusGroup;
@@ -3,18 +3,61 @@
 //! *This API requires the following crate features to be activated: modal*
 
 use super::overlay::modal::ModalOverlay;
+use crate::core::{
+    easing::Animation,
+    focus::{FindFocused, FocusIndex},
+};
 
 use iced_widget::core::{
-    self, alignment, event,
+    self, alignment, event, keyboard,
     layout::{Limits, Node},
     mouse::{self, Cursor},
     overlay, renderer,
-    widget::{Operation, Tree},
-    Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
+    widget::{
+        tree::{State as TreeState, Tag},
+        Operation, Tree,
+    },
+    window, Clipboard, Element, Event, Layout, Length, Padding, Point, Rectangle, Shell, Size,
+    Vector, Widget,
 };
+use std::time::Duration;
 
+pub use crate::core::easing::Easing;
 pub use crate::style::modal::StyleSheet;
 
+/// The default duration of the transition of a [`Modal`].
+const DEFAULT_DURATION: Duration = Duration::from_millis(200);
+
+/// How the content of a [`Modal`] comes in and goes out.
+///
+/// The backdrop of an animated [`Modal`] fades in and out. The content is
+/// moved, since it can't be scaled or faded by the renderer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Transition {
+    /// The [`Modal`] pops in and out instantly.
+    #[default]
+    None,
+    /// The content rises a little into its position.
+    Rise,
+    /// The content slides in from the edge of the viewport, e.g. from the
+    /// bottom for a bottom sheet.
+    Slide(Edge),
+}
+
+/// The edge of the viewport the content of a [`Modal`] slides in from.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Edge {
+    /// The top edge.
+    Top,
+    /// The bottom edge.
+    #[default]
+    Bottom,
+    /// The left edge.
+    Left,
+    /// The right edge.
+    Right,
+}
+
 /// A modal content as an overlay.
 ///
 /// Can be used in combination with the [`Card`](crate::card::Card)
@@ -36,10 +79,31 @@ pub use crate::style::modal::StyleSheet;
 /// )
 /// .backdrop(Message::CloseModal);
 /// ```
+///
+/// To only build the content while it is shown, use [`lazy`](Self::lazy):
+/// ```ignore
+/// let modal = Modal::lazy(Text::new("Underlay"), show_modal, || {
+///     Text::new("Overlay")
+/// });
+/// ```
+///
+/// To animate the content out too, keep the overlay while the [`Modal`] is
+/// closed with [`open`](Self::open):
+/// ```ignore
+/// let modal = Modal::new(Text::new("Underlay"), Some(Text::new("Overlay")))
+///     .open(show_modal)
+///     .transition(Transition::Slide(Edge::Bottom));
+/// ```
+///
+/// To let the user move a dialog out of the way, drag it by a handle at the
+/// top of its content with [`drag_handle`](Self::drag_handle):
+/// ```ignore
+/// let modal = Modal::new(Text::new("Underlay"), Some(Card::new(head, body)))
+///     .drag_handle(40.0);
+/// ```
 #[allow(missing_debug_implementations)]
 pub struct Modal<'a, Message, Renderer = crate::Renderer>
 where
-    Message: Clone,
     Renderer: core::Renderer,
     Renderer::Theme: StyleSheet,
 {
@@ -47,19 +111,40 @@ where
     underlay: Element<'a, Message, Renderer>,
     /// The optional content of the [`ModalOverlay`].
     overlay: Option<Element<'a, Message, Renderer>>,
-    /// The optional message that will be send when the user clicked on the backdrop.
-    backdrop: Option<Message>,
-    /// The optional message that will be send when the ESC key was pressed.
-    esc: Option<Message>,
+    /// The optional function producing the message that will be send when
+    /// the user clicked on the backdrop.
+    backdrop: Option<Box<dyn Fn() -> Message + 'a>>,
+    /// The optional function producing the message that will be send when
+    /// the ESC key was pressed.
+    esc: Option<Box<dyn Fn() -> Message + 'a>>,
+    /// The keys closing the [`ModalOverlay`].
+    close_keys: Vec<keyboard::KeyCode>,
+    /// The optional function producing the message with the key closing the
+    /// [`ModalOverlay`].
+    on_close_key: Option<Box<dyn Fn(keyboard::KeyCode) -> Message + 'a>>,
     /// The style of the [`ModalOverlay`].
     style: <Renderer::Theme as StyleSheet>::Style,
+    /// The horizontal alignment of the content of the [`ModalOverlay`].
     horizontal_alignment: alignment::Horizontal,
+    /// The vertical alignment of the content of the [`ModalOverlay`].
     vertical_alignment: alignment::Vertical,
+    /// The padding between the content of the [`ModalOverlay`] and the edges
+    /// of the viewport.
+    padding: Padding,
+    /// Whether the [`ModalOverlay`] is open, if it has content.
+    open: bool,
+    /// The transition of the [`ModalOverlay`].
+    transition: Transition,
+    /// The duration of the transition.
+    duration: Duration,
+    /// The easing of the transition.
+    easing: Easing,
+    /// The height of the region at the top of the content dragging it.
+    drag_handle: Option<f32>,
 }
 
 impl<'a, Message, Renderer> Modal<'a, Message, Renderer>
 where
-    Message: Clone,
     Renderer: core::Renderer,
     Renderer::Theme: StyleSheet,
 {
@@ -80,12 +165,43 @@ where
             overlay: overlay.map(Into::into),
             backdrop: None,
             esc: None,
+            close_keys: vec![keyboard::KeyCode::Escape],
+            on_close_key: None,
             style: <Renderer::Theme as StyleSheet>::Style::default(),
             horizontal_alignment: alignment::Horizontal::Center,
             vertical_alignment: alignment::Vertical::Center,
+            padding: Padding::ZERO,
+            open: true,
+            transition: Transition::None,
+            duration: DEFAULT_DURATION,
+            easing: Easing::default(),
+            drag_handle: None,
         }
     }
 
+    /// Creates a new [`Modal`] wrapping the underlying element to show the
+    /// content built by the given closure as an overlay.
+    ///
+    /// Unlike [`new`](Self::new), the content is only built if
+    /// `show_modal` is true, so an expensive dialog is neither built nor
+    /// diffed while the [`Modal`] is hidden. Since there is no content to
+    /// animate out, a hidden lazy [`Modal`] closes instantly.
+    ///
+    /// It expects:
+    ///     * the underlay [`Element`] on which this [`Modal`] will be wrapped around.
+    ///     * whether the overlay of the [`Modal`] is shown.
+    ///     * the closure building the overlay [`Element`] of the [`Modal`].
+    pub fn lazy<E>(
+        underlay: impl Into<Element<'a, Message, Renderer>>,
+        show_modal: bool,
+        content: impl FnOnce() -> E,
+    ) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Self::new(underlay, show_modal.then(content))
+    }
+
     /// Sets the content alignment for the horizontal axis of the [`Modal`].
     #[must_use]
     pub fn align_x(mut self, alignment: alignment::Horizontal) -> Self {
@@ -100,21 +216,144 @@ where
         self
     }
 
+    /// Sets the padding between the content and the edges of the viewport
+    /// of the [`Modal`].
+    ///
+    /// Combined with [`align_y`](Self::align_y), this pins the content to an
+    /// edge like a bottom sheet, or to a corner with [`align_x`](Self::align_x).
+    #[must_use]
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets whether the [`Modal`] is open.
+    ///
+    /// A closed [`Modal`] with an overlay plays its [`Transition`] out before
+    /// hiding the overlay, which removing the overlay can't. It is open by
+    /// default.
+    #[must_use]
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    /// Sets the [`Transition`] of the [`Modal`] when it opens and closes.
+    #[must_use]
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transition = transition;
+        self
+    }
+
+    /// Sets the duration of the [`Transition`] of the [`Modal`].
+    #[must_use]
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the [`Easing`] of the [`Transition`] of the [`Modal`].
+    #[must_use]
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Sets the height of the region at the top of the content of the
+    /// [`Modal`], e.g. the head of a [`Card`](crate::card::Card), that drags
+    /// the content within the viewport.
+    ///
+    /// The content keeps its position until the [`Modal`] is hidden.
+    #[must_use]
+    pub fn drag_handle(mut self, height: f32) -> Self {
+        self.drag_handle = Some(height);
+        self
+    }
+
+    /// Returns true if the [`Modal`] has an overlay and is open.
+    fn is_open(&self) -> bool {
+        self.overlay.is_some() && self.open
+    }
+
+    /// Returns true if any part of the overlay is shown.
+    fn is_visible(&self, state: &ModalState) -> bool {
+        self.overlay.is_some()
+            && (self.open
+                || (self.transition != Transition::None && state.animation.progress > 0.0))
+    }
+
     /// Sets the message that will be produced when the backdrop of the
     /// [`Modal`] is clicked.
     #[must_use]
-    pub fn backdrop(mut self, message: Message) -> Self {
-        self.backdrop = Some(message);
+    pub fn backdrop(self, message: Message) -> Self
+    where
+        Message: 'a + Clone,
+    {
+        self.backdrop_with(move || message.clone())
+    }
+
+    /// Sets the function producing the message when the backdrop of the
+    /// [`Modal`] is clicked.
+    ///
+    /// This is the same as [`backdrop`](Self::backdrop), but the message
+    /// doesn't need to be [`Clone`].
+    #[must_use]
+    pub fn backdrop_with<F>(mut self, backdrop: F) -> Self
+    where
+        F: 'a + Fn() -> Message,
+    {
+        self.backdrop = Some(Box::new(backdrop));
         self
     }
 
     /// Sets the message that will be produced when the Escape Key is
     /// pressed when the modal is open.
     ///
-    /// This can be used to close the modal on ESC.
+    /// This can be used to close the modal on ESC. The keys producing the
+    /// message are set with [`close_on_keys`](Self::close_on_keys).
     #[must_use]
-    pub fn on_esc(mut self, message: Message) -> Self {
-        self.esc = Some(message);
+    pub fn on_esc(self, message: Message) -> Self
+    where
+        Message: 'a + Clone,
+    {
+        self.on_esc_with(move || message.clone())
+    }
+
+    /// Sets the function producing the message when the Escape Key is
+    /// pressed when the modal is open.
+    ///
+    /// This is the same as [`on_esc`](Self::on_esc), but the message doesn't
+    /// need to be [`Clone`].
+    #[must_use]
+    pub fn on_esc_with<F>(mut self, on_esc: F) -> Self
+    where
+        F: 'a + Fn() -> Message,
+    {
+        self.esc = Some(Box::new(on_esc));
+        self
+    }
+
+    /// Sets the keys closing the [`Modal`] when it is open, instead of only
+    /// the Escape Key.
+    ///
+    /// A pressed key produces the message of [`on_close_key`](Self::on_close_key)
+    /// if set, or else the message of [`on_esc`](Self::on_esc). The keys are
+    /// handled before the content, so they don't reach e.g. a focused text
+    /// input of the content.
+    #[must_use]
+    pub fn close_on_keys(mut self, keys: &[keyboard::KeyCode]) -> Self {
+        self.close_keys = keys.to_vec();
+        self
+    }
+
+    /// Sets the function producing the message with the key that closed the
+    /// [`Modal`], e.g. to confirm on Enter and cancel on Escape.
+    #[must_use]
+    pub fn on_close_key<F>(mut self, on_close_key: F) -> Self
+    where
+        F: 'a + Fn(keyboard::KeyCode) -> Message,
+    {
+        self.on_close_key = Some(Box::new(on_close_key));
         self
     }
 
@@ -128,10 +367,17 @@ where
 
 impl<'a, Message, Renderer> Widget<Message, Renderer> for Modal<'a, Message, Renderer>
 where
-    Message: Clone,
     Renderer: core::Renderer,
     Renderer::Theme: StyleSheet,
 {
+    fn tag(&self) -> Tag {
+        Tag::of::<ModalState>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(ModalState::default())
+    }
+
     fn children(&self) -> Vec<Tree> {
         self.overlay.as_ref().map_or_else(
             || vec![Tree::new(&self.underlay)],
@@ -170,7 +416,33 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
-        if self.overlay.is_none() {
+        let modal_state = state.state.downcast_mut::<ModalState>();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let animation = &mut modal_state.animation;
+            if self.transition == Transition::None || self.overlay.is_none() {
+                animation.progress = if self.is_open() { 1.0 } else { 0.0 };
+            } else if animation.animate(self.is_open(), self.duration, now) {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+
+        // Restore the focus of the underlay once the modal closes.
+        if !self.is_open() && modal_state.focus_trap.trapped {
+            let previous = modal_state.focus_trap.release();
+            if previous.is_some() {
+                self.underlay.as_widget().operate(
+                    &mut state.children[0],
+                    layout,
+                    renderer,
+                    &mut FocusIndex::new(previous),
+                );
+            }
+        }
+
+        if !self.is_visible(state.state.downcast_ref()) {
+            state.state.downcast_mut::<ModalState>().drag = Drag::default();
+
             return self.underlay.as_widget_mut().on_event(
                 &mut state.children[0],
                 event,
@@ -194,7 +466,7 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        if self.overlay.is_none() {
+        if !self.is_visible(state.state.downcast_ref()) {
             return self.underlay.as_widget().mouse_interaction(
                 &state.children[0],
                 layout,
@@ -234,7 +506,26 @@ where
         layout: Layout<'_>,
         renderer: &Renderer,
     ) -> Option<overlay::Element<'b, Message, Renderer>> {
-        if let Some(overlay) = &mut self.overlay {
+        let modal_state = state.state.downcast_mut::<ModalState>();
+        let progress = if self.transition == Transition::None {
+            1.0
+        } else {
+            self.easing.apply(modal_state.animation.progress)
+        };
+
+        // Move the focus out of the underlay once the modal opens.
+        if self.is_open() && !modal_state.focus_trap.trapped {
+            let mut find_focused = FindFocused::unfocusing();
+            self.underlay.as_widget().operate(
+                &mut state.children[0],
+                layout,
+                renderer,
+                &mut find_focused,
+            );
+            modal_state.focus_trap.trap(find_focused.focused);
+        }
+
+        if let (true, Some(overlay)) = (self.is_visible(modal_state), &mut self.overlay) {
             let bounds = layout.bounds();
             let position = Point::new(bounds.x, bounds.y);
             overlay.as_widget().diff(&mut state.children[1]);
@@ -244,11 +535,20 @@ where
                 Box::new(ModalOverlay::new(
                     &mut state.children[1],
                     overlay,
-                    self.backdrop.clone(),
-                    self.esc.clone(),
+                    self.backdrop.as_deref(),
+                    self.esc.as_deref(),
+                    &self.close_keys,
+                    self.on_close_key.as_deref(),
                     self.style.clone(),
                     self.horizontal_alignment,
                     self.vertical_alignment,
+                    self.padding,
+                    self.open,
+                    self.transition,
+                    progress,
+                    &mut modal_state.focus_trap,
+                    self.drag_handle,
+                    &mut modal_state.drag,
                 )),
             ))
         } else {
@@ -265,7 +565,8 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
-        if let Some(overlay) = &self.overlay {
+        if let (true, Some(overlay)) = (self.is_visible(state.state.downcast_ref()), &self.overlay)
+        {
             overlay.as_widget().diff(&mut state.children[1]);
 
             overlay
@@ -281,7 +582,7 @@ where
 
 impl<'a, Message, Renderer> From<Modal<'a, Message, Renderer>> for Element<'a, Message, Renderer>
 where
-    Message: 'a + Clone,
+    Message: 'a,
     Renderer: 'a + core::Renderer,
     Renderer::Theme: StyleSheet,
 {
@@ -290,6 +591,103 @@ where
     }
 }
 
+/// The internal state of a [`Modal`].
+#[derive(Debug, Default)]
+struct ModalState {
+    /// The animation of the [`Transition`].
+    animation: Animation,
+    /// The focus trapped inside of the overlay.
+    focus_trap: FocusTrap,
+    /// The dragging of the content of the overlay.
+    drag: Drag,
+}
+
+/// The keyboard focus trapped inside of the overlay of an open [`Modal`].
+#[derive(Debug, Default)]
+pub struct FocusTrap {
+    /// Whether the focus is trapped inside of the overlay.
+    trapped: bool,
+    /// Whether the content of the overlay still has to be focused.
+    pending: bool,
+    /// The index of the focusable widget of the underlay focused before the
+    /// [`Modal`] opened.
+    previous: Option<usize>,
+}
+
+impl FocusTrap {
+    /// Traps the focus, remembering the focused widget of the underlay.
+    fn trap(&mut self, previous: Option<usize>) {
+        *self = Self {
+            trapped: true,
+            pending: true,
+            previous,
+        };
+    }
+
+    /// Releases the focus, returning the focused widget of the underlay to
+    /// restore.
+    fn release(&mut self) -> Option<usize> {
+        std::mem::take(self).previous
+    }
+
+    /// Returns true once if the content of the overlay has to be focused.
+    pub fn take_pending(&mut self) -> bool {
+        std::mem::take(&mut self.pending)
+    }
+}
+
+/// The content of the overlay of a [`Modal`] dragged by its handle.
+#[derive(Debug, Default)]
+pub struct Drag {
+    /// The offset of the content from its aligned position.
+    offset: Vector,
+    /// The position of the cursor and the offset when the drag started.
+    origin: Option<(Point, Vector)>,
+}
+
+impl Drag {
+    /// Returns the position of the content at the given aligned position,
+    /// moved by the offset while staying inside of the viewport.
+    #[must_use]
+    pub fn position(&self, aligned: Point, size: Size, viewport: Size) -> Point {
+        Point::new(
+            (aligned.x + self.offset.x).clamp(0.0, (viewport.width - size.width).max(0.0)),
+            (aligned.y + self.offset.y).clamp(0.0, (viewport.height - size.height).max(0.0)),
+        )
+    }
+
+    /// Returns true if the content is being dragged.
+    #[must_use]
+    pub fn is_dragging(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    /// Starts dragging the content at the given position of the cursor.
+    pub fn start(&mut self, cursor: Point) {
+        self.origin = Some((cursor, self.offset));
+    }
+
+    /// Drags the content with the given bounds to the given position of the
+    /// cursor, keeping it inside of the viewport.
+    pub fn drag(&mut self, cursor: Point, content: Rectangle, viewport: Size) {
+        if let Some((origin, offset)) = self.origin {
+            let aligned = content.position() - self.offset;
+            let position = Self {
+                offset: offset + (cursor - origin),
+                origin: None,
+            }
+            .position(aligned, content.size(), viewport);
+
+            self.offset = position - aligned;
+        }
+    }
+
+    /// Stops dragging the content, returning true if it was dragged.
+    pub fn end(&mut self) -> bool {
+        self.origin.take().is_some()
+    }
+}
+
 /// The state of the modal.
 #[derive(Debug, Default)]
 pub struct State<S> {
@@ -329,3 +727,148 @@ impl<S> State<S> {
         &self.state
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Modal;
+    use crate::test_utils::Harness;
+    use iced_widget::{
+        column, container,
+        core::{
+            keyboard::{KeyCode, Modifiers},
+            Point, Size,
+        },
+        text, text_input,
+    };
+
+    #[test]
+    fn modal_esc_test() {
+        let modal = Modal::new(text("Underlay"), Some(text("Overlay"))).on_esc(());
+        let mut harness = Harness::new(modal, Size::new(200.0, 200.0));
+
+        assert_eq!(harness.press_key(KeyCode::Escape), vec![()]);
+        assert!(harness.press_key(KeyCode::Enter).is_empty());
+    }
+
+    #[test]
+    fn modal_close_keys_test() {
+        let modal = Modal::new(text("Underlay"), Some(text("Overlay")))
+            .close_on_keys(&[KeyCode::Enter, KeyCode::Space])
+            .on_esc(KeyCode::Escape);
+        let mut harness = Harness::new(modal, Size::new(200.0, 200.0));
+
+        assert_eq!(harness.press_key(KeyCode::Enter), vec![KeyCode::Escape]);
+        assert!(harness.press_key(KeyCode::Escape).is_empty());
+
+        // The closing key is reported if requested.
+        let modal = Modal::new(text("Underlay"), Some(text("Overlay")))
+            .close_on_keys(&[KeyCode::Enter, KeyCode::Escape])
+            .on_close_key(|key_code| key_code);
+        let mut harness = Harness::new(modal, Size::new(200.0, 200.0));
+
+        assert_eq!(harness.press_key(KeyCode::Enter), vec![KeyCode::Enter]);
+        assert_eq!(harness.press_key(KeyCode::Escape), vec![KeyCode::Escape]);
+        assert!(harness.press_key(KeyCode::Space).is_empty());
+    }
+
+    #[test]
+    fn modal_message_producers_test() {
+        // The messages don't need to be cloneable.
+        #[derive(Debug, PartialEq)]
+        struct Close(&'static str);
+
+        let modal = Modal::new(text("Underlay"), Some(text("Overlay")))
+            .backdrop_with(|| Close("backdrop"))
+            .on_esc_with(|| Close("esc"));
+        let mut harness = Harness::new(modal, Size::new(200.0, 200.0));
+
+        assert_eq!(harness.press_key(KeyCode::Escape), vec![Close("esc")]);
+        assert_eq!(harness.press_key(KeyCode::Escape), vec![Close("esc")]);
+        assert_eq!(harness.click(Point::new(5.0, 5.0)), vec![Close("backdrop")]);
+    }
+
+    #[test]
+    fn modal_lazy_test() {
+        let mut built = false;
+        let hidden = Modal::lazy(text("Underlay"), false, || {
+            built = true;
+            text("Overlay")
+        })
+        .on_esc(());
+        assert!(!built);
+
+        let mut harness = Harness::new(hidden, Size::new(200.0, 200.0));
+        assert!(harness.press_key(KeyCode::Escape).is_empty());
+
+        let shown = Modal::lazy(text("Underlay"), true, || text("Overlay")).on_esc(());
+        let mut harness = Harness::new(shown, Size::new(200.0, 200.0));
+        assert_eq!(harness.press_key(KeyCode::Escape), vec![()]);
+    }
+
+    #[test]
+    fn modal_focus_trap_test() {
+        let content = column![
+            text_input("First", "").on_input(|_| 0),
+            text_input("Second", "").on_input(|_| 1),
+        ];
+        let modal = Modal::new(text_input("Underlay", "").on_input(|_| 2), Some(content));
+        let mut harness = Harness::new(modal, Size::new(200.0, 200.0));
+
+        // The first field of the content is focused when the modal opens.
+        assert_eq!(harness.type_text("a"), vec![0]);
+
+        let _ = harness.press_key(KeyCode::Tab);
+        assert_eq!(harness.type_text("b"), vec![1]);
+
+        // The focus wraps around inside of the content.
+        let _ = harness.press_key(KeyCode::Tab);
+        assert_eq!(harness.type_text("c"), vec![0]);
+
+        let _ = harness.set_modifiers(Modifiers::SHIFT);
+        let _ = harness.press_key(KeyCode::Tab);
+        let _ = harness.set_modifiers(Modifiers::default());
+        assert_eq!(harness.type_text("d"), vec![1]);
+    }
+
+    #[test]
+    fn modal_stacking_test() {
+        let stacked = |esc: bool| {
+            let inner = Modal::new(text("Outer"), Some(text("Inner"))).backdrop(1);
+            let inner = if esc { inner.on_esc(1) } else { inner };
+
+            Modal::new(text("Underlay"), Some(inner))
+                .backdrop(0)
+                .on_esc(0)
+        };
+
+        // Only the top-most modal handles the ESC key and its backdrop.
+        let mut harness = Harness::new(stacked(true), Size::new(200.0, 200.0));
+        assert_eq!(harness.press_key(KeyCode::Escape), vec![1]);
+        assert_eq!(harness.click(Point::new(5.0, 5.0)), vec![1]);
+
+        let mut harness = Harness::new(stacked(false), Size::new(200.0, 200.0));
+        assert!(harness.press_key(KeyCode::Escape).is_empty());
+    }
+
+    #[test]
+    fn modal_drag_handle_test() {
+        let content = container(text("Dialog")).width(100).height(100);
+        let modal = Modal::new(text("Underlay"), Some(content))
+            .backdrop(())
+            .drag_handle(20.0);
+        let mut harness = Harness::new(modal, Size::new(300.0, 300.0));
+
+        // The content is centered, with its handle at its top.
+        let messages = harness.drag(Point::new(150.0, 110.0), Point::new(100.0, 60.0), 5);
+        assert!(messages.is_empty());
+
+        // The dragged content moved, so its former center is the backdrop.
+        assert!(harness.click(Point::new(50.0, 50.0)).is_empty());
+        assert_eq!(harness.click(Point::new(180.0, 180.0)), vec![()]);
+
+        // The content stays inside of the viewport.
+        let _ = harness.drag(Point::new(100.0, 60.0), Point::new(-100.0, -100.0), 5);
+        assert!(harness.click(Point::new(90.0, 90.0)).is_empty());
+        assert_eq!(harness.click(Point::new(110.0, 50.0)), vec![()]);
+    }
+}
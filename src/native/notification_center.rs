@@ -0,0 +1,741 @@
+//! Use a notification center to review persistent notifications.
+//!
+//! *This API requires the following crate features to be activated: `notification_center`*
+
+use super::drawer::{Drawer, Edge};
+use crate::core::i18n;
+use crate::graphics::icons::{icon_to_char, Icon, ICON_FONT};
+
+use iced_widget::{
+    button, container,
+    core::{
+        self, event,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer,
+        widget::{Operation, Tree},
+        Alignment, Clipboard, Color, Element, Event, Font, Layout, Length, Rectangle, Shell,
+        Widget,
+    },
+    renderer::Renderer,
+    scrollable, text, Button, Column, MouseArea, Row, Scrollable, Space, Text,
+};
+use std::time::{Duration, SystemTime};
+
+pub use crate::style::notification_center::{Appearance, StyleSheet};
+
+/// The default width of the panel of a [`NotificationCenter`].
+const DEFAULT_WIDTH: f32 = 360.0;
+/// The padding around the elements.
+const PADDING: f32 = 10.0;
+/// The spacing between the elements.
+const SPACING: f32 = 10.0;
+/// The spacing between the elements of a notification.
+const ITEM_SPACING: f32 = 4.0;
+/// The diameter of the indicator next to unread notifications.
+const INDICATOR_SIZE: f32 = 8.0;
+/// The number of seconds in an hour.
+const HOUR: u64 = 60 * 60;
+/// The number of seconds in a day.
+const DAY: u64 = 24 * HOUR;
+
+/// How the notifications of a [`NotificationCenter`] are grouped.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum GroupBy {
+    /// Group the notifications by how long ago they were received.
+    #[default]
+    Time,
+    /// Group the notifications by their source.
+    Source,
+}
+
+/// A persistent notification listed in a [`NotificationCenter`].
+#[derive(Clone, Debug)]
+pub struct Notification<Message> {
+    /// The identifier of the [`Notification`].
+    id: usize,
+    /// The source of the [`Notification`], e.g. the name of an application.
+    source: String,
+    /// The title of the [`Notification`].
+    title: String,
+    /// The optional body text of the [`Notification`].
+    body: Option<String>,
+    /// The time the [`Notification`] was received.
+    timestamp: SystemTime,
+    /// If the [`Notification`] has been read.
+    read: bool,
+    /// The labeled actions of the [`Notification`].
+    actions: Vec<(String, Message)>,
+}
+
+impl<Message> Notification<Message> {
+    /// Creates a new unread [`Notification`] received now.
+    ///
+    /// It expects:
+    ///     * the identifier passed to the messages of the [`NotificationCenter`].
+    ///     * the source of the notification, e.g. the name of an application.
+    ///     * the title of the notification.
+    pub fn new(id: usize, source: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            id,
+            source: source.into(),
+            title: title.into(),
+            body: None,
+            timestamp: SystemTime::now(),
+            read: false,
+            actions: Vec::new(),
+        }
+    }
+
+    /// Sets the body text of the [`Notification`].
+    #[must_use]
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Sets the time the [`Notification`] was received.
+    #[must_use]
+    pub fn timestamp(mut self, timestamp: SystemTime) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    /// Sets if the [`Notification`] has been read.
+    #[must_use]
+    pub fn read(mut self, read: bool) -> Self {
+        self.read = read;
+        self
+    }
+
+    /// Adds an action button to the [`Notification`] producing the given
+    /// message when pressed.
+    #[must_use]
+    pub fn action(mut self, label: impl Into<String>, message: Message) -> Self {
+        self.actions.push((label.into(), message));
+        self
+    }
+
+    /// Gets the identifier of the [`Notification`].
+    #[must_use]
+    pub const fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Gets if the [`Notification`] has been read.
+    #[must_use]
+    pub const fn is_read(&self) -> bool {
+        self.read
+    }
+}
+
+/// Counts the unread notifications, e.g. to show them in a
+/// [`Badge`](crate::Badge) next to the button opening the [`NotificationCenter`].
+#[must_use]
+pub fn unread_count<Message>(notifications: &[Notification<Message>]) -> usize {
+    notifications.iter().filter(|n| !n.read).count()
+}
+
+/// A slide-over panel listing persistent notifications.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::Text;
+/// # use iced_aw::notification_center::{Notification, NotificationCenter};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Close,
+///     MarkRead(usize),
+///     ClearAll,
+/// }
+///
+/// let notifications = vec![Notification::new(0, "Mail", "New message")];
+///
+/// let center = NotificationCenter::new(true, Text::new("Underlay"), &notifications)
+///     .on_mark_read(Message::MarkRead)
+///     .on_clear_all(Message::ClearAll)
+///     .on_dismiss(Message::Close);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct NotificationCenter<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: StyleSheet + crate::style::drawer::StyleSheet,
+{
+    /// Show the panel of the [`NotificationCenter`].
+    open: bool,
+    /// The underlying element.
+    underlay: Element<'a, Message, Renderer<Theme>>,
+    /// The listed notifications.
+    notifications: &'a [Notification<Message>],
+    /// How the notifications are grouped.
+    group_by: GroupBy,
+    /// The title of the panel.
+    title: String,
+    /// The text shown if there are no notifications.
+    empty_text: String,
+    /// The edge the panel slides in from.
+    edge: Edge,
+    /// The width of the panel.
+    width: f32,
+    /// The function that produces a message when a notification is marked as read.
+    on_mark_read: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    /// The function that produces a message when a notification is removed.
+    on_remove: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    /// The optional message that will be send when all notifications are marked as read.
+    on_mark_all_read: Option<Message>,
+    /// The optional message that will be send when all notifications are cleared.
+    on_clear_all: Option<Message>,
+    /// The optional message that will be send when the panel is dismissed.
+    on_dismiss: Option<Message>,
+    /// The style of the [`NotificationCenter`].
+    style: <Theme as StyleSheet>::Style,
+    /// The style of the panel of the [`NotificationCenter`].
+    drawer_style: <Theme as crate::style::drawer::StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> NotificationCenter<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + StyleSheet
+        + crate::style::drawer::StyleSheet
+        + button::StyleSheet
+        + text::StyleSheet
+        + container::StyleSheet
+        + scrollable::StyleSheet,
+{
+    /// Creates a new [`NotificationCenter`] wrapping the underlying element.
+    ///
+    /// It expects:
+    ///     * if the panel of the [`NotificationCenter`] is open.
+    ///     * the underlay [`Element`] on which this [`NotificationCenter`] will be wrapped around.
+    ///     * the notifications to list.
+    pub fn new<U>(open: bool, underlay: U, notifications: &'a [Notification<Message>]) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer<Theme>>>,
+    {
+        let translations = i18n::get();
+
+        Self {
+            open,
+            underlay: underlay.into(),
+            notifications,
+            group_by: GroupBy::default(),
+            title: translations.notifications(),
+            empty_text: translations.no_notifications(),
+            edge: Edge::Right,
+            width: DEFAULT_WIDTH,
+            on_mark_read: None,
+            on_remove: None,
+            on_mark_all_read: None,
+            on_clear_all: None,
+            on_dismiss: None,
+            style: <Theme as StyleSheet>::Style::default(),
+            drawer_style: <Theme as crate::style::drawer::StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets how the notifications of the [`NotificationCenter`] are grouped.
+    #[must_use]
+    pub fn group_by(mut self, group_by: GroupBy) -> Self {
+        self.group_by = group_by;
+        self
+    }
+
+    /// Sets the title of the panel of the [`NotificationCenter`].
+    #[must_use]
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Sets the text shown if there are no notifications.
+    #[must_use]
+    pub fn empty_text(mut self, empty_text: impl Into<String>) -> Self {
+        self.empty_text = empty_text.into();
+        self
+    }
+
+    /// Sets the [`Edge`] the panel of the [`NotificationCenter`] slides in from.
+    #[must_use]
+    pub fn edge(mut self, edge: Edge) -> Self {
+        self.edge = edge;
+        self
+    }
+
+    /// Sets the width of the panel of the [`NotificationCenter`].
+    #[must_use]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the function that produces a message when a notification is
+    /// marked as read, either by clicking on it or its check button.
+    #[must_use]
+    pub fn on_mark_read<F>(mut self, on_mark_read: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        self.on_mark_read = Some(Box::new(on_mark_read));
+        self
+    }
+
+    /// Sets the function that produces a message when a notification is removed.
+    #[must_use]
+    pub fn on_remove<F>(mut self, on_remove: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        self.on_remove = Some(Box::new(on_remove));
+        self
+    }
+
+    /// Sets the message that will be produced when all notifications are
+    /// marked as read.
+    #[must_use]
+    pub fn on_mark_all_read(mut self, message: Message) -> Self {
+        self.on_mark_all_read = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when all notifications are cleared.
+    #[must_use]
+    pub fn on_clear_all(mut self, message: Message) -> Self {
+        self.on_clear_all = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when the panel is dismissed.
+    #[must_use]
+    pub fn on_dismiss(mut self, message: Message) -> Self {
+        self.on_dismiss = Some(message);
+        self
+    }
+
+    /// Sets the style of the [`NotificationCenter`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style of the panel of the [`NotificationCenter`].
+    #[must_use]
+    pub fn drawer_style(
+        mut self,
+        style: <Theme as crate::style::drawer::StyleSheet>::Style,
+    ) -> Self {
+        self.drawer_style = style;
+        self
+    }
+
+    /// Wraps the given element to draw it with a color of the [`Appearance`].
+    fn tinted(
+        &self,
+        content: impl Into<Element<'a, Message, Renderer<Theme>>>,
+        role: Role,
+    ) -> Element<'a, Message, Renderer<Theme>> {
+        Tinted {
+            content: content.into(),
+            role,
+            style: self.style.clone(),
+        }
+        .into()
+    }
+
+    /// Creates the header of the panel.
+    fn header(&self) -> Element<'a, Message, Renderer<Theme>> {
+        let translations = i18n::get();
+        let mut header = Row::new()
+            .spacing(SPACING)
+            .align_items(Alignment::Center)
+            .push(Text::new(self.title.clone()).size(20).width(Length::Fill));
+
+        if let Some(on_mark_all_read) = &self.on_mark_all_read {
+            if unread_count(self.notifications) > 0 {
+                header = header.push(
+                    Button::new(Text::new(translations.mark_all_as_read()).size(14))
+                        .on_press(on_mark_all_read.clone()),
+                );
+            }
+        }
+
+        if let Some(on_clear_all) = &self.on_clear_all {
+            if !self.notifications.is_empty() {
+                header = header.push(
+                    Button::new(Text::new(translations.clear_all()).size(14))
+                        .on_press(on_clear_all.clone()),
+                );
+            }
+        }
+
+        header.into()
+    }
+
+    /// Creates the entry of a single notification.
+    fn item(
+        &self,
+        notification: &Notification<Message>,
+        now: SystemTime,
+    ) -> Element<'a, Message, Renderer<Theme>> {
+        let text_role = if notification.read {
+            Role::Read
+        } else {
+            Role::Unread
+        };
+        let elapsed = now
+            .duration_since(notification.timestamp)
+            .unwrap_or_default();
+
+        let indicator: Element<'a, Message, Renderer<Theme>> = if notification.read {
+            Space::new(INDICATOR_SIZE, INDICATOR_SIZE).into()
+        } else {
+            self.tinted(Space::new(INDICATOR_SIZE, INDICATOR_SIZE), Role::Indicator)
+        };
+
+        let mut details = Column::new()
+            .spacing(ITEM_SPACING)
+            .width(Length::Fill)
+            .push(
+                Row::new()
+                    .spacing(SPACING)
+                    .align_items(Alignment::Center)
+                    .push(
+                        self.tinted(
+                            Text::new(notification.title.clone())
+                                .size(16)
+                                .font(Font {
+                                    weight: if notification.read {
+                                        core::font::Weight::Normal
+                                    } else {
+                                        core::font::Weight::Bold
+                                    },
+                                    ..Font::default()
+                                })
+                                .width(Length::Fill),
+                            text_role,
+                        ),
+                    )
+                    .push(self.tinted(Text::new(relative_time(elapsed)).size(12), Role::Secondary)),
+            );
+
+        if self.group_by == GroupBy::Time {
+            details = details.push(self.tinted(
+                Text::new(notification.source.clone()).size(12),
+                Role::Secondary,
+            ));
+        }
+
+        if let Some(body) = &notification.body {
+            details = details.push(self.tinted(Text::new(body.clone()).size(14), text_role));
+        }
+
+        let mut actions = Row::new().spacing(ITEM_SPACING);
+
+        for (label, message) in &notification.actions {
+            actions = actions
+                .push(Button::new(Text::new(label.clone()).size(14)).on_press(message.clone()));
+        }
+
+        let mark_read = self
+            .on_mark_read
+            .as_ref()
+            .filter(|_| !notification.read)
+            .map(|on_mark_read| on_mark_read(notification.id));
+
+        if let Some(message) = &mark_read {
+            actions = actions.push(
+                Button::new(
+                    Text::new(icon_to_char(Icon::Check).to_string())
+                        .size(14)
+                        .font(ICON_FONT),
+                )
+                .on_press(message.clone()),
+            );
+        }
+
+        if let Some(on_remove) = &self.on_remove {
+            actions = actions.push(
+                Button::new(
+                    Text::new(icon_to_char(Icon::X).to_string())
+                        .size(14)
+                        .font(ICON_FONT),
+                )
+                .on_press(on_remove(notification.id)),
+            );
+        }
+
+        let item = Row::new()
+            .spacing(SPACING)
+            .align_items(Alignment::Start)
+            .push(indicator)
+            .push(details.push(actions));
+
+        match mark_read {
+            Some(message) => MouseArea::new(item).on_press(message).into(),
+            None => item.into(),
+        }
+    }
+
+    /// Creates the grouped list of notifications.
+    fn list(&self) -> Element<'a, Message, Renderer<Theme>> {
+        if self.notifications.is_empty() {
+            return self.tinted(Text::new(self.empty_text.clone()), Role::Secondary);
+        }
+
+        let now = SystemTime::now();
+        let mut sorted: Vec<&Notification<Message>> = self.notifications.iter().collect();
+        sorted.sort_by_key(|notification| std::cmp::Reverse(notification.timestamp));
+
+        let mut groups: Vec<(String, Vec<&Notification<Message>>)> = Vec::new();
+        for notification in sorted {
+            let key = match self.group_by {
+                GroupBy::Source => notification.source.clone(),
+                GroupBy::Time => time_group(
+                    now.duration_since(notification.timestamp)
+                        .unwrap_or_default(),
+                ),
+            };
+
+            match groups.iter_mut().find(|(group, _)| *group == key) {
+                Some((_, members)) => members.push(notification),
+                None => groups.push((key, vec![notification])),
+            }
+        }
+
+        groups
+            .into_iter()
+            .fold(
+                Column::new().spacing(SPACING * 2.0),
+                |list, (group, members)| {
+                    let header = self.tinted(Text::new(group).size(14), Role::Group);
+
+                    list.push(members.into_iter().fold(
+                        Column::new().spacing(SPACING).push(header),
+                        |column, notification| column.push(self.item(notification, now)),
+                    ))
+                },
+            )
+            .into()
+    }
+}
+
+impl<'a, Message, Theme> From<NotificationCenter<'a, Message, Theme>>
+    for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + StyleSheet
+        + crate::style::drawer::StyleSheet
+        + button::StyleSheet
+        + text::StyleSheet
+        + container::StyleSheet
+        + scrollable::StyleSheet,
+{
+    fn from(center: NotificationCenter<'a, Message, Theme>) -> Self {
+        let content = Column::new()
+            .spacing(SPACING)
+            .padding(PADDING)
+            .push(center.header())
+            .push(Scrollable::new(center.list()).height(Length::Fill));
+
+        let drawer = Drawer::new(center.open, center.underlay, content)
+            .edge(center.edge)
+            .width(center.width)
+            .style(center.drawer_style);
+
+        match center.on_dismiss {
+            Some(on_dismiss) => drawer.on_dismiss(on_dismiss),
+            None => drawer,
+        }
+        .into()
+    }
+}
+
+/// Gets the label of the group of a notification received the given time ago.
+fn time_group(elapsed: Duration) -> String {
+    let translations = i18n::get();
+
+    match elapsed.as_secs() {
+        secs if secs < HOUR => translations.last_hour(),
+        secs if secs < DAY => translations.last_day(),
+        secs if secs < 7 * DAY => translations.last_week(),
+        _ => translations.older(),
+    }
+}
+
+/// Formats the given duration as a short relative time.
+fn relative_time(elapsed: Duration) -> String {
+    let translations = i18n::get();
+
+    match elapsed.as_secs() {
+        secs if secs < 60 => translations.now(),
+        secs if secs < HOUR => translations.minutes_ago(secs / 60),
+        secs if secs < DAY => translations.hours_ago(secs / HOUR),
+        secs => translations.days_ago(secs / DAY),
+    }
+}
+
+/// The color of the [`Appearance`] a [`Tinted`] element is drawn with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Role {
+    /// The header of a group.
+    Group,
+    /// The text of an unread notification.
+    Unread,
+    /// The text of a read notification.
+    Read,
+    /// The source and relative time of a notification.
+    Secondary,
+    /// The indicator next to an unread notification.
+    Indicator,
+}
+
+/// Draws its content with a color of the [`Appearance`] of the [`NotificationCenter`].
+struct Tinted<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The content of the [`Tinted`] element.
+    content: Element<'a, Message, Renderer<Theme>>,
+    /// The color of the [`Appearance`] to use.
+    role: Role,
+    /// The style of the [`NotificationCenter`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> Widget<Message, Renderer<Theme>> for Tinted<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer<Theme>, limits: &Limits) -> Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer<Theme>,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer<Theme>,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &state.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer<Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let appearance = theme.active(&self.style);
+
+        let color = match self.role {
+            Role::Group => appearance.group_text_color,
+            Role::Unread => appearance.unread_text_color,
+            Role::Read => appearance.read_text_color,
+            Role::Secondary => appearance.secondary_text_color,
+            Role::Indicator => {
+                let bounds = layout.bounds();
+
+                core::Renderer::fill_quad(
+                    renderer,
+                    renderer::Quad {
+                        bounds,
+                        border_radius: (bounds.width.min(bounds.height) / 2.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.unread_indicator_color,
+                );
+
+                return;
+            }
+        };
+
+        self.content.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            &renderer::Style { text_color: color },
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer<Theme>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut state.children[0], layout, renderer, operation);
+    }
+}
+
+impl<'a, Message, Theme> From<Tinted<'a, Message, Theme>> for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+{
+    fn from(tinted: Tinted<'a, Message, Theme>) -> Self {
+        Element::new(tinted)
+    }
+}
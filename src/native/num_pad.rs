@@ -0,0 +1,380 @@
+//! Use a numeric keypad to enter numbers without a physical keyboard, e.g.
+//! on touch screens and kiosks.
+//!
+//! *This API requires the following crate features to be activated: `num_pad`*
+use crate::core::i18n;
+use crate::native::overlay::num_pad::{KeypadKeys, KeypadState, NumPadOverlay};
+
+use iced_widget::core::{
+    self, event,
+    layout::{Limits, Node},
+    mouse::{self, Cursor},
+    overlay, renderer,
+    widget::{
+        tree::{State as TreeState, Tag},
+        Operation, Tree,
+    },
+    Clipboard, Element, Event, Font, Layout, Length, Rectangle, Shell, Widget,
+};
+
+pub use crate::core::num_pad::{edit, Key};
+pub use crate::style::num_pad::{Appearance, StyleSheet};
+
+/// The default size of a key.
+const DEFAULT_KEY_SIZE: f32 = 56.0;
+/// The default text size of the labels of the keys.
+const DEFAULT_TEXT_SIZE: f32 = 20.0;
+
+/// A numeric keypad with the digits, a decimal separator, a sign key, a
+/// backspace and a submit key, attached below an element like a
+/// [`NumberInput`](crate::NumberInput).
+///
+/// The keypad edits its own copy of the text of the number, taken from the
+/// given value when it opens, and produces the edited text on every key.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{NumberInput, NumPad};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Changed(f32),
+///     Typed(String),
+///     Close,
+/// }
+///
+/// let num_pad = NumPad::new(
+///     show_num_pad,
+///     NumberInput::new(value, 100.0, Message::Changed),
+///     value.to_string(),
+///     Message::Typed,
+/// )
+/// .on_submit(Message::Close)
+/// .on_cancel(Message::Close);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct NumPad<'a, Message, Renderer = crate::Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Whether the keypad is shown.
+    show: bool,
+    /// The element the keypad is attached to.
+    underlay: Element<'a, Message, Renderer>,
+    /// The text of the number the keypad starts with.
+    value: String,
+    /// The function producing the message of the edited text.
+    on_change: Box<dyn Fn(String) -> Message + 'a>,
+    /// The message produced by the submit key.
+    on_submit: Option<Message>,
+    /// The message produced by pressing outside of the keypad or Escape.
+    on_cancel: Option<Message>,
+    /// The keys enabled on the keypad.
+    keys: KeypadKeys,
+    /// The label of the submit key.
+    submit_label: String,
+    /// The size of a key.
+    key_size: f32,
+    /// The text size of the labels of the keys.
+    text_size: f32,
+    /// The font of the labels of the keys.
+    font: Font,
+    /// The style of the [`NumPad`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> NumPad<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`NumPad`] attached to the underlying element.
+    ///
+    /// It expects:
+    ///     * whether the keypad is shown.
+    ///     * the underlay [`Element`] the keypad is attached to.
+    ///     * the value of the number the keypad starts with when it opens.
+    ///     * the function producing the message of the edited text.
+    pub fn new<U, F>(show: bool, underlay: U, value: impl Into<String>, on_change: F) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer>>,
+        F: 'a + Fn(String) -> Message,
+    {
+        Self {
+            show,
+            underlay: underlay.into(),
+            value: value.into(),
+            on_change: Box::new(on_change),
+            on_submit: None,
+            on_cancel: None,
+            keys: KeypadKeys {
+                decimal: true,
+                signed: true,
+                separator: '.',
+            },
+            submit_label: i18n::get().ok(),
+            key_size: DEFAULT_KEY_SIZE,
+            text_size: DEFAULT_TEXT_SIZE,
+            font: Font::DEFAULT,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the message produced by the submit key, enabling it.
+    #[must_use]
+    pub fn on_submit(mut self, message: Message) -> Self {
+        self.on_submit = Some(message);
+        self
+    }
+
+    /// Sets the message produced by pressing outside of the keypad, or
+    /// Escape.
+    #[must_use]
+    pub fn on_cancel(mut self, message: Message) -> Self {
+        self.on_cancel = Some(message);
+        self
+    }
+
+    /// Sets whether the decimal separator can be entered, e.g. `false` for
+    /// integers. It is enabled by default.
+    #[must_use]
+    pub fn decimal(mut self, decimal: bool) -> Self {
+        self.keys.decimal = decimal;
+        self
+    }
+
+    /// Sets whether the sign can be toggled, e.g. `false` for unsigned
+    /// numbers. It is enabled by default.
+    #[must_use]
+    pub fn signed(mut self, signed: bool) -> Self {
+        self.keys.signed = signed;
+        self
+    }
+
+    /// Sets the decimal separator of the [`NumPad`], `.` by default.
+    #[must_use]
+    pub fn separator(mut self, separator: char) -> Self {
+        self.keys.separator = separator;
+        self
+    }
+
+    /// Sets the label of the submit key of the [`NumPad`].
+    #[must_use]
+    pub fn submit_label(mut self, label: impl Into<String>) -> Self {
+        self.submit_label = label.into();
+        self
+    }
+
+    /// Sets the size of the keys of the [`NumPad`].
+    #[must_use]
+    pub fn key_size(mut self, key_size: f32) -> Self {
+        self.key_size = key_size;
+        self
+    }
+
+    /// Sets the text size of the labels of the keys of the [`NumPad`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the labels of the keys of the [`NumPad`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`NumPad`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for NumPad<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<KeypadState>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(KeypadState::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.underlay)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.underlay]);
+    }
+
+    fn width(&self) -> Length {
+        self.underlay.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.underlay.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        self.underlay.as_widget().layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.underlay.as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.underlay.as_widget().mouse_interaction(
+            &state.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.underlay.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate<'b>(
+        &'b self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.underlay
+            .as_widget()
+            .operate(&mut state.children[0], layout, renderer, operation);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let keypad = state.state.downcast_mut::<KeypadState>();
+
+        if !self.show {
+            keypad.close();
+            return self
+                .underlay
+                .as_widget_mut()
+                .overlay(&mut state.children[0], layout, renderer);
+        }
+
+        keypad.open(&self.value);
+
+        Some(
+            NumPadOverlay::new(
+                keypad,
+                &*self.on_change,
+                self.on_submit.as_ref(),
+                self.on_cancel.as_ref(),
+                layout.bounds(),
+                self.keys,
+                &self.submit_label,
+                (self.key_size, self.text_size, self.font),
+                self.style.clone(),
+            )
+            .overlay(),
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<NumPad<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(num_pad: NumPad<'a, Message, Renderer>) -> Self {
+        Element::new(num_pad)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NumPad;
+    use crate::test_utils::Harness;
+    use iced_widget::{
+        core::{Point, Size},
+        text,
+    };
+
+    #[test]
+    fn num_pad_test() {
+        let num_pad =
+            NumPad::new(true, text("Value"), "1", |text| text).on_submit(String::from("Submit"));
+        let mut harness = Harness::new(num_pad, Size::new(300.0, 400.0));
+
+        // The centers of the keys in the columns and rows of the keypad,
+        // below the display of the keypad below the underlay.
+        let key = |column: f32, row: f32| Point::new(36.0 + column * 60.0, 106.0 + row * 60.0);
+
+        assert_eq!(harness.click(key(1.0, 2.0)), vec!["12"]);
+        assert_eq!(harness.click(key(2.0, 3.0)), vec!["12."]);
+        // A second decimal separator is disabled.
+        assert!(harness.click(key(2.0, 3.0)).is_empty());
+        assert_eq!(harness.click(key(3.0, 1.0)), vec!["-12."]);
+        assert_eq!(harness.click(key(3.0, 0.0)), vec!["-12"]);
+        assert_eq!(harness.click(key(3.0, 2.5)), vec!["Submit"]);
+    }
+}
@@ -25,6 +25,8 @@ use iced_widget::{
 use num_traits::{Num, NumAssignOps};
 use std::{fmt::Display, str::FromStr};
 
+use crate::core::lens::{Lens, Update};
+
 pub use crate::{
     graphics::icons::{Icon, ICON_FONT},
     style::number_input::{self, Appearance, StyleSheet},
@@ -129,6 +131,49 @@ where
         }
     }
 
+    /// Creates a new [`NumberInput`] bound to a value of the model through a
+    /// [`Lens`].
+    ///
+    /// Instead of the new value, the [`NumberInput`] produces an [`Update`]
+    /// setting it in the model, so the fields of a form can share a single
+    /// message.
+    ///
+    /// It expects:
+    /// - the model holding the value
+    /// - the [`Lens`] reading and writing the value
+    /// - the max value
+    /// - a function that produces a message from the [`Update`] of the model
+    ///
+    /// # Example
+    /// ```ignore
+    /// # use iced_aw::{lens::{lens, Update}, NumberInput};
+    /// #
+    /// struct Settings {
+    ///     width: u32,
+    /// }
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum Message {
+    ///     Edit(Update<Settings>),
+    /// }
+    ///
+    /// let settings = Settings { width: 640 };
+    /// let width = lens(|settings: &Settings| settings.width, |settings, width| settings.width = width);
+    ///
+    /// let input = NumberInput::bind(&settings, width, 4096, Message::Edit);
+    /// ```
+    pub fn bind<Model, L, F>(model: &Model, lens: L, max: T, on_update: F) -> Self
+    where
+        Model: 'static,
+        L: 'static + Lens<Model, Value = T> + Copy + Send + Sync,
+        F: 'static + Fn(Update<Model>) -> Message + Copy,
+        T: 'static + Send + Sync,
+    {
+        Self::new(lens.get(model), max, move |value| {
+            on_update(Update::set(lens, value))
+        })
+    }
+
     /// Sets the minimum & maximum value (bound) of the [`NumberInput`].
     #[must_use]
     pub fn bounds(mut self, bounds: (T, T)) -> Self {
@@ -772,3 +817,25 @@ where
         Element::new(num_input)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::NumberInput;
+    use crate::test_utils::{Harness, Input};
+    use iced_widget::core::{keyboard::KeyCode, Point, Size};
+
+    #[test]
+    fn number_input_stepping_test() {
+        let number_input = NumberInput::new(5, 10, |value| value).step(2);
+        let mut harness = Harness::new(number_input, Size::new(200.0, 40.0));
+
+        let messages = harness.simulate(&[
+            Input::Click(Point::new(10.0, 10.0)),
+            Input::Key(KeyCode::Up),
+            Input::Key(KeyCode::Down),
+        ]);
+
+        // The element is not rebuilt, so it steps down from its stepped value.
+        assert_eq!(messages, vec![7, 5]);
+    }
+}
@@ -0,0 +1,703 @@
+//! An on-screen keyboard entering text into the focused input of some
+//! content, e.g. on touch terminals and kiosks.
+//!
+//! *This API requires the following crate features to be activated: `on_screen_keyboard`*
+use crate::{
+    core::i18n,
+    graphics::icons::{icon_to_char, Icon, ICON_FONT},
+};
+
+use iced_widget::{
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event, keyboard,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        overlay, renderer, text, touch,
+        widget::{tree, Operation, Tree},
+        window, Clipboard, Color, Element, Event, Font, Layout, Length, Point, Rectangle, Shell,
+        Size, Widget,
+    },
+    text::LineHeight,
+};
+use std::time::{Duration, Instant};
+
+pub use crate::core::on_screen_keyboard::{rows, Key, KeyboardLayout, Page, Shift};
+pub use crate::style::on_screen_keyboard::{Appearance, StyleSheet};
+
+/// The padding around the keys.
+const PADDING: f32 = 6.0;
+/// The spacing between the keys.
+const SPACING: f32 = 4.0;
+/// The default height of a key.
+const DEFAULT_KEY_HEIGHT: f32 = 48.0;
+/// The default text size of the labels of the keys.
+const DEFAULT_TEXT_SIZE: f32 = 18.0;
+/// The default time a key is held before it repeats.
+const DEFAULT_REPEAT_DELAY: Duration = Duration::from_millis(500);
+/// The default time between two repetitions of a held key.
+const DEFAULT_REPEAT_INTERVAL: Duration = Duration::from_millis(50);
+
+/// An on-screen keyboard docked below some content, entering text into its
+/// focused input like a [`TextInput`](iced_widget::TextInput).
+///
+/// The keyboard has a page of letters in the chosen [`KeyboardLayout`] with
+/// a shift key, and a page of digits and symbols. Characters, space and
+/// backspace repeat while they are held.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::text_input;
+/// # use iced_aw::OnScreenKeyboard;
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Changed(String),
+/// }
+///
+/// let keyboard = OnScreenKeyboard::new(
+///     text_input("Name", &name).on_input(Message::Changed),
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct OnScreenKeyboard<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The content receiving the entered text.
+    content: Element<'a, Message, Renderer>,
+    /// Whether the keyboard is shown.
+    show: bool,
+    /// The layout of the letters.
+    layout: KeyboardLayout,
+    /// The height of a key.
+    key_height: f32,
+    /// The text size of the labels of the keys.
+    text_size: f32,
+    /// The font of the labels of the keys.
+    font: Font,
+    /// The labels of the keys showing the letters and the symbols.
+    page_labels: [String; 2],
+    /// The time a key is held before it repeats.
+    repeat_delay: Duration,
+    /// The time between two repetitions of a held key.
+    repeat_interval: Duration,
+    /// The style of the [`OnScreenKeyboard`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> OnScreenKeyboard<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`OnScreenKeyboard`] entering text into the focused
+    /// input of the given content.
+    pub fn new<C>(content: C) -> Self
+    where
+        C: Into<Element<'a, Message, Renderer>>,
+    {
+        let translations = i18n::get();
+
+        Self {
+            content: content.into(),
+            show: true,
+            layout: KeyboardLayout::default(),
+            key_height: DEFAULT_KEY_HEIGHT,
+            text_size: DEFAULT_TEXT_SIZE,
+            font: Font::DEFAULT,
+            page_labels: [translations.letters_page(), translations.symbols_page()],
+            repeat_delay: DEFAULT_REPEAT_DELAY,
+            repeat_interval: DEFAULT_REPEAT_INTERVAL,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets whether the keyboard of the [`OnScreenKeyboard`] is shown.
+    #[must_use]
+    pub fn show(mut self, show: bool) -> Self {
+        self.show = show;
+        self
+    }
+
+    /// Sets the [`KeyboardLayout`] of the letters of the [`OnScreenKeyboard`].
+    #[must_use]
+    pub fn layout(mut self, layout: KeyboardLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Sets the height of the keys of the [`OnScreenKeyboard`].
+    #[must_use]
+    pub fn key_height(mut self, key_height: f32) -> Self {
+        self.key_height = key_height;
+        self
+    }
+
+    /// Sets the text size of the labels of the keys of the [`OnScreenKeyboard`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the labels of the keys of the [`OnScreenKeyboard`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the time a key is held before it repeats, and the time between
+    /// two repetitions.
+    #[must_use]
+    pub fn repeat(mut self, delay: Duration, interval: Duration) -> Self {
+        self.repeat_delay = delay;
+        self.repeat_interval = interval;
+        self
+    }
+
+    /// Sets the style of the [`OnScreenKeyboard`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the height of the keyboard.
+    fn keyboard_height(&self) -> f32 {
+        if self.show {
+            self.key_height * 4.0 + SPACING * 3.0 + PADDING * 2.0
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the keys of the given page with their bounds inside of the
+    /// keyboard, each row being centered.
+    fn keys(&self, page: Page, bounds: Rectangle) -> Vec<(Key, Rectangle)> {
+        let rows = rows(self.layout, page);
+        let width = bounds.width - PADDING * 2.0;
+
+        // The width of a letter, fitting the widest of the rows.
+        let unit = rows
+            .iter()
+            .map(|row| {
+                let units: f32 = row.iter().map(|(_, units)| units).sum();
+                (width - SPACING * (row.len() as f32 - 1.0)) / units
+            })
+            .fold(f32::INFINITY, f32::min)
+            .max(0.0);
+
+        let mut keys = Vec::new();
+        let mut y = bounds.y + PADDING;
+
+        for row in rows {
+            let row_width = row
+                .iter()
+                .map(|(_, units)| units * unit + SPACING)
+                .sum::<f32>()
+                - SPACING;
+            let mut x = bounds.x + (bounds.width - row_width) / 2.0;
+
+            for (key, units) in row {
+                keys.push((
+                    key,
+                    Rectangle::new(Point::new(x, y), Size::new(units * unit, self.key_height)),
+                ));
+                x += units * unit + SPACING;
+            }
+
+            y += self.key_height + SPACING;
+        }
+
+        keys
+    }
+
+    /// Returns the key of the given page at the given position, if any.
+    fn key_at(&self, page: Page, bounds: Rectangle, position: Point) -> Option<Key> {
+        self.keys(page, bounds)
+            .into_iter()
+            .find(|(_, key_bounds)| key_bounds.contains(position))
+            .map(|(key, _)| key)
+    }
+
+    /// Returns the label of the key and its font.
+    fn label(&self, state: &State, key: Key) -> (String, Font) {
+        match key {
+            Key::Character(character) => (state.shift.apply(character).to_string(), self.font),
+            Key::Space => (self.layout.to_string(), self.font),
+            Key::Backspace => (icon_to_char(Icon::Backspace).to_string(), ICON_FONT),
+            Key::Enter => (icon_to_char(Icon::ArrowReturnLeft).to_string(), ICON_FONT),
+            Key::Shift if state.shift == Shift::Locked => {
+                (icon_to_char(Icon::ShiftFill).to_string(), ICON_FONT)
+            }
+            Key::Shift => (icon_to_char(Icon::Shift).to_string(), ICON_FONT),
+            Key::Page => match state.page {
+                Page::Letters => (self.page_labels[1].clone(), self.font),
+                Page::Symbols => (self.page_labels[0].clone(), self.font),
+            },
+        }
+    }
+}
+
+/// Presses the key on the keyboard, returning the events entering it into
+/// the focused input.
+fn press(state: &mut State, key: Key) -> Vec<Event> {
+    let key_code = |key_code| {
+        vec![
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers: keyboard::Modifiers::default(),
+            }),
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code,
+                modifiers: keyboard::Modifiers::default(),
+            }),
+        ]
+    };
+
+    match key {
+        Key::Character(character) => {
+            let character = state.shift.apply(character);
+            state.shift = state.shift.after_character();
+            vec![Event::Keyboard(keyboard::Event::CharacterReceived(
+                character,
+            ))]
+        }
+        Key::Space => vec![Event::Keyboard(keyboard::Event::CharacterReceived(' '))],
+        Key::Backspace => key_code(keyboard::KeyCode::Backspace),
+        Key::Enter => key_code(keyboard::KeyCode::Enter),
+        Key::Shift => {
+            state.shift = state.shift.next();
+            Vec::new()
+        }
+        Key::Page => {
+            state.page = match state.page {
+                Page::Letters => Page::Symbols,
+                Page::Symbols => Page::Letters,
+            };
+            state.shift = Shift::Off;
+            Vec::new()
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for OnScreenKeyboard<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content]);
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let height = self.keyboard_height();
+        let content = self
+            .content
+            .as_widget()
+            .layout(renderer, &limits.shrink(Size::new(0.0, height)));
+        let content_size = content.size();
+
+        let mut keyboard = Node::new(Size::new(content_size.width, height));
+        keyboard.move_to(Point::new(0.0, content_size.height));
+
+        Node::with_children(
+            Size::new(content_size.width, content_size.height + height),
+            vec![content, keyboard],
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let content_layout = children
+            .next()
+            .expect("Native: Layout should have a content layout.");
+        let keyboard = children
+            .next()
+            .expect("Native: Layout should have a keyboard layout.")
+            .bounds();
+        let state = tree.state.downcast_mut::<State>();
+
+        let mut events = Vec::new();
+
+        if self.show {
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                    // A press on the keyboard never reaches the content, so
+                    // that its input keeps the focus.
+                    if let Some(position) = cursor.position_over(keyboard) {
+                        if let Some(key) = self.key_at(state.page, keyboard, position) {
+                            state.pressed = Some(key);
+                            state.repeat_at = None;
+                            events = press(state, key);
+
+                            if key.repeats() {
+                                shell.request_redraw(window::RedrawRequest::NextFrame);
+                            }
+                        }
+
+                        for event in events {
+                            let _ = self.content.as_widget_mut().on_event(
+                                &mut tree.children[0],
+                                event,
+                                content_layout,
+                                Cursor::Unavailable,
+                                renderer,
+                                clipboard,
+                                shell,
+                                viewport,
+                            );
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(
+                    touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
+                ) => {
+                    state.pressed = None;
+                    state.repeat_at = None;
+                }
+                Event::Window(window::Event::RedrawRequested(now)) => {
+                    if let Some(key) = state.pressed.filter(|key| key.repeats()) {
+                        // The repetition starts with the first redraw after
+                        // the key was pressed.
+                        let repeat_at = *state.repeat_at.get_or_insert(now + self.repeat_delay);
+
+                        if now >= repeat_at {
+                            events = press(state, key);
+                            state.repeat_at = Some(repeat_at + self.repeat_interval);
+                        }
+
+                        shell.request_redraw(window::RedrawRequest::At(
+                            state.repeat_at.unwrap_or(now),
+                        ));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        events.push(event);
+
+        let cursor = if cursor.is_over(keyboard) {
+            Cursor::Unavailable
+        } else {
+            cursor
+        };
+
+        events
+            .into_iter()
+            .map(|event| {
+                self.content.as_widget_mut().on_event(
+                    &mut tree.children[0],
+                    event,
+                    content_layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let content_layout = children
+            .next()
+            .expect("Native: Layout should have a content layout.");
+        let keyboard = children
+            .next()
+            .expect("Native: Layout should have a keyboard layout.")
+            .bounds();
+
+        if let Some(position) = cursor.position_over(keyboard) {
+            let state = tree.state.downcast_ref::<State>();
+            return if self.key_at(state.page, keyboard, position).is_some() {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            };
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            content_layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let mut children = layout.children();
+        let content_layout = children
+            .next()
+            .expect("Graphics: Layout should have a content layout.");
+        let keyboard = children
+            .next()
+            .expect("Graphics: Layout should have a keyboard layout.")
+            .bounds();
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            content_layout,
+            cursor,
+            viewport,
+        );
+
+        if !self.show {
+            return;
+        }
+
+        let state = tree.state.downcast_ref::<State>();
+        let active = theme.active(&self.style);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: keyboard,
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            active.background,
+        );
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    height: active.border_width,
+                    ..keyboard
+                },
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            active.border_color,
+        );
+
+        let hovered = cursor
+            .position_over(keyboard)
+            .and_then(|position| self.key_at(state.page, keyboard, position));
+
+        for (key, bounds) in self.keys(state.page, keyboard) {
+            let appearance: Appearance = if state.pressed == Some(key) {
+                theme.pressed(&self.style)
+            } else if hovered == Some(key) {
+                theme.hovered(&self.style)
+            } else {
+                active
+            };
+
+            let (background, text_color) = match key {
+                Key::Shift if state.shift != Shift::Off => (
+                    appearance.active_modifier_background,
+                    appearance.active_modifier_text_color,
+                ),
+                Key::Character(_) | Key::Space => {
+                    (appearance.key_background, appearance.key_text_color)
+                }
+                Key::Backspace | Key::Enter | Key::Shift | Key::Page => {
+                    (appearance.modifier_background, appearance.key_text_color)
+                }
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: appearance.key_border_radius.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                background,
+            );
+
+            let (label, font) = self.label(state, key);
+            renderer.fill_text(core::text::Text {
+                content: &label,
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size: if key == Key::Space {
+                    self.text_size * 0.75
+                } else {
+                    self.text_size
+                },
+                line_height: LineHeight::default(),
+                color: text_color,
+                font,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Advanced,
+            });
+        }
+    }
+
+    fn operate<'b>(
+        &'b self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout."),
+            renderer,
+            operation,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next()?,
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<OnScreenKeyboard<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(keyboard: OnScreenKeyboard<'a, Message, Renderer>) -> Self {
+        Element::new(keyboard)
+    }
+}
+
+/// The state of the keys of an [`OnScreenKeyboard`].
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    /// The shown page of keys.
+    pub page: Page,
+    /// The state of the shift key.
+    pub shift: Shift,
+    /// The held key.
+    pub pressed: Option<Key>,
+    /// The time the held key repeats next.
+    pub repeat_at: Option<Instant>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OnScreenKeyboard;
+    use crate::test_utils::{Harness, Input};
+    use iced_widget::{
+        container,
+        core::{mouse, Length, Point, Size},
+        text_input,
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn on_screen_keyboard_test() {
+        let input = text_input("Name", "")
+            .on_input(|text| text)
+            .on_submit(String::from("Submit"));
+        let keyboard = OnScreenKeyboard::new(container(input).width(Length::Fill).height(100));
+        let mut harness = Harness::new(keyboard, Size::new(400.0, 320.0));
+
+        // The centers of the keys of the QWERTY layout below the content.
+        let (q, w) = (Point::new(21.0, 130.0), Point::new(55.0, 130.0));
+        let shift = Point::new(46.0, 234.0);
+        let enter = Point::new(371.0, 182.0);
+        let page = Point::new(82.0, 286.0);
+
+        let _ = harness.click(Point::new(10.0, 10.0));
+        assert_eq!(harness.click(q), vec!["q"]);
+
+        // Shift applies to the next letter only.
+        assert!(harness.click(shift).is_empty());
+        assert_eq!(harness.click(w), vec!["qW"]);
+        assert_eq!(harness.click(w), vec!["qWw"]);
+        assert_eq!(harness.click(enter), vec!["Submit"]);
+
+        // A held key repeats after a delay.
+        let messages = harness.simulate(&[
+            Input::MoveCursor(w),
+            Input::Press(mouse::Button::Left),
+            Input::Wait(Duration::ZERO),
+            Input::Wait(Duration::from_millis(400)),
+        ]);
+        assert_eq!(messages, vec!["qWww"]);
+        let messages = harness.simulate(&[
+            Input::Wait(Duration::from_millis(100)),
+            Input::Release(mouse::Button::Left),
+            Input::Wait(Duration::from_millis(100)),
+        ]);
+        assert_eq!(messages, vec!["qWwww"]);
+
+        assert!(harness.click(page).is_empty());
+        assert_eq!(harness.click(q), vec!["qWwww1"]);
+    }
+}
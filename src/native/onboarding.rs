@@ -0,0 +1,479 @@
+//! Use an onboarding to introduce an application in a few pages.
+//!
+//! *This API requires the following crate features to be activated: `onboarding`*
+
+use crate::core::i18n;
+use crate::native::gesture_detector::{Gesture, GestureDetector, SwipeDirection};
+use crate::native::helpers::Styled;
+
+use iced_widget::{
+    button,
+    core::{
+        self, event,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer, touch,
+        widget::Tree,
+        Alignment, Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size,
+        Widget,
+    },
+    renderer::Renderer,
+    text, Button, Column, Row, Space, Text,
+};
+
+pub use crate::style::onboarding::{Appearance, StyleSheet};
+
+/// The padding around the [`Onboarding`].
+const PADDING: f32 = 20.0;
+/// The spacing between the elements.
+const SPACING: f32 = 16.0;
+/// The radius of the dots.
+const DOT_RADIUS: f32 = 4.0;
+/// The space between the dots.
+const DOT_SPACING: f32 = 10.0;
+
+/// A page of an [`Onboarding`].
+#[allow(missing_debug_implementations)]
+pub struct OnboardingPage<'a, Message, Theme> {
+    /// The title of the page.
+    title: String,
+    /// The description of the page.
+    description: String,
+    /// The illustration shown above the title.
+    illustration: Option<Element<'a, Message, Renderer<Theme>>>,
+}
+
+impl<'a, Message, Theme> OnboardingPage<'a, Message, Theme> {
+    /// Creates a new [`OnboardingPage`] with the given title and description.
+    pub fn new(title: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            description: description.into(),
+            illustration: None,
+        }
+    }
+
+    /// Sets the illustration shown above the title of the [`OnboardingPage`].
+    #[must_use]
+    pub fn illustration(
+        mut self,
+        illustration: impl Into<Element<'a, Message, Renderer<Theme>>>,
+    ) -> Self {
+        self.illustration = Some(illustration.into());
+        self
+    }
+}
+
+/// An introduction of an application in a sequence of [`OnboardingPage`]s.
+///
+/// The current page is shown with dots indicating the progress below it.
+/// The pages are changed with the `Next` button, by clicking the dots or by
+/// swiping. Every page but the last one has a `Skip` button; the last one
+/// replaces the `Next` button with a call to action finishing the
+/// [`Onboarding`].
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::onboarding::{Onboarding, OnboardingPage};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     PageChanged(usize),
+///     Finished,
+/// }
+///
+/// let onboarding = Onboarding::new(
+///     vec![
+///         OnboardingPage::new("Welcome", "Thanks for installing this app."),
+///         OnboardingPage::new("Sync", "Your notes are on all of your devices."),
+///     ],
+///     0,
+///     Message::PageChanged,
+///     Message::Finished,
+/// )
+/// .call_to_action("Get started");
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Onboarding<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The pages of the [`Onboarding`].
+    pages: Vec<OnboardingPage<'a, Message, Theme>>,
+    /// The index of the current page.
+    current: usize,
+    /// The function producing the message when the page is changed.
+    on_change: Box<dyn Fn(usize) -> Message + 'a>,
+    /// The message produced when the [`Onboarding`] is finished.
+    on_finish: Message,
+    /// The message produced when the [`Onboarding`] is skipped.
+    on_skip: Option<Message>,
+    /// The label of the button finishing the [`Onboarding`].
+    call_to_action: String,
+    /// The text size of the descriptions.
+    text_size: f32,
+    /// The style of the [`Onboarding`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> Onboarding<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a + StyleSheet + button::StyleSheet + text::StyleSheet,
+{
+    /// Creates a new [`Onboarding`] of the given pages.
+    ///
+    /// It expects:
+    ///     * the pages of the [`Onboarding`].
+    ///     * the index of the current page.
+    ///     * the function producing the message with the index of the new
+    ///       page when the page is changed.
+    ///     * the message produced when the call to action of the last page
+    ///       is pressed.
+    pub fn new<F>(
+        pages: Vec<OnboardingPage<'a, Message, Theme>>,
+        current: usize,
+        on_change: F,
+        on_finish: Message,
+    ) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        Self {
+            pages,
+            current,
+            on_change: Box::new(on_change),
+            on_finish: on_finish.clone(),
+            on_skip: Some(on_finish),
+            call_to_action: i18n::get().get_started(),
+            text_size: 16.0,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the message produced when the [`Onboarding`] is skipped.
+    ///
+    /// By default, skipping finishes the [`Onboarding`]. With `None`, the
+    /// `Skip` button is hidden.
+    #[must_use]
+    pub fn on_skip(mut self, message: Option<Message>) -> Self {
+        self.on_skip = message;
+        self
+    }
+
+    /// Sets the label of the button finishing the [`Onboarding`] on its
+    /// last page.
+    #[must_use]
+    pub fn call_to_action(mut self, label: impl Into<String>) -> Self {
+        self.call_to_action = label.into();
+        self
+    }
+
+    /// Sets the text size of the descriptions of the [`Onboarding`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the style of the [`Onboarding`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Wraps the given element to draw its text with the [`Appearance`].
+    fn styled(
+        &self,
+        content: impl Into<Element<'a, Message, Renderer<Theme>>>,
+        role: Role,
+    ) -> Element<'a, Message, Renderer<Theme>> {
+        let style = self.style.clone();
+
+        Styled::new(
+            content,
+            move |_renderer, theme: &Theme, _defaults, _bounds, _cursor| {
+                role.text_color(&StyleSheet::active(theme, &style))
+            },
+        )
+        .into()
+    }
+}
+
+impl<'a, Message, Theme> From<Onboarding<'a, Message, Theme>>
+    for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'a + Clone,
+    Theme: 'a + StyleSheet + button::StyleSheet + text::StyleSheet,
+{
+    fn from(mut onboarding: Onboarding<'a, Message, Theme>) -> Self {
+        let count = onboarding.pages.len();
+        let last = count.saturating_sub(1);
+        let current = onboarding.current.min(last);
+
+        let targets: Vec<_> = (0..count)
+            .map(|page| (onboarding.on_change)(page))
+            .collect();
+        let previous = current.checked_sub(1).map(|page| targets[page].clone());
+        let next = (current < last).then(|| targets[current + 1].clone());
+
+        let translations = i18n::get();
+
+        let mut top = Row::new().push(Space::with_width(Length::Fill));
+        if let (true, Some(on_skip)) = (current < last, onboarding.on_skip.clone()) {
+            top = top.push(Button::new(Text::new(translations.skip())).on_press(on_skip));
+        }
+
+        let mut page = Column::new()
+            .spacing(SPACING)
+            .width(Length::Fill)
+            .align_items(Alignment::Center);
+        if current < count {
+            let OnboardingPage {
+                title,
+                description,
+                illustration,
+            } = onboarding.pages.swap_remove(current);
+
+            if let Some(illustration) = illustration {
+                page = page.push(illustration);
+            }
+            page = page
+                .push(onboarding.styled(
+                    Text::new(title).size(onboarding.text_size * 1.75),
+                    Role::Title,
+                ))
+                .push(onboarding.styled(
+                    Text::new(description).size(onboarding.text_size),
+                    Role::Description,
+                ));
+        }
+
+        // Swiping changes the page like the buttons.
+        let (swipe_back, swipe_next) = (previous.clone(), next.clone());
+        let page = GestureDetector::filter_map(
+            Column::new()
+                .height(Length::Fill)
+                .width(Length::Fill)
+                .align_items(Alignment::Center)
+                .push(Space::with_height(Length::Fill))
+                .push(page)
+                .push(Space::with_height(Length::Fill)),
+            move |gesture| match gesture {
+                Gesture::Swipe {
+                    direction: SwipeDirection::Left,
+                    ..
+                } => swipe_next.clone(),
+                Gesture::Swipe {
+                    direction: SwipeDirection::Right,
+                    ..
+                } => swipe_back.clone(),
+                _ => None,
+            },
+        );
+
+        let dots = Dots {
+            current,
+            targets: targets
+                .into_iter()
+                .enumerate()
+                .map(|(page, target)| (page != current).then_some(target))
+                .collect(),
+            style: onboarding.style.clone(),
+        };
+
+        let mut back = Button::new(Text::new(translations.back()));
+        if let Some(previous) = previous {
+            back = back.on_press(previous);
+        }
+        let forward = match next {
+            Some(next) => Button::new(Text::new(translations.next())).on_press(next),
+            None => Button::new(Text::new(onboarding.call_to_action.clone()))
+                .on_press(onboarding.on_finish.clone()),
+        };
+
+        let bottom = Row::new()
+            .spacing(SPACING)
+            .align_items(Alignment::Center)
+            .push(back)
+            .push(Space::with_width(Length::Fill))
+            .push(dots)
+            .push(Space::with_width(Length::Fill))
+            .push(forward);
+
+        Column::new()
+            .padding(PADDING)
+            .spacing(SPACING)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .push(top)
+            .push(page)
+            .push(bottom)
+            .into()
+    }
+}
+
+/// How a [`Styled`] element is drawn with the [`Appearance`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Role {
+    /// The title of a page.
+    Title,
+    /// The description of a page.
+    Description,
+}
+
+impl Role {
+    /// Returns the color of the text with the [`Role`].
+    const fn text_color(self, appearance: &Appearance) -> Color {
+        match self {
+            Self::Title => appearance.title_color,
+            Self::Description => appearance.description_color,
+        }
+    }
+}
+
+/// The dots of an [`Onboarding`], indicating the current page.
+struct Dots<Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The index of the current page.
+    current: usize,
+    /// The messages produced when the dots are clicked.
+    targets: Vec<Option<Message>>,
+    /// The style of the [`Onboarding`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<Message, Theme> Dots<Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Returns the centers of the dots.
+    #[allow(clippy::cast_precision_loss)]
+    fn centers(&self, bounds: Rectangle) -> impl Iterator<Item = Point> {
+        let (x, y) = (bounds.x + DOT_RADIUS, bounds.center_y());
+
+        (0..self.targets.len()).map(move |dot| {
+            Point::new(
+                (dot as f32).mul_add(DOT_RADIUS.mul_add(2.0, DOT_SPACING), x),
+                y,
+            )
+        })
+    }
+
+    /// Returns the dot under the cursor.
+    fn dot_at(&self, bounds: Rectangle, cursor: Cursor) -> Option<usize> {
+        let position = cursor.position_over(bounds)?;
+
+        self.centers(bounds)
+            .position(|center| (position.x - center.x).abs() <= DOT_RADIUS + DOT_SPACING / 2.0)
+    }
+}
+
+impl<Message, Theme> Widget<Message, Renderer<Theme>> for Dots<Message, Theme>
+where
+    Message: Clone,
+    Theme: StyleSheet,
+{
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn layout(&self, _renderer: &Renderer<Theme>, limits: &Limits) -> Node {
+        let count = self.targets.len() as f32;
+        let width = DOT_RADIUS.mul_add(2.0 * count, DOT_SPACING * (count - 1.0).max(0.0));
+
+        // The dots are a bit taller than drawn to be easier to click.
+        Node::new(limits.resolve(Size::new(width, DOT_RADIUS * 4.0)))
+    }
+
+    fn on_event(
+        &mut self,
+        _state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer<Theme>,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => self
+                .dot_at(layout.bounds(), cursor)
+                .and_then(|dot| self.targets[dot].clone())
+                .map_or(event::Status::Ignored, |message| {
+                    shell.publish(message);
+                    event::Status::Captured
+                }),
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer<Theme>,
+    ) -> mouse::Interaction {
+        match self.dot_at(layout.bounds(), cursor) {
+            Some(dot) if self.targets[dot].is_some() => mouse::Interaction::Pointer,
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        renderer: &mut Renderer<Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let appearance = theme.active(&self.style);
+
+        for (dot, center) in self.centers(layout.bounds()).enumerate() {
+            core::Renderer::fill_quad(
+                renderer,
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: center.x - DOT_RADIUS,
+                        y: center.y - DOT_RADIUS,
+                        width: DOT_RADIUS * 2.0,
+                        height: DOT_RADIUS * 2.0,
+                    },
+                    border_radius: DOT_RADIUS.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                if dot == self.current {
+                    appearance.active_dot_color
+                } else {
+                    appearance.dot_color
+                },
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme> From<Dots<Message, Theme>> for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'a + Clone,
+    Theme: 'a + StyleSheet,
+{
+    fn from(dots: Dots<Message, Theme>) -> Self {
+        Element::new(dots)
+    }
+}
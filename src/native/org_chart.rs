@@ -0,0 +1,853 @@
+//! Use an org chart to display a hierarchy as a tree of nodes.
+//!
+//! *This API requires the following crate features to be activated: `org_chart`*
+use crate::core::{
+    org_chart::{place, Placement},
+    viewport::Viewport,
+};
+use crate::graphics::icons::{Icon, ICON_FONT};
+
+use iced_widget::{
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer, touch,
+        widget::{
+            tree::{State as TreeState, Tag},
+            Operation, Tree,
+        },
+        Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+        Widget,
+    },
+    text::LineHeight,
+};
+
+pub use crate::style::org_chart::{Appearance, StyleSheet};
+
+/// The radius of the toggles collapsing and expanding the branches.
+const TOGGLE_RADIUS: f32 = 8.0;
+/// The distance in pixels the cursor has to move while pressed before the
+/// press becomes a pan instead of a click.
+const PAN_THRESHOLD: f32 = 3.0;
+/// The factor the zoom changes by for every line scrolled.
+const ZOOM_STEP: f32 = 1.2;
+
+/// A node of an [`OrgChart`] with its content and its children.
+#[allow(missing_debug_implementations)]
+pub struct OrgNode<'a, Message, Renderer = crate::Renderer> {
+    /// The content of the node.
+    content: Element<'a, Message, Renderer>,
+    /// The children of the node.
+    children: Vec<Self>,
+    /// The message produced when the node is clicked.
+    on_press: Option<Message>,
+}
+
+impl<'a, Message, Renderer> OrgNode<'a, Message, Renderer> {
+    /// Creates a new [`OrgNode`] without children showing the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            children: Vec::new(),
+            on_press: None,
+        }
+    }
+
+    /// Sets the children of the [`OrgNode`].
+    #[must_use]
+    pub fn children(mut self, children: impl IntoIterator<Item = Self>) -> Self {
+        self.children = children.into_iter().collect();
+        self
+    }
+
+    /// Adds a child to the [`OrgNode`].
+    #[must_use]
+    pub fn push(mut self, child: Self) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Sets the message produced when the [`OrgNode`] is clicked.
+    #[must_use]
+    pub fn on_press(mut self, message: Message) -> Self {
+        self.on_press = Some(message);
+        self
+    }
+}
+
+/// A hierarchy, like the structure of an organization, laid out as a tree
+/// of nodes from the top down.
+///
+/// Every node shows an [`Element`] in a box, connected to its parent by
+/// lines. A node with children has a toggle at its bottom collapsing and
+/// expanding its branch. The chart is panned by dragging and zoomed out with
+/// the mouse wheel, and a click on a node produces its message.
+///
+/// The renderer can't scale the content of the nodes, so a zoomed out chart
+/// only shows the boxes of the nodes for an overview.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{OrgChart, OrgNode};
+/// # use iced_widget::text;
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Selected(usize),
+/// }
+///
+/// let chart = OrgChart::new(
+///     OrgNode::new(text("CEO"))
+///         .on_press(Message::Selected(0))
+///         .push(OrgNode::new(text("CTO")).on_press(Message::Selected(1))),
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct OrgChart<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The contents of the nodes in preorder, starting with the root.
+    contents: Vec<Element<'a, Message, Renderer>>,
+    /// The messages produced when the nodes are clicked.
+    on_press: Vec<Option<Message>>,
+    /// The indices of the children of the nodes.
+    children: Vec<Vec<usize>>,
+    /// The width of the [`OrgChart`].
+    width: Length,
+    /// The height of the [`OrgChart`].
+    height: Length,
+    /// The maximal width of the content of a node.
+    node_width: f32,
+    /// The padding between the boxes of the nodes and their content.
+    padding: f32,
+    /// The gap between the siblings and between the levels of the tree.
+    spacing: Size,
+    /// The minimal zoom of the [`OrgChart`].
+    min_zoom: f32,
+    /// The style of the [`OrgChart`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> OrgChart<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`OrgChart`] of the tree with the given root.
+    pub fn new(root: OrgNode<'a, Message, Renderer>) -> Self {
+        let mut chart = Self {
+            contents: Vec::new(),
+            on_press: Vec::new(),
+            children: Vec::new(),
+            width: Length::Fill,
+            height: Length::Fill,
+            node_width: 200.0,
+            padding: 8.0,
+            spacing: Size::new(16.0, 32.0),
+            min_zoom: 0.25,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        };
+        let _ = chart.flatten(root);
+        chart
+    }
+
+    /// Adds the node and its descendants in preorder, returning its index.
+    fn flatten(&mut self, node: OrgNode<'a, Message, Renderer>) -> usize {
+        let index = self.contents.len();
+        self.contents.push(node.content);
+        self.on_press.push(node.on_press);
+        self.children.push(Vec::new());
+
+        for child in node.children {
+            let child = self.flatten(child);
+            self.children[index].push(child);
+        }
+
+        index
+    }
+
+    /// Sets the width of the [`OrgChart`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`OrgChart`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the maximal width of the content of a node of the [`OrgChart`].
+    #[must_use]
+    pub fn node_width(mut self, node_width: f32) -> Self {
+        self.node_width = node_width;
+        self
+    }
+
+    /// Sets the padding between the boxes of the nodes and their content.
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the horizontal gap between the siblings and the vertical gap
+    /// between the levels of the [`OrgChart`].
+    #[must_use]
+    pub fn spacing(mut self, horizontal: f32, vertical: f32) -> Self {
+        self.spacing = Size::new(horizontal, vertical);
+        self
+    }
+
+    /// Sets the minimal zoom of the [`OrgChart`], between 0.0 and 1.0.
+    #[must_use]
+    pub fn min_zoom(mut self, min_zoom: f32) -> Self {
+        self.min_zoom = min_zoom.clamp(f32::EPSILON, 1.0);
+        self
+    }
+
+    /// Sets the style of the [`OrgChart`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the placement of the visible nodes on the screen.
+    fn view(&self, state: &State, layout: Layout<'_>) -> View {
+        let sizes: Vec<Size> = layout
+            .children()
+            .map(|content| {
+                let size = content.bounds().size();
+                Size::new(
+                    size.width + self.padding * 2.0,
+                    size.height + self.padding * 2.0,
+                )
+            })
+            .collect();
+        let placement = place(&sizes, &self.children, &state.collapsed, self.spacing);
+
+        View {
+            origin: state.viewport.project(
+                anchor(layout.bounds()),
+                Point::new(-placement.size.width / 2.0, self.spacing.height / 2.0),
+            ),
+            zoom: state.viewport.zoom,
+            sizes,
+            placement,
+        }
+    }
+}
+
+/// Returns the bounds the [`Viewport`] of an [`OrgChart`] is centered in,
+/// which hang the chart from the top center of the widget.
+fn anchor(bounds: Rectangle) -> Rectangle {
+    Rectangle {
+        height: 0.0,
+        ..bounds
+    }
+}
+
+/// The state of an [`OrgChart`].
+#[derive(Clone, Debug, Default)]
+struct State {
+    /// Whether the branches of the nodes are collapsed.
+    collapsed: Vec<bool>,
+    /// The pan and zoom of the chart, with 1.0 showing the nodes at their
+    /// size.
+    viewport: Viewport,
+    /// The position the ongoing press started at, with the offset at that time.
+    press: Option<(Point, Vector)>,
+    /// Whether the ongoing press moved far enough to pan the chart.
+    panning: bool,
+}
+
+impl State {
+    /// Collapses or expands the branch of the given node.
+    fn toggle(&mut self, node: usize) {
+        if self.collapsed.len() <= node {
+            self.collapsed.resize(node + 1, false);
+        }
+        self.collapsed[node] = !self.collapsed[node];
+    }
+
+    /// Returns true if the branch of the given node is collapsed.
+    fn is_collapsed(&self, node: usize) -> bool {
+        self.collapsed.get(node).copied().unwrap_or(false)
+    }
+}
+
+/// The nodes of an [`OrgChart`] placed on the screen.
+struct View {
+    /// The sizes of the boxes of the nodes.
+    sizes: Vec<Size>,
+    /// The placement of the nodes in the chart.
+    placement: Placement,
+    /// The position of the top left corner of the chart on the screen.
+    origin: Point,
+    /// The zoom of the chart.
+    zoom: f32,
+}
+
+impl View {
+    /// Returns the box of the given node on the screen, if it is visible.
+    fn node_bounds(&self, node: usize) -> Option<Rectangle> {
+        let position = self.placement.positions[node]?;
+
+        Some(Rectangle {
+            x: self.origin.x + position.x * self.zoom,
+            y: self.origin.y + position.y * self.zoom,
+            width: self.sizes[node].width * self.zoom,
+            height: self.sizes[node].height * self.zoom,
+        })
+    }
+
+    /// Returns the center and the radius of the toggle of the given node,
+    /// if it is visible and has children.
+    fn toggle(&self, node: usize, children: &[Vec<usize>]) -> Option<(Point, f32)> {
+        if children[node].is_empty() {
+            return None;
+        }
+
+        let bounds = self.node_bounds(node)?;
+
+        Some((
+            Point::new(bounds.center_x(), bounds.y + bounds.height),
+            TOGGLE_RADIUS * self.zoom,
+        ))
+    }
+
+    /// Returns true if the content of the nodes is shown.
+    fn is_detailed(&self) -> bool {
+        self.zoom >= 1.0
+    }
+}
+
+/// Returns the translation from the layout of the content of a node to its
+/// box on the screen, and the cursor inside of the box.
+fn content_view(
+    bounds: Rectangle,
+    node: Rectangle,
+    padding: f32,
+    layout: Layout<'_>,
+    cursor: Cursor,
+) -> (Vector, Cursor) {
+    let content = layout.bounds();
+    let translation = Vector::new(node.x + padding - content.x, node.y + padding - content.y);
+    let cursor = match cursor.position() {
+        Some(position) if bounds.contains(position) && node.contains(position) => {
+            Cursor::Available(position - translation)
+        }
+        _ => Cursor::Unavailable,
+    };
+
+    (translation, cursor)
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for OrgChart<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.contents.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.contents);
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        let content_limits =
+            Limits::new(Size::ZERO, Size::new(self.node_width, limits.max().height));
+
+        // The contents are placed when the chart is drawn, since their
+        // placement depends on the collapsed branches.
+        let contents = self
+            .contents
+            .iter()
+            .map(|content| content.as_widget().layout(renderer, &content_limits))
+            .collect();
+
+        Node::with_children(limits.resolve(Size::ZERO), contents)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<State>();
+        let view = self.view(state, layout);
+
+        if view.is_detailed() && !state.panning {
+            let mut status = event::Status::Ignored;
+            for (node, ((content, tree), content_layout)) in self
+                .contents
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .enumerate()
+            {
+                let Some(node_bounds) = view.node_bounds(node) else {
+                    continue;
+                };
+                let (translation, cursor) =
+                    content_view(bounds, node_bounds, self.padding, content_layout, cursor);
+
+                status = status.merge(content.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    content_layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    &Rectangle {
+                        x: bounds.x - translation.x,
+                        y: bounds.y - translation.y,
+                        ..bounds
+                    },
+                ));
+            }
+
+            if status == event::Status::Captured {
+                return status;
+            }
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    let lines = match delta {
+                        mouse::ScrollDelta::Lines { y, .. } => y,
+                        mouse::ScrollDelta::Pixels { y, .. } => y / 60.0,
+                    };
+                    state.viewport.zoom_at(
+                        anchor(bounds),
+                        position,
+                        ZOOM_STEP.powf(lines),
+                        (self.min_zoom, 1.0),
+                    );
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    state.press = Some((position, state.viewport.offset));
+                    state.panning = false;
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some((origin, offset)) = state.press {
+                    let delta = position - origin;
+                    if state.panning || delta.x.hypot(delta.y) > PAN_THRESHOLD {
+                        state.panning = true;
+                        state.viewport.offset = offset + delta;
+                    }
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if let Some((origin, _)) = state.press.take() {
+                    if !state.panning {
+                        let toggled = (0..self.contents.len()).find(|node| {
+                            view.toggle(*node, &self.children)
+                                .is_some_and(|(center, radius)| center.distance(origin) <= radius)
+                        });
+
+                        if let Some(node) = toggled {
+                            state.toggle(node);
+                        } else if let Some(message) = (0..self.contents.len())
+                            .find(|node| {
+                                view.node_bounds(*node)
+                                    .is_some_and(|bounds| bounds.contains(origin))
+                            })
+                            .and_then(|node| self.on_press[node].clone())
+                        {
+                            shell.publish(message);
+                        }
+                    }
+                    state.panning = false;
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let view = self.view(state, layout);
+
+        if state.panning {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let Some(position) = cursor.position_over(bounds) else {
+            return mouse::Interaction::default();
+        };
+
+        if view.is_detailed() {
+            let interaction = self
+                .contents
+                .iter()
+                .zip(&tree.children)
+                .zip(layout.children())
+                .enumerate()
+                .filter_map(|(node, ((content, tree), content_layout))| {
+                    let node_bounds = view.node_bounds(node)?;
+                    let (translation, cursor) =
+                        content_view(bounds, node_bounds, self.padding, content_layout, cursor);
+
+                    Some(content.as_widget().mouse_interaction(
+                        tree,
+                        content_layout,
+                        cursor,
+                        &Rectangle {
+                            x: viewport.x - translation.x,
+                            y: viewport.y - translation.y,
+                            ..*viewport
+                        },
+                        renderer,
+                    ))
+                })
+                .max()
+                .unwrap_or_default();
+
+            if interaction != mouse::Interaction::default() {
+                return interaction;
+            }
+        }
+
+        let over_toggle = (0..self.contents.len()).any(|node| {
+            view.toggle(node, &self.children)
+                .is_some_and(|(center, radius)| center.distance(position) <= radius)
+        });
+        let over_pressable = (0..self.contents.len()).any(|node| {
+            self.on_press[node].is_some()
+                && view
+                    .node_bounds(node)
+                    .is_some_and(|bounds| bounds.contains(position))
+        });
+
+        if over_toggle || over_pressable {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let view = self.view(state, layout);
+        let appearance = theme.active(&self.style);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        let hovered = cursor
+            .position_over(bounds)
+            .filter(|_| !state.panning)
+            .and_then(|position| {
+                (0..self.contents.len()).find(|node| {
+                    self.on_press[*node].is_some()
+                        && view
+                            .node_bounds(*node)
+                            .is_some_and(|bounds| bounds.contains(position))
+                })
+            });
+
+        renderer.with_layer(bounds, |renderer| {
+            let line = |renderer: &mut Renderer, bounds: Rectangle| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: (0.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.connector_color,
+                );
+            };
+            let half = appearance.connector_width / 2.0;
+
+            // The connectors run down from the parent, across the row of its
+            // children and down to each child.
+            for (node, children) in self.children.iter().enumerate() {
+                let Some(parent) = view.node_bounds(node) else {
+                    continue;
+                };
+                let children: Vec<Rectangle> = if state.is_collapsed(node) {
+                    Vec::new()
+                } else {
+                    children
+                        .iter()
+                        .filter_map(|child| view.node_bounds(*child))
+                        .collect()
+                };
+                let (Some(first), Some(last)) = (children.first(), children.last()) else {
+                    continue;
+                };
+
+                let bottom = parent.y + parent.height;
+                let middle = bottom + (first.y - bottom) / 2.0;
+                line(
+                    renderer,
+                    Rectangle::new(
+                        Point::new(parent.center_x() - half, bottom),
+                        Size::new(appearance.connector_width, middle - bottom),
+                    ),
+                );
+                line(
+                    renderer,
+                    Rectangle::new(
+                        Point::new(first.center_x() - half, middle - half),
+                        Size::new(
+                            last.center_x() - first.center_x() + appearance.connector_width,
+                            appearance.connector_width,
+                        ),
+                    ),
+                );
+                for child in &children {
+                    line(
+                        renderer,
+                        Rectangle::new(
+                            Point::new(child.center_x() - half, middle),
+                            Size::new(appearance.connector_width, child.y - middle),
+                        ),
+                    );
+                }
+            }
+
+            for (node, ((content, tree), content_layout)) in self
+                .contents
+                .iter()
+                .zip(&tree.children)
+                .zip(layout.children())
+                .enumerate()
+            {
+                let Some(node_bounds) = view.node_bounds(node) else {
+                    continue;
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: node_bounds,
+                        border_radius: (appearance.node_border_radius * view.zoom).into(),
+                        border_width: appearance.node_border_width,
+                        border_color: if hovered == Some(node) {
+                            appearance.hovered_border_color
+                        } else {
+                            appearance.node_border_color
+                        },
+                    },
+                    appearance.node_background,
+                );
+
+                if view.is_detailed() {
+                    let (translation, cursor) =
+                        content_view(bounds, node_bounds, self.padding, content_layout, cursor);
+
+                    renderer.with_translation(translation, |renderer| {
+                        content.as_widget().draw(
+                            tree,
+                            renderer,
+                            theme,
+                            style,
+                            content_layout,
+                            cursor,
+                            &Rectangle {
+                                x: bounds.x - translation.x,
+                                y: bounds.y - translation.y,
+                                ..bounds
+                            },
+                        );
+                    });
+                }
+
+                if let Some((center, radius)) = view.toggle(node, &self.children) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: center.x - radius,
+                                y: center.y - radius,
+                                width: radius * 2.0,
+                                height: radius * 2.0,
+                            },
+                            border_radius: radius.into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        appearance.toggle_background,
+                    );
+
+                    let icon = if state.is_collapsed(node) {
+                        Icon::Plus
+                    } else {
+                        Icon::Dash
+                    };
+                    renderer.fill_text(core::text::Text {
+                        content: &char::from(icon).to_string(),
+                        bounds: Rectangle {
+                            x: center.x,
+                            y: center.y,
+                            width: radius * 2.0,
+                            height: radius * 2.0,
+                        },
+                        size: radius * 2.0,
+                        line_height: LineHeight::default(),
+                        color: appearance.toggle_text_color,
+                        font: ICON_FONT,
+                        horizontal_alignment: Horizontal::Center,
+                        vertical_alignment: Vertical::Center,
+                        shaping: core::text::Shaping::Basic,
+                    });
+                }
+            }
+        });
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        for ((content, tree), layout) in self
+            .contents
+            .iter()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            content
+                .as_widget()
+                .operate(tree, layout, renderer, operation);
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<OrgChart<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(org_chart: OrgChart<'a, Message, Renderer>) -> Self {
+        Element::new(org_chart)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OrgChart, OrgNode};
+    use crate::test_utils::Harness;
+    use iced_widget::{
+        container,
+        core::{Point, Size},
+        text,
+    };
+
+    #[test]
+    fn org_chart_test() {
+        let node = |label| container(text(label)).width(60).height(20);
+        let chart = OrgChart::new(OrgNode::new(node("Root")).on_press(0).children([
+            OrgNode::new(node("A")).on_press(1),
+            OrgNode::new(node("B")).on_press(2),
+        ]));
+        let mut harness = Harness::new(chart, Size::new(400.0, 300.0));
+
+        // The padded boxes of the children are centered below the root.
+        let root = Point::new(200.0, 34.0);
+        let toggle = Point::new(200.0, 52.0);
+        assert_eq!(harness.click(root), vec![0]);
+        assert_eq!(harness.click(Point::new(154.0, 102.0)), vec![1]);
+        assert_eq!(harness.click(Point::new(246.0, 102.0)), vec![2]);
+
+        // The toggle below the root collapses and expands its branch.
+        assert!(harness.click(toggle).is_empty());
+        assert!(harness.click(Point::new(154.0, 102.0)).is_empty());
+        assert!(harness.click(toggle).is_empty());
+        assert_eq!(harness.click(Point::new(154.0, 102.0)), vec![1]);
+
+        // Dragging pans the chart without clicking.
+        assert!(harness
+            .drag(Point::new(300.0, 250.0), Point::new(280.0, 250.0), 5)
+            .is_empty());
+        assert_eq!(harness.click(Point::new(134.0, 102.0)), vec![1]);
+
+        // Zooming out keeps the node under the cursor in place.
+        let root = Point::new(180.0, 34.0);
+        let _ = harness.move_cursor(root);
+        let _ = harness.scroll(iced_widget::core::Vector::new(0.0, -1.0));
+        assert_eq!(harness.click(root), vec![0]);
+        assert!(harness.click(Point::new(134.0, 114.0)).is_empty());
+        assert_eq!(harness.click(Point::new(142.0, 91.0)), vec![1]);
+    }
+}
@@ -5,7 +5,8 @@
 use crate::{
     color_picker,
     core::{
-        color::{HexString, Hsv},
+        color::{parse_hex, ColorSpace, HexString, Hsv},
+        layout_direction::{self, LayoutDirection},
         overlay::Position,
     },
     graphics::icons::{icon_to_char, Icon},
@@ -32,7 +33,7 @@ use iced_widget::{
     },
     graphics::geometry::Renderer as _,
     renderer::Renderer,
-    Button, Column, Row,
+    text_input, Button, Column, Row,
 };
 use std::collections::HashMap;
 
@@ -50,6 +51,21 @@ const HUE_STEP: i32 = 1;
 /// The step value of the keyboard change of the RGBA color values.
 const RGBA_STEP: i16 = 1;
 
+/// The padding inside of the text fields.
+const INPUT_PADDING: f32 = 5.0;
+
+/// The size of the swatches of the preset and the recent colors.
+const SWATCH_SIZE: f32 = 20.0;
+/// The number of recently submitted colors that are remembered.
+const RECENT_COLORS: usize = 8;
+
+/// The size of the squares of the checkerboard behind the preview of the color.
+const CHECKERBOARD_SIZE: f32 = 8.0;
+/// The color of the light squares of the checkerboard.
+const CHECKERBOARD_LIGHT: Color = Color::WHITE;
+/// The color of the dark squares of the checkerboard.
+const CHECKERBOARD_DARK: Color = Color::from_rgb(0.8, 0.8, 0.8);
+
 /// The overlay of the [`ColorPicker`](crate::native::ColorPicker).
 #[allow(missing_debug_implementations)]
 pub struct ColorPickerOverlay<'a, Message, Theme>
@@ -71,12 +87,14 @@ where
     style: <Theme as StyleSheet>::Style,
     /// The reference to the tree holding the state of this overlay.
     tree: &'a mut Tree,
+    /// The [`LayoutDirection`] the buttons of the [`ColorPickerOverlay`] are arranged in.
+    direction: LayoutDirection,
 }
 
 impl<'a, Message, Theme> ColorPickerOverlay<'a, Message, Theme>
 where
     Message: 'static + Clone,
-    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet,
+    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
 {
     /// Creates a new [`ColorPickerOverlay`] on the given position.
     pub fn new(
@@ -88,7 +106,7 @@ where
         tree: &'a mut Tree,
     ) -> Self {
         //state.color_hex = color_picker::State::color_as_string(state.color);
-        let color_picker::State { overlay_state } = state;
+        let color_picker::State { overlay_state, .. } = state;
 
         ColorPickerOverlay {
             state: overlay_state,
@@ -112,6 +130,7 @@ where
             position,
             style,
             tree,
+            direction: layout_direction::get(),
         }
     }
 
@@ -121,6 +140,72 @@ where
         overlay::Element::new(self.position, Box::new(self))
     }
 
+    /// Publishes the picked color, remembering it as a recent color.
+    fn submit(&mut self, shell: &mut Shell<Message>) {
+        self.state.remember(self.state.color);
+        shell.publish((self.on_submit)(self.state.color));
+        // The row of the recent colors may grow.
+        shell.invalidate_layout();
+    }
+
+    /// The event handling for the swatches of the preset and the recent
+    /// colors.
+    fn on_event_swatches(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) -> event::Status {
+        let Some(swatches_layout) = layout.children().nth(2) else {
+            return event::Status::Ignored;
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let picked = swatches_layout
+                    .children()
+                    .zip([&self.state.swatches, &self.state.recent])
+                    .flat_map(|(row_layout, colors)| row_layout.children().zip(colors))
+                    .find(|(swatch_layout, _)| cursor.is_over(swatch_layout.bounds()))
+                    .map(|(_, color)| *color);
+
+                picked.map_or(event::Status::Ignored, |color| {
+                    self.state.color = Color {
+                        a: if self.state.alpha { color.a } else { 1.0 },
+                        ..color
+                    };
+                    event::Status::Captured
+                })
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    /// The event handling for the switcher of the color space of the bars.
+    fn on_event_switcher(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let picked = layout
+                    .children()
+                    .zip(ColorSpace::ALL)
+                    .find(|(segment_layout, _)| cursor.is_over(segment_layout.bounds()));
+
+                picked.map_or(event::Status::Ignored, |(_, color_space)| {
+                    self.state.switch(color_space);
+                    event::Status::Captured
+                })
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
     /// The event handling for the HSV color area.
     fn on_event_hsv_color(
         &mut self,
@@ -280,47 +365,40 @@ where
             .expect("Native: Layout should have a blue bar layout")
             .bounds();
 
-        let mut alpha_row_children = rgba_color_children
-            .next()
-            .expect("Native: Layout should have an alpha row layout")
-            .children();
-        let _ = alpha_row_children.next();
-        let alpha_bar_bounds = alpha_row_children
-            .next()
-            .expect("Native: Layout should have an alpha bar layout")
-            .bounds();
+        // The alpha row is left out if the alpha channel is not picked.
+        let alpha_bar_bounds = rgba_color_children.next().map(|alpha_row| {
+            let mut alpha_row_children = alpha_row.children();
+            let _ = alpha_row_children.next();
+            alpha_row_children
+                .next()
+                .expect("Native: Layout should have an alpha bar layout")
+                .bounds()
+        });
+        let is_over_alpha_bar = alpha_bar_bounds.is_some_and(|bounds| cursor.is_over(bounds));
+
+        // The first three bars pick the channels of the color space.
+        let channel_bars = [red_bar_bounds, green_bar_bounds, blue_bar_bounds];
+        let maxima = self.state.color_space.maxima();
 
         match event {
             Event::Mouse(mouse::Event::WheelScrolled { delta }) => match delta {
                 mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => {
-                    let move_value =
-                        //|value: f32, y: f32| (value * 255.0 + y).clamp(0.0, 255.0) / 255.0;
-                        |value: f32, y: f32| value.mul_add(255.0, y).clamp(0.0, 255.0) / 255.0;
+                    let move_value = |value: f32, y: f32, max: u16| {
+                        let max = f32::from(max);
+                        value.mul_add(max, y).clamp(0.0, max) / max
+                    };
 
-                    if cursor.is_over(red_bar_bounds) {
-                        self.state.color = Color {
-                            r: move_value(self.state.color.r, *y),
-                            ..self.state.color
-                        };
-                        color_changed = true;
-                    }
-                    if cursor.is_over(green_bar_bounds) {
-                        self.state.color = Color {
-                            g: move_value(self.state.color.g, *y),
-                            ..self.state.color
-                        };
-                        color_changed = true;
-                    }
-                    if cursor.is_over(blue_bar_bounds) {
-                        self.state.color = Color {
-                            b: move_value(self.state.color.b, *y),
-                            ..self.state.color
-                        };
-                        color_changed = true;
+                    for (index, bounds) in channel_bars.into_iter().enumerate() {
+                        if cursor.is_over(bounds) {
+                            let value = self.state.channels()[index];
+                            self.state
+                                .set_channel(index, move_value(value, *y, maxima[index]));
+                            color_changed = true;
+                        }
                     }
-                    if cursor.is_over(alpha_bar_bounds) {
+                    if is_over_alpha_bar {
                         self.state.color = Color {
-                            a: move_value(self.state.color.a, *y),
+                            a: move_value(self.state.color.a, *y, 255),
                             ..self.state.color
                         };
                         color_changed = true;
@@ -341,7 +419,7 @@ where
                     self.state.color_bar_dragged = ColorBarDragged::Blue;
                     self.state.focus = Focus::Blue;
                 }
-                if cursor.is_over(alpha_bar_bounds) {
+                if is_over_alpha_bar {
                     self.state.color_bar_dragged = ColorBarDragged::Alpha;
                     self.state.focus = Focus::Alpha;
                 }
@@ -357,48 +435,29 @@ where
             (cursor_position.x.max(0.0) / bounds.width).min(1.0)
         };
 
-        match self.state.color_bar_dragged {
-            ColorBarDragged::Red => {
-                self.state.color = Color {
-                    r: cursor
-                        .position_in(red_bar_bounds)
-                        .map(|position| calc_percentage(red_bar_bounds, position))
-                        .unwrap_or_default(),
-                    ..self.state.color
-                };
-                color_changed = true;
-            }
-            ColorBarDragged::Green => {
-                self.state.color = Color {
-                    g: cursor
-                        .position_in(green_bar_bounds)
-                        .map(|position| calc_percentage(green_bar_bounds, position))
-                        .unwrap_or_default(),
-                    ..self.state.color
-                };
-                color_changed = true;
-            }
-            ColorBarDragged::Blue => {
-                self.state.color = Color {
-                    b: cursor
-                        .position_in(blue_bar_bounds)
-                        .map(|position| calc_percentage(blue_bar_bounds, position))
-                        .unwrap_or_default(),
-                    ..self.state.color
-                };
-                color_changed = true;
-            }
-            ColorBarDragged::Alpha => {
-                self.state.color = Color {
-                    a: cursor
-                        .position_in(alpha_bar_bounds)
-                        .map(|position| calc_percentage(alpha_bar_bounds, position))
-                        .unwrap_or_default(),
-                    ..self.state.color
-                };
-                color_changed = true;
-            }
-            _ => {}
+        if let Some(index) = self.state.color_bar_dragged.channel() {
+            let bounds = channel_bars[index];
+            self.state.set_channel(
+                index,
+                cursor
+                    .position_in(bounds)
+                    .map(|position| calc_percentage(bounds, position))
+                    .unwrap_or_default(),
+            );
+            color_changed = true;
+        }
+
+        if let (ColorBarDragged::Alpha, Some(alpha_bar_bounds)) =
+            (self.state.color_bar_dragged, alpha_bar_bounds)
+        {
+            self.state.color = Color {
+                a: cursor
+                    .position_in(alpha_bar_bounds)
+                    .map(|position| calc_percentage(alpha_bar_bounds, position))
+                    .unwrap_or_default(),
+                ..self.state.color
+            };
+            color_changed = true;
         }
 
         if color_changed {
@@ -408,6 +467,87 @@ where
         }
     }
 
+    /// The event handling for the text fields.
+    ///
+    /// The color changes to the values typed into the text fields that can be
+    /// parsed, and `Enter` submits it.
+    fn on_event_inputs(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        shell: &mut Shell<Message>,
+        renderer: &Renderer<Theme>,
+        clipboard: &mut dyn Clipboard,
+    ) -> event::Status {
+        let mut block2_children = layout
+            .children()
+            .nth(1)
+            .expect("Native: Layout should have a 2. block layout")
+            .children();
+        let rgba_color_layout = block2_children
+            .next()
+            .expect("Native: Layout should have a RGBA color layout");
+        let hex_text_layout = block2_children
+            .next()
+            .expect("Native: Layout should have a hex text layout");
+
+        // The alpha row is left out if the alpha channel is not picked.
+        let fields = Field::CHANNELS
+            .into_iter()
+            .zip(rgba_color_layout.children())
+            .filter_map(|(field, row)| row.children().nth(2).map(|input| (field, input)))
+            .chain(
+                hex_text_layout
+                    .children()
+                    .next()
+                    .map(|input| (Field::Hex, input)),
+            );
+
+        let mut status = event::Status::Ignored;
+        for (field, input_layout) in fields {
+            let mut input_messages = Vec::new();
+            let mut input_shell = Shell::new(&mut input_messages);
+            let mut value = text_input::Value::new(&self.state.field_text(field));
+
+            status = status.merge(text_input::update(
+                event.clone(),
+                input_layout,
+                cursor,
+                renderer,
+                clipboard,
+                &mut input_shell,
+                &mut value,
+                None,
+                text::LineHeight::default(),
+                None,
+                false,
+                Some(&InputMessage::Changed),
+                None,
+                &Some(InputMessage::Submitted),
+                || &mut self.state.text_inputs[field.index()],
+            ));
+
+            // The blinking cursor keeps redrawing.
+            if let Some(request) = input_shell.redraw_request() {
+                shell.request_redraw(request);
+            }
+
+            for message in input_messages {
+                match message {
+                    InputMessage::Changed(text) => self.state.type_text(field, text),
+                    InputMessage::Submitted => {
+                        if !self.state.input_error {
+                            self.submit(shell);
+                        }
+                    }
+                }
+            }
+        }
+
+        status
+    }
+
     /// The even handling for the keyboard input.
     fn on_event_keyboard(
         &mut self,
@@ -431,6 +571,14 @@ where
                 } else {
                     self.state.focus = self.state.focus.next();
                 }
+                // The alpha bar is skipped if it is not shown.
+                if self.state.focus == Focus::Alpha && !self.state.alpha {
+                    self.state.focus = if self.state.keyboard_modifiers.shift() {
+                        Focus::Blue
+                    } else {
+                        Focus::Cancel
+                    };
+                }
                 // TODO: maybe place this better
                 self.state.sat_value_canvas_cache.clear();
                 self.state.hue_canvas_cache.clear();
@@ -497,22 +645,24 @@ where
                     status
                 };
 
-                let rgba_bar_handle = |key_code: &keyboard::KeyCode, value: &mut f32| {
-                    let mut byte_value = (*value * 255.0) as i16;
+                // The bars step by one of the typed values of their channel.
+                let rgba_bar_handle = |key_code: &keyboard::KeyCode, value: &mut f32, max: u16| {
+                    let max = i32::from(max);
+                    let mut step_value = (*value * max as f32).round() as i32;
                     let mut status = event::Status::Captured;
 
                     match key_code {
                         keyboard::KeyCode::Left | keyboard::KeyCode::Down => {
-                            byte_value -= RGBA_STEP;
+                            step_value -= i32::from(RGBA_STEP);
                             status = event::Status::Captured;
                         }
                         keyboard::KeyCode::Right | keyboard::KeyCode::Up => {
-                            byte_value += RGBA_STEP;
+                            step_value += i32::from(RGBA_STEP);
                             status = event::Status::Captured;
                         }
                         _ => {}
                     }
-                    *value = f32::from(byte_value.clamp(0, 255)) / 255.0;
+                    *value = step_value.clamp(0, max) as f32 / max as f32;
 
                     status
                 };
@@ -520,10 +670,17 @@ where
                 match self.state.focus {
                     Focus::SatValue => status = sat_value_handle(key_code, &mut self.state.color),
                     Focus::Hue => status = hue_handle(key_code, &mut self.state.color),
-                    Focus::Red => status = rgba_bar_handle(key_code, &mut self.state.color.r),
-                    Focus::Green => status = rgba_bar_handle(key_code, &mut self.state.color.g),
-                    Focus::Blue => status = rgba_bar_handle(key_code, &mut self.state.color.b),
-                    Focus::Alpha => status = rgba_bar_handle(key_code, &mut self.state.color.a),
+                    Focus::Red | Focus::Green | Focus::Blue => {
+                        if let Some(index) = self.state.focus.channel() {
+                            let mut value = self.state.channels()[index];
+                            let max = self.state.color_space.maxima()[index];
+                            status = rgba_bar_handle(key_code, &mut value, max);
+                            self.state.set_channel(index, value);
+                        }
+                    }
+                    Focus::Alpha => {
+                        status = rgba_bar_handle(key_code, &mut self.state.color.a, 255);
+                    }
                     _ => {}
                 }
             }
@@ -542,7 +699,7 @@ impl<'a, Message, Theme> Overlay<Message, Renderer<Theme>>
     for ColorPickerOverlay<'a, Message, Theme>
 where
     Message: 'static + Clone,
-    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet,
+    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
 {
     fn layout(&self, renderer: &Renderer<Theme>, bounds: Size, position: Point) -> Node {
         let (max_width, max_height) = if bounds.width > bounds.height {
@@ -618,9 +775,23 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<Message>,
     ) -> event::Status {
+        // ----------- Text fields ----------------------
         if event::Status::Captured
-            == self.on_event_keyboard(&event, layout, cursor, shell, renderer, clipboard)
+            == self.on_event_inputs(&event, layout, cursor, shell, renderer, clipboard)
+        {
+            self.state.sat_value_canvas_cache.clear();
+            self.state.hue_canvas_cache.clear();
+            return event::Status::Captured;
+        }
+
+        let color = self.state.color;
+
+        // The keys typed into the text fields don't move the bars.
+        if !self.state.is_typing()
+            && event::Status::Captured
+                == self.on_event_keyboard(&event, layout, cursor, shell, renderer, clipboard)
         {
+            self.state.discard_input(color);
             self.state.sat_value_canvas_cache.clear();
             self.state.hue_canvas_cache.clear();
             return event::Status::Captured;
@@ -636,6 +807,7 @@ where
             .expect("Native: Layout should have a 1. block layout");
         let hsv_color_status =
             self.on_event_hsv_color(&event, block1_layout, cursor, shell, renderer, clipboard);
+        let swatches_status = self.on_event_swatches(&event, block1_layout, cursor);
         // ----------- Block 1 end ------------------
 
         // ----------- Block 2 ----------------------
@@ -684,7 +856,7 @@ where
             .expect("Native: Layout should have a submit button layout for a ColorPicker");
         let submit_button_status = self.submit_button.on_event(
             &mut self.tree.children[1],
-            event,
+            event.clone(),
             submit_button_layout,
             cursor,
             renderer,
@@ -694,22 +866,32 @@ where
         );
 
         if !fake_messages.is_empty() {
-            shell.publish((self.on_submit)(self.state.color));
+            self.submit(shell);
         }
+
+        // ----------- Color space switcher ------------
+        let switcher_layout = block2_children
+            .next()
+            .expect("Native: Layout should have a color space switcher layout");
+        let switcher_status = self.on_event_switcher(&event, switcher_layout, cursor);
         // ----------- Block 2 end ------------------
 
         if hsv_color_status == event::Status::Captured
+            || swatches_status == event::Status::Captured
             || rgba_color_status == event::Status::Captured
         {
+            self.state.discard_input(color);
             self.state.sat_value_canvas_cache.clear();
             self.state.hue_canvas_cache.clear();
         }
 
         status
             .merge(hsv_color_status)
+            .merge(swatches_status)
             .merge(rgba_color_status)
             .merge(cancel_button_status)
             .merge(submit_button_status)
+            .merge(switcher_status)
     }
 
     fn mouse_interaction(
@@ -742,6 +924,17 @@ where
         if cursor.is_over(hue_layout.bounds()) {
             block1_mouse_interaction = block1_mouse_interaction.max(mouse::Interaction::Pointer);
         }
+        // Swatches
+        if let Some(swatches_layout) = hsv_color_children.next() {
+            let over_swatch = swatches_layout
+                .children()
+                .flat_map(Layout::children)
+                .any(|swatch_layout| cursor.is_over(swatch_layout.bounds()));
+            if over_swatch {
+                block1_mouse_interaction =
+                    block1_mouse_interaction.max(mouse::Interaction::Pointer);
+            }
+        }
 
         // Block 2
         let block2_layout = children
@@ -762,11 +955,14 @@ where
             let bar_layout = children
                 .next()
                 .expect("Graphics: Layout should have a bar layout");
+            let value_layout = children
+                .next()
+                .expect("Graphics: Layout should have a value layout");
 
             if cursor.is_over(bar_layout.bounds()) {
                 mouse::Interaction::ResizingHorizontally
             } else {
-                mouse::Interaction::default()
+                text_input::mouse_interaction(value_layout, cursor, false)
             }
         };
         let red_row_layout = rgba_color_children
@@ -781,12 +977,19 @@ where
             .next()
             .expect("Graphics: Layout should have a blue row layout");
         block2_mouse_interaction = block2_mouse_interaction.max(f(blue_row_layout, cursor));
-        let alpha_row_layout = rgba_color_children
-            .next()
-            .expect("Graphics: Layout should have an alpha row layout");
-        block2_mouse_interaction = block2_mouse_interaction.max(f(alpha_row_layout, cursor));
+        if let Some(alpha_row_layout) = rgba_color_children.next() {
+            block2_mouse_interaction = block2_mouse_interaction.max(f(alpha_row_layout, cursor));
+        }
 
-        let _hex_text_layout = block2_children.next();
+        let hex_input_layout = block2_children
+            .next()
+            .and_then(|hex_text_layout| hex_text_layout.children().next())
+            .expect("Graphics: Layout should have a hex text field layout");
+        block2_mouse_interaction = block2_mouse_interaction.max(text_input::mouse_interaction(
+            hex_input_layout,
+            cursor,
+            false,
+        ));
 
         // Buttons
         let cancel_button_layout = block2_children
@@ -811,6 +1014,17 @@ where
             renderer,
         );
 
+        // Color space switcher
+        let switcher_layout = block2_children
+            .next()
+            .expect("Graphics: Layout should have a color space switcher layout");
+        if switcher_layout
+            .children()
+            .any(|segment_layout| cursor.is_over(segment_layout.bounds()))
+        {
+            block2_mouse_interaction = block2_mouse_interaction.max(mouse::Interaction::Pointer);
+        }
+
         mouse_interaction
             .max(block1_mouse_interaction)
             .max(block2_mouse_interaction)
@@ -879,22 +1093,25 @@ where
     }
 }
 
-/// Defines the layout of the 1. block of the color picker containing the HSV part.
+/// Defines the layout of the 1. block of the color picker containing the HSV part
+/// and the swatches.
 fn block1_layout<'a, Message, Theme>(
-    _color_picker: &ColorPickerOverlay<'a, Message, Theme>,
+    color_picker: &ColorPickerOverlay<'a, Message, Theme>,
     renderer: &Renderer<Theme>,
     bounds: Rectangle,
     _position: Point,
 ) -> Node
 where
     Message: 'static + Clone,
-    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet,
+    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
 {
     let block1_limits = Limits::new(Size::ZERO, bounds.size())
         .width(Length::Fill)
         .height(Length::Fill);
 
-    let mut block1_node = Column::<(), Renderer<Theme>>::new()
+    let swatches = swatches_layout(color_picker.state, bounds.width);
+
+    let mut block1_column = Column::<(), Renderer<Theme>>::new()
         .spacing(PADDING)
         .push(
             Row::new()
@@ -905,14 +1122,73 @@ where
             Row::new()
                 .width(Length::Fill)
                 .height(Length::FillPortion(1)),
-        )
-        .layout(renderer, &block1_limits);
+        );
+    if let Some(swatches) = &swatches {
+        block1_column = block1_column.push(
+            Row::new()
+                .width(Length::Fill)
+                .height(Length::Fixed(swatches.size().height)),
+        );
+    }
+    let block1_node = block1_column.layout(renderer, &block1_limits);
+
+    // The swatches take the place of the last row.
+    let mut children = block1_node.children().to_vec();
+    if let Some(mut swatches) = swatches {
+        let row = children.pop().expect("Column should have a swatches row");
+        swatches.move_to(row.bounds().position());
+        children.push(swatches);
+    }
+    let mut block1_node = Node::with_children(block1_node.size(), children);
 
     block1_node.move_to(Point::new(bounds.x + PADDING, bounds.y + PADDING));
 
     block1_node
 }
 
+/// Defines the layout of the swatches of the preset colors, wrapping into as
+/// many rows as needed, and below them the row of the recent colors, if
+/// there are any.
+fn swatches_layout(state: &State, width: f32) -> Option<Node> {
+    if state.swatches.is_empty() && state.recent.is_empty() {
+        return None;
+    }
+
+    let step = SWATCH_SIZE + BUTTON_SPACING;
+    let per_row = (((width + BUTTON_SPACING) / step).floor() as usize).max(1);
+    let row_node = |count: usize, y: f32| {
+        let rows = count.div_ceil(per_row);
+        let swatches = (0..count)
+            .map(|index| {
+                let mut swatch = Node::new(Size::new(SWATCH_SIZE, SWATCH_SIZE));
+                swatch.move_to(Point::new(
+                    (index % per_row) as f32 * step,
+                    (index / per_row) as f32 * step,
+                ));
+                swatch
+            })
+            .collect();
+        let height = (rows as f32 * step - BUTTON_SPACING).max(0.0);
+
+        let mut node = Node::with_children(Size::new(width, height), swatches);
+        node.move_to(Point::new(0.0, y));
+        node
+    };
+
+    let presets = row_node(state.swatches.len(), 0.0);
+    let recent_y = if state.swatches.is_empty() {
+        0.0
+    } else {
+        presets.size().height + PADDING
+    };
+    let recent = row_node(state.recent.len().min(per_row), recent_y);
+
+    Some(Node::with_children(
+        Size::new(width, recent_y + recent.size().height),
+        vec![presets, recent],
+    ))
+}
+
 /// Defines the layout of the 2. block of the color picker containing the RGBA part, Hex and buttons.
 fn block2_layout<'a, Message, Theme>(
     color_picker: &ColorPickerOverlay<'a, Message, Theme>,
@@ -922,7 +1198,7 @@ fn block2_layout<'a, Message, Theme>(
 ) -> Node
 where
     Message: 'static + Clone,
-    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet,
+    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
 {
     let block2_limits = Limits::new(Size::ZERO, bounds.size())
         .width(Length::Fill)
@@ -933,20 +1209,49 @@ where
     let cancel_button = color_picker.cancel_button.layout(renderer, &cancel_limits);
 
     let hex_text_limits = block2_limits;
-    let mut hex_text = Row::<(), Renderer<Theme>>::new()
+    let hex_text = Row::<(), Renderer<Theme>>::new()
         .width(Length::Fill)
         .height(Length::Fixed(renderer.default_size() + 2.0 * PADDING))
         .layout(renderer, &hex_text_limits);
 
+    // The hex text field is followed by a preview of the color.
+    let hex_input_width = renderer.measure_width(
+        "#DDDDDDDD",
+        renderer.default_size(),
+        renderer.default_font(),
+        text::Shaping::Basic,
+    ) + 2.0 * INPUT_PADDING;
+    let mut hex_input = input_layout(renderer, hex_input_width);
+    hex_input.move_to(Point::new(
+        0.0,
+        (hex_text.size().height - hex_input.size().height) / 2.0,
+    ));
+    let mut swatch = Node::new(Size::new(
+        (hex_text.size().width - hex_input_width - SPACING).max(0.0),
+        hex_text.size().height,
+    ));
+    swatch.move_to(Point::new(hex_input_width + SPACING, 0.0));
+    let mut hex_text = Node::with_children(hex_text.size(), vec![hex_input, swatch]);
+
+    // The switcher of the color space is as high as a text field.
+    let switcher_height = input_layout(renderer, 0.0).size().height;
+
     let block2_limits = block2_limits.shrink(Size::new(
         0.0,
-        cancel_button.bounds().height + hex_text.bounds().height + 2.0 * SPACING,
+        cancel_button.bounds().height + hex_text.bounds().height + switcher_height + 3.0 * SPACING,
     ));
 
     // RGBA Colors
+    let value_width = renderer.measure_width(
+        "000",
+        renderer.default_size(),
+        renderer.default_font(),
+        text::Shaping::Basic,
+    ) + 2.0 * INPUT_PADDING;
     let mut rgba_colors = Column::<(), Renderer<Theme>>::new();
 
-    for _ in 0..4 {
+    let rows = if color_picker.state.alpha { 4 } else { 3 };
+    for _ in 0..rows {
         rgba_colors = rgba_colors.push(
             Row::new()
                 .align_items(Alignment::Center)
@@ -963,25 +1268,47 @@ where
                         .width(Length::FillPortion(5))
                         .height(Length::Fill),
                 )
-                .push(
-                    widget::Text::new("XXX")
-                        .horizontal_alignment(Horizontal::Center)
-                        .vertical_alignment(Vertical::Center),
-                ),
+                .push(Row::new().width(Length::Fixed(value_width))),
         );
     }
 
-    let mut rgba_colors = rgba_colors.layout(renderer, &block2_limits);
+    let rgba_colors = rgba_colors.layout(renderer, &block2_limits);
+
+    // The values of the bars are text fields.
+    let rows = rgba_colors
+        .children()
+        .iter()
+        .map(|row| {
+            let children = row.children();
+            let value = &children[2];
+            let mut input = input_layout(renderer, value_width);
+            input.move_to(Point::new(
+                value.bounds().x,
+                (row.size().height - input.size().height) / 2.0,
+            ));
+
+            let mut row_node = Node::with_children(
+                row.size(),
+                vec![children[0].clone(), children[1].clone(), input],
+            );
+            row_node.move_to(row.bounds().position());
+            row_node
+        })
+        .collect();
+    let mut rgba_colors = Node::with_children(rgba_colors.size(), rows);
+
+    // Color space switcher
+    let switcher = switcher_layout(rgba_colors.size().width, switcher_height);
 
     rgba_colors.move_to(Point::new(
         rgba_colors.bounds().x + PADDING,
-        rgba_colors.bounds().y + PADDING,
+        rgba_colors.bounds().y + switcher_height + PADDING + SPACING,
     ));
 
     // Hex text
     hex_text.move_to(Point::new(
         hex_text.bounds().x + PADDING,
-        hex_text.bounds().y + rgba_colors.bounds().height + PADDING + SPACING,
+        hex_text.bounds().y + rgba_colors.bounds().y + rgba_colors.bounds().height + SPACING,
     ));
 
     // Buttons
@@ -997,39 +1324,69 @@ where
 
     cancel_button.move_to(Point::new(
         cancel_button.bounds().x + PADDING,
-        cancel_button.bounds().y
-            + rgba_colors.bounds().height
-            + hex_text.bounds().height
-            + PADDING
-            + 2.0 * SPACING,
+        cancel_button.bounds().y + hex_text.bounds().y + hex_text.bounds().height + SPACING,
     ));
 
     submit_button.move_to(Point::new(
         submit_button.bounds().x + rgba_colors.bounds().width - submit_button.bounds().width
             + PADDING,
-        submit_button.bounds().y
-            + rgba_colors.bounds().height
-            + hex_text.bounds().height
-            + PADDING
-            + 2.0 * SPACING,
+        submit_button.bounds().y + hex_text.bounds().y + hex_text.bounds().height + SPACING,
     ));
 
-    let mut block2_node = Node::with_children(
+    // The switcher comes last to keep the order of the other children.
+    let mut block2_node = color_picker.direction.arrange(Node::with_children(
         Size::new(
             rgba_colors.bounds().width + (2.0 * PADDING),
-            rgba_colors.bounds().height
-                + hex_text.bounds().height
-                + cancel_button.bounds().height
-                + (2.0 * PADDING)
-                + (2.0 * SPACING),
+            cancel_button.bounds().y + cancel_button.bounds().height + PADDING,
         ),
-        vec![rgba_colors, hex_text, cancel_button, submit_button],
-    );
+        vec![
+            rgba_colors,
+            hex_text,
+            cancel_button,
+            submit_button,
+            switcher,
+        ],
+    ));
     block2_node.move_to(Point::new(bounds.x, bounds.y));
 
     block2_node
 }
 
+/// Defines the layout of the switcher of the color space, with a segment of
+/// the same width for each color space.
+fn switcher_layout(width: f32, height: f32) -> Node {
+    let count = ColorSpace::ALL.len() as f32;
+    let segment_width = ((width - (count - 1.0) * BUTTON_SPACING) / count).max(0.0);
+
+    let segments = (0..ColorSpace::ALL.len())
+        .map(|index| {
+            let mut segment = Node::new(Size::new(segment_width, height));
+            segment.move_to(Point::new(
+                index as f32 * (segment_width + BUTTON_SPACING),
+                0.0,
+            ));
+            segment
+        })
+        .collect();
+
+    let mut switcher = Node::with_children(Size::new(width, height), segments);
+    switcher.move_to(Point::new(PADDING, PADDING));
+    switcher
+}
+
+/// Lays out a text field of the given width.
+fn input_layout<Theme>(renderer: &Renderer<Theme>, width: f32) -> Node {
+    text_input::layout(
+        renderer,
+        &Limits::new(Size::ZERO, Size::new(width, f32::INFINITY)),
+        Length::Fill,
+        Padding::from(INPUT_PADDING),
+        None,
+        text::LineHeight::default(),
+        None,
+    )
+}
+
 /// Draws the 1. block of the color picker containing the HSV part.
 fn block1<Message, Theme>(
     renderer: &mut Renderer<Theme>,
@@ -1039,7 +1396,7 @@ fn block1<Message, Theme>(
     style_sheet: &HashMap<StyleState, Appearance>,
 ) where
     Message: Clone,
-    Theme: StyleSheet + button::StyleSheet + widget::text::StyleSheet,
+    Theme: StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
 {
     // ----------- Block 1 ----------------------
     let hsv_color_layout = layout;
@@ -1054,9 +1411,55 @@ fn block1<Message, Theme>(
         style_sheet,
     );
 
+    // ----------- Swatches ----------------------
+    if let Some(swatches_layout) = layout.children().nth(2) {
+        swatches(renderer, color_picker, swatches_layout, cursor, style_sheet);
+    }
+
     // ----------- Block 1 end ------------------
 }
 
+/// Draws the swatches of the preset and the recent colors, outlining the one
+/// of the picked color.
+fn swatches<Message, Theme>(
+    renderer: &mut Renderer<Theme>,
+    color_picker: &ColorPickerOverlay<'_, Message, Theme>,
+    layout: Layout<'_>,
+    cursor: Cursor,
+    style_sheet: &HashMap<StyleState, Appearance>,
+) where
+    Message: Clone,
+    Theme: StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
+{
+    let state = &color_picker.state;
+
+    for (row_layout, colors) in layout.children().zip([&state.swatches, &state.recent]) {
+        for (swatch_layout, color) in row_layout.children().zip(colors) {
+            let bounds = swatch_layout.bounds();
+            let style_state = if cursor.is_over(bounds) {
+                StyleState::Hovered
+            } else if *color == state.color {
+                StyleState::Selected
+            } else {
+                StyleState::Active
+            };
+            let appearance = &style_sheet[&style_state];
+
+            checkerboard(renderer, bounds, appearance.bar_border_radius);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: appearance.bar_border_radius.into(),
+                    border_width: appearance.bar_border_width,
+                    border_color: appearance.bar_border_color,
+                },
+                *color,
+            );
+        }
+    }
+}
+
 /// Draws the 2. block of the color picker containing the RGBA part, Hex and buttons.
 #[allow(clippy::too_many_arguments)]
 fn block2<Message, Theme>(
@@ -1070,7 +1473,7 @@ fn block2<Message, Theme>(
     style_sheet: &HashMap<StyleState, Appearance>,
 ) where
     Message: Clone,
-    Theme: StyleSheet + button::StyleSheet + widget::text::StyleSheet,
+    Theme: StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
 {
     // ----------- Block 2 ----------------------
     let mut block2_children = layout.children();
@@ -1081,12 +1484,12 @@ fn block2<Message, Theme>(
         .expect("Graphics: Layout should have a RGBA color layout");
     rgba_color(
         renderer,
+        color_picker,
+        theme,
         rgba_color_layout,
-        &color_picker.state.color,
         cursor,
         style,
         style_sheet,
-        color_picker.state.focus,
     );
 
     // ----------- Hex text ----------------------
@@ -1095,12 +1498,11 @@ fn block2<Message, Theme>(
         .expect("Graphics: Layout should have a hex text layout");
     hex_text(
         renderer,
+        color_picker,
+        theme,
         hex_text_layout,
-        &color_picker.state.color,
         cursor,
-        style,
         style_sheet,
-        color_picker.state.focus,
     );
 
     // ----------- Buttons -------------------------
@@ -1132,6 +1534,19 @@ fn block2<Message, Theme>(
         viewport,
     );
 
+    // ----------- Color space switcher ------------
+    let switcher_layout = block2_children
+        .next()
+        .expect("Graphics: Layout should have a color space switcher layout");
+    switcher(
+        renderer,
+        color_picker,
+        switcher_layout,
+        cursor,
+        style,
+        style_sheet,
+    );
+
     // Buttons are not focusable right now...
     if color_picker.state.focus == Focus::Cancel {
         renderer.fill_quad(
@@ -1159,6 +1574,58 @@ fn block2<Message, Theme>(
     // ----------- Block 2 end ------------------
 }
 
+/// Draws the switcher of the color space of the bars, outlining the segment
+/// of the active color space.
+fn switcher<Message, Theme>(
+    renderer: &mut Renderer<Theme>,
+    color_picker: &ColorPickerOverlay<'_, Message, Theme>,
+    layout: Layout<'_>,
+    cursor: Cursor,
+    style: &renderer::Style,
+    style_sheet: &HashMap<StyleState, Appearance>,
+) where
+    Message: Clone,
+    Theme: StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
+{
+    for (segment_layout, color_space) in layout.children().zip(ColorSpace::ALL) {
+        let bounds = segment_layout.bounds();
+        let style_state = if color_space == color_picker.state.color_space {
+            StyleState::Selected
+        } else if cursor.is_over(bounds) {
+            StyleState::Hovered
+        } else {
+            StyleState::Active
+        };
+        let appearance = &style_sheet[&style_state];
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.bar_border_radius.into(),
+                border_width: appearance.bar_border_width,
+                border_color: appearance.bar_border_color,
+            },
+            appearance.background,
+        );
+
+        renderer.fill_text(Text {
+            content: color_space.name(),
+            bounds: Rectangle {
+                x: bounds.center_x(),
+                y: bounds.center_y(),
+                ..bounds
+            },
+            size: renderer.default_size(),
+            font: renderer.default_font(),
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Basic,
+            color: style.text_color,
+        });
+    }
+}
+
 /// Draws the HSV color area.
 #[allow(clippy::too_many_lines)]
 fn hsv_color<Message, Theme>(
@@ -1169,7 +1636,7 @@ fn hsv_color<Message, Theme>(
     style_sheet: &HashMap<StyleState, Appearance>,
 ) where
     Message: Clone,
-    Theme: StyleSheet + button::StyleSheet + widget::text::StyleSheet,
+    Theme: StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
 {
     let mut hsv_color_children = layout.children();
     let hsv_color: Hsv = color_picker.state.color.into();
@@ -1343,18 +1810,24 @@ fn hsv_color<Message, Theme>(
 
 /// Draws the RGBA color area.
 #[allow(clippy::too_many_lines)]
-fn rgba_color<Theme>(
+fn rgba_color<Message, Theme>(
     renderer: &mut Renderer<Theme>,
+    color_picker: &ColorPickerOverlay<'_, Message, Theme>,
+    theme: &Theme,
     layout: Layout<'_>,
-    color: &Color,
     cursor: Cursor,
     style: &renderer::Style,
     style_sheet: &HashMap<StyleState, Appearance>,
-    focus: Focus,
 ) where
-    Theme: StyleSheet + button::StyleSheet + widget::text::StyleSheet,
+    Message: Clone,
+    Theme: StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
 {
     let mut rgba_color_children = layout.children();
+    let color = color_picker.state.color;
+    let focus = color_picker.state.focus;
+    let color_space = color_picker.state.color_space;
+    let channels = color_picker.state.channels();
+    let [first_label, second_label, third_label] = color_space.labels();
 
     let f = |renderer: &mut Renderer<Theme>,
              layout: Layout,
@@ -1362,7 +1835,8 @@ fn rgba_color<Theme>(
              color: Color,
              value: f32,
              cursor: Cursor,
-             target: Focus| {
+             target: Focus,
+             field: Field| {
         let mut children = layout.children();
 
         let label_layout = children
@@ -1445,21 +1919,7 @@ fn rgba_color<Theme>(
         );
 
         // Value
-        renderer.fill_text(Text {
-            content: &format!("{}", (255.0 * value) as u8),
-            bounds: Rectangle {
-                x: value_layout.bounds().center_x(),
-                y: value_layout.bounds().center_y(),
-                ..value_layout.bounds()
-            },
-            size: renderer.default_size(),
-            font: renderer.default_font(),
-            horizontal_alignment: Horizontal::Center,
-            vertical_alignment: Vertical::Center,
-            line_height: iced_widget::text::LineHeight::Relative(1.3),
-            shaping: iced_widget::text::Shaping::Advanced,
-            color: style.text_color,
-        });
+        input(renderer, color_picker, theme, value_layout, cursor, field);
 
         if focus == target {
             renderer.fill_quad(
@@ -1492,11 +1952,12 @@ fn rgba_color<Theme>(
     f(
         renderer,
         red_row_layout,
-        "R:",
-        Color::from_rgb(color.r, 0.0, 0.0),
-        color.r,
+        first_label,
+        channel_color(color_space, channels, 0),
+        channels[0],
         cursor,
         Focus::Red,
+        Field::Red,
     );
 
     // Green
@@ -1507,11 +1968,12 @@ fn rgba_color<Theme>(
     f(
         renderer,
         green_row_layout,
-        "G:",
-        Color::from_rgb(0.0, color.g, 0.0),
-        color.g,
+        second_label,
+        channel_color(color_space, channels, 1),
+        channels[1],
         cursor,
         Focus::Green,
+        Field::Green,
     );
 
     // Blue
@@ -1522,82 +1984,204 @@ fn rgba_color<Theme>(
     f(
         renderer,
         blue_row_layout,
-        "B:",
-        Color::from_rgb(0.0, 0.0, color.b),
-        color.b,
+        third_label,
+        channel_color(color_space, channels, 2),
+        channels[2],
         cursor,
         Focus::Blue,
+        Field::Blue,
     );
 
     // Alpha
-    let alpha_row_layout = rgba_color_children
-        .next()
-        .expect("Graphics: Layout should have an alpha row layout");
+    if let Some(alpha_row_layout) = rgba_color_children.next() {
+        f(
+            renderer,
+            alpha_row_layout,
+            "A:",
+            Color::from_rgba(0.0, 0.0, 0.0, color.a),
+            color.a,
+            cursor,
+            Focus::Alpha,
+            Field::Alpha,
+        );
+    }
+}
 
-    f(
-        renderer,
-        alpha_row_layout,
-        "A:",
-        Color::from_rgba(0.0, 0.0, 0.0, color.a),
-        color.a,
-        cursor,
-        Focus::Alpha,
-    );
+/// Returns the color filling the bar of the channel with the given index
+/// among the channels of a color in the color space, showing the channel on
+/// its own.
+fn channel_color(color_space: ColorSpace, channels: [f32; 3], index: usize) -> Color {
+    let value = channels[index];
+
+    match (color_space, index) {
+        (ColorSpace::Rgb, _) => {
+            let mut rgb = [0.0; 3];
+            rgb[index] = value;
+            Color::from_rgb(rgb[0], rgb[1], rgb[2])
+        }
+        // The hue at full saturation, and the saturation of the hue.
+        (ColorSpace::Hsv | ColorSpace::Hsl, 0) => ColorSpace::Hsv.color([value, 1.0, 1.0]),
+        (ColorSpace::Hsv | ColorSpace::Hsl, 1) => ColorSpace::Hsv.color([channels[0], value, 1.0]),
+        // The chroma of the hue and the hue at a medium lightness.
+        (ColorSpace::Oklch, 1) => ColorSpace::Oklch.color([0.7, value, channels[2]]),
+        (ColorSpace::Oklch, 2) => ColorSpace::Oklch.color([0.7, 0.3, value]),
+        // The gray of the lightness or the value.
+        (ColorSpace::Oklch, _) => ColorSpace::Oklch.color([value, 0.0, 0.0]),
+        _ => Color::from_rgb(value, value, value),
+    }
 }
 
-/// Draws the hex text representation of the color.
-fn hex_text<Theme>(
+/// Draws the hex text field and the preview of the color.
+fn hex_text<Message, Theme>(
     renderer: &mut Renderer<Theme>,
+    color_picker: &ColorPickerOverlay<'_, Message, Theme>,
+    theme: &Theme,
     layout: Layout<'_>,
-    color: &Color,
     cursor: Cursor,
-    _style: &renderer::Style,
     style_sheet: &HashMap<StyleState, Appearance>,
-    _focus: Focus,
 ) where
-    Theme: StyleSheet + button::StyleSheet + widget::text::StyleSheet,
+    Message: Clone,
+    Theme: StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
 {
-    let hsv: Hsv = (*color).into();
+    let mut hex_text_children = layout.children();
+
+    let input_layout = hex_text_children
+        .next()
+        .expect("Graphics: Layout should have a hex text field layout");
+    input(
+        renderer,
+        color_picker,
+        theme,
+        input_layout,
+        cursor,
+        Field::Hex,
+    );
 
-    let hex_text_style_state = if cursor.is_over(layout.bounds()) {
+    let swatch_layout = hex_text_children
+        .next()
+        .expect("Graphics: Layout should have a swatch layout");
+    let swatch_style_state = if cursor.is_over(swatch_layout.bounds()) {
         StyleState::Hovered
     } else {
         StyleState::Active
     };
 
+    // The checkerboard shows through the transparent parts of the color.
+    checkerboard(
+        renderer,
+        swatch_layout.bounds(),
+        style_sheet[&swatch_style_state].bar_border_radius,
+    );
+
     renderer.fill_quad(
         renderer::Quad {
-            bounds: layout.bounds(),
-            border_radius: style_sheet[&hex_text_style_state].bar_border_radius.into(),
-            border_width: style_sheet[&hex_text_style_state].bar_border_width,
-            border_color: style_sheet[&hex_text_style_state].bar_border_color,
+            bounds: swatch_layout.bounds(),
+            border_radius: style_sheet[&swatch_style_state].bar_border_radius.into(),
+            border_width: style_sheet[&swatch_style_state].bar_border_width,
+            border_color: style_sheet[&swatch_style_state].bar_border_color,
         },
-        *color,
+        color_picker.state.color,
     );
+}
 
-    renderer.fill_text(Text {
-        content: &color.as_hex_string(),
-        bounds: Rectangle {
-            x: layout.bounds().center_x(),
-            y: layout.bounds().center_y(),
-            ..layout.bounds()
-        },
-        size: renderer.default_size(),
-        color: Color {
-            a: 1.0,
-            ..Hsv {
-                hue: 0,
-                saturation: 0.0,
-                value: if hsv.value < 0.5 { 1.0 } else { 0.0 },
-            }
-            .into()
+/// Draws a text field of the color picker, outlined while its text can't be
+/// parsed.
+fn input<Message, Theme>(
+    renderer: &mut Renderer<Theme>,
+    color_picker: &ColorPickerOverlay<'_, Message, Theme>,
+    theme: &Theme,
+    layout: Layout<'_>,
+    cursor: Cursor,
+    field: Field,
+) where
+    Message: Clone,
+    Theme: StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
+{
+    let state = &color_picker.state;
+    let input_style = <Theme as text_input::StyleSheet>::Style::default();
+
+    text_input::draw(
+        renderer,
+        theme,
+        layout,
+        cursor,
+        &state.text_inputs[field.index()],
+        &text_input::Value::new(&state.field_text(field)),
+        "",
+        None,
+        text::LineHeight::default(),
+        None,
+        false,
+        false,
+        None,
+        &input_style,
+    );
+
+    if state.input_error && state.typed_field() == Some(field) {
+        let invalid = StyleSheet::invalid(theme, &color_picker.style);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                border_radius: text_input::StyleSheet::active(theme, &input_style).border_radius,
+                border_width: invalid.bar_border_width.max(1.0),
+                border_color: invalid.bar_border_color,
+            },
+            Color::TRANSPARENT,
+        );
+    }
+}
+
+/// Draws a checkerboard of light and dark squares filling the given bounds,
+/// with its corners rounded by the given radius.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+fn checkerboard<Theme>(renderer: &mut Renderer<Theme>, bounds: Rectangle, border_radius: f32) {
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border_radius: border_radius.into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
         },
-        font: renderer.default_font(),
-        horizontal_alignment: Horizontal::Center,
-        vertical_alignment: Vertical::Center,
-        line_height: iced_widget::text::LineHeight::Relative(1.3),
-        shaping: iced_widget::text::Shaping::Basic,
-    });
+        CHECKERBOARD_LIGHT,
+    );
+
+    let columns = (bounds.width / CHECKERBOARD_SIZE).ceil().max(0.0) as usize;
+    let rows = (bounds.height / CHECKERBOARD_SIZE).ceil().max(0.0) as usize;
+
+    for row in 0..rows {
+        // The square in the top left corner is light.
+        for column in ((row + 1) % 2..columns).step_by(2) {
+            let x = bounds.x + column as f32 * CHECKERBOARD_SIZE;
+            let y = bounds.y + row as f32 * CHECKERBOARD_SIZE;
+            let corner = |is_corner: bool| if is_corner { border_radius } else { 0.0 };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x,
+                        y,
+                        width: CHECKERBOARD_SIZE.min(bounds.x + bounds.width - x),
+                        height: CHECKERBOARD_SIZE.min(bounds.y + bounds.height - y),
+                    },
+                    border_radius: [
+                        corner(column == 0 && row == 0),
+                        corner(column + 1 == columns && row == 0),
+                        corner(column + 1 == columns && row + 1 == rows),
+                        corner(column == 0 && row + 1 == rows),
+                    ]
+                    .into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                CHECKERBOARD_DARK,
+            );
+        }
+    }
 }
 
 /// The state of the [`ColorPickerOverlay`].
@@ -1615,6 +2199,25 @@ pub struct State {
     pub(crate) focus: Focus,
     /// The previously pressed keyboard modifiers.
     pub(crate) keyboard_modifiers: keyboard::Modifiers,
+    /// Whether the alpha channel of the color is picked.
+    pub(crate) alpha: bool,
+    /// The text typed into a text field, until the color is changed
+    /// otherwise.
+    pub(crate) input: Option<(Field, String)>,
+    /// Whether the typed text is not a value of its text field.
+    pub(crate) input_error: bool,
+    /// The states of the text fields of the values of the bars and of the hex
+    /// string.
+    pub(crate) text_inputs: [text_input::State; 5],
+    /// The preset colors shown as swatches.
+    pub(crate) swatches: Vec<Color>,
+    /// The recently submitted colors, the latest first.
+    pub(crate) recent: Vec<Color>,
+    /// The color space of the bars.
+    pub(crate) color_space: ColorSpace,
+    /// The channels last picked with the bars and the color they make, which
+    /// keep the hue of grays while it is picked.
+    channels: Option<(Color, [f32; 3])>,
 }
 
 impl State {
@@ -1626,6 +2229,134 @@ impl State {
             ..Self::default()
         }
     }
+
+    /// Returns the text shown in the given text field, which is the typed
+    /// text or else the value of the color.
+    fn field_text(&self, field: Field) -> String {
+        let channel = |value: f32, max: u16| {
+            let value = value * f32::from(max);
+            // The bytes are cut off like in the hex string.
+            if self.color_space == ColorSpace::Rgb {
+                format!("{}", value as u16)
+            } else {
+                format!("{}", value.round() as u16)
+            }
+        };
+
+        match &self.input {
+            Some((typed, text)) if *typed == field => text.clone(),
+            _ => match field {
+                Field::Red | Field::Green | Field::Blue => channel(
+                    self.channels()[field.index()],
+                    self.color_space.maxima()[field.index()],
+                ),
+                Field::Alpha => format!("{}", (255.0 * self.color.a) as u8),
+                Field::Hex => self.color.as_hex_string(),
+            },
+        }
+    }
+
+    /// Returns the text field the shown text was typed into, if any.
+    fn typed_field(&self) -> Option<Field> {
+        self.input.as_ref().map(|(field, _)| *field)
+    }
+
+    /// Types the given text into the given text field, changing the color if
+    /// the text can be parsed.
+    fn type_text(&mut self, field: Field, text: String) {
+        let channel = |max: u16| {
+            text.trim()
+                .parse::<u16>()
+                .ok()
+                .filter(|value| *value <= max)
+                .map(|value| f32::from(value) / f32::from(max))
+        };
+        let typed = match field {
+            Field::Red | Field::Green | Field::Blue => {
+                let index = field.index();
+                channel(self.color_space.maxima()[index])
+                    .map(|value| self.set_channel(index, value))
+            }
+            Field::Alpha => channel(255).map(|a| self.color.a = a),
+            Field::Hex => parse_hex(&text).map(|color| {
+                self.color = Color {
+                    a: if self.alpha { color.a } else { 1.0 },
+                    ..color
+                };
+            }),
+        };
+
+        self.input_error = typed.is_none() && !text.trim().is_empty();
+        self.input = Some((field, text));
+    }
+
+    /// Returns the channels of the color in the color space of the bars.
+    fn channels(&self) -> [f32; 3] {
+        match self.channels {
+            Some((color, channels))
+                if color
+                    == Color {
+                        a: color.a,
+                        ..self.color
+                    } =>
+            {
+                channels
+            }
+            _ => self.color_space.channels(self.color),
+        }
+    }
+
+    /// Sets the channel with the given index of the color in the color space
+    /// of the bars, between 0 and 1.
+    fn set_channel(&mut self, index: usize, value: f32) {
+        let mut channels = self.channels();
+        channels[index] = value.clamp(0.0, 1.0);
+
+        self.color = Color {
+            a: self.color.a,
+            ..self.color_space.color(channels)
+        };
+        self.channels = Some((self.color, channels));
+    }
+
+    /// Switches the bars to the given color space, discarding the typed
+    /// text.
+    pub(crate) fn switch(&mut self, color_space: ColorSpace) {
+        self.color_space = color_space;
+        self.channels = None;
+        self.input = None;
+        self.input_error = false;
+    }
+
+    /// Discards the typed text after the color changed away from the given
+    /// previous color by other means.
+    fn discard_input(&mut self, previous: Color) {
+        if self.color != previous {
+            self.input = None;
+            self.input_error = false;
+        }
+    }
+
+    /// Discards the typed text and unfocuses the text fields.
+    pub(crate) fn reset_input(&mut self) {
+        self.input = None;
+        self.input_error = false;
+        for text_input in &mut self.text_inputs {
+            text_input.unfocus();
+        }
+    }
+
+    /// Remembers the given color as the latest of the recent colors.
+    fn remember(&mut self, color: Color) {
+        self.recent.retain(|recent| *recent != color);
+        self.recent.insert(0, color);
+        self.recent.truncate(RECENT_COLORS);
+    }
+
+    /// Returns whether text is typed into one of the text fields.
+    fn is_typing(&self) -> bool {
+        self.text_inputs.iter().any(text_input::State::is_focused)
+    }
 }
 
 impl Default for State {
@@ -1637,10 +2368,55 @@ impl Default for State {
             color_bar_dragged: ColorBarDragged::None,
             focus: Focus::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            alpha: true,
+            input: None,
+            input_error: false,
+            text_inputs: Default::default(),
+            swatches: Vec::new(),
+            recent: Vec::new(),
+            color_space: ColorSpace::default(),
+            channels: None,
         }
     }
 }
 
+/// A text field of the [`ColorPickerOverlay`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Field {
+    /// The value of the red bar, or of the first bar of other color
+    /// spaces.
+    Red,
+    /// The value of the green bar, or of the second bar of other color
+    /// spaces.
+    Green,
+    /// The value of the blue bar, or of the third bar of other color
+    /// spaces.
+    Blue,
+    /// The value of the alpha bar.
+    Alpha,
+    /// The hex string of the color.
+    Hex,
+}
+
+impl Field {
+    /// The text fields of the values of the bars, from top to bottom.
+    const CHANNELS: [Self; 4] = [Self::Red, Self::Green, Self::Blue, Self::Alpha];
+
+    /// Returns the index of the state of the text field.
+    const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// A message of the text fields of the [`ColorPickerOverlay`].
+#[derive(Clone, Debug)]
+enum InputMessage {
+    /// The text was changed.
+    Changed(String),
+    /// The text was submitted.
+    Submitted,
+}
+
 /// Just a workaround to pass the button states from the tree to the overlay
 #[allow(missing_debug_implementations)]
 pub struct ColorPickerOverlayButtons<'a, Message, Theme>
@@ -1657,7 +2433,7 @@ where
 impl<'a, Message, Theme> Default for ColorPickerOverlayButtons<'a, Message, Theme>
 where
     Message: 'a + Clone,
-    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet,
+    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
 {
     fn default() -> Self {
         Self {
@@ -1678,7 +2454,7 @@ impl<'a, Message, Theme> Widget<Message, Renderer<Theme>>
     for ColorPickerOverlayButtons<'a, Message, Theme>
 where
     Message: Clone,
-    Theme: StyleSheet + button::StyleSheet + widget::text::StyleSheet,
+    Theme: StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
 {
     fn children(&self) -> Vec<Tree> {
         vec![
@@ -1721,7 +2497,7 @@ impl<'a, Message, Theme> From<ColorPickerOverlayButtons<'a, Message, Theme>>
     for Element<'a, Message, Renderer<Theme>>
 where
     Message: 'a + Clone,
-    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet,
+    Theme: 'a + StyleSheet + button::StyleSheet + widget::text::StyleSheet + text_input::StyleSheet,
 {
     fn from(overlay: ColorPickerOverlayButtons<'a, Message, Theme>) -> Self {
         Self::new(overlay)
@@ -1740,19 +2516,32 @@ pub enum ColorBarDragged {
     /// The hue area is focussed.
     Hue,
 
-    /// The red area is focussed.
+    /// The red area, or the first bar of other color spaces, is focussed.
     Red,
 
-    /// The green area is focussed.
+    /// The green area, or the second bar of other color spaces, is focussed.
     Green,
 
-    /// The blue area is focussed.
+    /// The blue area, or the third bar of other color spaces, is focussed.
     Blue,
 
     /// The alpha area is focussed.
     Alpha,
 }
 
+impl ColorBarDragged {
+    /// Returns the index of the channel of the color space picked by the
+    /// dragged bar, if it is one of the first three bars.
+    pub(crate) const fn channel(self) -> Option<usize> {
+        match self {
+            Self::Red => Some(0),
+            Self::Green => Some(1),
+            Self::Blue => Some(2),
+            _ => None,
+        }
+    }
+}
+
 impl Default for ColorBarDragged {
     fn default() -> Self {
         Self::None
@@ -1774,13 +2563,13 @@ pub enum Focus {
     /// The hue bar is in focus.
     Hue,
 
-    /// The red bar is in focus.
+    /// The red bar, or the first bar of other color spaces, is in focus.
     Red,
 
-    /// The green bar is in focus.
+    /// The green bar, or the second bar of other color spaces, is in focus.
     Green,
 
-    /// The blue bar is in focus.
+    /// The blue bar, or the third bar of other color spaces, is in focus.
     Blue,
 
     /// The alpha bar is in focus.
@@ -1794,6 +2583,17 @@ pub enum Focus {
 }
 
 impl Focus {
+    /// Returns the index of the channel of the color space picked by the
+    /// focused bar, if it is one of the first three bars.
+    pub(crate) const fn channel(self) -> Option<usize> {
+        match self {
+            Self::Red => Some(0),
+            Self::Green => Some(1),
+            Self::Blue => Some(2),
+            _ => None,
+        }
+    }
+
     /// Gets the next focusable element.
     #[must_use]
     pub const fn next(self) -> Self {
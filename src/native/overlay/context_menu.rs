@@ -19,7 +19,7 @@ use iced_widget::core::{
 #[allow(missing_debug_implementations)]
 pub struct ContextMenuOverlay<'a, Message, Renderer = crate::Renderer>
 where
-    Message: 'a + Clone,
+    Message: 'a,
     Renderer: 'a + core::Renderer,
     Renderer::Theme: StyleSheet,
 {
@@ -35,7 +35,6 @@ where
 
 impl<'a, Message, Renderer> ContextMenuOverlay<'a, Message, Renderer>
 where
-    Message: Clone,
     Renderer: core::Renderer,
     Renderer::Theme: StyleSheet,
 {
@@ -66,7 +65,7 @@ where
 impl<'a, Message, Renderer> overlay::Overlay<Message, Renderer>
     for ContextMenuOverlay<'a, Message, Renderer>
 where
-    Message: 'a + Clone,
+    Message: 'a,
     Renderer: 'a + core::Renderer,
     Renderer::Theme: StyleSheet,
 {
@@ -4,10 +4,12 @@
 
 use crate::{
     core::{
-        date::{Date, IsInMonth},
+        date::{Date, IsInMonth, Weekday},
+        i18n::{self, Translations},
+        layout_direction::{self, LayoutDirection},
         overlay::Position,
     },
-    date_picker,
+    date_picker::{self, OnSubmit},
     graphics::icons::{icon_to_char, Icon, ICON_FONT},
     style::{
         date_picker::{Appearance, StyleSheet},
@@ -15,7 +17,7 @@ use crate::{
     },
 };
 
-use chrono::{Datelike, Local, NaiveDate};
+use chrono::{Datelike, Duration, Local, NaiveDate};
 use iced_widget::{
     button, container,
     core::{
@@ -25,16 +27,19 @@ use iced_widget::{
         layout::{Limits, Node},
         mouse::{self, Cursor},
         overlay, renderer,
-        text::Renderer as _,
+        text::{LineHeight, Renderer as _},
         touch,
         widget::tree::Tree,
         Alignment, Clipboard, Color, Element, Event, Layout, Length, Overlay, Padding, Point,
         Rectangle, Renderer as _, Shell, Size, Widget,
     },
     renderer::Renderer,
-    text, Button, Column, Container, Row, Text,
+    text, text_input, Button, Column, Container, Row, Text,
+};
+use std::{
+    collections::{BTreeSet, HashMap},
+    sync::Arc,
 };
-use std::collections::HashMap;
 
 /// The padding around the elements.
 const PADDING: f32 = 10.0;
@@ -58,55 +63,90 @@ where
     cancel_button: Button<'a, Message, Renderer<Theme>>,
     /// The submit button of the [`DatePickerOverlay`].
     submit_button: Button<'a, Message, Renderer<Theme>>,
+    /// The message that is sent when the [`DatePickerOverlay`] is cancelled.
+    on_cancel: Message,
     /// The function that produces a message when the submit button of the [`DatePickerOverlay`] is pressed.
-    on_submit: &'a dyn Fn(Date) -> Message,
+    on_submit: &'a OnSubmit<Message>,
+    /// The dates that can be picked.
+    constraints: Constraints<'a>,
     /// The position of the [`DatePickerOverlay`].
     position: Point,
     /// The style of the [`DatePickerOverlay`].
     style: <Theme as StyleSheet>::Style,
     /// The reference to the tree holding the state of this overlay.
     tree: &'a mut Tree,
+    /// The [`LayoutDirection`] the [`DatePickerOverlay`] is arranged in.
+    direction: LayoutDirection,
+    /// The [`Translations`] of the names of the months and the weekdays.
+    translations: Arc<dyn Translations + Send + Sync>,
+    /// The weekday the weeks of the calendar start on.
+    first_weekday: Weekday,
+    /// Whether the week numbers are shown before the days of the calendar.
+    week_numbers: WeekNumbers,
+    /// The number of consecutive months shown side by side.
+    months_shown: u8,
+    /// The format of the dates typed into the text field, if it is shown.
+    input_format: Option<&'a str>,
 }
 
 impl<'a, Message, Theme> DatePickerOverlay<'a, Message, Theme>
 where
     Message: 'static + Clone,
-    Theme: 'a + StyleSheet + button::StyleSheet + text::StyleSheet + container::StyleSheet,
+    Theme: 'a
+        + StyleSheet
+        + button::StyleSheet
+        + text::StyleSheet
+        + container::StyleSheet
+        + text_input::StyleSheet,
 {
     /// Creates a new [`DatePickerOverlay`] on the given position.
+    ///
+    /// The buttons show the translated labels instead of their icons if
+    /// `labeled_buttons` is set, the weeks of the calendar start on
+    /// `first_weekday`, and `week_numbers` sets whether their numbers are
+    /// shown. The calendars of `months_shown` consecutive months are shown
+    /// side by side, below a text field for typing dates in the
+    /// `input_format` if it is given.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         state: &'a mut date_picker::State,
         on_cancel: Message,
-        on_submit: &'a dyn Fn(Date) -> Message,
+        on_submit: &'a OnSubmit<Message>,
+        constraints: Constraints<'a>,
+        labeled_buttons: bool,
+        first_weekday: Weekday,
+        week_numbers: WeekNumbers,
+        months_shown: u8,
+        input_format: Option<&'a str>,
         position: Point,
         style: <Theme as StyleSheet>::Style,
         tree: &'a mut Tree,
         //button_style: impl Clone +  Into<<Renderer as button::Renderer>::Style>, // clone not satisfied
     ) -> Self {
         let date_picker::State { overlay_state } = state;
+        let translations = i18n::get();
+        let label = |label: String| labeled_buttons.then_some(label);
 
         DatePickerOverlay {
             state: overlay_state,
-            cancel_button: Button::new(
-                text::Text::new(icon_to_char(Icon::X).to_string())
-                    .font(ICON_FONT)
-                    .horizontal_alignment(Horizontal::Center)
-                    .width(Length::Fill),
-            )
-            .width(Length::Fill)
-            .on_press(on_cancel.clone()),
-            submit_button: Button::new(
-                text::Text::new(icon_to_char(Icon::Check).to_string())
-                    .font(ICON_FONT)
-                    .horizontal_alignment(Horizontal::Center)
-                    .width(Length::Fill),
-            )
-            .width(Length::Fill)
-            .on_press(on_cancel), // Sending a fake message
+            cancel_button: Button::new(button_content(Icon::X, label(translations.cancel())))
+                .width(Length::Fill)
+                .on_press(on_cancel.clone()),
+            submit_button: Button::new(button_content(Icon::Check, label(translations.submit())))
+                .width(Length::Fill)
+                .on_press(on_cancel.clone()), // Sending a fake message
+            on_cancel,
             on_submit,
+            constraints,
             position,
             style,
             tree,
+            direction: layout_direction::get(),
+            translations,
+            first_weekday,
+            week_numbers,
+            months_shown: months_shown.max(1),
+            input_format,
         }
     }
 
@@ -116,14 +156,44 @@ where
         overlay::Element::new(self.position, Box::new(self))
     }
 
-    /// String representation of the current year.
-    fn year_as_string(&self) -> String {
-        crate::core::date::year_as_string(self.state.date)
+    /// Returns a date in each of the shown months, with the date of the
+    /// [`State`] in its month.
+    fn shown_months(&self) -> Vec<NaiveDate> {
+        let offset = self.state.month_offset.min(self.months_shown - 1);
+
+        (0..self.months_shown)
+            .map(|index| {
+                crate::core::date::add_months(self.state.date, i32::from(index) - i32::from(offset))
+            })
+            .collect()
     }
 
-    /// String representation of the current month.
-    fn month_as_string(&self) -> String {
-        crate::core::date::month_as_string(self.state.date)
+    /// Returns the message submitting the picked dates, unless a single date
+    /// or a range is picked and the shown date or the first or last day of
+    /// the range can't be picked.
+    fn submit(&self) -> Option<Message> {
+        match self.on_submit {
+            OnSubmit::Single(on_submit) => self
+                .constraints
+                .allows(self.state.date)
+                .then(|| on_submit(self.state.date.into())),
+            OnSubmit::Multiple(on_submit) => Some(on_submit(
+                self.state
+                    .selection
+                    .iter()
+                    .copied()
+                    .map(Date::from)
+                    .collect(),
+            )),
+            OnSubmit::Range(on_submit) => {
+                let (start, end) = self
+                    .state
+                    .range
+                    .unwrap_or((self.state.date, self.state.date));
+                (self.constraints.allows(start) && self.constraints.allows(end))
+                    .then(|| on_submit(start.into(), end.into()))
+            }
+        }
     }
 
     /// The event handling for the month / year bar.
@@ -167,10 +237,14 @@ where
                 }
 
                 if cursor.is_over(left_bounds) {
-                    self.state.date = crate::core::date::pred_month(self.state.date);
+                    self.state.date = self
+                        .constraints
+                        .browse(crate::core::date::pred_month(self.state.date));
                     status = event::Status::Captured;
                 } else if cursor.is_over(right_bounds) {
-                    self.state.date = crate::core::date::succ_month(self.state.date);
+                    self.state.date = self
+                        .constraints
+                        .browse(crate::core::date::succ_month(self.state.date));
                     status = event::Status::Captured;
                 }
             }
@@ -204,10 +278,14 @@ where
                 }
 
                 if cursor.is_over(left_bounds) {
-                    self.state.date = crate::core::date::pred_year(self.state.date);
+                    self.state.date = self
+                        .constraints
+                        .browse(crate::core::date::pred_year(self.state.date));
                     status = event::Status::Captured;
                 } else if cursor.is_over(right_bounds) {
-                    self.state.date = crate::core::date::succ_year(self.state.date);
+                    self.state.date = self
+                        .constraints
+                        .browse(crate::core::date::succ_year(self.state.date));
                     status = event::Status::Captured;
                 }
             }
@@ -217,11 +295,14 @@ where
         status
     }
 
-    /// The event handling for the calendar days.
+    /// The event handling for the calendar days of the month of the given
+    /// date.
+    #[allow(clippy::too_many_arguments)]
     fn on_event_days(
         &mut self,
         event: &Event,
         layout: Layout<'_>,
+        month: NaiveDate,
         cursor: Cursor,
         _messages: &mut Shell<Message>,
         _renderer: &Renderer<Theme>,
@@ -243,31 +324,51 @@ where
                 }
 
                 'outer: for (y, row) in children.enumerate() {
-                    for (x, label) in row.children().enumerate() {
+                    let mut cells = row.children();
+
+                    if self.week_numbers != WeekNumbers::Hidden {
+                        let week_bounds = cells
+                            .next()
+                            .expect("Native: Layout should have a week number layout")
+                            .bounds();
+
+                        if cursor.is_over(week_bounds) {
+                            if self.week_numbers == WeekNumbers::Pickable {
+                                let monday = crate::core::date::row_to_week_start(
+                                    y,
+                                    month.year(),
+                                    month.month(),
+                                    self.first_weekday,
+                                );
+                                let previous = self.state.date;
+                                self.state
+                                    .pick_week(monday, self.on_submit, self.constraints);
+                                self.state.follow(previous, self.months_shown);
+                                status = event::Status::Captured;
+                            }
+                            break 'outer;
+                        }
+                    }
+
+                    for (x, label) in cells.enumerate() {
                         let bounds = label.bounds();
                         if cursor.is_over(bounds) {
-                            let (day, is_in_month) = crate::core::date::position_to_day(
+                            let (day, is_in_month) = crate::core::date::position_to_day_from(
                                 x,
                                 y,
-                                self.state.date.year(),
-                                self.state.date.month(),
+                                month.year(),
+                                month.month(),
+                                self.first_weekday,
                             );
 
-                            self.state.date = match is_in_month {
-                                IsInMonth::Previous => {
-                                    crate::core::date::pred_month(self.state.date)
-                                        .with_day(day as u32)
-                                        .expect("Previous month with day should be valid")
-                                }
-                                IsInMonth::Same => self
-                                    .state
-                                    .date
-                                    .with_day(day as u32)
-                                    .expect("Same month with day should be valid"),
-                                IsInMonth::Next => crate::core::date::succ_month(self.state.date)
-                                    .with_day(day as u32)
-                                    .expect("Succeeding month with day should be valid"),
-                            };
+                            let date = cell_date(month, day, &is_in_month);
+
+                            if self.constraints.allows(date) {
+                                let previous = self.state.date;
+                                self.state.date = date;
+                                self.state.follow(previous, self.months_shown);
+                                self.state.pick(date, self.on_submit);
+                            }
 
                             status = event::Status::Captured;
                             break 'outer;
@@ -287,16 +388,34 @@ where
         event: &Event,
         _layout: Layout<'_>,
         _cursor: Cursor,
-        _messages: &mut Shell<Message>,
+        messages: &mut Shell<Message>,
         _renderer: &Renderer<Theme>,
         _clipboard: &mut dyn Clipboard,
     ) -> event::Status {
-        if self.state.focus == Focus::None {
-            return event::Status::Ignored;
-        }
-
-        if let Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) = event {
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code,
+            modifiers,
+        }) = event
+        {
             let mut status = event::Status::Ignored;
+            let previous = self.state.date;
+
+            // The days are navigated by any key pressed before anything is
+            // focused, so the picker can be used without a mouse right away.
+            if self.state.focus == Focus::None && *key_code != keyboard::KeyCode::Tab {
+                self.state.focus = Focus::Day;
+            }
+
+            // The arrow keys follow the arranged cells from right to left.
+            let key_code = match key_code {
+                keyboard::KeyCode::Left if self.direction.is_right_to_left() => {
+                    &keyboard::KeyCode::Right
+                }
+                keyboard::KeyCode::Right if self.direction.is_right_to_left() => {
+                    &keyboard::KeyCode::Left
+                }
+                key_code => key_code,
+            };
 
             match key_code {
                 keyboard::KeyCode::Tab => {
@@ -306,44 +425,130 @@ where
                         self.state.focus = self.state.focus.next();
                     }
                 }
+                keyboard::KeyCode::Escape => {
+                    messages.publish(self.on_cancel.clone());
+                    status = event::Status::Captured;
+                }
+                keyboard::KeyCode::Enter if self.state.focus == Focus::Cancel => {
+                    messages.publish(self.on_cancel.clone());
+                    status = event::Status::Captured;
+                }
+                keyboard::KeyCode::Enter
+                    if matches!(self.state.focus, Focus::Day | Focus::Submit) =>
+                {
+                    if let Some(message) = self.submit() {
+                        messages.publish(message);
+                    }
+                    status = event::Status::Captured;
+                }
                 _ => match self.state.focus {
                     Focus::Month => match key_code {
                         keyboard::KeyCode::Left => {
-                            self.state.date = crate::core::date::pred_month(self.state.date);
+                            self.state.date = self
+                                .constraints
+                                .browse(crate::core::date::pred_month(self.state.date));
                             status = event::Status::Captured;
                         }
                         keyboard::KeyCode::Right => {
-                            self.state.date = crate::core::date::succ_month(self.state.date);
+                            self.state.date = self
+                                .constraints
+                                .browse(crate::core::date::succ_month(self.state.date));
                             status = event::Status::Captured;
                         }
                         _ => {}
                     },
                     Focus::Year => match key_code {
                         keyboard::KeyCode::Left => {
-                            self.state.date = crate::core::date::pred_year(self.state.date);
+                            self.state.date = self
+                                .constraints
+                                .browse(crate::core::date::pred_year(self.state.date));
                             status = event::Status::Captured;
                         }
                         keyboard::KeyCode::Right => {
-                            self.state.date = crate::core::date::succ_year(self.state.date);
+                            self.state.date = self
+                                .constraints
+                                .browse(crate::core::date::succ_year(self.state.date));
                             status = event::Status::Captured;
                         }
                         _ => {}
                     },
                     Focus::Day => match key_code {
                         keyboard::KeyCode::Left => {
-                            self.state.date = crate::core::date::pred_day(self.state.date);
+                            if let Some(date) = crate::core::date::step_to(
+                                self.state.date,
+                                crate::core::date::pred_day,
+                                |date| self.constraints.allows(date),
+                            ) {
+                                self.state.date = date;
+                            }
                             status = event::Status::Captured;
                         }
                         keyboard::KeyCode::Right => {
-                            self.state.date = crate::core::date::succ_day(self.state.date);
+                            if let Some(date) = crate::core::date::step_to(
+                                self.state.date,
+                                crate::core::date::succ_day,
+                                |date| self.constraints.allows(date),
+                            ) {
+                                self.state.date = date;
+                            }
                             status = event::Status::Captured;
                         }
                         keyboard::KeyCode::Up => {
-                            self.state.date = crate::core::date::pred_week(self.state.date);
+                            if let Some(date) = crate::core::date::step_to(
+                                self.state.date,
+                                crate::core::date::pred_week,
+                                |date| self.constraints.allows(date),
+                            ) {
+                                self.state.date = date;
+                            }
                             status = event::Status::Captured;
                         }
                         keyboard::KeyCode::Down => {
-                            self.state.date = crate::core::date::succ_week(self.state.date);
+                            if let Some(date) = crate::core::date::step_to(
+                                self.state.date,
+                                crate::core::date::succ_week,
+                                |date| self.constraints.allows(date),
+                            ) {
+                                self.state.date = date;
+                            }
+                            status = event::Status::Captured;
+                        }
+                        keyboard::KeyCode::PageUp | keyboard::KeyCode::PageDown => {
+                            let step = match (key_code, modifiers.shift()) {
+                                (keyboard::KeyCode::PageUp, false) => crate::core::date::pred_month,
+                                (keyboard::KeyCode::PageUp, true) => crate::core::date::pred_year,
+                                (_, false) => crate::core::date::succ_month,
+                                (_, true) => crate::core::date::succ_year,
+                            };
+                            self.state.date = self.constraints.browse(step(self.state.date));
+                            status = event::Status::Captured;
+                        }
+                        keyboard::KeyCode::Home | keyboard::KeyCode::End => {
+                            // The first or the last day of the shown week
+                            // that can be picked.
+                            let start = crate::core::date::week_start_from(
+                                self.state.date,
+                                self.first_weekday,
+                            );
+                            let mut week = (0..7)
+                                .map(|day| start + Duration::days(day))
+                                .filter(|&date| self.constraints.allows(date));
+
+                            let date = if *key_code == keyboard::KeyCode::Home {
+                                week.next()
+                            } else {
+                                week.next_back()
+                            };
+                            if let Some(date) = date {
+                                self.state.date = date;
+                            }
+                            status = event::Status::Captured;
+                        }
+                        keyboard::KeyCode::Space
+                            if !matches!(self.on_submit, OnSubmit::Single(_))
+                                && self.constraints.allows(self.state.date) =>
+                        {
+                            self.state.pick(self.state.date, self.on_submit);
                             status = event::Status::Captured;
                         }
                         _ => {}
@@ -352,6 +557,12 @@ where
                 },
             }
 
+            // The shown months only scroll when the days are navigated out
+            // of them.
+            if self.state.focus == Focus::Day {
+                self.state.follow(previous, self.months_shown);
+            }
+
             status
         } else if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
             self.state.keyboard_modifiers = *modifiers;
@@ -360,142 +571,145 @@ where
             event::Status::Ignored
         }
     }
+
+    /// The event handling for the text field.
+    ///
+    /// The calendar jumps to a typed date that can be picked, and `Enter`
+    /// submits it.
+    #[allow(clippy::too_many_arguments)]
+    fn on_event_input(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        format: &str,
+        cursor: Cursor,
+        messages: &mut Shell<Message>,
+        renderer: &Renderer<Theme>,
+        clipboard: &mut dyn Clipboard,
+    ) -> event::Status {
+        let mut input_messages = Vec::new();
+        let mut input_shell = Shell::new(&mut input_messages);
+        let mut value = text_input::Value::new(&self.state.input_text(format));
+
+        let status = text_input::update(
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut input_shell,
+            &mut value,
+            None,
+            LineHeight::default(),
+            None,
+            false,
+            Some(&InputMessage::Changed),
+            None,
+            &Some(InputMessage::Submitted),
+            || &mut self.state.text_input,
+        );
+
+        // The blinking cursor keeps redrawing.
+        if let Some(request) = input_shell.redraw_request() {
+            messages.request_redraw(request);
+        }
+
+        for message in input_messages {
+            match message {
+                InputMessage::Changed(text) => {
+                    match crate::core::date::parse(&text, format)
+                        .filter(|&date| self.constraints.allows(date))
+                    {
+                        Some(date) => {
+                            let previous = self.state.date;
+                            self.state.date = date;
+                            self.state.follow(previous, self.months_shown);
+                            self.state.input_error = false;
+                        }
+                        None => self.state.input_error = !text.trim().is_empty(),
+                    }
+                    self.state.input = Some(text);
+                }
+                InputMessage::Submitted => {
+                    if !self.state.input_error {
+                        if let Some(message) = self.submit() {
+                            messages.publish(message);
+                        }
+                    }
+                }
+            }
+        }
+
+        status
+    }
 }
 
 impl<'a, Message, Theme> Overlay<Message, Renderer<Theme>> for DatePickerOverlay<'a, Message, Theme>
 where
     Message: 'static + Clone,
-    Theme: 'a + StyleSheet + button::StyleSheet + text::StyleSheet + container::StyleSheet,
+    Theme: 'a
+        + StyleSheet
+        + button::StyleSheet
+        + text::StyleSheet
+        + container::StyleSheet
+        + text_input::StyleSheet,
 {
     #[allow(clippy::too_many_lines)]
     fn layout(&self, renderer: &Renderer<Theme>, bounds: Size, position: Point) -> Node {
+        let months_shown = f32::from(self.months_shown);
         let limits = Limits::new(Size::ZERO, bounds)
             .pad(Padding::from(PADDING))
             .width(Length::Fill)
             .height(Length::Fill)
-            .max_width(300.0)
-            .max_height(300.0);
+            .max_width(300.0 * months_shown + SPACING * (months_shown - 1.0));
+
+        // The text field is shown above the calendars.
+        let input = self.input_format.map(|_| input_layout(renderer, &limits));
+        let input_height = input
+            .as_ref()
+            .map_or(0.0, |input| input.bounds().height + SPACING);
+
+        let limits = limits.max_height(300.0 + input_height);
 
         // Pre-Buttons TODO: get rid of it
         let cancel_limits = limits;
         let cancel_button = self.cancel_button.layout(renderer, &cancel_limits);
 
-        let limits = limits.shrink(Size::new(0.0, cancel_button.bounds().height + SPACING));
-
-        // Month/Year
-        let font_size = renderer.default_size();
-
-        let month_year = Row::<(), Renderer<Theme>>::new()
-            .width(Length::Fill)
-            .spacing(SPACING)
-            .push(
-                Row::new()
-                    .width(Length::Fill)
-                    .push(
-                        Container::new(
-                            Row::new() // Left Month arrow
-                                .width(Length::Shrink)
-                                .push(
-                                    Text::new(char::from(Icon::CaretLeftFill).to_string())
-                                        .size(font_size + 1.0)
-                                        .font(ICON_FONT),
-                                ),
-                        )
-                        .height(Length::Shrink),
-                    )
-                    .push(
-                        // Month
-                        Text::new("October")
-                            .width(Length::Fill)
-                            .height(Length::Shrink),
-                    )
-                    .push(
-                        // Right Month arrow
-                        Container::new(
-                            Text::new(char::from(Icon::CaretRightFill).to_string())
-                                .size(font_size + 1.0)
-                                .font(ICON_FONT),
-                        )
-                        .height(Length::Shrink)
-                        .width(Length::Shrink),
-                    ),
-            )
-            .push(
-                Row::new()
-                    .width(Length::Fill)
-                    .push(
-                        Container::new(
-                            Row::new() // Left Year arrow
-                                .width(Length::Shrink)
-                                .push(
-                                    Text::new(char::from(Icon::CaretLeftFill).to_string())
-                                        .size(font_size + 1.0)
-                                        .font(ICON_FONT),
-                                ),
-                        )
-                        .height(Length::Shrink)
-                        .width(Length::Shrink),
-                    )
-                    .push(
-                        // Year
-                        Text::new("9999").width(Length::Fill).height(Length::Shrink),
-                    )
-                    .push(
-                        // Right Year arrow
-                        Container::new(
-                            Row::new()
-                                .width(Length::Shrink)
-                                .height(Length::Shrink)
-                                .push(
-                                    Text::new(char::from(Icon::CaretRightFill).to_string())
-                                        .size(font_size + 1.0)
-                                        .font(ICON_FONT),
-                                ),
-                        )
-                        .height(Length::Shrink)
-                        .width(Length::Shrink),
-                    ),
-            );
+        let limits = limits.shrink(Size::new(
+            0.0,
+            cancel_button.bounds().height + SPACING + input_height,
+        ));
 
-        let days = Container::<(), Renderer<Theme>>::new((0..7).fold(
-            Column::new().width(Length::Fill).height(Length::Fill),
-            |column, _y| {
-                column.push(
-                    (0..7).fold(
-                        Row::new()
-                            .height(Length::Fill)
-                            .width(Length::Fill)
-                            .padding(DAY_CELL_PADDING),
-                        |row, _x| {
-                            row.push(
-                                Container::new(
-                                    Row::new()
-                                        .width(Length::Shrink)
-                                        .height(Length::Shrink)
-                                        .push(Text::new("31").size(font_size)),
-                                )
-                                .width(Length::Fill)
-                                .height(Length::Shrink),
-                            )
-                        },
-                    ),
-                )
-            },
-        ))
-        .width(Length::Fill)
-        .height(Length::Fill)
-        .center_y();
+        // The months share the width side by side.
+        let calendar_width =
+            ((limits.max().width - SPACING * (months_shown - 1.0)) / months_shown).min(300.0);
+        let calendar = calendar(
+            renderer,
+            &limits.max_width(calendar_width),
+            self.week_numbers,
+        );
+        let calendar_size = calendar.bounds().size();
 
-        let mut col = Column::<(), Renderer<Theme>>::new()
-            .spacing(SPACING)
-            .align_items(Alignment::Center)
-            .push(month_year)
-            .push(days)
-            .layout(renderer, &limits);
+        let mut col = Node::with_children(
+            Size::new(
+                calendar_size.width * months_shown + SPACING * (months_shown - 1.0),
+                calendar_size.height,
+            ),
+            (0..self.months_shown)
+                .map(|index| {
+                    let mut calendar = calendar.clone();
+                    calendar.move_to(Point::new(
+                        f32::from(index) * (calendar_size.width + SPACING),
+                        0.0,
+                    ));
+                    calendar
+                })
+                .collect(),
+        );
 
         col.move_to(Point::new(
             col.bounds().x + PADDING,
-            col.bounds().y + PADDING,
+            col.bounds().y + PADDING + input_height,
         ));
 
         // Buttons
@@ -511,23 +725,39 @@ where
 
         cancel_button.move_to(Point {
             x: cancel_button.bounds().x + PADDING,
-            y: cancel_button.bounds().y + col.bounds().height + PADDING + SPACING,
+            y: cancel_button.bounds().y + col.bounds().height + PADDING + SPACING + input_height,
         });
 
         submit_button.move_to(Point {
             x: submit_button.bounds().x + col.bounds().width - submit_button.bounds().width
                 + PADDING,
-            y: submit_button.bounds().y + col.bounds().height + PADDING + SPACING,
+            y: submit_button.bounds().y + col.bounds().height + PADDING + SPACING + input_height,
         });
 
-        let mut node = Node::with_children(
-            Size::new(
-                col.bounds().width + (2.0 * PADDING),
-                col.bounds().height + cancel_button.bounds().height + (2.0 * PADDING) + SPACING,
-            ),
-            vec![col, cancel_button, submit_button],
+        let size = Size::new(
+            col.bounds().width + (2.0 * PADDING),
+            col.bounds().height
+                + cancel_button.bounds().height
+                + (2.0 * PADDING)
+                + SPACING
+                + input_height,
         );
 
+        // The text field spans the calendars.
+        let input = input.map(|_| {
+            let mut input = input_layout(renderer, &limits.max_width(col.bounds().width));
+            input.move_to(Point::new(PADDING, PADDING));
+            input
+        });
+
+        let mut node = self.direction.arrange_deep(Node::with_children(
+            size,
+            [col, cancel_button, submit_button]
+                .into_iter()
+                .chain(input)
+                .collect(),
+        ));
+
         node.center_and_bounce(position, bounds);
 
         node
@@ -542,41 +772,75 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<Message>,
     ) -> event::Status {
-        if event::Status::Captured
-            == self.on_event_keyboard(&event, layout, cursor, shell, renderer, clipboard)
+        // ----------- Text field ----------------------
+        if let (Some(format), Some(input_layout)) = (self.input_format, layout.children().nth(3)) {
+            if event::Status::Captured
+                == self.on_event_input(
+                    &event,
+                    input_layout,
+                    format,
+                    cursor,
+                    shell,
+                    renderer,
+                    clipboard,
+                )
+            {
+                return event::Status::Captured;
+            }
+        }
+
+        let date = self.state.date;
+
+        // The keys typed into the text field don't navigate the calendar.
+        if !self.state.text_input.is_focused()
+            && event::Status::Captured
+                == self.on_event_keyboard(&event, layout, cursor, shell, renderer, clipboard)
         {
+            self.state.discard_input(date);
             return event::Status::Captured;
         }
 
         let mut children = layout.children();
 
-        let mut date_children = children
+        let calendars_layout = children
             .next()
-            .expect("Native: Layout should have date children")
-            .children();
+            .expect("Native: Layout should have a calendars layout");
+        let mut month_year_status = event::Status::Ignored;
+        let mut days_status = event::Status::Ignored;
 
-        // ----------- Year/Month----------------------
-        let month_year_layout = date_children
-            .next()
-            .expect("Native: Layout should have a month/year layout");
-        let month_year_status = self.on_event_month_year(
-            &event,
-            month_year_layout,
-            cursor,
-            shell,
-            renderer,
-            clipboard,
-        );
+        for (calendar_layout, month) in calendars_layout.children().zip(self.shown_months()) {
+            let mut date_children = calendar_layout.children();
 
-        // ----------- Days ----------------------
-        let days_layout = date_children
-            .next()
-            .expect("Native: Layout should have a days table parent")
-            .children()
-            .next()
-            .expect("Native: Layout should have a days table layout");
-        let days_status =
-            self.on_event_days(&event, days_layout, cursor, shell, renderer, clipboard);
+            // ----------- Year/Month----------------------
+            let month_year_layout = date_children
+                .next()
+                .expect("Native: Layout should have a month/year layout");
+            month_year_status = month_year_status.merge(self.on_event_month_year(
+                &event,
+                month_year_layout,
+                cursor,
+                shell,
+                renderer,
+                clipboard,
+            ));
+
+            // ----------- Days ----------------------
+            let days_layout = date_children
+                .next()
+                .expect("Native: Layout should have a days table parent")
+                .children()
+                .next()
+                .expect("Native: Layout should have a days table layout");
+            days_status = days_status.merge(self.on_event_days(
+                &event,
+                days_layout,
+                month,
+                cursor,
+                shell,
+                renderer,
+                clipboard,
+            ));
+        }
 
         // ----------- Buttons ------------------------
         let cancel_button_layout = children
@@ -611,10 +875,15 @@ where
             &layout.bounds(),
         );
 
+        // A disabled date shown after browsing the months can't be submitted.
         if !fake_messages.is_empty() {
-            shell.publish((self.on_submit)(self.state.date.into()));
+            if let Some(message) = self.submit() {
+                shell.publish(message);
+            }
         }
 
+        self.state.discard_input(date);
+
         month_year_status
             .merge(days_status)
             .merge(cancel_status)
@@ -628,26 +897,14 @@ where
         viewport: &Rectangle,
         renderer: &Renderer<Theme>,
     ) -> mouse::Interaction {
-        let mouse_interaction = mouse::Interaction::default();
+        let mut mouse_interaction = mouse::Interaction::default();
 
         let mut children = layout.children();
-        let mut date_children = children
-            .next()
-            .expect("Graphics: Layout should have a date layout")
-            .children();
-
-        // Month and year mouse interaction
-        let month_year_layout = date_children
-            .next()
-            .expect("Graphics: Layout should have a month/year layout");
-        let mut month_year_children = month_year_layout.children();
-        let month_layout = month_year_children
-            .next()
-            .expect("Graphics: Layout should have a month layout");
-        let year_layout = month_year_children
+        let calendars_layout = children
             .next()
-            .expect("Graphics: Layout should have a year layout");
+            .expect("Graphics: Layout should have a calendars layout");
 
+        // The arrows of the month and year bars and the days are clickable.
         let f = |layout: Layout<'_>| {
             let mut children = layout.children();
 
@@ -661,41 +918,49 @@ where
                 .expect("Graphics: Layout should have a right arrow layout")
                 .bounds();
 
-            let mut mouse_interaction = mouse::Interaction::default();
-
-            let left_arrow_hovered = cursor.is_over(left_bounds);
-            let right_arrow_hovered = cursor.is_over(right_bounds);
-
-            if left_arrow_hovered || right_arrow_hovered {
-                mouse_interaction = mouse_interaction.max(mouse::Interaction::Pointer);
+            if cursor.is_over(left_bounds) || cursor.is_over(right_bounds) {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
             }
-
-            mouse_interaction
         };
 
-        let month_mouse_interaction = f(month_layout);
-        let year_mouse_interaction = f(year_layout);
+        for calendar_layout in calendars_layout.children() {
+            let mut date_children = calendar_layout.children();
 
-        // Days
-        let days_layout = date_children
-            .next()
-            .expect("Graphics: Layout should have a days layout parent")
-            .children()
-            .next()
-            .expect("Graphics: Layout should have a days layout");
-        let mut days_children = days_layout.children();
-        let _day_labels_layout = days_children.next();
+            // Month and year mouse interaction
+            let month_year_layout = date_children
+                .next()
+                .expect("Graphics: Layout should have a month/year layout");
+            let mut month_year_children = month_year_layout.children();
+            let month_layout = month_year_children
+                .next()
+                .expect("Graphics: Layout should have a month layout");
+            let year_layout = month_year_children
+                .next()
+                .expect("Graphics: Layout should have a year layout");
 
-        let mut table_mouse_interaction = mouse::Interaction::default();
+            mouse_interaction = mouse_interaction.max(f(month_layout)).max(f(year_layout));
 
-        for row in days_children {
-            for label in row.children() {
-                let bounds = label.bounds();
+            // Days
+            let days_layout = date_children
+                .next()
+                .expect("Graphics: Layout should have a days layout parent")
+                .children()
+                .next()
+                .expect("Graphics: Layout should have a days layout");
+            let mut days_children = days_layout.children();
+            let _day_labels_layout = days_children.next();
+
+            for row in days_children {
+                let mut cells = row.children();
+                // Only the week numbers that can be picked are clickable.
+                if self.week_numbers == WeekNumbers::Shown {
+                    let _week_layout = cells.next();
+                }
 
-                let mouse_over = cursor.is_over(bounds);
-                if mouse_over {
-                    table_mouse_interaction =
-                        table_mouse_interaction.max(mouse::Interaction::Pointer);
+                if cells.any(|label| cursor.is_over(label.bounds())) {
+                    mouse_interaction = mouse_interaction.max(mouse::Interaction::Pointer);
                 }
             }
         }
@@ -725,12 +990,17 @@ where
             renderer,
         );
 
+        // Text field
+        let input_mouse_interaction = children
+            .next()
+            .map_or_else(mouse::Interaction::default, |input_layout| {
+                text_input::mouse_interaction(input_layout, cursor, false)
+            });
+
         mouse_interaction
-            .max(month_mouse_interaction)
-            .max(year_mouse_interaction)
-            .max(table_mouse_interaction)
             .max(cancel_button_mouse_interaction)
             .max(submit_button_mouse_interaction)
+            .max(input_mouse_interaction)
     }
 
     fn draw(
@@ -743,10 +1013,9 @@ where
     ) {
         let bounds = layout.bounds();
         let mut children = layout.children();
-        let mut date_children = children
+        let calendars_layout = children
             .next()
-            .expect("Graphics: Layout should have a date layout")
-            .children();
+            .expect("Graphics: Layout should have a calendars layout");
 
         let mut style_sheet: HashMap<StyleState, Appearance> = HashMap::new();
         let _ = style_sheet.insert(StyleState::Active, StyleSheet::active(theme, &self.style));
@@ -756,6 +1025,17 @@ where
         );
         let _ = style_sheet.insert(StyleState::Hovered, StyleSheet::hovered(theme, &self.style));
         let _ = style_sheet.insert(StyleState::Focused, StyleSheet::focused(theme, &self.style));
+        let weekdays: Vec<String> = (0..7)
+            .map(|offset| {
+                self.translations
+                    .weekday((self.first_weekday.num_days_from_monday() + offset) % 7)
+            })
+            .collect();
+        let week_label = self.translations.week();
+        let day_appearances = DayAppearances {
+            in_range: StyleSheet::in_range(theme, &self.style),
+            disabled: StyleSheet::disabled(theme, &self.style),
+        };
 
         let mut style_state = StyleState::Active;
         if self.state.focus == Focus::Overlay {
@@ -776,37 +1056,61 @@ where
             style_sheet[&style_state].background,
         );
 
-        // ----------- Year/Month----------------------
-        let month_year_layout = date_children
-            .next()
-            .expect("Graphics: Layout should have a month/year layout");
-
-        month_year(
-            renderer,
-            month_year_layout,
-            &self.month_as_string(),
-            &self.year_as_string(),
-            cursor.position().unwrap_or_default(),
-            &style_sheet,
-            self.state.focus,
-        );
+        let selection = match self.on_submit {
+            OnSubmit::Single(_) => Selection::Single(self.state.date),
+            OnSubmit::Multiple(_) => Selection::Multiple(&self.state.selection),
+            OnSubmit::Range(_) => Selection::Range(self.state.range),
+        };
 
-        // ----------- Days ---------------------------
-        let days_layout = date_children
-            .next()
-            .expect("Graphics: Layout should have a days layout parent")
-            .children()
-            .next()
-            .expect("Graphics: Layout should have a days layout");
+        for (calendar_layout, month) in calendars_layout.children().zip(self.shown_months()) {
+            let mut date_children = calendar_layout.children();
+            // Only the month of the date shows the focus.
+            let focus = if month == self.state.date {
+                self.state.focus
+            } else {
+                Focus::None
+            };
+
+            // ----------- Year/Month----------------------
+            let month_year_layout = date_children
+                .next()
+                .expect("Graphics: Layout should have a month/year layout");
+
+            month_year(
+                renderer,
+                month_year_layout,
+                &self.translations.month(month.month()),
+                &crate::core::date::year_as_string(month),
+                cursor.position().unwrap_or_default(),
+                &style_sheet,
+                focus,
+                self.direction,
+            );
 
-        days(
-            renderer,
-            days_layout,
-            self.state.date,
-            cursor.position().unwrap_or_default(),
-            &style_sheet,
-            self.state.focus,
-        );
+            // ----------- Days ---------------------------
+            let days_layout = date_children
+                .next()
+                .expect("Graphics: Layout should have a days layout parent")
+                .children()
+                .next()
+                .expect("Graphics: Layout should have a days layout");
+
+            days(
+                renderer,
+                days_layout,
+                month,
+                self.first_weekday,
+                selection,
+                self.constraints,
+                cursor.position().unwrap_or_default(),
+                &style_sheet,
+                &day_appearances,
+                &weekdays,
+                self.week_numbers,
+                &week_label,
+                focus,
+            );
+        }
 
         // ----------- Buttons ------------------------
         let cancel_button_layout = children
@@ -861,9 +1165,190 @@ where
                 Color::TRANSPARENT,
             );
         }
+
+        // ----------- Text field ---------------------
+        if let (Some(format), Some(input_layout)) = (self.input_format, children.next()) {
+            let input_style = <Theme as text_input::StyleSheet>::Style::default();
+
+            text_input::draw(
+                renderer,
+                theme,
+                input_layout,
+                cursor,
+                &self.state.text_input,
+                &text_input::Value::new(&self.state.input_text(format)),
+                &crate::core::date::format(self.state.date, format),
+                None,
+                LineHeight::default(),
+                None,
+                false,
+                false,
+                None,
+                &input_style,
+            );
+
+            // The text field is outlined while its text can't be picked.
+            if self.state.input_error {
+                let invalid = StyleSheet::invalid(theme, &self.style);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: input_layout.bounds(),
+                        border_radius: text_input::StyleSheet::active(theme, &input_style)
+                            .border_radius,
+                        border_width: invalid.border_width.max(1.0),
+                        border_color: invalid.border_color,
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+        }
     }
 }
 
+/// Lays out the text field of a [`DatePickerOverlay`] within the given limits.
+fn input_layout<Theme>(renderer: &Renderer<Theme>, limits: &Limits) -> Node {
+    text_input::layout(
+        renderer,
+        limits,
+        Length::Fill,
+        Padding::from(5.0),
+        None,
+        LineHeight::default(),
+        None,
+    )
+}
+
+/// Lays out the month and year bar and the days of the calendar of a
+/// [`DatePickerOverlay`] or a [`Calendar`](crate::native::Calendar) within
+/// the given limits.
+pub(crate) fn calendar<Theme>(
+    renderer: &Renderer<Theme>,
+    limits: &Limits,
+    week_numbers: WeekNumbers,
+) -> Node
+where
+    Theme: StyleSheet + button::StyleSheet + text::StyleSheet + container::StyleSheet,
+{
+    // Month/Year
+    let font_size = renderer.default_size();
+
+    let month_year = Row::<(), Renderer<Theme>>::new()
+        .width(Length::Fill)
+        .spacing(SPACING)
+        .push(
+            Row::new()
+                .width(Length::Fill)
+                .push(
+                    Container::new(
+                        Row::new() // Left Month arrow
+                            .width(Length::Shrink)
+                            .push(
+                                Text::new(char::from(Icon::CaretLeftFill).to_string())
+                                    .size(font_size + 1.0)
+                                    .font(ICON_FONT),
+                            ),
+                    )
+                    .height(Length::Shrink),
+                )
+                .push(
+                    // Month
+                    Text::new("October")
+                        .width(Length::Fill)
+                        .height(Length::Shrink),
+                )
+                .push(
+                    // Right Month arrow
+                    Container::new(
+                        Text::new(char::from(Icon::CaretRightFill).to_string())
+                            .size(font_size + 1.0)
+                            .font(ICON_FONT),
+                    )
+                    .height(Length::Shrink)
+                    .width(Length::Shrink),
+                ),
+        )
+        .push(
+            Row::new()
+                .width(Length::Fill)
+                .push(
+                    Container::new(
+                        Row::new() // Left Year arrow
+                            .width(Length::Shrink)
+                            .push(
+                                Text::new(char::from(Icon::CaretLeftFill).to_string())
+                                    .size(font_size + 1.0)
+                                    .font(ICON_FONT),
+                            ),
+                    )
+                    .height(Length::Shrink)
+                    .width(Length::Shrink),
+                )
+                .push(
+                    // Year
+                    Text::new("9999").width(Length::Fill).height(Length::Shrink),
+                )
+                .push(
+                    // Right Year arrow
+                    Container::new(
+                        Row::new()
+                            .width(Length::Shrink)
+                            .height(Length::Shrink)
+                            .push(
+                                Text::new(char::from(Icon::CaretRightFill).to_string())
+                                    .size(font_size + 1.0)
+                                    .font(ICON_FONT),
+                            ),
+                    )
+                    .height(Length::Shrink)
+                    .width(Length::Shrink),
+                ),
+        );
+
+    let cell = || {
+        Container::new(
+            Row::new()
+                .width(Length::Shrink)
+                .height(Length::Shrink)
+                .push(Text::new("31").size(font_size)),
+        )
+        .width(Length::Fill)
+        .height(Length::Shrink)
+    };
+
+    // The week numbers take an extra column before the days.
+    let columns = if week_numbers == WeekNumbers::Hidden {
+        7
+    } else {
+        8
+    };
+
+    let days = Container::<(), Renderer<Theme>>::new((0..7).fold(
+        Column::new().width(Length::Fill).height(Length::Fill),
+        |column, _y| {
+            column.push(
+                (0..columns).fold(
+                    Row::new()
+                        .height(Length::Fill)
+                        .width(Length::Fill)
+                        .padding(DAY_CELL_PADDING),
+                    |row, _x| row.push(cell()),
+                ),
+            )
+        },
+    ))
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .center_y();
+
+    Column::<(), Renderer<Theme>>::new()
+        .spacing(SPACING)
+        .align_items(Alignment::Center)
+        .push(month_year)
+        .push(days)
+        .layout(renderer, limits)
+}
+
 /// The state of the [`DatePickerOverlay`].
 #[derive(Debug)]
 pub struct State {
@@ -873,6 +1358,22 @@ pub struct State {
     pub(crate) focus: Focus,
     /// The previously pressed keyboard modifiers.
     pub(crate) keyboard_modifiers: keyboard::Modifiers,
+    /// The toggled dates if multiple dates can be picked.
+    pub(crate) selection: BTreeSet<NaiveDate>,
+    /// The first and the last picked date if a range of dates is picked.
+    pub(crate) range: Option<(NaiveDate, NaiveDate)>,
+    /// The first picked date of a range whose last date is not picked yet.
+    pub(crate) range_anchor: Option<NaiveDate>,
+    /// The index of the month of the date among the months shown side by
+    /// side.
+    pub(crate) month_offset: u8,
+    /// The text typed into the text field, until the date is changed
+    /// otherwise.
+    pub(crate) input: Option<String>,
+    /// Whether the typed text is not a date that can be picked.
+    pub(crate) input_error: bool,
+    /// The state of the text field.
+    pub(crate) text_input: text_input::State,
 }
 
 impl State {
@@ -884,6 +1385,104 @@ impl State {
             ..Self::default()
         }
     }
+
+    /// Picks the days of the ISO 8601 week starting on the given Monday that
+    /// can be picked, in the way of the given [`OnSubmit`].
+    ///
+    /// The date moves to the first of these days. They are all toggled on, or
+    /// off if they are all picked already, if multiple dates can be picked,
+    /// and they make up the range if a range of dates is picked.
+    fn pick_week<Message>(
+        &mut self,
+        monday: NaiveDate,
+        on_submit: &OnSubmit<Message>,
+        constraints: Constraints<'_>,
+    ) {
+        let days: Vec<NaiveDate> = monday
+            .iter_days()
+            .take(7)
+            .filter(|&date| constraints.allows(date))
+            .collect();
+        let (Some(&first), Some(&last)) = (days.first(), days.last()) else {
+            return;
+        };
+
+        self.date = first;
+        match on_submit {
+            OnSubmit::Single(_) => {}
+            OnSubmit::Multiple(_) => {
+                if days.iter().all(|date| self.selection.contains(date)) {
+                    for date in &days {
+                        let _ = self.selection.remove(date);
+                    }
+                } else {
+                    self.selection.extend(days);
+                }
+            }
+            OnSubmit::Range(_) => {
+                self.range = Some((first, last));
+                self.range_anchor = None;
+            }
+        }
+    }
+
+    /// Keeps showing the same months after the date moved from the given
+    /// previous date, unless it moved out of them.
+    fn follow(&mut self, previous: NaiveDate, months_shown: u8) {
+        let offset =
+            i32::from(self.month_offset) + crate::core::date::months_between(previous, self.date);
+
+        self.month_offset = u8::try_from(offset.clamp(0, i32::from(months_shown) - 1))
+            .expect("The offset should be clamped to the shown months");
+    }
+
+    /// Returns the text shown in the text field, which is the typed text or
+    /// else the date in the given format.
+    fn input_text(&self, format: &str) -> String {
+        self.input
+            .clone()
+            .unwrap_or_else(|| crate::core::date::format(self.date, format))
+    }
+
+    /// Discards the typed text after the date moved away from the given
+    /// previous date by other means.
+    fn discard_input(&mut self, previous: NaiveDate) {
+        if self.date != previous {
+            self.input = None;
+            self.input_error = false;
+        }
+    }
+
+    /// Discards the typed text and unfocuses the text field.
+    pub(crate) fn reset_input(&mut self) {
+        self.input = None;
+        self.input_error = false;
+        self.text_input.unfocus();
+    }
+
+    /// Picks the given date in the way of the given [`OnSubmit`].
+    ///
+    /// A date is toggled on or off if multiple dates can be picked. Every
+    /// other picked date of a range starts a new range, and the ones in
+    /// between end it.
+    fn pick<Message>(&mut self, date: NaiveDate, on_submit: &OnSubmit<Message>) {
+        match on_submit {
+            OnSubmit::Single(_) => {}
+            OnSubmit::Multiple(_) => {
+                if !self.selection.remove(&date) {
+                    let _ = self.selection.insert(date);
+                }
+            }
+            OnSubmit::Range(_) => {
+                if let Some(anchor) = self.range_anchor.take() {
+                    self.range = Some((anchor.min(date), anchor.max(date)));
+                } else {
+                    self.range = Some((date, date));
+                    self.range_anchor = Some(date);
+                }
+            }
+        }
+    }
 }
 
 impl Default for State {
@@ -892,10 +1491,26 @@ impl Default for State {
             date: Local::now().naive_local().date(),
             focus: Focus::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            selection: BTreeSet::new(),
+            range: None,
+            range_anchor: None,
+            month_offset: 0,
+            input: None,
+            input_error: false,
+            text_input: text_input::State::default(),
         }
     }
 }
 
+/// The messages of the text field of the [`DatePickerOverlay`].
+#[derive(Clone, Debug)]
+enum InputMessage {
+    /// The text was changed.
+    Changed(String),
+    /// The text was submitted.
+    Submitted,
+}
+
 /// Just a workaround to pass the button states from the tree to the overlay
 #[allow(missing_debug_implementations)]
 pub struct DatePickerOverlayButtons<'a, Message, Theme>
@@ -909,6 +1524,21 @@ where
     submit_button: Element<'a, Message, Renderer<Theme>>,
 }
 
+/// Returns the content of a button of the [`DatePickerOverlay`], which is
+/// either its label or its icon.
+fn button_content<'a, Theme>(icon: Icon, label: Option<String>) -> Text<'a, Renderer<Theme>>
+where
+    Theme: text::StyleSheet,
+{
+    label
+        .map_or_else(
+            || Text::new(icon_to_char(icon).to_string()).font(ICON_FONT),
+            Text::new,
+        )
+        .horizontal_alignment(Horizontal::Center)
+        .width(Length::Fill)
+}
+
 impl<'a, Message, Theme> Default for DatePickerOverlayButtons<'a, Message, Theme>
 where
     Message: 'a + Clone,
@@ -916,20 +1546,8 @@ where
 {
     fn default() -> Self {
         Self {
-            cancel_button: Button::new(
-                text::Text::new(icon_to_char(Icon::X).to_string())
-                    .font(ICON_FONT)
-                    .horizontal_alignment(Horizontal::Center)
-                    .width(Length::Fill),
-            )
-            .into(),
-            submit_button: Button::new(
-                text::Text::new(icon_to_char(Icon::Check).to_string())
-                    .font(ICON_FONT)
-                    .horizontal_alignment(Horizontal::Center)
-                    .width(Length::Fill),
-            )
-            .into(),
+            cancel_button: Button::new(button_content(Icon::X, None)).into(),
+            submit_button: Button::new(button_content(Icon::Check, None)).into(),
         }
     }
 }
@@ -1050,7 +1668,8 @@ impl Default for Focus {
 }
 
 /// Draws the month/year row
-fn month_year<Theme>(
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn month_year<Theme>(
     renderer: &mut Renderer<Theme>,
     layout: Layout<'_>,
     month: &str,
@@ -1059,11 +1678,20 @@ fn month_year<Theme>(
     //style: &Style,
     style: &HashMap<StyleState, Appearance>,
     focus: Focus,
+    direction: LayoutDirection,
 ) where
     Theme: StyleSheet + button::StyleSheet + container::StyleSheet + text::StyleSheet,
 {
     let mut children = layout.children();
 
+    // The arrows to the previous month and year are on the right from right
+    // to left, pointing to the right.
+    let (previous_icon, next_icon) = if direction.is_right_to_left() {
+        (Icon::CaretRightFill, Icon::CaretLeftFill)
+    } else {
+        (Icon::CaretLeftFill, Icon::CaretRightFill)
+    };
+
     let month_layout = children
         .next()
         .expect("Graphics: Layout should have a month layout");
@@ -1123,9 +1751,9 @@ fn month_year<Theme>(
 
         let mut buffer = [0; 4];
 
-        // Left caret
+        // Previous caret
         renderer.fill_text(core::text::Text {
-            content: char::from(Icon::CaretLeftFill).encode_utf8(&mut buffer),
+            content: char::from(previous_icon).encode_utf8(&mut buffer),
             bounds: Rectangle {
                 x: left_bounds.center_x(),
                 y: left_bounds.center_y(),
@@ -1163,9 +1791,9 @@ fn month_year<Theme>(
             shaping: text::Shaping::Basic,
         });
 
-        // Right caret
+        // Next caret
         renderer.fill_text(core::text::Text {
-            content: char::from(Icon::CaretRightFill).encode_utf8(&mut buffer),
+            content: char::from(next_icon).encode_utf8(&mut buffer),
             bounds: Rectangle {
                 x: right_bounds.center_x(),
                 y: right_bounds.center_y(),
@@ -1191,14 +1819,106 @@ fn month_year<Theme>(
     f(year_layout, year, Focus::Year);
 }
 
+/// Calculates the date of a day cell of the calendar showing the month of the given date.
+pub(crate) fn cell_date(date: NaiveDate, day: usize, is_in_month: &IsInMonth) -> NaiveDate {
+    match is_in_month {
+        IsInMonth::Previous => crate::core::date::pred_month(date)
+            .with_day(day as u32)
+            .expect("Previous month with day should be valid"),
+        IsInMonth::Same => date
+            .with_day(day as u32)
+            .expect("Same month with day should be valid"),
+        IsInMonth::Next => crate::core::date::succ_month(date)
+            .with_day(day as u32)
+            .expect("Succeeding month with day should be valid"),
+    }
+}
+
+/// The picked dates highlighted in the calendar.
+#[derive(Clone, Copy)]
+pub(crate) enum Selection<'a> {
+    /// The given date is picked.
+    Single(NaiveDate),
+    /// The toggled dates are picked.
+    Multiple(&'a BTreeSet<NaiveDate>),
+    /// The first and the last date of a range are picked.
+    Range(Option<(NaiveDate, NaiveDate)>),
+}
+
+/// The appearances of the days in the calendar besides the ones of their
+/// [`StyleState`].
+pub(crate) struct DayAppearances {
+    /// The appearance of the days between the first and the last day of a
+    /// range.
+    pub(crate) in_range: Appearance,
+    /// The appearance of the days that can't be picked.
+    pub(crate) disabled: Appearance,
+}
+
+/// Whether the ISO 8601 week numbers are shown in a column before the days of
+/// the calendar of a [`DatePickerOverlay`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WeekNumbers {
+    /// The week numbers are hidden.
+    #[default]
+    Hidden,
+    /// The week numbers are shown.
+    Shown,
+    /// The week numbers are shown, and clicking one picks the days of its
+    /// week.
+    Pickable,
+}
+
+/// The dates that can be picked in a [`DatePickerOverlay`].
+#[derive(Clone, Copy)]
+#[allow(missing_debug_implementations)]
+pub struct Constraints<'a> {
+    /// The earliest date that can be picked.
+    pub min: Option<NaiveDate>,
+    /// The latest date that can be picked.
+    pub max: Option<NaiveDate>,
+    /// The function disabling the dates that can't be picked.
+    pub disable_if: Option<&'a dyn Fn(Date) -> bool>,
+}
+
+impl Constraints<'_> {
+    /// Returns whether the given date can be picked.
+    #[must_use]
+    pub fn allows(&self, date: NaiveDate) -> bool {
+        (self.min.unwrap_or(NaiveDate::MIN)..=self.max.unwrap_or(NaiveDate::MAX)).contains(&date)
+            && !self
+                .disable_if
+                .is_some_and(|disable_if| disable_if(date.into()))
+    }
+
+    /// Returns the date to show after browsing to the given date, which is
+    /// kept within the earliest and the latest date and moved to the
+    /// nearest date of its month that can be picked, if any.
+    #[must_use]
+    pub fn browse(&self, date: NaiveDate) -> NaiveDate {
+        let date = self.max.map_or(date, |max| date.min(max));
+        let date = self.min.map_or(date, |min| date.max(min));
+
+        crate::core::date::nearest_in_month(date, |date| self.allows(date)).unwrap_or(date)
+    }
+}
+
 /// Draws the days
-fn days<Theme>(
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn days<Theme>(
     renderer: &mut Renderer<Theme>,
     layout: Layout<'_>,
     date: chrono::NaiveDate,
+    first_weekday: Weekday,
+    selection: Selection<'_>,
+    constraints: Constraints<'_>,
     cursor: Point,
     //style: &Style,
     style: &HashMap<StyleState, Appearance>,
+    day_appearances: &DayAppearances,
+    weekdays: &[String],
+    week_numbers: WeekNumbers,
+    week_label: &str,
     focus: Focus,
 ) where
     Theme: StyleSheet + button::StyleSheet + container::StyleSheet + text::StyleSheet,
@@ -1208,9 +1928,29 @@ fn days<Theme>(
     let day_labels_layout = children
         .next()
         .expect("Graphics: Layout should have a day labels layout");
-    day_labels(renderer, day_labels_layout, style, focus);
-
-    day_table(renderer, &mut children, date, cursor, style, focus);
+    let week_label = (week_numbers != WeekNumbers::Hidden).then_some(week_label);
+    day_labels(
+        renderer,
+        day_labels_layout,
+        style,
+        weekdays,
+        week_label,
+        focus,
+    );
+
+    day_table(
+        renderer,
+        &mut children,
+        date,
+        first_weekday,
+        selection,
+        constraints,
+        cursor,
+        style,
+        day_appearances,
+        week_numbers,
+        focus,
+    );
 }
 
 /// Draws the day labels
@@ -1218,25 +1958,36 @@ fn day_labels<Theme>(
     renderer: &mut Renderer<Theme>,
     layout: Layout<'_>,
     style: &HashMap<StyleState, Appearance>,
+    weekdays: &[String],
+    week_label: Option<&str>,
     _focus: Focus,
 ) where
     Theme: StyleSheet + button::StyleSheet + container::StyleSheet + text::StyleSheet,
 {
-    for (i, label) in layout.children().enumerate() {
+    let active = style
+        .get(&StyleState::Active)
+        .expect("Style Sheet not found.");
+    let labels = week_label
+        .map(|label| (label, active.text_attenuated_color))
+        .into_iter()
+        .chain(
+            weekdays
+                .iter()
+                .map(|weekday| (weekday.as_str(), active.text_color)),
+        );
+
+    for ((content, color), label) in labels.zip(layout.children()) {
         let bounds = label.bounds();
 
         renderer.fill_text(core::text::Text {
-            content: &crate::core::date::WEEKDAY_LABELS[i],
+            content,
             bounds: Rectangle {
                 x: bounds.center_x(),
                 y: bounds.center_y(),
                 ..bounds
             },
             size: renderer.default_size(),
-            color: style
-                .get(&StyleState::Active)
-                .expect("Style Sheet not found.")
-                .text_color,
+            color,
             font: renderer.default_font(),
             horizontal_alignment: Horizontal::Center,
             vertical_alignment: Vertical::Center,
@@ -1247,25 +1998,58 @@ fn day_labels<Theme>(
 }
 
 /// Draws the day table
+#[allow(clippy::too_many_arguments)]
 fn day_table<Theme>(
     renderer: &mut Renderer<Theme>,
     children: &mut dyn Iterator<Item = Layout<'_>>,
     date: chrono::NaiveDate,
+    first_weekday: Weekday,
+    selection: Selection<'_>,
+    constraints: Constraints<'_>,
     cursor: Point,
     style: &HashMap<StyleState, Appearance>,
+    day_appearances: &DayAppearances,
+    week_numbers: WeekNumbers,
     focus: Focus,
 ) where
     Theme: StyleSheet + button::StyleSheet + container::StyleSheet + text::StyleSheet,
 {
     for (y, row) in children.enumerate() {
-        for (x, label) in row.children().enumerate() {
+        let mut cells = row.children();
+
+        if week_numbers != WeekNumbers::Hidden {
+            let week_layout = cells
+                .next()
+                .expect("Graphics: Layout should have a week number layout");
+            let monday =
+                crate::core::date::row_to_week_start(y, date.year(), date.month(), first_weekday);
+
+            let is_hovered =
+                week_numbers == WeekNumbers::Pickable && week_layout.bounds().contains(cursor);
+
+            week_number(renderer, week_layout, monday, is_hovered, style);
+        }
+
+        for (x, label) in cells.enumerate() {
             let bounds = label.bounds();
-            let (number, is_in_month) =
-                crate::core::date::position_to_day(x, y, date.year(), date.month());
+            let (number, is_in_month) = crate::core::date::position_to_day_from(
+                x,
+                y,
+                date.year(),
+                date.month(),
+                first_weekday,
+            );
 
             let mouse_over = bounds.contains(cursor);
 
-            let selected = date.day() == number as u32 && is_in_month == IsInMonth::Same;
+            let cell = cell_date(date, number, &is_in_month);
+            let selected = match selection {
+                Selection::Single(picked) => cell == picked,
+                Selection::Multiple(selection) => selection.contains(&cell),
+                Selection::Range(range) => {
+                    range.is_some_and(|(start, end)| cell == start || cell == end)
+                }
+            };
 
             let mut style_state = StyleState::Active;
             if selected {
@@ -1275,6 +2059,19 @@ fn day_table<Theme>(
                 style_state = style_state.max(StyleState::Hovered);
             }
 
+            // The disabled days can't be hovered, and the days between the
+            // first and the last day of a range are highlighted unless they
+            // are hovered.
+            let appearance = match selection {
+                _ if !constraints.allows(cell) => &day_appearances.disabled,
+                Selection::Range(Some((start, end)))
+                    if start < cell && cell < end && !mouse_over =>
+                {
+                    &day_appearances.in_range
+                }
+                _ => style.get(&style_state).expect("Style Sheet not found."),
+            };
+
             renderer.fill_quad(
                 renderer::Quad {
                     bounds,
@@ -1282,13 +2079,10 @@ fn day_table<Theme>(
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
                 },
-                style
-                    .get(&style_state)
-                    .expect("Style Sheet not found.")
-                    .day_background,
+                appearance.day_background,
             );
 
-            if focus == Focus::Day && selected {
+            if focus == Focus::Day && cell == date {
                 renderer.fill_quad(
                     renderer::Quad {
                         bounds,
@@ -1319,15 +2113,9 @@ fn day_table<Theme>(
                 },
                 size: renderer.default_size(),
                 color: if is_in_month == IsInMonth::Same {
-                    style
-                        .get(&style_state)
-                        .expect("Style Sheet not found.")
-                        .text_color
+                    appearance.text_color
                 } else {
-                    style
-                        .get(&style_state)
-                        .expect("Style Sheet not found.")
-                        .text_attenuated_color
+                    appearance.text_attenuated_color
                 },
                 font: renderer.default_font(),
                 horizontal_alignment: Horizontal::Center,
@@ -1338,3 +2126,113 @@ fn day_table<Theme>(
         }
     }
 }
+
+/// Draws the ISO 8601 week number of the week starting on the given Monday,
+/// which is highlighted if it is hovered and can be picked.
+fn week_number<Theme>(
+    renderer: &mut Renderer<Theme>,
+    layout: Layout<'_>,
+    monday: NaiveDate,
+    is_hovered: bool,
+    style: &HashMap<StyleState, Appearance>,
+) where
+    Theme: StyleSheet + button::StyleSheet + container::StyleSheet + text::StyleSheet,
+{
+    let bounds = layout.bounds();
+    let (_, week) = crate::core::date::iso_week(monday);
+
+    if is_hovered {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (bounds.height / 2.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            style
+                .get(&StyleState::Hovered)
+                .expect("Style Sheet not found.")
+                .day_background,
+        );
+    }
+
+    renderer.fill_text(core::text::Text {
+        content: &format!("{week:02}"),
+        bounds: Rectangle {
+            x: bounds.center_x(),
+            y: bounds.center_y(),
+            ..bounds
+        },
+        size: renderer.default_size(),
+        color: style
+            .get(&StyleState::Active)
+            .expect("Style Sheet not found.")
+            .text_attenuated_color,
+        font: renderer.default_font(),
+        horizontal_alignment: Horizontal::Center,
+        vertical_alignment: Vertical::Center,
+        line_height: text::LineHeight::Relative(1.3),
+        shaping: text::Shaping::Basic,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Constraints, State};
+    use crate::{core::date::Date, date_picker::OnSubmit};
+    use chrono::NaiveDate;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2020, 12, day).expect("Day should exist")
+    }
+
+    fn range() -> OnSubmit<(Date, Date)> {
+        OnSubmit::Range(Box::new(|start, end| (start, end)))
+    }
+
+    #[test]
+    fn pick_range_test() {
+        let on_submit = range();
+        let mut state = State::new(date(1));
+
+        // The first picked day anchors the range and the second one ends it.
+        state.pick(date(9), &on_submit);
+        assert_eq!(state.range, Some((date(9), date(9))));
+        assert_eq!(state.range_anchor, Some(date(9)));
+        state.pick(date(14), &on_submit);
+        assert_eq!(state.range, Some((date(9), date(14))));
+        assert_eq!(state.range_anchor, None);
+
+        // Picking the end before the start orders the range.
+        state.pick(date(20), &on_submit);
+        state.pick(date(3), &on_submit);
+        assert_eq!(state.range, Some((date(3), date(20))));
+
+        // A third pick starts a new range anchored at it.
+        state.pick(date(11), &on_submit);
+        assert_eq!(state.range, Some((date(11), date(11))));
+        assert_eq!(state.range_anchor, Some(date(11)));
+        state.pick(date(10), &on_submit);
+        assert_eq!(state.range, Some((date(10), date(11))));
+    }
+
+    #[test]
+    fn pick_week_range_test() {
+        let on_submit = range();
+        let mut state = State::new(date(1));
+        let disable_if = |date: Date| date == Date::from_ymd(2020, 12, 13);
+        let constraints = Constraints {
+            min: Some(date(8)),
+            max: None,
+            disable_if: Some(&disable_if),
+        };
+
+        // The week becomes the range, without the days that can't be picked,
+        // and drops a started range.
+        state.pick(date(2), &on_submit);
+        state.pick_week(date(7), &on_submit, constraints);
+        assert_eq!(state.range, Some((date(8), date(12))));
+        assert_eq!(state.range_anchor, None);
+        assert_eq!(state.date, date(8));
+    }
+}
@@ -0,0 +1,331 @@
+//! Use a date time picker as an input element for picking a date and a time.
+//!
+//! *This API requires the following crate features to be activated: `date_time_picker`*
+
+use crate::{
+    core::layout_direction::{self, LayoutDirection},
+    date_picker::Date,
+    style::{date_picker::StyleSheet, style_state::StyleState},
+    time_picker::Time,
+};
+
+use chrono::NaiveDate;
+use iced_widget::{
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event,
+        layout::Node,
+        mouse::{self, Cursor},
+        overlay, renderer,
+        text::{self, Renderer as _},
+        touch, Clipboard, Color, Event, Layout, Overlay, Point, Rectangle, Renderer as _, Shell,
+        Size, Vector,
+    },
+    renderer::Renderer,
+};
+
+/// The spacing between the tabs and the page.
+const SPACING: f32 = 5.0;
+/// The padding around the tabs.
+const PADDING: f32 = 5.0;
+
+/// The page of a [`DateTimePickerOverlay`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Page {
+    /// The calendar picking the date is shown.
+    #[default]
+    Date,
+    /// The clock picking the time is shown.
+    Time,
+}
+
+/// The message of the page shown by a [`DateTimePickerOverlay`].
+#[derive(Clone, Debug)]
+pub enum PageMessage {
+    /// The date is submitted.
+    Date,
+    /// The time is submitted.
+    Time(Time),
+    /// The page is cancelled.
+    Cancel,
+}
+
+/// The overlay of the [`DateTimePicker`](crate::native::DateTimePicker),
+/// showing the overlay of a date picker or of a time picker as its page below
+/// a tab of each.
+#[allow(missing_debug_implementations)]
+pub struct DateTimePickerOverlay<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: StyleSheet,
+{
+    /// The shown page of the [`DateTimePickerOverlay`].
+    page: &'a mut Page,
+    /// The overlay of the shown page.
+    content: overlay::Element<'a, PageMessage, Renderer<Theme>>,
+    /// The picked date.
+    date: NaiveDate,
+    /// The picked time, as shown on its tab.
+    time: Time,
+    /// The message that is sent when the [`DateTimePickerOverlay`] is cancelled.
+    on_cancel: Message,
+    /// The function that produces a message when the date and the time are submitted.
+    on_submit: &'a dyn Fn(Date, Time) -> Message,
+    /// The position of the [`DateTimePickerOverlay`].
+    position: Point,
+    /// The style of the tabs of the [`DateTimePickerOverlay`].
+    style: <Theme as StyleSheet>::Style,
+    /// The [`LayoutDirection`] the tabs of the [`DateTimePickerOverlay`] are arranged in.
+    direction: LayoutDirection,
+}
+
+impl<'a, Message, Theme> DateTimePickerOverlay<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a + StyleSheet,
+{
+    /// Creates a new [`DateTimePickerOverlay`] on the given position, showing
+    /// the given overlay of its page.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        page: &'a mut Page,
+        content: overlay::Element<'a, PageMessage, Renderer<Theme>>,
+        date: NaiveDate,
+        time: Time,
+        on_cancel: Message,
+        on_submit: &'a dyn Fn(Date, Time) -> Message,
+        position: Point,
+        style: <Theme as StyleSheet>::Style,
+    ) -> Self {
+        DateTimePickerOverlay {
+            page,
+            content,
+            date,
+            time,
+            on_cancel,
+            on_submit,
+            position,
+            style,
+            direction: layout_direction::get(),
+        }
+    }
+
+    /// Turn this [`DateTimePickerOverlay`] into an overlay [`Element`](overlay::Element).
+    #[must_use]
+    pub fn overlay(self) -> overlay::Element<'a, Message, Renderer<Theme>> {
+        overlay::Element::new(self.position, Box::new(self))
+    }
+}
+
+impl<'a, Message, Theme> Overlay<Message, Renderer<Theme>>
+    for DateTimePickerOverlay<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a + StyleSheet,
+{
+    fn layout(&self, renderer: &Renderer<Theme>, bounds: Size, _position: Point) -> Node {
+        let tabs_height = renderer.default_size() * 2.0;
+        let offset = tabs_height + SPACING;
+
+        // The page is laid out below the tabs, keeping both centered on the
+        // position.
+        let mut content = self.content.layout(
+            renderer,
+            Size::new(bounds.width, (bounds.height - offset).max(0.0)),
+            Vector::new(0.0, -offset / 2.0),
+        );
+        let position = content.bounds().position();
+        let width = content.bounds().width;
+        content.move_to(Point::new(0.0, offset));
+
+        let tab_width = (width - 2.0 * PADDING - SPACING) / 2.0;
+        let mut date_tab = Node::new(Size::new(tab_width, tabs_height - 2.0 * PADDING));
+        date_tab.move_to(Point::new(PADDING, PADDING));
+        let mut time_tab = Node::new(Size::new(tab_width, tabs_height - 2.0 * PADDING));
+        time_tab.move_to(Point::new(width - PADDING - tab_width, PADDING));
+        let tabs = self.direction.arrange(Node::with_children(
+            Size::new(width, tabs_height),
+            vec![date_tab, time_tab],
+        ));
+
+        let mut node = Node::with_children(
+            Size::new(width, offset + content.bounds().height),
+            vec![tabs, content],
+        );
+        node.move_to(position);
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer<Theme>,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let tabs = children
+            .next()
+            .expect("Native: Layout should have a tabs layout");
+        let content = children
+            .next()
+            .expect("Native: Layout should have a page layout");
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            let clicked = [Page::Date, Page::Time]
+                .into_iter()
+                .zip(tabs.children())
+                .find(|(_, tab)| cursor.is_over(tab.bounds()));
+
+            if let Some((page, _)) = clicked {
+                if *self.page != page {
+                    *self.page = page;
+                    shell.invalidate_layout();
+                }
+
+                return event::Status::Captured;
+            }
+        }
+
+        let mut messages = Vec::new();
+        let mut content_shell = Shell::new(&mut messages);
+        let status = self.content.on_event(
+            event,
+            content,
+            cursor,
+            renderer,
+            clipboard,
+            &mut content_shell,
+        );
+
+        if content_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+        if let Some(request) = content_shell.redraw_request() {
+            shell.request_redraw(request);
+        }
+
+        for message in messages {
+            match message {
+                // The picked date leads on to the time.
+                PageMessage::Date => {
+                    *self.page = Page::Time;
+                    shell.invalidate_layout();
+                }
+                PageMessage::Time(time) => {
+                    shell.publish((self.on_submit)(self.date.into(), time));
+                }
+                PageMessage::Cancel => shell.publish(self.on_cancel.clone()),
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer<Theme>,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let tabs = children
+            .next()
+            .expect("Native: Layout should have a tabs layout");
+        let content = children
+            .next()
+            .expect("Native: Layout should have a page layout");
+
+        if tabs.children().any(|tab| cursor.is_over(tab.bounds())) {
+            mouse::Interaction::Pointer
+        } else {
+            self.content
+                .mouse_interaction(content, cursor, viewport, renderer)
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer<Theme>,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let mut children = layout.children();
+        let tabs = children
+            .next()
+            .expect("Graphics: Layout should have a tabs layout");
+        let content = children
+            .next()
+            .expect("Graphics: Layout should have a page layout");
+
+        let active = theme.active(&self.style);
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: tabs.bounds(),
+                border_radius: active.border_radius.into(),
+                border_width: active.border_width,
+                border_color: active.border_color,
+            },
+            active.background,
+        );
+
+        let labels = [
+            (Page::Date, Date::from(self.date).to_string()),
+            (Page::Time, self.time.to_string()),
+        ];
+
+        for ((page, label), tab) in labels.into_iter().zip(tabs.children()) {
+            let style_state = if *self.page == page {
+                StyleState::Selected
+            } else if cursor.is_over(tab.bounds()) {
+                StyleState::Hovered
+            } else {
+                StyleState::Active
+            };
+            let appearance = match style_state {
+                StyleState::Selected => theme.selected(&self.style),
+                StyleState::Hovered => theme.hovered(&self.style),
+                _ => active,
+            };
+
+            if style_state != StyleState::Active {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: tab.bounds(),
+                        border_radius: appearance.border_radius.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.day_background,
+                );
+            }
+
+            renderer.fill_text(core::Text {
+                content: &label,
+                bounds: Rectangle {
+                    x: tab.bounds().center_x(),
+                    y: tab.bounds().center_y(),
+                    ..tab.bounds()
+                },
+                size: renderer.default_size(),
+                color: appearance.text_color,
+                font: renderer.default_font(),
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+                line_height: text::LineHeight::Relative(1.3),
+                shaping: text::Shaping::Basic,
+            });
+        }
+
+        self.content.draw(renderer, theme, style, content, cursor);
+    }
+}
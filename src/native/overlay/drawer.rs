@@ -0,0 +1,389 @@
+//! A drawer for sliding a panel in from an edge of some content.
+//!
+//! *This API requires the following crate features to be activated: drawer*
+use crate::native::drawer::{self, Edge};
+use crate::style::drawer::{Appearance, StyleSheet};
+
+use iced_widget::core::{
+    self, event, keyboard,
+    layout::{Limits, Node},
+    mouse::{self, Cursor},
+    overlay, renderer, touch,
+    widget::Tree,
+    Background, Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size,
+};
+
+/// The distance a swipe has to travel before it is taken over from the content.
+const SWIPE_SLOP: f32 = 10.0;
+/// The fraction of the panel a swipe has to travel to dismiss the panel.
+const SWIPE_THRESHOLD: f32 = 0.3;
+
+/// The overlay of the [`Drawer`](crate::native::Drawer).
+#[allow(missing_debug_implementations)]
+pub struct DrawerOverlay<'a, 'b, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The state of the content of the [`DrawerOverlay`].
+    tree: &'b mut Tree,
+    /// The content of the [`DrawerOverlay`].
+    content: &'b mut Element<'a, Message, Renderer>,
+    /// The state shared between [`Drawer`](crate::native::Drawer) and [`DrawerOverlay`].
+    state: &'b mut drawer::State,
+    /// Show the panel of the [`DrawerOverlay`].
+    open: bool,
+    /// The edge the panel slides in from.
+    edge: Edge,
+    /// The extent of the panel along the axis it slides on.
+    extent: f32,
+    /// The optional message that will be send when the panel is dismissed.
+    on_dismiss: Option<Message>,
+    /// The style of the [`DrawerOverlay`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+    /// The bounds of the underlay element.
+    underlay_bounds: Rectangle,
+}
+
+impl<'a, 'b, Message, Renderer> DrawerOverlay<'a, 'b, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`DrawerOverlay`].
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        tree: &'b mut Tree,
+        content: &'b mut Element<'a, Message, Renderer>,
+        state: &'b mut drawer::State,
+        open: bool,
+        edge: Edge,
+        extent: f32,
+        on_dismiss: Option<Message>,
+        style: <Renderer::Theme as StyleSheet>::Style,
+        underlay_bounds: Rectangle,
+    ) -> Self {
+        DrawerOverlay {
+            tree,
+            content,
+            state,
+            open,
+            edge,
+            extent,
+            on_dismiss,
+            style,
+            underlay_bounds,
+        }
+    }
+
+    /// Turn this [`DrawerOverlay`] into an overlay [`Element`](overlay::Element).
+    pub fn overlay(self, position: Point) -> overlay::Element<'b, Message, Renderer> {
+        overlay::Element::new(position, Box::new(self))
+    }
+
+    /// Returns the extent of the panel, limited to the bounds of the underlay.
+    fn clamped_extent(&self) -> f32 {
+        if self.edge.is_horizontal() {
+            self.extent.min(self.underlay_bounds.width)
+        } else {
+            self.extent.min(self.underlay_bounds.height)
+        }
+    }
+
+    /// Returns how far the given position has travelled from the origin
+    /// towards the edge of the panel.
+    fn swipe_distance(&self, origin: Point, position: Point) -> f32 {
+        let distance = match self.edge {
+            Edge::Left => origin.x - position.x,
+            Edge::Right => position.x - origin.x,
+            Edge::Top => origin.y - position.y,
+            Edge::Bottom => position.y - origin.y,
+        };
+
+        distance.max(0.0)
+    }
+
+    /// Returns the visible fraction of the panel.
+    fn visibility(&self) -> f32 {
+        let extent = self.clamped_extent();
+        let swiped = if extent > 0.0 {
+            self.state.swipe_offset / extent
+        } else {
+            0.0
+        };
+
+        (self.state.animation.progress - swiped).clamp(0.0, 1.0)
+    }
+}
+
+impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for DrawerOverlay<'a, 'b, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(&self, renderer: &Renderer, _bounds: Size, position: Point) -> Node {
+        let area = self.underlay_bounds.size();
+        let extent = self.clamped_extent();
+        let hidden = (1.0 - self.visibility()) * extent;
+
+        let mut panel = layout_panel(
+            self.content,
+            renderer,
+            if self.edge.is_horizontal() {
+                Size::new(extent, area.height)
+            } else {
+                Size::new(area.width, extent)
+            },
+        );
+
+        panel.move_to(match self.edge {
+            Edge::Left => Point::new(-hidden, 0.0),
+            Edge::Right => Point::new(area.width - extent + hidden, 0.0),
+            Edge::Top => Point::new(0.0, -hidden),
+            Edge::Bottom => Point::new(0.0, area.height - extent + hidden),
+        });
+
+        let mut node = Node::with_children(area, vec![panel]);
+        node.move_to(position);
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        if !self.open {
+            return event::Status::Ignored;
+        }
+
+        let panel_layout = layout
+            .children()
+            .next()
+            .expect("Native: Layout should have a panel layout.");
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) => {
+                if let Some(on_dismiss) = &self.on_dismiss {
+                    shell.publish(on_dismiss.clone());
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let position = cursor.position().unwrap_or_default();
+
+                if !panel_layout.bounds().contains(position) {
+                    if let Some(on_dismiss) = &self.on_dismiss {
+                        shell.publish(on_dismiss.clone());
+                    }
+                    return event::Status::Captured;
+                }
+
+                if self.on_dismiss.is_some() {
+                    self.state.swipe_origin = Some(position);
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(origin) = self.state.swipe_origin {
+                    let distance = self.swipe_distance(origin, position);
+
+                    if distance > SWIPE_SLOP || self.state.swipe_offset > 0.0 {
+                        self.state.swipe_offset = distance.min(self.clamped_extent());
+                        shell.invalidate_layout();
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                let swiped =
+                    self.state.swipe_origin.take().is_some() && self.state.swipe_offset > 0.0;
+
+                if swiped {
+                    let extent = self.clamped_extent();
+
+                    if self.state.swipe_offset >= extent * SWIPE_THRESHOLD {
+                        if let Some(on_dismiss) = &self.on_dismiss {
+                            // Continue the closing animation from where the swipe ended.
+                            self.state.animation.progress = self.visibility();
+                            shell.publish(on_dismiss.clone());
+                        }
+                    }
+
+                    self.state.swipe_offset = 0.0;
+                    shell.invalidate_layout();
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        self.content.as_widget_mut().on_event(
+            self.tree,
+            event,
+            panel_layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout."),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &layout.bounds(),
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.state.swipe_offset > 0.0 {
+            return mouse::Interaction::Grabbing;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            self.tree,
+            layout
+                .children()
+                .next()
+                .and_then(|panel| panel.children().next())
+                .expect("Native: Layout should have a content layout."),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let visibility = self.visibility();
+
+        // Backdrop
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            match appearance.backdrop {
+                Background::Color(color) => Background::Color(Color {
+                    a: color.a * visibility,
+                    ..color
+                }),
+                background @ Background::Gradient(_) => background,
+            },
+        );
+
+        let panel_layout = layout
+            .children()
+            .next()
+            .expect("Graphics: Layout should have a panel layout.");
+
+        renderer.with_layer(bounds, |renderer| {
+            draw_panel(
+                self.content,
+                self.tree,
+                renderer,
+                theme,
+                style,
+                &appearance,
+                panel_layout,
+                cursor,
+                &bounds,
+            );
+        });
+    }
+
+    fn overlay<'c>(
+        &'c mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'c, Message, Renderer>> {
+        let content_layout = layout.children().next()?.children().next()?;
+
+        self.content
+            .as_widget_mut()
+            .overlay(self.tree, content_layout, renderer)
+    }
+}
+
+/// Lays out the content of a drawer panel with the given size.
+pub(crate) fn layout_panel<Message, Renderer>(
+    content: &Element<'_, Message, Renderer>,
+    renderer: &Renderer,
+    size: Size,
+) -> Node
+where
+    Renderer: core::Renderer,
+{
+    let limits = Limits::new(Size::ZERO, size)
+        .width(Length::Fill)
+        .height(Length::Fill);
+    let content = content.as_widget().layout(renderer, &limits);
+
+    Node::with_children(size, vec![content])
+}
+
+/// Draws the background and the content of a drawer panel.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn draw_panel<Message, Renderer>(
+    content: &Element<'_, Message, Renderer>,
+    tree: &Tree,
+    renderer: &mut Renderer,
+    theme: &Renderer::Theme,
+    style: &renderer::Style,
+    appearance: &Appearance,
+    layout: Layout<'_>,
+    cursor: Cursor,
+    viewport: &Rectangle,
+) where
+    Renderer: core::Renderer,
+{
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: layout.bounds(),
+            border_radius: (0.0).into(),
+            border_width: appearance.border_width,
+            border_color: appearance.border_color,
+        },
+        appearance.background,
+    );
+
+    content.as_widget().draw(
+        tree,
+        renderer,
+        theme,
+        style,
+        layout
+            .children()
+            .next()
+            .expect("Graphics: Layout should have a content layout."),
+        cursor,
+        viewport,
+    );
+}
@@ -0,0 +1,866 @@
+//! Use a duration picker as an input element for picking durations.
+//!
+//! *This API requires the following crate features to be activated: `duration_picker`*
+
+use crate::{
+    core::{
+        clock,
+        duration::{self, Unit},
+        i18n,
+        layout_direction::{self, LayoutDirection},
+        overlay::Position,
+    },
+    graphics::icons::{icon_to_char, Icon, ICON_FONT},
+};
+
+use iced_widget::{
+    button,
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event, keyboard,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        overlay, renderer,
+        text::{self, Renderer as _},
+        touch,
+        widget::tree::Tree,
+        Clipboard, Color, Element, Event, Layout, Length, Overlay, Point, Rectangle, Renderer as _,
+        Shell, Size, Widget,
+    },
+    renderer::Renderer,
+    Button,
+};
+use std::time::Duration;
+
+pub use crate::style::duration_picker::{Appearance, StyleSheet};
+
+/// The padding around the elements.
+const PADDING: f32 = 10.0;
+/// The spacing between the elements.
+const SPACING: f32 = 15.0;
+/// The spacing between the buttons.
+const BUTTON_SPACING: f32 = 5.0;
+/// The padding on both sides of a value.
+const VALUE_PADDING: f32 = 8.0;
+/// The smallest width of the content of the overlay.
+const MIN_WIDTH: f32 = 200.0;
+/// The size of the values relative to the default text size.
+const VALUE_SIZE_PERCENTAGE: f32 = 1.6;
+/// The size of the labels relative to the default text size.
+const LABEL_SIZE_PERCENTAGE: f32 = 0.8;
+
+/// The overlay of the [`DurationPicker`](crate::native::DurationPicker).
+#[allow(missing_debug_implementations)]
+pub struct DurationPickerOverlay<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: StyleSheet + button::StyleSheet,
+{
+    /// The state of the [`DurationPickerOverlay`].
+    state: &'a mut State,
+    /// The cancel button of the [`DurationPickerOverlay`].
+    cancel_button: Button<'a, Message, Renderer<Theme>>,
+    /// The submit button of the [`DurationPickerOverlay`].
+    submit_button: Button<'a, Message, Renderer<Theme>>,
+    /// The function that produces a message when the submit button of the [`DurationPickerOverlay`] is pressed.
+    on_submit: &'a dyn Fn(Duration) -> Message,
+    /// The position of the [`DurationPickerOverlay`].
+    position: Point,
+    /// The style of the [`DurationPickerOverlay`].
+    style: <Theme as StyleSheet>::Style,
+    /// The reference to the tree holding the state of this overlay.
+    tree: &'a mut Tree,
+    /// The [`LayoutDirection`] the buttons of the [`DurationPickerOverlay`] are arranged in.
+    direction: LayoutDirection,
+    /// The labels of the hours, the minutes and the seconds.
+    labels: [String; 3],
+}
+
+impl<'a, Message, Theme> DurationPickerOverlay<'a, Message, Theme>
+where
+    Message: 'static + Clone,
+    Theme: 'a + StyleSheet + button::StyleSheet + iced_widget::text::StyleSheet,
+{
+    /// Creates a new [`DurationPickerOverlay`] on the given position.
+    pub fn new(
+        state: &'a mut State,
+        on_cancel: Message,
+        on_submit: &'a dyn Fn(Duration) -> Message,
+        position: Point,
+        style: <Theme as StyleSheet>::Style,
+        tree: &'a mut Tree,
+    ) -> Self {
+        let translations = i18n::get();
+
+        DurationPickerOverlay {
+            state,
+            cancel_button: Button::new(
+                iced_widget::Text::new(icon_to_char(Icon::X).to_string())
+                    .font(ICON_FONT)
+                    .horizontal_alignment(Horizontal::Center)
+                    .width(Length::Fill),
+            )
+            .width(Length::Fill)
+            .on_press(on_cancel.clone()),
+            submit_button: Button::new(
+                iced_widget::Text::new(icon_to_char(Icon::Check).to_string())
+                    .font(ICON_FONT)
+                    .horizontal_alignment(Horizontal::Center)
+                    .width(Length::Fill),
+            )
+            .width(Length::Fill)
+            .on_press(on_cancel), // Sending a fake message
+            on_submit,
+            position,
+            style,
+            tree,
+            direction: layout_direction::get(),
+            labels: [
+                translations.hours(),
+                translations.minutes(),
+                translations.seconds(),
+            ],
+        }
+    }
+
+    /// Turn this [`DurationPickerOverlay`] into an overlay [`Element`](overlay::Element).
+    #[must_use]
+    pub fn overlay(self) -> overlay::Element<'a, Message, Renderer<Theme>> {
+        overlay::Element::new(self.position, Box::new(self))
+    }
+
+    /// Returns the label of the given unit.
+    fn label(&self, unit: Unit) -> &str {
+        match unit {
+            Unit::Hours => &self.labels[0],
+            Unit::Minutes => &self.labels[1],
+            Unit::Seconds => &self.labels[2],
+        }
+    }
+
+    /// The event handling for the segments.
+    fn on_event_segments(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) -> event::Status {
+        let units = self.state.units();
+        let Some((unit, segment)) = units
+            .iter()
+            .zip(layout.children().step_by(2))
+            .find(|(_, segment)| cursor.is_over(segment.bounds()))
+        else {
+            return event::Status::Ignored;
+        };
+        let unit = *unit;
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let mut children = segment.children();
+                let up_arrow = children
+                    .next()
+                    .expect("Native: Layout should have an up arrow");
+                let _ = children.next();
+                let down_arrow = children
+                    .next()
+                    .expect("Native: Layout should have a down arrow");
+
+                self.state.focus = Some(unit);
+                self.state.typed = None;
+                if cursor.is_over(up_arrow.bounds()) {
+                    self.state.step(unit, true);
+                } else if cursor.is_over(down_arrow.bounds()) {
+                    self.state.step(unit, false);
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => {
+                        *y
+                    }
+                };
+
+                if y == 0.0 {
+                    return event::Status::Ignored;
+                }
+
+                self.state.typed = None;
+                self.state.step(unit, y > 0.0);
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    /// The event handling for the keyboard input.
+    fn on_event_keyboard(&mut self, event: &Event) -> event::Status {
+        match event {
+            Event::Keyboard(keyboard::Event::CharacterReceived(character)) => {
+                self.state.type_character(*character)
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => {
+                let Some(unit) = self.state.focus else {
+                    return event::Status::Ignored;
+                };
+
+                match key_code {
+                    keyboard::KeyCode::Tab => {
+                        let units = self.state.units();
+                        let index = units.iter().position(|u| *u == unit).unwrap_or(0);
+                        let next = if self.state.keyboard_modifiers.shift() {
+                            index.checked_sub(1).unwrap_or(units.len() - 1)
+                        } else {
+                            (index + 1) % units.len()
+                        };
+
+                        self.state.focus = Some(units[next]);
+                    }
+                    keyboard::KeyCode::Up | keyboard::KeyCode::Right => {
+                        self.state.step(unit, true);
+                    }
+                    keyboard::KeyCode::Down | keyboard::KeyCode::Left => {
+                        self.state.step(unit, false);
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                self.state.typed = None;
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                self.state.keyboard_modifiers = *modifiers;
+                event::Status::Ignored
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+}
+
+impl<'a, Message, Theme> Overlay<Message, Renderer<Theme>>
+    for DurationPickerOverlay<'a, Message, Theme>
+where
+    Message: 'static + Clone,
+    Theme: 'a + StyleSheet + button::StyleSheet + iced_widget::text::StyleSheet,
+{
+    fn layout(&self, renderer: &Renderer<Theme>, bounds: Size, position: Point) -> Node {
+        let font = renderer.default_font();
+        let arrow_size = renderer.default_size();
+        let value_size = renderer.default_size() * VALUE_SIZE_PERCENTAGE;
+        let label_size = renderer.default_size() * LABEL_SIZE_PERCENTAGE;
+        let value_height = text::LineHeight::default().to_absolute(value_size.into()).0;
+        let label_height = text::LineHeight::default().to_absolute(label_size.into()).0;
+        let units = self.state.units();
+
+        // All segments are as wide as the widest value or label.
+        let hour_digits = Unit::Hours.of(self.state.max).to_string().len().max(2);
+        let segment_width = units
+            .iter()
+            .map(|unit| {
+                renderer.measure_width(self.label(*unit), label_size, font, text::Shaping::Basic)
+            })
+            .fold(
+                renderer.measure_width(
+                    &"0".repeat(hour_digits),
+                    value_size,
+                    font,
+                    text::Shaping::Basic,
+                ) + 2.0 * VALUE_PADDING,
+                f32::max,
+            );
+        let separator_width =
+            renderer.measure_width(":", value_size, font, text::Shaping::Basic) + VALUE_PADDING;
+        let segment_height = 2.0 * arrow_size + value_height + label_height;
+
+        let mut x = 0.0;
+        let mut children = Vec::new();
+        for i in 0..units.len() {
+            if i > 0 {
+                let mut separator = Node::new(Size::new(separator_width, value_height));
+                separator.move_to(Point::new(x, arrow_size));
+                children.push(separator);
+                x += separator_width;
+            }
+
+            let mut up_arrow = Node::new(Size::new(segment_width, arrow_size));
+            up_arrow.move_to(Point::new(0.0, 0.0));
+            let mut value = Node::new(Size::new(segment_width, value_height));
+            value.move_to(Point::new(0.0, arrow_size));
+            let mut down_arrow = Node::new(Size::new(segment_width, arrow_size));
+            down_arrow.move_to(Point::new(0.0, arrow_size + value_height));
+            let mut label = Node::new(Size::new(segment_width, label_height));
+            label.move_to(Point::new(0.0, 2.0 * arrow_size + value_height));
+
+            let mut segment = Node::with_children(
+                Size::new(segment_width, segment_height),
+                vec![up_arrow, value, down_arrow, label],
+            );
+            segment.move_to(Point::new(x, 0.0));
+            children.push(segment);
+            x += segment_width;
+        }
+
+        // Every other child is a segment, with the separators in between.
+        let row_width = x;
+        let content_width = row_width.max(MIN_WIDTH);
+        let mut segments = Node::with_children(Size::new(row_width, segment_height), children);
+        segments.move_to(Point::new(
+            PADDING + (content_width - row_width) / 2.0,
+            PADDING,
+        ));
+
+        // Buttons
+        let button_width = ((content_width - BUTTON_SPACING) / 2.0).max(0.0);
+        let button_limits = Limits::new(Size::ZERO, Size::new(button_width, bounds.height));
+        let mut cancel_button = self.cancel_button.layout(renderer, &button_limits);
+        let mut submit_button = self.submit_button.layout(renderer, &button_limits);
+        let buttons_y = PADDING + segment_height + SPACING;
+        cancel_button.move_to(Point::new(PADDING, buttons_y));
+        submit_button.move_to(Point::new(
+            PADDING + content_width - submit_button.bounds().width,
+            buttons_y,
+        ));
+
+        let size = Size::new(
+            content_width + 2.0 * PADDING,
+            buttons_y + cancel_button.bounds().height + PADDING,
+        );
+
+        let mut node = self.direction.arrange(Node::with_children(
+            size,
+            vec![segments, cancel_button, submit_button],
+        ));
+
+        node.center_and_bounce(position, bounds);
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer<Theme>,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        if self.on_event_keyboard(&event) == event::Status::Captured {
+            return event::Status::Captured;
+        }
+
+        let mut children = layout.children();
+
+        let segments_layout = children
+            .next()
+            .expect("Native: Layout should have a segments layout");
+        let segments_status = self.on_event_segments(&event, segments_layout, cursor);
+
+        // ----------- Buttons ------------------------
+        let cancel_button_layout = children
+            .next()
+            .expect("Native: Layout should have a cancel button layout for a DurationPicker");
+
+        let cancel_status = self.cancel_button.on_event(
+            &mut self.tree.children[0],
+            event.clone(),
+            cancel_button_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &layout.bounds(),
+        );
+
+        let submit_button_layout = children
+            .next()
+            .expect("Native: Layout should have a submit button layout for a DurationPicker");
+
+        let mut fake_messages: Vec<Message> = Vec::new();
+
+        let submit_status = self.submit_button.on_event(
+            &mut self.tree.children[1],
+            event,
+            submit_button_layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut Shell::new(&mut fake_messages),
+            &layout.bounds(),
+        );
+
+        if !fake_messages.is_empty() {
+            shell.publish((self.on_submit)(self.state.duration));
+        }
+
+        segments_status.merge(cancel_status).merge(submit_status)
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer<Theme>,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+
+        let segments_layout = children
+            .next()
+            .expect("Graphics: Layout should have a segments layout");
+        let arrow_hovered = segments_layout.children().step_by(2).any(|segment| {
+            let mut children = segment.children();
+            let up_arrow = children.next();
+            let _ = children.next();
+            let down_arrow = children.next();
+
+            up_arrow
+                .into_iter()
+                .chain(down_arrow)
+                .any(|arrow| cursor.is_over(arrow.bounds()))
+        });
+        let segments_mouse_interaction = if arrow_hovered {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        };
+
+        let cancel_button_layout = children
+            .next()
+            .expect("Graphics: Layout should have a cancel button layout for a DurationPicker");
+        let cancel_mouse_interaction = self.cancel_button.mouse_interaction(
+            &self.tree.children[0],
+            cancel_button_layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        let submit_button_layout = children
+            .next()
+            .expect("Graphics: Layout should have a submit button layout for a DurationPicker");
+        let submit_mouse_interaction = self.submit_button.mouse_interaction(
+            &self.tree.children[1],
+            submit_button_layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        segments_mouse_interaction
+            .max(cancel_mouse_interaction)
+            .max(submit_mouse_interaction)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer<Theme>,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let appearance = StyleSheet::active(theme, &self.style);
+
+        // Background
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius.into(),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        // ----------- Segments -----------------------
+        let segments_layout = children
+            .next()
+            .expect("Graphics: Layout should have a segments layout");
+        draw_segments(renderer, self, segments_layout, cursor, &appearance);
+
+        // ----------- Buttons ------------------------
+        let cancel_button_layout = children
+            .next()
+            .expect("Graphics: Layout should have a cancel button layout for a DurationPicker");
+
+        self.cancel_button.draw(
+            &self.tree.children[0],
+            renderer,
+            theme,
+            style,
+            cancel_button_layout,
+            cursor,
+            &bounds,
+        );
+
+        let submit_button_layout = children
+            .next()
+            .expect("Graphics: Layout should have a submit button layout for a DurationPicker");
+
+        self.submit_button.draw(
+            &self.tree.children[1],
+            renderer,
+            theme,
+            style,
+            submit_button_layout,
+            cursor,
+            &bounds,
+        );
+    }
+}
+
+/// Draws the segments of the hours, the minutes and the seconds.
+fn draw_segments<Message, Theme>(
+    renderer: &mut Renderer<Theme>,
+    duration_picker: &DurationPickerOverlay<'_, Message, Theme>,
+    layout: Layout<'_>,
+    cursor: Cursor,
+    appearance: &Appearance,
+) where
+    Message: 'static + Clone,
+    Theme: StyleSheet + button::StyleSheet + iced_widget::text::StyleSheet,
+{
+    let value_size = renderer.default_size() * VALUE_SIZE_PERCENTAGE;
+    let label_size = renderer.default_size() * LABEL_SIZE_PERCENTAGE;
+    let state = &duration_picker.state;
+    let mut children = layout.children();
+
+    for (i, unit) in state.units().iter().enumerate() {
+        if i > 0 {
+            let separator = children
+                .next()
+                .expect("Graphics: Layout should have a separator layout")
+                .bounds();
+
+            renderer.fill_text(core::Text {
+                content: ":",
+                bounds: Rectangle {
+                    x: separator.center_x(),
+                    y: separator.center_y(),
+                    ..separator
+                },
+                size: value_size,
+                color: appearance.text_color,
+                font: renderer.default_font(),
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+                line_height: text::LineHeight::default(),
+                shaping: text::Shaping::Basic,
+            });
+        }
+
+        let mut segment = children
+            .next()
+            .expect("Graphics: Layout should have a segment layout")
+            .children();
+        let mut bounds = || {
+            segment
+                .next()
+                .expect("Graphics: Layout should have the parts of a segment")
+                .bounds()
+        };
+        let (up_bounds, value_bounds, down_bounds, label_bounds) =
+            (bounds(), bounds(), bounds(), bounds());
+        let focused = state.focus == Some(*unit);
+
+        if focused {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: value_bounds,
+                    border_radius: (value_bounds.height / 4.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.focus_background,
+            );
+        }
+
+        let mut buffer = [0; 4];
+        for (icon, arrow_bounds) in [
+            (Icon::CaretUpFill, up_bounds),
+            (Icon::CaretDownFill, down_bounds),
+        ] {
+            renderer.fill_text(core::Text {
+                content: char::from(icon).encode_utf8(&mut buffer),
+                bounds: Rectangle {
+                    x: arrow_bounds.center_x(),
+                    y: arrow_bounds.center_y(),
+                    ..arrow_bounds
+                },
+                size: renderer.default_size()
+                    + if cursor.is_over(arrow_bounds) {
+                        1.0
+                    } else {
+                        0.0
+                    },
+                color: appearance.text_color,
+                font: ICON_FONT,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+                line_height: text::LineHeight::Relative(1.3),
+                shaping: text::Shaping::Basic,
+            });
+        }
+
+        renderer.fill_text(core::Text {
+            content: &format!("{:02}", unit.of(state.duration)),
+            bounds: Rectangle {
+                x: value_bounds.center_x(),
+                y: value_bounds.center_y(),
+                ..value_bounds
+            },
+            size: value_size,
+            color: if focused {
+                appearance.focus_text_color
+            } else {
+                appearance.text_color
+            },
+            font: renderer.default_font(),
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Basic,
+        });
+
+        renderer.fill_text(core::Text {
+            content: duration_picker.label(*unit),
+            bounds: Rectangle {
+                x: label_bounds.center_x(),
+                y: label_bounds.center_y(),
+                ..label_bounds
+            },
+            size: label_size,
+            color: appearance.label_color,
+            font: renderer.default_font(),
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Basic,
+        });
+    }
+}
+
+/// The state of the [`DurationPickerOverlay`].
+#[derive(Debug)]
+pub struct State {
+    /// The picked duration of the [`DurationPickerOverlay`].
+    pub(crate) duration: Duration,
+    /// The longest duration that can be picked.
+    pub(crate) max: Duration,
+    /// Toggle the use of the seconds of the [`DurationPickerOverlay`].
+    pub(crate) show_seconds: bool,
+    /// The focused segment of the [`DurationPickerOverlay`].
+    pub(crate) focus: Option<Unit>,
+    /// The segment and the digit typed into it, if another digit may follow.
+    pub(crate) typed: Option<(Unit, u32)>,
+    /// The previously pressed keyboard modifiers.
+    pub(crate) keyboard_modifiers: keyboard::Modifiers,
+}
+
+impl State {
+    /// Creates a new State with the given duration.
+    #[must_use]
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the units of the shown segments.
+    pub(crate) fn units(&self) -> &'static [Unit] {
+        if self.show_seconds {
+            &[Unit::Hours, Unit::Minutes, Unit::Seconds]
+        } else {
+            &[Unit::Hours, Unit::Minutes]
+        }
+    }
+
+    /// Clamps the picked duration to whole shown units within the maximum.
+    pub(crate) fn clamp(&mut self) {
+        let step = if self.show_seconds {
+            Unit::Seconds
+        } else {
+            Unit::Minutes
+        }
+        .step();
+        let whole = self.duration.as_secs() / step.as_secs() * step.as_secs();
+
+        self.duration = Duration::from_secs(whole).min(self.max);
+    }
+
+    /// Moves the picked duration by one step of the given unit.
+    fn step(&mut self, unit: Unit, forth: bool) {
+        self.duration = duration::step(self.duration, unit, forth, self.max);
+    }
+
+    /// Types the given character into the focused segment, starting with the
+    /// hours if no segment is focused.
+    ///
+    /// The focus moves on to the next segment when a segment is complete or
+    /// a `:` is typed.
+    fn type_character(&mut self, character: char) -> event::Status {
+        if self.focus.is_none() && character.is_ascii_digit() {
+            self.focus = Some(Unit::Hours);
+        }
+
+        let Some(unit) = self.focus else {
+            return event::Status::Ignored;
+        };
+
+        let units = self.units();
+        let next_segment = |state: &mut Self| {
+            if let Some(next) = units
+                .iter()
+                .position(|u| *u == unit)
+                .and_then(|index| units.get(index + 1))
+            {
+                state.focus = Some(*next);
+            }
+        };
+
+        if character == ':' {
+            self.typed = None;
+            next_segment(self);
+            return event::Status::Captured;
+        }
+
+        let Some(digit) = character.to_digit(10) else {
+            return event::Status::Ignored;
+        };
+        let range = match unit {
+            Unit::Hours => 0..=u32::try_from(Unit::Hours.of(self.max)).unwrap_or(u32::MAX),
+            Unit::Minutes | Unit::Seconds => 0..=59,
+        };
+        let typed = self
+            .typed
+            .filter(|(typed_unit, _)| *typed_unit == unit)
+            .map(|(_, typed)| typed);
+        let (value, more) = clock::type_digit(typed, digit, range);
+
+        if let Some(value) = value {
+            self.duration = duration::with_unit(self.duration, unit, u64::from(value), self.max);
+        }
+
+        self.typed = more.map(|typed| (unit, typed));
+        if value.is_some() && more.is_none() {
+            next_segment(self);
+        }
+
+        event::Status::Captured
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            duration: Duration::ZERO,
+            max: Duration::from_secs(99 * 3600 + 59 * 60 + 59),
+            show_seconds: false,
+            focus: None,
+            typed: None,
+            keyboard_modifiers: keyboard::Modifiers::default(),
+        }
+    }
+}
+
+/// Just a workaround to pass the button states from the tree to the overlay
+#[allow(missing_debug_implementations)]
+pub struct DurationPickerOverlayButtons<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: StyleSheet + button::StyleSheet,
+{
+    /// The cancel button of the [`DurationPickerOverlay`].
+    cancel_button: Element<'a, Message, Renderer<Theme>>,
+    /// The submit button of the [`DurationPickerOverlay`].
+    submit_button: Element<'a, Message, Renderer<Theme>>,
+}
+
+impl<'a, Message, Theme> Default for DurationPickerOverlayButtons<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a + StyleSheet + button::StyleSheet + iced_widget::text::StyleSheet,
+{
+    fn default() -> Self {
+        Self {
+            cancel_button: Button::new(
+                iced_widget::Text::new(icon_to_char(Icon::X).to_string())
+                    .font(ICON_FONT)
+                    .horizontal_alignment(Horizontal::Center)
+                    .width(Length::Fill),
+            )
+            .into(),
+            submit_button: Button::new(
+                iced_widget::Text::new(icon_to_char(Icon::Check).to_string())
+                    .font(ICON_FONT)
+                    .horizontal_alignment(Horizontal::Center)
+                    .width(Length::Fill),
+            )
+            .into(),
+        }
+    }
+}
+
+#[allow(clippy::unimplemented)]
+impl<Message, Theme> Widget<Message, Renderer<Theme>>
+    for DurationPickerOverlayButtons<'_, Message, Theme>
+where
+    Message: Clone,
+    Theme: StyleSheet + button::StyleSheet,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![
+            Tree::new(&self.cancel_button),
+            Tree::new(&self.submit_button),
+        ]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.cancel_button, &self.submit_button]);
+    }
+
+    fn width(&self) -> Length {
+        unimplemented!("This should never be reached!")
+    }
+
+    fn height(&self) -> Length {
+        unimplemented!("This should never be reached!")
+    }
+
+    fn layout(&self, _renderer: &Renderer<Theme>, _limits: &Limits) -> Node {
+        unimplemented!("This should never be reached!")
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        _renderer: &mut Renderer<Theme>,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        unimplemented!("This should never be reached!")
+    }
+}
+
+impl<'a, Message, Theme> From<DurationPickerOverlayButtons<'a, Message, Theme>>
+    for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'a + Clone,
+    Theme: 'a + StyleSheet + button::StyleSheet,
+{
+    fn from(overlay: DurationPickerOverlayButtons<'a, Message, Theme>) -> Self {
+        Self::new(overlay)
+    }
+}
@@ -0,0 +1,487 @@
+//! Use an editable grid to enter data in a spreadsheet like table of cells.
+//!
+//! *This API requires the following crate features to be activated: `editable_grid`*
+use crate::core::column_layout::ColumnLayout;
+use crate::native::editable_grid::State;
+use crate::style::editable_grid::StyleSheet;
+
+use iced_widget::{
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event, keyboard,
+        layout::Node,
+        mouse::{self, Cursor},
+        overlay, renderer, text, touch, Clipboard, Color, Event, Layout, Point, Rectangle, Shell,
+        Size,
+    },
+    text::LineHeight,
+};
+
+/// The width of the column menu.
+const WIDTH: f32 = 180.0;
+/// The padding around the entries of the column menu.
+const PADDING: f32 = 4.0;
+/// The size of the check boxes of the column menu.
+const CHECK_BOX_SIZE: f32 = 12.0;
+/// The minimum width of the dropdown list of a cell.
+const CHOICE_LIST_MIN_WIDTH: f32 = 120.0;
+
+/// The header context menu of the [`EditableGrid`](crate::native::EditableGrid)
+/// for showing and hiding its columns.
+#[allow(missing_debug_implementations)]
+pub struct ColumnMenu<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The state shared between the [`EditableGrid`](crate::native::EditableGrid) and the [`ColumnMenu`].
+    state: &'a mut State,
+    /// The current layout of the columns.
+    layout: &'a ColumnLayout,
+    /// The number of columns of the data.
+    columns: usize,
+    /// The names of the columns, indexed by data column.
+    names: Vec<String>,
+    /// The function producing the message when the layout of the columns changes.
+    on_change: &'a dyn Fn(ColumnLayout) -> Message,
+    /// The position the menu was opened at.
+    position: Point,
+    /// The height of an entry of the menu.
+    entry_height: f32,
+    /// The text size of the entries of the menu.
+    text_size: f32,
+    /// The font of the entries of the menu.
+    font: Renderer::Font,
+    /// The style of the [`EditableGrid`](crate::native::EditableGrid).
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> ColumnMenu<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`ColumnMenu`] at the given position.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        state: &'a mut State,
+        layout: &'a ColumnLayout,
+        names: Vec<String>,
+        on_change: &'a dyn Fn(ColumnLayout) -> Message,
+        position: Point,
+        entry_height: f32,
+        text_size: f32,
+        font: Renderer::Font,
+        style: <Renderer::Theme as StyleSheet>::Style,
+    ) -> Self {
+        Self {
+            state,
+            layout,
+            columns: names.len(),
+            names,
+            on_change,
+            position,
+            entry_height,
+            text_size,
+            font,
+            style,
+        }
+    }
+
+    /// Turn this [`ColumnMenu`] into an overlay [`Element`](overlay::Element).
+    pub fn overlay(self) -> overlay::Element<'a, Message, Renderer>
+    where
+        Message: 'a,
+        Renderer: 'a,
+    {
+        overlay::Element::new(self.position, Box::new(self))
+    }
+
+    /// Returns the bounds of the entry at the given index.
+    #[allow(clippy::cast_precision_loss)]
+    fn entry_bounds(&self, bounds: Rectangle, index: usize) -> Rectangle {
+        Rectangle {
+            x: bounds.x + PADDING,
+            y: bounds.y + PADDING + index as f32 * self.entry_height,
+            width: bounds.width - PADDING * 2.0,
+            height: self.entry_height,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for ColumnMenu<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    #[allow(clippy::cast_precision_loss)]
+    fn layout(&self, _renderer: &Renderer, bounds: Size, position: Point) -> Node {
+        let size = Size::new(
+            WIDTH,
+            self.columns as f32 * self.entry_height + PADDING * 2.0,
+        );
+
+        let mut node = Node::new(size);
+        node.move_to(Point::new(
+            position.x.min((bounds.width - size.width).max(0.0)),
+            position.y.min((bounds.height - size.height).max(0.0)),
+        ));
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) => {
+                self.state.menu = None;
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position() else {
+                    return event::Status::Ignored;
+                };
+
+                if !bounds.contains(position) {
+                    self.state.menu = None;
+                    return event::Status::Ignored;
+                }
+
+                let order = self.layout.order(self.columns);
+                let Some(column) = (0..order.len())
+                    .find(|index| self.entry_bounds(bounds, *index).contains(position))
+                    .map(|index| order[index])
+                else {
+                    return event::Status::Captured;
+                };
+
+                // The last visible column cannot be hidden.
+                if self.layout.is_hidden(column) || self.layout.visible(self.columns).len() > 1 {
+                    let mut layout = self.layout.clone();
+                    layout.toggle(column);
+                    shell.publish((self.on_change)(layout));
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (2.0).into(),
+                border_width: 1.0,
+                border_color: appearance.grid_line_color,
+            },
+            appearance.header_background,
+        );
+
+        for (index, column) in self.layout.order(self.columns).into_iter().enumerate() {
+            let entry = self.entry_bounds(bounds, index);
+
+            if cursor.is_over(entry) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: entry,
+                        border_radius: (2.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.selection_background,
+                );
+            }
+
+            let check_box = Rectangle {
+                x: entry.x + PADDING,
+                y: entry.center_y() - CHECK_BOX_SIZE / 2.0,
+                width: CHECK_BOX_SIZE,
+                height: CHECK_BOX_SIZE,
+            };
+            let visible = !self.layout.is_hidden(column);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: check_box,
+                    border_radius: (2.0).into(),
+                    border_width: 1.0,
+                    border_color: if visible {
+                        appearance.focus_border_color
+                    } else {
+                        appearance.grid_line_color
+                    },
+                },
+                if visible {
+                    appearance.focus_border_color
+                } else {
+                    Color::TRANSPARENT
+                },
+            );
+
+            renderer.fill_text(core::text::Text {
+                content: &self.names[column],
+                bounds: Rectangle {
+                    x: check_box.x + CHECK_BOX_SIZE + PADDING * 2.0,
+                    y: entry.center_y(),
+                    ..entry
+                },
+                size: self.text_size,
+                line_height: LineHeight::default(),
+                color: appearance.header_text_color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Basic,
+            });
+        }
+    }
+}
+
+/// The dropdown list of a cell of the [`EditableGrid`](crate::native::EditableGrid)
+/// edited by a [`CellEditor::Choice`](crate::core::cell_editor::CellEditor::Choice).
+#[allow(missing_debug_implementations)]
+pub struct ChoiceList<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The state shared between the [`EditableGrid`](crate::native::EditableGrid) and the [`ChoiceList`].
+    state: &'a mut State,
+    /// The options to choose from.
+    options: &'a [String],
+    /// The function producing the message when an option is chosen.
+    on_select: Box<dyn Fn(String) -> Message + 'a>,
+    /// The bounds of the edited cell.
+    cell: Rectangle,
+    /// The height of an option of the list.
+    entry_height: f32,
+    /// The text size of the options of the list.
+    text_size: f32,
+    /// The font of the options of the list.
+    font: Renderer::Font,
+    /// The style of the [`EditableGrid`](crate::native::EditableGrid).
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> ChoiceList<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`ChoiceList`] below the given edited cell.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        state: &'a mut State,
+        options: &'a [String],
+        on_select: Box<dyn Fn(String) -> Message + 'a>,
+        cell: Rectangle,
+        entry_height: f32,
+        text_size: f32,
+        font: Renderer::Font,
+        style: <Renderer::Theme as StyleSheet>::Style,
+    ) -> Self {
+        Self {
+            state,
+            options,
+            on_select,
+            cell,
+            entry_height,
+            text_size,
+            font,
+            style,
+        }
+    }
+
+    /// Turn this [`ChoiceList`] into an overlay [`Element`](overlay::Element).
+    pub fn overlay(self) -> overlay::Element<'a, Message, Renderer>
+    where
+        Message: 'a,
+        Renderer: 'a,
+    {
+        overlay::Element::new(self.cell.position(), Box::new(self))
+    }
+
+    /// Returns the bounds of the option at the given index.
+    #[allow(clippy::cast_precision_loss)]
+    fn entry_bounds(&self, bounds: Rectangle, index: usize) -> Rectangle {
+        Rectangle {
+            x: bounds.x + PADDING,
+            y: bounds.y + PADDING + index as f32 * self.entry_height,
+            width: bounds.width - PADDING * 2.0,
+            height: self.entry_height,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for ChoiceList<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    #[allow(clippy::cast_precision_loss)]
+    fn layout(&self, _renderer: &Renderer, bounds: Size, position: Point) -> Node {
+        let size = Size::new(
+            self.cell.width.max(CHOICE_LIST_MIN_WIDTH),
+            self.options.len() as f32 * self.entry_height + PADDING * 2.0,
+        );
+
+        // Open below the cell, or above if there is no room below.
+        let below = position.y + self.cell.height;
+        let y = if below + size.height <= bounds.height {
+            below
+        } else {
+            (position.y - size.height).max(0.0)
+        };
+
+        let mut node = Node::new(size);
+        node.move_to(Point::new(
+            position.x.min((bounds.width - size.width).max(0.0)),
+            y,
+        ));
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            let Some(position) = cursor.position_over(bounds) else {
+                return event::Status::Ignored;
+            };
+
+            if let Some(option) = (0..self.options.len())
+                .find(|index| self.entry_bounds(bounds, *index).contains(position))
+                .map(|index| &self.options[index])
+            {
+                shell.publish((self.on_select)(option.clone()));
+                self.state.editor = None;
+            }
+
+            return event::Status::Captured;
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let highlighted = self.state.editor.as_ref().and_then(|editor| editor.choice);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (2.0).into(),
+                border_width: 1.0,
+                border_color: appearance.grid_line_color,
+            },
+            appearance.editor_background,
+        );
+
+        for (index, option) in self.options.iter().enumerate() {
+            let entry = self.entry_bounds(bounds, index);
+
+            if highlighted == Some(index) || cursor.is_over(entry) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: entry,
+                        border_radius: (2.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.selection_background,
+                );
+            }
+
+            renderer.fill_text(core::text::Text {
+                content: option,
+                bounds: Rectangle {
+                    x: entry.x + PADDING,
+                    y: entry.center_y(),
+                    ..entry
+                },
+                size: self.text_size,
+                line_height: LineHeight::default(),
+                color: appearance.text_color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Basic,
+            });
+        }
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! *This API requires the following crate features to be activated: `floating_element`*
 
-use crate::native::floating_element::{Anchor, Offset};
+use crate::native::floating_element::{Anchor, Offset, Stacking};
 
 use iced_widget::core::{
     self, event, layout,
@@ -13,17 +13,19 @@ use iced_widget::core::{
 };
 
 /// The internal overlay of a [`FloatingElement`](crate::FloatingElement) for
-/// rendering a [`Element`](iced_widget::core::Element) as an overlay.
+/// rendering its [`Element`](iced_widget::core::Element)s as an overlay.
 #[allow(missing_debug_implementations)]
 pub struct FloatingElementOverlay<'a, 'b, Message, Renderer: core::Renderer> {
-    /// The state of the element.
-    state: &'b mut Tree,
-    /// The floating element
-    element: &'b mut Element<'a, Message, Renderer>,
-    /// The anchor of the element.
-    anchor: &'b Anchor,
-    /// The offset of the element.
+    /// The states of the elements.
+    state: &'b mut [Tree],
+    /// The floating elements.
+    elements: &'b mut [Element<'a, Message, Renderer>],
+    /// The anchors of the elements.
+    anchors: Vec<Anchor>,
+    /// The offset of the elements.
     offset: &'b Offset,
+    /// The stacking of the elements sharing an anchor and their spacing.
+    stacking: (Stacking, f32),
     /// The bounds of the underlay element.
     underlay_bounds: Rectangle,
 }
@@ -33,22 +35,71 @@ where
     Renderer: core::Renderer,
 {
     /// Creates a new [`FloatingElementOverlay`] containing the given
-    /// [`Element`](iced_widget::core::Element).
+    /// [`Element`](iced_widget::core::Element)s.
     pub fn new(
-        state: &'b mut Tree,
-        element: &'b mut Element<'a, Message, Renderer>,
-        anchor: &'b Anchor,
+        state: &'b mut [Tree],
+        elements: &'b mut [Element<'a, Message, Renderer>],
+        anchors: Vec<Anchor>,
         offset: &'b Offset,
+        stacking: (Stacking, f32),
         underlay_bounds: Rectangle,
     ) -> Self {
         FloatingElementOverlay {
             state,
-            element,
-            anchor,
+            elements,
+            anchors,
             offset,
+            stacking,
             underlay_bounds,
         }
     }
+
+    /// Returns the position of an element of the given size at the given
+    /// [`Anchor`], relative to the underlay.
+    fn anchored(&self, anchor: Anchor, size: Size) -> Point {
+        let underlay = self.underlay_bounds.size();
+
+        match anchor {
+            Anchor::NorthWest => Point::new(self.offset.x, self.offset.y),
+            Anchor::NorthEast => {
+                Point::new(underlay.width - size.width - self.offset.x, self.offset.y)
+            }
+            Anchor::SouthWest => {
+                Point::new(self.offset.x, underlay.height - size.height - self.offset.y)
+            }
+            Anchor::SouthEast => Point::new(
+                underlay.width - size.width - self.offset.x,
+                underlay.height - size.height - self.offset.y,
+            ),
+            Anchor::North => Point::new(
+                underlay.width / 2.0 - size.width / 2.0 + self.offset.x,
+                self.offset.y,
+            ),
+            Anchor::East => Point::new(
+                underlay.width - size.width - self.offset.x,
+                underlay.height / 2.0 - size.height / 2.0 + self.offset.y,
+            ),
+            Anchor::South => Point::new(
+                underlay.width / 2.0 - size.width / 2.0 + self.offset.x,
+                underlay.height - size.height - self.offset.y,
+            ),
+            Anchor::West => Point::new(
+                self.offset.x,
+                underlay.height / 2.0 - size.height / 2.0 + self.offset.y,
+            ),
+        }
+    }
+}
+
+/// Returns the direction in which the elements at the given [`Anchor`]
+/// cascade, away from the edge they are anchored to.
+fn cascade(anchor: Anchor, stacking: Stacking) -> (f32, f32) {
+    match (stacking, anchor) {
+        (Stacking::Vertical, Anchor::SouthWest | Anchor::South | Anchor::SouthEast) => (0.0, -1.0),
+        (Stacking::Vertical, _) => (0.0, 1.0),
+        (Stacking::Horizontal, Anchor::NorthEast | Anchor::East | Anchor::SouthEast) => (-1.0, 0.0),
+        (Stacking::Horizontal, _) => (1.0, 0.0),
+    }
 }
 
 impl<'a, 'b, Message, Renderer> core::Overlay<Message, Renderer>
@@ -61,44 +112,45 @@ where
         let limits = layout::Limits::new(Size::ZERO, self.underlay_bounds.size())
             .width(Length::Fill)
             .height(Length::Fill);
-        let mut node = self.element.as_widget().layout(renderer, &limits);
+        let (stacking, spacing) = self.stacking;
 
-        let position = match self.anchor {
-            Anchor::NorthWest => Point::new(position.x + self.offset.x, position.y + self.offset.y),
-            Anchor::NorthEast => Point::new(
-                position.x + self.underlay_bounds.width - node.bounds().width - self.offset.x,
-                position.y + self.offset.y,
-            ),
-            Anchor::SouthWest => Point::new(
-                position.x + self.offset.x,
-                position.y + self.underlay_bounds.height - node.bounds().height - self.offset.y,
-            ),
-            Anchor::SouthEast => Point::new(
-                position.x + self.underlay_bounds.width - node.bounds().width - self.offset.x,
-                position.y + self.underlay_bounds.height - node.bounds().height - self.offset.y,
-            ),
-            Anchor::North => Point::new(
-                position.x + self.underlay_bounds.width / 2.0 - node.bounds().width / 2.0
-                    + self.offset.x,
-                position.y + self.offset.y,
-            ),
-            Anchor::East => Point::new(
-                position.x + self.underlay_bounds.width - node.bounds().width - self.offset.x,
-                position.y + self.underlay_bounds.height / 2.0 - node.bounds().height / 2.0
-                    + self.offset.y,
-            ),
-            Anchor::South => Point::new(
-                position.x + self.underlay_bounds.width / 2.0 - node.bounds().width / 2.0
-                    + self.offset.x,
-                position.y + self.underlay_bounds.height - node.bounds().height - self.offset.y,
-            ),
-            Anchor::West => Point::new(
-                position.x + self.offset.x,
-                position.y + self.underlay_bounds.height / 2.0 - node.bounds().height / 2.0
-                    + self.offset.y,
-            ),
-        };
+        // The distance already covered by the elements stacked at an anchor.
+        let mut stacked: Vec<(Anchor, f32)> = Vec::new();
 
+        let children = self
+            .elements
+            .iter()
+            .zip(&self.anchors)
+            .map(|(element, anchor)| {
+                let mut node = element.as_widget().layout(renderer, &limits);
+                let size = node.size();
+
+                let index = stacked
+                    .iter()
+                    .position(|(other, _)| other == anchor)
+                    .unwrap_or_else(|| {
+                        stacked.push((*anchor, 0.0));
+                        stacked.len() - 1
+                    });
+                let distance = &mut stacked[index].1;
+
+                let (dx, dy) = cascade(*anchor, stacking);
+                let origin = self.anchored(*anchor, size);
+                node.move_to(Point::new(
+                    origin.x + dx * *distance,
+                    origin.y + dy * *distance,
+                ));
+
+                *distance += match stacking {
+                    Stacking::Vertical => size.height,
+                    Stacking::Horizontal => size.width,
+                } + spacing;
+
+                node
+            })
+            .collect();
+
+        let mut node = layout::Node::with_children(self.underlay_bounds.size(), children);
         node.move_to(position);
         node
     }
@@ -112,16 +164,23 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<Message>,
     ) -> event::Status {
-        self.element.as_widget_mut().on_event(
-            self.state,
-            event,
-            layout,
-            cursor,
-            renderer,
-            clipboard,
-            shell,
-            &layout.bounds(),
-        )
+        self.elements
+            .iter_mut()
+            .zip(self.state.iter_mut())
+            .zip(layout.children())
+            .map(|((element, state), layout)| {
+                element.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    &layout.bounds(),
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
     }
 
     fn mouse_interaction(
@@ -131,9 +190,17 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        self.element
-            .as_widget()
-            .mouse_interaction(self.state, layout, cursor, viewport, renderer)
+        self.elements
+            .iter()
+            .zip(self.state.iter())
+            .zip(layout.children())
+            .map(|((element, state), layout)| {
+                element
+                    .as_widget()
+                    .mouse_interaction(state, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
     }
 
     fn draw(
@@ -144,10 +211,23 @@ where
         layout: Layout<'_>,
         cursor: Cursor,
     ) {
-        let bounds = layout.bounds();
-        self.element
-            .as_widget()
-            .draw(self.state, renderer, theme, style, layout, cursor, &bounds);
+        for ((element, state), layout) in self
+            .elements
+            .iter()
+            .zip(self.state.iter())
+            .zip(layout.children())
+        {
+            let bounds = layout.bounds();
+            element
+                .as_widget()
+                .draw(state, renderer, theme, style, layout, cursor, &bounds);
+        }
+    }
+
+    fn is_over(&self, layout: Layout<'_>, _renderer: &Renderer, cursor_position: Point) -> bool {
+        layout
+            .children()
+            .any(|layout| layout.bounds().contains(cursor_position))
     }
 
     fn overlay<'c>(
@@ -155,8 +235,16 @@ where
         layout: Layout<'_>,
         renderer: &Renderer,
     ) -> Option<overlay::Element<'c, Message, Renderer>> {
-        self.element
-            .as_widget_mut()
-            .overlay(self.state, layout, renderer)
+        let children: Vec<_> = self
+            .elements
+            .iter_mut()
+            .zip(self.state.iter_mut())
+            .zip(layout.children())
+            .filter_map(|((element, state), layout)| {
+                element.as_widget_mut().overlay(state, layout, renderer)
+            })
+            .collect();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
     }
 }
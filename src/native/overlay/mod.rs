@@ -25,7 +25,47 @@ pub mod time_picker;
 #[cfg(feature = "time_picker")]
 pub use time_picker::{State, TimePickerOverlay};
 
+#[cfg(feature = "date_time_picker")]
+pub mod date_time_picker;
+#[cfg(feature = "date_time_picker")]
+pub use date_time_picker::DateTimePickerOverlay;
+
 #[cfg(feature = "context_menu")]
 pub mod context_menu;
 #[cfg(feature = "context_menu")]
 pub use context_menu::ContextMenuOverlay;
+
+#[cfg(feature = "drawer")]
+pub mod drawer;
+#[cfg(feature = "drawer")]
+pub use drawer::DrawerOverlay;
+
+#[cfg(feature = "seek_bar")]
+pub mod seek_bar;
+#[cfg(feature = "seek_bar")]
+pub use seek_bar::SeekBarOverlay;
+
+#[cfg(feature = "transport_controls")]
+pub mod transport_controls;
+#[cfg(feature = "transport_controls")]
+pub use transport_controls::VolumePopover;
+
+#[cfg(feature = "editable_grid")]
+pub mod editable_grid;
+#[cfg(feature = "editable_grid")]
+pub use editable_grid::{ChoiceList, ColumnMenu};
+
+#[cfg(feature = "split_button")]
+pub mod split_button;
+#[cfg(feature = "split_button")]
+pub use split_button::SplitButtonMenu;
+
+#[cfg(feature = "num_pad")]
+pub mod num_pad;
+#[cfg(feature = "num_pad")]
+pub use num_pad::NumPadOverlay;
+
+#[cfg(feature = "duration_picker")]
+pub mod duration_picker;
+#[cfg(feature = "duration_picker")]
+pub use duration_picker::DurationPickerOverlay;
@@ -4,18 +4,23 @@
 use iced_widget::core::{
     self, alignment, event, keyboard, layout,
     mouse::{self, Cursor},
-    renderer, touch,
+    overlay, renderer, touch,
     widget::Tree,
-    Alignment, Clipboard, Color, Element, Event, Layout, Overlay, Point, Rectangle, Shell, Size,
+    Alignment, Background, Clipboard, Color, Element, Event, Layout, Overlay, Padding, Point,
+    Rectangle, Shell, Size,
 };
 
+use crate::core::focus::{cycle, FindFocused, FocusIndex};
+use crate::native::modal::{Drag, Edge, FocusTrap, Transition};
 use crate::style::modal::StyleSheet;
 
+/// The distance the content rises by with [`Transition::Rise`].
+const RISE_DISTANCE: f32 = 24.0;
+
 /// The overlay of the modal.
 #[allow(missing_debug_implementations)]
 pub struct ModalOverlay<'a, 'b, Message, Renderer>
 where
-    Message: Clone,
     Renderer: core::Renderer,
     Renderer::Theme: StyleSheet,
 {
@@ -23,63 +28,145 @@ where
     state: &'b mut Tree,
     /// The content of the [`ModalOverlay`](ModalOverlay).
     content: &'b mut Element<'a, Message, Renderer>,
-    /// The optional message that will be send when the user clicks on the backdrop.
-    backdrop: Option<Message>,
-    /// The optional message that will be send when the ESC key was pressed.
-    esc: Option<Message>,
+    /// The optional function producing the message that will be send when
+    /// the user clicks on the backdrop.
+    backdrop: Option<&'b dyn Fn() -> Message>,
+    /// The optional function producing the message that will be send when
+    /// the ESC key was pressed.
+    esc: Option<&'b dyn Fn() -> Message>,
+    /// The keys closing the [`ModalOverlay`](ModalOverlay).
+    close_keys: &'b [keyboard::KeyCode],
+    /// The optional function producing the message with the key closing the
+    /// [`ModalOverlay`](ModalOverlay).
+    on_close_key: Option<&'b dyn Fn(keyboard::KeyCode) -> Message>,
     /// The style of the [`ModalOverlay`](ModalOverlay).
     style: <Renderer::Theme as StyleSheet>::Style,
+    /// The horizontal alignment of the content of the [`ModalOverlay`](ModalOverlay).
     horizontal_alignment: alignment::Horizontal,
+    /// The vertical alignment of the content of the [`ModalOverlay`](ModalOverlay).
     vertical_alignment: alignment::Vertical,
+    /// The padding between the content of the [`ModalOverlay`](ModalOverlay)
+    /// and the edges of the viewport.
+    padding: Padding,
+    /// Whether the [`ModalOverlay`](ModalOverlay) is open, or closing.
+    open: bool,
+    /// The transition of the [`ModalOverlay`](ModalOverlay).
+    transition: Transition,
+    /// The eased progress of the transition, from 0.0 (closed) to 1.0 (open).
+    progress: f32,
+    /// The focus trapped inside of the content.
+    focus_trap: &'b mut FocusTrap,
+    /// The height of the region at the top of the content dragging it.
+    drag_handle: Option<f32>,
+    /// The dragging of the content.
+    drag: &'b mut Drag,
 }
 
 impl<'a, 'b, Message, Renderer> ModalOverlay<'a, 'b, Message, Renderer>
 where
-    Message: Clone,
     Renderer: core::Renderer,
     Renderer::Theme: StyleSheet,
 {
     /// Creates a new [`ModalOverlay`](ModalOverlay).
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         state: &'b mut Tree,
         content: &'b mut Element<'a, Message, Renderer>,
-        backdrop: Option<Message>,
-        esc: Option<Message>,
+        backdrop: Option<&'b dyn Fn() -> Message>,
+        esc: Option<&'b dyn Fn() -> Message>,
+        close_keys: &'b [keyboard::KeyCode],
+        on_close_key: Option<&'b dyn Fn(keyboard::KeyCode) -> Message>,
         style: <Renderer::Theme as StyleSheet>::Style,
         horizontal_alignment: alignment::Horizontal,
         vertical_alignment: alignment::Vertical,
+        padding: Padding,
+        open: bool,
+        transition: Transition,
+        progress: f32,
+        focus_trap: &'b mut FocusTrap,
+        drag_handle: Option<f32>,
+        drag: &'b mut Drag,
     ) -> Self {
         ModalOverlay {
             state,
             content,
             backdrop,
             esc,
+            close_keys,
+            on_close_key,
             style,
             horizontal_alignment,
             vertical_alignment,
+            padding,
+            open,
+            transition,
+            progress,
+            focus_trap,
+            drag_handle,
+            drag,
         }
     }
+
+    /// Returns the bounds of the region dragging the content with the given
+    /// bounds, if any.
+    fn drag_handle_bounds(&self, content: Rectangle) -> Option<Rectangle> {
+        self.drag_handle.map(|height| Rectangle {
+            height: height.min(content.height),
+            ..content
+        })
+    }
 }
 
 impl<'a, 'b, Message, Renderer> Overlay<Message, Renderer>
     for ModalOverlay<'a, 'b, Message, Renderer>
 where
-    Message: Clone,
     Renderer: core::Renderer,
     Renderer::Theme: StyleSheet,
 {
     fn layout(&self, renderer: &Renderer, bounds: Size, _position: Point) -> layout::Node {
-        let limits = layout::Limits::new(Size::ZERO, bounds);
+        let limits = layout::Limits::new(Size::ZERO, bounds).pad(self.padding);
         let mut content = self.content.as_widget().layout(renderer, &limits);
-        let max_size = limits.max();
 
         content.align(
             Alignment::from(self.horizontal_alignment),
             Alignment::from(self.vertical_alignment),
-            max_size,
+            limits.max(),
         );
 
-        layout::Node::with_children(max_size, vec![content])
+        let content_bounds = content.bounds();
+        let hidden = 1.0 - self.progress;
+        let (offset_x, offset_y) = match self.transition {
+            Transition::None => (0.0, 0.0),
+            Transition::Rise => (0.0, RISE_DISTANCE * hidden),
+            Transition::Slide(Edge::Top) => (
+                0.0,
+                -(content_bounds.y + self.padding.top + content_bounds.height) * hidden,
+            ),
+            Transition::Slide(Edge::Bottom) => (
+                0.0,
+                (bounds.height - content_bounds.y - self.padding.top) * hidden,
+            ),
+            Transition::Slide(Edge::Left) => (
+                -(content_bounds.x + self.padding.left + content_bounds.width) * hidden,
+                0.0,
+            ),
+            Transition::Slide(Edge::Right) => (
+                (bounds.width - content_bounds.x - self.padding.left) * hidden,
+                0.0,
+            ),
+        };
+
+        let position = self.drag.position(
+            Point::new(
+                content_bounds.x + self.padding.left,
+                content_bounds.y + self.padding.top,
+            ),
+            content_bounds.size(),
+            bounds,
+        );
+        content.move_to(Point::new(position.x + offset_x, position.y + offset_y));
+
+        layout::Node::with_children(bounds, vec![content])
     }
 
     fn on_event(
@@ -91,57 +178,151 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<Message>,
     ) -> event::Status {
+        // A closing modal only plays its transition out.
+        if !self.open {
+            return event::Status::Ignored;
+        }
+
         let viewport = layout.bounds();
-        // TODO clean this up
-        let esc_status = self
-            .esc
-            .as_ref()
-            .map_or(event::Status::Ignored, |esc| match event {
-                Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => {
-                    if key_code == keyboard::KeyCode::Escape {
-                        shell.publish(esc.to_owned());
-                        event::Status::Captured
-                    } else {
-                        event::Status::Ignored
-                    }
-                }
-                _ => event::Status::Ignored,
-            });
+        let content_layout = layout
+            .children()
+            .next()
+            .expect("Native: Layout should have a content layout.");
 
-        let backdrop_status = self.backdrop.as_ref().zip(layout.children().next()).map_or(
+        if self.focus_trap.take_pending() {
+            self.content.as_widget().operate(
+                self.state,
+                content_layout,
+                renderer,
+                &mut FocusIndex::new(Some(0)),
+            );
+        }
+
+        let close_status = match event {
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. })
+                if self.close_keys.contains(&key_code) =>
+            {
+                let message = match (self.on_close_key, self.esc) {
+                    (Some(on_close_key), _) => Some(on_close_key(key_code)),
+                    (None, esc) => esc.map(|esc| esc()),
+                };
+
+                message.map_or(event::Status::Ignored, |message| {
+                    shell.publish(message);
+                    event::Status::Captured
+                })
+            }
+            _ => event::Status::Ignored,
+        };
+
+        let backdrop_status = self.backdrop.zip(layout.children().next()).map_or(
             event::Status::Ignored,
             |(backdrop, layout)| match event {
                 Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
                 | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                    if layout
-                        .bounds()
-                        .contains(cursor.position().unwrap_or_default())
+                    // The cursor is unavailable while it is over a modal
+                    // stacked on top of this one.
+                    if cursor
+                        .position()
+                        .is_some_and(|position| !layout.bounds().contains(position))
                     {
-                        event::Status::Ignored
-                    } else {
-                        shell.publish(backdrop.to_owned());
+                        shell.publish(backdrop());
                         event::Status::Captured
+                    } else {
+                        event::Status::Ignored
                     }
                 }
                 _ => event::Status::Ignored,
             },
         );
 
-        match esc_status.merge(backdrop_status) {
-            event::Status::Ignored => self.content.as_widget_mut().on_event(
-                self.state,
-                event,
-                layout
-                    .children()
-                    .next()
-                    .expect("Native: Layout should have a content layout."),
-                cursor,
-                renderer,
-                clipboard,
-                shell,
-                &viewport,
-            ),
-            event::Status::Captured => event::Status::Captured,
+        if close_status.merge(backdrop_status) == event::Status::Captured {
+            return event::Status::Captured;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if self.drag.is_dragging() =>
+            {
+                self.drag
+                    .drag(position, content_layout.bounds(), viewport.size());
+                shell.invalidate_layout();
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if self.drag.end() =>
+            {
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        let content_status = self.content.as_widget_mut().on_event(
+            self.state,
+            event.clone(),
+            content_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &viewport,
+        );
+
+        // Tab and Shift+Tab cycle the focus inside of the content.
+        let status = match (content_status, &event) {
+            (
+                event::Status::Ignored,
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key_code: keyboard::KeyCode::Tab,
+                    modifiers,
+                }),
+            ) => {
+                let mut find_focused = FindFocused::unfocusing();
+                self.content.as_widget().operate(
+                    self.state,
+                    content_layout,
+                    renderer,
+                    &mut find_focused,
+                );
+                self.content.as_widget().operate(
+                    self.state,
+                    content_layout,
+                    renderer,
+                    &mut FocusIndex::new(cycle(
+                        find_focused.focused,
+                        find_focused.count,
+                        !modifiers.shift(),
+                    )),
+                );
+
+                event::Status::Captured
+            }
+            // A press on the handle not handled by the content drags it.
+            (
+                event::Status::Ignored,
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. }),
+            ) => {
+                if let Some(position) = cursor.position_over(
+                    self.drag_handle_bounds(content_layout.bounds())
+                        .unwrap_or_default(),
+                ) {
+                    self.drag.start(position);
+                }
+
+                event::Status::Ignored
+            }
+            (status, _) => status,
+        };
+
+        // The input never reaches the layers below an open modal, so that
+        // only the top-most of stacked modals handles the ESC key and the
+        // backdrop.
+        match event {
+            Event::Keyboard(_) | Event::Mouse(_) | Event::Touch(_) => event::Status::Captured,
+            Event::Window(_) => status,
         }
     }
 
@@ -152,16 +333,31 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        self.content.as_widget().mouse_interaction(
+        let content_layout = layout
+            .children()
+            .next()
+            .expect("Native: Layout should have a content layout.");
+
+        if self.drag.is_dragging() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let interaction = self.content.as_widget().mouse_interaction(
             self.state,
-            layout
-                .children()
-                .next()
-                .expect("Native: Layout should have a content layout."),
+            content_layout,
             cursor,
             viewport,
             renderer,
-        )
+        );
+
+        match self.drag_handle_bounds(content_layout.bounds()) {
+            Some(handle)
+                if interaction == mouse::Interaction::default() && cursor.is_over(handle) =>
+            {
+                mouse::Interaction::Grab
+            }
+            _ => interaction,
+        }
     }
 
     fn draw(
@@ -176,6 +372,15 @@ where
 
         let style_sheet = theme.active(&self.style);
 
+        // The backdrop fades with the transition.
+        let background = match style_sheet.background {
+            Background::Color(color) => Background::Color(Color {
+                a: color.a * self.progress,
+                ..color
+            }),
+            background @ Background::Gradient(_) => background,
+        };
+
         // Background
         renderer.fill_quad(
             renderer::Quad {
@@ -184,7 +389,7 @@ where
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
             },
-            style_sheet.background,
+            background,
         );
 
         let content_layout = layout
@@ -203,4 +408,18 @@ where
             &bounds,
         );
     }
+
+    fn overlay<'c>(
+        &'c mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'c, Message, Renderer>> {
+        // A modal opened from within the content is stacked on top of this
+        // one, with its own backdrop.
+        let content_layout = layout.children().next()?;
+
+        self.content
+            .as_widget_mut()
+            .overlay(self.state, content_layout, renderer)
+    }
 }
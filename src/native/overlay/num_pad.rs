@@ -0,0 +1,446 @@
+//! Use a numeric keypad to enter numbers without a physical keyboard.
+//!
+//! *This API requires the following crate features to be activated: `num_pad`*
+use crate::core::num_pad::{edit, Key};
+use crate::graphics::icons::{icon_to_char, Icon, ICON_FONT};
+use crate::style::num_pad::{Appearance, StyleSheet};
+
+use iced_widget::{
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event, keyboard,
+        layout::Node,
+        mouse::{self, Cursor},
+        overlay, renderer, text, touch, Clipboard, Color, Event, Font, Layout, Point, Rectangle,
+        Shell, Size,
+    },
+    text::LineHeight,
+};
+
+/// The padding around the display and the keys.
+const PADDING: f32 = 8.0;
+/// The spacing between the keys.
+const SPACING: f32 = 4.0;
+/// The space between the keypad and the element it is attached to.
+const GAP: f32 = 2.0;
+/// The ratio between the height of the display and the size of a key.
+const DISPLAY_RATIO: f32 = 0.75;
+
+/// The keys of the keypad with their column, row, width and height in keys.
+const KEYS: [(Key, u8, u8, u8, u8); 14] = [
+    (Key::Digit(7), 0, 0, 1, 1),
+    (Key::Digit(8), 1, 0, 1, 1),
+    (Key::Digit(9), 2, 0, 1, 1),
+    (Key::Backspace, 3, 0, 1, 1),
+    (Key::Digit(4), 0, 1, 1, 1),
+    (Key::Digit(5), 1, 1, 1, 1),
+    (Key::Digit(6), 2, 1, 1, 1),
+    (Key::Sign, 3, 1, 1, 1),
+    (Key::Digit(1), 0, 2, 1, 1),
+    (Key::Digit(2), 1, 2, 1, 1),
+    (Key::Digit(3), 2, 2, 1, 1),
+    (Key::Submit, 3, 2, 1, 2),
+    (Key::Digit(0), 0, 3, 2, 1),
+    (Key::Decimal, 2, 3, 1, 1),
+];
+
+/// The state of the keypad of a [`NumPad`](crate::native::num_pad::NumPad).
+#[derive(Clone, Debug, Default)]
+pub struct KeypadState {
+    /// Whether the keypad is open.
+    pub(crate) is_open: bool,
+    /// The text of the number being entered.
+    pub(crate) text: String,
+    /// The pressed key.
+    pub(crate) pressed: Option<Key>,
+}
+
+impl KeypadState {
+    /// Opens the keypad editing the given text, unless it is open.
+    pub(crate) fn open(&mut self, text: &str) {
+        if !self.is_open {
+            *self = Self {
+                is_open: true,
+                text: text.to_owned(),
+                pressed: None,
+            };
+        }
+    }
+
+    /// Closes the keypad.
+    pub(crate) fn close(&mut self) {
+        self.is_open = false;
+        self.pressed = None;
+    }
+}
+
+/// The keys enabled on a keypad.
+#[derive(Clone, Copy, Debug)]
+pub struct KeypadKeys {
+    /// Whether the decimal separator is enabled.
+    pub(crate) decimal: bool,
+    /// Whether the sign can be toggled.
+    pub(crate) signed: bool,
+    /// The decimal separator.
+    pub(crate) separator: char,
+}
+
+/// The keypad of a [`NumPad`](crate::native::num_pad::NumPad).
+#[allow(missing_debug_implementations)]
+pub struct NumPadOverlay<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The state shared between the [`NumPad`](crate::native::num_pad::NumPad)
+    /// and the [`NumPadOverlay`].
+    state: &'a mut KeypadState,
+    /// The function producing the message of the edited text.
+    on_change: &'a dyn Fn(String) -> Message,
+    /// The message produced by the submit key.
+    on_submit: Option<&'a Message>,
+    /// The message produced by pressing outside of the keypad or Escape.
+    on_cancel: Option<&'a Message>,
+    /// The bounds of the element the keypad is attached to.
+    anchor: Rectangle,
+    /// The keys enabled on the keypad.
+    keys: KeypadKeys,
+    /// The label of the submit key.
+    submit_label: &'a str,
+    /// The size of a key.
+    key_size: f32,
+    /// The text size of the labels.
+    text_size: f32,
+    /// The font of the labels.
+    font: Font,
+    /// The style of the keypad.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> NumPadOverlay<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`NumPadOverlay`] below the given element.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        state: &'a mut KeypadState,
+        on_change: &'a dyn Fn(String) -> Message,
+        on_submit: Option<&'a Message>,
+        on_cancel: Option<&'a Message>,
+        anchor: Rectangle,
+        keys: KeypadKeys,
+        submit_label: &'a str,
+        (key_size, text_size, font): (f32, f32, Font),
+        style: <Renderer::Theme as StyleSheet>::Style,
+    ) -> Self {
+        Self {
+            state,
+            on_change,
+            on_submit,
+            on_cancel,
+            anchor,
+            keys,
+            submit_label,
+            key_size,
+            text_size,
+            font,
+            style,
+        }
+    }
+
+    /// Turn this [`NumPadOverlay`] into an overlay [`Element`](overlay::Element).
+    pub fn overlay(self) -> overlay::Element<'a, Message, Renderer>
+    where
+        Message: 'a,
+        Renderer: 'a,
+    {
+        overlay::Element::new(self.anchor.position(), Box::new(self))
+    }
+
+    /// Returns the bounds of the display of the entered text.
+    fn display_bounds(&self, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            x: bounds.x + PADDING,
+            y: bounds.y + PADDING,
+            width: bounds.width - PADDING * 2.0,
+            height: self.key_size * DISPLAY_RATIO,
+        }
+    }
+
+    /// Returns the bounds of the key at the given column and row, spanning
+    /// the given number of keys.
+    fn key_bounds(
+        &self,
+        bounds: Rectangle,
+        (column, row, width, height): (u8, u8, u8, u8),
+    ) -> Rectangle {
+        let span = |keys: u8| f32::from(keys) * self.key_size + f32::from(keys - 1) * SPACING;
+        let step = self.key_size + SPACING;
+
+        Rectangle {
+            x: bounds.x + PADDING + f32::from(column) * step,
+            y: bounds.y + PADDING + self.key_size * DISPLAY_RATIO + SPACING + f32::from(row) * step,
+            width: span(width),
+            height: span(height),
+        }
+    }
+
+    /// Returns the enabled key at the given position, if any.
+    fn key_at(&self, bounds: Rectangle, position: Point) -> Option<Key> {
+        KEYS.iter()
+            .find(|(_, column, row, width, height)| {
+                self.key_bounds(bounds, (*column, *row, *width, *height))
+                    .contains(position)
+            })
+            .map(|(key, ..)| *key)
+            .filter(|key| self.is_enabled(*key))
+    }
+
+    /// Returns true if the key can be pressed.
+    fn is_enabled(&self, key: Key) -> bool {
+        match key {
+            Key::Decimal => self.keys.decimal && !self.state.text.contains(self.keys.separator),
+            Key::Sign => self.keys.signed,
+            Key::Submit => self.on_submit.is_some(),
+            Key::Digit(_) | Key::Backspace => true,
+        }
+    }
+
+    /// Presses the key, editing the text or submitting it.
+    fn activate(&mut self, key: Key, shell: &mut Shell<'_, Message>) {
+        if key == Key::Submit {
+            if let Some(on_submit) = self.on_submit {
+                shell.publish(on_submit.clone());
+            }
+            return;
+        }
+
+        let text = edit(&self.state.text, key, self.keys.separator);
+        if text != self.state.text {
+            self.state.text.clone_from(&text);
+            shell.publish((self.on_change)(text));
+        }
+    }
+
+    /// Returns the label of the key and its font.
+    fn label(&self, key: Key) -> (String, Font) {
+        match key {
+            Key::Digit(digit) => (digit.to_string(), self.font),
+            Key::Decimal => (self.keys.separator.to_string(), self.font),
+            Key::Sign => (icon_to_char(Icon::PlusSlashMinus).to_string(), ICON_FONT),
+            Key::Backspace => (icon_to_char(Icon::Backspace).to_string(), ICON_FONT),
+            Key::Submit => (self.submit_label.to_owned(), self.font),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for NumPadOverlay<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(&self, _renderer: &Renderer, bounds: Size, position: Point) -> Node {
+        let keys = self.key_size * 4.0 + SPACING * 3.0;
+        let size = Size::new(
+            keys + PADDING * 2.0,
+            self.key_size * DISPLAY_RATIO + SPACING + keys + PADDING * 2.0,
+        );
+
+        // Open below the element, or above if there is no room below.
+        let below = position.y + self.anchor.height + GAP;
+        let y = if below + size.height <= bounds.height {
+            below
+        } else {
+            (position.y - size.height - GAP).max(0.0)
+        };
+
+        let mut node = Node::new(size);
+        node.move_to(Point::new(
+            position.x.min((bounds.width - size.width).max(0.0)),
+            y,
+        ));
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) => {
+                if let Some(on_cancel) = self.on_cancel {
+                    shell.publish(on_cancel.clone());
+                    return event::Status::Captured;
+                }
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position() else {
+                    return event::Status::Ignored;
+                };
+
+                // A press outside of the keypad cancels it, except on the
+                // element it is attached to.
+                if !bounds.contains(position) {
+                    if let Some(on_cancel) =
+                        self.on_cancel.filter(|_| !self.anchor.contains(position))
+                    {
+                        shell.publish(on_cancel.clone());
+                    }
+                    return event::Status::Ignored;
+                }
+
+                self.state.pressed = self.key_at(bounds, position);
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                let Some(pressed) = self.state.pressed.take() else {
+                    return event::Status::Ignored;
+                };
+
+                if cursor
+                    .position()
+                    .and_then(|position| self.key_at(bounds, position))
+                    == Some(pressed)
+                {
+                    self.activate(pressed, shell);
+                }
+
+                event::Status::Captured
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                self.state.pressed = None;
+                event::Status::Ignored
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor
+            .position()
+            .and_then(|position| self.key_at(layout.bounds(), position))
+            .is_some()
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let active = theme.active(&self.style);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: active.border_radius.into(),
+                border_width: active.border_width,
+                border_color: active.border_color,
+            },
+            active.background,
+        );
+
+        let display = self.display_bounds(bounds);
+        renderer.fill_text(core::text::Text {
+            content: &self.state.text,
+            bounds: Rectangle {
+                x: display.x + display.width - PADDING,
+                y: display.center_y(),
+                ..display
+            },
+            size: self.text_size * 1.2,
+            line_height: LineHeight::default(),
+            color: active.key_text_color,
+            font: self.font,
+            horizontal_alignment: Horizontal::Right,
+            vertical_alignment: Vertical::Center,
+            shaping: text::Shaping::Basic,
+        });
+
+        let hovered = cursor
+            .position()
+            .and_then(|position| self.key_at(bounds, position));
+
+        for (key, column, row, width, height) in KEYS {
+            let key_bounds = self.key_bounds(bounds, (column, row, width, height));
+            let appearance: Appearance = if self.state.pressed == Some(key) {
+                theme.pressed(&self.style)
+            } else if hovered == Some(key) {
+                theme.hovered(&self.style)
+            } else {
+                active
+            };
+
+            let (background, text_color) = if key == Key::Submit {
+                (appearance.submit_background, appearance.submit_text_color)
+            } else {
+                (appearance.key_background, appearance.key_text_color)
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: key_bounds,
+                    border_radius: appearance.key_border_radius.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                background,
+            );
+
+            let (label, font) = self.label(key);
+            renderer.fill_text(core::text::Text {
+                content: &label,
+                bounds: Rectangle {
+                    x: key_bounds.center_x(),
+                    y: key_bounds.center_y(),
+                    ..key_bounds
+                },
+                size: self.text_size,
+                line_height: LineHeight::default(),
+                color: if self.is_enabled(key) {
+                    text_color
+                } else {
+                    appearance.disabled_text_color
+                },
+                font,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Basic,
+            });
+        }
+    }
+}
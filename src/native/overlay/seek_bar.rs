@@ -0,0 +1,134 @@
+//! Use a seek bar to show and change the playback position of some media.
+//!
+//! *This API requires the following crate features to be activated: `seek_bar`*
+use crate::style::seek_bar::StyleSheet;
+
+use iced_widget::{
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        layout::Node,
+        mouse::Cursor,
+        overlay, renderer, text, Color, Point, Rectangle, Size,
+    },
+    text::LineHeight,
+};
+
+/// The padding around the label of the time preview.
+const PADDING: f32 = 4.0;
+/// The distance between the time preview and the rail.
+const SPACING: f32 = 6.0;
+
+/// The hover time preview of the [`SeekBar`](crate::native::SeekBar).
+#[allow(missing_debug_implementations)]
+pub struct SeekBarOverlay<Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The label of the time preview.
+    label: String,
+    /// The point on top of the seek bar the time preview points at.
+    anchor: Point,
+    /// The text size of the label.
+    text_size: f32,
+    /// The font of the label.
+    font: Renderer::Font,
+    /// The style of the [`SeekBar`](crate::native::SeekBar).
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<Renderer> SeekBarOverlay<Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`SeekBarOverlay`] showing the given label above the anchor.
+    pub(crate) fn new(
+        label: String,
+        anchor: Point,
+        text_size: f32,
+        font: Renderer::Font,
+        style: <Renderer::Theme as StyleSheet>::Style,
+    ) -> Self {
+        Self {
+            label,
+            anchor,
+            text_size,
+            font,
+            style,
+        }
+    }
+
+    /// Turn this [`SeekBarOverlay`] into an overlay [`Element`](overlay::Element).
+    pub fn overlay<'a, Message>(self) -> overlay::Element<'a, Message, Renderer>
+    where
+        Renderer: 'a,
+    {
+        overlay::Element::new(self.anchor, Box::new(self))
+    }
+}
+
+impl<Message, Renderer> overlay::Overlay<Message, Renderer> for SeekBarOverlay<Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(&self, renderer: &Renderer, bounds: Size, position: Point) -> Node {
+        let text = renderer.measure(
+            &self.label,
+            self.text_size,
+            LineHeight::default(),
+            self.font,
+            Size::INFINITY,
+            text::Shaping::Basic,
+        );
+        let size = Size::new(text.width + PADDING * 2.0, text.height + PADDING * 2.0);
+
+        // Center the preview above the anchor, but keep it inside of the window.
+        let x = (position.x - size.width / 2.0).clamp(0.0, (bounds.width - size.width).max(0.0));
+        let y = (position.y - size.height - SPACING).max(0.0);
+
+        let mut node = Node::new(size);
+        node.move_to(Point::new(x, y));
+        node
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: core::Layout<'_>,
+        _cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (PADDING).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.tooltip_background,
+        );
+
+        renderer.fill_text(text::Text {
+            content: &self.label,
+            bounds: Rectangle {
+                x: bounds.center_x(),
+                y: bounds.center_y(),
+                ..bounds
+            },
+            size: self.text_size,
+            line_height: LineHeight::default(),
+            color: appearance.tooltip_text_color,
+            font: self.font,
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+            shaping: text::Shaping::Basic,
+        });
+    }
+}
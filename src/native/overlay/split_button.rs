@@ -0,0 +1,361 @@
+//! Use a split button to run a main action or choose an alternative one.
+//!
+//! *This API requires the following crate features to be activated: `split_button`*
+use crate::core::menu_item::{self, MenuItem};
+use crate::style::split_button::StyleSheet;
+
+use iced_widget::{
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event, keyboard,
+        layout::Node,
+        mouse::{self, Cursor},
+        overlay, renderer, text, touch, Clipboard, Color, Event, Font, Layout, Pixels, Point,
+        Rectangle, Shell, Size,
+    },
+    text::LineHeight,
+};
+
+/// The minimum width of the dropdown menu.
+const MIN_WIDTH: f32 = 160.0;
+/// The padding around the actions of the dropdown menu.
+const PADDING: f32 = 4.0;
+/// The horizontal padding of the label of an action.
+const LABEL_PADDING: f32 = 8.0;
+/// The space between the dropdown menu and the button.
+const SPACING: f32 = 2.0;
+
+/// The state of the dropdown menu of a button.
+#[derive(Clone, Debug, Default)]
+pub struct MenuState {
+    /// Whether the dropdown menu is open.
+    pub(crate) is_open: bool,
+    /// The action of the dropdown menu highlighted with the keyboard.
+    pub(crate) highlighted: Option<usize>,
+}
+
+impl MenuState {
+    /// Closes the dropdown menu.
+    pub(crate) fn close(&mut self) {
+        self.is_open = false;
+        self.highlighted = None;
+    }
+}
+
+/// The dropdown menu of a [`SplitButton`](crate::native::split_button::SplitButton)
+/// or a [`MenuButton`](crate::native::menu_button::MenuButton).
+#[allow(missing_debug_implementations)]
+pub struct SplitButtonMenu<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The state shared between the button and the [`SplitButtonMenu`].
+    state: &'a mut MenuState,
+    /// The actions of the menu.
+    items: &'a [MenuItem<Message>],
+    /// The bounds of the button the menu belongs to.
+    anchor: Rectangle,
+    /// The part of the button toggling the menu, where a press does not
+    /// dismiss it.
+    trigger: Rectangle,
+    /// The text size of the actions.
+    text_size: f32,
+    /// The font of the actions.
+    font: Font,
+    /// The style of the button.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> SplitButtonMenu<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`SplitButtonMenu`] below the given button.
+    pub(crate) fn new(
+        state: &'a mut MenuState,
+        items: &'a [MenuItem<Message>],
+        anchor: Rectangle,
+        trigger: Rectangle,
+        text_size: f32,
+        font: Font,
+        style: <Renderer::Theme as StyleSheet>::Style,
+    ) -> Self {
+        Self {
+            state,
+            items,
+            anchor,
+            trigger,
+            text_size,
+            font,
+            style,
+        }
+    }
+
+    /// Turn this [`SplitButtonMenu`] into an overlay [`Element`](overlay::Element).
+    pub fn overlay(self) -> overlay::Element<'a, Message, Renderer>
+    where
+        Message: 'a,
+        Renderer: 'a,
+    {
+        overlay::Element::new(self.anchor.position(), Box::new(self))
+    }
+
+    /// Returns the height of an action.
+    fn entry_height(&self) -> f32 {
+        LineHeight::default().to_absolute(Pixels(self.text_size)).0 + PADDING * 2.0
+    }
+
+    /// Returns the bounds of the action at the given index.
+    #[allow(clippy::cast_precision_loss)]
+    fn entry_bounds(&self, bounds: Rectangle, index: usize) -> Rectangle {
+        Rectangle {
+            x: bounds.x + PADDING,
+            y: bounds.y + PADDING + index as f32 * self.entry_height(),
+            width: bounds.width - PADDING * 2.0,
+            height: self.entry_height(),
+        }
+    }
+
+    /// Chooses the action at the given index, if it is enabled.
+    fn select(&mut self, index: usize, shell: &mut Shell<'_, Message>) {
+        if let Some(message) = self.items.get(index).and_then(MenuItem::message) {
+            shell.publish(message.clone());
+            self.state.close();
+        }
+    }
+}
+
+impl<'a, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for SplitButtonMenu<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    #[allow(clippy::cast_precision_loss)]
+    fn layout(&self, renderer: &Renderer, bounds: Size, position: Point) -> Node {
+        let widest = self
+            .items
+            .iter()
+            .map(|item| {
+                let label = renderer.measure_width(
+                    item.label(),
+                    self.text_size,
+                    self.font,
+                    text::Shaping::Advanced,
+                );
+                let shortcut = item.shortcut_hint().map_or(0.0, |shortcut| {
+                    renderer.measure_width(
+                        shortcut,
+                        self.text_size,
+                        self.font,
+                        text::Shaping::Advanced,
+                    ) + LABEL_PADDING * 2.0
+                });
+                label + shortcut + LABEL_PADDING * 2.0
+            })
+            .fold(0.0, f32::max);
+
+        let size = Size::new(
+            (widest + PADDING * 2.0)
+                .max(self.anchor.width)
+                .max(MIN_WIDTH),
+            self.items.len() as f32 * self.entry_height() + PADDING * 2.0,
+        );
+
+        // Open below the button, or above if there is no room below.
+        let below = position.y + self.anchor.height + SPACING;
+        let y = if below + size.height <= bounds.height {
+            below
+        } else {
+            (position.y - size.height - SPACING).max(0.0)
+        };
+
+        let mut node = Node::new(size);
+        node.move_to(Point::new(
+            position.x.min((bounds.width - size.width).max(0.0)),
+            y,
+        ));
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => {
+                match key_code {
+                    keyboard::KeyCode::Escape => self.state.close(),
+                    keyboard::KeyCode::Up | keyboard::KeyCode::Down => {
+                        self.state.highlighted = menu_item::next_enabled(
+                            self.items,
+                            self.state.highlighted,
+                            key_code == keyboard::KeyCode::Down,
+                        );
+                    }
+                    keyboard::KeyCode::Enter | keyboard::KeyCode::NumpadEnter => {
+                        if let Some(index) = self.state.highlighted {
+                            self.select(index, shell);
+                        }
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    self.state.highlighted = (0..self.items.len())
+                        .find(|index| self.entry_bounds(bounds, *index).contains(position))
+                        .filter(|index| self.items[*index].is_enabled());
+                }
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position() else {
+                    return event::Status::Ignored;
+                };
+
+                // A press outside of the menu dismisses it, except on the
+                // trigger, which closes the menu itself.
+                if !bounds.contains(position) {
+                    if !self.trigger.contains(position) {
+                        self.state.close();
+                    }
+                    return event::Status::Ignored;
+                }
+
+                if let Some(index) = (0..self.items.len())
+                    .find(|index| self.entry_bounds(bounds, *index).contains(position))
+                {
+                    self.select(index, shell);
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+
+        let over_enabled = cursor.position_over(bounds).is_some_and(|position| {
+            (0..self.items.len()).any(|index| {
+                self.items[index].is_enabled()
+                    && self.entry_bounds(bounds, index).contains(position)
+            })
+        });
+
+        if over_enabled {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius.into(),
+                border_width: 1.0,
+                border_color: appearance.menu_border_color,
+            },
+            appearance.menu_background,
+        );
+
+        for (index, item) in self.items.iter().enumerate() {
+            let entry = self.entry_bounds(bounds, index);
+            let highlighted = self.state.highlighted == Some(index);
+
+            if highlighted {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: entry,
+                        border_radius: (2.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.menu_hovered_background,
+                );
+            }
+
+            let color = if !item.is_enabled() {
+                appearance.menu_disabled_text_color
+            } else if highlighted {
+                appearance.menu_hovered_text_color
+            } else {
+                appearance.menu_text_color
+            };
+
+            renderer.fill_text(core::text::Text {
+                content: item.label(),
+                bounds: Rectangle {
+                    x: entry.x + LABEL_PADDING,
+                    y: entry.center_y(),
+                    ..entry
+                },
+                size: self.text_size,
+                line_height: LineHeight::default(),
+                color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Advanced,
+            });
+
+            if let Some(shortcut) = item.shortcut_hint() {
+                renderer.fill_text(core::text::Text {
+                    content: shortcut,
+                    bounds: Rectangle {
+                        x: entry.x + entry.width - LABEL_PADDING,
+                        y: entry.center_y(),
+                        ..entry
+                    },
+                    size: self.text_size,
+                    line_height: LineHeight::default(),
+                    color: if highlighted {
+                        appearance.menu_hovered_text_color
+                    } else {
+                        appearance.shortcut_text_color
+                    },
+                    font: self.font,
+                    horizontal_alignment: Horizontal::Right,
+                    vertical_alignment: Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                });
+            }
+        }
+    }
+}
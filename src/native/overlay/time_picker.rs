@@ -8,10 +8,15 @@ use crate::{
         MINUTE_RADIUS_PERCENTAGE, MINUTE_RADIUS_PERCENTAGE_NO_SECONDS, PERIOD_PERCENTAGE,
         SECOND_RADIUS_PERCENTAGE,
     },
-    core::{clock, overlay::Position, time::Period},
+    core::{
+        clock, i18n,
+        layout_direction::{self, LayoutDirection},
+        overlay::Position,
+        time::{range_duration, Period},
+    },
     graphics::icons::{icon_to_char, ICON_FONT},
     style::style_state::StyleState,
-    time_picker::{self, Time},
+    time_picker::{self, OnSubmit, Time},
     Icon,
 };
 
@@ -51,6 +56,8 @@ const BUTTON_SPACING: f32 = 5.0;
 const NUMBER_SIZE_PERCENTAGE: f32 = 0.15;
 /// The percentage size of the period.
 const PERIOD_SIZE_PERCENTAGE: f32 = 0.2;
+/// The percentage of the inner radius of the arc of a picked range.
+const RANGE_RADIUS_PERCENTAGE: f32 = 0.96;
 
 /// The overlay of the [`TimePicker`](crate::native::TimePicker).
 #[allow(missing_debug_implementations)]
@@ -66,13 +73,19 @@ where
     /// The submit button of the [`TimePickerOverlay`].
     submit_button: Button<'a, Message, Renderer<Theme>>,
     /// The function that produces a message when the submit button of the [`TimePickerOverlay`] is pressed.
-    on_submit: &'a dyn Fn(Time) -> Message,
+    on_submit: &'a OnSubmit<Message>,
     /// The position of the [`TimePickerOverlay`].
     position: Point,
     /// The style of the [`TimePickerOverlay`].
     style: <Theme as StyleSheet>::Style,
     /// The reference to the tree holding the state of this overlay.
     tree: &'a mut Tree,
+    /// The [`LayoutDirection`] the buttons of the [`TimePickerOverlay`] are arranged in.
+    direction: LayoutDirection,
+    /// The labels of the start and the end of a picked range.
+    range_labels: [String; 2],
+    /// The labels of the morning and the afternoon of a 12 hour clock.
+    period_labels: [String; 2],
 }
 
 impl<'a, Message, Theme> TimePickerOverlay<'a, Message, Theme>
@@ -84,12 +97,13 @@ where
     pub fn new(
         state: &'a mut time_picker::State,
         on_cancel: Message,
-        on_submit: &'a dyn Fn(Time) -> Message,
+        on_submit: &'a OnSubmit<Message>,
         position: Point,
         style: <Theme as StyleSheet>::Style,
         tree: &'a mut Tree,
     ) -> Self {
         let time_picker::State { overlay_state } = state;
+        let translations = i18n::get();
 
         TimePickerOverlay {
             state: overlay_state,
@@ -113,6 +127,9 @@ where
             position,
             style,
             tree,
+            direction: layout_direction::get(),
+            range_labels: [translations.range_start(), translations.range_end()],
+            period_labels: [translations.am(), translations.pm()],
         }
     }
 
@@ -258,7 +275,10 @@ where
                     self.state.time = self
                         .state
                         .time
-                        .with_minute(nearest_point as u32)
+                        .with_minute(clock::snap_minute(
+                            nearest_point as u32,
+                            self.state.minute_step,
+                        ))
                         .expect("New time with minute should be valid");
                     event::Status::Captured
                 }
@@ -344,12 +364,12 @@ where
         let calculate_time = |time: &mut NaiveTime,
                               up_arrow: Layout<'_>,
                               down_arrow: Layout<'_>,
-                              duration: Duration| {
+                              (back, forth): (Duration, Duration)| {
             if cursor.is_over(up_arrow.bounds()) {
-                *time += duration;
+                *time += forth;
                 event::Status::Captured
             } else if cursor.is_over(down_arrow.bounds()) {
-                *time -= duration;
+                *time -= back;
                 event::Status::Captured
             } else {
                 event::Status::Ignored
@@ -366,16 +386,18 @@ where
                         &mut self.state.time,
                         hour_up_arrow,
                         hour_down_arrow,
-                        Duration::hours(1),
+                        (Duration::hours(1), Duration::hours(1)),
                     )
                 } else if cursor.is_over(minute_layout.bounds()) {
                     self.state.focus = Focus::DigitalMinute;
 
+                    let steps = minute_steps(self.state.time, self.state.minute_step);
+
                     calculate_time(
                         &mut self.state.time,
                         minute_up_arrow,
                         minute_down_arrow,
-                        Duration::minutes(1),
+                        steps,
                     )
                 } else {
                     event::Status::Ignored
@@ -410,7 +432,7 @@ where
                             &mut self.state.time,
                             second_up_arrow,
                             second_down_arrow,
-                            Duration::seconds(1),
+                            (Duration::seconds(1), Duration::seconds(1)),
                         )
                     } else {
                         event::Status::Ignored
@@ -431,6 +453,34 @@ where
         digital_clock_status
     }
 
+    /// The event handling for the tabs of the bounds of a picked range.
+    fn on_event_range(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) -> event::Status {
+        if !matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        ) {
+            return event::Status::Ignored;
+        }
+
+        let tab = [Bound::Start, Bound::End]
+            .into_iter()
+            .zip(layout.children())
+            .find(|(_, tab)| cursor.is_over(tab.bounds()));
+
+        if let Some((bound, _)) = tab {
+            self.state.edit(bound);
+            event::Status::Captured
+        } else {
+            event::Status::Ignored
+        }
+    }
+
     /// The event handling for the keyboard input.
     fn on_event_keyboard(
         &mut self,
@@ -441,29 +491,40 @@ where
         _renderer: &Renderer<Theme>,
         _clipboard: &mut dyn Clipboard,
     ) -> event::Status {
+        if let Event::Keyboard(keyboard::Event::CharacterReceived(character)) = event {
+            return self.state.type_character(*character);
+        }
+
         if self.state.focus == Focus::None {
             return event::Status::Ignored;
         }
 
         if let Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) = event {
             let mut status = event::Status::Ignored;
+            let show_period = !self.state.use_24h;
 
             if matches!(key_code, keyboard::KeyCode::Tab) {
+                self.state.typed = None;
                 if self.state.keyboard_modifiers.shift() {
-                    self.state.focus = self.state.focus.previous(self.state.show_seconds);
+                    self.state.focus = self
+                        .state
+                        .focus
+                        .previous(self.state.show_seconds, show_period);
                 } else {
-                    self.state.focus = self.state.focus.next(self.state.show_seconds);
+                    self.state.focus = self.state.focus.next(self.state.show_seconds, show_period);
                 }
             } else {
                 let mut keyboard_handle =
-                    |key_code: &keyboard::KeyCode, time: &mut NaiveTime, duration: Duration| {
+                    |key_code: &keyboard::KeyCode,
+                     time: &mut NaiveTime,
+                     (back, forth): (Duration, Duration)| {
                         match key_code {
                             keyboard::KeyCode::Left | keyboard::KeyCode::Down => {
-                                *time -= duration;
+                                *time -= back;
                                 status = event::Status::Captured;
                             }
                             keyboard::KeyCode::Right | keyboard::KeyCode::Up => {
-                                *time += duration;
+                                *time += forth;
                                 status = event::Status::Captured;
                             }
                             _ => {}
@@ -472,19 +533,36 @@ where
 
                 match self.state.focus {
                     Focus::DigitalHour => {
-                        keyboard_handle(key_code, &mut self.state.time, Duration::hours(1));
+                        keyboard_handle(
+                            key_code,
+                            &mut self.state.time,
+                            (Duration::hours(1), Duration::hours(1)),
+                        );
                     }
                     Focus::DigitalMinute => {
-                        keyboard_handle(key_code, &mut self.state.time, Duration::minutes(1));
+                        let steps = minute_steps(self.state.time, self.state.minute_step);
+                        keyboard_handle(key_code, &mut self.state.time, steps);
                     }
                     Focus::DigitalSecond => {
-                        keyboard_handle(key_code, &mut self.state.time, Duration::seconds(1));
+                        keyboard_handle(
+                            key_code,
+                            &mut self.state.time,
+                            (Duration::seconds(1), Duration::seconds(1)),
+                        );
+                    }
+                    Focus::Period => {
+                        keyboard_handle(
+                            key_code,
+                            &mut self.state.time,
+                            (Duration::hours(12), Duration::hours(12)),
+                        );
                     }
                     _ => {}
                 }
             }
 
             if status == event::Status::Captured {
+                self.state.typed = None;
                 self.state.clock_cache.clear();
             }
 
@@ -519,9 +597,20 @@ where
         let cancel_limits = limits;
         let cancel_button = self.cancel_button.layout(renderer, &cancel_limits);
 
+        // The tabs of the bounds of a picked range sit above the clock.
+        let range_height = renderer.default_size() * 2.0;
+        let range_offset = if self.state.other_bound.is_some() {
+            range_height + SPACING
+        } else {
+            0.0
+        };
+
         let limits = limits.shrink(Size::new(
             0.0,
-            digital_clock.bounds().height + cancel_button.bounds().height + 2.0 * SPACING,
+            digital_clock.bounds().height
+                + cancel_button.bounds().height
+                + 2.0 * SPACING
+                + range_offset,
         ));
 
         // Clock-Canvas
@@ -532,12 +621,12 @@ where
 
         clock.move_to(Point::new(
             clock.bounds().x + PADDING,
-            clock.bounds().y + PADDING,
+            clock.bounds().y + PADDING + range_offset,
         ));
 
         digital_clock.move_to(Point::new(
             digital_clock.bounds().x + PADDING,
-            digital_clock.bounds().y + PADDING + SPACING + clock.bounds().height,
+            digital_clock.bounds().y + PADDING + SPACING + clock.bounds().height + range_offset,
         ));
 
         // Buttons
@@ -557,7 +646,8 @@ where
                 + clock.bounds().height
                 + PADDING
                 + digital_clock.bounds().height
-                + 2.0 * SPACING,
+                + 2.0 * SPACING
+                + range_offset,
         });
 
         submit_button.move_to(Point {
@@ -567,20 +657,37 @@ where
                 + clock.bounds().height
                 + PADDING
                 + digital_clock.bounds().height
-                + 2.0 * SPACING,
+                + 2.0 * SPACING
+                + range_offset,
         });
 
-        let mut node = Node::with_children(
-            Size::new(
-                clock.bounds().width + (2.0 * PADDING),
-                clock.bounds().height
-                    + digital_clock.bounds().height
-                    + cancel_button.bounds().height
-                    + (2.0 * PADDING)
-                    + 2.0 * SPACING,
-            ),
-            vec![clock, digital_clock, cancel_button, submit_button],
+        let size = Size::new(
+            clock.bounds().width + (2.0 * PADDING),
+            clock.bounds().height
+                + digital_clock.bounds().height
+                + cancel_button.bounds().height
+                + (2.0 * PADDING)
+                + 2.0 * SPACING
+                + range_offset,
         );
+        let range_width = clock.bounds().width;
+
+        let mut children = vec![clock, digital_clock, cancel_button, submit_button];
+        if self.state.other_bound.is_some() {
+            let tab_width = (range_width / 2.0 - BUTTON_SPACING).max(0.0);
+            let start_tab = Node::new(Size::new(tab_width, range_height));
+            let mut end_tab = Node::new(Size::new(tab_width, range_height));
+            end_tab.move_to(Point::new(range_width - tab_width, 0.0));
+
+            let mut range = self.direction.arrange(Node::with_children(
+                Size::new(range_width, range_height),
+                vec![start_tab, end_tab],
+            ));
+            range.move_to(Point::new(PADDING, PADDING));
+            children.push(range);
+        }
+
+        let mut node = self.direction.arrange(Node::with_children(size, children));
 
         node.center_and_bounce(position, bounds);
 
@@ -602,6 +709,14 @@ where
             return event::Status::Captured;
         }
 
+        // ----------- Range tabs ---------------------
+        let range_status = layout
+            .children()
+            .nth(4)
+            .map_or(event::Status::Ignored, |range_layout| {
+                self.on_event_range(&event, range_layout, cursor)
+            });
+
         let mut children = layout.children();
 
         // Clock canvas
@@ -661,32 +776,25 @@ where
         );
 
         if !fake_messages.is_empty() {
-            let (hour, period) = if self.state.use_24h {
-                (self.state.time.hour(), Period::H24)
-            } else {
-                let (period, hour) = self.state.time.hour12();
-                (hour, if period { Period::Pm } else { Period::Am })
-            };
-
-            let time = if self.state.show_seconds {
-                Time::Hms {
-                    hour,
-                    minute: self.state.time.minute(),
-                    second: self.state.time.second(),
-                    period,
+            match self.on_submit {
+                OnSubmit::Single(on_submit) => {
+                    shell.publish(on_submit(self.state.picked(self.state.time)));
                 }
-            } else {
-                Time::Hm {
-                    hour,
-                    minute: self.state.time.minute(),
-                    period,
+                OnSubmit::Range(on_submit) => {
+                    // An empty range can't be submitted.
+                    if let Some((start, end)) = self
+                        .state
+                        .range()
+                        .filter(|(start, end)| range_duration(*start, *end).is_some())
+                    {
+                        shell.publish(on_submit(self.state.picked(start), self.state.picked(end)));
+                    }
                 }
-            };
-
-            shell.publish((self.on_submit)(time));
+            }
         }
 
-        clock_status
+        range_status
+            .merge(clock_status)
             .merge(digital_clock_status)
             .merge(cancel_status)
             .merge(submit_status)
@@ -801,7 +909,14 @@ where
             renderer,
         );
 
+        let range_mouse_interaction = layout
+            .children()
+            .nth(4)
+            .filter(|range_layout| cursor.is_over(range_layout.bounds()))
+            .map_or_else(mouse::Interaction::default, |_| mouse::Interaction::Pointer);
+
         mouse_interaction
+            .max(range_mouse_interaction)
             .max(clock_mouse_interaction)
             .max(hour_mouse_interaction)
             .max(minute_mouse_interaction)
@@ -890,6 +1005,11 @@ where
             &bounds,
         );
 
+        // ----------- Range tabs ---------------------
+        if let Some(range_layout) = layout.children().nth(4) {
+            draw_range(renderer, self, range_layout, cursor, &style_sheet);
+        }
+
         // Buttons are not focusable right now...
         if self.state.focus == Focus::Cancel {
             renderer.fill_quad(
@@ -940,7 +1060,7 @@ where
         digital_clock_row = digital_clock_row.push(
             Column::new() // Just a placeholder
                 .height(Length::Shrink)
-                .push(text::Text::new("AM").size(font_size)),
+                .push(text::Text::new(period_placeholder(time_picker)).size(font_size)),
         );
     }
 
@@ -1028,7 +1148,7 @@ where
         digital_clock_row = digital_clock_row.push(
             Column::new()
                 .height(Length::Shrink)
-                .push(text::Text::new("AM").size(font_size)),
+                .push(text::Text::new(period_placeholder(time_picker)).size(font_size)),
         );
     }
 
@@ -1040,6 +1160,24 @@ where
         .layout(renderer, &limits)
 }
 
+/// Returns the longer label of the periods, reserving the space of the period
+/// in the layout of the digital clock.
+fn period_placeholder<'a, Message, Theme>(
+    time_picker: &'a TimePickerOverlay<'_, Message, Theme>,
+) -> &'a str
+where
+    Message: 'static + Clone,
+    Theme: StyleSheet + button::StyleSheet + text::StyleSheet + container::StyleSheet,
+{
+    let [am, pm] = &time_picker.period_labels;
+
+    if pm.chars().count() > am.chars().count() {
+        pm
+    } else {
+        am
+    }
+}
+
 /// Draws the analog clock.
 #[allow(clippy::too_many_lines)]
 fn draw_clock<Message, Theme>(
@@ -1114,6 +1252,45 @@ fn draw_clock<Message, Theme>(
                 NearestRadius::None
             };
 
+            // The picked range is an arc around the clock, which is a full
+            // ring for twelve hours or more.
+            if let Some((start, end)) = time_picker.state.range() {
+                if let Some(duration) = range_duration(start, end) {
+                    let from = clock::time_angle(start.hour(), start.minute());
+                    #[allow(clippy::cast_precision_loss)]
+                    let sweep = (std::f32::consts::TAU * duration.num_minutes() as f32 / 720.0)
+                        .min(std::f32::consts::TAU);
+                    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                    let steps = sweep.to_degrees().ceil().max(1.0) as usize;
+                    #[allow(clippy::cast_precision_loss)]
+                    let angle = |step: usize| from + sweep * step as f32 / steps as f32;
+                    let point = |distance: f32, angle: f32| {
+                        center + Vector::new(angle.cos() * distance, angle.sin() * distance)
+                    };
+                    let (outer, inner) = (radius, radius * RANGE_RADIUS_PERCENTAGE);
+
+                    // The arc is traced with one corner per degree.
+                    let arc = Path::new(|builder| {
+                        builder.move_to(point(outer, from));
+                        for step in 1..=steps {
+                            builder.line_to(point(outer, angle(step)));
+                        }
+                        for step in (0..=steps).rev() {
+                            builder.line_to(point(inner, angle(step)));
+                        }
+                        builder.close();
+                    });
+
+                    frame.fill(
+                        &arc,
+                        style
+                            .get(&StyleState::Selected)
+                            .expect("Style Sheet not found.")
+                            .clock_number_background,
+                    );
+                }
+            }
+
             let hour_points = crate::core::clock::circle_points(hour_radius, center, 12);
             let minute_points = crate::core::clock::circle_points(minute_radius, center, 60);
             let second_points = crate::core::clock::circle_points(second_radius, center, 60);
@@ -1156,8 +1333,10 @@ fn draw_clock<Message, Theme>(
                     );
                 }
                 NearestRadius::Minute => {
-                    let nearest_point = minute_points
-                        [crate::core::clock::nearest_point(&minute_points, internal_cursor)];
+                    let nearest_point = minute_points[clock::snap_minute(
+                        crate::core::clock::nearest_point(&minute_points, internal_cursor) as u32,
+                        time_picker.state.minute_step,
+                    ) as usize];
 
                     frame.fill(
                         &Path::circle(nearest_point, 5.0),
@@ -1246,6 +1425,11 @@ fn draw_clock<Message, Theme>(
             });
 
             minute_points.iter().enumerate().for_each(|(i, p)| {
+                // Only the minutes the clock snaps to are offered.
+                if !(i as u32).is_multiple_of(time_picker.state.minute_step) {
+                    return;
+                }
+
                 let selected = time_picker.state.time.minute() == i as u32;
 
                 let mut style_state = StyleState::Active;
@@ -1344,6 +1528,82 @@ fn draw_clock<Message, Theme>(
     });
 }
 
+/// Draws the tabs of the start and the end of a picked range.
+fn draw_range<Message, Theme>(
+    renderer: &mut Renderer<Theme>,
+    time_picker: &TimePickerOverlay<'_, Message, Theme>,
+    layout: Layout<'_>,
+    cursor: Cursor,
+    style: &HashMap<StyleState, Appearance>,
+) where
+    Message: 'static + Clone,
+    Theme: StyleSheet + button::StyleSheet + text::StyleSheet,
+{
+    let Some((start, end)) = time_picker.state.range() else {
+        return;
+    };
+
+    let tabs = [
+        (Bound::Start, start, String::new()),
+        // The end of a range wrapping past midnight is on the next day.
+        (
+            Bound::End,
+            end,
+            if end < start {
+                String::from(" +1")
+            } else {
+                String::new()
+            },
+        ),
+    ];
+
+    for ((bound, time, suffix), (tab, label)) in tabs
+        .into_iter()
+        .zip(layout.children().zip(&time_picker.range_labels))
+    {
+        let style_state = if time_picker.state.bound == bound {
+            StyleState::Selected
+        } else if cursor.is_over(tab.bounds()) {
+            StyleState::Hovered
+        } else {
+            StyleState::Active
+        };
+        let appearance = style.get(&style_state).expect("Style Sheet not found.");
+
+        if style_state != StyleState::Active {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: tab.bounds(),
+                    border_radius: appearance.border_radius.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.clock_number_background,
+            );
+        }
+
+        renderer.fill_text(core::Text {
+            content: &format!("{label} {}{suffix}", time_picker.state.picked(time)),
+            bounds: Rectangle {
+                x: tab.bounds().center_x(),
+                y: tab.bounds().center_y(),
+                ..tab.bounds()
+            },
+            size: renderer.default_size(),
+            color: if style_state == StyleState::Active {
+                appearance.text_color
+            } else {
+                appearance.clock_number_color
+            },
+            font: renderer.default_font(),
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+            line_height: text::LineHeight::Relative(1.3),
+            shaping: text::Shaping::Basic,
+        });
+    }
+}
+
 /// Draws the digital clock.
 #[allow(clippy::too_many_lines)]
 fn draw_digital_clock<Message, Theme>(
@@ -1572,12 +1832,22 @@ fn draw_digital_clock<Message, Theme>(
         let period = children
             .next()
             .expect("Graphics: Layout should have a period layout");
+
+        if time_picker.state.focus == Focus::Period {
+            let focused = &style[&StyleState::Focused];
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: period.bounds(),
+                    border_color: focused.border_color,
+                    border_radius: focused.border_radius.into(),
+                    border_width: focused.border_width,
+                },
+                focused.background,
+            );
+        }
+
         renderer.fill_text(core::Text {
-            content: if time_picker.state.time.hour12().0 {
-                "PM"
-            } else {
-                "AM"
-            },
+            content: &time_picker.period_labels[usize::from(time_picker.state.time.hour12().0)],
             bounds: Rectangle {
                 x: period.bounds().center_x(),
                 y: period.bounds().center_y(),
@@ -1597,6 +1867,17 @@ fn draw_digital_clock<Message, Theme>(
     }
 }
 
+/// Returns the durations to go back and forth from the given time to the
+/// previous and the next minute of the given interval.
+fn minute_steps(time: NaiveTime, minute_step: u32) -> (Duration, Duration) {
+    let (back, forth) = clock::minute_steps(time.minute(), minute_step);
+
+    (
+        Duration::minutes(i64::from(back)),
+        Duration::minutes(i64::from(forth)),
+    )
+}
+
 /// The state of the [`TimePickerOverlay`].
 #[derive(Debug)]
 pub struct State {
@@ -1610,10 +1891,20 @@ pub struct State {
     pub(crate) use_24h: bool,
     /// Toggle the use of the seconds of the [`TimePickerOverlay`].
     pub(crate) show_seconds: bool,
+    /// The interval of the minutes the [`TimePickerOverlay`] snaps to.
+    pub(crate) minute_step: u32,
+    /// The bound of the picked range the time is not edited as, if a range
+    /// of times is picked.
+    pub(crate) other_bound: Option<NaiveTime>,
+    /// The bound of the picked range the time is edited as.
+    pub(crate) bound: Bound,
     /// The dragged clock element of the [`TimePickerOverlay`].
     pub(crate) clock_dragged: ClockDragged,
     /// The focus of the [`TimePickerOverlay`].
     pub(crate) focus: Focus,
+    /// The segment of the digital clock and the digit typed into it, if
+    /// another digit may follow.
+    pub(crate) typed: Option<(Focus, u32)>,
     /// The previously pressed keyboard modifiers.
     pub(crate) keyboard_modifiers: keyboard::Modifiers,
 }
@@ -1627,6 +1918,147 @@ impl State {
             ..Self::default()
         }
     }
+
+    /// Returns the start and the end of the picked range, if a range of
+    /// times is picked.
+    pub(crate) fn range(&self) -> Option<(NaiveTime, NaiveTime)> {
+        self.other_bound.map(|other| match self.bound {
+            Bound::Start => (self.time, other),
+            Bound::End => (other, self.time),
+        })
+    }
+
+    /// Returns the given time as picked, in the format of the picker.
+    pub(crate) fn picked(&self, time: NaiveTime) -> Time {
+        let (hour, period) = if self.use_24h {
+            (time.hour(), Period::H24)
+        } else {
+            let (period, hour) = time.hour12();
+            (hour, if period { Period::Pm } else { Period::Am })
+        };
+
+        if self.show_seconds {
+            Time::Hms {
+                hour,
+                minute: time.minute(),
+                second: time.second(),
+                period,
+            }
+        } else {
+            Time::Hm {
+                hour,
+                minute: time.minute(),
+                period,
+            }
+        }
+    }
+
+    /// Edits the given bound of the picked range.
+    pub(crate) fn edit(&mut self, bound: Bound) {
+        if let Some(other) = self.other_bound.as_mut() {
+            if self.bound != bound {
+                std::mem::swap(&mut self.time, other);
+                self.bound = bound;
+                self.typed = None;
+                self.clock_cache.clear();
+            }
+        }
+    }
+
+    /// Types the given character into the focused segment of the digital
+    /// clock, starting with the hour if no segment is focused.
+    ///
+    /// The focus moves on to the next segment when a segment is complete or
+    /// a `:` is typed. On the 12h clock, `a` and `p` set the period.
+    fn type_character(&mut self, character: char) -> event::Status {
+        if matches!(self.focus, Focus::None | Focus::Overlay) && character.is_ascii_digit() {
+            self.focus = Focus::DigitalHour;
+        }
+
+        if !self.focus.is_segment() {
+            return event::Status::Ignored;
+        }
+
+        let next = self.focus.next(self.show_seconds, !self.use_24h);
+        let next_segment = |state: &mut Self| {
+            if next.is_segment() {
+                state.focus = next;
+            }
+        };
+
+        match character {
+            ':' => {
+                self.typed = None;
+                next_segment(self);
+            }
+            'a' | 'A' | 'p' | 'P' if !self.use_24h => {
+                let pm = character.eq_ignore_ascii_case(&'p');
+                if let Some(time) = self
+                    .time
+                    .with_hour(self.time.hour12().1 % 12 + 12 * u32::from(pm))
+                {
+                    self.time = time;
+                }
+                self.typed = None;
+            }
+            _ => {
+                let Some(digit) = character.to_digit(10) else {
+                    return event::Status::Ignored;
+                };
+                let range = match self.focus {
+                    Focus::DigitalHour if self.use_24h => 0..=23,
+                    Focus::DigitalHour => 1..=12,
+                    Focus::DigitalMinute | Focus::DigitalSecond => 0..=59,
+                    _ => return event::Status::Ignored,
+                };
+                let typed = self
+                    .typed
+                    .filter(|(focus, _)| *focus == self.focus)
+                    .map(|(_, typed)| typed);
+                let (value, more) = clock::type_digit(typed, digit, range);
+
+                if let Some(value) = value {
+                    let time = match self.focus {
+                        Focus::DigitalHour if self.use_24h => self.time.with_hour(value),
+                        Focus::DigitalHour => self
+                            .time
+                            .with_hour(value % 12 + 12 * u32::from(self.time.hour12().0)),
+                        Focus::DigitalMinute => self
+                            .time
+                            .with_minute(clock::snap_minute(value, self.minute_step)),
+                        _ => self.time.with_second(value),
+                    };
+                    if let Some(time) = time {
+                        self.time = time;
+                    }
+                }
+
+                self.typed = more.map(|typed| (self.focus, typed));
+                if value.is_some() && more.is_none() {
+                    next_segment(self);
+                }
+            }
+        }
+
+        self.clock_cache.clear();
+        event::Status::Captured
+    }
+
+    /// Rounds the picked times to the nearest minute of the interval.
+    pub(crate) fn snap_minutes(&mut self) {
+        let minute_step = self.minute_step;
+        let snap = |time: &mut NaiveTime| {
+            if let Some(snapped) = time.with_minute(clock::snap_minute(time.minute(), minute_step))
+            {
+                *time = snapped;
+            }
+        };
+
+        snap(&mut self.time);
+        if let Some(other) = self.other_bound.as_mut() {
+            snap(other);
+        }
+    }
 }
 
 impl Default for State {
@@ -1637,8 +2069,12 @@ impl Default for State {
             clock_cache: canvas::Cache::new(),
             use_24h: false,
             show_seconds: false,
+            minute_step: 1,
+            other_bound: None,
+            bound: Bound::Start,
             clock_dragged: ClockDragged::None,
             focus: Focus::default(),
+            typed: None,
             keyboard_modifiers: keyboard::Modifiers::default(),
         }
     }
@@ -1737,6 +2173,16 @@ where
     }
 }
 
+/// The bound of the range of times picked by a [`TimePickerOverlay`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Bound {
+    /// The start of the range.
+    #[default]
+    Start,
+    /// The end of the range.
+    End,
+}
+
 /// The state of the currently dragged watch hand.
 #[derive(Copy, Clone, Debug)]
 pub enum ClockDragged {
@@ -1771,6 +2217,9 @@ pub enum Focus {
     /// The digital second is in focus.
     DigitalSecond,
 
+    /// The period of the 12h clock is in focus.
+    Period,
+
     /// The cancel button is in focus.
     Cancel,
 
@@ -1781,18 +2230,13 @@ pub enum Focus {
 impl Focus {
     /// Gets the next focusable element.
     #[must_use]
-    pub const fn next(self, show_seconds: bool) -> Self {
+    pub const fn next(self, show_seconds: bool, show_period: bool) -> Self {
         match self {
             Self::Overlay => Self::DigitalHour,
             Self::DigitalHour => Self::DigitalMinute,
-            Self::DigitalMinute => {
-                if show_seconds {
-                    Self::DigitalSecond
-                } else {
-                    Self::Cancel
-                }
-            }
-            Self::DigitalSecond => Self::Cancel,
+            Self::DigitalMinute if show_seconds => Self::DigitalSecond,
+            Self::DigitalMinute | Self::DigitalSecond if show_period => Self::Period,
+            Self::DigitalMinute | Self::DigitalSecond | Self::Period => Self::Cancel,
             Self::Cancel => Self::Submit,
             Self::Submit | Self::None => Self::Overlay,
         }
@@ -1800,23 +2244,26 @@ impl Focus {
 
     /// Gets the previous focusable element.
     #[must_use]
-    pub const fn previous(self, show_seconds: bool) -> Self {
+    pub const fn previous(self, show_seconds: bool, show_period: bool) -> Self {
         match self {
             Self::None => Self::None,
             Self::Overlay => Self::Submit,
             Self::DigitalHour => Self::Overlay,
             Self::DigitalMinute => Self::DigitalHour,
-            Self::DigitalSecond => Self::DigitalMinute,
-            Self::Cancel => {
-                if show_seconds {
-                    Self::DigitalSecond
-                } else {
-                    Self::DigitalMinute
-                }
-            }
+            Self::Cancel if show_period => Self::Period,
+            Self::Cancel | Self::Period if show_seconds => Self::DigitalSecond,
+            Self::DigitalSecond | Self::Cancel | Self::Period => Self::DigitalMinute,
             Self::Submit => Self::Cancel,
         }
     }
+
+    /// Returns whether the element is a segment of the digital clock.
+    const fn is_segment(self) -> bool {
+        matches!(
+            self,
+            Self::DigitalHour | Self::DigitalMinute | Self::DigitalSecond | Self::Period
+        )
+    }
 }
 
 impl Default for Focus {
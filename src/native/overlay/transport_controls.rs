@@ -0,0 +1,262 @@
+//! Use transport controls to play, pause and seek some media.
+//!
+//! *This API requires the following crate features to be activated: `transport_controls`*
+use crate::native::transport_controls::{State, TransportEvent};
+use crate::style::transport_controls::StyleSheet;
+
+use iced_widget::core::{
+    self, event, keyboard,
+    layout::Node,
+    mouse::{self, Cursor},
+    overlay, renderer, touch, Clipboard, Color, Event, Layout, Point, Rectangle, Shell, Size,
+};
+
+/// The size of the volume popover.
+const SIZE: Size = Size::new(36.0, 140.0);
+/// The padding between the volume popover and its slider.
+const PADDING: f32 = 14.0;
+/// The distance between the volume popover and the volume button.
+const SPACING: f32 = 4.0;
+/// The width of the rail of the volume slider.
+const RAIL_WIDTH: f32 = 4.0;
+
+/// The volume popover of the [`TransportControls`](crate::native::TransportControls).
+#[allow(missing_debug_implementations)]
+pub struct VolumePopover<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The state shared between the [`TransportControls`](crate::native::TransportControls) and the [`VolumePopover`].
+    state: &'a mut State,
+    /// The current volume from 0.0 to 1.0.
+    volume: f32,
+    /// The function producing the messages of the [`TransportControls`](crate::native::TransportControls).
+    on_event: &'a dyn Fn(TransportEvent) -> Message,
+    /// The bounds of the volume button the popover belongs to.
+    anchor: Rectangle,
+    /// The style of the [`TransportControls`](crate::native::TransportControls).
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> VolumePopover<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`VolumePopover`] above the given volume button.
+    pub(crate) fn new(
+        state: &'a mut State,
+        volume: f32,
+        on_event: &'a dyn Fn(TransportEvent) -> Message,
+        anchor: Rectangle,
+        style: <Renderer::Theme as StyleSheet>::Style,
+    ) -> Self {
+        Self {
+            state,
+            volume,
+            on_event,
+            anchor,
+            style,
+        }
+    }
+
+    /// Turn this [`VolumePopover`] into an overlay [`Element`](overlay::Element).
+    pub fn overlay(self) -> overlay::Element<'a, Message, Renderer>
+    where
+        Message: 'a,
+        Renderer: 'a,
+    {
+        overlay::Element::new(self.anchor.position(), Box::new(self))
+    }
+
+    /// Returns the bounds of the rail of the volume slider inside of the popover.
+    fn rail(bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            x: bounds.center_x() - RAIL_WIDTH / 2.0,
+            y: bounds.y + PADDING,
+            width: RAIL_WIDTH,
+            height: bounds.height - PADDING * 2.0,
+        }
+    }
+
+    /// Returns the volume at the given vertical position of the rail.
+    fn volume_at(rail: Rectangle, y: f32) -> f32 {
+        (1.0 - (y - rail.y) / rail.height).clamp(0.0, 1.0)
+    }
+}
+
+impl<'a, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for VolumePopover<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(&self, _renderer: &Renderer, bounds: Size, position: Point) -> Node {
+        // Open above the volume button, or below if there is no room above.
+        let x = (position.x + self.anchor.width / 2.0 - SIZE.width / 2.0)
+            .clamp(0.0, (bounds.width - SIZE.width).max(0.0));
+        let above = position.y - SIZE.height - SPACING;
+        let y = if above >= 0.0 {
+            above
+        } else {
+            position.y + self.anchor.height + SPACING
+        };
+
+        let mut node = Node::new(SIZE);
+        node.move_to(Point::new(x, y));
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let rail = Self::rail(bounds);
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) => {
+                self.state.volume_open = false;
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position() else {
+                    return event::Status::Ignored;
+                };
+
+                if bounds.contains(position) {
+                    self.state.volume_drag = true;
+                    shell.publish((self.on_event)(TransportEvent::Volume(Self::volume_at(
+                        rail, position.y,
+                    ))));
+                    return event::Status::Captured;
+                }
+
+                // The volume button toggles the popover itself.
+                if !self.anchor.contains(position) {
+                    self.state.volume_open = false;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if self.state.volume_drag =>
+            {
+                shell.publish((self.on_event)(TransportEvent::Volume(Self::volume_at(
+                    rail, position.y,
+                ))));
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if self.state.volume_drag =>
+            {
+                self.state.volume_drag = false;
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let lines = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / 20.0,
+                };
+                let volume = (self.volume + lines * 0.05).clamp(0.0, 1.0);
+                shell.publish((self.on_event)(TransportEvent::Volume(volume)));
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.state.volume_drag {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let rail = Self::rail(bounds);
+        let appearance = theme.active(&self.style);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius.into(),
+                border_width: 1.0,
+                border_color: appearance.popover_border_color,
+            },
+            appearance.popover_background,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: rail,
+                border_radius: (RAIL_WIDTH / 2.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.volume_rail_color,
+        );
+
+        let volume = self.volume.clamp(0.0, 1.0);
+        let fill_height = rail.height * volume;
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    y: rail.y + rail.height - fill_height,
+                    height: fill_height,
+                    ..rail
+                },
+                border_radius: (RAIL_WIDTH / 2.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.volume_fill_color,
+        );
+
+        // Handle
+        let radius = RAIL_WIDTH * 2.0;
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: rail.center_x() - radius,
+                    y: rail.y + rail.height - fill_height - radius,
+                    width: radius * 2.0,
+                    height: radius * 2.0,
+                },
+                border_radius: radius.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.volume_fill_color,
+        );
+    }
+}
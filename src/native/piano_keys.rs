@@ -0,0 +1,521 @@
+//! Use piano keys to play notes.
+//!
+//! *This API requires the following crate features to be activated: `piano_keys`*
+use crate::core::piano::{is_black_key, note_name, white_keys_below};
+use crate::style::piano_keys::StyleSheet;
+
+use iced_widget::{
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event, keyboard,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer, text, touch,
+        widget::{
+            tree::{State as TreeState, Tag},
+            Tree,
+        },
+        Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+    },
+    text::LineHeight,
+};
+use std::collections::HashMap;
+
+/// The width of a black key relative to a white key.
+const BLACK_KEY_WIDTH: f32 = 0.6;
+/// The height of a black key relative to a white key.
+const BLACK_KEY_HEIGHT: f32 = 0.62;
+
+/// The keys of a computer keyboard playing notes, with their distance in
+/// semitones from the base note of the keyboard mapping.
+const KEY_MAP: [(keyboard::KeyCode, u8); 18] = [
+    (keyboard::KeyCode::A, 0),
+    (keyboard::KeyCode::W, 1),
+    (keyboard::KeyCode::S, 2),
+    (keyboard::KeyCode::E, 3),
+    (keyboard::KeyCode::D, 4),
+    (keyboard::KeyCode::F, 5),
+    (keyboard::KeyCode::T, 6),
+    (keyboard::KeyCode::G, 7),
+    (keyboard::KeyCode::Y, 8),
+    (keyboard::KeyCode::H, 9),
+    (keyboard::KeyCode::U, 10),
+    (keyboard::KeyCode::J, 11),
+    (keyboard::KeyCode::K, 12),
+    (keyboard::KeyCode::O, 13),
+    (keyboard::KeyCode::L, 14),
+    (keyboard::KeyCode::P, 15),
+    (keyboard::KeyCode::Semicolon, 16),
+    (keyboard::KeyCode::Apostrophe, 17),
+];
+
+/// A piano keyboard for playing MIDI notes.
+///
+/// Keys are played with the mouse, with several fingers on touch screens, and,
+/// if enabled with [`keyboard_mapping`](PianoKeys::keyboard_mapping), with the
+/// rows of a computer keyboard. Notes held elsewhere, for example on a MIDI
+/// controller, can be highlighted with [`held`](PianoKeys::held).
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::PianoKeys;
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     NoteOn(u8),
+///     NoteOff(u8),
+/// }
+///
+/// // Two octaves from middle C
+/// let piano_keys = PianoKeys::new(60, 83, Message::NoteOn, Message::NoteOff)
+///     .keyboard_mapping(60);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct PianoKeys<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The lowest note, always on a white key.
+    lowest: u8,
+    /// The highest note, always on a white key.
+    highest: u8,
+    /// The function producing the message when a note starts.
+    on_note_on: Box<dyn Fn(u8) -> Message + 'a>,
+    /// The function producing the message when a note stops.
+    on_note_off: Box<dyn Fn(u8) -> Message + 'a>,
+    /// The notes held outside of the [`PianoKeys`].
+    held: Vec<u8>,
+    /// The note played by the first key of the computer keyboard mapping.
+    keyboard_base: Option<u8>,
+    /// The width of the [`PianoKeys`].
+    width: Length,
+    /// The height of the [`PianoKeys`].
+    height: f32,
+    /// Show the note names on the C keys.
+    show_labels: bool,
+    /// The text size of the note names.
+    text_size: f32,
+    /// The style of the [`PianoKeys`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> PianoKeys<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates new [`PianoKeys`] from the `lowest` to the `highest` MIDI note,
+    /// extended to the next white keys, producing the messages of
+    /// `on_note_on` and `on_note_off` when notes start and stop.
+    pub fn new<F, G>(lowest: u8, highest: u8, on_note_on: F, on_note_off: G) -> Self
+    where
+        F: 'a + Fn(u8) -> Message,
+        G: 'a + Fn(u8) -> Message,
+    {
+        let (mut lowest, mut highest) = (lowest.min(highest), highest.max(lowest).min(127));
+        if is_black_key(lowest) {
+            lowest -= 1;
+        }
+        if is_black_key(highest) {
+            highest += 1;
+        }
+
+        Self {
+            lowest,
+            highest,
+            on_note_on: Box::new(on_note_on),
+            on_note_off: Box::new(on_note_off),
+            held: Vec::new(),
+            keyboard_base: None,
+            width: Length::Fill,
+            height: 120.0,
+            show_labels: true,
+            text_size: 10.0,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the notes held outside of the [`PianoKeys`] to highlight them.
+    #[must_use]
+    pub fn held(mut self, notes: impl IntoIterator<Item = u8>) -> Self {
+        self.held = notes.into_iter().collect();
+        self
+    }
+
+    /// Enables playing the [`PianoKeys`] with a computer keyboard, with `A`
+    /// playing the given base note and the keys of the row above playing the
+    /// black keys.
+    #[must_use]
+    pub fn keyboard_mapping(mut self, base: u8) -> Self {
+        self.keyboard_base = Some(base);
+        self
+    }
+
+    /// Sets the width of the [`PianoKeys`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`PianoKeys`].
+    #[must_use]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets whether the note names are shown on the C keys of the [`PianoKeys`].
+    #[must_use]
+    pub fn show_labels(mut self, show_labels: bool) -> Self {
+        self.show_labels = show_labels;
+        self
+    }
+
+    /// Sets the text size of the note names of the [`PianoKeys`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the style of the [`PianoKeys`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the width of a white key.
+    #[allow(clippy::cast_precision_loss)]
+    fn white_key_width(&self, bounds: Rectangle) -> f32 {
+        let count = white_keys_below(self.highest) + 1 - white_keys_below(self.lowest);
+        bounds.width / count as f32
+    }
+
+    /// Returns the bounds of the key of the given note.
+    #[allow(clippy::cast_precision_loss)]
+    fn key_bounds(&self, bounds: Rectangle, note: u8) -> Rectangle {
+        let white_width = self.white_key_width(bounds);
+        let x = bounds.x
+            + (white_keys_below(note) - white_keys_below(self.lowest)) as f32 * white_width;
+
+        if is_black_key(note) {
+            let width = white_width * BLACK_KEY_WIDTH;
+            Rectangle {
+                x: x - width / 2.0,
+                y: bounds.y,
+                width,
+                height: bounds.height * BLACK_KEY_HEIGHT,
+            }
+        } else {
+            Rectangle {
+                x,
+                width: white_width,
+                ..bounds
+            }
+        }
+    }
+
+    /// Returns the note of the key at the given position.
+    fn note_at(&self, bounds: Rectangle, position: Point) -> Option<u8> {
+        if !bounds.contains(position) {
+            return None;
+        }
+
+        // The black keys lie on top of the white keys.
+        let notes = self.lowest..=self.highest;
+        notes
+            .clone()
+            .filter(|note| is_black_key(*note))
+            .chain(notes.filter(|note| !is_black_key(*note)))
+            .find(|note| self.key_bounds(bounds, *note).contains(position))
+    }
+
+    /// Moves the given input to the given note, or releases it, publishing
+    /// the note changes this causes.
+    fn press(
+        &self,
+        state: &mut State,
+        input: Input,
+        note: Option<u8>,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let previous = match input {
+            Input::Mouse => std::mem::replace(&mut state.mouse, note),
+            Input::Finger(finger) => match note {
+                Some(note) => state.fingers.insert(finger, note),
+                None => state.fingers.remove(&finger),
+            },
+            Input::Key(key_code) => match note {
+                Some(note) => state.keys.insert(key_code, note),
+                None => state.keys.remove(&key_code),
+            },
+        };
+
+        if previous == note {
+            return;
+        }
+
+        // A note keeps sounding while any other input still presses it.
+        if let Some(previous) = previous {
+            if state.presses(previous) == 0 {
+                shell.publish((self.on_note_off)(previous));
+            }
+        }
+
+        // A note only starts when no other input pressed it before.
+        if let Some(note) = note {
+            if state.presses(note) == 1 {
+                shell.publish((self.on_note_on)(note));
+            }
+        }
+    }
+
+    /// Returns the note played by the given key of the computer keyboard.
+    fn note_of_key(&self, key_code: keyboard::KeyCode) -> Option<u8> {
+        let base = self.keyboard_base?;
+        KEY_MAP
+            .iter()
+            .find(|(key, _)| *key == key_code)
+            .and_then(|(_, offset)| base.checked_add(*offset))
+            .filter(|note| *note <= 127)
+    }
+}
+
+/// The state of [`PianoKeys`].
+#[derive(Clone, Debug, Default)]
+struct State {
+    /// The note pressed with the mouse.
+    mouse: Option<u8>,
+    /// The notes pressed by the fingers touching the keys.
+    fingers: HashMap<touch::Finger, u8>,
+    /// The notes pressed with the computer keyboard.
+    keys: HashMap<keyboard::KeyCode, u8>,
+}
+
+impl State {
+    /// Returns the number of inputs pressing the given note.
+    fn presses(&self, note: u8) -> usize {
+        self.mouse
+            .iter()
+            .chain(self.fingers.values())
+            .chain(self.keys.values())
+            .filter(|pressed| **pressed == note)
+            .count()
+    }
+}
+
+/// The inputs pressing the keys of [`PianoKeys`].
+#[derive(Clone, Copy, Debug)]
+enum Input {
+    /// The mouse.
+    Mouse,
+    /// A finger on a touch screen.
+    Finger(touch::Finger),
+    /// A key of the computer keyboard.
+    Key(keyboard::KeyCode),
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for PianoKeys<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Fixed(self.height)
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(Length::Fixed(self.height));
+
+        Node::new(limits.resolve(Size::new(limits.max().width, self.height)))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(note) = cursor.position().and_then(|p| self.note_at(bounds, p)) {
+                    self.press(state, Input::Mouse, Some(note), shell);
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) if state.mouse.is_some() => {
+                // Glide over the keys while the button is held.
+                if let Some(note) = self.note_at(bounds, position) {
+                    self.press(state, Input::Mouse, Some(note), shell);
+                }
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if state.mouse.is_some() =>
+            {
+                self.press(state, Input::Mouse, None, shell);
+                return event::Status::Captured;
+            }
+            Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                if let Some(note) = self.note_at(bounds, position) {
+                    self.press(state, Input::Finger(id), Some(note), shell);
+                    return event::Status::Captured;
+                }
+            }
+            Event::Touch(touch::Event::FingerMoved { id, position })
+                if state.fingers.contains_key(&id) =>
+            {
+                if let Some(note) = self.note_at(bounds, position) {
+                    self.press(state, Input::Finger(id), Some(note), shell);
+                }
+                return event::Status::Captured;
+            }
+            Event::Touch(
+                touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. },
+            ) if state.fingers.contains_key(&id) => {
+                self.press(state, Input::Finger(id), None, shell);
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if !modifiers.command() => {
+                // Key repeats of a held key are ignored.
+                if let Some(note) = self.note_of_key(key_code) {
+                    if !state.keys.contains_key(&key_code) {
+                        self.press(state, Input::Key(key_code), Some(note), shell);
+                    }
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyReleased { key_code, .. })
+                if state.keys.contains_key(&key_code) =>
+            {
+                self.press(state, Input::Key(key_code), None, shell);
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let pressed = |note: u8| state.presses(note) > 0 || self.held.contains(&note);
+
+        // White keys first, so that the black keys are drawn on top of them.
+        for black in [false, true] {
+            for note in (self.lowest..=self.highest).filter(|note| is_black_key(*note) == black) {
+                let key = self.key_bounds(bounds, note);
+                let color = match (black, pressed(note)) {
+                    (false, false) => appearance.white_key_color,
+                    (false, true) => appearance.pressed_white_key_color,
+                    (true, false) => appearance.black_key_color,
+                    (true, true) => appearance.pressed_black_key_color,
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: key,
+                        border_radius: [0.0, 0.0, 3.0, 3.0].into(),
+                        border_width: 1.0,
+                        border_color: appearance.border_color,
+                    },
+                    color,
+                );
+
+                if self.show_labels && note % 12 == 0 {
+                    renderer.fill_text(core::text::Text {
+                        content: &note_name(note),
+                        bounds: Rectangle {
+                            x: key.center_x(),
+                            y: key.y + key.height - self.text_size,
+                            ..key
+                        },
+                        size: self.text_size,
+                        line_height: LineHeight::default(),
+                        color: appearance.label_color,
+                        font: core::Font::default(),
+                        horizontal_alignment: Horizontal::Center,
+                        vertical_alignment: Vertical::Center,
+                        shaping: text::Shaping::Basic,
+                    });
+                }
+            }
+        }
+
+        // Outline around all keys.
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: 1.0,
+                border_color: appearance.border_color,
+            },
+            Color::TRANSPARENT,
+        );
+    }
+}
+
+impl<'a, Message, Renderer> From<PianoKeys<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(piano_keys: PianoKeys<'a, Message, Renderer>) -> Self {
+        Element::new(piano_keys)
+    }
+}
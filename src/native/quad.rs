@@ -2,6 +2,8 @@
 //!
 //! *This API requires the following crate features to be activated: `quad`*
 
+use crate::core::pixel;
+
 use iced_widget::core::{
     layout::{Limits, Node},
     mouse::Cursor,
@@ -87,6 +89,8 @@ pub struct Quad {
     pub border_width: f32,
     /// Border color of the quad
     pub border_color: Color,
+    /// Scale factor the quad is snapped to, if any
+    scale_factor: Option<f32>,
 }
 impl Default for Quad {
     fn default() -> Self {
@@ -99,10 +103,79 @@ impl Default for Quad {
             border_radius: [0.0, 0.0, 0.0, 0.0],
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
+            scale_factor: None,
         }
     }
 }
 
+impl Quad {
+    /// Sets the width of the quad.
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the quad.
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the color of the quad.
+    #[must_use]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Sets the background color of the quad.
+    #[must_use]
+    pub fn background(mut self, background: Color) -> Self {
+        self.background = Some(background);
+        self
+    }
+
+    /// Sets the method creating the inner bounds of the quad.
+    #[must_use]
+    pub fn inner_bounds(mut self, inner_bounds: InnerBounds) -> Self {
+        self.inner_bounds = inner_bounds;
+        self
+    }
+
+    /// Sets the border radius of the quad.
+    #[must_use]
+    pub fn border_radius(mut self, border_radius: [f32; 4]) -> Self {
+        self.border_radius = border_radius;
+        self
+    }
+
+    /// Sets the border width of the quad.
+    #[must_use]
+    pub fn border_width(mut self, border_width: f32) -> Self {
+        self.border_width = border_width;
+        self
+    }
+
+    /// Sets the border color of the quad.
+    #[must_use]
+    pub fn border_color(mut self, border_color: Color) -> Self {
+        self.border_color = border_color;
+        self
+    }
+
+    /// Sets the scale factor the quad is rendered at, to snap it to physical
+    /// pixels, e.g. to keep thin separators sharp at fractional scale factors.
+    ///
+    /// Nothing is snapped unless the scale factor is set.
+    #[must_use]
+    pub fn scale_factor(mut self, scale_factor: f32) -> Self {
+        self.scale_factor = Some(scale_factor);
+        self
+    }
+}
+
 impl<Message, Renderer> Widget<Message, Renderer> for Quad
 where
     Renderer: renderer::Renderer,
@@ -130,12 +203,14 @@ where
         _cursor: Cursor,
         _viewport: &Rectangle,
     ) {
+        let border_width = pixel::snap_width_to(self.border_width, self.scale_factor);
+
         if let Some(b) = self.background {
             renderer.fill_quad(
                 renderer::Quad {
-                    bounds: layout.bounds(),
+                    bounds: pixel::snap_rectangle_to(layout.bounds(), self.scale_factor),
                     border_radius: self.border_radius.into(),
-                    border_width: self.border_width,
+                    border_width,
                     border_color: self.border_color,
                 },
                 b,
@@ -143,9 +218,12 @@ where
         }
         renderer.fill_quad(
             renderer::Quad {
-                bounds: self.inner_bounds.get_bounds(layout.bounds()),
+                bounds: pixel::snap_rectangle_to(
+                    self.inner_bounds.get_bounds(layout.bounds()),
+                    self.scale_factor,
+                ),
                 border_radius: self.border_radius.into(),
-                border_width: self.border_width,
+                border_width,
                 border_color: self.border_color,
             },
             self.color,
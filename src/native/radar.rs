@@ -0,0 +1,607 @@
+//! Use a radar chart to compare series of values over a number of axes.
+//!
+//! *This API requires the following crate features to be activated: `radar`*
+use crate::core::{
+    axis::nice_ticks,
+    radar::{fraction, polygon_area, polygon_contains, spoke_angle, spoke_point},
+};
+use crate::native::helpers::chart;
+
+use iced_widget::{
+    canvas::{self, Path, Stroke},
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer,
+        text::{self, Renderer as _},
+        widget::Tree,
+        Color, Element, Layout, Length, Point, Rectangle, Renderer as _, Size, Vector, Widget,
+    },
+    graphics::geometry::Renderer as _,
+    renderer::Renderer,
+    text::LineHeight,
+};
+
+pub use crate::style::radar::{Appearance, StyleSheet};
+
+/// The padding around the labels and the text of the tooltip.
+const PADDING: f32 = 4.0;
+/// The distance in pixels from a value within which the cursor hovers it.
+const HOVER_DISTANCE: f32 = 8.0;
+/// The factor the opacity of the series besides the hovered one is reduced
+/// by.
+const DIMMED: f32 = 0.3;
+
+/// A radar chart, also known as a spider chart, comparing series of values
+/// over a number of axes arranged like the spokes of a wheel.
+///
+/// Each series is drawn as a filled polygon connecting its values on the
+/// spokes. The scale adapts to the values unless it is given a fixed range.
+/// Hovering a series highlights it, and hovering one of its values shows it
+/// in a tooltip.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::RadarChart;
+/// #
+/// let axes = ["Speed", "Power", "Range", "Armor", "Agility"].map(String::from);
+/// let scout = [9.0, 3.0, 6.0, 2.0, 8.0];
+/// let tank = [2.0, 8.0, 4.0, 10.0, 3.0];
+///
+/// let chart = RadarChart::new(&axes)
+///     .series("Scout", &scout)
+///     .series("Tank", &tank)
+///     .range(0.0, 10.0);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct RadarChart<'a, Theme = iced_widget::style::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The labels of the axes.
+    axes: &'a [String],
+    /// The labels and the values of the compared series, one value per axis.
+    series: Vec<(String, &'a [f32])>,
+    /// The fixed range of the scale.
+    range: Option<(f32, f32)>,
+    /// The number of rings of the scale.
+    rings: usize,
+    /// The opacity of the fill of the series.
+    fill_opacity: f32,
+    /// Whether each of the series is shown.
+    visible: &'a [bool],
+    /// The series highlighted while the cursor doesn't hover one.
+    highlight: Option<usize>,
+    /// The width of the [`RadarChart`].
+    width: Length,
+    /// The height of the [`RadarChart`].
+    height: Length,
+    /// The text size of the labels.
+    text_size: f32,
+    /// The function formatting the labels of the scale and the value in the
+    /// tooltip.
+    format_value: Box<dyn Fn(f32) -> String + 'a>,
+    /// The style of the [`RadarChart`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Theme> RadarChart<'a, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`RadarChart`] with the given labels of its axes.
+    #[must_use]
+    pub fn new(axes: &'a [String]) -> Self {
+        Self {
+            axes,
+            series: Vec::new(),
+            range: None,
+            rings: 4,
+            fill_opacity: 0.25,
+            visible: &[],
+            highlight: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            text_size: 12.0,
+            format_value: Box::new(|value| format!("{value}")),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Adds a series with the given label and values to the [`RadarChart`],
+    /// with one value per axis.
+    ///
+    /// Missing values are drawn at the center.
+    #[must_use]
+    pub fn series(mut self, label: impl Into<String>, values: &'a [f32]) -> Self {
+        self.series.push((label.into(), values));
+        self
+    }
+
+    /// Sets the fixed range of the scale from the center to the end of the
+    /// spokes, instead of adapting it to the values.
+    #[must_use]
+    pub fn range(mut self, min: f32, max: f32) -> Self {
+        self.range = Some((min.min(max), max.max(min)));
+        self
+    }
+
+    /// Sets the number of rings of the scale of the [`RadarChart`].
+    ///
+    /// The rings of an adapted scale are placed at round values, so their
+    /// number may differ slightly.
+    #[must_use]
+    pub fn rings(mut self, rings: usize) -> Self {
+        self.rings = rings.max(1);
+        self
+    }
+
+    /// Sets the opacity of the fill of the series, from 0.0 for only drawing
+    /// their outlines to 1.0.
+    #[must_use]
+    pub fn fill_opacity(mut self, fill_opacity: f32) -> Self {
+        self.fill_opacity = fill_opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets whether each of the series of the [`RadarChart`] is shown, in the
+    /// order they were added, with the series past the end of the slice
+    /// shown.
+    ///
+    /// Hidden series keep their colors from the palette, so the colors of the
+    /// other series don't change when one is hidden.
+    #[must_use]
+    pub fn visible(mut self, visible: &'a [bool]) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Sets the index of the series highlighted like a hovered one while the
+    /// cursor doesn't hover a series, e.g. the one hovered in a chart legend.
+    #[must_use]
+    pub fn highlight(mut self, highlight: Option<usize>) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Sets the width of the [`RadarChart`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`RadarChart`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the text size of the labels of the [`RadarChart`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the function formatting the labels of the scale and the value
+    /// shown in the tooltip.
+    #[must_use]
+    pub fn format_value<F>(mut self, format_value: F) -> Self
+    where
+        F: 'a + Fn(f32) -> String,
+    {
+        self.format_value = Box::new(format_value);
+        self
+    }
+
+    /// Sets the style of the [`RadarChart`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the range of the scale and the values of its rings.
+    fn scale(&self) -> ((f32, f32), Vec<f32>) {
+        if let Some((min, max)) = self.range {
+            #[allow(clippy::cast_precision_loss)]
+            let rings = (1..=self.rings)
+                .map(|ring| min + (max - min) * ring as f32 / self.rings as f32)
+                .collect();
+
+            return ((min, max), rings);
+        }
+
+        let (min, max) = self
+            .series
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| self.is_shown(*index))
+            .flat_map(|(_, (_, values))| values.iter())
+            .filter(|value| value.is_finite())
+            .fold((0.0_f32, 0.0_f32), |(min, max), value| {
+                (min.min(*value), max.max(*value))
+            });
+        let ticks = nice_ticks(min, max, self.rings);
+
+        match (ticks.first(), ticks.last()) {
+            (Some(&low), Some(&high)) if high > low => ((low, high), ticks[1..].to_vec()),
+            _ => ((0.0, 1.0), vec![1.0]),
+        }
+    }
+
+    /// Returns whether the series at the given index is shown.
+    fn is_shown(&self, index: usize) -> bool {
+        self.visible.get(index).copied().unwrap_or(true)
+    }
+
+    /// Returns the points of the values of the given series.
+    fn polygon(&self, values: &[f32], center: Point, radius: f32, range: (f32, f32)) -> Vec<Point> {
+        let count = self.axes.len();
+
+        (0..count)
+            .map(|axis| {
+                let value = values.get(axis).copied().unwrap_or(range.0);
+
+                spoke_point(
+                    center,
+                    radius * fraction(value, range.0, range.1),
+                    axis,
+                    count,
+                )
+            })
+            .collect()
+    }
+}
+
+/// Returns the hovered series at the given position and the axis of its
+/// hovered value, if any.
+///
+/// A value near the cursor is hovered first, and otherwise the smallest
+/// series enclosing the cursor.
+fn hovered(polygons: &[Vec<Point>], position: Point) -> Option<(usize, Option<usize>)> {
+    let nearest = polygons
+        .iter()
+        .enumerate()
+        .flat_map(|(series, polygon)| {
+            polygon
+                .iter()
+                .enumerate()
+                .map(move |(axis, point)| (series, axis, point.distance(position)))
+        })
+        .filter(|(_, _, distance)| *distance <= HOVER_DISTANCE)
+        .min_by(|a, b| a.2.total_cmp(&b.2));
+
+    if let Some((series, axis, _)) = nearest {
+        return Some((series, Some(axis)));
+    }
+
+    polygons
+        .iter()
+        .enumerate()
+        .filter(|(_, polygon)| polygon_contains(polygon, position))
+        .min_by(|(_, a), (_, b)| polygon_area(a).total_cmp(&polygon_area(b)))
+        .map(|(series, _)| (series, None))
+}
+
+impl<Message, Theme> Widget<Message, Renderer<Theme>> for RadarChart<'_, Theme>
+where
+    Theme: StyleSheet,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer<Theme>, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        Node::new(limits.resolve(Size::new(f32::INFINITY, f32::INFINITY)))
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer<Theme>,
+    ) -> mouse::Interaction {
+        mouse::Interaction::default()
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn draw(
+        &self,
+        _state: &Tree,
+        renderer: &mut Renderer<Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let line_height = self.text_size * 1.3;
+        let count = self.axes.len();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        if count < 3 {
+            return;
+        }
+
+        // The labels of the axes are placed around the spokes.
+        let label_width = self
+            .axes
+            .iter()
+            .map(|label| {
+                renderer.measure_width(
+                    label,
+                    self.text_size,
+                    core::Font::default(),
+                    text::Shaping::Advanced,
+                )
+            })
+            .fold(0.0, f32::max);
+        let center = bounds.center();
+        let radius = (bounds.width / 2.0 - label_width - PADDING * 2.0)
+            .min(bounds.height / 2.0 - line_height - PADDING * 2.0)
+            .max(1.0);
+
+        let (range, rings) = self.scale();
+        // The hidden series have no points, so they can't be hovered.
+        let polygons: Vec<Vec<Point>> = self
+            .series
+            .iter()
+            .enumerate()
+            .map(|(index, (_, values))| {
+                if self.is_shown(index) {
+                    self.polygon(values, center, radius, range)
+                } else {
+                    Vec::new()
+                }
+            })
+            .collect();
+        let hovered = cursor
+            .position_over(bounds)
+            .and_then(|position| hovered(&polygons, position))
+            .or_else(|| {
+                self.highlight
+                    .filter(|series| *series < polygons.len() && self.is_shown(*series))
+                    .map(|series| (series, None))
+            });
+
+        let local = |point: Point| point - Vector::new(bounds.x, bounds.y);
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+
+        // Rings and spokes
+        for ring in &rings {
+            let distance = radius * fraction(*ring, range.0, range.1);
+            let path = Path::new(|builder| {
+                builder.move_to(local(spoke_point(center, distance, 0, count)));
+                for axis in 1..count {
+                    builder.line_to(local(spoke_point(center, distance, axis, count)));
+                }
+                builder.close();
+            });
+            frame.stroke(
+                &path,
+                Stroke::default()
+                    .with_color(appearance.grid_color)
+                    .with_width(1.0),
+            );
+        }
+
+        let spokes = Path::new(|builder| {
+            for axis in 0..count {
+                builder.move_to(local(center));
+                builder.line_to(local(spoke_point(center, radius, axis, count)));
+            }
+        });
+        frame.stroke(
+            &spokes,
+            Stroke::default()
+                .with_color(appearance.axis_color)
+                .with_width(1.0),
+        );
+
+        // Series, with the hovered one drawn last and the others dimmed.
+        let mut order: Vec<usize> = (0..polygons.len())
+            .filter(|index| self.is_shown(*index))
+            .collect();
+        if let Some((series, _)) = hovered {
+            order.retain(|index| *index != series);
+            order.push(series);
+        }
+
+        for index in order {
+            let color = appearance.palette[index % appearance.palette.len()];
+            let (fill_opacity, line_opacity, width) = match hovered {
+                Some((series, _)) if series == index => (
+                    (self.fill_opacity * 1.5).min(1.0),
+                    1.0,
+                    appearance.line_width * 1.5,
+                ),
+                Some(_) => (self.fill_opacity * DIMMED, DIMMED, appearance.line_width),
+                None => (self.fill_opacity, 1.0, appearance.line_width),
+            };
+
+            let path = Path::new(|builder| {
+                for (i, point) in polygons[index].iter().enumerate() {
+                    if i == 0 {
+                        builder.move_to(local(*point));
+                    } else {
+                        builder.line_to(local(*point));
+                    }
+                }
+                builder.close();
+            });
+            frame.fill(
+                &path,
+                Color {
+                    a: color.a * fill_opacity,
+                    ..color
+                },
+            );
+            frame.stroke(
+                &path,
+                Stroke::default()
+                    .with_color(Color {
+                        a: color.a * line_opacity,
+                        ..color
+                    })
+                    .with_width(width),
+            );
+        }
+
+        if let Some((series, _)) = hovered {
+            let color = appearance.palette[series % appearance.palette.len()];
+            for point in &polygons[series] {
+                frame.fill(&Path::circle(local(*point), 3.0), color);
+            }
+        }
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+                renderer.draw(vec![frame.into_geometry()]);
+            });
+        });
+
+        renderer.with_layer(bounds, |renderer| {
+            for (axis, label) in self.axes.iter().enumerate() {
+                let angle = spoke_angle(axis, count);
+                let position = spoke_point(center, radius + PADDING, axis, count);
+
+                renderer.fill_text(core::text::Text {
+                    content: label,
+                    bounds: Rectangle {
+                        x: position.x,
+                        y: position.y,
+                        width: label_width,
+                        height: line_height,
+                    },
+                    size: self.text_size,
+                    line_height: LineHeight::default(),
+                    color: appearance.text_color,
+                    font: core::Font::default(),
+                    horizontal_alignment: match angle.cos() {
+                        cos if cos > 0.1 => Horizontal::Left,
+                        cos if cos < -0.1 => Horizontal::Right,
+                        _ => Horizontal::Center,
+                    },
+                    vertical_alignment: match angle.sin() {
+                        sin if sin > 0.1 => Vertical::Top,
+                        sin if sin < -0.1 => Vertical::Bottom,
+                        _ => Vertical::Center,
+                    },
+                    shaping: text::Shaping::Advanced,
+                });
+            }
+
+            // The values of the rings are labeled right below them, next to
+            // the first spoke.
+            for ring in &rings {
+                let distance = radius * fraction(*ring, range.0, range.1);
+
+                renderer.fill_text(core::text::Text {
+                    content: &(self.format_value)(*ring),
+                    bounds: Rectangle {
+                        x: center.x + PADDING,
+                        y: center.y - distance + 1.0,
+                        width: radius,
+                        height: line_height,
+                    },
+                    size: self.text_size * 0.85,
+                    line_height: LineHeight::default(),
+                    color: Color {
+                        a: appearance.text_color.a * 0.6,
+                        ..appearance.text_color
+                    },
+                    font: core::Font::default(),
+                    horizontal_alignment: Horizontal::Left,
+                    vertical_alignment: Vertical::Top,
+                    shaping: text::Shaping::Advanced,
+                });
+            }
+        });
+
+        let (Some(position), Some((series, Some(axis)))) = (cursor.position(), hovered) else {
+            return;
+        };
+
+        let (label, values) = &self.series[series];
+        let value = values.get(axis).copied().unwrap_or(range.0);
+        let lines = [
+            label.clone(),
+            format!("{}: {}", self.axes[axis], (self.format_value)(value)),
+        ];
+        chart::tooltip(
+            renderer,
+            &lines,
+            self.text_size,
+            text::Shaping::Advanced,
+            position,
+            bounds,
+            appearance.tooltip_background,
+            appearance.tooltip_text_color,
+        );
+    }
+}
+
+impl<'a, Message, Theme> From<RadarChart<'a, Theme>> for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+{
+    fn from(radar_chart: RadarChart<'a, Theme>) -> Self {
+        Element::new(radar_chart)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RadarChart;
+    use crate::test_utils::Harness;
+    use iced_widget::core::{Point, Size};
+
+    #[test]
+    fn radar_test() {
+        let axes = ["A", "B", "C", "D", "E"].map(String::from);
+        let large = [8.0, 9.0, 7.0, 8.0, 9.0];
+        let small = [3.0, 2.0, 4.0, 3.0, 2.0];
+
+        let chart = RadarChart::new(&axes)
+            .series("Large", &large)
+            .series("Small", &small)
+            .range(0.0, 10.0);
+        let mut harness = Harness::<()>::new(chart, Size::new(200.0, 200.0));
+        let plain = harness.snapshot();
+
+        // Hovering inside of both series highlights the smaller one, and
+        // hovering outside of them highlights none.
+        let _ = harness.move_cursor(Point::new(100.0, 105.0));
+        assert!(plain
+            .diff(&harness.snapshot(), 8)
+            .is_some_and(|count| count > 100));
+
+        let _ = harness.move_cursor(Point::new(2.0, 2.0));
+        assert_eq!(plain.diff(&harness.snapshot(), 8), Some(0));
+    }
+}
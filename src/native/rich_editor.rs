@@ -0,0 +1,1473 @@
+//! Use a rich editor to edit formatted text.
+//!
+//! *This API requires the following crate features to be activated: `rich_editor`*
+use crate::core::{
+    i18n,
+    ime::Caret,
+    rich_text::{BlockKind, Document, Format, Position, SpanStyle},
+    text_boundary,
+};
+use crate::style::rich_editor::StyleSheet;
+
+use std::{borrow::Cow, ops::Range};
+
+use iced_widget::{
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event, font, keyboard,
+        layout::{Limits, Node},
+        mouse::{self, click, Cursor},
+        renderer, text, touch,
+        widget::{
+            tree::{State as TreeState, Tag},
+            Operation, Tree,
+        },
+        Clipboard, Color, Element, Event, Font, Layout, Length, Pixels, Point, Rectangle, Shell,
+        Size, Vector, Widget,
+    },
+    text::LineHeight,
+};
+
+/// The height of the toolbar.
+const TOOLBAR_HEIGHT: f32 = 32.0;
+/// The spacing between and around the buttons of the toolbar.
+const TOOLBAR_SPACING: f32 = 4.0;
+/// The space between two blocks.
+const BLOCK_SPACING: f32 = 4.0;
+/// The number of pixels scrolled per scrolled line.
+const SCROLL_STEP: f32 = 40.0;
+
+/// The buttons of the toolbar with their minimum widths.
+const TOOLS: [(Tool, f32); 7] = [
+    (Tool::Format(Format::Bold), 28.0),
+    (Tool::Format(Format::Italic), 28.0),
+    (Tool::Format(Format::Underline), 28.0),
+    (Tool::Format(Format::Code), 32.0),
+    (Tool::Link, 44.0),
+    (Tool::List(BlockKind::Bullet), 28.0),
+    (Tool::List(BlockKind::Numbered), 32.0),
+];
+/// The average width of a character of the labels of the toolbar, relative
+/// to their text size.
+const LABEL_CHAR_WIDTH: f32 = 0.6;
+
+/// An editor of formatted text with a toolbar.
+///
+/// The [`Document`] of the editor has paragraphs, bulleted and numbered
+/// lists, and bold, italic, underlined, code and linked text. It can be
+/// serialized to and parsed from Markdown.
+///
+/// The toolbar toggles the formats and lists of the selection. `Ctrl+B`,
+/// `Ctrl+I` and `Ctrl+U` toggle bold, italic and underlined text. With a URL
+/// in the clipboard, the link button or pasting links the selected text, and
+/// the link button removes the link of selected linked text. A `Ctrl` click
+/// on a link opens it with [`on_link`](Self::on_link).
+///
+/// Fonts have no italic style, so italic text is drawn in a different color.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{Document, RichEditor};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Edited(Document),
+/// }
+///
+/// let document = Document::from_markdown("Some **bold** text");
+///
+/// let editor = RichEditor::new(&document, Message::Edited);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct RichEditor<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The edited document.
+    document: Cow<'a, Document>,
+    /// The function producing the message when the document is edited.
+    on_change: Box<dyn Fn(Document) -> Message + 'a>,
+    /// The function producing the message when a link is opened.
+    on_link: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    /// Whether the toolbar is shown.
+    toolbar: bool,
+    /// The labels of the buttons of the toolbar, in the order of `TOOLS`.
+    tool_labels: [String; 7],
+    /// The width of the [`RichEditor`].
+    width: Length,
+    /// The height of the [`RichEditor`].
+    height: Length,
+    /// The padding around the text.
+    padding: f32,
+    /// The text size.
+    text_size: f32,
+    /// The line height.
+    line_height: LineHeight,
+    /// The font of the text.
+    font: Font,
+    /// The font of inline code.
+    code_font: Font,
+    /// The indentation of list items.
+    indent: f32,
+    /// The style of the [`RichEditor`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> RichEditor<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`RichEditor`] of the given [`Document`], producing the
+    /// message of `on_change` with the edited document.
+    pub fn new<F>(document: &'a Document, on_change: F) -> Self
+    where
+        F: 'a + Fn(Document) -> Message,
+    {
+        Self {
+            document: Cow::Borrowed(document),
+            on_change: Box::new(on_change),
+            on_link: None,
+            toolbar: true,
+            tool_labels: tool_labels(),
+            width: Length::Fill,
+            height: Length::Shrink,
+            padding: 8.0,
+            text_size: 16.0,
+            line_height: LineHeight::default(),
+            font: Font::DEFAULT,
+            code_font: Font::MONOSPACE,
+            indent: 24.0,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the function producing the message with the target of a link
+    /// opened with a `Ctrl` click.
+    #[must_use]
+    pub fn on_link<F>(mut self, on_link: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        self.on_link = Some(Box::new(on_link));
+        self
+    }
+
+    /// Shows or hides the toolbar of the [`RichEditor`].
+    #[must_use]
+    pub fn toolbar(mut self, toolbar: bool) -> Self {
+        self.toolbar = toolbar;
+        self
+    }
+
+    /// Sets the width of the [`RichEditor`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`RichEditor`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the padding around the text of the [`RichEditor`].
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`RichEditor`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the line height of the [`RichEditor`].
+    #[must_use]
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the font of the text of the [`RichEditor`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the font of the inline code of the [`RichEditor`].
+    #[must_use]
+    pub fn code_font(mut self, code_font: Font) -> Self {
+        self.code_font = code_font;
+        self
+    }
+
+    /// Sets the indentation of the list items of the [`RichEditor`].
+    #[must_use]
+    pub fn indent(mut self, indent: f32) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Sets the style of the [`RichEditor`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the height of the toolbar.
+    fn toolbar_height(&self) -> f32 {
+        if self.toolbar {
+            TOOLBAR_HEIGHT
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the height of a line.
+    fn line_pixels(&self) -> f32 {
+        self.line_height.to_absolute(Pixels(self.text_size)).0
+    }
+
+    /// Returns the font of text of the given style.
+    fn span_font(&self, style: &SpanStyle) -> Font {
+        let font = if style.code {
+            self.code_font
+        } else {
+            self.font
+        };
+
+        if style.bold {
+            Font {
+                weight: font::Weight::Bold,
+                ..font
+            }
+        } else {
+            font
+        }
+    }
+
+    /// Returns the bounds of the text of the [`RichEditor`] with the given bounds.
+    fn text_bounds(&self, bounds: Rectangle) -> Rectangle {
+        let top = self.toolbar_height();
+
+        Rectangle {
+            x: bounds.x + self.padding,
+            y: bounds.y + top + self.padding,
+            width: (bounds.width - self.padding * 2.0).max(0.0),
+            height: (bounds.height - top - self.padding * 2.0).max(0.0),
+        }
+    }
+
+    /// Lays out the text of the document in lines of the given width.
+    fn lines(&self, renderer: &Renderer, width: f32) -> Vec<Line> {
+        let line_pixels = self.line_pixels();
+        let mut lines = Vec::new();
+        let mut y = 0.0;
+
+        for (block_index, block) in self.document.blocks.iter().enumerate() {
+            let indent = if block.kind == BlockKind::Paragraph {
+                0.0
+            } else {
+                self.indent
+            };
+            let available = (width - indent).max(1.0);
+
+            let mut line = Line::new(block_index, 0, indent, y);
+            let mut x = 0.0;
+            let mut offset = 0;
+
+            for (span_index, span) in block.spans.iter().enumerate() {
+                let font = self.span_font(&span.style);
+
+                for piece in words(&span.text) {
+                    let width = renderer.measure_width(
+                        piece,
+                        self.text_size,
+                        font,
+                        text::Shaping::Advanced,
+                    );
+                    let fit = renderer.measure_width(
+                        piece.trim_end(),
+                        self.text_size,
+                        font,
+                        text::Shaping::Advanced,
+                    );
+
+                    // Words wrap to the next line, unless they are the first of a line.
+                    if x + fit > available && line.end > line.start {
+                        y += line_pixels;
+                        lines.push(std::mem::replace(
+                            &mut line,
+                            Line::new(block_index, offset, indent, y),
+                        ));
+                        x = 0.0;
+                    }
+
+                    match line.fragments.last_mut() {
+                        Some(fragment)
+                            if fragment.span == span_index && fragment.range.end == offset =>
+                        {
+                            fragment.range.end += piece.len();
+                            fragment.width += width;
+                        }
+                        _ => line.fragments.push(Fragment {
+                            span: span_index,
+                            range: offset..offset + piece.len(),
+                            x,
+                            width,
+                        }),
+                    }
+
+                    x += width;
+                    offset += piece.len();
+                    line.end = offset;
+                }
+            }
+
+            lines.push(line);
+            y += line_pixels + BLOCK_SPACING;
+        }
+
+        lines
+    }
+
+    /// Returns the height of the given lines.
+    fn content_height(&self, lines: &[Line]) -> f32 {
+        lines.last().map_or(0.0, |line| line.y + self.line_pixels())
+    }
+
+    /// Returns the index of the line holding the given position.
+    fn line_of(lines: &[Line], position: Position) -> usize {
+        lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line.block == position.block)
+            .find(|(index, line)| {
+                position.offset < line.end
+                    || lines
+                        .get(index + 1)
+                        .is_none_or(|next| next.block != line.block)
+            })
+            .map_or(0, |(index, _)| index)
+    }
+
+    /// Returns the x coordinate of the given byte offset in the given line.
+    fn x_in_line(&self, renderer: &Renderer, line: &Line, offset: usize) -> f32 {
+        let block = &self.document.blocks[line.block];
+        let text = block.text();
+
+        line.fragments
+            .iter()
+            .find(|fragment| fragment.range.start <= offset && offset <= fragment.range.end)
+            .map_or(line.x, |fragment| {
+                let font = self.span_font(&block.spans[fragment.span].style);
+
+                line.x
+                    + fragment.x
+                    + renderer.measure_width(
+                        &text[fragment.range.start..offset],
+                        self.text_size,
+                        font,
+                        text::Shaping::Advanced,
+                    )
+            })
+    }
+
+    /// Returns the position of the caret at the given point of the text.
+    fn position_at(&self, renderer: &Renderer, lines: &[Line], point: Point) -> Position {
+        let index = lines
+            .iter()
+            .rposition(|line| line.y <= point.y)
+            .unwrap_or(0);
+        let line = &lines[index];
+        let is_last = lines
+            .get(index + 1)
+            .is_none_or(|next| next.block != line.block);
+
+        let x = point.x - line.x;
+        if x <= 0.0 {
+            return Position::new(line.block, line.start);
+        }
+
+        let block = &self.document.blocks[line.block];
+        let text = block.text();
+
+        let Some(fragment) = line
+            .fragments
+            .iter()
+            .find(|fragment| x < fragment.x + fragment.width)
+        else {
+            // Past the end of a wrapped line, the caret stays before the
+            // space the line wrapped at.
+            let end = if is_last {
+                line.end
+            } else {
+                text_boundary::previous_char(&text, line.end)
+            };
+            return Position::new(line.block, end.max(line.start));
+        };
+
+        let content = &text[fragment.range.clone()];
+        let offset = renderer
+            .hit_test(
+                content,
+                self.text_size,
+                self.line_height,
+                self.span_font(&block.spans[fragment.span].style),
+                Size::INFINITY,
+                text::Shaping::Advanced,
+                Point::new(x - fragment.x, self.line_pixels() / 2.0),
+                true,
+            )
+            .map_or(content.len(), text::Hit::cursor);
+
+        Position::new(
+            line.block,
+            fragment.range.start + text_boundary::floor_char_boundary(content, offset),
+        )
+    }
+
+    /// Returns the position before the given position, in the previous block
+    /// at the start of a block.
+    fn previous(&self, position: Position) -> Position {
+        if position.offset > 0 {
+            let text = self.document.blocks[position.block].text();
+            Position::new(
+                position.block,
+                text_boundary::previous_char(&text, position.offset),
+            )
+        } else if position.block > 0 {
+            Position::new(
+                position.block - 1,
+                self.document.blocks[position.block - 1].len(),
+            )
+        } else {
+            position
+        }
+    }
+
+    /// Returns the position after the given position, in the next block at
+    /// the end of a block.
+    fn next(&self, position: Position) -> Position {
+        let block = &self.document.blocks[position.block];
+
+        if position.offset < block.len() {
+            Position::new(
+                position.block,
+                text_boundary::next_char(&block.text(), position.offset),
+            )
+        } else if position.block + 1 < self.document.blocks.len() {
+            Position::new(position.block + 1, 0)
+        } else {
+            position
+        }
+    }
+
+    /// Returns the position in the line above or below the given position.
+    fn vertical(
+        &self,
+        renderer: &Renderer,
+        lines: &[Line],
+        position: Position,
+        down: bool,
+    ) -> Position {
+        let index = Self::line_of(lines, position);
+        let x = self.x_in_line(renderer, &lines[index], position.offset);
+
+        let target = if down {
+            index + 1
+        } else if let Some(target) = index.checked_sub(1) {
+            target
+        } else {
+            return Position::new(position.block, 0);
+        };
+        let Some(line) = lines.get(target) else {
+            return self.document.end();
+        };
+
+        self.position_at(
+            renderer,
+            lines,
+            Point::new(x, line.y + self.line_pixels() / 2.0),
+        )
+    }
+
+    /// Returns the style of text typed at the given position.
+    ///
+    /// Typed text continues the style of the text before it, but only
+    /// continues a link inside of it.
+    fn typing_style(&self, state: &State, position: Position) -> SpanStyle {
+        if let Some(pending) = &state.pending {
+            return pending.clone();
+        }
+
+        let mut style = self.document.blocks[position.block].style_at(position.offset);
+        if style.link.is_some() && self.document.link_at(position) != style.link {
+            style.link = None;
+        }
+        style
+    }
+
+    /// Returns the buttons of the toolbar with their bounds.
+    fn tools(&self, bounds: Rectangle) -> Vec<(Tool, &str, Rectangle)> {
+        if !self.toolbar {
+            return Vec::new();
+        }
+
+        let mut x = bounds.x + TOOLBAR_SPACING;
+
+        TOOLS
+            .iter()
+            .zip(&self.tool_labels)
+            .map(|((tool, width), label)| {
+                #[allow(clippy::cast_precision_loss)]
+                let label_width =
+                    label.chars().count() as f32 * self.text_size * 0.9 * LABEL_CHAR_WIDTH
+                        + TOOLBAR_SPACING * 2.0;
+                let button = Rectangle {
+                    x,
+                    y: bounds.y + TOOLBAR_SPACING,
+                    width: width.max(label_width),
+                    height: TOOLBAR_HEIGHT - TOOLBAR_SPACING * 2.0,
+                };
+                x += button.width + TOOLBAR_SPACING;
+                (*tool, label.as_str(), button)
+            })
+            .collect()
+    }
+
+    /// Returns `true` if the given tool applies to the selection.
+    fn is_active(&self, state: &State, tool: Tool) -> bool {
+        let selection = state.selection();
+
+        match tool {
+            Tool::Format(format) if selection.is_empty() => {
+                self.typing_style(state, state.focus).has(format)
+            }
+            Tool::Format(format) => self.document.has_format(selection, format),
+            Tool::Link => self.document.link_at(selection.start).is_some(),
+            Tool::List(kind) => self.document.blocks[selection.start.block..=selection.end.block]
+                .iter()
+                .all(|block| block.kind == kind),
+        }
+    }
+
+    /// Applies the given tool to the selection.
+    fn apply(
+        &mut self,
+        state: &mut State,
+        tool: Tool,
+        clipboard: &dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let selection = state.selection();
+
+        match tool {
+            // Without a selection, the format applies to the typed text.
+            Tool::Format(format) if selection.is_empty() => {
+                let mut style = self.typing_style(state, state.focus);
+                style.set(format, !style.has(format));
+                state.pending = Some(style);
+                return;
+            }
+            Tool::Format(format) => self.document.to_mut().toggle(selection, format),
+            Tool::Link if self.document.link_at(selection.start).is_some() => {
+                self.document.to_mut().set_link(selection, None);
+            }
+            Tool::Link => {
+                let Some(url) = clipboard.read().filter(|url| is_url(url)) else {
+                    return;
+                };
+                if selection.is_empty() {
+                    return;
+                }
+                self.document.to_mut().set_link(selection, Some(url.trim()));
+            }
+            Tool::List(kind) => {
+                let kind = if self.is_active(state, tool) {
+                    BlockKind::Paragraph
+                } else {
+                    kind
+                };
+                self.document
+                    .to_mut()
+                    .set_kind(selection.start.block..selection.end.block + 1, kind);
+            }
+        }
+
+        shell.publish((self.on_change)(self.document.as_ref().clone()));
+    }
+
+    /// Replaces the selection with the given text in the typing style.
+    fn insert(&mut self, state: &mut State, text: &str, shell: &mut Shell<'_, Message>) {
+        let selection = state.selection();
+        let style = self.typing_style(state, selection.start);
+
+        let document = self.document.to_mut();
+        document.delete(selection.clone());
+        let position = document.insert(selection.start, text, &style);
+
+        state.move_to(position, false);
+        shell.publish((self.on_change)(self.document.as_ref().clone()));
+    }
+
+    /// Deletes the given range.
+    fn delete(
+        &mut self,
+        state: &mut State,
+        range: Range<Position>,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if range.is_empty() {
+            return;
+        }
+
+        self.document.to_mut().delete(range.clone());
+        state.move_to(range.start, false);
+        shell.publish((self.on_change)(self.document.as_ref().clone()));
+    }
+
+    /// Splits the block at the selection, or ends a list at an empty item.
+    fn split(&mut self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        let selection = state.selection();
+        let block = &self.document.blocks[selection.start.block];
+        let ends_list =
+            selection.is_empty() && block.is_empty() && block.kind != BlockKind::Paragraph;
+
+        let document = self.document.to_mut();
+        if ends_list {
+            document.set_kind(
+                selection.start.block..selection.start.block + 1,
+                BlockKind::Paragraph,
+            );
+        } else {
+            document.delete(selection.clone());
+            let position = document.split(selection.start);
+            state.move_to(position, false);
+        }
+
+        shell.publish((self.on_change)(self.document.as_ref().clone()));
+    }
+
+    /// Handles a pressed key while the [`RichEditor`] is focused.
+    #[allow(clippy::too_many_arguments)]
+    fn key_pressed(
+        &mut self,
+        state: &mut State,
+        renderer: &Renderer,
+        lines: &[Line],
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::Modifiers,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let selection = state.selection();
+        let extend = modifiers.shift();
+
+        match key_code {
+            keyboard::KeyCode::B if modifiers.command() => {
+                self.apply(state, Tool::Format(Format::Bold), clipboard, shell);
+            }
+            keyboard::KeyCode::I if modifiers.command() => {
+                self.apply(state, Tool::Format(Format::Italic), clipboard, shell);
+            }
+            keyboard::KeyCode::U if modifiers.command() => {
+                self.apply(state, Tool::Format(Format::Underline), clipboard, shell);
+            }
+            keyboard::KeyCode::A if modifiers.command() => {
+                state.anchor = Position::default();
+                state.focus = self.document.end();
+            }
+            keyboard::KeyCode::C | keyboard::KeyCode::X if modifiers.command() => {
+                if !selection.is_empty() {
+                    clipboard.write(self.document.text(selection.clone()));
+
+                    if key_code == keyboard::KeyCode::X {
+                        self.delete(state, selection, shell);
+                    }
+                }
+            }
+            keyboard::KeyCode::V if modifiers.command() => {
+                let Some(content) = clipboard.read() else {
+                    return event::Status::Captured;
+                };
+
+                // Pasting a URL over selected text links it.
+                if is_url(&content) && !selection.is_empty() {
+                    self.document
+                        .to_mut()
+                        .set_link(selection, Some(content.trim()));
+                    shell.publish((self.on_change)(self.document.as_ref().clone()));
+                } else {
+                    self.insert(state, &content.replace("\r\n", "\n"), shell);
+                }
+            }
+            keyboard::KeyCode::Enter | keyboard::KeyCode::NumpadEnter => self.split(state, shell),
+            keyboard::KeyCode::Backspace => {
+                let block = &self.document.blocks[selection.start.block];
+
+                if !selection.is_empty() {
+                    self.delete(state, selection, shell);
+                } else if selection.start.offset == 0 && block.kind != BlockKind::Paragraph {
+                    // Deleting at the start of a list item ends the list.
+                    self.document.to_mut().set_kind(
+                        selection.start.block..selection.start.block + 1,
+                        BlockKind::Paragraph,
+                    );
+                    shell.publish((self.on_change)(self.document.as_ref().clone()));
+                } else {
+                    let start = self.previous(selection.start);
+                    self.delete(state, start..selection.start, shell);
+                }
+            }
+            keyboard::KeyCode::Delete => {
+                let end = if selection.is_empty() {
+                    self.next(selection.end)
+                } else {
+                    selection.end
+                };
+                self.delete(state, selection.start..end, shell);
+            }
+            keyboard::KeyCode::Left => {
+                let position = if extend || selection.is_empty() {
+                    self.previous(state.focus)
+                } else {
+                    selection.start
+                };
+                state.move_to(position, extend);
+            }
+            keyboard::KeyCode::Right => {
+                let position = if extend || selection.is_empty() {
+                    self.next(state.focus)
+                } else {
+                    selection.end
+                };
+                state.move_to(position, extend);
+            }
+            keyboard::KeyCode::Up | keyboard::KeyCode::Down => {
+                let position = self.vertical(
+                    renderer,
+                    lines,
+                    state.focus,
+                    key_code == keyboard::KeyCode::Down,
+                );
+                state.move_to(position, extend);
+            }
+            keyboard::KeyCode::Home => {
+                let line = &lines[Self::line_of(lines, state.focus)];
+                state.move_to(Position::new(line.block, line.start), extend);
+            }
+            keyboard::KeyCode::End => {
+                let index = Self::line_of(lines, state.focus);
+                let line = &lines[index];
+                let position = self.position_at(
+                    renderer,
+                    lines,
+                    Point::new(f32::INFINITY, line.y + self.line_pixels() / 2.0),
+                );
+                state.move_to(position, extend);
+            }
+            keyboard::KeyCode::Escape => state.move_to(state.focus, false),
+            _ => return event::Status::Ignored,
+        }
+
+        event::Status::Captured
+    }
+
+    /// Returns the [`Caret`] at the focus of the selection.
+    fn caret(&self, renderer: &Renderer, state: &State, lines: &[Line], origin: Point) -> Caret {
+        let line = &lines[Self::line_of(lines, state.focus)];
+        let x = self.x_in_line(renderer, line, state.focus.offset);
+
+        Caret::new(
+            Point::new(origin.x + x, origin.y + line.y),
+            self.line_pixels(),
+        )
+    }
+
+    /// Draws the toolbar.
+    fn draw_toolbar(
+        &self,
+        renderer: &mut Renderer,
+        appearance: &crate::style::rich_editor::Appearance,
+        state: &State,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    height: TOOLBAR_HEIGHT,
+                    ..bounds
+                },
+                border_radius: [appearance.border_radius, appearance.border_radius, 0.0, 0.0]
+                    .into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.toolbar_background,
+        );
+
+        for (tool, label, button) in self.tools(bounds) {
+            let background = if self.is_active(state, tool) {
+                appearance.active_button_background
+            } else if cursor.is_over(button) {
+                appearance.hovered_button_background
+            } else {
+                Color::TRANSPARENT
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: button,
+                    border_radius: (3.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                background,
+            );
+
+            let style = SpanStyle {
+                bold: tool == Tool::Format(Format::Bold),
+                code: tool == Tool::Format(Format::Code),
+                ..SpanStyle::default()
+            };
+
+            renderer.fill_text(core::text::Text {
+                content: label,
+                bounds: Rectangle {
+                    x: button.center_x(),
+                    y: button.center_y(),
+                    ..button
+                },
+                size: self.text_size * 0.9,
+                line_height: LineHeight::default(),
+                color: if tool == Tool::Format(Format::Italic) {
+                    appearance.italic_color
+                } else {
+                    appearance.button_text_color
+                },
+                font: self.span_font(&style),
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Advanced,
+            });
+
+            if tool == Tool::Format(Format::Underline) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: button.center_x() - 4.0,
+                            y: button.center_y() + self.text_size * 0.4,
+                            width: 8.0,
+                            height: 1.0,
+                        },
+                        border_radius: (0.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.button_text_color,
+                );
+            }
+        }
+    }
+
+    /// Draws the given line of text with its origin at the given point.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_line(
+        &self,
+        renderer: &mut Renderer,
+        appearance: &crate::style::rich_editor::Appearance,
+        state: &State,
+        line: &Line,
+        origin: Point,
+        number: usize,
+    ) {
+        let block = &self.document.blocks[line.block];
+        let text = block.text();
+        let line_pixels = self.line_pixels();
+        let y = origin.y + line.y;
+
+        // The marker of a list item is drawn in front of its first line.
+        if line.start == 0 && block.kind != BlockKind::Paragraph {
+            let marker = match block.kind {
+                BlockKind::Numbered => format!("{number}."),
+                _ => String::from("•"),
+            };
+
+            renderer.fill_text(core::text::Text {
+                content: &marker,
+                bounds: Rectangle {
+                    x: origin.x + line.x - 6.0,
+                    y,
+                    width: line.x,
+                    height: line_pixels,
+                },
+                size: self.text_size,
+                line_height: self.line_height,
+                color: appearance.marker_color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Right,
+                vertical_alignment: Vertical::Top,
+                shaping: text::Shaping::Advanced,
+            });
+        }
+
+        let selection = state.selection();
+        if selection.start.block <= line.block && line.block <= selection.end.block {
+            let start = if selection.start.block == line.block {
+                selection.start.offset.max(line.start)
+            } else {
+                line.start
+            };
+            let end = if selection.end.block == line.block {
+                selection.end.offset.min(line.end)
+            } else {
+                line.end
+            };
+            // A selected block break is shown as a space at the end of the block.
+            let block_break = selection.end.block > line.block && line.end == block.len();
+
+            if start < end || (block_break && start <= end) {
+                let x = self.x_in_line(renderer, line, start);
+                let width = self.x_in_line(renderer, line, end) - x;
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: origin.x + x,
+                            y,
+                            width: width
+                                + if block_break {
+                                    self.text_size / 3.0
+                                } else {
+                                    0.0
+                                },
+                            height: line_pixels,
+                        },
+                        border_radius: (0.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.selection_background,
+                );
+            }
+        }
+
+        for fragment in &line.fragments {
+            let style = &block.spans[fragment.span].style;
+            let bounds = Rectangle {
+                x: origin.x + line.x + fragment.x,
+                y,
+                width: fragment.width,
+                height: line_pixels,
+            };
+
+            if style.code {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: (2.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.code_background,
+                );
+            }
+
+            let color = if style.link.is_some() {
+                appearance.link_color
+            } else if style.italic {
+                appearance.italic_color
+            } else {
+                appearance.text_color
+            };
+
+            renderer.fill_text(core::text::Text {
+                content: &text[fragment.range.clone()],
+                bounds: Rectangle {
+                    width: f32::INFINITY,
+                    ..bounds
+                },
+                size: self.text_size,
+                line_height: self.line_height,
+                color,
+                font: self.span_font(style),
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Top,
+                shaping: text::Shaping::Advanced,
+            });
+
+            if style.underline || style.link.is_some() {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            y: y + line_pixels / 2.0 + self.text_size / 2.0,
+                            height: 1.0,
+                            ..bounds
+                        },
+                        border_radius: (0.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    color,
+                );
+            }
+        }
+    }
+}
+
+/// Returns the labels of the buttons of the toolbar, in the order of `TOOLS`.
+///
+/// The formats and the link are translated, the symbols of the code and
+/// list buttons are not.
+fn tool_labels() -> [String; 7] {
+    let translations = i18n::get();
+
+    TOOLS.map(|(tool, _)| match tool {
+        Tool::Format(Format::Bold) => translations.bold(),
+        Tool::Format(Format::Italic) => translations.italic(),
+        Tool::Format(Format::Underline) => translations.underline(),
+        Tool::Format(Format::Code) => String::from("<>"),
+        Tool::Link => translations.link(),
+        Tool::List(BlockKind::Bullet) => String::from("•"),
+        Tool::List(_) => String::from("1."),
+    })
+}
+
+/// A button of the toolbar of a [`RichEditor`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Tool {
+    /// Toggles a [`Format`].
+    Format(Format),
+    /// Links the selection to the URL in the clipboard, or removes its link.
+    Link,
+    /// Toggles a kind of list.
+    List(BlockKind),
+}
+
+/// A laid out line of a block of a [`RichEditor`].
+#[derive(Clone, Debug)]
+struct Line {
+    /// The index of the block.
+    block: usize,
+    /// The byte offset of the start of the line in the block.
+    start: usize,
+    /// The byte offset of the end of the line in the block.
+    end: usize,
+    /// The indentation of the line.
+    x: f32,
+    /// The offset of the top of the line in the text.
+    y: f32,
+    /// The runs of text of the same span.
+    fragments: Vec<Fragment>,
+}
+
+impl Line {
+    /// Creates a new empty [`Line`].
+    fn new(block: usize, start: usize, x: f32, y: f32) -> Self {
+        Self {
+            block,
+            start,
+            end: start,
+            x,
+            y,
+            fragments: Vec::new(),
+        }
+    }
+}
+
+/// A run of the text of a span in a [`Line`].
+#[derive(Clone, Debug)]
+struct Fragment {
+    /// The index of the span in the block.
+    span: usize,
+    /// The byte range of the run in the block.
+    range: Range<usize>,
+    /// The offset of the run in the line.
+    x: f32,
+    /// The width of the run.
+    width: f32,
+}
+
+/// The state of a [`RichEditor`].
+#[derive(Clone, Debug, Default)]
+struct State {
+    /// The position the selection started at.
+    anchor: Position,
+    /// The position of the caret, at the other end of the selection.
+    focus: Position,
+    /// The style of the next typed text, toggled without a selection.
+    pending: Option<SpanStyle>,
+    /// Whether the [`RichEditor`] receives the keyboard input.
+    is_focused: bool,
+    /// Whether a selection is being dragged.
+    is_dragging: bool,
+    /// The scroll offset of the text.
+    scroll: f32,
+    /// The pressed keyboard modifiers.
+    modifiers: keyboard::Modifiers,
+    /// The last click, for detecting double and triple clicks.
+    last_click: Option<click::Click>,
+}
+
+impl State {
+    /// Returns the selected range.
+    fn selection(&self) -> Range<Position> {
+        self.anchor.min(self.focus)..self.anchor.max(self.focus)
+    }
+
+    /// Moves the caret to the given position, extending the selection or
+    /// collapsing it at the position.
+    fn move_to(&mut self, position: Position, extend: bool) {
+        self.focus = position;
+        if !extend {
+            self.anchor = position;
+        }
+        self.pending = None;
+    }
+}
+
+/// Splits the given text after each run of spaces.
+fn words(text: &str) -> impl Iterator<Item = &str> {
+    text.split_inclusive(' ').flat_map(|piece| {
+        // The spaces of a run of spaces stay with the word before them.
+        let trimmed = piece.trim_start_matches(' ');
+        let spaces = piece.len() - trimmed.len();
+        [&piece[..spaces], trimmed]
+            .into_iter()
+            .filter(|piece| !piece.is_empty())
+    })
+}
+
+/// Returns `true` if the given text is a link target.
+fn is_url(text: &str) -> bool {
+    let text = text.trim();
+
+    ["http://", "https://", "mailto:"]
+        .iter()
+        .any(|scheme| text.starts_with(scheme))
+        && !text.contains(char::is_whitespace)
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for RichEditor<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        // The caret is kept inside of a document changed by the application.
+        let state = tree.state.downcast_mut::<State>();
+        let end = self.document.end();
+
+        for position in [&mut state.anchor, &mut state.focus] {
+            if *position > end {
+                *position = end;
+            }
+            let block = &self.document.blocks[position.block];
+            position.offset =
+                text_boundary::floor_char_boundary(&block.text(), position.offset.min(block.len()));
+        }
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        let width = limits.max().width;
+
+        let lines = self.lines(renderer, (width - self.padding * 2.0).max(0.0));
+        let height = self.toolbar_height() + self.content_height(&lines) + self.padding * 2.0;
+
+        Node::new(limits.resolve(Size::new(width, height)))
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let text_bounds = self.text_bounds(bounds);
+        let lines = self.lines(renderer, text_bounds.width);
+        let origin = Vector::new(text_bounds.x, text_bounds.y - state.scroll);
+
+        let status = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    state.is_focused = false;
+                    state.is_dragging = false;
+                    return event::Status::Ignored;
+                };
+                state.is_focused = true;
+
+                if let Some((tool, _, _)) = self
+                    .tools(bounds)
+                    .into_iter()
+                    .find(|(_, _, button)| button.contains(position))
+                {
+                    self.apply(state, tool, clipboard, shell);
+                    return event::Status::Captured;
+                }
+
+                let caret = self.position_at(renderer, &lines, position - origin);
+
+                if state.modifiers.command() {
+                    if let (Some(link), Some(on_link)) =
+                        (self.document.link_at(caret), &self.on_link)
+                    {
+                        shell.publish(on_link(link));
+                        return event::Status::Captured;
+                    }
+                }
+
+                let click = click::Click::new(position, state.last_click);
+                state.last_click = Some(click);
+                let text = self.document.blocks[caret.block].text();
+
+                match click.kind() {
+                    click::Kind::Single => {
+                        state.move_to(caret, state.modifiers.shift());
+                        state.is_dragging = true;
+                    }
+                    click::Kind::Double => {
+                        let word = text_boundary::word_at(&text, caret.offset);
+                        state.move_to(Position::new(caret.block, word.start), false);
+                        state.move_to(Position::new(caret.block, word.end), true);
+                    }
+                    click::Kind::Triple => {
+                        state.move_to(Position::new(caret.block, 0), false);
+                        state.move_to(Position::new(caret.block, text.len()), true);
+                    }
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. })
+                if state.is_dragging =>
+            {
+                if let Some(position) = cursor.position() {
+                    let caret = self.position_at(renderer, &lines, position - origin);
+                    state.move_to(caret, true);
+                }
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if state.is_dragging =>
+            {
+                state.is_dragging = false;
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * SCROLL_STEP,
+                    mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+                let max = (self.content_height(&lines) - text_bounds.height).max(0.0);
+
+                if max <= 0.0 {
+                    return event::Status::Ignored;
+                }
+                state.scroll = (state.scroll - delta).clamp(0.0, max);
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+                return event::Status::Ignored;
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                if state.is_focused && !c.is_control() && !state.modifiers.command() =>
+            {
+                self.insert(state, &c.to_string(), shell);
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if state.is_focused => self.key_pressed(
+                state, renderer, &lines, key_code, modifiers, clipboard, shell,
+            ),
+            _ => return event::Status::Ignored,
+        };
+
+        // The caret is scrolled into view.
+        if state.is_focused {
+            let lines = self.lines(renderer, text_bounds.width);
+            let line = &lines[Self::line_of(&lines, state.focus)];
+            let line_pixels = self.line_pixels();
+
+            if line.y < state.scroll {
+                state.scroll = line.y;
+            } else if line.y + line_pixels > state.scroll + text_bounds.height {
+                state.scroll = line.y + line_pixels - text_bounds.height;
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+
+        if self
+            .tools(bounds)
+            .iter()
+            .any(|(_, _, button)| cursor.is_over(*button))
+        {
+            mouse::Interaction::Pointer
+        } else if cursor.is_over(self.text_bounds(bounds)) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let text_bounds = self.text_bounds(bounds);
+        let lines = self.lines(renderer, text_bounds.width);
+        let origin = Point::new(text_bounds.x, text_bounds.y - state.scroll);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius.into(),
+                border_width: appearance.border_width,
+                border_color: if state.is_focused {
+                    appearance.focused_border_color
+                } else {
+                    appearance.border_color
+                },
+            },
+            appearance.background,
+        );
+
+        if self.toolbar {
+            self.draw_toolbar(renderer, &appearance, state, bounds, cursor);
+        }
+
+        let clip = Rectangle {
+            x: bounds.x,
+            width: bounds.width,
+            ..text_bounds
+        };
+
+        renderer.with_layer(clip, |renderer| {
+            let mut number = 0;
+
+            for line in &lines {
+                if line.start == 0 {
+                    number = match self.document.blocks[line.block].kind {
+                        BlockKind::Numbered => number + 1,
+                        _ => 0,
+                    };
+                }
+
+                let y = origin.y + line.y;
+                if y + self.line_pixels() < clip.y || y > clip.y + clip.height {
+                    continue;
+                }
+
+                self.draw_line(renderer, &appearance, state, line, origin, number);
+            }
+
+            if state.is_focused && state.anchor == state.focus {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: self.caret(renderer, state, &lines, origin).bounds,
+                        border_radius: (0.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.cursor_color,
+                );
+            }
+        });
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        // The caret is reported for the IME.
+        if state.is_focused {
+            let text_bounds = self.text_bounds(layout.bounds());
+            let lines = self.lines(renderer, text_bounds.width);
+            let origin = Point::new(text_bounds.x, text_bounds.y - state.scroll);
+
+            self.caret(renderer, state, &lines, origin)
+                .report(operation);
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<RichEditor<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(editor: RichEditor<'a, Message, Renderer>) -> Self {
+        Element::new(editor)
+    }
+}
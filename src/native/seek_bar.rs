@@ -0,0 +1,484 @@
+//! Use a seek bar to show and change the playback position of some media.
+//!
+//! *This API requires the following crate features to be activated: `seek_bar`*
+use super::overlay::seek_bar::SeekBarOverlay;
+use crate::style::seek_bar::StyleSheet;
+
+use iced_widget::core::{
+    self, event,
+    layout::{Limits, Node},
+    mouse::{self, Cursor},
+    overlay, renderer, touch,
+    widget::{
+        tree::{State as TreeState, Tag},
+        Tree,
+    },
+    Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+/// A bar showing the playback position of some media, which can be dragged to
+/// seek to another position.
+///
+/// The [`SeekBar`] shows the buffered ranges and the chapters of the media and
+/// previews the time under the cursor. Dragging vertically away from the bar
+/// slows the scrubbing down for fine adjustments.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::SeekBar;
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Seek(f64),
+/// }
+///
+/// let seek_bar = SeekBar::new(120.0, 42.0, Message::Seek)
+///     .buffered([(0.0, 60.0)])
+///     .chapter(30.0, "Verse");
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct SeekBar<'a, Message, Renderer = crate::Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The duration of the media in seconds.
+    duration: f64,
+    /// The playback position in seconds.
+    position: f64,
+    /// The buffered ranges in seconds.
+    buffered: Vec<(f64, f64)>,
+    /// The start times in seconds and the titles of the chapters.
+    chapters: Vec<(f64, String)>,
+    /// The function producing the message when seeking to a position.
+    on_seek: Box<dyn Fn(f64) -> Message + 'a>,
+    /// The message produced when the handle is released.
+    on_release: Option<Message>,
+    /// The width of the [`SeekBar`].
+    width: Length,
+    /// The height of the [`SeekBar`].
+    height: f32,
+    /// The height of the rail.
+    rail_height: f32,
+    /// The vertical distance from the bar at which scrubbing slows down to half the speed.
+    fine_scrub_distance: f32,
+    /// The text size of the time preview.
+    text_size: f32,
+    /// The font of the time preview.
+    font: Renderer::Font,
+    /// The style of the [`SeekBar`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> SeekBar<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`SeekBar`] for media of the given `duration` at the
+    /// playback `position`, both in seconds, producing the message of
+    /// `on_seek` when seeking to another position.
+    pub fn new<F>(duration: f64, position: f64, on_seek: F) -> Self
+    where
+        F: 'a + Fn(f64) -> Message,
+    {
+        Self {
+            duration,
+            position,
+            buffered: Vec::new(),
+            chapters: Vec::new(),
+            on_seek: Box::new(on_seek),
+            on_release: None,
+            width: Length::Fill,
+            height: 20.0,
+            rail_height: 4.0,
+            fine_scrub_distance: 50.0,
+            text_size: 12.0,
+            font: core::Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the buffered ranges of the [`SeekBar`] from their start to their end in seconds.
+    #[must_use]
+    pub fn buffered(mut self, ranges: impl IntoIterator<Item = (f64, f64)>) -> Self {
+        self.buffered = ranges.into_iter().collect();
+        self
+    }
+
+    /// Adds a chapter starting at the given time in seconds to the [`SeekBar`].
+    #[must_use]
+    pub fn chapter(mut self, start: f64, title: impl Into<String>) -> Self {
+        self.chapters.push((start, title.into()));
+        self.chapters.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        self
+    }
+
+    /// Sets the message produced when the handle of the [`SeekBar`] is released.
+    #[must_use]
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets the width of the [`SeekBar`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`SeekBar`].
+    #[must_use]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the height of the rail of the [`SeekBar`].
+    #[must_use]
+    pub fn rail_height(mut self, rail_height: f32) -> Self {
+        self.rail_height = rail_height;
+        self
+    }
+
+    /// Sets the vertical distance from the [`SeekBar`] at which scrubbing
+    /// slows down to half the speed.
+    #[must_use]
+    pub fn fine_scrub_distance(mut self, distance: f32) -> Self {
+        self.fine_scrub_distance = distance;
+        self
+    }
+
+    /// Sets the text size of the time preview of the [`SeekBar`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the time preview of the [`SeekBar`].
+    #[must_use]
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`SeekBar`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the time at the given horizontal position.
+    fn time_at(&self, bounds: Rectangle, x: f32) -> f64 {
+        let ratio = f64::from(((x - bounds.x) / bounds.width).clamp(0.0, 1.0));
+        ratio * self.duration.max(0.0)
+    }
+
+    /// Returns the horizontal position of the given time.
+    fn x_of(&self, bounds: Rectangle, time: f64) -> f32 {
+        if self.duration <= 0.0 {
+            return bounds.x;
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        let ratio = (time / self.duration).clamp(0.0, 1.0) as f32;
+        bounds.x + ratio * bounds.width
+    }
+
+    /// Returns the label of the time preview for the given time.
+    fn label(&self, time: f64) -> String {
+        let chapter = self
+            .chapters
+            .iter()
+            .rev()
+            .find(|(start, _)| *start <= time)
+            .map(|(_, title)| title);
+
+        chapter.map_or_else(
+            || format_time(time),
+            |title| format!("{title} ({})", format_time(time)),
+        )
+    }
+}
+
+/// The state of a [`SeekBar`].
+#[derive(Clone, Copy, Debug, Default)]
+struct State {
+    /// The position of the cursor hovering the [`SeekBar`].
+    hovered: Option<Point>,
+    /// The ongoing drag of the handle.
+    drag: Option<Drag>,
+}
+
+/// The drag of the handle of a [`SeekBar`].
+#[derive(Clone, Copy, Debug)]
+struct Drag {
+    /// The time the handle was dragged to.
+    time: f64,
+    /// The previous horizontal position of the cursor.
+    last_x: f32,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for SeekBar<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Fixed(self.height)
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(Length::Fixed(self.height));
+
+        Node::new(limits.resolve(Size::new(limits.max().width, self.height)))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    let time = self.time_at(bounds, position.x);
+                    state.drag = Some(Drag {
+                        time,
+                        last_x: position.x,
+                    });
+                    shell.publish((self.on_seek)(time));
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                state.hovered = bounds.contains(position).then_some(position);
+
+                if let Some(drag) = &mut state.drag {
+                    // Slow the scrubbing down the further the cursor is away from the bar.
+                    let distance =
+                        ((position.y - bounds.center_y()).abs() - bounds.height / 2.0).max(0.0);
+                    let speed = 1.0 / (1.0 + distance / self.fine_scrub_distance.max(1.0));
+
+                    let seconds_per_pixel = self.duration.max(0.0) / f64::from(bounds.width);
+                    drag.time = (drag.time
+                        + f64::from((position.x - drag.last_x) * speed) * seconds_per_pixel)
+                        .clamp(0.0, self.duration.max(0.0));
+                    drag.last_x = position.x;
+
+                    shell.publish((self.on_seek)(drag.time));
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if state.drag.is_some() =>
+            {
+                state.drag = None;
+                if let Some(on_release) = &self.on_release {
+                    shell.publish(on_release.clone());
+                }
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorLeft) => state.hovered = None,
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if tree.state.downcast_ref::<State>().drag.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+
+        let rail = Rectangle {
+            x: bounds.x,
+            y: bounds.center_y() - self.rail_height / 2.0,
+            width: bounds.width,
+            height: self.rail_height,
+        };
+
+        let bar = |renderer: &mut Renderer, start: f32, end: f32, color: Color| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: start,
+                        width: (end - start).max(0.0),
+                        ..rail
+                    },
+                    border_radius: (self.rail_height / 2.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                color,
+            );
+        };
+
+        bar(renderer, rail.x, rail.x + rail.width, appearance.rail_color);
+
+        for (start, end) in &self.buffered {
+            bar(
+                renderer,
+                self.x_of(bounds, *start),
+                self.x_of(bounds, *end),
+                appearance.buffered_color,
+            );
+        }
+
+        let position = state.drag.map_or(self.position, |drag| drag.time);
+        let handle_x = self.x_of(bounds, position);
+        bar(renderer, rail.x, handle_x, appearance.progress_color);
+
+        // The chapter markers split the rail with small gaps.
+        for (start, _) in &self.chapters {
+            let x = self.x_of(bounds, *start);
+            if x <= rail.x || x >= rail.x + rail.width {
+                continue;
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: x - 1.0,
+                        width: 2.0,
+                        ..rail
+                    },
+                    border_radius: (0.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.chapter_marker_color,
+            );
+        }
+
+        // Handle
+        let radius = if state.hovered.is_some() || state.drag.is_some() {
+            self.rail_height * 2.0
+        } else {
+            self.rail_height * 1.5
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: handle_x - radius,
+                    y: bounds.center_y() - radius,
+                    width: radius * 2.0,
+                    height: radius * 2.0,
+                },
+                border_radius: radius.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.handle_color,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let time = match (state.drag, state.hovered) {
+            (Some(drag), _) => drag.time,
+            (None, Some(hovered)) => self.time_at(bounds, hovered.x),
+            (None, None) => return None,
+        };
+
+        Some(
+            SeekBarOverlay::new(
+                self.label(time),
+                Point::new(self.x_of(bounds, time), bounds.y),
+                self.text_size,
+                self.font,
+                self.style.clone(),
+            )
+            .overlay(),
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<SeekBar<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(seek_bar: SeekBar<'a, Message, Renderer>) -> Self {
+        Element::new(seek_bar)
+    }
+}
+
+/// Formats the given time in seconds as `m:ss`, or `h:mm:ss` from an hour on.
+#[must_use]
+pub fn format_time(seconds: f64) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let total = seconds.max(0.0).floor() as u64;
+    let (hours, minutes, seconds) = (total / 3600, total % 3600 / 60, total % 60);
+
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes}:{seconds:02}")
+    }
+}
@@ -0,0 +1,407 @@
+//! Use a segment display to show numbers like a retro instrument panel.
+//!
+//! *This API requires the following crate features to be activated: `segment_display`*
+use crate::core::segment_display::{cells, segment_polygon, slant, Cell};
+
+use iced_widget::{
+    canvas::{self, Path},
+    core::{
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer,
+        widget::Tree,
+        Color, Element, Layout, Length, Point, Rectangle, Renderer as _, Size, Vector, Widget,
+    },
+    graphics::geometry::Renderer as _,
+    renderer::Renderer,
+};
+
+pub use crate::{
+    core::segment_display::Segments,
+    style::segment_display::{Appearance, StyleSheet},
+};
+
+/// The thickness of the segments relative to the height of the characters.
+const THICKNESS: f32 = 0.12;
+/// The spacing between the characters relative to their height.
+const SPACING: f32 = 0.12;
+
+/// A display showing numbers and a limited set of characters in seven or
+/// fourteen segments, like a retro instrument panel or a timer.
+///
+/// A `.` lights the decimal point of the character before it and a `:` is
+/// shown as a colon. The unlit segments are drawn faintly, like on a real
+/// display.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::SegmentDisplay;
+/// #
+/// let display = SegmentDisplay::new(format!("{:.1}", 21.5))
+///     .digits(5)
+///     .digit_height(48.0);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct SegmentDisplay<Theme = iced_widget::style::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The shown text.
+    text: String,
+    /// The number of segments of each character.
+    segments: Segments,
+    /// The least number of characters, which the text is aligned right in.
+    digits: usize,
+    /// The height of the characters.
+    digit_height: f32,
+    /// The slant of the characters to the right relative to their height.
+    slant: f32,
+    /// Whether the characters have decimal points.
+    decimal_points: bool,
+    /// The padding around the characters.
+    padding: f32,
+    /// The width of the [`SegmentDisplay`].
+    width: Length,
+    /// The height of the [`SegmentDisplay`].
+    height: Length,
+    /// The style of the [`SegmentDisplay`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<Theme> SegmentDisplay<Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`SegmentDisplay`] showing the given text.
+    #[must_use]
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            segments: Segments::default(),
+            digits: 0,
+            digit_height: 32.0,
+            slant: 0.1,
+            decimal_points: true,
+            padding: 8.0,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the number of [`Segments`] of each character of the
+    /// [`SegmentDisplay`].
+    #[must_use]
+    pub fn segments(mut self, segments: Segments) -> Self {
+        self.segments = segments;
+        self
+    }
+
+    /// Sets the least number of characters of the [`SegmentDisplay`], which
+    /// a shorter text is aligned right in, like on a counter.
+    #[must_use]
+    pub fn digits(mut self, digits: usize) -> Self {
+        self.digits = digits;
+        self
+    }
+
+    /// Sets the height of the characters of the [`SegmentDisplay`].
+    #[must_use]
+    pub fn digit_height(mut self, digit_height: f32) -> Self {
+        self.digit_height = digit_height.max(1.0);
+        self
+    }
+
+    /// Sets the slant of the characters of the [`SegmentDisplay`] to the
+    /// right, as the offset of their top relative to their height, e.g. 0.0
+    /// for upright characters.
+    #[must_use]
+    pub fn slant(mut self, slant: f32) -> Self {
+        self.slant = slant.clamp(-0.5, 0.5);
+        self
+    }
+
+    /// Sets whether the characters of the [`SegmentDisplay`] have decimal
+    /// points, which are lit by a `.` after them.
+    ///
+    /// Without decimal points, the characters are closer together and a `.`
+    /// takes a blank character.
+    #[must_use]
+    pub fn decimal_points(mut self, decimal_points: bool) -> Self {
+        self.decimal_points = decimal_points;
+        self
+    }
+
+    /// Sets the padding around the characters of the [`SegmentDisplay`].
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the width of the [`SegmentDisplay`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`SegmentDisplay`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`SegmentDisplay`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the shown cells, with blank characters before the text to
+    /// fill the least number of characters.
+    fn cells(&self) -> Vec<Cell> {
+        let mut cells = cells(&self.text, self.segments);
+        if !self.decimal_points {
+            // The points take the blank characters without decimal points.
+            for cell in &mut cells {
+                if let Cell::Glyph { point, segments } = cell {
+                    if *point {
+                        *point = false;
+                        *segments = 0;
+                    }
+                }
+            }
+        }
+
+        let glyphs = cells
+            .iter()
+            .filter(|cell| matches!(cell, Cell::Glyph { .. }))
+            .count();
+        let blank = Cell::Glyph {
+            segments: 0,
+            point: false,
+        };
+
+        std::iter::repeat_n(blank, self.digits.saturating_sub(glyphs))
+            .chain(cells)
+            .collect()
+    }
+
+    /// Returns the width of the characters and the spacing after them.
+    fn metrics(&self) -> (f32, f32) {
+        let width = match self.segments {
+            Segments::Seven => 0.55,
+            Segments::Fourteen => 0.62,
+        } * self.digit_height;
+        let spacing = if self.decimal_points {
+            SPACING + THICKNESS * 1.5
+        } else {
+            SPACING
+        } * self.digit_height;
+
+        (width, spacing)
+    }
+
+    /// Returns the width of the given cell including its spacing.
+    fn cell_width(&self, cell: Cell) -> f32 {
+        let (width, spacing) = self.metrics();
+
+        match cell {
+            Cell::Glyph { .. } => width + spacing,
+            Cell::Colon => THICKNESS * 3.0 * self.digit_height,
+        }
+    }
+}
+
+impl<Message, Theme> Widget<Message, Renderer<Theme>> for SegmentDisplay<Theme>
+where
+    Theme: StyleSheet,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer<Theme>, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        // The slant sticks out of the top right corner.
+        let width = self
+            .cells()
+            .iter()
+            .map(|cell| self.cell_width(*cell))
+            .sum::<f32>()
+            + self.slant.abs() * self.digit_height
+            + self.padding * 2.0;
+
+        Node::new(limits.resolve(Size::new(width, self.digit_height + self.padding * 2.0)))
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer<Theme>,
+    ) -> mouse::Interaction {
+        mouse::Interaction::default()
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        renderer: &mut Renderer<Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        let height = self.digit_height;
+        let thickness = THICKNESS * height;
+        let (width, _) = self.metrics();
+        let size = Size::new(width, height);
+        let show_unlit = appearance.unlit_color.a > 0.0;
+        let color = |lit: bool| {
+            if lit {
+                appearance.segment_color
+            } else {
+                appearance.unlit_color
+            }
+        };
+
+        // The characters are centered, and slanted within their height.
+        let content_width = self
+            .cells()
+            .iter()
+            .map(|cell| self.cell_width(*cell))
+            .sum::<f32>()
+            + self.slant.abs() * height;
+        let mut x = (bounds.width - content_width) / 2.0 + self.slant.min(0.0).abs() * height;
+        let y = (bounds.height - height) / 2.0;
+
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+        let polygon = |points: &[Point], origin: Vector| {
+            Path::new(|builder| {
+                for (i, point) in points.iter().enumerate() {
+                    let point = slant(*point, height, self.slant) + origin;
+                    if i == 0 {
+                        builder.move_to(point);
+                    } else {
+                        builder.line_to(point);
+                    }
+                }
+                builder.close();
+            })
+        };
+        let dot = |center: Point| {
+            let half = thickness / 2.0;
+            [
+                Point::new(center.x - half, center.y - half),
+                Point::new(center.x + half, center.y - half),
+                Point::new(center.x + half, center.y + half),
+                Point::new(center.x - half, center.y + half),
+            ]
+        };
+
+        for cell in self.cells() {
+            let origin = Vector::new(x, y);
+
+            match cell {
+                Cell::Glyph { segments, point } => {
+                    for segment in self.segments.all() {
+                        let lit = segments & segment == *segment;
+                        if lit || show_unlit {
+                            frame.fill(
+                                &polygon(&segment_polygon(*segment, size, thickness), origin),
+                                color(lit),
+                            );
+                        }
+                    }
+
+                    // The decimal point sits in the spacing after the
+                    // character.
+                    if self.decimal_points && (point || show_unlit) {
+                        let center = Point::new(width + thickness * 1.25, height - thickness / 2.0);
+                        frame.fill(&polygon(&dot(center), origin), color(point));
+                    }
+                }
+                Cell::Colon => {
+                    let center_x = thickness * 1.5;
+                    for center_y in [height * 0.3, height * 0.7] {
+                        frame.fill(
+                            &polygon(&dot(Point::new(center_x, center_y)), origin),
+                            appearance.segment_color,
+                        );
+                    }
+                }
+            }
+
+            x += self.cell_width(cell);
+        }
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+                renderer.draw(vec![frame.into_geometry()]);
+            });
+        });
+    }
+}
+
+impl<'a, Message, Theme> From<SegmentDisplay<Theme>> for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+{
+    fn from(segment_display: SegmentDisplay<Theme>) -> Self {
+        Element::new(segment_display)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SegmentDisplay;
+    use crate::test_utils::Harness;
+    use iced_widget::core::Size;
+
+    #[test]
+    fn segment_display_test() {
+        let display = SegmentDisplay::new("8.1").slant(0.0);
+        let mut harness = Harness::<()>::new(display, Size::new(100.0, 48.0));
+        let snapshot = harness.snapshot();
+        let color = |x, y| snapshot.color(x, y).expect("Pixel is in bounds");
+
+        // The top segment of the 8 is lit, but the one of the 1 is not.
+        let lit = color(17, 9);
+        assert!(lit.r > 0.9 && lit.g < 0.4);
+        let unlit = color(44, 9);
+        assert!(unlit.r < 0.3 && unlit.r > unlit.g);
+
+        // The point after the 8 is lit.
+        let point = color(30, 38);
+        assert!(point.r > 0.9 && point.g < 0.4);
+
+        // The background surrounds the characters.
+        let background = color(2, 24);
+        assert!(background.r < 0.1);
+    }
+}
@@ -0,0 +1,564 @@
+//! Use a selectable text to display read-only text that can be copied.
+//!
+//! *This API requires the following crate features to be activated: `selectable_text`*
+use crate::core::text_boundary;
+use crate::style::selectable_text::StyleSheet;
+
+use std::{borrow::Cow, ops::Range};
+
+use iced_widget::{
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event, keyboard,
+        layout::{Limits, Node},
+        mouse::{self, click, Cursor},
+        renderer, text, touch,
+        widget::{
+            tree::{State as TreeState, Tag},
+            Tree,
+        },
+        Clipboard, Color, Element, Event, Layout, Length, Pixels, Point, Rectangle, Shell, Size,
+        Widget,
+    },
+    text::LineHeight,
+};
+
+/// A read-only text whose content can be selected and copied.
+///
+/// Text is selected by dragging, a double click selects a word and a triple
+/// click a line. Once clicked, the [`SelectableText`] takes the keyboard: the
+/// arrow keys, `Home` and `End` move the selection, extended with `Shift`,
+/// `Ctrl+A` selects all of the text and `Ctrl+C` copies the selection to the
+/// clipboard.
+///
+/// Lines are broken at line breaks only, so long lines are clipped to the
+/// bounds of the [`SelectableText`].
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::SelectableText;
+/// #
+/// let log = SelectableText::new("Compiling iced_aw\nFinished dev profile");
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct SelectableText<'a, Renderer = crate::Renderer>
+where
+    Renderer: core::text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The content of the [`SelectableText`].
+    content: Cow<'a, str>,
+    /// The text size of the [`SelectableText`].
+    size: Option<f32>,
+    /// The line height of the [`SelectableText`].
+    line_height: LineHeight,
+    /// The font of the [`SelectableText`].
+    font: Option<Renderer::Font>,
+    /// The shaping strategy of the [`SelectableText`].
+    shaping: text::Shaping,
+    /// The width of the [`SelectableText`].
+    width: Length,
+    /// The height of the [`SelectableText`].
+    height: Length,
+    /// The style of the [`SelectableText`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Renderer> SelectableText<'a, Renderer>
+where
+    Renderer: core::text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`SelectableText`] with the given content.
+    pub fn new(content: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            content: content.into(),
+            size: None,
+            line_height: LineHeight::default(),
+            font: None,
+            shaping: text::Shaping::Basic,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the text size of the [`SelectableText`].
+    #[must_use]
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into().0);
+        self
+    }
+
+    /// Sets the line height of the [`SelectableText`].
+    #[must_use]
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the font of the [`SelectableText`].
+    #[must_use]
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the shaping strategy of the [`SelectableText`].
+    #[must_use]
+    pub fn shaping(mut self, shaping: text::Shaping) -> Self {
+        self.shaping = shaping;
+        self
+    }
+
+    /// Sets the width of the [`SelectableText`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`SelectableText`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`SelectableText`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the text size and the font of the [`SelectableText`].
+    fn text_settings(&self, renderer: &Renderer) -> (f32, Renderer::Font) {
+        (
+            self.size.unwrap_or_else(|| renderer.default_size()),
+            self.font.unwrap_or_else(|| renderer.default_font()),
+        )
+    }
+
+    /// Returns the height of a line in pixels.
+    fn line_pixels(&self, renderer: &Renderer) -> f32 {
+        let (size, _) = self.text_settings(renderer);
+        self.line_height.to_absolute(Pixels(size)).0
+    }
+
+    /// Returns the byte index of the content at the given position.
+    fn index_at(&self, renderer: &Renderer, bounds: Rectangle, position: Point) -> usize {
+        let (size, font) = self.text_settings(renderer);
+        let line_pixels = self.line_pixels(renderer);
+        let lines = text_boundary::lines(&self.content);
+
+        // The line is clamped to the existing lines.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let line = ((position.y - bounds.y) / line_pixels).floor().max(0.0) as usize;
+        let line = lines[line.min(lines.len() - 1)].clone();
+
+        let x = position.x - bounds.x;
+        if x <= 0.0 {
+            return line.start;
+        }
+
+        let content = &self.content[line.clone()];
+        let offset = renderer
+            .hit_test(
+                content,
+                size,
+                self.line_height,
+                font,
+                Size::INFINITY,
+                self.shaping,
+                Point::new(x, line_pixels / 2.0),
+                true,
+            )
+            .map_or(content.len(), text::Hit::cursor);
+
+        line.start + text_boundary::floor_char_boundary(content, offset)
+    }
+
+    /// Returns the x offset of the given byte index in its line.
+    fn offset_of(&self, renderer: &Renderer, index: usize) -> f32 {
+        let (size, font) = self.text_settings(renderer);
+        let line = text_boundary::line_at(&self.content, index);
+
+        renderer.measure_width(&self.content[line.start..index], size, font, self.shaping)
+    }
+
+    /// Returns the byte index of the line above or below the given index,
+    /// at the same horizontal position.
+    fn vertical_index(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        index: usize,
+        down: bool,
+    ) -> usize {
+        let lines = text_boundary::lines(&self.content);
+        let line = lines
+            .iter()
+            .position(|line| line.contains(&index) || line.end == index)
+            .unwrap_or(0);
+
+        let target = if down {
+            line + 1
+        } else {
+            match line.checked_sub(1) {
+                Some(target) => target,
+                None => return 0,
+            }
+        };
+        if target >= lines.len() {
+            return self.content.len();
+        }
+
+        let line_pixels = self.line_pixels(renderer);
+        // The line index is small enough to be represented exactly.
+        #[allow(clippy::cast_precision_loss)]
+        let y = (target as f32 + 0.5) * line_pixels;
+
+        self.index_at(
+            renderer,
+            bounds,
+            Point::new(bounds.x + self.offset_of(renderer, index), bounds.y + y),
+        )
+    }
+}
+
+/// The state of a [`SelectableText`].
+#[derive(Clone, Debug, Default)]
+struct State {
+    /// The byte index the selection started at.
+    anchor: usize,
+    /// The byte index at the other end of the selection.
+    focus: usize,
+    /// Whether the [`SelectableText`] receives the keyboard input.
+    is_focused: bool,
+    /// Whether a selection is being dragged.
+    is_dragging: bool,
+    /// The pressed keyboard modifiers.
+    modifiers: keyboard::Modifiers,
+    /// The last click, for detecting double and triple clicks.
+    last_click: Option<click::Click>,
+}
+
+impl State {
+    /// Returns the selected byte range.
+    fn selection(&self) -> Range<usize> {
+        self.anchor.min(self.focus)..self.anchor.max(self.focus)
+    }
+
+    /// Moves the focus to the given byte index, extending the selection or
+    /// collapsing it at the index.
+    fn move_to(&mut self, index: usize, extend: bool) {
+        self.focus = index;
+        if !extend {
+            self.anchor = index;
+        }
+    }
+
+    /// Selects the given byte range.
+    fn select(&mut self, range: Range<usize>) {
+        self.anchor = range.start;
+        self.focus = range.end;
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for SelectableText<'a, Renderer>
+where
+    Renderer: core::text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        // A changed content may no longer contain the selection.
+        let state = tree.state.downcast_mut::<State>();
+        let len = self.content.len();
+
+        if state.anchor > len || state.focus > len {
+            *state = State::default();
+        } else {
+            state.anchor = text_boundary::floor_char_boundary(&self.content, state.anchor);
+            state.focus = text_boundary::floor_char_boundary(&self.content, state.focus);
+        }
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        let (size, font) = self.text_settings(renderer);
+
+        let content = renderer.measure(
+            &self.content,
+            size,
+            self.line_height,
+            font,
+            Size::INFINITY,
+            self.shaping,
+        );
+
+        Node::new(limits.resolve(content))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    if state.is_focused {
+                        state.is_focused = false;
+                        state.move_to(state.focus, false);
+                    }
+                    return event::Status::Ignored;
+                };
+                state.is_focused = true;
+
+                let index = self.index_at(renderer, bounds, position);
+                let click = click::Click::new(position, state.last_click);
+                state.last_click = Some(click);
+
+                match click.kind() {
+                    click::Kind::Single => {
+                        state.move_to(index, state.modifiers.shift());
+                        state.is_dragging = true;
+                    }
+                    click::Kind::Double => {
+                        state.select(text_boundary::word_at(&self.content, index));
+                    }
+                    click::Kind::Triple => {
+                        state.select(text_boundary::line_at(&self.content, index));
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. })
+                if state.is_dragging =>
+            {
+                if let Some(position) = cursor.position() {
+                    state.focus = self.index_at(renderer, bounds, position);
+                }
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if state.is_dragging =>
+            {
+                state.is_dragging = false;
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if state.is_focused => {
+                let extend = modifiers.shift();
+                let content = &self.content;
+
+                match key_code {
+                    keyboard::KeyCode::C if modifiers.command() => {
+                        let selection = state.selection();
+                        if !selection.is_empty() {
+                            clipboard.write(content[selection].to_owned());
+                        }
+                    }
+                    keyboard::KeyCode::A if modifiers.command() => {
+                        state.select(0..content.len());
+                    }
+                    keyboard::KeyCode::Left => {
+                        let selection = state.selection();
+                        if extend || selection.is_empty() {
+                            state.move_to(
+                                text_boundary::previous_char(content, state.focus),
+                                extend,
+                            );
+                        } else {
+                            state.move_to(selection.start, false);
+                        }
+                    }
+                    keyboard::KeyCode::Right => {
+                        let selection = state.selection();
+                        if extend || selection.is_empty() {
+                            state.move_to(text_boundary::next_char(content, state.focus), extend);
+                        } else {
+                            state.move_to(selection.end, false);
+                        }
+                    }
+                    keyboard::KeyCode::Up | keyboard::KeyCode::Down => {
+                        let index = self.vertical_index(
+                            renderer,
+                            bounds,
+                            state.focus,
+                            key_code == keyboard::KeyCode::Down,
+                        );
+                        state.move_to(index, extend);
+                    }
+                    keyboard::KeyCode::Home => {
+                        let line = text_boundary::line_at(content, state.focus);
+                        state.move_to(line.start, extend);
+                    }
+                    keyboard::KeyCode::End => {
+                        let line = text_boundary::line_at(content, state.focus);
+                        state.move_to(line.end, extend);
+                    }
+                    keyboard::KeyCode::Escape => state.move_to(state.focus, false),
+                    _ => return event::Status::Ignored,
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let (size, font) = self.text_settings(renderer);
+        let line_pixels = self.line_pixels(renderer);
+        let selection = state.selection();
+
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        renderer.with_layer(clip, |renderer| {
+            for (index, line) in text_boundary::lines(&self.content).into_iter().enumerate() {
+                // The line index is small enough to be represented exactly.
+                #[allow(clippy::cast_precision_loss)]
+                let y = bounds.y + index as f32 * line_pixels;
+
+                // Only the visible lines are drawn, which keeps long logs fast.
+                if y + line_pixels < clip.y {
+                    continue;
+                }
+                if y > clip.y + clip.height {
+                    break;
+                }
+
+                let content = &self.content[line.clone()];
+                let start = selection.start.clamp(line.start, line.end);
+                let end = selection.end.clamp(line.start, line.end);
+
+                // A selected line break is shown as a space at the end of the line.
+                let line_break = selection.contains(&line.end) && line.end < self.content.len();
+                if start < end || line_break {
+                    let x = renderer.measure_width(
+                        &content[..start - line.start],
+                        size,
+                        font,
+                        self.shaping,
+                    );
+                    let width = renderer.measure_width(
+                        &content[..end - line.start],
+                        size,
+                        font,
+                        self.shaping,
+                    ) - x;
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: bounds.x + x,
+                                y,
+                                width: width + if line_break { size / 3.0 } else { 0.0 },
+                                height: line_pixels,
+                            },
+                            border_radius: (0.0).into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        appearance.selection_background,
+                    );
+                }
+
+                renderer.fill_text(core::text::Text {
+                    content,
+                    bounds: Rectangle {
+                        x: bounds.x,
+                        y,
+                        width: f32::INFINITY,
+                        height: line_pixels,
+                    },
+                    size,
+                    line_height: self.line_height,
+                    color: appearance.text_color.unwrap_or(style.text_color),
+                    font,
+                    horizontal_alignment: Horizontal::Left,
+                    vertical_alignment: Vertical::Top,
+                    shaping: self.shaping,
+                });
+            }
+        });
+    }
+}
+
+impl<'a, Message, Renderer> From<SelectableText<'a, Renderer>> for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + core::text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(text: SelectableText<'a, Renderer>) -> Self {
+        Element::new(text)
+    }
+}
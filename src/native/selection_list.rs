@@ -5,11 +5,14 @@ use crate::style::selection_list::StyleSheet;
 use iced_widget::{
     container,
     core::{
-        self, event,
+        self, event, keyboard,
         layout::{Limits, Node},
         mouse::{self, Cursor},
-        renderer,
-        widget::Tree,
+        renderer, touch,
+        widget::{
+            tree::{State as TreeState, Tag},
+            Id, Operation, Tree,
+        },
         Clipboard, Element, Event, Layout, Length, Rectangle, Shell, Size, Widget,
     },
     runtime::Font,
@@ -17,9 +20,13 @@ use iced_widget::{
     text::LineHeight,
     Container, Scrollable,
 };
-use std::{fmt::Display, hash::Hash, marker::PhantomData};
+use std::{any::Any, fmt::Display, hash::Hash, marker::PhantomData};
 
 pub use list::List;
+use list::ListState;
+
+/// The function formatting the copied option of a [`SelectionList`].
+type CopyFn<'a, T> = Box<dyn Fn(usize, &T) -> String + 'a>;
 
 /// A widget for selecting a single value from a dynamic scrollable list of options.
 #[allow(missing_debug_implementations)]
@@ -47,6 +54,8 @@ where
     text_size: f32,
     /// Style for Looks
     style: <Renderer::Theme as StyleSheet>::Style,
+    /// The function formatting the copied option.
+    copy_format: Option<CopyFn<'a, T>>,
 }
 
 #[allow(clippy::type_repetition_in_bounds)]
@@ -84,6 +93,7 @@ where
             height: Length::Fill,
             padding: 5.0,
             text_size: 12.0,
+            copy_format: None,
         }
     }
 
@@ -120,6 +130,7 @@ where
             height: Length::Fill,
             padding,
             text_size,
+            copy_format: None,
         }
     }
 
@@ -143,6 +154,51 @@ where
         self.style = style;
         self
     }
+
+    /// Sets the function formatting the selected option copied with
+    /// `Ctrl+C`, given its index.
+    ///
+    /// By default, the option is copied as its displayed text.
+    #[must_use]
+    pub fn copy_with<F>(mut self, format: F) -> Self
+    where
+        F: 'a + Fn(usize, &T) -> String,
+    {
+        self.copy_format = Some(Box::new(format));
+        self
+    }
+}
+
+/// The state of a [`SelectionList`].
+#[derive(Clone, Copy, Debug, Default)]
+struct State {
+    /// Whether the [`SelectionList`] receives the keyboard input.
+    is_focused: bool,
+}
+
+/// The [`Operation`] finding the index of the selected option in the
+/// [`ListState`] of the [`List`] of a [`SelectionList`].
+#[derive(Clone, Copy, Debug, Default)]
+struct SelectedIndex {
+    /// The index of the selected option, if any.
+    index: Option<usize>,
+}
+
+impl<T> Operation<T> for SelectedIndex {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, _id: Option<&Id>) {
+        if let Some(list) = state.downcast_ref::<ListState>() {
+            self.index = list.selected_index();
+        }
+    }
 }
 
 impl<'a, T, Message, Renderer> Widget<Message, Renderer> for SelectionList<'a, T, Message, Renderer>
@@ -152,6 +208,14 @@ where
     Renderer: core::Renderer + core::text::Renderer<Font = core::Font> + 'a,
     Renderer::Theme: StyleSheet + container::StyleSheet,
 {
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
     fn children(&self) -> Vec<Tree> {
         vec![Tree::new(&self.container as &dyn Widget<_, _>)]
     }
@@ -203,6 +267,24 @@ where
         Node::with_children(size, vec![content])
     }
 
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.container.operate(
+            &mut state.children[0],
+            layout
+                .children()
+                .next()
+                .expect("Scrollable Child Missing in Selection List"),
+            renderer,
+            operation,
+        );
+    }
+
     fn on_event(
         &mut self,
         state: &mut Tree,
@@ -214,6 +296,33 @@ where
         shell: &mut Shell<Message>,
         viewport: &Rectangle,
     ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.state.downcast_mut::<State>().is_focused = cursor.is_over(layout.bounds());
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::C,
+                modifiers,
+            }) if modifiers.command() && state.state.downcast_ref::<State>().is_focused => {
+                let mut selected = SelectedIndex::default();
+                self.operate(state, layout, renderer, &mut selected);
+
+                if let Some((index, option)) = selected
+                    .index
+                    .and_then(|index| self.options.get(index).map(|option| (index, option)))
+                {
+                    clipboard.write(
+                        self.copy_format
+                            .as_ref()
+                            .map_or_else(|| option.to_string(), |format| format(index, option)),
+                    );
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
         self.container.on_event(
             &mut state.children[0],
             event,
@@ -288,3 +397,52 @@ where
         Element::new(selection_list)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::SelectionList;
+    use crate::test_utils::Harness;
+    use iced_widget::core::{
+        keyboard::{KeyCode, Modifiers},
+        Point, Size,
+    };
+
+    const OPTIONS: [&str; 3] = ["Apple", "Banana", "Cherry"];
+
+    /// Returns the position of the option with the given index, below the
+    /// padding of the container around the list.
+    fn option(index: f32) -> Point {
+        Point::new(20.0, 1.0 + 22.0 * index + 11.0)
+    }
+
+    #[test]
+    fn copy_test() {
+        let options = OPTIONS.map(String::from);
+        let mut harness = Harness::new(
+            SelectionList::new(&options, |index, option| (index, option)),
+            Size::new(200.0, 100.0),
+        );
+        let _ = harness.set_modifiers(Modifiers::COMMAND);
+
+        // Nothing is copied before an option is selected.
+        assert!(harness.press_key(KeyCode::C).is_empty());
+        assert_eq!(harness.clipboard(), None);
+
+        assert_eq!(
+            harness.click(option(1.0)),
+            vec![(1, String::from("Banana"))]
+        );
+        let _ = harness.press_key(KeyCode::C);
+        assert_eq!(harness.clipboard(), Some("Banana"));
+
+        let mut harness = Harness::new(
+            SelectionList::new(&options, |index, option| (index, option))
+                .copy_with(|index, option| format!("{index}: {option}")),
+            Size::new(200.0, 100.0),
+        );
+        let _ = harness.click(option(2.0));
+        let _ = harness.set_modifiers(Modifiers::COMMAND);
+        let _ = harness.press_key(KeyCode::C);
+        assert_eq!(harness.clipboard(), Some("2: Cherry"));
+    }
+}
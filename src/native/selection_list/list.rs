@@ -11,7 +11,7 @@ use iced_widget::{
         renderer, touch,
         widget::{
             tree::{State, Tag},
-            Tree,
+            Operation, Tree,
         },
         Clipboard, Color, Element, Event, Layout, Length, Rectangle, Shell, Size, Widget,
     },
@@ -61,6 +61,14 @@ pub struct ListState {
     pub last_selected_index: Option<(usize, u64)>,
 }
 
+impl ListState {
+    /// Returns the index of the selected option, if any.
+    #[must_use]
+    pub fn selected_index(&self) -> Option<usize> {
+        self.last_selected_index.map(|(index, _)| index)
+    }
+}
+
 impl<'a, T, Message, Renderer> Widget<Message, Renderer> for List<'a, T, Message, Renderer>
 where
     T: Clone + Display + Eq + Hash,
@@ -122,6 +130,16 @@ where
         layout::Node::new(intrinsic)
     }
 
+    fn operate(
+        &self,
+        state: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.custom(state.state.downcast_mut::<ListState>(), None);
+    }
+
     fn on_event(
         &mut self,
         state: &mut Tree,
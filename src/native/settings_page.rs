@@ -0,0 +1,804 @@
+//! Use a settings page to show grouped settings with a searchable list of
+//! their sections.
+//!
+//! *This API requires the following crate features to be activated: `settings_page`*
+
+use crate::core::{find::Query, i18n};
+use crate::native::helpers::Styled;
+
+use iced_widget::{
+    button, container,
+    core::{
+        self,
+        mouse::{self, Cursor},
+        renderer,
+        widget::{
+            operation::{Operation, Outcome},
+            Id,
+        },
+        Alignment, Color, Element, Length, Rectangle,
+    },
+    overlay::menu,
+    pick_list,
+    renderer::Renderer,
+    runtime::Command,
+    scrollable::{self, AbsoluteOffset},
+    slider, text, text_input, toggler, Button, Column, MouseArea, PickList, Row, Scrollable,
+    Slider, Text, TextInput, Toggler,
+};
+use std::{any::Any, ops::RangeInclusive, rc::Rc};
+
+pub use crate::style::settings_page::{Appearance, StyleSheet};
+
+/// The padding around the elements.
+const PADDING: f32 = 12.0;
+/// The spacing between the elements.
+const SPACING: f32 = 8.0;
+/// The padding around a section in the section list.
+const ITEM_PADDING: f32 = 6.0;
+/// The width of the slider of a row.
+const SLIDER_WIDTH: f32 = 160.0;
+
+/// The control changing the value of a [`SettingsRow`].
+enum Control<'a, Message> {
+    /// A toggler switching the setting on or off.
+    Toggle {
+        /// Whether the setting is on.
+        is_on: bool,
+        /// The function producing the message when the setting is toggled.
+        on_toggle: Box<dyn Fn(bool) -> Message + 'a>,
+    },
+    /// A dropdown choosing one of some options.
+    Dropdown {
+        /// The options of the setting.
+        options: Vec<String>,
+        /// The chosen option.
+        selected: Option<String>,
+        /// The function producing the message when an option is chosen.
+        on_select: Box<dyn Fn(String) -> Message + 'a>,
+    },
+    /// A slider choosing a value in a range.
+    Slider {
+        /// The range of the value.
+        range: RangeInclusive<f32>,
+        /// The value of the setting.
+        value: f32,
+        /// The step of the slider.
+        step: f32,
+        /// The function producing the message when the value is changed.
+        on_change: Box<dyn Fn(f32) -> Message + 'a>,
+    },
+    /// A button performing an action.
+    Action {
+        /// The label of the button.
+        label: String,
+        /// The message produced when the button is pressed.
+        on_press: Message,
+    },
+}
+
+/// A row of a [`SettingsSection`]: a title, an optional description and
+/// the control changing the setting.
+#[allow(missing_debug_implementations)]
+pub struct SettingsRow<'a, Message> {
+    /// The title of the setting.
+    title: String,
+    /// The description shown below the title.
+    description: Option<String>,
+    /// The control changing the setting.
+    control: Control<'a, Message>,
+}
+
+impl<'a, Message> SettingsRow<'a, Message> {
+    /// Creates a new [`SettingsRow`] switching a setting on or off.
+    pub fn toggle<F>(title: impl Into<String>, is_on: bool, on_toggle: F) -> Self
+    where
+        F: 'a + Fn(bool) -> Message,
+    {
+        Self::new(
+            title,
+            Control::Toggle {
+                is_on,
+                on_toggle: Box::new(on_toggle),
+            },
+        )
+    }
+
+    /// Creates a new [`SettingsRow`] choosing one of the given options from
+    /// a dropdown list.
+    pub fn dropdown<O, F>(
+        title: impl Into<String>,
+        options: O,
+        selected: Option<impl Into<String>>,
+        on_select: F,
+    ) -> Self
+    where
+        O: IntoIterator,
+        O::Item: Into<String>,
+        F: 'a + Fn(String) -> Message,
+    {
+        Self::new(
+            title,
+            Control::Dropdown {
+                options: options.into_iter().map(Into::into).collect(),
+                selected: selected.map(Into::into),
+                on_select: Box::new(on_select),
+            },
+        )
+    }
+
+    /// Creates a new [`SettingsRow`] choosing a value in the given range
+    /// with a slider.
+    pub fn slider<F>(
+        title: impl Into<String>,
+        range: RangeInclusive<f32>,
+        value: f32,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'a + Fn(f32) -> Message,
+    {
+        Self::new(
+            title,
+            Control::Slider {
+                range,
+                value,
+                step: 1.0,
+                on_change: Box::new(on_change),
+            },
+        )
+    }
+
+    /// Creates a new [`SettingsRow`] with a button producing the given
+    /// message.
+    pub fn action(title: impl Into<String>, label: impl Into<String>, on_press: Message) -> Self {
+        Self::new(
+            title,
+            Control::Action {
+                label: label.into(),
+                on_press,
+            },
+        )
+    }
+
+    /// Creates a new [`SettingsRow`] with the given control.
+    fn new(title: impl Into<String>, control: Control<'a, Message>) -> Self {
+        Self {
+            title: title.into(),
+            description: None,
+            control,
+        }
+    }
+
+    /// Sets the description shown below the title of the [`SettingsRow`].
+    #[must_use]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the step of the slider of a [`SettingsRow`] created with
+    /// [`SettingsRow::slider`].
+    #[must_use]
+    pub fn step(mut self, step: f32) -> Self {
+        if let Control::Slider { step: old, .. } = &mut self.control {
+            *old = step;
+        }
+        self
+    }
+
+    /// Returns whether the title or the description of the [`SettingsRow`]
+    /// matches the search query.
+    fn matches(&self, query: &Query) -> bool {
+        query.text.is_empty()
+            || has_match(query, &self.title)
+            || self
+                .description
+                .as_ref()
+                .is_some_and(|description| has_match(query, description))
+    }
+}
+
+/// A titled group of [`SettingsRow`]s.
+#[allow(missing_debug_implementations)]
+pub struct SettingsSection<'a, Message> {
+    /// The title of the section.
+    title: String,
+    /// The rows of the section.
+    rows: Vec<SettingsRow<'a, Message>>,
+}
+
+impl<'a, Message> SettingsSection<'a, Message> {
+    /// Creates a new empty [`SettingsSection`] with the given title.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Adds a row to the [`SettingsSection`].
+    #[must_use]
+    pub fn push(mut self, row: SettingsRow<'a, Message>) -> Self {
+        self.rows.push(row);
+        self
+    }
+}
+
+/// A settings page: a searchable list of sections on the left and the
+/// grouped settings of all sections in a scrollable on the right.
+///
+/// Typing in the search field produces a message with the new query; the
+/// [`SettingsPage`] then only shows the rows whose title or description
+/// match it, highlighting the matches. Clicking a section in the list
+/// produces a message with its index, which can scroll to the section with
+/// [`scroll_to_section`].
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::scrollable;
+/// # use iced_aw::settings_page::{SettingsPage, SettingsRow, SettingsSection};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Search(String),
+///     SectionSelected(usize),
+///     DarkModeToggled(bool),
+/// }
+///
+/// let settings_page = SettingsPage::new(
+///     scrollable::Id::new("settings"),
+///     vec![SettingsSection::new("Appearance")
+///         .push(SettingsRow::toggle("Dark mode", true, Message::DarkModeToggled))],
+///     "",
+///     Message::Search,
+///     Message::SectionSelected,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct SettingsPage<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The id of the scrollable showing the sections.
+    id: scrollable::Id,
+    /// The sections of the settings.
+    sections: Vec<SettingsSection<'a, Message>>,
+    /// The search query.
+    search: &'a str,
+    /// The function producing the message when the search query is changed.
+    on_search: Rc<dyn Fn(String) -> Message + 'a>,
+    /// The function producing the message when a section is clicked.
+    on_section: Box<dyn Fn(usize) -> Message + 'a>,
+    /// The index of the highlighted section in the section list.
+    selected: Option<usize>,
+    /// The width of the section list.
+    sidebar_width: f32,
+    /// The text size of the [`SettingsPage`].
+    text_size: f32,
+    /// The style of the [`SettingsPage`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> SettingsPage<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + StyleSheet
+        + button::StyleSheet
+        + container::StyleSheet
+        + menu::StyleSheet
+        + pick_list::StyleSheet
+        + scrollable::StyleSheet
+        + slider::StyleSheet
+        + text::StyleSheet
+        + text_input::StyleSheet
+        + toggler::StyleSheet,
+    <Theme as menu::StyleSheet>::Style: From<<Theme as pick_list::StyleSheet>::Style>,
+{
+    /// Creates a new [`SettingsPage`] of the given sections.
+    ///
+    /// It expects:
+    ///     * the id of the scrollable showing the sections, used by
+    ///       [`scroll_to_section`].
+    ///     * the sections of the settings.
+    ///     * the search query.
+    ///     * the function producing the message when the search query is
+    ///       changed.
+    ///     * the function producing the message with the index of a clicked
+    ///       section.
+    pub fn new<S, F>(
+        id: scrollable::Id,
+        sections: Vec<SettingsSection<'a, Message>>,
+        search: &'a str,
+        on_search: S,
+        on_section: F,
+    ) -> Self
+    where
+        S: 'a + Fn(String) -> Message,
+        F: 'a + Fn(usize) -> Message,
+    {
+        Self {
+            id,
+            sections,
+            search,
+            on_search: Rc::new(on_search),
+            on_section: Box::new(on_section),
+            selected: None,
+            sidebar_width: 200.0,
+            text_size: 16.0,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the index of the highlighted section of the [`SettingsPage`].
+    #[must_use]
+    pub fn selected(mut self, selected: Option<usize>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Sets the width of the section list of the [`SettingsPage`].
+    #[must_use]
+    pub fn sidebar_width(mut self, sidebar_width: f32) -> Self {
+        self.sidebar_width = sidebar_width;
+        self
+    }
+
+    /// Sets the text size of the [`SettingsPage`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the style of the [`SettingsPage`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Wraps the given element to draw it with the [`Appearance`].
+    fn styled(
+        &self,
+        content: impl Into<Element<'a, Message, Renderer<Theme>>>,
+        role: Role,
+    ) -> Element<'a, Message, Renderer<Theme>> {
+        let style = self.style.clone();
+
+        let styled = Styled::new(
+            content,
+            move |renderer, theme: &Theme, defaults, bounds, cursor| {
+                role.draw(
+                    renderer,
+                    &StyleSheet::active(theme, &style),
+                    defaults.text_color,
+                    bounds,
+                    cursor,
+                )
+            },
+        )
+        .interaction(matches!(role, Role::Item { .. }).then_some(mouse::Interaction::Pointer));
+
+        match role {
+            Role::Section(section) => styled
+                .operate(move |bounds, operation| {
+                    operation.custom(&mut Anchor { section, bounds }, None);
+                })
+                .into(),
+            _ => styled.into(),
+        }
+    }
+
+    /// Creates the text, highlighting the matches of the search query.
+    fn highlighted(
+        &self,
+        content: &str,
+        query: &Query,
+        size: f32,
+    ) -> Element<'a, Message, Renderer<Theme>> {
+        let matches = if query.text.is_empty() {
+            Vec::new()
+        } else {
+            query.find(content).unwrap_or_default()
+        };
+
+        if matches.is_empty() {
+            return Text::new(content.to_owned()).size(size).into();
+        }
+
+        let mut row = Row::new();
+        let mut end = 0;
+        for range in matches {
+            if range.start > end {
+                row = row.push(Text::new(content[end..range.start].to_owned()).size(size));
+            }
+            row = row.push(self.styled(
+                Text::new(content[range.clone()].to_owned()).size(size),
+                Role::Highlight,
+            ));
+            end = range.end;
+        }
+        if end < content.len() {
+            row = row.push(Text::new(content[end..].to_owned()).size(size));
+        }
+
+        row.into()
+    }
+
+    /// Creates the control of a row.
+    fn control(&self, control: Control<'a, Message>) -> Element<'a, Message, Renderer<Theme>> {
+        match control {
+            Control::Toggle { is_on, on_toggle } => Toggler::new(None, is_on, on_toggle)
+                .width(Length::Shrink)
+                .size(self.text_size * 1.4)
+                .into(),
+            Control::Dropdown {
+                options,
+                selected,
+                on_select,
+            } => PickList::new(options, selected, on_select)
+                .text_size(self.text_size)
+                .into(),
+            Control::Slider {
+                range,
+                value,
+                step,
+                on_change,
+            } => Row::new()
+                .spacing(SPACING)
+                .align_items(Alignment::Center)
+                .push(
+                    Slider::new(range, value, on_change)
+                        .step(step)
+                        .width(Length::Fixed(SLIDER_WIDTH)),
+                )
+                .push(Text::new(format!("{value}")).size(self.text_size))
+                .into(),
+            Control::Action { label, on_press } => {
+                Button::new(Text::new(label).size(self.text_size))
+                    .on_press(on_press)
+                    .into()
+            }
+        }
+    }
+
+    /// Creates a row of a section.
+    fn row(
+        &self,
+        row: SettingsRow<'a, Message>,
+        query: &Query,
+    ) -> Element<'a, Message, Renderer<Theme>> {
+        let mut label = Column::new()
+            .spacing(2.0)
+            .width(Length::Fill)
+            .push(self.highlighted(&row.title, query, self.text_size));
+
+        if let Some(description) = &row.description {
+            label = label.push(self.styled(
+                self.highlighted(description, query, self.text_size * 0.85),
+                Role::Description,
+            ));
+        }
+
+        Row::new()
+            .spacing(SPACING)
+            .padding(PADDING)
+            .align_items(Alignment::Center)
+            .push(label)
+            .push(self.control(row.control))
+            .into()
+    }
+
+    /// Creates the section list with the search field.
+    fn sidebar(&self, visible: &[bool]) -> Element<'a, Message, Renderer<Theme>> {
+        let on_search = Rc::clone(&self.on_search);
+        let search = TextInput::new(&i18n::get().search_settings(), self.search)
+            .on_input(move |text| on_search(text))
+            .size(self.text_size)
+            .padding(ITEM_PADDING);
+
+        let items = self
+            .sections
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| visible[*index])
+            .fold(Column::new().spacing(2.0), |items, (index, section)| {
+                let item = self.styled(
+                    Row::new()
+                        .width(Length::Fill)
+                        .padding(ITEM_PADDING)
+                        .push(Text::new(section.title.clone()).size(self.text_size)),
+                    Role::Item {
+                        selected: self.selected == Some(index),
+                    },
+                );
+
+                items.push(MouseArea::new(item).on_press((self.on_section)(index)))
+            });
+
+        self.styled(
+            Column::new()
+                .spacing(SPACING)
+                .padding(PADDING)
+                .width(Length::Fixed(self.sidebar_width))
+                .height(Length::Fill)
+                .push(search)
+                .push(items),
+            Role::Sidebar,
+        )
+    }
+}
+
+impl<'a, Message, Theme> From<SettingsPage<'a, Message, Theme>>
+    for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + StyleSheet
+        + button::StyleSheet
+        + container::StyleSheet
+        + menu::StyleSheet
+        + pick_list::StyleSheet
+        + scrollable::StyleSheet
+        + slider::StyleSheet
+        + text::StyleSheet
+        + text_input::StyleSheet
+        + toggler::StyleSheet,
+    <Theme as menu::StyleSheet>::Style: From<<Theme as pick_list::StyleSheet>::Style>,
+{
+    fn from(mut settings_page: SettingsPage<'a, Message, Theme>) -> Self {
+        let query = Query::new(settings_page.search);
+
+        // A section is shown if its title or any of its rows match.
+        let sections: Vec<_> = std::mem::take(&mut settings_page.sections)
+            .into_iter()
+            .map(|mut section| {
+                if !has_match(&query, &section.title) {
+                    section.rows.retain(|row| row.matches(&query));
+                }
+                section
+            })
+            .collect();
+        let visible: Vec<_> = sections
+            .iter()
+            .map(|section| query.text.is_empty() || !section.rows.is_empty())
+            .collect();
+
+        settings_page.sections = sections;
+        let sidebar = settings_page.sidebar(&visible);
+
+        let mut content = Column::new().spacing(PADDING * 2.0).padding(PADDING);
+        for (index, section) in std::mem::take(&mut settings_page.sections)
+            .into_iter()
+            .enumerate()
+            .filter(|(index, _)| visible[*index])
+        {
+            let title = settings_page.styled(
+                settings_page.highlighted(&section.title, &query, settings_page.text_size * 1.25),
+                Role::Title,
+            );
+            let rows = section.rows.into_iter().fold(Column::new(), |rows, row| {
+                rows.push(settings_page.row(row, &query))
+            });
+
+            content = content.push(
+                settings_page.styled(
+                    Column::new()
+                        .spacing(SPACING)
+                        .push(title)
+                        .push(settings_page.styled(rows, Role::Group)),
+                    Role::Section(index),
+                ),
+            );
+        }
+
+        if !visible.contains(&true) {
+            content = content.push(settings_page.styled(
+                Text::new(i18n::get().no_matching_settings()).size(settings_page.text_size),
+                Role::Description,
+            ));
+        }
+
+        Row::new()
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .push(sidebar)
+            .push(
+                Scrollable::new(content)
+                    .id(settings_page.id.clone())
+                    .width(Length::Fill)
+                    .height(Length::Fill),
+            )
+            .into()
+    }
+}
+
+/// Returns whether the text contains a match of the search query.
+fn has_match(query: &Query, text: &str) -> bool {
+    query.find(text).is_ok_and(|matches| !matches.is_empty())
+}
+
+/// Produces a [`Command`] scrolling the [`SettingsPage`] with the given
+/// scrollable [`Id`](scrollable::Id) to the start of the section with the
+/// given index.
+///
+/// Nothing is scrolled if the section is hidden by the search query.
+pub fn scroll_to_section<Message: 'static>(id: scrollable::Id, section: usize) -> Command<Message> {
+    Command::widget(ScrollToSection {
+        target: id.into(),
+        section,
+        viewport: None,
+        inside: false,
+        offset: None,
+    })
+}
+
+/// The position of a section, found by [`ScrollToSection`].
+struct Anchor {
+    /// The index of the section.
+    section: usize,
+    /// The bounds of the section, before scrolling.
+    bounds: Rectangle,
+}
+
+/// The [`Operation`] finding the offset of a section in the scrollable of a
+/// [`SettingsPage`] and scrolling to it.
+struct ScrollToSection {
+    /// The id of the scrollable.
+    target: Id,
+    /// The index of the section.
+    section: usize,
+    /// The bounds of the scrollable.
+    viewport: Option<Rectangle>,
+    /// Whether the operation is inside of the scrollable.
+    inside: bool,
+    /// The found offset of the section.
+    offset: Option<f32>,
+}
+
+impl<T: 'static> Operation<T> for ScrollToSection {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        let target = id == Some(&self.target);
+        self.inside |= target;
+        operate_on_children(self);
+        if target {
+            self.inside = false;
+        }
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn core::widget::operation::Scrollable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        _translation: core::Vector,
+    ) {
+        if id == Some(&self.target) {
+            self.viewport = Some(bounds);
+        }
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, _id: Option<&Id>) {
+        let (true, Some(viewport), Some(anchor)) =
+            (self.inside, self.viewport, state.downcast_ref::<Anchor>())
+        else {
+            return;
+        };
+
+        if anchor.section == self.section && self.offset.is_none() {
+            self.offset = Some(anchor.bounds.y - viewport.y);
+        }
+    }
+
+    fn finish(&self) -> Outcome<T> {
+        self.offset.map_or(Outcome::None, |y| {
+            Outcome::Chain(Box::new(core::widget::operation::scrollable::scroll_to(
+                self.target.clone(),
+                AbsoluteOffset { x: 0.0, y },
+            )))
+        })
+    }
+}
+
+/// How a [`Styled`] element is drawn with the [`Appearance`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Role {
+    /// The background of the section list.
+    Sidebar,
+    /// A section in the section list.
+    Item {
+        /// Whether the section is highlighted.
+        selected: bool,
+    },
+    /// A section with the given index, found by [`scroll_to_section`].
+    Section(usize),
+    /// The title of a section.
+    Title,
+    /// The background of the rows of a section.
+    Group,
+    /// A description.
+    Description,
+    /// A match of the search query.
+    Highlight,
+}
+
+impl Role {
+    /// Draws the background of an element with the [`Role`], returning the
+    /// color of its text.
+    fn draw<Renderer: core::Renderer>(
+        self,
+        renderer: &mut Renderer,
+        appearance: &Appearance,
+        text_color: Color,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Color {
+        let mut fill = |background, border_radius: f32, border_width, border_color| {
+            core::Renderer::fill_quad(
+                renderer,
+                renderer::Quad {
+                    bounds,
+                    border_radius: border_radius.into(),
+                    border_width,
+                    border_color,
+                },
+                background,
+            );
+        };
+
+        match self {
+            Self::Sidebar => {
+                fill(appearance.sidebar_background, 0.0, 0.0, Color::TRANSPARENT);
+                text_color
+            }
+            Self::Item { selected: true } => {
+                fill(
+                    appearance.selected_background,
+                    appearance.border_radius,
+                    0.0,
+                    Color::TRANSPARENT,
+                );
+                appearance.selected_text_color
+            }
+            Self::Item { selected: false } => {
+                if cursor.is_over(bounds) {
+                    fill(
+                        appearance.hovered_background,
+                        appearance.border_radius,
+                        0.0,
+                        Color::TRANSPARENT,
+                    );
+                }
+                text_color
+            }
+            Self::Group => {
+                fill(
+                    appearance.group_background,
+                    appearance.border_radius,
+                    appearance.border_width,
+                    appearance.border_color,
+                );
+                text_color
+            }
+            Self::Highlight => {
+                fill(
+                    appearance.highlight_background,
+                    2.0,
+                    0.0,
+                    Color::TRANSPARENT,
+                );
+                text_color
+            }
+            Self::Title => appearance.title_color,
+            Self::Description => appearance.description_color,
+            Self::Section(_) => text_color,
+        }
+    }
+}
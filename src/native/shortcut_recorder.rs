@@ -0,0 +1,532 @@
+//! Use a shortcut recorder to record a key combination.
+//!
+//! *This API requires the following crate features to be activated: `shortcut_recorder`*
+use crate::core::{
+    i18n,
+    shortcut::{is_function_key, is_modifier, modifier_labels},
+};
+use crate::style::shortcut_recorder::StyleSheet;
+
+use iced_widget::{
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event,
+        keyboard::{self, KeyCode, Modifiers},
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer, text, touch,
+        widget::{
+            tree::{State as TreeState, Tag},
+            Tree,
+        },
+        Clipboard, Element, Event, Font, Layout, Length, Pixels, Rectangle, Shell, Size, Widget,
+    },
+    text::LineHeight,
+};
+
+pub use crate::core::shortcut::Shortcut;
+
+/// The padding inside of the keycaps.
+const KEYCAP_PADDING: f32 = 4.0;
+/// The spacing between the keycaps.
+const KEYCAP_SPACING: f32 = 4.0;
+
+/// A field recording the next key combination pressed while it is focused.
+///
+/// The field is focused by a click and shows the recorded [`Shortcut`] as
+/// keycaps. While recording, the held modifiers are shown as they are
+/// pressed; `Escape` cancels recording, and `Backspace` clears the
+/// [`Shortcut`] if [`on_clear`](Self::on_clear) is set.
+///
+/// The reserved shortcuts, and the shortcuts without modifiers other than
+/// the function keys, are rejected with a message instead of being
+/// recorded.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::shortcut_recorder::{Shortcut, ShortcutRecorder};
+/// # use iced::keyboard::{KeyCode, Modifiers};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Recorded(Shortcut),
+/// }
+///
+/// let reserved = [Shortcut::new(Modifiers::CTRL, KeyCode::Q)];
+///
+/// let shortcut_recorder = ShortcutRecorder::new(
+///     Some(Shortcut::new(Modifiers::CTRL, KeyCode::S)),
+///     Message::Recorded,
+/// )
+/// .reserved(&reserved);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ShortcutRecorder<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The recorded shortcut.
+    shortcut: Option<Shortcut>,
+    /// The function producing the message when a shortcut is recorded.
+    on_record: Box<dyn Fn(Shortcut) -> Message + 'a>,
+    /// The message produced when the shortcut is cleared.
+    on_clear: Option<Message>,
+    /// The shortcuts which can't be recorded.
+    reserved: &'a [Shortcut],
+    /// Whether a shortcut needs a modifier, unless it is a function key.
+    require_modifier: bool,
+    /// The text shown when there is no shortcut.
+    placeholder: String,
+    /// The width of the [`ShortcutRecorder`].
+    width: Length,
+    /// The padding around the keycaps.
+    padding: f32,
+    /// The text size of the [`ShortcutRecorder`].
+    text_size: f32,
+    /// The font of the [`ShortcutRecorder`].
+    font: Font,
+    /// The style of the [`ShortcutRecorder`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> ShortcutRecorder<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`ShortcutRecorder`] of the given shortcut.
+    ///
+    /// It expects:
+    ///     * the recorded shortcut, if any.
+    ///     * the function producing the message with the newly recorded
+    ///       shortcut.
+    pub fn new<F>(shortcut: Option<Shortcut>, on_record: F) -> Self
+    where
+        F: 'a + Fn(Shortcut) -> Message,
+    {
+        Self {
+            shortcut,
+            on_record: Box::new(on_record),
+            on_clear: None,
+            reserved: &[],
+            require_modifier: true,
+            placeholder: i18n::get().record_shortcut(),
+            width: Length::Fill,
+            padding: 5.0,
+            text_size: 16.0,
+            font: Font::DEFAULT,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the message produced when the shortcut is cleared with
+    /// `Backspace` while recording.
+    #[must_use]
+    pub fn on_clear(mut self, message: Message) -> Self {
+        self.on_clear = Some(message);
+        self
+    }
+
+    /// Sets the shortcuts which can't be recorded, e.g. the ones of the
+    /// system or of other actions.
+    #[must_use]
+    pub fn reserved(mut self, reserved: &'a [Shortcut]) -> Self {
+        self.reserved = reserved;
+        self
+    }
+
+    /// Sets whether a shortcut needs a modifier, unless it is a function
+    /// key.
+    #[must_use]
+    pub fn require_modifier(mut self, require_modifier: bool) -> Self {
+        self.require_modifier = require_modifier;
+        self
+    }
+
+    /// Sets the text shown when the [`ShortcutRecorder`] has no shortcut.
+    #[must_use]
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the width of the [`ShortcutRecorder`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding around the keycaps of the [`ShortcutRecorder`].
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`ShortcutRecorder`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the [`ShortcutRecorder`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`ShortcutRecorder`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns why the shortcut can't be recorded, if it can't.
+    fn rejection(&self, shortcut: Shortcut) -> Option<Rejection> {
+        if self.reserved.contains(&shortcut) {
+            Some(Rejection::Reserved)
+        } else if self.require_modifier
+            && shortcut.modifiers.is_empty()
+            && !is_function_key(shortcut.key_code)
+        {
+            Some(Rejection::NeedsModifier)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the keycaps and the text to show.
+    fn content(&self, state: &State) -> (Vec<String>, Option<(String, Text)>) {
+        match (state.is_recording, state.rejected) {
+            (true, Some((shortcut, reason))) => {
+                (shortcut.keys(), Some((reason.text(), Text::Error)))
+            }
+            (true, None) if !state.modifiers.is_empty() => (
+                modifier_labels(state.modifiers)
+                    .into_iter()
+                    .map(str::to_owned)
+                    .collect(),
+                None,
+            ),
+            (true, None) => (
+                Vec::new(),
+                Some((i18n::get().press_shortcut(), Text::Placeholder)),
+            ),
+            (false, _) => self.shortcut.map_or_else(
+                || {
+                    (
+                        Vec::new(),
+                        Some((self.placeholder.clone(), Text::Placeholder)),
+                    )
+                },
+                |shortcut| (shortcut.keys(), None),
+            ),
+        }
+    }
+}
+
+/// The kind of a text shown after the keycaps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Text {
+    /// A placeholder or prompt.
+    Placeholder,
+    /// The reason why a shortcut was rejected.
+    Error,
+}
+
+/// The reason why a shortcut was rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Rejection {
+    /// The shortcut is reserved.
+    Reserved,
+    /// The shortcut has no modifier, but one is required.
+    NeedsModifier,
+}
+
+impl Rejection {
+    /// Gets the translated text of the [`Rejection`].
+    fn text(self) -> String {
+        let translations = i18n::get();
+
+        match self {
+            Self::Reserved => translations.shortcut_reserved(),
+            Self::NeedsModifier => translations.shortcut_needs_modifier(),
+        }
+    }
+}
+
+/// The state of a [`ShortcutRecorder`].
+#[derive(Clone, Copy, Debug, Default)]
+struct State {
+    /// Whether the [`ShortcutRecorder`] is recording.
+    is_recording: bool,
+    /// The modifiers held while recording.
+    modifiers: Modifiers,
+    /// The last rejected shortcut, and why it was rejected.
+    rejected: Option<(Shortcut, Rejection)>,
+}
+
+impl State {
+    /// Stops recording.
+    fn stop(&mut self) {
+        *self = Self::default();
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for ShortcutRecorder<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let line_height = LineHeight::default().to_absolute(Pixels(self.text_size)).0;
+        let placeholder = renderer.measure_width(
+            &self.placeholder,
+            self.text_size,
+            self.font,
+            text::Shaping::Advanced,
+        );
+
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let size = limits.resolve(Size::new(
+            placeholder + self.padding * 2.0,
+            KEYCAP_PADDING.mul_add(2.0, line_height) + self.padding * 2.0,
+        ));
+
+        Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if cursor.is_over(layout.bounds()) {
+                    *state = State {
+                        is_recording: true,
+                        ..State::default()
+                    };
+                    event::Status::Captured
+                } else {
+                    state.stop();
+                    event::Status::Ignored
+                }
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) if state.is_recording => {
+                state.modifiers = modifiers;
+                if !modifiers.is_empty() {
+                    state.rejected = None;
+                }
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if state.is_recording => {
+                if is_modifier(key_code) {
+                    return event::Status::Captured;
+                }
+
+                if modifiers.is_empty() {
+                    match key_code {
+                        KeyCode::Escape => {
+                            state.stop();
+                            return event::Status::Captured;
+                        }
+                        KeyCode::Tab => {
+                            state.stop();
+                            return event::Status::Ignored;
+                        }
+                        KeyCode::Backspace => {
+                            if let Some(on_clear) = self.on_clear.clone() {
+                                shell.publish(on_clear);
+                                state.stop();
+                                return event::Status::Captured;
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+
+                let shortcut = Shortcut::new(modifiers, key_code);
+
+                if let Some(reason) = self.rejection(shortcut) {
+                    state.rejected = Some((shortcut, reason));
+                } else {
+                    shell.publish((self.on_record)(shortcut));
+                    state.stop();
+                }
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyReleased { .. }) if state.is_recording => {
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let (keys, text) = self.content(state);
+
+        let border_color = match (state.is_recording, state.rejected) {
+            (true, Some(_)) => appearance.error_color,
+            (true, None) => appearance.recording_border_color,
+            (false, _) => appearance.border_color,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius.into(),
+                border_width: appearance.border_width,
+                border_color,
+            },
+            appearance.background,
+        );
+
+        let line_height = LineHeight::default().to_absolute(Pixels(self.text_size)).0;
+        let height = KEYCAP_PADDING.mul_add(2.0, line_height);
+        let mut x = bounds.x + self.padding;
+
+        for key in &keys {
+            let width = KEYCAP_PADDING.mul_add(
+                2.0,
+                renderer.measure_width(key, self.text_size, self.font, text::Shaping::Advanced),
+            );
+            let keycap = Rectangle {
+                x,
+                y: bounds.center_y() - height / 2.0,
+                width: width.max(height),
+                height,
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: keycap,
+                    border_radius: appearance.border_radius.into(),
+                    border_width: 1.0,
+                    border_color: appearance.keycap_border_color,
+                },
+                appearance.keycap_background,
+            );
+
+            renderer.fill_text(core::text::Text {
+                content: key,
+                bounds: Rectangle {
+                    x: keycap.center_x(),
+                    y: keycap.center_y(),
+                    ..keycap
+                },
+                size: self.text_size,
+                line_height: LineHeight::default(),
+                color: appearance.keycap_text_color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Advanced,
+            });
+
+            x += keycap.width + KEYCAP_SPACING;
+        }
+
+        if let Some((content, kind)) = text {
+            renderer.fill_text(core::text::Text {
+                content: &content,
+                bounds: Rectangle {
+                    x: if keys.is_empty() {
+                        x
+                    } else {
+                        x + KEYCAP_SPACING
+                    },
+                    y: bounds.center_y(),
+                    width: f32::INFINITY,
+                    height: line_height,
+                },
+                size: self.text_size,
+                line_height: LineHeight::default(),
+                color: match kind {
+                    Text::Placeholder => appearance.placeholder_color,
+                    Text::Error => appearance.error_color,
+                },
+                font: self.font,
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Advanced,
+            });
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<ShortcutRecorder<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(shortcut_recorder: ShortcutRecorder<'a, Message, Renderer>) -> Self {
+        Element::new(shortcut_recorder)
+    }
+}
@@ -0,0 +1,449 @@
+//! Use signal bars to show the strength of a connection.
+//!
+//! *This API requires the following crate features to be activated: `signal_bars`*
+use std::time::{Duration, Instant};
+
+use crate::{
+    core::signal_bars::{lit_bars, sweep, MAX_BARS},
+    graphics::icons::{icon_to_char, Icon, ICON_FONT},
+};
+
+use iced_widget::core::{
+    self,
+    alignment::{Horizontal, Vertical},
+    event,
+    layout::{Limits, Node},
+    mouse::{self, Cursor},
+    renderer,
+    text::{self, LineHeight},
+    widget::{
+        tree::{State as TreeState, Tag},
+        Tree,
+    },
+    window, Clipboard, Color, Element, Event, Layout, Length, Rectangle, Shell, Size, Widget,
+};
+
+pub use crate::{
+    core::signal_bars::{Shape, Status},
+    style::signal_bars::{Appearance, StyleSheet},
+};
+
+/// An indicator of the strength of a connection, lighting a number of bars or
+/// arcs for its level, sweeping through them while searching and crossing
+/// them out when the connection failed.
+///
+/// The indicator times its sweeping itself, so it needs no timer subscription
+/// of the application.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{SignalBars, signal_bars::{Shape, Status}};
+/// #
+/// let wifi = SignalBars::new(0.7)
+///     .shape(Shape::Arcs)
+///     .bars(3)
+///     .status(Status::Connected);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct SignalBars<Renderer = crate::Renderer>
+where
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The level of the signal, from `0.0` to `1.0`.
+    level: f32,
+    /// The number of bars.
+    bars: u8,
+    /// The shape of the bars.
+    shape: Shape,
+    /// The state of the connection.
+    status: Status,
+    /// The height of the bars.
+    size: f32,
+    /// The time between the steps of the sweep while searching.
+    sweep_step: Duration,
+    /// The width of the [`SignalBars`].
+    width: Length,
+    /// The height of the [`SignalBars`].
+    height: Length,
+    /// The style of the [`SignalBars`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<Renderer> SignalBars<Renderer>
+where
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates new [`SignalBars`] showing the given level of the signal, from
+    /// `0.0` to `1.0`.
+    #[must_use]
+    pub fn new(level: f32) -> Self {
+        Self {
+            level,
+            bars: 4,
+            shape: Shape::default(),
+            status: Status::default(),
+            size: 16.0,
+            sweep_step: Duration::from_millis(300),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the number of bars of the [`SignalBars`], from 1 to 5.
+    #[must_use]
+    pub fn bars(mut self, bars: u8) -> Self {
+        self.bars = bars.clamp(1, MAX_BARS);
+        self
+    }
+
+    /// Sets the [`Shape`] of the bars of the [`SignalBars`].
+    #[must_use]
+    pub fn shape(mut self, shape: Shape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Sets the [`Status`] of the connection shown by the [`SignalBars`].
+    #[must_use]
+    pub fn status(mut self, status: Status) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Sets the height of the bars of the [`SignalBars`].
+    #[must_use]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the time between lighting one more bar while the [`SignalBars`]
+    /// are searching.
+    #[must_use]
+    pub fn sweep_step(mut self, sweep_step: Duration) -> Self {
+        self.sweep_step = sweep_step;
+        self
+    }
+
+    /// Sets the width of the [`SignalBars`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`SignalBars`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`SignalBars`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The width of a bar of the [`Shape::Bars`].
+    fn bar_width(&self) -> f32 {
+        self.size / 4.0
+    }
+
+    /// The size the bars take up.
+    fn indicator_size(&self) -> Size {
+        let width = match self.shape {
+            Shape::Bars => self.bar_width() * (3.0 * f32::from(self.bars) - 1.0) / 2.0,
+            Shape::Arcs => self.size * (2.0 - 1.0 / f32::from(self.bars)),
+        };
+
+        Size::new(width, self.size)
+    }
+}
+
+/// The state of [`SignalBars`].
+#[derive(Clone, Copy, Debug)]
+struct State {
+    /// The time the bars started searching.
+    started: Option<Instant>,
+    /// The number of bars lit by the sweep while searching.
+    swept: u8,
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for SignalBars<Renderer>
+where
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State {
+            started: None,
+            swept: 1,
+        })
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        Node::new(limits.resolve(self.indicator_size()))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+
+            if self.status == Status::Searching {
+                let started = *state.started.get_or_insert(now);
+                let (swept, change) = sweep(
+                    self.bars,
+                    now.saturating_duration_since(started),
+                    self.sweep_step,
+                );
+                state.swept = swept;
+
+                if let Some(change) = change {
+                    shell.request_redraw(window::RedrawRequest::At(now + change));
+                }
+            } else {
+                state.started = None;
+                state.swept = 1;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        mouse::Interaction::default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let (lit, lit_color) = match self.status {
+            Status::Connected => (lit_bars(self.level, self.bars), appearance.lit_color),
+            Status::Searching => (
+                tree.state.downcast_ref::<State>().swept,
+                appearance.searching_color,
+            ),
+            Status::Error => (0, appearance.lit_color),
+        };
+        let color = |bar: u8| {
+            if bar < lit {
+                lit_color
+            } else {
+                appearance.off_color
+            }
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        let size = self.indicator_size();
+        let indicator = Rectangle {
+            x: bounds.x,
+            y: bounds.center_y() - size.height / 2.0,
+            width: size.width,
+            height: size.height,
+        };
+
+        match self.shape {
+            Shape::Bars => {
+                let width = self.bar_width();
+
+                for bar in 0..self.bars {
+                    let height = self.size * f32::from(bar + 1) / f32::from(self.bars);
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: indicator.x + f32::from(bar) * width * 1.5,
+                                y: indicator.y + indicator.height - height,
+                                width,
+                                height,
+                            },
+                            border_radius: (width / 4.0).into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        color(bar),
+                    );
+                }
+            }
+            Shape::Arcs => {
+                // The dot and the arcs around it are as thick as the gaps
+                // between them.
+                let thickness = self.size / (2.0 * f32::from(self.bars));
+                let center_x = indicator.center_x();
+                let center_y = indicator.y + indicator.height - thickness;
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: center_x - thickness,
+                            y: center_y - thickness,
+                            width: thickness * 2.0,
+                            height: thickness * 2.0,
+                        },
+                        border_radius: thickness.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    color(0),
+                );
+
+                // Only the upper halves of the rings around the dot are shown.
+                let upper = Rectangle {
+                    height: center_y - indicator.y,
+                    ..indicator
+                };
+                renderer.with_layer(upper, |renderer| {
+                    for bar in 1..self.bars {
+                        let radius = thickness * f32::from(2 * bar + 1);
+
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: center_x - radius,
+                                    y: center_y - radius,
+                                    width: radius * 2.0,
+                                    height: radius * 2.0,
+                                },
+                                border_radius: radius.into(),
+                                border_width: thickness,
+                                border_color: color(bar),
+                            },
+                            Color::TRANSPARENT,
+                        );
+                    }
+                });
+            }
+        }
+
+        if self.status == Status::Error {
+            renderer.fill_text(core::text::Text {
+                content: &icon_to_char(Icon::X).to_string(),
+                bounds: Rectangle {
+                    x: indicator.x + indicator.width,
+                    y: indicator.y + indicator.height,
+                    ..indicator
+                },
+                size: self.size * 0.75,
+                line_height: LineHeight::Relative(1.0),
+                color: appearance.error_color,
+                font: ICON_FONT,
+                horizontal_alignment: Horizontal::Right,
+                vertical_alignment: Vertical::Bottom,
+                shaping: text::Shaping::Basic,
+            });
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<SignalBars<Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: 'a + StyleSheet,
+{
+    fn from(signal_bars: SignalBars<Renderer>) -> Self {
+        Element::new(signal_bars)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Shape, SignalBars, Status};
+    use crate::test_utils::Harness;
+    use iced_widget::core::Size;
+    use std::time::Duration;
+
+    #[test]
+    fn signal_bars_test() {
+        let bars = SignalBars::new(0.5).size(32.0);
+        let mut harness = Harness::<()>::new(bars, Size::new(60.0, 40.0));
+        let snapshot = harness.snapshot();
+        let color = |x, y| snapshot.color(x, y).expect("Pixel is in bounds");
+
+        // Half of the four bars are lit.
+        let lit = color(4, 29);
+        let off = color(40, 29);
+        assert!(lit.r < 0.2);
+        assert!(off.r > 0.6);
+        assert_eq!(color(16, 29), lit);
+        assert_eq!(color(28, 29), off);
+
+        // Searching sweeps through the bars by itself as time passes.
+        let bars = SignalBars::new(0.5)
+            .size(32.0)
+            .status(Status::Searching)
+            .sweep_step(Duration::from_millis(300));
+        let mut harness = Harness::<()>::new(bars, Size::new(60.0, 40.0));
+        let _ = harness.advance(Duration::ZERO);
+        let searching = harness.snapshot().color(4, 29).expect("Pixel is in bounds");
+        assert!(searching.b > searching.r);
+        assert_eq!(harness.snapshot().color(16, 29), Some(off));
+        let _ = harness.advance(Duration::from_millis(700));
+        assert_eq!(harness.snapshot().color(28, 29), Some(searching));
+        let _ = harness.advance(Duration::from_millis(600));
+        assert_eq!(harness.snapshot().color(16, 29), Some(off));
+
+        // A failed connection lights none of the arcs.
+        let bars = SignalBars::new(1.0)
+            .size(32.0)
+            .shape(Shape::Arcs)
+            .bars(3)
+            .status(Status::Error);
+        let mut harness = Harness::<()>::new(bars, Size::new(60.0, 40.0));
+        assert_eq!(harness.snapshot().color(26, 26), Some(off));
+    }
+}
@@ -19,6 +19,11 @@ use iced_widget::{
     Container, Row,
 };
 
+use crate::core::{
+    layout_direction::{self, LayoutDirection},
+    pixel,
+};
+
 pub use crate::style::split::{Appearance, StyleSheet};
 
 /// A split can divide the available space by half to display two different elements.
@@ -70,6 +75,11 @@ where
     on_resize: Box<dyn Fn(u16) -> Message>,
     /// The style of the [`Split`].
     style: <Renderer::Theme as StyleSheet>::Style,
+    /// The scale factor the divider of the [`Split`] is snapped to, if any.
+    scale_factor: Option<f32>,
+    /// The [`LayoutDirection`] deciding the side of the first element of a
+    /// vertical [`Split`].
+    direction: LayoutDirection,
 }
 
 impl<'a, Message, Renderer> Split<'a, Message, Renderer>
@@ -117,6 +127,8 @@ where
             min_size_second: 5,
             on_resize: Box::new(on_resize),
             style: <Renderer::Theme as StyleSheet>::Style::default(),
+            scale_factor: None,
+            direction: layout_direction::get(),
         }
     }
 
@@ -163,6 +175,16 @@ where
         self
     }
 
+    /// Sets the scale factor the [`Split`] is rendered at, to snap its
+    /// divider and borders to physical pixels.
+    ///
+    /// Nothing is snapped unless the scale factor is set.
+    #[must_use]
+    pub fn scale_factor(mut self, scale_factor: f32) -> Self {
+        self.scale_factor = Some(scale_factor);
+        self
+    }
+
     /// Sets the style of the [`Split`].
     #[must_use]
     pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
@@ -208,7 +230,9 @@ where
 
         match self.axis {
             Axis::Horizontal => horizontal_split(self, renderer, limits, &space),
-            Axis::Vertical => vertical_split(self, renderer, limits, &space),
+            Axis::Vertical => self
+                .direction
+                .arrange(vertical_split(self, renderer, limits, &space)),
         }
     }
 
@@ -266,6 +290,11 @@ where
                 if split_state.dragging {
                     let position = match self.axis {
                         Axis::Horizontal => position.y,
+                        // The divider is positioned from the right from right
+                        // to left.
+                        Axis::Vertical if self.direction.is_right_to_left() => {
+                            layout.bounds().x + layout.bounds().width - position.x
+                        }
                         Axis::Vertical => position.x,
                     };
 
@@ -358,9 +387,12 @@ where
         // Background
         renderer.fill_quad(
             renderer::Quad {
-                bounds: layout.bounds(),
+                bounds: pixel::snap_rectangle_to(layout.bounds(), self.scale_factor),
                 border_radius: (0.0).into(),
-                border_width: theme.active(&self.style).border_width,
+                border_width: pixel::snap_width_to(
+                    theme.active(&self.style).border_width,
+                    self.scale_factor,
+                ),
                 border_color: theme.active(&self.style).border_color,
             },
             theme
@@ -453,9 +485,12 @@ where
 
         renderer.fill_quad(
             renderer::Quad {
-                bounds: divider_layout.bounds(),
+                bounds: pixel::snap_rectangle_to(divider_layout.bounds(), self.scale_factor),
                 border_radius: (0.0).into(),
-                border_width: divider_style.divider_border_width,
+                border_width: pixel::snap_width_to(
+                    divider_style.divider_border_width,
+                    self.scale_factor,
+                ),
                 border_color: divider_style.divider_border_color,
             },
             divider_style.divider_background,
@@ -687,6 +722,9 @@ pub enum Axis {
     /// Split horizontally.
     Horizontal,
     /// Split vertically.
+    ///
+    /// The first element is on the right and the position of the divider is
+    /// measured from the right if the [`LayoutDirection`] is right to left.
     Vertical,
 }
 
@@ -695,3 +733,29 @@ impl Default for Axis {
         Self::Vertical
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Axis, Split};
+    use crate::test_utils::Harness;
+    use iced_widget::{
+        core::{Point, Size},
+        text,
+    };
+
+    #[test]
+    fn split_drag_test() {
+        let split = Split::new(
+            text("First"),
+            text("Second"),
+            Some(100),
+            Axis::Vertical,
+            |position| position,
+        );
+        let mut harness = Harness::new(split, Size::new(200.0, 100.0));
+
+        let messages = harness.drag(Point::new(100.0, 50.0), Point::new(150.0, 50.0), 2);
+
+        assert_eq!(messages.last(), Some(&150));
+    }
+}
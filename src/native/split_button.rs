@@ -0,0 +1,454 @@
+//! Use a split button to run a main action or choose an alternative one.
+//!
+//! *This API requires the following crate features to be activated: `split_button`*
+use crate::core::menu_item::MenuItem;
+use crate::graphics::icons::{icon_to_char, Icon, ICON_FONT};
+use crate::native::overlay::split_button::{MenuState, SplitButtonMenu};
+use crate::style::split_button::{Appearance, StyleSheet};
+
+use iced_widget::{
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        overlay, renderer, text, touch,
+        widget::{
+            tree::{State as TreeState, Tag},
+            Tree,
+        },
+        Clipboard, Color, Element, Event, Font, Layout, Length, Pixels, Rectangle, Shell, Size,
+        Widget,
+    },
+    text::LineHeight,
+};
+
+/// The width of the chevron section.
+const CHEVRON_WIDTH: f32 = 28.0;
+
+/// A button running a main action, fused with a chevron opening a dropdown
+/// menu of alternative actions.
+///
+/// The main action is disabled without a message set with
+/// [`on_press`](Self::on_press), and the chevron is disabled if none of the
+/// [`MenuItem`]s are enabled.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{MenuItem, SplitButton};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Save,
+///     SaveAs,
+///     SaveAll,
+/// }
+///
+/// let split_button = SplitButton::new(
+///     "Save",
+///     vec![
+///         MenuItem::new("Save as…").on_select(Message::SaveAs),
+///         MenuItem::new("Save all").on_select(Message::SaveAll),
+///     ],
+/// )
+/// .on_press(Message::Save);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct SplitButton<Message, Renderer = crate::Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The label of the main action.
+    label: String,
+    /// The alternative actions of the dropdown menu.
+    items: Vec<MenuItem<Message>>,
+    /// The message produced by the main action.
+    on_press: Option<Message>,
+    /// The width of the [`SplitButton`].
+    width: Length,
+    /// The padding around the label.
+    padding: f32,
+    /// The text size of the [`SplitButton`].
+    text_size: f32,
+    /// The font of the [`SplitButton`].
+    font: Font,
+    /// The style of the [`SplitButton`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<Message, Renderer> SplitButton<Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`SplitButton`] with the label of the main action and
+    /// the alternative actions of its dropdown menu.
+    pub fn new(label: impl Into<String>, items: Vec<MenuItem<Message>>) -> Self {
+        Self {
+            label: label.into(),
+            items,
+            on_press: None,
+            width: Length::Shrink,
+            padding: 8.0,
+            text_size: 16.0,
+            font: Font::DEFAULT,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the message produced by the main action, enabling it.
+    #[must_use]
+    pub fn on_press(mut self, message: Message) -> Self {
+        self.on_press = Some(message);
+        self
+    }
+
+    /// Sets the width of the [`SplitButton`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding around the label of the [`SplitButton`].
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`SplitButton`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the [`SplitButton`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`SplitButton`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the bounds of the main action and the chevron.
+    fn sections(bounds: Rectangle) -> (Rectangle, Rectangle) {
+        let main = Rectangle {
+            width: (bounds.width - CHEVRON_WIDTH).max(0.0),
+            ..bounds
+        };
+        let chevron = Rectangle {
+            x: main.x + main.width,
+            width: bounds.width - main.width,
+            ..bounds
+        };
+
+        (main, chevron)
+    }
+
+    /// Returns `true` if the dropdown menu has an enabled action.
+    fn has_menu(&self) -> bool {
+        self.items.iter().any(MenuItem::is_enabled)
+    }
+
+    /// Returns the appearance of a section.
+    fn appearance(
+        &self,
+        theme: &Renderer::Theme,
+        enabled: bool,
+        pressed: bool,
+        hovered: bool,
+    ) -> Appearance {
+        if !enabled {
+            theme.disabled(&self.style)
+        } else if pressed {
+            theme.pressed(&self.style)
+        } else if hovered {
+            theme.hovered(&self.style)
+        } else {
+            theme.active(&self.style)
+        }
+    }
+}
+
+/// The section of a [`SplitButton`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Section {
+    /// The main action.
+    Main,
+    /// The chevron opening the dropdown menu.
+    Chevron,
+}
+
+/// The state of a [`SplitButton`].
+#[derive(Clone, Debug, Default)]
+struct State {
+    /// The pressed section.
+    pressed: Option<Section>,
+    /// The state of the dropdown menu.
+    menu: MenuState,
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for SplitButton<Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let label_width = renderer.measure_width(
+            &self.label,
+            self.text_size,
+            self.font,
+            text::Shaping::Advanced,
+        );
+        let line_height = LineHeight::default().to_absolute(Pixels(self.text_size)).0;
+
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let size = limits.resolve(Size::new(
+            label_width + self.padding * 2.0 + CHEVRON_WIDTH,
+            line_height + self.padding * 2.0,
+        ));
+
+        Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let (main, chevron) = Self::sections(layout.bounds());
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if cursor.is_over(main) && self.on_press.is_some() {
+                    state.pressed = Some(Section::Main);
+                    return event::Status::Captured;
+                }
+                if cursor.is_over(chevron) && self.has_menu() {
+                    state.pressed = Some(Section::Chevron);
+                    state.menu.is_open = !state.menu.is_open;
+                    state.menu.highlighted = None;
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                let pressed = state.pressed.take();
+
+                if pressed == Some(Section::Main) && cursor.is_over(main) {
+                    if let Some(on_press) = &self.on_press {
+                        shell.publish(on_press.clone());
+                    }
+                    return event::Status::Captured;
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => state.pressed = None,
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let (main, chevron) = Self::sections(layout.bounds());
+
+        if (cursor.is_over(main) && self.on_press.is_some())
+            || (cursor.is_over(chevron) && self.has_menu())
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let (main, chevron) = Self::sections(bounds);
+
+        let main_appearance = self.appearance(
+            theme,
+            self.on_press.is_some(),
+            state.pressed == Some(Section::Main),
+            cursor.is_over(main),
+        );
+        let chevron_appearance = self.appearance(
+            theme,
+            self.has_menu(),
+            state.menu.is_open,
+            cursor.is_over(chevron),
+        );
+
+        for (section, appearance, radius) in [
+            (
+                main,
+                &main_appearance,
+                [
+                    main_appearance.border_radius,
+                    0.0,
+                    0.0,
+                    main_appearance.border_radius,
+                ],
+            ),
+            (
+                chevron,
+                &chevron_appearance,
+                [
+                    0.0,
+                    chevron_appearance.border_radius,
+                    chevron_appearance.border_radius,
+                    0.0,
+                ],
+            ),
+        ] {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: section,
+                    border_radius: radius.into(),
+                    border_width: appearance.border_width,
+                    border_color: appearance.border_color,
+                },
+                appearance.background,
+            );
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: chevron.x,
+                    y: chevron.y + self.padding / 2.0,
+                    width: 1.0,
+                    height: (chevron.height - self.padding).max(0.0),
+                },
+                border_radius: (0.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            main_appearance.separator_color,
+        );
+
+        renderer.fill_text(core::text::Text {
+            content: &self.label,
+            bounds: Rectangle {
+                x: main.center_x(),
+                y: main.center_y(),
+                ..main
+            },
+            size: self.text_size,
+            line_height: LineHeight::default(),
+            color: main_appearance.text_color,
+            font: self.font,
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+            shaping: text::Shaping::Advanced,
+        });
+
+        renderer.fill_text(core::text::Text {
+            content: &icon_to_char(Icon::CaretDownFill).to_string(),
+            bounds: Rectangle {
+                x: chevron.center_x(),
+                y: chevron.center_y(),
+                ..chevron
+            },
+            size: self.text_size * 0.75,
+            line_height: LineHeight::default(),
+            color: chevron_appearance.text_color,
+            font: ICON_FONT,
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+            shaping: text::Shaping::Basic,
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.menu.is_open {
+            return None;
+        }
+
+        let (_, chevron) = Self::sections(layout.bounds());
+
+        Some(
+            SplitButtonMenu::new(
+                &mut state.menu,
+                &self.items,
+                layout.bounds(),
+                chevron,
+                self.text_size,
+                self.font,
+                self.style.clone(),
+            )
+            .overlay(),
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<SplitButton<Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(split_button: SplitButton<Message, Renderer>) -> Self {
+        Element::new(split_button)
+    }
+}
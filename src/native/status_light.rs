@@ -0,0 +1,385 @@
+//! Use a status light to show the state of a service or a device.
+//!
+//! *This API requires the following crate features to be activated: `status_light`*
+use std::time::Instant;
+
+use crate::core::status_light::phase;
+
+use iced_widget::core::{
+    self,
+    alignment::{Horizontal, Vertical},
+    event,
+    layout::{Limits, Node},
+    mouse::{self, Cursor},
+    renderer,
+    text::{self, LineHeight},
+    widget::{
+        tree::{State as TreeState, Tag},
+        Tree,
+    },
+    window, Clipboard, Color, Element, Event, Layout, Length, Rectangle, Shell, Size, Widget,
+};
+
+pub use crate::{
+    core::status_light::{Pattern, Severity, Shape},
+    style::status_light::{Appearance, StyleSheet},
+};
+
+/// A small light showing the [`Severity`] of a state, lit, off or blinking
+/// with a [`Pattern`], with an optional label next to it.
+///
+/// The light times its blinking itself, so it needs no timer subscription of
+/// the application.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{StatusLight, status_light::{Pattern, Severity}};
+/// #
+/// let connection = StatusLight::new(Severity::Warning)
+///     .pattern(Pattern::slow())
+///     .label("Reconnecting");
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct StatusLight<Renderer = crate::Renderer>
+where
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The severity choosing the color of the light.
+    severity: Severity,
+    /// The way the light is lit.
+    pattern: Pattern,
+    /// The shape of the light.
+    shape: Shape,
+    /// The diameter of the light.
+    size: f32,
+    /// The label next to the light.
+    label: Option<String>,
+    /// The text size of the label.
+    text_size: f32,
+    /// The font of the label.
+    font: core::Font,
+    /// The space between the light and the label.
+    spacing: f32,
+    /// The width of the [`StatusLight`].
+    width: Length,
+    /// The height of the [`StatusLight`].
+    height: Length,
+    /// The style of the [`StatusLight`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<Renderer> StatusLight<Renderer>
+where
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`StatusLight`] lit in the color of the given
+    /// [`Severity`].
+    #[must_use]
+    pub fn new(severity: Severity) -> Self {
+        Self {
+            severity,
+            pattern: Pattern::default(),
+            shape: Shape::default(),
+            size: 12.0,
+            label: None,
+            text_size: 16.0,
+            font: core::Font::default(),
+            spacing: 8.0,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the [`Pattern`] the [`StatusLight`] is lit with.
+    #[must_use]
+    pub fn pattern(mut self, pattern: Pattern) -> Self {
+        self.pattern = pattern;
+        self
+    }
+
+    /// Sets the [`Shape`] of the [`StatusLight`].
+    #[must_use]
+    pub fn shape(mut self, shape: Shape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Sets the diameter of the light of the [`StatusLight`].
+    #[must_use]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the label next to the light of the [`StatusLight`].
+    #[must_use]
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the text size of the label of the [`StatusLight`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the label of the [`StatusLight`].
+    #[must_use]
+    pub fn font(mut self, font: core::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the space between the light and the label of the
+    /// [`StatusLight`].
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the width of the [`StatusLight`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`StatusLight`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`StatusLight`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+/// The state of a [`StatusLight`].
+#[derive(Clone, Copy, Debug)]
+struct State {
+    /// The time the light started blinking.
+    started: Option<Instant>,
+    /// Whether the blinking light is on.
+    lit: bool,
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for StatusLight<Renderer>
+where
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State {
+            started: None,
+            lit: true,
+        })
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = self.label.as_ref().map_or_else(
+            || Size::new(self.size, self.size),
+            |label| {
+                let width = renderer.measure_width(
+                    label,
+                    self.text_size,
+                    self.font,
+                    text::Shaping::Advanced,
+                );
+                let height = LineHeight::default().to_absolute(self.text_size.into()).0;
+
+                Size::new(self.size + self.spacing + width, self.size.max(height))
+            },
+        );
+
+        Node::new(limits.resolve(size))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+            let started = *state.started.get_or_insert(now);
+            let (lit, change) = phase(self.pattern, now.saturating_duration_since(started));
+            state.lit = lit;
+
+            if let Some(change) = change {
+                shell.request_redraw(window::RedrawRequest::At(now + change));
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        mouse::Interaction::default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let lit = match self.pattern {
+            Pattern::On => true,
+            Pattern::Off => false,
+            Pattern::Blink { .. } => tree.state.downcast_ref::<State>().lit,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        let light = Rectangle {
+            x: bounds.x,
+            y: bounds.center_y() - self.size / 2.0,
+            width: self.size,
+            height: self.size,
+        };
+        let color = if lit {
+            match self.severity {
+                Severity::Neutral => appearance.neutral_color,
+                Severity::Success => appearance.success_color,
+                Severity::Warning => appearance.warning_color,
+                Severity::Danger => appearance.danger_color,
+            }
+        } else {
+            appearance.off_color
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: light,
+                border_radius: match self.shape {
+                    Shape::Circle => self.size / 2.0,
+                    Shape::Square => self.size / 6.0,
+                }
+                .into(),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            color,
+        );
+
+        if let Some(label) = &self.label {
+            renderer.fill_text(core::text::Text {
+                content: label,
+                bounds: Rectangle {
+                    x: light.x + self.size + self.spacing,
+                    y: bounds.center_y(),
+                    width: f32::INFINITY,
+                    ..bounds
+                },
+                size: self.text_size,
+                line_height: LineHeight::default(),
+                color: appearance.label_color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Advanced,
+            });
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<StatusLight<Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: 'a + StyleSheet,
+{
+    fn from(status_light: StatusLight<Renderer>) -> Self {
+        Element::new(status_light)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Pattern, Severity, StatusLight};
+    use crate::test_utils::Harness;
+    use iced_widget::core::Size;
+    use std::time::Duration;
+
+    #[test]
+    fn status_light_test() {
+        let light = StatusLight::new(Severity::Danger)
+            .pattern(Pattern::Blink {
+                on: Duration::from_millis(500),
+                off: Duration::from_millis(500),
+            })
+            .size(16.0);
+        let mut harness = Harness::<()>::new(light, Size::new(40.0, 20.0));
+
+        // The light starts on, in the color of its severity.
+        let lit = harness.snapshot().color(8, 10).expect("Pixel is in bounds");
+        assert!(lit.r > 0.6 && lit.g < 0.4);
+
+        // It blinks by itself as time passes.
+        let _ = harness.advance(Duration::ZERO);
+        let _ = harness.advance(Duration::from_millis(600));
+        let off = harness.snapshot().color(8, 10).expect("Pixel is in bounds");
+        assert!(off.g > 0.6);
+        let _ = harness.advance(Duration::from_millis(500));
+        assert_eq!(harness.snapshot().color(8, 10), Some(lit));
+
+        // A light that is off stays off.
+        let light = StatusLight::new(Severity::Danger)
+            .pattern(Pattern::Off)
+            .size(16.0);
+        let mut harness = Harness::<()>::new(light, Size::new(40.0, 20.0));
+        let _ = harness.advance(Duration::from_millis(600));
+        assert_eq!(harness.snapshot().color(8, 10), Some(off));
+    }
+}
@@ -0,0 +1,478 @@
+//! Use a step grid to toggle the steps of a sequencer.
+//!
+//! *This API requires the following crate features to be activated: `step_grid`*
+use crate::style::step_grid::StyleSheet;
+
+use iced_widget::core::{
+    self, event,
+    layout::{Limits, Node},
+    mouse::{self, Cursor},
+    renderer, touch,
+    widget::{
+        tree::{State as TreeState, Tag},
+        Tree,
+    },
+    Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+/// The velocity of newly painted cells.
+const DEFAULT_VELOCITY: f32 = 0.8;
+/// The vertical distance in pixels to drag for changing the velocity from 0.0 to 1.0.
+const VELOCITY_DRAG: f32 = 100.0;
+/// The vertical distance in pixels a drag has to travel to change the velocity.
+const DRAG_SLOP: f32 = 3.0;
+
+/// A matrix of the steps of a sequencer, with a row per instrument or
+/// parameter, like in drum machines and automation editors.
+///
+/// Each cell is either inactive or active with a velocity from 0.0 to 1.0.
+/// Dragging from an inactive cell paints active cells, dragging from an active
+/// cell to others erases them, and dragging an active cell vertically changes
+/// its velocity. The step at the playhead, driven by the tick of the
+/// application, is highlighted.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::StepGrid;
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     CellChanged(usize, usize, Option<f32>),
+/// }
+///
+/// let cells = vec![vec![None; 16]; 4];
+///
+/// let step_grid = StepGrid::new(&cells, Message::CellChanged).playhead(Some(0));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct StepGrid<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The rows of cells, with the velocities of the active cells.
+    cells: &'a [Vec<Option<f32>>],
+    /// The function producing the message when a cell changes.
+    on_change: Box<dyn Fn(usize, usize, Option<f32>) -> Message + 'a>,
+    /// The step at the playhead.
+    playhead: Option<usize>,
+    /// The number of steps in a beat.
+    steps_per_beat: usize,
+    /// The width of the [`StepGrid`].
+    width: Length,
+    /// The height of a row.
+    row_height: f32,
+    /// The spacing between the cells.
+    spacing: f32,
+    /// The style of the [`StepGrid`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> StepGrid<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`StepGrid`] of the given rows of cells, producing the
+    /// message of `on_change` with the row, the step and the new velocity of
+    /// a changed cell, or `None` if it was deactivated.
+    pub fn new<F>(cells: &'a [Vec<Option<f32>>], on_change: F) -> Self
+    where
+        F: 'a + Fn(usize, usize, Option<f32>) -> Message,
+    {
+        Self {
+            cells,
+            on_change: Box::new(on_change),
+            playhead: None,
+            steps_per_beat: 4,
+            width: Length::Fill,
+            row_height: 24.0,
+            spacing: 2.0,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the step at the playhead of the [`StepGrid`].
+    #[must_use]
+    pub fn playhead(mut self, playhead: Option<usize>) -> Self {
+        self.playhead = playhead;
+        self
+    }
+
+    /// Sets the number of steps in a beat of the [`StepGrid`].
+    #[must_use]
+    pub fn steps_per_beat(mut self, steps_per_beat: usize) -> Self {
+        self.steps_per_beat = steps_per_beat.max(1);
+        self
+    }
+
+    /// Sets the width of the [`StepGrid`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of a row of the [`StepGrid`].
+    #[must_use]
+    pub fn row_height(mut self, row_height: f32) -> Self {
+        self.row_height = row_height;
+        self
+    }
+
+    /// Sets the spacing between the cells of the [`StepGrid`].
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the style of the [`StepGrid`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the number of steps of the longest row.
+    fn steps(&self) -> usize {
+        self.cells.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    /// Returns the total height of the rows.
+    #[allow(clippy::cast_precision_loss)]
+    fn total_height(&self) -> f32 {
+        let rows = self.cells.len() as f32;
+        (rows * (self.row_height + self.spacing) - self.spacing).max(0.0)
+    }
+
+    /// Returns the width of a cell.
+    #[allow(clippy::cast_precision_loss)]
+    fn cell_width(&self, bounds: Rectangle) -> f32 {
+        let steps = self.steps().max(1) as f32;
+        ((bounds.width - self.spacing * (steps - 1.0)) / steps).max(0.0)
+    }
+
+    /// Returns the bounds of the given cell.
+    #[allow(clippy::cast_precision_loss)]
+    fn cell_bounds(&self, bounds: Rectangle, row: usize, step: usize) -> Rectangle {
+        let width = self.cell_width(bounds);
+
+        Rectangle {
+            x: bounds.x + step as f32 * (width + self.spacing),
+            y: bounds.y + row as f32 * (self.row_height + self.spacing),
+            width,
+            height: self.row_height,
+        }
+    }
+
+    /// Returns the row and the step of the cell at the given position.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    fn cell_at(&self, bounds: Rectangle, position: Point) -> Option<(usize, usize)> {
+        if !bounds.contains(position) {
+            return None;
+        }
+
+        let row = ((position.y - bounds.y) / (self.row_height + self.spacing)) as usize;
+        let step = ((position.x - bounds.x) / (self.cell_width(bounds) + self.spacing)) as usize;
+
+        self.cells
+            .get(row)
+            .filter(|cells| step < cells.len())
+            .map(|_| (row, step))
+    }
+
+    /// Returns the velocity of the given cell, if it is active.
+    fn velocity(&self, (row, step): (usize, usize)) -> Option<f32> {
+        self.cells.get(row)?.get(step).copied().flatten()
+    }
+}
+
+/// The state of a [`StepGrid`].
+#[derive(Clone, Copy, Debug, Default)]
+struct State {
+    /// The ongoing drag.
+    drag: Option<Drag>,
+}
+
+/// A drag on a [`StepGrid`].
+#[derive(Clone, Copy, Debug)]
+enum Drag {
+    /// Activates the cells dragged over.
+    Paint,
+    /// Deactivates the cells dragged over.
+    Erase,
+    /// Pressed an active cell, but did not move far enough yet to decide
+    /// between erasing and changing the velocity.
+    Pending {
+        /// The pressed cell.
+        cell: (usize, usize),
+        /// The position the drag started at.
+        origin: Point,
+        /// The velocity of the pressed cell.
+        velocity: f32,
+    },
+    /// Changes the velocity of a cell.
+    Velocity {
+        /// The cell to change.
+        cell: (usize, usize),
+        /// The position the drag started at.
+        origin: Point,
+        /// The velocity of the cell when the drag started.
+        velocity: f32,
+    },
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for StepGrid<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &Limits) -> Node {
+        let height = self.total_height();
+        let limits = limits.width(self.width).height(Length::Fixed(height));
+
+        Node::new(limits.resolve(Size::new(limits.max().width, height)))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position() else {
+                    return event::Status::Ignored;
+                };
+                let Some(cell) = self.cell_at(bounds, position) else {
+                    return event::Status::Ignored;
+                };
+
+                if let Some(velocity) = self.velocity(cell) {
+                    state.drag = Some(Drag::Pending {
+                        cell,
+                        origin: position,
+                        velocity,
+                    });
+                } else {
+                    state.drag = Some(Drag::Paint);
+                    shell.publish((self.on_change)(cell.0, cell.1, Some(DEFAULT_VELOCITY)));
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let Some(drag) = state.drag else {
+                    return event::Status::Ignored;
+                };
+                let hovered = self.cell_at(bounds, position);
+
+                match drag {
+                    Drag::Paint => {
+                        if let Some(cell) = hovered.filter(|cell| self.velocity(*cell).is_none()) {
+                            shell.publish((self.on_change)(cell.0, cell.1, Some(DEFAULT_VELOCITY)));
+                        }
+                    }
+                    Drag::Erase => {
+                        if let Some(cell) = hovered.filter(|cell| self.velocity(*cell).is_some()) {
+                            shell.publish((self.on_change)(cell.0, cell.1, None));
+                        }
+                    }
+                    Drag::Pending {
+                        cell,
+                        origin,
+                        velocity,
+                    } => {
+                        if hovered.is_some_and(|hovered| hovered != cell) {
+                            // Moved on to another cell, so erase both.
+                            state.drag = Some(Drag::Erase);
+                            shell.publish((self.on_change)(cell.0, cell.1, None));
+
+                            if let Some(hovered) =
+                                hovered.filter(|hovered| self.velocity(*hovered).is_some())
+                            {
+                                shell.publish((self.on_change)(hovered.0, hovered.1, None));
+                            }
+                        } else if (position.y - origin.y).abs() > DRAG_SLOP {
+                            state.drag = Some(Drag::Velocity {
+                                cell,
+                                origin,
+                                velocity,
+                            });
+                        }
+                    }
+                    Drag::Velocity { .. } => {}
+                }
+
+                if let Some(Drag::Velocity {
+                    cell,
+                    origin,
+                    velocity,
+                }) = state.drag
+                {
+                    // Dragging up raises the velocity.
+                    let velocity =
+                        (velocity + (origin.y - position.y) / VELOCITY_DRAG).clamp(0.0, 1.0);
+                    shell.publish((self.on_change)(cell.0, cell.1, Some(velocity)));
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                match state.drag.take() {
+                    // A click on an active cell deactivates it.
+                    Some(Drag::Pending { cell, .. }) => {
+                        shell.publish((self.on_change)(cell.0, cell.1, None));
+                    }
+                    Some(_) => {}
+                    None => return event::Status::Ignored,
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match tree.state.downcast_ref::<State>().drag {
+            Some(Drag::Velocity { .. }) => mouse::Interaction::ResizingVertically,
+            _ if cursor
+                .position()
+                .and_then(|position| self.cell_at(layout.bounds(), position))
+                .is_some() =>
+            {
+                mouse::Interaction::Pointer
+            }
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        for (row, cells) in self.cells.iter().enumerate() {
+            for (step, velocity) in cells.iter().enumerate() {
+                let cell = self.cell_bounds(bounds, row, step);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: cell,
+                        border_radius: appearance.cell_border_radius.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    if step % self.steps_per_beat == 0 {
+                        appearance.beat_cell_background
+                    } else {
+                        appearance.cell_background
+                    },
+                );
+
+                // Active cells are filled from the bottom up to their velocity.
+                if let Some(velocity) = velocity {
+                    let height = cell.height * velocity.clamp(0.0, 1.0);
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                y: cell.y + cell.height - height,
+                                height,
+                                ..cell
+                            },
+                            border_radius: appearance.cell_border_radius.into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        appearance.active_color,
+                    );
+                }
+
+                if self.playhead == Some(step) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: cell,
+                            border_radius: appearance.cell_border_radius.into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        appearance.playhead_color,
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<StepGrid<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(step_grid: StepGrid<'a, Message, Renderer>) -> Self {
+        Element::new(step_grid)
+    }
+}
@@ -0,0 +1,713 @@
+//! Use a sunburst to show hierarchical size data as rings of segments.
+//!
+//! *This API requires the following crate features to be activated: `sunburst`*
+use std::time::{Duration, Instant};
+
+use crate::core::hierarchy::{partition, polar, Segment};
+use crate::native::helpers::chart::{self, mix};
+
+use iced_widget::{
+    canvas::{self, Path, Stroke},
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer,
+        text::{self, Renderer as _},
+        touch,
+        widget::{
+            tree::{State as TreeState, Tag},
+            Tree,
+        },
+        window, Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Renderer as _,
+        Shell, Size, Vector, Widget,
+    },
+    graphics::geometry::Renderer as _,
+    renderer::Renderer,
+    text::LineHeight,
+};
+
+pub use crate::core::easing::Easing;
+pub use crate::core::hierarchy::HierarchyNode;
+pub use crate::style::sunburst::{Appearance, StyleSheet};
+
+/// The function coloring the segments by their paths and nodes.
+type ColorFn<'a> = Box<dyn Fn(&[usize], &HierarchyNode) -> Color + 'a>;
+
+/// The radius of the circle in the center relative to the radius of the
+/// [`Sunburst`].
+const CENTER_RATIO: f32 = 0.25;
+
+/// A sunburst showing the descendants of a [`HierarchyNode`] as rings of
+/// segments around a circle, one ring per level, spanning the fractions of
+/// the turn given by their totals.
+///
+/// Clicking a segment with children drills down into it, and clicking the
+/// circle in the center, or right clicking, drills back up. The segments
+/// sweep to their new angles with an animated transition. Hovering a segment
+/// shows its label and its total in a tooltip.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::sunburst::{HierarchyNode, Sunburst};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Drilled(Vec<usize>),
+/// }
+///
+/// let root = HierarchyNode::new("Disk", 0.0).children([
+///     HierarchyNode::new("Photos", 120.0),
+///     HierarchyNode::new("Music", 80.0),
+/// ]);
+///
+/// let sunburst = Sunburst::new(&root).on_drill(Message::Drilled);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Sunburst<'a, Message, Theme = iced_widget::style::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The root of the shown data.
+    root: &'a HierarchyNode,
+    /// The function producing the message with the path of the shown level.
+    on_drill: Option<Box<dyn Fn(Vec<usize>) -> Message + 'a>>,
+    /// The function coloring a segment by the path and the node of the
+    /// segment.
+    color_by: Option<ColorFn<'a>>,
+    /// The function formatting a total for the tooltip.
+    format: Box<dyn Fn(f32) -> String + 'a>,
+    /// The number of rings shown at once.
+    rings: usize,
+    /// The width of the [`Sunburst`].
+    width: Length,
+    /// The height of the [`Sunburst`].
+    height: Length,
+    /// The text size of the labels.
+    text_size: f32,
+    /// The duration of the transition between two levels.
+    duration: Duration,
+    /// The easing of the transition between two levels.
+    easing: Easing,
+    /// The style of the [`Sunburst`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> Sunburst<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Sunburst`] of the given data.
+    #[must_use]
+    pub fn new(root: &'a HierarchyNode) -> Self {
+        Self {
+            root,
+            on_drill: None,
+            color_by: None,
+            format: Box::new(|value| format!("{value}")),
+            rings: 3,
+            width: Length::Fill,
+            height: Length::Fill,
+            text_size: 14.0,
+            duration: Duration::from_millis(300),
+            easing: Easing::default(),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the function producing the message with the path of child
+    /// indices from the root to the level shown after drilling down or up.
+    #[must_use]
+    pub fn on_drill<F>(mut self, on_drill: F) -> Self
+    where
+        F: 'a + Fn(Vec<usize>) -> Message,
+    {
+        self.on_drill = Some(Box::new(on_drill));
+        self
+    }
+
+    /// Sets the function coloring a segment by the path of child indices
+    /// from the root to its node and by the node itself.
+    #[must_use]
+    pub fn color_by<F>(mut self, color_by: F) -> Self
+    where
+        F: 'a + Fn(&[usize], &HierarchyNode) -> Color,
+    {
+        self.color_by = Some(Box::new(color_by));
+        self
+    }
+
+    /// Sets the function formatting the totals shown in the tooltip.
+    #[must_use]
+    pub fn format<F>(mut self, format: F) -> Self
+    where
+        F: 'a + Fn(f32) -> String,
+    {
+        self.format = Box::new(format);
+        self
+    }
+
+    /// Sets the number of rings shown at once.
+    #[must_use]
+    pub fn rings(mut self, rings: usize) -> Self {
+        self.rings = rings.max(1);
+        self
+    }
+
+    /// Sets the width of the [`Sunburst`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Sunburst`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the text size of the labels of the [`Sunburst`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the duration of the transition between two levels.
+    #[must_use]
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the easing of the transition between two levels.
+    #[must_use]
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Sets the style of the [`Sunburst`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the longest prefix of the given path that still leads to a
+    /// node of the data.
+    fn level<'b>(&self, path: &'b [usize]) -> &'b [usize] {
+        let len = (0..=path.len())
+            .rev()
+            .find(|&len| self.root.get(&path[..len]).is_some())
+            .unwrap_or(0);
+
+        &path[..len]
+    }
+
+    /// Returns the segments of the level at the given path.
+    fn segments(&self, path: &[usize]) -> Vec<Segment> {
+        self.root
+            .get(path)
+            .map_or_else(Vec::new, |node| partition(node, self.rings))
+    }
+
+    /// Returns the radius of the circle in the center and the width of a
+    /// ring.
+    #[allow(clippy::cast_precision_loss)]
+    fn radii(&self, bounds: Rectangle) -> (f32, f32) {
+        let radius = (bounds.width.min(bounds.height) / 2.0 - 2.0).max(0.0);
+        let center = radius * CENTER_RATIO;
+
+        (center, (radius - center) / self.rings as f32)
+    }
+
+    /// Returns whether the given position is in the circle in the center.
+    fn is_in_center(&self, bounds: Rectangle, position: Point) -> bool {
+        position.distance(bounds.center()) < self.radii(bounds).0
+    }
+
+    /// Returns the segment at the given position.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    fn segment_at(&self, path: &[usize], bounds: Rectangle, position: Point) -> Option<Segment> {
+        let (center, ring_width) = self.radii(bounds);
+        let offset = position - bounds.center();
+        let distance = position.distance(bounds.center());
+
+        if distance < center || ring_width <= 0.0 {
+            return None;
+        }
+
+        let ring = ((distance - center) / ring_width) as usize + 1;
+        // The turn starts at the top and goes clockwise.
+        let turn = (offset.y.atan2(offset.x) / std::f32::consts::TAU + 0.25).rem_euclid(1.0);
+
+        self.segments(path).into_iter().find(|segment| {
+            segment.path.len() == ring && segment.start <= turn && turn < segment.end
+        })
+    }
+
+    /// Returns the color of the segment of the node at the given path
+    /// relative to the shown level.
+    fn color(&self, appearance: &Appearance, level: &[usize], segment: &Segment) -> Color {
+        let path: Vec<usize> = level.iter().chain(&segment.path).copied().collect();
+
+        if let (Some(color_by), Some(node)) = (&self.color_by, self.root.get(&path)) {
+            color_by(&path, node)
+        } else {
+            // The outer segments are lighter shades of the segment of
+            // their innermost ancestor.
+            let color = appearance.palette[segment.path[0] % appearance.palette.len()];
+            #[allow(clippy::cast_precision_loss)]
+            let shade = (segment.path.len() - 1) as f32 * 0.2;
+
+            mix(color, Color::WHITE, shade.min(0.8))
+        }
+    }
+}
+
+/// The state of a [`Sunburst`].
+#[derive(Debug, Default)]
+struct State {
+    /// The path of child indices from the root to the shown level.
+    path: Vec<usize>,
+    /// The running transition between two levels.
+    sweep: Option<Sweep>,
+}
+
+/// The transition of a [`Sunburst`] into a new level, which maps the full
+/// turn of the new level onto a span of the turn that grows to the full
+/// turn.
+#[derive(Clone, Copy, Debug)]
+struct Sweep {
+    /// The start of the span the new level starts to be drawn in.
+    start: f32,
+    /// The end of the span the new level starts to be drawn in.
+    end: f32,
+    /// The progress of the transition, from 0.0 to 1.0.
+    progress: f32,
+    /// The time of the last step of the transition.
+    last_update: Option<Instant>,
+}
+
+impl Sweep {
+    /// Creates a new [`Sweep`] starting at the given span.
+    fn new(start: f32, end: f32) -> Self {
+        Self {
+            start,
+            end,
+            progress: 0.0,
+            last_update: None,
+        }
+    }
+
+    /// Returns the span the level is drawn in at the eased progress.
+    fn span(&self, easing: Easing) -> (f32, f32) {
+        let t = easing.apply(self.progress);
+
+        (self.start * (1.0 - t), self.end + (1.0 - self.end) * t)
+    }
+}
+
+impl<Message, Theme> Sunburst<'_, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Shows the level at the given path, sweeping out of the segment of its
+    /// node or into the segment of the previous level.
+    fn drill(&self, state: &mut State, path: Vec<usize>) -> Option<Message> {
+        let level = self.level(&state.path).to_vec();
+        if path == level {
+            return None;
+        }
+
+        let sweep = if path.starts_with(&level) {
+            // The new level grows out of its segment in the previous level.
+            self.segments(&level)
+                .into_iter()
+                .find(|segment| segment.path == path[level.len()..])
+                .map(|segment| Sweep::new(segment.start, segment.end))
+        } else {
+            // The previous level shrinks into its segment in the new level.
+            self.segments(&path)
+                .into_iter()
+                .find(|segment| segment.path == [level[path.len()]])
+                .filter(|segment| segment.end > segment.start)
+                .map(|segment| {
+                    let scale = 1.0 / (segment.end - segment.start);
+                    let start = -segment.start * scale;
+
+                    Sweep::new(start, start + scale)
+                })
+        };
+
+        state.sweep = sweep;
+        state.path = path;
+
+        self.on_drill
+            .as_ref()
+            .map(|on_drill| on_drill(state.path.clone()))
+    }
+}
+
+impl<Message, Theme> Widget<Message, Renderer<Theme>> for Sunburst<'_, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer<Theme>, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        Node::new(limits.resolve(Size::new(f32::INFINITY, f32::INFINITY)))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer<Theme>,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if let Some(sweep) = &mut state.sweep {
+                    let last_update = sweep.last_update.replace(now).unwrap_or(now);
+                    sweep.progress = if self.duration.is_zero() {
+                        1.0
+                    } else {
+                        sweep.progress
+                            + (now - last_update).as_secs_f32() / self.duration.as_secs_f32()
+                    };
+
+                    if sweep.progress >= 1.0 {
+                        state.sweep = None;
+                    } else {
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+                let level = self.level(&state.path).to_vec();
+
+                let path = if self.is_in_center(bounds, position) {
+                    level.split_last().map(|(_, parent)| parent.to_vec())
+                } else {
+                    // A click drills into the segment with children.
+                    self.segment_at(&level, bounds, position)
+                        .map(|segment| {
+                            level
+                                .iter()
+                                .chain(&segment.path)
+                                .copied()
+                                .collect::<Vec<_>>()
+                        })
+                        .filter(|path| {
+                            self.root
+                                .get(path)
+                                .is_some_and(|node| !node.children.is_empty())
+                        })
+                };
+
+                let Some(path) = path else {
+                    return event::Status::Ignored;
+                };
+
+                if let Some(message) = self.drill(state, path) {
+                    shell.publish(message);
+                }
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => {
+                let level = self.level(&state.path).to_vec();
+
+                if let (true, Some((_, parent))) = (cursor.is_over(bounds), level.split_last()) {
+                    if let Some(message) = self.drill(state, parent.to_vec()) {
+                        shell.publish(message);
+                    }
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer<Theme>,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let level = self.level(&state.path);
+
+        let Some(position) = cursor.position_over(bounds) else {
+            return mouse::Interaction::default();
+        };
+
+        let is_drillable = if self.is_in_center(bounds, position) {
+            !level.is_empty()
+        } else {
+            self.segment_at(level, bounds, position)
+                .and_then(|segment| self.root.get(level)?.get(&segment.path))
+                .is_some_and(|node| !node.children.is_empty())
+        };
+
+        if is_drillable {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer<Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let level = self.level(&state.path);
+        let appearance = theme.active(&self.style);
+        let (center_radius, ring_width) = self.radii(bounds);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        let (start, end) = state
+            .sweep
+            .map_or((0.0, 1.0), |sweep| sweep.span(self.easing));
+        let hovered = cursor
+            .position_over(bounds)
+            .filter(|_| state.sweep.is_none())
+            .and_then(|position| self.segment_at(level, bounds, position));
+
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+        let center = Point::new(bounds.width / 2.0, bounds.height / 2.0);
+
+        #[allow(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            clippy::cast_precision_loss
+        )]
+        let ring = |segment: &Segment, from: f32, to: f32| {
+            let inner = center_radius + (segment.path.len() - 1) as f32 * ring_width;
+            let outer = inner + ring_width;
+            // The arcs are traced with one corner per degree.
+            let steps = ((to - from) * 360.0).ceil().max(1.0) as usize;
+            let turn = |step: usize| from + (to - from) * step as f32 / steps as f32;
+
+            Path::new(|builder| {
+                builder.move_to(polar(center, outer, from));
+                for step in 1..=steps {
+                    builder.line_to(polar(center, outer, turn(step)));
+                }
+                for step in (0..=steps).rev() {
+                    builder.line_to(polar(center, inner, turn(step)));
+                }
+                builder.close();
+            })
+        };
+
+        for segment in self.segments(level) {
+            // The segments are mapped onto the span of the transition, and
+            // cut off where they leave the full turn.
+            let from = (start + segment.start * (end - start)).clamp(0.0, 1.0);
+            let to = (start + segment.end * (end - start)).clamp(0.0, 1.0);
+            if to - from <= f32::EPSILON {
+                continue;
+            }
+
+            let path = ring(&segment, from, to);
+            frame.fill(&path, self.color(&appearance, level, &segment));
+            frame.stroke(
+                &path,
+                Stroke::default()
+                    .with_color(appearance.border_color)
+                    .with_width(1.0),
+            );
+        }
+
+        if let Some(segment) = &hovered {
+            frame.stroke(
+                &ring(segment, segment.start, segment.end),
+                Stroke::default()
+                    .with_color(appearance.hovered_border_color)
+                    .with_width(2.0),
+            );
+        }
+
+        frame.fill(
+            &Path::circle(center, center_radius),
+            appearance.center_color,
+        );
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+                renderer.draw(vec![frame.into_geometry()]);
+            });
+        });
+
+        if let Some(node) = self.root.get(level) {
+            renderer.with_layer(bounds, |renderer| {
+                renderer.fill_text(core::text::Text {
+                    content: &node.label,
+                    bounds: Rectangle {
+                        x: bounds.center_x(),
+                        y: bounds.center_y(),
+                        width: center_radius * 2.0,
+                        height: self.text_size * 1.3,
+                    },
+                    size: self.text_size,
+                    line_height: LineHeight::default(),
+                    color: appearance.center_text_color,
+                    font: core::Font::default(),
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                });
+            });
+        }
+
+        let (Some(segment), Some(position)) = (hovered, cursor.position()) else {
+            return;
+        };
+        let Some(node) = self
+            .root
+            .get(level)
+            .and_then(|node| node.get(&segment.path))
+        else {
+            return;
+        };
+
+        let content = format!("{}: {}", node.label, (self.format)(node.total()));
+        chart::tooltip(
+            renderer,
+            &[content],
+            self.text_size,
+            text::Shaping::Advanced,
+            position,
+            bounds,
+            appearance.tooltip_background,
+            appearance.tooltip_text_color,
+        );
+    }
+}
+
+impl<'a, Message, Theme> From<Sunburst<'a, Message, Theme>>
+    for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+{
+    fn from(sunburst: Sunburst<'a, Message, Theme>) -> Self {
+        Element::new(sunburst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HierarchyNode, Sunburst};
+    use crate::test_utils::{Harness, Input};
+    use iced_widget::core::{mouse, Point, Size};
+    use std::time::Duration;
+
+    fn hierarchy() -> HierarchyNode {
+        HierarchyNode::new("Root", 0.0).children([
+            HierarchyNode::new("A", 0.0)
+                .children([HierarchyNode::new("A1", 2.0), HierarchyNode::new("A2", 4.0)]),
+            HierarchyNode::new("B", 2.0),
+        ])
+    }
+
+    #[test]
+    fn sunburst_test() {
+        #[derive(Clone, Debug, PartialEq)]
+        enum Change {
+            Drill(Vec<usize>),
+        }
+
+        let root = hierarchy();
+        let sunburst = Sunburst::new(&root).on_drill(Change::Drill);
+        let mut harness = Harness::new(sunburst, Size::new(200.0, 200.0));
+
+        // A spans the first three quarters of the innermost ring clockwise
+        // from the top, and B the last quarter, which has no children.
+        assert!(harness.click(Point::new(70.0, 70.0)).is_empty());
+        assert_eq!(
+            harness.click(Point::new(140.0, 100.0)),
+            vec![Change::Drill(vec![0])]
+        );
+        assert!(harness.advance(Duration::from_millis(400)).is_empty());
+
+        // A click in the center or a right click drills back up.
+        assert_eq!(
+            harness.click(Point::new(100.0, 100.0)),
+            vec![Change::Drill(vec![])]
+        );
+        assert!(harness.click(Point::new(100.0, 100.0)).is_empty());
+        assert!(harness.click(Point::new(100.0, 160.0)).is_empty());
+        assert_eq!(
+            harness.click(Point::new(140.0, 100.0)),
+            vec![Change::Drill(vec![0])]
+        );
+        assert_eq!(
+            harness.simulate(&[Input::Press(mouse::Button::Right)]),
+            vec![Change::Drill(vec![])]
+        );
+    }
+}
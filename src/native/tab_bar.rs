@@ -6,6 +6,11 @@
 //! *This API requires the following crate features to be activated: `tab_bar`*
 
 pub mod tab_label;
+use crate::core::{
+    badge::{accessible_text, count_text, CountTracker, DEFAULT_MAX_COUNT},
+    i18n,
+    layout_direction::{self, LayoutDirection},
+};
 use crate::graphics::icons;
 
 use iced_widget::{
@@ -15,14 +20,18 @@ use iced_widget::{
         event, layout,
         mouse::{self, Cursor},
         renderer, touch,
-        widget::Tree,
-        Alignment, Clipboard, Color, Element, Event, Layout, Length, Rectangle, Shell, Widget,
+        widget::{
+            tree::{State as TreeState, Tag},
+            Tree,
+        },
+        window, Alignment, Clipboard, Color, Element, Event, Layout, Length, Rectangle, Shell,
+        Widget,
     },
     runtime::Font,
     text::{self, LineHeight},
     Column, Row, Text,
 };
-use std::marker::PhantomData;
+use std::{marker::PhantomData, time::Instant};
 
 pub use crate::style::tab_bar::{Appearance, StyleSheet};
 pub use tab_label::TabLabel;
@@ -37,6 +46,10 @@ const DEFAULT_CLOSE_SIZE: f32 = 16.0;
 const DEFAULT_PADDING: f32 = 5.0;
 /// The default spacing around the tabs.
 const DEFAULT_SPACING: f32 = 0.0;
+/// The ratio between the text size of the count badges and of the labels.
+const BADGE_TEXT_RATIO: f32 = 0.75;
+/// How far the ring played when a count changes grows around its badge.
+const BADGE_PULSE_SPREAD: f32 = 4.0;
 
 /// A tab bar to show tabs.
 ///
@@ -81,6 +94,12 @@ where
     on_select: Box<dyn Fn(TabId) -> Message>,
     /// The function that produces the message when the close icon was pressed.
     on_close: Option<Box<dyn Fn(TabId) -> Message>>,
+    /// The vector containing the counts shown on the badges of the tabs.
+    badges: Vec<Option<usize>>,
+    /// The singular and plural nouns describing the counts of the badges.
+    badge_label: (String, String),
+    /// The function that produces the message announcing a changed count.
+    on_announce: Option<Box<dyn Fn(TabId, String) -> Message>>,
     /// The width of the [`TabBar`].
     width: Length,
     /// The width of the tabs of the [`TabBar`].
@@ -107,6 +126,8 @@ where
     style: <Renderer::Theme as StyleSheet>::Style,
     /// Where the icon is placed relative to text
     position: Position,
+    /// The [`LayoutDirection`] the tabs are ordered in.
+    direction: LayoutDirection,
     #[allow(clippy::missing_docs_in_private_items)]
     _renderer: PhantomData<Renderer>,
 }
@@ -156,12 +177,17 @@ where
     where
         F: 'static + Fn(TabId) -> Message,
     {
+        let translations = i18n::get();
+
         Self {
             active_tab: 0,
+            badges: vec![None; tab_labels.len()],
             tab_indices: tab_labels.iter().map(|(id, _)| id.clone()).collect(),
             tab_labels: tab_labels.into_iter().map(|(_, label)| label).collect(),
             on_select: Box::new(on_select),
             on_close: None,
+            badge_label: (translations.item(), translations.items()),
+            on_announce: None,
             width: Length::Fill,
             tab_width: Length::Fill,
             height: Length::Shrink,
@@ -175,10 +201,44 @@ where
             text_font: None,
             style: <Renderer::Theme as StyleSheet>::Style::default(),
             position: Position::default(),
+            direction: layout_direction::get(),
             _renderer: PhantomData,
         }
     }
 
+    /// Sets the count shown on the badge of the tab with the given id.
+    ///
+    /// The badge shows `99+` when the count overflows, and plays a short
+    /// animation and announces the count when it changes.
+    #[must_use]
+    pub fn badge(mut self, id: &TabId, count: usize) -> Self {
+        if let Some(index) = self.tab_indices.iter().position(|tab_id| tab_id == id) {
+            self.badges[index] = Some(count);
+        }
+        self
+    }
+
+    /// Sets the singular and plural nouns describing the counts of the
+    /// badges to assistive technologies, e.g. `"unread message"` and
+    /// `"unread messages"`.
+    #[must_use]
+    pub fn badge_label(mut self, singular: impl Into<String>, plural: impl Into<String>) -> Self {
+        self.badge_label = (singular.into(), plural.into());
+        self
+    }
+
+    /// Sets the message that will be produced when the count of the badge of
+    /// a tab changes, with the text announcing it to assistive technologies,
+    /// e.g. `"3 unread messages"`.
+    #[must_use]
+    pub fn on_announce<F>(mut self, on_announce: F) -> Self
+    where
+        F: 'static + Fn(TabId, String) -> Message,
+    {
+        self.on_announce = Some(Box::new(on_announce));
+        self
+    }
+
     /// Sets the size of the close icon of the
     /// [`TabLabel`](crate::tab_bar::TabLabel)s of the [`TabBar`].
     #[must_use]
@@ -265,6 +325,7 @@ where
     pub fn push(mut self, id: TabId, tab_label: TabLabel) -> Self {
         self.tab_labels.push(tab_label);
         self.tab_indices.push(id);
+        self.badges.push(None);
         self
     }
 
@@ -336,12 +397,27 @@ where
     }
 }
 
+/// The state of a [`TabBar`].
+#[derive(Debug, Default)]
+struct State {
+    /// The trackers of the counts of the badges of the tabs.
+    badges: Vec<CountTracker>,
+}
+
 impl<Message, TabId, Renderer> Widget<Message, Renderer> for TabBar<Message, TabId, Renderer>
 where
     Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
     Renderer::Theme: StyleSheet + text::StyleSheet,
     TabId: Eq + Clone,
 {
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
     fn width(&self) -> Length {
         self.width
     }
@@ -377,7 +453,8 @@ where
                 .vertical_alignment(alignment::Vertical::Center)
         }
 
-        self.tab_labels
+        let row = self
+            .tab_labels
             .iter()
             .fold(Row::<Message, Renderer>::new(), |row, tab_label| {
                 let mut label_row = Row::new()
@@ -471,12 +548,28 @@ where
             .width(self.width)
             .height(self.height)
             .spacing(self.spacing)
-            .layout(renderer, &limits.loose())
+            .layout(renderer, &limits.loose());
+
+        if !self.direction.is_right_to_left() {
+            return row;
+        }
+
+        // The close buttons are moved to the start of their tabs as well.
+        let mut tabs = layout::Node::with_children(
+            row.size(),
+            row.children()
+                .iter()
+                .map(|tab| self.direction.arrange(tab.clone()))
+                .collect(),
+        );
+        tabs.move_to(row.bounds().position());
+
+        self.direction.arrange(tabs)
     }
 
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        state: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: Cursor,
@@ -485,6 +578,42 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        // The badges are only tracked if the tree holds the state of the
+        // tab bar, which wrapping widgets may not provide.
+        if let (Event::Window(window::Event::RedrawRequested(now)), true) =
+            (&event, state.tag == Tag::of::<State>())
+        {
+            let state = state.state.downcast_mut::<State>();
+            state
+                .badges
+                .resize(self.badges.len(), CountTracker::default());
+
+            for ((tracker, count), id) in state
+                .badges
+                .iter_mut()
+                .zip(&self.badges)
+                .zip(&self.tab_indices)
+            {
+                let Some(count) = *count else {
+                    continue;
+                };
+
+                if tracker.update(count, *now) {
+                    if let Some(on_announce) = &self.on_announce {
+                        let (singular, plural) = &self.badge_label;
+                        shell.publish(on_announce(
+                            id.clone(),
+                            accessible_text(count, singular, plural),
+                        ));
+                    }
+                }
+
+                if tracker.pulse(*now).is_some() {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
@@ -556,7 +685,7 @@ where
 
     fn draw(
         &self,
-        _state: &Tree,
+        state: &Tree,
         renderer: &mut Renderer,
         theme: &Renderer::Theme,
         _style: &renderer::Style,
@@ -585,7 +714,16 @@ where
                 .unwrap_or_else(|| Color::TRANSPARENT.into()),
         );
 
+        let trackers = if state.tag == Tag::of::<State>() {
+            state.state.downcast_ref::<State>().badges.as_slice()
+        } else {
+            &[]
+        };
+        let now = Instant::now();
+
         for ((i, tab), layout) in self.tab_labels.iter().enumerate().zip(children) {
+            let is_selected = i == self.get_active_tab_idx();
+
             draw_tab(
                 renderer,
                 tab,
@@ -593,16 +731,118 @@ where
                 self.position,
                 theme,
                 &self.style,
-                i == self.get_active_tab_idx(),
+                is_selected,
                 cursor,
                 (self.icon_font.unwrap_or(icons::ICON_FONT), self.icon_size),
                 (self.text_font.unwrap_or_default(), self.text_size),
                 self.close_size,
             );
+
+            if let Some(count) = self.badges[i] {
+                let appearance = if cursor.is_over(layout.bounds()) {
+                    theme.hovered(&self.style, is_selected)
+                } else {
+                    theme.active(&self.style, is_selected)
+                };
+
+                draw_badge(
+                    renderer,
+                    count,
+                    layout.bounds(),
+                    &appearance,
+                    (self.text_font.unwrap_or_default(), self.text_size),
+                    trackers.get(i).and_then(|tracker| tracker.pulse(now)),
+                    self.direction,
+                );
+            }
         }
     }
 }
 
+/// Draws the count badge in the top corner of a tab at the end of the
+/// [`LayoutDirection`].
+fn draw_badge<Renderer>(
+    renderer: &mut Renderer,
+    count: usize,
+    tab_bounds: Rectangle,
+    appearance: &Appearance,
+    text_data: (Font, f32),
+    pulse: Option<f32>,
+    direction: LayoutDirection,
+) where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+{
+    let content = count_text(count, DEFAULT_MAX_COUNT);
+    let size = text_data.1 * BADGE_TEXT_RATIO;
+    let height = size * 1.4;
+    let width = (renderer.measure_width(&content, size, text_data.0, text::Shaping::Basic)
+        + height * 0.6)
+        .max(height);
+
+    let bounds = Rectangle {
+        x: if direction.is_right_to_left() {
+            tab_bounds.x + 2.0
+        } else {
+            tab_bounds.x + tab_bounds.width - width - 2.0
+        },
+        y: tab_bounds.y + 2.0,
+        width,
+        height,
+    };
+
+    if let Some(progress) = pulse {
+        let spread = BADGE_PULSE_SPREAD * progress;
+        let color = match appearance.badge_background {
+            core::Background::Color(color) => color,
+            core::Background::Gradient(_) => appearance.badge_text_color,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x - spread,
+                    y: bounds.y - spread,
+                    width: bounds.width + spread * 2.0,
+                    height: bounds.height + spread * 2.0,
+                },
+                border_radius: (height / 2.0 + spread).into(),
+                border_width: 2.0,
+                border_color: Color {
+                    a: color.a * (1.0 - progress),
+                    ..color
+                },
+            },
+            Color::TRANSPARENT,
+        );
+    }
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border_radius: (height / 2.0).into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        },
+        appearance.badge_background,
+    );
+
+    renderer.fill_text(core::text::Text {
+        content: &content,
+        bounds: Rectangle {
+            x: bounds.center_x(),
+            y: bounds.center_y(),
+            ..bounds
+        },
+        size,
+        color: appearance.badge_text_color,
+        font: text_data.0,
+        horizontal_alignment: Horizontal::Center,
+        vertical_alignment: Vertical::Center,
+        line_height: LineHeight::default(),
+        shaping: text::Shaping::Basic,
+    });
+}
+
 /// Draws a tab.
 #[allow(
     clippy::borrowed_box,
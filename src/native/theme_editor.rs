@@ -0,0 +1,346 @@
+//! Use a theme editor to edit and export a palette.
+//!
+//! *This API requires the following crate features to be activated: `theme_editor`*
+
+use crate::core::{color::HexString, i18n};
+use crate::native::color_picker::{self, ColorPicker};
+use crate::native::helpers::Styled;
+
+use iced_widget::{
+    button,
+    core::{self, renderer, Alignment, Color, Element, Length, Rectangle},
+    renderer::Renderer,
+    style::theme::palette::{Extended, Palette},
+    text, text_input, Button, Column, Row, Space, Text,
+};
+use std::rc::Rc;
+
+pub use crate::core::palette::{export, Export, PaletteColor};
+pub use crate::style::theme_editor::{Appearance, StyleSheet};
+
+/// The padding around the [`ThemeEditor`].
+const PADDING: f32 = 16.0;
+/// The spacing between the elements.
+const SPACING: f32 = 12.0;
+/// The size of a swatch.
+const SWATCH_SIZE: f32 = 28.0;
+/// The width of the names of the colors.
+const NAME_WIDTH: f32 = 100.0;
+
+/// The function producing the message when the palette is exported.
+type ExportFn<'a, Message> = Box<dyn Fn(Export, String) -> Message + 'a>;
+
+/// An editor of the colors of a [`Palette`].
+///
+/// Every color of the [`Palette`] is shown in a row with a swatch opening a
+/// [`ColorPicker`] when pressed, and with a preview of the weak, base and
+/// strong shades generated from it by the [`Extended`] palette. The
+/// [`Palette`] can be exported as Rust code or as TOML.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::theme_editor::{PaletteColor, ThemeEditor};
+/// # use iced::theme::Palette;
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Edit(Option<PaletteColor>),
+///     Changed(Palette),
+/// }
+///
+/// let theme_editor = ThemeEditor::new(
+///     Palette::LIGHT,
+///     None,
+///     Message::Edit,
+///     Message::Changed,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ThemeEditor<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The edited palette.
+    palette: Palette,
+    /// The color being picked.
+    editing: Option<PaletteColor>,
+    /// The function producing the message when a swatch is pressed, or when
+    /// picking a color is canceled.
+    on_edit: Rc<dyn Fn(Option<PaletteColor>) -> Message>,
+    /// The function producing the message when a color is picked.
+    on_change: Rc<dyn Fn(Palette) -> Message>,
+    /// The function producing the message when the palette is exported.
+    on_export: Option<ExportFn<'a, Message>>,
+    /// The text size of the [`ThemeEditor`].
+    text_size: f32,
+    /// The style of the [`ThemeEditor`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> ThemeEditor<'a, Message, Theme>
+where
+    Message: 'static + Clone,
+    Theme: 'a
+        + StyleSheet
+        + color_picker::StyleSheet
+        + button::StyleSheet
+        + text::StyleSheet
+        + text_input::StyleSheet,
+{
+    /// Creates a new [`ThemeEditor`] of the given palette.
+    ///
+    /// It expects:
+    ///     * the edited palette, e.g. `theme.palette()`.
+    ///     * the color being picked, if any.
+    ///     * the function producing the message with the color when its
+    ///       swatch is pressed, or with `None` when picking it is canceled.
+    ///     * the function producing the message with the new palette when a
+    ///       color is picked.
+    pub fn new<E, F>(
+        palette: Palette,
+        editing: Option<PaletteColor>,
+        on_edit: E,
+        on_change: F,
+    ) -> Self
+    where
+        E: 'static + Fn(Option<PaletteColor>) -> Message,
+        F: 'static + Fn(Palette) -> Message,
+    {
+        Self {
+            palette,
+            editing,
+            on_edit: Rc::new(on_edit),
+            on_change: Rc::new(on_change),
+            on_export: None,
+            text_size: 16.0,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the function producing the message with the exported palette,
+    /// showing the export buttons of the [`ThemeEditor`].
+    #[must_use]
+    pub fn on_export<F>(mut self, on_export: F) -> Self
+    where
+        F: 'a + Fn(Export, String) -> Message,
+    {
+        self.on_export = Some(Box::new(on_export));
+        self
+    }
+
+    /// Sets the text size of the [`ThemeEditor`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the style of the [`ThemeEditor`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Wraps the given element to draw it with the [`Appearance`].
+    fn styled(
+        &self,
+        content: impl Into<Element<'a, Message, Renderer<Theme>>>,
+        role: Role,
+    ) -> Element<'a, Message, Renderer<Theme>> {
+        let style = self.style.clone();
+
+        Styled::new(
+            content,
+            move |renderer, theme: &Theme, _defaults, bounds, _cursor| {
+                role.draw(renderer, &StyleSheet::active(theme, &style), bounds)
+            },
+        )
+        .into()
+    }
+
+    /// Creates a swatch of the given color.
+    fn swatch(&self, color: Color) -> Element<'a, Message, Renderer<Theme>> {
+        self.styled(Space::new(SWATCH_SIZE, SWATCH_SIZE), Role::Swatch(color))
+    }
+
+    /// Creates the row of the given color of the palette.
+    fn row(
+        &self,
+        entry: PaletteColor,
+        extended: &Extended,
+    ) -> Element<'a, Message, Renderer<Theme>> {
+        let color = entry.get(&self.palette);
+
+        let (palette, on_edit, on_change) = (
+            self.palette,
+            Rc::clone(&self.on_edit),
+            Rc::clone(&self.on_change),
+        );
+
+        let picker = ColorPicker::new(
+            self.editing == Some(entry),
+            color,
+            Button::new(
+                Row::new()
+                    .spacing(SPACING / 2.0)
+                    .align_items(Alignment::Center)
+                    .push(self.swatch(color))
+                    .push(Text::new(color.as_hex_string()).size(self.text_size)),
+            )
+            .on_press((self.on_edit)(Some(entry))),
+            on_edit(None),
+            move |color| {
+                let mut palette = palette;
+                entry.set(&mut palette, color);
+                on_change(palette)
+            },
+        );
+
+        let shades = entry
+            .shades(extended)
+            .into_iter()
+            .fold(Row::new().spacing(SPACING / 4.0), |shades, shade| {
+                shades.push(self.swatch(shade))
+            });
+
+        Row::new()
+            .spacing(SPACING)
+            .align_items(Alignment::Center)
+            .push(
+                self.styled(
+                    Text::new(entry.name())
+                        .size(self.text_size)
+                        .width(NAME_WIDTH),
+                    Role::Label,
+                ),
+            )
+            .push(picker)
+            .push(Space::with_width(Length::Fill))
+            .push(shades)
+            .into()
+    }
+}
+
+impl<'a, Message, Theme> From<ThemeEditor<'a, Message, Theme>>
+    for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'static + Clone,
+    Theme: 'a
+        + StyleSheet
+        + color_picker::StyleSheet
+        + button::StyleSheet
+        + text::StyleSheet
+        + text_input::StyleSheet,
+{
+    fn from(editor: ThemeEditor<'a, Message, Theme>) -> Self {
+        let extended = Extended::generate(editor.palette);
+
+        let mut content = PaletteColor::ALL.into_iter().fold(
+            Column::new().padding(PADDING).spacing(SPACING),
+            |content, entry| content.push(editor.row(entry, &extended)),
+        );
+
+        if let Some(on_export) = &editor.on_export {
+            let translations = i18n::get();
+            let button = |label: &str, format| {
+                Button::new(Text::new(label.to_owned()).size(editor.text_size))
+                    .on_press(on_export(format, export(&editor.palette, format)))
+            };
+
+            content = content.push(
+                Row::new()
+                    .spacing(SPACING)
+                    .push(Space::with_width(Length::Fill))
+                    .push(button(&translations.export_rust(), Export::Rust))
+                    .push(button(&translations.export_toml(), Export::Toml)),
+            );
+        }
+
+        editor.styled(content, Role::Frame)
+    }
+}
+
+/// How a [`Styled`] element is drawn with the [`Appearance`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Role {
+    /// The background of the [`ThemeEditor`].
+    Frame,
+    /// A text drawn with the text color.
+    Label,
+    /// A swatch of the given color.
+    Swatch(Color),
+}
+
+impl Role {
+    /// Draws the background of an element with the [`Role`], returning the
+    /// color of its text.
+    fn draw<Renderer: core::Renderer>(
+        self,
+        renderer: &mut Renderer,
+        appearance: &Appearance,
+        bounds: Rectangle,
+    ) -> Color {
+        let quad = match self {
+            Self::Frame => Some((
+                appearance.background,
+                appearance.border_radius,
+                appearance.border_width,
+                appearance.border_color,
+            )),
+            Self::Label => None,
+            Self::Swatch(color) => Some((
+                color.into(),
+                appearance.swatch_border_radius,
+                1.0,
+                appearance.swatch_border_color,
+            )),
+        };
+
+        if let Some((background, border_radius, border_width, border_color)) = quad {
+            core::Renderer::fill_quad(
+                renderer,
+                renderer::Quad {
+                    bounds,
+                    border_radius: border_radius.into(),
+                    border_width,
+                    border_color,
+                },
+                background,
+            );
+        }
+
+        appearance.text_color
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PaletteColor, ThemeEditor};
+    use crate::test_utils::Harness;
+    use iced_widget::{
+        core::{Point, Size},
+        style::theme::Palette,
+    };
+
+    #[test]
+    fn theme_editor_picker_test() {
+        let editor = |editing| ThemeEditor::new(Palette::LIGHT, editing, Err, Ok);
+        let size = Size::new(600.0, 400.0);
+
+        let mut harness = Harness::new(editor(None), size);
+        let closed = harness.snapshot();
+        assert_eq!(
+            harness.click(Point::new(147.0, 134.0)),
+            vec![Err(Some(PaletteColor::Primary))]
+        );
+
+        // The picker of the edited color opens and submits the palette.
+        let mut harness = Harness::new(editor(Some(PaletteColor::Primary)), size);
+        assert!(harness.snapshot().diff(&closed, 8) > Some(10_000));
+        assert_eq!(
+            harness.click(Point::new(521.0, 295.0)),
+            vec![Ok(Palette::LIGHT)]
+        );
+    }
+}
@@ -2,7 +2,8 @@
 //!
 //! *This API requires the following crate features to be activated: `time_picker`*
 
-use super::overlay::time_picker::{self, TimePickerOverlay, TimePickerOverlayButtons};
+use super::overlay::time_picker::{self, Bound, TimePickerOverlay, TimePickerOverlayButtons};
+use crate::core::lens::{Lens, Update};
 
 use chrono::Local;
 use iced_widget::{
@@ -63,7 +64,9 @@ where
     /// The message that is send if the cancel button of the [`TimePickerOverlay`] is pressed.
     on_cancel: Message,
     /// The function that produces a message when the submit button of the [`TimePickerOverlay`] is pressed.
-    on_submit: Box<dyn Fn(Time) -> Message>,
+    on_submit: OnSubmit<Message>,
+    /// The initially picked range if a range of times is picked.
+    range: Option<(Time, Time)>,
     /// The style of the [`TimePickerOverlay`].
     style: <Theme as StyleSheet>::Style,
     /// The buttons of the overlay.
@@ -72,6 +75,8 @@ where
     use_24h: bool,
     /// Toggle the use of the seconds of the [`TimePickerOverlay`].
     show_seconds: bool,
+    /// The interval of the minutes the [`TimePickerOverlay`] snaps to.
+    minute_step: u8,
 }
 
 impl<'a, Message, Theme> TimePicker<'a, Message, Theme>
@@ -105,11 +110,106 @@ where
             time: time.into(),
             underlay: underlay.into(),
             on_cancel,
-            on_submit: Box::new(on_submit),
+            on_submit: OnSubmit::Single(Box::new(on_submit)),
+            range: None,
             style: <Theme as StyleSheet>::Style::default(),
             overlay_state: TimePickerOverlayButtons::default().into(),
             use_24h: false,
             show_seconds: false,
+            minute_step: 1,
+        }
+    }
+
+    /// Creates a new [`TimePicker`] bound to the time of the model through a
+    /// [`Lens`], wrapping around the given underlay.
+    ///
+    /// Instead of the picked time, the [`TimePicker`] submits an [`Update`]
+    /// setting it in the model, so the fields of a form can share a single
+    /// message.
+    ///
+    /// # Example
+    /// ```ignore
+    /// # use iced_aw::{time_picker::Time, lens::{lens, Update}, TimePicker};
+    /// #
+    /// struct Settings {
+    ///     alarm: Time,
+    /// }
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum Message {
+    ///     Cancel,
+    ///     Edit(Update<Settings>),
+    /// }
+    ///
+    /// let settings = Settings { alarm: Time::now_hm(true) };
+    /// let alarm = lens(|settings: &Settings| settings.alarm, |settings, alarm| settings.alarm = alarm);
+    ///
+    /// let picker = TimePicker::bind(true, &settings, alarm, "Underlay", Message::Cancel, Message::Edit);
+    /// ```
+    pub fn bind<U, Model, L, F>(
+        show_picker: bool,
+        model: &Model,
+        lens: L,
+        underlay: U,
+        on_cancel: Message,
+        on_update: F,
+    ) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer<Theme>>>,
+        Model: 'static,
+        L: 'static + Lens<Model, Value = Time> + Copy + Send + Sync,
+        F: 'static + Fn(Update<Model>) -> Message,
+    {
+        Self::new(
+            show_picker,
+            lens.get(model),
+            underlay,
+            on_cancel,
+            move |time| on_update(Update::set(lens, time)),
+        )
+    }
+
+    /// Creates a new [`TimePicker`] for picking a range of times wrapping
+    /// around the given underlay.
+    ///
+    /// The overlay switches between editing the start and the end of the
+    /// range, which is shown as an arc on the clock. The end may be before
+    /// the start for a range wrapping past midnight, but not the same time,
+    /// which can't be submitted.
+    ///
+    /// It expects:
+    ///     * if the overlay of the time picker is visible.
+    ///     * the start and the end of the initially picked range.
+    ///     * the underlay [`Element`] on which this [`TimePicker`]
+    ///         will be wrapped around.
+    ///     * a message that will be send when the cancel button of the [`TimePicker`] is pressed.
+    ///     * a function that will be called when the submit button of the [`TimePicker`]
+    ///         is pressed, which takes the start and the end of the picked range.
+    pub fn new_range<U, F>(
+        show_picker: bool,
+        range: (impl Into<Time>, impl Into<Time>),
+        underlay: U,
+        on_cancel: Message,
+        on_submit: F,
+    ) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer<Theme>>>,
+        F: 'static + Fn(Time, Time) -> Message,
+    {
+        let (start, end) = (range.0.into(), range.1.into());
+
+        Self {
+            show_picker,
+            time: start,
+            underlay: underlay.into(),
+            on_cancel,
+            on_submit: OnSubmit::Range(Box::new(on_submit)),
+            range: Some((start, end)),
+            style: <Theme as StyleSheet>::Style::default(),
+            overlay_state: TimePickerOverlayButtons::default().into(),
+            use_24h: false,
+            show_seconds: false,
+            minute_step: 1,
         }
     }
 
@@ -120,6 +220,16 @@ where
         self
     }
 
+    /// Sets the interval of the minutes the [`TimePicker`] snaps to, e.g. 5,
+    /// 10 or 15 minutes, when dragging the clock or using the spinners.
+    ///
+    /// The picked time is rounded to the nearest minute of the interval.
+    #[must_use]
+    pub fn minute_step(mut self, minute_step: u8) -> Self {
+        self.minute_step = minute_step.clamp(1, 60);
+        self
+    }
+
     /// Sets the style of the [`TimePicker`].
     #[must_use]
     pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
@@ -135,6 +245,30 @@ where
     }
 }
 
+impl<Message, Theme> TimePicker<'_, Message, Theme>
+where
+    Message: Clone,
+    Theme: StyleSheet + button::StyleSheet,
+{
+    /// Resets the picked range of the given state to the initially picked one.
+    fn reset_range(&self, state: &mut State) {
+        if let Some((start, end)) = self.range {
+            state.overlay_state.time = start.into();
+            state.overlay_state.other_bound = Some(end.into());
+            state.overlay_state.bound = Bound::Start;
+        }
+    }
+}
+
+/// The function producing the message when the picked time of a [`TimePicker`] is submitted.
+#[allow(missing_debug_implementations)]
+pub enum OnSubmit<Message> {
+    /// A single time is picked.
+    Single(Box<dyn Fn(Time) -> Message>),
+    /// A range of times is picked by its start and end.
+    Range(Box<dyn Fn(Time, Time) -> Message>),
+}
+
 /// The state of the [`TimePicker`] / [`TimePickerOverlay`].
 #[derive(Debug)]
 pub struct State {
@@ -178,7 +312,10 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::new(self.time))
+        let mut state = State::new(self.time);
+        self.reset_range(&mut state);
+
+        tree::State::new(state)
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -186,6 +323,11 @@ where
     }
 
     fn diff(&self, tree: &mut Tree) {
+        // Discard the picked range of a cancelled picker while it is hidden.
+        if !self.show_picker {
+            self.reset_range(tree.state.downcast_mut());
+        }
+
         tree.diff_children(&[&self.underlay, &self.overlay_state]);
     }
 
@@ -277,6 +419,12 @@ where
                 .overlay(&mut state.children[0], layout, renderer);
         }
 
+        let overlay_state = &mut picker_state.overlay_state;
+        overlay_state.use_24h = self.use_24h;
+        overlay_state.show_seconds = self.show_seconds;
+        overlay_state.minute_step = u32::from(self.minute_step);
+        overlay_state.snap_minutes();
+
         let bounds = layout.bounds();
         let position = Point::new(bounds.center_x(), bounds.center_y());
 
@@ -304,3 +452,87 @@ where
         Element::new(time_picker)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::TimePicker;
+    use crate::test_utils::Harness;
+    use chrono::NaiveTime;
+    use iced_widget::{
+        core::{keyboard::KeyCode, Point, Size},
+        text,
+    };
+
+    #[test]
+    fn time_picker_range_test() {
+        let time = |hour, minute| NaiveTime::from_hms_opt(hour, minute, 0).expect("Valid time");
+        let time_picker = TimePicker::new_range(
+            true,
+            (time(22, 0), time(6, 0)),
+            text("Underlay"),
+            None,
+            |start, end| Some((NaiveTime::from(start), NaiveTime::from(end))),
+        )
+        .use_24h();
+        let mut harness = Harness::new(time_picker, Size::new(400.0, 400.0));
+
+        // The arc on the clock runs clockwise from 22:00 to 06:00, passing
+        // 03:00 on the right but not 21:00 on the left.
+        let snapshot = harness.snapshot();
+        assert_ne!(snapshot.color(238, 200), snapshot.color(82, 200));
+
+        // The range wraps past midnight.
+        let submit = Point::new(237.0, 345.0);
+        assert_eq!(harness.click(submit), vec![Some((time(22, 0), time(6, 0)))]);
+
+        // The end tab is selected and its minutes stepped up.
+        let _ = harness.click(Point::new(237.0, 25.0));
+        let _ = harness.click(Point::new(176.0, 269.0));
+        assert_eq!(harness.click(submit), vec![Some((time(22, 0), time(6, 1)))]);
+
+        // An empty range is not submitted.
+        let empty = TimePicker::new_range(
+            true,
+            (time(8, 0), time(8, 0)),
+            text("Underlay"),
+            None,
+            |start, end| Some((NaiveTime::from(start), NaiveTime::from(end))),
+        );
+        let mut harness = Harness::new(empty, Size::new(400.0, 400.0));
+        assert!(harness.click(submit).is_empty());
+    }
+
+    #[test]
+    fn time_picker_typing_test() {
+        let time = |hour, minute| NaiveTime::from_hms_opt(hour, minute, 0).expect("Valid time");
+        let time_picker = TimePicker::new(true, time(10, 15), text("Underlay"), None, |picked| {
+            Some(NaiveTime::from(picked))
+        });
+        let mut harness = Harness::new(time_picker, Size::new(400.0, 400.0));
+        let submit = Point::new(237.0, 345.0);
+
+        // The hour starts with a 0, so the 9 completes it and the focus moves
+        // on to the minutes and then to the period.
+        let _ = harness.type_text("0930p");
+        assert_eq!(harness.click(submit), vec![Some(time(21, 30))]);
+
+        // A `:` moves on to the minutes before a second digit of the hour.
+        let time_picker = TimePicker::new(true, time(10, 15), text("Underlay"), None, |picked| {
+            Some(NaiveTime::from(picked))
+        })
+        .use_24h()
+        .minute_step(5);
+        let mut harness = Harness::new(time_picker, Size::new(400.0, 400.0));
+        let _ = harness.type_text("1:0");
+        assert_eq!(harness.click(submit), vec![Some(time(1, 0))]);
+
+        // Typed minutes snap to the interval, the arrow keys step from them.
+        let _ = harness.press_key(KeyCode::Tab);
+        let _ = harness.press_key(KeyCode::Tab);
+        let _ = harness.press_key(KeyCode::Tab);
+        let _ = harness.press_key(KeyCode::Tab);
+        let _ = harness.type_text("2338");
+        let _ = harness.press_key(KeyCode::Up);
+        assert_eq!(harness.click(submit), vec![Some(time(23, 45))]);
+    }
+}
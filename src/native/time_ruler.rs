@@ -0,0 +1,415 @@
+//! Use a time ruler to display the tick marks of a time range.
+//!
+//! *This API requires the following crate features to be activated: `time_ruler`*
+use crate::style::time_ruler::StyleSheet;
+
+use iced_widget::{
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        layout::{Limits, Node},
+        mouse::Cursor,
+        renderer, text,
+        widget::Tree,
+        Color, Element, Layout, Length, Point, Rectangle, Size, Widget,
+    },
+    text::LineHeight,
+};
+
+/// The number of seconds in a minute.
+const MINUTE: f64 = 60.0;
+/// The number of seconds in an hour.
+const HOUR: f64 = 60.0 * MINUTE;
+/// The number of seconds in a day.
+const DAY: f64 = 24.0 * HOUR;
+
+/// The steps between the major tick marks, with the number of minor
+/// subdivisions of each step.
+const STEPS: [(f64, TimeUnit, u32); 27] = [
+    (0.01, TimeUnit::Seconds, 5),
+    (0.02, TimeUnit::Seconds, 2),
+    (0.05, TimeUnit::Seconds, 5),
+    (0.1, TimeUnit::Seconds, 5),
+    (0.2, TimeUnit::Seconds, 2),
+    (0.5, TimeUnit::Seconds, 5),
+    (1.0, TimeUnit::Seconds, 5),
+    (2.0, TimeUnit::Seconds, 2),
+    (5.0, TimeUnit::Seconds, 5),
+    (10.0, TimeUnit::Seconds, 2),
+    (15.0, TimeUnit::Seconds, 3),
+    (30.0, TimeUnit::Seconds, 3),
+    (MINUTE, TimeUnit::Minutes, 6),
+    (2.0 * MINUTE, TimeUnit::Minutes, 2),
+    (5.0 * MINUTE, TimeUnit::Minutes, 5),
+    (10.0 * MINUTE, TimeUnit::Minutes, 2),
+    (15.0 * MINUTE, TimeUnit::Minutes, 3),
+    (30.0 * MINUTE, TimeUnit::Minutes, 3),
+    (HOUR, TimeUnit::Hours, 4),
+    (2.0 * HOUR, TimeUnit::Hours, 2),
+    (3.0 * HOUR, TimeUnit::Hours, 3),
+    (6.0 * HOUR, TimeUnit::Hours, 6),
+    (12.0 * HOUR, TimeUnit::Hours, 2),
+    (DAY, TimeUnit::Days, 4),
+    (2.0 * DAY, TimeUnit::Days, 2),
+    (7.0 * DAY, TimeUnit::Days, 7),
+    (14.0 * DAY, TimeUnit::Days, 2),
+];
+
+/// The unit the labels of a [`TimeRuler`] are shown in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeUnit {
+    /// The ticks are seconds or fractions of seconds apart.
+    Seconds,
+    /// The ticks are minutes apart.
+    Minutes,
+    /// The ticks are hours apart.
+    Hours,
+    /// The ticks are days apart.
+    Days,
+}
+
+/// The distance between the tick marks of a [`TimeRuler`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TickStep {
+    /// The number of seconds between two major tick marks.
+    pub seconds: f64,
+    /// The unit of the step.
+    pub unit: TimeUnit,
+    /// The number of minor subdivisions between two major tick marks.
+    pub subdivisions: u32,
+}
+
+impl TickStep {
+    /// Chooses the smallest step keeping the major tick marks at least
+    /// `min_spacing` pixels apart when `seconds_per_pixel` seconds are
+    /// shown per pixel.
+    #[must_use]
+    pub fn for_zoom(seconds_per_pixel: f64, min_spacing: f32) -> Self {
+        let min_seconds = seconds_per_pixel * f64::from(min_spacing);
+
+        if let Some(&(seconds, unit, subdivisions)) =
+            STEPS.iter().find(|(seconds, _, _)| *seconds >= min_seconds)
+        {
+            return Self {
+                seconds,
+                unit,
+                subdivisions,
+            };
+        }
+
+        // Beyond the table, keep doubling the number of weeks.
+        let doublings = (min_seconds / (28.0 * DAY)).log2().ceil();
+
+        Self {
+            seconds: 28.0 * DAY * doublings.exp2(),
+            unit: TimeUnit::Days,
+            subdivisions: 4,
+        }
+    }
+
+    /// Returns the positions of the major tick marks inside of the range from
+    /// `start` to `end`.
+    pub fn ticks(self, start: f64, end: f64) -> impl Iterator<Item = f64> {
+        let first = (start / self.seconds).ceil();
+
+        (0..)
+            .map(move |i| (first + f64::from(i)) * self.seconds)
+            .take_while(move |time| *time <= end)
+    }
+}
+
+/// A horizontal ruler showing tick marks and labels for a time range.
+///
+/// The distance between the tick marks adapts to the zoom, switching from
+/// seconds to minutes, hours and days when zooming out.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::TimeRuler;
+/// #
+/// // Show the first two hours.
+/// let ruler = TimeRuler::new(0.0, 7200.0);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TimeRuler<'a, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The start of the time range in seconds.
+    start: f64,
+    /// The end of the time range in seconds.
+    end: f64,
+    /// The width of the [`TimeRuler`].
+    width: Length,
+    /// The height of the [`TimeRuler`].
+    height: f32,
+    /// The minimum distance between two major tick marks.
+    min_spacing: f32,
+    /// The text size of the labels.
+    text_size: f32,
+    /// The font of the labels.
+    font: Renderer::Font,
+    /// The function formatting the labels of the major tick marks.
+    format: Option<Box<dyn Fn(f64, TickStep) -> String + 'a>>,
+    /// The style of the [`TimeRuler`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Renderer> TimeRuler<'a, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`TimeRuler`] showing the time range from `start` to
+    /// `end` in seconds.
+    #[must_use]
+    pub fn new(start: f64, end: f64) -> Self {
+        Self {
+            start,
+            end,
+            width: Length::Fill,
+            height: 30.0,
+            min_spacing: 80.0,
+            text_size: 12.0,
+            font: core::Font::default(),
+            format: None,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`TimeRuler`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`TimeRuler`].
+    #[must_use]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the minimum distance between two major tick marks of the [`TimeRuler`].
+    #[must_use]
+    pub fn min_spacing(mut self, min_spacing: f32) -> Self {
+        self.min_spacing = min_spacing;
+        self
+    }
+
+    /// Sets the text size of the labels of the [`TimeRuler`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the labels of the [`TimeRuler`].
+    #[must_use]
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the function formatting the labels of the major tick marks of the
+    /// [`TimeRuler`] from the time in seconds and the current [`TickStep`].
+    #[must_use]
+    pub fn format<F>(mut self, format: F) -> Self
+    where
+        F: 'a + Fn(f64, TickStep) -> String,
+    {
+        self.format = Some(Box::new(format));
+        self
+    }
+
+    /// Sets the style of the [`TimeRuler`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for TimeRuler<'a, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Fixed(self.height)
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(Length::Fixed(self.height));
+
+        Node::new(limits.resolve(Size::new(limits.max().width, self.height)))
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        let duration = self.end - self.start;
+        if bounds.width <= 0.0 || duration <= 0.0 {
+            return;
+        }
+
+        let seconds_per_pixel = duration / f64::from(bounds.width);
+        let step = TickStep::for_zoom(seconds_per_pixel, self.min_spacing);
+        #[allow(clippy::cast_possible_truncation)]
+        let to_x = |time: f64| bounds.x + ((time - self.start) / seconds_per_pixel) as f32;
+
+        let tick = |renderer: &mut Renderer, x: f32, length: f32, color: Color| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: x.floor(),
+                        y: bounds.y + bounds.height - length,
+                        width: 1.0,
+                        height: length,
+                    },
+                    border_radius: (0.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                color,
+            );
+        };
+
+        renderer.with_layer(bounds, |renderer| {
+            // Minor ticks
+            let minor = TickStep {
+                seconds: step.seconds / f64::from(step.subdivisions.max(1)),
+                ..step
+            };
+            for time in minor.ticks(self.start, self.end) {
+                tick(
+                    renderer,
+                    to_x(time),
+                    bounds.height * 0.25,
+                    appearance.minor_tick_color,
+                );
+            }
+
+            // Major ticks with their labels. The label of a tick just before
+            // the range is still drawn as it may reach into the range.
+            for time in step.ticks(self.start - step.seconds, self.end) {
+                let x = to_x(time);
+                tick(renderer, x, bounds.height * 0.5, appearance.tick_color);
+
+                let label = self
+                    .format
+                    .as_ref()
+                    .map_or_else(|| format_time(time, step), |format| format(time, step));
+
+                renderer.fill_text(text::Text {
+                    content: &label,
+                    bounds: Rectangle {
+                        x: x + 3.0,
+                        y: bounds.y + 2.0,
+                        width: f32::INFINITY,
+                        height: bounds.height * 0.5,
+                    },
+                    size: self.text_size,
+                    line_height: LineHeight::default(),
+                    color: appearance.text_color,
+                    font: self.font,
+                    horizontal_alignment: Horizontal::Left,
+                    vertical_alignment: Vertical::Top,
+                    shaping: text::Shaping::Basic,
+                });
+            }
+
+            // Baseline
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle::new(
+                        Point::new(bounds.x, bounds.y + bounds.height - 1.0),
+                        Size::new(bounds.width, 1.0),
+                    ),
+                    border_radius: (0.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.tick_color,
+            );
+        });
+    }
+}
+
+impl<'a, Message, Renderer> From<TimeRuler<'a, Renderer>> for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(time_ruler: TimeRuler<'a, Renderer>) -> Self {
+        Element::new(time_ruler)
+    }
+}
+
+/// Formats the given time in seconds with the precision of the given step.
+///
+/// The format depends on the unit of the step, for example `1:05.5` for
+/// seconds, `14:30` for minutes and hours and `3d` for days.
+#[must_use]
+pub fn format_time(time: f64, step: TickStep) -> String {
+    let sign = if time < 0.0 { "-" } else { "" };
+    // Round to the finest precision to hide floating point errors of the ticks.
+    let time = (time.abs() * 100.0).round() / 100.0;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let (days, hours, minutes) = (
+        (time / DAY).floor() as u64,
+        (time % DAY / HOUR).floor() as u64,
+        (time % HOUR / MINUTE).floor() as u64,
+    );
+    let seconds = time % MINUTE;
+
+    match step.unit {
+        TimeUnit::Days if hours == 0 => format!("{sign}{days}d"),
+        TimeUnit::Days | TimeUnit::Hours | TimeUnit::Minutes if days > 0 => {
+            format!("{sign}{days}d {hours:02}:{minutes:02}")
+        }
+        TimeUnit::Days | TimeUnit::Hours | TimeUnit::Minutes => {
+            format!("{sign}{hours:02}:{minutes:02}")
+        }
+        TimeUnit::Seconds => {
+            let minutes = minutes + 60 * (hours + 24 * days);
+            let precision = if step.seconds >= 1.0 {
+                0
+            } else if step.seconds >= 0.1 {
+                1
+            } else {
+                2
+            };
+            let width = if precision == 0 { 2 } else { precision + 3 };
+
+            format!("{sign}{minutes}:{seconds:0width$.precision$}")
+        }
+    }
+}
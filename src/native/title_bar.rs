@@ -0,0 +1,528 @@
+//! Use a title bar to move and control an undecorated window.
+//!
+//! *This API requires the following crate features to be activated: `title_bar`*
+use crate::graphics::icons::{icon_to_char, Icon, ICON_FONT};
+use crate::style::title_bar::{Appearance, StyleSheet};
+
+use iced_widget::{
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event,
+        layout::{Limits, Node},
+        mouse::{self, click, Cursor},
+        renderer, text, touch,
+        widget::{
+            tree::{State as TreeState, Tag},
+            Tree,
+        },
+        Background, Clipboard, Color, Element, Event, Font, Layout, Length, Rectangle, Shell, Size,
+        Widget,
+    },
+    runtime::{window, Command},
+    text::LineHeight,
+};
+
+/// The width of a window button.
+const BUTTON_WIDTH: f32 = 46.0;
+/// The size of the icons of the window buttons.
+const ICON_SIZE: f32 = 10.0;
+/// The horizontal padding of the title.
+const TITLE_PADDING: f32 = 12.0;
+
+/// A request of a [`TitleBar`] to control its window.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WindowControl {
+    /// Start moving the window with the pressed mouse button.
+    Drag,
+    /// Maximize the window, or restore it if it is maximized.
+    ToggleMaximize,
+    /// Minimize the window.
+    Minimize,
+    /// Close the window.
+    Close,
+}
+
+impl WindowControl {
+    /// Returns the [`Command`] applying the [`WindowControl`] to the window.
+    pub fn command<Message>(self) -> Command<Message> {
+        match self {
+            Self::Drag => window::drag(),
+            Self::ToggleMaximize => window::toggle_maximize(),
+            Self::Minimize => window::minimize(true),
+            Self::Close => window::close(),
+        }
+    }
+}
+
+/// Where the window buttons of a [`TitleBar`] are placed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ControlsPlacement {
+    /// Close, minimize and maximize on the left, with a centered title, as
+    /// on macOS.
+    Leading,
+    /// Minimize, maximize and close on the right, with the title on the left,
+    /// as on Windows and most Linux desktops.
+    Trailing,
+}
+
+impl ControlsPlacement {
+    /// Returns the placement of the window buttons of the current platform.
+    #[must_use]
+    pub const fn platform() -> Self {
+        if cfg!(target_os = "macos") {
+            Self::Leading
+        } else {
+            Self::Trailing
+        }
+    }
+}
+
+impl Default for ControlsPlacement {
+    fn default() -> Self {
+        Self::platform()
+    }
+}
+
+/// A title bar for undecorated windows, moving the window when dragged,
+/// maximizing it when double-clicked, and showing buttons to minimize,
+/// maximize and close it.
+///
+/// The [`TitleBar`] only produces [`WindowControl`]s, which the application
+/// applies to its window, e.g. with [`WindowControl::command`].
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{title_bar::WindowControl, TitleBar};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Window(WindowControl),
+/// }
+///
+/// let title_bar = TitleBar::new("My application", Message::Window);
+///
+/// // In the update function:
+/// // Message::Window(control) => control.command(),
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TitleBar<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The title of the window.
+    title: String,
+    /// The function producing the message controlling the window.
+    on_control: Box<dyn Fn(WindowControl) -> Message + 'a>,
+    /// Where the window buttons are placed.
+    placement: ControlsPlacement,
+    /// Whether the window can be minimized.
+    minimizable: bool,
+    /// Whether the window can be maximized.
+    maximizable: bool,
+    /// The height of the [`TitleBar`].
+    height: f32,
+    /// The text size of the title.
+    text_size: f32,
+    /// The font of the title.
+    font: Font,
+    /// The style of the [`TitleBar`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> TitleBar<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`TitleBar`] with the given title.
+    ///
+    /// It expects:
+    ///     * the title of the window.
+    ///     * the function producing the message controlling the window.
+    pub fn new<F>(title: impl Into<String>, on_control: F) -> Self
+    where
+        F: 'a + Fn(WindowControl) -> Message,
+    {
+        Self {
+            title: title.into(),
+            on_control: Box::new(on_control),
+            placement: ControlsPlacement::default(),
+            minimizable: true,
+            maximizable: true,
+            height: 32.0,
+            text_size: 14.0,
+            font: Font::DEFAULT,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the [`ControlsPlacement`] of the [`TitleBar`], instead of the
+    /// one of the current platform.
+    #[must_use]
+    pub fn placement(mut self, placement: ControlsPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets whether the [`TitleBar`] shows a button minimizing the window.
+    #[must_use]
+    pub fn minimizable(mut self, minimizable: bool) -> Self {
+        self.minimizable = minimizable;
+        self
+    }
+
+    /// Sets whether the [`TitleBar`] shows a button maximizing the window
+    /// and maximizes it when double-clicked.
+    #[must_use]
+    pub fn maximizable(mut self, maximizable: bool) -> Self {
+        self.maximizable = maximizable;
+        self
+    }
+
+    /// Sets the height of the [`TitleBar`].
+    #[must_use]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the text size of the title of the [`TitleBar`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the title of the [`TitleBar`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`TitleBar`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the window buttons with their bounds, in order from left to
+    /// right.
+    #[allow(clippy::cast_precision_loss)]
+    fn buttons(&self, bounds: Rectangle) -> Vec<(WindowControl, Rectangle)> {
+        let controls: Vec<WindowControl> = match self.placement {
+            ControlsPlacement::Leading => vec![
+                WindowControl::Close,
+                WindowControl::Minimize,
+                WindowControl::ToggleMaximize,
+            ],
+            ControlsPlacement::Trailing => vec![
+                WindowControl::Minimize,
+                WindowControl::ToggleMaximize,
+                WindowControl::Close,
+            ],
+        }
+        .into_iter()
+        .filter(|control| match control {
+            WindowControl::Minimize => self.minimizable,
+            WindowControl::ToggleMaximize => self.maximizable,
+            _ => true,
+        })
+        .collect();
+
+        let start = match self.placement {
+            ControlsPlacement::Leading => bounds.x,
+            ControlsPlacement::Trailing => {
+                bounds.x + bounds.width - controls.len() as f32 * BUTTON_WIDTH
+            }
+        };
+
+        controls
+            .into_iter()
+            .enumerate()
+            .map(|(index, control)| {
+                let button = Rectangle {
+                    x: start + index as f32 * BUTTON_WIDTH,
+                    width: BUTTON_WIDTH,
+                    ..bounds
+                };
+                (control, button)
+            })
+            .collect()
+    }
+
+    /// Draws the icon of a window button.
+    fn draw_icon(renderer: &mut Renderer, control: WindowControl, button: Rectangle, color: Color) {
+        let center = button.center();
+        let icon = Rectangle {
+            x: center.x - ICON_SIZE / 2.0,
+            y: center.y - ICON_SIZE / 2.0,
+            width: ICON_SIZE,
+            height: ICON_SIZE,
+        };
+
+        match control {
+            WindowControl::Minimize => renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: center.y,
+                        height: 1.0,
+                        ..icon
+                    },
+                    border_radius: (0.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                color,
+            ),
+            WindowControl::ToggleMaximize => renderer.fill_quad(
+                renderer::Quad {
+                    bounds: icon,
+                    border_radius: (1.0).into(),
+                    border_width: 1.0,
+                    border_color: color,
+                },
+                Color::TRANSPARENT,
+            ),
+            _ => {
+                renderer.fill_text(core::text::Text {
+                    content: &icon_to_char(Icon::X).to_string(),
+                    bounds: Rectangle {
+                        x: center.x,
+                        y: center.y,
+                        ..button
+                    },
+                    size: ICON_SIZE * 1.6,
+                    line_height: LineHeight::default(),
+                    color,
+                    font: ICON_FONT,
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    shaping: text::Shaping::Basic,
+                });
+            }
+        }
+    }
+}
+
+/// The state of a [`TitleBar`].
+#[derive(Debug, Default)]
+struct State {
+    /// The pressed window button.
+    pressed: Option<WindowControl>,
+    /// The last click on the drag region, to detect double clicks.
+    last_click: Option<click::Click>,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for TitleBar<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        Length::Fill
+    }
+
+    fn height(&self) -> Length {
+        Length::Fixed(self.height)
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(Length::Fill).height(self.height);
+
+        Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                if let Some((control, _)) = self
+                    .buttons(bounds)
+                    .into_iter()
+                    .find(|(_, button)| button.contains(position))
+                {
+                    state.pressed = Some(control);
+                    return event::Status::Captured;
+                }
+
+                let click = click::Click::new(position, state.last_click);
+                state.last_click = Some(click);
+
+                if matches!(click.kind(), click::Kind::Double) && self.maximizable {
+                    shell.publish((self.on_control)(WindowControl::ToggleMaximize));
+                } else {
+                    shell.publish((self.on_control)(WindowControl::Drag));
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                let Some(pressed) = state.pressed.take() else {
+                    return event::Status::Ignored;
+                };
+
+                let released = self
+                    .buttons(bounds)
+                    .into_iter()
+                    .any(|(control, button)| control == pressed && cursor.is_over(button));
+
+                if released {
+                    shell.publish((self.on_control)(pressed));
+                }
+
+                event::Status::Captured
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.pressed = None;
+                event::Status::Ignored
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let over_button = self
+            .buttons(layout.bounds())
+            .iter()
+            .any(|(_, button)| cursor.is_over(*button));
+
+        if over_button {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let appearance: Appearance = theme.active(&self.style);
+        let buttons = self.buttons(bounds);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        #[allow(clippy::cast_precision_loss)]
+        let controls_width = buttons.len() as f32 * BUTTON_WIDTH;
+        let (x, horizontal_alignment) = match self.placement {
+            ControlsPlacement::Leading => (bounds.center_x(), Horizontal::Center),
+            ControlsPlacement::Trailing => (bounds.x + TITLE_PADDING, Horizontal::Left),
+        };
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.fill_text(core::text::Text {
+                content: &self.title,
+                bounds: Rectangle {
+                    x,
+                    y: bounds.center_y(),
+                    width: (bounds.width - controls_width - TITLE_PADDING * 2.0).max(0.0),
+                    ..bounds
+                },
+                size: self.text_size,
+                line_height: LineHeight::default(),
+                color: appearance.text_color,
+                font: self.font,
+                horizontal_alignment,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Advanced,
+            });
+        });
+
+        for (control, button) in buttons {
+            let hovered = cursor.is_over(button);
+            let pressed = hovered && state.pressed == Some(control);
+            let is_close = control == WindowControl::Close;
+
+            let background: Option<Background> = match (pressed, hovered, is_close) {
+                (_, true, true) => Some(appearance.close_hovered_background),
+                (true, _, false) => Some(appearance.button_pressed_background),
+                (false, true, false) => Some(appearance.button_hovered_background),
+                _ => None,
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: button,
+                        border_radius: (0.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    background,
+                );
+            }
+
+            let color = if hovered && is_close {
+                appearance.close_hovered_icon_color
+            } else {
+                appearance.icon_color
+            };
+
+            Self::draw_icon(renderer, control, button, color);
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<TitleBar<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(title_bar: TitleBar<'a, Message, Renderer>) -> Self {
+        Element::new(title_bar)
+    }
+}
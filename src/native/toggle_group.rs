@@ -0,0 +1,515 @@
+//! Use a toggle group to select none, one or several of a row of options.
+//!
+//! *This API requires the following crate features to be activated: `toggle_group`*
+use crate::{core::focus::rove, style::toggle_group::StyleSheet};
+
+use iced_widget::{
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event, keyboard,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer, text, touch,
+        widget::{
+            operation::Focusable,
+            tree::{State as TreeState, Tag},
+            Operation, Tree,
+        },
+        Clipboard, Color, Element, Event, Font, Layout, Length, Pixels, Rectangle, Shell, Size,
+        Widget,
+    },
+    text::LineHeight,
+};
+
+/// How many options of a [`ToggleGroup`] can be selected.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SelectionMode {
+    /// At most one option is selected, like radio buttons.
+    #[default]
+    Exclusive,
+    /// Any number of options are selected, like checkboxes.
+    Multiple,
+}
+
+/// A row of toggle buttons selecting none, one or several options.
+///
+/// Unlike a [`SegmentedButton`](crate::SegmentedButton), the selection of a
+/// [`ToggleGroup`] can be empty, and holds several options in
+/// [`SelectionMode::Multiple`].
+///
+/// The [`ToggleGroup`] is a single Tab stop for operations like
+/// [`focus_next`](iced_widget::focus_next), returning to the button focused
+/// last. When focused, the arrow keys, `Home` and `End` move the keyboard
+/// focus between the buttons, and `Space` or `Enter` toggle the focused one.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{toggle_group::SelectionMode, ToggleGroup};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     StyleChanged(Vec<usize>),
+/// }
+///
+/// let selection = vec![0, 2];
+///
+/// let toggle_group = ToggleGroup::new(
+///     ["Bold", "Italic", "Underline"],
+///     &selection,
+///     Message::StyleChanged,
+/// )
+/// .mode(SelectionMode::Multiple);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ToggleGroup<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The labels of the options.
+    labels: Vec<String>,
+    /// The indices of the selected options.
+    selection: &'a [usize],
+    /// The function producing the message when the selection changes.
+    on_change: Box<dyn Fn(Vec<usize>) -> Message + 'a>,
+    /// How many options can be selected.
+    mode: SelectionMode,
+    /// Whether the last selected option can be deselected.
+    allow_empty: bool,
+    /// The width of the [`ToggleGroup`].
+    width: Length,
+    /// The padding around the labels.
+    padding: f32,
+    /// The text size of the labels.
+    text_size: f32,
+    /// The font of the labels.
+    font: Font,
+    /// The style of the [`ToggleGroup`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> ToggleGroup<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`ToggleGroup`] of the given options.
+    ///
+    /// It expects:
+    ///     * the labels of the options.
+    ///     * the sorted indices of the selected options.
+    ///     * the function producing the message with the sorted indices of
+    ///       the newly selected options.
+    pub fn new<L, F>(labels: L, selection: &'a [usize], on_change: F) -> Self
+    where
+        L: IntoIterator,
+        L::Item: Into<String>,
+        F: 'a + Fn(Vec<usize>) -> Message,
+    {
+        Self {
+            labels: labels.into_iter().map(Into::into).collect(),
+            selection,
+            on_change: Box::new(on_change),
+            mode: SelectionMode::default(),
+            allow_empty: true,
+            width: Length::Shrink,
+            padding: 8.0,
+            text_size: 16.0,
+            font: Font::DEFAULT,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the [`SelectionMode`] of the [`ToggleGroup`].
+    #[must_use]
+    pub fn mode(mut self, mode: SelectionMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets whether the last selected option can be deselected, leaving the
+    /// selection empty.
+    #[must_use]
+    pub fn allow_empty(mut self, allow_empty: bool) -> Self {
+        self.allow_empty = allow_empty;
+        self
+    }
+
+    /// Sets the width of the [`ToggleGroup`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding around the labels of the [`ToggleGroup`].
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`ToggleGroup`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the [`ToggleGroup`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`ToggleGroup`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the natural widths of the buttons.
+    fn natural_widths(&self, renderer: &Renderer) -> Vec<f32> {
+        self.labels
+            .iter()
+            .map(|label| {
+                renderer.measure_width(label, self.text_size, self.font, text::Shaping::Advanced)
+                    + self.padding * 2.0
+            })
+            .collect()
+    }
+
+    /// Returns the bounds of the buttons, sharing the space left over by
+    /// their natural widths equally.
+    #[allow(clippy::cast_precision_loss)]
+    fn buttons(&self, renderer: &Renderer, bounds: Rectangle) -> Vec<Rectangle> {
+        let widths = self.natural_widths(renderer);
+        let extra = (bounds.width - widths.iter().sum::<f32>()) / widths.len().max(1) as f32;
+        let mut x = bounds.x;
+
+        widths
+            .into_iter()
+            .map(|width| {
+                let button = Rectangle {
+                    x,
+                    width: (width + extra).max(0.0),
+                    ..bounds
+                };
+                x += button.width;
+                button
+            })
+            .collect()
+    }
+
+    /// Returns the selection with the given option toggled, if it changes.
+    fn toggled(&self, index: usize) -> Option<Vec<usize>> {
+        let selected = self.selection.contains(&index);
+
+        let selection = match (self.mode, selected) {
+            (SelectionMode::Exclusive, true) => Vec::new(),
+            (SelectionMode::Exclusive, false) => vec![index],
+            (SelectionMode::Multiple, true) => self
+                .selection
+                .iter()
+                .copied()
+                .filter(|selected| *selected != index)
+                .collect(),
+            (SelectionMode::Multiple, false) => {
+                let mut selection = self.selection.to_vec();
+                selection.push(index);
+                selection.sort_unstable();
+                selection
+            }
+        };
+
+        (self.allow_empty || !selection.is_empty()).then_some(selection)
+    }
+
+    /// Toggles the given option.
+    fn toggle(&self, index: usize, shell: &mut Shell<'_, Message>) {
+        if let Some(selection) = self.toggled(index) {
+            shell.publish((self.on_change)(selection));
+        }
+    }
+}
+
+/// The state of a [`ToggleGroup`].
+#[derive(Clone, Copy, Debug, Default)]
+struct State {
+    /// Whether the [`ToggleGroup`] has the keyboard focus.
+    is_focused: bool,
+    /// The button with the keyboard focus.
+    focused: usize,
+}
+
+impl Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for ToggleGroup<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let line_height = LineHeight::default().to_absolute(Pixels(self.text_size)).0;
+
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let size = limits.resolve(Size::new(
+            self.natural_widths(renderer).iter().sum(),
+            line_height + self.padding * 2.0,
+        ));
+
+        Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let count = self.labels.len();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(layout.bounds()) else {
+                    state.is_focused = false;
+                    return event::Status::Ignored;
+                };
+
+                if let Some(index) = self
+                    .buttons(renderer, layout.bounds())
+                    .iter()
+                    .position(|button| button.contains(position))
+                {
+                    state.is_focused = true;
+                    state.focused = index;
+                    self.toggle(index, shell);
+                }
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. })
+                if state.is_focused && count > 0 =>
+            {
+                let focused = state.focused.min(count - 1);
+
+                state.focused = match key_code {
+                    keyboard::KeyCode::Space
+                    | keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => {
+                        self.toggle(focused, shell);
+                        focused
+                    }
+                    keyboard::KeyCode::Escape => {
+                        state.is_focused = false;
+                        return event::Status::Ignored;
+                    }
+                    // Tab is left to the operations moving the focus.
+                    key_code => match rove(focused, count, key_code, true) {
+                        Some(target) => target,
+                        None => return event::Status::Ignored,
+                    },
+                };
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        operation.container(None, layout.bounds(), &mut |operation| {
+            operation.focusable(state, None);
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let buttons = self.buttons(renderer, bounds);
+        let last = buttons.len().saturating_sub(1);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        for (index, (button, label)) in buttons.iter().zip(&self.labels).enumerate() {
+            let selected = self.selection.contains(&index);
+            let radius = appearance.border_radius;
+            let (left, right) = (
+                if index == 0 { radius } else { 0.0 },
+                if index == last { radius } else { 0.0 },
+            );
+
+            let background = if selected {
+                Some(appearance.selected_background)
+            } else if cursor.is_over(*button) {
+                Some(appearance.hovered_background)
+            } else {
+                None
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: *button,
+                        border_radius: [left, right, right, left].into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    background,
+                );
+            }
+
+            if index > 0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            width: appearance.border_width,
+                            ..*button
+                        },
+                        border_radius: (0.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.border_color,
+                );
+            }
+
+            renderer.fill_text(core::text::Text {
+                content: label,
+                bounds: Rectangle {
+                    x: button.center_x(),
+                    y: button.center_y(),
+                    ..*button
+                },
+                size: self.text_size,
+                line_height: LineHeight::default(),
+                color: if selected {
+                    appearance.selected_text_color
+                } else {
+                    appearance.text_color
+                },
+                font: self.font,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Advanced,
+            });
+
+            if state.is_focused && state.focused == index {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: button.x + 2.0,
+                            y: button.y + 2.0,
+                            width: (button.width - 4.0).max(0.0),
+                            height: (button.height - 4.0).max(0.0),
+                        },
+                        border_radius: (radius / 2.0).into(),
+                        border_width: 2.0,
+                        border_color: appearance.focus_color,
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius.into(),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            Color::TRANSPARENT,
+        );
+    }
+}
+
+impl<'a, Message, Renderer> From<ToggleGroup<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(toggle_group: ToggleGroup<'a, Message, Renderer>) -> Self {
+        Element::new(toggle_group)
+    }
+}
@@ -0,0 +1,604 @@
+//! Use transport controls to play, pause and seek some media.
+//!
+//! *This API requires the following crate features to be activated: `transport_controls`*
+use super::overlay::transport_controls::VolumePopover;
+use super::seek_bar::{format_time, SeekBar};
+use crate::graphics::icons::{Icon, ICON_FONT};
+use crate::style::{seek_bar, transport_controls::StyleSheet};
+
+use iced_widget::{
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        overlay, renderer, text, touch,
+        widget::{
+            tree::{State as TreeState, Tag},
+            Tree,
+        },
+        Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+    },
+    text::LineHeight,
+};
+use std::rc::Rc;
+
+/// The buttons of the [`TransportControls`] in the order they are laid out,
+/// before the time labels and the [`SeekBar`].
+const BUTTONS: [Button; 4] = [
+    Button::Previous,
+    Button::PlayPause,
+    Button::Stop,
+    Button::Next,
+];
+
+/// The messages produced by the [`TransportControls`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TransportEvent {
+    /// The play button was pressed.
+    Play,
+    /// The pause button was pressed.
+    Pause,
+    /// The stop button was pressed.
+    Stop,
+    /// The button skipping to the previous track was pressed.
+    Previous,
+    /// The button skipping to the next track was pressed.
+    Next,
+    /// The [`SeekBar`] was moved to the given position in seconds.
+    Seek(f64),
+    /// The handle of the [`SeekBar`] was released.
+    SeekReleased,
+    /// The volume was changed to the given value from 0.0 to 1.0.
+    Volume(f32),
+}
+
+/// A cluster of the controls of a media player: play/pause, stop and skip
+/// buttons, a [`SeekBar`] between the elapsed and remaining time, and a
+/// volume button opening a volume slider in a popover.
+///
+/// All controls report through a single [`TransportEvent`].
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{TransportControls, TransportEvent};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Transport(TransportEvent),
+/// }
+///
+/// let controls = TransportControls::new(true, 42.0, 180.0, 0.8, Message::Transport)
+///     .buffered([(0.0, 90.0)]);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TransportControls<'a, Message, Renderer = crate::Renderer>
+where
+    Message: Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet + seek_bar::StyleSheet,
+{
+    /// Whether the media is playing.
+    playing: bool,
+    /// The playback position in seconds.
+    position: f64,
+    /// The duration of the media in seconds.
+    duration: f64,
+    /// The volume from 0.0 to 1.0.
+    volume: f32,
+    /// The [`SeekBar`] between the elapsed and remaining time.
+    seek_bar: SeekBar<'a, Message, Renderer>,
+    /// The function producing the messages of the [`TransportControls`].
+    on_event: Rc<dyn Fn(TransportEvent) -> Message + 'a>,
+    /// The width of the [`TransportControls`].
+    width: Length,
+    /// The height of the [`TransportControls`].
+    height: f32,
+    /// The spacing between the controls.
+    spacing: f32,
+    /// The size of the icons of the buttons.
+    icon_size: f32,
+    /// The text size of the elapsed and remaining time.
+    text_size: f32,
+    /// The font of the elapsed and remaining time.
+    font: Renderer::Font,
+    /// The style of the [`TransportControls`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> TransportControls<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet + seek_bar::StyleSheet,
+{
+    /// Creates new [`TransportControls`] for media of the given `duration`
+    /// at the playback `position`, both in seconds.
+    ///
+    /// It expects:
+    ///     * whether the media is playing
+    ///     * the playback position in seconds
+    ///     * the duration of the media in seconds
+    ///     * the volume from 0.0 to 1.0
+    ///     * the function producing a message from a [`TransportEvent`]
+    pub fn new<F>(playing: bool, position: f64, duration: f64, volume: f32, on_event: F) -> Self
+    where
+        F: 'a + Fn(TransportEvent) -> Message,
+    {
+        let on_event: Rc<dyn Fn(TransportEvent) -> Message + 'a> = Rc::new(on_event);
+        let on_seek = Rc::clone(&on_event);
+
+        Self {
+            playing,
+            position,
+            duration,
+            volume,
+            seek_bar: SeekBar::new(duration, position, move |time| {
+                on_seek(TransportEvent::Seek(time))
+            })
+            .on_release(on_event(TransportEvent::SeekReleased)),
+            on_event,
+            width: Length::Fill,
+            height: 32.0,
+            spacing: 8.0,
+            icon_size: 16.0,
+            text_size: 14.0,
+            font: core::Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the buffered ranges of the [`SeekBar`] from their start to their end in seconds.
+    #[must_use]
+    pub fn buffered(mut self, ranges: impl IntoIterator<Item = (f64, f64)>) -> Self {
+        self.seek_bar = self.seek_bar.buffered(ranges);
+        self
+    }
+
+    /// Adds a chapter starting at the given time in seconds to the [`SeekBar`].
+    #[must_use]
+    pub fn chapter(mut self, start: f64, title: impl Into<String>) -> Self {
+        self.seek_bar = self.seek_bar.chapter(start, title);
+        self
+    }
+
+    /// Sets the width of the [`TransportControls`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`TransportControls`].
+    #[must_use]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self.seek_bar = self.seek_bar.height(height);
+        self
+    }
+
+    /// Sets the spacing between the controls of the [`TransportControls`].
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the size of the icons of the buttons of the [`TransportControls`].
+    #[must_use]
+    pub fn icon_size(mut self, icon_size: f32) -> Self {
+        self.icon_size = icon_size;
+        self
+    }
+
+    /// Sets the text size of the elapsed and remaining time of the [`TransportControls`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the elapsed and remaining time of the [`TransportControls`].
+    #[must_use]
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self.seek_bar = self.seek_bar.font(font);
+        self
+    }
+
+    /// Sets the style of the [`TransportControls`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style of the [`SeekBar`] of the [`TransportControls`].
+    #[must_use]
+    pub fn seek_bar_style(
+        mut self,
+        style: <Renderer::Theme as seek_bar::StyleSheet>::Style,
+    ) -> Self {
+        self.seek_bar = self.seek_bar.style(style);
+        self
+    }
+
+    /// Returns the labels of the elapsed and remaining time.
+    fn labels(&self) -> (String, String) {
+        (
+            format_time(self.position),
+            format!("-{}", format_time(self.duration - self.position)),
+        )
+    }
+
+    /// Returns the event published when the given button is pressed.
+    fn event(&self, button: Button) -> TransportEvent {
+        match button {
+            Button::Previous => TransportEvent::Previous,
+            Button::PlayPause if self.playing => TransportEvent::Pause,
+            Button::PlayPause => TransportEvent::Play,
+            Button::Stop => TransportEvent::Stop,
+            Button::Next => TransportEvent::Next,
+        }
+    }
+
+    /// Returns the icon of the given button.
+    fn icon(&self, button: Button) -> Icon {
+        match button {
+            Button::Previous => Icon::SkipStartFill,
+            Button::PlayPause if self.playing => Icon::PauseFill,
+            Button::PlayPause => Icon::PlayFill,
+            Button::Stop => Icon::StopFill,
+            Button::Next => Icon::SkipEndFill,
+        }
+    }
+
+    /// Returns the icon of the volume button.
+    fn volume_icon(&self) -> Icon {
+        if self.volume <= 0.0 {
+            Icon::VolumeMuteFill
+        } else if self.volume < 0.5 {
+            Icon::VolumeDownFill
+        } else {
+            Icon::VolumeUpFill
+        }
+    }
+}
+
+/// A button of the [`TransportControls`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Button {
+    /// Skips to the previous track.
+    Previous,
+    /// Toggles between playing and pausing.
+    PlayPause,
+    /// Stops the playback.
+    Stop,
+    /// Skips to the next track.
+    Next,
+}
+
+/// The state of the [`TransportControls`].
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct State {
+    /// Whether the volume popover is open.
+    pub volume_open: bool,
+    /// Whether the volume slider of the popover is being dragged.
+    pub volume_drag: bool,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for TransportControls<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet + seek_bar::StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.seek_bar as &dyn Widget<_, _>)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.seek_bar as &dyn Widget<_, _>]);
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Fixed(self.height)
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(Length::Fixed(self.height));
+        let size = limits.resolve(Size::new(limits.max().width, self.height));
+
+        let button = Size::new(self.height, self.height);
+        let (elapsed, remaining) = self.labels();
+        let label = |content: &str| {
+            let width = renderer
+                .measure(
+                    content,
+                    self.text_size,
+                    LineHeight::default(),
+                    self.font,
+                    Size::INFINITY,
+                    text::Shaping::Basic,
+                )
+                .width;
+            Size::new(width.ceil(), self.height)
+        };
+
+        let mut children: Vec<Node> = BUTTONS.iter().map(|_| Node::new(button)).collect();
+        children.push(Node::new(label(&elapsed)));
+        let seek_bar_index = children.len();
+        children.push(Node::default());
+        children.push(Node::new(label(&remaining)));
+        children.push(Node::new(button));
+
+        // The seek bar takes the width left by the other controls.
+        #[allow(clippy::cast_precision_loss)]
+        let fixed = children.iter().map(|child| child.size().width).sum::<f32>()
+            + self.spacing * (children.len() - 1) as f32;
+        children[seek_bar_index] = self.seek_bar.layout(
+            renderer,
+            &Limits::new(
+                Size::ZERO,
+                Size::new((size.width - fixed).max(0.0), self.height),
+            ),
+        );
+
+        let mut x = 0.0;
+        for child in &mut children {
+            let height = child.size().height;
+            child.move_to(Point::new(x, (self.height - height) / 2.0));
+            x += child.size().width + self.spacing;
+        }
+
+        Node::with_children(size, children)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let buttons: Vec<_> = children.by_ref().take(BUTTONS.len()).collect();
+        let _elapsed = children.next();
+        let seek_bar = children
+            .next()
+            .expect("Native: Layout should have a seek bar layout");
+        let _remaining = children.next();
+        let volume = children
+            .next()
+            .expect("Native: Layout should have a volume button layout");
+
+        if self.seek_bar.on_event(
+            &mut tree.children[0],
+            event.clone(),
+            seek_bar,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        ) == event::Status::Captured
+        {
+            return event::Status::Captured;
+        }
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            for (button, layout) in BUTTONS.iter().zip(&buttons) {
+                if cursor.is_over(layout.bounds()) {
+                    shell.publish((self.on_event)(self.event(*button)));
+                    return event::Status::Captured;
+                }
+            }
+
+            if cursor.is_over(volume.bounds()) {
+                let state = tree.state.downcast_mut::<State>();
+                state.volume_open = !state.volume_open;
+                return event::Status::Captured;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let buttons: Vec<_> = children.by_ref().take(BUTTONS.len()).collect();
+        let _elapsed = children.next();
+        let seek_bar = children
+            .next()
+            .expect("Native: Layout should have a seek bar layout");
+        let _remaining = children.next();
+        let volume = children
+            .next()
+            .expect("Native: Layout should have a volume button layout");
+
+        if buttons
+            .iter()
+            .chain(std::iter::once(&volume))
+            .any(|layout| cursor.is_over(layout.bounds()))
+        {
+            return mouse::Interaction::Pointer;
+        }
+
+        self.seek_bar
+            .mouse_interaction(&tree.children[0], seek_bar, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = StyleSheet::active(theme, &self.style);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius.into(),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        let mut children = layout.children();
+        let buttons: Vec<_> = children.by_ref().take(BUTTONS.len()).collect();
+        let elapsed = children
+            .next()
+            .expect("Native: Layout should have an elapsed time layout");
+        let seek_bar = children
+            .next()
+            .expect("Native: Layout should have a seek bar layout");
+        let remaining = children
+            .next()
+            .expect("Native: Layout should have a remaining time layout");
+        let volume = children
+            .next()
+            .expect("Native: Layout should have a volume button layout");
+
+        let draw_button = |renderer: &mut Renderer, bounds: Rectangle, icon: Icon| {
+            if cursor.is_over(bounds) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: appearance.border_radius.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.hovered_button_background,
+                );
+            }
+
+            renderer.fill_text(core::text::Text {
+                content: &char::from(icon).to_string(),
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size: self.icon_size,
+                line_height: LineHeight::default(),
+                color: appearance.icon_color,
+                font: ICON_FONT,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Basic,
+            });
+        };
+
+        for (button, layout) in BUTTONS.iter().zip(&buttons) {
+            draw_button(renderer, layout.bounds(), self.icon(*button));
+        }
+        draw_button(renderer, volume.bounds(), self.volume_icon());
+
+        let (elapsed_label, remaining_label) = self.labels();
+        for (content, bounds) in [
+            (elapsed_label, elapsed.bounds()),
+            (remaining_label, remaining.bounds()),
+        ] {
+            renderer.fill_text(core::text::Text {
+                content: &content,
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size: self.text_size,
+                line_height: LineHeight::default(),
+                color: appearance.text_color,
+                font: self.font,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Basic,
+            });
+        }
+
+        self.seek_bar.draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            seek_bar,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let mut children = layout.children().skip(BUTTONS.len() + 1);
+        let seek_bar = children.next()?;
+        let volume = children.nth(1)?;
+
+        let (state, child_trees) = (&mut tree.state, &mut tree.children);
+        let state = state.downcast_mut::<State>();
+
+        if state.volume_open {
+            return Some(
+                VolumePopover::new(
+                    state,
+                    self.volume,
+                    &*self.on_event,
+                    volume.bounds(),
+                    self.style.clone(),
+                )
+                .overlay(),
+            );
+        }
+
+        self.seek_bar
+            .overlay(&mut child_trees[0], seek_bar, renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<TransportControls<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet + seek_bar::StyleSheet,
+{
+    fn from(transport_controls: TransportControls<'a, Message, Renderer>) -> Self {
+        Element::new(transport_controls)
+    }
+}
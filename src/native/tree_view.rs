@@ -0,0 +1,1171 @@
+//! Use a tree view to display and select the nodes of a hierarchy.
+//!
+//! *This API requires the following crate features to be activated: `tree_view`*
+use crate::core::tree_node::{self, CheckState, DropPosition, TreeNode};
+use crate::graphics::icons::{Icon, ICON_FONT};
+use crate::style::tree_view::StyleSheet;
+
+use std::{collections::HashSet, ops::Range};
+
+use iced_widget::{
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event, keyboard,
+        layout::{Limits, Node},
+        mouse::{self, click, Cursor},
+        renderer, text, touch,
+        widget::{
+            tree::{State as TreeState, Tag},
+            Tree,
+        },
+        Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+    },
+    text::LineHeight,
+};
+
+/// The number of pixels scrolled per scrolled line.
+const SCROLL_STEP: f32 = 40.0;
+/// The distance a node has to be dragged before it is moved.
+const DRAG_THRESHOLD: f32 = 4.0;
+/// The share of the height of a row at its top and bottom that drops a node
+/// before or after the row instead of into it.
+const DROP_EDGE: f32 = 0.25;
+
+/// A function of the dragged node, the target node and the [`DropPosition`].
+type DropFn<'a, T> = Box<dyn Fn(usize, usize, DropPosition) -> T + 'a>;
+
+/// A tree of expandable nodes with labels.
+///
+/// A node with children is expanded or collapsed by clicking its toggle,
+/// and a node is selected by clicking its label. Once clicked, the
+/// [`TreeView`] takes the keyboard: the arrow keys move the selection up and
+/// down, and expand or collapse the selected node.
+///
+/// With checkboxes, checking a node checks all of its descendants, and a node
+/// whose descendants are partly checked shows an indeterminate checkbox.
+///
+/// With [`on_move`](Self::on_move), nodes are dragged by their labels and
+/// dropped before, after or into another node, as shown by a drop indicator.
+///
+/// With [`on_rename`](Self::on_rename), a double click or `F2` edits the label
+/// of the selected node in place. `Enter` commits the new label and `Escape`
+/// cancels the edit.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{TreeNode, TreeView};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Selected(usize),
+/// }
+///
+/// let nodes = vec![TreeNode::new(0, "src").children([TreeNode::new(1, "main.rs")])];
+///
+/// let tree_view = TreeView::new(&nodes).on_select(Message::Selected);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TreeView<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The root nodes of the tree.
+    nodes: &'a [TreeNode],
+    /// The function producing the message when a node is selected.
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    /// The checked nodes.
+    checked: Vec<usize>,
+    /// The function producing the message when the checked nodes change.
+    on_check: Option<Box<dyn Fn(Vec<usize>) -> Message + 'a>>,
+    /// The function producing the message when a node is dropped.
+    on_move: Option<DropFn<'a, Message>>,
+    /// The function deciding whether a node can be dropped.
+    can_drop: Option<DropFn<'a, bool>>,
+    /// The function producing the message when a node is renamed.
+    on_rename: Option<Box<dyn Fn(usize, String) -> Message + 'a>>,
+    /// The width of the [`TreeView`].
+    width: Length,
+    /// The height of the [`TreeView`].
+    height: Length,
+    /// The height of a row.
+    row_height: f32,
+    /// The indentation of each level of the tree.
+    indent: f32,
+    /// The horizontal padding of the rows.
+    padding: f32,
+    /// The text size of the labels.
+    text_size: f32,
+    /// The font of the labels.
+    font: Renderer::Font,
+    /// The style of the [`TreeView`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> TreeView<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`TreeView`] of the given root nodes.
+    #[must_use]
+    pub fn new(nodes: &'a [TreeNode]) -> Self {
+        Self {
+            nodes,
+            on_select: None,
+            checked: Vec::new(),
+            on_check: None,
+            on_move: None,
+            can_drop: None,
+            on_rename: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            row_height: 24.0,
+            indent: 18.0,
+            padding: 4.0,
+            text_size: 14.0,
+            font: core::Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the function producing the message with the identifier of a node
+    /// when it is selected.
+    #[must_use]
+    pub fn on_select<F>(mut self, on_select: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Shows a checkbox in front of each node, with the given checked nodes,
+    /// and sets the function producing the message with all checked nodes
+    /// when a checkbox is toggled.
+    ///
+    /// The checked nodes are best kept as produced by the message, which
+    /// lists every checked node including the checked parents.
+    #[must_use]
+    pub fn checkboxes<F>(mut self, checked: &[usize], on_check: F) -> Self
+    where
+        F: 'a + Fn(Vec<usize>) -> Message,
+    {
+        self.checked = checked.to_vec();
+        self.on_check = Some(Box::new(on_check));
+        self
+    }
+
+    /// Lets nodes be dragged and sets the function producing the message with
+    /// the dragged node, the target node and the [`DropPosition`] when a node
+    /// is dropped.
+    ///
+    /// [`tree_node::move_node`] applies the move to the nodes.
+    #[must_use]
+    pub fn on_move<F>(mut self, on_move: F) -> Self
+    where
+        F: 'a + Fn(usize, usize, DropPosition) -> Message,
+    {
+        self.on_move = Some(Box::new(on_move));
+        self
+    }
+
+    /// Sets the function deciding whether the dragged node can be dropped at
+    /// the given position relative to the target node.
+    ///
+    /// A node can never be dropped onto itself or its descendants.
+    #[must_use]
+    pub fn can_drop<F>(mut self, can_drop: F) -> Self
+    where
+        F: 'a + Fn(usize, usize, DropPosition) -> bool,
+    {
+        self.can_drop = Some(Box::new(can_drop));
+        self
+    }
+
+    /// Lets the labels of the nodes be edited and sets the function producing
+    /// the message with the identifier and the new label of a renamed node.
+    #[must_use]
+    pub fn on_rename<F>(mut self, on_rename: F) -> Self
+    where
+        F: 'a + Fn(usize, String) -> Message,
+    {
+        self.on_rename = Some(Box::new(on_rename));
+        self
+    }
+
+    /// Sets the width of the [`TreeView`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`TreeView`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the height of a row of the [`TreeView`].
+    #[must_use]
+    pub fn row_height(mut self, row_height: f32) -> Self {
+        self.row_height = row_height;
+        self
+    }
+
+    /// Sets the indentation of each level of the [`TreeView`].
+    #[must_use]
+    pub fn indent(mut self, indent: f32) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Sets the horizontal padding of the rows of the [`TreeView`].
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the labels of the [`TreeView`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the labels of the [`TreeView`].
+    #[must_use]
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`TreeView`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the displayed rows, which are the nodes whose ancestors are
+    /// all expanded.
+    fn rows(&self, state: &State) -> Vec<Row<'a>> {
+        /// Pushes the rows of the given nodes and their expanded descendants.
+        fn push<'a>(
+            rows: &mut Vec<Row<'a>>,
+            nodes: &'a [TreeNode],
+            depth: usize,
+            expanded: &HashSet<usize>,
+        ) {
+            for node in nodes {
+                rows.push(Row { node, depth });
+
+                if expanded.contains(&node.id) {
+                    push(rows, &node.children, depth + 1, expanded);
+                }
+            }
+        }
+
+        let mut rows = Vec::new();
+        push(&mut rows, self.nodes, 0, &state.expanded);
+        rows
+    }
+
+    /// Returns the range of rows inside of the given bounds.
+    fn row_range(&self, state: &State, bounds: Rectangle, rows: usize) -> Range<usize> {
+        let first = (state.scroll / self.row_height).floor().max(0.0);
+        let last = ((state.scroll + bounds.height) / self.row_height).ceil();
+
+        // The row indices are small positive integers.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let range = (first as usize)..(last as usize);
+
+        range.start.min(rows)..range.end.min(rows)
+    }
+
+    /// Returns the index of the row at the given position.
+    fn row_at(&self, state: &State, bounds: Rectangle, position: Point) -> usize {
+        // The position is inside of the bounds, so the row is positive.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let row = ((position.y - bounds.y + state.scroll) / self.row_height).floor() as usize;
+
+        row
+    }
+
+    /// Returns the bounds of the given row.
+    fn row_bounds(&self, state: &State, bounds: Rectangle, row: usize) -> Rectangle {
+        #[allow(clippy::cast_precision_loss)]
+        let y = row as f32 * self.row_height;
+
+        Rectangle {
+            x: bounds.x,
+            y: bounds.y + y - state.scroll,
+            width: bounds.width,
+            height: self.row_height,
+        }
+    }
+
+    /// Returns the bounds of the toggle of a row at the given depth.
+    fn toggle_bounds(&self, row_bounds: Rectangle, depth: usize) -> Rectangle {
+        #[allow(clippy::cast_precision_loss)]
+        let x = row_bounds.x + self.padding + depth as f32 * self.indent;
+
+        Rectangle {
+            x,
+            width: self.indent,
+            ..row_bounds
+        }
+    }
+
+    /// Returns the bounds of the checkbox of a row at the given depth.
+    fn checkbox_bounds(&self, row_bounds: Rectangle, depth: usize) -> Option<Rectangle> {
+        let _ = self.on_check.as_ref()?;
+
+        let toggle = self.toggle_bounds(row_bounds, depth);
+        let size = self.text_size;
+
+        Some(Rectangle {
+            x: toggle.x + toggle.width,
+            y: row_bounds.center_y() - size / 2.0,
+            width: size,
+            height: size,
+        })
+    }
+
+    /// Returns the x coordinate of the label of a row at the given depth.
+    fn label_x(&self, row_bounds: Rectangle, depth: usize) -> f32 {
+        self.checkbox_bounds(row_bounds, depth).map_or_else(
+            || {
+                let toggle = self.toggle_bounds(row_bounds, depth);
+                toggle.x + toggle.width
+            },
+            |checkbox| checkbox.x + checkbox.width + self.padding,
+        )
+    }
+
+    /// Returns the part of the given row at the given position.
+    fn part_at(&self, row_bounds: Rectangle, row: &Row<'_>, position: Point) -> Part {
+        if !row.node.children.is_empty()
+            && self.toggle_bounds(row_bounds, row.depth).contains(position)
+        {
+            Part::Toggle
+        } else if self
+            .checkbox_bounds(row_bounds, row.depth)
+            .is_some_and(|checkbox| {
+                position.x >= checkbox.x && position.x < self.label_x(row_bounds, row.depth)
+            })
+        {
+            Part::Checkbox
+        } else {
+            Part::Label
+        }
+    }
+
+    /// Returns the maximum scroll offset of the given number of rows.
+    fn max_scroll(&self, bounds: Rectangle, rows: usize) -> f32 {
+        #[allow(clippy::cast_precision_loss)]
+        let height = rows as f32 * self.row_height;
+
+        (height - bounds.height).max(0.0)
+    }
+
+    /// Scrolls the given row into view.
+    fn scroll_to(&self, state: &mut State, bounds: Rectangle, row: usize) {
+        #[allow(clippy::cast_precision_loss)]
+        let y = row as f32 * self.row_height;
+
+        if y < state.scroll {
+            state.scroll = y;
+        } else if y + self.row_height > state.scroll + bounds.height {
+            state.scroll = y + self.row_height - bounds.height;
+        }
+    }
+
+    /// Returns the target and the [`DropPosition`] of the given dragged node
+    /// at the given position, if it can be dropped there.
+    fn drop_target(
+        &self,
+        state: &State,
+        bounds: Rectangle,
+        rows: &[Row<'_>],
+        source: usize,
+        position: Point,
+    ) -> Option<(usize, DropPosition)> {
+        let index = self.row_at(state, bounds, position);
+        let row = rows.get(index)?;
+        let row_bounds = self.row_bounds(state, bounds, index);
+
+        let y = (position.y - row_bounds.y) / row_bounds.height;
+        let drop_position = if y < DROP_EDGE {
+            DropPosition::Before
+        } else if y > 1.0 - DROP_EDGE {
+            DropPosition::After
+        } else {
+            DropPosition::Into
+        };
+
+        let target = row.node.id;
+        let is_descendant =
+            tree_node::find(self.nodes, source).is_some_and(|node| node.find(target).is_some());
+        let allowed = self
+            .can_drop
+            .as_ref()
+            .is_none_or(|can_drop| can_drop(source, target, drop_position));
+
+        (!is_descendant && allowed).then_some((target, drop_position))
+    }
+
+    /// Starts editing the label of the given node.
+    fn start_rename(&self, state: &mut State, id: usize) {
+        if self.on_rename.is_none() {
+            return;
+        }
+
+        if let Some(node) = tree_node::find(self.nodes, id) {
+            state.rename = Some(Rename::new(id, node.label.clone()));
+            state.drag = None;
+        }
+    }
+
+    /// Commits the edited label, if it changed and is not blank.
+    fn commit_rename(&self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        let (Some(rename), Some(on_rename)) = (state.rename.take(), &self.on_rename) else {
+            return;
+        };
+
+        let changed =
+            tree_node::find(self.nodes, rename.id).is_some_and(|node| node.label != rename.value);
+        if changed && !rename.value.trim().is_empty() {
+            shell.publish(on_rename(rename.id, rename.value));
+        }
+    }
+
+    /// Applies the given keyboard event to the edited label.
+    fn edit_label(
+        &self,
+        state: &mut State,
+        event: keyboard::Event,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let Some(rename) = &mut state.rename else {
+            return;
+        };
+
+        match event {
+            keyboard::Event::CharacterReceived(c)
+                if !c.is_control() && !state.modifiers.command() =>
+            {
+                rename.value.insert(rename.cursor, c);
+                rename.cursor += c.len_utf8();
+            }
+            keyboard::Event::KeyPressed { key_code, .. } => match key_code {
+                keyboard::KeyCode::Enter | keyboard::KeyCode::NumpadEnter => {
+                    self.commit_rename(state, shell);
+                }
+                keyboard::KeyCode::Escape => state.rename = None,
+                keyboard::KeyCode::Backspace if rename.cursor > 0 => {
+                    let previous = rename.previous();
+                    rename.value.replace_range(previous..rename.cursor, "");
+                    rename.cursor = previous;
+                }
+                keyboard::KeyCode::Delete if rename.cursor < rename.value.len() => {
+                    let next = rename.next();
+                    rename.value.replace_range(rename.cursor..next, "");
+                }
+                keyboard::KeyCode::Left => rename.cursor = rename.previous(),
+                keyboard::KeyCode::Right => rename.cursor = rename.next(),
+                keyboard::KeyCode::Home => rename.cursor = 0,
+                keyboard::KeyCode::End => rename.cursor = rename.value.len(),
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    /// Selects the given node.
+    fn select(&self, state: &mut State, id: usize, shell: &mut Shell<'_, Message>) {
+        if state.selected == Some(id) {
+            return;
+        }
+
+        state.selected = Some(id);
+        if let Some(on_select) = &self.on_select {
+            shell.publish(on_select(id));
+        }
+    }
+
+    /// Toggles the checkbox of the given node.
+    fn toggle_check(&self, id: usize, shell: &mut Shell<'_, Message>) {
+        if let Some(on_check) = &self.on_check {
+            shell.publish(on_check(tree_node::toggle_check(
+                self.nodes,
+                &self.checked,
+                id,
+            )));
+        }
+    }
+
+    /// Draws the editor of the given label in the given row, starting at `x`.
+    fn draw_rename(
+        &self,
+        renderer: &mut Renderer,
+        appearance: &crate::style::tree_view::Appearance,
+        row_bounds: Rectangle,
+        x: f32,
+        rename: &Rename,
+    ) {
+        let bounds = Rectangle {
+            x: x - self.padding,
+            width: (row_bounds.x + row_bounds.width - x).max(0.0),
+            ..row_bounds
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (2.0).into(),
+                border_width: 1.0,
+                border_color: appearance.editor_border_color,
+            },
+            appearance.editor_background,
+        );
+
+        renderer.fill_text(core::text::Text {
+            content: &rename.value,
+            bounds: Rectangle {
+                x,
+                y: bounds.center_y(),
+                width: (bounds.width - self.padding).max(0.0),
+                height: bounds.height,
+            },
+            size: self.text_size,
+            line_height: LineHeight::default(),
+            color: appearance.text_color,
+            font: self.font,
+            horizontal_alignment: Horizontal::Left,
+            vertical_alignment: Vertical::Center,
+            shaping: text::Shaping::Advanced,
+        });
+
+        let offset = renderer.measure_width(
+            &rename.value[..rename.cursor],
+            self.text_size,
+            self.font,
+            text::Shaping::Advanced,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: (x + offset).min(bounds.x + bounds.width - 2.0),
+                    y: bounds.y + (bounds.height - self.text_size * 1.2) / 2.0,
+                    width: 1.0,
+                    height: self.text_size * 1.2,
+                },
+                border_radius: (0.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.cursor_color,
+        );
+    }
+
+    /// Draws the indicator of a node dropped at the given position relative to
+    /// the given row, whose label starts at `x`.
+    fn draw_drop_indicator(
+        &self,
+        renderer: &mut Renderer,
+        appearance: &crate::style::tree_view::Appearance,
+        row_bounds: Rectangle,
+        x: f32,
+        position: DropPosition,
+    ) {
+        let (bounds, border_width) = match position {
+            DropPosition::Before | DropPosition::After => {
+                let y = if position == DropPosition::Before {
+                    row_bounds.y
+                } else {
+                    row_bounds.y + row_bounds.height
+                };
+
+                (
+                    Rectangle {
+                        x,
+                        y: y - 1.0,
+                        width: (row_bounds.x + row_bounds.width - x - self.padding).max(0.0),
+                        height: 2.0,
+                    },
+                    0.0,
+                )
+            }
+            DropPosition::Into => (
+                Rectangle {
+                    x: x - self.padding,
+                    width: (row_bounds.x + row_bounds.width - x).max(0.0),
+                    ..row_bounds
+                },
+                2.0,
+            ),
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (2.0).into(),
+                border_width,
+                border_color: appearance.drop_indicator_color,
+            },
+            if border_width > 0.0 {
+                Color::TRANSPARENT
+            } else {
+                appearance.drop_indicator_color
+            },
+        );
+    }
+
+    /// Draws the checkbox of the given node.
+    fn draw_checkbox(
+        &self,
+        renderer: &mut Renderer,
+        appearance: &crate::style::tree_view::Appearance,
+        bounds: Rectangle,
+        node: &TreeNode,
+    ) {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (2.0).into(),
+                border_width: 1.0,
+                border_color: appearance.checkbox_border_color,
+            },
+            appearance.checkbox_background,
+        );
+
+        match node.check_state(&self.checked) {
+            CheckState::Unchecked => {}
+            CheckState::Checked => {
+                renderer.fill_text(core::text::Text {
+                    content: &char::from(Icon::Check).to_string(),
+                    bounds: Rectangle {
+                        x: bounds.center_x(),
+                        y: bounds.center_y(),
+                        ..bounds
+                    },
+                    size: bounds.height,
+                    line_height: LineHeight::default(),
+                    color: appearance.check_color,
+                    font: ICON_FONT,
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    shaping: text::Shaping::Basic,
+                });
+            }
+            CheckState::Indeterminate => {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + bounds.width * 0.25,
+                            y: bounds.center_y() - 1.0,
+                            width: bounds.width * 0.5,
+                            height: 2.0,
+                        },
+                        border_radius: (0.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.check_color,
+                );
+            }
+        }
+    }
+}
+
+/// A displayed node of a [`TreeView`].
+#[derive(Clone, Copy, Debug)]
+struct Row<'a> {
+    /// The node of the row.
+    node: &'a TreeNode,
+    /// The depth of the node in the tree.
+    depth: usize,
+}
+
+/// A part of a row of a [`TreeView`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Part {
+    /// The toggle expanding or collapsing the node.
+    Toggle,
+    /// The checkbox of the node.
+    Checkbox,
+    /// The label of the node.
+    Label,
+}
+
+/// The state of a [`TreeView`].
+#[derive(Clone, Debug, Default)]
+struct State {
+    /// The expanded nodes.
+    expanded: HashSet<usize>,
+    /// The selected node.
+    selected: Option<usize>,
+    /// Whether the [`TreeView`] receives the keyboard input.
+    is_focused: bool,
+    /// The scroll offset of the rows.
+    scroll: f32,
+    /// The dragged node.
+    drag: Option<Drag>,
+    /// The label being edited.
+    rename: Option<Rename>,
+    /// The pressed keyboard modifiers.
+    modifiers: keyboard::Modifiers,
+    /// The last click, for detecting double clicks.
+    last_click: Option<click::Click>,
+}
+
+/// The label of a node of a [`TreeView`] being edited.
+#[derive(Clone, Debug)]
+struct Rename {
+    /// The renamed node.
+    id: usize,
+    /// The edited label.
+    value: String,
+    /// The byte index of the text cursor.
+    cursor: usize,
+}
+
+impl Rename {
+    /// Creates a new [`Rename`] of the given node with the cursor at the end.
+    fn new(id: usize, value: String) -> Self {
+        Self {
+            id,
+            cursor: value.len(),
+            value,
+        }
+    }
+
+    /// Returns the byte index of the character before the cursor.
+    fn previous(&self) -> usize {
+        self.value[..self.cursor]
+            .char_indices()
+            .next_back()
+            .map_or(0, |(index, _)| index)
+    }
+
+    /// Returns the byte index of the character after the cursor.
+    fn next(&self) -> usize {
+        self.value[self.cursor..]
+            .chars()
+            .next()
+            .map_or(self.cursor, |c| self.cursor + c.len_utf8())
+    }
+}
+
+/// A node of a [`TreeView`] being dragged.
+#[derive(Clone, Copy, Debug)]
+struct Drag {
+    /// The dragged node.
+    source: usize,
+    /// The position the drag started at.
+    origin: Point,
+    /// Whether the drag went past the threshold.
+    is_moving: bool,
+    /// The node and the position the dragged node would be dropped at.
+    target: Option<(usize, DropPosition)>,
+}
+
+impl State {
+    /// Expands the given node if it is collapsed, or collapses it otherwise.
+    fn toggle(&mut self, id: usize) {
+        if !self.expanded.remove(&id) {
+            let _ = self.expanded.insert(id);
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for TreeView<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        Node::new(limits.resolve(Size::ZERO))
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let rows = self.rows(state);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    self.commit_rename(state, shell);
+                    state.is_focused = false;
+                    return event::Status::Ignored;
+                };
+                state.is_focused = true;
+
+                let index = self.row_at(state, bounds, position);
+                let Some(row) = rows.get(index) else {
+                    self.commit_rename(state, shell);
+                    return event::Status::Captured;
+                };
+
+                let part = self.part_at(self.row_bounds(state, bounds, index), row, position);
+                if part == Part::Label
+                    && state
+                        .rename
+                        .as_ref()
+                        .is_some_and(|rename| rename.id == row.node.id)
+                {
+                    return event::Status::Captured;
+                }
+                self.commit_rename(state, shell);
+
+                let click = click::Click::new(position, state.last_click);
+                state.last_click = Some(click);
+
+                match part {
+                    Part::Toggle => state.toggle(row.node.id),
+                    Part::Checkbox => self.toggle_check(row.node.id, shell),
+                    Part::Label => {
+                        self.select(state, row.node.id, shell);
+
+                        if matches!(click.kind(), click::Kind::Double) && self.on_rename.is_some() {
+                            self.start_rename(state, row.node.id);
+                        } else if self.on_move.is_some() {
+                            state.drag = Some(Drag {
+                                source: row.node.id,
+                                origin: position,
+                                is_moving: false,
+                                target: None,
+                            });
+                        }
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let Some(mut drag) = state.drag else {
+                    return event::Status::Ignored;
+                };
+                let Some(position) = cursor.position() else {
+                    return event::Status::Ignored;
+                };
+
+                drag.is_moving |= position.distance(drag.origin) > DRAG_THRESHOLD;
+                if drag.is_moving {
+                    drag.target = cursor.position_over(bounds).and_then(|_| {
+                        self.drop_target(state, bounds, &rows, drag.source, position)
+                    });
+                }
+                state.drag = Some(drag);
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                let Some(drag) = state.drag.take() else {
+                    return event::Status::Ignored;
+                };
+
+                if let (Some((target, position)), Some(on_move)) = (drag.target, &self.on_move) {
+                    shell.publish(on_move(drag.source, target, position));
+
+                    // The target is expanded to show the dropped node.
+                    if position == DropPosition::Into {
+                        let _ = state.expanded.insert(target);
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) if state.drag.is_some() => {
+                state.drag = None;
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * SCROLL_STEP,
+                    mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+
+                state.scroll =
+                    (state.scroll - delta).clamp(0.0, self.max_scroll(bounds, rows.len()));
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+            }
+            Event::Keyboard(event) if state.rename.is_some() => {
+                self.edit_label(state, event, shell);
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) if state.is_focused => {
+                let selected = state
+                    .selected
+                    .and_then(|id| rows.iter().position(|row| row.node.id == id));
+
+                let Some(selected) = selected else {
+                    if let Some(row) = rows.first() {
+                        self.select(state, row.node.id, shell);
+                    }
+                    return event::Status::Captured;
+                };
+                let row = rows[selected];
+
+                let target = match key_code {
+                    keyboard::KeyCode::Up => selected.checked_sub(1),
+                    keyboard::KeyCode::Down => Some(selected + 1).filter(|row| *row < rows.len()),
+                    keyboard::KeyCode::Right if !row.node.children.is_empty() => {
+                        if state.expanded.contains(&row.node.id) {
+                            Some(selected + 1)
+                        } else {
+                            state.toggle(row.node.id);
+                            None
+                        }
+                    }
+                    // Left collapses the selected node, or selects its parent.
+                    keyboard::KeyCode::Left => {
+                        if state.expanded.contains(&row.node.id) {
+                            state.toggle(row.node.id);
+                            None
+                        } else {
+                            rows[..selected]
+                                .iter()
+                                .rposition(|parent| parent.depth < row.depth)
+                        }
+                    }
+                    keyboard::KeyCode::Space => {
+                        self.toggle_check(row.node.id, shell);
+                        None
+                    }
+                    keyboard::KeyCode::F2 if self.on_rename.is_some() => {
+                        self.start_rename(state, row.node.id);
+                        None
+                    }
+                    _ => return event::Status::Ignored,
+                };
+
+                if let Some(target) = target {
+                    self.select(state, rows[target].node.id, shell);
+                    self.scroll_to(state, bounds, target);
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let rows = self.rows(state);
+
+        if state.drag.is_some_and(|drag| drag.is_moving) {
+            return if state.drag.is_some_and(|drag| drag.target.is_some()) {
+                mouse::Interaction::Grabbing
+            } else {
+                mouse::Interaction::NotAllowed
+            };
+        }
+
+        let Some(position) = cursor.position_over(bounds) else {
+            return mouse::Interaction::default();
+        };
+
+        let index = self.row_at(state, bounds, position);
+        if rows.get(index).is_some_and(|row| {
+            self.part_at(self.row_bounds(state, bounds, index), row, position) != Part::Label
+        }) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let rows = self.rows(state);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        let hovered = cursor
+            .position_over(bounds)
+            .map(|position| self.row_at(state, bounds, position));
+
+        renderer.with_layer(bounds, |renderer| {
+            for index in self.row_range(state, bounds, rows.len()) {
+                let row = rows[index];
+                let row_bounds = self.row_bounds(state, bounds, index);
+                let selected = state.selected == Some(row.node.id);
+
+                if selected || hovered == Some(index) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: row_bounds,
+                            border_radius: (0.0).into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        if selected {
+                            appearance.selected_background
+                        } else {
+                            appearance.hovered_background
+                        },
+                    );
+                }
+
+                if !row.node.children.is_empty() {
+                    let toggle = self.toggle_bounds(row_bounds, row.depth);
+
+                    renderer.fill_text(core::text::Text {
+                        content: &char::from(if state.expanded.contains(&row.node.id) {
+                            Icon::CaretDownFill
+                        } else {
+                            Icon::CaretRightFill
+                        })
+                        .to_string(),
+                        bounds: Rectangle {
+                            x: toggle.center_x(),
+                            y: toggle.center_y(),
+                            ..toggle
+                        },
+                        size: self.text_size * 0.8,
+                        line_height: LineHeight::default(),
+                        color: if selected {
+                            appearance.selected_text_color
+                        } else {
+                            appearance.toggle_color
+                        },
+                        font: ICON_FONT,
+                        horizontal_alignment: Horizontal::Center,
+                        vertical_alignment: Vertical::Center,
+                        shaping: text::Shaping::Basic,
+                    });
+                }
+
+                if let Some(checkbox) = self.checkbox_bounds(row_bounds, row.depth) {
+                    self.draw_checkbox(renderer, &appearance, checkbox, row.node);
+                }
+
+                let x = self.label_x(row_bounds, row.depth);
+                if let Some(rename) = state
+                    .rename
+                    .as_ref()
+                    .filter(|rename| rename.id == row.node.id)
+                {
+                    self.draw_rename(renderer, &appearance, row_bounds, x, rename);
+                    continue;
+                }
+
+                renderer.fill_text(core::text::Text {
+                    content: &row.node.label,
+                    bounds: Rectangle {
+                        x,
+                        y: row_bounds.center_y(),
+                        width: (row_bounds.x + row_bounds.width - x).max(0.0),
+                        height: row_bounds.height,
+                    },
+                    size: self.text_size,
+                    line_height: LineHeight::default(),
+                    color: if selected {
+                        appearance.selected_text_color
+                    } else {
+                        appearance.text_color
+                    },
+                    font: self.font,
+                    horizontal_alignment: Horizontal::Left,
+                    vertical_alignment: Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                });
+
+                if let Some((_, position)) = state
+                    .drag
+                    .and_then(|drag| drag.target)
+                    .filter(|(target, _)| *target == row.node.id)
+                {
+                    self.draw_drop_indicator(renderer, &appearance, row_bounds, x, position);
+                }
+            }
+        });
+    }
+}
+
+impl<'a, Message, Renderer> From<TreeView<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(tree_view: TreeView<'a, Message, Renderer>) -> Self {
+        Element::new(tree_view)
+    }
+}
@@ -0,0 +1,774 @@
+//! Use a treemap to show hierarchical size data as nested tiles.
+//!
+//! *This API requires the following crate features to be activated: `treemap`*
+use std::time::{Duration, Instant};
+
+use crate::core::hierarchy::{tiles, Tile};
+use crate::native::helpers::chart::{self, mix};
+
+use iced_widget::{
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer,
+        text::{self, Renderer as _},
+        touch,
+        widget::{
+            tree::{State as TreeState, Tag},
+            Tree,
+        },
+        window, Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Renderer as _,
+        Shell, Size, Widget,
+    },
+    renderer::Renderer,
+    text::LineHeight,
+};
+
+pub use crate::core::easing::Easing;
+pub use crate::core::hierarchy::HierarchyNode;
+pub use crate::style::treemap::{Appearance, StyleSheet};
+
+/// The function coloring the tiles by their paths and nodes.
+type ColorFn<'a> = Box<dyn Fn(&[usize], &HierarchyNode) -> Color + 'a>;
+
+/// The padding around the breadcrumbs and the text of the tooltip.
+const PADDING: f32 = 4.0;
+/// The padding between a tile and the tiles of its children.
+const TILE_PADDING: f32 = 2.0;
+/// The separator between the breadcrumbs.
+const SEPARATOR: &str = " › ";
+
+/// A treemap showing the children of a [`HierarchyNode`] as tiles sized by
+/// their totals, with the tiles of their children nested inside of them.
+///
+/// Clicking a tile with children drills down into it, and clicking a
+/// breadcrumb above the tiles, or right clicking, drills back up. The
+/// levels zoom into each other with an animated transition. Hovering a tile
+/// shows its label and its total in a tooltip.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::treemap::{HierarchyNode, Treemap};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Drilled(Vec<usize>),
+/// }
+///
+/// let root = HierarchyNode::new("Disk", 0.0).children([
+///     HierarchyNode::new("Photos", 120.0),
+///     HierarchyNode::new("Music", 80.0),
+/// ]);
+///
+/// let treemap = Treemap::new(&root).on_drill(Message::Drilled);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Treemap<'a, Message, Theme = iced_widget::style::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The root of the shown data.
+    root: &'a HierarchyNode,
+    /// The function producing the message with the path of the shown level.
+    on_drill: Option<Box<dyn Fn(Vec<usize>) -> Message + 'a>>,
+    /// The function coloring a tile by the path and the node of the tile.
+    color_by: Option<ColorFn<'a>>,
+    /// The function formatting a total for the tooltip.
+    format: Box<dyn Fn(f32) -> String + 'a>,
+    /// The number of nested levels shown at once.
+    levels: usize,
+    /// The width of the [`Treemap`].
+    width: Length,
+    /// The height of the [`Treemap`].
+    height: Length,
+    /// The text size of the labels.
+    text_size: f32,
+    /// The duration of the transition between two levels.
+    duration: Duration,
+    /// The easing of the transition between two levels.
+    easing: Easing,
+    /// The style of the [`Treemap`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> Treemap<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Treemap`] of the given data.
+    #[must_use]
+    pub fn new(root: &'a HierarchyNode) -> Self {
+        Self {
+            root,
+            on_drill: None,
+            color_by: None,
+            format: Box::new(|value| format!("{value}")),
+            levels: 2,
+            width: Length::Fill,
+            height: Length::Fill,
+            text_size: 14.0,
+            duration: Duration::from_millis(300),
+            easing: Easing::default(),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the function producing the message with the path of child
+    /// indices from the root to the level shown after drilling down or up.
+    #[must_use]
+    pub fn on_drill<F>(mut self, on_drill: F) -> Self
+    where
+        F: 'a + Fn(Vec<usize>) -> Message,
+    {
+        self.on_drill = Some(Box::new(on_drill));
+        self
+    }
+
+    /// Sets the function coloring a tile by the path of child indices from
+    /// the root to its node and by the node itself.
+    #[must_use]
+    pub fn color_by<F>(mut self, color_by: F) -> Self
+    where
+        F: 'a + Fn(&[usize], &HierarchyNode) -> Color,
+    {
+        self.color_by = Some(Box::new(color_by));
+        self
+    }
+
+    /// Sets the function formatting the totals shown in the tooltip.
+    #[must_use]
+    pub fn format<F>(mut self, format: F) -> Self
+    where
+        F: 'a + Fn(f32) -> String,
+    {
+        self.format = Box::new(format);
+        self
+    }
+
+    /// Sets the number of nested levels shown at once.
+    #[must_use]
+    pub fn levels(mut self, levels: usize) -> Self {
+        self.levels = levels.max(1);
+        self
+    }
+
+    /// Sets the width of the [`Treemap`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Treemap`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the text size of the labels of the [`Treemap`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the duration of the transition between two levels.
+    #[must_use]
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the easing of the transition between two levels.
+    #[must_use]
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Sets the style of the [`Treemap`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the longest prefix of the given path that still leads to a
+    /// node of the data.
+    fn level<'b>(&self, path: &'b [usize]) -> &'b [usize] {
+        let len = (0..=path.len())
+            .rev()
+            .find(|&len| self.root.get(&path[..len]).is_some())
+            .unwrap_or(0);
+
+        &path[..len]
+    }
+
+    /// Returns the height of a line of text with its padding.
+    fn line_height(&self) -> f32 {
+        self.text_size * 1.3 + PADDING * 2.0
+    }
+
+    /// Returns the area of the tiles below the breadcrumbs.
+    fn area(&self, bounds: Rectangle) -> Rectangle {
+        let header = self.line_height().min(bounds.height);
+
+        Rectangle {
+            y: bounds.y + header,
+            height: bounds.height - header,
+            ..bounds
+        }
+    }
+
+    /// Returns the tiles of the level at the given path laid out in the
+    /// given bounds.
+    fn tiles(&self, path: &[usize], bounds: Rectangle) -> Vec<Tile> {
+        self.root.get(path).map_or_else(Vec::new, |node| {
+            tiles(
+                node,
+                bounds,
+                self.levels,
+                self.text_size * 1.3 + TILE_PADDING * 2.0,
+                TILE_PADDING,
+            )
+        })
+    }
+
+    /// Returns the innermost tile at the given position.
+    fn tile_at(&self, path: &[usize], area: Rectangle, position: Point) -> Option<Tile> {
+        self.tiles(path, area)
+            .into_iter()
+            .rev()
+            .find(|tile| tile.bounds.contains(position))
+    }
+
+    /// Returns the bounds of the breadcrumbs of the level at the given path,
+    /// from the root to the level.
+    fn breadcrumbs(
+        &self,
+        renderer: &Renderer<Theme>,
+        path: &[usize],
+        bounds: Rectangle,
+    ) -> Vec<(String, Rectangle)> {
+        let separator = renderer.measure_width(
+            SEPARATOR,
+            self.text_size,
+            core::Font::default(),
+            text::Shaping::Advanced,
+        );
+        let mut x = bounds.x + PADDING;
+
+        (0..=path.len())
+            .filter_map(|len| self.root.get(&path[..len]))
+            .map(|node| {
+                let width = renderer.measure_width(
+                    &node.label,
+                    self.text_size,
+                    core::Font::default(),
+                    text::Shaping::Advanced,
+                );
+                let crumb = Rectangle {
+                    x,
+                    y: bounds.y,
+                    width,
+                    height: self.line_height().min(bounds.height),
+                };
+                x += width + separator;
+
+                (node.label.clone(), crumb)
+            })
+            .collect()
+    }
+
+    /// Returns the color of the tile of the node at the given path relative
+    /// to the shown level.
+    fn color(&self, appearance: &Appearance, level: &[usize], tile: &Tile) -> Color {
+        let path: Vec<usize> = level.iter().chain(&tile.path).copied().collect();
+
+        if let (Some(color_by), Some(node)) = (&self.color_by, self.root.get(&path)) {
+            color_by(&path, node)
+        } else {
+            // The nested tiles are lighter shades of the tile of their
+            // top level ancestor.
+            let color = appearance.palette[tile.path[0] % appearance.palette.len()];
+            #[allow(clippy::cast_precision_loss)]
+            let shade = (tile.path.len() - 1) as f32 * 0.2;
+
+            mix(color, Color::WHITE, shade.min(0.8))
+        }
+    }
+}
+
+/// The state of a [`Treemap`].
+#[derive(Debug, Default)]
+struct State {
+    /// The path of child indices from the root to the shown level.
+    path: Vec<usize>,
+    /// The running transition between two levels.
+    zoom: Option<Zoom>,
+}
+
+/// The transition of a [`Treemap`] into a new level.
+#[derive(Clone, Copy, Debug)]
+struct Zoom {
+    /// The bounds the new level starts to be drawn in, relative to the area
+    /// of the tiles, which they end at.
+    from: Rectangle,
+    /// The progress of the transition, from 0.0 to 1.0.
+    progress: f32,
+    /// The time of the last step of the transition.
+    last_update: Option<Instant>,
+}
+
+impl Zoom {
+    /// Creates a new [`Zoom`] starting at the given relative bounds.
+    fn new(from: Rectangle) -> Self {
+        Self {
+            from,
+            progress: 0.0,
+            last_update: None,
+        }
+    }
+
+    /// Returns the bounds the level is drawn in at the eased progress.
+    fn bounds(&self, area: Rectangle, easing: Easing) -> Rectangle {
+        let t = easing.apply(self.progress);
+        let from = Rectangle {
+            x: area.x + self.from.x * area.width,
+            y: area.y + self.from.y * area.height,
+            width: self.from.width * area.width,
+            height: self.from.height * area.height,
+        };
+
+        Rectangle {
+            x: from.x + (area.x - from.x) * t,
+            y: from.y + (area.y - from.y) * t,
+            width: from.width + (area.width - from.width) * t,
+            height: from.height + (area.height - from.height) * t,
+        }
+    }
+}
+
+/// Returns the given bounds relative to the area.
+fn relative(bounds: Rectangle, area: Rectangle) -> Rectangle {
+    Rectangle {
+        x: (bounds.x - area.x) / area.width,
+        y: (bounds.y - area.y) / area.height,
+        width: bounds.width / area.width,
+        height: bounds.height / area.height,
+    }
+}
+
+impl<Message, Theme> Treemap<'_, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Shows the level at the given path, zooming from the tile of its child
+    /// or into the tile of the previous level.
+    fn drill(&self, state: &mut State, path: Vec<usize>, area: Rectangle) -> Option<Message> {
+        let level = self.level(&state.path).to_vec();
+        if path == level || area.width <= 0.0 || area.height <= 0.0 {
+            return None;
+        }
+
+        let zoom = if path.starts_with(&level) {
+            // The new level grows out of its tile in the previous level.
+            self.tiles(&level, area)
+                .into_iter()
+                .find(|tile| tile.path == [path[level.len()]])
+                .map(|tile| relative(tile.bounds, area))
+        } else {
+            // The previous level shrinks into its tile in the new level.
+            self.tiles(&path, area)
+                .into_iter()
+                .find(|tile| tile.path == [level[path.len()]])
+                .map(|tile| {
+                    let tile = relative(tile.bounds, area);
+
+                    Rectangle {
+                        x: -tile.x / tile.width,
+                        y: -tile.y / tile.height,
+                        width: 1.0 / tile.width,
+                        height: 1.0 / tile.height,
+                    }
+                })
+        };
+
+        state.zoom = zoom.map(Zoom::new);
+        state.path = path;
+
+        self.on_drill
+            .as_ref()
+            .map(|on_drill| on_drill(state.path.clone()))
+    }
+}
+
+impl<Message, Theme> Widget<Message, Renderer<Theme>> for Treemap<'_, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer<Theme>, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        Node::new(limits.resolve(Size::new(f32::INFINITY, f32::INFINITY)))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer<Theme>,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let area = self.area(bounds);
+
+        match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if let Some(zoom) = &mut state.zoom {
+                    let last_update = zoom.last_update.replace(now).unwrap_or(now);
+                    zoom.progress = if self.duration.is_zero() {
+                        1.0
+                    } else {
+                        zoom.progress
+                            + (now - last_update).as_secs_f32() / self.duration.as_secs_f32()
+                    };
+
+                    if zoom.progress >= 1.0 {
+                        state.zoom = None;
+                    } else {
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+                let level = self.level(&state.path).to_vec();
+
+                let path = if area.contains(position) {
+                    // A click drills into the top level tile with children.
+                    self.tile_at(&level, area, position).and_then(|tile| {
+                        let mut path = level.clone();
+                        path.push(tile.path[0]);
+
+                        self.root
+                            .get(&path)
+                            .filter(|node| !node.children.is_empty())
+                            .map(|_| path)
+                    })
+                } else {
+                    self.breadcrumbs(renderer, &level, bounds)
+                        .iter()
+                        .position(|(_, crumb)| crumb.contains(position))
+                        .map(|len| level[..len].to_vec())
+                };
+
+                if let Some(path) = path {
+                    if let Some(message) = self.drill(state, path, area) {
+                        shell.publish(message);
+                    }
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => {
+                let level = self.level(&state.path).to_vec();
+
+                if cursor.is_over(bounds) && !level.is_empty() {
+                    let path = level[..level.len() - 1].to_vec();
+
+                    if let Some(message) = self.drill(state, path, area) {
+                        shell.publish(message);
+                    }
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer<Theme>,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let area = self.area(bounds);
+        let level = self.level(&state.path);
+
+        let Some(position) = cursor.position_over(bounds) else {
+            return mouse::Interaction::default();
+        };
+
+        let is_drillable = if area.contains(position) {
+            self.tile_at(level, area, position).is_some_and(|tile| {
+                self.root
+                    .get(level)
+                    .and_then(|node| node.children.get(tile.path[0]))
+                    .is_some_and(|node| !node.children.is_empty())
+            })
+        } else {
+            let crumbs = self.breadcrumbs(renderer, level, bounds);
+
+            crumbs[..crumbs.len() - 1]
+                .iter()
+                .any(|(_, crumb)| crumb.contains(position))
+        };
+
+        if is_drillable {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer<Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let area = self.area(bounds);
+        let level = self.level(&state.path);
+        let appearance = theme.active(&self.style);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    height: bounds.height - area.height,
+                    ..bounds
+                },
+                border_radius: (0.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.breadcrumb_background,
+        );
+
+        for (index, (label, crumb)) in self
+            .breadcrumbs(renderer, level, bounds)
+            .into_iter()
+            .enumerate()
+        {
+            let mut content = label;
+            if index < level.len() {
+                content.push_str(SEPARATOR);
+            }
+
+            renderer.fill_text(core::text::Text {
+                content: &content,
+                bounds: Rectangle {
+                    y: crumb.center_y(),
+                    width: f32::INFINITY,
+                    ..crumb
+                },
+                size: self.text_size,
+                line_height: LineHeight::default(),
+                color: appearance.breadcrumb_text_color,
+                font: core::Font::default(),
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Advanced,
+            });
+        }
+
+        let target = state
+            .zoom
+            .map_or(area, |zoom| zoom.bounds(area, self.easing));
+        let hovered = cursor
+            .position_over(area)
+            .filter(|_| state.zoom.is_none())
+            .and_then(|position| self.tile_at(level, area, position));
+
+        renderer.with_layer(area, |renderer| {
+            for tile in self.tiles(level, target) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: tile.bounds,
+                        border_radius: (0.0).into(),
+                        border_width: 1.0,
+                        border_color: appearance.border_color,
+                    },
+                    self.color(&appearance, level, &tile),
+                );
+
+                let Some(node) = self.root.get(level).and_then(|node| node.get(&tile.path)) else {
+                    continue;
+                };
+                if tile.bounds.width < self.text_size * 2.0
+                    || tile.bounds.height < self.text_size * 1.3 + TILE_PADDING * 2.0
+                {
+                    continue;
+                }
+
+                // The labels are clipped to their tiles.
+                renderer.with_layer(tile.bounds, |renderer| {
+                    renderer.fill_text(core::text::Text {
+                        content: &node.label,
+                        bounds: Rectangle {
+                            x: tile.bounds.x + PADDING,
+                            y: tile.bounds.y + TILE_PADDING,
+                            width: f32::INFINITY,
+                            height: self.text_size * 1.3,
+                        },
+                        size: self.text_size,
+                        line_height: LineHeight::default(),
+                        color: appearance.text_color,
+                        font: core::Font::default(),
+                        horizontal_alignment: Horizontal::Left,
+                        vertical_alignment: Vertical::Top,
+                        shaping: text::Shaping::Advanced,
+                    });
+                });
+            }
+
+            if let Some(tile) = &hovered {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: tile.bounds,
+                        border_radius: (0.0).into(),
+                        border_width: 2.0,
+                        border_color: appearance.hovered_border_color,
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+        });
+
+        let (Some(tile), Some(position)) = (hovered, cursor.position()) else {
+            return;
+        };
+        let Some(node) = self.root.get(level).and_then(|node| node.get(&tile.path)) else {
+            return;
+        };
+
+        let content = format!("{}: {}", node.label, (self.format)(node.total()));
+        chart::tooltip(
+            renderer,
+            &[content],
+            self.text_size,
+            text::Shaping::Advanced,
+            position,
+            bounds,
+            appearance.tooltip_background,
+            appearance.tooltip_text_color,
+        );
+    }
+}
+
+impl<'a, Message, Theme> From<Treemap<'a, Message, Theme>> for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+{
+    fn from(treemap: Treemap<'a, Message, Theme>) -> Self {
+        Element::new(treemap)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HierarchyNode, Treemap};
+    use crate::test_utils::{Harness, Input};
+    use iced_widget::core::{mouse, Point, Size};
+    use std::time::Duration;
+
+    fn hierarchy() -> HierarchyNode {
+        HierarchyNode::new("Root", 0.0).children([
+            HierarchyNode::new("A", 0.0)
+                .children([HierarchyNode::new("A1", 2.0), HierarchyNode::new("A2", 4.0)]),
+            HierarchyNode::new("B", 2.0),
+        ])
+    }
+
+    #[test]
+    fn treemap_test() {
+        #[derive(Clone, Debug, PartialEq)]
+        enum Change {
+            Drill(Vec<usize>),
+        }
+
+        let root = hierarchy();
+        let treemap = Treemap::new(&root).on_drill(Change::Drill);
+        let mut harness = Harness::new(treemap, Size::new(200.0, 130.0));
+
+        // A takes the left three quarters below the breadcrumbs, and a click
+        // on any of its nested tiles drills into it.
+        assert_eq!(
+            harness.click(Point::new(50.0, 100.0)),
+            vec![Change::Drill(vec![0])]
+        );
+        assert!(harness.advance(Duration::from_millis(400)).is_empty());
+
+        // The leaves can't be drilled into.
+        assert!(harness.click(Point::new(50.0, 100.0)).is_empty());
+
+        // A right click drills back up, and so does the breadcrumb of the root.
+        assert_eq!(
+            harness.simulate(&[Input::Press(mouse::Button::Right)]),
+            vec![Change::Drill(vec![])]
+        );
+        assert!(harness.click(Point::new(175.0, 100.0)).is_empty());
+        assert_eq!(
+            harness.click(Point::new(50.0, 100.0)),
+            vec![Change::Drill(vec![0])]
+        );
+        assert_eq!(
+            harness.click(Point::new(10.0, 13.0)),
+            vec![Change::Drill(vec![])]
+        );
+    }
+}
@@ -0,0 +1,431 @@
+//! Use a volume slider to change the volume of some audio.
+//!
+//! *This API requires the following crate features to be activated: `volume_slider`*
+use crate::core::volume::VolumeScale;
+use crate::graphics::icons::{Icon, ICON_FONT};
+use crate::style::volume_slider::StyleSheet;
+
+use iced_widget::{
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event,
+        layout::{Limits, Node},
+        mouse::{self, click, Cursor},
+        renderer, text, touch,
+        widget::{
+            tree::{State as TreeState, Tag},
+            Tree,
+        },
+        Clipboard, Color, Element, Event, Layout, Length, Rectangle, Shell, Size, Widget,
+    },
+    text::LineHeight,
+};
+
+/// The amount the position of the slider changes per scrolled line.
+const SCROLL_STEP: f32 = 0.05;
+/// The spacing between the speaker icon and the rail.
+const SPACING: f32 = 6.0;
+
+/// A slider for the volume of some audio with a speaker icon to mute it.
+///
+/// The slider maps its position to a gain with a [`VolumeScale`], which
+/// defaults to decibels, so that the loudness changes evenly along the slider.
+/// The volume can be changed with the scroll wheel and is reset to its
+/// default with a double click.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::VolumeSlider;
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     VolumeChanged(f32),
+///     MuteToggled(bool),
+/// }
+///
+/// let volume_slider = VolumeSlider::new(0.5, Message::VolumeChanged)
+///     .muted(false)
+///     .on_toggle_mute(Message::MuteToggled);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct VolumeSlider<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The gain from 0.0 to 1.0.
+    volume: f32,
+    /// Whether the audio is muted.
+    muted: bool,
+    /// The function producing the message when the volume changes.
+    on_change: Box<dyn Fn(f32) -> Message + 'a>,
+    /// The function producing the message when the speaker icon is pressed.
+    on_toggle_mute: Option<Box<dyn Fn(bool) -> Message + 'a>>,
+    /// The scale mapping the position of the slider to the gain.
+    scale: VolumeScale,
+    /// The gain a double click resets the volume to.
+    default_volume: f32,
+    /// The width of the [`VolumeSlider`].
+    width: Length,
+    /// The height of the [`VolumeSlider`].
+    height: f32,
+    /// The height of the rail.
+    rail_height: f32,
+    /// The size of the speaker icon.
+    icon_size: f32,
+    /// The style of the [`VolumeSlider`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> VolumeSlider<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`VolumeSlider`] with the given gain from 0.0 to 1.0,
+    /// producing the message of `on_change` when the volume changes.
+    pub fn new<F>(volume: f32, on_change: F) -> Self
+    where
+        F: 'a + Fn(f32) -> Message,
+    {
+        Self {
+            volume,
+            muted: false,
+            on_change: Box::new(on_change),
+            on_toggle_mute: None,
+            scale: VolumeScale::default(),
+            default_volume: 1.0,
+            width: Length::Fill,
+            height: 24.0,
+            rail_height: 4.0,
+            icon_size: 18.0,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets whether the audio of the [`VolumeSlider`] is muted.
+    #[must_use]
+    pub fn muted(mut self, muted: bool) -> Self {
+        self.muted = muted;
+        self
+    }
+
+    /// Sets the function producing the message when the speaker icon of the
+    /// [`VolumeSlider`] is pressed, receiving whether to mute the audio.
+    #[must_use]
+    pub fn on_toggle_mute<F>(mut self, on_toggle_mute: F) -> Self
+    where
+        F: 'a + Fn(bool) -> Message,
+    {
+        self.on_toggle_mute = Some(Box::new(on_toggle_mute));
+        self
+    }
+
+    /// Sets the [`VolumeScale`] of the [`VolumeSlider`].
+    #[must_use]
+    pub fn scale(mut self, scale: VolumeScale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Sets the gain a double click resets the [`VolumeSlider`] to.
+    #[must_use]
+    pub fn default_volume(mut self, default_volume: f32) -> Self {
+        self.default_volume = default_volume;
+        self
+    }
+
+    /// Sets the width of the [`VolumeSlider`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`VolumeSlider`].
+    #[must_use]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the height of the rail of the [`VolumeSlider`].
+    #[must_use]
+    pub fn rail_height(mut self, rail_height: f32) -> Self {
+        self.rail_height = rail_height;
+        self
+    }
+
+    /// Sets the size of the speaker icon of the [`VolumeSlider`].
+    #[must_use]
+    pub fn icon_size(mut self, icon_size: f32) -> Self {
+        self.icon_size = icon_size;
+        self
+    }
+
+    /// Sets the style of the [`VolumeSlider`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the bounds of the speaker icon and the rail.
+    fn split(bounds: Rectangle) -> (Rectangle, Rectangle) {
+        let icon = Rectangle {
+            width: bounds.height,
+            ..bounds
+        };
+        let rail = Rectangle {
+            x: icon.x + icon.width + SPACING,
+            width: (bounds.width - icon.width - SPACING).max(0.0),
+            ..bounds
+        };
+
+        (icon, rail)
+    }
+
+    /// Returns the gain at the given horizontal position of the rail.
+    fn gain_at(&self, rail: Rectangle, x: f32) -> f32 {
+        let position = if rail.width > 0.0 {
+            (x - rail.x) / rail.width
+        } else {
+            0.0
+        };
+
+        self.scale.gain_at(position)
+    }
+}
+
+/// The state of a [`VolumeSlider`].
+#[derive(Clone, Copy, Debug, Default)]
+struct State {
+    /// Whether the handle is being dragged.
+    dragging: bool,
+    /// The last click on the rail, for detecting double clicks.
+    last_click: Option<click::Click>,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for VolumeSlider<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Fixed(self.height)
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(Length::Fixed(self.height));
+
+        Node::new(limits.resolve(Size::new(limits.max().width, self.height)))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let (icon, rail) = Self::split(layout.bounds());
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(on_toggle_mute) = &self.on_toggle_mute {
+                    if cursor.is_over(icon) {
+                        shell.publish(on_toggle_mute(!self.muted));
+                        return event::Status::Captured;
+                    }
+                }
+
+                if let Some(position) = cursor.position_over(rail) {
+                    let click = click::Click::new(position, state.last_click);
+                    state.last_click = Some(click);
+
+                    if matches!(click.kind(), click::Kind::Double) {
+                        state.dragging = false;
+                        shell.publish((self.on_change)(self.default_volume));
+                    } else {
+                        state.dragging = true;
+                        shell.publish((self.on_change)(self.gain_at(rail, position.x)));
+                    }
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if state.dragging =>
+            {
+                shell.publish((self.on_change)(self.gain_at(rail, position.x)));
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if state.dragging =>
+            {
+                state.dragging = false;
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if cursor.is_over(layout.bounds()) =>
+            {
+                let lines = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / 20.0,
+                };
+                let position = self.scale.position_of(self.volume) + lines * SCROLL_STEP;
+                shell.publish((self.on_change)(self.scale.gain_at(position)));
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let (icon, rail) = Self::split(layout.bounds());
+
+        if tree.state.downcast_ref::<State>().dragging {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(rail) || (self.on_toggle_mute.is_some() && cursor.is_over(icon)) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let (icon, rail) = Self::split(layout.bounds());
+        let appearance = theme.active(&self.style);
+        let position = self.scale.position_of(self.volume);
+
+        let speaker = if self.muted || self.volume <= 0.0 {
+            Icon::VolumeMuteFill
+        } else if position < 0.5 {
+            Icon::VolumeDownFill
+        } else {
+            Icon::VolumeUpFill
+        };
+
+        renderer.fill_text(core::text::Text {
+            content: &char::from(speaker).to_string(),
+            bounds: Rectangle {
+                x: icon.center_x(),
+                y: icon.center_y(),
+                ..icon
+            },
+            size: self.icon_size,
+            line_height: LineHeight::default(),
+            color: appearance.icon_color,
+            font: ICON_FONT,
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+            shaping: text::Shaping::Basic,
+        });
+
+        let track = Rectangle {
+            y: rail.center_y() - self.rail_height / 2.0,
+            height: self.rail_height,
+            ..rail
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: track,
+                border_radius: (self.rail_height / 2.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.rail_color,
+        );
+
+        let fill_color = if self.muted {
+            appearance.muted_fill_color
+        } else {
+            appearance.fill_color
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    width: track.width * position,
+                    ..track
+                },
+                border_radius: (self.rail_height / 2.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            fill_color,
+        );
+
+        // Handle
+        let radius = self.rail_height * 2.0;
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: track.x + track.width * position - radius,
+                    y: track.center_y() - radius,
+                    width: radius * 2.0,
+                    height: radius * 2.0,
+                },
+                border_radius: radius.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            if self.muted {
+                appearance.muted_fill_color
+            } else {
+                appearance.handle_color
+            },
+        );
+    }
+}
+
+impl<'a, Message, Renderer> From<VolumeSlider<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(volume_slider: VolumeSlider<'a, Message, Renderer>) -> Self {
+        Element::new(volume_slider)
+    }
+}
@@ -0,0 +1,539 @@
+//! Use a waveform to display the peaks of audio samples.
+//!
+//! *This API requires the following crate features to be activated: `waveform`*
+use crate::style::waveform::StyleSheet;
+
+use iced_widget::core::{
+    self, event, keyboard,
+    layout::{Limits, Node},
+    mouse::{self, Cursor},
+    renderer, touch,
+    widget::{
+        tree::{State as TreeState, Tag},
+        Tree,
+    },
+    Background, Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size,
+    Widget,
+};
+
+/// The number of samples summarized by a peak of the finest level of [`Peaks`].
+const BLOCK_SIZE: usize = 16;
+/// The factor the zoom changes with per scrolled line.
+const ZOOM_STEP: f64 = 1.2;
+/// The number of pixels scrolled per scrolled line.
+const SCROLL_STEP: f64 = 40.0;
+
+/// The minimum and maximum values of audio samples, precomputed as a pyramid of
+/// levels each summarizing twice as many samples per peak as the level below.
+///
+/// Building the [`Peaks`] is linear in the number of samples, but afterwards
+/// the peaks of any range are found by only looking at a few precomputed peaks,
+/// keeping the drawing of long files fast at every zoom.
+#[derive(Clone, Debug, Default)]
+pub struct Peaks {
+    /// The samples.
+    samples: Vec<f32>,
+    /// The levels of the pyramid, starting with [`BLOCK_SIZE`] samples per peak.
+    levels: Vec<Vec<(f32, f32)>>,
+}
+
+impl Peaks {
+    /// Creates new [`Peaks`] from the given samples.
+    ///
+    /// The samples are expected to be in the range from -1.0 to 1.0.
+    #[must_use]
+    pub fn new(samples: impl Into<Vec<f32>>) -> Self {
+        let samples = samples.into();
+        let mut levels: Vec<Vec<(f32, f32)>> = Vec::new();
+
+        let mut level: Vec<(f32, f32)> = samples.chunks(BLOCK_SIZE).map(min_max).collect();
+
+        while level.len() > 1 {
+            let next = level
+                .chunks(2)
+                .map(|pair| {
+                    pair.iter()
+                        .fold((f32::MAX, f32::MIN), |(min, max), (low, high)| {
+                            (min.min(*low), max.max(*high))
+                        })
+                })
+                .collect();
+            levels.push(level);
+            level = next;
+        }
+        levels.push(level);
+
+        Self { samples, levels }
+    }
+
+    /// Returns the number of samples.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Returns `true` if there are no samples.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Returns the samples.
+    #[must_use]
+    pub fn samples(&self) -> &[f32] {
+        &self.samples
+    }
+
+    /// Returns the minimum and maximum value of the samples from `start` to
+    /// `end`, if the range contains any samples.
+    ///
+    /// Ranges spanning many samples are answered from the coarsest level whose
+    /// peaks fit at least twice into the range, so the result may include a few
+    /// samples next to the range.
+    #[must_use]
+    pub fn range(&self, start: usize, end: usize) -> Option<(f32, f32)> {
+        let end = end.min(self.samples.len());
+        if start >= end {
+            return None;
+        }
+
+        let length = end - start;
+        if length < BLOCK_SIZE * 2 {
+            return Some(min_max(&self.samples[start..end]));
+        }
+
+        // The coarsest level with peaks of at most half the length of the range.
+        let level = (usize::BITS - (length / (BLOCK_SIZE * 2)).leading_zeros() - 1) as usize;
+        let level = level.min(self.levels.len() - 1);
+        let block = BLOCK_SIZE << level;
+        let peaks = &self.levels[level];
+
+        let first = start / block;
+        let last = end.div_ceil(block).min(peaks.len());
+
+        peaks[first..last]
+            .iter()
+            .copied()
+            .reduce(|(min, max), (low, high)| (min.min(low), max.max(high)))
+    }
+}
+
+/// Returns the minimum and maximum of the given samples.
+fn min_max(samples: &[f32]) -> (f32, f32) {
+    samples
+        .iter()
+        .fold((f32::MAX, f32::MIN), |(min, max), sample| {
+            (min.min(*sample), max.max(*sample))
+        })
+}
+
+/// A display of the peaks of audio samples.
+///
+/// The [`Waveform`] can be zoomed with the mouse wheel while holding the
+/// control key and scrolled with the mouse wheel otherwise. Dragging over the
+/// [`Waveform`] selects a region.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{waveform::Peaks, Waveform};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Select(usize, usize),
+/// }
+///
+/// let peaks = Peaks::new(vec![0.0, 0.5, -0.5, 0.25]);
+///
+/// let waveform = Waveform::new(&peaks)
+///     .playhead(2)
+///     .on_select(Message::Select);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Waveform<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The peaks of the samples to display.
+    peaks: &'a Peaks,
+    /// The sample the playhead is at.
+    playhead: Option<usize>,
+    /// The selected region from the start to the end sample.
+    selection: Option<(usize, usize)>,
+    /// The function producing the message when a region is selected.
+    on_select: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    /// The width of the [`Waveform`].
+    width: Length,
+    /// The height of the [`Waveform`].
+    height: Length,
+    /// The style of the [`Waveform`].
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Waveform<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Waveform`] displaying the given [`Peaks`].
+    #[must_use]
+    pub fn new(peaks: &'a Peaks) -> Self {
+        Self {
+            peaks,
+            playhead: None,
+            selection: None,
+            on_select: None,
+            width: Length::Fill,
+            height: Length::Fixed(100.0),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the sample the playhead of the [`Waveform`] is at.
+    #[must_use]
+    pub fn playhead(mut self, sample: usize) -> Self {
+        self.playhead = Some(sample);
+        self
+    }
+
+    /// Sets the selected region of the [`Waveform`] from the `start` to the
+    /// `end` sample.
+    #[must_use]
+    pub fn selection(mut self, start: usize, end: usize) -> Self {
+        self.selection = Some((start.min(end), start.max(end)));
+        self
+    }
+
+    /// Sets the function producing the message with the start and end sample
+    /// when a region of the [`Waveform`] is selected.
+    #[must_use]
+    pub fn on_select<F>(mut self, on_select: F) -> Self
+    where
+        F: 'a + Fn(usize, usize) -> Message,
+    {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the width of the [`Waveform`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Waveform`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`Waveform`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+/// The state of a [`Waveform`].
+#[derive(Clone, Copy, Debug)]
+struct State {
+    /// The zoom relative to showing all samples.
+    zoom: f64,
+    /// The first visible sample.
+    offset: f64,
+    /// The sample a selection was started at.
+    drag_origin: Option<usize>,
+    /// The pressed keyboard modifiers.
+    keyboard_modifiers: keyboard::Modifiers,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            zoom: 1.0,
+            offset: 0.0,
+            drag_origin: None,
+            keyboard_modifiers: keyboard::Modifiers::default(),
+        }
+    }
+}
+
+impl State {
+    /// Returns the number of samples per pixel for the given width.
+    fn samples_per_pixel(&self, len: usize, width: f32) -> f64 {
+        #[allow(clippy::cast_precision_loss)]
+        let len = len as f64;
+        len / f64::from(width.max(1.0)) / self.zoom
+    }
+
+    /// Limits the offset to keep the view inside of the samples.
+    fn clamp_offset(&mut self, len: usize, width: f32) {
+        let visible = self.samples_per_pixel(len, width) * f64::from(width);
+
+        #[allow(clippy::cast_precision_loss)]
+        let max = (len as f64 - visible).max(0.0);
+        self.offset = self.offset.clamp(0.0, max);
+    }
+
+    /// Returns the sample at the given horizontal position.
+    fn sample_at(&self, len: usize, bounds: Rectangle, x: f32) -> usize {
+        let spp = self.samples_per_pixel(len, bounds.width);
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let sample = (self.offset + f64::from(x - bounds.x) * spp).max(0.0) as usize;
+        sample.min(len)
+    }
+
+    /// Returns the horizontal position of the given sample.
+    fn x_of(&self, len: usize, bounds: Rectangle, sample: usize) -> f32 {
+        let spp = self.samples_per_pixel(len, bounds.width);
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+        let x = ((sample as f64 - self.offset) / spp) as f32;
+        bounds.x + x
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Waveform<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let len = self.peaks.len();
+
+        match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.keyboard_modifiers = modifiers;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                let (x, y) = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => (f64::from(x), f64::from(y)),
+                    mouse::ScrollDelta::Pixels { x, y } => {
+                        (f64::from(x) / SCROLL_STEP, f64::from(y) / SCROLL_STEP)
+                    }
+                };
+
+                if state.keyboard_modifiers.command() {
+                    // Zoom around the sample under the cursor.
+                    let anchor = state.sample_at(len, bounds, position.x);
+                    state.zoom = (state.zoom * ZOOM_STEP.powf(y)).max(1.0);
+
+                    let spp = state.samples_per_pixel(len, bounds.width);
+                    #[allow(clippy::cast_precision_loss)]
+                    let offset = anchor as f64 - f64::from(position.x - bounds.x) * spp;
+                    state.offset = offset;
+                } else {
+                    let spp = state.samples_per_pixel(len, bounds.width);
+                    state.offset -= (x + y) * SCROLL_STEP * spp;
+                }
+
+                state.clamp_offset(len, bounds.width);
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if self.on_select.is_some() =>
+            {
+                if let Some(position) = cursor.position_over(bounds) {
+                    state.drag_origin = Some(state.sample_at(len, bounds, position.x));
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let (Some(origin), Some(on_select)) = (state.drag_origin, &self.on_select) {
+                    let sample = state.sample_at(len, bounds, position.x);
+                    shell.publish(on_select(origin.min(sample), origin.max(sample)));
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if state.drag_origin.is_some() =>
+            {
+                state.drag_origin = None;
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.on_select.is_some() && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let len = self.peaks.len();
+
+        // The view may have become invalid when the samples or the bounds changed.
+        let mut state = *tree.state.downcast_ref::<State>();
+        state.clamp_offset(len, bounds.width);
+
+        let fill = |renderer: &mut Renderer, bounds: Rectangle, background: Background| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: (0.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                background,
+            );
+        };
+
+        fill(renderer, bounds, appearance.background);
+
+        renderer.with_layer(bounds, |renderer| {
+            // Selection
+            if let Some((start, end)) = self.selection {
+                let left = state.x_of(len, bounds, start);
+                let right = state.x_of(len, bounds, end);
+
+                fill(
+                    renderer,
+                    Rectangle {
+                        x: left,
+                        y: bounds.y,
+                        width: (right - left).max(1.0),
+                        height: bounds.height,
+                    },
+                    appearance.selection_background,
+                );
+            }
+
+            // Center line
+            let center = bounds.center_y();
+            fill(
+                renderer,
+                Rectangle::new(
+                    Point::new(bounds.x, center.floor()),
+                    Size::new(bounds.width, 1.0),
+                ),
+                appearance.center_line_color.into(),
+            );
+
+            // Peaks, one column per pixel.
+            let spp = state.samples_per_pixel(len, bounds.width);
+            let half_height = bounds.height / 2.0;
+
+            #[allow(
+                clippy::cast_possible_truncation,
+                clippy::cast_sign_loss,
+                clippy::cast_precision_loss
+            )]
+            for column in 0..bounds.width.ceil() as usize {
+                let start = (state.offset + column as f64 * spp) as usize;
+                let end = ((state.offset + (column + 1) as f64 * spp) as usize).max(start + 1);
+
+                if let Some((min, max)) = self.peaks.range(start, end) {
+                    let top = center - max.clamp(-1.0, 1.0) * half_height;
+                    let bottom = center - min.clamp(-1.0, 1.0) * half_height;
+
+                    fill(
+                        renderer,
+                        Rectangle {
+                            x: bounds.x + column as f32,
+                            y: top,
+                            width: 1.0,
+                            height: (bottom - top).max(1.0),
+                        },
+                        appearance.wave_color.into(),
+                    );
+                }
+            }
+
+            // Playhead
+            if let Some(playhead) = self.playhead {
+                let x = state.x_of(len, bounds, playhead);
+
+                fill(
+                    renderer,
+                    Rectangle {
+                        x: x.floor() - 1.0,
+                        y: bounds.y,
+                        width: 2.0,
+                        height: bounds.height,
+                    },
+                    appearance.playhead_color.into(),
+                );
+            }
+        });
+    }
+}
+
+impl<'a, Message, Renderer> From<Waveform<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(waveform: Waveform<'a, Message, Renderer>) -> Self {
+        Element::new(waveform)
+    }
+}
@@ -0,0 +1,588 @@
+//! Use a wizard to guide through a sequence of pages in a modal dialog.
+//!
+//! *This API requires the following crate features to be activated: `wizard`*
+
+use crate::core::i18n;
+use crate::native::{card::Card, modal::Modal};
+use crate::style::{card, modal};
+
+use iced_widget::{
+    button,
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer, touch,
+        widget::Tree,
+        Alignment, Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size,
+        Widget,
+    },
+    renderer::Renderer,
+    text::{self, LineHeight},
+    Button, Column, Row, Space, Text,
+};
+
+pub use crate::style::wizard::{Appearance, StyleSheet};
+
+/// The radius of the circles of the steps.
+const STEP_RADIUS: f32 = 12.0;
+/// The space between the circles of the steps and their titles.
+const STEP_SPACING: f32 = 4.0;
+/// The spacing between the elements.
+const SPACING: f32 = 10.0;
+
+/// The progress through the pages of a [`Wizard`].
+///
+/// The application keeps the [`WizardProgress`] and updates it with the
+/// messages of the [`Wizard`]. It is plain data, so it can be persisted to
+/// resume an unfinished [`Wizard`] where it was left.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct WizardProgress {
+    /// The index of the current page.
+    pub current: usize,
+    /// The index of the furthest page reached so far.
+    pub reached: usize,
+}
+
+impl WizardProgress {
+    /// Creates a new [`WizardProgress`] on the first page.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            current: 0,
+            reached: 0,
+        }
+    }
+
+    /// Returns the [`WizardProgress`] on the next page.
+    #[must_use]
+    pub fn next(self) -> Self {
+        self.go_to(self.current + 1)
+    }
+
+    /// Returns the [`WizardProgress`] on the previous page.
+    #[must_use]
+    pub fn back(self) -> Self {
+        self.go_to(self.current.saturating_sub(1))
+    }
+
+    /// Returns the [`WizardProgress`] on the given page, extending the
+    /// reached pages if it is further.
+    #[must_use]
+    pub fn go_to(self, page: usize) -> Self {
+        Self {
+            current: page,
+            reached: self.reached.max(page),
+        }
+    }
+}
+
+/// A page of a [`Wizard`].
+#[allow(missing_debug_implementations)]
+pub struct WizardPage<'a, Message, Theme> {
+    /// The title of the page.
+    title: String,
+    /// The content of the page.
+    content: Element<'a, Message, Renderer<Theme>>,
+    /// The function validating the page before going to the next one.
+    validate: Option<Box<dyn Fn() -> bool + 'a>>,
+}
+
+impl<'a, Message, Theme> WizardPage<'a, Message, Theme> {
+    /// Creates a new [`WizardPage`] with the given title and content.
+    pub fn new<C>(title: impl Into<String>, content: C) -> Self
+    where
+        C: Into<Element<'a, Message, Renderer<Theme>>>,
+    {
+        Self {
+            title: title.into(),
+            content: content.into(),
+            validate: None,
+        }
+    }
+
+    /// Sets the function validating the [`WizardPage`].
+    ///
+    /// The `Next` and `Finish` buttons are disabled while it returns `false`.
+    #[must_use]
+    pub fn validate<F>(mut self, validate: F) -> Self
+    where
+        F: 'a + Fn() -> bool,
+    {
+        self.validate = Some(Box::new(validate));
+        self
+    }
+
+    /// Returns whether the [`WizardPage`] is valid.
+    fn is_valid(&self) -> bool {
+        self.validate.as_ref().is_none_or(|validate| validate())
+    }
+}
+
+/// A dialog guiding through a sequence of [`WizardPage`]s, shown in a
+/// [`Modal`] over the underlay.
+///
+/// The dialog is a [`Card`] with the steps and the current page in its body,
+/// and `Back`, `Next` and `Finish` buttons in its foot. The `Next` and
+/// `Finish` buttons are only enabled while the current page is valid. The
+/// steps up to the furthest reached page can be clicked to go back to them.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::Text;
+/// # use iced_aw::wizard::{Wizard, WizardPage, WizardProgress};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     PageChanged(WizardProgress),
+///     Finished,
+/// }
+///
+/// let name = String::from("Jane");
+/// let progress = WizardProgress::new();
+///
+/// let wizard = Wizard::new(
+///     Text::new("Underlay"),
+///     true,
+///     "Setup",
+///     vec![
+///         WizardPage::new("Name", Text::new("Enter your name"))
+///             .validate(|| !name.is_empty()),
+///         WizardPage::new("Done", Text::new("All set!")),
+///     ],
+///     progress,
+///     Message::PageChanged,
+///     Message::Finished,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Wizard<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The underlay of the [`Wizard`].
+    underlay: Element<'a, Message, Renderer<Theme>>,
+    /// Whether the [`Wizard`] is shown.
+    show: bool,
+    /// The title of the [`Wizard`].
+    title: String,
+    /// The pages of the [`Wizard`].
+    pages: Vec<WizardPage<'a, Message, Theme>>,
+    /// The progress through the pages.
+    progress: WizardProgress,
+    /// The function producing the message when the page is changed.
+    on_change: Box<dyn Fn(WizardProgress) -> Message + 'a>,
+    /// The message produced when the last page is finished.
+    on_finish: Message,
+    /// The message produced when the [`Wizard`] is cancelled.
+    on_cancel: Option<Message>,
+    /// The maximum width of the dialog.
+    max_width: f32,
+    /// The text size of the steps.
+    text_size: f32,
+    /// The style of the steps of the [`Wizard`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> Wizard<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + StyleSheet
+        + button::StyleSheet
+        + card::StyleSheet
+        + modal::StyleSheet
+        + text::StyleSheet,
+{
+    /// Creates a new [`Wizard`] over the given underlay.
+    ///
+    /// It expects:
+    ///     * the underlay [`Element`] over which the [`Wizard`] is shown.
+    ///     * whether the [`Wizard`] is shown.
+    ///     * the title of the [`Wizard`].
+    ///     * the pages of the [`Wizard`].
+    ///     * the current [`WizardProgress`].
+    ///     * the function producing the message with the new [`WizardProgress`]
+    ///       when the page is changed.
+    ///     * the message produced when the last page is finished.
+    pub fn new<U, F>(
+        underlay: U,
+        show: bool,
+        title: impl Into<String>,
+        pages: Vec<WizardPage<'a, Message, Theme>>,
+        progress: WizardProgress,
+        on_change: F,
+        on_finish: Message,
+    ) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer<Theme>>>,
+        F: 'a + Fn(WizardProgress) -> Message,
+    {
+        Self {
+            underlay: underlay.into(),
+            show,
+            title: title.into(),
+            pages,
+            progress,
+            on_change: Box::new(on_change),
+            on_finish,
+            on_cancel: None,
+            max_width: 500.0,
+            text_size: 14.0,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the message produced when the [`Wizard`] is cancelled with its
+    /// close button or the Escape key.
+    #[must_use]
+    pub fn on_cancel(mut self, message: Message) -> Self {
+        self.on_cancel = Some(message);
+        self
+    }
+
+    /// Sets the maximum width of the dialog of the [`Wizard`].
+    #[must_use]
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the text size of the steps of the [`Wizard`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the style of the steps of the [`Wizard`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Creates the dialog showing the current page.
+    fn dialog(&mut self) -> Element<'a, Message, Renderer<Theme>> {
+        let last = self.pages.len().saturating_sub(1);
+        let current = self.progress.current.min(last);
+        let progress = WizardProgress {
+            current,
+            reached: self.progress.reached.clamp(current, last),
+        };
+        let valid = self.pages.get(current).is_some_and(WizardPage::is_valid);
+
+        let steps = Steps {
+            titles: self.pages.iter().map(|page| page.title.clone()).collect(),
+            progress,
+            targets: (0..self.pages.len())
+                .map(|page| {
+                    (page != current && page <= progress.reached)
+                        .then(|| (self.on_change)(progress.go_to(page)))
+                })
+                .collect(),
+            text_size: self.text_size,
+            style: self.style.clone(),
+        };
+
+        let translations = i18n::get();
+        let button = |label: String, message: Option<Message>| {
+            let button = Button::new(Text::new(label));
+            match message {
+                Some(message) => button.on_press(message),
+                None => button,
+            }
+        };
+
+        let back = button(
+            translations.back(),
+            (current > 0).then(|| (self.on_change)(progress.back())),
+        );
+        let next = if current == last {
+            button(translations.finish(), valid.then(|| self.on_finish.clone()))
+        } else {
+            button(
+                translations.next(),
+                valid.then(|| (self.on_change)(progress.next())),
+            )
+        };
+
+        let mut foot = Row::new().spacing(SPACING).align_items(Alignment::Center);
+        if let Some(on_cancel) = self.on_cancel.clone() {
+            foot = foot.push(button(translations.cancel(), Some(on_cancel)));
+        }
+        let foot = foot
+            .push(Space::with_width(Length::Fill))
+            .push(back)
+            .push(next);
+
+        let mut body = Column::new().spacing(SPACING * 2.0).push(steps);
+        if current < self.pages.len() {
+            body = body.push(self.pages.swap_remove(current).content);
+        }
+
+        let mut card = Card::new(Text::new(self.title.clone()), body)
+            .foot(foot)
+            .max_width(self.max_width);
+        if let Some(on_cancel) = self.on_cancel.clone() {
+            card = card.on_close(on_cancel);
+        }
+
+        card.into()
+    }
+}
+
+impl<'a, Message, Theme> From<Wizard<'a, Message, Theme>> for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + StyleSheet
+        + button::StyleSheet
+        + card::StyleSheet
+        + modal::StyleSheet
+        + text::StyleSheet,
+{
+    fn from(mut wizard: Wizard<'a, Message, Theme>) -> Self {
+        let dialog = wizard.show.then(|| wizard.dialog());
+        let mut modal = Modal::new(wizard.underlay, dialog);
+
+        if let Some(on_cancel) = wizard.on_cancel {
+            modal = modal.on_esc(on_cancel);
+        }
+
+        modal.into()
+    }
+}
+
+/// The steps of a [`Wizard`], showing the progress through its pages.
+struct Steps<Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The titles of the pages.
+    titles: Vec<String>,
+    /// The progress through the pages.
+    progress: WizardProgress,
+    /// The messages produced when the steps are clicked.
+    targets: Vec<Option<Message>>,
+    /// The text size of the titles.
+    text_size: f32,
+    /// The style of the steps.
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<Message, Theme> Steps<Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Returns the centers of the circles of the steps.
+    #[allow(clippy::cast_precision_loss)]
+    fn centers(&self, bounds: Rectangle) -> Vec<Point> {
+        let width = bounds.width / self.titles.len().max(1) as f32;
+
+        (0..self.titles.len())
+            .map(|step| {
+                Point::new(
+                    (step as f32 + 0.5).mul_add(width, bounds.x),
+                    bounds.y + STEP_RADIUS,
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the step under the cursor.
+    #[allow(clippy::cast_precision_loss)]
+    fn step_at(&self, bounds: Rectangle, cursor: Cursor) -> Option<usize> {
+        let position = cursor.position_over(bounds)?;
+
+        let half_width = bounds.width / self.titles.len() as f32 / 2.0;
+
+        self.centers(bounds)
+            .iter()
+            .position(|center| (position.x - center.x).abs() <= half_width)
+    }
+}
+
+impl<Message, Theme> Widget<Message, Renderer<Theme>> for Steps<Message, Theme>
+where
+    Message: Clone,
+    Theme: StyleSheet,
+{
+    fn width(&self) -> Length {
+        Length::Fill
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, _renderer: &Renderer<Theme>, limits: &Limits) -> Node {
+        let height = STEP_RADIUS.mul_add(
+            2.0,
+            STEP_SPACING + LineHeight::default().to_absolute(self.text_size.into()).0,
+        );
+
+        Node::new(limits.width(Length::Fill).resolve(Size::new(0.0, height)))
+    }
+
+    fn on_event(
+        &mut self,
+        _state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer<Theme>,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let target = self
+                    .step_at(layout.bounds(), cursor)
+                    .and_then(|step| self.targets[step].clone());
+
+                target.map_or(event::Status::Ignored, |message| {
+                    shell.publish(message);
+                    event::Status::Captured
+                })
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer<Theme>,
+    ) -> mouse::Interaction {
+        match self.step_at(layout.bounds(), cursor) {
+            Some(step) if self.targets[step].is_some() => mouse::Interaction::Pointer,
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn draw(
+        &self,
+        _state: &Tree,
+        renderer: &mut Renderer<Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let appearance = theme.active(&self.style);
+        let bounds = layout.bounds();
+        let centers = self.centers(bounds);
+
+        for (step, pair) in centers.windows(2).enumerate() {
+            core::Renderer::fill_quad(
+                renderer,
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: pair[0].x + STEP_RADIUS,
+                        y: pair[0].y - appearance.line_width / 2.0,
+                        width: pair[1].x - pair[0].x - STEP_RADIUS * 2.0,
+                        height: appearance.line_width,
+                    },
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                if step < self.progress.reached {
+                    appearance.completed_color
+                } else {
+                    appearance.pending_color
+                },
+            );
+        }
+
+        for (step, (center, title)) in centers.iter().zip(&self.titles).enumerate() {
+            let (background, text_color) = if step == self.progress.current {
+                (appearance.current_color, appearance.current_text_color)
+            } else if step <= self.progress.reached {
+                (appearance.completed_color, appearance.completed_text_color)
+            } else {
+                (appearance.pending_color, appearance.pending_text_color)
+            };
+
+            core::Renderer::fill_quad(
+                renderer,
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: center.x - STEP_RADIUS,
+                        y: center.y - STEP_RADIUS,
+                        width: STEP_RADIUS * 2.0,
+                        height: STEP_RADIUS * 2.0,
+                    },
+                    border_radius: STEP_RADIUS.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                background,
+            );
+
+            core::text::Renderer::fill_text(
+                renderer,
+                core::text::Text {
+                    content: &(step + 1).to_string(),
+                    bounds: Rectangle {
+                        x: center.x,
+                        y: center.y,
+                        width: STEP_RADIUS * 2.0,
+                        height: STEP_RADIUS * 2.0,
+                    },
+                    size: self.text_size,
+                    line_height: LineHeight::default(),
+                    color: text_color,
+                    font: core::Font::DEFAULT,
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    shaping: core::text::Shaping::Basic,
+                },
+            );
+
+            core::text::Renderer::fill_text(
+                renderer,
+                core::text::Text {
+                    content: title,
+                    bounds: Rectangle {
+                        x: center.x,
+                        y: center.y + STEP_RADIUS + STEP_SPACING,
+                        width: bounds.width / self.titles.len() as f32,
+                        height: bounds.height,
+                    },
+                    size: self.text_size,
+                    line_height: LineHeight::default(),
+                    color: if step == self.progress.current {
+                        appearance.title_color
+                    } else {
+                        appearance.pending_title_color
+                    },
+                    font: core::Font::DEFAULT,
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Top,
+                    shaping: core::text::Shaping::Advanced,
+                },
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme> From<Steps<Message, Theme>> for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'a + Clone,
+    Theme: 'a + StyleSheet,
+{
+    fn from(steps: Steps<Message, Theme>) -> Self {
+        Element::new(steps)
+    }
+}
@@ -0,0 +1,76 @@
+//! Use an animated number to show a value rolling or counting to its updates.
+//!
+//! *This API requires the following crate features to be activated: `animated_number`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of an [`AnimatedNumber`](crate::native::animated_number::AnimatedNumber).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`AnimatedNumber`](crate::native::animated_number::AnimatedNumber).
+    pub background: Background,
+    /// The color of the number at rest.
+    pub text_color: Color,
+    /// The color the number fades from while it rises.
+    pub rising_color: Color,
+    /// The color the number fades from while it falls.
+    pub falling_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::TRANSPARENT.into(),
+            text_color: Color::BLACK,
+            rising_color: [0.2, 0.6, 0.3].into(),
+            falling_color: [0.8, 0.25, 0.25].into(),
+        }
+    }
+}
+
+/// The appearance of an [`AnimatedNumber`](crate::native::animated_number::AnimatedNumber).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of an [`AnimatedNumber`](crate::native::animated_number::AnimatedNumber).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of an [`AnimatedNumber`](crate::native::animated_number::AnimatedNumber).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum AnimatedNumberStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl AnimatedNumberStyles {
+    /// Creates a custom [`AnimatedNumberStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = AnimatedNumberStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let AnimatedNumberStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            text_color: palette.background.base.text,
+            rising_color: palette.success.base.color,
+            falling_color: palette.danger.base.color,
+            ..Appearance::default()
+        }
+    }
+}
@@ -0,0 +1,118 @@
+//! Use an authentication form to sign in with an identifier and a password.
+//!
+//! *This API requires the following crate features to be activated: `auth_form`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of an [`AuthForm`](crate::native::auth_form::AuthForm).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`AuthForm`](crate::native::auth_form::AuthForm).
+    pub background: Background,
+    /// The border radius of the [`AuthForm`](crate::native::auth_form::AuthForm).
+    pub border_radius: f32,
+    /// The border width of the [`AuthForm`](crate::native::auth_form::AuthForm).
+    pub border_width: f32,
+    /// The border color of the [`AuthForm`](crate::native::auth_form::AuthForm).
+    pub border_color: Color,
+    /// The color of the labels and texts.
+    pub text_color: Color,
+    /// The color of the title.
+    pub title_color: Color,
+    /// The border radius of the error banner.
+    pub banner_border_radius: f32,
+    /// The background of the error banner.
+    pub error_background: Background,
+    /// The color of the errors of the banner and the fields.
+    pub error_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: 8.0,
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            text_color: Color::BLACK,
+            title_color: Color::BLACK,
+            banner_border_radius: 4.0,
+            error_background: Color::from_rgb(1.0, 0.92, 0.92).into(),
+            error_color: [0.75, 0.15, 0.15].into(),
+        }
+    }
+}
+
+/// The appearance of an [`AuthForm`](crate::native::auth_form::AuthForm).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of an [`AuthForm`](crate::native::auth_form::AuthForm).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearances of an [`AuthForm`](crate::native::auth_form::AuthForm).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum AuthFormStyles {
+    #[default]
+    Default,
+    /// A flat card with slightly rounded corners, like Material Design.
+    Material,
+    /// A borderless grouped panel with large rounded corners, like the
+    /// Cupertino design.
+    Cupertino,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl AuthFormStyles {
+    /// Creates a custom [`AuthFormStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = AuthFormStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+
+        let default = Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            title_color: palette.background.base.text,
+            error_background: Color {
+                a: 0.12,
+                ..palette.danger.base.color
+            }
+            .into(),
+            error_color: palette.danger.base.color,
+            ..Appearance::default()
+        };
+
+        match style {
+            AuthFormStyles::Default => default,
+            AuthFormStyles::Material => Appearance {
+                border_radius: 4.0,
+                border_width: 0.0,
+                title_color: palette.primary.strong.color,
+                banner_border_radius: 4.0,
+                ..default
+            },
+            AuthFormStyles::Cupertino => Appearance {
+                background: palette.background.weak.color.into(),
+                border_radius: 14.0,
+                border_width: 0.0,
+                banner_border_radius: 10.0,
+                ..default
+            },
+            AuthFormStyles::Custom(custom) => custom.active(self),
+        }
+    }
+}
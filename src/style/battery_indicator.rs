@@ -0,0 +1,128 @@
+//! Use a battery indicator to show the charge of a battery.
+//!
+//! *This API requires the following crate features to be activated: `battery_indicator`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`BatteryIndicator`](crate::native::battery_indicator::BatteryIndicator).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`BatteryIndicator`](crate::native::battery_indicator::BatteryIndicator).
+    pub background: Background,
+    /// The background of the empty part of the battery.
+    pub body_background: Background,
+    /// The border radius of the battery.
+    pub border_radius: f32,
+    /// The border width of the battery.
+    pub border_width: f32,
+    /// The border color of the battery.
+    pub border_color: Color,
+    /// The space between the border of the battery and its charge.
+    pub padding: f32,
+    /// The color of the terminal cap of the battery.
+    pub cap_color: Color,
+    /// The color of the charge.
+    pub fill_color: Color,
+    /// The color of the charge while charging.
+    pub charging_color: Color,
+    /// The color of the charge when it is low.
+    pub low_color: Color,
+    /// The color of the bolt shown while charging.
+    pub bolt_color: Color,
+    /// The color of the percentage.
+    pub text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::TRANSPARENT.into(),
+            body_background: Color::TRANSPARENT.into(),
+            border_radius: 3.0,
+            border_width: 1.0,
+            border_color: [0.5, 0.5, 0.5].into(),
+            padding: 2.0,
+            cap_color: [0.5, 0.5, 0.5].into(),
+            fill_color: [0.2, 0.7, 0.3].into(),
+            charging_color: [0.2, 0.7, 0.3].into(),
+            low_color: [0.85, 0.2, 0.2].into(),
+            bolt_color: Color::WHITE,
+            text_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`BatteryIndicator`](crate::native::battery_indicator::BatteryIndicator).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`BatteryIndicator`](crate::native::battery_indicator::BatteryIndicator).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearances of a [`BatteryIndicator`](crate::native::battery_indicator::BatteryIndicator).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum BatteryIndicatorStyles {
+    #[default]
+    Default,
+    /// A solid battery without a border, its empty part in a lighter tone,
+    /// like Material Design.
+    Material,
+    /// An outlined battery with rounded corners, its charge turning green
+    /// while charging, like the Cupertino design.
+    Cupertino,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl BatteryIndicatorStyles {
+    /// Creates a custom [`BatteryIndicatorStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = BatteryIndicatorStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+
+        let default = Appearance {
+            border_color: palette.background.strong.color,
+            cap_color: palette.background.strong.color,
+            fill_color: palette.success.base.color,
+            charging_color: palette.success.base.color,
+            low_color: palette.danger.base.color,
+            bolt_color: palette.success.base.text,
+            text_color: palette.background.base.text,
+            ..Appearance::default()
+        };
+
+        match style {
+            BatteryIndicatorStyles::Default => default,
+            BatteryIndicatorStyles::Material => Appearance {
+                body_background: palette.background.strong.color.into(),
+                border_radius: 2.0,
+                border_width: 0.0,
+                padding: 0.0,
+                fill_color: palette.background.base.text,
+                charging_color: palette.background.base.text,
+                bolt_color: palette.background.base.color,
+                ..default
+            },
+            BatteryIndicatorStyles::Cupertino => Appearance {
+                border_radius: 4.0,
+                padding: 1.5,
+                fill_color: palette.background.base.text,
+                bolt_color: palette.background.base.color,
+                ..default
+            },
+            BatteryIndicatorStyles::Custom(custom) => custom.active(self),
+        }
+    }
+}
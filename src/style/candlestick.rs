@@ -0,0 +1,124 @@
+//! Use a candlestick chart to show the prices of financial data over time.
+//!
+//! *This API requires the following crate features to be activated: `candlestick`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`CandlestickChart`](crate::native::candlestick::CandlestickChart).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`CandlestickChart`](crate::native::candlestick::CandlestickChart).
+    pub background: Background,
+    /// The color of the candles during which the price rose.
+    pub bullish_color: Color,
+    /// The color of the candles during which the price fell.
+    pub bearish_color: Color,
+    /// The colors of the lines of the overlays, which are used in turn.
+    pub overlay_palette: [Color; 6],
+    /// The width of the lines of the overlays.
+    pub overlay_width: f32,
+    /// The color of the grid lines at the axis labels.
+    pub grid_color: Color,
+    /// The color of the axes.
+    pub axis_color: Color,
+    /// The color of the axis labels.
+    pub text_color: Color,
+    /// The color of the crosshair following the cursor.
+    pub crosshair_color: Color,
+    /// The color of the guide lines, the points and the labels of the
+    /// annotations.
+    pub annotation_color: Color,
+    /// The color of the shaded regions of the annotations.
+    pub region_color: Color,
+    /// The background of the readouts of the crosshair.
+    pub readout_background: Background,
+    /// The color of the text of the readouts of the crosshair.
+    pub readout_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::TRANSPARENT.into(),
+            bullish_color: Color::from_rgb(0.15, 0.65, 0.4),
+            bearish_color: Color::from_rgb(0.85, 0.25, 0.25),
+            overlay_palette: [
+                [0.31, 0.47, 0.65].into(),
+                [0.95, 0.56, 0.17].into(),
+                [0.69, 0.48, 0.63].into(),
+                [0.46, 0.72, 0.7].into(),
+                [0.6, 0.6, 0.2].into(),
+                [0.5, 0.5, 0.5].into(),
+            ],
+            overlay_width: 1.5,
+            grid_color: Color::from_rgb(0.9, 0.9, 0.9),
+            axis_color: Color::from_rgb(0.5, 0.5, 0.5),
+            text_color: Color::BLACK,
+            crosshair_color: Color::from_rgba(0.0, 0.0, 0.0, 0.5),
+            annotation_color: Color::from_rgb(0.55, 0.3, 0.75),
+            region_color: Color::from_rgba(0.55, 0.3, 0.75, 0.12),
+            readout_background: Color::from_rgba(0.0, 0.0, 0.0, 0.8).into(),
+            readout_text_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of a [`CandlestickChart`](crate::native::candlestick::CandlestickChart).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`CandlestickChart`](crate::native::candlestick::CandlestickChart).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`CandlestickChart`](crate::native::candlestick::CandlestickChart).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum CandlestickStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl CandlestickStyles {
+    /// Creates a custom [`CandlestickStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = CandlestickStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let CandlestickStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            bullish_color: palette.success.base.color,
+            bearish_color: palette.danger.base.color,
+            grid_color: palette.background.weak.color,
+            axis_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            crosshair_color: Color {
+                a: 0.5,
+                ..palette.background.base.text
+            },
+            annotation_color: palette.primary.strong.color,
+            region_color: Color {
+                a: 0.12,
+                ..palette.primary.base.color
+            },
+            readout_background: palette.background.strong.color.into(),
+            readout_text_color: palette.background.strong.text,
+            ..Appearance::default()
+        }
+    }
+}
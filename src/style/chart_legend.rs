@@ -0,0 +1,83 @@
+//! Use a chart legend to list the series of a chart and toggle them.
+//!
+//! *This API requires the following crate features to be activated: `chart_legend`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`ChartLegend`](crate::native::chart_legend::ChartLegend).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`ChartLegend`](crate::native::chart_legend::ChartLegend).
+    pub background: Background,
+    /// The text color of the labels of the shown series.
+    pub text_color: Color,
+    /// The text color of the labels of the hidden series.
+    pub hidden_text_color: Color,
+    /// The background of a hovered entry.
+    pub hovered_background: Background,
+    /// The border radius of the swatches and of the background of a hovered
+    /// entry.
+    pub border_radius: f32,
+    /// The width of the outline of the swatches of the hidden series.
+    pub swatch_border_width: f32,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::TRANSPARENT.into(),
+            text_color: Color::BLACK,
+            hidden_text_color: [0.6, 0.6, 0.6].into(),
+            hovered_background: Background::Color([0.9, 0.9, 0.9].into()),
+            border_radius: 2.0,
+            swatch_border_width: 1.5,
+        }
+    }
+}
+
+/// The appearance of a [`ChartLegend`](crate::native::chart_legend::ChartLegend).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`ChartLegend`](crate::native::chart_legend::ChartLegend).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`ChartLegend`](crate::native::chart_legend::ChartLegend).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum ChartLegendStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl ChartLegendStyles {
+    /// Creates a custom [`ChartLegendStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = ChartLegendStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let ChartLegendStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            text_color: palette.background.base.text,
+            hidden_text_color: palette.background.strong.color,
+            hovered_background: palette.background.weak.color.into(),
+            ..Appearance::default()
+        }
+    }
+}
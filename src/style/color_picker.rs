@@ -42,7 +42,8 @@ pub trait StyleSheet {
     fn active(&self, style: &Self::Style) -> Appearance;
 
     /// The appearance when something is selected of the
-    /// [`ColorPicker`](crate::native::ColorPicker).
+    /// [`ColorPicker`](crate::native::ColorPicker), like the swatch of the
+    /// picked color.
     fn selected(&self, style: &Self::Style) -> Appearance;
 
     /// The appearance when something is hovered of the
@@ -52,6 +53,16 @@ pub trait StyleSheet {
     /// The appearance when something is focused of the
     /// [`ColorPicker`](crate::native::ColorPicker).
     fn focused(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of the text fields of the
+    /// [`ColorPicker`](crate::native::ColorPicker) when their text can't be
+    /// parsed.
+    fn invalid(&self, style: &Self::Style) -> Appearance {
+        Appearance {
+            bar_border_color: Color::from_rgb(0.8, 0.2, 0.2),
+            ..self.active(style)
+        }
+    }
 }
 
 /// The default appearance of the [`ColorPicker`](crate::native::ColorPicker).
@@ -97,7 +108,11 @@ impl StyleSheet for Theme {
             return custom.selected(self);
         }
 
-        self.active(style)
+        Appearance {
+            bar_border_width: 2.0,
+            bar_border_color: self.extended_palette().primary.strong.color,
+            ..self.active(style)
+        }
     }
 
     fn hovered(&self, style: &Self::Style) -> Appearance {
@@ -120,4 +135,15 @@ impl StyleSheet for Theme {
             ..self.active(style)
         }
     }
+
+    fn invalid(&self, style: &Self::Style) -> Appearance {
+        if let ColorPickerStyles::Custom(custom) = style {
+            return custom.invalid(self);
+        }
+
+        Appearance {
+            bar_border_color: self.extended_palette().danger.base.color,
+            ..self.active(style)
+        }
+    }
 }
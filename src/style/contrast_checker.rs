@@ -0,0 +1,102 @@
+//! Use a contrast checker to check the contrast between two colors.
+//!
+//! *This API requires the following crate features to be activated: `contrast_checker`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`ContrastChecker`](crate::native::contrast_checker::ContrastChecker).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`ContrastChecker`](crate::native::contrast_checker::ContrastChecker).
+    pub background: Background,
+    /// The border radius of the [`ContrastChecker`](crate::native::contrast_checker::ContrastChecker).
+    pub border_radius: f32,
+    /// The border width of the [`ContrastChecker`](crate::native::contrast_checker::ContrastChecker).
+    pub border_width: f32,
+    /// The border color of the [`ContrastChecker`](crate::native::contrast_checker::ContrastChecker).
+    pub border_color: Color,
+    /// The color of the labels and of the ratio.
+    pub text_color: Color,
+    /// The border radius of the swatches, the sample and the badges.
+    pub swatch_border_radius: f32,
+    /// The border color of the swatches and the sample.
+    pub swatch_border_color: Color,
+    /// The background of the badges of the passed levels.
+    pub pass_background: Background,
+    /// The text color of the badges of the passed levels.
+    pub pass_text_color: Color,
+    /// The background of the badges of the failed levels.
+    pub fail_background: Background,
+    /// The text color of the badges of the failed levels.
+    pub fail_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: 8.0,
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            text_color: Color::BLACK,
+            swatch_border_radius: 4.0,
+            swatch_border_color: [0.6, 0.6, 0.6].into(),
+            pass_background: Color::from_rgb(0.12, 0.55, 0.25).into(),
+            pass_text_color: Color::WHITE,
+            fail_background: Color::from_rgb(0.75, 0.15, 0.15).into(),
+            fail_text_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of a [`ContrastChecker`](crate::native::contrast_checker::ContrastChecker).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`ContrastChecker`](crate::native::contrast_checker::ContrastChecker).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`ContrastChecker`](crate::native::contrast_checker::ContrastChecker).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum ContrastCheckerStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl ContrastCheckerStyles {
+    /// Creates a custom [`ContrastCheckerStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = ContrastCheckerStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let ContrastCheckerStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            swatch_border_color: palette.background.strong.color,
+            pass_background: palette.success.base.color.into(),
+            pass_text_color: palette.success.base.text,
+            fail_background: palette.danger.base.color.into(),
+            fail_text_color: palette.danger.base.text,
+            ..Appearance::default()
+        }
+    }
+}
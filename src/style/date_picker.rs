@@ -54,6 +54,32 @@ pub trait StyleSheet {
     /// The appearance when something is focused of the
     /// [`DatePicker`](crate::native::DatePicker).
     fn focused(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of the days between the first and the last day of a
+    /// range picked with the [`DatePicker`](crate::native::DatePicker).
+    fn in_range(&self, style: &Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// The appearance of the days that can't be picked with the
+    /// [`DatePicker`](crate::native::DatePicker).
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        let active = self.active(style);
+
+        Appearance {
+            text_color: active.text_attenuated_color,
+            ..active
+        }
+    }
+
+    /// The appearance of the text field of the
+    /// [`DatePicker`](crate::native::DatePicker) when its text can't be parsed.
+    fn invalid(&self, style: &Self::Style) -> Appearance {
+        Appearance {
+            border_color: Color::from_rgb(0.8, 0.2, 0.2),
+            ..self.active(style)
+        }
+    }
 }
 
 /// The default appearance of the [`DatePicker`](crate::native::DatePicker).
@@ -135,4 +161,46 @@ impl StyleSheet for Theme {
             ..self.active(style)
         }
     }
+
+    fn in_range(&self, style: &Self::Style) -> Appearance {
+        if let DatePickerStyle::Custom(custom) = style {
+            return custom.in_range(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            day_background: palette.background.strong.color.into(),
+            text_color: palette.background.strong.text,
+            ..self.active(style)
+        }
+    }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        if let DatePickerStyle::Custom(custom) = style {
+            return custom.disabled(self);
+        }
+
+        let active = self.active(style);
+
+        Appearance {
+            text_color: active.text_attenuated_color,
+            text_attenuated_color: Color {
+                a: active.text_attenuated_color.a * 0.5,
+                ..active.text_attenuated_color
+            },
+            ..active
+        }
+    }
+
+    fn invalid(&self, style: &Self::Style) -> Appearance {
+        if let DatePickerStyle::Custom(custom) = style {
+            return custom.invalid(self);
+        }
+
+        Appearance {
+            border_color: self.extended_palette().danger.base.color,
+            ..self.active(style)
+        }
+    }
 }
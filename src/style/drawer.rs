@@ -0,0 +1,83 @@
+//! Use a drawer to slide a panel in from an edge over some content.
+//!
+//! *This API requires the following crate features to be activated: drawer*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`Drawer`](crate::native::drawer::Drawer).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the backdrop behind the panel of the
+    /// [`Drawer`](crate::native::drawer::Drawer).
+    ///
+    /// The alpha of the backdrop is scaled with the slide progress of the panel.
+    pub backdrop: Background,
+    /// The background of the panel of the [`Drawer`](crate::native::drawer::Drawer).
+    pub background: Background,
+    /// The border width of the panel of the [`Drawer`](crate::native::drawer::Drawer).
+    pub border_width: f32,
+    /// The border color of the panel of the [`Drawer`](crate::native::drawer::Drawer).
+    pub border_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            backdrop: Background::Color([0.0, 0.0, 0.0, 0.35].into()),
+            background: Color::WHITE.into(),
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+        }
+    }
+}
+
+/// The appearance of a [`Drawer`](crate::native::drawer::Drawer).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`Drawer`](crate::native::drawer::Drawer).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`Drawer`](crate::native::drawer::Drawer).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum DrawerStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl DrawerStyles {
+    /// Creates a custom [`DrawerStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = DrawerStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let DrawerStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            backdrop: Color {
+                a: 0.35,
+                ..Color::BLACK
+            }
+            .into(),
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            ..Appearance::default()
+        }
+    }
+}
@@ -0,0 +1,94 @@
+//! Use a duration picker as an input element for picking durations.
+//!
+//! *This API requires the following crate features to be activated: `duration_picker`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`DurationPicker`](crate::native::duration_picker::DurationPicker).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`DurationPicker`](crate::native::duration_picker::DurationPicker).
+    pub background: Background,
+    /// The border radius of the [`DurationPicker`](crate::native::duration_picker::DurationPicker).
+    pub border_radius: f32,
+    /// The border width of the [`DurationPicker`](crate::native::duration_picker::DurationPicker).
+    pub border_width: f32,
+    /// The border color of the [`DurationPicker`](crate::native::duration_picker::DurationPicker).
+    pub border_color: Color,
+    /// The color of the values and the arrows.
+    pub text_color: Color,
+    /// The color of the units below the values.
+    pub label_color: Color,
+    /// The background of the focused segment.
+    pub focus_background: Background,
+    /// The color of the value of the focused segment.
+    pub focus_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: 15.0,
+            border_width: 1.0,
+            border_color: Color::BLACK,
+            text_color: Color::BLACK,
+            label_color: [0.5, 0.5, 0.5].into(),
+            focus_background: Color::from_rgb(0.87, 0.87, 0.87).into(),
+            focus_text_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`DurationPicker`](crate::native::duration_picker::DurationPicker).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`DurationPicker`](crate::native::duration_picker::DurationPicker).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`DurationPicker`](crate::native::duration_picker::DurationPicker).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum DurationPickerStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl DurationPickerStyles {
+    /// Creates a custom [`DurationPickerStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = DurationPickerStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let DurationPickerStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            label_color: Color {
+                a: 0.6,
+                ..palette.background.base.text
+            },
+            focus_background: palette.primary.base.color.into(),
+            focus_text_color: palette.primary.base.text,
+            ..Appearance::default()
+        }
+    }
+}
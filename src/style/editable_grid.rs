@@ -0,0 +1,104 @@
+//! Use an editable grid to enter data in a spreadsheet like table of cells.
+//!
+//! *This API requires the following crate features to be activated: `editable_grid`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of an [`EditableGrid`](crate::native::editable_grid::EditableGrid).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the cells.
+    pub background: Background,
+    /// The color of the lines between the cells.
+    pub grid_line_color: Color,
+    /// The text color of the cells.
+    pub text_color: Color,
+    /// The background of the frozen header cells.
+    pub header_background: Background,
+    /// The text color of the frozen header cells.
+    pub header_text_color: Color,
+    /// The background laid over the selected cells.
+    pub selection_background: Background,
+    /// The border color of the focused cell.
+    pub focus_border_color: Color,
+    /// The background of the cell being edited.
+    pub editor_background: Background,
+    /// The color of the text cursor of the cell being edited.
+    pub cursor_color: Color,
+    /// The border color of a cell being edited with content its editor rejects.
+    pub invalid_border_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            grid_line_color: [0.85, 0.85, 0.85].into(),
+            text_color: Color::BLACK,
+            header_background: Background::Color([0.95, 0.95, 0.95].into()),
+            header_text_color: [0.3, 0.3, 0.3].into(),
+            selection_background: Background::Color([0.2, 0.5, 0.8, 0.15].into()),
+            focus_border_color: [0.2, 0.5, 0.8].into(),
+            editor_background: Color::WHITE.into(),
+            cursor_color: Color::BLACK,
+            invalid_border_color: [0.8, 0.2, 0.2].into(),
+        }
+    }
+}
+
+/// The appearance of an [`EditableGrid`](crate::native::editable_grid::EditableGrid).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of an [`EditableGrid`](crate::native::editable_grid::EditableGrid).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of an [`EditableGrid`](crate::native::editable_grid::EditableGrid).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum EditableGridStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl EditableGridStyles {
+    /// Creates a custom [`EditableGridStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = EditableGridStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let EditableGridStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            grid_line_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            header_background: palette.background.weak.color.into(),
+            header_text_color: palette.background.weak.text,
+            selection_background: Color {
+                a: 0.15,
+                ..palette.primary.base.color
+            }
+            .into(),
+            focus_border_color: palette.primary.base.color,
+            editor_background: palette.background.base.color.into(),
+            cursor_color: palette.background.base.text,
+            invalid_border_color: palette.danger.base.color,
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! Use an equalizer to edit the gains of frequency bands.
+//!
+//! *This API requires the following crate features to be activated: `equalizer`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of an [`Equalizer`](crate::native::equalizer::Equalizer).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`Equalizer`](crate::native::equalizer::Equalizer).
+    pub background: Background,
+    /// The color of the line at 0 dB.
+    pub zero_line_color: Color,
+    /// The color of the rails of the band sliders.
+    pub rail_color: Color,
+    /// The color of the rails between 0 dB and the gain of a band.
+    pub fill_color: Color,
+    /// The color of the handles of the band sliders.
+    pub handle_color: Color,
+    /// The color of the response curve.
+    pub curve_color: Color,
+    /// The color of the frequency labels.
+    pub text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            zero_line_color: [0.8, 0.8, 0.8].into(),
+            rail_color: [0.9, 0.9, 0.9].into(),
+            fill_color: [0.2, 0.5, 0.8, 0.5].into(),
+            handle_color: [0.2, 0.5, 0.8].into(),
+            curve_color: [0.9, 0.5, 0.1].into(),
+            text_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of an [`Equalizer`](crate::native::equalizer::Equalizer).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of an [`Equalizer`](crate::native::equalizer::Equalizer).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of an [`Equalizer`](crate::native::equalizer::Equalizer).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum EqualizerStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl EqualizerStyles {
+    /// Creates a custom [`EqualizerStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = EqualizerStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let EqualizerStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            zero_line_color: palette.background.strong.color,
+            rail_color: palette.background.weak.color,
+            fill_color: Color {
+                a: 0.5,
+                ..palette.primary.base.color
+            },
+            handle_color: palette.primary.base.color,
+            curve_color: palette.primary.strong.color,
+            text_color: palette.background.base.text,
+        }
+    }
+}
@@ -0,0 +1,87 @@
+//! Use a find bar to search and replace the text of another widget.
+//!
+//! *This API requires the following crate features to be activated: `find_bar`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`FindBar`](crate::native::find_bar::FindBar).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`FindBar`](crate::native::find_bar::FindBar).
+    pub background: Background,
+    /// The border radius of the [`FindBar`](crate::native::find_bar::FindBar).
+    pub border_radius: f32,
+    /// The border width of the [`FindBar`](crate::native::find_bar::FindBar).
+    pub border_width: f32,
+    /// The border color of the [`FindBar`](crate::native::find_bar::FindBar).
+    pub border_color: Color,
+    /// The color of the match counter.
+    pub counter_text_color: Color,
+    /// The color of the match counter without matches or with an invalid pattern.
+    pub no_match_text_color: Color,
+    /// The color of the outline of the enabled case and regex toggles.
+    pub active_toggle_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::from_rgb(0.95, 0.95, 0.95).into(),
+            border_radius: 4.0,
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            counter_text_color: [0.4, 0.4, 0.4].into(),
+            no_match_text_color: [0.8, 0.2, 0.2].into(),
+            active_toggle_color: [0.2, 0.5, 0.8].into(),
+        }
+    }
+}
+
+/// The appearance of a [`FindBar`](crate::native::find_bar::FindBar).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`FindBar`](crate::native::find_bar::FindBar).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`FindBar`](crate::native::find_bar::FindBar).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum FindBarStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl FindBarStyles {
+    /// Creates a custom [`FindBarStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = FindBarStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let FindBarStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.weak.color.into(),
+            border_color: palette.background.strong.color,
+            counter_text_color: palette.background.strong.text,
+            no_match_text_color: palette.danger.base.color,
+            active_toggle_color: palette.primary.strong.color,
+            ..Appearance::default()
+        }
+    }
+}
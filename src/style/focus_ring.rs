@@ -0,0 +1,72 @@
+//! Use a focus ring to outline the content holding the keyboard focus.
+//!
+//! *This API requires the following crate features to be activated: `focus_ring`*
+use std::rc::Rc;
+
+use iced_widget::{core::Color, style::Theme};
+
+/// The appearance of a [`FocusRing`](crate::native::focus_ring::FocusRing).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The color of the ring.
+    pub color: Color,
+    /// The width of the ring.
+    pub width: f32,
+    /// The border radius of the ring.
+    pub border_radius: f32,
+    /// The gap between the content and the ring.
+    pub offset: f32,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            color: [0.2, 0.45, 0.85].into(),
+            width: 2.0,
+            border_radius: 4.0,
+            offset: 2.0,
+        }
+    }
+}
+
+/// The appearance of a [`FocusRing`](crate::native::focus_ring::FocusRing).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The appearance of the ring around the focused content of a
+    /// [`FocusRing`](crate::native::focus_ring::FocusRing).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`FocusRing`](crate::native::focus_ring::FocusRing).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum FocusRingStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl FocusRingStyles {
+    /// Creates a custom [`FocusRingStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = FocusRingStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let FocusRingStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            color: palette.primary.strong.color,
+            ..Appearance::default()
+        }
+    }
+}
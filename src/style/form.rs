@@ -0,0 +1,69 @@
+//! Use a form to lay out labeled fields in aligned rows.
+//!
+//! *This API requires the following crate features to be activated: `form`*
+use std::rc::Rc;
+
+use iced_widget::{core::Color, style::Theme};
+
+/// The appearance of a [`Form`](crate::native::form::Form).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The color of the labels.
+    pub label_color: Color,
+    /// The color of the markers of required fields.
+    pub required_color: Color,
+    /// The color of the help texts.
+    pub help_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            label_color: Color::BLACK,
+            required_color: [0.8, 0.2, 0.2].into(),
+            help_color: [0.45, 0.45, 0.45].into(),
+        }
+    }
+}
+
+/// The appearance of a [`Form`](crate::native::form::Form).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`Form`](crate::native::form::Form).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`Form`](crate::native::form::Form).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum FormStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl FormStyles {
+    /// Creates a custom [`FormStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = FormStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let FormStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            label_color: palette.background.base.text,
+            required_color: palette.danger.base.color,
+            help_color: palette.background.strong.color,
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! Use gamepad targets to move the focus and activate elements with a
+//! gamepad.
+//!
+//! *This API requires the following crate features to be activated: `gamepad`*
+use std::rc::Rc;
+
+use iced_widget::{core::Color, style::Theme};
+
+/// The appearance of the focus ring of a [`GamepadTarget`](crate::native::gamepad::GamepadTarget).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The color of the focus ring.
+    pub focus_color: Color,
+    /// The width of the focus ring.
+    pub border_width: f32,
+    /// The border radius of the focus ring.
+    pub border_radius: f32,
+    /// The space between the focus ring and the content.
+    pub offset: f32,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            focus_color: [0.1, 0.35, 0.65].into(),
+            border_width: 3.0,
+            border_radius: 6.0,
+            offset: 2.0,
+        }
+    }
+}
+
+/// The appearance of the focus ring of a [`GamepadTarget`](crate::native::gamepad::GamepadTarget).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The appearance of the focus ring of a focused [`GamepadTarget`](crate::native::gamepad::GamepadTarget).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of the focus ring of a [`GamepadTarget`](crate::native::gamepad::GamepadTarget).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum GamepadTargetStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl GamepadTargetStyles {
+    /// Creates a custom [`GamepadTargetStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = GamepadTargetStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let GamepadTargetStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            focus_color: palette.primary.strong.color,
+            ..Appearance::default()
+        }
+    }
+}
@@ -0,0 +1,96 @@
+//! Use a Gantt chart to schedule tasks linked by their dependencies.
+//!
+//! *This API requires the following crate features to be activated: `gantt`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`Gantt`](crate::native::gantt::Gantt) chart.
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`Gantt`](crate::native::gantt::Gantt) chart.
+    pub background: Background,
+    /// The color of the labels of the tasks.
+    pub text_color: Color,
+    /// The color of the lines separating the rows of the tasks.
+    pub grid_color: Color,
+    /// The color of the bars of the tasks.
+    pub bar_color: Color,
+    /// The color of the bars of the tasks on the critical path.
+    pub critical_bar_color: Color,
+    /// The color of the links between the tasks.
+    pub link_color: Color,
+    /// The color of the links between the tasks on the critical path.
+    pub critical_link_color: Color,
+    /// The color of the selected link.
+    pub selected_link_color: Color,
+    /// The color of the handles at the ends of the bars for linking tasks.
+    pub handle_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::TRANSPARENT.into(),
+            text_color: Color::BLACK,
+            grid_color: [0.9, 0.9, 0.9].into(),
+            bar_color: [0.3, 0.55, 0.85].into(),
+            critical_bar_color: [0.85, 0.3, 0.3].into(),
+            link_color: [0.5, 0.5, 0.5].into(),
+            critical_link_color: [0.85, 0.3, 0.3].into(),
+            selected_link_color: [0.95, 0.65, 0.1].into(),
+            handle_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of a [`Gantt`](crate::native::gantt::Gantt) chart.
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`Gantt`](crate::native::gantt::Gantt) chart.
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`Gantt`](crate::native::gantt::Gantt) chart.
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum GanttStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl GanttStyles {
+    /// Creates a custom [`GanttStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = GanttStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let GanttStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            text_color: palette.background.base.text,
+            grid_color: palette.background.weak.color,
+            bar_color: palette.primary.base.color,
+            critical_bar_color: palette.danger.base.color,
+            link_color: palette.background.strong.color,
+            critical_link_color: palette.danger.strong.color,
+            selected_link_color: palette.primary.strong.color,
+            handle_color: palette.background.base.color,
+            ..Appearance::default()
+        }
+    }
+}
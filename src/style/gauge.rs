@@ -0,0 +1,87 @@
+//! Use a gauge to show a value on the arc of a dial.
+//!
+//! *This API requires the following crate features to be activated: `gauge`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`Gauge`](crate::native::gauge::Gauge).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`Gauge`](crate::native::gauge::Gauge).
+    pub background: Background,
+    /// The color of the arc of the dial outside of the zones.
+    pub track_color: Color,
+    /// The width of the arc of the dial.
+    pub track_width: f32,
+    /// The color of the needle and of its hub.
+    pub needle_color: Color,
+    /// The color of the ticks of the scale.
+    pub tick_color: Color,
+    /// The color of the labels of the ticks.
+    pub text_color: Color,
+    /// The color of the value shown in the center.
+    pub value_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::TRANSPARENT.into(),
+            track_color: Color::from_rgb(0.88, 0.88, 0.88),
+            track_width: 12.0,
+            needle_color: Color::from_rgb(0.85, 0.2, 0.2),
+            tick_color: Color::from_rgb(0.45, 0.45, 0.45),
+            text_color: Color::from_rgb(0.3, 0.3, 0.3),
+            value_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`Gauge`](crate::native::gauge::Gauge).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`Gauge`](crate::native::gauge::Gauge).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`Gauge`](crate::native::gauge::Gauge).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum GaugeStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl GaugeStyles {
+    /// Creates a custom [`GaugeStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = GaugeStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let GaugeStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            track_color: palette.background.weak.color,
+            needle_color: palette.primary.strong.color,
+            tick_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            value_color: palette.background.base.text,
+            ..Appearance::default()
+        }
+    }
+}
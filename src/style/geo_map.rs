@@ -0,0 +1,97 @@
+//! Use a map to color the regions of the world by a value.
+//!
+//! *This API requires the following crate features to be activated: `geo_map`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`GeoMap`](crate::native::geo_map::GeoMap).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`GeoMap`](crate::native::geo_map::GeoMap), e.g.
+    /// the color of the sea.
+    pub background: Background,
+    /// The color of the regions without a value.
+    pub no_data_color: Color,
+    /// The color of the regions with the lowest value.
+    pub low_color: Color,
+    /// The color of the regions with the highest value.
+    pub high_color: Color,
+    /// The color of the borders of the regions.
+    pub border_color: Color,
+    /// The width of the borders of the regions.
+    pub border_width: f32,
+    /// The color of the border of the hovered region.
+    pub hovered_border_color: Color,
+    /// The background of the tooltip of the hovered region.
+    pub tooltip_background: Background,
+    /// The color of the text of the tooltip of the hovered region.
+    pub tooltip_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::from_rgb(0.85, 0.9, 0.95).into(),
+            no_data_color: [0.85, 0.85, 0.85].into(),
+            low_color: [0.85, 0.92, 0.98].into(),
+            high_color: [0.05, 0.25, 0.55].into(),
+            border_color: Color::WHITE,
+            border_width: 0.5,
+            hovered_border_color: Color::BLACK,
+            tooltip_background: Color::from_rgba(0.0, 0.0, 0.0, 0.8).into(),
+            tooltip_text_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of a [`GeoMap`](crate::native::geo_map::GeoMap).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`GeoMap`](crate::native::geo_map::GeoMap).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`GeoMap`](crate::native::geo_map::GeoMap).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum GeoMapStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl GeoMapStyles {
+    /// Creates a custom [`GeoMapStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = GeoMapStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let GeoMapStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.weak.color.into(),
+            no_data_color: palette.background.strong.color,
+            low_color: palette.primary.weak.color,
+            high_color: palette.primary.strong.color,
+            border_color: palette.background.base.color,
+            hovered_border_color: palette.background.base.text,
+            tooltip_background: palette.background.strong.color.into(),
+            tooltip_text_color: palette.background.strong.text,
+            ..Appearance::default()
+        }
+    }
+}
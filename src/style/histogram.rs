@@ -0,0 +1,84 @@
+//! Use a histogram to show the distribution of values and brush over a range.
+//!
+//! *This API requires the following crate features to be activated: `histogram`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`Histogram`](crate::native::histogram::Histogram).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`Histogram`](crate::native::histogram::Histogram).
+    pub background: Background,
+    /// The color of the bars outside of the selected range.
+    pub bar_color: Color,
+    /// The color of the bars inside of the selected range.
+    pub selected_bar_color: Color,
+    /// The background of the brush over the selected range.
+    pub brush_background: Background,
+    /// The color of the edges of the brush.
+    pub brush_edge_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            bar_color: [0.8, 0.8, 0.8].into(),
+            selected_bar_color: [0.2, 0.5, 0.8].into(),
+            brush_background: Background::Color([0.2, 0.5, 0.8, 0.15].into()),
+            brush_edge_color: [0.2, 0.5, 0.8].into(),
+        }
+    }
+}
+
+/// The appearance of a [`Histogram`](crate::native::histogram::Histogram).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`Histogram`](crate::native::histogram::Histogram).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`Histogram`](crate::native::histogram::Histogram).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum HistogramStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl HistogramStyles {
+    /// Creates a custom [`HistogramStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = HistogramStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let HistogramStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            bar_color: palette.background.strong.color,
+            selected_bar_color: palette.primary.base.color,
+            brush_background: Color {
+                a: 0.15,
+                ..palette.primary.base.color
+            }
+            .into(),
+            brush_edge_color: palette.primary.strong.color,
+        }
+    }
+}
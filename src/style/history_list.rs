@@ -0,0 +1,96 @@
+//! Use a history list to visualize and navigate an undo stack.
+//!
+//! *This API requires the following crate features to be activated: `history_list`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`HistoryList`](crate::native::history_list::HistoryList).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`HistoryList`](crate::native::history_list::HistoryList).
+    pub background: Background,
+    /// The border width of the [`HistoryList`](crate::native::history_list::HistoryList).
+    pub border_width: f32,
+    /// The border color of the [`HistoryList`](crate::native::history_list::HistoryList).
+    pub border_color: Color,
+    /// The text color of the entries that have been applied.
+    pub text_color: Color,
+    /// The text color of the entries that have been undone and can be redone.
+    pub undone_text_color: Color,
+    /// The background of a hovered entry.
+    pub hovered_background: Background,
+    /// The text color of a hovered entry.
+    pub hovered_text_color: Color,
+    /// The background of the entry of the current state.
+    pub current_background: Background,
+    /// The text color of the entry of the current state.
+    pub current_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_width: 1.0,
+            border_color: [0.7, 0.7, 0.7].into(),
+            text_color: Color::BLACK,
+            undone_text_color: [0.6, 0.6, 0.6].into(),
+            hovered_background: Background::Color([0.87, 0.87, 0.87].into()),
+            hovered_text_color: Color::BLACK,
+            current_background: Background::Color([0.2, 0.5, 0.8].into()),
+            current_text_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of a [`HistoryList`](crate::native::history_list::HistoryList).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`HistoryList`](crate::native::history_list::HistoryList).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`HistoryList`](crate::native::history_list::HistoryList).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum HistoryListStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl HistoryListStyles {
+    /// Creates a custom [`HistoryListStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = HistoryListStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let HistoryListStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            undone_text_color: palette.background.strong.color,
+            hovered_background: palette.primary.weak.color.into(),
+            hovered_text_color: palette.primary.weak.text,
+            current_background: palette.primary.strong.color.into(),
+            current_text_color: palette.primary.strong.text,
+            ..Appearance::default()
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! Use a live chart to plot streaming data over a scrolling time window.
+//!
+//! *This API requires the following crate features to be activated: `live_chart`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`LiveChart`](crate::native::live_chart::LiveChart).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`LiveChart`](crate::native::live_chart::LiveChart).
+    pub background: Background,
+    /// The colors of the lines of the series, which are used in turn.
+    pub palette: [Color; 6],
+    /// The width of the lines of the series.
+    pub line_width: f32,
+    /// The color of the grid lines at the axis labels.
+    pub grid_color: Color,
+    /// The color of the axes.
+    pub axis_color: Color,
+    /// The color of the axis labels.
+    pub text_color: Color,
+    /// The color of the crosshair following the cursor.
+    pub crosshair_color: Color,
+    /// The color of the guide lines, the points and the labels of the
+    /// annotations.
+    pub annotation_color: Color,
+    /// The color of the shaded regions of the annotations.
+    pub region_color: Color,
+    /// The background of the tooltip with the values at the cursor.
+    pub tooltip_background: Background,
+    /// The color of the text of the tooltip with the values at the cursor.
+    pub tooltip_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::TRANSPARENT.into(),
+            palette: [
+                [0.31, 0.47, 0.65].into(),
+                [0.95, 0.56, 0.17].into(),
+                [0.88, 0.34, 0.35].into(),
+                [0.46, 0.72, 0.7].into(),
+                [0.35, 0.63, 0.31].into(),
+                [0.69, 0.48, 0.63].into(),
+            ],
+            line_width: 1.5,
+            grid_color: Color::from_rgb(0.9, 0.9, 0.9),
+            axis_color: Color::from_rgb(0.5, 0.5, 0.5),
+            text_color: Color::BLACK,
+            crosshair_color: Color::from_rgba(0.0, 0.0, 0.0, 0.5),
+            annotation_color: Color::from_rgb(0.55, 0.3, 0.75),
+            region_color: Color::from_rgba(0.55, 0.3, 0.75, 0.12),
+            tooltip_background: Color::from_rgba(0.0, 0.0, 0.0, 0.8).into(),
+            tooltip_text_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of a [`LiveChart`](crate::native::live_chart::LiveChart).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`LiveChart`](crate::native::live_chart::LiveChart).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`LiveChart`](crate::native::live_chart::LiveChart).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum LiveChartStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl LiveChartStyles {
+    /// Creates a custom [`LiveChartStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = LiveChartStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let LiveChartStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            grid_color: palette.background.weak.color,
+            axis_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            crosshair_color: Color {
+                a: 0.5,
+                ..palette.background.base.text
+            },
+            annotation_color: palette.primary.strong.color,
+            region_color: Color {
+                a: 0.12,
+                ..palette.primary.base.color
+            },
+            tooltip_background: palette.background.strong.color.into(),
+            tooltip_text_color: palette.background.strong.text,
+            ..Appearance::default()
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! Use a mind map to edit a tree of ideas around a central one.
+//!
+//! *This API requires the following crate features to be activated: `mind_map`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`MindMap`](crate::native::mind_map::MindMap).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`MindMap`](crate::native::mind_map::MindMap).
+    pub background: Background,
+    /// The background of the nodes.
+    pub node_background: Color,
+    /// The background of the root node.
+    pub root_background: Color,
+    /// The border color of the nodes.
+    pub node_border_color: Color,
+    /// The color of the labels of the nodes.
+    pub text_color: Color,
+    /// The color of the label of the root node.
+    pub root_text_color: Color,
+    /// The border color of the selected node.
+    pub selected_border_color: Color,
+    /// The border color of the node a dragged node is dropped onto.
+    pub drop_target_color: Color,
+    /// The color of the lines connecting the nodes.
+    pub connector_color: Color,
+    /// The background of the buttons adding and removing nodes.
+    pub button_background: Color,
+    /// The color of the icons of the buttons adding and removing nodes.
+    pub button_icon_color: Color,
+    /// The color of the text cursor of the editor of a label.
+    pub cursor_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::TRANSPARENT.into(),
+            node_background: Color::WHITE,
+            root_background: [0.2, 0.5, 0.8].into(),
+            node_border_color: [0.7, 0.7, 0.7].into(),
+            text_color: Color::BLACK,
+            root_text_color: Color::WHITE,
+            selected_border_color: [0.2, 0.5, 0.8].into(),
+            drop_target_color: [0.3, 0.7, 0.4].into(),
+            connector_color: [0.6, 0.6, 0.6].into(),
+            button_background: [0.2, 0.5, 0.8].into(),
+            button_icon_color: Color::WHITE,
+            cursor_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`MindMap`](crate::native::mind_map::MindMap).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`MindMap`](crate::native::mind_map::MindMap).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`MindMap`](crate::native::mind_map::MindMap).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum MindMapStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl MindMapStyles {
+    /// Creates a custom [`MindMapStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = MindMapStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let MindMapStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            node_background: palette.background.base.color,
+            root_background: palette.primary.base.color,
+            node_border_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            root_text_color: palette.primary.base.text,
+            selected_border_color: palette.primary.strong.color,
+            drop_target_color: palette.success.base.color,
+            connector_color: palette.background.strong.color,
+            button_background: palette.primary.strong.color,
+            button_icon_color: palette.primary.strong.text,
+            cursor_color: palette.background.base.text,
+            ..Appearance::default()
+        }
+    }
+}
@@ -66,3 +66,248 @@ pub use context_menu::ContextMenuStyle;
 pub mod segmented_button;
 #[cfg(feature = "segmented_button")]
 pub use segmented_button::SegmentedButton;
+
+#[cfg(feature = "drawer")]
+pub mod drawer;
+#[cfg(feature = "drawer")]
+pub use drawer::DrawerStyles;
+
+#[cfg(feature = "notification_center")]
+pub mod notification_center;
+#[cfg(feature = "notification_center")]
+pub use notification_center::NotificationCenterStyles;
+
+#[cfg(feature = "history_list")]
+pub mod history_list;
+#[cfg(feature = "history_list")]
+pub use history_list::HistoryListStyles;
+
+#[cfg(feature = "time_ruler")]
+pub mod time_ruler;
+#[cfg(feature = "time_ruler")]
+pub use time_ruler::TimeRulerStyles;
+
+#[cfg(feature = "waveform")]
+pub mod waveform;
+#[cfg(feature = "waveform")]
+pub use waveform::WaveformStyles;
+
+#[cfg(feature = "seek_bar")]
+pub mod seek_bar;
+#[cfg(feature = "seek_bar")]
+pub use seek_bar::SeekBarStyles;
+
+#[cfg(feature = "transport_controls")]
+pub mod transport_controls;
+#[cfg(feature = "transport_controls")]
+pub use transport_controls::TransportControlsStyles;
+
+#[cfg(feature = "volume_slider")]
+pub mod volume_slider;
+#[cfg(feature = "volume_slider")]
+pub use volume_slider::VolumeSliderStyles;
+
+#[cfg(feature = "equalizer")]
+pub mod equalizer;
+#[cfg(feature = "equalizer")]
+pub use equalizer::EqualizerStyles;
+
+#[cfg(feature = "piano_keys")]
+pub mod piano_keys;
+#[cfg(feature = "piano_keys")]
+pub use piano_keys::PianoKeysStyles;
+
+#[cfg(feature = "step_grid")]
+pub mod step_grid;
+#[cfg(feature = "step_grid")]
+pub use step_grid::StepGridStyles;
+
+#[cfg(feature = "editable_grid")]
+pub mod editable_grid;
+#[cfg(feature = "editable_grid")]
+pub use editable_grid::EditableGridStyles;
+
+#[cfg(feature = "tree_view")]
+pub mod tree_view;
+#[cfg(feature = "tree_view")]
+pub use tree_view::TreeViewStyles;
+
+#[cfg(feature = "selectable_text")]
+pub mod selectable_text;
+#[cfg(feature = "selectable_text")]
+pub use selectable_text::SelectableTextStyles;
+
+#[cfg(feature = "rich_editor")]
+pub mod rich_editor;
+#[cfg(feature = "rich_editor")]
+pub use rich_editor::RichEditorStyles;
+
+#[cfg(feature = "find_bar")]
+pub mod find_bar;
+#[cfg(feature = "find_bar")]
+pub use find_bar::FindBarStyles;
+
+#[cfg(feature = "split_button")]
+pub mod split_button;
+#[cfg(feature = "split_button")]
+pub use split_button::SplitButtonStyles;
+
+#[cfg(feature = "toggle_group")]
+pub mod toggle_group;
+#[cfg(feature = "toggle_group")]
+pub use toggle_group::ToggleGroupStyles;
+
+#[cfg(feature = "title_bar")]
+pub mod title_bar;
+#[cfg(feature = "title_bar")]
+pub use title_bar::TitleBarStyles;
+
+#[cfg(feature = "form")]
+pub mod form;
+#[cfg(feature = "form")]
+pub use form::FormStyles;
+
+#[cfg(feature = "settings_page")]
+pub mod settings_page;
+#[cfg(feature = "settings_page")]
+pub use settings_page::SettingsPageStyles;
+
+#[cfg(feature = "wizard")]
+pub mod wizard;
+#[cfg(feature = "wizard")]
+pub use wizard::WizardStyles;
+
+#[cfg(feature = "auth_form")]
+pub mod auth_form;
+#[cfg(feature = "auth_form")]
+pub use auth_form::AuthFormStyles;
+
+#[cfg(feature = "onboarding")]
+pub mod onboarding;
+#[cfg(feature = "onboarding")]
+pub use onboarding::OnboardingStyles;
+
+#[cfg(feature = "contrast_checker")]
+pub mod contrast_checker;
+#[cfg(feature = "contrast_checker")]
+pub use contrast_checker::ContrastCheckerStyles;
+
+#[cfg(feature = "theme_editor")]
+pub mod theme_editor;
+#[cfg(feature = "theme_editor")]
+pub use theme_editor::ThemeEditorStyles;
+
+#[cfg(feature = "shortcut_recorder")]
+pub mod shortcut_recorder;
+#[cfg(feature = "shortcut_recorder")]
+pub use shortcut_recorder::ShortcutRecorderStyles;
+
+#[cfg(feature = "gamepad")]
+pub mod gamepad;
+#[cfg(feature = "gamepad")]
+pub use gamepad::GamepadTargetStyles;
+
+#[cfg(feature = "num_pad")]
+pub mod num_pad;
+#[cfg(feature = "num_pad")]
+pub use num_pad::NumPadStyles;
+
+#[cfg(feature = "on_screen_keyboard")]
+pub mod on_screen_keyboard;
+#[cfg(feature = "on_screen_keyboard")]
+pub use on_screen_keyboard::OnScreenKeyboardStyles;
+
+#[cfg(feature = "geo_map")]
+pub mod geo_map;
+#[cfg(feature = "geo_map")]
+pub use geo_map::GeoMapStyles;
+
+#[cfg(feature = "org_chart")]
+pub mod org_chart;
+#[cfg(feature = "org_chart")]
+pub use org_chart::OrgChartStyles;
+
+#[cfg(feature = "mind_map")]
+pub mod mind_map;
+#[cfg(feature = "mind_map")]
+pub use mind_map::MindMapStyles;
+
+#[cfg(feature = "gantt")]
+pub mod gantt;
+#[cfg(feature = "gantt")]
+pub use gantt::GanttStyles;
+
+#[cfg(feature = "treemap")]
+pub mod treemap;
+#[cfg(feature = "treemap")]
+pub use treemap::TreemapStyles;
+
+#[cfg(feature = "sunburst")]
+pub mod sunburst;
+#[cfg(feature = "sunburst")]
+pub use sunburst::SunburstStyles;
+
+#[cfg(feature = "live_chart")]
+pub mod live_chart;
+#[cfg(feature = "live_chart")]
+pub use live_chart::LiveChartStyles;
+
+#[cfg(feature = "candlestick")]
+pub mod candlestick;
+#[cfg(feature = "candlestick")]
+pub use candlestick::CandlestickStyles;
+
+#[cfg(feature = "histogram")]
+pub mod histogram;
+#[cfg(feature = "histogram")]
+pub use histogram::HistogramStyles;
+
+#[cfg(feature = "radar")]
+pub mod radar;
+#[cfg(feature = "radar")]
+pub use radar::RadarChartStyles;
+
+#[cfg(feature = "chart_legend")]
+pub mod chart_legend;
+#[cfg(feature = "chart_legend")]
+pub use chart_legend::ChartLegendStyles;
+
+#[cfg(feature = "gauge")]
+pub mod gauge;
+#[cfg(feature = "gauge")]
+pub use gauge::GaugeStyles;
+
+#[cfg(feature = "segment_display")]
+pub mod segment_display;
+#[cfg(feature = "segment_display")]
+pub use segment_display::SegmentDisplayStyles;
+
+#[cfg(feature = "animated_number")]
+pub mod animated_number;
+#[cfg(feature = "animated_number")]
+pub use animated_number::AnimatedNumberStyles;
+
+#[cfg(feature = "status_light")]
+pub mod status_light;
+#[cfg(feature = "status_light")]
+pub use status_light::StatusLightStyles;
+
+#[cfg(feature = "signal_bars")]
+pub mod signal_bars;
+#[cfg(feature = "signal_bars")]
+pub use signal_bars::SignalBarsStyles;
+
+#[cfg(feature = "battery_indicator")]
+pub mod battery_indicator;
+#[cfg(feature = "battery_indicator")]
+pub use battery_indicator::BatteryIndicatorStyles;
+
+#[cfg(feature = "duration_picker")]
+pub mod duration_picker;
+#[cfg(feature = "duration_picker")]
+pub use duration_picker::DurationPickerStyles;
+
+#[cfg(feature = "focus_ring")]
+pub mod focus_ring;
+#[cfg(feature = "focus_ring")]
+pub use focus_ring::FocusRingStyles;
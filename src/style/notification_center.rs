@@ -0,0 +1,77 @@
+//! Use a notification center to review persistent notifications.
+//!
+//! *This API requires the following crate features to be activated: `notification_center`*
+use std::rc::Rc;
+
+use iced_widget::{core::Color, style::Theme};
+
+/// The appearance of a [`NotificationCenter`](crate::native::notification_center::NotificationCenter).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The color of the indicator next to unread notifications.
+    pub unread_indicator_color: Color,
+    /// The text color of the group headers.
+    pub group_text_color: Color,
+    /// The text color of the title and body of unread notifications.
+    pub unread_text_color: Color,
+    /// The text color of the title and body of read notifications.
+    pub read_text_color: Color,
+    /// The text color of the source and relative time of the notifications.
+    pub secondary_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            unread_indicator_color: [0.12, 0.56, 1.0].into(),
+            group_text_color: [0.4, 0.4, 0.4].into(),
+            unread_text_color: Color::BLACK,
+            read_text_color: [0.4, 0.4, 0.4].into(),
+            secondary_text_color: [0.5, 0.5, 0.5].into(),
+        }
+    }
+}
+
+/// The appearance of a [`NotificationCenter`](crate::native::notification_center::NotificationCenter).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`NotificationCenter`](crate::native::notification_center::NotificationCenter).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`NotificationCenter`](crate::native::notification_center::NotificationCenter).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum NotificationCenterStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl NotificationCenterStyles {
+    /// Creates a custom [`NotificationCenterStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = NotificationCenterStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let NotificationCenterStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            unread_indicator_color: palette.primary.base.color,
+            group_text_color: palette.background.strong.color,
+            unread_text_color: palette.background.base.text,
+            read_text_color: palette.background.strong.color,
+            secondary_text_color: palette.background.strong.color,
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! Use a numeric keypad to enter numbers without a physical keyboard.
+//!
+//! *This API requires the following crate features to be activated: `num_pad`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`NumPad`](crate::native::num_pad::NumPad).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the keypad.
+    pub background: Background,
+    /// The border radius of the keypad.
+    pub border_radius: f32,
+    /// The border width of the keypad.
+    pub border_width: f32,
+    /// The border color of the keypad.
+    pub border_color: Color,
+    /// The background of the keys.
+    pub key_background: Background,
+    /// The border radius of the keys.
+    pub key_border_radius: f32,
+    /// The color of the labels of the keys.
+    pub key_text_color: Color,
+    /// The background of the key submitting the number.
+    pub submit_background: Background,
+    /// The color of the label of the key submitting the number.
+    pub submit_text_color: Color,
+    /// The color of the labels of the disabled keys.
+    pub disabled_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::from_rgb(0.95, 0.95, 0.95).into(),
+            border_radius: 8.0,
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            key_background: Color::WHITE.into(),
+            key_border_radius: 6.0,
+            key_text_color: Color::BLACK,
+            submit_background: Color::from_rgb(0.2, 0.5, 0.8).into(),
+            submit_text_color: Color::WHITE,
+            disabled_text_color: [0.7, 0.7, 0.7].into(),
+        }
+    }
+}
+
+/// The appearance of a [`NumPad`](crate::native::num_pad::NumPad).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`NumPad`](crate::native::num_pad::NumPad).
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a hovered key of a [`NumPad`](crate::native::num_pad::NumPad).
+    fn hovered(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a pressed key of a [`NumPad`](crate::native::num_pad::NumPad).
+    fn pressed(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`NumPad`](crate::native::num_pad::NumPad).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum NumPadStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl NumPadStyles {
+    /// Creates a custom [`NumPadStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = NumPadStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let NumPadStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.weak.color.into(),
+            border_color: palette.background.strong.color,
+            key_background: palette.background.base.color.into(),
+            key_text_color: palette.background.base.text,
+            submit_background: palette.primary.strong.color.into(),
+            submit_text_color: palette.primary.strong.text,
+            disabled_text_color: palette.background.strong.color,
+            ..Appearance::default()
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        if let NumPadStyles::Custom(custom) = style {
+            return custom.hovered(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            key_background: palette.background.strong.color.into(),
+            submit_background: palette.primary.base.color.into(),
+            ..self.active(style)
+        }
+    }
+
+    fn pressed(&self, style: &Self::Style) -> Appearance {
+        if let NumPadStyles::Custom(custom) = style {
+            return custom.pressed(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            key_background: palette.primary.weak.color.into(),
+            submit_background: palette.primary.weak.color.into(),
+            ..self.active(style)
+        }
+    }
+}
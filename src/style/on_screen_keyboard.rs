@@ -0,0 +1,129 @@
+//! Enter text on touch screens without a physical keyboard.
+//!
+//! *This API requires the following crate features to be activated: `on_screen_keyboard`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`OnScreenKeyboard`](crate::native::on_screen_keyboard::OnScreenKeyboard).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the keyboard.
+    pub background: Background,
+    /// The width of the border at the top of the keyboard.
+    pub border_width: f32,
+    /// The color of the border at the top of the keyboard.
+    pub border_color: Color,
+    /// The background of the keys entering characters.
+    pub key_background: Background,
+    /// The border radius of the keys.
+    pub key_border_radius: f32,
+    /// The color of the labels of the keys.
+    pub key_text_color: Color,
+    /// The background of the keys like shift, backspace and enter.
+    pub modifier_background: Background,
+    /// The background of the shift key while it is active.
+    pub active_modifier_background: Background,
+    /// The color of the label of the shift key while it is active.
+    pub active_modifier_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::from_rgb(0.9, 0.9, 0.9).into(),
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            key_background: Color::WHITE.into(),
+            key_border_radius: 6.0,
+            key_text_color: Color::BLACK,
+            modifier_background: Color::from_rgb(0.8, 0.8, 0.8).into(),
+            active_modifier_background: Color::from_rgb(0.2, 0.5, 0.8).into(),
+            active_modifier_text_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of a [`OnScreenKeyboard`](crate::native::on_screen_keyboard::OnScreenKeyboard).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`OnScreenKeyboard`](crate::native::on_screen_keyboard::OnScreenKeyboard).
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a hovered key of a [`OnScreenKeyboard`](crate::native::on_screen_keyboard::OnScreenKeyboard).
+    fn hovered(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a pressed key of a [`OnScreenKeyboard`](crate::native::on_screen_keyboard::OnScreenKeyboard).
+    fn pressed(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`OnScreenKeyboard`](crate::native::on_screen_keyboard::OnScreenKeyboard).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum OnScreenKeyboardStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl OnScreenKeyboardStyles {
+    /// Creates a custom [`OnScreenKeyboardStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = OnScreenKeyboardStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let OnScreenKeyboardStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.weak.color.into(),
+            border_color: palette.background.strong.color,
+            key_background: palette.background.base.color.into(),
+            key_text_color: palette.background.base.text,
+            modifier_background: palette.background.strong.color.into(),
+            active_modifier_background: palette.primary.strong.color.into(),
+            active_modifier_text_color: palette.primary.strong.text,
+            ..Appearance::default()
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        if let OnScreenKeyboardStyles::Custom(custom) = style {
+            return custom.hovered(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            key_background: palette.background.strong.color.into(),
+            modifier_background: palette.background.base.color.into(),
+            ..self.active(style)
+        }
+    }
+
+    fn pressed(&self, style: &Self::Style) -> Appearance {
+        if let OnScreenKeyboardStyles::Custom(custom) = style {
+            return custom.pressed(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            key_background: palette.primary.weak.color.into(),
+            modifier_background: palette.primary.weak.color.into(),
+            ..self.active(style)
+        }
+    }
+}
@@ -0,0 +1,76 @@
+//! Use an onboarding to introduce an application in a few pages.
+//!
+//! *This API requires the following crate features to be activated: `onboarding`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of an [`Onboarding`](crate::native::onboarding::Onboarding).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The color of the titles of the pages.
+    pub title_color: Color,
+    /// The color of the descriptions of the pages.
+    pub description_color: Color,
+    /// The background of the dots of the other pages.
+    pub dot_color: Background,
+    /// The background of the dot of the current page.
+    pub active_dot_color: Background,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            title_color: Color::BLACK,
+            description_color: [0.35, 0.35, 0.35].into(),
+            dot_color: Color::from_rgb(0.8, 0.8, 0.8).into(),
+            active_dot_color: Color::from_rgb(0.2, 0.5, 0.8).into(),
+        }
+    }
+}
+
+/// The appearance of an [`Onboarding`](crate::native::onboarding::Onboarding).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of an [`Onboarding`](crate::native::onboarding::Onboarding).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of an [`Onboarding`](crate::native::onboarding::Onboarding).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum OnboardingStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl OnboardingStyles {
+    /// Creates a custom [`OnboardingStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = OnboardingStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let OnboardingStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            title_color: palette.background.base.text,
+            description_color: palette.background.strong.text,
+            dot_color: palette.background.strong.color.into(),
+            active_dot_color: palette.primary.strong.color.into(),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+//! Use an org chart to display a hierarchy as a tree of nodes.
+//!
+//! *This API requires the following crate features to be activated: `org_chart`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of an [`OrgChart`](crate::native::org_chart::OrgChart).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`OrgChart`](crate::native::org_chart::OrgChart).
+    pub background: Background,
+    /// The background of the nodes.
+    pub node_background: Background,
+    /// The border color of the nodes.
+    pub node_border_color: Color,
+    /// The border width of the nodes.
+    pub node_border_width: f32,
+    /// The border radius of the nodes.
+    pub node_border_radius: f32,
+    /// The border color of the hovered node.
+    pub hovered_border_color: Color,
+    /// The color of the lines connecting the nodes.
+    pub connector_color: Color,
+    /// The width of the lines connecting the nodes.
+    pub connector_width: f32,
+    /// The background of the toggles collapsing and expanding the branches.
+    pub toggle_background: Color,
+    /// The color of the signs of the toggles.
+    pub toggle_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::TRANSPARENT.into(),
+            node_background: Color::WHITE.into(),
+            node_border_color: [0.7, 0.7, 0.7].into(),
+            node_border_width: 1.0,
+            node_border_radius: 4.0,
+            hovered_border_color: [0.2, 0.5, 0.8].into(),
+            connector_color: [0.6, 0.6, 0.6].into(),
+            connector_width: 1.0,
+            toggle_background: [0.6, 0.6, 0.6].into(),
+            toggle_text_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of an [`OrgChart`](crate::native::org_chart::OrgChart).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of an [`OrgChart`](crate::native::org_chart::OrgChart).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of an [`OrgChart`](crate::native::org_chart::OrgChart).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum OrgChartStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl OrgChartStyles {
+    /// Creates a custom [`OrgChartStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = OrgChartStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let OrgChartStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            node_background: palette.background.base.color.into(),
+            node_border_color: palette.background.strong.color,
+            hovered_border_color: palette.primary.strong.color,
+            connector_color: palette.background.strong.color,
+            toggle_background: palette.background.strong.color,
+            toggle_text_color: palette.background.strong.text,
+            ..Appearance::default()
+        }
+    }
+}
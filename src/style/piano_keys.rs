@@ -0,0 +1,79 @@
+//! Use piano keys to play notes.
+//!
+//! *This API requires the following crate features to be activated: `piano_keys`*
+use std::rc::Rc;
+
+use iced_widget::{core::Color, style::Theme};
+
+/// The appearance of [`PianoKeys`](crate::native::piano_keys::PianoKeys).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The color of the white keys.
+    pub white_key_color: Color,
+    /// The color of the black keys.
+    pub black_key_color: Color,
+    /// The color of pressed white keys.
+    pub pressed_white_key_color: Color,
+    /// The color of pressed black keys.
+    pub pressed_black_key_color: Color,
+    /// The color of the borders between the keys.
+    pub border_color: Color,
+    /// The color of the note names on the C keys.
+    pub label_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            white_key_color: Color::WHITE,
+            black_key_color: [0.1, 0.1, 0.1].into(),
+            pressed_white_key_color: [0.6, 0.8, 1.0].into(),
+            pressed_black_key_color: [0.2, 0.5, 0.8].into(),
+            border_color: [0.3, 0.3, 0.3].into(),
+            label_color: [0.5, 0.5, 0.5].into(),
+        }
+    }
+}
+
+/// The appearance of [`PianoKeys`](crate::native::piano_keys::PianoKeys).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of [`PianoKeys`](crate::native::piano_keys::PianoKeys).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of [`PianoKeys`](crate::native::piano_keys::PianoKeys).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum PianoKeysStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl PianoKeysStyles {
+    /// Creates a custom [`PianoKeysStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = PianoKeysStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let PianoKeysStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            pressed_white_key_color: palette.primary.weak.color,
+            pressed_black_key_color: palette.primary.strong.color,
+            border_color: palette.background.strong.color,
+            ..Appearance::default()
+        }
+    }
+}
@@ -0,0 +1,97 @@
+//! Use a radar chart to compare series of values over a number of axes.
+//!
+//! *This API requires the following crate features to be activated: `radar`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`RadarChart`](crate::native::radar::RadarChart).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`RadarChart`](crate::native::radar::RadarChart).
+    pub background: Background,
+    /// The colors of the series, which are used in turn.
+    pub palette: [Color; 6],
+    /// The width of the outlines of the series.
+    pub line_width: f32,
+    /// The color of the rings of the scale.
+    pub grid_color: Color,
+    /// The color of the spokes of the axes.
+    pub axis_color: Color,
+    /// The color of the labels of the axes and the scale.
+    pub text_color: Color,
+    /// The background of the tooltip with the hovered value.
+    pub tooltip_background: Background,
+    /// The color of the text of the tooltip with the hovered value.
+    pub tooltip_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::TRANSPARENT.into(),
+            palette: [
+                [0.31, 0.47, 0.65].into(),
+                [0.95, 0.56, 0.17].into(),
+                [0.88, 0.34, 0.35].into(),
+                [0.46, 0.72, 0.7].into(),
+                [0.35, 0.63, 0.31].into(),
+                [0.69, 0.48, 0.63].into(),
+            ],
+            line_width: 2.0,
+            grid_color: Color::from_rgb(0.85, 0.85, 0.85),
+            axis_color: Color::from_rgb(0.6, 0.6, 0.6),
+            text_color: Color::BLACK,
+            tooltip_background: Color::from_rgba(0.0, 0.0, 0.0, 0.8).into(),
+            tooltip_text_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of a [`RadarChart`](crate::native::radar::RadarChart).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`RadarChart`](crate::native::radar::RadarChart).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`RadarChart`](crate::native::radar::RadarChart).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum RadarChartStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl RadarChartStyles {
+    /// Creates a custom [`RadarChartStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = RadarChartStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let RadarChartStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            grid_color: palette.background.weak.color,
+            axis_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            tooltip_background: palette.background.strong.color.into(),
+            tooltip_text_color: palette.background.strong.text,
+            ..Appearance::default()
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! Use a rich editor to edit formatted text.
+//!
+//! *This API requires the following crate features to be activated: `rich_editor`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`RichEditor`](crate::native::rich_editor::RichEditor).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`RichEditor`](crate::native::rich_editor::RichEditor).
+    pub background: Background,
+    /// The border radius of the [`RichEditor`](crate::native::rich_editor::RichEditor).
+    pub border_radius: f32,
+    /// The border width of the [`RichEditor`](crate::native::rich_editor::RichEditor).
+    pub border_width: f32,
+    /// The border color of the [`RichEditor`](crate::native::rich_editor::RichEditor).
+    pub border_color: Color,
+    /// The border color of the focused [`RichEditor`](crate::native::rich_editor::RichEditor).
+    pub focused_border_color: Color,
+    /// The color of the text.
+    pub text_color: Color,
+    /// The color of italic text, which the fonts can't slant.
+    pub italic_color: Color,
+    /// The color of linked text.
+    pub link_color: Color,
+    /// The background of inline code.
+    pub code_background: Color,
+    /// The color of the bullets and numbers of list items.
+    pub marker_color: Color,
+    /// The background of the selected text.
+    pub selection_background: Color,
+    /// The color of the text cursor.
+    pub cursor_color: Color,
+    /// The background of the toolbar.
+    pub toolbar_background: Color,
+    /// The background of the hovered toolbar buttons.
+    pub hovered_button_background: Color,
+    /// The background of the toolbar buttons of the formats of the selection.
+    pub active_button_background: Color,
+    /// The color of the labels of the toolbar buttons.
+    pub button_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: 4.0,
+            border_width: 1.0,
+            border_color: [0.7, 0.7, 0.7].into(),
+            focused_border_color: [0.2, 0.5, 0.8].into(),
+            text_color: Color::BLACK,
+            italic_color: [0.35, 0.35, 0.35].into(),
+            link_color: [0.1, 0.4, 0.8].into(),
+            code_background: [0.92, 0.92, 0.92].into(),
+            marker_color: [0.4, 0.4, 0.4].into(),
+            selection_background: [0.6, 0.75, 0.95].into(),
+            cursor_color: Color::BLACK,
+            toolbar_background: [0.95, 0.95, 0.95].into(),
+            hovered_button_background: [0.88, 0.88, 0.88].into(),
+            active_button_background: [0.78, 0.85, 0.95].into(),
+            button_text_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`RichEditor`](crate::native::rich_editor::RichEditor).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`RichEditor`](crate::native::rich_editor::RichEditor).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`RichEditor`](crate::native::rich_editor::RichEditor).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum RichEditorStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl RichEditorStyles {
+    /// Creates a custom [`RichEditorStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = RichEditorStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let RichEditorStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            focused_border_color: palette.primary.strong.color,
+            text_color: palette.background.base.text,
+            italic_color: palette.secondary.base.color,
+            link_color: palette.primary.strong.color,
+            code_background: palette.background.weak.color,
+            marker_color: palette.background.strong.color,
+            selection_background: palette.primary.weak.color,
+            cursor_color: palette.background.base.text,
+            toolbar_background: palette.background.weak.color,
+            hovered_button_background: palette.background.strong.color,
+            active_button_background: palette.primary.weak.color,
+            button_text_color: palette.background.base.text,
+            ..Appearance::default()
+        }
+    }
+}
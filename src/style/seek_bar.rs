@@ -0,0 +1,87 @@
+//! Use a seek bar to show and change the playback position of some media.
+//!
+//! *This API requires the following crate features to be activated: `seek_bar`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`SeekBar`](crate::native::seek_bar::SeekBar).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The color of the rail of the [`SeekBar`](crate::native::seek_bar::SeekBar).
+    pub rail_color: Color,
+    /// The color of the buffered ranges on the rail.
+    pub buffered_color: Color,
+    /// The color of the played part of the rail.
+    pub progress_color: Color,
+    /// The color of the handle at the playback position.
+    pub handle_color: Color,
+    /// The color of the chapter markers on the rail.
+    pub chapter_marker_color: Color,
+    /// The background of the hover time preview.
+    pub tooltip_background: Background,
+    /// The text color of the hover time preview.
+    pub tooltip_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            rail_color: [0.85, 0.85, 0.85].into(),
+            buffered_color: [0.65, 0.65, 0.65].into(),
+            progress_color: [0.2, 0.5, 0.8].into(),
+            handle_color: [0.2, 0.5, 0.8].into(),
+            chapter_marker_color: Color::WHITE,
+            tooltip_background: Background::Color([0.1, 0.1, 0.1, 0.9].into()),
+            tooltip_text_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of a [`SeekBar`](crate::native::seek_bar::SeekBar).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`SeekBar`](crate::native::seek_bar::SeekBar).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`SeekBar`](crate::native::seek_bar::SeekBar).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum SeekBarStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl SeekBarStyles {
+    /// Creates a custom [`SeekBarStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = SeekBarStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let SeekBarStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            rail_color: palette.background.weak.color,
+            buffered_color: palette.background.strong.color,
+            progress_color: palette.primary.base.color,
+            handle_color: palette.primary.base.color,
+            chapter_marker_color: palette.background.base.color,
+            ..Appearance::default()
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! Use a segment display to show numbers like a retro instrument panel.
+//!
+//! *This API requires the following crate features to be activated: `segment_display`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`SegmentDisplay`](crate::native::segment_display::SegmentDisplay).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`SegmentDisplay`](crate::native::segment_display::SegmentDisplay).
+    pub background: Background,
+    /// The border radius of the background.
+    pub border_radius: f32,
+    /// The color of the lit segments.
+    pub segment_color: Color,
+    /// The color of the unlit segments, which are hidden if it is
+    /// transparent.
+    pub unlit_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::from_rgb(0.08, 0.08, 0.08).into(),
+            border_radius: 4.0,
+            segment_color: Color::from_rgb(1.0, 0.25, 0.2),
+            unlit_color: Color::from_rgba(1.0, 0.25, 0.2, 0.08),
+        }
+    }
+}
+
+/// The appearance of a [`SegmentDisplay`](crate::native::segment_display::SegmentDisplay).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`SegmentDisplay`](crate::native::segment_display::SegmentDisplay).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`SegmentDisplay`](crate::native::segment_display::SegmentDisplay).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum SegmentDisplayStyles {
+    #[default]
+    Default,
+    /// Green segments on a dark background, like a vintage LCD or VFD.
+    Green,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl SegmentDisplayStyles {
+    /// Creates a custom [`SegmentDisplayStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = SegmentDisplayStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        match style {
+            SegmentDisplayStyles::Default => Appearance::default(),
+            SegmentDisplayStyles::Green => {
+                let green = Color::from_rgb(0.3, 0.95, 0.45);
+
+                Appearance {
+                    segment_color: green,
+                    unlit_color: Color { a: 0.08, ..green },
+                    ..Appearance::default()
+                }
+            }
+            SegmentDisplayStyles::Custom(custom) => custom.active(self),
+        }
+    }
+}
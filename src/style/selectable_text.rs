@@ -0,0 +1,65 @@
+//! Use a selectable text to display read-only text that can be copied.
+//!
+//! *This API requires the following crate features to be activated: `selectable_text`*
+use std::rc::Rc;
+
+use iced_widget::{core::Color, style::Theme};
+
+/// The appearance of a [`SelectableText`](crate::native::selectable_text::SelectableText).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The color of the text, or the text color of the parent if `None`.
+    pub text_color: Option<Color>,
+    /// The background of the selected text.
+    pub selection_background: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            text_color: None,
+            selection_background: [0.6, 0.75, 0.95].into(),
+        }
+    }
+}
+
+/// The appearance of a [`SelectableText`](crate::native::selectable_text::SelectableText).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`SelectableText`](crate::native::selectable_text::SelectableText).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`SelectableText`](crate::native::selectable_text::SelectableText).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum SelectableTextStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl SelectableTextStyles {
+    /// Creates a custom [`SelectableTextStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = SelectableTextStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let SelectableTextStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            selection_background: palette.primary.weak.color,
+            ..Appearance::default()
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! Use a settings page to show grouped settings with a searchable list of
+//! their sections.
+//!
+//! *This API requires the following crate features to be activated: `settings_page`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`SettingsPage`](crate::native::settings_page::SettingsPage).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the section list.
+    pub sidebar_background: Background,
+    /// The background of the highlighted section in the section list.
+    pub selected_background: Background,
+    /// The text color of the highlighted section in the section list.
+    pub selected_text_color: Color,
+    /// The background of the hovered section in the section list.
+    pub hovered_background: Background,
+    /// The background of the rows of a section.
+    pub group_background: Background,
+    /// The border radius of the rows of a section and of the section list items.
+    pub border_radius: f32,
+    /// The border width of the rows of a section.
+    pub border_width: f32,
+    /// The border color of the rows of a section.
+    pub border_color: Color,
+    /// The color of the section titles.
+    pub title_color: Color,
+    /// The color of the descriptions of the rows.
+    pub description_color: Color,
+    /// The background of the matches of the search query.
+    pub highlight_background: Background,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            sidebar_background: Color::from_rgb(0.95, 0.95, 0.95).into(),
+            selected_background: Color::from_rgb(0.2, 0.5, 0.8).into(),
+            selected_text_color: Color::WHITE,
+            hovered_background: Color::from_rgb(0.9, 0.9, 0.9).into(),
+            group_background: Color::WHITE.into(),
+            border_radius: 4.0,
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            title_color: [0.2, 0.2, 0.2].into(),
+            description_color: [0.45, 0.45, 0.45].into(),
+            highlight_background: Color::from_rgba(1.0, 0.85, 0.2, 0.6).into(),
+        }
+    }
+}
+
+/// The appearance of a [`SettingsPage`](crate::native::settings_page::SettingsPage).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`SettingsPage`](crate::native::settings_page::SettingsPage).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`SettingsPage`](crate::native::settings_page::SettingsPage).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum SettingsPageStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl SettingsPageStyles {
+    /// Creates a custom [`SettingsPageStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = SettingsPageStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let SettingsPageStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            sidebar_background: palette.background.weak.color.into(),
+            selected_background: palette.primary.strong.color.into(),
+            selected_text_color: palette.primary.strong.text,
+            hovered_background: palette.background.strong.color.into(),
+            group_background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            title_color: palette.background.base.text,
+            description_color: palette.background.strong.color,
+            highlight_background: Color {
+                a: 0.4,
+                ..palette.primary.base.color
+            }
+            .into(),
+            ..Appearance::default()
+        }
+    }
+}
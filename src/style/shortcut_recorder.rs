@@ -0,0 +1,99 @@
+//! Use a shortcut recorder to record a key combination.
+//!
+//! *This API requires the following crate features to be activated: `shortcut_recorder`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`ShortcutRecorder`](crate::native::shortcut_recorder::ShortcutRecorder).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`ShortcutRecorder`](crate::native::shortcut_recorder::ShortcutRecorder).
+    pub background: Background,
+    /// The border radius of the [`ShortcutRecorder`](crate::native::shortcut_recorder::ShortcutRecorder).
+    pub border_radius: f32,
+    /// The border width of the [`ShortcutRecorder`](crate::native::shortcut_recorder::ShortcutRecorder).
+    pub border_width: f32,
+    /// The border color of the [`ShortcutRecorder`](crate::native::shortcut_recorder::ShortcutRecorder).
+    pub border_color: Color,
+    /// The border color while recording.
+    pub recording_border_color: Color,
+    /// The color of the placeholder.
+    pub placeholder_color: Color,
+    /// The color of the border and of the message of a rejected shortcut.
+    pub error_color: Color,
+    /// The background of the keycaps.
+    pub keycap_background: Background,
+    /// The border color of the keycaps.
+    pub keycap_border_color: Color,
+    /// The text color of the keycaps.
+    pub keycap_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: 4.0,
+            border_width: 1.0,
+            border_color: [0.7, 0.7, 0.7].into(),
+            recording_border_color: [0.2, 0.5, 0.8].into(),
+            placeholder_color: [0.55, 0.55, 0.55].into(),
+            error_color: [0.75, 0.15, 0.15].into(),
+            keycap_background: Color::from_rgb(0.94, 0.94, 0.94).into(),
+            keycap_border_color: [0.7, 0.7, 0.7].into(),
+            keycap_text_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`ShortcutRecorder`](crate::native::shortcut_recorder::ShortcutRecorder).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`ShortcutRecorder`](crate::native::shortcut_recorder::ShortcutRecorder).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`ShortcutRecorder`](crate::native::shortcut_recorder::ShortcutRecorder).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum ShortcutRecorderStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl ShortcutRecorderStyles {
+    /// Creates a custom [`ShortcutRecorderStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = ShortcutRecorderStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let ShortcutRecorderStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            recording_border_color: palette.primary.base.color,
+            placeholder_color: palette.background.strong.color,
+            error_color: palette.danger.base.color,
+            keycap_background: palette.background.weak.color.into(),
+            keycap_border_color: palette.background.strong.color,
+            keycap_text_color: palette.background.weak.text,
+            ..Appearance::default()
+        }
+    }
+}
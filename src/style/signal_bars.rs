@@ -0,0 +1,80 @@
+//! Use signal bars to show the strength of a connection.
+//!
+//! *This API requires the following crate features to be activated: `signal_bars`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`SignalBars`](crate::native::signal_bars::SignalBars).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`SignalBars`](crate::native::signal_bars::SignalBars).
+    pub background: Background,
+    /// The color of a lit bar.
+    pub lit_color: Color,
+    /// The color of a bar that is not lit.
+    pub off_color: Color,
+    /// The color of the bars while searching for a connection.
+    pub searching_color: Color,
+    /// The color of the cross over the bars of a failed connection.
+    pub error_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::TRANSPARENT.into(),
+            lit_color: Color::BLACK,
+            off_color: [0.8, 0.8, 0.8].into(),
+            searching_color: [0.5, 0.5, 0.5].into(),
+            error_color: [0.85, 0.2, 0.2].into(),
+        }
+    }
+}
+
+/// The appearance of a [`SignalBars`](crate::native::signal_bars::SignalBars).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`SignalBars`](crate::native::signal_bars::SignalBars).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`SignalBars`](crate::native::signal_bars::SignalBars).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum SignalBarsStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl SignalBarsStyles {
+    /// Creates a custom [`SignalBarsStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = SignalBarsStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let SignalBarsStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            lit_color: palette.background.base.text,
+            off_color: palette.background.strong.color,
+            searching_color: palette.primary.base.color,
+            error_color: palette.danger.base.color,
+            ..Appearance::default()
+        }
+    }
+}
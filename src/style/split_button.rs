@@ -0,0 +1,161 @@
+//! Use a split button to run a main action or choose an alternative one.
+//!
+//! *This API requires the following crate features to be activated: `split_button`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`SplitButton`](crate::native::split_button::SplitButton).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the sections of the [`SplitButton`](crate::native::split_button::SplitButton).
+    pub background: Background,
+    /// The border radius of the [`SplitButton`](crate::native::split_button::SplitButton).
+    pub border_radius: f32,
+    /// The border width of the [`SplitButton`](crate::native::split_button::SplitButton).
+    pub border_width: f32,
+    /// The border color of the [`SplitButton`](crate::native::split_button::SplitButton).
+    pub border_color: Color,
+    /// The color of the label and the chevron.
+    pub text_color: Color,
+    /// The color of the line between the main action and the chevron.
+    pub separator_color: Color,
+    /// The background of the dropdown menu.
+    pub menu_background: Color,
+    /// The border color of the dropdown menu.
+    pub menu_border_color: Color,
+    /// The text color of the actions of the dropdown menu.
+    pub menu_text_color: Color,
+    /// The background of the hovered action of the dropdown menu.
+    pub menu_hovered_background: Color,
+    /// The text color of the hovered action of the dropdown menu.
+    pub menu_hovered_text_color: Color,
+    /// The text color of the disabled actions of the dropdown menu.
+    pub menu_disabled_text_color: Color,
+    /// The text color of the shortcuts of the actions of the dropdown menu.
+    pub shortcut_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::from_rgb(0.2, 0.5, 0.8).into(),
+            border_radius: 4.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            text_color: Color::WHITE,
+            separator_color: [0.15, 0.4, 0.65].into(),
+            menu_background: Color::WHITE,
+            menu_border_color: [0.8, 0.8, 0.8].into(),
+            menu_text_color: Color::BLACK,
+            menu_hovered_background: [0.2, 0.5, 0.8].into(),
+            menu_hovered_text_color: Color::WHITE,
+            menu_disabled_text_color: [0.6, 0.6, 0.6].into(),
+            shortcut_text_color: [0.5, 0.5, 0.5].into(),
+        }
+    }
+}
+
+/// The appearance of a [`SplitButton`](crate::native::split_button::SplitButton).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`SplitButton`](crate::native::split_button::SplitButton).
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a hovered section of a [`SplitButton`](crate::native::split_button::SplitButton).
+    fn hovered(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a pressed section of a [`SplitButton`](crate::native::split_button::SplitButton).
+    fn pressed(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a disabled section of a [`SplitButton`](crate::native::split_button::SplitButton).
+    fn disabled(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`SplitButton`](crate::native::split_button::SplitButton).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum SplitButtonStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl SplitButtonStyles {
+    /// Creates a custom [`SplitButtonStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = SplitButtonStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let SplitButtonStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.primary.strong.color.into(),
+            text_color: palette.primary.strong.text,
+            separator_color: palette.primary.base.color,
+            menu_background: palette.background.base.color,
+            menu_border_color: palette.background.strong.color,
+            menu_text_color: palette.background.base.text,
+            menu_hovered_background: palette.primary.weak.color,
+            menu_hovered_text_color: palette.primary.weak.text,
+            menu_disabled_text_color: palette.background.strong.color,
+            shortcut_text_color: palette.background.strong.color,
+            ..Appearance::default()
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        if let SplitButtonStyles::Custom(custom) = style {
+            return custom.hovered(self);
+        }
+
+        Appearance {
+            background: self.extended_palette().primary.base.color.into(),
+            ..self.active(style)
+        }
+    }
+
+    fn pressed(&self, style: &Self::Style) -> Appearance {
+        if let SplitButtonStyles::Custom(custom) = style {
+            return custom.pressed(self);
+        }
+
+        self.active(style)
+    }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        if let SplitButtonStyles::Custom(custom) = style {
+            return custom.disabled(self);
+        }
+
+        let active = self.active(style);
+
+        Appearance {
+            background: match active.background {
+                Background::Color(color) => Background::Color(Color {
+                    a: color.a * 0.5,
+                    ..color
+                }),
+                background @ Background::Gradient(_) => background,
+            },
+            text_color: Color {
+                a: active.text_color.a * 0.5,
+                ..active.text_color
+            },
+            ..active
+        }
+    }
+}
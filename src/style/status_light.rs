@@ -0,0 +1,93 @@
+//! Use a status light to show the state of a service or a device.
+//!
+//! *This API requires the following crate features to be activated: `status_light`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`StatusLight`](crate::native::status_light::StatusLight).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`StatusLight`](crate::native::status_light::StatusLight).
+    pub background: Background,
+    /// The color of the light when it is off.
+    pub off_color: Color,
+    /// The color of a lit neutral light.
+    pub neutral_color: Color,
+    /// The color of a lit success light.
+    pub success_color: Color,
+    /// The color of a lit warning light.
+    pub warning_color: Color,
+    /// The color of a lit danger light.
+    pub danger_color: Color,
+    /// The border width of the light.
+    pub border_width: f32,
+    /// The border color of the light.
+    pub border_color: Color,
+    /// The color of the label.
+    pub label_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::TRANSPARENT.into(),
+            off_color: [0.8, 0.8, 0.8].into(),
+            neutral_color: [0.2, 0.45, 0.85].into(),
+            success_color: [0.2, 0.7, 0.3].into(),
+            warning_color: [0.95, 0.65, 0.1].into(),
+            danger_color: [0.85, 0.2, 0.2].into(),
+            border_width: 1.0,
+            border_color: [0.0, 0.0, 0.0, 0.2].into(),
+            label_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`StatusLight`](crate::native::status_light::StatusLight).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`StatusLight`](crate::native::status_light::StatusLight).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`StatusLight`](crate::native::status_light::StatusLight).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum StatusLightStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl StatusLightStyles {
+    /// Creates a custom [`StatusLightStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = StatusLightStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let StatusLightStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            off_color: palette.background.strong.color,
+            neutral_color: palette.primary.base.color,
+            success_color: palette.success.base.color,
+            danger_color: palette.danger.base.color,
+            label_color: palette.background.base.text,
+            ..Appearance::default()
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! Use a step grid to toggle the steps of a sequencer.
+//!
+//! *This API requires the following crate features to be activated: `step_grid`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`StepGrid`](crate::native::step_grid::StepGrid).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`StepGrid`](crate::native::step_grid::StepGrid).
+    pub background: Background,
+    /// The background of the cells.
+    pub cell_background: Color,
+    /// The background of the cells on the first step of a beat.
+    pub beat_cell_background: Color,
+    /// The color of active cells, filled up to their velocity.
+    pub active_color: Color,
+    /// The color laid over the cells of the step at the playhead.
+    pub playhead_color: Color,
+    /// The border radius of the cells.
+    pub cell_border_radius: f32,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::TRANSPARENT.into(),
+            cell_background: [0.9, 0.9, 0.9].into(),
+            beat_cell_background: [0.82, 0.82, 0.82].into(),
+            active_color: [0.2, 0.5, 0.8].into(),
+            playhead_color: [1.0, 1.0, 1.0, 0.35].into(),
+            cell_border_radius: 3.0,
+        }
+    }
+}
+
+/// The appearance of a [`StepGrid`](crate::native::step_grid::StepGrid).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`StepGrid`](crate::native::step_grid::StepGrid).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`StepGrid`](crate::native::step_grid::StepGrid).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum StepGridStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl StepGridStyles {
+    /// Creates a custom [`StepGridStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = StepGridStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let StepGridStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            cell_background: palette.background.weak.color,
+            beat_cell_background: palette.background.strong.color,
+            active_color: palette.primary.base.color,
+            playhead_color: Color {
+                a: 0.35,
+                ..palette.background.base.text
+            },
+            ..Appearance::default()
+        }
+    }
+}
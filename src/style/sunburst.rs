@@ -0,0 +1,99 @@
+//! Use a sunburst to show hierarchical size data as rings of segments.
+//!
+//! *This API requires the following crate features to be activated: `sunburst`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`Sunburst`](crate::native::sunburst::Sunburst).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`Sunburst`](crate::native::sunburst::Sunburst).
+    pub background: Background,
+    /// The colors of the segments of the innermost ring, which are used in
+    /// turn unless the segments are colored by a function.
+    pub palette: [Color; 6],
+    /// The color of the borders of the segments.
+    pub border_color: Color,
+    /// The color of the circle in the center.
+    pub center_color: Color,
+    /// The color of the label in the center.
+    pub center_text_color: Color,
+    /// The color of the border of the hovered segment.
+    pub hovered_border_color: Color,
+    /// The background of the tooltip of the hovered segment.
+    pub tooltip_background: Background,
+    /// The color of the text of the tooltip of the hovered segment.
+    pub tooltip_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::TRANSPARENT.into(),
+            palette: [
+                [0.31, 0.47, 0.65].into(),
+                [0.95, 0.56, 0.17].into(),
+                [0.88, 0.34, 0.35].into(),
+                [0.46, 0.72, 0.7].into(),
+                [0.35, 0.63, 0.31].into(),
+                [0.69, 0.48, 0.63].into(),
+            ],
+            border_color: Color::WHITE,
+            center_color: Color::from_rgb(0.9, 0.9, 0.9),
+            center_text_color: Color::BLACK,
+            hovered_border_color: Color::BLACK,
+            tooltip_background: Color::from_rgba(0.0, 0.0, 0.0, 0.8).into(),
+            tooltip_text_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of a [`Sunburst`](crate::native::sunburst::Sunburst).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`Sunburst`](crate::native::sunburst::Sunburst).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`Sunburst`](crate::native::sunburst::Sunburst).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum SunburstStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl SunburstStyles {
+    /// Creates a custom [`SunburstStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = SunburstStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let SunburstStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            border_color: palette.background.base.color,
+            center_color: palette.background.weak.color,
+            center_text_color: palette.background.weak.text,
+            hovered_border_color: palette.background.base.text,
+            tooltip_background: palette.background.strong.color.into(),
+            tooltip_text_color: palette.background.strong.text,
+            ..Appearance::default()
+        }
+    }
+}
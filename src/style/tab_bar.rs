@@ -45,6 +45,12 @@ pub struct Appearance {
 
     /// The text color of the tab labels.
     pub text_color: Color,
+
+    /// The background of the count badges of the tab labels.
+    pub badge_background: Background,
+
+    /// The text color of the count badges of the tab labels.
+    pub badge_text_color: Color,
 }
 
 /// The appearance of a [`TabBar`](crate::native::tab_bar::TabBar).
@@ -76,6 +82,8 @@ impl Default for Appearance {
             icon_background: Some(Background::Color(Color::TRANSPARENT)),
             icon_border_radius: 4.0.into(),
             text_color: Color::BLACK,
+            badge_background: Background::Color([0.86, 0.21, 0.27].into()),
+            badge_text_color: Color::WHITE,
         }
     }
 }
@@ -0,0 +1,86 @@
+//! Use a theme editor to edit and export a palette.
+//!
+//! *This API requires the following crate features to be activated: `theme_editor`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`ThemeEditor`](crate::native::theme_editor::ThemeEditor).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`ThemeEditor`](crate::native::theme_editor::ThemeEditor).
+    pub background: Background,
+    /// The border radius of the [`ThemeEditor`](crate::native::theme_editor::ThemeEditor).
+    pub border_radius: f32,
+    /// The border width of the [`ThemeEditor`](crate::native::theme_editor::ThemeEditor).
+    pub border_width: f32,
+    /// The border color of the [`ThemeEditor`](crate::native::theme_editor::ThemeEditor).
+    pub border_color: Color,
+    /// The color of the names of the colors.
+    pub text_color: Color,
+    /// The border radius of the swatches.
+    pub swatch_border_radius: f32,
+    /// The border color of the swatches.
+    pub swatch_border_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: 8.0,
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            text_color: Color::BLACK,
+            swatch_border_radius: 4.0,
+            swatch_border_color: [0.6, 0.6, 0.6].into(),
+        }
+    }
+}
+
+/// The appearance of a [`ThemeEditor`](crate::native::theme_editor::ThemeEditor).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`ThemeEditor`](crate::native::theme_editor::ThemeEditor).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`ThemeEditor`](crate::native::theme_editor::ThemeEditor).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum ThemeEditorStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl ThemeEditorStyles {
+    /// Creates a custom [`ThemeEditorStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = ThemeEditorStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let ThemeEditorStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            swatch_border_color: palette.background.strong.color,
+            ..Appearance::default()
+        }
+    }
+}
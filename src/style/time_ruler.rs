@@ -0,0 +1,76 @@
+//! Use a time ruler to display the tick marks of a time range.
+//!
+//! *This API requires the following crate features to be activated: `time_ruler`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`TimeRuler`](crate::native::time_ruler::TimeRuler).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`TimeRuler`](crate::native::time_ruler::TimeRuler).
+    pub background: Background,
+    /// The color of the major tick marks and the baseline.
+    pub tick_color: Color,
+    /// The color of the minor tick marks.
+    pub minor_tick_color: Color,
+    /// The color of the labels of the major tick marks.
+    pub text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::TRANSPARENT.into(),
+            tick_color: [0.3, 0.3, 0.3].into(),
+            minor_tick_color: [0.6, 0.6, 0.6].into(),
+            text_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`TimeRuler`](crate::native::time_ruler::TimeRuler).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`TimeRuler`](crate::native::time_ruler::TimeRuler).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`TimeRuler`](crate::native::time_ruler::TimeRuler).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum TimeRulerStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl TimeRulerStyles {
+    /// Creates a custom [`TimeRulerStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = TimeRulerStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let TimeRulerStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            tick_color: palette.background.base.text,
+            minor_tick_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            ..Appearance::default()
+        }
+    }
+}
@@ -0,0 +1,93 @@
+//! Use a title bar to move and control an undecorated window.
+//!
+//! *This API requires the following crate features to be activated: `title_bar`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`TitleBar`](crate::native::title_bar::TitleBar).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`TitleBar`](crate::native::title_bar::TitleBar).
+    pub background: Background,
+    /// The color of the title.
+    pub text_color: Color,
+    /// The color of the icons of the window buttons.
+    pub icon_color: Color,
+    /// The background of a hovered window button.
+    pub button_hovered_background: Background,
+    /// The background of a pressed window button.
+    pub button_pressed_background: Background,
+    /// The background of the hovered close button.
+    pub close_hovered_background: Background,
+    /// The color of the icon of the hovered close button.
+    pub close_hovered_icon_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::from_rgb(0.93, 0.93, 0.93).into(),
+            text_color: Color::BLACK,
+            icon_color: Color::BLACK,
+            button_hovered_background: Color::from_rgba(0.0, 0.0, 0.0, 0.08).into(),
+            button_pressed_background: Color::from_rgba(0.0, 0.0, 0.0, 0.16).into(),
+            close_hovered_background: Color::from_rgb(0.77, 0.17, 0.11).into(),
+            close_hovered_icon_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of a [`TitleBar`](crate::native::title_bar::TitleBar).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default;
+    /// The appearance of the [`TitleBar`](crate::native::title_bar::TitleBar)
+    /// of the focused window.
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`TitleBar`](crate::native::title_bar::TitleBar).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum TitleBarStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl TitleBarStyles {
+    /// Creates a custom [`TitleBarStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = TitleBarStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let TitleBarStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+        let overlay = |alpha| Color {
+            a: alpha,
+            ..palette.background.base.text
+        };
+
+        Appearance {
+            background: palette.background.weak.color.into(),
+            text_color: palette.background.weak.text,
+            icon_color: palette.background.weak.text,
+            button_hovered_background: overlay(0.08).into(),
+            button_pressed_background: overlay(0.16).into(),
+            close_hovered_background: palette.danger.base.color.into(),
+            close_hovered_icon_color: palette.danger.base.text,
+        }
+    }
+}
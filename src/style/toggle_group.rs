@@ -0,0 +1,95 @@
+//! Use a toggle group to select none, one or several of a row of options.
+//!
+//! *This API requires the following crate features to be activated: `toggle_group`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`ToggleGroup`](crate::native::toggle_group::ToggleGroup).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the unselected buttons.
+    pub background: Background,
+    /// The background of the selected buttons.
+    pub selected_background: Background,
+    /// The background of the hovered unselected button.
+    pub hovered_background: Background,
+    /// The border radius of the [`ToggleGroup`](crate::native::toggle_group::ToggleGroup).
+    pub border_radius: f32,
+    /// The border width of the [`ToggleGroup`](crate::native::toggle_group::ToggleGroup).
+    pub border_width: f32,
+    /// The color of the border and of the lines between the buttons.
+    pub border_color: Color,
+    /// The text color of the unselected buttons.
+    pub text_color: Color,
+    /// The text color of the selected buttons.
+    pub selected_text_color: Color,
+    /// The color of the ring around the button with the keyboard focus.
+    pub focus_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            selected_background: Color::from_rgb(0.2, 0.5, 0.8).into(),
+            hovered_background: Color::from_rgb(0.92, 0.92, 0.92).into(),
+            border_radius: 4.0,
+            border_width: 1.0,
+            border_color: [0.7, 0.7, 0.7].into(),
+            text_color: Color::BLACK,
+            selected_text_color: Color::WHITE,
+            focus_color: [0.1, 0.35, 0.65].into(),
+        }
+    }
+}
+
+/// The appearance of a [`ToggleGroup`](crate::native::toggle_group::ToggleGroup).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`ToggleGroup`](crate::native::toggle_group::ToggleGroup).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`ToggleGroup`](crate::native::toggle_group::ToggleGroup).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum ToggleGroupStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl ToggleGroupStyles {
+    /// Creates a custom [`ToggleGroupStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = ToggleGroupStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let ToggleGroupStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            selected_background: palette.primary.strong.color.into(),
+            hovered_background: palette.background.weak.color.into(),
+            border_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            selected_text_color: palette.primary.strong.text,
+            focus_color: palette.primary.base.color,
+            ..Appearance::default()
+        }
+    }
+}
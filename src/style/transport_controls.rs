@@ -0,0 +1,103 @@
+//! Use transport controls to play, pause and seek some media.
+//!
+//! *This API requires the following crate features to be activated: `transport_controls`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of [`TransportControls`](crate::native::transport_controls::TransportControls).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`TransportControls`](crate::native::transport_controls::TransportControls).
+    pub background: Background,
+    /// The border radius of the [`TransportControls`](crate::native::transport_controls::TransportControls).
+    pub border_radius: f32,
+    /// The border width of the [`TransportControls`](crate::native::transport_controls::TransportControls).
+    pub border_width: f32,
+    /// The border color of the [`TransportControls`](crate::native::transport_controls::TransportControls).
+    pub border_color: Color,
+    /// The color of the icons of the buttons.
+    pub icon_color: Color,
+    /// The background of a hovered button.
+    pub hovered_button_background: Background,
+    /// The color of the elapsed and remaining time.
+    pub text_color: Color,
+    /// The background of the volume popover.
+    pub popover_background: Background,
+    /// The border color of the volume popover.
+    pub popover_border_color: Color,
+    /// The color of the rail of the volume slider.
+    pub volume_rail_color: Color,
+    /// The color of the filled part of the volume slider.
+    pub volume_fill_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: 4.0,
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            icon_color: Color::BLACK,
+            hovered_button_background: Background::Color([0.9, 0.9, 0.9].into()),
+            text_color: Color::BLACK,
+            popover_background: Color::WHITE.into(),
+            popover_border_color: [0.8, 0.8, 0.8].into(),
+            volume_rail_color: [0.85, 0.85, 0.85].into(),
+            volume_fill_color: [0.2, 0.5, 0.8].into(),
+        }
+    }
+}
+
+/// The appearance of [`TransportControls`](crate::native::transport_controls::TransportControls).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of [`TransportControls`](crate::native::transport_controls::TransportControls).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of [`TransportControls`](crate::native::transport_controls::TransportControls).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum TransportControlsStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl TransportControlsStyles {
+    /// Creates a custom [`TransportControlsStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = TransportControlsStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let TransportControlsStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            icon_color: palette.background.base.text,
+            hovered_button_background: palette.background.weak.color.into(),
+            text_color: palette.background.base.text,
+            popover_background: palette.background.base.color.into(),
+            popover_border_color: palette.background.strong.color,
+            volume_rail_color: palette.background.weak.color,
+            volume_fill_color: palette.primary.base.color,
+            ..Appearance::default()
+        }
+    }
+}
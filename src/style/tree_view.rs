@@ -0,0 +1,112 @@
+//! Use a tree view to display and select the nodes of a hierarchy.
+//!
+//! *This API requires the following crate features to be activated: `tree_view`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`TreeView`](crate::native::tree_view::TreeView).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`TreeView`](crate::native::tree_view::TreeView).
+    pub background: Background,
+    /// The color of the labels.
+    pub text_color: Color,
+    /// The color of the expand and collapse toggles.
+    pub toggle_color: Color,
+    /// The background of the hovered row.
+    pub hovered_background: Color,
+    /// The background of the selected row.
+    pub selected_background: Color,
+    /// The color of the label of the selected row.
+    pub selected_text_color: Color,
+    /// The background of the checkboxes.
+    pub checkbox_background: Color,
+    /// The border color of the checkboxes.
+    pub checkbox_border_color: Color,
+    /// The color of the marks of checked and indeterminate checkboxes.
+    pub check_color: Color,
+    /// The color of the indicator showing where a dragged node is dropped.
+    pub drop_indicator_color: Color,
+    /// The background of the editor of a renamed label.
+    pub editor_background: Color,
+    /// The border color of the editor of a renamed label.
+    pub editor_border_color: Color,
+    /// The color of the text cursor of the editor.
+    pub cursor_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::TRANSPARENT.into(),
+            text_color: Color::BLACK,
+            toggle_color: [0.4, 0.4, 0.4].into(),
+            hovered_background: [0.92, 0.92, 0.92].into(),
+            selected_background: [0.2, 0.5, 0.8].into(),
+            selected_text_color: Color::WHITE,
+            checkbox_background: Color::WHITE,
+            checkbox_border_color: [0.5, 0.5, 0.5].into(),
+            check_color: [0.2, 0.5, 0.8].into(),
+            drop_indicator_color: [0.2, 0.5, 0.8].into(),
+            editor_background: Color::WHITE,
+            editor_border_color: [0.2, 0.5, 0.8].into(),
+            cursor_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`TreeView`](crate::native::tree_view::TreeView).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`TreeView`](crate::native::tree_view::TreeView).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`TreeView`](crate::native::tree_view::TreeView).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum TreeViewStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl TreeViewStyles {
+    /// Creates a custom [`TreeViewStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = TreeViewStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let TreeViewStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            text_color: palette.background.base.text,
+            toggle_color: palette.background.strong.color,
+            hovered_background: palette.background.weak.color,
+            selected_background: palette.primary.base.color,
+            selected_text_color: palette.primary.base.text,
+            checkbox_background: palette.background.base.color,
+            checkbox_border_color: palette.background.strong.color,
+            check_color: palette.primary.base.color,
+            drop_indicator_color: palette.primary.strong.color,
+            editor_background: palette.background.base.color,
+            editor_border_color: palette.primary.strong.color,
+            cursor_color: palette.background.base.text,
+            ..Appearance::default()
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! Use a treemap to show hierarchical size data as nested tiles.
+//!
+//! *This API requires the following crate features to be activated: `treemap`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`Treemap`](crate::native::treemap::Treemap).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`Treemap`](crate::native::treemap::Treemap).
+    pub background: Background,
+    /// The colors of the tiles of the top level, which are used in turn
+    /// unless the tiles are colored by a function.
+    pub palette: [Color; 6],
+    /// The color of the borders of the tiles.
+    pub border_color: Color,
+    /// The color of the labels of the tiles.
+    pub text_color: Color,
+    /// The color of the border of the hovered tile.
+    pub hovered_border_color: Color,
+    /// The background of the breadcrumbs of the shown level.
+    pub breadcrumb_background: Background,
+    /// The color of the breadcrumbs of the shown level.
+    pub breadcrumb_text_color: Color,
+    /// The background of the tooltip of the hovered tile.
+    pub tooltip_background: Background,
+    /// The color of the text of the tooltip of the hovered tile.
+    pub tooltip_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            palette: [
+                [0.31, 0.47, 0.65].into(),
+                [0.95, 0.56, 0.17].into(),
+                [0.88, 0.34, 0.35].into(),
+                [0.46, 0.72, 0.7].into(),
+                [0.35, 0.63, 0.31].into(),
+                [0.69, 0.48, 0.63].into(),
+            ],
+            border_color: Color::WHITE,
+            text_color: Color::WHITE,
+            hovered_border_color: Color::BLACK,
+            breadcrumb_background: Color::from_rgb(0.9, 0.9, 0.9).into(),
+            breadcrumb_text_color: Color::BLACK,
+            tooltip_background: Color::from_rgba(0.0, 0.0, 0.0, 0.8).into(),
+            tooltip_text_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of a [`Treemap`](crate::native::treemap::Treemap).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`Treemap`](crate::native::treemap::Treemap).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`Treemap`](crate::native::treemap::Treemap).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum TreemapStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl TreemapStyles {
+    /// Creates a custom [`TreemapStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = TreemapStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let TreemapStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.base.color,
+            hovered_border_color: palette.background.base.text,
+            breadcrumb_background: palette.background.weak.color.into(),
+            breadcrumb_text_color: palette.background.weak.text,
+            tooltip_background: palette.background.strong.color.into(),
+            tooltip_text_color: palette.background.strong.text,
+            ..Appearance::default()
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! Use a volume slider to change the volume of some audio.
+//!
+//! *This API requires the following crate features to be activated: `volume_slider`*
+use std::rc::Rc;
+
+use iced_widget::{core::Color, style::Theme};
+
+/// The appearance of a [`VolumeSlider`](crate::native::volume_slider::VolumeSlider).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The color of the speaker icon.
+    pub icon_color: Color,
+    /// The color of the rail.
+    pub rail_color: Color,
+    /// The color of the filled part of the rail.
+    pub fill_color: Color,
+    /// The color of the filled part of the rail while muted.
+    pub muted_fill_color: Color,
+    /// The color of the handle.
+    pub handle_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            icon_color: Color::BLACK,
+            rail_color: [0.85, 0.85, 0.85].into(),
+            fill_color: [0.2, 0.5, 0.8].into(),
+            muted_fill_color: [0.6, 0.6, 0.6].into(),
+            handle_color: [0.2, 0.5, 0.8].into(),
+        }
+    }
+}
+
+/// The appearance of a [`VolumeSlider`](crate::native::volume_slider::VolumeSlider).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`VolumeSlider`](crate::native::volume_slider::VolumeSlider).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`VolumeSlider`](crate::native::volume_slider::VolumeSlider).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum VolumeSliderStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl VolumeSliderStyles {
+    /// Creates a custom [`VolumeSliderStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = VolumeSliderStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let VolumeSliderStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            icon_color: palette.background.base.text,
+            rail_color: palette.background.weak.color,
+            fill_color: palette.primary.base.color,
+            muted_fill_color: palette.background.strong.color,
+            handle_color: palette.primary.base.color,
+        }
+    }
+}
@@ -0,0 +1,84 @@
+//! Use a waveform to display the peaks of audio samples.
+//!
+//! *This API requires the following crate features to be activated: `waveform`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`Waveform`](crate::native::waveform::Waveform).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`Waveform`](crate::native::waveform::Waveform).
+    pub background: Background,
+    /// The color of the peaks of the samples.
+    pub wave_color: Color,
+    /// The color of the line at zero amplitude.
+    pub center_line_color: Color,
+    /// The background of the selected region.
+    pub selection_background: Background,
+    /// The color of the playhead marker.
+    pub playhead_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            wave_color: [0.2, 0.5, 0.8].into(),
+            center_line_color: [0.8, 0.8, 0.8].into(),
+            selection_background: Background::Color([0.2, 0.5, 0.8, 0.2].into()),
+            playhead_color: [0.9, 0.2, 0.2].into(),
+        }
+    }
+}
+
+/// The appearance of a [`Waveform`](crate::native::waveform::Waveform).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`Waveform`](crate::native::waveform::Waveform).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`Waveform`](crate::native::waveform::Waveform).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum WaveformStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl WaveformStyles {
+    /// Creates a custom [`WaveformStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = WaveformStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let WaveformStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            wave_color: palette.primary.base.color,
+            center_line_color: palette.background.strong.color,
+            selection_background: Color {
+                a: 0.2,
+                ..palette.primary.base.color
+            }
+            .into(),
+            playhead_color: palette.danger.base.color,
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! Use a wizard to guide through a sequence of pages in a modal dialog.
+//!
+//! *This API requires the following crate features to be activated: `wizard`*
+use std::rc::Rc;
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of the steps of a [`Wizard`](crate::native::wizard::Wizard).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the step of the current page.
+    pub current_color: Background,
+    /// The text color of the step of the current page.
+    pub current_text_color: Color,
+    /// The background of the steps of the reached pages, and of the lines
+    /// between them.
+    pub completed_color: Background,
+    /// The text color of the steps of the reached pages.
+    pub completed_text_color: Color,
+    /// The background of the steps of the pages not reached yet, and of the
+    /// lines between them.
+    pub pending_color: Background,
+    /// The text color of the steps of the pages not reached yet.
+    pub pending_text_color: Color,
+    /// The width of the lines between the steps.
+    pub line_width: f32,
+    /// The color of the title of the current page.
+    pub title_color: Color,
+    /// The color of the titles of the other pages.
+    pub pending_title_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            current_color: Color::from_rgb(0.2, 0.5, 0.8).into(),
+            current_text_color: Color::WHITE,
+            completed_color: Color::from_rgb(0.6, 0.75, 0.9).into(),
+            completed_text_color: Color::WHITE,
+            pending_color: Color::from_rgb(0.85, 0.85, 0.85).into(),
+            pending_text_color: [0.4, 0.4, 0.4].into(),
+            line_width: 2.0,
+            title_color: Color::BLACK,
+            pending_title_color: [0.45, 0.45, 0.45].into(),
+        }
+    }
+}
+
+/// The appearance of the steps of a [`Wizard`](crate::native::wizard::Wizard).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of the steps of a [`Wizard`](crate::native::wizard::Wizard).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of the steps of a [`Wizard`](crate::native::wizard::Wizard).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum WizardStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl WizardStyles {
+    /// Creates a custom [`WizardStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = WizardStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let WizardStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            current_color: palette.primary.strong.color.into(),
+            current_text_color: palette.primary.strong.text,
+            completed_color: palette.primary.weak.color.into(),
+            completed_text_color: palette.primary.weak.text,
+            pending_color: palette.background.strong.color.into(),
+            pending_text_color: palette.background.strong.text,
+            title_color: palette.background.base.text,
+            pending_title_color: palette.background.strong.color,
+            ..Appearance::default()
+        }
+    }
+}
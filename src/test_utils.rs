@@ -0,0 +1,548 @@
+//! Lay out, rasterize and interact with widgets headlessly, e.g. to write
+//! rendering and interaction regression tests.
+//!
+//! A [`Harness`] feeds its element with synthetic mouse, touch and keyboard
+//! [`Input`]s and collects the published messages, just like the runtime of
+//! an application would.
+//!
+//! *This API requires the following crate features to be activated: `test_utils`*
+use crate::headless;
+
+use iced_widget::{
+    core::{
+        clipboard,
+        keyboard::{self, KeyCode, Modifiers},
+        mouse::{self, Cursor},
+        renderer::Style,
+        touch,
+        widget::{operation::Outcome, Operation},
+        window, Color, Element, Event, Point, Size, Vector,
+    },
+    renderer::Renderer,
+    runtime::user_interface::{Cache, UserInterface},
+    style::Theme,
+};
+use std::time::{Duration, Instant};
+
+/// A headless harness laying out an [`Element`], feeding it with events and
+/// rasterizing it to a [`Snapshot`].
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::Text;
+/// # use iced::{Point, Size};
+/// # use iced_aw::{test_utils::Harness, Badge};
+/// #
+/// let mut harness = Harness::new(Badge::new(Text::new("Badge")), Size::new(100.0, 40.0));
+///
+/// let messages: Vec<()> = harness.click(Point::new(50.0, 20.0));
+/// let snapshot = harness.snapshot();
+///
+/// assert_eq!((snapshot.width, snapshot.height), (100, 40));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Harness<'a, Message> {
+    /// The user interface of the element.
+    interface: UserInterface<'a, Message, Renderer<Theme>>,
+    /// The renderer used to lay out and draw the element.
+    renderer: Renderer<Theme>,
+    /// The logical size of the viewport.
+    size: Size,
+    /// The theme the element is drawn with.
+    theme: Theme,
+    /// The scale factor of the viewport.
+    scale_factor: f32,
+    /// The position of the cursor.
+    cursor: Cursor,
+    /// The pressed modifier keys.
+    modifiers: Modifiers,
+    /// The clipboard shared with the element.
+    clipboard: Clipboard,
+    /// The simulated time of the last redraw.
+    now: Instant,
+}
+
+impl<'a, Message> Harness<'a, Message> {
+    /// Creates a new [`Harness`] laying out the given element in a viewport
+    /// of the given logical size.
+    pub fn new<E>(element: E, size: Size) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer<Theme>>>,
+    {
+        let mut renderer = headless::renderer();
+        let interface = UserInterface::build(element, size, Cache::new(), &mut renderer);
+
+        Harness {
+            interface,
+            renderer,
+            size,
+            theme: Theme::default(),
+            scale_factor: 1.0,
+            cursor: Cursor::Unavailable,
+            modifiers: Modifiers::default(),
+            clipboard: Clipboard::default(),
+            now: Instant::now(),
+        }
+    }
+
+    /// Sets the [`Theme`] the element is drawn with.
+    #[must_use]
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Sets the scale factor of the [`Snapshot`]s taken by the [`Harness`].
+    #[must_use]
+    pub fn scale_factor(mut self, scale_factor: f32) -> Self {
+        self.scale_factor = scale_factor;
+        self
+    }
+
+    /// Gets the content of the clipboard shared with the element.
+    #[must_use]
+    pub fn clipboard(&self) -> Option<&str> {
+        self.clipboard.contents.as_deref()
+    }
+
+    /// Sets the content of the clipboard shared with the element.
+    pub fn set_clipboard(&mut self, contents: impl Into<String>) {
+        self.clipboard.contents = Some(contents.into());
+    }
+
+    /// Feeds the element with the given events, returning the published
+    /// messages.
+    pub fn events(&mut self, events: &[Event]) -> Vec<Message> {
+        let mut messages = Vec::new();
+
+        let _ = self.interface.update(
+            events,
+            self.cursor,
+            &mut self.renderer,
+            &mut self.clipboard,
+            &mut messages,
+        );
+
+        messages
+    }
+
+    /// Feeds the element with the given event, returning the published
+    /// messages.
+    pub fn event(&mut self, event: Event) -> Vec<Message> {
+        self.events(&[event])
+    }
+
+    /// Runs the given operation on the element and the operations it chains,
+    /// like the focus operations of the runtime, returning the produced
+    /// messages.
+    pub fn operate(&mut self, operation: &mut dyn Operation<Message>) -> Vec<Message> {
+        self.interface.operate(&self.renderer, operation);
+
+        let mut outcome = operation.finish();
+        while let Outcome::Chain(mut next) = outcome {
+            self.interface.operate(&self.renderer, next.as_mut());
+            outcome = next.finish();
+        }
+
+        match outcome {
+            Outcome::Some(message) => vec![message],
+            Outcome::None | Outcome::Chain(_) => Vec::new(),
+        }
+    }
+
+    /// Moves the cursor to the given position.
+    pub fn move_cursor(&mut self, position: Point) -> Vec<Message> {
+        self.cursor = Cursor::Available(position);
+        self.event(Event::Mouse(mouse::Event::CursorMoved { position }))
+    }
+
+    /// Moves the cursor to the given position and clicks the left mouse
+    /// button.
+    pub fn click(&mut self, position: Point) -> Vec<Message> {
+        let mut messages = self.move_cursor(position);
+        messages.extend(self.events(&[
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ]));
+        messages
+    }
+
+    /// Scrolls the mouse wheel by the given number of lines at the position
+    /// of the cursor.
+    pub fn scroll(&mut self, delta: Vector) -> Vec<Message> {
+        self.event(Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Lines {
+                x: delta.x,
+                y: delta.y,
+            },
+        }))
+    }
+
+    /// Taps the given position with a finger.
+    pub fn tap(&mut self, position: Point) -> Vec<Message> {
+        let id = touch::Finger(0);
+        self.cursor = Cursor::Available(position);
+        self.events(&[
+            Event::Touch(touch::Event::FingerPressed { id, position }),
+            Event::Touch(touch::Event::FingerLifted { id, position }),
+        ])
+    }
+
+    /// Sets the pressed modifier keys.
+    pub fn set_modifiers(&mut self, modifiers: Modifiers) -> Vec<Message> {
+        self.modifiers = modifiers;
+        self.event(Event::Keyboard(keyboard::Event::ModifiersChanged(
+            modifiers,
+        )))
+    }
+
+    /// Presses and releases the given key with the pressed modifier keys.
+    pub fn press_key(&mut self, key_code: KeyCode) -> Vec<Message> {
+        let modifiers = self.modifiers;
+        self.events(&[
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }),
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code,
+                modifiers,
+            }),
+        ])
+    }
+
+    /// Types the given text character by character.
+    pub fn type_text(&mut self, text: &str) -> Vec<Message> {
+        let events: Vec<_> = text
+            .chars()
+            .map(|c| Event::Keyboard(keyboard::Event::CharacterReceived(c)))
+            .collect();
+
+        self.events(&events)
+    }
+
+    /// Moves the cursor to the given position and double clicks the left
+    /// mouse button.
+    pub fn double_click(&mut self, position: Point) -> Vec<Message> {
+        let mut messages = self.click(position);
+        messages.extend(self.click(position));
+        messages
+    }
+
+    /// Drags the left mouse button from the given position to the other in
+    /// the given number of steps.
+    pub fn drag(&mut self, from: Point, to: Point, steps: usize) -> Vec<Message> {
+        let steps = steps.max(1);
+        let mut inputs = vec![Input::MoveCursor(from), Input::Press(mouse::Button::Left)];
+        inputs.extend((1..=steps).map(|step| {
+            let progress = step as f32 / steps as f32;
+            Input::MoveCursor(from + (to - from) * progress)
+        }));
+        inputs.push(Input::Release(mouse::Button::Left));
+
+        self.simulate(&inputs)
+    }
+
+    /// Advances the time of the [`Harness`] by the given duration and
+    /// requests a redraw, driving the animations of the element.
+    pub fn advance(&mut self, duration: Duration) -> Vec<Message> {
+        self.now += duration;
+        self.event(Event::Window(window::Event::RedrawRequested(self.now)))
+    }
+
+    /// Feeds the element with the given sequence of [`Input`]s, returning
+    /// all published messages in order.
+    ///
+    /// # Example
+    /// ```ignore
+    /// # use iced::keyboard::KeyCode;
+    /// # use iced::Point;
+    /// # use iced_aw::test_utils::Input;
+    /// #
+    /// let messages = harness.simulate(&[
+    ///     Input::Click(Point::new(10.0, 10.0)),
+    ///     Input::Key(KeyCode::Up),
+    ///     Input::Key(KeyCode::Up),
+    /// ]);
+    /// ```
+    pub fn simulate(&mut self, inputs: &[Input]) -> Vec<Message> {
+        let mut messages = Vec::new();
+
+        for input in inputs {
+            messages.extend(match input {
+                Input::MoveCursor(position) => self.move_cursor(*position),
+                Input::Press(button) => {
+                    self.event(Event::Mouse(mouse::Event::ButtonPressed(*button)))
+                }
+                Input::Release(button) => {
+                    self.event(Event::Mouse(mouse::Event::ButtonReleased(*button)))
+                }
+                Input::Click(position) => self.click(*position),
+                Input::Scroll(delta) => self.scroll(*delta),
+                Input::FingerPressed(id, position) => {
+                    self.cursor = Cursor::Available(*position);
+                    self.event(Event::Touch(touch::Event::FingerPressed {
+                        id: touch::Finger(*id),
+                        position: *position,
+                    }))
+                }
+                Input::FingerMoved(id, position) => {
+                    self.cursor = Cursor::Available(*position);
+                    self.event(Event::Touch(touch::Event::FingerMoved {
+                        id: touch::Finger(*id),
+                        position: *position,
+                    }))
+                }
+                Input::FingerLifted(id, position) => {
+                    self.cursor = Cursor::Available(*position);
+                    self.event(Event::Touch(touch::Event::FingerLifted {
+                        id: touch::Finger(*id),
+                        position: *position,
+                    }))
+                }
+                Input::Modifiers(modifiers) => self.set_modifiers(*modifiers),
+                Input::Key(key_code) => self.press_key(*key_code),
+                Input::Text(text) => self.type_text(text),
+                Input::Wait(duration) => self.advance(*duration),
+                Input::Event(event) => self.event(event.clone()),
+            });
+        }
+
+        messages
+    }
+
+    /// Draws the element and rasterizes it to a [`Snapshot`].
+    pub fn snapshot(&mut self) -> Snapshot {
+        let _ = self.interface.draw(
+            &mut self.renderer,
+            &self.theme,
+            &Style {
+                text_color: self.theme.palette().text,
+            },
+            self.cursor,
+        );
+
+        let (width, height, pixels) = headless::rasterize(
+            &mut self.renderer,
+            self.size,
+            self.scale_factor,
+            self.theme.palette().background,
+        );
+
+        Snapshot {
+            width,
+            height,
+            pixels,
+        }
+    }
+}
+
+/// A synthetic input fed to the element of a [`Harness`] by
+/// [`Harness::simulate`].
+#[derive(Clone, Debug)]
+pub enum Input {
+    /// Moves the cursor to the position.
+    MoveCursor(Point),
+    /// Presses the mouse button.
+    Press(mouse::Button),
+    /// Releases the mouse button.
+    Release(mouse::Button),
+    /// Moves the cursor to the position and clicks the left mouse button.
+    Click(Point),
+    /// Scrolls the mouse wheel by the number of lines.
+    Scroll(Vector),
+    /// Presses the finger with the id at the position.
+    FingerPressed(u64, Point),
+    /// Moves the finger with the id to the position.
+    FingerMoved(u64, Point),
+    /// Lifts the finger with the id at the position.
+    FingerLifted(u64, Point),
+    /// Sets the pressed modifier keys.
+    Modifiers(Modifiers),
+    /// Presses and releases the key with the pressed modifier keys.
+    Key(KeyCode),
+    /// Types the text character by character.
+    Text(String),
+    /// Advances the time by the duration and requests a redraw.
+    Wait(Duration),
+    /// Feeds the raw event.
+    Event(Event),
+}
+
+/// A rasterized [`Element`] drawn by a [`Harness`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Snapshot {
+    /// The width of the [`Snapshot`] in physical pixels.
+    pub width: u32,
+    /// The height of the [`Snapshot`] in physical pixels.
+    pub height: u32,
+    /// The premultiplied RGBA pixels of the [`Snapshot`], row by row.
+    pub pixels: Vec<u8>,
+}
+
+impl Snapshot {
+    /// Gets the RGBA pixel at the given physical position, if it lies within
+    /// the [`Snapshot`].
+    #[must_use]
+    pub fn pixel(&self, x: u32, y: u32) -> Option<[u8; 4]> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        let index = (y as usize * self.width as usize + x as usize) * 4;
+        self.pixels
+            .get(index..index + 4)
+            .and_then(|pixel| pixel.try_into().ok())
+    }
+
+    /// Gets the [`Color`] of the pixel at the given physical position, if it
+    /// lies within the [`Snapshot`].
+    #[must_use]
+    pub fn color(&self, x: u32, y: u32) -> Option<Color> {
+        self.pixel(x, y).map(|[r, g, b, a]| {
+            if a == 0 {
+                return Color::TRANSPARENT;
+            }
+
+            let alpha = f32::from(a) / 255.0;
+            Color::from_rgba(
+                f32::from(r) / 255.0 / alpha,
+                f32::from(g) / 255.0 / alpha,
+                f32::from(b) / 255.0 / alpha,
+                alpha,
+            )
+        })
+    }
+
+    /// Returns the number of pixels differing from the other [`Snapshot`] by
+    /// more than the given tolerance in any channel, or `None` if the sizes
+    /// differ.
+    #[must_use]
+    pub fn diff(&self, other: &Self, tolerance: u8) -> Option<usize> {
+        if self.width != other.width || self.height != other.height {
+            return None;
+        }
+
+        Some(
+            self.pixels
+                .chunks_exact(4)
+                .zip(other.pixels.chunks_exact(4))
+                .filter(|(a, b)| {
+                    a.iter()
+                        .zip(b.iter())
+                        .any(|(a, b)| a.abs_diff(*b) > tolerance)
+                })
+                .count(),
+        )
+    }
+}
+
+/// An in-memory clipboard shared with the element of a [`Harness`].
+#[derive(Debug, Default)]
+struct Clipboard {
+    /// The contents of the clipboard.
+    contents: Option<String>,
+}
+
+impl clipboard::Clipboard for Clipboard {
+    fn read(&self) -> Option<String> {
+        self.contents.clone()
+    }
+
+    fn write(&mut self, contents: String) {
+        self.contents = Some(contents);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Harness, Input};
+    use iced_widget::{
+        button, container,
+        core::{
+            keyboard::{KeyCode, Modifiers},
+            Color, Length, Point, Size,
+        },
+        style::Theme,
+        text, text_input,
+    };
+
+    #[test]
+    fn snapshot_test() {
+        let mut harness = Harness::<()>::new(text(""), Size::new(20.0, 10.0)).scale_factor(2.0);
+        let snapshot = harness.snapshot();
+
+        assert_eq!((snapshot.width, snapshot.height), (40, 20));
+        assert_eq!(snapshot.pixels.len(), 40 * 20 * 4);
+        assert_eq!(
+            snapshot.color(39, 19),
+            Some(Theme::Light.palette().background)
+        );
+        assert_eq!(snapshot.pixel(40, 0), None);
+        assert_eq!(snapshot.diff(&snapshot, 0), Some(0));
+
+        let dark = Harness::<()>::new(text(""), Size::new(20.0, 10.0))
+            .scale_factor(2.0)
+            .theme(Theme::Dark)
+            .snapshot();
+        assert_eq!(snapshot.diff(&dark, 8), Some(40 * 20));
+        assert_eq!(
+            dark.diff(
+                &Harness::<()>::new(text(""), Size::new(20.0, 10.0)).snapshot(),
+                0
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn click_test() {
+        let content = container(
+            button(text(""))
+                .on_press(())
+                .width(Length::Fixed(50.0))
+                .height(Length::Fixed(20.0)),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill);
+        let mut harness = Harness::new(content, Size::new(100.0, 40.0));
+
+        assert_eq!(harness.click(Point::new(10.0, 10.0)), vec![()]);
+        assert!(harness.click(Point::new(90.0, 30.0)).is_empty());
+        assert_eq!(
+            harness.simulate(&[
+                Input::Click(Point::new(10.0, 10.0)),
+                Input::Click(Point::new(40.0, 15.0)),
+            ]),
+            vec![(), ()]
+        );
+        assert_ne!(
+            harness.snapshot().color(10, 10),
+            Some(Color::WHITE),
+            "the button is drawn"
+        );
+    }
+
+    #[test]
+    fn keyboard_test() {
+        let input = || {
+            text_input("", "")
+                .on_input(|value| value)
+                .on_submit(String::from("submit"))
+        };
+        let mut harness = Harness::new(input(), Size::new(100.0, 40.0));
+
+        // The input only handles the keyboard once it is focused, and keeps
+        // its typed value since the element is not rebuilt.
+        assert!(harness.type_text("a").is_empty());
+        let _ = harness.click(Point::new(50.0, 10.0));
+        assert_eq!(harness.type_text("ab"), vec!["a", "ab"]);
+        assert_eq!(harness.press_key(KeyCode::Enter), vec!["submit"]);
+
+        // Pasting reads the clipboard of the harness.
+        harness.set_clipboard("pasted");
+        let _ = harness.set_modifiers(Modifiers::COMMAND);
+        assert_eq!(harness.press_key(KeyCode::V), vec!["abpasted"]);
+        assert_eq!(harness.clipboard(), Some("pasted"));
+    }
+}